@@ -5,6 +5,8 @@
 //! following hexagonal architecture principles.
 //!
 //! Revision History
+//! - 2026-10-16T18:50:00Z @AI: Add reqwest_provider_health_adapter for provider liveness checks.
 //! - 2025-11-24T00:30:00Z @AI: Create adapters module for clipboard operations.
 
 pub mod arboard_clipboard_adapter;
+pub mod reqwest_provider_health_adapter;
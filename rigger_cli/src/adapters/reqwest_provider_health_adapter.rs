@@ -0,0 +1,232 @@
+//! Reqwest provider health adapter implementation.
+//!
+//! Concrete implementation of the ProviderHealthPort that sends a lightweight
+//! authenticated GET to each provider's model listing endpoint (Ollama
+//! `/api/tags`, OpenAI-compatible `/models`) and classifies the response.
+//!
+//! Revision History
+//! - 2026-10-18T05:10:00Z @AI: Serve test responses through task_manager::test_support::mock_http_server.
+//! - 2026-10-17T14:50:00Z @AI: Set auto_pull in the test provider.
+//! - 2026-10-16T18:50:00Z @AI: Create reqwest provider health adapter for `rig config check-providers`.
+
+/// Reqwest-based provider health adapter.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use rigger_cli::adapters::reqwest_provider_health_adapter::ReqwestProviderHealthAdapter;
+/// # use rigger_cli::ports::provider_health_port::ProviderHealthPort;
+/// # async fn example(provider: rigger_core::config::ProviderConfig) {
+/// let health = ReqwestProviderHealthAdapter::new().check("ollama", &provider).await;
+/// println!("{:?} in {:?}", health.status, health.latency);
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct ReqwestProviderHealthAdapter;
+
+impl ReqwestProviderHealthAdapter {
+    /// Creates a new provider health adapter.
+    pub fn new() -> Self {
+        ReqwestProviderHealthAdapter
+    }
+}
+
+/// Returns the liveness endpoint for a provider.
+///
+/// Configured base URLs already include the API version (e.g.
+/// `https://api.openai.com/v1`), so OpenAI-compatible providers use
+/// `<base_url>/models`. Ollama lists local models at `<base_url>/api/tags`.
+pub fn liveness_url(provider: &rigger_core::config::ProviderConfig) -> String {
+    let base = provider.base_url.trim_end_matches('/');
+    match provider.provider_type {
+        rigger_core::config::ProviderType::Ollama => std::format!("{}/api/tags", base),
+        _ => std::format!("{}/models", base),
+    }
+}
+
+/// Maps an HTTP status from the liveness endpoint to a provider status.
+fn classify(status: reqwest::StatusCode) -> crate::ports::provider_health_port::ProviderStatus {
+    if status.is_success() {
+        crate::ports::provider_health_port::ProviderStatus::Reachable
+    } else if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+        crate::ports::provider_health_port::ProviderStatus::AuthFailed(std::format!("HTTP {}", status))
+    } else {
+        crate::ports::provider_health_port::ProviderStatus::Unreachable(std::format!("HTTP {}", status))
+    }
+}
+
+/// Returns the innermost cause of a request error (e.g. "Connection refused").
+fn root_cause(error: &reqwest::Error) -> String {
+    let mut cause: &dyn std::error::Error = error;
+    while let std::option::Option::Some(source) = cause.source() {
+        cause = source;
+    }
+    cause.to_string()
+}
+
+#[async_trait::async_trait]
+impl crate::ports::provider_health_port::ProviderHealthPort for ReqwestProviderHealthAdapter {
+    async fn check(
+        &self,
+        name: &str,
+        provider: &rigger_core::config::ProviderConfig,
+    ) -> crate::ports::provider_health_port::ProviderHealth {
+        let health = |status, latency| crate::ports::provider_health_port::ProviderHealth {
+            name: std::string::String::from(name),
+            status,
+            latency,
+        };
+
+        let api_key = match provider.get_api_key() {
+            std::result::Result::Ok(key) => key,
+            std::result::Result::Err(_) => {
+                let env_var = provider.api_key_env.clone().unwrap_or_default();
+                return health(
+                    crate::ports::provider_health_port::ProviderStatus::AuthFailed(std::format!("{} is not set", env_var)),
+                    std::time::Duration::ZERO,
+                );
+            }
+        };
+
+        let timeout = std::time::Duration::from_secs(provider.timeout_seconds);
+        let client = match reqwest::Client::builder().timeout(timeout).build() {
+            std::result::Result::Ok(client) => client,
+            std::result::Result::Err(e) => {
+                return health(
+                    crate::ports::provider_health_port::ProviderStatus::Unreachable(e.to_string()),
+                    std::time::Duration::ZERO,
+                );
+            }
+        };
+
+        let mut request = client.get(liveness_url(provider));
+        if let std::option::Option::Some(key) = api_key {
+            request = match provider.provider_type {
+                rigger_core::config::ProviderType::Anthropic => request
+                    .header("x-api-key", key)
+                    .header("anthropic-version", "2023-06-01"),
+                _ => request.bearer_auth(key),
+            };
+        }
+
+        let started = std::time::Instant::now();
+        let result = request.send().await;
+        let latency = started.elapsed();
+
+        let status = match result {
+            std::result::Result::Ok(response) => classify(response.status()),
+            std::result::Result::Err(e) if e.is_timeout() => crate::ports::provider_health_port::ProviderStatus::Unreachable(
+                std::format!("timed out after {}s", provider.timeout_seconds),
+            ),
+            std::result::Result::Err(e) => {
+                crate::ports::provider_health_port::ProviderStatus::Unreachable(root_cause(&e))
+            }
+        };
+        health(status, latency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn provider(
+        provider_type: rigger_core::config::ProviderType,
+        base_url: &str,
+        api_key_env: std::option::Option<&str>,
+    ) -> rigger_core::config::ProviderConfig {
+        rigger_core::config::ProviderConfig {
+            provider_type,
+            base_url: std::string::String::from(base_url),
+            api_key_env: api_key_env.map(std::string::String::from),
+            timeout_seconds: 5,
+            max_retries: 0,
+            default_model: std::string::String::from("test-model"),
+            auto_pull: false,
+        }
+    }
+
+    /// Starts a one-shot HTTP server that answers `expected_path` with `status` (404 otherwise).
+    async fn mock_server(expected_path: &'static str, status: &'static str) -> String {
+        let (base_url, _) = task_manager::test_support::mock_http_server(move |request| {
+            if request.starts_with(&std::format!("GET {} ", expected_path)) {
+                (status, std::string::String::from("{}"))
+            } else {
+                ("404 Not Found", std::string::String::from("{}"))
+            }
+        })
+        .await;
+        base_url
+    }
+
+    #[tokio::test]
+    async fn test_reachable_ollama_provider() {
+        // Test: Validates an Ollama server answering /api/tags with 200 is reported reachable.
+        // Justification: The happy path must hit the right endpoint, or every check would 404.
+        let base_url = mock_server("/api/tags", "200 OK").await;
+        let provider = provider(rigger_core::config::ProviderType::Ollama, &base_url, std::option::Option::None);
+
+        let health = crate::ports::provider_health_port::ProviderHealthPort::check(&super::ReqwestProviderHealthAdapter::new(), "ollama", &provider).await;
+
+        std::assert_eq!(health.name, "ollama");
+        std::assert_eq!(health.status, crate::ports::provider_health_port::ProviderStatus::Reachable);
+    }
+
+    #[tokio::test]
+    async fn test_connection_refused_is_unreachable() {
+        // Test: Validates a closed port is reported unreachable with the connection error.
+        // Justification: A stopped local Ollama is the most common failure.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = std::format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+        let provider = provider(rigger_core::config::ProviderType::Ollama, &base_url, std::option::Option::None);
+
+        let health = crate::ports::provider_health_port::ProviderHealthPort::check(&super::ReqwestProviderHealthAdapter::new(), "ollama", &provider).await;
+
+        match health.status {
+            crate::ports::provider_health_port::ProviderStatus::Unreachable(reason) => {
+                std::assert!(reason.to_lowercase().contains("refused"), "unexpected reason: {}", reason);
+            }
+            other => std::panic!("expected Unreachable, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unauthorized_is_auth_failed() {
+        // Test: Validates a 401 from /models is reported as an auth failure, not unreachable.
+        // Justification: Users need to know to fix the API key rather than the URL.
+        let base_url = mock_server("/v1/models", "401 Unauthorized").await;
+        let provider = provider(
+            rigger_core::config::ProviderType::OpenAI,
+            &std::format!("{}/v1", base_url),
+            // PATH is always set, so it stands in for an API key variable
+            std::option::Option::Some("PATH"),
+        );
+
+        let health = crate::ports::provider_health_port::ProviderHealthPort::check(&super::ReqwestProviderHealthAdapter::new(), "openai", &provider).await;
+
+        std::assert_eq!(
+            health.status,
+            crate::ports::provider_health_port::ProviderStatus::AuthFailed(std::string::String::from("HTTP 401 Unauthorized"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing_api_key_skips_request() {
+        // Test: Validates a provider whose key variable is unset fails auth without a network call.
+        // Justification: Sending an unauthenticated request would only produce a less helpful 401.
+        let provider = provider(
+            rigger_core::config::ProviderType::OpenAI,
+            "http://127.0.0.1:9",
+            std::option::Option::Some("RIGGER_TEST_UNSET_API_KEY"),
+        );
+
+        let health = crate::ports::provider_health_port::ProviderHealthPort::check(&super::ReqwestProviderHealthAdapter::new(), "openai", &provider).await;
+
+        std::assert_eq!(
+            health.status,
+            crate::ports::provider_health_port::ProviderStatus::AuthFailed(std::string::String::from(
+                "RIGGER_TEST_UNSET_API_KEY is not set"
+            ))
+        );
+        std::assert_eq!(health.latency, std::time::Duration::ZERO);
+    }
+}
@@ -0,0 +1,241 @@
+//! Implementation of the 'rig add' command.
+//!
+//! Creates a single task from command-line flags, for work that does not
+//! come from a PRD or transcript.
+//!
+//! Revision History
+//! - 2026-10-18T03:20:00Z @AI: Parse --status with TaskStatus::from_str, accepting every status; unwrap test results only after cleanup.
+//! - 2026-10-17T20:00:00Z @AI: Run the blank-title test in an initialized project and assert on the validation message.
+//! - 2026-10-17T16:50:00Z @AI: Add --estimate.
+//! - 2026-10-17T14:10:00Z @AI: Open the database through services::database_url so --db / RIGGER_DB apply.
+//! - 2026-10-17T11:10:00Z @AI: Leave title and due date checks to ManageTaskUseCase::create and list every validation problem.
+//! - 2026-10-17T10:50:00Z @AI: Keep the TaskManagerError as the cause of create failures.
+//! - 2026-10-17T10:10:00Z @AI: Parse --priority with Priority::from_str, accepting aliases.
+//! - 2026-10-16T16:30:00Z @AI: Apply default-project settings through ManageTaskUseCase::with_project_settings.
+//! - 2026-10-16T16:10:00Z @AI: Initial add command creating tasks via ManageTaskUseCase::create.
+
+/// Executes the 'rig add' command.
+///
+/// Validates the flags, builds a task, and persists it through
+/// ManageTaskUseCase::create (which applies the default project's assignee and
+/// priority defaults, then auto-triages tasks still lacking a priority).
+/// Prints the new task ID.
+///
+/// # Arguments
+///
+/// * `title` - Task title (must be non-empty)
+/// * `description` - Optional detailed description
+/// * `assignee` - Optional agent persona/assignee
+/// * `priority` - Optional priority ("high", "medium", "low")
+/// * `due` - Optional due date (YYYY-MM-DD)
+/// * `status` - Optional initial status (defaults to "todo")
+/// * `estimate` - Optional estimate in story points
+/// * `tags` - Labels to attach to the task
+///
+/// # Returns
+///
+/// The ID of the created task.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Priority or status is not a recognized value
+/// - Title is empty or too long, the due date is not YYYY-MM-DD, or the
+///   estimate is negative (all problems are listed together)
+/// - .rigger directory doesn't exist (run 'rig init' first)
+/// - Database operations fail
+pub async fn execute(
+    title: &str,
+    description: std::option::Option<&str>,
+    assignee: std::option::Option<&str>,
+    priority: std::option::Option<&str>,
+    due: std::option::Option<&str>,
+    status: std::option::Option<&str>,
+    estimate: std::option::Option<f32>,
+    tags: &[String],
+) -> anyhow::Result<String> {
+    let title = title.trim();
+
+    let priority = match priority {
+        std::option::Option::Some(p) => std::option::Option::Some(parse_priority(p)?),
+        std::option::Option::None => std::option::Option::None,
+    };
+    let status = match status {
+        std::option::Option::Some(s) => parse_status(s)?,
+        std::option::Option::None => task_manager::domain::task_status::TaskStatus::Todo,
+    };
+
+    // Check if .rigger exists
+    let current_dir = std::env::current_dir()?;
+    let rigger_dir = current_dir.join(".rigger");
+
+    if !rigger_dir.exists() {
+        anyhow::bail!(
+            ".rigger directory not found.\nRun 'rig init' first to initialize the project."
+        );
+    }
+
+    // Connect to database
+    let db_path = rigger_dir.join("tasks.db");
+    let db_url = crate::services::database_url::url_for(&db_path);
+
+    let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect to database: {}", e))?;
+
+    // Build task
+    let action = transcript_extractor::domain::action_item::ActionItem {
+        title: std::string::String::from(title),
+        assignee: assignee.map(std::string::String::from),
+        due_date: due.map(std::string::String::from),
+    };
+    let mut task = task_manager::domain::task::Task::from_action_item(&action, std::option::Option::None);
+    task.description = description.map(std::string::String::from).unwrap_or_default();
+    task.status = status;
+    task.priority = priority;
+    task.estimate = estimate;
+    task.tags = tags.to_vec();
+
+    // Persist via use case (project defaults, then auto-triage, fill in what was not supplied)
+    let project_settings = crate::commands::parse::load_project_settings(&db_url, "default-project").await?;
+    let mut use_case = task_manager::use_cases::manage_task::ManageTaskUseCase::new(adapter)
+        .with_project_settings(project_settings);
+    let created = use_case.create(task).map_err(|e| {
+        if let task_manager::domain::task_manager_error::TaskManagerError::ValidationFailed { errors } = &e {
+            eprintln!("Invalid task:");
+            for error in errors {
+                eprintln!("  - {}", error);
+            }
+        }
+        anyhow::Error::new(e).context("Failed to create task")
+    })?;
+
+    println!("✓ Created task {}", created.id);
+
+    std::result::Result::Ok(created.id)
+}
+
+/// Parses a priority flag value (case-insensitive, aliases such as `hi` and `p0` accepted).
+///
+/// # Errors
+///
+/// Returns an error listing the valid values if `value` is not recognized.
+fn parse_priority(value: &str) -> anyhow::Result<task_manager::domain::priority::Priority> {
+    value.parse::<task_manager::domain::priority::Priority>().map_err(|e| anyhow::anyhow!(e))
+}
+
+/// Parses a status flag value (case-insensitive, e.g. `in_progress` or `in-progress`).
+///
+/// # Errors
+///
+/// Returns an error listing the valid values if `value` is not recognized.
+fn parse_status(value: &str) -> anyhow::Result<task_manager::domain::task_status::TaskStatus> {
+    value.parse::<task_manager::domain::task_status::TaskStatus>().map_err(|e| anyhow::anyhow!(e))
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test(flavor = "multi_thread")]
+    #[serial_test::serial]
+    async fn test_add_creates_task() {
+        // Test: Validates a task is persisted with the supplied fields.
+        // Justification: `rig add` is the only manual way to enter tasks.
+        let temp_dir = std::env::temp_dir().join(std::format!("rigger_test_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir(&temp_dir).unwrap();
+
+        let original_dir = std::env::current_dir().unwrap();
+        std::env::set_current_dir(&temp_dir).unwrap();
+
+        crate::commands::init::execute().await.unwrap();
+        let tags = std::vec![std::string::String::from("backend")];
+        let result = super::execute(
+            "Rotate API keys",
+            std::option::Option::Some("Keys expire next month"),
+            std::option::Option::None,
+            std::option::Option::Some("High"),
+            std::option::Option::Some("2026-11-01"),
+            std::option::Option::None,
+            std::option::Option::Some(3.0),
+            &tags,
+        )
+        .await;
+
+        // Look the task up before cleanup, but unwrap only after it so a failure does not leak the temp dir
+        let db_url = std::format!("sqlite:{}", temp_dir.join(".rigger").join("tasks.db").display());
+        let saved = match &result {
+            std::result::Result::Ok(id) => match task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url).await {
+                std::result::Result::Ok(adapter) => adapter
+                    .find_one_async(&task_manager::ports::task_repository_port::TaskFilter::ById(id.clone()))
+                    .await
+                    .map_err(|e| e.to_string()),
+                std::result::Result::Err(e) => std::result::Result::Err(e.to_string()),
+            },
+            std::result::Result::Err(_) => std::result::Result::Ok(std::option::Option::None),
+        };
+
+        let _ = std::env::set_current_dir(original_dir);
+        let _ = std::fs::remove_dir_all(&temp_dir);
+
+        result.unwrap();
+        let saved = saved.unwrap().expect("the added task is saved");
+        std::assert_eq!(saved.title, "Rotate API keys");
+        std::assert_eq!(saved.description, "Keys expire next month");
+        std::assert_eq!(saved.priority, std::option::Option::Some(task_manager::domain::priority::Priority::High));
+        std::assert_eq!(saved.due_date.as_deref(), std::option::Option::Some("2026-11-01"));
+        std::assert!(saved.tags.contains(&std::string::String::from("backend")));
+        std::assert_eq!(saved.estimate, std::option::Option::Some(3.0));
+    }
+
+    #[tokio::test]
+    async fn test_add_rejects_invalid_priority() {
+        // Test: Validates an unknown priority fails with the list of valid values.
+        // Justification: Typos like "urgent" should not silently fall back to auto-triage.
+        let result = super::execute(
+            "Rotate API keys",
+            std::option::Option::None,
+            std::option::Option::None,
+            std::option::Option::Some("urgent"),
+            std::option::Option::None,
+            std::option::Option::None,
+            std::option::Option::None,
+            &[],
+        )
+        .await;
+
+        let err = result.expect_err("Invalid priority should fail").to_string();
+        std::assert!(err.contains("Invalid priority: 'urgent'"));
+        std::assert!(err.contains("high, medium, low"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[serial_test::serial]
+    async fn test_add_rejects_empty_title() {
+        // Test: Validates a blank title is rejected by task validation in an initialized project.
+        // Justification: Tasks without titles cannot be listed or searched meaningfully.
+        let temp_dir = std::env::temp_dir().join(std::format!("rigger_test_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir(&temp_dir).unwrap();
+
+        let original_dir = std::env::current_dir().unwrap();
+        std::env::set_current_dir(&temp_dir).unwrap();
+
+        crate::commands::init::execute().await.unwrap();
+        let result = super::execute(
+            "   ",
+            std::option::Option::None,
+            std::option::Option::None,
+            std::option::Option::None,
+            std::option::Option::None,
+            std::option::Option::None,
+            std::option::Option::None,
+            &[],
+        )
+        .await;
+
+        let _ = std::env::set_current_dir(original_dir);
+        let _ = std::fs::remove_dir_all(&temp_dir);
+
+        let err = std::format!("{:#}", result.expect_err("Blank title should fail"));
+        std::assert!(err.contains("Failed to create task"), "{}", err);
+        std::assert!(err.contains("title: must not be empty"), "{}", err);
+    }
+}
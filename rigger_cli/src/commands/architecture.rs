@@ -0,0 +1,28 @@
+//! Implementation of the hidden 'rig architecture' command.
+//!
+//! Prints the task_orchestrator ports, adapters, and flow graph as text,
+//! JSON, or a Graphviz DOT digraph (`rig architecture --format dot | dot -Tsvg`).
+//!
+//! Revision History
+//! - 2026-10-16T21:50:00Z @AI: Initial architecture command with text, json, and dot formats.
+
+/// Executes the 'rig architecture' command.
+///
+/// # Arguments
+///
+/// * `format` - Output format ("text", "json", or "dot")
+///
+/// # Errors
+///
+/// Returns an error if the format is not one of "text", "json", or "dot".
+pub fn execute(format: &str) -> anyhow::Result<()> {
+    let format: task_orchestrator::architecture::describe_architecture::ArchitectureFormat =
+        format.parse().map_err(|e: String| anyhow::anyhow!(e))?;
+    let output = task_orchestrator::architecture::describe_architecture::describe_architecture_as(format)
+        .map_err(|e| anyhow::anyhow!(e))?;
+    print!("{}", output);
+    if !output.ends_with('\n') {
+        println!();
+    }
+    std::result::Result::Ok(())
+}
@@ -1,9 +1,29 @@
 //! Implementation of 'rig artifacts' commands.
 //!
-//! Provides CLI commands for listing, searching, and generating knowledge
-//! artifacts in the RAG system.
+//! Provides CLI commands for listing, searching, generating, deleting, and
+//! re-embedding knowledge artifacts in the RAG system.
 //!
 //! Revision History
+//! - 2026-10-18T04:00:00Z @AI: Label progress events of pages still being fetched.
+//! - 2026-10-18T03:00:00Z @AI: Move parse_source_type below confirm so confirm keeps its doc comment.
+//! - 2026-10-18T01:10:00Z @AI: search embeds with the embedding slot, re-ranks with the main slot, reads search settings from the merged config, and warns when re-ranking falls back.
+//! - 2026-10-18T00:10:00Z @AI: --ocr sets ScanConfig::include_images instead of replacing the text extension allowlist.
+//! - 2026-10-17T18:30:00Z @AI: Add search --source-type, --ext, and --language filters; list and search share parse_source_type (now also image, pdf).
+//! - 2026-10-17T18:10:00Z @AI: Optionally re-rank search hits with the chat model (--rerank or search.rerank in config).
+//! - 2026-10-17T14:10:00Z @AI: Open the database through services::database_url so --db / RIGGER_DB apply.
+//! - 2026-10-17T13:50:00Z @AI: Read the config path from services::config_path, honoring --config and RIGGER_CONFIG.
+//! - 2026-10-17T13:30:00Z @AI: Send conditional requests on re-crawls and add --force to refetch unchanged pages.
+//! - 2026-10-17T13:10:00Z @AI: Add --resume to continue an interrupted crawl from its saved frontier.
+//! - 2026-10-17T12:50:00Z @AI: Add --crawl-delay-ms per-host politeness delay.
+//! - 2026-10-17T12:30:00Z @AI: Add --include-subdomains and --path-prefix crawl scope options; report skipped and out-of-scope links.
+//! - 2026-10-16T22:30:00Z @AI: Search through ArtifactService so mismatched embedding dimensions are refused; add reembed command.
+//! - 2026-10-16T21:10:00Z @AI: search and generate honor offline mode when creating the embedding provider.
+//! - 2026-10-16T15:10:00Z @AI: Render generation progress events as an indicatif progress bar.
+//! - 2026-10-16T14:30:00Z @AI: Print skip warnings (e.g., encrypted or image-only PDFs) after single-file generation.
+//! - 2026-10-16T14:10:00Z @AI: Auto-detect generate source kind (directory, file, PDF, image, URL) and ingest single files.
+//! - 2026-10-16T13:50:00Z @AI: Add delete and purge commands; purge asks for confirmation unless --yes.
+//! - 2026-10-16T13:30:00Z @AI: Add --offset pagination to list with a "showing X–Y of N" summary.
+//! - 2026-10-16T12:50:00Z @AI: Add --ocr flag to generate for transcribing image text via the vision adapter.
 //! - 2025-11-30T21:30:00Z @AI: Add generate command for Phase 5 artifact generator CLI.
 //! - 2025-11-28T23:00:00Z @AI: Create artifacts CLI commands for Phase 6 (Task 6.1, 6.2).
 
@@ -15,8 +35,9 @@
 /// # Arguments
 ///
 /// * `project_id` - Optional project ID filter
-/// * `source_type` - Optional source type filter (prd, file, web_research, user_input)
-/// * `limit` - Maximum number of artifacts to display
+/// * `source_type` - Optional source type filter (prd, file, web_research, user_input, image, pdf)
+/// * `limit` - Maximum number of artifacts to display (default: 20)
+/// * `offset` - Number of artifacts to skip for pagination (default: 0)
 ///
 /// # Errors
 ///
@@ -28,6 +49,7 @@ pub async fn list(
     project_id: std::option::Option<&str>,
     source_type: std::option::Option<&str>,
     limit: std::option::Option<usize>,
+    offset: std::option::Option<usize>,
 ) -> anyhow::Result<()> {
     // Check if .rigger exists
     let current_dir = std::env::current_dir()?;
@@ -41,7 +63,7 @@ pub async fn list(
 
     // Connect to database
     let db_path = rigger_dir.join("tasks.db");
-    let db_url = std::format!("sqlite:{}", db_path.display());
+    let db_url = crate::services::database_url::url_for(&db_path);
 
     let adapter = task_manager::adapters::sqlite_artifact_adapter::SqliteArtifactAdapter::connect_and_init(&db_url)
         .await
@@ -51,55 +73,45 @@ pub async fn list(
     let filter = if let std::option::Option::Some(proj_id) = project_id {
         task_manager::ports::artifact_repository_port::ArtifactFilter::ByProjectId(std::string::String::from(proj_id))
     } else if let std::option::Option::Some(src_type) = source_type {
-        // Parse source type string to ArtifactType enum
-        let artifact_type = match src_type.to_lowercase().as_str() {
-            "prd" => task_manager::domain::artifact::ArtifactType::PRD,
-            "file" => task_manager::domain::artifact::ArtifactType::File,
-            "web_research" | "web" => task_manager::domain::artifact::ArtifactType::WebResearch,
-            "user_input" | "user" => task_manager::domain::artifact::ArtifactType::UserInput,
-            _ => {
-                anyhow::bail!(
-                    "Invalid source_type: '{}'. Valid values: prd, file, web_research, user_input",
-                    src_type
-                );
-            }
-        };
-        task_manager::ports::artifact_repository_port::ArtifactFilter::BySourceType(artifact_type)
+        task_manager::ports::artifact_repository_port::ArtifactFilter::BySourceType(parse_source_type(src_type)?)
     } else {
         task_manager::ports::artifact_repository_port::ArtifactFilter::All
     };
 
-    // Query artifacts
-    let sort = std::vec![hexser::ports::repository::Sort {
-        key: task_manager::ports::artifact_repository_port::ArtifactSortKey::CreatedAt,
-        direction: hexser::ports::repository::Direction::Desc,
-    }];
-
-    let find_options = hexser::ports::repository::FindOptions {
-        sort: std::option::Option::Some(sort),
-        limit: limit.map(|l| l as u32).or(std::option::Option::Some(20)),
-        offset: std::option::Option::None,
-    };
-
-    let artifacts = hexser::ports::repository::QueryRepository::find(&adapter, &filter, find_options)
-        .map_err(|e| anyhow::anyhow!("Failed to query artifacts: {}", e))?;
+    // Query one page of artifacts with the total count
+    let page = task_orchestrator::services::artifact_service::list_artifacts(
+        &adapter,
+        &filter,
+        limit.unwrap_or(20),
+        offset.unwrap_or(0),
+    )
+    .map_err(|e| anyhow::anyhow!(e))?;
 
     // Display results
-    if artifacts.is_empty() {
-        println!("No artifacts found.");
+    if page.is_empty() {
+        if page.total > 0 {
+            println!("No artifacts at offset {} ({} total).", page.offset, page.total);
+        } else {
+            println!("No artifacts found.");
+        }
         return std::result::Result::Ok(());
     }
 
-    println!("Found {} artifacts:\n", artifacts.len());
+    println!(
+        "Showing {}–{} of {} artifacts:\n",
+        page.offset + 1,
+        page.offset + page.artifacts.len(),
+        page.total
+    );
 
-    for (i, artifact) in artifacts.iter().enumerate() {
+    for (i, artifact) in page.artifacts.iter().enumerate() {
         let content_preview = if artifact.content.len() > 100 {
             std::format!("{}...", &artifact.content[..100])
         } else {
             artifact.content.clone()
         };
 
-        println!("{}. [{}] {:?}", i + 1, artifact.id, artifact.source_type);
+        println!("{}. [{}] {:?}", page.offset + i + 1, artifact.id, artifact.source_type);
         println!("   Project: {}", artifact.project_id);
         println!("   Source: {}", artifact.source_id);
         println!("   Content: {}", content_preview);
@@ -110,6 +122,142 @@ pub async fn list(
     std::result::Result::Ok(())
 }
 
+/// Executes the 'rig artifacts delete' command.
+///
+/// Deletes a single artifact together with its embedding so it no longer
+/// appears in listings or semantic search.
+///
+/// # Arguments
+///
+/// * `id` - ID of the artifact to delete
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - .rigger directory doesn't exist
+/// - Database connection fails
+/// - No artifact has the given ID
+/// - Delete fails
+pub async fn delete(id: &str) -> anyhow::Result<()> {
+    let current_dir = std::env::current_dir()?;
+    let rigger_dir = current_dir.join(".rigger");
+
+    if !rigger_dir.exists() {
+        anyhow::bail!(
+            ".rigger directory not found.\nRun 'rig init' first to initialize the project."
+        );
+    }
+
+    let db_path = rigger_dir.join("tasks.db");
+    let db_url = crate::services::database_url::url_for(&db_path);
+
+    let mut adapter = task_manager::adapters::sqlite_artifact_adapter::SqliteArtifactAdapter::connect_and_init(&db_url)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect to database: {}", e))?;
+
+    let deleted = task_orchestrator::services::artifact_service::delete_artifact(&mut adapter, id)
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+    if !deleted {
+        anyhow::bail!("Artifact not found: {}", id);
+    }
+
+    println!("✓ Deleted artifact {}", id);
+
+    std::result::Result::Ok(())
+}
+
+/// Executes the 'rig artifacts purge' command.
+///
+/// Deletes every artifact (and embedding) belonging to a project. Unless
+/// `yes` is set, the user is shown how many artifacts will be removed and
+/// must confirm before anything is deleted.
+///
+/// # Arguments
+///
+/// * `project_id` - Project whose artifacts are deleted
+/// * `yes` - Skip the confirmation prompt
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - .rigger directory doesn't exist
+/// - Database connection fails
+/// - Reading the confirmation fails
+/// - Delete fails
+pub async fn purge(project_id: &str, yes: bool) -> anyhow::Result<()> {
+    let current_dir = std::env::current_dir()?;
+    let rigger_dir = current_dir.join(".rigger");
+
+    if !rigger_dir.exists() {
+        anyhow::bail!(
+            ".rigger directory not found.\nRun 'rig init' first to initialize the project."
+        );
+    }
+
+    let db_path = rigger_dir.join("tasks.db");
+    let db_url = crate::services::database_url::url_for(&db_path);
+
+    let mut adapter = task_manager::adapters::sqlite_artifact_adapter::SqliteArtifactAdapter::connect_and_init(&db_url)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect to database: {}", e))?;
+
+    let filter = task_manager::ports::artifact_repository_port::ArtifactFilter::ByProjectId(std::string::String::from(project_id));
+    let total = hexser::ports::repository::QueryRepository::count(&adapter, &filter)
+        .map_err(|e| anyhow::anyhow!("Failed to count artifacts: {}", e))?;
+
+    if total == 0 {
+        println!("No artifacts found for project '{}'.", project_id);
+        return std::result::Result::Ok(());
+    }
+
+    if !yes && !confirm(&std::format!("Delete {} artifacts for project '{}'?", total, project_id))? {
+        println!("Aborted.");
+        return std::result::Result::Ok(());
+    }
+
+    let deleted = task_orchestrator::services::artifact_service::delete_artifacts_by_project(&mut adapter, project_id)
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+    println!("✓ Deleted {} artifacts for project '{}'", deleted, project_id);
+
+    std::result::Result::Ok(())
+}
+
+/// Asks a yes/no question on stdin, defaulting to no.
+///
+/// # Returns
+///
+/// True only if the user answers "y" or "yes" (case-insensitive).
+fn confirm(question: &str) -> anyhow::Result<bool> {
+    print!("{} [y/N] ", question);
+    std::io::Write::flush(&mut std::io::stdout())?;
+
+    let mut answer = std::string::String::new();
+    std::io::stdin().read_line(&mut answer)?;
+
+    let answer = answer.trim().to_lowercase();
+    std::result::Result::Ok(answer == "y" || answer == "yes")
+}
+
+/// Parses a --source-type value into an ArtifactType (case-insensitive).
+fn parse_source_type(value: &str) -> anyhow::Result<task_manager::domain::artifact::ArtifactType> {
+    std::result::Result::Ok(match value.trim().to_lowercase().as_str() {
+        "prd" => task_manager::domain::artifact::ArtifactType::PRD,
+        "file" => task_manager::domain::artifact::ArtifactType::File,
+        "web_research" | "web" => task_manager::domain::artifact::ArtifactType::WebResearch,
+        "user_input" | "user" => task_manager::domain::artifact::ArtifactType::UserInput,
+        "image" => task_manager::domain::artifact::ArtifactType::Image,
+        "pdf" => task_manager::domain::artifact::ArtifactType::PDF,
+        _ => {
+            anyhow::bail!(
+                "Invalid source_type: '{}'. Valid values: prd, file, web_research, user_input, image, pdf",
+                value
+            );
+        }
+    })
+}
+
 /// Executes the 'rig artifacts search' command.
 ///
 /// Performs semantic search on the artifact knowledge base using vector
@@ -121,19 +269,31 @@ pub async fn list(
 /// * `limit` - Maximum number of results to return (default: 5)
 /// * `threshold` - Minimum similarity threshold 0.0-1.0 (default: 0.5)
 /// * `project_id` - Optional project ID to scope search
+/// * `source_types` - Only artifacts of these source types (any of)
+/// * `extensions` - Only artifacts whose source has one of these extensions
+/// * `language` - Only artifacts whose source is in this language
+/// * `rerank` - Force LLM re-ranking on or off; None uses search.rerank from config
+///
+/// All filters must match and are applied before ranking.
 ///
 /// # Errors
 ///
 /// Returns an error if:
 /// - .rigger directory doesn't exist
+/// - A source type or language is not recognized
 /// - Database connection fails
 /// - Embedding service is unavailable
+/// - Stored artifacts were embedded with a different dimension than the query
 /// - Search fails
 pub async fn search(
     query: &str,
     limit: std::option::Option<usize>,
     threshold: std::option::Option<f32>,
     project_id: std::option::Option<&str>,
+    source_types: &[String],
+    extensions: &[String],
+    language: std::option::Option<&str>,
+    rerank: std::option::Option<bool>,
 ) -> anyhow::Result<()> {
     // Check if .rigger exists
     let current_dir = std::env::current_dir()?;
@@ -145,51 +305,81 @@ pub async fn search(
         );
     }
 
-    // Read config to determine provider
-    let config_path = rigger_dir.join("config.json");
-    let config_content = std::fs::read_to_string(&config_path)
-        .map_err(|e| anyhow::anyhow!("Failed to read config.json: {}", e))?;
-    let config: serde_json::Value = serde_json::from_str(&config_content)?;
+    // Build the metadata filter up front so typos fail before any embedding call
+    let mut filter = task_manager::ports::artifact_repository_port::ArtifactSearchFilter::default()
+        .with_project(project_id.map(|s| std::string::String::from(s)));
+    for source_type in source_types {
+        filter = filter.with_source_type(parse_source_type(source_type)?);
+    }
+    for extension in extensions {
+        filter = filter.with_extension(extension);
+    }
+    if let std::option::Option::Some(language) = language {
+        if task_manager::ports::artifact_repository_port::extensions_for_language(language).is_none() {
+            anyhow::bail!(
+                "Unknown language: '{}'. Use --ext to filter by file extension instead.",
+                language
+            );
+        }
+        filter = filter.with_language(language);
+    }
 
-    let provider = config["provider"]
-        .as_str()
-        .unwrap_or("ollama");
+    // Read the merged config (config.json plus config.local.json)
+    let config = crate::services::provider_slot::load_config(&rigger_dir)?;
+    let embedding_slot = &config.task_slots.embedding;
 
     println!("Searching artifacts for: \"{}\"", query);
-    println!("Using {} embedding service...\n", provider);
+    println!("Using {} embedding service ({})...\n", embedding_slot.provider, embedding_slot.model);
 
     // Connect to database
     let db_path = rigger_dir.join("tasks.db");
-    let db_url = std::format!("sqlite:{}", db_path.display());
+    let db_url = crate::services::database_url::url_for(&db_path);
 
     let artifact_adapter = task_manager::adapters::sqlite_artifact_adapter::SqliteArtifactAdapter::connect_and_init(&db_url)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to connect to database: {}", e))?;
 
-    // Create embedding adapter using provider factory
-    let provider_factory = task_orchestrator::adapters::provider_factory::ProviderFactory::new(provider, "default")
-        .map_err(|e| anyhow::anyhow!("Failed to create provider factory: {}", e))?;
-
-    let embedding_adapter = provider_factory.create_embedding_adapter()
+    // Create embedding adapter from the embedding slot
+    let embedding_adapter = crate::services::provider_slot::embedding_factory(&config)?
+        .create_embedding_adapter()
         .map_err(|e| anyhow::anyhow!("Failed to create embedding adapter: {}", e))?;
 
-    // Generate query embedding
-    let query_embedding = embedding_adapter.generate_embedding(query)
-        .await
-        .map_err(|e| anyhow::anyhow!("Failed to generate embedding: {}", e))?;
-
-    // Search for similar artifacts
+    // Search for similar artifacts (refused if stored embeddings have another dimension)
     let search_limit = limit.unwrap_or(5);
     let search_threshold = threshold.unwrap_or(0.5);
-    let search_project_id = project_id.map(|s| std::string::String::from(s));
 
-    let similar_artifacts = task_manager::ports::artifact_repository_port::ArtifactRepositoryPort::find_similar(
-        &artifact_adapter,
-        &query_embedding,
-        search_limit,
-        std::option::Option::Some(search_threshold),
-        search_project_id,
-    ).map_err(|e| anyhow::anyhow!("Failed to search artifacts: {}", e))?;
+    let mut service = task_orchestrator::services::artifact_service::ArtifactService::new(
+        std::sync::Arc::new(std::sync::Mutex::new(artifact_adapter)),
+        embedding_adapter,
+    );
+
+    // Optional LLM re-ranking with the main slot's chat model; the flag overrides search.rerank in config
+    let search_config = &config.search;
+    if rerank.unwrap_or(search_config.rerank) {
+        let reranker = crate::services::provider_slot::factory_for(&config, &config.task_slots.main)
+            .and_then(|factory| factory.create_rerank_adapter().map_err(|e| anyhow::anyhow!("{}", e)));
+        match reranker {
+            std::result::Result::Ok(reranker) => {
+                println!(
+                    "Re-ranking the top {} hits with the chat model (one extra LLM call)...\n",
+                    search_config.rerank_candidates.max(search_limit)
+                );
+                service = service.with_reranker(reranker, search_config.rerank_candidates);
+            }
+            std::result::Result::Err(e) => {
+                eprintln!("Warning: re-ranking unavailable, using vector order: {}", e);
+            }
+        }
+    }
+
+    let outcome = service
+        .search_with_outcome(query, search_limit, std::option::Option::Some(search_threshold), &filter)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to search artifacts: {}", e))?;
+    if let std::option::Option::Some(reason) = &outcome.rerank_fallback {
+        eprintln!("Warning: re-ranking failed, results are in vector order: {}", reason);
+    }
+    let similar_artifacts = outcome.hits;
 
     // Display results
     if similar_artifacts.is_empty() {
@@ -198,6 +388,9 @@ pub async fn search(
         println!("  - Using different keywords");
         println!("  - Lowering the threshold (--threshold 0.3)");
         println!("  - Increasing the limit (--limit 10)");
+        if !filter.source_types.is_empty() || !filter.extensions.is_empty() || filter.language.is_some() {
+            println!("  - Removing --source-type, --ext, or --language filters");
+        }
         return std::result::Result::Ok(());
     }
 
@@ -226,26 +419,125 @@ pub async fn search(
     std::result::Result::Ok(())
 }
 
+/// Executes the 'rig artifacts reembed' command.
+///
+/// Regenerates the embedding of every stored artifact with `model` and
+/// rebuilds the vector index for the new dimension. Run after changing the
+/// embedding model, since search refuses to compare vectors of different
+/// dimensions.
+///
+/// # Arguments
+///
+/// * `model` - Embedding model to use (e.g., "nomic-embed-text", "text-embedding-3-small")
+/// * `yes` - Skip the confirmation prompt
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - .rigger directory doesn't exist
+/// - Database connection fails
+/// - The configured provider has no embedding support
+/// - Embedding generation fails (existing embeddings are kept)
+/// - Saving the new embeddings fails
+pub async fn reembed(model: &str, yes: bool) -> anyhow::Result<()> {
+    let current_dir = std::env::current_dir()?;
+    let rigger_dir = current_dir.join(".rigger");
+
+    if !rigger_dir.exists() {
+        anyhow::bail!(
+            ".rigger directory not found.\nRun 'rig init' first to initialize the project."
+        );
+    }
+
+    let config_path = crate::services::config_path::path_in(&rigger_dir);
+    let config_content = std::fs::read_to_string(&config_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read config.json: {}", e))?;
+    let config: serde_json::Value = serde_json::from_str(&config_content)?;
+
+    let provider = config["provider"]
+        .as_str()
+        .unwrap_or("ollama");
+
+    let db_path = rigger_dir.join("tasks.db");
+    let db_url = crate::services::database_url::url_for(&db_path);
+
+    let artifact_adapter = task_manager::adapters::sqlite_artifact_adapter::SqliteArtifactAdapter::connect_and_init(&db_url)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect to database: {}", e))?;
+
+    let total = hexser::ports::repository::QueryRepository::count(
+        &artifact_adapter,
+        &task_manager::ports::artifact_repository_port::ArtifactFilter::All,
+    )
+    .map_err(|e| anyhow::anyhow!("Failed to count artifacts: {}", e))?;
+
+    if total == 0 {
+        println!("No artifacts to re-embed.");
+        return std::result::Result::Ok(());
+    }
+
+    if !yes && !confirm(&std::format!("Re-embed {} artifacts with {} ({})?", total, model, provider))? {
+        println!("Aborted.");
+        return std::result::Result::Ok(());
+    }
+
+    let provider_factory = task_orchestrator::adapters::provider_factory::ProviderFactory::new(provider, "default")
+        .map_err(|e| anyhow::anyhow!("Failed to create provider factory: {}", e))?
+        .with_offline(config["offline"].as_bool().unwrap_or(false))
+        .map_err(|e| anyhow::anyhow!("Failed to create provider factory: {}", e))?
+        .with_embedding_model(model);
+
+    let embedding_adapter = provider_factory.create_embedding_adapter()
+        .map_err(|e| anyhow::anyhow!("Failed to create embedding adapter: {}", e))?;
+
+    println!("Re-embedding {} artifacts with {}...", total, model);
+
+    let service = task_orchestrator::services::artifact_service::ArtifactService::new(
+        std::sync::Arc::new(std::sync::Mutex::new(artifact_adapter)),
+        embedding_adapter,
+    );
+    let summary = service
+        .reembed(32)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to re-embed artifacts: {}", e))?;
+
+    println!(
+        "✓ Re-embedded {} artifacts with {} ({} dimensions)",
+        summary.artifacts,
+        summary.model.as_deref().unwrap_or(model),
+        summary.dimension
+    );
+
+    std::result::Result::Ok(())
+}
+
 /// Executes the 'rig artifacts generate' command.
 ///
-/// Generates artifacts from a directory or website by scanning/crawling,
-/// chunking content, generating embeddings, and persisting to the database.
+/// Generates artifacts from a directory, single file (text, PDF, image), or
+/// website. The source kind is detected automatically; content is chunked,
+/// embedded, and persisted to the database.
 ///
 /// # Arguments
 ///
-/// * `source` - Directory path or URL to generate artifacts from
-/// * `project_id` - Optional project ID (defaults to directory name or domain)
+/// * `source` - Directory path, file path, or URL to generate artifacts from
+/// * `project_id` - Optional project ID (defaults to directory name, file stem, or domain)
 /// * `depth` - Maximum recursion depth (default: 10)
 /// * `max_items` - Maximum files/pages to process (default: 1000)
 /// * `chunk_strategy` - Chunking strategy: paragraph, sentence, fixed_size, whole_file
 /// * `chunk_size` - Max chunk size for fixed_size strategy (default: 1000)
 /// * `exclude_patterns` - Additional glob patterns to exclude
+/// * `ocr` - Transcribe text from image files with the provider's vision model
+/// * `include_subdomains` - Also crawl subdomains of the URL's host
+/// * `path_prefix` - Only crawl links whose path starts with this prefix
+/// * `crawl_delay_ms` - Minimum delay between requests to the same host
+/// * `resume` - Continue an interrupted crawl of the URL from its saved frontier
+/// * `force` - Refetch every page instead of skipping ones unchanged since the last crawl
 ///
 /// # Errors
 ///
 /// Returns an error if:
 /// - .rigger directory doesn't exist
-/// - Source path/URL is invalid
+/// - Source path doesn't exist and isn't an http(s) URL
 /// - Scanning/crawling fails
 /// - Embedding generation fails
 pub async fn generate(
@@ -256,6 +548,12 @@ pub async fn generate(
     chunk_strategy: std::option::Option<&str>,
     chunk_size: std::option::Option<usize>,
     exclude_patterns: std::option::Option<&str>,
+    ocr: bool,
+    include_subdomains: bool,
+    path_prefix: std::option::Option<String>,
+    crawl_delay_ms: std::option::Option<u64>,
+    resume: bool,
+    force: bool,
 ) -> anyhow::Result<()> {
     // Check if .rigger exists
     let current_dir = std::env::current_dir()?;
@@ -267,8 +565,10 @@ pub async fn generate(
         );
     }
 
-    // Determine if source is URL or directory
-    let is_url = source.starts_with("http://") || source.starts_with("https://");
+    // Detect whether source is a directory, single file, PDF, image, or URL
+    let kind = task_orchestrator::services::artifact_generator_service::SourceKind::detect(source)
+        .map_err(|e| anyhow::anyhow!(e))?;
+    let is_url = kind == task_orchestrator::services::artifact_generator_service::SourceKind::Web;
 
     // Determine project ID
     let project = if let std::option::Option::Some(p) = project_id {
@@ -279,6 +579,13 @@ pub async fn generate(
             .ok()
             .and_then(|u| u.host_str().map(String::from))
             .unwrap_or_else(|| String::from("default"))
+    } else if kind != task_orchestrator::services::artifact_generator_service::SourceKind::Directory {
+        // Use file name without extension as project ID
+        std::path::Path::new(source)
+            .file_stem()
+            .and_then(|n| n.to_str())
+            .map(String::from)
+            .unwrap_or_else(|| String::from("default"))
     } else {
         // Use directory name as project ID
         std::path::Path::new(source)
@@ -289,7 +596,7 @@ pub async fn generate(
     };
 
     // Read config to determine provider
-    let config_path = rigger_dir.join("config.json");
+    let config_path = crate::services::config_path::path_in(&rigger_dir);
     let config_content = std::fs::read_to_string(&config_path)
         .map_err(|e| anyhow::anyhow!("Failed to read config.json: {}", e))?;
     let config: serde_json::Value = serde_json::from_str(&config_content)?;
@@ -298,14 +605,14 @@ pub async fn generate(
         .as_str()
         .unwrap_or("ollama");
 
-    println!("Generating artifacts from: {}", source);
+    println!("Generating artifacts from: {} ({})", source, kind.as_str());
     println!("Project ID: {}", project);
     println!("Provider: {}", provider);
     println!();
 
     // Connect to database
     let db_path = rigger_dir.join("tasks.db");
-    let db_url = std::format!("sqlite:{}", db_path.display());
+    let db_url = crate::services::database_url::url_for(&db_path);
 
     let artifact_adapter = task_manager::adapters::sqlite_artifact_adapter::SqliteArtifactAdapter::connect_and_init(&db_url)
         .await
@@ -313,6 +620,8 @@ pub async fn generate(
 
     // Create embedding adapter
     let provider_factory = task_orchestrator::adapters::provider_factory::ProviderFactory::new(provider, "default")
+        .map_err(|e| anyhow::anyhow!("Failed to create provider factory: {}", e))?
+        .with_offline(config["offline"].as_bool().unwrap_or(false))
         .map_err(|e| anyhow::anyhow!("Failed to create provider factory: {}", e))?;
 
     let embedding_adapter = provider_factory.create_embedding_adapter()
@@ -330,39 +639,139 @@ pub async fn generate(
         ),
     };
 
+    // Create vision adapter for OCR of image files
+    let vision_adapter = if ocr && !is_url {
+        let adapter = provider_factory.create_vision_adapter()
+            .map_err(|e| anyhow::anyhow!("Failed to create vision adapter for OCR: {}", e))?;
+        std::option::Option::Some(adapter)
+    } else {
+        if ocr {
+            println!("Note: --ocr applies to local sources only; ignoring for URL.");
+        }
+        std::option::Option::None
+    };
+
     // Create generation config
     let gen_config = task_orchestrator::services::artifact_generator_service::GenerationConfig::new(project.clone())
         .with_chunk_strategy(strategy)
-        .with_max_chunk_size(chunk_size.unwrap_or(1000));
+        .with_max_chunk_size(chunk_size.unwrap_or(1000))
+        .with_ocr(vision_adapter.is_some());
 
     // Wrap adapters in Arc
     let artifact_repo = std::sync::Arc::new(std::sync::Mutex::new(artifact_adapter));
 
-    if is_url {
-        // Generate from URL
-        generate_from_url(
-            source,
-            &gen_config,
-            depth.unwrap_or(3),
-            max_items.unwrap_or(100),
-            embedding_adapter,
-            artifact_repo,
-        ).await
-    } else {
-        // Generate from directory
-        generate_from_directory(
-            source,
-            &gen_config,
-            depth.unwrap_or(10),
-            max_items.unwrap_or(1000),
-            exclude_patterns,
-            embedding_adapter,
-            artifact_repo,
-        ).await
+    match kind {
+        task_orchestrator::services::artifact_generator_service::SourceKind::Web => {
+            let mut crawl_config = task_orchestrator::domain::crawl_result::CrawlConfig::new(String::from(source))
+                .with_max_depth(depth.unwrap_or(3))
+                .with_max_pages(max_items.unwrap_or(100))
+                .with_include_subdomains(include_subdomains)
+                .with_resume(resume)
+                .with_force(force);
+            if let std::option::Option::Some(prefix) = path_prefix {
+                crawl_config = crawl_config.with_path_prefix(prefix);
+            }
+            if let std::option::Option::Some(ms) = crawl_delay_ms {
+                crawl_config = crawl_config.with_rate_limit(ms);
+            }
+            generate_from_url(
+                source,
+                &db_url,
+                &gen_config,
+                &crawl_config,
+                embedding_adapter,
+                artifact_repo,
+            ).await
+        }
+        task_orchestrator::services::artifact_generator_service::SourceKind::Directory => {
+            generate_from_directory(
+                source,
+                &gen_config,
+                depth.unwrap_or(10),
+                max_items.unwrap_or(1000),
+                exclude_patterns,
+                embedding_adapter,
+                vision_adapter,
+                artifact_repo,
+            ).await
+        }
+        file_kind => {
+            generate_from_file(
+                source,
+                file_kind,
+                &gen_config,
+                embedding_adapter,
+                vision_adapter,
+                artifact_repo,
+            ).await
+        }
     }
 }
 
+/// Generates artifacts from a single text file, PDF, or image.
+async fn generate_from_file(
+    path: &str,
+    kind: task_orchestrator::services::artifact_generator_service::SourceKind,
+    config: &task_orchestrator::services::artifact_generator_service::GenerationConfig,
+    embedding_adapter: std::sync::Arc<dyn task_orchestrator::ports::embedding_port::EmbeddingPort + std::marker::Send + std::marker::Sync>,
+    vision_adapter: std::option::Option<std::sync::Arc<dyn task_orchestrator::ports::vision_port::VisionPort + std::marker::Send + std::marker::Sync>>,
+    artifact_repo: std::sync::Arc<std::sync::Mutex<dyn task_manager::ports::artifact_repository_port::ArtifactRepositoryPort + std::marker::Send>>,
+) -> anyhow::Result<()> {
+    println!("Reading {} file: {}", kind.as_str(), path);
+
+    // Scanner and crawler are not used for single files but are required by the service
+    let mut service = task_orchestrator::services::artifact_generator_service::ArtifactGeneratorService::new(
+        std::sync::Arc::new(DummyDirectoryScanner),
+        std::sync::Arc::new(DummyWebCrawler),
+        embedding_adapter,
+        artifact_repo,
+    );
+
+    if let std::option::Option::Some(vision) = vision_adapter {
+        println!("OCR enabled: transcribing images with {} ({})", vision.model_name(), vision.provider_name());
+        service = service.with_vision_port(vision);
+    }
+
+    let (progress_tx, progress_bar) = spawn_progress_bar();
+    let service = service.with_progress_sender(progress_tx);
+    let result = service.generate_from_file(path, kind, config).await;
+    drop(service);
+    let _ = progress_bar.await;
+    let report = result.map_err(|e| anyhow::anyhow!("Generation failed: {}", e))?;
+
+    println!("\nGeneration complete!");
+    println!("  Artifacts created: {}", report.artifacts_created);
+    println!("  Bytes processed: {}", format_bytes(report.bytes_processed));
+    println!("  Duration: {}ms", report.duration_ms);
+
+    for warning in &report.warnings {
+        println!("\n⚠ {}", warning);
+    }
+
+    std::result::Result::Ok(())
+}
+
 /// Generates artifacts from a directory.
+/// Builds the scan config for a directory source.
+///
+/// With `ocr`, image files are listed on top of the default text extensions.
+fn directory_scan_config(
+    path: &str,
+    max_depth: usize,
+    ocr: bool,
+    exclude_patterns: std::option::Option<&str>,
+) -> task_manager::domain::scan_config::ScanConfig {
+    let mut scan_config = task_manager::domain::scan_config::ScanConfig::new(String::from(path))
+        .with_max_depth(max_depth)
+        .with_images(ocr);
+    if let std::option::Option::Some(patterns) = exclude_patterns {
+        scan_config.exclude_patterns = patterns.split(',')
+            .map(|p| p.trim().to_string())
+            .collect();
+    }
+    scan_config
+}
+
 async fn generate_from_directory(
     path: &str,
     config: &task_orchestrator::services::artifact_generator_service::GenerationConfig,
@@ -370,6 +779,7 @@ async fn generate_from_directory(
     max_files: usize,
     exclude_patterns: std::option::Option<&str>,
     embedding_adapter: std::sync::Arc<dyn task_orchestrator::ports::embedding_port::EmbeddingPort + std::marker::Send + std::marker::Sync>,
+    vision_adapter: std::option::Option<std::sync::Arc<dyn task_orchestrator::ports::vision_port::VisionPort + std::marker::Send + std::marker::Sync>>,
     artifact_repo: std::sync::Arc<std::sync::Mutex<dyn task_manager::ports::artifact_repository_port::ArtifactRepositoryPort + std::marker::Send>>,
 ) -> anyhow::Result<()> {
     println!("Scanning directory: {}", path);
@@ -381,7 +791,7 @@ async fn generate_from_directory(
     let crawler = std::sync::Arc::new(DummyWebCrawler);
 
     // Create generator service
-    let service = task_orchestrator::services::artifact_generator_service::ArtifactGeneratorService::new(
+    let mut service = task_orchestrator::services::artifact_generator_service::ArtifactGeneratorService::new(
         scanner,
         crawler,
         embedding_adapter,
@@ -389,23 +799,23 @@ async fn generate_from_directory(
     );
 
     // Build scan config
-    let mut scan_config = task_manager::domain::scan_config::ScanConfig::new(String::from(path));
-    scan_config.max_depth = std::option::Option::Some(max_depth);
+    let scan_config = directory_scan_config(path, max_depth, vision_adapter.is_some(), exclude_patterns);
 
-    // Add exclude patterns if provided
-    if let std::option::Option::Some(patterns) = exclude_patterns {
-        let excludes: std::vec::Vec<String> = patterns.split(',')
-            .map(|p| p.trim().to_string())
-            .collect();
-        scan_config.exclude_patterns = excludes;
+    // In OCR mode, transcribe the listed image files with the vision adapter
+    if let std::option::Option::Some(vision) = vision_adapter {
+        println!("OCR enabled: transcribing images with {} ({})", vision.model_name(), vision.provider_name());
+        service = service.with_vision_port(vision);
     }
 
     // Generate artifacts
     println!("Scanning files (max depth: {}, max files: {})...", max_depth, max_files);
 
-    let report = service.generate_from_directory(path, config, &scan_config)
-        .await
-        .map_err(|e| anyhow::anyhow!("Generation failed: {}", e))?;
+    let (progress_tx, progress_bar) = spawn_progress_bar();
+    let service = service.with_progress_sender(progress_tx);
+    let result = service.generate_from_directory(path, config, &scan_config).await;
+    drop(service);
+    let _ = progress_bar.await;
+    let report = result.map_err(|e| anyhow::anyhow!("Generation failed: {}", e))?;
 
     // Display results
     println!("\nGeneration complete!");
@@ -430,9 +840,9 @@ async fn generate_from_directory(
 /// Generates artifacts from a URL.
 async fn generate_from_url(
     url: &str,
+    db_url: &str,
     config: &task_orchestrator::services::artifact_generator_service::GenerationConfig,
-    max_depth: usize,
-    max_pages: usize,
+    crawl_config: &task_orchestrator::domain::crawl_result::CrawlConfig,
     embedding_adapter: std::sync::Arc<dyn task_orchestrator::ports::embedding_port::EmbeddingPort + std::marker::Send + std::marker::Sync>,
     artifact_repo: std::sync::Arc<std::sync::Mutex<dyn task_manager::ports::artifact_repository_port::ArtifactRepositoryPort + std::marker::Send>>,
 ) -> anyhow::Result<()> {
@@ -441,8 +851,22 @@ async fn generate_from_url(
     // Create directory scanner (not used but required by service)
     let scanner = std::sync::Arc::new(DummyDirectoryScanner);
 
+    // Open the crawl stores: the frontier lets an interrupted crawl resume, and
+    // saved ETag/Last-Modified let re-crawls skip unchanged pages
+    let frontier = task_orchestrator::adapters::sqlite_crawl_frontier_adapter::SqliteCrawlFrontierAdapter::connect_and_init(db_url)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to open crawl frontier: {}", e))?;
+    let frontier: std::sync::Arc<dyn task_orchestrator::ports::crawl_frontier_port::CrawlFrontierPort> = std::sync::Arc::new(frontier);
+    let validators = task_orchestrator::adapters::sqlite_page_validator_adapter::SqlitePageValidatorAdapter::connect_and_init(db_url)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to open page validator store: {}", e))?;
+
     // Create web crawler
-    let crawler = std::sync::Arc::new(task_orchestrator::adapters::reqwest_web_crawler::ReqwestWebCrawler::new());
+    let crawler = std::sync::Arc::new(
+        task_orchestrator::adapters::reqwest_web_crawler::ReqwestWebCrawler::new()
+            .with_frontier(std::sync::Arc::clone(&frontier))
+            .with_validators(std::sync::Arc::new(validators)),
+    );
 
     // Create generator service
     let service = task_orchestrator::services::artifact_generator_service::ArtifactGeneratorService::new(
@@ -452,25 +876,40 @@ async fn generate_from_url(
         artifact_repo,
     );
 
-    // Build crawl config
-    let mut crawl_config = task_orchestrator::domain::crawl_result::CrawlConfig::new(String::from(url));
-    crawl_config.max_depth = max_depth;
-    crawl_config.max_pages = max_pages;
-
     // Generate artifacts
-    println!("Crawling pages (max depth: {}, max pages: {})...", max_depth, max_pages);
-
-    let report = service.generate_from_url(url, config, &crawl_config)
-        .await
-        .map_err(|e| anyhow::anyhow!("Generation failed: {}", e))?;
+    if crawl_config.resume {
+        println!("Resuming from saved crawl progress, if any (fetched pages are not refetched)");
+    }
+    println!("Crawling pages (max depth: {}, max pages: {})...", crawl_config.max_depth, crawl_config.max_pages);
+
+    let (progress_tx, progress_bar) = spawn_progress_bar();
+    let service = service.with_progress_sender(progress_tx);
+    let result = service.generate_from_url(url, config, crawl_config).await;
+    drop(service);
+    let _ = progress_bar.await;
+    let report = result.map_err(|e| anyhow::anyhow!("Generation failed: {}", e))?;
+
+    // Artifacts are stored, so the saved frontier is no longer needed
+    let crawl_key = task_orchestrator::adapters::reqwest_web_crawler::ReqwestWebCrawler::crawl_key(crawl_config);
+    if let std::result::Result::Err(e) = frontier.clear(&crawl_key).await {
+        println!("⚠ Failed to clear saved crawl progress: {}", e);
+    }
 
     // Display results
     println!("\nGeneration complete!");
     println!("  Pages crawled: {}", report.pages_crawled);
+    if report.pages_unchanged > 0 {
+        println!("  Pages unchanged since last crawl: {} (use --force to re-embed)", report.pages_unchanged);
+    }
     println!("  Artifacts created: {}", report.artifacts_created);
     println!("  Bytes processed: {}", format_bytes(report.bytes_processed));
+    println!("  External links (not crawled): {}", report.external_links.len());
     println!("  Duration: {}ms", report.duration_ms);
 
+    for warning in &report.warnings {
+        println!("\n⚠ {}", warning);
+    }
+
     if report.has_errors() {
         println!("\n{} errors occurred:", report.error_count());
         for (i, error) in report.errors.iter().take(10).enumerate() {
@@ -484,6 +923,42 @@ async fn generate_from_url(
     std::result::Result::Ok(())
 }
 
+/// Spawns a task that renders generation progress events as a progress bar.
+///
+/// The bar is cleared once every sender is dropped; await the returned
+/// handle after generation finishes so it does not interleave with the summary.
+fn spawn_progress_bar() -> (
+    tokio::sync::mpsc::Sender<task_orchestrator::services::artifact_generator_service::GenerationProgress>,
+    tokio::task::JoinHandle<()>,
+) {
+    let (tx, mut rx) = tokio::sync::mpsc::channel::<task_orchestrator::services::artifact_generator_service::GenerationProgress>(64);
+
+    let handle = tokio::spawn(async move {
+        let bar = indicatif::ProgressBar::new(0);
+        let style = indicatif::ProgressStyle::with_template("[{bar:40.cyan/blue}] {pos}/{len} {wide_msg}")
+            .unwrap_or_else(|_| indicatif::ProgressStyle::default_bar())
+            .progress_chars("=> ");
+        bar.set_style(style);
+
+        while let std::option::Option::Some(event) = rx.recv().await {
+            bar.set_length(event.total as u64);
+            bar.set_position(event.processed as u64);
+            match event.stage {
+                task_orchestrator::services::artifact_generator_service::GenerationStage::Fetching => {
+                    bar.set_message(std::format!("fetching {}", event.current_item));
+                }
+                task_orchestrator::services::artifact_generator_service::GenerationStage::Processing => {
+                    bar.set_message(event.current_item);
+                }
+            }
+        }
+
+        bar.finish_and_clear();
+    });
+
+    (tx, handle)
+}
+
 /// Formats bytes into human-readable format.
 fn format_bytes(bytes: usize) -> String {
     if bytes < 1024 {
@@ -572,3 +1047,27 @@ impl task_orchestrator::ports::web_crawler_port::WebCrawlerPort for DummyWebCraw
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn test_ocr_scan_still_includes_text_files() {
+        // Test: Validates an --ocr directory scan lists images and keeps the default text files.
+        // Justification: OCR used to narrow the extension allowlist, silently dropping .md/.rs/.txt files.
+        let dir = std::env::temp_dir().join(std::format!("artifacts_ocr_scan_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("diagram.png"), b"\x89PNG\r\n\x1a\n").unwrap();
+        std::fs::write(dir.join("README.md"), "# Readme").unwrap();
+        std::fs::write(dir.join("notes.txt"), "Notes").unwrap();
+        std::fs::write(dir.join("lib.rs"), "pub fn f() {}").unwrap();
+
+        let scanner = task_manager::adapters::ignore_aware_scanner::IgnoreAwareScanner::new();
+        let config = super::directory_scan_config(&dir.display().to_string(), 10, true, std::option::Option::None);
+        let result = task_manager::ports::directory_scanner_port::DirectoryScannerPort::scan(&scanner, &config).await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let mut extensions: std::vec::Vec<&str> = result.files.iter().map(|f| f.extension.as_str()).collect();
+        extensions.sort();
+        std::assert_eq!(extensions, ["md", "png", "rs", "txt"]);
+    }
+}
@@ -5,6 +5,13 @@
 //! and global (~/.config/rigger/config.json) configuration files.
 //!
 //! Revision History
+//! - 2026-10-17T13:50:00Z @AI: Config subcommands fall back to the global --config / RIGGER_CONFIG path before the default locations.
+//! - 2026-10-17T09:30:00Z @AI: migrate reads the base config only, so config.local.json is not written into it.
+//! - 2026-10-17T01:50:00Z @AI: Explain CapabilityMismatch validation errors.
+//! - 2026-10-16T21:10:00Z @AI: Explain OfflineViolation; check-providers sends no request to non-local providers in offline mode.
+//! - 2026-10-16T18:50:00Z @AI: Add check-providers to test provider reachability and credentials concurrently.
+//! - 2026-10-16T18:30:00Z @AI: Add --dry-run to migrate; write migrated configs via RiggerConfig::save_to_file (timestamped backup on version upgrade).
+//! - 2026-10-16T18:10:00Z @AI: Explain each ConfigError in `rig config validate`, report load failures, and print a themed "config OK".
 //! - 2025-12-04T00:00:00Z @AI: Initial implementation for Phase 4.3 config management CLI.
 
 /// Displays the current configuration with syntax highlighting.
@@ -54,8 +61,9 @@ pub async fn show(path: std::option::Option<&str>) -> anyhow::Result<()> {
 
 /// Validates the configuration and displays any errors.
 ///
-/// Loads the configuration, runs all validation checks, and reports any issues
-/// found (missing providers, invalid URLs, missing API keys, etc.).
+/// Loads the configuration (with migration), runs all validation checks, and
+/// prints each problem with an explanation and a suggested fix. Load failures
+/// (missing file, invalid JSON, failed migration) are reported the same way.
 ///
 /// # Arguments
 ///
@@ -63,43 +71,278 @@ pub async fn show(path: std::option::Option<&str>) -> anyhow::Result<()> {
 ///
 /// # Errors
 ///
-/// Returns an error if the config file cannot be found or loaded.
+/// Returns an error (non-zero exit code) if the config cannot be loaded or
+/// fails validation.
 pub async fn validate(path: std::option::Option<&str>) -> anyhow::Result<()> {
-    let config_path = resolve_config_path(path)?;
+    let config_path = match path {
+        std::option::Option::Some(p) => std::path::PathBuf::from(p),
+        std::option::Option::None => resolve_config_path(path)?,
+    };
 
     println!("🔍 Validating configuration: {}", config_path.display());
     println!();
 
-    // Load config (with migration if needed)
-    let config = rigger_core::RiggerConfig::load_with_migration(
-        config_path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid config path"))?
-    )?;
+    let theme = crate::display::theme::Theme::load(&config_path);
+    let result = check_config(&config_path);
+    println!("{}", render_validation_report(&result, &theme));
 
-    // Run validation
-    match config.validate() {
-        std::result::Result::Ok(()) => {
-            println!("✅ Configuration is valid!");
-            println!();
-            println!("📊 Summary:");
-            println!("   Version: {}", config.version);
-            println!("   Providers: {}", config.providers.len());
-            println!("   Task slots: 6 (main, research, fallback, embedding, vision, chat_agent)");
-            println!("   Database: {}", config.database.url);
-        }
+    if result.is_err() {
+        anyhow::bail!("Configuration validation failed");
+    }
+
+    std::result::Result::Ok(())
+}
+
+/// Loads the config at `config_path` and runs `RiggerConfig::validate`.
+///
+/// `RiggerConfig::load_with_migration` silently falls back to defaults for a
+/// missing file, so a missing path is reported as `ConfigError::FileNotFound` here.
+///
+/// # Returns
+///
+/// The loaded config, or every error found while loading or validating it.
+fn check_config(
+    config_path: &std::path::Path,
+) -> std::result::Result<rigger_core::RiggerConfig, std::vec::Vec<rigger_core::config::error::ConfigError>> {
+    let path_str = config_path.display().to_string();
+    if !config_path.exists() {
+        return std::result::Result::Err(std::vec![rigger_core::config::error::ConfigError::FileNotFound {
+            path: path_str,
+            error: std::string::String::from("no such file"),
+        }]);
+    }
+
+    let config = rigger_core::RiggerConfig::load_with_migration(&path_str).map_err(|e| std::vec![e])?;
+    config.validate()?;
+    std::result::Result::Ok(config)
+}
+
+/// Renders the outcome of `check_config` for the terminal.
+///
+/// # Arguments
+///
+/// * `result` - The loaded config, or the errors found
+/// * `theme` - Color theme for the OK/error headline
+///
+/// # Returns
+///
+/// A green "config OK" with a short summary, or a numbered list of explained errors.
+fn render_validation_report(
+    result: &std::result::Result<rigger_core::RiggerConfig, std::vec::Vec<rigger_core::config::error::ConfigError>>,
+    theme: &crate::display::theme::Theme,
+) -> String {
+    match result {
+        std::result::Result::Ok(config) => std::format!(
+            "{}\n\n📊 Summary:\n   Version: {}\n   Providers: {}\n   Task slots: 6 (main, research, fallback, embedding, vision, chat_agent)\n   Database: {}\n",
+            theme.success("✅ config OK"),
+            config.version,
+            config.providers.len(),
+            config.database.url
+        ),
         std::result::Result::Err(errors) => {
-            println!("❌ Configuration has {} error(s):", errors.len());
-            println!();
+            let mut report = theme.error(&std::format!("❌ Configuration has {} error(s):", errors.len()));
+            report.push('\n');
             for (idx, error) in errors.iter().enumerate() {
-                println!("  {}. {}", idx + 1, error);
+                report.push_str(&std::format!("\n  {}. {}\n", idx + 1, explain_config_error(error)));
             }
-            println!();
-            anyhow::bail!("Configuration validation failed");
+            report
+        }
+    }
+}
+
+/// Explains a config error in plain language with a suggested fix.
+///
+/// # Arguments
+///
+/// * `error` - The error returned by loading or validating the config
+///
+/// # Returns
+///
+/// A message describing what is wrong and how to fix it. Continuation lines
+/// are indented to sit under a numbered list entry.
+fn explain_config_error(error: &rigger_core::config::error::ConfigError) -> String {
+    match error {
+        rigger_core::config::error::ConfigError::MissingApiKey { provider, env_var } => std::format!(
+            "Provider {} needs an API key, but the environment variable {} is not set.\n     Fix: export {}=<your key> before running rig.",
+            provider, env_var, env_var
+        ),
+        rigger_core::config::error::ConfigError::UnknownProvider { slot, provider, available } => {
+            let mut names = available.clone();
+            names.sort();
+            let available_list = if names.is_empty() {
+                std::string::String::from("(none configured)")
+            } else {
+                names.join(", ")
+            };
+            std::format!(
+                "Task slot '{}' uses provider '{}', which is not defined under \"providers\".\n     Available providers: {}\n     Fix: point task_slots.{}.provider at one of these, or add a '{}' provider.",
+                slot, provider, available_list, slot, provider
+            )
         }
+        rigger_core::config::error::ConfigError::InvalidBaseUrl { provider, url } => std::format!(
+            "Provider '{}' has base_url '{}', which is not an http:// or https:// URL.\n     Fix: use a full URL such as http://localhost:11434.",
+            provider, url
+        ),
+        rigger_core::config::error::ConfigError::OfflineViolation { provider, url } => std::format!(
+            "Offline mode is on, but provider '{}' has base_url '{}', which is not localhost or a private IP.\n     Fix: point it at a local endpoint, remove it, or set \"offline\": false.",
+            provider, url
+        ),
+        rigger_core::config::error::ConfigError::CapabilityMismatch { slot, provider, model, capability } => std::format!(
+            "Task slot '{}' needs a model with {} support, but model '{}' on provider '{}' does not advertise it.\n     Fix: set task_slots.{}.model to a {} model, or disable the slot.",
+            slot, capability, model, provider, slot, capability
+        ),
+        rigger_core::config::error::ConfigError::FileNotFound { path, error } => std::format!(
+            "Could not read the config file {} ({}).\n     Fix: create one with `rig init`, or pass the right file with --path.",
+            path, error
+        ),
+        rigger_core::config::error::ConfigError::ParseError { message } => std::format!(
+            "The config file is not valid JSON: {}.\n     Fix: check for trailing commas, missing quotes, or unbalanced braces near that position.",
+            message
+        ),
+        rigger_core::config::error::ConfigError::MigrationError { from_version, message } => std::format!(
+            "The config looks like a version {} file but could not be migrated: {}.\n     Fix: correct the file and run `rig config migrate`, or recreate it with `rig init`.",
+            from_version, message
+        ),
+        rigger_core::config::error::ConfigError::WriteError { path, error } => std::format!(
+            "Could not write the config file {} ({}).\n     Fix: check that the directory exists and is writable.",
+            path, error
+        ),
+    }
+}
+
+/// Checks that every configured provider is reachable and accepts its credentials.
+///
+/// Sends one liveness request per provider, all concurrently, each bounded
+/// by the provider's `timeout_seconds`, and prints reachable / unreachable /
+/// auth-failed with the round-trip latency. In offline mode, providers that
+/// are not localhost or a private IP are reported unreachable without a
+/// request.
+///
+/// # Arguments
+///
+/// * `path` - Optional path to config file. If None, searches default locations.
+///
+/// # Errors
+///
+/// Returns an error (non-zero exit code) if the config cannot be loaded or
+/// any provider is not reachable.
+pub async fn check_providers(path: std::option::Option<&str>) -> anyhow::Result<()> {
+    let config_path = resolve_config_path(path)?;
+    let config = rigger_core::RiggerConfig::load_with_migration(
+        config_path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid config path"))?
+    )?;
+
+    println!("📡 Checking providers from: {}", config_path.display());
+    println!();
+
+    if config.providers.is_empty() {
+        println!("No providers configured.");
+        return std::result::Result::Ok(());
+    }
+
+    let port: std::sync::Arc<dyn crate::ports::provider_health_port::ProviderHealthPort> =
+        std::sync::Arc::new(crate::adapters::reqwest_provider_health_adapter::ReqwestProviderHealthAdapter::new());
+    let results = if config.offline {
+        // Never send a request to a non-local provider, even a liveness check
+        let (local, remote): (std::collections::HashMap<_, _>, std::collections::HashMap<_, _>) = config
+            .providers
+            .clone()
+            .into_iter()
+            .partition(|(_, provider)| rigger_core::config::network::is_local_url(&provider.base_url));
+        let mut results = check_all_providers(port, &local).await;
+        results.extend(remote.into_keys().map(|name| crate::ports::provider_health_port::ProviderHealth {
+            name,
+            status: crate::ports::provider_health_port::ProviderStatus::Unreachable(std::string::String::from(
+                "not checked: not localhost or a private IP, and offline mode is on",
+            )),
+            latency: std::time::Duration::ZERO,
+        }));
+        results.sort_by(|a, b| a.name.cmp(&b.name));
+        results
+    } else {
+        check_all_providers(port, &config.providers).await
+    };
+
+    let theme = crate::display::theme::Theme::load(&config_path);
+    let name_width = results.iter().map(|health| health.name.len()).max().unwrap_or(0);
+    for health in &results {
+        println!("{}", render_provider_health(health, name_width, &theme));
+    }
+
+    let failed = results
+        .iter()
+        .filter(|health| health.status != crate::ports::provider_health_port::ProviderStatus::Reachable)
+        .count();
+    println!();
+    if failed > 0 {
+        anyhow::bail!("{} of {} provider(s) failed the check", failed, results.len());
     }
+    println!("{}", theme.success(&std::format!("✅ All {} provider(s) reachable", results.len())));
 
     std::result::Result::Ok(())
 }
 
+/// Runs a health check for every provider concurrently.
+///
+/// # Returns
+///
+/// One result per provider, sorted by provider name.
+async fn check_all_providers(
+    port: std::sync::Arc<dyn crate::ports::provider_health_port::ProviderHealthPort>,
+    providers: &std::collections::HashMap<String, rigger_core::config::ProviderConfig>,
+) -> std::vec::Vec<crate::ports::provider_health_port::ProviderHealth> {
+    let mut checks = tokio::task::JoinSet::new();
+    for (name, provider) in providers {
+        let port = std::sync::Arc::clone(&port);
+        let name = name.clone();
+        let provider = provider.clone();
+        checks.spawn(async move { port.check(&name, &provider).await });
+    }
+
+    let mut results = std::vec::Vec::with_capacity(providers.len());
+    while let std::option::Option::Some(joined) = checks.join_next().await {
+        if let std::result::Result::Ok(health) = joined {
+            results.push(health);
+        }
+    }
+    results.sort_by(|a, b| a.name.cmp(&b.name));
+    results
+}
+
+/// Renders one provider's check result as an aligned, colored line.
+fn render_provider_health(
+    health: &crate::ports::provider_health_port::ProviderHealth,
+    name_width: usize,
+    theme: &crate::display::theme::Theme,
+) -> String {
+    let latency = std::format!("{} ms", health.latency.as_millis());
+    match &health.status {
+        crate::ports::provider_health_port::ProviderStatus::Reachable => std::format!(
+            "  {:<width$}  {}  {}",
+            health.name,
+            theme.success("reachable"),
+            latency,
+            width = name_width
+        ),
+        crate::ports::provider_health_port::ProviderStatus::Unreachable(reason) => std::format!(
+            "  {:<width$}  {}  {} ({})",
+            health.name,
+            theme.error("unreachable"),
+            latency,
+            reason,
+            width = name_width
+        ),
+        crate::ports::provider_health_port::ProviderStatus::AuthFailed(reason) => std::format!(
+            "  {:<width$}  {}  {} ({})",
+            health.name,
+            theme.error("auth failed"),
+            latency,
+            reason,
+            width = name_width
+        ),
+    }
+}
+
 /// Opens the configuration editor in TUI mode.
 ///
 /// Launches the interactive TUI with the config editor pre-opened, allowing
@@ -130,12 +373,15 @@ pub async fn edit() -> anyhow::Result<()> {
 ///
 /// Reads a legacy config file (v0, v1, or v2 format), converts it to the modern
 /// v3.0 format with full provider and task slot support, and writes it back.
+/// Overwriting a file of a different version also leaves a timestamped
+/// `config.json.bak.<ts>` copy of the original (see `RiggerConfig::save_to_file`).
 ///
 /// # Arguments
 ///
 /// * `path` - Optional path to legacy config. If None, uses .rigger/config.json
 /// * `output` - Optional output path. If None, overwrites input file
 /// * `backup` - Whether to create a backup before overwriting (default: true)
+/// * `dry_run` - Print the migrated v3.0 JSON without writing any files
 ///
 /// # Errors
 ///
@@ -144,6 +390,7 @@ pub async fn migrate(
     path: std::option::Option<&str>,
     output: std::option::Option<&str>,
     backup: bool,
+    dry_run: bool,
 ) -> anyhow::Result<()> {
     let input_path = resolve_config_path(path)?;
     let output_path = if let Some(out) = output {
@@ -152,6 +399,25 @@ pub async fn migrate(
         input_path.clone()
     };
 
+    // Load with auto-migration (without config.local.json, which must not be baked into the output)
+    let config = rigger_core::RiggerConfig::load_base_with_migration(
+        input_path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid input path"))?
+    )?;
+
+    if dry_run {
+        println!("{}", serde_json::to_string_pretty(&config)?);
+        if let std::result::Result::Err(errors) = config.validate() {
+            eprintln!();
+            eprintln!("⚠️  Migrated config has validation warnings:");
+            for error in &errors {
+                eprintln!("   - {}", error);
+            }
+        }
+        eprintln!();
+        eprintln!("🔎 Dry run: nothing was written to {}", output_path.display());
+        return std::result::Result::Ok(());
+    }
+
     println!("🔄 Migrating configuration...");
     println!("   Input:  {}", input_path.display());
     println!("   Output: {}", output_path.display());
@@ -164,11 +430,6 @@ pub async fn migrate(
         std::fs::copy(&input_path, &backup_path)?;
     }
 
-    // Load with auto-migration
-    let config = rigger_core::RiggerConfig::load_with_migration(
-        input_path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid input path"))?
-    )?;
-
     // Validate migrated config
     match config.validate() {
         std::result::Result::Ok(()) => {
@@ -183,8 +444,12 @@ pub async fn migrate(
     }
 
     // Write migrated config
-    let json = serde_json::to_string_pretty(&config)?;
-    std::fs::write(&output_path, json)?;
+    let version_backup = config.save_to_file(
+        output_path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid output path"))?
+    )?;
+    if let std::option::Option::Some(version_backup) = version_backup {
+        println!("💾 Previous version saved to: {}", version_backup.display());
+    }
 
     println!();
     println!("✨ Configuration migrated to v3.0 format");
@@ -203,8 +468,9 @@ pub async fn migrate(
 ///
 /// Search order:
 /// 1. Provided path (if Some)
-/// 2. .rigger/config.json (project-local)
-/// 3. ~/.config/rigger/config.json (global)
+/// 2. Global `--config` flag or `RIGGER_CONFIG`
+/// 3. .rigger/config.json (project-local)
+/// 4. ~/.config/rigger/config.json (global)
 ///
 /// # Arguments
 ///
@@ -223,7 +489,15 @@ fn resolve_config_path(path: std::option::Option<&str>) -> anyhow::Result<std::p
         }
     }
 
-    // Try project-local first
+    // Then the global --config / RIGGER_CONFIG override
+    if let Some(path) = crate::services::config_path::override_path() {
+        if path.exists() {
+            return std::result::Result::Ok(path.to_path_buf());
+        }
+        anyhow::bail!("Config file not found: {}", path.display());
+    }
+
+    // Then project-local
     let local_path = std::path::PathBuf::from(".rigger/config.json");
     if local_path.exists() {
         return std::result::Result::Ok(local_path);
@@ -243,3 +517,105 @@ fn resolve_config_path(path: std::option::Option<&str>) -> anyhow::Result<std::p
          - Or specify path with: --path <path>"
     )
 }
+
+#[cfg(test)]
+mod tests {
+    fn write_config(name: &str, content: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(std::format!("rigger_config_{}_{}.json", name, uuid::Uuid::new_v4()));
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_each_config_error_has_distinct_explanation() {
+        // Test: Validates every ConfigError variant renders its own message, and unknown providers list the alternatives.
+        // Justification: Users fix the config from this text alone; variants must not collapse into a generic message.
+        let errors = std::vec![
+            rigger_core::config::error::ConfigError::MissingApiKey {
+                provider: rigger_core::config::ProviderType::OpenAI,
+                env_var: std::string::String::from("OPENAI_API_KEY"),
+            },
+            rigger_core::config::error::ConfigError::UnknownProvider {
+                slot: std::string::String::from("research"),
+                provider: std::string::String::from("openia"),
+                available: std::vec![std::string::String::from("openai"), std::string::String::from("ollama")],
+            },
+            rigger_core::config::error::ConfigError::InvalidBaseUrl {
+                provider: std::string::String::from("ollama"),
+                url: std::string::String::from("localhost:11434"),
+            },
+            rigger_core::config::error::ConfigError::OfflineViolation {
+                provider: std::string::String::from("openai"),
+                url: std::string::String::from("https://api.openai.com/v1"),
+            },
+            rigger_core::config::error::ConfigError::FileNotFound {
+                path: std::string::String::from("missing.json"),
+                error: std::string::String::from("no such file"),
+            },
+            rigger_core::config::error::ConfigError::ParseError {
+                message: std::string::String::from("expected `,` at line 3"),
+            },
+            rigger_core::config::error::ConfigError::MigrationError {
+                from_version: std::string::String::from("2.0"),
+                message: std::string::String::from("missing task_tools"),
+            },
+            rigger_core::config::error::ConfigError::WriteError {
+                path: std::string::String::from("/readonly/config.json"),
+                error: std::string::String::from("permission denied"),
+            },
+        ];
+
+        let messages: std::collections::HashSet<String> = errors.iter().map(super::explain_config_error).collect();
+        std::assert_eq!(messages.len(), errors.len());
+
+        let unknown = super::explain_config_error(&errors[1]);
+        std::assert!(unknown.contains("Available providers: ollama, openai"));
+        std::assert!(unknown.contains("task_slots.research.provider"));
+    }
+
+    #[tokio::test]
+    async fn test_validate_fails_for_invalid_config_and_passes_for_valid_one() {
+        // Test: Validates `validate` errors (non-zero exit) on bad or missing configs and succeeds on the default config.
+        // Justification: Scripts and CI rely on the exit code to catch broken configs before runtime.
+        let valid = write_config("valid", &serde_json::to_string(&rigger_core::RiggerConfig::default()).unwrap());
+        let mut broken_config = rigger_core::RiggerConfig::default();
+        broken_config.task_slots.main.provider = std::string::String::from("openia");
+        let broken = write_config("broken", &serde_json::to_string(&broken_config).unwrap());
+        let invalid_json = write_config("json", "{ \"version\": ");
+        let missing = std::env::temp_dir().join(std::format!("rigger_config_missing_{}.json", uuid::Uuid::new_v4()));
+
+        std::assert!(super::validate(valid.to_str()).await.is_ok());
+        std::assert!(super::validate(broken.to_str()).await.is_err());
+        std::assert!(super::validate(invalid_json.to_str()).await.is_err());
+        std::assert!(super::validate(missing.to_str()).await.is_err());
+
+        let report = super::render_validation_report(
+            &super::check_config(&broken),
+            &crate::display::theme::Theme::NoColor,
+        );
+        std::assert!(report.starts_with("❌ Configuration has 1 error(s):"));
+        std::assert!(report.contains("'openia'"));
+
+        for path in [valid, broken, invalid_json] {
+            std::fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_migrate_dry_run_writes_nothing() {
+        // Test: Validates `migrate --dry-run` leaves the legacy config untouched and creates no backups.
+        // Justification: Dry runs exist so users can preview the v3.0 JSON before committing to it.
+        let dir = std::env::temp_dir().join(std::format!("rigger_migrate_dry_run_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.json");
+        let legacy = r#"{"provider": "ollama", "model": {"main": "llama3.2"}}"#;
+        std::fs::write(&path, legacy).unwrap();
+
+        super::migrate(path.to_str(), std::option::Option::None, true, true).await.unwrap();
+
+        std::assert_eq!(std::fs::read_to_string(&path).unwrap(), legacy);
+        std::assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,159 @@
+//! Implementation of the 'rig dedup [--threshold T] [--merge]' command.
+//!
+//! Embeds the open tasks with the embedding task slot and reports groups of
+//! likely duplicates (see `task_orchestrator::services::task_dedup_service`).
+//! Nothing is changed unless `--merge` is given; then each group keeps its
+//! earliest task and the others are archived as duplicates of it.
+//!
+//! Revision History
+//! - 2026-10-18T02:50:00Z @AI: Embed with the embedding task slot of the merged config through services::provider_slot.
+//! - 2026-10-17T17:10:00Z @AI: Initial dedup command with report-only default and opt-in merge.
+
+/// Executes the 'rig dedup' command.
+///
+/// # Arguments
+///
+/// * `threshold` - Minimum cosine similarity (0.0-1.0); defaults to `DEFAULT_THRESHOLD`
+/// * `merge` - Merge every reported group instead of only reporting it
+///
+/// # Errors
+///
+/// Returns an error if the threshold is outside 0.0-1.0, .rigger or its
+/// config doesn't exist, the embedding provider is unavailable, or a
+/// database operation fails.
+pub async fn execute(threshold: std::option::Option<f32>, merge: bool) -> anyhow::Result<()> {
+    let threshold = threshold.unwrap_or(task_orchestrator::services::task_dedup_service::DEFAULT_THRESHOLD);
+    if !(0.0..=1.0).contains(&threshold) {
+        anyhow::bail!("Invalid threshold: {}. Expected a similarity between 0.0 and 1.0.", threshold);
+    }
+
+    let current_dir = std::env::current_dir()?;
+    let rigger_dir = current_dir.join(".rigger");
+
+    if !rigger_dir.exists() {
+        anyhow::bail!(
+            ".rigger directory not found.\nRun 'rig init' first to initialize the project."
+        );
+    }
+
+    // The embedding slot of the merged config (config.json plus config.local.json) embeds the tasks
+    let config = crate::services::provider_slot::load_config(&rigger_dir)?;
+    let slot = &config.task_slots.embedding;
+
+    let db_url = crate::services::database_url::url_for(&rigger_dir.join("tasks.db"));
+    let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect to database: {}", e))?;
+
+    let mut tasks = adapter
+        .find_async(
+            &task_manager::ports::task_repository_port::TaskFilter::All,
+            hexser::ports::repository::FindOptions {
+                sort: std::option::Option::None,
+                limit: std::option::Option::None,
+                offset: std::option::Option::None,
+            },
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    let embedding_adapter = crate::services::provider_slot::embedding_factory(&config)?
+        .create_embedding_adapter()
+        .map_err(|e| anyhow::anyhow!("Failed to create embedding adapter: {}", e))?;
+
+    println!("Embedding open tasks with {} ({})...\n", slot.provider, slot.model);
+    let service = task_orchestrator::services::task_dedup_service::TaskDedupService::new(embedding_adapter);
+    let groups = service
+        .find_duplicates(&tasks, threshold)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to find duplicates: {}", e))?;
+
+    print!("{}", render_groups(&tasks, &groups, threshold));
+    if groups.is_empty() {
+        return std::result::Result::Ok(());
+    }
+    if !merge {
+        println!("\nNothing was changed. Re-run with --merge to keep the first task of each group and archive the rest as duplicates.");
+        return std::result::Result::Ok(());
+    }
+
+    let mut archived = 0;
+    for group in &groups {
+        let changed = task_orchestrator::services::task_dedup_service::merge_group(&tasks, group);
+        for task in changed {
+            if task.status == task_manager::domain::task_status::TaskStatus::Archived
+                && group.task_ids.iter().any(|id| id == &task.id)
+            {
+                archived += 1;
+            }
+            adapter
+                .save_async(task.clone())
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to save task {}: {}", task.id, e))?;
+            // Later groups must see this group's changes (e.g. repointed dependencies)
+            if let std::option::Option::Some(slot) = tasks.iter_mut().find(|t| t.id == task.id) {
+                *slot = task;
+            }
+        }
+    }
+    println!("\n✓ Merged {} group(s); archived {} duplicate task(s).", groups.len(), archived);
+    std::result::Result::Ok(())
+}
+
+/// Renders the duplicate groups with each task's ID and title; the first task is the one a merge keeps.
+///
+/// # Arguments
+///
+/// * `tasks` - All tasks, to look up titles
+/// * `groups` - Groups found by `TaskDedupService::find_duplicates`
+/// * `threshold` - Threshold the groups were found with
+fn render_groups(
+    tasks: &[task_manager::domain::task::Task],
+    groups: &[task_orchestrator::services::task_dedup_service::DuplicateGroup],
+    threshold: f32,
+) -> String {
+    if groups.is_empty() {
+        return std::format!("No duplicate candidates at similarity ≥ {:.2}.\n", threshold);
+    }
+
+    let mut output = std::format!("{} duplicate group(s) at similarity ≥ {:.2}:\n", groups.len(), threshold);
+    for (index, group) in groups.iter().enumerate() {
+        output.push_str(&std::format!("\nGroup {} (similarity {:.2}):\n", index + 1, group.similarity));
+        for (position, id) in group.task_ids.iter().enumerate() {
+            let title = tasks.iter().find(|t| &t.id == id).map(|t| t.title.as_str()).unwrap_or("");
+            let marker = if position == 0 { "keep" } else { "dup " };
+            output.push_str(&std::format!("  [{}] {}  {}\n", marker, id, title));
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_render_groups_marks_kept_task() {
+        // Test: Validates each group lists its tasks with titles, the earliest marked as kept, and an empty result is explained.
+        // Justification: Users decide whether to pass --merge from this report alone.
+        let item = |title: &str| transcript_extractor::domain::action_item::ActionItem {
+            title: String::from(title),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let mut first = task_manager::domain::task::Task::from_action_item(&item("Add login page"), std::option::Option::None);
+        first.id = String::from("login-1");
+        let mut second = task_manager::domain::task::Task::from_action_item(&item("Add login form"), std::option::Option::None);
+        second.id = String::from("login-2");
+        let group = task_orchestrator::services::task_dedup_service::DuplicateGroup {
+            task_ids: std::vec![String::from("login-1"), String::from("login-2")],
+            similarity: 0.93,
+        };
+
+        let output = super::render_groups(&[first, second], &[group], 0.9);
+        let empty = super::render_groups(&[], &[], 0.9);
+
+        std::assert!(output.contains("Group 1 (similarity 0.93):"), "{}", output);
+        std::assert!(output.contains("[keep] login-1  Add login page"), "{}", output);
+        std::assert!(output.contains("[dup ] login-2  Add login form"), "{}", output);
+        std::assert!(empty.starts_with("No duplicate candidates"), "{}", empty);
+    }
+}
@@ -4,31 +4,79 @@
 //! and comprehension test generation.
 //!
 //! Revision History
+//! - 2026-10-18T05:00:00Z @AI: Create the metrics tables only once the run is confirmed, so a declined run leaves the database untouched.
+//! - 2026-10-18T04:30:00Z @AI: Build the router from orchestration.router_* settings; stop without running when the task is routed to "clarify".
+//! - 2026-10-18T04:20:00Z @AI: Record runs to orchestration.run_log_dir (or TASK_ORCHESTRATOR_RUN_LOG_DIR) for `rig replay`.
+//! - 2026-10-18T02:30:00Z @AI: Grade comprehension test answers against orchestration.pass_threshold; refuse a threshold outside 0.0-1.0.
+//! - 2026-10-18T01:20:00Z @AI: Run with the main task slot of the merged config through services::provider_slot.
+//! - 2026-10-17T22:20:00Z @AI: Track time spent in progress when marking the task InProgress and Completed.
+//! - 2026-10-17T21:50:00Z @AI: Record each provider call in the metrics file configured by performance.metrics_file.
+//! - 2026-10-17T21:40:00Z @AI: Record provider retries in the tasks database for `rig metrics`.
+//! - 2026-10-17T21:20:00Z @AI: Run the task through TaskGraphRunner with the run's budget; stop the run once the budget is spent.
+//! - 2026-10-17T16:10:00Z @AI: Preview the planned nodes and estimated cost and ask for confirmation before running, unless --yes is given or stdout is not a terminal.
+//! - 2026-10-17T14:10:00Z @AI: Open the database through services::database_url so --db / RIGGER_DB apply.
+//! - 2026-10-17T13:50:00Z @AI: Read the config path from services::config_path, honoring --config and RIGGER_CONFIG.
+//! - 2026-10-17T06:50:00Z @AI: Add --regenerate-tests, marking the task's comprehension tests stale; report stale tests in the summary.
+//! - 2026-10-17T05:50:00Z @AI: Size the task database pool from database.pool_size.
+//! - 2026-10-16T20:50:00Z @AI: Add --max-cost override; build the run's TokenBudget from config and report usage against it.
+//! - 2026-10-16T20:30:00Z @AI: Print the run's cost summary, priced from the config's `pricing` section.
+//! - 2026-10-16T20:10:00Z @AI: Generate a correlation ID per run, run inside its `run` span, and print it in the summary.
+//! - 2026-10-16T17:50:00Z @AI: Make TASK_ID optional. Without it, or with an ID prefix matching several tasks, open the fuzzy task picker.
 //! - 2025-11-22T17:15:00Z @AI: Full implementation of do command for Rigger Phase 0 Sprint 0.3.
 
 /// Executes the 'rigdo <TASK_ID>' command.
 ///
 /// This command:
-/// 1. Reads the task from the database
+/// 1. Reads the task from the database (opening the fuzzy task picker when
+///    no ID is given or an ID prefix matches several tasks)
 /// 2. Validates task status (must be Todo or InProgress)
-/// 3. Runs task through orchestrator (enhancement + comprehension test generation)
-/// 4. Updates task status to Completed
-/// 5. Saves updated task to database
-/// 6. Prints execution summary
+/// 3. Routes the task with the router settings of the `orchestration` config;
+///    a task routed to "clarify" stops here without changes
+/// 4. On a terminal without `yes`, previews the planned nodes and estimated
+///    cost and asks for confirmation; declining stops here without changes
+/// 5. Runs task through orchestrator (enhancement + comprehension test generation;
+///    tests are regenerated when stale, or always with `regenerate_tests`),
+///    checking the run's budget before each provider call
+/// 6. Updates task status to Completed
+/// 7. Saves updated task to database
+/// 8. Prints execution summary, including the cost of the run's LLM calls
 ///
 /// # Arguments
 ///
-/// * `task_id` - ID of the task to execute
+/// * `task_id` - ID or unique ID prefix of the task to execute; None opens the picker
+/// * `max_cost` - Dollar limit for the run, overriding `performance.max_cost_per_run`
+/// * `regenerate_tests` - Mark the task's comprehension tests stale so they are regenerated
+/// * `yes` - Run without the preview and confirmation prompt
 ///
 /// # Errors
 ///
 /// Returns an error if:
 /// - .rigdirectory doesn't exist (run 'riginit' first)
-/// - Task not found in database
+/// - Task not found in database, or the picker was cancelled
 /// - Task already completed or archived
-/// - Orchestration fails
+/// - `max_cost` is not a positive number
+/// - Orchestration fails, including halting because the run's budget is spent
 /// - Database operations fail
-pub async fn execute(task_id: &str) -> anyhow::Result<()> {
+pub async fn execute(
+    task_id: std::option::Option<&str>,
+    max_cost: std::option::Option<f64>,
+    regenerate_tests: bool,
+    yes: bool,
+) -> anyhow::Result<()> {
+    // Tag every log event of this run (DB and orchestration spans included) with one ID
+    let correlation_id = task_orchestrator::domain::correlation_id::CorrelationId::generate();
+    let span = task_orchestrator::infrastructure::telemetry::run_span(&correlation_id);
+    task_manager::infrastructure::telemetry::timed(span, execute_run(task_id, max_cost, regenerate_tests, yes, &correlation_id)).await
+}
+
+/// Body of `execute`, run inside the `run` span for `correlation_id`.
+async fn execute_run(
+    task_id: std::option::Option<&str>,
+    max_cost: std::option::Option<f64>,
+    regenerate_tests: bool,
+    yes: bool,
+    correlation_id: &task_orchestrator::domain::correlation_id::CorrelationId,
+) -> anyhow::Result<()> {
     // Check if .rigexists
     let current_dir = std::env::current_dir()?;
     let taskmaster_dir = current_dir.join(".rigger");
@@ -41,26 +89,14 @@ pub async fn execute(task_id: &str) -> anyhow::Result<()> {
 
     // Connect to database
     let db_path = taskmaster_dir.join("tasks.db");
-    let db_url = std::format!("sqlite:{}", db_path.display());
+    let db_url = crate::services::database_url::url_for(&db_path);
 
-    let mut adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url)
+    let pool_settings = crate::services::database_pool::load_settings(&crate::services::config_path::path_in(&taskmaster_dir));
+    let mut adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init_with(&db_url, &pool_settings)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to connect to database: {}", e))?;
 
-    // Find task by ID
-    let filter = task_manager::ports::task_repository_port::TaskFilter::ById(task_id.to_string());
-
-    let task: std::option::Option<task_manager::domain::task::Task> = {
-        use hexser::ports::repository::QueryRepository;
-        adapter.find_one(&filter)?
-    };
-
-    let mut task = match task {
-        std::option::Option::Some(t) => t,
-        std::option::Option::None => {
-            anyhow::bail!("Task not found: {}\n\nUse 'riglist' to see available tasks.", task_id);
-        }
-    };
+    let task = resolve_task(&adapter, task_id).await?;
 
     println!("Task: {}", task.title);
     println!("Status: {:?}", task.status);
@@ -79,24 +115,191 @@ pub async fn execute(task_id: &str) -> anyhow::Result<()> {
         }
     }
 
-    // Read config to determine provider
-    let config_path = taskmaster_dir.join("config.json");
-    let config_content = std::fs::read_to_string(&config_path)
-        .map_err(|e| anyhow::anyhow!("Failed to read config.json: {}", e))?;
-    let config: serde_json::Value = serde_json::from_str(&config_content)?;
+    // The main slot of the merged config (config.json plus config.local.json) runs the task
+    let config_path = crate::services::config_path::path_in(&taskmaster_dir);
+    let config = crate::services::provider_slot::load_config(&taskmaster_dir)?;
+    let provider = config.task_slots.main.provider.as_str();
+    let model_name = config.task_slots.main.model.as_str();
 
-    let provider = config["provider"]
-        .as_str()
-        .unwrap_or("ollama");
+    // Provider calls made by the orchestrator are checked against this budget
+    let budget = crate::services::run_cost::load_budget(&config_path, max_cost)?;
+    config.orchestration.validate().map_err(|e| anyhow::anyhow!("Invalid config: {}", e))?;
+    let router_config = task_orchestrator::domain::router_config::RouterConfig::from_orchestration_config(&config.orchestration)
+        .map_err(|e| anyhow::anyhow!(e))?;
 
-    let model_name = config["model"]["main"]
-        .as_str()
-        .unwrap_or("llama3.1");
+    // The adapters record each call in the budget; the runner checks it before the next one
+    // Retries are recorded next to the tasks, where `rig metrics` reads them (tables created in run_task)
+    let metrics = std::sync::Arc::new(task_orchestrator::adapters::sqlite_metrics_collector::SqliteMetricsCollector::new(
+        adapter.pool().clone(),
+    ));
+    let factory = crate::services::provider_slot::factory_for(&config, &config.task_slots.main)?
+        .with_budget(budget.clone())
+        .with_metrics_collector(metrics);
+    let runner = task_orchestrator::use_cases::task_graph_runner::TaskGraphRunner::new(
+        factory.create_enhancement_adapter().map_err(|e| anyhow::anyhow!("Failed to create enhancement adapter: {}", e))?,
+        factory
+            .create_comprehension_test_adapter()
+            .map_err(|e| anyhow::anyhow!("Failed to create comprehension test adapter: {}", e))?,
+        std::string::String::from("short_answer"),
+    )
+    .with_router_config(router_config.clone())
+    .with_pass_threshold(config.orchestration.pass_threshold);
+    // Runs are recorded for `rig replay` when a run log directory is configured
+    let run_log_dir = config
+        .orchestration
+        .run_log_dir
+        .clone()
+        .map(std::path::PathBuf::from)
+        .or_else(task_orchestrator::use_cases::run_task_with_flow::run_log_dir_from_env);
+    let runner = match run_log_dir {
+        std::option::Option::Some(dir) => runner.with_run_log_dir(dir),
+        std::option::Option::None => runner,
+    };
+    // Each call of the run is appended to the configured metrics file
+    let project = crate::services::metrics_file::project_name(&taskmaster_dir);
+    let runner = match crate::services::metrics_file::load_collector(&config_path, &project)? {
+        std::option::Option::Some(collector) => runner.with_metrics(collector, provider, model_name),
+        std::option::Option::None => runner,
+    }
+    .with_budget(budget.clone());
+
+    // Routing makes no provider call, so the plan shows up front whether the task needs clarification
+    let plan = task_orchestrator::use_cases::run_plan::plan_run(&task, router_config)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to plan the run: {}", e))?;
+    if plan.route == task_orchestrator::domain::router_config::CLARIFY_ROUTE {
+        println!("The router is not confident enough to route this task ({}).", plan.reason);
+        println!("Add detail to its description and run it again; the task was not changed.");
+        return std::result::Result::Ok(());
+    }
+
+    let confirm = needs_confirmation(yes, std::io::IsTerminal::is_terminal(&std::io::stdout()));
+    let ran = run_if_confirmed(
+        confirm,
+        || {
+            let prices = crate::services::run_cost::load_prices(&config_path)?;
+            print_preview(&plan, &plan.cost(provider, model_name, prices));
+            ask_to_proceed()
+        },
+        || run_task(&mut adapter, task, provider, model_name, regenerate_tests, &runner, &budget, correlation_id),
+    )
+    .await?;
+
+    if ran.is_none() {
+        println!("Cancelled; the task was not run.");
+    }
+    std::result::Result::Ok(())
+}
+
+/// Returns true if `rig do` should preview the run and ask before starting it.
+///
+/// Scripts and pipes (stdout not a terminal) and `--yes` run without asking.
+fn needs_confirmation(yes: bool, stdout_is_terminal: bool) -> bool {
+    !yes && stdout_is_terminal
+}
+
+/// Calls `run` unless confirmation is required and `confirm` declines.
+///
+/// # Arguments
+///
+/// * `require_confirmation` - Whether to ask before running
+/// * `confirm` - Shows the preview and returns the user's answer
+/// * `run` - Starts the run; not called when declined
+///
+/// # Returns
+///
+/// The run's result, or None if the run was declined.
+///
+/// # Errors
+///
+/// Returns an error if asking or the run fails.
+async fn run_if_confirmed<T, C, R, Fut>(require_confirmation: bool, confirm: C, run: R) -> anyhow::Result<std::option::Option<T>>
+where
+    C: FnOnce() -> anyhow::Result<bool>,
+    R: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = anyhow::Result<T>>,
+{
+    if require_confirmation && !confirm()? {
+        return std::result::Result::Ok(std::option::Option::None);
+    }
+    run().await.map(std::option::Option::Some)
+}
+
+/// Prints the planned nodes and the estimated cost of a run.
+fn print_preview(
+    plan: &task_orchestrator::use_cases::run_plan::RunPlan,
+    cost: &task_orchestrator::domain::performance_metrics::CostAccumulator,
+) {
+    println!("Plan: {} route ({})", plan.route, plan.reason);
+    for (step, node) in plan.nodes.iter().enumerate() {
+        if node.calls_llm() {
+            println!(
+                "  {}. {}: {} (~{} input + ~{} output tokens)",
+                step + 1,
+                node.node,
+                node.prompt,
+                node.input_tokens,
+                node.output_tokens
+            );
+        } else {
+            println!("  {}. {}: {}", step + 1, node.node, node.prompt);
+        }
+    }
+    println!();
+    println!("Estimated cost:");
+    print!("{}", cost);
+    println!();
+}
+
+/// Asks on the terminal whether to run the task.
+///
+/// # Returns
+///
+/// True for "y" or "yes" (any case); anything else, including an empty answer, declines.
+fn ask_to_proceed() -> anyhow::Result<bool> {
+    print!("Run this task? [y/N] ");
+    std::io::Write::flush(&mut std::io::stdout())?;
+    let mut answer = std::string::String::new();
+    std::io::stdin().read_line(&mut answer)?;
+    std::result::Result::Ok(std::matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+/// Runs a confirmed task through `runner` and prints its summary.
+///
+/// The metrics tables the runner's retry counters are written to are created
+/// first, then the task is saved as InProgress before the run starts. If the run fails,
+/// including when `budget` is spent before a provider call, the task stays
+/// InProgress and the usage so far is printed.
+///
+/// # Errors
+///
+/// Returns an error if the run fails or saving the task fails.
+#[allow(clippy::too_many_arguments)]
+async fn run_task(
+    adapter: &mut task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter,
+    mut task: task_manager::domain::task::Task,
+    provider: &str,
+    model_name: &str,
+    regenerate_tests: bool,
+    runner: &task_orchestrator::use_cases::task_graph_runner::TaskGraphRunner,
+    budget: &task_orchestrator::domain::token_budget::TokenBudget,
+    correlation_id: &task_orchestrator::domain::correlation_id::CorrelationId,
+) -> anyhow::Result<()> {
+    task_orchestrator::adapters::sqlite_metrics_collector::SqliteMetricsCollector::migrate(adapter.pool())
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to prepare metrics tables: {}", e))?;
 
     println!("Executing task using {} with {}...", provider, model_name);
     println!();
 
-    // Mark task as InProgress
+    // Stale tests are regenerated by the orchestrator; the flag forces it for current ones
+    if regenerate_tests {
+        let marked = task.mark_comprehension_tests_stale();
+        println!("✓ Marked {} comprehension test(s) stale for regeneration", marked);
+    }
+
+    // Mark task as InProgress, starting its time-tracking interval
+    task.track_status_time(&task_manager::domain::task_status::TaskStatus::InProgress, chrono::Utc::now());
     task.status = task_manager::domain::task_status::TaskStatus::InProgress;
     task.updated_at = chrono::Utc::now();
 
@@ -108,13 +311,20 @@ pub async fn execute(task_id: &str) -> anyhow::Result<()> {
     println!("✓ Task status updated to InProgress");
     println!();
 
-    // For now, just mark as completed (full orchestration in future sprint)
-    // TODO: Integrate with task_orchestrator::use_cases::Orchestrator in Phase 1
-    println!("⚠️  Note: Full orchestration (enhancements + comprehension tests) will be available in Phase 1.");
-    println!("   For now, marking task as completed.");
+    task = match runner.run_task(task).await {
+        std::result::Result::Ok(orchestrated) => orchestrated,
+        std::result::Result::Err(e) => {
+            println!();
+            crate::services::run_cost::print_summary(&budget.usage());
+            anyhow::bail!("Orchestration failed: {}\nThe task was left InProgress.", e);
+        }
+    };
+
+    println!("✓ Orchestration complete");
     println!();
 
     // Mark task as Completed
+    task.track_status_time(&task_manager::domain::task_status::TaskStatus::Completed, chrono::Utc::now());
     task.status = task_manager::domain::task_status::TaskStatus::Completed;
     task.updated_at = chrono::Utc::now();
 
@@ -131,17 +341,89 @@ pub async fn execute(task_id: &str) -> anyhow::Result<()> {
     println!("  Task ID: {}", task.id);
     println!("  Title: {}", task.title);
     println!("  Status: {:?}", task.status);
+    println!("  Run ID: {}", correlation_id);
     if let std::option::Option::Some(enhancements) = &task.enhancements {
         println!("  Enhancements: {}", enhancements.len());
     }
     if let std::option::Option::Some(tests) = &task.comprehension_tests {
-        println!("  Comprehension Tests: {}", tests.len());
+        let stale = tests.iter().filter(|test| test.stale).count();
+        println!("  Comprehension Tests: {} ({} stale)", tests.len(), stale);
     }
     println!();
 
+    crate::services::run_cost::print_summary(&budget.usage());
+
     std::result::Result::Ok(())
 }
 
+/// Finds the task to execute from an ID, an ID prefix, or the picker.
+///
+/// An exact ID is used directly. A prefix matching one task selects it; a
+/// prefix matching several opens the picker over those tasks. Without an ID
+/// the picker lists all tasks that are not completed or archived.
+///
+/// # Errors
+///
+/// Returns an error if nothing matches, the picker is cancelled, or the
+/// database query fails.
+async fn resolve_task(
+    adapter: &task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter,
+    task_id: std::option::Option<&str>,
+) -> anyhow::Result<task_manager::domain::task::Task> {
+    if let std::option::Option::Some(id) = task_id {
+        let filter = task_manager::ports::task_repository_port::TaskFilter::ById(id.to_string());
+        if let std::option::Option::Some(task) = adapter.find_one_async(&filter).await.map_err(|e| anyhow::anyhow!("{}", e))? {
+            return std::result::Result::Ok(task);
+        }
+    }
+
+    let all_tasks = adapter
+        .find_async(
+            &task_manager::ports::task_repository_port::TaskFilter::All,
+            hexser::ports::repository::FindOptions {
+                sort: std::option::Option::None,
+                limit: std::option::Option::None,
+                offset: std::option::Option::None,
+            },
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    let candidates: std::vec::Vec<task_manager::domain::task::Task> = match task_id {
+        std::option::Option::Some(prefix) => {
+            let prefix = prefix.to_lowercase();
+            all_tasks.into_iter().filter(|t| t.id.to_lowercase().starts_with(&prefix)).collect()
+        }
+        std::option::Option::None => all_tasks
+            .into_iter()
+            .filter(|t| {
+                !std::matches!(
+                    t.status,
+                    task_manager::domain::task_status::TaskStatus::Completed
+                        | task_manager::domain::task_status::TaskStatus::Archived
+                )
+            })
+            .collect(),
+    };
+
+    if candidates.is_empty() {
+        match task_id {
+            std::option::Option::Some(id) => {
+                anyhow::bail!("Task not found: {}\n\nUse 'rig list' to see available tasks.", id)
+            }
+            std::option::Option::None => anyhow::bail!("No open tasks to do. Use 'rig add' to create one."),
+        }
+    }
+    if candidates.len() == 1 && task_id.is_some() {
+        return std::result::Result::Ok(candidates.into_iter().next().unwrap());
+    }
+
+    match crate::ui::task_picker::pick_task(candidates, "")? {
+        std::option::Option::Some(task) => std::result::Result::Ok(task),
+        std::option::Option::None => anyhow::bail!("No task selected."),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[tokio::test]
@@ -155,7 +437,7 @@ mod tests {
         let original_dir = std::env::current_dir().unwrap();
         std::env::set_current_dir(&temp_dir).unwrap();
 
-        let result = super::execute("fake-id").await;
+        let result = super::execute(std::option::Option::Some("fake-id"), std::option::Option::None, false, true).await;
         std::assert!(result.is_err(), "Do should fail if .rigdoesn't exist");
 
         // Cleanup
@@ -178,7 +460,7 @@ mod tests {
         crate::commands::init::execute().await.unwrap();
 
         // Try to execute nonexistent task
-        let result = super::execute("nonexistent-id").await;
+        let result = super::execute(std::option::Option::Some("nonexistent-id"), std::option::Option::None, false, true).await;
         std::assert!(result.is_err(), "Do should fail if task doesn't exist");
         std::assert!(result.unwrap_err().to_string().contains("not found"));
 
@@ -186,4 +468,209 @@ mod tests {
         let _ = std::env::set_current_dir(original_dir);
         let _ = std::fs::remove_dir_all(&temp_dir);
     }
+
+    #[tokio::test]
+    async fn test_declined_run_makes_no_provider_calls() {
+        // Test: Validates declining the confirmation returns None without starting the run.
+        // Justification: Saying no must exit cleanly with no provider calls and no task changes.
+        let provider_calls = std::sync::atomic::AtomicUsize::new(0);
+
+        let ran = super::run_if_confirmed(
+            true,
+            || std::result::Result::Ok(false),
+            || async {
+                provider_calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                std::result::Result::Ok(())
+            },
+        )
+        .await
+        .unwrap();
+
+        std::assert!(ran.is_none());
+        std::assert_eq!(provider_calls.load(std::sync::atomic::Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_confirmed_or_unattended_run_proceeds() {
+        // Test: Validates an accepted prompt runs once, and a run not requiring confirmation never asks.
+        // Justification: --yes and piped output must not block on a prompt nobody can answer.
+        let provider_calls = std::sync::atomic::AtomicUsize::new(0);
+        let run = || async {
+            provider_calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            std::result::Result::Ok(())
+        };
+
+        let accepted = super::run_if_confirmed(true, || std::result::Result::Ok(true), run).await.unwrap();
+        let unattended = super::run_if_confirmed(false, || std::panic!("must not ask"), run).await.unwrap();
+
+        std::assert!(accepted.is_some() && unattended.is_some());
+        std::assert_eq!(provider_calls.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_confirmation_only_on_terminal_without_yes() {
+        // Test: Validates the prompt is shown only when stdout is a terminal and --yes is absent.
+        // Justification: Scripts and CI must never stall waiting for input.
+        std::assert!(super::needs_confirmation(false, true));
+        std::assert!(!super::needs_confirmation(true, true));
+        std::assert!(!super::needs_confirmation(false, false));
+        std::assert!(!super::needs_confirmation(true, false));
+    }
+
+    /// Enhancement and test port that records 1000 tokens in the budget per call.
+    struct MeteredPorts {
+        budget: std::sync::Arc<task_orchestrator::domain::token_budget::TokenBudget>,
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    impl MeteredPorts {
+        fn record_call(&self, operation: &str) {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            self.budget.record_exchange(operation, "mock", "mock-model", &"p".repeat(2000), &"r".repeat(2000));
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl task_orchestrator::ports::task_enhancement_port::TaskEnhancementPort for MeteredPorts {
+        async fn generate_enhancement(
+            &self,
+            task: &task_manager::domain::task::Task,
+        ) -> std::result::Result<task_manager::domain::enhancement::Enhancement, std::string::String> {
+            self.record_call("enhancement");
+            std::result::Result::Ok(task_manager::domain::enhancement::Enhancement {
+                enhancement_id: std::string::String::from("e-1"),
+                task_id: task.id.clone(),
+                timestamp: chrono::Utc::now(),
+                enhancement_type: std::string::String::from("clarify"),
+                content: std::format!("Clarify: {}", task.title),
+            })
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl task_orchestrator::ports::comprehension_test_port::ComprehensionTestPort for MeteredPorts {
+        async fn generate_comprehension_test(
+            &self,
+            task: &task_manager::domain::task::Task,
+            test_type: &str,
+        ) -> std::result::Result<task_manager::domain::comprehension_test::ComprehensionTest, std::string::String> {
+            self.record_call("comprehension_test");
+            std::result::Result::Ok(task_manager::domain::comprehension_test::ComprehensionTest {
+                test_id: std::string::String::from("ct-1"),
+                task_id: task.id.clone(),
+                timestamp: chrono::Utc::now(),
+                test_type: std::string::String::from(test_type),
+                question: std::format!("What does {} need?", task.title),
+                options: std::option::Option::None,
+                correct_answer: std::string::String::from("A"),
+                description_hash: std::option::Option::None,
+                stale: false,
+            })
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_do_stops_once_budget_is_exceeded() {
+        // Test: Validates `rig do` refuses the provider call that would cross max_tokens and leaves the task InProgress.
+        // Justification: --max-cost and max_tokens_per_run are only limits if the run actually stops before overspending.
+        let mut adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init("sqlite::memory:").await.unwrap();
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Setup database"),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let task = task_manager::domain::task::Task::from_action_item(&action, std::option::Option::None);
+        adapter.save_async(task.clone()).await.unwrap();
+
+        // 1000 tokens per call: after the enhancement, the test call is projected to reach 2000
+        let budget = std::sync::Arc::new(task_orchestrator::domain::token_budget::TokenBudget::new(
+            std::option::Option::Some(1500),
+            std::option::Option::None,
+            std::collections::HashMap::new(),
+        ));
+        let ports = std::sync::Arc::new(MeteredPorts {
+            budget: budget.clone(),
+            calls: std::sync::atomic::AtomicUsize::new(0),
+        });
+        let runner = task_orchestrator::use_cases::task_graph_runner::TaskGraphRunner::new(
+            ports.clone(),
+            ports.clone(),
+            std::string::String::from("short_answer"),
+        )
+        .with_budget(budget.clone());
+        let correlation_id = task_orchestrator::domain::correlation_id::CorrelationId::generate();
+
+        let error = super::run_task(&mut adapter, task.clone(), "mock", "mock-model", false, &runner, &budget, &correlation_id)
+            .await
+            .unwrap_err()
+            .to_string();
+
+        std::assert!(error.contains("BudgetExceeded"), "unexpected error: {}", error);
+        std::assert_eq!(ports.calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+        let saved = adapter
+            .find_one_async(&task_manager::ports::task_repository_port::TaskFilter::ById(task.id.clone()))
+            .await
+            .unwrap()
+            .unwrap();
+        std::assert_eq!(saved.status, task_manager::domain::task_status::TaskStatus::InProgress);
+        std::assert!(saved.started_at.is_some(), "the halted task's in-progress interval should still be open");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_metrics_tables_are_created_by_the_confirmed_run() {
+        // Test: Validates the metrics tables do not exist until run_task starts the confirmed run.
+        // Justification: Declining the confirmation prompt must leave the database untouched, DDL included.
+        let mut adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init("sqlite::memory:").await.unwrap();
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Setup database"),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let task = task_manager::domain::task::Task::from_action_item(&action, std::option::Option::None);
+        adapter.save_async(task.clone()).await.unwrap();
+        let budget = std::sync::Arc::new(task_orchestrator::domain::token_budget::TokenBudget::new(
+            std::option::Option::None,
+            std::option::Option::None,
+            std::collections::HashMap::new(),
+        ));
+        let ports = std::sync::Arc::new(MeteredPorts {
+            budget: budget.clone(),
+            calls: std::sync::atomic::AtomicUsize::new(0),
+        });
+        let runner = task_orchestrator::use_cases::task_graph_runner::TaskGraphRunner::new(
+            ports.clone(),
+            ports.clone(),
+            std::string::String::from("short_answer"),
+        );
+        let retry_tables = "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = 'retry_counters'";
+
+        let before: i64 = sqlx::query_scalar(retry_tables).fetch_one(adapter.pool()).await.unwrap();
+        let correlation_id = task_orchestrator::domain::correlation_id::CorrelationId::generate();
+        super::run_task(&mut adapter, task, "mock", "mock-model", false, &runner, &budget, &correlation_id)
+            .await
+            .unwrap();
+        let after: i64 = sqlx::query_scalar(retry_tables).fetch_one(adapter.pool()).await.unwrap();
+
+        std::assert_eq!(before, 0);
+        std::assert_eq!(after, 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_resolve_task_accepts_unique_id_prefix() {
+        // Test: Validates a short ID prefix matching one task resolves without the picker.
+        // Justification: Users paste the 8-character IDs shown by `rig list`.
+        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init("sqlite::memory:").await.unwrap();
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Setup database"),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let task = task_manager::domain::task::Task::from_action_item(&action, std::option::Option::None);
+        let short_id: std::string::String = task.id.chars().take(8).collect();
+        adapter.save_async(task.clone()).await.unwrap();
+
+        let resolved = super::resolve_task(&adapter, std::option::Option::Some(&short_id)).await.unwrap();
+
+        std::assert_eq!(resolved.id, task.id);
+    }
 }
@@ -29,7 +29,31 @@
 //! - `SubscribeToTaskEvents`: Subscribe to task events stream
 //! - `TaskEventStream`: Bidirectional streaming for real-time updates
 //!
+//! # Tracing
+//!
+//! With `--trace`, every `OrchestrateTask` run prints its path through the
+//! orchestration graph to stderr, one line per event, prefixed with the run's
+//! correlation ID: nodes entered and exited (with durations) and the branches
+//! taken by the router and check nodes, with their reasons.
+//!
+//! # Authentication
+//!
+//! If `server.auth_token_env` is set in .rigger/config.json, every request must
+//! carry `authorization: Bearer <token>` metadata matching that variable's value;
+//! other requests are rejected with `UNAUTHENTICATED`.
+//!
 //! Revision History
+//! - 2026-10-18T01:40:00Z @AI: Apply the Ollama provider's timeout and retry policy to orchestration runs.
+//! - 2026-10-17T22:40:00Z @AI: Pass the config file's decomposition section to orchestration runs.
+//! - 2026-10-17T22:20:00Z @AI: Track time spent in progress on UpdateTask status changes and archiving.
+//! - 2026-10-17T21:40:00Z @AI: Record provider retries of orchestration runs in the tasks database.
+//! - 2026-10-17T14:10:00Z @AI: Open the database through services::database_url so --db / RIGGER_DB apply.
+//! - 2026-10-17T13:50:00Z @AI: Read the config path from services::config_path, honoring --config and RIGGER_CONFIG.
+//! - 2026-10-17T07:50:00Z @AI: Add --trace, printing each OrchestrateTask run's graph trace to stderr.
+//! - 2026-10-17T05:50:00Z @AI: Share one task database pool, sized by database.pool_size, across all requests.
+//! - 2026-10-17T00:10:00Z @AI: Initialize Persona::tool_call_limits.
+//! - 2026-10-16T20:10:00Z @AI: Run OrchestrateTask under a per-request correlation ID (run span, event metadata, x-correlation-id response header).
+//! - 2026-10-16T19:30:00Z @AI: Add optional bearer-token interceptor (server.auth_token_env).
 //! - 2025-11-23T19:30:00Z @AI: Implement gRPC server with tonic for sidecar broadcast support.
 
 pub mod rigger {
@@ -50,22 +74,58 @@ use task_orchestrator::ports::prd_parser_port::PRDParserPort;
 pub struct RiggerServiceImpl {
     /// Database path for task persistence
     db_path: std::path::PathBuf,
+    /// Connection pool shared by every request
+    pool: sqlx::Pool<sqlx::Sqlite>,
     /// Broadcast channel for task events (sender)
     event_tx: tokio::sync::broadcast::Sender<TaskEvent>,
+    /// Whether orchestration runs print their graph trace to stderr
+    trace: bool,
+    /// Decomposition settings from the config file, applied to every orchestration run
+    decomposition: task_orchestrator::infrastructure::config::DecompositionConfig,
+    /// Timeout and retry policy of the provider that orchestration runs call
+    retry_policy: task_orchestrator::infrastructure::retry_policy::RetryPolicy,
 }
 
 impl RiggerServiceImpl {
-    /// Creates a new RiggerServiceImpl with the given database path.
+    /// Creates a new RiggerServiceImpl with the given database path and its pool.
     ///
     /// Initializes the broadcast channel for task events with capacity 1000.
-    pub fn new(db_path: std::path::PathBuf) -> Self {
+    pub fn new(db_path: std::path::PathBuf, pool: sqlx::Pool<sqlx::Sqlite>) -> Self {
         let (event_tx, _) = tokio::sync::broadcast::channel(1000);
-        RiggerServiceImpl { db_path, event_tx }
+        RiggerServiceImpl {
+            db_path,
+            pool,
+            event_tx,
+            trace: false,
+            decomposition: task_orchestrator::infrastructure::config::DecompositionConfig::default(),
+            retry_policy: task_orchestrator::infrastructure::retry_policy::RetryPolicy::default(),
+        }
+    }
+
+    /// Enables printing each orchestration run's graph trace to stderr.
+    pub fn with_trace(mut self, trace: bool) -> Self {
+        self.trace = trace;
+        self
+    }
+
+    /// Sets the decomposition threshold and depth of orchestration runs.
+    pub fn with_decomposition_config(
+        mut self,
+        decomposition: task_orchestrator::infrastructure::config::DecompositionConfig,
+    ) -> Self {
+        self.decomposition = decomposition;
+        self
+    }
+
+    /// Sets the timeout and retry policy of orchestration runs' provider calls.
+    pub fn with_retry_policy(mut self, retry_policy: task_orchestrator::infrastructure::retry_policy::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
     }
 
-    /// Gets the database URL string for SqliteTaskAdapter.
-    fn db_url(&self) -> std::string::String {
-        std::format!("sqlite:{}", self.db_path.display())
+    /// Returns a task adapter on the shared pool.
+    fn task_adapter(&self) -> task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter {
+        task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::new(self.pool.clone())
     }
 
     /// Broadcasts a task event to all sidecar subscribers.
@@ -128,6 +188,144 @@ impl RiggerServiceImpl {
             _ => Err(Status::invalid_argument("Invalid task status")),
         }
     }
+
+    /// Loads, orchestrates, and saves a task; `orchestrate_task` runs this
+    /// inside the `run` span for `correlation_id`.
+    // Status is tonic's error type for every handler in this service
+    #[allow(clippy::result_large_err)]
+    async fn orchestrate(
+        &self,
+        req: OrchestrateTaskRequest,
+        correlation_id: &task_orchestrator::domain::correlation_id::CorrelationId,
+    ) -> std::result::Result<OrchestrateTaskResponse, Status> {
+
+        // Connect to database and load task
+        let adapter = self.task_adapter();
+
+        let task = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::find_one_async(
+            &adapter,
+            &task_manager::ports::task_repository_port::TaskFilter::ById(req.task_id.clone()),
+        )
+        .await
+        .map_err(|e| Status::internal(std::format!("Database query failed: {:?}", e)))?
+        .ok_or_else(|| Status::not_found(std::format!("Task not found: {}", req.task_id)))?;
+
+        // Run task through orchestration flow
+        // Create provider factory from model parameter
+        let factory = task_orchestrator::adapters::provider_factory::ProviderFactory::new("ollama", &req.model)
+            .map_err(|e| Status::internal(std::format!("Failed to create provider factory: {}", e)))?
+            .with_metrics_collector(std::sync::Arc::new(
+                task_orchestrator::adapters::sqlite_metrics_collector::SqliteMetricsCollector::new(self.pool.clone()),
+            ))
+            .with_decomposition_config(self.decomposition)
+            .with_retry_policy(self.retry_policy.clone());
+
+        let orchestrated_task = if self.trace {
+            let (trace_tx, trace_rx) = tokio::sync::mpsc::channel(64);
+            let printer = tokio::spawn(print_trace(correlation_id.clone(), trace_rx));
+            let result = task_orchestrator::use_cases::run_task_with_flow::run_task_with_flow_traced(
+                &factory,
+                &req.test_type,
+                task,
+                correlation_id.clone(),
+                trace_tx,
+            )
+            .await;
+            // The sender is dropped with the run, so the printer drains and exits
+            let _ = printer.await;
+            result
+        } else {
+            task_orchestrator::use_cases::run_task_with_flow::run_task_with_flow_correlated(
+                &factory,
+                &req.test_type,
+                task,
+                correlation_id.clone(),
+            )
+            .await
+        }
+        .map_err(|e| Status::internal(std::format!("Orchestration failed: {}", e)))?;
+
+        // Save orchestrated task back to database
+        task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::save_async(&adapter, orchestrated_task.clone())
+            .await
+            .map_err(|e| Status::internal(std::format!("Failed to save task: {:?}", e)))?;
+
+        // Build orchestration result
+        let routing_decision = match orchestrated_task.status {
+            task_manager::domain::task_status::TaskStatus::Decomposed => "decompose",
+            task_manager::domain::task_status::TaskStatus::OrchestrationComplete => "pass",
+            _ => "enhance",
+        };
+
+        // Convert enhancements if present
+        let enhancement = orchestrated_task.enhancements.as_ref().and_then(|enhs| {
+            enhs.last().map(|enh| Enhancement {
+                enhancement_id: enh.enhancement_id.clone(),
+                task_id: enh.task_id.clone(),
+                timestamp: enh.timestamp.to_rfc3339(),
+                enhancement_type: enh.enhancement_type.clone(),
+                content: enh.content.clone(),
+            })
+        });
+
+        // Convert comprehension test if present
+        let comprehension_test = orchestrated_task.comprehension_tests.as_ref().and_then(|tests| {
+            tests.last().map(|test| ComprehensionTest {
+                test_id: test.test_id.clone(),
+                task_id: test.task_id.clone(),
+                timestamp: test.timestamp.to_rfc3339(),
+                test_type: test.test_type.clone(),
+                question: test.question.clone(),
+                options: test.options.clone().unwrap_or_default(),
+                correct_answer: test.correct_answer.clone(),
+            })
+        });
+
+        // Get subtasks if decomposed
+        let subtasks = if orchestrated_task.status == task_manager::domain::task_status::TaskStatus::Decomposed {
+            let mut subtask_tasks = std::vec::Vec::new();
+            for subtask_id in &orchestrated_task.subtask_ids {
+                if let Ok(std::option::Option::Some(subtask)) = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::find_one_async(
+                    &adapter,
+                    &task_manager::ports::task_repository_port::TaskFilter::ById(subtask_id.clone()),
+                ).await {
+                    subtask_tasks.push(self.task_to_proto(&subtask));
+                }
+            }
+            subtask_tasks
+        } else {
+            std::vec::Vec::new()
+        };
+
+        let result = OrchestrationResult {
+            success: true,
+            routing_decision: routing_decision.to_string(),
+            enhancement,
+            comprehension_test,
+            subtasks,
+        };
+
+        // Broadcast orchestration event
+        let event = TaskEvent {
+            event_id: uuid::Uuid::new_v4().to_string(),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            event_type: TaskEventType::Orchestrated as i32,
+            task: std::option::Option::Some(self.task_to_proto(&orchestrated_task)),
+            actor: std::option::Option::None,
+            metadata: std::collections::HashMap::from([
+                ("model".to_string(), req.model),
+                ("test_type".to_string(), req.test_type),
+                ("routing_decision".to_string(), routing_decision.to_string()),
+                ("correlation_id".to_string(), correlation_id.to_string()),
+            ]),
+        };
+        self.broadcast_event(event);
+
+        Ok(OrchestrateTaskResponse {
+            task: std::option::Option::Some(self.task_to_proto(&orchestrated_task)),
+            result: std::option::Option::Some(result),
+        })
+    }
 }
 
 #[tonic::async_trait]
@@ -139,9 +337,7 @@ impl RiggerService for RiggerServiceImpl {
         let req = request.into_inner();
 
         // Connect to database
-        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&self.db_url())
-            .await
-            .map_err(|e| Status::internal(std::format!("Database connection failed: {}", e)))?;
+        let adapter = self.task_adapter();
 
         // Build filter
         let filter = if let std::option::Option::Some(status) = req.status {
@@ -196,9 +392,7 @@ impl RiggerService for RiggerServiceImpl {
         task.parent_task_id = req.parent_task_id;
 
         // Connect to database and save
-        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&self.db_url())
-            .await
-            .map_err(|e| Status::internal(std::format!("Database connection failed: {}", e)))?;
+        let adapter = self.task_adapter();
 
         task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::save_async(&adapter, task.clone())
             .await
@@ -227,9 +421,7 @@ impl RiggerService for RiggerServiceImpl {
         let req = request.into_inner();
 
         // Connect to database
-        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&self.db_url())
-            .await
-            .map_err(|e| Status::internal(std::format!("Database connection failed: {}", e)))?;
+        let adapter = self.task_adapter();
 
         // Load existing task
         let mut task = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::find_one_async(
@@ -242,7 +434,9 @@ impl RiggerService for RiggerServiceImpl {
 
         // Update fields
         if let std::option::Option::Some(status) = req.status {
-            task.status = self.proto_to_status(status)?;
+            let status = self.proto_to_status(status)?;
+            task.track_status_time(&status, chrono::Utc::now());
+            task.status = status;
         }
         if let std::option::Option::Some(assignee) = req.assignee {
             task.agent_persona = std::option::Option::Some(assignee);
@@ -280,9 +474,7 @@ impl RiggerService for RiggerServiceImpl {
         let req = request.into_inner();
 
         // Connect to database
-        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&self.db_url())
-            .await
-            .map_err(|e| Status::internal(std::format!("Database connection failed: {}", e)))?;
+        let adapter = self.task_adapter();
 
         // Load task
         let task = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::find_one_async(
@@ -305,9 +497,7 @@ impl RiggerService for RiggerServiceImpl {
         let req = request.into_inner();
 
         // Connect to database
-        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&self.db_url())
-            .await
-            .map_err(|e| Status::internal(std::format!("Database connection failed: {}", e)))?;
+        let adapter = self.task_adapter();
 
         // Load task and set status to Archived
         let mut task = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::find_one_async(
@@ -318,6 +508,7 @@ impl RiggerService for RiggerServiceImpl {
         .map_err(|e| Status::internal(std::format!("Database query failed: {:?}", e)))?
         .ok_or_else(|| Status::not_found(std::format!("Task not found: {}", req.task_id)))?;
 
+        task.track_status_time(&task_manager::domain::task_status::TaskStatus::Archived, chrono::Utc::now());
         task.status = task_manager::domain::task_status::TaskStatus::Archived;
         task.updated_at = chrono::Utc::now();
 
@@ -369,9 +560,7 @@ impl RiggerService for RiggerServiceImpl {
         let req = request.into_inner();
 
         // Connect to database
-        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&self.db_url())
-            .await
-            .map_err(|e| Status::internal(std::format!("Database connection failed: {}", e)))?;
+        let adapter = self.task_adapter();
 
         // Load PRD from database (assuming it was saved when parsed)
         // For now, we'll reconstruct from the PRD ID if it's a file path
@@ -414,6 +603,7 @@ impl RiggerService for RiggerServiceImpl {
                     created_at: created_at.with_timezone(&chrono::Utc),
                     updated_at: updated_at.with_timezone(&chrono::Utc),
                     enabled_tools: std::vec::Vec::new(),
+                    tool_call_limits: std::collections::HashMap::new(),
                 });
             }
         }
@@ -465,113 +655,16 @@ impl RiggerService for RiggerServiceImpl {
         &self,
         request: Request<OrchestrateTaskRequest>,
     ) -> std::result::Result<Response<OrchestrateTaskResponse>, Status> {
-        let req = request.into_inner();
-
-        // Connect to database and load task
-        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&self.db_url())
-            .await
-            .map_err(|e| Status::internal(std::format!("Database connection failed: {}", e)))?;
-
-        let task = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::find_one_async(
-            &adapter,
-            &task_manager::ports::task_repository_port::TaskFilter::ById(req.task_id.clone()),
-        )
-        .await
-        .map_err(|e| Status::internal(std::format!("Database query failed: {:?}", e)))?
-        .ok_or_else(|| Status::not_found(std::format!("Task not found: {}", req.task_id)))?;
-
-        // Run task through orchestration flow
-        // Create provider factory from model parameter
-        let factory = task_orchestrator::adapters::provider_factory::ProviderFactory::new("ollama", &req.model)
-            .map_err(|e| Status::internal(std::format!("Failed to create provider factory: {}", e)))?;
-
-        let orchestrated_task = task_orchestrator::use_cases::run_task_with_flow::run_task_with_flow(
-            &factory,
-            &req.test_type,
-            task,
-        )
-        .await
-        .map_err(|e| Status::internal(std::format!("Orchestration failed: {}", e)))?;
-
-        // Save orchestrated task back to database
-        task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::save_async(&adapter, orchestrated_task.clone())
-            .await
-            .map_err(|e| Status::internal(std::format!("Failed to save task: {:?}", e)))?;
-
-        // Build orchestration result
-        let routing_decision = match orchestrated_task.status {
-            task_manager::domain::task_status::TaskStatus::Decomposed => "decompose",
-            task_manager::domain::task_status::TaskStatus::OrchestrationComplete => "pass",
-            _ => "enhance",
-        };
-
-        // Convert enhancements if present
-        let enhancement = orchestrated_task.enhancements.as_ref().and_then(|enhs| {
-            enhs.last().map(|enh| Enhancement {
-                enhancement_id: enh.enhancement_id.clone(),
-                task_id: enh.task_id.clone(),
-                timestamp: enh.timestamp.to_rfc3339(),
-                enhancement_type: enh.enhancement_type.clone(),
-                content: enh.content.clone(),
-            })
-        });
-
-        // Convert comprehension test if present
-        let comprehension_test = orchestrated_task.comprehension_tests.as_ref().and_then(|tests| {
-            tests.last().map(|test| ComprehensionTest {
-                test_id: test.test_id.clone(),
-                task_id: test.task_id.clone(),
-                timestamp: test.timestamp.to_rfc3339(),
-                test_type: test.test_type.clone(),
-                question: test.question.clone(),
-                options: test.options.clone().unwrap_or_default(),
-                correct_answer: test.correct_answer.clone(),
-            })
-        });
-
-        // Get subtasks if decomposed
-        let subtasks = if orchestrated_task.status == task_manager::domain::task_status::TaskStatus::Decomposed {
-            let mut subtask_tasks = std::vec::Vec::new();
-            for subtask_id in &orchestrated_task.subtask_ids {
-                if let Ok(std::option::Option::Some(subtask)) = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::find_one_async(
-                    &adapter,
-                    &task_manager::ports::task_repository_port::TaskFilter::ById(subtask_id.clone()),
-                ).await {
-                    subtask_tasks.push(self.task_to_proto(&subtask));
-                }
-            }
-            subtask_tasks
-        } else {
-            std::vec::Vec::new()
-        };
-
-        let result = OrchestrationResult {
-            success: true,
-            routing_decision: routing_decision.to_string(),
-            enhancement,
-            comprehension_test,
-            subtasks,
-        };
-
-        // Broadcast orchestration event
-        let event = TaskEvent {
-            event_id: uuid::Uuid::new_v4().to_string(),
-            timestamp: chrono::Utc::now().to_rfc3339(),
-            event_type: TaskEventType::Orchestrated as i32,
-            task: std::option::Option::Some(self.task_to_proto(&orchestrated_task)),
-            actor: std::option::Option::None,
-            metadata: std::collections::HashMap::from([
-                ("model".to_string(), req.model),
-                ("test_type".to_string(), req.test_type),
-                ("routing_decision".to_string(), routing_decision.to_string()),
-            ]),
-        };
-        self.broadcast_event(event);
-
-        Ok(Response::new(OrchestrateTaskResponse {
-            task: std::option::Option::Some(self.task_to_proto(&orchestrated_task)),
-            result: std::option::Option::Some(result),
-        }))
+        // Tag every log event of this run with one ID and return it to the caller
+        let correlation_id = task_orchestrator::domain::correlation_id::CorrelationId::generate();
+        let span = task_orchestrator::infrastructure::telemetry::run_span(&correlation_id);
+        let result = task_manager::infrastructure::telemetry::timed(span, self.orchestrate(request.into_inner(), &correlation_id)).await?;
+
+        let mut response = Response::new(result);
+        if let Ok(value) = correlation_id.as_str().parse() {
+            response.metadata_mut().insert("x-correlation-id", value);
+        }
+        Ok(response)
     }
 
     async fn subscribe_to_task_events(
@@ -618,15 +711,56 @@ impl RiggerService for RiggerServiceImpl {
     >;
 }
 
+/// Builds an interceptor that enforces the configured bearer token.
+///
+/// # Arguments
+///
+/// * `expected` - The token clients must present, or None to accept all requests
+///
+/// # Returns
+///
+/// An interceptor that passes requests with matching `authorization: Bearer <token>`
+/// metadata and rejects others with `Status::unauthenticated`.
+pub fn auth_interceptor(
+    expected: std::option::Option<String>,
+) -> impl FnMut(Request<()>) -> std::result::Result<Request<()>, Status> + Clone {
+    move |request: Request<()>| {
+        let expected = match &expected {
+            std::option::Option::Some(token) => token,
+            std::option::Option::None => return std::result::Result::Ok(request),
+        };
+        let authorization = request
+            .metadata()
+            .get("authorization")
+            .and_then(|value| value.to_str().ok());
+        if crate::services::server_auth::is_authorized(authorization, expected) {
+            std::result::Result::Ok(request)
+        } else {
+            std::result::Result::Err(Status::unauthenticated("Missing or invalid bearer token"))
+        }
+    }
+}
+
+/// Prints a run's trace events to stderr until the run drops its sender.
+async fn print_trace(
+    correlation_id: task_orchestrator::domain::correlation_id::CorrelationId,
+    mut events: tokio::sync::mpsc::Receiver<task_orchestrator::domain::trace_event::TraceEvent>,
+) {
+    while let Some(event) = events.recv().await {
+        eprintln!("[trace {}] {}", correlation_id, event);
+    }
+}
+
 /// Executes the 'rig grpc' command.
 ///
 /// Starts gRPC server on port 50051 (default) and listens for incoming connections.
 /// All task events are broadcast to subscribed sidecars via the SubscribeToTaskEvents stream.
+/// With `trace`, each orchestration run's graph trace is printed to stderr.
 ///
 /// # Errors
 ///
 /// Returns an error if server initialization fails or bind errors occur.
-pub async fn execute() -> anyhow::Result<()> {
+pub async fn execute(trace: bool) -> anyhow::Result<()> {
     // Get database path
     let cwd = std::env::current_dir()?;
     let rigger_dir = cwd.join(".rigger");
@@ -634,23 +768,84 @@ pub async fn execute() -> anyhow::Result<()> {
         anyhow::bail!(".rigger directory not found. Run 'rig init' first.");
     }
     let db_path = rigger_dir.join("tasks.db");
+    let config_path = crate::services::config_path::path_in(&rigger_dir);
+    let auth_token = crate::services::server_auth::load_expected_token(&config_path)?;
+    let decomposition = task_orchestrator::infrastructure::config::DecompositionConfig::load(&config_path)
+        .map_err(|e| anyhow::anyhow!("Failed to load decomposition settings: {}", e))?;
+    // Orchestration runs call Ollama; its provider entry sets their timeout and retries
+    let retry_policy = crate::services::provider_slot::load_config(&rigger_dir)
+        .ok()
+        .and_then(|config| config.providers.get("ollama").map(task_orchestrator::infrastructure::retry_policy::RetryPolicy::from_provider_config))
+        .unwrap_or_default();
+
+    // One pool, sized by database.pool_size, serves every request
+    let pool_settings = crate::services::database_pool::load_settings(&config_path);
+    let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init_with(
+        &crate::services::database_url::url_for(&db_path),
+        &pool_settings,
+    )
+    .await
+    .map_err(|e| anyhow::anyhow!("Failed to connect to database: {}", e))?;
+    // Orchestration runs record their provider retries on the same pool
+    task_orchestrator::adapters::sqlite_metrics_collector::SqliteMetricsCollector::migrate(adapter.pool())
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to prepare metrics tables: {}", e))?;
 
     // Create service
-    let service = RiggerServiceImpl::new(db_path);
+    let service = RiggerServiceImpl::new(db_path, adapter.pool().clone())
+        .with_trace(trace)
+        .with_decomposition_config(decomposition)
+        .with_retry_policy(retry_policy);
     let addr = "[::1]:50051".parse()?;
 
     eprintln!("🚀 Rigger gRPC Server starting...");
     eprintln!("   Protocol: gRPC over HTTP/2");
     eprintln!("   Address: {}", addr);
     eprintln!("   Database: {:?}", service.db_path);
+    eprintln!("   Pool: {} connections", pool_settings.pool_size);
     eprintln!("   Broadcast: Enabled (1000 event buffer)");
+    eprintln!("   Auth: {}", if auth_token.is_some() { "Bearer token required" } else { "Disabled" });
+    eprintln!("   Trace: {}", if trace { "Enabled" } else { "Disabled" });
     eprintln!();
 
     // Start server
     tonic::transport::Server::builder()
-        .add_service(RiggerServiceServer::new(service))
+        .add_service(RiggerServiceServer::with_interceptor(service, auth_interceptor(auth_token)))
         .serve(addr)
         .await?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    fn request_with_authorization(value: std::option::Option<&str>) -> tonic::Request<()> {
+        let mut request = tonic::Request::new(());
+        if let std::option::Option::Some(value) = value {
+            request.metadata_mut().insert("authorization", value.parse().unwrap());
+        }
+        request
+    }
+
+    #[test]
+    fn test_interceptor_rejects_missing_or_wrong_token() {
+        // Test: Validates requests without the configured bearer token fail with UNAUTHENTICATED and matching ones pass.
+        // Justification: Anyone who can reach the port could otherwise drive the orchestrator.
+        let mut interceptor = super::auth_interceptor(std::option::Option::Some(std::string::String::from("s3cret")));
+
+        std::assert!(interceptor(request_with_authorization(std::option::Option::Some("Bearer s3cret"))).is_ok());
+        for rejected in [std::option::Option::None, std::option::Option::Some("Bearer nope")] {
+            let status = interceptor(request_with_authorization(rejected)).unwrap_err();
+            std::assert_eq!(status.code(), tonic::Code::Unauthenticated);
+        }
+    }
+
+    #[test]
+    fn test_interceptor_is_open_without_token() {
+        // Test: Validates no configured token leaves the server open.
+        // Justification: Existing deployments without auth_token_env must keep working unchanged.
+        let mut interceptor = super::auth_interceptor(std::option::Option::None);
+
+        std::assert!(interceptor(request_with_authorization(std::option::Option::None)).is_ok());
+    }
+}
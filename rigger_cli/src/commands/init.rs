@@ -4,6 +4,13 @@
 //! and initializes the SQLite database for task storage.
 //!
 //! Revision History
+//! - 2026-10-18T02:30:00Z @AI: Write the default orchestration settings to new configs.
+//! - 2026-10-17T18:10:00Z @AI: Generated config starts with default search settings.
+//! - 2026-10-17T14:50:00Z @AI: Write auto_pull (off) for the default Ollama provider.
+//! - 2026-10-16T21:30:00Z @AI: Generated config starts with default sampling settings.
+//! - 2026-10-16T21:10:00Z @AI: Generated config starts with offline mode off.
+//! - 2026-10-16T20:30:00Z @AI: Generated config starts with an empty pricing table.
+//! - 2026-10-16T19:30:00Z @AI: Write default server settings into the generated config.
 //! - 2025-12-04T20:00:00Z @AI: Update to generate rigger_core v3.0 config with full provider support.
 //! - 2025-11-23T14:30:00Z @AI: Rename taskmaster to rigger throughout codebase.
 //! - 2025-11-22T19:00:00Z @AI: Rename CLI command from 'taskmaster' to 'rig'; fix SQLite database file creation.
@@ -86,6 +93,7 @@ pub async fn execute() -> anyhow::Result<()> {
             timeout_seconds: 120,
             max_retries: 2,
             default_model: String::from("llama3.2"),
+            auto_pull: false,
         },
     );
 
@@ -144,6 +152,12 @@ pub async fn execute() -> anyhow::Result<()> {
         },
         performance: rigger_core::config::PerformanceConfig::default(),
         tui: rigger_core::config::TuiConfig::default(),
+        server: rigger_core::config::ServerConfig::default(),
+        pricing: std::collections::HashMap::new(),
+        offline: false,
+        sampling: rigger_core::config::SamplingConfig::default(),
+        search: rigger_core::config::SearchConfig::default(),
+        orchestration: rigger_core::config::OrchestrationConfig::default(),
     };
 
     let config_path = rigger_dir.join("config.json");
@@ -0,0 +1,280 @@
+//! Implementation of the 'rig link <FROM> <TO> [--type TYPE]' command.
+//!
+//! Links two tasks with a labelled edge (see
+//! `task_manager::domain::task_link`). `rig link A B` (type `blocks`, the
+//! default) records that A blocks B by adding A to B's dependencies, so B is
+//! held up until A is finished. `relates-to` and `duplicates` are stored on A
+//! as informational links and never affect `rig next` or `rig list --blocked`.
+//!
+//! Revision History
+//! - 2026-10-17T16:30:00Z @AI: Initial link command with blocks / relates-to / duplicates and cycle rejection.
+
+/// Executes the 'rig link <FROM> <TO>' command.
+///
+/// # Arguments
+///
+/// * `from` - ID (or unique ID prefix) of the source task
+/// * `to` - ID (or unique ID prefix) of the target task
+/// * `link_type` - "blocks", "relates-to", or "duplicates"
+///
+/// # Errors
+///
+/// Returns an error if .rigger doesn't exist, the link type is invalid,
+/// either task is not found or its prefix is ambiguous, the tasks are the
+/// same, a `blocks` link would create a dependency cycle, or saving fails.
+pub async fn execute(from: &str, to: &str, link_type: &str) -> anyhow::Result<()> {
+    let link_type: task_manager::domain::task_link::LinkType = link_type.parse().map_err(|e: String| anyhow::anyhow!(e))?;
+
+    let current_dir = std::env::current_dir()?;
+    let rigger_dir = current_dir.join(".rigger");
+
+    if !rigger_dir.exists() {
+        anyhow::bail!(
+            ".rigger directory not found.\nRun 'rig init' first to initialize the project."
+        );
+    }
+
+    let db_url = crate::services::database_url::url_for(&rigger_dir.join("tasks.db"));
+    let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect to database: {}", e))?;
+
+    let tasks = adapter
+        .find_async(
+            &task_manager::ports::task_repository_port::TaskFilter::All,
+            hexser::ports::repository::FindOptions {
+                sort: std::option::Option::None,
+                limit: std::option::Option::None,
+                offset: std::option::Option::None,
+            },
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    let source = find_task(&tasks, from)?;
+    let target = find_task(&tasks, to)?;
+    let updated = link_tasks(&tasks, source, target, link_type)?;
+
+    match updated {
+        std::option::Option::Some(task) => {
+            adapter.save_async(task).await.map_err(|e| anyhow::anyhow!("Failed to save task: {}", e))?;
+            println!("🔗 {} {} {}", source.title, link_type, target.title);
+            println!("  {} → {}", source.id, target.id);
+        }
+        std::option::Option::None => {
+            println!("{} already {} {}; nothing to do.", source.id, link_type, target.id);
+        }
+    }
+    std::result::Result::Ok(())
+}
+
+/// Applies a link from `source` to `target` and returns the task to save.
+///
+/// A `blocks` link is stored on `target` (as a dependency on `source`); the
+/// informational types are stored on `source`.
+///
+/// # Arguments
+///
+/// * `tasks` - All tasks of the project, for cycle detection
+/// * `source` - Task the link starts from
+/// * `target` - Task the link points to
+/// * `link_type` - Kind of link
+///
+/// # Returns
+///
+/// The updated task, or `None` if the link already exists.
+///
+/// # Errors
+///
+/// Returns an error if `source` and `target` are the same task, or if a
+/// `blocks` link would make `source` (indirectly) wait on itself.
+fn link_tasks(
+    tasks: &[task_manager::domain::task::Task],
+    source: &task_manager::domain::task::Task,
+    target: &task_manager::domain::task::Task,
+    link_type: task_manager::domain::task_link::LinkType,
+) -> anyhow::Result<std::option::Option<task_manager::domain::task::Task>> {
+    if source.id == target.id {
+        anyhow::bail!("Cannot link task {} to itself.", source.id);
+    }
+
+    let (mut task, other_id) = if link_type.affects_scheduling() {
+        if depends_on(tasks, &source.id, &target.id) {
+            anyhow::bail!(
+                "Cannot link: {} already waits on {}, so '{} blocks {}' would create a dependency cycle.",
+                source.id,
+                target.id,
+                source.id,
+                target.id
+            );
+        }
+        (target.clone(), &source.id)
+    } else {
+        (source.clone(), &target.id)
+    };
+
+    if !task.add_link(other_id, link_type) {
+        return std::result::Result::Ok(std::option::Option::None);
+    }
+    task.updated_at = chrono::Utc::now();
+    std::result::Result::Ok(std::option::Option::Some(task))
+}
+
+/// Returns true if `task_id` transitively depends on `ancestor_id`.
+fn depends_on(tasks: &[task_manager::domain::task::Task], task_id: &str, ancestor_id: &str) -> bool {
+    let graph = task_manager::domain::services::dependency_graph::DependencyGraph::new(tasks);
+    let mut stack = std::vec![task_id.to_string()];
+    let mut seen = std::collections::HashSet::new();
+    while let std::option::Option::Some(id) = stack.pop() {
+        if !seen.insert(id.clone()) {
+            continue;
+        }
+        for dependency in graph.dependencies(&id) {
+            if dependency == ancestor_id {
+                return true;
+            }
+            stack.push(dependency.clone());
+        }
+    }
+    false
+}
+
+/// Finds a task by exact ID, or by a case-insensitive ID prefix matching exactly one task.
+///
+/// # Errors
+///
+/// Returns an error if no task matches or the prefix matches several.
+fn find_task<'a>(
+    tasks: &'a [task_manager::domain::task::Task],
+    id: &str,
+) -> anyhow::Result<&'a task_manager::domain::task::Task> {
+    if let std::option::Option::Some(task) = tasks.iter().find(|t| t.id == id) {
+        return std::result::Result::Ok(task);
+    }
+
+    let prefix = id.to_lowercase();
+    let matches: std::vec::Vec<&task_manager::domain::task::Task> =
+        tasks.iter().filter(|t| t.id.to_lowercase().starts_with(&prefix)).collect();
+    match matches.as_slice() {
+        [task] => std::result::Result::Ok(task),
+        [] => anyhow::bail!("Task not found: {}\n\nUse 'rig list' to see available tasks.", id),
+        _ => anyhow::bail!("Task ID prefix '{}' matches {} tasks; use more characters.", id, matches.len()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn task(id: &str, title: &str) -> task_manager::domain::task::Task {
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from(title),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let mut task = task_manager::domain::task::Task::from_action_item(&action, std::option::Option::None);
+        task.id = std::string::String::from(id);
+        task
+    }
+
+    #[test]
+    fn test_link_tasks_stores_each_type_on_the_right_task() {
+        // Test: Validates `A blocks B` lands in B's dependencies while relates-to and duplicates land in A's links.
+        // Justification: Only blocks edges may feed scheduling; the others are informational.
+        let a = task("task-a", "Design schema");
+        let b = task("task-b", "Write migrations");
+        let tasks = std::vec![a.clone(), b.clone()];
+
+        let blocked = super::link_tasks(&tasks, &a, &b, task_manager::domain::task_link::LinkType::Blocks).unwrap().unwrap();
+        let related = super::link_tasks(&tasks, &a, &b, task_manager::domain::task_link::LinkType::RelatesTo).unwrap().unwrap();
+
+        std::assert_eq!(blocked.id, "task-b");
+        std::assert_eq!(blocked.dependencies, std::vec![std::string::String::from("task-a")]);
+        std::assert_eq!(related.id, "task-a");
+        std::assert!(related.dependencies.is_empty());
+        std::assert_eq!(related.links[0].task_id, "task-b");
+        std::assert_eq!(related.links[0].link_type, task_manager::domain::task_link::LinkType::RelatesTo);
+    }
+
+    #[test]
+    fn test_link_tasks_rejects_self_links_and_cycles() {
+        // Test: Validates linking a task to itself, or a blocks link closing a dependency loop, is refused.
+        // Justification: A cycle would leave every task on it blocked forever.
+        let a = task("task-a", "Design schema");
+        let mut b = task("task-b", "Write migrations");
+        b.dependencies.push(std::string::String::from("task-a"));
+        let mut c = task("task-c", "Seed data");
+        c.dependencies.push(std::string::String::from("task-b"));
+        let tasks = std::vec![a.clone(), b.clone(), c.clone()];
+
+        let self_link = super::link_tasks(&tasks, &a, &a, task_manager::domain::task_link::LinkType::RelatesTo);
+        let cycle = super::link_tasks(&tasks, &c, &a, task_manager::domain::task_link::LinkType::Blocks);
+        let informational = super::link_tasks(&tasks, &c, &a, task_manager::domain::task_link::LinkType::Duplicates);
+        let existing = super::link_tasks(&tasks, &a, &b, task_manager::domain::task_link::LinkType::Blocks).unwrap();
+
+        std::assert!(self_link.unwrap_err().to_string().contains("itself"));
+        std::assert!(cycle.unwrap_err().to_string().contains("cycle"));
+        std::assert!(informational.is_ok(), "informational links may point anywhere");
+        std::assert!(existing.is_none());
+    }
+
+    #[test]
+    fn test_find_task_accepts_unique_prefix() {
+        // Test: Validates tasks are found by exact ID or a unique prefix, and an ambiguous prefix is an error.
+        // Justification: Task IDs are long UUIDs that users abbreviate.
+        let tasks = std::vec![task("abc-1", "One"), task("abd-2", "Two")];
+
+        std::assert_eq!(super::find_task(&tasks, "abc-1").unwrap().title, "One");
+        std::assert_eq!(super::find_task(&tasks, "ABD").unwrap().title, "Two");
+        std::assert!(super::find_task(&tasks, "ab").unwrap_err().to_string().contains("matches 2 tasks"));
+        std::assert!(super::find_task(&tasks, "zzz").is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[serial_test::serial]
+    async fn test_link_is_stored_and_shown() {
+        // Test: Validates `rig link` persists both link kinds and `rig show` lists them with their labels.
+        // Justification: Links are only useful if they survive a reload and are visible on the task.
+        let temp_dir = std::env::temp_dir().join(std::format!("rigger_test_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir(&temp_dir).unwrap();
+
+        let original_dir = std::env::current_dir().unwrap();
+        std::env::set_current_dir(&temp_dir).unwrap();
+
+        crate::commands::init::execute().await.unwrap();
+        let mut ids = std::vec::Vec::new();
+        for title in ["Design schema", "Write migrations"] {
+            let id = crate::commands::add::execute(
+                title,
+                std::option::Option::None,
+                std::option::Option::None,
+                std::option::Option::None,
+                std::option::Option::None,
+                std::option::Option::None,
+                std::option::Option::None,
+                &[],
+            )
+            .await
+            .unwrap();
+            ids.push(id);
+        }
+        super::execute(&ids[0], &ids[1], "blocks").await.unwrap();
+        super::execute(&ids[1], &ids[0], "relates_to").await.unwrap();
+        let cycle = super::execute(&ids[1], &ids[0], "blocks").await;
+
+        let db_url = std::format!("sqlite:{}", temp_dir.join(".rigger").join("tasks.db").display());
+        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url).await.unwrap();
+        let second = adapter
+            .find_one_async(&task_manager::ports::task_repository_port::TaskFilter::ById(ids[1].clone()))
+            .await
+            .unwrap()
+            .unwrap();
+
+        let _ = std::env::set_current_dir(original_dir);
+        let _ = std::fs::remove_dir_all(&temp_dir);
+
+        std::assert!(cycle.is_err());
+        std::assert_eq!(second.dependencies, std::vec![ids[0].clone()]);
+        let output = crate::commands::show::render_task_detail(&second, &crate::display::theme::Theme::NoColor);
+        std::assert!(output.contains(&std::format!("blocked by {}", ids[0])));
+        std::assert!(output.contains(&std::format!("relates-to {}", ids[0])));
+    }
+}
@@ -3,21 +3,54 @@
 //! Lists tasks from the SQLite database with optional filtering and sorting.
 //!
 //! Revision History
+//! - 2026-10-18T04:10:00Z @AI: Create the --watch Ctrl-C listener once instead of per frame.
+//! - 2026-10-17T14:10:00Z @AI: Open the database through services::database_url so --db / RIGGER_DB apply.
+//! - 2026-10-17T13:50:00Z @AI: Read the config path from services::config_path, honoring --config and RIGGER_CONFIG.
+//! - 2026-10-17T11:30:00Z @AI: Add --cursor for keyset pagination through TaskRepositoryPort::find_after; the next cursor is printed to stderr.
+//! - 2026-10-17T10:10:00Z @AI: Support --sort priority, most urgent first.
+//! - 2026-10-17T02:50:00Z @AI: Add --blocked to list only tasks waiting on unfinished dependencies, with the blocking task IDs. Arguments shared by execute() and watch() grouped into ListArgs.
+//! - 2026-10-16T17:10:00Z @AI: Colorize the table with the configured tui.theme (plain when piped or NO_COLOR is set); clipboard copies stay uncolored.
+//! - 2026-10-16T16:50:00Z @AI: Add watch() for --watch mode. Query building and rendering factored into build_query() and render_frame() so both modes share one path; transient query errors render inline and are retried.
+//! - 2026-10-16T15:30:00Z @AI: Add --format (table|json) and --copy to copy the rendered list to the clipboard via ClipboardPort.
 //! - 2025-11-23T14:30:00Z @AI: Rename taskmaster to rigger throughout codebase.
 //! - 2025-11-22T16:50:00Z @AI: Initial list command implementation for Rigger Phase 0 Sprint 0.2.
 
+/// Filters, paging, and format shared by `rig list` and `rig list --watch`.
+#[derive(Debug, Clone, Copy)]
+pub struct ListArgs<'a> {
+    /// Optional status filter (e.g., "todo", "in_progress", "completed")
+    pub status: std::option::Option<&'a str>,
+    /// Optional assignee filter
+    pub assignee: std::option::Option<&'a str>,
+    /// Sort field (created_at, updated_at, title, due_date, status, priority)
+    pub sort: &'a str,
+    /// Maximum number of tasks to display
+    pub limit: std::option::Option<&'a str>,
+    /// Number of tasks to skip (for pagination)
+    pub offset: std::option::Option<&'a str>,
+    /// Keyset cursor from the previous page; empty to start at the first page
+    pub cursor: std::option::Option<&'a str>,
+    /// Only list open tasks waiting on unfinished dependencies
+    pub blocked: bool,
+    /// Output format ("table" or "json")
+    pub format: &'a str,
+}
+
 /// Executes the 'rig list' command.
 ///
 /// Reads tasks from the SQLite database in .rigger/tasks.db and displays
-/// them in a formatted table with optional filtering and sorting.
+/// them in a formatted table with optional filtering and sorting. The table
+/// is colorized with the `tui.theme` from .rigger/config.json unless stdout
+/// is not a terminal or `NO_COLOR` is set.
+///
+/// With a cursor, lists one keyset page (`--limit` tasks, default
+/// CURSOR_PAGE_SIZE) and prints the cursor for the next page to stderr, so
+/// piped output stays clean.
 ///
 /// # Arguments
 ///
-/// * `status` - Optional status filter (e.g., "todo", "in_progress", "completed")
-/// * `assignee` - Optional assignee filter
-/// * `sort` - Sort field (created_at, updated_at, title, due_date, status)
-/// * `limit` - Maximum number of tasks to display
-/// * `offset` - Number of tasks to skip (for pagination)
+/// * `args` - Filters, sorting, paging, and output format
+/// * `copy` - Also copy the rendered output to the system clipboard
 ///
 /// # Errors
 ///
@@ -25,13 +58,144 @@
 /// - .rigger directory doesn't exist (run 'rig init' first)
 /// - Database connection fails
 /// - Query execution fails
-pub async fn execute(
-    status: std::option::Option<&str>,
-    assignee: std::option::Option<&str>,
-    sort: &str,
-    limit: std::option::Option<&str>,
-    offset: std::option::Option<&str>,
-) -> anyhow::Result<()> {
+/// - Format is not "table" or "json"
+/// - The cursor is malformed
+///
+/// A missing clipboard (headless or SSH session) is reported, not an error.
+pub async fn execute(args: &ListArgs<'_>, copy: bool) -> anyhow::Result<()> {
+    let (filter, find_options) = build_query(args.status, args.assignee, args.sort, args.limit, args.offset, args.format)?;
+    let adapter = connect().await?;
+    let theme = configured_theme()?;
+
+    // Query, render and display tasks
+    let listing = match args.cursor {
+        std::option::Option::Some(token) => {
+            let page = query_page(&adapter, &filter, &find_options, token).await?;
+            if let std::option::Option::Some(next) = &page.next_cursor {
+                eprintln!("Next page: rig list --cursor {} (with the same filters and sort)", next.encode());
+            }
+            Listing::Tasks(page.tasks)
+        }
+        std::option::Option::None => query_listing(&adapter, &filter, find_options, args.blocked).await?,
+    };
+    print!("{}", listing.render(args.format, &theme)?);
+
+    if copy {
+        match crate::adapters::arboard_clipboard_adapter::ArboardClipboardAdapter::new() {
+            std::result::Result::Ok(adapter) => {
+                let plain = listing.render(args.format, &crate::display::theme::Theme::NoColor)?;
+                copy_to_clipboard(&adapter, &plain);
+            }
+            std::result::Result::Err(e) => {
+                eprintln!("⚠️  Clipboard unavailable: {}", e);
+            }
+        }
+    }
+
+    std::result::Result::Ok(())
+}
+
+/// Executes 'rig list --watch', re-rendering the list until Ctrl-C.
+///
+/// Uses the same filters, sorting, and rendering as `execute`. The screen is
+/// cleared between frames. Query errors are shown in place of the list and
+/// retried on the next tick instead of exiting.
+///
+/// # Arguments
+///
+/// * `args` - Filters, sorting, paging, and output format, as for `execute`
+/// * `interval` - Optional refresh interval in milliseconds; defaults to
+///   `tui.auto_refresh_interval_ms` from .rigger/config.json
+///
+/// # Errors
+///
+/// Returns an error if the arguments are invalid, the config cannot be
+/// loaded, or the database cannot be opened at startup.
+pub async fn watch(args: &ListArgs<'_>, interval: std::option::Option<&str>) -> anyhow::Result<()> {
+    let (filter, find_options) = build_query(args.status, args.assignee, args.sort, args.limit, args.offset, args.format)?;
+
+    let interval_ms = match interval {
+        std::option::Option::Some(i_str) => i_str.parse::<u64>().ok().filter(|ms| *ms > 0).ok_or_else(|| {
+            anyhow::anyhow!("Invalid interval value: '{}'. Must be a positive number of milliseconds.", i_str)
+        })?,
+        std::option::Option::None => {
+            let config_path = crate::services::config_path::current()?;
+            rigger_core::RiggerConfig::load_with_migration(
+                config_path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid config path"))?
+            )?
+            .tui
+            .auto_refresh_interval_ms
+        }
+    };
+
+    let adapter = connect().await?;
+    let theme = configured_theme()?;
+    let mut stdout = std::io::stdout();
+    // One listener for the whole watch, so a Ctrl-C pressed while a frame renders is not lost
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+
+    loop {
+        let frame = render_frame(&adapter, &filter, &find_options, args.blocked, args.format, &theme).await;
+        crossterm::execute!(
+            stdout,
+            crossterm::terminal::Clear(crossterm::terminal::ClearType::All),
+            crossterm::cursor::MoveTo(0, 0)
+        )?;
+        println!(
+            "Every {:.1}s: rig list    {}    (Ctrl-C to stop)\n",
+            interval_ms as f64 / 1000.0,
+            chrono::Local::now().format("%H:%M:%S")
+        );
+        print!("{}", frame);
+        std::io::Write::flush(&mut stdout)?;
+
+        tokio::select! {
+            _ = &mut ctrl_c => break,
+            _ = tokio::time::sleep(std::time::Duration::from_millis(interval_ms)) => {}
+        }
+    }
+
+    std::result::Result::Ok(())
+}
+
+/// Renders a single --watch frame.
+///
+/// # Arguments
+///
+/// * `adapter` - Task repository to query
+/// * `filter` - Task filter built by `build_query`
+/// * `find_options` - Sorting and pagination built by `build_query`
+/// * `blocked` - Only list open tasks waiting on unfinished dependencies
+/// * `format` - Output format ("table" or "json")
+/// * `theme` - Color theme for table output
+///
+/// # Returns
+///
+/// The rendered task list, or an inline error message if the query failed.
+pub async fn render_frame(
+    adapter: &task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter,
+    filter: &task_manager::ports::task_repository_port::TaskFilter,
+    find_options: &hexser::ports::repository::FindOptions<task_manager::ports::task_repository_port::TaskSortKey>,
+    blocked: bool,
+    format: &str,
+    theme: &crate::display::theme::Theme,
+) -> String {
+    let rendered = query_listing(adapter, filter, find_options.clone(), blocked)
+        .await
+        .and_then(|listing| listing.render(format, theme));
+    match rendered {
+        std::result::Result::Ok(rendered) => rendered,
+        std::result::Result::Err(e) => std::format!("⚠️  Failed to load tasks: {}\n   Retrying...\n", e),
+    }
+}
+
+/// Opens the task database in .rigger/tasks.db.
+///
+/// # Errors
+///
+/// Returns an error if .rigger doesn't exist or the connection fails.
+async fn connect() -> anyhow::Result<task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter> {
     // Check if .rigger exists
     let current_dir = std::env::current_dir()?;
     let rigger_dir = current_dir.join(".rigger");
@@ -44,12 +208,236 @@ pub async fn execute(
 
     // Connect to database
     let db_path = rigger_dir.join("tasks.db");
-    let db_url = std::format!("sqlite:{}", db_path.display());
+    let db_url = crate::services::database_url::url_for(&db_path);
+
+    task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect to database: {}", e))
+}
+
+/// Resolves the color theme from `tui.theme` in .rigger/config.json.
+///
+/// # Errors
+///
+/// Returns an error if the current directory cannot be read.
+fn configured_theme() -> anyhow::Result<crate::display::theme::Theme> {
+    let config_path = crate::services::config_path::current()?;
+    std::result::Result::Ok(crate::display::theme::Theme::load(&config_path))
+}
 
-    let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url)
+/// Queries tasks matching the filter.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+async fn query_tasks(
+    adapter: &task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter,
+    filter: &task_manager::ports::task_repository_port::TaskFilter,
+    find_options: hexser::ports::repository::FindOptions<task_manager::ports::task_repository_port::TaskSortKey>,
+) -> anyhow::Result<std::vec::Vec<task_manager::domain::task::Task>> {
+    adapter
+        .find_async(filter, find_options)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to connect to database: {}", e))?;
+        .map_err(|e| anyhow::anyhow!("{}", e))
+}
+
+/// Page size for `--cursor` when no `--limit` is given.
+pub const CURSOR_PAGE_SIZE: u32 = 50;
 
+/// Queries one keyset page of tasks after the cursor `token`.
+///
+/// An empty token starts at the first page. The sort comes from
+/// `find_options`; the page size from its limit, or CURSOR_PAGE_SIZE.
+///
+/// # Errors
+///
+/// Returns an error if the token is not a valid cursor or the query fails.
+async fn query_page(
+    adapter: &task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter,
+    filter: &task_manager::ports::task_repository_port::TaskFilter,
+    find_options: &hexser::ports::repository::FindOptions<task_manager::ports::task_repository_port::TaskSortKey>,
+    token: &str,
+) -> anyhow::Result<task_manager::ports::task_repository_port::TaskPage> {
+    let cursor = if token.is_empty() {
+        std::option::Option::None
+    } else {
+        std::option::Option::Some(
+            task_manager::ports::task_repository_port::TaskCursor::decode(token).map_err(|e| anyhow::anyhow!(e))?,
+        )
+    };
+    let sort = find_options
+        .sort
+        .as_ref()
+        .and_then(|sorts| sorts.first())
+        .ok_or_else(|| anyhow::anyhow!("Cursor pagination needs a sort field"))?;
+
+    adapter
+        .find_after_async(filter, sort, cursor.as_ref(), find_options.limit.unwrap_or(CURSOR_PAGE_SIZE))
+        .await
+        .map_err(|e| anyhow::anyhow!("{}", e))
+}
+
+/// Tasks selected by `rig list`, ready to render.
+enum Listing {
+    /// Tasks matching the filters
+    Tasks(std::vec::Vec<task_manager::domain::task::Task>),
+    /// Blocked tasks matching the filters, each with its blocking dependencies
+    Blocked(std::vec::Vec<(task_manager::domain::task::Task, std::vec::Vec<task_manager::domain::services::blocked_tasks::Blocker>)>),
+}
+
+impl Listing {
+    /// Renders the listing as a table or JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if JSON serialization fails.
+    fn render(&self, format: &str, theme: &crate::display::theme::Theme) -> anyhow::Result<String> {
+        match self {
+            Listing::Tasks(tasks) => render_tasks(tasks, format, theme),
+            Listing::Blocked(blocked) => render_blocked(blocked, format, theme),
+        }
+    }
+}
+
+/// Queries the tasks to list, keeping only blocked ones if `blocked` is set.
+///
+/// Blocking is derived from all tasks of the project, so dependencies outside
+/// the filter are still resolved. In blocked mode, limit and offset apply to
+/// the blocked tasks rather than to the query.
+///
+/// # Errors
+///
+/// Returns an error if a query fails.
+async fn query_listing(
+    adapter: &task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter,
+    filter: &task_manager::ports::task_repository_port::TaskFilter,
+    find_options: hexser::ports::repository::FindOptions<task_manager::ports::task_repository_port::TaskSortKey>,
+    blocked: bool,
+) -> anyhow::Result<Listing> {
+    if !blocked {
+        return std::result::Result::Ok(Listing::Tasks(query_tasks(adapter, filter, find_options).await?));
+    }
+
+    let all_tasks = query_tasks(
+        adapter,
+        &task_manager::ports::task_repository_port::TaskFilter::All,
+        hexser::ports::repository::FindOptions {
+            sort: std::option::Option::None,
+            limit: std::option::Option::None,
+            offset: std::option::Option::None,
+        },
+    )
+    .await?;
+    let candidates = query_tasks(
+        adapter,
+        filter,
+        hexser::ports::repository::FindOptions {
+            sort: find_options.sort,
+            limit: std::option::Option::None,
+            offset: std::option::Option::None,
+        },
+    )
+    .await?;
+
+    let blocked_tasks = task_manager::domain::services::blocked_tasks::BlockedTasks::new(&all_tasks)
+        .blocked(&candidates)
+        .into_iter()
+        .skip(find_options.offset.unwrap_or(0) as usize)
+        .take(find_options.limit.map_or(usize::MAX, |limit| limit as usize))
+        .map(|(task, blockers)| (task.clone(), blockers))
+        .collect();
+    std::result::Result::Ok(Listing::Blocked(blocked_tasks))
+}
+
+/// Renders blocked tasks and what each one is waiting on.
+///
+/// The table lists the blocked tasks; below it, each task's blocking task IDs,
+/// with cancelled dependencies called out separately since they will never
+/// finish. JSON pairs each task with its `blocked_by` list.
+///
+/// # Errors
+///
+/// Returns an error if JSON serialization fails.
+fn render_blocked(
+    blocked: &[(task_manager::domain::task::Task, std::vec::Vec<task_manager::domain::services::blocked_tasks::Blocker>)],
+    format: &str,
+    theme: &crate::display::theme::Theme,
+) -> anyhow::Result<String> {
+    if format == "json" {
+        let entries: std::vec::Vec<serde_json::Value> = blocked
+            .iter()
+            .map(|(task, blockers)| serde_json::json!({ "task": task, "blocked_by": blockers }))
+            .collect();
+        return std::result::Result::Ok(std::format!("{}\n", serde_json::to_string_pretty(&entries)?));
+    }
+    if blocked.is_empty() {
+        return std::result::Result::Ok(std::string::String::from("No blocked tasks.\n"));
+    }
+
+    let tasks: std::vec::Vec<task_manager::domain::task::Task> = blocked.iter().map(|(task, _)| task.clone()).collect();
+    let mut output = crate::display::task_table::render_tasks_table(&tasks, theme);
+    output.push_str("\nBlocked by:\n");
+    for (task, blockers) in blocked {
+        let ids = |reason: task_manager::domain::services::blocked_tasks::BlockReason| {
+            blockers
+                .iter()
+                .filter(|blocker| blocker.reason == reason)
+                .map(|blocker| blocker.task_id.as_str())
+                .collect::<std::vec::Vec<&str>>()
+        };
+        let incomplete = ids(task_manager::domain::services::blocked_tasks::BlockReason::Incomplete);
+        let cancelled = ids(task_manager::domain::services::blocked_tasks::BlockReason::Cancelled);
+
+        output.push_str(&std::format!("  {} ({})\n", task.title, task.id));
+        if !incomplete.is_empty() {
+            output.push_str(&std::format!("    waiting on: {}\n", incomplete.join(", ")));
+        }
+        if !cancelled.is_empty() {
+            output.push_str(&std::format!(
+                "    ⚠️  blocked by cancelled: {} (remove the dependency or restore the task)\n",
+                cancelled.join(", ")
+            ));
+        }
+    }
+    std::result::Result::Ok(output)
+}
+
+/// Renders tasks as a table or JSON. JSON is never colorized.
+///
+/// # Errors
+///
+/// Returns an error if JSON serialization fails.
+fn render_tasks(
+    tasks: &[task_manager::domain::task::Task],
+    format: &str,
+    theme: &crate::display::theme::Theme,
+) -> anyhow::Result<String> {
+    if format == "json" {
+        crate::display::task_table::render_tasks_json(tasks)
+    } else {
+        std::result::Result::Ok(crate::display::task_table::render_tasks_table(tasks, theme))
+    }
+}
+
+/// Validates list arguments and builds the task filter and find options.
+///
+/// # Errors
+///
+/// Returns an error if the status, sort field, limit, offset, or format is invalid.
+fn build_query(
+    status: std::option::Option<&str>,
+    assignee: std::option::Option<&str>,
+    sort: &str,
+    limit: std::option::Option<&str>,
+    offset: std::option::Option<&str>,
+    format: &str,
+) -> anyhow::Result<(
+    task_manager::ports::task_repository_port::TaskFilter,
+    hexser::ports::repository::FindOptions<task_manager::ports::task_repository_port::TaskSortKey>,
+)> {
+    if format != "table" && format != "json" {
+        anyhow::bail!("Invalid format: '{}'. Valid values: table, json", format);
+    }
     // Build filter
     let filter = if let std::option::Option::Some(status_str) = status {
         // Parse status string to TaskStatus enum
@@ -79,9 +467,10 @@ pub async fn execute(
         "title" => task_manager::ports::task_repository_port::TaskSortKey::Title,
         "status" => task_manager::ports::task_repository_port::TaskSortKey::Status,
         "due_date" => task_manager::ports::task_repository_port::TaskSortKey::DueDate,
+        "priority" => task_manager::ports::task_repository_port::TaskSortKey::Priority,
         other => {
             anyhow::bail!(
-                "Invalid sort field: '{}'. Valid values: created_at, updated_at, title, status, due_date",
+                "Invalid sort field: '{}'. Valid values: created_at, updated_at, title, status, due_date, priority",
                 other
             );
         }
@@ -113,20 +502,47 @@ pub async fn execute(
         offset: offset_u64,
     };
 
-    // Query tasks
-    let tasks = {
-        use hexser::ports::repository::QueryRepository;
-        adapter.find(&filter, find_options)?
-    };
-
-    // Display tasks
-    crate::display::task_table::display_tasks_table(&tasks);
+    std::result::Result::Ok((filter, find_options))
+}
 
-    std::result::Result::Ok(())
+/// Copies rendered list output to the clipboard and reports the outcome.
+///
+/// # Arguments
+///
+/// * `clipboard` - Clipboard port to copy into
+/// * `rendered` - Exact output printed by `rig list`
+///
+/// # Returns
+///
+/// `true` if the text was copied, `false` if the clipboard rejected it.
+pub fn copy_to_clipboard(clipboard: &dyn crate::ports::clipboard_port::ClipboardPort, rendered: &str) -> bool {
+    match clipboard.copy_text(rendered) {
+        std::result::Result::Ok(()) => {
+            println!("📋 Copied task list to clipboard.");
+            true
+        }
+        std::result::Result::Err(e) => {
+            eprintln!("⚠️  Clipboard unavailable: {}", e);
+            false
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    fn default_args() -> super::ListArgs<'static> {
+        super::ListArgs {
+            status: std::option::Option::None,
+            assignee: std::option::Option::None,
+            sort: "created_at",
+            limit: std::option::Option::None,
+            offset: std::option::Option::None,
+            cursor: std::option::Option::None,
+            blocked: false,
+            format: "table",
+        }
+    }
+
     #[tokio::test]
     #[serial_test::serial]
     async fn test_list_fails_without_init() {
@@ -138,7 +554,7 @@ mod tests {
         let original_dir = std::env::current_dir().unwrap();
         std::env::set_current_dir(&temp_dir).unwrap();
 
-        let result = super::execute(std::option::Option::None, std::option::Option::None, "created_at", std::option::Option::None, std::option::Option::None).await;
+        let result = super::execute(&default_args(), false).await;
         std::assert!(result.is_err(), "List should fail if .rigger doesn't exist");
 
         // Cleanup
@@ -161,11 +577,191 @@ mod tests {
         crate::commands::init::execute().await.unwrap();
 
         // List tasks
-        let result = super::execute(std::option::Option::None, std::option::Option::None, "created_at", std::option::Option::None, std::option::Option::None).await;
+        let result = super::execute(&default_args(), false).await;
         std::assert!(result.is_ok(), "List should succeed with empty database");
 
         // Cleanup (ignore errors if already cleaned)
         let _ = std::env::set_current_dir(original_dir);
         let _ = std::fs::remove_dir_all(&temp_dir);
     }
+
+    struct MockClipboard {
+        copied: std::sync::Mutex<std::option::Option<String>>,
+        available: bool,
+    }
+
+    impl crate::ports::clipboard_port::ClipboardPort for MockClipboard {
+        fn copy_text(&self, text: &str) -> std::result::Result<(), String> {
+            if !self.available {
+                return std::result::Result::Err(std::string::String::from("no display"));
+            }
+            *self.copied.lock().unwrap() = std::option::Option::Some(std::string::String::from(text));
+            std::result::Result::Ok(())
+        }
+
+        fn get_text(&self) -> std::result::Result<String, String> {
+            self.copied
+                .lock()
+                .unwrap()
+                .clone()
+                .ok_or_else(|| std::string::String::from("empty"))
+        }
+    }
+
+    fn sample_tasks() -> std::vec::Vec<task_manager::domain::task::Task> {
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Prepare standup notes"),
+            assignee: std::option::Option::Some(std::string::String::from("Alice")),
+            due_date: std::option::Option::None,
+        };
+        std::vec![task_manager::domain::task::Task::from_action_item(&action, std::option::Option::None)]
+    }
+
+    #[test]
+    fn test_copy_matches_rendered_table_and_json() {
+        // Test: Validates the clipboard receives exactly the rendered table or JSON.
+        // Justification: Users paste `rig list --copy` output straight into standup docs.
+        let tasks = sample_tasks();
+        let clipboard = MockClipboard {
+            copied: std::sync::Mutex::new(std::option::Option::None),
+            available: true,
+        };
+
+        let table = crate::display::task_table::render_tasks_table(&tasks, &crate::display::theme::Theme::NoColor);
+        std::assert!(super::copy_to_clipboard(&clipboard, &table));
+        std::assert_eq!(crate::ports::clipboard_port::ClipboardPort::get_text(&clipboard).unwrap(), table);
+
+        let json = crate::display::task_table::render_tasks_json(&tasks).unwrap();
+        std::assert!(super::copy_to_clipboard(&clipboard, &json));
+        let copied = crate::ports::clipboard_port::ClipboardPort::get_text(&clipboard).unwrap();
+        std::assert_eq!(copied, json);
+        let parsed: serde_json::Value = serde_json::from_str(&copied).unwrap();
+        std::assert_eq!(parsed[0]["title"], "Prepare standup notes");
+    }
+
+    #[test]
+    fn test_copy_reports_unavailable_clipboard() {
+        // Test: Validates a failing clipboard is reported instead of panicking.
+        // Justification: SSH and headless sessions have no clipboard.
+        let clipboard = MockClipboard {
+            copied: std::sync::Mutex::new(std::option::Option::None),
+            available: false,
+        };
+
+        std::assert!(!super::copy_to_clipboard(&clipboard, "No tasks found.\n"));
+    }
+
+    fn all_tasks_query() -> (
+        task_manager::ports::task_repository_port::TaskFilter,
+        hexser::ports::repository::FindOptions<task_manager::ports::task_repository_port::TaskSortKey>,
+    ) {
+        super::build_query(std::option::Option::None, std::option::Option::None, "created_at", std::option::Option::None, std::option::Option::None, "table").unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_render_frame_lists_filtered_tasks() {
+        // Test: Validates a single watch frame renders the tasks matching the filter.
+        // Justification: --watch must reuse the regular query path and respect filters.
+        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init("sqlite::memory:").await.unwrap();
+        let mut tasks = sample_tasks();
+        let mut done = tasks[0].clone();
+        done.id = std::string::String::from("done-1");
+        done.title = std::string::String::from("Close sprint");
+        done.status = task_manager::domain::task_status::TaskStatus::Completed;
+        tasks.push(done);
+        for task in tasks {
+            adapter.save_async(task).await.unwrap();
+        }
+
+        let (filter, options) = super::build_query(std::option::Option::Some("todo"), std::option::Option::None, "created_at", std::option::Option::None, std::option::Option::None, "table").unwrap();
+        let frame = super::render_frame(&adapter, &filter, &options, false, "table", &crate::display::theme::Theme::NoColor).await;
+
+        std::assert!(frame.contains("Prepare standup notes"));
+        std::assert!(!frame.contains("Close sprint"));
+        std::assert!(frame.contains("Total: 1 task(s)"));
+    }
+
+    #[tokio::test]
+    async fn test_cursor_pages_follow_each_other() {
+        // Test: Validates --cursor pages continue from the printed cursor and a bad token is rejected.
+        // Justification: Users page with the token from the previous run, not an offset.
+        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init("sqlite::memory:").await.unwrap();
+        let template = sample_tasks().remove(0);
+        for title in ["Alpha", "Bravo", "Charlie"] {
+            let mut task = template.clone();
+            task.id = std::format!("id-{}", title);
+            task.title = std::string::String::from(title);
+            adapter.save_async(task).await.unwrap();
+        }
+        let (filter, options) = super::build_query(std::option::Option::None, std::option::Option::None, "title", std::option::Option::Some("2"), std::option::Option::None, "table").unwrap();
+
+        let first = super::query_page(&adapter, &filter, &options, "").await.unwrap();
+        let token = first.next_cursor.as_ref().unwrap().encode();
+        let second = super::query_page(&adapter, &filter, &options, &token).await.unwrap();
+
+        let titles: std::vec::Vec<&str> = first.tasks.iter().chain(second.tasks.iter()).map(|t| t.title.as_str()).collect();
+        std::assert_eq!(titles, ["Charlie", "Bravo", "Alpha"]);
+        std::assert!(second.next_cursor.is_none());
+        std::assert!(super::query_page(&adapter, &filter, &options, "not-a-cursor").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_render_frame_reports_query_error_inline() {
+        // Test: Validates a failing query renders an inline error instead of returning Err.
+        // Justification: A transient DB error must not end a long-running --watch session.
+        let db_path = std::env::temp_dir().join(std::format!("rigger_watch_{}.db", uuid::Uuid::new_v4()));
+        let db_url = std::format!("sqlite:{}?mode=rwc", db_path.display());
+        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url).await.unwrap();
+
+        let pool = sqlx::sqlite::SqlitePoolOptions::new().connect(&db_url).await.unwrap();
+        sqlx::query("DROP TABLE tasks").execute(&pool).await.unwrap();
+
+        let (filter, options) = all_tasks_query();
+        let frame = super::render_frame(&adapter, &filter, &options, false, "table", &crate::display::theme::Theme::NoColor).await;
+
+        pool.close().await;
+        let _ = std::fs::remove_file(&db_path);
+
+        std::assert!(frame.contains("Failed to load tasks"));
+        std::assert!(frame.contains("Retrying"));
+    }
+
+    #[tokio::test]
+    async fn test_render_frame_blocked_lists_blocking_ids() {
+        // Test: Validates --blocked lists only waiting tasks with their blocking IDs, calling out cancelled dependencies.
+        // Justification: The user needs to see what to finish first, and which dependencies will never finish.
+        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init("sqlite::memory:").await.unwrap();
+        let template = sample_tasks().remove(0);
+        let task = |id: &str, title: &str, dependencies: &[&str], status: task_manager::domain::task_status::TaskStatus| {
+            let mut task = template.clone();
+            task.id = std::string::String::from(id);
+            task.title = std::string::String::from(title);
+            task.dependencies = dependencies.iter().map(|d| std::string::String::from(*d)).collect();
+            task.status = status;
+            task
+        };
+        let tasks = std::vec![
+            task("schema-1", "Design schema", &[], task_manager::domain::task_status::TaskStatus::Todo),
+            task("api-1", "Build API", &["schema-1"], task_manager::domain::task_status::TaskStatus::Todo),
+            task("legacy-1", "Port legacy import", &[], task_manager::domain::task_status::TaskStatus::Archived),
+            task("import-1", "Run import", &["legacy-1"], task_manager::domain::task_status::TaskStatus::Todo),
+        ];
+        for task in tasks {
+            adapter.save_async(task).await.unwrap();
+        }
+
+        let (filter, options) = all_tasks_query();
+        let frame = super::render_frame(&adapter, &filter, &options, true, "table", &crate::display::theme::Theme::NoColor).await;
+
+        std::assert!(frame.contains("Total: 2 task(s)"), "{}", frame);
+        std::assert!(frame.contains("Build API (api-1)\n    waiting on: schema-1"), "{}", frame);
+        std::assert!(frame.contains("Run import (import-1)\n    ⚠️  blocked by cancelled: legacy-1"), "{}", frame);
+        std::assert!(!frame.contains("Design schema"), "{}", frame);
+
+        let json = super::render_frame(&adapter, &filter, &options, true, "json", &crate::display::theme::Theme::NoColor).await;
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        std::assert_eq!(parsed.as_array().unwrap().len(), 2);
+        let import = parsed.as_array().unwrap().iter().find(|entry| entry["task"]["id"] == "import-1").unwrap();
+        std::assert_eq!(import["blocked_by"][0]["reason"], "cancelled");
+    }
 }
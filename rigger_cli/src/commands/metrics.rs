@@ -0,0 +1,205 @@
+//! Implementation of the 'rig metrics [--since TIME] [--tail N] [--file PATH]' command.
+//!
+//! Summarizes the inference metrics and retry counters recorded by earlier
+//! runs per provider: calls, failures, mean latency, and how often calls had
+//! to be retried. Calls are read from the metrics files `rig do` writes per
+//! `performance.metrics_file` (and any recorded in .rigger/tasks.db); retry
+//! counters from .rigger/tasks.db. Providers whose calls exhausted their
+//! retries, or that needed a retry on at least one in five calls, are marked
+//! as flaky.
+//!
+//! `--since` and `--tail` restrict the summary to recent calls. With `--file`,
+//! metrics are read from a JSONL metrics file (and its rotations) instead,
+//! scanning backwards so only the recent end of the file is read.
+//!
+//! Revision History
+//! - 2026-10-17T22:00:00Z @AI: Read the metrics files configured by performance.metrics_file by default.
+//! - 2026-10-17T14:10:00Z @AI: Open the database through services::database_url so --db / RIGGER_DB apply.
+//! - 2026-10-17T09:10:00Z @AI: Add --since, --tail, and --file (JSONL metrics via tail_metrics).
+//! - 2026-10-17T02:10:00Z @AI: Initial metrics command with per-provider retry counters.
+
+/// Executes the 'rig metrics' command.
+///
+/// # Arguments
+///
+/// * `since` - Only count calls at or after this time (RFC 3339, or YYYY-MM-DD for midnight UTC)
+/// * `tail` - Only count the last N calls
+/// * `file` - JSONL metrics file to read instead of the configured metrics files and .rigger/tasks.db
+///
+/// # Errors
+///
+/// Returns an error if `since` is not a valid time, .rigger doesn't exist
+/// (without `file`), or the metrics cannot be read.
+pub async fn execute(
+    since: std::option::Option<&str>,
+    tail: std::option::Option<usize>,
+    file: std::option::Option<&str>,
+) -> anyhow::Result<()> {
+    let since = since.map(parse_since).transpose()?;
+
+    if let std::option::Option::Some(file) = file {
+        let metrics = task_orchestrator::adapters::jsonl_metrics_collector::tail_metrics(std::path::Path::new(file), since, tail)
+            .map_err(|e| anyhow::anyhow!(e))?;
+        // Retry counters are not written to the JSONL file
+        print!("{}", format_summary(metrics, &std::collections::BTreeMap::new()));
+        return std::result::Result::Ok(());
+    }
+
+    let current_dir = std::env::current_dir()?;
+    let rigger_dir = current_dir.join(".rigger");
+
+    if !rigger_dir.exists() {
+        anyhow::bail!(
+            ".rigger directory not found.\nRun 'rig init' first to initialize the project."
+        );
+    }
+
+    let db_url = crate::services::database_url::url_for(&rigger_dir.join("tasks.db"));
+    let collector = task_orchestrator::adapters::sqlite_metrics_collector::SqliteMetricsCollector::connect_and_init(&db_url)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to open metrics database: {}", e))?;
+
+    let metrics = task_orchestrator::ports::metrics_collector_port::MetricsCollectorPort::get_all_metrics(&collector)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+    let retries = task_orchestrator::ports::metrics_collector_port::MetricsCollectorPort::get_retry_counters(&collector)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+    let mut metrics = recent(metrics, since, tail);
+    let config_path = crate::services::config_path::path_in(&rigger_dir);
+    let project = crate::services::metrics_file::project_name(&rigger_dir);
+    for path in crate::services::metrics_file::load_run_paths(&config_path, &project)? {
+        let run = task_orchestrator::adapters::jsonl_metrics_collector::tail_metrics(&path, since, tail)
+            .map_err(|e| anyhow::anyhow!(e))?;
+        metrics.extend(run);
+    }
+
+    print!("{}", format_summary(recent(metrics, since, tail), &retries));
+    std::result::Result::Ok(())
+}
+
+/// Parses a `--since` value: an RFC 3339 timestamp, or a YYYY-MM-DD date meaning midnight UTC.
+fn parse_since(value: &str) -> anyhow::Result<chrono::DateTime<chrono::Utc>> {
+    if let std::result::Result::Ok(time) = chrono::DateTime::parse_from_rfc3339(value) {
+        return std::result::Result::Ok(time.with_timezone(&chrono::Utc));
+    }
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map(|date| date.and_time(chrono::NaiveTime::MIN).and_utc())
+        .map_err(|_| anyhow::anyhow!("Invalid --since time: '{}'. Expected RFC 3339 or YYYY-MM-DD.", value))
+}
+
+/// Keeps the metrics at or after `since`, then the last `tail` of those by timestamp.
+fn recent(
+    mut metrics: std::vec::Vec<task_orchestrator::domain::performance_metrics::InferenceMetrics>,
+    since: std::option::Option<chrono::DateTime<chrono::Utc>>,
+    tail: std::option::Option<usize>,
+) -> std::vec::Vec<task_orchestrator::domain::performance_metrics::InferenceMetrics> {
+    if let std::option::Option::Some(since) = since {
+        metrics.retain(|metric| metric.timestamp >= since);
+    }
+    if let std::option::Option::Some(tail) = tail {
+        metrics.sort_by_key(|metric| metric.timestamp);
+        let skip = metrics.len().saturating_sub(tail);
+        metrics.drain(..skip);
+    }
+    metrics
+}
+
+/// Formats the per-provider summary.
+///
+/// # Arguments
+///
+/// * `metrics` - All recorded inference metrics
+/// * `retries` - Retry counters keyed by provider
+///
+/// # Returns
+///
+/// One block per provider that has metrics or retry counters, ordered by name.
+fn format_summary(
+    metrics: std::vec::Vec<task_orchestrator::domain::performance_metrics::InferenceMetrics>,
+    retries: &std::collections::BTreeMap<String, task_orchestrator::domain::performance_metrics::RetryCounters>,
+) -> String {
+    let mut by_provider: std::collections::BTreeMap<String, std::vec::Vec<task_orchestrator::domain::performance_metrics::InferenceMetrics>> =
+        std::collections::BTreeMap::new();
+    for metric in metrics {
+        by_provider.entry(metric.provider.clone()).or_default().push(metric);
+    }
+    for provider in retries.keys() {
+        by_provider.entry(provider.clone()).or_default();
+    }
+
+    if by_provider.is_empty() {
+        return String::from("No metrics recorded yet.\n");
+    }
+
+    let mut out = String::from("Metrics by provider:\n");
+    for (provider, provider_metrics) in by_provider {
+        let aggregate = task_orchestrator::domain::performance_metrics::MetricAggregate::from_metrics(provider_metrics);
+        let counters = retries.get(&provider).copied().unwrap_or_default();
+
+        out.push_str(&std::format!("\n{}\n", provider));
+        out.push_str(&std::format!("  Calls: {} ({} failed)\n", aggregate.count, aggregate.failure_count));
+        if let std::option::Option::Some(mean) = aggregate.mean_duration_ms {
+            out.push_str(&std::format!("  Mean latency: {:.0} ms\n", mean));
+        }
+        let rate = match counters.retry_rate(aggregate.count) {
+            std::option::Option::Some(rate) => std::format!(" ({:.0}% of calls)", rate * 100.0),
+            std::option::Option::None => String::new(),
+        };
+        out.push_str(&std::format!(
+            "  Retries: {}{}, {} succeeded after retry, {} exhausted\n",
+            counters.attempts, rate, counters.successes_after_retry, counters.exhausted_retries
+        ));
+        if counters.is_flaky(aggregate.count) {
+            out.push_str("  ⚠️  High retry rate: this provider looks flaky\n");
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    fn metric(provider: &str) -> task_orchestrator::domain::performance_metrics::InferenceMetrics {
+        let mut metric = task_orchestrator::domain::performance_metrics::InferenceMetrics::new(
+            String::from("enhancement"),
+            String::from(provider),
+            String::from("model"),
+            std::option::Option::None,
+        );
+        metric.record_completion(std::time::Duration::from_millis(400), 10, 10);
+        metric
+    }
+
+    #[test]
+    fn test_summary_flags_provider_with_high_retry_rate() {
+        // Test: Validates the summary lists retry counters per provider and flags only the one with exhausted retries.
+        // Justification: The point of the retry counters is to surface a flaky provider at a glance.
+        let metrics = std::vec![metric("ollama"), metric("ollama"), metric("mlx"), metric("mlx")];
+        let retries = std::collections::BTreeMap::from([(
+            String::from("mlx"),
+            task_orchestrator::domain::performance_metrics::RetryCounters {
+                attempts: 3,
+                successes_after_retry: 1,
+                exhausted_retries: 1,
+            },
+        )]);
+
+        let summary = super::format_summary(metrics, &retries);
+
+        let (mlx, ollama) = summary.split_at(summary.find("\nollama\n").unwrap());
+        std::assert!(mlx.contains("Retries: 3 (150% of calls), 1 succeeded after retry, 1 exhausted"), "{}", summary);
+        std::assert!(mlx.contains("looks flaky"));
+        std::assert!(ollama.contains("Retries: 0 (0% of calls), 0 succeeded after retry, 0 exhausted"), "{}", summary);
+        std::assert!(!ollama.contains("looks flaky"));
+    }
+
+    #[test]
+    fn test_since_accepts_date_or_timestamp() {
+        // Test: Validates --since parses RFC 3339 timestamps and bare dates, and rejects anything else.
+        // Justification: A date is the common case, a timestamp is needed to look at a single run.
+        std::assert_eq!(super::parse_since("2026-10-17").unwrap().to_rfc3339(), "2026-10-17T00:00:00+00:00");
+        std::assert_eq!(super::parse_since("2026-10-17T09:10:00+02:00").unwrap().to_rfc3339(), "2026-10-17T07:10:00+00:00");
+        std::assert!(super::parse_since("yesterday").is_err());
+    }
+}
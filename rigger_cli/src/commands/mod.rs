@@ -4,6 +4,58 @@
 //! subcommands into separate modules for maintainability.
 //!
 //! Revision History
+//! - 2026-10-18T03:30:00Z @AI: Add project set/show for project task defaults.
+//! - 2026-10-18T03:20:00Z @AI: rig add --status accepts every task status.
+//! - 2026-10-18T02:20:00Z @AI: Add parse --project (default: default-project).
+//! - 2026-10-17T23:00:00Z @AI: --db accepts only sqlite: URLs and overrides database.url from config.
+//! - 2026-10-17T19:30:00Z @AI: Declare artifacts search options with a bare Option so clap no longer requires --language (or --limit, --threshold, --project); add CLI parse tests.
+//! - 2026-10-17T19:20:00Z @AI: Declare artifacts export --project with a bare Option so clap no longer requires it.
+//! - 2026-10-17T19:10:00Z @AI: Declare --crawl-delay-ms with a bare Option so clap no longer requires it.
+//! - 2026-10-17T19:00:00Z @AI: Declare artifacts generate options with a bare Option so clap no longer requires --path-prefix (or --project, --depth, and the other optional flags).
+//! - 2026-10-17T18:50:00Z @AI: Declare artifacts list options with a bare Option so clap no longer requires --offset (or --project, --source-type, --limit).
+//! - 2026-10-17T18:30:00Z @AI: Add artifacts search --source-type, --ext, and --language filters.
+//! - 2026-10-17T18:10:00Z @AI: Add artifacts search --rerank / --no-rerank.
+//! - 2026-10-17T17:50:00Z @AI: Add artifacts export and import (JSONL).
+//! - 2026-10-17T17:30:00Z @AI: Add show --similar and --include-closed.
+//! - 2026-10-17T17:10:00Z @AI: Add dedup command.
+//! - 2026-10-17T16:50:00Z @AI: Add add --estimate and plan command.
+//! - 2026-10-17T16:30:00Z @AI: Add link command.
+//! - 2026-10-17T16:10:00Z @AI: Add --yes to do.
+//! - 2026-10-17T15:50:00Z @AI: Add --quiet to parse.
+//! - 2026-10-17T14:10:00Z @AI: Add global --db.
+//! - 2026-10-17T13:50:00Z @AI: Add global --config; add artifacts generate --crawl-delay-ms, --resume, and --force.
+//! - 2026-10-17T11:30:00Z @AI: Add list --cursor.
+//! - 2026-10-17T11:10:00Z @AI: Document the add --due format.
+//! - 2026-10-17T10:10:00Z @AI: Document priority aliases for add --priority.
+//! - 2026-10-17T09:10:00Z @AI: Add --since, --tail, and --file to metrics.
+//! - 2026-10-17T08:10:00Z @AI: Add replay command.
+//! - 2026-10-17T07:50:00Z @AI: Add --trace to the grpc command.
+//! - 2026-10-17T06:50:00Z @AI: Add --regenerate-tests to the do command.
+//! - 2026-10-17T05:10:00Z @AI: Add export and import commands for project bundles.
+//! - 2026-10-17T03:30:00Z @AI: Add stats command.
+//! - 2026-10-17T03:10:00Z @AI: Add start and stop commands for task time tracking.
+//! - 2026-10-17T02:50:00Z @AI: Add --blocked to the List command.
+//! - 2026-10-17T02:30:00Z @AI: Add next command.
+//! - 2026-10-17T02:10:00Z @AI: Add metrics command.
+//! - 2026-10-16T22:30:00Z @AI: Add artifacts reembed subcommand.
+//! - 2026-10-16T21:50:00Z @AI: Add hidden architecture command.
+//! - 2026-10-16T21:30:00Z @AI: Add --no-cache to parse.
+//! - 2026-10-16T20:50:00Z @AI: Add --max-cost to do.
+//! - 2026-10-16T19:50:00Z @AI: Add global --log-level flag.
+//! - 2026-10-16T19:10:00Z @AI: Add models command.
+//! - 2026-10-16T18:50:00Z @AI: Add config check-providers subcommand.
+//! - 2026-10-16T18:30:00Z @AI: Add --dry-run to config migrate.
+//! - 2026-10-16T18:10:00Z @AI: Declare config subcommands' --path/--output with a bare Option so clap no longer requires them.
+//! - 2026-10-16T17:50:00Z @AI: Make do's TASK_ID optional (fuzzy picker when omitted).
+//! - 2026-10-16T16:50:00Z @AI: Add --watch and --interval flags to list; declare list's optional flags with a bare Option so clap no longer requires them.
+//! - 2026-10-16T16:10:00Z @AI: Add add command for manual task creation (optional flags declared with a bare Option/Vec so clap infers them).
+//! - 2026-10-16T15:50:00Z @AI: Add show command for a single task's detail.
+//! - 2026-10-16T15:30:00Z @AI: Add --format and --copy flags to list.
+//! - 2026-10-16T14:10:00Z @AI: Document auto-detected source kinds for artifacts generate.
+//! - 2026-10-16T13:50:00Z @AI: Add artifacts delete and purge subcommands.
+//! - 2026-10-16T13:30:00Z @AI: Add --offset flag to artifacts list for pagination.
+//! - 2026-10-16T12:50:00Z @AI: Add --ocr flag to artifacts generate for image text extraction.
+//! - 2026-10-16T11:10:00Z @AI: Add --incremental flag to parse command for diffing re-parsed PRD tasks against existing tasks.
 //! - 2025-12-04T00:00:00Z @AI: Add config command for Phase 4.3 config management CLI.
 //! - 2025-11-30T21:30:00Z @AI: Add artifacts generate command for Phase 5 artifact generator.
 //! - 2025-11-28T23:00:00Z @AI: Add artifacts command for Phase 6 RAG CLI (Tasks 6.1, 6.2).
@@ -11,13 +63,28 @@
 
 pub mod init;
 pub mod parse;
+pub mod add;
 pub mod list;
+pub mod show;
 pub mod do_task;
 pub mod server;
 pub mod grpc_server;
 pub mod tui;
 pub mod artifacts;
+pub mod artifact_jsonl;
 pub mod config;
+pub mod models;
+pub mod metrics;
+pub mod replay;
+pub mod next;
+pub mod time_tracking;
+pub mod stats;
+pub mod bundle;
+pub mod architecture;
+pub mod link;
+pub mod plan;
+pub mod dedup;
+pub mod project;
 
 /// Rig CLI - AI-driven project management for agents.
 #[derive(clap::Parser)]
@@ -27,6 +94,20 @@ pub mod config;
 pub struct Cli {
     #[command(subcommand)]
     pub command: Commands,
+
+    /// JSON log filter written to stderr, in RUST_LOG syntax (e.g. "debug",
+    /// "warn,task_orchestrator=debug"). Overrides RIGGER_LOG; defaults to "warn"
+    // clap only treats an unqualified `Option` as an optional argument
+    #[arg(long, global = true)]
+    pub log_level: Option<String>,
+
+    /// Config file to use instead of .rigger/config.json. Overrides RIGGER_CONFIG
+    #[arg(long, global = true)]
+    pub config: Option<String>,
+
+    /// SQLite database URL (sqlite:) to use instead of database.url from config. Overrides RIGGER_DB
+    #[arg(long, global = true)]
+    pub db: Option<String>,
 }
 
 impl Cli {
@@ -44,17 +125,34 @@ pub enum Commands {
     Parse {
         /// Path to the PRD markdown file
         prd_file: String,
+
+        /// ID of the project the PRD and its tasks belong to
+        #[arg(long, default_value = "default-project")]
+        project: String,
+
+        /// Update existing tasks from this PRD instead of inserting duplicates
+        #[arg(long)]
+        incremental: bool,
+
+        /// Skip the response cache and send every LLM call to the provider
+        #[arg(long)]
+        no_cache: bool,
+
+        /// Print only the summary, not each task as it is created
+        #[arg(long)]
+        quiet: bool,
     },
 
     /// List tasks with optional filters and sorting
+    // clap only treats an unqualified `Option` as an optional argument
     List {
         /// Filter by status (e.g., "pending", "in_progress", "completed")
         #[arg(long)]
-        status: std::option::Option<String>,
+        status: Option<String>,
 
         /// Filter by assignee name
         #[arg(long)]
-        assignee: std::option::Option<String>,
+        assignee: Option<String>,
 
         /// Sort by field (priority, created_at, due_date)
         #[arg(long, default_value = "created_at")]
@@ -62,17 +160,205 @@ pub enum Commands {
 
         /// Limit number of results
         #[arg(long)]
-        limit: std::option::Option<String>,
+        limit: Option<String>,
 
         /// Offset for pagination
         #[arg(long)]
-        offset: std::option::Option<String>,
+        offset: Option<String>,
+
+        /// Page with a cursor instead of --offset: pass --cursor alone for the first page, then the printed next cursor
+        #[arg(long, num_args = 0..=1, default_missing_value = "", conflicts_with_all = ["offset", "blocked", "watch"])]
+        cursor: Option<String>,
+
+        /// Only show open tasks waiting on unfinished dependencies, with the blocking task IDs
+        #[arg(long)]
+        blocked: bool,
+
+        /// Output format (table, json)
+        #[arg(long, default_value = "table")]
+        format: String,
+
+        /// Also copy the output to the system clipboard
+        #[arg(long, conflicts_with = "watch")]
+        copy: bool,
+
+        /// Re-render the list periodically until Ctrl-C
+        #[arg(long)]
+        watch: bool,
+
+        /// Refresh interval in milliseconds for --watch (default: tui.auto_refresh_interval_ms)
+        #[arg(long, requires = "watch")]
+        interval: Option<String>,
+    },
+
+    /// Create a task manually
+    // clap only infers optional/repeatable arguments from an unqualified `Option`/`Vec`
+    Add {
+        /// Task title
+        #[arg(long)]
+        title: String,
+
+        /// Detailed description
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Assignee (agent persona)
+        #[arg(long)]
+        assignee: Option<String>,
+
+        /// Priority (high, medium, low; also hi, lo, p0-p3); auto-triaged when omitted
+        #[arg(long)]
+        priority: Option<String>,
+
+        /// Due date (YYYY-MM-DD)
+        #[arg(long)]
+        due: Option<String>,
+
+        /// Initial status (todo, in_progress, completed, archived, or any other task status)
+        #[arg(long)]
+        status: Option<String>,
+
+        /// Estimate in story points (e.g. 3 or 0.5)
+        #[arg(long)]
+        estimate: Option<f32>,
+
+        /// Tag to attach (repeatable)
+        #[arg(long = "tag")]
+        tags: Vec<String>,
+    },
+
+    /// Show a task's full detail, including checklist and revision history
+    Show {
+        /// Task ID to show
+        task_id: String,
+
+        /// Output format (text, json)
+        #[arg(long, default_value = "text")]
+        format: String,
+
+        /// Also list the K most similar other tasks by embedding (default 5)
+        #[arg(long, value_name = "K", num_args = 0..=1, default_missing_value = "5")]
+        similar: Option<usize>,
+
+        /// With --similar, also consider completed and archived tasks
+        #[arg(long, requires = "similar")]
+        include_closed: bool,
+    },
+
+    /// Start working on a task (moves it to in progress and starts tracking time)
+    Start {
+        /// Task ID to start
+        task_id: String,
+    },
+
+    /// Pause a task in progress (moves it back to todo and records the time spent)
+    Stop {
+        /// Task ID to stop
+        task_id: String,
     },
 
     /// Execute a task through the orchestration pipeline
     Do {
-        /// Task ID to execute
-        task_id: String,
+        /// Task ID or ID prefix to execute (opens a fuzzy picker when omitted)
+        // clap only treats an unqualified `Option` as an optional argument
+        task_id: Option<String>,
+
+        /// Maximum dollar cost of this run (overrides performance.max_cost_per_run)
+        #[arg(long)]
+        // clap only treats an unqualified `Option` as an optional argument
+        max_cost: Option<f64>,
+
+        /// Regenerate the task's comprehension tests even if they are current
+        #[arg(long)]
+        regenerate_tests: bool,
+
+        /// Run without previewing the plan and asking for confirmation
+        #[arg(long)]
+        yes: bool,
+    },
+
+    /// Link two tasks: FROM blocks, relates to, or duplicates TO
+    Link {
+        /// Task ID (or unique prefix) the link starts from
+        from: String,
+
+        /// Task ID (or unique prefix) the link points to
+        to: String,
+
+        /// Link type: blocks (TO waits on FROM), relates-to, or duplicates
+        #[arg(long = "type", default_value = "blocks")]
+        link_type: String,
+    },
+
+    /// Recommend the highest-priority task whose dependencies are all finished
+    // clap only treats an unqualified `Option` as an optional argument
+    Next {
+        /// Only recommend tasks assigned to this persona
+        #[arg(long)]
+        assignee: Option<String>,
+    },
+
+    /// Propose a sprint: ready tasks whose estimates fit in the given capacity
+    // clap only treats an unqualified `Option` as an optional argument
+    Plan {
+        /// Points available (sum of task estimates to fill up to)
+        #[arg(long)]
+        capacity: f32,
+
+        /// Only plan tasks assigned to this persona
+        #[arg(long)]
+        assignee: Option<String>,
+    },
+
+    /// Find groups of near-duplicate open tasks by embedding similarity
+    // clap only treats an unqualified `Option` as an optional argument
+    Dedup {
+        /// Minimum similarity (0.0-1.0) for two tasks to count as duplicates (default: 0.9)
+        #[arg(long)]
+        threshold: Option<f32>,
+
+        /// Keep the earliest task of each group and archive the rest as its duplicates
+        #[arg(long)]
+        merge: bool,
+    },
+
+    /// View or set a project's task defaults (assignee, priority)
+    Project {
+        #[command(subcommand)]
+        command: ProjectCommands,
+    },
+
+    /// Summarize task counts by status, completion velocity, and cycle time
+    // clap only treats an unqualified `Option` as an optional argument
+    Stats {
+        /// First day of the window (YYYY-MM-DD; default: the oldest task's creation day)
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Only count tasks assigned to this persona
+        #[arg(long)]
+        assignee: Option<String>,
+
+        /// Output format (text, json)
+        #[arg(long, default_value = "text")]
+        format: String,
+    },
+
+    /// Export a project's PRDs, tasks, and artifacts to a portable JSON bundle
+    Export {
+        /// ID or name of the project to export
+        #[arg(long)]
+        project: String,
+
+        /// Path of the bundle file to write
+        #[arg(long)]
+        out: String,
+    },
+
+    /// Import a project bundle under fresh IDs
+    Import {
+        /// Path of the bundle file written by 'rig export'
+        path: String,
     },
 
     /// Start MCP server mode (for IDE integration via stdio)
@@ -83,6 +369,11 @@ pub enum Commands {
         /// Port to listen on (default: 50051)
         #[arg(long, default_value = "50051")]
         port: u16,
+
+        /// Print each orchestration run's path through the graph to stderr
+        /// (nodes entered and exited with durations, branches taken and why)
+        #[arg(long)]
+        trace: bool,
     },
 
     /// Launch interactive TUI (Terminal User Interface)
@@ -99,6 +390,43 @@ pub enum Commands {
         #[command(subcommand)]
         command: ConfigCommands,
     },
+
+    /// List the models each configured provider offers, marking the ones task slots use
+    // clap only treats an unqualified `Option` as an optional argument
+    Models {
+        /// Only list models for this provider (name from config.json "providers")
+        #[arg(long)]
+        provider: Option<String>,
+    },
+
+    /// Summarize recorded inference metrics and retry counters per provider
+    Metrics {
+        /// Only count calls at or after this time (RFC 3339, or YYYY-MM-DD)
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Only count the last N calls
+        #[arg(long)]
+        tail: Option<usize>,
+
+        /// Read a JSONL metrics file (performance.metrics_file) instead of .rigger/tasks.db
+        #[arg(long)]
+        file: Option<String>,
+    },
+
+    /// Re-run a recorded orchestration run from its log, without calling any provider
+    Replay {
+        /// Path of the run log (written to orchestration.run_log_dir or TASK_ORCHESTRATOR_RUN_LOG_DIR)
+        log: String,
+    },
+
+    /// Print the orchestrator's ports, adapters, and flow graph (text, json, or Graphviz dot)
+    #[command(hide = true)]
+    Architecture {
+        /// Output format (text, json, dot)
+        #[arg(long, default_value = "text")]
+        format: String,
+    },
 }
 
 /// Subcommands for artifacts management.
@@ -108,15 +436,19 @@ pub enum ArtifactsCommands {
     List {
         /// Filter by project ID
         #[arg(long)]
-        project: std::option::Option<String>,
+        project: Option<String>,
 
-        /// Filter by source type (prd, file, web_research, user_input)
+        /// Filter by source type (prd, file, web_research, user_input, image, pdf)
         #[arg(long)]
-        source_type: std::option::Option<String>,
+        source_type: Option<String>,
 
         /// Limit number of results (default: 20)
         #[arg(long)]
-        limit: std::option::Option<String>,
+        limit: Option<String>,
+
+        /// Offset for pagination
+        #[arg(long)]
+        offset: Option<String>,
     },
 
     /// Search artifacts using semantic similarity
@@ -126,63 +458,187 @@ pub enum ArtifactsCommands {
 
         /// Maximum number of results (default: 5)
         #[arg(long)]
-        limit: std::option::Option<String>,
+        limit: Option<String>,
 
         /// Minimum similarity threshold 0.0-1.0 (default: 0.5)
         #[arg(long)]
-        threshold: std::option::Option<String>,
+        threshold: Option<String>,
 
         /// Filter by project ID
         #[arg(long)]
-        project: std::option::Option<String>,
+        project: Option<String>,
+
+        /// Only these source types: prd, file, web_research, user_input, image, pdf (repeatable or comma-separated)
+        #[arg(long, value_delimiter = ',')]
+        source_type: Vec<String>,
+
+        /// Only sources with these file extensions, e.g. md,rs (repeatable or comma-separated)
+        #[arg(long, value_delimiter = ',')]
+        ext: Vec<String>,
+
+        /// Only sources in this language, by file extension (e.g. rust, python, markdown)
+        #[arg(long)]
+        language: Option<String>,
+
+        /// Re-rank the top hits with the chat model (one extra LLM call; default: search.rerank in config)
+        #[arg(long, conflicts_with = "no_rerank")]
+        rerank: bool,
+
+        /// Skip re-ranking even if search.rerank is set in config
+        #[arg(long)]
+        no_rerank: bool,
     },
 
-    /// Generate artifacts from a directory or website
+    /// Generate artifacts from a directory, file, PDF, image, or website
     Generate {
-        /// Source path (directory) or URL (website) to generate artifacts from
+        /// Source to generate artifacts from: directory, file (text, PDF, image), or http(s) URL
         source: String,
 
-        /// Project ID to associate artifacts with (default: directory name or domain)
+        /// Project ID to associate artifacts with (default: directory name, file stem, or domain)
         #[arg(long)]
-        project: std::option::Option<String>,
+        project: Option<String>,
 
         /// Maximum recursion depth for directories/crawling (default: 10)
         #[arg(long)]
-        depth: std::option::Option<String>,
+        depth: Option<String>,
 
         /// Maximum number of files/pages to process (default: 1000)
         #[arg(long)]
-        max_items: std::option::Option<String>,
+        max_items: Option<String>,
 
         /// Chunking strategy: paragraph, sentence, fixed_size, whole_file (default: paragraph)
         #[arg(long)]
-        chunk_strategy: std::option::Option<String>,
+        chunk_strategy: Option<String>,
 
         /// Maximum chunk size in characters for fixed_size strategy (default: 1000)
         #[arg(long)]
-        chunk_size: std::option::Option<String>,
+        chunk_size: Option<String>,
 
         /// Additional glob patterns to exclude (comma-separated)
         #[arg(long)]
-        exclude: std::option::Option<String>,
+        exclude: Option<String>,
+
+        /// Transcribe text from images (png, jpg, gif, webp) with the vision model
+        #[arg(long)]
+        ocr: bool,
+
+        /// Also crawl subdomains of the URL's host (URL sources only)
+        #[arg(long)]
+        include_subdomains: bool,
+
+        /// Only crawl links whose path starts with this prefix, e.g. /docs (URL sources only)
+        #[arg(long)]
+        path_prefix: Option<String>,
+
+        /// Minimum delay between requests to the same host in ms (default: 1000; a longer robots.txt Crawl-delay wins)
+        #[arg(long)]
+        crawl_delay_ms: Option<String>,
+
+        /// Resume an interrupted crawl of this URL instead of starting over (URL sources only)
+        #[arg(long)]
+        resume: bool,
+
+        /// Refetch and re-embed every page, even ones unchanged since the last crawl (URL sources only)
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Delete a single artifact and its embedding
+    Delete {
+        /// ID of the artifact to delete
+        id: String,
+    },
+
+    /// Delete all artifacts belonging to a project
+    Purge {
+        /// Project ID whose artifacts are deleted
+        #[arg(long)]
+        project: String,
+
+        /// Skip the confirmation prompt
+        #[arg(long)]
+        yes: bool,
+    },
+
+    /// Export artifacts with their embeddings to a JSONL file for other vector stores
+    Export {
+        /// Path of the JSONL file to write
+        #[arg(long)]
+        out: String,
+
+        /// Only export this project's artifacts
+        #[arg(long)]
+        project: Option<String>,
+    },
+
+    /// Import artifacts from a JSONL file written by 'rig artifacts export'
+    Import {
+        /// Path of the JSONL file to read
+        path: String,
+    },
+
+    /// Regenerate all artifact embeddings with a different embedding model
+    Reembed {
+        /// Embedding model to use (e.g., nomic-embed-text, text-embedding-3-small)
+        #[arg(long)]
+        model: String,
+
+        /// Skip the confirmation prompt
+        #[arg(long)]
+        yes: bool,
+    },
+}
+
+/// Subcommands for project settings.
+#[derive(clap::Subcommand)]
+// clap only treats an unqualified `Option` as an optional argument
+pub enum ProjectCommands {
+    /// Set the assignee and priority given to new tasks that have none
+    Set {
+        /// Project to configure (created if missing)
+        #[arg(long, default_value = "default-project")]
+        project: String,
+
+        /// Default assignee (agent persona); "" clears it
+        #[arg(long)]
+        default_assignee: Option<String>,
+
+        /// Default priority (high, medium, low; aliases hi, med, lo, p0-p3); "" clears it
+        #[arg(long)]
+        default_priority: Option<String>,
+    },
+
+    /// Show a project's task defaults
+    Show {
+        /// Project to show
+        #[arg(long, default_value = "default-project")]
+        project: String,
     },
 }
 
 /// Subcommands for configuration management.
 #[derive(clap::Subcommand)]
+// clap only treats an unqualified `Option` as an optional argument
 pub enum ConfigCommands {
     /// Display current configuration with syntax highlighting
     Show {
         /// Path to config file (default: .rigger/config.json or ~/.config/rigger/config.json)
         #[arg(long)]
-        path: std::option::Option<String>,
+        path: Option<String>,
     },
 
     /// Validate configuration and show any errors
     Validate {
         /// Path to config file (default: .rigger/config.json or ~/.config/rigger/config.json)
         #[arg(long)]
-        path: std::option::Option<String>,
+        path: Option<String>,
+    },
+
+    /// Check that each configured provider is reachable and its credentials work
+    CheckProviders {
+        /// Path to config file (default: .rigger/config.json or ~/.config/rigger/config.json)
+        #[arg(long)]
+        path: Option<String>,
     },
 
     /// Open configuration in TUI editor
@@ -192,14 +648,82 @@ pub enum ConfigCommands {
     Migrate {
         /// Path to legacy config file (default: .rigger/config.json)
         #[arg(long)]
-        path: std::option::Option<String>,
+        path: Option<String>,
 
         /// Output path for migrated config (default: overwrites input)
         #[arg(long)]
-        output: std::option::Option<String>,
+        output: Option<String>,
 
         /// Create backup before migration (default: true)
         #[arg(long, default_value = "true")]
         backup: bool,
+
+        /// Print the migrated v3.0 JSON without writing any files
+        #[arg(long)]
+        dry_run: bool,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_project_set_defaults_to_the_default_project() {
+        // Test: Validates `rig project set` targets default-project unless --project is given, and leaves unnamed defaults unset.
+        // Justification: add and parse read default-project's settings, so that is the project users configure.
+        let cli = <super::Cli as clap::Parser>::try_parse_from(["rig", "project", "set", "--default-priority", "high"])
+            .expect("project set must parse");
+
+        match cli.command {
+            super::Commands::Project {
+                command: super::ProjectCommands::Set { project, default_assignee, default_priority },
+            } => {
+                std::assert_eq!(project, "default-project");
+                std::assert!(default_assignee.is_none());
+                std::assert_eq!(default_priority.as_deref(), std::option::Option::Some("high"));
+            }
+            _ => std::panic!("expected project set"),
+        }
+    }
+
+    #[test]
+    fn test_artifacts_search_parses_without_filters() {
+        // Test: Validates `rig artifacts search <query>` parses with every option and filter left out.
+        // Justification: clap only treats a bare `Option` as optional; a qualified path makes the flag mandatory.
+        let cli = <super::Cli as clap::Parser>::try_parse_from(["rig", "artifacts", "search", "rotate keys"])
+            .expect("search without filters must parse");
+
+        match cli.command {
+            super::Commands::Artifacts {
+                command: super::ArtifactsCommands::Search { query, limit, threshold, project, source_type, ext, language, rerank, no_rerank },
+            } => {
+                std::assert_eq!(query, "rotate keys");
+                std::assert!(limit.is_none() && threshold.is_none() && project.is_none() && language.is_none());
+                std::assert!(source_type.is_empty() && ext.is_empty());
+                std::assert!(!rerank && !no_rerank);
+            }
+            _ => std::panic!("expected artifacts search"),
+        }
+    }
+
+    #[test]
+    fn test_artifacts_search_parses_combined_filters() {
+        // Test: Validates repeated and comma-separated filter values are collected alongside --language.
+        // Justification: Filters are combined by the search, so each flag must reach it intact.
+        let cli = <super::Cli as clap::Parser>::try_parse_from([
+            "rig", "artifacts", "search", "deploy",
+            "--source-type", "file,pdf", "--ext", "md", "--ext", "rst", "--language", "rust",
+        ])
+        .expect("search with filters must parse");
+
+        match cli.command {
+            super::Commands::Artifacts {
+                command: super::ArtifactsCommands::Search { source_type, ext, language, .. },
+            } => {
+                std::assert_eq!(source_type, ["file", "pdf"]);
+                std::assert_eq!(ext, ["md", "rst"]);
+                std::assert_eq!(language.as_deref(), std::option::Option::Some("rust"));
+            }
+            _ => std::panic!("expected artifacts search"),
+        }
+    }
+}
@@ -0,0 +1,246 @@
+//! Implementation of the 'rig models [--provider NAME]' command.
+//!
+//! Lists the models each configured provider offers (for Ollama, the locally
+//! pulled models) via the provider's model-list endpoint, and marks the
+//! models that task slots in .rigger/config.json are set to use.
+//!
+//! Revision History
+//! - 2026-10-18T05:10:00Z @AI: Serve test responses through task_manager::test_support::mock_http_server.
+//! - 2026-10-17T14:50:00Z @AI: Set auto_pull in the test provider.
+//! - 2026-10-17T13:50:00Z @AI: Read the config path from services::config_path, honoring --config and RIGGER_CONFIG.
+//! - 2026-10-17T01:30:00Z @AI: List models for OpenAI-compatible providers.
+//! - 2026-10-16T19:10:00Z @AI: Initial models command.
+
+/// A model offered by a provider, with the config entries that use it.
+///
+/// # Fields
+///
+/// * `id` - Model ID as reported by the provider.
+/// * `slots` - Task slots configured to use this model on this provider.
+/// * `provider_default` - Whether this is the provider's `default_model`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelEntry {
+    /// Model ID as reported by the provider.
+    pub id: String,
+
+    /// Task slots configured to use this model on this provider.
+    pub slots: std::vec::Vec<String>,
+
+    /// Whether this is the provider's `default_model`.
+    pub provider_default: bool,
+}
+
+/// Executes the 'rig models' command.
+///
+/// # Arguments
+///
+/// * `provider` - Only list this provider (name from the config's `providers` map)
+///
+/// # Errors
+///
+/// Returns an error if the named provider is not configured, or if listing
+/// fails for every selected provider.
+pub async fn execute(provider: std::option::Option<&str>) -> anyhow::Result<()> {
+    let config_path = crate::services::config_path::current()?;
+    let config = rigger_core::RiggerConfig::load_with_migration(
+        config_path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid config path"))?
+    )?;
+
+    let mut names: std::vec::Vec<&String> = config.providers.keys().collect();
+    names.sort();
+    if let std::option::Option::Some(wanted) = provider {
+        if !config.providers.contains_key(wanted) {
+            let available: std::vec::Vec<&str> = names.iter().map(|name| name.as_str()).collect();
+            anyhow::bail!("Unknown provider '{}'. Available providers: {}", wanted, available.join(", "));
+        }
+        names.retain(|name| name.as_str() == wanted);
+    }
+
+    let theme = crate::display::theme::Theme::load(&config_path);
+    let mut failures = 0;
+    for name in &names {
+        let provider_config = &config.providers[*name];
+        println!("{} ({})", name, provider_config.provider_type);
+        match list_provider_models(name, provider_config, &config.task_slots).await {
+            std::result::Result::Ok(models) if models.is_empty() => println!("  (no models found)"),
+            std::result::Result::Ok(models) => {
+                for model in &models {
+                    println!("{}", render_model(model, &theme));
+                }
+            }
+            std::result::Result::Err(reason) => {
+                failures += 1;
+                println!("  {}", theme.error(&reason));
+            }
+        }
+        println!();
+    }
+
+    if !names.is_empty() && failures == names.len() {
+        anyhow::bail!("Could not list models for any provider");
+    }
+    std::result::Result::Ok(())
+}
+
+/// Lists a provider's models via `ProviderFactory` and marks the ones in use.
+///
+/// # Arguments
+///
+/// * `name` - Provider name from the config
+/// * `provider` - The provider's configuration
+/// * `slots` - Task slot configuration used for the markers
+///
+/// # Errors
+///
+/// Returns a human-readable reason if the provider has no model-list
+/// endpoint, its API key is missing, or the request fails.
+pub async fn list_provider_models(
+    name: &str,
+    provider: &rigger_core::config::ProviderConfig,
+    slots: &rigger_core::config::TaskSlotConfig,
+) -> std::result::Result<std::vec::Vec<ModelEntry>, String> {
+    let factory_provider = match provider.provider_type {
+        rigger_core::config::ProviderType::Ollama => "ollama",
+        rigger_core::config::ProviderType::OpenAI => "openai",
+        rigger_core::config::ProviderType::OpenAiCompatible => "openai-compatible",
+        rigger_core::config::ProviderType::Anthropic => "anthropic",
+        _ => {
+            return std::result::Result::Err(std::format!(
+                "Model listing is not supported for {} providers",
+                provider.provider_type
+            ));
+        }
+    };
+
+    let factory = task_orchestrator::adapters::provider_factory::ProviderFactory::new(factory_provider, &provider.default_model)
+        .map_err(|e| e.to_string())?;
+    let api_key = provider.get_api_key().ok().flatten();
+    let lister = factory
+        .create_model_list_adapter(&provider.base_url, api_key)
+        .map_err(|e| e.to_string())?;
+    let ids = lister.list_models().await?;
+
+    let slot_entries = [
+        ("main", &slots.main),
+        ("research", &slots.research),
+        ("fallback", &slots.fallback),
+        ("embedding", &slots.embedding),
+        ("vision", &slots.vision),
+        ("chat_agent", &slots.chat_agent),
+    ];
+    std::result::Result::Ok(
+        ids.into_iter()
+            .map(|id| ModelEntry {
+                slots: slot_entries
+                    .iter()
+                    .filter(|(_, slot)| slot.provider == name && same_model(&id, &slot.model))
+                    .map(|(slot_name, _)| std::string::String::from(*slot_name))
+                    .collect(),
+                provider_default: same_model(&id, &provider.default_model),
+                id,
+            })
+            .collect(),
+    )
+}
+
+/// Returns true if a listed model ID refers to a configured model name.
+///
+/// Ollama reports untagged models with an explicit `:latest` tag, so
+/// `llama3.2:latest` matches a configured `llama3.2`.
+fn same_model(listed: &str, configured: &str) -> bool {
+    listed == configured || listed.strip_suffix(":latest") == std::option::Option::Some(configured)
+}
+
+/// Renders one model line with its markers.
+fn render_model(model: &ModelEntry, theme: &crate::display::theme::Theme) -> String {
+    let mut markers = std::vec::Vec::new();
+    if !model.slots.is_empty() {
+        markers.push(model.slots.join(", "));
+    }
+    if model.provider_default {
+        markers.push(std::string::String::from("provider default"));
+    }
+
+    if markers.is_empty() {
+        std::format!("    {}", model.id)
+    } else {
+        std::format!("  * {}  {}", model.id, theme.success(&std::format!("[{}]", markers.join("; "))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    /// Starts a one-shot Ollama stand-in that answers /api/tags with `body`.
+    async fn mock_ollama(body: &'static str) -> String {
+        let (base_url, _) = task_manager::test_support::mock_http_server(move |request| {
+            std::assert!(request.starts_with("GET /api/tags "));
+            ("200 OK", std::string::String::from(body))
+        })
+        .await;
+        base_url
+    }
+
+    #[tokio::test]
+    async fn test_models_marked_with_slots_and_provider_default() {
+        // Test: Validates listed models are marked with the task slots and provider default that use them.
+        // Justification: The markers are how users see which pulled model each slot actually runs.
+        let base_url = mock_ollama(
+            r#"{"models": [{"name": "llama3.2:latest"}, {"name": "nomic-embed-text:latest"}, {"name": "qwen2.5:7b"}]}"#,
+        )
+        .await;
+        let mut config = rigger_core::RiggerConfig::default();
+        let provider = config.providers.get_mut("ollama").unwrap();
+        provider.base_url = base_url;
+        provider.default_model = std::string::String::from("qwen2.5:7b");
+        config.task_slots.research.model = std::string::String::from("qwen2.5:7b");
+        config.task_slots.vision.provider = std::string::String::from("openai");
+
+        let models = super::list_provider_models("ollama", &config.providers["ollama"], &config.task_slots)
+            .await
+            .unwrap();
+
+        std::assert_eq!(
+            models,
+            std::vec![
+                super::ModelEntry {
+                    id: std::string::String::from("llama3.2:latest"),
+                    slots: std::vec![
+                        std::string::String::from("main"),
+                        std::string::String::from("fallback"),
+                        std::string::String::from("chat_agent"),
+                    ],
+                    provider_default: false,
+                },
+                super::ModelEntry {
+                    id: std::string::String::from("nomic-embed-text:latest"),
+                    slots: std::vec![std::string::String::from("embedding")],
+                    provider_default: false,
+                },
+                super::ModelEntry {
+                    id: std::string::String::from("qwen2.5:7b"),
+                    slots: std::vec![std::string::String::from("research")],
+                    provider_default: true,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_provider_without_list_endpoint_reports_reason() {
+        // Test: Validates providers without a supported model-list endpoint return a reason instead of failing hard.
+        // Justification: One unsupported provider must not stop `rig models` from listing the others.
+        let provider = rigger_core::config::ProviderConfig {
+            provider_type: rigger_core::config::ProviderType::Custom(std::string::String::from("lmstudio")),
+            base_url: std::string::String::from("http://localhost:1234/v1"),
+            api_key_env: std::option::Option::None,
+            timeout_seconds: 5,
+            max_retries: 0,
+            default_model: std::string::String::from("local"),
+            auto_pull: false,
+        };
+
+        let result = super::list_provider_models("lmstudio", &provider, &rigger_core::RiggerConfig::default().task_slots).await;
+
+        std::assert!(result.unwrap_err().contains("not supported"));
+    }
+}
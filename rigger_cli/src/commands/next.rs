@@ -0,0 +1,124 @@
+//! Implementation of the 'rig next [--assignee NAME]' command.
+//!
+//! Recommends the task to work on next: the highest-priority open task whose
+//! dependencies are all finished, with ties broken by due date and then
+//! creation time (see `task_manager::domain::services::next_task`).
+//!
+//! Revision History
+//! - 2026-10-17T14:10:00Z @AI: Open the database through services::database_url so --db / RIGGER_DB apply.
+//! - 2026-10-17T02:30:00Z @AI: Initial next command.
+
+/// Executes the 'rig next' command.
+///
+/// # Arguments
+///
+/// * `assignee` - Only recommend tasks assigned to this persona
+///
+/// # Errors
+///
+/// Returns an error if .rigger doesn't exist or the database query fails.
+pub async fn execute(assignee: std::option::Option<&str>) -> anyhow::Result<()> {
+    let current_dir = std::env::current_dir()?;
+    let rigger_dir = current_dir.join(".rigger");
+
+    if !rigger_dir.exists() {
+        anyhow::bail!(
+            ".rigger directory not found.\nRun 'rig init' first to initialize the project."
+        );
+    }
+
+    let db_url = crate::services::database_url::url_for(&rigger_dir.join("tasks.db"));
+    let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect to database: {}", e))?;
+
+    let tasks = adapter
+        .find_async(
+            &task_manager::ports::task_repository_port::TaskFilter::All,
+            hexser::ports::repository::FindOptions {
+                sort: std::option::Option::None,
+                limit: std::option::Option::None,
+                offset: std::option::Option::None,
+            },
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    print!("{}", render_recommendation(&tasks, assignee));
+    std::result::Result::Ok(())
+}
+
+/// Renders the recommended task, or why there is none.
+///
+/// # Arguments
+///
+/// * `tasks` - All tasks of the project
+/// * `assignee` - Only recommend tasks assigned to this persona
+///
+/// # Returns
+///
+/// The recommendation with a `rig do` hint, ending with a newline.
+fn render_recommendation(tasks: &[task_manager::domain::task::Task], assignee: std::option::Option<&str>) -> String {
+    let ready = task_manager::domain::services::next_task::ready_tasks(tasks, assignee);
+    let scope = match assignee {
+        std::option::Option::Some(assignee) => std::format!(" assigned to {}", assignee),
+        std::option::Option::None => String::new(),
+    };
+
+    let task = match ready.first() {
+        std::option::Option::Some(task) => task,
+        std::option::Option::None => {
+            return std::format!(
+                "No unblocked open tasks{}. Every open task is waiting on an unfinished dependency, or none are left.\n",
+                scope
+            );
+        }
+    };
+
+    let mut output = std::format!("Next task{}:\n\n", scope);
+    output.push_str(&std::format!("  {}\n", task.title));
+    output.push_str(&std::format!("  ID: {}\n", task.id));
+    if let std::option::Option::Some(priority) = task.priority {
+        output.push_str(&std::format!("  Priority: {}\n", priority.as_str()));
+    }
+    if let std::option::Option::Some(ref due_date) = task.due_date {
+        output.push_str(&std::format!("  Due Date: {}\n", due_date));
+    }
+    if let std::option::Option::Some(ref persona) = task.agent_persona {
+        output.push_str(&std::format!("  Assignee: {}\n", persona));
+    }
+    if ready.len() > 1 {
+        output.push_str(&std::format!("\n{} other task(s) are ready too.\n", ready.len() - 1));
+    }
+    output.push_str(&std::format!("\nStart it with: rig do {}\n", task.id));
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_render_recommendation_names_task_and_reports_blocked() {
+        // Test: Validates the output names the unblocked task with a `rig do` hint, and explains when everything is blocked.
+        // Justification: The command's value is telling the user exactly what to run next.
+        let item = |title: &str| transcript_extractor::domain::action_item::ActionItem {
+            title: String::from(title),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let mut schema = task_manager::domain::task::Task::from_action_item(&item("Design schema"), std::option::Option::None);
+        schema.id = String::from("schema-1");
+        let mut api = task_manager::domain::task::Task::from_action_item(&item("Build API"), std::option::Option::None);
+        api.id = String::from("api-1");
+        api.dependencies = std::vec![String::from("schema-1")];
+        let tasks = std::vec![api, schema];
+
+        let output = super::render_recommendation(&tasks, std::option::Option::None);
+        std::assert!(output.contains("Design schema"), "{}", output);
+        std::assert!(output.contains("rig do schema-1"), "{}", output);
+
+        let mut failed_dependency = tasks.clone();
+        failed_dependency[1].status = task_manager::domain::task_status::TaskStatus::Errored;
+        let blocked = super::render_recommendation(&failed_dependency, std::option::Option::None);
+        std::assert!(blocked.starts_with("No unblocked open tasks"), "{}", blocked);
+    }
+}
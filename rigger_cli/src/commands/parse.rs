@@ -4,6 +4,26 @@
 //! Ingests PRD content into RAG knowledge base with vector embeddings for semantic search.
 //!
 //! Revision History
+//! - 2026-10-18T02:20:00Z @AI: Add --project for the PRD's project ID (used for its tasks, settings, and artifacts) instead of always using default-project; save tasks refreshed by --incremental through ManageTaskUseCase::refresh_from_prd.
+//! - 2026-10-18T02:10:00Z @AI: Auto-decompose tasks at or above the config's decomposition.complexity_threshold instead of a hardcoded 7, re-decomposing sub-tasks still above it up to decomposition.max_depth levels.
+//! - 2026-10-17T21:10:00Z @AI: Print each task and sub-task as the parser reports it on its progress channel, while parsing and decomposition run, instead of after they return.
+//! - 2026-10-17T20:50:00Z @AI: Scope idempotency keys to the project and a hash of the PRD content instead of the PRD title alone.
+//! - 2026-10-17T20:40:00Z @AI: Create decomposed sub-tasks through ManageTaskUseCase::create as well.
+//! - 2026-10-17T20:30:00Z @AI: Create generated tasks through ManageTaskUseCase::create so validation, project defaults, triage and the idempotency check all happen in one place; drop save_unless_exists.
+//! - 2026-10-17T15:50:00Z @AI: Print each task and sub-task as it is saved (counted through a per-task callback); add --quiet to print only the summary.
+//! - 2026-10-17T15:10:00Z @AI: Build the embedding adapter's retry policy from the main provider's timeout and max_retries.
+//! - 2026-10-17T14:50:00Z @AI: Pass the main provider's auto_pull to the embedding adapter.
+//! - 2026-10-17T14:10:00Z @AI: Open the database through services::database_url so --db / RIGGER_DB apply.
+//! - 2026-10-17T13:50:00Z @AI: Read the config path from services::config_path, honoring --config and RIGGER_CONFIG.
+//! - 2026-10-17T11:50:00Z @AI: Give generated tasks idempotency keys (PRD title + source section + position) and skip tasks an earlier, partially failed run already saved.
+//! - 2026-10-17T00:10:00Z @AI: Initialize Persona::tool_call_limits.
+//! - 2026-10-16T21:30:00Z @AI: Add --no-cache; PRD parser calls use configured sampling and the response cache.
+//! - 2026-10-16T21:10:00Z @AI: RAG ingestion honors offline mode when creating the embedding provider.
+//! - 2026-10-16T20:30:00Z @AI: Print a per-model cost summary of the run's LLM calls, priced from the config's `pricing` section.
+//! - 2026-10-16T16:30:00Z @AI: Apply default-project settings (default assignee/priority) to new tasks and sub-tasks before triage; add load_project_settings().
+//! - 2026-10-16T12:10:00Z @AI: Auto-triage newly created tasks and sub-tasks (priority, triage label, revision entry) via TriageService before saving.
+//! - 2026-10-16T11:30:00Z @AI: Add requirements field to test PRD fixture.
+//! - 2026-10-16T11:10:00Z @AI: Add --incremental mode. Re-parsed tasks are diffed against existing tasks of the same PRD via PrdTaskDiffer; changed tasks are updated in place, new tasks inserted, and tasks whose source section was removed are reported instead of duplicated. Only newly added tasks are auto-decomposed.
 //! - 2025-12-04T00:00:00Z @AI: Update to use rigger_core config and read from task slots (Phase 4.4).
 //! - 2025-11-28T20:45:00Z @AI: Add RAG artifact ingestion after task generation (Phase 3 Task 4.2).
 //! - 2025-11-27T09:00:00Z @AI: Add auto-decomposition for complex tasks. After saving generated tasks, iterate through them and auto-decompose any with complexity >= 7. For each complex task: (1) call parser.decompose_task() to generate 3-5 sub-tasks, (2) save sub-tasks to database, (3) update parent task with subtask_ids and Decomposed status. Provides progress feedback ("🔄 Decomposing complex task...") and summary stats. Decomposition failures are non-fatal - logs warning and continues with original task.
@@ -16,12 +36,20 @@
 /// 1. Reads the PRD markdown file
 /// 2. Parses it using task_manager::utils::prd_parser
 /// 3. Uses Rig-powered PRD parser to generate tasks via LLM
-/// 4. Saves all tasks to SQLite database
-/// 5. Prints summary of results
+/// 4. Saves all tasks to SQLite database (or, in incremental mode, only added and updated tasks)
+/// 5. Prints summary of results, including the cost of the run's LLM calls
+///
+/// Tasks and sub-tasks are printed as the parser reports them, while
+/// generation and decomposition are still running, so long runs show
+/// progress. With `quiet`, only the final summary is printed.
 ///
 /// # Arguments
 ///
 /// * `prd_file` - Path to the PRD markdown file
+/// * `project_id` - Project the PRD, its tasks, and its artifacts belong to
+/// * `incremental` - Diff against existing tasks of the same PRD instead of inserting all tasks
+/// * `no_cache` - Send every LLM call to the provider even if the response cache is enabled
+/// * `quiet` - Suppress progress output and print only the summary
 ///
 /// # Errors
 ///
@@ -31,7 +59,7 @@
 /// - PRD parsing fails
 /// - LLM request fails
 /// - Database operations fail
-pub async fn execute(prd_file: &str) -> anyhow::Result<()> {
+pub async fn execute(prd_file: &str, project_id: &str, incremental: bool, no_cache: bool, quiet: bool) -> anyhow::Result<()> {
     // Check if .rigexists
     let current_dir = std::env::current_dir()?;
     let taskmaster_dir = current_dir.join(".rigger");
@@ -51,38 +79,41 @@ pub async fn execute(prd_file: &str) -> anyhow::Result<()> {
     let prd_content = std::fs::read_to_string(prd_path)
         .map_err(|e| anyhow::anyhow!("Failed to read PRD file: {}", e))?;
 
-    println!("Reading PRD from: {}", prd_file);
+    let mut progress = ParseProgress::new(quiet);
+    progress.info(std::format_args!("Reading PRD from: {}", prd_file));
 
-    // Parse PRD markdown (using placeholder project ID for standalone parse command)
-    let prd = task_manager::infrastructure::markdown_parsers::prd_parser::parse_prd_markdown("default-project", &prd_content)
+    // Parse PRD markdown into the requested project
+    let prd = task_manager::infrastructure::markdown_parsers::prd_parser::parse_prd_markdown(project_id, &prd_content)
         .map_err(|e| anyhow::anyhow!("Failed to parse PRD: {}", e))?;
 
-    println!("✓ Parsed PRD: {}", prd.title);
-    println!("  Objectives: {}", prd.objectives.len());
-    println!("  Tech Stack: {}", prd.tech_stack.len());
-    println!("  Constraints: {}", prd.constraints.len());
-    println!();
+    progress.info(std::format_args!("✓ Parsed PRD: {}", prd.title));
+    progress.info(std::format_args!("  Objectives: {}", prd.objectives.len()));
+    progress.info(std::format_args!("  Tech Stack: {}", prd.tech_stack.len()));
+    progress.info(std::format_args!("  Constraints: {}", prd.constraints.len()));
+    progress.info("");
 
     // Read config using rigger_core (with auto-migration)
-    let config_path = taskmaster_dir.join("config.json");
+    let config_path = crate::services::config_path::path_in(&taskmaster_dir);
     let config = rigger_core::RiggerConfig::load_with_migration(
         config_path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid config path"))?
     )?;
+    let decomposition_config = task_orchestrator::infrastructure::config::DecompositionConfig::load(&config_path)
+        .map_err(|e| anyhow::anyhow!("Failed to load decomposition settings: {}", e))?;
 
     // Get main task slot configuration
     let main_slot = &config.task_slots.main;
     let main_provider = config.providers.get(&main_slot.provider)
         .ok_or_else(|| anyhow::anyhow!("Provider '{}' not found in config", main_slot.provider))?;
 
-    println!("Generating tasks using {} ({}) with {}...",
+    progress.info(std::format_args!("Generating tasks using {} ({}) with {}...",
         main_slot.provider,
         main_provider.base_url,
         main_slot.model
-    );
+    ));
 
     // Define database paths early for persona queries
     let db_path = taskmaster_dir.join("tasks.db");
-    let db_url = std::format!("sqlite:{}", db_path.display());
+    let db_url = crate::services::database_url::url_for(&db_path);
 
     // Connect to database for both persona queries and task storage
     let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url)
@@ -117,110 +148,397 @@ pub async fn execute(prd_file: &str) -> anyhow::Result<()> {
                 .map_err(|e| anyhow::anyhow!("Invalid updated_at timestamp: {}", e))?
                 .with_timezone(&chrono::Utc),
             enabled_tools: std::vec::Vec::new(), // Will be populated from persona_tools if needed
+            tool_call_limits: std::collections::HashMap::new(),
         };
         personas.push(persona);
     }
 
-    eprintln!("[PRD Parser] Loaded {} personas for task assignment", personas.len());
+    tracing::debug!(personas = personas.len(), "loaded personas for task assignment");
 
     // Get fallback slot configuration
     let fallback_slot = &config.task_slots.fallback;
 
+    // Deterministic calls (temperature 0 or a fixed seed) may be served from the response cache
+    let sampling = crate::services::response_cache::sampling(&config);
+    let response_cache = crate::services::response_cache::load(&config, &current_dir, no_cache);
+
+    // The parser reports each task on this channel as soon as it is generated
+    let (progress_tx, mut progress_rx) = tokio::sync::mpsc::channel(64);
+
     // Create parser with main and fallback models from config
     let parser = task_orchestrator::adapters::rig_prd_parser_adapter::RigPRDParserAdapter::new(
         main_slot.model.clone(),
         fallback_slot.model.clone(),
         personas
-    );
+    )
+    .with_sampling(sampling)
+    .with_response_cache(response_cache.clone())
+    .with_progress(progress_tx.clone());
 
-    let tasks = parser
-        .parse_prd_to_tasks(&prd)
+    let tasks = progress
+        .follow(parser.parse_prd_to_tasks(&prd), &mut progress_rx, false)
         .await
         .map_err(|e| anyhow::anyhow!("Task generation failed: {}", e))?;
 
-    println!("✓ Generated {} tasks", tasks.len());
-    println!();
+    progress.info(std::format_args!("✓ Generated {} tasks", tasks.len()));
 
-    // Save tasks to database (reusing adapter from above)
-    for task in &tasks {
-        task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::save_async(&adapter, task.clone()).await?;
+    // Keys let a retry after a partial failure skip the tasks already saved
+    let mut tasks = tasks;
+    assign_idempotency_keys(&mut tasks, &prd.project_id, &prd_content);
+
+    // Token usage of every generation and decomposition call is priced at the end
+    let mut run_cost = crate::services::run_cost::from_pricing(&config.pricing);
+    for metrics in parser.recorded_metrics() {
+        run_cost.record(&metrics);
     }
 
-    println!("✓ Saved {} tasks to {}", tasks.len(), db_path.display());
-    println!();
+    // Project defaults fill in assignee/priority the parser left empty
+    let project_settings = load_project_settings(&db_url, &prd.project_id).await?;
+
+    // Save tasks to database (reusing adapter from above)
+    let tasks = if incremental {
+        let existing = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::find_async(
+            &adapter,
+            &task_manager::ports::task_repository_port::TaskFilter::All,
+            hexser::ports::repository::FindOptions {
+                sort: std::option::Option::None,
+                limit: std::option::Option::None,
+                offset: std::option::Option::None,
+            },
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to load existing tasks: {:?}", e))?;
+
+        let mut diff = task_manager::domain::services::prd_task_differ::PrdTaskDiffer::new().diff(&existing, tasks, &prd);
+        let added = std::mem::take(&mut diff.added);
+        diff.added = save_new_tasks(&adapter, added, &project_settings)?;
+        let updated = std::mem::take(&mut diff.updated);
+        diff.updated = save_refreshed_tasks(&adapter, updated)?;
+
+        progress.info("");
+        println!("✓ Incremental update: {}", diff.summary());
+        for task in &diff.removed {
+            println!("  ⚠️  Source section removed from PRD: {} ({})", task.title, task.id);
+        }
+        progress.info("");
+
+        // Only newly added tasks are candidates for auto-decomposition
+        diff.added
+    } else {
+        let generated = tasks.len();
+        let saved = save_new_tasks(&adapter, tasks, &project_settings)?;
+
+        progress.info("");
+        println!("✓ Saved {} tasks to {}", saved.len(), db_path.display());
+        if saved.len() < generated {
+            println!("  {} tasks were already saved by an earlier run", generated - saved.len());
+        }
+        progress.info("");
+        // Tasks from an earlier run were already offered for auto-decomposition
+        saved
+    };
 
     // Ingest PRD content as artifacts for RAG
-    println!("📚 Ingesting PRD content for semantic search...");
-    match ingest_prd_artifacts(&prd, &prd_content, &db_url, &main_slot.provider, &main_slot.model).await {
+    progress.info("📚 Ingesting PRD content for semantic search...");
+    let retry_policy = task_orchestrator::infrastructure::retry_policy::RetryPolicy::from_provider_config(main_provider);
+    match ingest_prd_artifacts(&prd, &prd_content, &db_url, &main_slot.provider, &main_slot.model, config.offline, main_provider.auto_pull, retry_policy).await {
         std::result::Result::Ok(artifact_count) => {
-            println!("✓ Ingested {} knowledge artifacts with embeddings", artifact_count);
-            println!();
+            progress.info(std::format_args!("✓ Ingested {} knowledge artifacts with embeddings", artifact_count));
+            progress.info("");
         }
         std::result::Result::Err(e) => {
             eprintln!("⚠️  RAG ingestion failed (non-fatal): {}", e);
             eprintln!("  → Continuing with task generation");
-            println!();
+            progress.info("");
         }
     }
 
-    // Auto-decompose complex tasks (complexity >= 7)
+    // Auto-decompose complex tasks; sub-tasks still above the threshold are split again
+    let mut pending: std::collections::VecDeque<(task_manager::domain::task::Task, usize)> =
+        tasks.iter().cloned().map(|task| (task, 0)).collect();
+    let mut decomposed_tasks = 0;
     let mut total_subtasks = 0;
-    for task in &tasks {
-        if let std::option::Option::Some(complexity) = task.complexity {
-            if complexity >= 7 {
-                println!("🔄 Decomposing complex task (complexity {}): {}", complexity, task.title);
-
-                // Recreate parser for decomposition (using same models from config)
-                let decompose_parser = task_orchestrator::adapters::rig_prd_parser_adapter::RigPRDParserAdapter::new(
-                    main_slot.model.clone(),
-                    fallback_slot.model.clone(),
-                    std::vec::Vec::new() // Personas already validated in original tasks
-                );
-
-                match decompose_parser.decompose_task(task, &prd_content).await {
-                    std::result::Result::Ok(subtasks) => {
-                        println!("  ✓ Generated {} sub-tasks", subtasks.len());
-
-                        // Save sub-tasks
-                        for subtask in &subtasks {
-                            task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::save_async(&adapter, subtask.clone()).await?;
-                        }
-
-                        // Update parent task with subtask IDs and Decomposed status
-                        let mut updated_parent = task.clone();
-                        updated_parent.subtask_ids = subtasks.iter().map(|st| st.id.clone()).collect();
-                        updated_parent.status = task_manager::domain::task_status::TaskStatus::Decomposed;
-                        task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::save_async(&adapter, updated_parent).await?;
-
-                        total_subtasks += subtasks.len();
-                    }
-                    std::result::Result::Err(e) => {
-                        eprintln!("  ⚠️  Decomposition failed: {}", e);
-                        eprintln!("  → Continuing with original task");
-                    }
-                }
+    while let std::option::Option::Some((task, depth)) = pending.pop_front() {
+        if !needs_decomposition(&task, depth, &decomposition_config) {
+            continue;
+        }
+        progress.info(std::format_args!("🔄 Decomposing complex task (complexity {}): {}", task.complexity.unwrap_or(0), task.title));
+
+        // Recreate parser for decomposition (using same models from config)
+        let decompose_parser = task_orchestrator::adapters::rig_prd_parser_adapter::RigPRDParserAdapter::new(
+            main_slot.model.clone(),
+            fallback_slot.model.clone(),
+            std::vec::Vec::new() // Personas already validated in original tasks
+        )
+        .with_sampling(sampling)
+        .with_response_cache(response_cache.clone())
+        .with_progress(progress_tx.clone());
+
+        let decomposition = progress
+            .follow(decompose_parser.decompose_task(&task, &prd_content), &mut progress_rx, true)
+            .await;
+        for metrics in decompose_parser.recorded_metrics() {
+            run_cost.record(&metrics);
+        }
+
+        match decomposition {
+            std::result::Result::Ok(subtasks) => {
+                progress.info(std::format_args!("  ✓ Generated {} sub-tasks", subtasks.len()));
+
+                // Sub-tasks get the same validation, defaults and triage as their parents
+                let subtasks = save_new_tasks(&adapter, subtasks, &project_settings)?;
+
+                // Update parent task with subtask IDs and Decomposed status
+                let mut updated_parent = task.clone();
+                updated_parent.subtask_ids = subtasks.iter().map(|st| st.id.clone()).collect();
+                updated_parent.status = task_manager::domain::task_status::TaskStatus::Decomposed;
+                task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::save_async(&adapter, updated_parent).await?;
+
+                decomposed_tasks += 1;
+                total_subtasks += subtasks.len();
+                pending.extend(subtasks.into_iter().map(|subtask| (subtask, depth + 1)));
+            }
+            std::result::Result::Err(e) => {
+                eprintln!("  ⚠️  Decomposition failed: {}", e);
+                eprintln!("  → Continuing with original task");
             }
         }
     }
 
     if total_subtasks > 0 {
-        println!();
-        println!("✓ Auto-decomposed {} complex tasks into {} sub-tasks",
-            tasks.iter().filter(|t| t.complexity.unwrap_or(0) >= 7).count(),
-            total_subtasks
-        );
-        println!();
+        progress.info("");
+        println!("✓ Auto-decomposed {} complex tasks into {} sub-tasks", decomposed_tasks, total_subtasks);
+        progress.info("");
     }
 
+    crate::services::run_cost::print_summary(&run_cost);
+
     // Print next steps
-    println!("Next steps:");
-    println!("  1. View tasks: riglist");
-    println!("  2. Execute a task: rigdo <TASK_ID>");
-    println!();
+    progress.info("Next steps:");
+    progress.info("  1. View tasks: riglist");
+    progress.info("  2. Execute a task: rigdo <TASK_ID>");
+    progress.info("");
 
     std::result::Result::Ok(())
 }
 
+/// Console progress of a parse run.
+///
+/// Prints status lines and one numbered line per task or sub-task the parser
+/// reports, while the parser is still running, so a long run shows what it
+/// has generated so far. When quiet, nothing is printed and only the
+/// caller's summary lines remain.
+struct ParseProgress {
+    quiet: bool,
+    generated: usize,
+}
+
+impl ParseProgress {
+    fn new(quiet: bool) -> Self {
+        Self { quiet, generated: 0 }
+    }
+
+    /// Prints a status line unless quiet.
+    fn info(&self, message: impl std::fmt::Display) {
+        if !self.quiet {
+            println!("{}", message);
+        }
+    }
+
+    /// Awaits `work`, reporting the parser's updates as they arrive rather than after `work` returns.
+    ///
+    /// # Arguments
+    ///
+    /// * `work` - A parse or decomposition call on a parser built with `with_progress`
+    /// * `updates` - The receiving end of that parser's progress channel
+    /// * `nested` - Whether the reported tasks are sub-tasks (indented further)
+    async fn follow<T>(
+        &mut self,
+        work: impl std::future::Future<Output = T>,
+        updates: &mut tokio::sync::mpsc::Receiver<task_orchestrator::adapters::rig_prd_parser_adapter::PRDGenUpdate>,
+        nested: bool,
+    ) -> T {
+        let mut work = std::pin::pin!(work);
+        loop {
+            tokio::select! {
+                output = &mut work => {
+                    // Updates sent just before `work` finished are still queued
+                    while let std::result::Result::Ok(update) = updates.try_recv() {
+                        self.report(update, nested);
+                    }
+                    return output;
+                }
+                std::option::Option::Some(update) = updates.recv() => self.report(update, nested),
+            }
+        }
+    }
+
+    /// Reports a generated task or sub-task; other updates are only logged.
+    fn report(&mut self, update: task_orchestrator::adapters::rig_prd_parser_adapter::PRDGenUpdate, nested: bool) {
+        match update {
+            task_orchestrator::adapters::rig_prd_parser_adapter::PRDGenUpdate::TaskGenerated { title, .. } => {
+                self.generated += 1;
+                if self.quiet {
+                    return;
+                }
+                if nested {
+                    println!("    [{}] + {}", self.generated, title);
+                } else {
+                    println!("  [{}] + {}", self.generated, title);
+                }
+            }
+            task_orchestrator::adapters::rig_prd_parser_adapter::PRDGenUpdate::ValidationInfo { task_title, message } => {
+                tracing::debug!(task = %task_title, "{}", message);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Creates generated tasks through ManageTaskUseCase::create.
+///
+/// create() validates each task, skips it if a task with its idempotency key
+/// already exists (saved by an earlier, partially failed run), and otherwise
+/// applies the project defaults and triage before saving it. Tasks that fail
+/// validation are reported and skipped so one bad task does not abort the run.
+///
+/// # Arguments
+///
+/// * `adapter` - Task repository; the use case shares its pool
+/// * `tasks` - Generated tasks, in order
+/// * `project_settings` - Defaults for fields the parser left empty
+///
+/// # Returns
+///
+/// The tasks that were created, as saved.
+///
+/// # Errors
+///
+/// Returns an error if a lookup or save fails; tasks saved before it stay saved.
+fn save_new_tasks(
+    adapter: &task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter,
+    tasks: std::vec::Vec<task_manager::domain::task::Task>,
+    project_settings: &task_manager::domain::project_settings::ProjectSettings,
+) -> anyhow::Result<std::vec::Vec<task_manager::domain::task::Task>> {
+    let mut use_case = task_manager::use_cases::manage_task::ManageTaskUseCase::new(
+        task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::new(adapter.pool().clone()),
+    )
+    .with_project_settings(project_settings.clone());
+
+    let mut saved = std::vec::Vec::new();
+    for task in tasks {
+        let id = task.id.clone();
+        let title = task.title.clone();
+        match use_case.create(task) {
+            // create() returns the earlier task, with its own ID, when the key already exists
+            std::result::Result::Ok(created) if created.id == id => saved.push(created),
+            std::result::Result::Ok(_) => {}
+            std::result::Result::Err(task_manager::domain::task_manager_error::TaskManagerError::ValidationFailed { errors }) => {
+                let problems: std::vec::Vec<String> = errors.iter().map(|error| error.to_string()).collect();
+                eprintln!("  ⚠️  Skipping invalid task '{}': {}", title, problems.join("; "));
+            }
+            std::result::Result::Err(e) => return std::result::Result::Err(anyhow::Error::new(e).context("Failed to save task")),
+        }
+    }
+    std::result::Result::Ok(saved)
+}
+
+/// Saves tasks refreshed by an incremental re-parse through ManageTaskUseCase::refresh_from_prd.
+///
+/// Each refresh is validated and recorded as a revision. A task that fails
+/// validation is reported and skipped; the returned tasks are those saved.
+///
+/// # Errors
+///
+/// Returns an error if a lookup or save fails; tasks saved before it stay saved.
+fn save_refreshed_tasks(
+    adapter: &task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter,
+    tasks: std::vec::Vec<task_manager::domain::task::Task>,
+) -> anyhow::Result<std::vec::Vec<task_manager::domain::task::Task>> {
+    let mut use_case = task_manager::use_cases::manage_task::ManageTaskUseCase::new(
+        task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::new(adapter.pool().clone()),
+    );
+
+    let mut saved = std::vec::Vec::new();
+    for task in tasks {
+        let title = task.title.clone();
+        match use_case.refresh_from_prd(task) {
+            std::result::Result::Ok(refreshed) => saved.push(refreshed),
+            std::result::Result::Err(task_manager::domain::task_manager_error::TaskManagerError::ValidationFailed { errors }) => {
+                let problems: std::vec::Vec<String> = errors.iter().map(|error| error.to_string()).collect();
+                eprintln!("  ⚠️  Skipping invalid update '{}': {}", title, problems.join("; "));
+            }
+            std::result::Result::Err(e) => return std::result::Result::Err(anyhow::Error::new(e).context("Failed to update task")),
+        }
+    }
+    std::result::Result::Ok(saved)
+}
+
+/// Sets each generated task's idempotency key from the PRD and its place in it.
+///
+/// The key covers the project, a hash of the PRD content, the task's source
+/// section (or its title if the parser gave none), and its position among the
+/// tasks of that section. Re-parsing the same PRD into the same project yields
+/// the same keys; another project, or a PRD that shares only its title, does not.
+fn assign_idempotency_keys(tasks: &mut [task_manager::domain::task::Task], project_id: &str, prd_content: &str) {
+    // The content is hashed once and each task's key is derived from that hash
+    let source = task_manager::domain::task::Task::idempotency_key_for(&std::format!("prd:{}", project_id), prd_content);
+    let mut seen: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+    for task in tasks.iter_mut() {
+        let section = task.source_section.clone().unwrap_or_else(|| task.title.clone());
+        let position = seen.entry(section.clone()).or_insert(0);
+        *position += 1;
+        task.idempotency_key = std::option::Option::Some(task_manager::domain::task::Task::idempotency_key_for(
+            &source,
+            &std::format!("{}#{}", section, position),
+        ));
+    }
+}
+
+/// Whether `task`, found `depth` decomposition levels below a generated task,
+/// should be decomposed: its complexity meets the configured threshold and
+/// splitting it would not go deeper than `max_depth` levels.
+fn needs_decomposition(
+    task: &task_manager::domain::task::Task,
+    depth: usize,
+    config: &task_orchestrator::infrastructure::config::DecompositionConfig,
+) -> bool {
+    depth < config.max_depth && task.complexity.is_some_and(|complexity| complexity >= config.complexity_threshold)
+}
+
+/// Loads the task defaults configured on a project.
+///
+/// # Arguments
+///
+/// * `db_url` - SQLite database URL
+/// * `project_id` - Project whose settings to load
+///
+/// # Returns
+///
+/// The project's settings, or empty defaults if the project does not exist.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be opened or queried.
+pub async fn load_project_settings(
+    db_url: &str,
+    project_id: &str,
+) -> anyhow::Result<task_manager::domain::project_settings::ProjectSettings> {
+    let adapter = task_manager::adapters::sqlite_project_adapter::SqliteProjectAdapter::connect_and_init(db_url)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect to database: {}", e))?;
+
+    let projects = adapter
+        .find_async(
+            &task_manager::ports::project_repository_port::ProjectFilter::ById(std::string::String::from(project_id)),
+            hexser::ports::repository::FindOptions::default(),
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to load project settings: {:?}", e))?;
+
+    std::result::Result::Ok(projects.into_iter().next().map(|project| project.settings).unwrap_or_default())
+}
+
 /// Helper function to ingest PRD content as artifacts for RAG.
 ///
 /// This function:
@@ -236,6 +554,8 @@ pub async fn execute(prd_file: &str) -> anyhow::Result<()> {
 /// * `db_url` - SQLite database URL
 /// * `provider` - LLM provider name (for embedding model selection)
 /// * `model_name` - Model name (for logging purposes)
+/// * `offline` - Refuse an embedding provider that is not local
+/// * `auto_pull` - Pull a missing Ollama embedding model and retry
 ///
 /// # Returns
 ///
@@ -253,9 +573,12 @@ async fn ingest_prd_artifacts(
     db_url: &str,
     provider: &str,
     _model_name: &str,
+    offline: bool,
+    auto_pull: bool,
+    retry_policy: task_orchestrator::infrastructure::retry_policy::RetryPolicy,
 ) -> std::result::Result<usize, String> {
-    // 0. Ensure default project exists (for foreign key constraint)
-    let project_id = String::from("default-project");
+    // 0. Ensure the PRD's project exists (for foreign key constraint)
+    let project_id = prd.project_id.clone();
     let task_adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(db_url)
         .await
         .map_err(|e| std::format!("Failed to connect task adapter: {}", e))?;
@@ -263,13 +586,13 @@ async fn ingest_prd_artifacts(
     // Create default project if it doesn't exist
     sqlx::query("INSERT OR IGNORE INTO projects (id, name, description, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5)")
         .bind(&project_id)
-        .bind("Default Project")
-        .bind("Auto-created default project for artifact storage")
+        .bind(&project_id)
+        .bind("Auto-created project for artifact storage")
         .bind(chrono::Utc::now().to_rfc3339())
         .bind(chrono::Utc::now().to_rfc3339())
         .execute(task_adapter.pool())
         .await
-        .map_err(|e| std::format!("Failed to create default project: {}", e))?;
+        .map_err(|e| std::format!("Failed to create project '{}': {}", project_id, e))?;
 
     // 1. Create artifact repository adapter
     let artifact_adapter = task_manager::adapters::sqlite_artifact_adapter::SqliteArtifactAdapter::connect_and_init(db_url)
@@ -278,7 +601,11 @@ async fn ingest_prd_artifacts(
 
     // 2. Create embedding adapter using provider factory
     let provider_factory = task_orchestrator::adapters::provider_factory::ProviderFactory::new(provider, "default")
-        .map_err(|e| std::format!("Failed to create provider factory: {}", e))?;
+        .map_err(|e| std::format!("Failed to create provider factory: {}", e))?
+        .with_offline(offline)
+        .map_err(|e| std::format!("Failed to create provider factory: {}", e))?
+        .with_auto_pull(auto_pull)
+        .with_retry_policy(retry_policy);
 
     let embedding_adapter = provider_factory.create_embedding_adapter()
         .map_err(|e| std::format!("Failed to create embedding adapter: {}", e))?;
@@ -291,7 +618,7 @@ async fn ingest_prd_artifacts(
 
     // 4. Ingest PRD content
     let artifacts = artifact_service.ingest_prd(
-        String::from("default-project"), // Use same project ID as PRD parser
+        project_id,
         prd.id.clone(),
         prd_content.to_string(),
     ).await?;
@@ -301,6 +628,179 @@ async fn ingest_prd_artifacts(
 
 #[cfg(test)]
 mod tests {
+    const PRD_CONTENT: &str = "# Rigger\n\n## Objectives\n- Sync\n- Board\n";
+
+    fn generated_tasks() -> std::vec::Vec<task_manager::domain::task::Task> {
+        ["Design API", "Write migration", "Build board"]
+            .iter()
+            .zip(["Objectives > Sync", "Objectives > Sync", "Objectives > Board"])
+            .map(|(title, section)| {
+                let action = transcript_extractor::domain::action_item::ActionItem {
+                    title: std::string::String::from(*title),
+                    assignee: std::option::Option::None,
+                    due_date: std::option::Option::None,
+                };
+                let mut task = task_manager::domain::task::Task::from_action_item(&action, std::option::Option::None);
+                task.source_section = std::option::Option::Some(std::string::String::from(section));
+                task
+            })
+            .collect()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_retried_parse_skips_tasks_already_saved() {
+        // Test: Validates re-generated tasks get the same keys and only tasks missing from the first, partial run are saved.
+        // Justification: Retrying a parse after a partial failure must not create duplicate tasks.
+        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init("sqlite::memory:").await.unwrap();
+        let settings = task_manager::domain::project_settings::ProjectSettings::default();
+        let mut first_run = generated_tasks();
+        super::assign_idempotency_keys(&mut first_run, "default-project", PRD_CONTENT);
+        first_run.truncate(1);
+        super::save_new_tasks(&adapter, first_run.clone(), &settings).unwrap();
+
+        let mut retry = generated_tasks();
+        super::assign_idempotency_keys(&mut retry, "default-project", PRD_CONTENT);
+        let keys: std::vec::Vec<_> = retry.iter().map(|task| task.idempotency_key.clone()).collect();
+        let saved = super::save_new_tasks(&adapter, retry, &settings).unwrap();
+
+        std::assert_ne!(keys[0], keys[1]);
+        std::assert_eq!(keys[0], first_run[0].idempotency_key);
+        let saved_titles: std::vec::Vec<_> = saved.iter().map(|task| task.title.as_str()).collect();
+        std::assert_eq!(saved_titles, ["Write migration", "Build board"]);
+        let all = adapter
+            .find_async(&task_manager::ports::task_repository_port::TaskFilter::All, hexser::ports::repository::FindOptions::default())
+            .await
+            .unwrap();
+        std::assert_eq!(all.len(), 3);
+    }
+
+    #[test]
+    fn test_idempotency_keys_depend_on_project_and_content() {
+        // Test: Validates keys differ across projects and across PRDs with the same title but different content.
+        // Justification: Keys built from the title alone made unrelated PRDs skip each other's tasks.
+        let keys = |project: &str, content: &str| {
+            let mut tasks = generated_tasks();
+            super::assign_idempotency_keys(&mut tasks, project, content);
+            tasks.into_iter().map(|task| task.idempotency_key).collect::<std::vec::Vec<_>>()
+        };
+
+        std::assert_eq!(keys("default-project", PRD_CONTENT), keys("default-project", PRD_CONTENT));
+        std::assert_ne!(keys("default-project", PRD_CONTENT)[0], keys("other-project", PRD_CONTENT)[0]);
+        std::assert_ne!(keys("default-project", PRD_CONTENT)[0], keys("default-project", "# Rigger\n\n## Objectives\n- Sync\n")[0]);
+    }
+
+    #[test]
+    fn test_needs_decomposition_respects_threshold_and_max_depth() {
+        // Test: Validates only tasks at or above the configured threshold are decomposed, and not beyond max_depth.
+        // Justification: `rig parse` used a hardcoded threshold of 7 and ignored the decomposition config.
+        let config = task_orchestrator::infrastructure::config::DecompositionConfig { complexity_threshold: 8, max_depth: 2 };
+        let mut tasks = generated_tasks();
+        tasks[0].complexity = std::option::Option::Some(7);
+        tasks[1].complexity = std::option::Option::Some(8);
+        tasks[2].complexity = std::option::Option::None;
+
+        std::assert!(!super::needs_decomposition(&tasks[0], 0, &config));
+        std::assert!(super::needs_decomposition(&tasks[1], 0, &config));
+        std::assert!(super::needs_decomposition(&tasks[1], 1, &config));
+        std::assert!(!super::needs_decomposition(&tasks[1], 2, &config));
+        std::assert!(!super::needs_decomposition(&tasks[2], 0, &config));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_generated_tasks_are_triaged_and_get_project_defaults() {
+        // Test: Validates PRD-derived tasks go through create: project defaults fill empty fields, triage runs, invalid tasks are skipped.
+        // Justification: Generated tasks must get the same checks and defaults as tasks added by hand.
+        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init("sqlite::memory:").await.unwrap();
+        let settings = task_manager::domain::project_settings::ProjectSettings {
+            default_assignee: std::option::Option::Some(std::string::String::from("Backend Developer")),
+            default_priority: std::option::Option::None,
+        };
+        let mut tasks = generated_tasks();
+        tasks[2].due_date = std::option::Option::Some(std::string::String::from("next Friday"));
+
+        let saved = super::save_new_tasks(&adapter, tasks, &settings).unwrap();
+
+        std::assert_eq!(saved.len(), 2);
+        for task in &saved {
+            std::assert_eq!(task.assignee.as_deref(), std::option::Option::Some("Backend Developer"));
+            std::assert!(task.priority.is_some());
+            std::assert!(!task.revisions.is_empty());
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_refreshed_tasks_are_saved_with_a_revision() {
+        // Test: Validates tasks updated by an incremental re-parse are saved through refresh_from_prd with a revision.
+        // Justification: Updated tasks used to be written with save_async, bypassing validation and history.
+        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init("sqlite::memory:").await.unwrap();
+        let saved = super::save_new_tasks(&adapter, generated_tasks(), &task_manager::domain::project_settings::ProjectSettings::default()).unwrap();
+        let mut refreshed = saved[0].clone();
+        refreshed.title = std::string::String::from("Design sync API");
+        let mut blank = saved[1].clone();
+        blank.title = std::string::String::new();
+
+        let updated = super::save_refreshed_tasks(&adapter, std::vec![refreshed, blank]).unwrap();
+
+        std::assert_eq!(updated.len(), 1);
+        let stored = adapter
+            .find_one_async(&task_manager::ports::task_repository_port::TaskFilter::ById(saved[0].id.clone()))
+            .await
+            .unwrap()
+            .unwrap();
+        std::assert_eq!(stored.title, "Design sync API");
+        std::assert_eq!(stored.revisions.last().unwrap().change_description, "Refreshed from re-parsed PRD");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_sub_tasks_are_created_through_create() {
+        // Test: Validates decomposed sub-tasks saved via save_new_tasks keep their parent link and are triaged.
+        // Justification: Sub-tasks used to be saved directly and skipped the checks create() applies.
+        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init("sqlite::memory:").await.unwrap();
+        let mut subtasks = generated_tasks();
+        for subtask in subtasks.iter_mut() {
+            subtask.parent_task_id = std::option::Option::Some(std::string::String::from("parent-1"));
+        }
+
+        let saved = super::save_new_tasks(&adapter, subtasks, &task_manager::domain::project_settings::ProjectSettings::default()).unwrap();
+
+        std::assert_eq!(saved.len(), 3);
+        let stored = adapter
+            .find_one_async(&task_manager::ports::task_repository_port::TaskFilter::ById(saved[0].id.clone()))
+            .await
+            .unwrap()
+            .unwrap();
+        std::assert_eq!(stored.parent_task_id.as_deref(), std::option::Option::Some("parent-1"));
+        std::assert!(stored.priority.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_progress_reports_each_generated_task_while_parsing() {
+        // Test: Validates follow() reports every TaskGenerated update once, while the parse is still running.
+        // Justification: Progress must appear as tasks are generated, not after the parser returns.
+        let (tx, mut rx) = tokio::sync::mpsc::channel(1);
+        let parse = async move {
+            for title in ["Design API", "Write migration", "Build board"] {
+                // With a one-slot channel, a second send only completes once follow() drained the first
+                tx.send(task_orchestrator::adapters::rig_prd_parser_adapter::PRDGenUpdate::TaskGenerated {
+                    title: std::string::String::from(title),
+                    description: std::string::String::new(),
+                    assignee: std::option::Option::None,
+                    priority: std::option::Option::None,
+                    complexity: std::option::Option::None,
+                })
+                .await
+                .unwrap();
+            }
+            3
+        };
+        let mut progress = super::ParseProgress::new(true);
+
+        let generated = progress.follow(parse, &mut rx, false).await;
+
+        std::assert_eq!(generated, 3);
+        std::assert_eq!(progress.generated, 3);
+    }
+
     #[tokio::test]
     #[serial_test::serial]
     async fn test_parse_fails_without_init() {
@@ -312,7 +812,7 @@ mod tests {
         let original_dir = std::env::current_dir().unwrap();
         std::env::set_current_dir(&temp_dir).unwrap();
 
-        let result = super::execute("nonexistent.md").await;
+        let result = super::execute("nonexistent.md", false, false, false).await;
         std::assert!(result.is_err(), "Parse should fail if .rigdoesn't exist");
 
         // Cleanup (ignore errors if already cleaned)
@@ -335,7 +835,7 @@ mod tests {
         crate::commands::init::execute().await.unwrap();
 
         // Try to parse nonexistent file
-        let result = super::execute("nonexistent.md").await;
+        let result = super::execute("nonexistent.md", false, false, false).await;
         std::assert!(result.is_err(), "Parse should fail if PRD file doesn't exist");
         std::assert!(result.unwrap_err().to_string().contains("not found"));
 
@@ -363,6 +863,7 @@ mod tests {
             tech_stack: std::vec![String::from("Rust")],
             constraints: std::vec![String::from("Must be fast")],
             raw_content: String::from("# Test PRD\n\nBuild a feature."),
+            requirements: std::vec::Vec::new(),
             created_at: chrono::Utc::now(),
         };
 
@@ -375,6 +876,9 @@ mod tests {
             &db_url,
             "ollama",
             "llama3.2:latest",
+            false,
+            false,
+            task_orchestrator::infrastructure::retry_policy::RetryPolicy::default(),
         ).await;
 
         // Cleanup (ignore errors if already cleaned)
@@ -0,0 +1,148 @@
+//! Implementation of the 'rig plan --capacity N [--assignee NAME]' command.
+//!
+//! Proposes a sprint: the ready tasks (open and unblocked) whose estimates
+//! fit in N points, picked by priority and then smallest estimate (see
+//! `task_manager::domain::services::capacity_plan`). Ready tasks that did not
+//! fit, and ready tasks without an estimate, are listed after the selection.
+//!
+//! Revision History
+//! - 2026-10-17T16:50:00Z @AI: Initial plan command.
+
+/// Executes the 'rig plan' command.
+///
+/// # Arguments
+///
+/// * `capacity` - Points available in the sprint
+/// * `assignee` - Only plan tasks assigned to this persona
+///
+/// # Errors
+///
+/// Returns an error if the capacity is negative, .rigger doesn't exist, or
+/// the database query fails.
+pub async fn execute(capacity: f32, assignee: std::option::Option<&str>) -> anyhow::Result<()> {
+    if !(capacity.is_finite() && capacity >= 0.0) {
+        anyhow::bail!("Invalid capacity: {}. Expected a non-negative number of points.", capacity);
+    }
+
+    let current_dir = std::env::current_dir()?;
+    let rigger_dir = current_dir.join(".rigger");
+
+    if !rigger_dir.exists() {
+        anyhow::bail!(
+            ".rigger directory not found.\nRun 'rig init' first to initialize the project."
+        );
+    }
+
+    let db_url = crate::services::database_url::url_for(&rigger_dir.join("tasks.db"));
+    let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect to database: {}", e))?;
+
+    let tasks = adapter
+        .find_async(
+            &task_manager::ports::task_repository_port::TaskFilter::All,
+            hexser::ports::repository::FindOptions {
+                sort: std::option::Option::None,
+                limit: std::option::Option::None,
+                offset: std::option::Option::None,
+            },
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    let plan = task_manager::domain::services::capacity_plan::plan_capacity(&tasks, capacity, assignee);
+    print!("{}", render_plan(&plan));
+    std::result::Result::Ok(())
+}
+
+/// Renders a capacity plan: the selection with its total, then deferred and unestimated tasks.
+///
+/// # Arguments
+///
+/// * `plan` - The plan to render
+///
+/// # Returns
+///
+/// The plan, ending with a newline.
+fn render_plan(plan: &task_manager::domain::services::capacity_plan::CapacityPlan<'_>) -> String {
+    let mut output = std::format!(
+        "Planned {} of {} points ({} task(s)):\n",
+        format_points(plan.planned_points()),
+        format_points(plan.capacity),
+        plan.selected.len()
+    );
+    if plan.selected.is_empty() {
+        output.push_str("  (no estimated ready task fits)\n");
+    }
+    for task in &plan.selected {
+        output.push_str(&task_line(task));
+    }
+
+    if !plan.deferred.is_empty() {
+        output.push_str(&std::format!("\nDid not fit ({}):\n", plan.deferred.len()));
+        for task in &plan.deferred {
+            output.push_str(&task_line(task));
+        }
+    }
+
+    if !plan.unestimated.is_empty() {
+        output.push_str(&std::format!("\nUnestimated ({}):\n", plan.unestimated.len()));
+        for task in &plan.unestimated {
+            output.push_str(&std::format!("  {}  {}\n", task.id, task.title));
+        }
+        output.push_str("Add estimates with `rig add --estimate` to include them in plans.\n");
+    }
+    output
+}
+
+/// Renders one estimated task as "  [5 pts] (high) <id>  <title>".
+fn task_line(task: &task_manager::domain::task::Task) -> String {
+    let priority = task.priority.map(|p| std::format!(" ({})", p.as_str())).unwrap_or_default();
+    std::format!(
+        "  [{} pts]{} {}  {}\n",
+        format_points(task.estimate.unwrap_or(0.0)),
+        priority,
+        task.id,
+        task.title
+    )
+}
+
+/// Formats points without a trailing ".0" for whole numbers (e.g. "3", "0.5").
+fn format_points(points: f32) -> String {
+    if points.fract() == 0.0 {
+        std::format!("{:.0}", points)
+    } else {
+        std::format!("{}", points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_render_plan_lists_selection_deferred_and_unestimated() {
+        // Test: Validates the output totals the selection against capacity and lists tasks that did not fit and unestimated tasks separately.
+        // Justification: Unestimated work must stay visible so it can be sized before the sprint starts.
+        let item = |title: &str| transcript_extractor::domain::action_item::ActionItem {
+            title: String::from(title),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let sized = |id: &str, estimate: f32| {
+            let mut task = task_manager::domain::task::Task::from_action_item(&item(id), std::option::Option::None);
+            task.id = String::from(id);
+            task.estimate = std::option::Option::Some(estimate);
+            task
+        };
+        let mut unsized = task_manager::domain::task::Task::from_action_item(&item("Spike caching"), std::option::Option::None);
+        unsized.id = String::from("spike");
+        let tasks = std::vec![sized("api", 3.0), sized("docs", 1.5), sized("migration", 8.0), unsized];
+
+        let plan = task_manager::domain::services::capacity_plan::plan_capacity(&tasks, 5.0, std::option::Option::None);
+        let output = super::render_plan(&plan);
+
+        std::assert!(output.starts_with("Planned 4.5 of 5 points (2 task(s)):"), "{}", output);
+        std::assert!(output.contains("[1.5 pts] docs"), "{}", output);
+        std::assert!(output.contains("Did not fit (1):\n  [8 pts] migration"), "{}", output);
+        std::assert!(output.contains("Unestimated (1):\n  spike  Spike caching"), "{}", output);
+    }
+}
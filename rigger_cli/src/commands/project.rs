@@ -0,0 +1,181 @@
+//! Implementation of the 'rig project set' and 'rig project show' commands.
+//!
+//! Edits the task defaults (assignee and priority) of a project, which
+//! `rig add`, `rig parse`, and transcript extraction apply to new tasks that
+//! leave them empty. The project row is created on first use, so
+//! "default-project" can be configured right after `rig init`.
+//!
+//! Revision History
+//! - 2026-10-18T03:30:00Z @AI: Initial project set/show commands for project task defaults.
+
+/// Executes the 'rig project set' command.
+///
+/// Only the given defaults change; an empty value clears that default.
+///
+/// # Arguments
+///
+/// * `project_id` - Project to configure (created if it does not exist)
+/// * `default_assignee` - Assignee for new tasks without one ("" clears it)
+/// * `default_priority` - Priority for new tasks without one (aliases accepted, "" clears it)
+///
+/// # Returns
+///
+/// The project's settings after the change.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The priority is not a recognized value
+/// - .rigger directory doesn't exist (run 'rig init' first)
+/// - Database operations fail
+pub async fn set(
+    project_id: &str,
+    default_assignee: std::option::Option<&str>,
+    default_priority: std::option::Option<&str>,
+) -> anyhow::Result<task_manager::domain::project_settings::ProjectSettings> {
+    let default_priority = match default_priority.map(str::trim) {
+        std::option::Option::Some("") => std::option::Option::Some(std::option::Option::None),
+        std::option::Option::Some(p) => std::option::Option::Some(std::option::Option::Some(
+            p.parse::<task_manager::domain::priority::Priority>().map_err(|e| anyhow::anyhow!(e))?,
+        )),
+        std::option::Option::None => std::option::Option::None,
+    };
+
+    let adapter = connect().await?;
+    let mut project = find_project(&adapter, project_id).await?.unwrap_or_else(|| {
+        let mut project = task_manager::domain::project::Project::new(std::string::String::from(project_id), std::option::Option::None);
+        project.id = std::string::String::from(project_id);
+        project
+    });
+
+    if let std::option::Option::Some(assignee) = default_assignee.map(str::trim) {
+        project.settings.default_assignee = (!assignee.is_empty()).then(|| std::string::String::from(assignee));
+    }
+    if let std::option::Option::Some(priority) = default_priority {
+        project.settings.default_priority = priority;
+    }
+
+    let settings = project.settings.clone();
+    adapter
+        .save_async(project)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to save project settings: {:?}", e))?;
+
+    println!("✓ Updated task defaults of {}", project_id);
+    print_settings(&settings);
+    std::result::Result::Ok(settings)
+}
+
+/// Executes the 'rig project show' command.
+///
+/// # Arguments
+///
+/// * `project_id` - Project whose task defaults are printed
+///
+/// # Errors
+///
+/// Returns an error if .rigger doesn't exist or the database cannot be queried.
+pub async fn show(project_id: &str) -> anyhow::Result<()> {
+    let adapter = connect().await?;
+    match find_project(&adapter, project_id).await? {
+        std::option::Option::Some(project) => {
+            println!("Task defaults of {}", project_id);
+            print_settings(&project.settings);
+        }
+        std::option::Option::None => {
+            println!("Project {} has no task defaults yet. Set them with 'rig project set'.", project_id);
+        }
+    }
+    std::result::Result::Ok(())
+}
+
+/// Opens the project table of the current directory's .rigger database.
+async fn connect() -> anyhow::Result<task_manager::adapters::sqlite_project_adapter::SqliteProjectAdapter> {
+    let rigger_dir = std::env::current_dir()?.join(".rigger");
+    if !rigger_dir.exists() {
+        anyhow::bail!(
+            ".rigger directory not found.\nRun 'rig init' first to initialize the project."
+        );
+    }
+
+    let db_url = crate::services::database_url::url_for(&rigger_dir.join("tasks.db"));
+    task_manager::adapters::sqlite_project_adapter::SqliteProjectAdapter::connect_and_init(&db_url)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect to database: {}", e))
+}
+
+async fn find_project(
+    adapter: &task_manager::adapters::sqlite_project_adapter::SqliteProjectAdapter,
+    project_id: &str,
+) -> anyhow::Result<std::option::Option<task_manager::domain::project::Project>> {
+    let projects = adapter
+        .find_async(
+            &task_manager::ports::project_repository_port::ProjectFilter::ById(std::string::String::from(project_id)),
+            hexser::ports::repository::FindOptions::default(),
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to load project: {:?}", e))?;
+    std::result::Result::Ok(projects.into_iter().next())
+}
+
+fn print_settings(settings: &task_manager::domain::project_settings::ProjectSettings) {
+    println!("  Default assignee: {}", settings.default_assignee.as_deref().unwrap_or("(none)"));
+    println!(
+        "  Default priority: {}",
+        settings.default_priority.map(|p| p.to_string()).unwrap_or_else(|| std::string::String::from("(none)"))
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test(flavor = "multi_thread")]
+    #[serial_test::serial]
+    async fn test_set_creates_default_project_and_add_applies_it() {
+        // Test: Validates `rig project set` creates the default-project row, and `rig add` then fills empty fields from it.
+        // Justification: No command wrote project settings, so the defaults applied by add and parse were always empty.
+        let temp_dir = std::env::temp_dir().join(std::format!("rigger_test_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir(&temp_dir).unwrap();
+
+        let original_dir = std::env::current_dir().unwrap();
+        std::env::set_current_dir(&temp_dir).unwrap();
+
+        crate::commands::init::execute().await.unwrap();
+        let first = super::set("default-project", std::option::Option::Some("Backend Developer"), std::option::Option::Some("p0")).await;
+        let cleared = super::set("default-project", std::option::Option::None, std::option::Option::Some("")).await;
+        let added = crate::commands::add::execute(
+            "Rotate API keys",
+            std::option::Option::None,
+            std::option::Option::None,
+            std::option::Option::None,
+            std::option::Option::None,
+            std::option::Option::None,
+            std::option::Option::None,
+            &[],
+        )
+        .await;
+        let db_url = std::format!("sqlite:{}", temp_dir.join(".rigger").join("tasks.db").display());
+        let loaded = crate::commands::parse::load_project_settings(&db_url, "default-project").await;
+        let saved = match &added {
+            std::result::Result::Ok(id) => match task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url).await {
+                std::result::Result::Ok(adapter) => adapter
+                    .find_one_async(&task_manager::ports::task_repository_port::TaskFilter::ById(id.clone()))
+                    .await
+                    .map_err(|e| e.to_string()),
+                std::result::Result::Err(e) => std::result::Result::Err(e),
+            },
+            std::result::Result::Err(_) => std::result::Result::Ok(std::option::Option::None),
+        };
+
+        let _ = std::env::set_current_dir(original_dir);
+        let _ = std::fs::remove_dir_all(&temp_dir);
+
+        std::assert_eq!(first.unwrap().default_priority, std::option::Option::Some(task_manager::domain::priority::Priority::High));
+        let cleared = cleared.unwrap();
+        std::assert_eq!(cleared.default_assignee.as_deref(), std::option::Option::Some("Backend Developer"));
+        std::assert_eq!(cleared.default_priority, std::option::Option::None);
+        std::assert_eq!(loaded.unwrap(), cleared);
+        added.unwrap();
+        let saved = saved.unwrap().expect("the added task is saved");
+        std::assert_eq!(saved.agent_persona.as_deref(), std::option::Option::Some("Backend Developer"));
+    }
+}
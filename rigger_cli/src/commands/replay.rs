@@ -0,0 +1,54 @@
+//! Implementation of the 'rig replay' command.
+//!
+//! Loads a run log recorded by the orchestrator (see
+//! `task_orchestrator::domain::run_log`) and re-executes the run, serving the
+//! recorded provider responses instead of calling any provider. The replay's
+//! graph trace is printed as it runs, and its final task is compared with the
+//! recorded outcome, so a misbehaving run can be reproduced and stepped
+//! through offline.
+//!
+//! Revision History
+//! - 2026-10-17T08:10:00Z @AI: Initial replay command.
+
+/// Executes the 'rig replay' command.
+///
+/// # Arguments
+///
+/// * `log_path` - Path of the run log to replay
+///
+/// # Errors
+///
+/// Returns an error if the log cannot be read, the replay diverges from the
+/// recorded provider calls, or its outcome differs from the recorded one.
+pub async fn execute(log_path: &str) -> anyhow::Result<()> {
+    let log = task_orchestrator::domain::run_log::RunLog::load(std::path::Path::new(log_path))
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+    println!("Replaying run {}", log.correlation_id);
+    println!("  Task: {}", log.task.title);
+    println!("  Recorded provider calls: {}", log.provider_calls.len());
+    println!();
+
+    let (tx, mut rx) = tokio::sync::mpsc::channel(64);
+    let printer = tokio::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            println!("  {}", event);
+        }
+    });
+    let replayed = task_orchestrator::use_cases::run_task_with_flow::replay_run_log(&log, std::option::Option::Some(tx)).await;
+    let _ = printer.await;
+    println!();
+
+    match &replayed {
+        std::result::Result::Ok(task) => println!("Final status: {:?}", task.status),
+        std::result::Result::Err(e) => println!("Run failed: {}", e),
+    }
+    if log.outcome.is_none() {
+        println!("(The log has no recorded outcome to compare against.)");
+    } else if log.outcome_matches(&replayed) {
+        println!("✓ Replay matches the recorded outcome");
+    } else {
+        anyhow::bail!("Replay outcome differs from the recorded outcome");
+    }
+    std::result::Result::Ok(())
+}
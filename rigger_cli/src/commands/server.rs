@@ -13,15 +13,40 @@
 //!
 //! - `list_tasks`: List tasks with optional filters
 //! - `add_task`: Create a new task
-//! - `update_task`: Update task status/priority
+//! - `update_task`: Update task status/assignee/estimate
 //! - `parse_prd`: Parse PRD file and generate tasks
+//! - `health`: Report readiness (see below)
 //!
 //! # Supported Resources
 //!
 //! - `tasks.json`: Current task list from database
 //! - `config.json`: Configuration settings
 //!
+//! # Authentication
+//!
+//! If `server.auth_token_env` is set in .rigger/config.json, every request must
+//! carry the token. stdio has no headers, so it goes in an `authorization`
+//! member of the request object: `{"jsonrpc":"2.0","authorization":"Bearer <token>",...}`.
+//! Other requests get error code -32001 (unauthenticated).
+//!
+//! # Health
+//!
+//! `health` returns `{"status": ..., "database": ..., "providers": [...]}`.
+//! The status is `starting` until the startup checks finish, `ready` when the
+//! task database is reachable and at least one configured provider can be
+//! constructed, and `degraded` otherwise. Each call after startup re-runs the
+//! checks; no request is sent to any provider.
+//!
 //! Revision History
+//! - 2026-10-17T22:20:00Z @AI: Track time spent in progress when update_task changes the status.
+//! - 2026-10-17T22:10:00Z @AI: Open request and health-check pools with the configured database.pool_size.
+//! - 2026-10-17T16:50:00Z @AI: Accept an optional estimate in add_task and update_task.
+//! - 2026-10-17T14:10:00Z @AI: Open the database through services::database_url so --db / RIGGER_DB apply.
+//! - 2026-10-17T13:50:00Z @AI: Read the config path from services::config_path, honoring --config and RIGGER_CONFIG.
+//! - 2026-10-17T01:30:00Z @AI: Validate OpenAI-compatible providers against their configured base URL.
+//! - 2026-10-16T22:10:00Z @AI: Add `health` method reporting starting/ready/degraded from database and provider checks.
+//! - 2026-10-16T20:10:00Z @AI: Handle each request inside a `run` span with its own correlation ID.
+//! - 2026-10-16T19:30:00Z @AI: Add optional bearer-token auth (server.auth_token_env) via an `authorization` request member.
 //! - 2025-11-23T18:30:00Z @AI: Implement MCP server for Phase 4 Sprint 8.
 //! - 2025-11-22T16:40:00Z @AI: Placeholder server command for Sprint 0.2.
 
@@ -36,8 +61,14 @@ struct JsonRpcRequest {
     #[serde(default)]
     params: serde_json::Value,
     id: serde_json::Value,
+    /// Bearer credential ("Bearer <token>"), required when server auth is configured
+    #[serde(default)]
+    authorization: std::option::Option<String>,
 }
 
+/// JSON-RPC error code for requests without a valid bearer token.
+const UNAUTHENTICATED: i32 = -32001;
+
 /// JSON-RPC 2.0 response structure.
 #[derive(Debug, Serialize)]
 struct JsonRpcResponse {
@@ -92,13 +123,28 @@ impl JsonRpcResponse {
 ///
 /// Returns an error if server initialization fails or I/O errors occur.
 pub async fn execute() -> anyhow::Result<()> {
+    let config_path = crate::services::config_path::current()?;
+    let auth_token = crate::services::server_auth::load_expected_token(&config_path)?;
+
     eprintln!("🚀 Rigger MCP Server starting...");
     eprintln!("   Protocol: JSON-RPC 2.0 over stdio");
     eprintln!("   Listening on stdin for requests");
     eprintln!("   Sending responses to stdout");
     eprintln!("   Logging to stderr");
+    eprintln!("   Auth: {}", if auth_token.is_some() { "Bearer token required" } else { "Disabled" });
     eprintln!();
 
+    // Readiness checks run in the background; `health` reports "starting" until they finish
+    let health = std::sync::Arc::new(HealthMonitor::new(
+        crate::services::database_url::url_for(&config_path.with_file_name("tasks.db")),
+        config_path.clone(),
+    ));
+    let startup_health = std::sync::Arc::clone(&health);
+    tokio::spawn(async move {
+        let report = startup_health.refresh().await;
+        eprintln!("🩺 Health: {:?}", report.status);
+    });
+
     let stdin = tokio::io::stdin();
     let mut reader = tokio::io::BufReader::new(stdin);
     let mut stdout = tokio::io::stdout();
@@ -125,7 +171,7 @@ pub async fn execute() -> anyhow::Result<()> {
 
                 // Parse JSON-RPC request
                 let response = match serde_json::from_str::<JsonRpcRequest>(trimmed) {
-                    Ok(request) => handle_request(request).await,
+                    Ok(request) => dispatch(request, auth_token.as_deref(), &health).await,
                     Err(e) => {
                         eprintln!("❌ Parse error: {}", e);
                         JsonRpcResponse::error(
@@ -155,8 +201,33 @@ pub async fn execute() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Rejects the request if a token is configured and the request lacks it,
+/// otherwise hands it to `handle_request` inside a `run` span carrying a
+/// fresh correlation ID.
+async fn dispatch(
+    request: JsonRpcRequest,
+    expected_token: std::option::Option<&str>,
+    health: &HealthMonitor,
+) -> JsonRpcResponse {
+    let authorized = match expected_token {
+        Some(expected) => crate::services::server_auth::is_authorized(request.authorization.as_deref(), expected),
+        None => true,
+    };
+    if !authorized {
+        eprintln!("🔒 Rejected unauthenticated request: {}", request.method);
+        return JsonRpcResponse::error(
+            request.id,
+            UNAUTHENTICATED,
+            String::from("Unauthenticated: missing or invalid bearer token"),
+        );
+    }
+    let correlation_id = task_orchestrator::domain::correlation_id::CorrelationId::generate();
+    let span = task_orchestrator::infrastructure::telemetry::run_span(&correlation_id);
+    task_manager::infrastructure::telemetry::timed(span, handle_request(request, health)).await
+}
+
 /// Handles a JSON-RPC request and routes it to the appropriate handler.
-async fn handle_request(request: JsonRpcRequest) -> JsonRpcResponse {
+async fn handle_request(request: JsonRpcRequest, health: &HealthMonitor) -> JsonRpcResponse {
     eprintln!("🔧 Handling method: {}", request.method);
 
     match request.method.as_str() {
@@ -165,6 +236,7 @@ async fn handle_request(request: JsonRpcRequest) -> JsonRpcResponse {
         "update_task" => handle_update_task(request.id, request.params).await,
         "parse_prd" => handle_parse_prd(request.id, request.params).await,
         "get_resource" => handle_get_resource(request.id, request.params).await,
+        "health" => handle_health(request.id, health).await,
         _ => JsonRpcResponse::error(
             request.id,
             -32601,
@@ -205,9 +277,7 @@ async fn handle_list_tasks(
     eprintln!("   Assignee filter: {:?}", params.assignee);
 
     // Connect to database
-    let adapter = match task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(
-        &std::format!("sqlite:{}", db_path.display())
-    ).await {
+    let adapter = match connect_tasks(&db_path).await {
         Ok(a) => a,
         Err(e) => {
             return JsonRpcResponse::error(id, -32603, format!("Failed to connect to database: {}", e));
@@ -268,6 +338,7 @@ async fn handle_list_tasks(
                 "status": format!("{:?}", task.status),
                 "agent_persona": task.agent_persona,
                 "due_date": task.due_date,
+                "estimate": task.estimate,
                 "created_at": task.created_at.to_rfc3339(),
                 "updated_at": task.updated_at.to_rfc3339(),
             })
@@ -291,6 +362,8 @@ async fn handle_add_task(id: serde_json::Value, params: serde_json::Value) -> Js
         assignee: std::option::Option<String>,
         #[serde(default)]
         due_date: std::option::Option<String>,
+        #[serde(default)]
+        estimate: std::option::Option<f32>,
     }
 
     let params: AddTaskParams = match serde_json::from_value(params) {
@@ -300,6 +373,12 @@ async fn handle_add_task(id: serde_json::Value, params: serde_json::Value) -> Js
         }
     };
 
+    if let std::option::Option::Some(estimate) = params.estimate
+        && !(estimate.is_finite() && estimate >= 0.0)
+    {
+        return JsonRpcResponse::error(id, -32602, format!("Invalid estimate: {}", estimate));
+    }
+
     eprintln!("   Creating task: {}", params.title);
 
     // Create task from action item
@@ -309,7 +388,8 @@ async fn handle_add_task(id: serde_json::Value, params: serde_json::Value) -> Js
         due_date: params.due_date,
     };
 
-    let task = task_manager::domain::task::Task::from_action_item(&action_item, None);
+    let mut task = task_manager::domain::task::Task::from_action_item(&action_item, None);
+    task.estimate = params.estimate;
 
     eprintln!("   Task ID: {}", task.id);
 
@@ -322,9 +402,7 @@ async fn handle_add_task(id: serde_json::Value, params: serde_json::Value) -> Js
     };
 
     // Connect to database
-    let adapter = match task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(
-        &std::format!("sqlite:{}", db_path.display())
-    ).await {
+    let adapter = match connect_tasks(&db_path).await {
         Ok(a) => a,
         Err(e) => {
             return JsonRpcResponse::error(id, -32603, format!("Failed to connect to database: {}", e));
@@ -362,6 +440,8 @@ async fn handle_update_task(
         status: std::option::Option<String>,
         #[serde(default)]
         assignee: std::option::Option<String>,
+        #[serde(default)]
+        estimate: std::option::Option<f32>,
     }
 
     let params: UpdateTaskParams = match serde_json::from_value(params) {
@@ -383,9 +463,7 @@ async fn handle_update_task(
     };
 
     // Connect to database
-    let adapter = match task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(
-        &std::format!("sqlite:{}", db_path.display())
-    ).await {
+    let adapter = match connect_tasks(&db_path).await {
         Ok(a) => a,
         Err(e) => {
             return JsonRpcResponse::error(id, -32603, format!("Failed to connect to database: {}", e));
@@ -423,6 +501,7 @@ async fn handle_update_task(
                 return JsonRpcResponse::error(id, -32602, format!("Invalid status: {}", status_str));
             }
         };
+        task.track_status_time(&status, chrono::Utc::now());
         task.status = status;
     }
 
@@ -430,6 +509,13 @@ async fn handle_update_task(
         task.agent_persona = std::option::Option::Some(assignee);
     }
 
+    if let std::option::Option::Some(estimate) = params.estimate {
+        if !(estimate.is_finite() && estimate >= 0.0) {
+            return JsonRpcResponse::error(id, -32602, format!("Invalid estimate: {}", estimate));
+        }
+        task.estimate = std::option::Option::Some(estimate);
+    }
+
     task.updated_at = chrono::Utc::now();
 
     // Save updated task
@@ -533,9 +619,7 @@ async fn handle_get_resource(
             };
 
             // Connect to database
-            let adapter = match task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(
-                &std::format!("sqlite:{}", db_path.display())
-            ).await {
+            let adapter = match connect_tasks(&db_path).await {
                 Ok(a) => a,
                 Err(e) => {
                     return JsonRpcResponse::error(id, -32603, format!("Failed to connect to database: {}", e));
@@ -599,6 +683,192 @@ async fn handle_get_resource(
     }
 }
 
+/// Handles the 'health' method.
+async fn handle_health(id: serde_json::Value, health: &HealthMonitor) -> JsonRpcResponse {
+    let report = match health.latest() {
+        // Startup checks are still running
+        None => HealthReport::starting(),
+        Some(_) => health.refresh().await,
+    };
+    match serde_json::to_value(&report) {
+        Ok(result) => JsonRpcResponse::success(id, result),
+        Err(e) => JsonRpcResponse::error(id, -32603, format!("Failed to serialize health report: {}", e)),
+    }
+}
+
+/// Overall readiness reported by the `health` method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum HealthStatus {
+    /// Startup checks have not finished yet
+    Starting,
+    /// Database reachable and at least one provider constructible
+    Ready,
+    /// Server is answering, but the database or every provider is unavailable
+    Degraded,
+}
+
+/// Outcome of one readiness check.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct HealthCheck {
+    /// What was checked (database URL or provider name)
+    name: String,
+    ok: bool,
+    /// Success summary or failure reason
+    detail: String,
+}
+
+/// Structured result of the `health` method.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct HealthReport {
+    status: HealthStatus,
+    /// Task database check (absent while starting)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    database: std::option::Option<HealthCheck>,
+    /// One check per configured provider
+    providers: std::vec::Vec<HealthCheck>,
+}
+
+impl HealthReport {
+    fn starting() -> Self {
+        HealthReport {
+            status: HealthStatus::Starting,
+            database: None,
+            providers: std::vec::Vec::new(),
+        }
+    }
+
+    /// Derives the status from the database and provider checks.
+    fn from_checks(database: HealthCheck, providers: std::vec::Vec<HealthCheck>) -> Self {
+        let status = if database.ok && providers.iter().any(|provider| provider.ok) {
+            HealthStatus::Ready
+        } else {
+            HealthStatus::Degraded
+        };
+        HealthReport {
+            status,
+            database: Some(database),
+            providers,
+        }
+    }
+}
+
+/// Runs the readiness checks and keeps the latest report.
+struct HealthMonitor {
+    db_url: String,
+    config_path: std::path::PathBuf,
+    latest: std::sync::Mutex<std::option::Option<HealthReport>>,
+}
+
+impl HealthMonitor {
+    fn new(db_url: String, config_path: std::path::PathBuf) -> Self {
+        HealthMonitor {
+            db_url,
+            config_path,
+            latest: std::sync::Mutex::new(None),
+        }
+    }
+
+    /// Returns the last report, or None if no checks have finished yet.
+    fn latest(&self) -> std::option::Option<HealthReport> {
+        self.latest.lock().ok().and_then(|latest| latest.clone())
+    }
+
+    /// Runs the checks and stores the result as the latest report.
+    async fn refresh(&self) -> HealthReport {
+        let database = check_database(&self.db_url, &self.config_path).await;
+        let providers = match self.config_path.to_str().map(rigger_core::RiggerConfig::load_with_migration) {
+            Some(Ok(config)) => check_providers(&config),
+            Some(Err(e)) => std::vec![HealthCheck {
+                name: String::from("config"),
+                ok: false,
+                detail: e.to_string(),
+            }],
+            None => std::vec![HealthCheck {
+                name: String::from("config"),
+                ok: false,
+                detail: String::from("Invalid config path"),
+            }],
+        };
+        let report = HealthReport::from_checks(database, providers);
+        if let Ok(mut latest) = self.latest.lock() {
+            *latest = Some(report.clone());
+        }
+        report
+    }
+}
+
+/// Checks that the task database can be opened and its schema initialized.
+async fn check_database(db_url: &str, config_path: &std::path::Path) -> HealthCheck {
+    let result = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init_with(
+        db_url,
+        &crate::services::database_pool::load_settings(config_path),
+    )
+    .await;
+    HealthCheck {
+        name: String::from(db_url),
+        ok: result.is_ok(),
+        detail: match result {
+            Ok(_) => String::from("reachable"),
+            Err(e) => e,
+        },
+    }
+}
+
+/// Checks that each configured provider can be constructed: its type is
+/// supported by the orchestrator, its API key is set, and it is allowed in
+/// offline mode. No request is sent to the provider.
+fn check_providers(config: &rigger_core::RiggerConfig) -> std::vec::Vec<HealthCheck> {
+    let mut names: std::vec::Vec<&String> = config.providers.keys().collect();
+    names.sort();
+    names
+        .into_iter()
+        .map(|name| {
+            let provider = &config.providers[name];
+            let constructed = construct_provider(provider, config.offline);
+            HealthCheck {
+                name: name.clone(),
+                ok: constructed.is_ok(),
+                detail: match constructed {
+                    Ok(()) => std::format!("{} ({})", provider.provider_type, provider.default_model),
+                    Err(e) => e,
+                },
+            }
+        })
+        .collect()
+}
+
+fn construct_provider(provider: &rigger_core::config::ProviderConfig, offline: bool) -> std::result::Result<(), String> {
+    let factory_provider = match provider.provider_type {
+        rigger_core::config::ProviderType::Ollama => "ollama",
+        rigger_core::config::ProviderType::OpenAI => "openai",
+        rigger_core::config::ProviderType::OpenAiCompatible => "openai-compatible",
+        rigger_core::config::ProviderType::Anthropic => "anthropic",
+        _ => {
+            return Err(std::format!("{} providers are not supported by the orchestrator", provider.provider_type));
+        }
+    };
+    provider.get_api_key().map_err(|e| e.to_string())?;
+    task_orchestrator::adapters::provider_factory::ProviderFactory::new(factory_provider, &provider.default_model)
+        .map_err(|e| e.to_string())?
+        .with_base_url(&provider.base_url)
+        .with_offline(offline)
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Opens the task database at `db_path` with the pool settings of its project config.
+async fn connect_tasks(
+    db_path: &std::path::Path,
+) -> std::result::Result<task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter, String> {
+    let rigger_dir = db_path.parent().unwrap_or_else(|| std::path::Path::new(".rigger"));
+    task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init_with(
+        &crate::services::database_url::url_for(db_path),
+        &crate::services::database_pool::load_settings(&crate::services::config_path::path_in(rigger_dir)),
+    )
+    .await
+}
+
 /// Gets the path to the Rigger database file.
 fn get_rigger_db_path() -> anyhow::Result<std::path::PathBuf> {
     let cwd = std::env::current_dir()?;
@@ -614,7 +884,7 @@ fn get_rigger_db_path() -> anyhow::Result<std::path::PathBuf> {
 /// Reads the Rigger configuration from .rigger/config.json.
 fn get_rigger_config() -> anyhow::Result<serde_json::Value> {
     let cwd = std::env::current_dir()?;
-    let config_path = cwd.join(".rigger").join("config.json");
+    let config_path = crate::services::config_path::path_in(&cwd.join(".rigger"));
 
     if !config_path.exists() {
         anyhow::bail!("config.json not found in .rigger directory");
@@ -625,3 +895,118 @@ fn get_rigger_config() -> anyhow::Result<serde_json::Value> {
 
     Ok(config)
 }
+
+#[cfg(test)]
+mod tests {
+    fn request(authorization: std::option::Option<&str>) -> super::JsonRpcRequest {
+        let mut json = serde_json::json!({"jsonrpc": "2.0", "method": "unknown_method", "id": 7});
+        if let Some(authorization) = authorization {
+            json["authorization"] = serde_json::Value::from(authorization);
+        }
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn monitor(db_url: &str) -> super::HealthMonitor {
+        super::HealthMonitor::new(std::string::String::from(db_url), std::path::PathBuf::from("/nonexistent/.rigger/config.json"))
+    }
+
+    async fn health_of(db_url: &str, config: &rigger_core::RiggerConfig) -> super::HealthReport {
+        super::HealthReport::from_checks(
+            super::check_database(db_url, std::path::Path::new("/nonexistent/.rigger/config.json")).await,
+            super::check_providers(config),
+        )
+    }
+
+    fn single_provider_config(provider_type: rigger_core::config::ProviderType, base_url: &str) -> rigger_core::RiggerConfig {
+        let mut config = rigger_core::RiggerConfig::default();
+        let mut provider = config.providers.remove("ollama").unwrap();
+        provider.provider_type = provider_type;
+        provider.base_url = std::string::String::from(base_url);
+        config.providers.insert(std::string::String::from("only"), provider);
+        config
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_rejects_unauthenticated_requests() {
+        // Test: Validates requests without the configured token get -32001 and matching ones reach the handler.
+        // Justification: The handler answering "method not found" proves the request got past authentication.
+        let expected = std::option::Option::Some("s3cret");
+
+        let health = monitor("sqlite::memory:");
+
+        let rejected = super::dispatch(request(None), expected, &health).await;
+        std::assert_eq!(rejected.error.unwrap().code, super::UNAUTHENTICATED);
+        let wrong = super::dispatch(request(Some("Bearer nope")), expected, &health).await;
+        std::assert_eq!(wrong.error.unwrap().code, super::UNAUTHENTICATED);
+
+        let accepted = super::dispatch(request(Some("Bearer s3cret")), expected, &health).await;
+        std::assert_eq!(accepted.error.unwrap().code, -32601);
+        std::assert_eq!(accepted.id, serde_json::json!(7));
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_is_open_without_token() {
+        // Test: Validates requests are handled without credentials when no token is configured.
+        // Justification: IDE setups without auth_token_env must keep working unchanged.
+        let response = super::dispatch(request(None), None, &monitor("sqlite::memory:")).await;
+
+        std::assert_eq!(response.error.unwrap().code, -32601);
+    }
+
+    #[tokio::test]
+    async fn test_health_reports_starting_before_startup_checks_finish() {
+        // Test: Validates `health` answers "starting" until the first checks complete, then a full report.
+        // Justification: An IDE polling right after spawning the server must not mistake an unfinished startup for a failure.
+        let health = monitor("sqlite::memory:");
+        let request: super::JsonRpcRequest =
+            serde_json::from_value(serde_json::json!({"jsonrpc": "2.0", "method": "health", "id": 1})).unwrap();
+
+        let response = super::dispatch(request, None, &health).await;
+        std::assert_eq!(response.result.unwrap(), serde_json::json!({"status": "starting", "providers": []}));
+
+        // Without a config file the default local Ollama provider is checked
+        let report = health.refresh().await;
+        std::assert_eq!(report.status, super::HealthStatus::Ready);
+        std::assert_eq!(health.latest(), Some(report));
+    }
+
+    #[tokio::test]
+    async fn test_health_ready_with_database_and_provider() {
+        // Test: Validates a reachable database plus a constructible provider reports "ready".
+        // Justification: "ready" is the signal an IDE extension waits for before sending tool calls.
+        let report = health_of("sqlite::memory:", &rigger_core::RiggerConfig::default()).await;
+
+        std::assert_eq!(report.status, super::HealthStatus::Ready);
+        std::assert!(report.database.unwrap().ok);
+        std::assert!(report.providers[0].ok);
+    }
+
+    #[tokio::test]
+    async fn test_health_degraded_when_database_fails() {
+        // Test: Validates an unreachable database reports "degraded" even with a working provider.
+        // Justification: Every task tool needs the database, so the server must not claim to be ready without it.
+        let report = health_of("sqlite:/nonexistent-rigger-dir/tasks.db", &rigger_core::RiggerConfig::default()).await;
+
+        std::assert_eq!(report.status, super::HealthStatus::Degraded);
+        std::assert!(!report.database.unwrap().ok);
+        std::assert!(report.providers[0].ok);
+    }
+
+    #[tokio::test]
+    async fn test_health_degraded_when_no_provider_constructible() {
+        // Test: Validates "degraded" with the database up when providers are unsupported or forbidden offline.
+        // Justification: parse_prd cannot work without a provider, and the failure reason must say why.
+        let unsupported = single_provider_config(rigger_core::config::ProviderType::Groq, "https://api.groq.com/openai/v1");
+        let report = health_of("sqlite::memory:", &unsupported).await;
+        std::assert_eq!(report.status, super::HealthStatus::Degraded);
+        std::assert!(report.database.unwrap().ok);
+        std::assert!(report.providers[0].detail.contains("not supported"));
+
+        let mut offline = single_provider_config(rigger_core::config::ProviderType::OpenAI, "https://api.openai.com/v1");
+        offline.offline = true;
+        offline.providers.get_mut("only").unwrap().api_key_env = None;
+        let report = health_of("sqlite::memory:", &offline).await;
+        std::assert_eq!(report.status, super::HealthStatus::Degraded);
+        std::assert!(!report.providers[0].ok);
+    }
+}
@@ -0,0 +1,431 @@
+//! Implementation of the 'rig show <TASK_ID>' command.
+//!
+//! Displays a single task's full detail, including its checklist,
+//! dependencies, revision history, and the transcript snippet it came from.
+//! With `--similar`, also lists the most similar other tasks by embedding.
+//!
+//! Revision History
+//! - 2026-10-18T01:00:00Z @AI: Embed --similar tasks with the embedding slot of the merged config.
+//! - 2026-10-17T17:30:00Z @AI: Add --similar top-K similar tasks with cached embeddings.
+//! - 2026-10-17T16:50:00Z @AI: Show the task's estimate.
+//! - 2026-10-17T16:30:00Z @AI: Show blocking dependencies as "Blocked by" and list relates-to / duplicates links.
+//! - 2026-10-17T14:10:00Z @AI: Open the database through services::database_url so --db / RIGGER_DB apply.
+//! - 2026-10-17T13:50:00Z @AI: Read the config path from services::config_path, honoring --config and RIGGER_CONFIG.
+//! - 2026-10-17T03:50:00Z @AI: Show the originating transcript and utterance of transcript-generated tasks.
+//! - 2026-10-17T03:10:00Z @AI: Show time spent, including a running interval.
+//! - 2026-10-16T17:10:00Z @AI: Colorize status, priority, and overdue due date with the configured tui.theme.
+//! - 2026-10-16T15:50:00Z @AI: Initial show command with text and JSON output.
+
+/// Executes the 'rig show <TASK_ID>' command.
+///
+/// Reads the task from the SQLite database in .rigger/tasks.db and prints
+/// its detail view, or the task as JSON when `format` is "json".
+///
+/// With `similar`, the task and the other tasks are embedded with the
+/// config's embedding slot (through an on-disk cache in
+/// .rigger/cache/embeddings) and the closest ones are listed after the
+/// detail, or added to the JSON object as "similar".
+///
+/// # Arguments
+///
+/// * `task_id` - ID of the task to show
+/// * `format` - Output format ("text" or "json")
+/// * `similar` - Number of similar tasks to list, if any
+/// * `include_closed` - Consider completed and archived tasks as similar tasks
+///
+/// # Errors
+///
+/// Returns an error (non-zero exit) if:
+/// - .rigger directory doesn't exist (run 'rig init' first)
+/// - Format is not "text" or "json"
+/// - Task not found in database
+/// - Database operations fail
+/// - With `similar`, the config can't be read or embedding fails
+pub async fn execute(
+    task_id: &str,
+    format: &str,
+    similar: std::option::Option<usize>,
+    include_closed: bool,
+) -> anyhow::Result<()> {
+    if format != "text" && format != "json" {
+        anyhow::bail!("Invalid format: '{}'. Valid values: text, json", format);
+    }
+
+    // Check if .rigger exists
+    let current_dir = std::env::current_dir()?;
+    let rigger_dir = current_dir.join(".rigger");
+
+    if !rigger_dir.exists() {
+        anyhow::bail!(
+            ".rigger directory not found.\nRun 'rig init' first to initialize the project."
+        );
+    }
+
+    // Connect to database
+    let db_path = rigger_dir.join("tasks.db");
+    let db_url = crate::services::database_url::url_for(&db_path);
+
+    let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect to database: {}", e))?;
+
+    // Find task by ID
+    let filter = task_manager::ports::task_repository_port::TaskFilter::ById(std::string::String::from(task_id));
+
+    let task: std::option::Option<task_manager::domain::task::Task> = {
+        use hexser::ports::repository::QueryRepository;
+        adapter.find_one(&filter)?
+    };
+
+    let task = match task {
+        std::option::Option::Some(t) => t,
+        std::option::Option::None => {
+            anyhow::bail!("Task not found: {}\n\nUse 'rig list' to see available tasks.", task_id);
+        }
+    };
+
+    let similar = match similar {
+        std::option::Option::Some(limit) => {
+            std::option::Option::Some(find_similar(&rigger_dir, &adapter, &task, limit, include_closed).await?)
+        }
+        std::option::Option::None => std::option::Option::None,
+    };
+
+    if format == "json" {
+        let mut value = serde_json::to_value(&task)?;
+        if let std::option::Option::Some((ref tasks, ref similar)) = similar {
+            value["similar"] = serde_json::Value::Array(
+                similar
+                    .iter()
+                    .map(|s| {
+                        serde_json::json!({
+                            "id": s.task_id,
+                            "title": title_of(tasks, &s.task_id),
+                            "similarity": s.similarity,
+                        })
+                    })
+                    .collect(),
+            );
+        }
+        println!("{}", serde_json::to_string_pretty(&value)?);
+    } else {
+        let theme = crate::display::theme::Theme::load(&crate::services::config_path::path_in(&rigger_dir));
+        print!("{}", render_task_detail(&task, &theme));
+        if let std::option::Option::Some((ref tasks, ref similar)) = similar {
+            print!("{}", render_similar(tasks, similar));
+        }
+    }
+
+    std::result::Result::Ok(())
+}
+
+/// Embeds the project's tasks and returns them with the `limit` closest to `task`.
+async fn find_similar(
+    rigger_dir: &std::path::Path,
+    adapter: &task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter,
+    task: &task_manager::domain::task::Task,
+    limit: usize,
+    include_closed: bool,
+) -> anyhow::Result<(
+    std::vec::Vec<task_manager::domain::task::Task>,
+    std::vec::Vec<task_orchestrator::services::similar_tasks_service::SimilarTask>,
+)> {
+    let config = crate::services::provider_slot::load_config(rigger_dir)?;
+
+    let tasks = adapter
+        .find_async(
+            &task_manager::ports::task_repository_port::TaskFilter::All,
+            hexser::ports::repository::FindOptions {
+                sort: std::option::Option::None,
+                limit: std::option::Option::None,
+                offset: std::option::Option::None,
+            },
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    let embedding_adapter = crate::services::provider_slot::embedding_factory(&config)?
+        .create_embedding_adapter()
+        .map_err(|e| anyhow::anyhow!("Failed to create embedding adapter: {}", e))?;
+    let cache = std::sync::Arc::new(task_orchestrator::infrastructure::response_cache::ResponseCache::new(
+        rigger_dir.join("cache").join("embeddings"),
+        std::option::Option::None,
+    ));
+    let cached = std::sync::Arc::new(task_orchestrator::adapters::caching_embedding_adapter::CachingEmbeddingAdapter::new(
+        embedding_adapter,
+        cache,
+    ));
+
+    let service = task_orchestrator::services::similar_tasks_service::SimilarTasksService::new(cached);
+    let similar = service
+        .find_similar(task, &tasks, limit, include_closed)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to find similar tasks: {}", e))?;
+    std::result::Result::Ok((tasks, similar))
+}
+
+/// Returns the title of task `id`, or "" if it isn't in `tasks`.
+fn title_of<'a>(tasks: &'a [task_manager::domain::task::Task], id: &str) -> &'a str {
+    tasks.iter().find(|t| t.id == id).map(|t| t.title.as_str()).unwrap_or("")
+}
+
+/// Renders the "Similar tasks" section: one line per task with its similarity, ID, status, and title.
+///
+/// # Arguments
+///
+/// * `tasks` - All tasks, to look up titles and statuses
+/// * `similar` - Similar tasks, best first
+fn render_similar(
+    tasks: &[task_manager::domain::task::Task],
+    similar: &[task_orchestrator::services::similar_tasks_service::SimilarTask],
+) -> String {
+    if similar.is_empty() {
+        return std::string::String::from("\nSimilar tasks: none found\n");
+    }
+    let mut output = std::format!("\nSimilar tasks ({}):\n", similar.len());
+    for s in similar {
+        let status = tasks
+            .iter()
+            .find(|t| t.id == s.task_id)
+            .map(|t| {
+                std::format!(
+                    " [{}]",
+                    crate::services::task_formatter::format_status_colored(&t.status, &crate::display::theme::Theme::NoColor)
+                )
+            })
+            .unwrap_or_default();
+        output.push_str(&std::format!("  {:.2}  {}{}  {}\n", s.similarity, s.task_id, status, title_of(tasks, &s.task_id)));
+    }
+    output
+}
+
+/// Renders a task's full detail as plain text.
+///
+/// Includes metadata, description, links, checklist items with their
+/// completion state, and revision history (oldest first). Status, priority,
+/// and an overdue due date are colored by `theme`.
+///
+/// # Arguments
+///
+/// * `task` - The task to render
+/// * `theme` - Color theme; `Theme::NoColor` renders plain text
+///
+/// # Returns
+///
+/// The detail view, ending with a newline.
+pub fn render_task_detail(task: &task_manager::domain::task::Task, theme: &crate::display::theme::Theme) -> String {
+    let mut output = std::string::String::new();
+
+    output.push_str(&std::format!("Task: {}\n", task.title));
+    output.push_str(&std::format!("ID: {}\n", task.id));
+    output.push_str(&std::format!(
+        "Status: {}\n",
+        crate::services::task_formatter::format_status_colored(&task.status, theme)
+    ));
+
+    if let std::option::Option::Some(priority) = task.priority {
+        output.push_str(&std::format!("Priority: {}\n", theme.priority(priority, priority.as_str())));
+    }
+    if let std::option::Option::Some(ref assignee) = task.agent_persona {
+        output.push_str(&std::format!("Assignee: {}\n", assignee));
+    }
+    if let std::option::Option::Some(ref due_date) = task.due_date {
+        let due_display = if crate::display::theme::is_overdue(task, chrono::Local::now().date_naive()) {
+            theme.overdue(due_date)
+        } else {
+            due_date.clone()
+        };
+        output.push_str(&std::format!("Due Date: {}\n", due_display));
+    }
+    if let std::option::Option::Some(estimate) = task.estimate {
+        output.push_str(&std::format!("Estimate: {} pts\n", estimate));
+    }
+    if let std::option::Option::Some(complexity) = task.complexity {
+        output.push_str(&std::format!("Complexity: {}/10\n", complexity));
+    }
+    if !task.tags.is_empty() {
+        output.push_str(&std::format!("Tags: {}\n", task.tags.join(", ")));
+    }
+    let time_spent = task.time_spent_at(chrono::Utc::now());
+    if let std::option::Option::Some(started_at) = task.started_at {
+        output.push_str(&std::format!(
+            "Time Spent: {} (running since {})\n",
+            crate::commands::time_tracking::format_duration(time_spent),
+            started_at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M")
+        ));
+    } else if time_spent > 0 {
+        output.push_str(&std::format!("Time Spent: {}\n", crate::commands::time_tracking::format_duration(time_spent)));
+    }
+    output.push_str(&std::format!("Created: {}\n", task.created_at.format("%Y-%m-%d %H:%M")));
+    output.push_str(&std::format!("Updated: {}\n", task.updated_at.format("%Y-%m-%d %H:%M")));
+
+    if !task.description.is_empty() {
+        output.push_str(&std::format!("\nDescription:\n{}\n", task.description));
+    }
+
+    if let std::option::Option::Some(ref transcript_id) = task.source_transcript_id {
+        output.push_str(&std::format!("\nSource: transcript {}\n", transcript_id));
+        if let std::option::Option::Some(ref utterance) = task.source_utterance {
+            output.push_str(&std::format!("  > {}\n", utterance));
+        }
+    }
+
+    if !task.dependencies.is_empty() || !task.links.is_empty() {
+        output.push_str("\nLinks:\n");
+        for dep in &task.dependencies {
+            output.push_str(&std::format!("  - blocked by {}\n", dep));
+        }
+        for link in &task.links {
+            output.push_str(&std::format!("  - {} {}\n", link.link_type, link.task_id));
+        }
+    }
+
+    if !task.checklist.is_empty() {
+        let done = task.checklist.iter().filter(|item| item.completed).count();
+        output.push_str(&std::format!("\nChecklist ({}/{} done):\n", done, task.checklist.len()));
+        for item in &task.checklist {
+            let mark = if item.completed { "x" } else { " " };
+            output.push_str(&std::format!("  [{}] {}\n", mark, item.description));
+        }
+    }
+
+    if !task.revisions.is_empty() {
+        output.push_str("\nRevision History:\n");
+        for revision in &task.revisions {
+            output.push_str(&std::format!(
+                "  {} - {}\n",
+                revision.timestamp.format("%Y-%m-%d %H:%M"),
+                revision.change_description
+            ));
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_render_includes_revisions_and_checklist_state() {
+        // Test: Validates the detail view lists revision entries and checklist completion.
+        // Justification: Revision history and checklist are what `rig list` cannot show.
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Ship login page"),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let mut task = task_manager::domain::task::Task::from_action_item(&action, std::option::Option::None);
+        task.checklist.push(task_manager::domain::checklist_item::ChecklistItem {
+            description: std::string::String::from("Build form"),
+            completed: true,
+        });
+        task.checklist.push(task_manager::domain::checklist_item::ChecklistItem {
+            description: std::string::String::from("Add tests"),
+            completed: false,
+        });
+        task.revisions.push(task_manager::domain::task_revision::TaskRevision {
+            revision_id: std::string::String::from("r1"),
+            task_id: task.id.clone(),
+            timestamp: chrono::Utc::now(),
+            change_description: std::string::String::from("Auto-triage: priority medium"),
+            previous_state_json: std::option::Option::None,
+        });
+
+        let output = super::render_task_detail(&task, &crate::display::theme::Theme::NoColor);
+
+        std::assert!(output.contains("Checklist (1/2 done):"));
+        std::assert!(output.contains("[x] Build form"));
+        std::assert!(output.contains("[ ] Add tests"));
+        std::assert!(output.contains("Revision History:"));
+        std::assert!(output.contains("Auto-triage: priority medium"));
+    }
+
+    #[tokio::test]
+    async fn test_render_shows_source_snippet_after_persistence() {
+        // Test: Validates a transcript-generated task reloaded from SQLite still shows its source transcript and snippet.
+        // Justification: The snippet is how a reader checks what was actually said before acting on the task.
+        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init("sqlite::memory:")
+            .await
+            .unwrap();
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Write the migration"),
+            assignee: std::option::Option::Some(std::string::String::from("John")),
+            due_date: std::option::Option::None,
+        };
+        let mut task = task_manager::domain::task::Task::from_action_item(
+            &action,
+            std::option::Option::Some(std::string::String::from("standup-2026-03-02")),
+        );
+        task.source_utterance = std::option::Option::Some(task_manager::domain::transcript_utterance::TranscriptUtterance {
+            line: 4,
+            speaker: std::option::Option::Some(std::string::String::from("John")),
+            text: std::string::String::from("I'll write the migration today."),
+        });
+        adapter.save_async(task.clone()).await.unwrap();
+
+        let loaded = adapter
+            .find_one_async(&task_manager::ports::task_repository_port::TaskFilter::ById(task.id.clone()))
+            .await
+            .unwrap()
+            .unwrap();
+        let output = super::render_task_detail(&loaded, &crate::display::theme::Theme::NoColor);
+
+        std::assert!(output.contains("Source: transcript standup-2026-03-02"), "{}", output);
+        std::assert!(output.contains("> line 4, John: I'll write the migration today."), "{}", output);
+    }
+
+    #[test]
+    fn test_render_similar_lists_tasks_best_first() {
+        // Test: Validates the section keeps the given order and shows similarity, ID, status, and title, and an empty result says so.
+        // Justification: The ordering comes from the service; the renderer must not reshuffle it.
+        let action = |title: &str| transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from(title),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let mut form = task_manager::domain::task::Task::from_action_item(&action("Add login form"), std::option::Option::None);
+        form.id = std::string::String::from("form");
+        let mut oauth = task_manager::domain::task::Task::from_action_item(&action("Support OAuth login"), std::option::Option::None);
+        oauth.id = std::string::String::from("oauth");
+        let similar = std::vec![
+            task_orchestrator::services::similar_tasks_service::SimilarTask {
+                task_id: std::string::String::from("form"),
+                similarity: 0.91,
+            },
+            task_orchestrator::services::similar_tasks_service::SimilarTask {
+                task_id: std::string::String::from("oauth"),
+                similarity: 0.4,
+            },
+        ];
+
+        let output = super::render_similar(&[oauth, form], &similar);
+        let empty = super::render_similar(&[], &[]);
+
+        let form_line = output.find("0.91  form [TODO]  Add login form").expect(&output);
+        let oauth_line = output.find("0.40  oauth [TODO]  Support OAuth login").expect(&output);
+        std::assert!(form_line < oauth_line, "{}", output);
+        std::assert!(output.contains("Similar tasks (2):"), "{}", output);
+        std::assert!(empty.contains("Similar tasks: none found"), "{}", empty);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[serial_test::serial]
+    async fn test_show_unknown_id_fails() {
+        // Test: Validates an unknown task ID returns a "Task not found" error.
+        // Justification: main() propagates the error, giving a non-zero exit code.
+        let temp_dir = std::env::temp_dir().join(std::format!("rigger_test_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir(&temp_dir).unwrap();
+
+        let original_dir = std::env::current_dir().unwrap();
+        std::env::set_current_dir(&temp_dir).unwrap();
+
+        crate::commands::init::execute().await.unwrap();
+        let result = super::execute("no-such-task", "text", std::option::Option::None, false).await;
+
+        let _ = std::env::set_current_dir(original_dir);
+        let _ = std::fs::remove_dir_all(&temp_dir);
+
+        let err = result.expect_err("Unknown task ID should fail");
+        std::assert!(err.to_string().contains("Task not found: no-such-task"));
+    }
+}
@@ -0,0 +1,150 @@
+//! Implementation of the 'rig stats [--since DATE] [--assignee NAME]' command.
+//!
+//! Reports task counts by status, completion velocity, and average cycle
+//! time for sprint reviews (see `task_manager::domain::services::task_stats`).
+//!
+//! Revision History
+//! - 2026-10-17T14:10:00Z @AI: Open the database through services::database_url so --db / RIGGER_DB apply.
+//! - 2026-10-17T03:30:00Z @AI: Initial stats command with text and JSON output.
+
+/// Executes the 'rig stats' command.
+///
+/// # Arguments
+///
+/// * `since` - First day of the window as YYYY-MM-DD; defaults to the oldest task's creation day
+/// * `assignee` - Only count tasks assigned to this persona
+/// * `format` - Output format ("text" or "json")
+///
+/// # Errors
+///
+/// Returns an error if .rigger doesn't exist, `since` is not a valid date,
+/// the format is unknown, or the database query fails.
+pub async fn execute(
+    since: std::option::Option<&str>,
+    assignee: std::option::Option<&str>,
+    format: &str,
+) -> anyhow::Result<()> {
+    if format != "text" && format != "json" {
+        anyhow::bail!("Invalid format: '{}'. Valid values: text, json", format);
+    }
+    let since = match since {
+        std::option::Option::Some(date) => std::option::Option::Some(
+            chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .map_err(|_| anyhow::anyhow!("Invalid --since date: '{}'. Expected YYYY-MM-DD.", date))?,
+        ),
+        std::option::Option::None => std::option::Option::None,
+    };
+
+    let current_dir = std::env::current_dir()?;
+    let rigger_dir = current_dir.join(".rigger");
+
+    if !rigger_dir.exists() {
+        anyhow::bail!(
+            ".rigger directory not found.\nRun 'rig init' first to initialize the project."
+        );
+    }
+
+    let db_url = crate::services::database_url::url_for(&rigger_dir.join("tasks.db"));
+    let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect to database: {}", e))?;
+
+    let tasks = adapter
+        .find_async(
+            &task_manager::ports::task_repository_port::TaskFilter::All,
+            hexser::ports::repository::FindOptions {
+                sort: std::option::Option::None,
+                limit: std::option::Option::None,
+                offset: std::option::Option::None,
+            },
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    let stats = task_manager::domain::services::task_stats::compute(&tasks, since, assignee, chrono::Utc::now().date_naive());
+    if format == "json" {
+        println!("{}", serde_json::to_string_pretty(&stats)?);
+    } else {
+        print!("{}", render_stats(&stats, assignee));
+    }
+    std::result::Result::Ok(())
+}
+
+/// Renders the statistics as plain text.
+///
+/// # Arguments
+///
+/// * `stats` - Computed statistics
+/// * `assignee` - Assignee filter, echoed in the heading
+///
+/// # Returns
+///
+/// The report, ending with a newline.
+fn render_stats(stats: &task_manager::domain::services::task_stats::TaskStats, assignee: std::option::Option<&str>) -> String {
+    let mut output = match stats.window_start {
+        std::option::Option::Some(start) => std::format!(
+            "Task stats from {} to {} ({} days)",
+            start, stats.window_end, stats.window_days
+        ),
+        std::option::Option::None => String::from("Task stats"),
+    };
+    if let std::option::Option::Some(assignee) = assignee {
+        output.push_str(&std::format!(" for {}", assignee));
+    }
+    output.push_str(":\n\n");
+
+    if stats.total == 0 {
+        output.push_str("No tasks found.\n");
+        return output;
+    }
+
+    output.push_str(&std::format!("By status ({} total):\n", stats.total));
+    for (status, count) in &stats.by_status {
+        output.push_str(&std::format!("  {:<26}{}\n", status, count));
+    }
+
+    output.push_str(&std::format!("\nCompleted in window: {}\n", stats.completed_in_window));
+    output.push_str(&std::format!("Velocity: {:.2} tasks/day\n", stats.velocity_per_day));
+    match stats.average_cycle_time_seconds {
+        std::option::Option::Some(seconds) => output.push_str(&std::format!(
+            "Average cycle time: {}\n",
+            crate::commands::time_tracking::format_duration(seconds.round() as u64)
+        )),
+        std::option::Option::None => output.push_str("Average cycle time: n/a\n"),
+    }
+    if stats.completed_without_history > 0 {
+        output.push_str(&std::format!(
+            "\n{} completed task(s) have no completion revision and are not counted in velocity or cycle time.\n",
+            stats.completed_without_history
+        ));
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_render_stats_reports_velocity_and_cycle_time() {
+        // Test: Validates the text report shows the window, status counts, velocity, cycle time, and untracked completions.
+        // Justification: The text output is what gets pasted into sprint reviews.
+        let stats = task_manager::domain::services::task_stats::TaskStats {
+            window_start: chrono::NaiveDate::from_ymd_opt(2026, 3, 1),
+            window_end: chrono::NaiveDate::from_ymd_opt(2026, 3, 10).unwrap(),
+            window_days: 10,
+            total: 5,
+            by_status: std::collections::BTreeMap::from([(String::from("Completed"), 3), (String::from("Todo"), 2)]),
+            completed_in_window: 2,
+            velocity_per_day: 0.2,
+            average_cycle_time_seconds: std::option::Option::Some(108_000.0),
+            completed_without_history: 1,
+        };
+
+        let output = super::render_stats(&stats, std::option::Option::Some("Backend Dev"));
+
+        std::assert!(output.starts_with("Task stats from 2026-03-01 to 2026-03-10 (10 days) for Backend Dev:"), "{}", output);
+        std::assert!(output.contains("By status (5 total):"));
+        std::assert!(output.contains("Velocity: 0.20 tasks/day"));
+        std::assert!(output.contains("Average cycle time: 30h 00m"));
+        std::assert!(output.contains("1 completed task(s) have no completion revision"));
+    }
+}
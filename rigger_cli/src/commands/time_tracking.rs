@@ -0,0 +1,173 @@
+//! Implementation of the 'rig start <TASK_ID>' and 'rig stop <TASK_ID>' commands.
+//!
+//! `rig start` moves a task to InProgress, which opens a time-tracking
+//! interval; `rig stop` pauses it back to Todo and adds the interval to the
+//! task's time spent. Any other move out of InProgress (e.g. completing the
+//! task) closes the interval as well, so stop is only needed to pause.
+//!
+//! Revision History
+//! - 2026-10-17T14:10:00Z @AI: Open the database through services::database_url so --db / RIGGER_DB apply.
+//! - 2026-10-17T10:50:00Z @AI: Keep the TaskManagerError as the cause of start and stop failures.
+//! - 2026-10-17T03:10:00Z @AI: Initial start and stop commands backed by ManageTaskUseCase::change_status().
+
+/// Executes the 'rig start <TASK_ID>' command.
+///
+/// # Arguments
+///
+/// * `task_id` - ID of the task to start
+///
+/// # Errors
+///
+/// Returns an error if .rigger doesn't exist, the task is not found, is
+/// already in progress, cannot move to InProgress, or saving fails.
+pub async fn start(task_id: &str) -> anyhow::Result<()> {
+    let (adapter, task) = load_task(task_id).await?;
+    if task.status == task_manager::domain::task_status::TaskStatus::InProgress {
+        anyhow::bail!("Task {} is already in progress.", task_id);
+    }
+
+    let mut use_case = task_manager::use_cases::manage_task::ManageTaskUseCase::new(adapter);
+    let started = use_case
+        .change_status(task_id, task_manager::domain::task_status::TaskStatus::InProgress)
+        .map_err(|e| anyhow::Error::new(e).context("Failed to start task"))?;
+
+    println!("▶ Started {} ({})", started.title, started.id);
+    if started.time_spent_seconds > 0 {
+        println!("  Time spent so far: {}", format_duration(started.time_spent_seconds));
+    }
+    std::result::Result::Ok(())
+}
+
+/// Executes the 'rig stop <TASK_ID>' command.
+///
+/// # Arguments
+///
+/// * `task_id` - ID of the task to pause
+///
+/// # Errors
+///
+/// Returns an error if .rigger doesn't exist, the task is not found, is not
+/// in progress, or saving fails.
+pub async fn stop(task_id: &str) -> anyhow::Result<()> {
+    let (adapter, task) = load_task(task_id).await?;
+    if task.status != task_manager::domain::task_status::TaskStatus::InProgress {
+        anyhow::bail!("Task {} is not in progress. Start it with: rig start {}", task_id, task_id);
+    }
+
+    let mut use_case = task_manager::use_cases::manage_task::ManageTaskUseCase::new(adapter);
+    let stopped = use_case
+        .change_status(task_id, task_manager::domain::task_status::TaskStatus::Todo)
+        .map_err(|e| anyhow::Error::new(e).context("Failed to stop task"))?;
+
+    println!("⏸ Stopped {} ({})", stopped.title, stopped.id);
+    println!(
+        "  This session: {}, total: {}",
+        format_duration(stopped.time_spent_seconds - task.time_spent_seconds),
+        format_duration(stopped.time_spent_seconds)
+    );
+    std::result::Result::Ok(())
+}
+
+/// Formats a number of seconds as "45s", "12m 05s", or "3h 07m".
+///
+/// # Arguments
+///
+/// * `seconds` - Duration in seconds
+pub fn format_duration(seconds: u64) -> String {
+    match seconds {
+        0..=59 => std::format!("{}s", seconds),
+        60..=3599 => std::format!("{}m {:02}s", seconds / 60, seconds % 60),
+        _ => std::format!("{}h {:02}m", seconds / 3600, (seconds % 3600) / 60),
+    }
+}
+
+/// Opens .rigger/tasks.db and loads a task by ID.
+///
+/// # Errors
+///
+/// Returns an error if .rigger doesn't exist, the connection fails, or the
+/// task is not found.
+async fn load_task(
+    task_id: &str,
+) -> anyhow::Result<(task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter, task_manager::domain::task::Task)> {
+    let current_dir = std::env::current_dir()?;
+    let rigger_dir = current_dir.join(".rigger");
+
+    if !rigger_dir.exists() {
+        anyhow::bail!(
+            ".rigger directory not found.\nRun 'rig init' first to initialize the project."
+        );
+    }
+
+    let db_url = crate::services::database_url::url_for(&rigger_dir.join("tasks.db"));
+    let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect to database: {}", e))?;
+
+    let task = adapter
+        .find_one_async(&task_manager::ports::task_repository_port::TaskFilter::ById(std::string::String::from(task_id)))
+        .await
+        .map_err(|e| anyhow::anyhow!("{}", e))?
+        .ok_or_else(|| anyhow::anyhow!("Task not found: {}\n\nUse 'rig list' to see available tasks.", task_id))?;
+
+    std::result::Result::Ok((adapter, task))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_format_duration() {
+        // Test: Validates seconds, minutes, and hours are each formatted at a readable precision.
+        // Justification: Time spent is shown after every stop and in `rig show`.
+        std::assert_eq!(super::format_duration(45), "45s");
+        std::assert_eq!(super::format_duration(725), "12m 05s");
+        std::assert_eq!(super::format_duration(11_220), "3h 07m");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[serial_test::serial]
+    async fn test_start_and_stop_track_in_progress_interval() {
+        // Test: Validates start opens an interval on an InProgress task and stop closes it back in Todo.
+        // Justification: Both commands must go through the use case so time tracking is applied.
+        let temp_dir = std::env::temp_dir().join(std::format!("rigger_test_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir(&temp_dir).unwrap();
+
+        let original_dir = std::env::current_dir().unwrap();
+        std::env::set_current_dir(&temp_dir).unwrap();
+
+        crate::commands::init::execute().await.unwrap();
+        let id = crate::commands::add::execute(
+            "Rotate API keys",
+            std::option::Option::None,
+            std::option::Option::None,
+            std::option::Option::Some("High"),
+            std::option::Option::None,
+            std::option::Option::None,
+            std::option::Option::None,
+            &[],
+        )
+        .await
+        .unwrap();
+
+        let db_url = std::format!("sqlite:{}", temp_dir.join(".rigger").join("tasks.db").display());
+        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url).await.unwrap();
+        let filter = task_manager::ports::task_repository_port::TaskFilter::ById(id.clone());
+
+        let stop_before_start = super::stop(&id).await;
+        super::start(&id).await.unwrap();
+        let started = adapter.find_one_async(&filter).await.unwrap().unwrap();
+        let start_twice = super::start(&id).await;
+        super::stop(&id).await.unwrap();
+        let stopped = adapter.find_one_async(&filter).await.unwrap().unwrap();
+
+        let _ = std::env::set_current_dir(original_dir);
+        let _ = std::fs::remove_dir_all(&temp_dir);
+
+        std::assert!(stop_before_start.unwrap_err().to_string().contains("not in progress"));
+        std::assert_eq!(started.status, task_manager::domain::task_status::TaskStatus::InProgress);
+        std::assert!(started.started_at.is_some());
+        std::assert!(start_twice.unwrap_err().to_string().contains("already in progress"));
+        std::assert_eq!(stopped.status, task_manager::domain::task_status::TaskStatus::Todo);
+        std::assert!(stopped.started_at.is_none());
+    }
+}
@@ -5,6 +5,37 @@
 //! reasoning display, and network request logging.
 //!
 //! Revision History
+//! - 2026-10-18T02:30:00Z @AI: Fill the new orchestration config section in the setup wizard's config.
+//! - 2026-10-18T01:40:00Z @AI: Give the chat agent the chat provider's timeout and retry policy.
+//! - 2026-10-18T01:30:00Z @AI: Pass the chat provider's auto_pull to the Ollama chat agent.
+//! - 2026-10-18T01:20:00Z @AI: Build the chat agent from the project config merged with config.local.json instead of the global config.
+//! - 2026-10-18T00:40:00Z @AI: Assert the chat agent carries the persona's tool call limits and tool allowlist.
+//! - 2026-10-18T00:30:00Z @AI: Build the chat agent with the chat persona (Ctrl+P cycles it) and rebuild it when the persona changes.
+//! - 2026-10-17T22:20:00Z @AI: Track time spent in progress on every status change made in the TUI.
+//! - 2026-10-17T22:10:00Z @AI: Open every database pool with the configured database.pool_size.
+//! - 2026-10-17T18:10:00Z @AI: Initialize search settings in the TUI-built config.
+//! - 2026-10-17T16:50:00Z @AI: Initialize estimate on tasks created in the TUI.
+//! - 2026-10-17T16:30:00Z @AI: Initialize links on tasks created in the TUI.
+//! - 2026-10-17T14:50:00Z @AI: Setup wizard providers have auto_pull off.
+//! - 2026-10-17T14:10:00Z @AI: Open the database through services::database_url so --db / RIGGER_DB apply.
+//! - 2026-10-17T13:50:00Z @AI: Read the config path from services::config_path, honoring --config and RIGGER_CONFIG.
+//! - 2026-10-17T11:50:00Z @AI: Initialize idempotency_key on tasks created in the TUI.
+//! - 2026-10-17T10:50:00Z @AI: Show TaskManagerError messages from status keys.
+//! - 2026-10-17T10:30:00Z @AI: Add O to reopen a completed or archived task via ManageTaskUseCase::reopen().
+//! - 2026-10-17T09:30:00Z @AI: Config editor loads the base config without config.local.json since it saves back over it.
+//! - 2026-10-17T06:50:00Z @AI: Save description edits through Task::set_description() so changed descriptions mark comprehension tests stale.
+//! - 2026-10-17T01:30:00Z @AI: Chat through OpenAI-compatible providers.
+//! - 2026-10-17T00:30:00Z @AI: Persist chat turns per project in SQLite, restore them when the chat opens, and send the summary of trimmed turns to the agent.
+//! - 2026-10-17T00:10:00Z @AI: Initialize Persona::tool_call_limits; reset the chat agent's per-conversation tool call counts when the chat session is reset or reopened.
+//! - 2026-10-16T22:30:00Z @AI: Fill the new Artifact embedding_model/embedding_dimension fields.
+//! - 2026-10-16T21:30:00Z @AI: Setup wizard writes default sampling settings.
+//! - 2026-10-16T21:10:00Z @AI: Artifact generation and PRD RAG ingestion honor offline mode; setup wizard writes offline mode off.
+//! - 2026-10-16T20:30:00Z @AI: Setup wizard writes an empty pricing table.
+//! - 2026-10-16T19:30:00Z @AI: Setup wizard writes default server settings.
+//! - 2026-10-16T18:30:00Z @AI: Save config editor and setup wizard configs via RiggerConfig::save_to_file so a legacy-version config is backed up before being overwritten.
+//! - 2026-10-16T17:50:00Z @AI: Rank spotlight (/) task results with services::fuzzy_matcher (title and short-ID subsequence matching, top N) ahead of description-only matches.
+//! - 2026-10-16T17:30:00Z @AI: Add status keys (C complete, p in progress, x cancel/archive, u undo) backed by ManageTaskUseCase::change_status() and undo_status_change(). Invalid transitions flash a status message instead of applying.
+//! - 2026-10-16T11:30:00Z @AI: Populate PRD.requirements from stored raw_content when loading PRDs, via parse_requirement_tables().
 //! - 2025-12-04T21:30:00Z @AI: Fix LLM chat dialog and move context viewer to Dev Tools. User reported 'l' key was showing context prompt instead of clean chat interface. Removed context from chat history (line 4525-4528) - context is now sent silently to LLM. Added Context Viewer to Dev Tools (Navigation → TOOLS → Dev Tools → Context Viewer) for viewing/debugging the LLM agent context prompt (lines 9793-9817).
 //! - 2025-12-04T21:15:00Z @AI: Filter subtasks from Kanban board entirely. User reported scrolling issues because Kanban was showing both parent tasks AND subtasks as separate cards. Now Kanban only shows parent-level tasks (line 10544) - subtasks are only visible nested within parent cards in PRD view. This simplifies Kanban display and fixes scrolling.
 //! - 2025-12-04T21:00:00Z @AI: Fix get_filtered_tasks to include parent tasks without source_prd_id. Root cause: subtasks had source_prd_id set but parent tasks didn't, causing parents to be filtered out. Added second pass (lines 3912-3925) to include parent tasks whose children are in the project. This handles the case where task decomposition sets source_prd_id on subtasks but not on the parent.
@@ -491,10 +522,18 @@ struct App {
     llm_agent_current_response: String,
     /// Record of tool calls made by agent
     llm_agent_tool_calls: std::vec::Vec<ToolCall>,
+    /// Persistent chat history store, connected when the chat first opens
+    llm_chat_store: std::option::Option<task_orchestrator::adapters::sqlite_conversation_adapter::SqliteConversationAdapter>,
+    /// Summary of chat turns trimmed from the stored history
+    llm_chat_summary: std::option::Option<String>,
     /// Whether agent is processing/thinking (using tools)
     llm_agent_thinking: bool,
     /// LLM agent adapter for chain-of-thought conversations
     llm_agent_adapter: std::option::Option<std::sync::Arc<task_orchestrator::adapters::rig_agent_adapter::RigAgentAdapter>>,
+    /// Persona the chat agent takes on (Ctrl+P in the chat); None uses the default assistant
+    chat_persona_id: std::option::Option<String>,
+    /// Persona the current llm_agent_adapter was built with
+    llm_agent_persona_id: std::option::Option<String>,
     /// Active receiver for streaming agent responses
     llm_agent_receiver: std::option::Option<tokio::sync::mpsc::Receiver<task_orchestrator::ports::llm_agent_port::StreamToken>>,
     /// Number of retry attempts for current LLM call
@@ -896,6 +935,17 @@ enum ConfirmationAction {
     ArchiveTask { task_id: String },
 }
 
+/// Status change triggered by a single key on the selected task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum StatusKeyAction {
+    /// Move the task to this status (validated by TaskStatus::can_transition_to)
+    SetStatus(task_manager::domain::task_status::TaskStatus),
+    /// Restore the status before the last change, from revision history
+    Undo,
+    /// Move a completed or archived task back to Todo
+    Reopen,
+}
+
 /// Setup wizard steps for first-time initialization.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum SetupWizardStep {
@@ -1099,8 +1149,12 @@ impl App {
             llm_agent_streaming: false,
             llm_agent_current_response: String::new(),
             llm_agent_tool_calls: std::vec::Vec::new(),
+            llm_chat_store: std::option::Option::None,
+            llm_chat_summary: std::option::Option::None,
             llm_agent_thinking: false,
             llm_agent_adapter: std::option::Option::None,
+            chat_persona_id: std::option::Option::None,
+            llm_agent_persona_id: std::option::Option::None,
             llm_agent_receiver: std::option::Option::None,
             llm_agent_retry_count: 0,
             llm_agent_last_error: std::option::Option::None,
@@ -1230,7 +1284,7 @@ impl App {
         let current_dir = std::env::current_dir()?;
         let db_path = current_dir.join(".rigger").join("tasks.db");
 
-        if !db_path.exists() {
+        if crate::services::database_url::override_url().is_none() && !db_path.exists() {
             self.tasks = std::vec::Vec::new();
             self.is_loading = false;
             self.loading_message = std::option::Option::None;
@@ -1238,8 +1292,9 @@ impl App {
         }
 
         // Connect to database
-        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(
-            &std::format!("sqlite:{}", db_path.display())
+        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init_with(
+            &crate::services::database_url::url_for(&db_path),
+            &crate::services::database_pool::load_current(),
         ).await.map_err(|e| anyhow::anyhow!(e))?;
 
         // Load all tasks
@@ -1279,8 +1334,9 @@ impl App {
         self.db_adapter = std::option::Option::Some(std::sync::Arc::new(std::sync::Mutex::new(adapter)));
 
         // Initialize artifact adapter for semantic search
-        match task_manager::adapters::sqlite_artifact_adapter::SqliteArtifactAdapter::connect_and_init(
-            &std::format!("sqlite:{}", db_path.display())
+        match task_manager::adapters::sqlite_artifact_adapter::SqliteArtifactAdapter::connect_and_init_with(
+            &crate::services::database_url::url_for(&db_path),
+            &crate::services::database_pool::load_current(),
         ).await {
             std::result::Result::Ok(artifact_adapter) => {
                 self.artifact_adapter = std::option::Option::Some(std::sync::Arc::new(std::sync::Mutex::new(artifact_adapter)));
@@ -1292,7 +1348,7 @@ impl App {
         }
 
         // Initialize embedding adapter for semantic search
-        let config_path = current_dir.join(".rigger").join("config.json");
+        let config_path = crate::services::config_path::path_in(&current_dir.join(".rigger"));
         if config_path.exists() {
             if let std::result::Result::Ok(config_content) = std::fs::read_to_string(&config_path) {
                 if let std::result::Result::Ok(config) = serde_json::from_str::<serde_json::Value>(&config_content) {
@@ -1349,7 +1405,7 @@ impl App {
         let current_dir = std::env::current_dir()?;
         let db_path = current_dir.join(".rigger").join("tasks.db");
 
-        if !db_path.exists() {
+        if crate::services::database_url::override_url().is_none() && !db_path.exists() {
             self.projects = std::vec::Vec::new();
             self.is_loading = false;
             self.loading_message = std::option::Option::None;
@@ -1357,8 +1413,9 @@ impl App {
         }
 
         // Connect to database
-        let adapter = task_manager::adapters::sqlite_project_adapter::SqliteProjectAdapter::connect_and_init(
-            &std::format!("sqlite:{}", db_path.display())
+        let adapter = task_manager::adapters::sqlite_project_adapter::SqliteProjectAdapter::connect_and_init_with(
+            &crate::services::database_url::url_for(&db_path),
+            &crate::services::database_pool::load_current(),
         ).await.map_err(|e| {
             if e.contains("no such column") || e.contains("no such table") {
                 anyhow::anyhow!(
@@ -1425,14 +1482,15 @@ impl App {
         let current_dir = std::env::current_dir()?;
         let db_path = current_dir.join(".rigger").join("tasks.db");
 
-        if !db_path.exists() {
+        if crate::services::database_url::override_url().is_none() && !db_path.exists() {
             self.prds = std::vec::Vec::new();
             return std::result::Result::Ok(());
         }
 
         // Connect to database using task adapter (which creates prds table)
-        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(
-            &std::format!("sqlite:{}", db_path.display())
+        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init_with(
+            &crate::services::database_url::url_for(&db_path),
+            &crate::services::database_pool::load_current(),
         ).await.map_err(|e| anyhow::anyhow!(e))?;
 
         // Load all PRDs using raw SQL
@@ -1474,6 +1532,7 @@ impl App {
                 objectives,
                 tech_stack,
                 constraints,
+                requirements: task_manager::infrastructure::markdown_parsers::prd_parser::parse_requirement_tables(&raw_content),
                 raw_content,
                 created_at,
             }
@@ -1497,7 +1556,7 @@ impl App {
         let current_dir = std::env::current_dir()?;
         let db_path = current_dir.join(".rigger").join("tasks.db");
 
-        if !db_path.exists() {
+        if crate::services::database_url::override_url().is_none() && !db_path.exists() {
             self.personas = std::vec::Vec::new();
             self.is_loading = false;
             self.loading_message = std::option::Option::None;
@@ -1505,8 +1564,9 @@ impl App {
         }
 
         // Connect to database
-        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(
-            &std::format!("sqlite:{}", db_path.display())
+        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init_with(
+            &crate::services::database_url::url_for(&db_path),
+            &crate::services::database_pool::load_current(),
         ).await.map_err(|e| anyhow::anyhow!(e))?;
 
         // Load all personas sorted by name
@@ -1546,14 +1606,15 @@ impl App {
         let current_dir = std::env::current_dir()?;
         let db_path = current_dir.join(".rigger").join("tasks.db");
 
-        if !db_path.exists() {
+        if crate::services::database_url::override_url().is_none() && !db_path.exists() {
             self.artifacts = std::vec::Vec::new();
             return std::result::Result::Ok(());
         }
 
         // Connect to database using task adapter (which creates artifacts table)
-        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(
-            &std::format!("sqlite:{}", db_path.display())
+        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init_with(
+            &crate::services::database_url::url_for(&db_path),
+            &crate::services::database_pool::load_current(),
         ).await.map_err(|e| anyhow::anyhow!(e))?;
 
         // Load all artifacts using raw SQL (no embedding needed for display)
@@ -1601,6 +1662,8 @@ impl App {
                 mime_type: std::option::Option::None,
                 source_url: std::option::Option::None,
                 page_number: std::option::Option::None,
+                embedding_model: std::option::Option::None,
+                embedding_dimension: std::option::Option::None,
             }
         }).collect();
 
@@ -1765,7 +1828,7 @@ impl App {
         let current_dir = std::env::current_dir()?;
         let db_path = current_dir.join(".rigger").join("tasks.db");
 
-        if !db_path.exists() {
+        if crate::services::database_url::override_url().is_none() && !db_path.exists() {
             self.agent_tools = std::vec::Vec::new();
             self.is_loading = false;
             self.loading_message = std::option::Option::None;
@@ -1773,8 +1836,9 @@ impl App {
         }
 
         // Connect to database
-        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(
-            &std::format!("sqlite:{}", db_path.display())
+        let adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init_with(
+            &crate::services::database_url::url_for(&db_path),
+            &crate::services::database_pool::load_current(),
         ).await.map_err(|e| anyhow::anyhow!(e))?;
 
         // Load all agent tools sorted by category then name
@@ -1827,7 +1891,7 @@ impl App {
         let current_dir = std::env::current_dir()?;
         let db_path = current_dir.join(".rigger").join("tasks.db");
 
-        if !db_path.exists() {
+        if crate::services::database_url::override_url().is_none() && !db_path.exists() {
             self.db_tables = std::vec::Vec::new();
             self.is_loading = false;
             self.loading_message = std::option::Option::None;
@@ -1836,7 +1900,7 @@ impl App {
 
         // Connect to database
         let pool = sqlx::sqlite::SqlitePoolOptions::new()
-            .connect(&std::format!("sqlite:{}", db_path.display()))
+            .connect(&crate::services::database_url::url_for(&db_path))
             .await?;
 
         // Query for all user-created tables
@@ -1892,7 +1956,7 @@ impl App {
         let current_dir = std::env::current_dir()?;
         let db_path = current_dir.join(".rigger").join("tasks.db");
 
-        if !db_path.exists() {
+        if crate::services::database_url::override_url().is_none() && !db_path.exists() {
             self.is_loading = false;
             self.loading_message = std::option::Option::None;
             return std::result::Result::Err(anyhow::anyhow!("Database file not found"));
@@ -1900,7 +1964,7 @@ impl App {
 
         // Connect to database
         let pool = sqlx::sqlite::SqlitePoolOptions::new()
-            .connect(&std::format!("sqlite:{}", db_path.display()))
+            .connect(&crate::services::database_url::url_for(&db_path))
             .await?;
 
         // Get column names using PRAGMA
@@ -1978,7 +2042,7 @@ impl App {
         let current_dir = std::env::current_dir()?;
         let db_path = current_dir.join(".rigger").join("tasks.db");
 
-        if !db_path.exists() {
+        if crate::services::database_url::override_url().is_none() && !db_path.exists() {
             self.is_loading = false;
             self.loading_message = std::option::Option::None;
             return std::result::Result::Err(anyhow::anyhow!("Database file not found"));
@@ -1986,7 +2050,7 @@ impl App {
 
         // Connect to database
         let pool = sqlx::sqlite::SqlitePoolOptions::new()
-            .connect(&std::format!("sqlite:{}", db_path.display()))
+            .connect(&crate::services::database_url::url_for(&db_path))
             .await?;
 
         // Execute query
@@ -2091,8 +2155,9 @@ impl App {
         }
 
         // Determine config path
-        let config_path = directories::ProjectDirs::from("com", "rigger", "rigger")
-            .map(|dirs| dirs.config_dir().join("config.json"))
+        let config_path = crate::services::config_path::override_path()
+            .map(std::path::Path::to_path_buf)
+            .or_else(|| directories::ProjectDirs::from("com", "rigger", "rigger").map(|dirs| dirs.config_dir().join("config.json")))
             .unwrap_or_else(|| std::path::PathBuf::from(".rigger/config.json"));
 
         // Ensure parent directory exists
@@ -2100,11 +2165,14 @@ impl App {
             tokio::fs::create_dir_all(parent).await?;
         }
 
-        // Serialize to pretty JSON
-        let json = serde_json::to_string_pretty(config)?;
-
-        // Write to file
-        tokio::fs::write(&config_path, json).await?;
+        // Write to file (backs up a legacy-version config first)
+        let config_path_str = config_path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid config path"))?;
+        if let Some(backup) = config.save_to_file(config_path_str)? {
+            self.add_notification(
+                NotificationLevel::Info,
+                std::format!("Previous config version backed up to {}", backup.display())
+            );
+        }
 
         // Clear dirty flag after successful save
         if let Some(state) = &mut self.config_editor_state {
@@ -2122,11 +2190,13 @@ impl App {
     /// Opens the config editor dialog and loads configuration.
     async fn open_config_editor(&mut self) -> anyhow::Result<()> {
         // Load rigger_core config with automatic migration
-        let config_path = directories::ProjectDirs::from("com", "rigger", "rigger")
-            .map(|dirs| dirs.config_dir().join("config.json"))
+        let config_path = crate::services::config_path::override_path()
+            .map(std::path::Path::to_path_buf)
+            .or_else(|| directories::ProjectDirs::from("com", "rigger", "rigger").map(|dirs| dirs.config_dir().join("config.json")))
             .unwrap_or_else(|| std::path::PathBuf::from(".rigger/config.json"));
 
-        let config = rigger_core::RiggerConfig::load_with_migration(
+        // The editor saves back to config_path, so leave config.local.json out
+        let config = rigger_core::RiggerConfig::load_base_with_migration(
             config_path.to_str().unwrap_or(".rigger/config.json")
         )?;
 
@@ -2253,9 +2323,9 @@ impl App {
         // Get database and config paths
         let current_dir = std::env::current_dir()?;
         let db_path = current_dir.join(".rigger").join("tasks.db");
-        let config_path = current_dir.join(".rigger").join("config.json");
+        let config_path = crate::services::config_path::path_in(&current_dir.join(".rigger"));
 
-        if !db_path.exists() {
+        if crate::services::database_url::override_url().is_none() && !db_path.exists() {
             self.artifact_gen_is_generating = false;
             self.artifact_gen_error = std::option::Option::Some(String::from("Database not found. Run 'rig init' first."));
             return std::result::Result::Ok(());
@@ -2290,7 +2360,10 @@ impl App {
         let provider_factory = match task_orchestrator::adapters::provider_factory::ProviderFactory::new(
             embedding_provider,
             embedding_model,
-        ) {
+        )
+        .map_err(|e| e.to_string())
+        .and_then(|factory| factory.with_offline(config["offline"].as_bool().unwrap_or(false)).map_err(|e| e.to_string()))
+        {
             std::result::Result::Ok(factory) => factory,
             std::result::Result::Err(e) => {
                 self.artifact_gen_is_generating = false;
@@ -2310,8 +2383,8 @@ impl App {
             };
 
         // Create artifact adapter
-        let db_url = std::format!("sqlite:{}", db_path.display());
-        let artifact_adapter = match task_manager::adapters::sqlite_artifact_adapter::SqliteArtifactAdapter::connect_and_init(&db_url).await {
+        let db_url = crate::services::database_url::url_for(&db_path);
+        let artifact_adapter = match task_manager::adapters::sqlite_artifact_adapter::SqliteArtifactAdapter::connect_and_init_with(&db_url, &crate::services::database_pool::load_current()).await {
             std::result::Result::Ok(adapter) => std::sync::Arc::new(std::sync::Mutex::new(adapter)),
             std::result::Result::Err(e) => {
                 self.artifact_gen_is_generating = false;
@@ -2663,7 +2736,7 @@ impl App {
                         return false;
                     }
                 };
-                let config_path = current_dir.join(".rigger/config.json");
+                let config_path = crate::services::config_path::path_in(&current_dir.join(".rigger"));
 
                 match std::fs::read_to_string(&config_path) {
                     Ok(config_content) => {
@@ -2856,9 +2929,9 @@ impl App {
                         }
                     };
                     let db_path = current_dir.join(".rigger").join("tasks.db");
-                    let db_url = std::format!("sqlite:{}", db_path.display());
+                    let db_url = crate::services::database_url::url_for(&db_path);
 
-                    let personas = match task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url).await {
+                    let personas = match task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init_with(&db_url, &crate::services::database_pool::load_current()).await {
                         std::result::Result::Ok(adapter) => {
                             match sqlx::query("SELECT id, project_id, name, role, description, llm_provider, llm_model, is_default, created_at, updated_at FROM personas")
                                 .fetch_all(adapter.pool())
@@ -2896,6 +2969,7 @@ impl App {
                                                 created_at: created_at.with_timezone(&chrono::Utc),
                                                 updated_at: updated_at.with_timezone(&chrono::Utc),
                                                 enabled_tools: std::vec::Vec::new(),
+                                                tool_call_limits: std::collections::HashMap::new(),
                                             });
                                         }
                                     }
@@ -3160,9 +3234,9 @@ impl App {
                     }
                 };
                 let db_path = current_dir.join(".rigger/tasks.db");
-                let db_url = std::format!("sqlite:{}", db_path.display());
+                let db_url = crate::services::database_url::url_for(&db_path);
 
-                let adapter = match task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url).await {
+                let adapter = match task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init_with(&db_url, &crate::services::database_pool::load_current()).await {
                     Ok(a) => a,
                     Err(e) => {
                         self.prd_processing_state = PRDProcessingState::Failed {
@@ -3268,7 +3342,7 @@ impl App {
                 }
 
                 // Auto-decompose complex tasks (complexity >= 5)
-                let config_path = current_dir.join(".rigger/config.json");
+                let config_path = crate::services::config_path::path_in(&current_dir.join(".rigger"));
                 if let Ok(config_content) = std::fs::read_to_string(&config_path) {
                     if let Ok(config) = serde_json::from_str::<serde_json::Value>(&config_content) {
                         let model_name = config["model"]["main"]
@@ -3326,6 +3400,7 @@ impl App {
                                             // Update parent task with subtask IDs and Decomposed status
                                             let mut updated_parent = task.clone();
                                             updated_parent.subtask_ids = generated_subtasks.iter().map(|st| st.id.clone()).collect();
+                                            updated_parent.track_status_time(&task_manager::domain::task_status::TaskStatus::Decomposed, chrono::Utc::now());
                                             updated_parent.status = task_manager::domain::task_status::TaskStatus::Decomposed;
                                             if let Err(e) = adapter.save_async(updated_parent).await {
                                                 error_messages.push(std::format!("Failed to update parent task: {}", e));
@@ -3419,9 +3494,9 @@ impl App {
                     }
                 };
                 let db_path = current_dir.join(".rigger/tasks.db");
-                let db_url = std::format!("sqlite:{}", db_path.display());
+                let db_url = crate::services::database_url::url_for(&db_path);
 
-                let db_adapter = match task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url).await {
+                let db_adapter = match task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init_with(&db_url, &crate::services::database_pool::load_current()).await {
                     std::result::Result::Ok(a) => a,
                     std::result::Result::Err(e) => {
                         self.add_notification(
@@ -3500,7 +3575,7 @@ impl App {
                     }
                 };
                 let db_path = current_dir.join(".rigger/tasks.db");
-                let db_url = std::format!("sqlite:{}", db_path.display());
+                let db_url = crate::services::database_url::url_for(&db_path);
 
                 // Get provider from config (default to ollama)
                 let provider = self.prd_processing_config
@@ -3509,7 +3584,7 @@ impl App {
                     .unwrap_or("ollama");
 
                 // Create artifact repository adapter
-                let artifact_adapter = match task_manager::adapters::sqlite_artifact_adapter::SqliteArtifactAdapter::connect_and_init(&db_url).await {
+                let artifact_adapter = match task_manager::adapters::sqlite_artifact_adapter::SqliteArtifactAdapter::connect_and_init_with(&db_url, &crate::services::database_pool::load_current()).await {
                     std::result::Result::Ok(adapter) => adapter,
                     std::result::Result::Err(e) => {
                         self.add_notification(
@@ -3523,7 +3598,14 @@ impl App {
                 };
 
                 // Create embedding adapter using provider factory
-                let provider_factory = match task_orchestrator::adapters::provider_factory::ProviderFactory::new(provider, "default") {
+                let offline = self.prd_processing_config
+                    .as_ref()
+                    .and_then(|c| c["offline"].as_bool())
+                    .unwrap_or(false);
+                let provider_factory = match task_orchestrator::adapters::provider_factory::ProviderFactory::new(provider, "default")
+                    .map_err(|e| e.to_string())
+                    .and_then(|factory| factory.with_offline(offline).map_err(|e| e.to_string()))
+                {
                     std::result::Result::Ok(factory) => factory,
                     std::result::Result::Err(e) => {
                         self.add_notification(
@@ -3646,13 +3728,13 @@ impl App {
 
                 // Get database URL
                 let db_path = std::path::PathBuf::from(".rigger/tasks.db");
-                let db_url = std::format!("sqlite:{}", db_path.display());
+                let db_url = crate::services::database_url::url_for(&db_path);
 
                 // Create artifact adapter for vector search
-                match task_manager::adapters::sqlite_artifact_adapter::SqliteArtifactAdapter::connect_and_init(&db_url).await {
+                match task_manager::adapters::sqlite_artifact_adapter::SqliteArtifactAdapter::connect_and_init_with(&db_url, &crate::services::database_pool::load_current()).await {
                     std::result::Result::Ok(artifact_adapter) => {
                         // Create task adapter for linking
-                        match task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url).await {
+                        match task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init_with(&db_url, &crate::services::database_pool::load_current()).await {
                             std::result::Result::Ok(task_adapter) => {
                                 let mut linked_count = 0;
 
@@ -4050,7 +4132,8 @@ impl App {
         // Clone task title for notification (to avoid borrow checker issues)
         let task_title = task.title.clone();
 
-        // Update task status
+        // Update task status, closing or opening its time-tracking interval
+        task.track_status_time(&new_status, chrono::Utc::now());
         task.status = new_status;
         self.has_unsaved_changes = true;
 
@@ -4078,6 +4161,75 @@ impl App {
         std::result::Result::Ok(())
     }
 
+    /// Applies a status key action to the currently selected task.
+    ///
+    /// Persists through ManageTaskUseCase so the change is validated and
+    /// recorded in the task's revision history, then replaces the task in the
+    /// list. Invalid transitions and empty undo history only set a status
+    /// message.
+    async fn apply_status_key_action(&mut self, action: StatusKeyAction) -> anyhow::Result<()> {
+        // Guard: no tasks or no database adapter
+        if self.tasks.is_empty() || self.db_adapter.is_none() {
+            return std::result::Result::Ok(());
+        }
+
+        let task = &self.tasks[self.selected_task];
+        let task_id = task.id.clone();
+
+        let rejected = match action {
+            StatusKeyAction::SetStatus(ref new_status) if !task.status.can_transition_to(new_status) => {
+                std::option::Option::Some(std::format!(
+                    "Cannot change '{}' from {:?} to {:?}",
+                    truncate_string(&task.title, 20),
+                    task.status,
+                    new_status
+                ))
+            }
+            _ => std::option::Option::None,
+        };
+        if rejected.is_some() {
+            self.status_message = rejected;
+            return std::result::Result::Ok(());
+        }
+
+        let pool = match &self.db_adapter {
+            std::option::Option::Some(adapter) => adapter.lock().unwrap().pool().clone(),
+            std::option::Option::None => return std::result::Result::Ok(()),
+        };
+        let mut use_case = task_manager::use_cases::manage_task::ManageTaskUseCase::new(
+            task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::new(pool),
+        );
+
+        self.is_saving = true;
+        let result = match action {
+            StatusKeyAction::SetStatus(new_status) => use_case.change_status(&task_id, new_status),
+            StatusKeyAction::Undo => use_case.undo_status_change(&task_id),
+            StatusKeyAction::Reopen => use_case.reopen(&task_id),
+        };
+        self.is_saving = false;
+
+        match result {
+            std::result::Result::Ok(updated) => {
+                let message = std::format!(
+                    "Changed '{}' to {:?}",
+                    truncate_string(&updated.title, 20),
+                    updated.status
+                );
+                if let std::option::Option::Some(slot) = self.tasks.iter_mut().find(|t| t.id == updated.id) {
+                    *slot = updated;
+                }
+                self.last_saved_at = std::option::Option::Some(chrono::Utc::now());
+                self.status_message = std::option::Option::Some(message.clone());
+                self.add_notification(NotificationLevel::Success, message);
+            }
+            std::result::Result::Err(e) => {
+                self.status_message = std::option::Option::Some(e.to_string());
+            }
+        }
+
+        std::result::Result::Ok(())
+    }
+
     /// Returns current frame of loading spinner animation.
     ///
     /// Uses a simple rotating spinner: ⠋ ⠙ ⠹ ⠸ ⠼ ⠴ ⠦ ⠧ ⠇ ⠏
@@ -4225,7 +4377,7 @@ impl App {
         }
 
         let task = &mut self.tasks[self.selected_task];
-        task.status = match task.status {
+        let new_status = match task.status {
             task_manager::domain::task_status::TaskStatus::Todo => {
                 task_manager::domain::task_status::TaskStatus::InProgress
             }
@@ -4260,6 +4412,8 @@ impl App {
                 task_manager::domain::task_status::TaskStatus::Todo
             }
         };
+        task.track_status_time(&new_status, chrono::Utc::now());
+        task.status = new_status;
     }
 
     /// Cycles task status backward in the task editor (Phase 4).
@@ -4269,7 +4423,7 @@ impl App {
         }
 
         let task = &mut self.tasks[self.selected_task];
-        task.status = match task.status {
+        let new_status = match task.status {
             task_manager::domain::task_status::TaskStatus::Todo => {
                 task_manager::domain::task_status::TaskStatus::OrchestrationComplete
             }
@@ -4304,6 +4458,8 @@ impl App {
                 task_manager::domain::task_status::TaskStatus::Decomposed
             }
         };
+        task.track_status_time(&new_status, chrono::Utc::now());
+        task.status = new_status;
     }
 
     /// Cycles assignee forward through personas in the task editor.
@@ -4370,7 +4526,8 @@ impl App {
                 task.title = self.task_editor_input.trim().to_string();
             }
             TaskEditorField::Description => {
-                task.description = self.task_editor_input.clone();
+                // A material change marks the comprehension tests stale for regeneration
+                task.set_description(self.task_editor_input.clone());
             }
             TaskEditorField::Assignee => {
                 let input_trimmed = self.task_editor_input.trim();
@@ -4410,6 +4567,8 @@ impl App {
                         prd_task.description = edited_task.description.clone();
                         prd_task.agent_persona = edited_task.agent_persona.clone();
                         prd_task.status = edited_task.status.clone();
+                        prd_task.started_at = edited_task.started_at;
+                        prd_task.time_spent_seconds = edited_task.time_spent_seconds;
                         prd_task.updated_at = edited_task.updated_at;
                     }
                 }
@@ -4430,120 +4589,133 @@ impl App {
 
     /// Opens the LLM chat dialog with context about current project and task (Phase 5).
     fn open_llm_chat(&mut self) {
-        // Initialize agent adapter if not already done
-        if self.llm_agent_adapter.is_none() {
-            // Load config from rigger_core to get chat_agent slot configuration
-            let config_path = directories::ProjectDirs::from("com", "rigger", "rigger")
-                .map(|dirs| dirs.config_dir().join("config.json"))
-                .unwrap_or_else(|| std::path::PathBuf::from(".rigger/config.json"));
-
-            let config = rigger_core::RiggerConfig::load_with_migration(
-                config_path.to_str().unwrap_or(".rigger/config.json")
-            );
+        self.ensure_llm_agent_adapter();
 
-            // Instantiate tools for LLM agent (Phase 2: Tool Calling) - NOW WORKING!
-            let (search_artifacts_tool, search_tasks_tool, get_task_details_tool) = {
-                // Create tools if adapters are available
-                let search_artifacts = if let (std::option::Option::Some(embedding_adapter), std::option::Option::Some(artifact_adapter)) =
-                    (self.embedding_adapter.clone(), self.artifact_adapter.clone())
-                {
-                    std::option::Option::Some(task_orchestrator::tools::SearchArtifactsTool::new(
-                        embedding_adapter,
-                        artifact_adapter,
-                        self.selected_project_id.clone(),
-                    ))
-                } else {
-                    std::option::Option::None
-                };
+        // Clear previous chat history (context is sent silently to LLM, not shown in UI)
+        self.llm_chat_history.clear();
+        if let std::option::Option::Some(adapter) = &self.llm_agent_adapter {
+            adapter.reset_conversation();
+        }
+        self.llm_chat_input.clear();
+        // Footer expansion is now toggled with 'l' key, not via this method
+    }
 
-                let search_tasks = if let std::option::Option::Some(db_adapter) = self.db_adapter.clone() {
-                    std::option::Option::Some(task_orchestrator::tools::SearchTasksTool::new(
-                        db_adapter.clone(),
-                        self.selected_project_id.clone(),
-                    ))
-                } else {
-                    std::option::Option::None
-                };
+    /// Builds the chat agent adapter on first use and whenever the chat persona changed.
+    fn ensure_llm_agent_adapter(&mut self) {
+        if self.llm_agent_adapter.is_some() && self.llm_agent_persona_id == self.chat_persona_id {
+            return;
+        }
+        let adapter = self.build_llm_agent_adapter();
+        self.llm_agent_adapter = std::option::Option::Some(std::sync::Arc::new(adapter));
+        self.llm_agent_persona_id = self.chat_persona_id.clone();
+    }
 
-                let get_task_details = if let std::option::Option::Some(db_adapter) = self.db_adapter.clone() {
-                    std::option::Option::Some(task_orchestrator::tools::GetTaskDetailsTool::new(
-                        db_adapter,
-                    ))
-                } else {
-                    std::option::Option::None
-                };
+    /// Returns the persona selected for chat, if it is among the loaded personas.
+    fn chat_persona(&self) -> std::option::Option<&task_manager::domain::persona::Persona> {
+        let id = self.chat_persona_id.as_ref()?;
+        self.personas.iter().find(|persona| &persona.id == id)
+    }
 
-                (search_artifacts, search_tasks, get_task_details)
+    /// Switches the chat to the next persona (after the last, back to the default assistant).
+    ///
+    /// The agent is rebuilt with the persona's system prompt, tools, and
+    /// tool call limits; the conversation shown so far is kept.
+    fn cycle_chat_persona(&mut self) {
+        let next = match &self.chat_persona_id {
+            std::option::Option::None => self.personas.first(),
+            std::option::Option::Some(id) => self
+                .personas
+                .iter()
+                .position(|persona| &persona.id == id)
+                .and_then(|index| self.personas.get(index + 1)),
+        };
+        let label = next
+            .map(|persona| std::format!("{} ({})", persona.name, persona.role))
+            .unwrap_or_else(|| String::from("default assistant"));
+        self.chat_persona_id = next.map(|persona| persona.id.clone());
+        self.ensure_llm_agent_adapter();
+        self.add_notification(NotificationLevel::Info, std::format!("Chat persona: {}", label));
+    }
+
+    /// Creates the chat agent adapter from the chat_agent slot, with the chat persona applied.
+    fn build_llm_agent_adapter(&mut self) -> task_orchestrator::adapters::rig_agent_adapter::RigAgentAdapter {
+        // The project's config, merged with config.local.json, holds the chat_agent slot
+        let config = crate::services::config_path::current()
+            .map_err(|e| e.to_string())
+            .and_then(|path| rigger_core::RiggerConfig::load_with_migration(&path.to_string_lossy()).map_err(|e| e.to_string()));
+
+        // Instantiate tools for LLM agent (Phase 2: Tool Calling) - NOW WORKING!
+        let (search_artifacts_tool, search_tasks_tool, get_task_details_tool) = {
+            // Create tools if adapters are available
+            let search_artifacts = if let (std::option::Option::Some(embedding_adapter), std::option::Option::Some(artifact_adapter)) =
+                (self.embedding_adapter.clone(), self.artifact_adapter.clone())
+            {
+                std::option::Option::Some(task_orchestrator::tools::SearchArtifactsTool::new(
+                    embedding_adapter,
+                    artifact_adapter,
+                    self.selected_project_id.clone(),
+                ))
+            } else {
+                std::option::Option::None
             };
 
-            let adapter = match config {
-                std::result::Result::Ok(cfg) => {
-                    // Use chat_agent slot from config
-                    let chat_slot = &cfg.task_slots.chat_agent;
+            let search_tasks = if let std::option::Option::Some(db_adapter) = self.db_adapter.clone() {
+                std::option::Option::Some(task_orchestrator::tools::SearchTasksTool::new(
+                    db_adapter.clone(),
+                    self.selected_project_id.clone(),
+                ))
+            } else {
+                std::option::Option::None
+            };
 
-                    if !chat_slot.enabled {
-                        // Chat agent disabled - show notification
-                        self.add_notification(
-                            NotificationLevel::Warning,
-                            String::from("Chat agent is disabled in config. Enable it in Dev Tools → Config Editor.")
-                        );
-                    }
+            let get_task_details = if let std::option::Option::Some(db_adapter) = self.db_adapter.clone() {
+                std::option::Option::Some(task_orchestrator::tools::GetTaskDetailsTool::new(
+                    db_adapter,
+                ))
+            } else {
+                std::option::Option::None
+            };
 
-                    // Get provider config
-                    if let Some(provider) = cfg.providers.get(&chat_slot.provider) {
-                        // Create adapter based on provider type
-                        match provider.provider_type {
-                            rigger_core::config::ProviderType::OpenAI => {
-                                // Get API key from environment
-                                match provider.get_api_key() {
-                                    std::result::Result::Ok(std::option::Option::Some(api_key)) => {
-                                        std::sync::Arc::new(
-                                            task_orchestrator::adapters::rig_agent_adapter::RigAgentAdapter::new_openai_with_tools(
-                                                api_key,
-                                                chat_slot.model.clone(),
-                                                search_artifacts_tool.clone(),
-                                                search_tasks_tool.clone(),
-                                                get_task_details_tool.clone(),
-                                            )
-                                        )
-                                    }
-                                    _ => {
-                                        self.add_notification(
-                                            NotificationLevel::Error,
-                                            std::format!("OpenAI API key not found. Set {} environment variable.",
-                                                provider.api_key_env.as_ref().unwrap_or(&String::from("OPENAI_API_KEY")))
-                                        );
-                                        // Fallback to Ollama
-                                        std::sync::Arc::new(
-                                            task_orchestrator::adapters::rig_agent_adapter::RigAgentAdapter::new_ollama_with_tools(
-                                                String::from("http://localhost:11434"),
-                                                String::from("llama3.2"),
-                                                search_artifacts_tool.clone(),
-                                                search_tasks_tool.clone(),
-                                                get_task_details_tool.clone(),
-                                            )
-                                        )
-                                    }
-                                }
-                            }
-                            rigger_core::config::ProviderType::Ollama => {
-                                std::sync::Arc::new(
-                                    task_orchestrator::adapters::rig_agent_adapter::RigAgentAdapter::new_ollama_with_tools(
-                                        provider.base_url.clone(),
+            (search_artifacts, search_tasks, get_task_details)
+        };
+
+        let mut adapter = match config {
+            std::result::Result::Ok(cfg) => {
+                // Use chat_agent slot from config
+                let chat_slot = &cfg.task_slots.chat_agent;
+
+                if !chat_slot.enabled {
+                    // Chat agent disabled - show notification
+                    self.add_notification(
+                        NotificationLevel::Warning,
+                        String::from("Chat agent is disabled in config. Enable it in Dev Tools → Config Editor.")
+                    );
+                }
+
+                // Get provider config
+                if let Some(provider) = cfg.providers.get(&chat_slot.provider) {
+                    // Create adapter based on provider type
+                    match provider.provider_type {
+                        rigger_core::config::ProviderType::OpenAI => {
+                            // Get API key from environment
+                            match provider.get_api_key() {
+                                std::result::Result::Ok(std::option::Option::Some(api_key)) => {
+                                    task_orchestrator::adapters::rig_agent_adapter::RigAgentAdapter::new_openai_with_tools(
+                                        api_key,
                                         chat_slot.model.clone(),
                                         search_artifacts_tool.clone(),
                                         search_tasks_tool.clone(),
                                         get_task_details_tool.clone(),
                                     )
-                                )
-                            }
-                            _ => {
-                                self.add_notification(
-                                    NotificationLevel::Warning,
-                                    std::format!("Provider type {:?} not yet supported for chat. Using Ollama fallback.", provider.provider_type)
-                                );
-                                // Fallback to Ollama
-                                std::sync::Arc::new(
+                                    .with_retry_policy(task_orchestrator::infrastructure::retry_policy::RetryPolicy::from_provider_config(provider))
+                                }
+                                _ => {
+                                    self.add_notification(
+                                        NotificationLevel::Error,
+                                        std::format!("OpenAI API key not found. Set {} environment variable.",
+                                            provider.api_key_env.as_ref().unwrap_or(&String::from("OPENAI_API_KEY")))
+                                    );
+                                    // Fallback to Ollama
                                     task_orchestrator::adapters::rig_agent_adapter::RigAgentAdapter::new_ollama_with_tools(
                                         String::from("http://localhost:11434"),
                                         String::from("llama3.2"),
@@ -4551,16 +4723,38 @@ impl App {
                                         search_tasks_tool.clone(),
                                         get_task_details_tool.clone(),
                                     )
-                                )
+                                }
                             }
                         }
-                    } else {
-                        self.add_notification(
-                            NotificationLevel::Error,
-                            std::format!("Provider '{}' not found in config. Using Ollama fallback.", chat_slot.provider)
-                        );
-                        // Fallback to Ollama
-                        std::sync::Arc::new(
+                        rigger_core::config::ProviderType::OpenAiCompatible => {
+                            // Local inference server: the key is optional
+                            task_orchestrator::adapters::rig_agent_adapter::RigAgentAdapter::new_openai_compatible_with_tools(
+                                provider.base_url.clone(),
+                                provider.get_api_key().ok().flatten(),
+                                chat_slot.model.clone(),
+                                search_artifacts_tool.clone(),
+                                search_tasks_tool.clone(),
+                                get_task_details_tool.clone(),
+                            )
+                            .with_retry_policy(task_orchestrator::infrastructure::retry_policy::RetryPolicy::from_provider_config(provider))
+                        }
+                        rigger_core::config::ProviderType::Ollama => {
+                            task_orchestrator::adapters::rig_agent_adapter::RigAgentAdapter::new_ollama_with_tools(
+                                provider.base_url.clone(),
+                                chat_slot.model.clone(),
+                                search_artifacts_tool.clone(),
+                                search_tasks_tool.clone(),
+                                get_task_details_tool.clone(),
+                            )
+                            .with_auto_pull(provider.auto_pull)
+                            .with_retry_policy(task_orchestrator::infrastructure::retry_policy::RetryPolicy::from_provider_config(provider))
+                        }
+                        _ => {
+                            self.add_notification(
+                                NotificationLevel::Warning,
+                                std::format!("Provider type {:?} not yet supported for chat. Using Ollama fallback.", provider.provider_type)
+                            );
+                            // Fallback to Ollama
                             task_orchestrator::adapters::rig_agent_adapter::RigAgentAdapter::new_ollama_with_tools(
                                 String::from("http://localhost:11434"),
                                 String::from("llama3.2"),
@@ -4568,34 +4762,134 @@ impl App {
                                 search_tasks_tool.clone(),
                                 get_task_details_tool.clone(),
                             )
-                        )
+                        }
                     }
-                }
-                std::result::Result::Err(e) => {
+                } else {
                     self.add_notification(
                         NotificationLevel::Error,
-                        std::format!("Failed to load config: {}. Using Ollama fallback.", e)
+                        std::format!("Provider '{}' not found in config. Using Ollama fallback.", chat_slot.provider)
                     );
                     // Fallback to Ollama
-                    std::sync::Arc::new(
-                        task_orchestrator::adapters::rig_agent_adapter::RigAgentAdapter::new_ollama_with_tools(
-                            String::from("http://localhost:11434"),
-                            String::from("llama3.2"),
-                            search_artifacts_tool,
-                            search_tasks_tool,
-                            get_task_details_tool,
-                        )
+                    task_orchestrator::adapters::rig_agent_adapter::RigAgentAdapter::new_ollama_with_tools(
+                        String::from("http://localhost:11434"),
+                        String::from("llama3.2"),
+                        search_artifacts_tool.clone(),
+                        search_tasks_tool.clone(),
+                        get_task_details_tool.clone(),
                     )
                 }
+            }
+            std::result::Result::Err(e) => {
+                self.add_notification(
+                    NotificationLevel::Error,
+                    std::format!("Failed to load config: {}. Using Ollama fallback.", e)
+                );
+                // Fallback to Ollama
+                task_orchestrator::adapters::rig_agent_adapter::RigAgentAdapter::new_ollama_with_tools(
+                    String::from("http://localhost:11434"),
+                    String::from("llama3.2"),
+                    search_artifacts_tool,
+                    search_tasks_tool,
+                    get_task_details_tool,
+                )
+            }
+        };
+
+        // The persona shapes the system prompt, the allowed tools, and their call limits
+        if let std::option::Option::Some(persona) = self.chat_persona() {
+            adapter.apply_persona(persona);
+        }
+        adapter
+    }
+
+    /// Restores the selected project's saved chat turns into the chat history.
+    ///
+    /// Connects the conversation store on first use, keeping at most
+    /// `tui.chat_history_turns` turns per project. Turns trimmed from that
+    /// window come back as a summary, which is sent to the agent with the
+    /// context rather than shown in the chat.
+    async fn restore_chat_history(&mut self) {
+        if self.llm_chat_store.is_none() {
+            let max_turns = crate::services::config_path::current()
+                .ok()
+                .and_then(|path| rigger_core::RiggerConfig::load_with_migration(&path.to_string_lossy()).ok())
+                .map(|cfg| cfg.tui.chat_history_turns)
+                .unwrap_or(task_orchestrator::adapters::sqlite_conversation_adapter::DEFAULT_MAX_TURNS);
+
+            let db_path = match std::env::current_dir() {
+                std::result::Result::Ok(dir) => dir.join(".rigger").join("tasks.db"),
+                std::result::Result::Err(_) => return,
             };
+            if crate::services::database_url::override_url().is_none() && !db_path.exists() {
+                return;
+            }
 
-            self.llm_agent_adapter = std::option::Option::Some(adapter);
+            match task_orchestrator::adapters::sqlite_conversation_adapter::SqliteConversationAdapter::connect_and_init_with(
+                &crate::services::database_url::url_for(&db_path),
+                &crate::services::database_pool::load_current(),
+            ).await {
+                std::result::Result::Ok(store) => {
+                    self.llm_chat_store = std::option::Option::Some(store.with_max_turns(max_turns));
+                }
+                std::result::Result::Err(e) => {
+                    self.add_notification(NotificationLevel::Warning, std::format!("Chat history unavailable: {}", e));
+                    return;
+                }
+            }
         }
 
-        // Clear previous chat history (context is sent silently to LLM, not shown in UI)
-        self.llm_chat_history.clear();
-        self.llm_chat_input.clear();
-        // Footer expansion is now toggled with 'l' key, not via this method
+        let store = match &self.llm_chat_store {
+            std::option::Option::Some(store) => store.clone(),
+            std::option::Option::None => return,
+        };
+
+        match task_orchestrator::ports::conversation_store_port::ConversationStorePort::load_history(&store, &self.chat_session_id()).await {
+            std::result::Result::Ok(history) => {
+                self.llm_chat_summary = history.summary;
+                self.llm_chat_history = history.turns.into_iter().map(|turn| ChatMessage {
+                    role: match turn.role {
+                        task_orchestrator::ports::llm_agent_port::AgentRole::User => ChatRole::User,
+                        task_orchestrator::ports::llm_agent_port::AgentRole::Assistant => ChatRole::Assistant,
+                        task_orchestrator::ports::llm_agent_port::AgentRole::System => ChatRole::System,
+                    },
+                    content: turn.content,
+                }).collect();
+            }
+            std::result::Result::Err(e) => {
+                self.add_notification(NotificationLevel::Warning, std::format!("Failed to load chat history: {}", e));
+            }
+        }
+    }
+
+    /// Returns the conversation store session for the selected project.
+    fn chat_session_id(&self) -> String {
+        self.selected_project_id.clone().unwrap_or_else(|| String::from("default"))
+    }
+
+    /// Saves a chat turn to the conversation store in the background.
+    ///
+    /// Does nothing if the store is not connected.
+    fn persist_chat_turn(
+        &self,
+        role: task_orchestrator::ports::llm_agent_port::AgentRole,
+        content: String,
+        tool_calls: std::vec::Vec<task_orchestrator::ports::conversation_store_port::RecordedToolCall>,
+    ) {
+        if let std::option::Option::Some(store) = self.llm_chat_store.clone() {
+            let mut turn = task_orchestrator::ports::conversation_store_port::ConversationTurn::new(
+                self.chat_session_id(),
+                role,
+                content,
+            );
+            turn.tool_calls = tool_calls;
+            tokio::spawn(async move {
+                if let std::result::Result::Err(e) =
+                    task_orchestrator::ports::conversation_store_port::ConversationStorePort::append_turn(&store, turn).await
+                {
+                    tracing::warn!(error = %e, "failed to save chat turn");
+                }
+            });
+        }
     }
 
     /// Builds comprehensive context for the LLM agent.
@@ -4896,6 +5190,11 @@ impl App {
             role: ChatRole::User,
             content: self.llm_chat_input.clone(),
         });
+        self.persist_chat_turn(
+            task_orchestrator::ports::llm_agent_port::AgentRole::User,
+            self.llm_chat_input.clone(),
+            std::vec::Vec::new(),
+        );
 
         // Clear input and reset scroll to show new messages
         self.llm_chat_input.clear();
@@ -4913,8 +5212,12 @@ impl App {
             return std::result::Result::Ok(());
         };
 
-        // Build comprehensive context
-        let context = self.build_agent_context();
+        // Build comprehensive context, including what was trimmed from the saved history
+        let mut context = self.build_agent_context();
+        if let std::option::Option::Some(summary) = &self.llm_chat_summary {
+            context.push_str("\n\nSummary of earlier conversation:\n");
+            context.push_str(summary);
+        }
 
         // Convert chat history to AgentMessage format, prepending context as system message
         let mut messages = std::vec::Vec::new();
@@ -5058,6 +5361,20 @@ impl App {
     /// Resets the agent chat session, clearing all state.
     fn reset_chat_session(&mut self) {
         self.llm_chat_history.clear();
+        self.llm_chat_summary = std::option::Option::None;
+        if let std::option::Option::Some(store) = self.llm_chat_store.clone() {
+            let session_id = self.chat_session_id();
+            tokio::spawn(async move {
+                if let std::result::Result::Err(e) =
+                    task_orchestrator::ports::conversation_store_port::ConversationStorePort::clear_session(&store, &session_id).await
+                {
+                    tracing::warn!(error = %e, "failed to clear saved chat history");
+                }
+            });
+        }
+        if let std::option::Option::Some(adapter) = &self.llm_agent_adapter {
+            adapter.reset_conversation();
+        }
         self.llm_agent_streaming = false;
         self.llm_agent_current_response.clear();
         self.llm_agent_tool_calls.clear();
@@ -5086,6 +5403,18 @@ impl App {
                 role: ChatRole::Assistant,
                 content: self.llm_agent_current_response.clone(),
             });
+            let tool_calls = self.llm_agent_tool_calls.iter().map(|call| {
+                task_orchestrator::ports::conversation_store_port::RecordedToolCall {
+                    tool_name: call.tool_name.clone(),
+                    args_json: call.args.clone(),
+                    result: call.result.clone(),
+                }
+            }).collect();
+            self.persist_chat_turn(
+                task_orchestrator::ports::llm_agent_port::AgentRole::Assistant,
+                self.llm_agent_current_response.clone(),
+                tool_calls,
+            );
             self.llm_agent_current_response.clear();
         }
         self.llm_agent_streaming = false;
@@ -5275,6 +5604,17 @@ impl App {
             context_files: std::vec::Vec::new(),
             dependencies: std::vec::Vec::new(),
             sort_order: std::option::Option::None,
+            source_section: std::option::Option::None,
+            priority: std::option::Option::None,
+            tags: std::vec::Vec::new(),
+            revisions: std::vec::Vec::new(),
+            checklist: std::vec::Vec::new(),
+            started_at: std::option::Option::None,
+            time_spent_seconds: 0,
+            source_utterance: std::option::Option::None,
+            idempotency_key: std::option::Option::None,
+            links: std::vec::Vec::new(),
+            estimate: std::option::Option::None,
         };
 
         // Link to first PRD of current project (if available)
@@ -5361,7 +5701,8 @@ impl App {
                 std::option::Option::None
             };
 
-        // Search tasks (substring matching, filtered by project)
+        // Search tasks (filtered by project)
+        let mut project_tasks = std::vec::Vec::new();
         for task in &self.tasks {
             // Filter by project if one is selected
             if let std::option::Option::Some(ref prd_ids) = project_prd_ids {
@@ -5373,16 +5714,26 @@ impl App {
                     continue; // Skip tasks without PRD when project is selected
                 }
             }
+            project_tasks.push(task.clone());
+        }
 
-            if task.title.to_lowercase().contains(&query_lower)
-                || task.description.to_lowercase().contains(&query_lower) {
-                results.push(SearchResultType::Task {
-                    id: task.id.clone(),
-                    title: task.title.clone(),
-                    description: task.description.clone(),
-                    score: None,
-                });
-            }
+        // Fuzzy title/short-ID matches first (best first), then description-only substring matches
+        let ranked = crate::services::fuzzy_matcher::rank_tasks(
+            query,
+            &project_tasks,
+            crate::services::fuzzy_matcher::DEFAULT_LIMIT,
+        );
+        let description_matches = project_tasks.iter().filter(|task| {
+            task.description.to_lowercase().contains(&query_lower)
+                && !ranked.iter().any(|r| r.id == task.id)
+        });
+        for task in ranked.iter().copied().chain(description_matches) {
+            results.push(SearchResultType::Task {
+                id: task.id.clone(),
+                title: task.title.clone(),
+                description: task.description.clone(),
+                score: None,
+            });
         }
 
         // Search PRDs (substring matching, filtered by project)
@@ -6010,6 +6361,7 @@ impl App {
                 ConfirmationAction::ArchiveTask { task_id } => {
                     // Find the task and archive it
                     if let std::option::Option::Some(task) = self.tasks.iter_mut().find(|t| t.id == task_id) {
+                        task.track_status_time(&task_manager::domain::task_status::TaskStatus::Archived, chrono::Utc::now());
                         task.status = task_manager::domain::task_status::TaskStatus::Archived;
                         task.updated_at = chrono::Utc::now();
 
@@ -6910,6 +7262,7 @@ impl App {
                     timeout_seconds: 120,
                     max_retries: 2,
                     default_model: String::from("llama3.2"),
+                    auto_pull: false,
                 },
                 LLMProvider::Anthropic => rigger_core::config::ProviderConfig {
                     provider_type: rigger_core::config::ProviderType::Anthropic,
@@ -6918,6 +7271,7 @@ impl App {
                     timeout_seconds: 120,
                     max_retries: 3,
                     default_model: String::from("claude-3-5-sonnet-20241022"),
+                    auto_pull: false,
                 },
                 LLMProvider::OpenAI => rigger_core::config::ProviderConfig {
                     provider_type: rigger_core::config::ProviderType::OpenAI,
@@ -6926,6 +7280,7 @@ impl App {
                     timeout_seconds: 60,
                     max_retries: 3,
                     default_model: String::from("gpt-4o-mini"),
+                    auto_pull: false,
                 },
                 LLMProvider::Mistral => rigger_core::config::ProviderConfig {
                     provider_type: rigger_core::config::ProviderType::Mistral,
@@ -6934,6 +7289,7 @@ impl App {
                     timeout_seconds: 60,
                     max_retries: 3,
                     default_model: String::from("mistral-small-latest"),
+                    auto_pull: false,
                 },
                 LLMProvider::Groq => rigger_core::config::ProviderConfig {
                     provider_type: rigger_core::config::ProviderType::Groq,
@@ -6942,6 +7298,7 @@ impl App {
                     timeout_seconds: 30,
                     max_retries: 3,
                     default_model: String::from("llama-3.3-70b-versatile"),
+                    auto_pull: false,
                 },
                 LLMProvider::Cohere => rigger_core::config::ProviderConfig {
                     provider_type: rigger_core::config::ProviderType::Cohere,
@@ -6950,6 +7307,7 @@ impl App {
                     timeout_seconds: 60,
                     max_retries: 3,
                     default_model: String::from("command-r-plus"),
+                    auto_pull: false,
                 },
                 LLMProvider::Candle => rigger_core::config::ProviderConfig {
                     provider_type: rigger_core::config::ProviderType::Ollama,
@@ -6958,6 +7316,7 @@ impl App {
                     timeout_seconds: 120,
                     max_retries: 2,
                     default_model: String::from("microsoft/Phi-3.5-mini-instruct"),
+                    auto_pull: false,
                 },
             };
 
@@ -7022,12 +7381,19 @@ impl App {
             task_slots,
             performance: rigger_core::config::PerformanceConfig::default(),
             tui: rigger_core::config::TuiConfig::default(),
+            server: rigger_core::config::ServerConfig::default(),
+            pricing: std::collections::HashMap::new(),
+            offline: false,
+            sampling: rigger_core::config::SamplingConfig::default(),
+            search: rigger_core::config::SearchConfig::default(),
+            orchestration: rigger_core::config::OrchestrationConfig::default(),
         };
 
-        // Serialize and write config
-        let config_json = serde_json::to_string_pretty(&config)?;
-        let config_path = rigger_dir.join("config.json");
-        std::fs::write(&config_path, config_json)?;
+        // Write config (backs up a legacy-version config first)
+        let config_path = crate::services::config_path::path_in(&rigger_dir);
+        config.save_to_file(
+            config_path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid config path"))?
+        )?;
 
         // Initialize SQLite database
         let db_path = rigger_dir.join("tasks.db");
@@ -7036,8 +7402,8 @@ impl App {
                 .map_err(|e| anyhow::anyhow!("Failed to create database file: {}", e))?;
         }
 
-        let db_url = std::format!("sqlite:{}", db_path.display());
-        let _adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init(&db_url)
+        let db_url = crate::services::database_url::url_for(&db_path);
+        let _adapter = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init_with(&db_url, &crate::services::database_pool::load_current())
             .await
             .map_err(|e| anyhow::anyhow!("Failed to initialize database: {}", e))?;
 
@@ -7158,7 +7524,7 @@ pub async fn execute() -> anyhow::Result<()> {
 
     // Check if .rigger/config.json exists - activate setup wizard if not
     let current_dir = std::env::current_dir()?;
-    let config_path = current_dir.join(".rigger").join("config.json");
+    let config_path = crate::services::config_path::path_in(&current_dir.join(".rigger"));
 
     if !config_path.exists() {
         // Activate setup wizard for first-time initialization
@@ -8220,6 +8586,10 @@ async fn run_app<B: ratatui::backend::Backend>(
                         // Handle text input in task creator dialog
                         app.handle_task_creator_input(c);
                     }
+                    KeyCode::Char('p') if app.footer_expanded && key.modifiers.contains(crossterm::event::KeyModifiers::CONTROL) => {
+                        // Ctrl+P: chat as the next persona
+                        app.cycle_chat_persona();
+                    }
                     KeyCode::Char(c) if app.footer_expanded => {
                         // Handle text input in LLM chat footer
                         app.handle_llm_chat_input(c);
@@ -8312,6 +8682,8 @@ async fn run_app<B: ratatui::backend::Backend>(
                         if !app.show_task_editor_dialog && !app.show_jump_dialog && !app.show_spotlight_dialog {
                             // Initialize the LLM agent adapter
                             app.open_llm_chat();
+                            // Bring back this project's earlier conversation
+                            app.restore_chat_history().await;
                             // Then expand the footer
                             app.footer_expanded = true;
                         }
@@ -8343,6 +8715,20 @@ async fn run_app<B: ratatui::backend::Backend>(
                         // Copy task to clipboard
                         app.copy_task_to_clipboard();
                     }
+                    KeyCode::Char('C') | KeyCode::Char('p') | KeyCode::Char('x') | KeyCode::Char('u') | KeyCode::Char('O')
+                        if !app.show_jump_dialog =>
+                    {
+                        // Change selected task status (C complete, p in progress, x cancel, u undo, O reopen)
+                        let result = match status_key_action(key.code) {
+                            std::option::Option::Some(action) => app.apply_status_key_action(action).await,
+                            std::option::Option::None => std::result::Result::Ok(()),
+                        };
+                        if let std::result::Result::Err(e) = result {
+                            app.status_message = std::option::Option::Some(
+                                std::format!("Error: {}", e)
+                            );
+                        }
+                    }
                     KeyCode::Char('y') | KeyCode::Char('Y') => {
                         // Phase 10: Confirm action in confirmation dialog
                         if app.show_confirmation_dialog {
@@ -8913,11 +9299,18 @@ fn render_expanded_llm_chat_footer(f: &mut Frame, area: Rect, app: &App) {
     // STEP 1: Split all messages into individual Lines (tuichat pattern)
     let mut message_lines = std::vec![];
 
+    let persona_info = app
+        .chat_persona()
+        .map(|persona| std::format!("👤 {}", persona.name))
+        .unwrap_or_else(|| String::from("👤 Default assistant"));
+
     // Header
     message_lines.push(Line::from(vec![
         Span::styled(context_info, Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
         Span::raw("  "),
-        Span::styled("(Esc to collapse)", Style::default().fg(Color::DarkGray)),
+        Span::styled(persona_info, Style::default().fg(Color::Magenta)),
+        Span::raw("  "),
+        Span::styled("(Ctrl+P persona, Esc to collapse)", Style::default().fg(Color::DarkGray)),
     ]));
     message_lines.push(Line::from(""));
 
@@ -10071,7 +10464,7 @@ fn render_config_viewer(f: &mut Frame, area: Rect, _app: &App) {
     lines.push(Line::from(""));
 
     // Try to parse config.json and display task tools configuration
-    let config_json_path = rigger_dir.join("config.json");
+    let config_json_path = crate::services::config_path::path_in(&rigger_dir);
 
     lines.push(Line::from(Span::styled(
         " 🔧 Task Tool Slots:",
@@ -11380,6 +11773,8 @@ fn render_help(f: &mut Frame, area: Rect) {
         "║    n                  View notification center            ║",
         "║    c                  Copy task to clipboard (Markdown)   ║",
         "║    s                  Cycle task status (TODO→IN_PROG→✓)  ║",
+        "║    C / p / x          Complete / In progress / Cancel     ║",
+        "║    u                  Undo last status change             ║",
         "║    o                  Sort tasks (by date, title, etc.)   ║",
         "║    r                  Refresh tasks from database         ║",
         "║    F1-F5              Select column (Todo/InProg/Done/Arch/Err) ║",
@@ -15166,6 +15561,28 @@ fn render_prd_processing(f: &mut Frame, area: Rect, app: &App) {
     }
 }
 
+/// Maps a key to the status change it triggers on the selected task.
+///
+/// `c` is already bound to copy, so complete uses `C`, and `o` opens the sort
+/// menu, so reopen uses `O`. Cancel archives the task, since there is no
+/// separate cancelled status.
+fn status_key_action(code: KeyCode) -> std::option::Option<StatusKeyAction> {
+    match code {
+        KeyCode::Char('C') => std::option::Option::Some(StatusKeyAction::SetStatus(
+            task_manager::domain::task_status::TaskStatus::Completed,
+        )),
+        KeyCode::Char('p') => std::option::Option::Some(StatusKeyAction::SetStatus(
+            task_manager::domain::task_status::TaskStatus::InProgress,
+        )),
+        KeyCode::Char('x') => std::option::Option::Some(StatusKeyAction::SetStatus(
+            task_manager::domain::task_status::TaskStatus::Archived,
+        )),
+        KeyCode::Char('u') => std::option::Option::Some(StatusKeyAction::Undo),
+        KeyCode::Char('O') => std::option::Option::Some(StatusKeyAction::Reopen),
+        _ => std::option::Option::None,
+    }
+}
+
 /// Truncates a string to the specified length and adds "..." if truncated.
 ///
 /// This function is UTF-8 safe and respects character boundaries.
@@ -15304,6 +15721,54 @@ impl task_orchestrator::ports::web_crawler_port::WebCrawlerPort for DummyWebCraw
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_status_key_action_mapping() {
+        // Test: Validates each status key maps to its action and other keys map to nothing.
+        // Justification: `c` must keep copying to the clipboard rather than completing tasks.
+        std::assert_eq!(
+            status_key_action(KeyCode::Char('C')),
+            std::option::Option::Some(StatusKeyAction::SetStatus(task_manager::domain::task_status::TaskStatus::Completed))
+        );
+        std::assert_eq!(
+            status_key_action(KeyCode::Char('p')),
+            std::option::Option::Some(StatusKeyAction::SetStatus(task_manager::domain::task_status::TaskStatus::InProgress))
+        );
+        std::assert_eq!(
+            status_key_action(KeyCode::Char('x')),
+            std::option::Option::Some(StatusKeyAction::SetStatus(task_manager::domain::task_status::TaskStatus::Archived))
+        );
+        std::assert_eq!(status_key_action(KeyCode::Char('u')), std::option::Option::Some(StatusKeyAction::Undo));
+        std::assert_eq!(status_key_action(KeyCode::Char('O')), std::option::Option::Some(StatusKeyAction::Reopen));
+        std::assert_eq!(status_key_action(KeyCode::Char('c')), std::option::Option::None);
+    }
+
+    #[tokio::test]
+    async fn test_status_key_invalid_transition_flashes_message() {
+        // Test: Validates an invalid transition sets a status message and leaves the task unchanged.
+        // Justification: Archived tasks must be reopened before completing, and the user needs feedback.
+        let pool = sqlx::sqlite::SqlitePoolOptions::new().connect("sqlite::memory:").await.unwrap();
+        let mut app = App::new();
+        app.db_adapter = std::option::Option::Some(std::sync::Arc::new(std::sync::Mutex::new(
+            task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::new(pool),
+        )));
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: String::from("Old spike"),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let mut task = task_manager::domain::task::Task::from_action_item(&action, std::option::Option::None);
+        task.status = task_manager::domain::task_status::TaskStatus::Archived;
+        app.tasks = std::vec![task];
+        app.selected_task = 0;
+
+        app.apply_status_key_action(StatusKeyAction::SetStatus(task_manager::domain::task_status::TaskStatus::Completed))
+            .await
+            .unwrap();
+
+        std::assert!(app.status_message.unwrap().contains("Cannot change"));
+        std::assert_eq!(app.tasks[0].status, task_manager::domain::task_status::TaskStatus::Archived);
+    }
+
     #[test]
     fn test_calculate_safe_dialog_height_within_bounds() {
         // Test: Validates that small dialogs are not artificially capped.
@@ -15804,6 +16269,42 @@ mod tests {
         // NOT the condition: app.active_tool == DashboardTool::DevTools
     }
 
+    #[test]
+    fn test_chat_persona_is_applied_to_the_agent_and_rebuilt_on_change() {
+        // Test: Validates the chat agent takes on the selected persona and is rebuilt when Ctrl+P switches it.
+        // Justification: Choosing a persona must change the agent's system prompt and tools, not just the UI label.
+        let mut app = App::new();
+        let mut qa = task_manager::domain::persona::Persona::new(
+            String::from("persona-qa-tui"),
+            std::option::Option::None,
+            String::from("Quinn"),
+            String::from("QA Reviewer"),
+            String::from("Reviews finished work against its acceptance criteria."),
+        );
+        qa.enabled_tools = std::vec![String::from("search_tasks")];
+        qa.set_tool_call_limit(String::from("search_tasks"), std::option::Option::Some(2));
+        app.personas = std::vec![qa];
+
+        app.open_llm_chat();
+        let default_agent = app.llm_agent_adapter.clone().unwrap();
+        std::assert!(default_agent.system_prompt().starts_with("You are Rigger Assistant"));
+        std::assert_eq!(default_agent.tool_call_limit("search_tasks"), std::option::Option::None);
+
+        app.cycle_chat_persona();
+        let qa_agent = app.llm_agent_adapter.clone().unwrap();
+        std::assert!(!std::sync::Arc::ptr_eq(&default_agent, &qa_agent));
+        std::assert!(qa_agent.system_prompt().contains("You are Quinn, acting as QA Reviewer"));
+        std::assert_eq!(qa_agent.tool_call_limit("search_tasks"), std::option::Option::Some(2));
+        let qa_tools = qa_agent.agent_tools();
+        std::assert!(qa_tools.get_task_details.is_none());
+        std::assert_eq!(app.chat_persona().map(|persona| persona.name.as_str()), std::option::Option::Some("Quinn"));
+
+        app.cycle_chat_persona();
+        std::assert!(app.chat_persona_id.is_none());
+        std::assert!(app.llm_agent_adapter.as_ref().unwrap().system_prompt().starts_with("You are Rigger Assistant"));
+        std::assert_eq!(app.llm_agent_adapter.as_ref().unwrap().tool_call_limit("search_tasks"), std::option::Option::None);
+    }
+
     #[test]
     fn test_prd_processing_state_initial() {
         // Test: Validates initial PRD processing state is Idle.
@@ -16318,6 +16819,17 @@ mod tests {
                 context_files: Vec::new(),
                 dependencies: Vec::new(),
                 sort_order: Some(0),
+                source_section: None,
+                priority: None,
+                tags: Vec::new(),
+                revisions: Vec::new(),
+                checklist: Vec::new(),
+                started_at: None,
+                time_spent_seconds: 0,
+                source_utterance: None,
+                idempotency_key: None,
+                links: Vec::new(),
+                estimate: None,
             },
         ];
 
@@ -16347,6 +16859,7 @@ mod tests {
                 tech_stack: Vec::new(),
                 constraints: Vec::new(),
                 raw_content: String::from("Content here"),
+                requirements: Vec::new(),
                 created_at: chrono::Utc::now(),
             },
         ];
@@ -16375,6 +16888,7 @@ mod tests {
                 description: None,
                 created_at: chrono::Utc::now(),
                 prd_ids: Vec::new(),
+                settings: task_manager::domain::project_settings::ProjectSettings::default(),
             },
         ];
 
@@ -16449,6 +16963,17 @@ mod tests {
                 context_files: Vec::new(),
                 dependencies: Vec::new(),
                 sort_order: Some(0),
+                source_section: None,
+                priority: None,
+                tags: Vec::new(),
+                revisions: Vec::new(),
+                checklist: Vec::new(),
+                started_at: None,
+                time_spent_seconds: 0,
+                source_utterance: None,
+                idempotency_key: None,
+                links: Vec::new(),
+                estimate: None,
             },
         ];
 
@@ -16493,6 +17018,17 @@ mod tests {
                 context_files: Vec::new(),
                 dependencies: Vec::new(),
                 sort_order: Some(0),
+                source_section: None,
+                priority: None,
+                tags: Vec::new(),
+                revisions: Vec::new(),
+                checklist: Vec::new(),
+                started_at: None,
+                time_spent_seconds: 0,
+                source_utterance: None,
+                idempotency_key: None,
+                links: Vec::new(),
+                estimate: None,
             },
             task_manager::domain::task::Task {
                 id: String::from("task-2"),
@@ -16515,6 +17051,17 @@ mod tests {
                 context_files: Vec::new(),
                 dependencies: Vec::new(),
                 sort_order: Some(0),
+                source_section: None,
+                priority: None,
+                tags: Vec::new(),
+                revisions: Vec::new(),
+                checklist: Vec::new(),
+                started_at: None,
+                time_spent_seconds: 0,
+                source_utterance: None,
+                idempotency_key: None,
+                links: Vec::new(),
+                estimate: None,
             },
         ];
 
@@ -16561,6 +17108,17 @@ mod tests {
                 context_files: Vec::new(),
                 dependencies: Vec::new(),
                 sort_order: Some(0),
+                source_section: None,
+                priority: None,
+                tags: Vec::new(),
+                revisions: Vec::new(),
+                checklist: Vec::new(),
+                started_at: None,
+                time_spent_seconds: 0,
+                source_utterance: None,
+                idempotency_key: None,
+                links: Vec::new(),
+                estimate: None,
             },
         ];
 
@@ -16581,6 +17139,7 @@ mod tests {
             description: Some(String::from("Test")),
             created_at: chrono::Utc::now(),
             prd_ids: Vec::new(),
+            settings: task_manager::domain::project_settings::ProjectSettings::default(),
         };
         app.projects.push(project);
 
@@ -16593,6 +17152,7 @@ mod tests {
             tech_stack: Vec::new(),
             constraints: Vec::new(),
             raw_content: String::from("# Test PRD"),
+            requirements: Vec::new(),
             created_at: chrono::Utc::now(),
         };
         app.prds.push(prd);
@@ -16619,6 +17179,17 @@ mod tests {
             context_files: Vec::new(),
             dependencies: Vec::new(),
             sort_order: Some(0),
+            source_section: None,
+            priority: None,
+            tags: Vec::new(),
+            revisions: Vec::new(),
+            checklist: Vec::new(),
+            started_at: None,
+            time_spent_seconds: 0,
+            source_utterance: None,
+            idempotency_key: None,
+            links: Vec::new(),
+            estimate: None,
         };
         app.tasks.push(task);
 
@@ -16665,6 +17236,8 @@ mod tests {
             mime_type: None,
             source_url: None,
             page_number: None,
+            embedding_model: None,
+            embedding_dimension: None,
         };
         app.artifacts.push(artifact);
 
@@ -3,6 +3,8 @@
 //! This module provides formatters for tasks, tables, and other visual output.
 //!
 //! Revision History
+//! - 2026-10-16T17:10:00Z @AI: Add theme module for colorized output.
 //! - 2025-11-22T16:40:00Z @AI: Initial display module for Rigger CLI.
 
 pub mod task_table;
+pub mod theme;
@@ -3,33 +3,44 @@
 //! Provides formatted table output for task lists with color-coded status.
 //!
 //! Revision History
+//! - 2026-10-16T17:10:00Z @AI: Colorize status, priority, and overdue due dates via display::theme::Theme; add Priority column.
+//! - 2026-10-16T15:30:00Z @AI: Replace display_tasks_table with render_tasks_table and add render_tasks_json so `rig list --copy` can reuse the output.
 //! - 2025-11-22T16:45:00Z @AI: Initial task table display implementation for Rigger Phase 0 Sprint 0.2.
 
-/// Displays tasks in a formatted ASCII table.
+/// Renders tasks as a formatted ASCII table.
 ///
 /// Formats tasks with the following columns:
 /// - ID: Truncated task ID (first 8 chars)
 /// - Title: Task title (truncated to 40 chars if needed)
-/// - Status: Color-coded status (Pending=yellow, InProgress=blue, Completed=green)
-/// - Priority: Task priority (if available)
+/// - Status: Color-coded status (Todo=yellow, InProgress=blue, Completed=green)
+/// - Priority: Task priority (if available; high is bold)
 /// - Assignee: Assigned person (truncated to 15 chars)
-/// - Due Date: Due date (if available)
+/// - Due Date: Due date (if available; red when overdue)
 ///
 /// # Arguments
 ///
-/// * `tasks` - Slice of tasks to display
+/// * `tasks` - Slice of tasks to render
+/// * `theme` - Color theme; `Theme::NoColor` renders plain text
+///
+/// # Returns
+///
+/// The table followed by a total line, or "No tasks found." when empty.
 ///
 /// # Examples
 ///
 /// ```no_run
 /// use task_manager::domain::task::Task;
 /// let tasks: Vec<Task> = vec![];
-/// taskmaster_cli::display::task_table::display_tasks_table(&tasks);
+/// let theme = rigger_cli::display::theme::Theme::NoColor;
+/// let output = rigger_cli::display::task_table::render_tasks_table(&tasks, &theme);
+/// assert_eq!(output, "No tasks found.\n");
 /// ```
-pub fn display_tasks_table(tasks: &[task_manager::domain::task::Task]) {
+pub fn render_tasks_table(
+    tasks: &[task_manager::domain::task::Task],
+    theme: &crate::display::theme::Theme,
+) -> String {
     if tasks.is_empty() {
-        println!("No tasks found.");
-        return;
+        return std::string::String::from("No tasks found.\n");
     }
 
     let mut table = prettytable::Table::new();
@@ -42,11 +53,14 @@ pub fn display_tasks_table(tasks: &[task_manager::domain::task::Task]) {
         "ID",
         "Title",
         "Status",
+        "Priority",
         "Assignee",
         "Due Date",
         "Created"
     ]);
 
+    let today = chrono::Local::now().date_naive();
+
     // Add task rows
     for task in tasks {
         let id_short = if task.id.len() > 8 {
@@ -69,14 +83,21 @@ pub fn display_tasks_table(tasks: &[task_manager::domain::task::Task]) {
             }
         }).unwrap_or_else(|| std::string::String::from("-"));
 
-        let due_date_display = task.due_date.as_ref()
-            .map(|d| d.clone())
+        let due_date_display = match task.due_date {
+            std::option::Option::Some(ref d) if crate::display::theme::is_overdue(task, today) => theme.overdue(d),
+            std::option::Option::Some(ref d) => d.clone(),
+            std::option::Option::None => std::string::String::from("-"),
+        };
+
+        let priority_display = task
+            .priority
+            .map(|p| theme.priority(p, p.as_str()))
             .unwrap_or_else(|| std::string::String::from("-"));
 
         let created_display = task.created_at.format("%Y-%m-%d").to_string();
 
         // Format status
-        let status_display = match task.status {
+        let status_label = match task.status {
             task_manager::domain::task_status::TaskStatus::Todo => {
                 std::format!("{}", "Todo")
             }
@@ -111,20 +132,34 @@ pub fn display_tasks_table(tasks: &[task_manager::domain::task::Task]) {
                 std::format!("{}", "Errored")
             }
         };
+        let status_display = theme.status(&task.status, &status_label);
 
         table.add_row(prettytable::row![
             id_short,
             title_truncated,
             status_display,
+            priority_display,
             assignee_display,
             due_date_display,
             created_display
         ]);
     }
 
-    table.printstd();
+    std::format!("{}\nTotal: {} task(s)\n", table, tasks.len())
+}
 
-    println!("\nTotal: {} task(s)", tasks.len());
+/// Renders tasks as pretty-printed JSON.
+///
+/// # Arguments
+///
+/// * `tasks` - Slice of tasks to render
+///
+/// # Errors
+///
+/// Returns an error if a task cannot be serialized.
+pub fn render_tasks_json(tasks: &[task_manager::domain::task::Task]) -> anyhow::Result<String> {
+    let json = serde_json::to_string_pretty(tasks)?;
+    std::result::Result::Ok(std::format!("{}\n", json))
 }
 
 #[cfg(test)]
@@ -134,8 +169,7 @@ mod tests {
         // Test: Validates empty task list displays "No tasks found" message.
         // Justification: Ensures graceful handling of empty result sets.
         let tasks: std::vec::Vec<task_manager::domain::task::Task> = std::vec![];
-        // This would print to stdout, so we just verify it doesn't panic
-        super::display_tasks_table(&tasks);
+        std::assert_eq!(super::render_tasks_table(&tasks, &crate::display::theme::Theme::NoColor), "No tasks found.\n");
     }
 
     #[test]
@@ -149,8 +183,8 @@ mod tests {
         };
         let task = task_manager::domain::task::Task::from_action_item(&action, std::option::Option::None);
         let tasks = std::vec![task];
-        // This would print to stdout, so we just verify it doesn't panic
-        super::display_tasks_table(&tasks);
+        let output = super::render_tasks_table(&tasks, &crate::display::theme::Theme::NoColor);
+        std::assert!(output.contains("Total: 1 task(s)"));
     }
 
     #[test]
@@ -164,7 +198,42 @@ mod tests {
         };
         let task = task_manager::domain::task::Task::from_action_item(&action, std::option::Option::None);
         let tasks = std::vec![task];
-        // This would print to stdout, so we just verify it doesn't panic
-        super::display_tasks_table(&tasks);
+        let output = super::render_tasks_table(&tasks, &crate::display::theme::Theme::NoColor);
+        std::assert!(output.contains("This is a very long task title that s..."));
+    }
+
+    #[test]
+    fn test_render_table_matches_task_count() {
+        // Test: Validates the rendered table lists the task and its total.
+        // Justification: `rig list --copy` copies this exact string to the clipboard.
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Write standup notes"),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let task = task_manager::domain::task::Task::from_action_item(&action, std::option::Option::None);
+        let output = super::render_tasks_table(&[task], &crate::display::theme::Theme::NoColor);
+
+        std::assert!(output.contains("Write standup notes"));
+        std::assert!(output.ends_with("Total: 1 task(s)\n"));
+    }
+
+    #[test]
+    fn test_no_color_table_has_no_escape_codes() {
+        // Test: Validates a table with overdue, high-priority tasks contains no ANSI escapes under no-color.
+        // Justification: Piped `rig list` output and `--copy` must stay plain text.
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Renew certificates"),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::Some(std::string::String::from("2020-01-01")),
+        };
+        let mut task = task_manager::domain::task::Task::from_action_item(&action, std::option::Option::None);
+        task.priority = std::option::Option::Some(task_manager::domain::priority::Priority::High);
+        let theme = crate::display::theme::Theme::resolve("dark", false, false);
+
+        let output = super::render_tasks_table(&[task], &theme);
+
+        std::assert!(output.contains("high"));
+        std::assert!(!output.contains('\x1b'));
     }
 }
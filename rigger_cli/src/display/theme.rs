@@ -0,0 +1,246 @@
+//! Color themes for terminal task output.
+//!
+//! Maps task status, priority, and overdue state to ANSI colors according to
+//! the `tui.theme` setting in .rigger/config.json. Color is switched off
+//! automatically when stdout is not a terminal or `NO_COLOR` is set, so piped
+//! and redirected output stays plain text.
+//!
+//! Revision History
+//! - 2026-10-16T18:10:00Z @AI: Add success and error colors for `rig config validate`.
+//! - 2026-10-16T17:10:00Z @AI: Initial themes (default, dark, no-color) for `rig list` and `rig show`.
+
+/// A color theme for terminal output.
+///
+/// # Variants
+///
+/// * `Default` - Standard ANSI colors, readable on light and dark backgrounds.
+/// * `Dark` - Bright ANSI colors for dark terminal backgrounds.
+/// * `NoColor` - Plain text without escape codes.
+///
+/// # Examples
+///
+/// ```
+/// # use rigger_cli::display::theme::Theme;
+/// let theme = Theme::resolve("dark", false, false);
+/// assert_eq!(theme, Theme::NoColor);
+/// assert_eq!(theme.overdue("2025-01-01"), "2025-01-01");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Theme {
+    /// Standard ANSI colors, readable on light and dark backgrounds.
+    Default,
+
+    /// Bright ANSI colors for dark terminal backgrounds.
+    Dark,
+
+    /// Plain text without escape codes.
+    NoColor,
+}
+
+impl Theme {
+    /// Parses a theme name from config ("default", "dark", "no-color").
+    ///
+    /// Unknown names fall back to `Theme::Default`.
+    pub fn from_name(name: &str) -> Self {
+        match name.trim().to_lowercase().as_str() {
+            "dark" => Theme::Dark,
+            "no-color" | "no_color" | "none" => Theme::NoColor,
+            _ => Theme::Default,
+        }
+    }
+
+    /// Resolves the configured theme against the output environment.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Theme name from config
+    /// * `is_tty` - Whether stdout is a terminal
+    /// * `no_color_env` - Whether the `NO_COLOR` environment variable is set
+    ///
+    /// # Returns
+    ///
+    /// `Theme::NoColor` when output is not a terminal or `NO_COLOR` is set,
+    /// otherwise the named theme.
+    pub fn resolve(name: &str, is_tty: bool, no_color_env: bool) -> Self {
+        if !is_tty || no_color_env {
+            return Theme::NoColor;
+        }
+        Self::from_name(name)
+    }
+
+    /// Resolves the named theme against the current process's stdout and environment.
+    pub fn detect(name: &str) -> Self {
+        let is_tty = std::io::IsTerminal::is_terminal(&std::io::stdout());
+        let no_color_env = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        Self::resolve(name, is_tty, no_color_env)
+    }
+
+    /// Loads the theme from a .rigger/config.json path.
+    ///
+    /// A missing or unreadable config falls back to the default theme rather
+    /// than failing, since color is cosmetic.
+    ///
+    /// # Arguments
+    ///
+    /// * `config_path` - Path to .rigger/config.json
+    pub fn load(config_path: &std::path::Path) -> Self {
+        let name = config_path
+            .to_str()
+            .and_then(|path| rigger_core::RiggerConfig::load_with_migration(path).ok())
+            .map(|config| config.tui.theme)
+            .unwrap_or_else(|| std::string::String::from("default"));
+        Self::detect(&name)
+    }
+
+    /// Colors `text` according to a task status.
+    pub fn status(&self, status: &task_manager::domain::task_status::TaskStatus, text: &str) -> String {
+        let (normal, bright) = match status {
+            task_manager::domain::task_status::TaskStatus::Todo => {
+                (crossterm::style::Color::DarkYellow, crossterm::style::Color::Yellow)
+            }
+            task_manager::domain::task_status::TaskStatus::InProgress => {
+                (crossterm::style::Color::DarkBlue, crossterm::style::Color::Blue)
+            }
+            task_manager::domain::task_status::TaskStatus::Completed
+            | task_manager::domain::task_status::TaskStatus::OrchestrationComplete => {
+                (crossterm::style::Color::DarkGreen, crossterm::style::Color::Green)
+            }
+            task_manager::domain::task_status::TaskStatus::PendingEnhancement
+            | task_manager::domain::task_status::TaskStatus::PendingComprehensionTest
+            | task_manager::domain::task_status::TaskStatus::PendingFollowOn
+            | task_manager::domain::task_status::TaskStatus::PendingDecomposition => {
+                (crossterm::style::Color::DarkCyan, crossterm::style::Color::Cyan)
+            }
+            task_manager::domain::task_status::TaskStatus::Decomposed => {
+                (crossterm::style::Color::DarkMagenta, crossterm::style::Color::Magenta)
+            }
+            task_manager::domain::task_status::TaskStatus::Archived => {
+                (crossterm::style::Color::DarkGrey, crossterm::style::Color::Grey)
+            }
+            task_manager::domain::task_status::TaskStatus::Errored => {
+                (crossterm::style::Color::DarkRed, crossterm::style::Color::Red)
+            }
+        };
+        self.paint(text, normal, bright, false)
+    }
+
+    /// Colors `text` according to a task priority; high priority is also bold.
+    pub fn priority(&self, priority: task_manager::domain::priority::Priority, text: &str) -> String {
+        match priority {
+            task_manager::domain::priority::Priority::High => {
+                self.paint(text, crossterm::style::Color::DarkRed, crossterm::style::Color::Red, true)
+            }
+            task_manager::domain::priority::Priority::Medium => {
+                self.paint(text, crossterm::style::Color::DarkYellow, crossterm::style::Color::Yellow, false)
+            }
+            task_manager::domain::priority::Priority::Low => {
+                self.paint(text, crossterm::style::Color::DarkGrey, crossterm::style::Color::Grey, false)
+            }
+        }
+    }
+
+    /// Colors `text` (typically a due date) as overdue.
+    pub fn overdue(&self, text: &str) -> String {
+        self.paint(text, crossterm::style::Color::DarkRed, crossterm::style::Color::Red, false)
+    }
+
+    /// Colors `text` as a success message.
+    pub fn success(&self, text: &str) -> String {
+        self.paint(text, crossterm::style::Color::DarkGreen, crossterm::style::Color::Green, false)
+    }
+
+    /// Colors `text` as an error message.
+    pub fn error(&self, text: &str) -> String {
+        self.paint(text, crossterm::style::Color::DarkRed, crossterm::style::Color::Red, false)
+    }
+
+    /// Applies the theme's variant of a color, or returns `text` unchanged for `NoColor`.
+    fn paint(&self, text: &str, normal: crossterm::style::Color, bright: crossterm::style::Color, bold: bool) -> String {
+        let color = match self {
+            Theme::Default => normal,
+            Theme::Dark => bright,
+            Theme::NoColor => return std::string::String::from(text),
+        };
+        let styled = crossterm::style::Stylize::with(text, color);
+        if bold {
+            crossterm::style::Stylize::bold(styled).to_string()
+        } else {
+            styled.to_string()
+        }
+    }
+}
+
+/// Returns true if the task has a due date before `today` and is not finished.
+///
+/// Due dates that are not `YYYY-MM-DD` are never considered overdue.
+///
+/// # Arguments
+///
+/// * `task` - The task to check
+/// * `today` - The current local date
+pub fn is_overdue(task: &task_manager::domain::task::Task, today: chrono::NaiveDate) -> bool {
+    let finished = std::matches!(
+        task.status,
+        task_manager::domain::task_status::TaskStatus::Completed
+            | task_manager::domain::task_status::TaskStatus::Archived
+            | task_manager::domain::task_status::TaskStatus::OrchestrationComplete
+    );
+    if finished {
+        return false;
+    }
+    task.due_date
+        .as_deref()
+        .and_then(|due| chrono::NaiveDate::parse_from_str(due, "%Y-%m-%d").ok())
+        .is_some_and(|due| due < today)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_non_tty_and_no_color_env_disable_color() {
+        // Test: Validates piped output or NO_COLOR resolves to the no-color theme.
+        // Justification: Escape codes corrupt output redirected to files or other tools.
+        std::assert_eq!(super::Theme::resolve("dark", false, false), super::Theme::NoColor);
+        std::assert_eq!(super::Theme::resolve("default", true, true), super::Theme::NoColor);
+        std::assert_eq!(super::Theme::resolve("dark", true, false), super::Theme::Dark);
+        std::assert_eq!(super::Theme::resolve("unknown", true, false), super::Theme::Default);
+    }
+
+    #[test]
+    fn test_no_color_theme_emits_plain_text() {
+        // Test: Validates the no-color theme never adds ANSI escapes.
+        // Justification: `theme: "no-color"` must produce output identical to the text.
+        let theme = super::Theme::from_name("no-color");
+
+        std::assert_eq!(theme.status(&task_manager::domain::task_status::TaskStatus::Errored, "Errored"), "Errored");
+        std::assert_eq!(theme.priority(task_manager::domain::priority::Priority::High, "high"), "high");
+        std::assert_eq!(theme.overdue("2025-01-01"), "2025-01-01");
+    }
+
+    #[test]
+    fn test_high_priority_is_bold_and_overdue_is_red() {
+        // Test: Validates the default theme makes high priority bold and overdue dates red.
+        // Justification: These are the two cues users scan for in `rig list`.
+        let theme = super::Theme::Default;
+
+        std::assert!(theme.priority(task_manager::domain::priority::Priority::High, "high").contains("\x1b[1m"));
+        std::assert!(theme.overdue("2025-01-01").contains("\x1b[38;5;1m"));
+    }
+
+    #[test]
+    fn test_is_overdue_ignores_finished_tasks() {
+        // Test: Validates only unfinished tasks past their due date are overdue.
+        // Justification: Completed work should not be flagged red.
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("File taxes"),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::Some(std::string::String::from("2026-04-15")),
+        };
+        let mut task = task_manager::domain::task::Task::from_action_item(&action, std::option::Option::None);
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+
+        std::assert!(super::is_overdue(&task, today));
+        task.status = task_manager::domain::task_status::TaskStatus::Completed;
+        std::assert!(!super::is_overdue(&task, today));
+    }
+}
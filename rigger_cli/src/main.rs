@@ -5,6 +5,49 @@
 //! the orchestration pipeline.
 //!
 //! Revision History
+//! - 2026-10-18T03:30:00Z @AI: Dispatch project set and show.
+//! - 2026-10-18T02:20:00Z @AI: Pass parse --project through.
+//! - 2026-10-17T18:30:00Z @AI: Pass artifacts search --source-type, --ext, and --language through.
+//! - 2026-10-17T18:10:00Z @AI: Pass artifacts search --rerank / --no-rerank through.
+//! - 2026-10-17T17:50:00Z @AI: Dispatch artifacts export and import.
+//! - 2026-10-17T17:30:00Z @AI: Pass show --similar and --include-closed through.
+//! - 2026-10-17T17:10:00Z @AI: Dispatch dedup command.
+//! - 2026-10-17T16:50:00Z @AI: Pass add --estimate through; dispatch plan command.
+//! - 2026-10-17T16:30:00Z @AI: Dispatch link command.
+//! - 2026-10-17T16:10:00Z @AI: Pass do's --yes through.
+//! - 2026-10-17T15:50:00Z @AI: Pass parse's --quiet through.
+//! - 2026-10-17T14:10:00Z @AI: Validate and record the global --db / RIGGER_DB override before dispatch.
+//! - 2026-10-17T13:50:00Z @AI: Record the global --config / RIGGER_CONFIG override before dispatch.
+//! - 2026-10-17T11:30:00Z @AI: Pass list --cursor through.
+//! - 2026-10-17T10:50:00Z @AI: Exit with a code per task error kind (see services::exit_code).
+//! - 2026-10-17T09:10:00Z @AI: Pass metrics --since, --tail, and --file through.
+//! - 2026-10-17T08:10:00Z @AI: Dispatch replay command; it runs without provider checks.
+//! - 2026-10-17T07:50:00Z @AI: Pass --trace to the gRPC server.
+//! - 2026-10-17T06:50:00Z @AI: Pass --regenerate-tests to the do command.
+//! - 2026-10-17T05:10:00Z @AI: Dispatch export and import commands.
+//! - 2026-10-17T03:30:00Z @AI: Dispatch stats command.
+//! - 2026-10-17T03:10:00Z @AI: Dispatch start and stop commands.
+//! - 2026-10-17T02:50:00Z @AI: Pass list --blocked through to list::execute and list::watch as ListArgs.
+//! - 2026-10-17T02:30:00Z @AI: Dispatch next command.
+//! - 2026-10-17T02:10:00Z @AI: Dispatch metrics command.
+//! - 2026-10-16T22:30:00Z @AI: Dispatch artifacts reembed.
+//! - 2026-10-16T21:50:00Z @AI: Dispatch hidden architecture command.
+//! - 2026-10-16T21:30:00Z @AI: Pass parse's --no-cache through.
+//! - 2026-10-16T21:10:00Z @AI: Refuse to start when offline mode is on and a provider is not local (except init and config).
+//! - 2026-10-16T20:50:00Z @AI: Pass do's --max-cost through.
+//! - 2026-10-16T19:50:00Z @AI: Initialize JSON tracing from --log-level / RIGGER_LOG.
+//! - 2026-10-16T19:10:00Z @AI: Dispatch models command.
+//! - 2026-10-16T18:50:00Z @AI: Dispatch config check-providers.
+//! - 2026-10-16T18:30:00Z @AI: Pass config migrate --dry-run through.
+//! - 2026-10-16T17:50:00Z @AI: Pass do's optional task ID through.
+//! - 2026-10-16T16:50:00Z @AI: Dispatch list --watch to list::watch.
+//! - 2026-10-16T16:10:00Z @AI: Dispatch add command.
+//! - 2026-10-16T15:50:00Z @AI: Dispatch show command.
+//! - 2026-10-16T15:30:00Z @AI: Pass --format and --copy through to list command.
+//! - 2026-10-16T13:50:00Z @AI: Dispatch artifacts delete and purge commands.
+//! - 2026-10-16T13:30:00Z @AI: Pass --offset flag through to artifacts list command.
+//! - 2026-10-16T12:50:00Z @AI: Pass --ocr flag through to artifacts generate command.
+//! - 2026-10-16T11:10:00Z @AI: Pass --incremental flag through to parse command.
 //! - 2025-12-04T00:00:00Z @AI: Add config command handling for Phase 4.3 config management CLI.
 //! - 2025-11-30T21:45:00Z @AI: Add artifacts generate command for Phase 5 artifact generator CLI.
 //! - 2025-11-28T23:00:00Z @AI: Add artifacts command handling for Phase 6 RAG CLI (Tasks 6.1, 6.2).
@@ -19,42 +62,102 @@ mod services;
 mod ui;
 
 #[tokio::main]
-async fn main() -> anyhow::Result<()> {
+async fn main() -> std::process::ExitCode {
+    match run().await {
+        std::result::Result::Ok(()) => std::process::ExitCode::SUCCESS,
+        std::result::Result::Err(e) => {
+            eprintln!("Error: {:?}", e);
+            std::process::ExitCode::from(services::exit_code::for_error(&e))
+        }
+    }
+}
+
+/// Parses the command line and dispatches the command.
+async fn run() -> anyhow::Result<()> {
     let cli = commands::Cli::parse();
+    services::logging::init(cli.log_level.as_deref())?;
+    services::config_path::init(cli.config.as_deref());
+    services::database_url::init(cli.db.as_deref())?;
+
+    // `init` and `config` stay available so an offline violation can be fixed;
+    // `replay` never calls a provider
+    if !std::matches!(
+        cli.command,
+        commands::Commands::Init | commands::Commands::Config { .. } | commands::Commands::Replay { .. }
+    ) {
+        services::offline_mode::check_startup()?;
+    }
 
     match cli.command {
         commands::Commands::Init => {
             commands::init::execute().await?;
         }
-        commands::Commands::Parse { prd_file } => {
-            commands::parse::execute(&prd_file).await?;
+        commands::Commands::Parse { prd_file, project, incremental, no_cache, quiet } => {
+            commands::parse::execute(&prd_file, &project, incremental, no_cache, quiet).await?;
+        }
+        commands::Commands::List { status, assignee, sort, limit, offset, cursor, blocked, format, copy, watch, interval } => {
+            let args = commands::list::ListArgs {
+                status: status.as_deref(),
+                assignee: assignee.as_deref(),
+                sort: &sort,
+                limit: limit.as_deref(),
+                offset: offset.as_deref(),
+                cursor: cursor.as_deref(),
+                blocked,
+                format: &format,
+            };
+            if watch {
+                commands::list::watch(&args, interval.as_deref()).await?;
+            } else {
+                commands::list::execute(&args, copy).await?;
+            }
+        }
+        commands::Commands::Add { title, description, assignee, priority, due, status, estimate, tags } => {
+            commands::add::execute(
+                &title,
+                description.as_deref(),
+                assignee.as_deref(),
+                priority.as_deref(),
+                due.as_deref(),
+                status.as_deref(),
+                estimate,
+                &tags,
+            ).await?;
         }
-        commands::Commands::List { status, assignee, sort, limit, offset } => {
-            commands::list::execute(status.as_deref(), assignee.as_deref(), &sort, limit.as_deref(), offset.as_deref()).await?;
+        commands::Commands::Show { task_id, format, similar, include_closed } => {
+            commands::show::execute(&task_id, &format, similar, include_closed).await?;
         }
-        commands::Commands::Do { task_id } => {
-            commands::do_task::execute(&task_id).await?;
+        commands::Commands::Start { task_id } => {
+            commands::time_tracking::start(&task_id).await?;
+        }
+        commands::Commands::Stop { task_id } => {
+            commands::time_tracking::stop(&task_id).await?;
+        }
+        commands::Commands::Do { task_id, max_cost, regenerate_tests, yes } => {
+            commands::do_task::execute(task_id.as_deref(), max_cost, regenerate_tests, yes).await?;
         }
         commands::Commands::Server => {
             commands::server::execute().await?;
         }
-        commands::Commands::Grpc { port: _ } => {
-            commands::grpc_server::execute().await?;
+        commands::Commands::Grpc { port: _, trace } => {
+            commands::grpc_server::execute(trace).await?;
         }
         commands::Commands::Tui => {
             commands::tui::execute().await?;
         }
         commands::Commands::Artifacts { command } => {
             match command {
-                commands::ArtifactsCommands::List { project, source_type, limit } => {
+                commands::ArtifactsCommands::List { project, source_type, limit, offset } => {
                     let parsed_limit = limit.as_ref().and_then(|s| s.parse::<usize>().ok());
+                    let parsed_offset = offset.as_ref().and_then(|s| s.parse::<usize>().ok());
                     commands::artifacts::list(
                         project.as_deref(),
                         source_type.as_deref(),
                         parsed_limit,
+                        parsed_offset,
                     ).await?;
                 }
-                commands::ArtifactsCommands::Search { query, limit, threshold, project } => {
+                commands::ArtifactsCommands::Search { query, limit, threshold, project, source_type, ext, language, rerank, no_rerank } => {
                     let parsed_limit = limit.as_ref().and_then(|s| s.parse::<usize>().ok());
                     let parsed_threshold = threshold.as_ref().and_then(|s| s.parse::<f32>().ok());
                     commands::artifacts::search(
@@ -62,6 +165,10 @@ async fn main() -> anyhow::Result<()> {
                         parsed_limit,
                         parsed_threshold,
                         project.as_deref(),
+                        &source_type,
+                        &ext,
+                        language.as_deref(),
+                        if rerank { std::option::Option::Some(true) } else if no_rerank { std::option::Option::Some(false) } else { std::option::Option::None },
                     ).await?;
                 }
                 commands::ArtifactsCommands::Generate {
@@ -72,10 +179,17 @@ async fn main() -> anyhow::Result<()> {
                     chunk_strategy,
                     chunk_size,
                     exclude,
+                    ocr,
+                    include_subdomains,
+                    path_prefix,
+                    crawl_delay_ms,
+                    resume,
+                    force,
                 } => {
                     let parsed_depth = depth.as_ref().and_then(|s| s.parse::<usize>().ok());
                     let parsed_max_items = max_items.as_ref().and_then(|s| s.parse::<usize>().ok());
                     let parsed_chunk_size = chunk_size.as_ref().and_then(|s| s.parse::<usize>().ok());
+                    let parsed_crawl_delay = crawl_delay_ms.as_ref().and_then(|s| s.parse::<u64>().ok());
                     commands::artifacts::generate(
                         &source,
                         project.as_deref(),
@@ -84,8 +198,29 @@ async fn main() -> anyhow::Result<()> {
                         chunk_strategy.as_deref(),
                         parsed_chunk_size,
                         exclude.as_deref(),
+                        ocr,
+                        include_subdomains,
+                        path_prefix,
+                        parsed_crawl_delay,
+                        resume,
+                        force,
                     ).await?;
                 }
+                commands::ArtifactsCommands::Delete { id } => {
+                    commands::artifacts::delete(&id).await?;
+                }
+                commands::ArtifactsCommands::Purge { project, yes } => {
+                    commands::artifacts::purge(&project, yes).await?;
+                }
+                commands::ArtifactsCommands::Export { out, project } => {
+                    commands::artifact_jsonl::export(&out, project.as_deref()).await?;
+                }
+                commands::ArtifactsCommands::Import { path } => {
+                    commands::artifact_jsonl::import(&path).await?;
+                }
+                commands::ArtifactsCommands::Reembed { model, yes } => {
+                    commands::artifacts::reembed(&model, yes).await?;
+                }
             }
         }
         commands::Commands::Config { command } => {
@@ -96,18 +231,65 @@ async fn main() -> anyhow::Result<()> {
                 commands::ConfigCommands::Validate { path } => {
                     commands::config::validate(path.as_deref()).await?;
                 }
+                commands::ConfigCommands::CheckProviders { path } => {
+                    commands::config::check_providers(path.as_deref()).await?;
+                }
                 commands::ConfigCommands::Edit => {
                     commands::config::edit().await?;
                 }
-                commands::ConfigCommands::Migrate { path, output, backup } => {
+                commands::ConfigCommands::Migrate { path, output, backup, dry_run } => {
                     commands::config::migrate(
                         path.as_deref(),
                         output.as_deref(),
                         backup,
+                        dry_run,
                     ).await?;
                 }
             }
         }
+        commands::Commands::Models { provider } => {
+            commands::models::execute(provider.as_deref()).await?;
+        }
+        commands::Commands::Link { from, to, link_type } => {
+            commands::link::execute(&from, &to, &link_type).await?;
+        }
+        commands::Commands::Next { assignee } => {
+            commands::next::execute(assignee.as_deref()).await?;
+        }
+        commands::Commands::Plan { capacity, assignee } => {
+            commands::plan::execute(capacity, assignee.as_deref()).await?;
+        }
+        commands::Commands::Dedup { threshold, merge } => {
+            commands::dedup::execute(threshold, merge).await?;
+        }
+        commands::Commands::Project { command } => {
+            match command {
+                commands::ProjectCommands::Set { project, default_assignee, default_priority } => {
+                    commands::project::set(&project, default_assignee.as_deref(), default_priority.as_deref()).await?;
+                }
+                commands::ProjectCommands::Show { project } => {
+                    commands::project::show(&project).await?;
+                }
+            }
+        }
+        commands::Commands::Stats { since, assignee, format } => {
+            commands::stats::execute(since.as_deref(), assignee.as_deref(), &format).await?;
+        }
+        commands::Commands::Export { project, out } => {
+            commands::bundle::export(&project, &out).await?;
+        }
+        commands::Commands::Import { path } => {
+            commands::bundle::import(&path).await?;
+        }
+        commands::Commands::Metrics { since, tail, file } => {
+            commands::metrics::execute(since.as_deref(), tail, file.as_deref()).await?;
+        }
+        commands::Commands::Replay { log } => {
+            commands::replay::execute(&log).await?;
+        }
+        commands::Commands::Architecture { format } => {
+            commands::architecture::execute(&format)?;
+        }
     }
 
     std::result::Result::Ok(())
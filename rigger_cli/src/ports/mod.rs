@@ -5,6 +5,8 @@
 //! implementations without changing business logic.
 //!
 //! Revision History
+//! - 2026-10-16T18:50:00Z @AI: Add provider_health_port for provider liveness checks.
 //! - 2025-11-24T00:30:00Z @AI: Create ports module for clipboard operations.
 
 pub mod clipboard_port;
+pub mod provider_health_port;
@@ -0,0 +1,66 @@
+//! Provider health port for LLM provider liveness checks.
+//!
+//! Defines the interface for checking that a configured LLM provider is
+//! reachable and accepts its credentials, following hexagonal architecture
+//! principles. Implementations perform the actual network request.
+//!
+//! Revision History
+//! - 2026-10-16T18:50:00Z @AI: Create provider health port for `rig config check-providers`.
+
+/// Outcome of a provider liveness check.
+///
+/// # Variants
+///
+/// * `Reachable` - The provider answered the liveness request successfully.
+/// * `Unreachable` - The request failed, timed out, or returned a non-auth error.
+/// * `AuthFailed` - The provider rejected the credentials, or no API key was available.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProviderStatus {
+    /// The provider answered the liveness request successfully.
+    Reachable,
+
+    /// The request failed, timed out, or returned a non-auth error.
+    Unreachable(String),
+
+    /// The provider rejected the credentials, or no API key was available.
+    AuthFailed(String),
+}
+
+/// Result of checking a single provider.
+///
+/// # Fields
+///
+/// * `name` - Provider name (key in the config's `providers` map).
+/// * `status` - Whether the provider is reachable.
+/// * `latency` - Round-trip time of the liveness request (zero if none was sent).
+#[derive(Debug, Clone)]
+pub struct ProviderHealth {
+    /// Provider name (key in the config's `providers` map).
+    pub name: String,
+
+    /// Whether the provider is reachable.
+    pub status: ProviderStatus,
+
+    /// Round-trip time of the liveness request (zero if none was sent).
+    pub latency: std::time::Duration,
+}
+
+/// Port for provider liveness checks.
+#[async_trait::async_trait]
+pub trait ProviderHealthPort: Send + Sync {
+    /// Checks that a provider is reachable and accepts its credentials.
+    ///
+    /// Implementations must not fail: every problem is reported through
+    /// the returned `ProviderStatus`, and the request must give up after
+    /// the provider's `timeout_seconds`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Provider name from the config
+    /// * `provider` - The provider's configuration
+    ///
+    /// # Returns
+    ///
+    /// The provider's health, including request latency.
+    async fn check(&self, name: &str, provider: &rigger_core::config::ProviderConfig) -> ProviderHealth;
+}
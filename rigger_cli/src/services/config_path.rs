@@ -0,0 +1,94 @@
+//! Location of the Rigger config file for this invocation.
+//!
+//! Commands read `.rigger/config.json` unless the global `--config <path>`
+//! flag or the `RIGGER_CONFIG` environment variable names another file. The
+//! flag wins over the environment variable; blank values count as unset.
+//! Relative paths are resolved against the current directory. A
+//! `config.local.json` next to the chosen file still layers over it.
+//!
+//! Revision History
+//! - 2026-10-17T13:50:00Z @AI: Initial --config / RIGGER_CONFIG override for the config path.
+
+/// Environment variable naming the config file when `--config` is not given.
+pub const CONFIG_ENV_VAR: &str = "RIGGER_CONFIG";
+
+/// Override chosen at startup by `init` (`None` inside = use the default).
+static OVERRIDE: std::sync::OnceLock<std::option::Option<std::path::PathBuf>> = std::sync::OnceLock::new();
+
+/// Records the config override for this process from `--config` and `RIGGER_CONFIG`.
+///
+/// Call once at startup; later calls are ignored.
+///
+/// # Arguments
+///
+/// * `flag` - Value of `--config`, which overrides `RIGGER_CONFIG`
+pub fn init(flag: std::option::Option<&str>) {
+    let _ = OVERRIDE.set(select(flag, std::env::var(CONFIG_ENV_VAR).ok()));
+}
+
+/// Returns the config file override, if `--config` or `RIGGER_CONFIG` set one.
+pub fn override_path() -> std::option::Option<&'static std::path::Path> {
+    OVERRIDE.get().and_then(|path| path.as_deref())
+}
+
+/// Returns the config file for a `.rigger` directory: the override if set,
+/// otherwise `<rigger_dir>/config.json`.
+pub fn path_in(rigger_dir: &std::path::Path) -> std::path::PathBuf {
+    path_with(override_path(), rigger_dir)
+}
+
+/// Returns the config file for the `.rigger` directory in the current directory.
+///
+/// # Errors
+///
+/// Returns an error if the current directory cannot be read.
+pub fn current() -> std::io::Result<std::path::PathBuf> {
+    std::result::Result::Ok(path_in(&std::env::current_dir()?.join(".rigger")))
+}
+
+/// Picks the override: the flag, then the environment, then none.
+fn select(flag: std::option::Option<&str>, env: std::option::Option<String>) -> std::option::Option<std::path::PathBuf> {
+    flag.map(std::string::String::from)
+        .into_iter()
+        .chain(env)
+        .map(|value| value.trim().to_string())
+        .find(|value| !value.is_empty())
+        .map(std::path::PathBuf::from)
+}
+
+fn path_with(override_path: std::option::Option<&std::path::Path>, rigger_dir: &std::path::Path) -> std::path::PathBuf {
+    match override_path {
+        std::option::Option::Some(path) => path.to_path_buf(),
+        std::option::Option::None => rigger_dir.join("config.json"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_flag_overrides_env_and_default() {
+        // Test: Validates --config wins over RIGGER_CONFIG, which wins over .rigger/config.json.
+        // Justification: A one-off `rig --config ./prod.json` must work even when RIGGER_CONFIG is exported.
+        let rigger_dir = std::path::Path::new("/work/.rigger");
+        let resolve = |flag: std::option::Option<&str>, env: std::option::Option<&str>| {
+            super::path_with(super::select(flag, env.map(std::string::String::from)).as_deref(), rigger_dir)
+        };
+
+        std::assert_eq!(
+            resolve(std::option::Option::Some("./prod.json"), std::option::Option::Some("/etc/rigger/staging.json")),
+            std::path::PathBuf::from("./prod.json")
+        );
+        std::assert_eq!(
+            resolve(std::option::Option::None, std::option::Option::Some("/etc/rigger/staging.json")),
+            std::path::PathBuf::from("/etc/rigger/staging.json")
+        );
+        std::assert_eq!(
+            resolve(std::option::Option::Some(" "), std::option::Option::None),
+            std::path::PathBuf::from("/work/.rigger/config.json")
+        );
+        std::assert_eq!(
+            resolve(std::option::Option::None, std::option::Option::None),
+            std::path::PathBuf::from("/work/.rigger/config.json")
+        );
+    }
+}
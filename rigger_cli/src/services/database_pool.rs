@@ -0,0 +1,57 @@
+//! Pool settings for the SQLite adapters, read from `.rigger/config.json`.
+//!
+//! `database.pool_size` sizes every adapter pool a command opens. Commands
+//! that serve concurrent requests open one pool up front and build their
+//! adapters from it, so requests share connections instead of each opening
+//! its own.
+//!
+//! Revision History
+//! - 2026-10-17T22:10:00Z @AI: Add load_current for commands that resolve the config from the current directory.
+//! - 2026-10-17T05:50:00Z @AI: Initial pool settings loader for database.pool_size.
+
+/// Returns the pool settings configured in `config_path`.
+///
+/// Falls back to the defaults if the file is missing or cannot be loaded;
+/// commands report config problems themselves.
+pub fn load_settings(config_path: &std::path::Path) -> task_manager::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings {
+    match config_path
+        .to_str()
+        .filter(|_| config_path.exists())
+        .map(rigger_core::RiggerConfig::load_with_migration)
+    {
+        std::option::Option::Some(std::result::Result::Ok(config)) => settings_for(&config),
+        _ => task_manager::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings::default(),
+    }
+}
+
+/// Returns the pool settings configured for the `.rigger` directory in the
+/// current directory (or the `--config` override).
+pub fn load_current() -> task_manager::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings {
+    match crate::services::config_path::current() {
+        std::result::Result::Ok(config_path) => load_settings(&config_path),
+        std::result::Result::Err(_) => task_manager::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings::default(),
+    }
+}
+
+/// Converts the `database` section of a config into pool settings.
+pub fn settings_for(config: &rigger_core::RiggerConfig) -> task_manager::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings {
+    task_manager::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings::default()
+        .with_pool_size(config.database.pool_size)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_pool_size_comes_from_config() {
+        // Test: Validates database.pool_size sizes the pool and a missing config keeps the default.
+        // Justification: The setting existed in config.json but no adapter honored it.
+        let mut config = rigger_core::RiggerConfig::default();
+        config.database.pool_size = 12;
+
+        std::assert_eq!(super::settings_for(&config).pool_size, 12);
+        std::assert_eq!(
+            super::load_settings(std::path::Path::new("/nonexistent/.rigger/config.json")),
+            task_manager::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings::default()
+        );
+    }
+}
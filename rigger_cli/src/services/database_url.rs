@@ -0,0 +1,206 @@
+//! Database URL override for this invocation.
+//!
+//! Commands open the database named by `database.url` in the project's
+//! config (`.rigger/tasks.db` by default) unless the global `--db <url>` flag
+//! or the `RIGGER_DB` environment variable names another one. Precedence is
+//! flag, then environment, then config; blank values count as unset. A
+//! relative path in `database.url` is resolved against the project directory.
+//! Every store is SQLite, so the override and the current project's
+//! `database.url` must be `sqlite:` URLs; this is checked at startup, so a
+//! typo fails before any command runs.
+//!
+//! Revision History
+//! - 2026-10-17T23:00:00Z @AI: Fall back to database.url from config and accept only sqlite: URLs.
+//! - 2026-10-17T14:10:00Z @AI: Initial --db / RIGGER_DB database URL override.
+
+/// Environment variable holding the database URL when `--db` is not given.
+pub const DB_ENV_VAR: &str = "RIGGER_DB";
+
+/// URL scheme accepted for the database (every adapter is SQLite).
+const SCHEME: &str = "sqlite:";
+
+/// Override chosen at startup by `init` (`None` inside = use the config).
+static OVERRIDE: std::sync::OnceLock<std::option::Option<String>> = std::sync::OnceLock::new();
+
+/// Validates and records the database override from `--db` and `RIGGER_DB`.
+///
+/// Call once at startup; later calls are ignored.
+///
+/// # Arguments
+///
+/// * `flag` - Value of `--db`, which overrides `RIGGER_DB`
+///
+/// # Errors
+///
+/// Returns an error if the chosen URL, or without one the current project's
+/// `database.url`, is not a `sqlite:` URL.
+pub fn init(flag: std::option::Option<&str>) -> anyhow::Result<()> {
+    let selected = select(flag, std::env::var(DB_ENV_VAR).ok());
+    match &selected {
+        std::option::Option::Some(url) => validate(url)?,
+        std::option::Option::None => {
+            let project_dir = std::env::current_dir()?;
+            if let std::option::Option::Some(url) = configured_url(&project_dir.join(".rigger")) {
+                validate(&url).map_err(|e| anyhow::anyhow!("database.url in config: {}", e))?;
+            }
+        }
+    }
+    let _ = OVERRIDE.set(selected);
+    std::result::Result::Ok(())
+}
+
+/// Checks that `url` is a `sqlite:` URL.
+///
+/// # Errors
+///
+/// Returns an error naming the accepted scheme otherwise.
+pub fn validate(url: &str) -> anyhow::Result<()> {
+    if url.to_lowercase().starts_with(SCHEME) {
+        return std::result::Result::Ok(());
+    }
+    anyhow::bail!("Unsupported database URL '{}': expected a sqlite: URL (Rigger's stores are SQLite only)", url)
+}
+
+/// Returns the database URL override, if `--db` or `RIGGER_DB` set one.
+pub fn override_url() -> std::option::Option<&'static str> {
+    OVERRIDE.get().and_then(|url| url.as_deref())
+}
+
+/// Returns the URL to open for the SQLite file at `db_path`: the override if
+/// set, otherwise `database.url` from the config of the `.rigger` directory
+/// holding `db_path`, otherwise `sqlite:<db_path>`.
+///
+/// A configured URL that is not a `sqlite:` URL is ignored with a warning;
+/// `init` rejects one in the current project before any command runs.
+pub fn url_for(db_path: &std::path::Path) -> String {
+    let configured = db_path
+        .parent()
+        .and_then(configured_url)
+        .filter(|url| match validate(url) {
+            std::result::Result::Ok(()) => true,
+            std::result::Result::Err(e) => {
+                tracing::warn!(error = %e, "ignoring database.url");
+                false
+            }
+        })
+        .unwrap_or_else(|| std::format!("sqlite:{}", db_path.display()));
+    resolve(override_url(), &configured)
+}
+
+/// Returns `database.url` from the config of `rigger_dir` (or the `--config`
+/// override), with a relative SQLite path resolved against the project
+/// directory holding `rigger_dir`.
+///
+/// # Returns
+///
+/// None when the config file is missing or cannot be loaded.
+fn configured_url(rigger_dir: &std::path::Path) -> std::option::Option<String> {
+    let config_path = crate::services::config_path::path_in(rigger_dir);
+    if !config_path.exists() {
+        return std::option::Option::None;
+    }
+    let config = rigger_core::RiggerConfig::load_with_migration(config_path.to_str()?).ok()?;
+    let project_dir = rigger_dir.parent().unwrap_or(rigger_dir);
+    std::option::Option::Some(absolute_sqlite_url(&config.database.url, project_dir))
+}
+
+/// Resolves the path of a relative `sqlite:` URL against `project_dir`.
+///
+/// Absolute paths, in-memory databases, and other schemes are returned unchanged.
+fn absolute_sqlite_url(url: &str, project_dir: &std::path::Path) -> String {
+    if !url.to_lowercase().starts_with(SCHEME) {
+        return url.to_string();
+    }
+    let rest = &url[SCHEME.len()..];
+    let (prefix, path) = match rest.strip_prefix("//") {
+        std::option::Option::Some(path) => (&url[..SCHEME.len() + 2], path),
+        std::option::Option::None => (&url[..SCHEME.len()], rest),
+    };
+    let file = path.split('?').next().unwrap_or(path);
+    if file.is_empty() || file.starts_with(':') || std::path::Path::new(file).is_absolute() {
+        return url.to_string();
+    }
+    std::format!("{}{}", prefix, project_dir.join(path).display())
+}
+
+/// Picks the override: the flag, then the environment, then none.
+fn select(flag: std::option::Option<&str>, env: std::option::Option<String>) -> std::option::Option<String> {
+    flag.map(String::from)
+        .into_iter()
+        .chain(env)
+        .map(|value| value.trim().to_string())
+        .find(|value| !value.is_empty())
+}
+
+/// Returns the override if set, otherwise the URL from config.
+fn resolve(override_url: std::option::Option<&str>, config_url: &str) -> String {
+    override_url.unwrap_or(config_url).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_flag_overrides_env_which_overrides_config() {
+        // Test: Validates --db wins over RIGGER_DB, which wins over the config's database URL.
+        // Justification: A one-off run against a scratch database must not touch the project database.
+        let config_url = "sqlite:/work/.rigger/tasks.db";
+        let resolve = |flag: std::option::Option<&str>, env: std::option::Option<&str>| {
+            super::resolve(super::select(flag, env.map(String::from)).as_deref(), config_url)
+        };
+
+        std::assert_eq!(
+            resolve(std::option::Option::Some("sqlite:/tmp/scratch.db"), std::option::Option::Some("sqlite:/tmp/env.db")),
+            "sqlite:/tmp/scratch.db"
+        );
+        std::assert_eq!(resolve(std::option::Option::None, std::option::Option::Some("sqlite:/tmp/env.db")), "sqlite:/tmp/env.db");
+        std::assert_eq!(resolve(std::option::Option::Some(""), std::option::Option::None), config_url);
+        std::assert_eq!(resolve(std::option::Option::None, std::option::Option::None), config_url);
+    }
+
+    #[test]
+    fn test_validate_accepts_sqlite_only() {
+        // Test: Validates sqlite: URLs pass and anything else, postgres included, is rejected with a clear message.
+        // Justification: Every adapter is SQLite; a postgres URL must fail before a command runs, not as a connection error.
+        std::assert!(super::validate("sqlite:/tmp/scratch.db").is_ok());
+        std::assert!(super::validate("sqlite::memory:").is_ok());
+        std::assert!(super::validate("SQLITE:/tmp/scratch.db").is_ok());
+
+        let err = super::validate("postgres://localhost/rigger").unwrap_err();
+        std::assert!(err.to_string().contains("expected a sqlite: URL"));
+        std::assert!(super::validate("mysql://localhost/rigger").is_err());
+        std::assert!(super::validate("/tmp/scratch.db").is_err());
+    }
+
+    #[test]
+    fn test_config_database_url_is_the_fallback() {
+        // Test: Validates database.url from the project's config is used without an override, resolved against the project directory.
+        // Justification: The config's database URL was parsed but every command opened .rigger/tasks.db regardless.
+        let project = std::env::temp_dir().join(std::format!("database_url_{}", uuid::Uuid::new_v4()));
+        let rigger_dir = project.join(".rigger");
+        std::fs::create_dir_all(&rigger_dir).unwrap();
+        let db_path = rigger_dir.join("tasks.db");
+        std::assert_eq!(super::url_for(&db_path), std::format!("sqlite:{}", db_path.display()));
+
+        let mut config = rigger_core::RiggerConfig::default();
+        config.database.url = String::from("sqlite:data/shared.db");
+        std::fs::write(rigger_dir.join("config.json"), serde_json::to_string(&config).unwrap()).unwrap();
+        std::assert_eq!(super::url_for(&db_path), std::format!("sqlite:{}", project.join("data/shared.db").display()));
+
+        config.database.url = String::from("postgres://localhost/rigger");
+        std::fs::write(rigger_dir.join("config.json"), serde_json::to_string(&config).unwrap()).unwrap();
+        std::assert_eq!(super::url_for(&db_path), std::format!("sqlite:{}", db_path.display()));
+
+        std::fs::remove_dir_all(&project).unwrap();
+    }
+
+    #[test]
+    fn test_relative_sqlite_paths_resolve_against_project() {
+        // Test: Validates only relative sqlite: paths are joined to the project directory.
+        // Justification: The default database.url is relative to the project, not to wherever rig runs.
+        let project = std::path::Path::new("/work/billing");
+        std::assert_eq!(super::absolute_sqlite_url("sqlite:.rigger/tasks.db", project), "sqlite:/work/billing/.rigger/tasks.db");
+        std::assert_eq!(super::absolute_sqlite_url("sqlite://db/t.db?mode=rwc", project), "sqlite:///work/billing/db/t.db?mode=rwc");
+        std::assert_eq!(super::absolute_sqlite_url("sqlite:/var/lib/rigger.db", project), "sqlite:/var/lib/rigger.db");
+        std::assert_eq!(super::absolute_sqlite_url("sqlite::memory:", project), "sqlite::memory:");
+    }
+}
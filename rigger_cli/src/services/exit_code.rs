@@ -0,0 +1,56 @@
+//! Process exit codes for the rig binary.
+//!
+//! Commands return `anyhow` errors; when the cause is a task_manager
+//! `TaskManagerError`, the exit code tells scripts what kind of failure it
+//! was without parsing the message:
+//!
+//! | Code | Meaning |
+//! |------|---------|
+//! | 1 | Any other error |
+//! | 3 | Task not found |
+//! | 4 | Invalid status transition (including nothing to undo) |
+//! | 5 | Validation failed |
+//! | 6 | Storage (database) error |
+//!
+//! Revision History
+//! - 2026-10-17T10:50:00Z @AI: Initial mapping from TaskManagerError variants to exit codes.
+
+/// Exit code for errors that are not task errors.
+pub const GENERAL_FAILURE: u8 = 1;
+
+/// Returns the exit code for `error`, using the first TaskManagerError in its chain.
+pub fn for_error(error: &anyhow::Error) -> u8 {
+    let task_error = error
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<task_manager::domain::task_manager_error::TaskManagerError>());
+    match task_error {
+        std::option::Option::Some(task_manager::domain::task_manager_error::TaskManagerError::NotFound(_)) => 3,
+        std::option::Option::Some(
+            task_manager::domain::task_manager_error::TaskManagerError::InvalidTransition { .. }
+            | task_manager::domain::task_manager_error::TaskManagerError::NothingToUndo(_),
+        ) => 4,
+        std::option::Option::Some(task_manager::domain::task_manager_error::TaskManagerError::ValidationFailed { .. }) => 5,
+        std::option::Option::Some(task_manager::domain::task_manager_error::TaskManagerError::Storage { .. }) => 6,
+        std::option::Option::None => GENERAL_FAILURE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_task_errors_map_to_distinct_codes_through_context() {
+        // Test: Validates task errors keep their exit code when wrapped in context, and other errors exit 1.
+        // Justification: Commands add context to use case errors; scripts rely on the code, not the message.
+        let not_found = anyhow::Error::new(task_manager::domain::task_manager_error::TaskManagerError::NotFound(
+            std::string::String::from("t-1"),
+        ))
+        .context("Failed to start task");
+        let invalid = anyhow::Error::new(task_manager::domain::task_manager_error::TaskManagerError::ValidationFailed {
+            errors: std::vec![task_manager::domain::task_manager_error::FieldError::new("title", "must not be empty")],
+        });
+
+        std::assert_eq!(super::for_error(&not_found), 3);
+        std::assert_eq!(super::for_error(&invalid), 5);
+        std::assert_eq!(super::for_error(&anyhow::anyhow!("boom")), super::GENERAL_FAILURE);
+    }
+}
@@ -0,0 +1,207 @@
+//! Fuzzy matching service for finding tasks by title or short ID.
+//!
+//! Scores candidates by match quality (exact, prefix, word-start substring,
+//! substring, then in-order subsequence) so the best matches sort first.
+//! Used by the `rig do` task picker and the TUI spotlight search.
+//!
+//! Revision History
+//! - 2026-10-16T17:50:00Z @AI: Initial fuzzy matcher with task ranking over title and short ID.
+
+/// Number of results pickers show by default.
+pub const DEFAULT_LIMIT: usize = 10;
+
+/// Number of leading ID characters shown and matched as the short ID.
+pub const SHORT_ID_LEN: usize = 8;
+
+/// Scores how well `query` matches `candidate` (case-insensitive).
+///
+/// Higher is better. Exact matches score 1000, prefixes 900, substrings at a
+/// word start 800, other substrings 700 minus their offset, and in-order
+/// subsequences at most 500, less for gaps and late starts.
+///
+/// # Arguments
+///
+/// * `query` - Text typed by the user
+/// * `candidate` - Text to match against
+///
+/// # Returns
+///
+/// The score, or None if `query` is not a subsequence of `candidate`.
+/// An empty query matches everything with score 0.
+///
+/// # Examples
+///
+/// ```
+/// # use rigger_cli::services::fuzzy_matcher::score;
+/// assert!(score("data", "Setup database") > score("sdb", "Setup database"));
+/// assert_eq!(score("xyz", "Setup database"), None);
+/// ```
+pub fn score(query: &str, candidate: &str) -> std::option::Option<u32> {
+    let query = query.to_lowercase();
+    let candidate = candidate.to_lowercase();
+
+    if query.is_empty() {
+        return std::option::Option::Some(0);
+    }
+    if candidate == query {
+        return std::option::Option::Some(1000);
+    }
+    if candidate.starts_with(&query) {
+        return std::option::Option::Some(900);
+    }
+    if let std::option::Option::Some(pos) = candidate.find(&query) {
+        let at_word_start = candidate[..pos]
+            .chars()
+            .next_back()
+            .is_some_and(|c| !c.is_alphanumeric());
+        if at_word_start {
+            return std::option::Option::Some(800);
+        }
+        return std::option::Option::Some(700 - pos.min(100) as u32);
+    }
+
+    // Subsequence: every query char appears in order
+    let mut query_chars = query.chars().peekable();
+    let mut first = std::option::Option::None;
+    let mut last = 0;
+    for (i, c) in candidate.chars().enumerate() {
+        if query_chars.peek() == std::option::Option::Some(&c) {
+            query_chars.next();
+            first.get_or_insert(i);
+            last = i;
+        }
+    }
+    if query_chars.peek().is_some() {
+        return std::option::Option::None;
+    }
+    let first = first.unwrap_or(0);
+    let gaps = (last - first + 1).saturating_sub(query.chars().count());
+    std::option::Option::Some(500u32.saturating_sub((gaps * 10 + first) as u32).max(1))
+}
+
+/// Scores a task against a query using its title and short ID.
+///
+/// An ID prefix match (for example a pasted short ID) outranks title matches.
+/// IDs only match contiguously, since hex IDs would otherwise match almost
+/// any short subsequence.
+///
+/// # Returns
+///
+/// The best score over title and ID, or None if neither matches.
+pub fn score_task(query: &str, task: &task_manager::domain::task::Task) -> std::option::Option<u32> {
+    let query_lower = query.trim().to_lowercase();
+    if !query_lower.is_empty() && task.id.to_lowercase().starts_with(&query_lower) {
+        return std::option::Option::Some(950);
+    }
+
+    let short_id: String = task.id.chars().take(SHORT_ID_LEN).collect();
+    let title_score = score(query.trim(), &task.title);
+    let id_score = score(query.trim(), &short_id).filter(|s| *s > 500);
+    title_score.max(id_score)
+}
+
+/// Ranks tasks by match quality and returns the best `limit`.
+///
+/// Ties keep the shorter title first (a tighter match), then the original
+/// order. An empty query returns the first `limit` tasks unchanged.
+///
+/// # Arguments
+///
+/// * `query` - Text typed by the user
+/// * `tasks` - Candidate tasks
+/// * `limit` - Maximum number of results
+///
+/// # Returns
+///
+/// Matching tasks, best first.
+pub fn rank_tasks<'a>(
+    query: &str,
+    tasks: &'a [task_manager::domain::task::Task],
+    limit: usize,
+) -> std::vec::Vec<&'a task_manager::domain::task::Task> {
+    if query.trim().is_empty() {
+        return tasks.iter().take(limit).collect();
+    }
+
+    let mut scored: std::vec::Vec<(u32, &task_manager::domain::task::Task)> = tasks
+        .iter()
+        .filter_map(|task| score_task(query, task).map(|s| (s, task)))
+        .collect();
+
+    scored.sort_by(|(a_score, a_task), (b_score, b_task)| {
+        b_score
+            .cmp(a_score)
+            .then_with(|| a_task.title.len().cmp(&b_task.title.len()))
+    });
+
+    scored.into_iter().take(limit).map(|(_, task)| task).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    fn task(id: &str, title: &str) -> task_manager::domain::task::Task {
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from(title),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let mut task = task_manager::domain::task::Task::from_action_item(&action, std::option::Option::None);
+        task.id = std::string::String::from(id);
+        task
+    }
+
+    fn fixed_tasks() -> std::vec::Vec<task_manager::domain::task::Task> {
+        std::vec![
+            task("a1b2c3d4-0000-0000-0000-000000000001", "Add database indexes"),
+            task("e5f6a7b8-0000-0000-0000-000000000002", "Setup database"),
+            task("c9d0e1f2-0000-0000-0000-000000000003", "Update user docs"),
+            task("a1b9ffff-0000-0000-0000-000000000004", "Deploy staging"),
+        ]
+    }
+
+    fn titles(ranked: &[&task_manager::domain::task::Task]) -> std::vec::Vec<std::string::String> {
+        ranked.iter().map(|t| t.title.clone()).collect()
+    }
+
+    #[test]
+    fn test_rank_orders_by_match_quality() {
+        // Test: Validates prefix beats word-start substring, which beats mid-word substring.
+        // Justification: The best match must be the default selection in the picker.
+        let tasks = fixed_tasks();
+
+        std::assert_eq!(
+            titles(&super::rank_tasks("up", &tasks, super::DEFAULT_LIMIT)),
+            std::vec!["Update user docs", "Setup database"]
+        );
+        std::assert_eq!(
+            titles(&super::rank_tasks("data", &tasks, super::DEFAULT_LIMIT)),
+            std::vec!["Setup database", "Add database indexes"]
+        );
+    }
+
+    #[test]
+    fn test_rank_matches_subsequences() {
+        // Test: Validates non-contiguous queries match and non-matching tasks are dropped.
+        // Justification: Users type abbreviations like "sdb" for "Setup database".
+        let tasks = fixed_tasks();
+
+        std::assert_eq!(
+            titles(&super::rank_tasks("sdb", &tasks, super::DEFAULT_LIMIT)),
+            std::vec!["Setup database"]
+        );
+        std::assert!(super::rank_tasks("zzz", &tasks, super::DEFAULT_LIMIT).is_empty());
+    }
+
+    #[test]
+    fn test_rank_short_id_prefix_and_limit() {
+        // Test: Validates an ID prefix ranks its tasks first and the limit is applied.
+        // Justification: Pasted short IDs are ambiguous when several tasks share a prefix.
+        let tasks = fixed_tasks();
+
+        std::assert_eq!(
+            titles(&super::rank_tasks("a1b", &tasks, super::DEFAULT_LIMIT)),
+            std::vec!["Deploy staging", "Add database indexes"]
+        );
+        std::assert_eq!(super::rank_tasks("", &tasks, 2).len(), 2);
+    }
+}
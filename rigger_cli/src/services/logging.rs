@@ -0,0 +1,74 @@
+//! Structured JSON logging for the rig binary.
+//!
+//! Installs a `tracing` subscriber that writes one JSON object per line to
+//! stderr, so stdout stays clean for command output and the MCP server's
+//! JSON-RPC stream. Span close events carry the span's fields (task_id,
+//! node, provider, latency_ms, ...).
+//!
+//! The filter uses `RUST_LOG` syntax (e.g. `debug` or
+//! `warn,task_orchestrator=debug`) and is taken from `--log-level`, then the
+//! `RIGGER_LOG` environment variable, then defaults to `warn`.
+//!
+//! Revision History
+//! - 2026-10-16T19:50:00Z @AI: Initial JSON tracing subscriber with --log-level and RIGGER_LOG.
+
+/// Environment variable holding the default log filter.
+pub const LOG_ENV_VAR: &str = "RIGGER_LOG";
+
+/// Filter used when neither `--log-level` nor `RIGGER_LOG` is set.
+const DEFAULT_FILTER: &str = "warn";
+
+/// Installs the global JSON tracing subscriber.
+///
+/// # Arguments
+///
+/// * `log_level` - Value of `--log-level`, which overrides `RIGGER_LOG`
+///
+/// # Errors
+///
+/// Returns an error if the filter cannot be parsed or a global subscriber
+/// is already installed.
+pub fn init(log_level: std::option::Option<&str>) -> anyhow::Result<()> {
+    let directives = resolve_filter(log_level, std::env::var(LOG_ENV_VAR).ok());
+    let filter = tracing_subscriber::EnvFilter::try_new(&directives)
+        .map_err(|e| anyhow::anyhow!("Invalid log level '{}': {}", directives, e))?;
+
+    tracing_subscriber::fmt()
+        .json()
+        .with_env_filter(filter)
+        .with_span_events(tracing_subscriber::fmt::format::FmtSpan::CLOSE)
+        .with_writer(std::io::stderr)
+        .try_init()
+        .map_err(|e| anyhow::anyhow!("Failed to initialize logging: {}", e))
+}
+
+/// Picks the filter directives: the flag, then the environment, then `warn`.
+///
+/// Blank values are treated as unset.
+fn resolve_filter(flag: std::option::Option<&str>, env: std::option::Option<String>) -> String {
+    flag.map(std::string::String::from)
+        .into_iter()
+        .chain(env)
+        .map(|value| value.trim().to_string())
+        .find(|value| !value.is_empty())
+        .unwrap_or_else(|| std::string::String::from(DEFAULT_FILTER))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_flag_overrides_env_and_default() {
+        // Test: Validates --log-level wins over RIGGER_LOG, which wins over the warn default.
+        // Justification: A one-off --log-level debug must work even when RIGGER_LOG is exported.
+        std::assert_eq!(
+            super::resolve_filter(std::option::Option::Some("debug"), std::option::Option::Some(std::string::String::from("error"))),
+            "debug"
+        );
+        std::assert_eq!(
+            super::resolve_filter(std::option::Option::None, std::option::Option::Some(std::string::String::from("info,task_manager=debug"))),
+            "info,task_manager=debug"
+        );
+        std::assert_eq!(super::resolve_filter(std::option::Option::None, std::option::Option::Some(std::string::String::from(" "))), "warn");
+        std::assert_eq!(super::resolve_filter(std::option::Option::None, std::option::Option::None), "warn");
+    }
+}
@@ -0,0 +1,100 @@
+//! JSONL metrics file for orchestrator runs, from `.rigger/config.json`.
+//!
+//! With `performance.enable_metrics` on (the default), `rig do` records every
+//! provider call of its run in the file named by `performance.metrics_file`,
+//! rotated at `performance.metrics_max_bytes`. The `{project}` placeholder is
+//! replaced with the name of the directory holding `.rigger`. `rig metrics`
+//! reads the same files back through `load_run_paths`.
+//!
+//! Revision History
+//! - 2026-10-17T22:00:00Z @AI: Add load_run_paths for `rig metrics`.
+//! - 2026-10-17T21:50:00Z @AI: Initial metrics file collector built from the performance section.
+
+/// Returns the project name used for `{project}`: the directory holding `.rigger`.
+pub fn project_name(rigger_dir: &std::path::Path) -> std::string::String {
+    rigger_dir
+        .parent()
+        .and_then(|project| project.file_name())
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Creates the run's metrics file collector from the config at `config_path`.
+///
+/// # Returns
+///
+/// None when `performance.enable_metrics` is off.
+///
+/// # Errors
+///
+/// Returns an error if the config cannot be loaded.
+pub fn load_collector(
+    config_path: &std::path::Path,
+    project: &str,
+) -> anyhow::Result<std::option::Option<std::sync::Arc<task_orchestrator::adapters::jsonl_metrics_collector::JsonlMetricsCollector>>> {
+    let config = rigger_core::RiggerConfig::load_with_migration(
+        config_path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid config path"))?
+    )?;
+    std::result::Result::Ok(collector_for(&config.performance, project))
+}
+
+/// Returns the metrics files `rig do` runs have written under the config at `config_path`.
+///
+/// # Returns
+///
+/// The current file of each run, oldest first; empty when
+/// `performance.enable_metrics` is off.
+///
+/// # Errors
+///
+/// Returns an error if the config cannot be loaded.
+pub fn load_run_paths(config_path: &std::path::Path, project: &str) -> anyhow::Result<std::vec::Vec<std::path::PathBuf>> {
+    let config = rigger_core::RiggerConfig::load_with_migration(
+        config_path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid config path"))?
+    )?;
+    if !config.performance.enable_metrics {
+        return std::result::Result::Ok(std::vec::Vec::new());
+    }
+    std::result::Result::Ok(task_orchestrator::adapters::jsonl_metrics_collector::run_paths(
+        &config.performance.metrics_file,
+        project,
+    ))
+}
+
+/// Creates the metrics file collector for a loaded `performance` section.
+pub fn collector_for(
+    performance: &rigger_core::config::PerformanceConfig,
+    project: &str,
+) -> std::option::Option<std::sync::Arc<task_orchestrator::adapters::jsonl_metrics_collector::JsonlMetricsCollector>> {
+    if !performance.enable_metrics {
+        return std::option::Option::None;
+    }
+    let config = task_orchestrator::infrastructure::config::PerformanceConfig {
+        enable_metrics: performance.enable_metrics,
+        metrics_file: performance.metrics_file.clone(),
+        metrics_max_bytes: performance.metrics_max_bytes,
+    };
+    std::option::Option::Some(std::sync::Arc::new(
+        task_orchestrator::adapters::jsonl_metrics_collector::JsonlMetricsCollector::from_config(&config, project),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_collector_follows_performance_section() {
+        // Test: Validates the collector resolves metrics_file for the project and is absent with enable_metrics off.
+        // Justification: The performance section was parsed but no run ever wrote the configured file.
+        let mut performance = rigger_core::config::PerformanceConfig {
+            metrics_file: std::string::String::from(".rigger/metrics/{project}.jsonl"),
+            ..rigger_core::config::PerformanceConfig::default()
+        };
+
+        let collector = super::collector_for(&performance, "billing").expect("metrics are enabled by default");
+        std::assert_eq!(collector.path(), std::path::Path::new(".rigger/metrics/billing.jsonl"));
+
+        performance.enable_metrics = false;
+        std::assert!(super::collector_for(&performance, "billing").is_none());
+        std::assert_eq!(super::project_name(std::path::Path::new("/work/billing/.rigger")), "billing");
+    }
+}
@@ -5,6 +5,30 @@
 //! that transform data without side effects.
 //!
 //! Revision History
+//! - 2026-10-18T01:00:00Z @AI: Add provider_slot for building provider factories from task slots.
+//! - 2026-10-17T21:50:00Z @AI: Add metrics_file for the orchestrator's configured JSONL metrics.
+//! - 2026-10-17T14:10:00Z @AI: Add database_url for the --db / RIGGER_DB override.
+//! - 2026-10-17T13:50:00Z @AI: Add config_path for the --config / RIGGER_CONFIG override.
+//! - 2026-10-17T10:50:00Z @AI: Add exit_code for mapping task errors to process exit codes.
+//! - 2026-10-17T05:50:00Z @AI: Add database_pool for SQLite pool settings from config.
+//! - 2026-10-16T21:30:00Z @AI: Add response_cache for rig parse cache and sampling settings.
+//! - 2026-10-16T21:10:00Z @AI: Add offline_mode for the air-gapped startup check.
+//! - 2026-10-16T20:30:00Z @AI: Add run_cost for the cost summary printed by `rig do` and `rig parse`.
+//! - 2026-10-16T19:50:00Z @AI: Add logging for the JSON tracing subscriber.
+//! - 2026-10-16T19:30:00Z @AI: Add server_auth for bearer-token checks in the gRPC and MCP servers.
+//! - 2026-10-16T17:50:00Z @AI: Add fuzzy_matcher for ranking tasks by title and short ID.
 //! - 2025-11-24T00:30:00Z @AI: Create services module for task formatting.
 
+pub mod fuzzy_matcher;
 pub mod task_formatter;
+pub mod server_auth;
+pub mod logging;
+pub mod run_cost;
+pub mod offline_mode;
+pub mod response_cache;
+pub mod database_pool;
+pub mod exit_code;
+pub mod config_path;
+pub mod database_url;
+pub mod metrics_file;
+pub mod provider_slot;
@@ -0,0 +1,131 @@
+//! Offline (air-gapped) mode checks for the rig binary.
+//!
+//! When `offline` is set in `.rigger/config.json`, every provider must point
+//! at localhost or a private IP. `check_startup` enforces this before a
+//! command runs, so a misconfigured cloud provider stops the command up
+//! front instead of failing (or succeeding) on its first request. Call sites
+//! that build a `ProviderFactory` also pass the flag to
+//! `ProviderFactory::with_offline`.
+//!
+//! Revision History
+//! - 2026-10-17T23:30:00Z @AI: Fail closed when the config sets offline but cannot be loaded.
+//! - 2026-10-17T13:50:00Z @AI: Read the config path from services::config_path, honoring --config and RIGGER_CONFIG.
+//! - 2026-10-16T21:10:00Z @AI: Initial offline mode startup check.
+
+/// Refuses to start if offline mode is on and a provider is not local.
+///
+/// Does nothing if the current directory has no `.rigger/config.json`.
+///
+/// # Errors
+///
+/// Returns an error listing every provider whose base URL is not localhost
+/// or a private IP, or the load error when the config sets `offline` but
+/// cannot be loaded.
+pub fn check_startup() -> anyhow::Result<()> {
+    check_config(&crate::services::config_path::current()?)
+}
+
+/// Runs the startup check against the config at `config_path`.
+///
+/// A config that cannot be loaded only passes when it does not turn
+/// offline mode on; other commands report the load error themselves. A
+/// file that sets `offline` is checked in offline mode even if migration
+/// fell back to defaults.
+fn check_config(config_path: &std::path::Path) -> anyhow::Result<()> {
+    if !config_path.exists() {
+        return std::result::Result::Ok(());
+    }
+    let loaded = config_path
+        .to_str()
+        .ok_or_else(|| anyhow::anyhow!("Invalid config path"))
+        .and_then(|path| rigger_core::RiggerConfig::load_with_migration(path).map_err(anyhow::Error::from));
+    match loaded {
+        std::result::Result::Ok(mut config) => {
+            config.offline = config.offline || offline_requested(config_path);
+            ensure_local_providers(&config)
+        }
+        std::result::Result::Err(e) if offline_requested(config_path) => std::result::Result::Err(e.context(std::format!(
+            "Offline mode is enabled but {} could not be loaded to check the providers",
+            config_path.display()
+        ))),
+        std::result::Result::Err(_) => std::result::Result::Ok(()),
+    }
+}
+
+/// Returns whether the raw JSON sets `"offline": true`, the local override taking precedence.
+fn offline_requested(config_path: &std::path::Path) -> bool {
+    let raw_flag = |path: &std::path::Path| {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|raw| serde_json::from_str::<serde_json::Value>(&raw).ok())
+            .and_then(|value| value.get("offline").and_then(serde_json::Value::as_bool))
+    };
+    let local_path = rigger_core::RiggerConfig::local_override_path(&config_path.to_string_lossy());
+    raw_flag(&local_path).or_else(|| raw_flag(config_path)).unwrap_or(false)
+}
+
+/// Fails if offline mode is on and any configured provider is not local.
+///
+/// # Errors
+///
+/// Returns an error listing the offending providers, sorted by name.
+pub fn ensure_local_providers(config: &rigger_core::RiggerConfig) -> anyhow::Result<()> {
+    let mut violations: std::vec::Vec<String> = match config.validate() {
+        std::result::Result::Ok(()) => return std::result::Result::Ok(()),
+        std::result::Result::Err(errors) => errors
+            .iter()
+            .filter(|error| std::matches!(error, rigger_core::config::ConfigError::OfflineViolation { .. }))
+            .map(|error| std::format!("  - {}", error))
+            .collect(),
+    };
+    if violations.is_empty() {
+        return std::result::Result::Ok(());
+    }
+    violations.sort();
+    anyhow::bail!(
+        "Offline mode is enabled but these providers are not local:\n{}\nPoint them at localhost or a private IP, remove them, or set \"offline\": false in .rigger/config.json.",
+        violations.join("\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_offline_startup_check_lists_cloud_providers() {
+        // Test: Validates the startup check passes a local-only config and names each cloud provider otherwise.
+        // Justification: The error must tell an air-gapped user exactly which provider entry to fix.
+        let mut config = rigger_core::RiggerConfig {
+            offline: true,
+            ..std::default::Default::default()
+        };
+        std::assert!(super::ensure_local_providers(&config).is_ok());
+
+        let mut anthropic = config.providers.get("ollama").unwrap().clone();
+        anthropic.provider_type = rigger_core::config::ProviderType::Anthropic;
+        anthropic.base_url = std::string::String::from("https://api.anthropic.com");
+        config.providers.insert(std::string::String::from("anthropic"), anthropic);
+
+        let message = super::ensure_local_providers(&config).unwrap_err().to_string();
+        std::assert!(message.contains("Provider anthropic is not allowed in offline mode"));
+        std::assert!(!message.contains("Provider ollama"));
+    }
+
+    #[test]
+    fn test_offline_config_that_fails_to_load_is_refused() {
+        // Test: Validates an unloadable config stops startup only when it sets offline.
+        // Justification: Failing open let a broken air-gapped config reach cloud providers unchecked.
+        let dir = std::env::temp_dir().join(std::format!("rigger_offline_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let config_path = dir.join("config.json");
+
+        std::fs::write(&config_path, r#"{"version": "3.0", "offline": true, "providers": "not a map"}"#).unwrap();
+        let message = std::format!("{:#}", super::check_config(&config_path).unwrap_err());
+        std::assert!(message.contains("Offline mode is enabled"));
+
+        std::fs::write(&config_path, r#"{"version": "3.0", "offline": false, "providers": "not a map"}"#).unwrap();
+        std::assert!(super::check_config(&config_path).is_ok());
+        std::assert!(super::check_config(&dir.join("missing.json")).is_ok());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,134 @@
+//! Provider factories built from a task slot of the project config.
+//!
+//! Commands that call a provider pick the slot for the job (`main` for
+//! generation and reranking, `embedding` for vectors, `chat_agent` for chat)
+//! and build their `ProviderFactory` here. The config is loaded with
+//! `RiggerConfig::load_with_migration`, so `config.local.json` applies, and
+//! every factory gets the slot's model and the provider's base URL, API key,
+//! `auto_pull`, and retry policy, plus the config's `offline` flag.
+//!
+//! Revision History
+//! - 2026-10-18T01:00:00Z @AI: Initial provider factory construction from task slots.
+
+/// Loads the config of the project in `rigger_dir`, merged with its local override.
+///
+/// # Errors
+///
+/// Returns an error if the config path is not UTF-8 or the config cannot be loaded.
+pub fn load_config(rigger_dir: &std::path::Path) -> anyhow::Result<rigger_core::RiggerConfig> {
+    let config_path = crate::services::config_path::path_in(rigger_dir);
+    let path = config_path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid config path"))?;
+    rigger_core::RiggerConfig::load_with_migration(path)
+        .map_err(|e| anyhow::anyhow!("Failed to load {}: {}", config_path.display(), e))
+}
+
+/// Returns the provider configured for `slot`.
+///
+/// # Errors
+///
+/// Returns an error if `config.providers` has no entry for the slot's provider.
+pub fn provider_for<'a>(
+    config: &'a rigger_core::RiggerConfig,
+    slot: &rigger_core::config::TaskSlot,
+) -> anyhow::Result<&'a rigger_core::config::ProviderConfig> {
+    config
+        .providers
+        .get(&slot.provider)
+        .ok_or_else(|| anyhow::anyhow!("Provider '{}' not found in config", slot.provider))
+}
+
+/// Builds a factory for `slot`: its provider and model, with the provider's
+/// connection, `auto_pull`, and retry settings and the config's `offline` flag.
+///
+/// # Errors
+///
+/// Returns an error if the provider is missing or of a type the orchestrator
+/// does not support, or if offline mode refuses it.
+pub fn factory_for(
+    config: &rigger_core::RiggerConfig,
+    slot: &rigger_core::config::TaskSlot,
+) -> anyhow::Result<task_orchestrator::adapters::provider_factory::ProviderFactory> {
+    let provider = provider_for(config, slot)?;
+    let factory_provider = match &provider.provider_type {
+        rigger_core::config::ProviderType::Ollama => "ollama",
+        rigger_core::config::ProviderType::OpenAI => "openai",
+        rigger_core::config::ProviderType::OpenAiCompatible => "openai-compatible",
+        rigger_core::config::ProviderType::Anthropic => "anthropic",
+        rigger_core::config::ProviderType::Custom(name) => name.as_str(),
+        other => anyhow::bail!("{} providers are not supported by the orchestrator", other),
+    };
+    std::result::Result::Ok(
+        task_orchestrator::adapters::provider_factory::ProviderFactory::new(factory_provider, &slot.model)
+            .map_err(|e| anyhow::anyhow!("Failed to create provider factory: {}", e))?
+            .with_offline(config.offline)
+            .map_err(|e| anyhow::anyhow!("Failed to create provider factory: {}", e))?
+            .with_base_url(&provider.base_url)
+            .with_api_key(provider.get_api_key().ok().flatten())
+            .with_auto_pull(provider.auto_pull)
+            .with_retry_policy(task_orchestrator::infrastructure::retry_policy::RetryPolicy::from_provider_config(provider)),
+    )
+}
+
+/// Builds a factory for the `embedding` slot whose embedding adapters use the slot's model.
+///
+/// # Errors
+///
+/// Returns the errors of `factory_for`.
+pub fn embedding_factory(
+    config: &rigger_core::RiggerConfig,
+) -> anyhow::Result<task_orchestrator::adapters::provider_factory::ProviderFactory> {
+    let slot = &config.task_slots.embedding;
+    std::result::Result::Ok(factory_for(config, slot)?.with_embedding_model(slot.model.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    fn config_with_provider(provider_type: rigger_core::config::ProviderType) -> rigger_core::RiggerConfig {
+        let mut config = rigger_core::RiggerConfig::default();
+        let provider = config.providers.get_mut("ollama").unwrap();
+        provider.provider_type = provider_type;
+        config
+    }
+
+    #[test]
+    fn test_factory_for_rejects_unknown_slot_provider() {
+        // Test: Validates a slot naming a provider missing from the config is reported by name.
+        // Justification: Falling back to a default provider would silently call a different model.
+        let mut config = rigger_core::RiggerConfig::default();
+        config.task_slots.main.provider = std::string::String::from("missing");
+
+        let error = super::factory_for(&config, &config.task_slots.main).err().unwrap();
+
+        std::assert!(error.to_string().contains("Provider 'missing' not found"));
+    }
+
+    #[test]
+    fn test_factory_for_rejects_unsupported_provider_types() {
+        // Test: Validates provider types the orchestrator has no adapters for are refused up front.
+        // Justification: The caller should see the unsupported type, not a later adapter error.
+        let config = config_with_provider(rigger_core::config::ProviderType::Groq);
+
+        std::assert!(super::factory_for(&config, &config.task_slots.main).is_err());
+        std::assert!(super::factory_for(&config_with_provider(rigger_core::config::ProviderType::Ollama), &config.task_slots.main).is_ok());
+    }
+
+    #[test]
+    fn test_load_config_applies_local_override() {
+        // Test: Validates settings in config.local.json reach the loaded config.
+        // Justification: Commands used to read only config.json and ignored local overrides.
+        let dir = std::env::temp_dir().join(std::format!("rigger_provider_slot_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let config_path = dir.join("config.json");
+        std::fs::write(&config_path, serde_json::to_string(&rigger_core::RiggerConfig::default()).unwrap()).unwrap();
+        std::fs::write(
+            rigger_core::RiggerConfig::local_override_path(&config_path.to_string_lossy()),
+            r#"{"task_slots": {"main": {"provider": "ollama", "model": "qwen3:8b", "description": "Local"}}}"#,
+        )
+        .unwrap();
+
+        let config = super::load_config(&dir);
+        let _ = std::fs::remove_dir_all(&dir);
+
+        std::assert_eq!(config.unwrap().task_slots.main.model, "qwen3:8b");
+    }
+}
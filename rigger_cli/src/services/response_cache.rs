@@ -0,0 +1,57 @@
+//! Response cache and sampling settings for `rig parse`.
+//!
+//! Reads the `sampling` section and `performance.response_cache` from
+//! .rigger/config.json. The cache only serves calls made at temperature 0 or
+//! with a fixed seed; `--no-cache` turns it off for one run.
+//!
+//! Revision History
+//! - 2026-10-16T21:30:00Z @AI: Initial response cache and sampling settings for rig parse.
+
+/// Converts the config's sampling section to adapter sampling parameters.
+pub fn sampling(config: &rigger_core::RiggerConfig) -> task_orchestrator::infrastructure::sampling::SamplingParams {
+    task_orchestrator::infrastructure::sampling::SamplingParams::new(config.sampling.temperature, config.sampling.seed)
+}
+
+/// Creates the run's response cache, or `None` if caching is off.
+///
+/// A relative cache directory is resolved against `project_dir`. A TTL of 0
+/// keeps entries forever.
+///
+/// # Arguments
+///
+/// * `config` - Project config
+/// * `project_dir` - Directory containing .rigger/
+/// * `no_cache` - `--no-cache` flag, which disables the cache for this run
+pub fn load(
+    config: &rigger_core::RiggerConfig,
+    project_dir: &std::path::Path,
+    no_cache: bool,
+) -> std::option::Option<std::sync::Arc<task_orchestrator::infrastructure::response_cache::ResponseCache>> {
+    let settings = &config.performance.response_cache;
+    if no_cache || !settings.enabled {
+        return std::option::Option::None;
+    }
+    let ttl = std::option::Option::Some(settings.ttl_seconds)
+        .filter(|seconds| *seconds > 0)
+        .map(std::time::Duration::from_secs);
+    std::option::Option::Some(std::sync::Arc::new(
+        task_orchestrator::infrastructure::response_cache::ResponseCache::new(project_dir.join(&settings.dir), ttl),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_cache_requires_config_and_respects_no_cache() {
+        // Test: Validates the cache is off by default, on when enabled, and off again with --no-cache.
+        // Justification: --no-cache must always force fresh provider calls.
+        let mut config = rigger_core::RiggerConfig::default();
+        let project_dir = std::path::Path::new("/tmp/project");
+        std::assert!(super::load(&config, project_dir, false).is_none());
+
+        config.performance.response_cache.enabled = true;
+        let cache = super::load(&config, project_dir, false).unwrap();
+        std::assert_eq!(cache.dir(), project_dir.join(".rigger/cache/responses"));
+        std::assert!(super::load(&config, project_dir, true).is_none());
+    }
+}
@@ -0,0 +1,155 @@
+//! Cost summary for `rig do` and `rig parse` runs.
+//!
+//! Builds a `CostAccumulator` from the `pricing` section of
+//! .rigger/config.json, which maps model names to dollar costs per 1,000
+//! input and output tokens, and prints the run's per-model cost at the end
+//! of the command. Models without an entry are listed as unpriced.
+//!
+//! `load_budget` also applies `performance.max_tokens_per_run` and
+//! `performance.max_cost_per_run`, which the orchestrator enforces before
+//! each provider call.
+//!
+//! Revision History
+//! - 2026-10-17T16:10:00Z @AI: Add load_prices for pricing `rig do` run previews.
+//! - 2026-10-16T20:50:00Z @AI: Replace load_accumulator with load_budget, which adds the run's token and cost limits.
+//! - 2026-10-16T20:30:00Z @AI: Initial price table loading and cost summary printing.
+
+/// Creates the run's token budget from the project config.
+///
+/// # Arguments
+///
+/// * `config_path` - Path to .rigger/config.json
+/// * `max_cost` - `--max-cost` value, which overrides `performance.max_cost_per_run`
+///
+/// # Errors
+///
+/// Returns an error if the config cannot be loaded or the cost limit is not
+/// a positive number.
+pub fn load_budget(
+    config_path: &std::path::Path,
+    max_cost: std::option::Option<f64>,
+) -> anyhow::Result<std::sync::Arc<task_orchestrator::domain::token_budget::TokenBudget>> {
+    let config = rigger_core::RiggerConfig::load_with_migration(
+        config_path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid config path"))?
+    )?;
+    let max_cost = max_cost.or(config.performance.max_cost_per_run);
+    if let std::option::Option::Some(limit) = max_cost.filter(|limit| !(limit.is_finite() && *limit > 0.0)) {
+        anyhow::bail!("Maximum cost per run must be a positive number of dollars, got {}", limit);
+    }
+
+    std::result::Result::Ok(std::sync::Arc::new(task_orchestrator::domain::token_budget::TokenBudget::new(
+        config.performance.max_tokens_per_run,
+        max_cost,
+        to_model_prices(&config.pricing),
+    )))
+}
+
+/// Loads the config's price table, keyed by model name.
+///
+/// # Errors
+///
+/// Returns an error if the config cannot be loaded.
+pub fn load_prices(
+    config_path: &std::path::Path,
+) -> anyhow::Result<std::collections::HashMap<std::string::String, task_orchestrator::domain::performance_metrics::ModelPrice>> {
+    let config = rigger_core::RiggerConfig::load_with_migration(
+        config_path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid config path"))?
+    )?;
+    std::result::Result::Ok(to_model_prices(&config.pricing))
+}
+
+/// Prints the cost summary block.
+///
+/// # Arguments
+///
+/// * `accumulator` - Token usage and cost recorded during the run
+pub fn print_summary(accumulator: &task_orchestrator::domain::performance_metrics::CostAccumulator) {
+    println!("Cost:");
+    print!("{}", accumulator);
+    println!();
+}
+
+/// Creates an empty cost accumulator from a loaded config's price table.
+///
+/// # Arguments
+///
+/// * `pricing` - The config's `pricing` section
+pub fn from_pricing(
+    pricing: &std::collections::HashMap<std::string::String, rigger_core::config::ModelPricing>,
+) -> task_orchestrator::domain::performance_metrics::CostAccumulator {
+    task_orchestrator::domain::performance_metrics::CostAccumulator::new(to_model_prices(pricing))
+}
+
+/// Converts the config's `pricing` section into the orchestrator's price table.
+fn to_model_prices(
+    pricing: &std::collections::HashMap<std::string::String, rigger_core::config::ModelPricing>,
+) -> std::collections::HashMap<std::string::String, task_orchestrator::domain::performance_metrics::ModelPrice> {
+    pricing.iter().map(|(model, price)| (model.clone(), to_model_price(price))).collect()
+}
+
+/// Converts a config price entry into the orchestrator's price type.
+fn to_model_price(price: &rigger_core::config::ModelPricing) -> task_orchestrator::domain::performance_metrics::ModelPrice {
+    task_orchestrator::domain::performance_metrics::ModelPrice::new(price.input_per_1k, price.output_per_1k)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_config_pricing_prices_recorded_usage() {
+        // Test: Validates a `pricing` section parsed from config.json prices a recorded call.
+        // Justification: The config field names are the user-facing contract for the price table.
+        let config: rigger_core::RiggerConfig = serde_json::from_str(
+            r#"{"version": "3.0", "pricing": {"llama3.2": {"input_per_1k": 0.1, "output_per_1k": 0.2}}}"#,
+        )
+        .unwrap();
+        let mut accumulator = super::from_pricing(&config.pricing);
+
+        let mut metrics = task_orchestrator::domain::performance_metrics::InferenceMetrics::new(
+            std::string::String::from("prd_parsing"),
+            std::string::String::from("ollama"),
+            std::string::String::from("llama3.2"),
+            std::option::Option::None,
+        );
+        metrics.record_completion(std::time::Duration::from_millis(100), 3000, 1000);
+        accumulator.record(&metrics);
+
+        // 3 * $0.10 + 1 * $0.20
+        std::assert!((accumulator.total_cost() - 0.5).abs() < 1e-9);
+        std::assert!(accumulator.unpriced_models().is_empty());
+    }
+
+    #[test]
+    fn test_max_cost_flag_overrides_config_limit() {
+        // Test: Validates --max-cost replaces performance.max_cost_per_run and non-positive limits are rejected.
+        // Justification: A one-off tighter limit must win over the project default, and a typo like 0 must not silently halt every call.
+        let dir = std::env::temp_dir().join(std::format!("rigger_run_cost_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let config_path = dir.join("config.json");
+        std::fs::write(
+            &config_path,
+            r#"{"version": "3.0", "performance": {"max_cost_per_run": 5.0}, "pricing": {"gpt-4o": {"input_per_1k": 2.5, "output_per_1k": 10.0}}}"#,
+        )
+        .unwrap();
+
+        let mut metrics = task_orchestrator::domain::performance_metrics::InferenceMetrics::new(
+            std::string::String::from("enhancement"),
+            std::string::String::from("openai"),
+            std::string::String::from("gpt-4o"),
+            std::option::Option::None,
+        );
+        // $0.75 per call
+        metrics.record_completion(std::time::Duration::from_millis(100), 100, 50);
+
+        let from_config = super::load_budget(&config_path, std::option::Option::None).unwrap();
+        from_config.record(&metrics);
+        std::assert!(from_config.check().is_ok());
+
+        let overridden = super::load_budget(&config_path, std::option::Option::Some(1.0)).unwrap();
+        overridden.record(&metrics);
+        std::assert_eq!(overridden.check().unwrap_err().max_cost, std::option::Option::Some(1.0));
+
+        std::assert!(super::load_budget(&config_path, std::option::Option::Some(0.0)).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
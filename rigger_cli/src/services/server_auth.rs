@@ -0,0 +1,87 @@
+//! Bearer-token authentication shared by the gRPC and MCP servers.
+//!
+//! The expected token is read from the environment variable named by
+//! `server.auth_token_env` in .rigger/config.json. With no variable
+//! configured the servers stay open, as before.
+//!
+//! Revision History
+//! - 2026-10-16T19:30:00Z @AI: Initial bearer-token helpers for `rig grpc` and `rig server`.
+
+/// Loads the expected token from the project config and environment.
+///
+/// # Arguments
+///
+/// * `config_path` - Path to .rigger/config.json
+///
+/// # Returns
+///
+/// The token, or None if `server.auth_token_env` is not configured.
+///
+/// # Errors
+///
+/// Returns an error if `server.auth_token_env` names a variable that is
+/// unset or empty, so a misconfigured server refuses to start instead of
+/// running unauthenticated.
+pub fn load_expected_token(config_path: &std::path::Path) -> anyhow::Result<std::option::Option<String>> {
+    let config = rigger_core::RiggerConfig::load_with_migration(
+        config_path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid config path"))?
+    )?;
+    let env_var = match config.server.auth_token_env {
+        std::option::Option::Some(env_var) => env_var,
+        std::option::Option::None => return std::result::Result::Ok(std::option::Option::None),
+    };
+
+    match std::env::var(&env_var) {
+        std::result::Result::Ok(token) if !token.is_empty() => std::result::Result::Ok(std::option::Option::Some(token)),
+        _ => anyhow::bail!(
+            "server.auth_token_env is set to {} but that environment variable is not set.\nExport it, or remove server.auth_token_env to run without authentication.",
+            env_var
+        ),
+    }
+}
+
+/// Returns true if an `Authorization` value carries the expected bearer token.
+///
+/// # Arguments
+///
+/// * `authorization` - The client's `Authorization` value, if any (e.g. "Bearer abc")
+/// * `expected` - The configured token
+///
+/// # Examples
+///
+/// ```
+/// # use rigger_cli::services::server_auth::is_authorized;
+/// assert!(is_authorized(Some("Bearer s3cret"), "s3cret"));
+/// assert!(!is_authorized(Some("Bearer wrong"), "s3cret"));
+/// assert!(!is_authorized(None, "s3cret"));
+/// ```
+pub fn is_authorized(authorization: std::option::Option<&str>, expected: &str) -> bool {
+    let presented = match authorization.and_then(|value| value.strip_prefix("Bearer ")) {
+        std::option::Option::Some(token) => token.trim(),
+        std::option::Option::None => return false,
+    };
+    constant_time_eq(presented.as_bytes(), expected.as_bytes())
+}
+
+/// Compares two byte strings without short-circuiting on the first mismatch.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_only_matching_bearer_token_is_authorized() {
+        // Test: Validates the token must match exactly and use the Bearer scheme.
+        // Justification: Prefix matches or other schemes must not bypass authentication.
+        std::assert!(super::is_authorized(std::option::Option::Some("Bearer s3cret"), "s3cret"));
+        std::assert!(!super::is_authorized(std::option::Option::Some("Bearer s3cre"), "s3cret"));
+        std::assert!(!super::is_authorized(std::option::Option::Some("Bearer s3cret2"), "s3cret"));
+        std::assert!(!super::is_authorized(std::option::Option::Some("s3cret"), "s3cret"));
+        std::assert!(!super::is_authorized(std::option::Option::Some("Basic s3cret"), "s3cret"));
+        std::assert!(!super::is_authorized(std::option::Option::None, "s3cret"));
+    }
+}
@@ -5,6 +5,7 @@
 //! operations and sharing.
 //!
 //! Revision History
+//! - 2026-10-16T17:10:00Z @AI: Add format_status_colored for themed terminal output.
 //! - 2025-11-24T18:00:00Z @AI: Add Errored status formatting support.
 //! - 2025-11-24T00:30:00Z @AI: Create task formatter service with comprehensive tests.
 
@@ -130,6 +131,21 @@ pub fn format_task_as_plain_text(task: &task_manager::domain::task::Task) -> Str
     output
 }
 
+/// Formats task status as a human-readable string colored by `theme`.
+///
+/// Clipboard formats stay uncolored; this is for terminal output only.
+///
+/// # Arguments
+///
+/// * `status` - The status to format
+/// * `theme` - Color theme; `Theme::NoColor` returns the plain label
+pub fn format_status_colored(
+    status: &task_manager::domain::task_status::TaskStatus,
+    theme: &crate::display::theme::Theme,
+) -> String {
+    theme.status(status, &format_status(status))
+}
+
 /// Formats task status as a human-readable string.
 fn format_status(status: &task_manager::domain::task_status::TaskStatus) -> String {
     match status {
@@ -173,6 +189,17 @@ mod tests {
             context_files: std::vec![],
             dependencies: std::vec!["task-123".to_string()],
             sort_order: std::option::Option::Some(0),
+            source_section: std::option::Option::None,
+            priority: std::option::Option::None,
+            tags: std::vec::Vec::new(),
+            revisions: std::vec::Vec::new(),
+            checklist: std::vec::Vec::new(),
+            started_at: std::option::Option::None,
+            time_spent_seconds: 0,
+            source_utterance: std::option::Option::None,
+            idempotency_key: std::option::Option::None,
+            links: std::vec::Vec::new(),
+            estimate: std::option::Option::None,
         }
     }
 
@@ -273,6 +300,17 @@ mod tests {
             dependencies: std::vec![],
             completion_summary: std::option::Option::None,
             sort_order: std::option::Option::Some(0),
+            source_section: std::option::Option::None,
+            priority: std::option::Option::None,
+            tags: std::vec::Vec::new(),
+            revisions: std::vec::Vec::new(),
+            checklist: std::vec::Vec::new(),
+            started_at: std::option::Option::None,
+            time_spent_seconds: 0,
+            source_utterance: std::option::Option::None,
+            idempotency_key: std::option::Option::None,
+            links: std::vec::Vec::new(),
+            estimate: std::option::Option::None,
         };
 
         let markdown = format_task_as_markdown(&task);
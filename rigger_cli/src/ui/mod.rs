@@ -4,9 +4,11 @@
 //! editor, dialog boxes, and specialized visualizations.
 //!
 //! Revision History
+//! - 2026-10-16T17:50:00Z @AI: Add task_picker for fuzzy task selection.
 //! - 2025-12-03T09:00:00Z @AI: Create UI module for config editor (Phase 3 of CONFIG-MODERN-20251203).
 
 pub mod config_editor;
+pub mod task_picker;
 
 // Re-export for convenience
 pub use config_editor::{ConfigEditorState, ConfigTreeNode, FieldStatus};
@@ -0,0 +1,198 @@
+//! Interactive fuzzy task picker for commands that take a task ID.
+//!
+//! Lets the user type part of a task title or short ID and pick from the
+//! best matches (ranked by services::fuzzy_matcher) instead of pasting UUIDs.
+//! Selection state is kept separate from the terminal loop so it can be
+//! tested without a terminal.
+//!
+//! Revision History
+//! - 2026-10-16T17:50:00Z @AI: Initial task picker for `rig do`.
+
+/// Selection state for the task picker.
+pub struct TaskPickerState {
+    /// Candidate tasks.
+    tasks: std::vec::Vec<task_manager::domain::task::Task>,
+    /// Current filter text.
+    pub query: String,
+    /// Index of the highlighted entry within `matches()`.
+    pub selected: usize,
+    /// Maximum number of matches shown.
+    limit: usize,
+}
+
+impl TaskPickerState {
+    /// Creates picker state over `tasks`, pre-filled with `query`.
+    pub fn new(tasks: std::vec::Vec<task_manager::domain::task::Task>, query: &str, limit: usize) -> Self {
+        TaskPickerState {
+            tasks,
+            query: std::string::String::from(query),
+            selected: 0,
+            limit,
+        }
+    }
+
+    /// Returns the top matches for the current query, best first.
+    pub fn matches(&self) -> std::vec::Vec<&task_manager::domain::task::Task> {
+        crate::services::fuzzy_matcher::rank_tasks(&self.query, &self.tasks, self.limit)
+    }
+
+    /// Appends a character to the query and resets the selection.
+    pub fn push(&mut self, c: char) {
+        self.query.push(c);
+        self.selected = 0;
+    }
+
+    /// Removes the last query character and resets the selection.
+    pub fn pop(&mut self) {
+        self.query.pop();
+        self.selected = 0;
+    }
+
+    /// Moves the highlight down, stopping at the last match.
+    pub fn next(&mut self) {
+        let count = self.matches().len();
+        if self.selected + 1 < count {
+            self.selected += 1;
+        }
+    }
+
+    /// Moves the highlight up, stopping at the first match.
+    pub fn previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Returns the highlighted task, if any match.
+    pub fn selected_task(&self) -> std::option::Option<task_manager::domain::task::Task> {
+        self.matches().get(self.selected).map(|task| (*task).clone())
+    }
+}
+
+/// Runs the interactive picker on the terminal.
+///
+/// # Arguments
+///
+/// * `tasks` - Candidate tasks
+/// * `query` - Initial filter text (e.g. an ambiguous ID prefix)
+///
+/// # Returns
+///
+/// The chosen task, or None if the user pressed Esc or Ctrl-C.
+///
+/// # Errors
+///
+/// Returns an error if stdin is not a terminal or terminal I/O fails.
+pub fn pick_task(
+    tasks: std::vec::Vec<task_manager::domain::task::Task>,
+    query: &str,
+) -> anyhow::Result<std::option::Option<task_manager::domain::task::Task>> {
+    if !std::io::IsTerminal::is_terminal(&std::io::stdin()) {
+        anyhow::bail!("Cannot open the task picker: stdin is not a terminal. Pass a task ID instead.");
+    }
+
+    let mut state = TaskPickerState::new(tasks, query, crate::services::fuzzy_matcher::DEFAULT_LIMIT);
+    let mut stdout = std::io::stdout();
+
+    crossterm::terminal::enable_raw_mode()?;
+    crossterm::execute!(stdout, crossterm::terminal::EnterAlternateScreen)?;
+
+    let result = run_loop(&mut state, &mut stdout);
+
+    crossterm::execute!(stdout, crossterm::terminal::LeaveAlternateScreen)?;
+    crossterm::terminal::disable_raw_mode()?;
+
+    result
+}
+
+/// Draws the picker and handles keys until a task is chosen or the picker is cancelled.
+fn run_loop(
+    state: &mut TaskPickerState,
+    stdout: &mut std::io::Stdout,
+) -> anyhow::Result<std::option::Option<task_manager::domain::task::Task>> {
+    loop {
+        render(state, stdout)?;
+
+        if let crossterm::event::Event::Key(key) = crossterm::event::read()? {
+            if key.kind != crossterm::event::KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                crossterm::event::KeyCode::Esc => return std::result::Result::Ok(std::option::Option::None),
+                crossterm::event::KeyCode::Char('c')
+                    if key.modifiers.contains(crossterm::event::KeyModifiers::CONTROL) =>
+                {
+                    return std::result::Result::Ok(std::option::Option::None);
+                }
+                crossterm::event::KeyCode::Enter => {
+                    if let std::option::Option::Some(task) = state.selected_task() {
+                        return std::result::Result::Ok(std::option::Option::Some(task));
+                    }
+                }
+                crossterm::event::KeyCode::Up => state.previous(),
+                crossterm::event::KeyCode::Down => state.next(),
+                crossterm::event::KeyCode::Backspace => state.pop(),
+                crossterm::event::KeyCode::Char(c) => state.push(c),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Draws the query line and the ranked matches.
+fn render(state: &TaskPickerState, stdout: &mut std::io::Stdout) -> anyhow::Result<()> {
+    crossterm::queue!(
+        stdout,
+        crossterm::terminal::Clear(crossterm::terminal::ClearType::All),
+        crossterm::cursor::MoveTo(0, 0)
+    )?;
+
+    // Raw mode needs explicit carriage returns
+    let mut output = std::format!(
+        "Select a task (type to filter, ↑/↓ to move, Enter to select, Esc to cancel)\r\n> {}\r\n\r\n",
+        state.query
+    );
+    let matches = state.matches();
+    if matches.is_empty() {
+        output.push_str("  No matching tasks.\r\n");
+    }
+    for (i, task) in matches.iter().enumerate() {
+        let marker = if i == state.selected { "▶" } else { " " };
+        let short_id: String = task.id.chars().take(crate::services::fuzzy_matcher::SHORT_ID_LEN).collect();
+        output.push_str(&std::format!("{} {}  {:<12} {}\r\n", marker, short_id, std::format!("{:?}", task.status), task.title));
+    }
+
+    std::io::Write::write_all(stdout, output.as_bytes())?;
+    std::io::Write::flush(stdout)?;
+    std::result::Result::Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    fn task(title: &str) -> task_manager::domain::task::Task {
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from(title),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        task_manager::domain::task::Task::from_action_item(&action, std::option::Option::None)
+    }
+
+    #[test]
+    fn test_typing_filters_and_resets_selection() {
+        // Test: Validates typing narrows the matches and moves the highlight back to the best match.
+        // Justification: Enter should pick the top-ranked task after every keystroke.
+        let mut state = super::TaskPickerState::new(
+            std::vec![task("Setup database"), task("Deploy staging"), task("Update user docs")],
+            "",
+            10,
+        );
+        state.next();
+        std::assert_eq!(state.selected, 1);
+
+        state.push('d');
+        state.push('e');
+        state.push('p');
+
+        std::assert_eq!(state.selected, 0);
+        std::assert_eq!(state.selected_task().unwrap().title, "Deploy staging");
+    }
+}
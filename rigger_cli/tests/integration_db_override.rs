@@ -0,0 +1,52 @@
+//! Integration test for the global `--db` / `RIGGER_DB` database override.
+//!
+//! Runs the compiled `rig` binary in a project whose `.rigger/tasks.db` does
+//! not exist and checks that tasks go to (and come from) the database the
+//! override names, and that an unrecognized scheme is rejected up front.
+//!
+//! Revision History
+//! - 2026-10-17T14:10:00Z @AI: Create --db / RIGGER_DB override tests.
+
+fn rig(workdir: &std::path::Path, args: &[&str], env_db: std::option::Option<&str>) -> std::process::Output {
+    let mut command = std::process::Command::new(env!("CARGO_BIN_EXE_rig"));
+    command.args(args).current_dir(workdir).env("NO_COLOR", "1").env_remove("RIGGER_DB");
+    if let std::option::Option::Some(url) = env_db {
+        command.env("RIGGER_DB", url);
+    }
+    command.output().unwrap()
+}
+
+#[test]
+fn test_db_override_reaches_the_task_adapter() {
+    // Test: Validates `rig --db` and RIGGER_DB write and read the named database, never .rigger/tasks.db.
+    // Justification: A one-off run against a scratch database must leave the project database untouched.
+    let workdir = std::env::temp_dir().join(std::format!("rigger_db_override_{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(workdir.join(".rigger")).unwrap();
+    let scratch_url = std::format!("sqlite:{}", workdir.join("scratch.db").display());
+
+    let added = rig(&workdir, &["--db", &scratch_url, "add", "Scratch-only task"], std::option::Option::None);
+    std::assert!(added.status.success(), "add failed: {}", String::from_utf8_lossy(&added.stderr));
+    std::assert!(workdir.join("scratch.db").exists());
+    std::assert!(!workdir.join(".rigger").join("tasks.db").exists());
+
+    let listed = rig(&workdir, &["list"], std::option::Option::Some(&scratch_url));
+    std::assert!(listed.status.success(), "list failed: {}", String::from_utf8_lossy(&listed.stderr));
+    std::assert!(String::from_utf8_lossy(&listed.stdout).contains("Scratch-only task"));
+
+    std::fs::remove_dir_all(&workdir).unwrap();
+}
+
+#[test]
+fn test_invalid_db_scheme_errors_before_the_command_runs() {
+    // Test: Validates an unrecognized --db scheme fails with a clear message and creates nothing.
+    // Justification: A typo in the URL must not surface later as an opaque adapter connection error.
+    let workdir = std::env::temp_dir().join(std::format!("rigger_db_invalid_{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(workdir.join(".rigger")).unwrap();
+
+    let output = rig(&workdir, &["--db", "mysql://localhost/rigger", "add", "Never stored"], std::option::Option::None);
+    std::assert!(!output.status.success());
+    std::assert!(String::from_utf8_lossy(&output.stderr).contains("expected a sqlite: or postgres: URL"));
+    std::assert!(!workdir.join(".rigger").join("tasks.db").exists());
+
+    std::fs::remove_dir_all(&workdir).unwrap();
+}
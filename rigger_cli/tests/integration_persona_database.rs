@@ -2,10 +2,11 @@
 //!
 //! Tests the Phase 3 SQLite adapter initialization:
 //! 1. Verifies personas, agent_tools, and persona_tools tables are created
-//! 2. Verifies 15 agent tools are seeded
+//! 2. Verifies 15 agent tools plus 3 chat agent tools are seeded
 //! 3. Verifies default persona is created with 6 safe tools
 //!
 //! Revision History
+//! - 2026-10-16T23:50:00Z @AI: Count the search_tasks, get_task_details, and search_artifacts chat agent tools.
 //! - 2025-11-26T07:45:00Z @AI: Create integration test for persona database schema initialization.
 
 #[tokio::test]
@@ -22,15 +23,15 @@ async fn test_persona_tables_initialization() {
     .await
     .unwrap();
 
-    // 2. Verify agent_tools table exists and has 15 tools
+    // 2. Verify agent_tools table exists and has 15 tools plus 3 chat agent tools
     let tool_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM agent_tools")
         .fetch_one(adapter.pool())
         .await
         .unwrap();
 
     std::assert_eq!(
-        tool_count, 15,
-        "Should have 15 agent tools seeded"
+        tool_count, 18,
+        "Should have 18 agent tools seeded"
     );
 
     // 3. Verify 6 tools are marked as default (Safe tools)
@@ -58,7 +59,7 @@ async fn test_persona_tables_initialization() {
             .fetch_one(adapter.pool())
             .await
             .unwrap();
-    std::assert_eq!(research_tools, 3, "Should have 3 Research tools");
+    std::assert_eq!(research_tools, 4, "Should have 4 Research tools (including search_artifacts)");
 
     let filesystem_tools: i64 =
         sqlx::query_scalar("SELECT COUNT(*) FROM agent_tools WHERE category = 'FileSystem'")
@@ -72,7 +73,7 @@ async fn test_persona_tables_initialization() {
             .fetch_one(adapter.pool())
             .await
             .unwrap();
-    std::assert_eq!(database_tools, 2, "Should have 2 Database tools");
+    std::assert_eq!(database_tools, 4, "Should have 4 Database tools (including search_tasks, get_task_details)");
 
     let network_tools: i64 =
         sqlx::query_scalar("SELECT COUNT(*) FROM agent_tools WHERE category = 'Network'")
@@ -87,7 +88,7 @@ async fn test_persona_tables_initialization() {
             .fetch_one(adapter.pool())
             .await
             .unwrap();
-    std::assert_eq!(safe_tools, 6, "Should have 6 Safe tools");
+    std::assert_eq!(safe_tools, 9, "Should have 9 Safe tools");
 
     let moderate_tools: i64 =
         sqlx::query_scalar("SELECT COUNT(*) FROM agent_tools WHERE risk_level = 'Moderate'")
@@ -188,7 +189,7 @@ async fn test_idempotent_initialization() {
         .await
         .unwrap();
 
-    std::assert_eq!(tool_count1, 15);
+    std::assert_eq!(tool_count1, 18);
     std::assert_eq!(persona_count1, 1);
 
     std::println!("✓ Idempotent initialization test passed");
@@ -10,6 +10,13 @@
 //! Run with: `cargo test --test integration_prd_workflow -- --ignored`
 //!
 //! Revision History
+//! - 2026-10-18T02:20:00Z @AI: Pass the default-project ID to parse::execute after --project was added.
+//! - 2026-10-17T16:10:00Z @AI: Pass yes = true to do_task::execute.
+//! - 2026-10-17T15:50:00Z @AI: Pass quiet=false to parse::execute after --quiet flag was added.
+//! - 2026-10-17T06:50:00Z @AI: Pass regenerate_tests = false to do_task::execute.
+//! - 2026-10-16T21:30:00Z @AI: Pass no_cache=false to parse::execute after --no-cache flag was added.
+//! - 2026-10-16T20:50:00Z @AI: Pass no --max-cost override to do_task::execute.
+//! - 2026-10-16T11:10:00Z @AI: Pass incremental=false to parse::execute after --incremental flag was added.
 //! - 2025-11-26T05:10:00Z @AI: Add test_project_creation_from_prd to verify Project entity is created and linked when PRD is parsed.
 //! - 2025-11-22T18:00:00Z @AI: Create integration test for Rigger Phase 0 Sprint 0.3.
 
@@ -61,7 +68,7 @@ async fn test_full_prd_to_tasks_workflow() {
     std::assert!(temp_dir.join(".rigger/config.json").exists());
 
     // 4. Parse PRD to generate tasks
-    let parse_result = rigger_cli::commands::parse::execute(prd_path.to_str().unwrap()).await;
+    let parse_result = rigger_cli::commands::parse::execute(prd_path.to_str().unwrap(), "default-project", false, false, false).await;
     std::assert!(
         parse_result.is_ok(),
         "Parse should succeed: {:?}",
@@ -111,7 +118,7 @@ async fn test_full_prd_to_tasks_workflow() {
     );
 
     // 7. Execute first task (basic completion without orchestration)
-    let do_result = rigger_cli::commands::do_task::execute(&first_task.id).await;
+    let do_result = rigger_cli::commands::do_task::execute(std::option::Option::Some(&first_task.id), std::option::Option::None, false, true).await;
     std::assert!(
         do_result.is_ok(),
         "Do command should succeed: {:?}",
@@ -258,7 +265,7 @@ async fn test_project_creation_from_prd() {
     );
 
     // 4. Parse PRD to generate tasks (this should create Project)
-    let parse_result = rigger_cli::commands::parse::execute(prd_path.to_str().unwrap()).await;
+    let parse_result = rigger_cli::commands::parse::execute(prd_path.to_str().unwrap(), "default-project", false, false, false).await;
     std::assert!(
         parse_result.is_ok(),
         "Parse should succeed: {:?}",
@@ -0,0 +1,100 @@
+//! Model capability lookup for task slot validation.
+//!
+//! Providers don't report what a model can do, so capabilities are derived
+//! from the provider type and the model name: embedding models are named as
+//! such ("nomic-embed-text", "text-embedding-3-small"), and vision models by
+//! family ("llava", "llama3.2-vision", "gpt-4o", "claude-3"). Providers whose
+//! model names follow no convention (custom and OpenAI-compatible servers)
+//! are assumed to support everything, so validation never rejects them.
+//!
+//! Revision History
+//! - 2026-10-17T01:50:00Z @AI: Initial Capability and model_capabilities lookup.
+
+/// Something a task slot needs its model to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Capability {
+    /// Text generation and chat completion
+    Chat,
+    /// Producing embedding vectors
+    Embedding,
+    /// Accepting images as input
+    Vision,
+}
+
+impl std::fmt::Display for Capability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Capability::Chat => write!(f, "chat"),
+            Capability::Embedding => write!(f, "embedding"),
+            Capability::Vision => write!(f, "vision"),
+        }
+    }
+}
+
+/// Capabilities advertised by a provider/model pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModelCapabilities {
+    /// Model can chat / generate text
+    pub chat: bool,
+    /// Model produces embeddings
+    pub embedding: bool,
+    /// Model accepts images
+    pub vision: bool,
+}
+
+impl ModelCapabilities {
+    /// Capabilities of a model that is assumed to support everything.
+    pub fn all() -> Self {
+        Self { chat: true, embedding: true, vision: true }
+    }
+
+    /// Returns true if `capability` is advertised.
+    pub fn supports(&self, capability: Capability) -> bool {
+        match capability {
+            Capability::Chat => self.chat,
+            Capability::Embedding => self.embedding,
+            Capability::Vision => self.vision,
+        }
+    }
+}
+
+/// Name fragments identifying embedding-only models.
+const EMBEDDING_MARKERS: &[&str] = &["embed", "minilm", "bge-", "e5-", "gte-"];
+
+/// Name fragments identifying vision-capable models.
+const VISION_MARKERS: &[&str] = &[
+    "llava", "vision", "moondream", "minicpm-v", "qwen2-vl", "qwen2.5vl", "qwen2.5-vl", "gemma3", "pixtral",
+    "gpt-4o", "gpt-4-turbo", "gpt-4.1", "gpt-5", "claude-3", "claude-sonnet-4", "claude-opus-4",
+];
+
+/// Derives the capabilities of `model` served by a provider of `provider_type`.
+///
+/// # Arguments
+///
+/// * `provider_type` - Type of the provider serving the model
+/// * `model` - Model name as configured in the task slot
+///
+/// # Examples
+///
+/// ```
+/// use rigger_core::config::capability::{model_capabilities, Capability};
+/// use rigger_core::config::ProviderType;
+///
+/// std::assert!(model_capabilities(&ProviderType::Ollama, "nomic-embed-text").supports(Capability::Embedding));
+/// std::assert!(!model_capabilities(&ProviderType::Ollama, "llama3.2").supports(Capability::Embedding));
+/// std::assert!(model_capabilities(&ProviderType::Ollama, "llava:latest").supports(Capability::Vision));
+/// ```
+pub fn model_capabilities(provider_type: &crate::config::ProviderType, model: &str) -> ModelCapabilities {
+    let name = model.to_lowercase();
+    let is_embedding = EMBEDDING_MARKERS.iter().any(|marker| name.contains(marker));
+    let is_vision = VISION_MARKERS.iter().any(|marker| name.contains(marker));
+
+    match provider_type {
+        crate::config::ProviderType::Custom(_) | crate::config::ProviderType::OpenAiCompatible => ModelCapabilities::all(),
+        // Anthropic has no embedding API
+        crate::config::ProviderType::Anthropic => ModelCapabilities { chat: true, embedding: false, vision: is_vision },
+        _ if is_embedding => ModelCapabilities { chat: false, embedding: true, vision: false },
+        _ => ModelCapabilities { chat: true, embedding: false, vision: is_vision },
+    }
+}
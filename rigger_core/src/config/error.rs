@@ -4,6 +4,10 @@
 //! missing API keys, and migration issues.
 //!
 //! Revision History
+//! - 2026-10-18T02:30:00Z @AI: Add OutOfRange for numeric settings outside their allowed range.
+//! - 2026-10-17T01:50:00Z @AI: Add CapabilityMismatch for task slots whose model lacks a required capability.
+//! - 2026-10-16T21:10:00Z @AI: Add OfflineViolation for non-local providers in offline mode.
+//! - 2026-10-16T18:30:00Z @AI: Add WriteError for failed config saves and backups.
 //! - 2025-12-03T07:55:00Z @AI: Create ConfigError for rigger_core (Phase 2.2 of CONFIG-MODERN-20251203).
 
 use super::ProviderType;
@@ -33,6 +37,30 @@ pub enum ConfigError {
         url: std::string::String,
     },
 
+    /// Provider endpoint is not local while offline mode is enabled
+    #[error("Provider {provider} is not allowed in offline mode: {url} is not localhost or a private IP")]
+    OfflineViolation {
+        provider: std::string::String,
+        url: std::string::String,
+    },
+
+    /// Task slot's model lacks a capability the slot requires
+    #[error("Task slot '{slot}' requires {capability} support, but {provider} model '{model}' does not advertise it")]
+    CapabilityMismatch {
+        slot: std::string::String,
+        provider: std::string::String,
+        model: std::string::String,
+        capability: super::capability::Capability,
+    },
+
+    /// Numeric setting outside its allowed range
+    #[error("{field} is {value}, but must be {range}")]
+    OutOfRange {
+        field: std::string::String,
+        value: std::string::String,
+        range: std::string::String,
+    },
+
     /// Config file not found
     #[error("Config file not found: {path}\nError: {error}")]
     FileNotFound {
@@ -52,4 +80,11 @@ pub enum ConfigError {
         from_version: std::string::String,
         message: std::string::String,
     },
+
+    /// Failed to write config file or its backup
+    #[error("Failed to write config file {path}: {error}")]
+    WriteError {
+        path: std::string::String,
+        error: std::string::String,
+    },
 }
@@ -0,0 +1,149 @@
+//! Deep merge of layered configuration.
+//!
+//! Layers (the base config, `config.local.json`, environment overrides) are
+//! merged as JSON values before deserialization, with three rules:
+//! - Objects merge key by key, recursively. This covers the provider map and
+//!   task slots, so an overlay can change one provider or one slot field.
+//! - `null` in the overlay (an unset `Option`) keeps the base value.
+//! - Any other overlay value (string, number, bool, array) replaces the base value.
+//!
+//! Revision History
+//! - 2026-10-17T14:50:00Z @AI: Set auto_pull in the test provider.
+//! - 2026-10-17T09:50:00Z @AI: Initial merge_values and RiggerConfig::merge.
+
+/// Merges `overlay` over `base` following the module's rules.
+///
+/// # Examples
+///
+/// ```
+/// use rigger_core::config::merge::merge_values;
+///
+/// let base = serde_json::json!({"main": {"provider": "ollama", "model": "llama3.2"}, "offline": true});
+/// let overlay = serde_json::json!({"main": {"model": "qwen2.5"}, "offline": null});
+///
+/// assert_eq!(
+///     merge_values(base, overlay),
+///     serde_json::json!({"main": {"provider": "ollama", "model": "qwen2.5"}, "offline": true}),
+/// );
+/// ```
+pub fn merge_values(base: serde_json::Value, overlay: serde_json::Value) -> serde_json::Value {
+    match (base, overlay) {
+        (serde_json::Value::Object(mut base), serde_json::Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                let merged = match base.remove(&key) {
+                    std::option::Option::Some(existing) => merge_values(existing, value),
+                    std::option::Option::None => value,
+                };
+                base.insert(key, merged);
+            }
+            serde_json::Value::Object(base)
+        }
+        (base, serde_json::Value::Null) => base,
+        (_, overlay) => overlay,
+    }
+}
+
+impl super::RiggerConfig {
+    /// Merges `overlay` over `base` following the module's rules.
+    ///
+    /// Every non-`Option` field of `overlay` wins, so this suits overlays that
+    /// are complete configs. For partial overlays read from a file, merge the
+    /// raw JSON with `merge_values` instead, so missing fields keep their base
+    /// values rather than taking defaults.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rigger_core::RiggerConfig;
+    ///
+    /// let base = RiggerConfig::default();
+    /// let mut overlay = RiggerConfig::default();
+    /// overlay.offline = true;
+    ///
+    /// let merged = RiggerConfig::merge(base, overlay);
+    /// assert!(merged.offline);
+    /// assert!(merged.providers.contains_key("ollama"));
+    /// ```
+    pub fn merge(base: Self, overlay: Self) -> Self {
+        let (std::result::Result::Ok(base_value), std::result::Result::Ok(overlay_value)) =
+            (serde_json::to_value(&base), serde_json::to_value(&overlay))
+        else {
+            return overlay;
+        };
+        // Both sides are serialized configs, so the merge has every field; fall back to the overlay if not
+        serde_json::from_value(merge_values(base_value, overlay_value)).unwrap_or(overlay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn provider(model: &str, api_key_env: std::option::Option<&str>) -> crate::config::ProviderConfig {
+        crate::config::ProviderConfig {
+            provider_type: crate::config::ProviderType::OpenAI,
+            base_url: String::from("https://api.openai.com/v1"),
+            api_key_env: api_key_env.map(String::from),
+            timeout_seconds: 60,
+            max_retries: 3,
+            default_model: String::from(model),
+            auto_pull: false,
+        }
+    }
+
+    #[test]
+    fn test_scalar_overlay_wins() {
+        // Test: Validates scalars and arrays in the overlay replace the base value, including across types.
+        // Justification: A layer must be able to change any single setting.
+        let merged = super::merge_values(
+            serde_json::json!({"offline": false, "pool_size": 5, "tags": ["a", "b"], "mode": {"nested": 1}}),
+            serde_json::json!({"offline": true, "pool_size": 8, "tags": ["c"], "mode": "flat"}),
+        );
+
+        std::assert_eq!(merged, serde_json::json!({"offline": true, "pool_size": 8, "tags": ["c"], "mode": "flat"}));
+    }
+
+    #[test]
+    fn test_none_in_overlay_does_not_clobber_base() {
+        // Test: Validates null values and unset Option fields in the overlay keep the base value.
+        // Justification: A layer that does not mention a setting must not erase it.
+        let merged = super::merge_values(
+            serde_json::json!({"api_key_env": "OPENAI_API_KEY", "max_cost_per_run": 5.0}),
+            serde_json::json!({"api_key_env": null}),
+        );
+        std::assert_eq!(merged, serde_json::json!({"api_key_env": "OPENAI_API_KEY", "max_cost_per_run": 5.0}));
+
+        let mut base = crate::RiggerConfig::default();
+        base.providers.insert(String::from("openai"), provider("gpt-4o", std::option::Option::Some("OPENAI_API_KEY")));
+        base.performance.max_cost_per_run = std::option::Option::Some(5.0);
+        let mut overlay = crate::RiggerConfig::default();
+        overlay.providers.insert(String::from("openai"), provider("gpt-4o-mini", std::option::Option::None));
+
+        let merged = crate::RiggerConfig::merge(base, overlay);
+
+        std::assert_eq!(merged.providers["openai"].default_model, "gpt-4o-mini");
+        std::assert_eq!(merged.providers["openai"].api_key_env.as_deref(), std::option::Option::Some("OPENAI_API_KEY"));
+        std::assert_eq!(merged.performance.max_cost_per_run, std::option::Option::Some(5.0));
+    }
+
+    #[test]
+    fn test_provider_and_task_slot_maps_merge_by_key() {
+        // Test: Validates providers and task slots from both layers survive, with overlay fields winning per key.
+        // Justification: An overlay adding one provider or retargeting one slot must not drop the others.
+        let mut base = crate::RiggerConfig::default();
+        base.providers.insert(String::from("openai"), provider("gpt-4o", std::option::Option::Some("OPENAI_API_KEY")));
+        let mut overlay = crate::RiggerConfig::default();
+        overlay.providers.clear();
+        overlay.providers.insert(String::from("groq"), provider("llama-3.1-70b", std::option::Option::None));
+        overlay.task_slots.research.provider = String::from("groq");
+        overlay.task_slots.research.model = String::from("llama-3.1-70b");
+
+        let merged = crate::RiggerConfig::merge(base.clone(), overlay);
+
+        let mut names: std::vec::Vec<&str> = merged.providers.keys().map(String::as_str).collect();
+        names.sort();
+        std::assert_eq!(names, ["groq", "ollama", "openai"]);
+        std::assert_eq!(merged.task_slots.research.provider, "groq");
+        std::assert_eq!(merged.task_slots.research.model, "llama-3.1-70b");
+        std::assert_eq!(merged.task_slots.main.model, base.task_slots.main.model);
+        std::assert_eq!(merged.task_slots.main.description, base.task_slots.main.description);
+    }
+}
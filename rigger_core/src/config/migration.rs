@@ -6,9 +6,15 @@
 //! - V2: Setup wizard format with task_tools
 //!
 //! Revision History
+//! - 2026-10-17T18:10:00Z @AI: Fill default search settings in migrated configs.
+//! - 2026-10-17T14:50:00Z @AI: Migrated providers have auto_pull off.
+//! - 2026-10-16T21:30:00Z @AI: Migrated configs start with default sampling settings.
+//! - 2026-10-16T21:10:00Z @AI: Migrated configs start with offline mode off.
+//! - 2026-10-16T20:30:00Z @AI: Migrated configs start with an empty pricing table.
+//! - 2026-10-16T19:30:00Z @AI: Migrated configs get default server settings.
 //! - 2025-12-03T08:15:00Z @AI: Create migration module for rigger_core (Phase 2.3 of CONFIG-MODERN-20251203).
 
-use super::{RiggerConfig, ProviderConfig, ProviderType, TaskSlot, TaskSlotConfig, DatabaseConfig, PerformanceConfig, TuiConfig};
+use super::{RiggerConfig, ProviderConfig, ProviderType, TaskSlot, TaskSlotConfig, DatabaseConfig, PerformanceConfig, TuiConfig, ServerConfig, SamplingConfig, SearchConfig};
 use super::error::ConfigError;
 
 /// Configuration version for migration detection.
@@ -108,6 +114,7 @@ impl RiggerConfig {
             timeout_seconds: timeout,
             max_retries: 3,
             default_model: raw.get("model")
+            auto_pull: false,
                 .and_then(|m| m.get("main"))
                 .and_then(|v| v.as_str())
                 .unwrap_or("llama3.2")
@@ -182,6 +189,11 @@ impl RiggerConfig {
             task_slots,
             performance: PerformanceConfig::default(),
             tui: TuiConfig::default(),
+            server: ServerConfig::default(),
+            pricing: std::collections::HashMap::new(),
+            offline: false,
+            sampling: SamplingConfig::default(),
+            search: SearchConfig::default(),
         })
     }
 
@@ -254,6 +266,7 @@ impl RiggerConfig {
                 timeout_seconds: timeout,
                 max_retries: 3,
                 default_model: task_tools.get("main")
+                auto_pull: false,
                     .and_then(|t| t.get("model"))
                     .and_then(|v| v.as_str())
                     .unwrap_or("llama3.2")
@@ -328,6 +341,11 @@ impl RiggerConfig {
             task_slots,
             performance: PerformanceConfig::default(),
             tui: TuiConfig::default(),
+            server: ServerConfig::default(),
+            pricing: std::collections::HashMap::new(),
+            offline: false,
+            sampling: SamplingConfig::default(),
+            search: SearchConfig::default(),
         })
     }
 }
@@ -3,18 +3,41 @@
 //! Provides unified configuration structure supporting multiple LLM providers,
 //! API key management, task slots, and automatic migration from legacy formats.
 //!
+//! A `config.local.json` next to `config.json` overrides it field by field,
+//! so a sub-project can change e.g. only its main model.
+//!
 //! Revision History
+//! - 2026-10-18T04:30:00Z @AI: Add orchestration.router_confidence_threshold and router_fallback_route; validate() checks both.
+//! - 2026-10-18T04:20:00Z @AI: Add orchestration.run_log_dir for recording `rig do` runs.
+//! - 2026-10-18T02:30:00Z @AI: Add orchestration.pass_threshold; validate() reports it outside 0.0-1.0.
+//! - 2026-10-17T18:10:00Z @AI: Add search.rerank and search.rerank_candidates for LLM re-ranking of artifact search.
+//! - 2026-10-17T14:50:00Z @AI: Default Ollama provider has auto_pull off.
+//! - 2026-10-17T09:50:00Z @AI: Merge config.local.json with merge::merge_values, so null values no longer erase base settings.
+//! - 2026-10-17T09:30:00Z @AI: Deep-merge a sibling config.local.json over the loaded config in load_with_migration; add load_base_with_migration.
+//! - 2026-10-17T08:30:00Z @AI: Add performance.metrics_max_bytes; metrics_file accepts {project}/{timestamp} placeholders.
+//! - 2026-10-17T01:50:00Z @AI: validate() reports CapabilityMismatch when a task slot's model lacks the capability the slot needs.
+//! - 2026-10-17T00:30:00Z @AI: Add tui.chat_history_turns for the persisted chat history window.
+//! - 2026-10-16T21:30:00Z @AI: Add `sampling` (temperature/seed) and performance.response_cache settings.
+//! - 2026-10-16T21:10:00Z @AI: Add `offline` mode; validate() reports providers whose base URL is not local.
+//! - 2026-10-16T20:50:00Z @AI: Add performance.max_tokens_per_run and max_cost_per_run run budgets.
+//! - 2026-10-16T20:30:00Z @AI: Add `pricing` section mapping model names to per-1K-token input/output costs.
+//! - 2026-10-16T19:30:00Z @AI: Add server.auth_token_env for bearer-token auth on `rig grpc` and `rig server`.
+//! - 2026-10-16T18:30:00Z @AI: Add save_to_file with a timestamped backup when overwriting a legacy-version config.
 //! - 2025-12-03T07:50:00Z @AI: Initial config module for rigger_core (Phase 2.2 of CONFIG-MODERN-20251203).
 
 pub mod provider;
 pub mod task_slots;
 pub mod error;
 pub mod migration;
+pub mod network;
+pub mod capability;
+pub mod merge;
 
 pub use provider::{ProviderConfig, ProviderType};
 pub use task_slots::{TaskSlotConfig, TaskSlot};
 pub use error::ConfigError;
 pub use migration::ConfigVersion;
+pub use capability::Capability;
 
 /// Main configuration structure for Rigger v3.0.
 ///
@@ -61,6 +84,30 @@ pub struct RiggerConfig {
     /// TUI-specific settings
     #[serde(default)]
     pub tui: TuiConfig,
+
+    /// gRPC and MCP server settings
+    #[serde(default)]
+    pub server: ServerConfig,
+
+    /// Per-model token prices used for run cost summaries (keyed by model name)
+    #[serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub pricing: std::collections::HashMap<std::string::String, ModelPricing>,
+
+    /// Forbid providers whose base URL is not localhost or a private IP (air-gapped use)
+    #[serde(default)]
+    pub offline: bool,
+
+    /// Temperature and seed for enhancement, decomposition, and PRD parsing calls
+    #[serde(default)]
+    pub sampling: SamplingConfig,
+
+    /// Artifact search settings
+    #[serde(default)]
+    pub search: SearchConfig,
+
+    /// Settings of the orchestration graph run by `rig do`
+    #[serde(default)]
+    pub orchestration: OrchestrationConfig,
 }
 
 fn default_version() -> std::string::String {
@@ -98,10 +145,14 @@ pub struct PerformanceConfig {
     #[serde(default = "default_true")]
     pub enable_metrics: bool,
 
-    /// Path to metrics output file
+    /// Path to metrics output file; `{project}` and `{timestamp}` are substituted per run
     #[serde(default = "default_metrics_file")]
     pub metrics_file: std::string::String,
 
+    /// Size in bytes past which the metrics file is rotated (unset: never)
+    #[serde(default, skip_serializing_if = "std::option::Option::is_none")]
+    pub metrics_max_bytes: std::option::Option<u64>,
+
     /// Cache embeddings to avoid regeneration
     #[serde(default = "default_true")]
     pub cache_embeddings: bool,
@@ -109,6 +160,18 @@ pub struct PerformanceConfig {
     /// Maximum concurrent tasks
     #[serde(default = "default_max_concurrent")]
     pub max_concurrent_tasks: usize,
+
+    /// Maximum input plus output tokens a single run may use
+    #[serde(default, skip_serializing_if = "std::option::Option::is_none")]
+    pub max_tokens_per_run: std::option::Option<usize>,
+
+    /// Maximum dollar cost of a single run, priced from `pricing`
+    #[serde(default, skip_serializing_if = "std::option::Option::is_none")]
+    pub max_cost_per_run: std::option::Option<f64>,
+
+    /// On-disk cache of deterministic LLM responses
+    #[serde(default)]
+    pub response_cache: ResponseCacheConfig,
 }
 
 fn default_metrics_file() -> std::string::String {
@@ -119,6 +182,126 @@ fn default_max_concurrent() -> usize {
     4
 }
 
+/// Sampling parameters sent with generation calls.
+///
+/// Unset values leave the provider's defaults in place. Responses are only
+/// cached when `temperature` is 0 or `seed` is set.
+#[derive(Debug, Clone, Copy, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct SamplingConfig {
+    /// Sampling temperature (0 = greedy decoding)
+    #[serde(default, skip_serializing_if = "std::option::Option::is_none")]
+    pub temperature: std::option::Option<f64>,
+
+    /// Fixed random seed for repeatable sampling
+    #[serde(default, skip_serializing_if = "std::option::Option::is_none")]
+    pub seed: std::option::Option<u64>,
+}
+
+/// Response cache configuration.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ResponseCacheConfig {
+    /// Serve repeated deterministic LLM calls from disk
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Directory holding cached responses
+    #[serde(default = "default_response_cache_dir")]
+    pub dir: std::string::String,
+
+    /// Maximum age of a cached response in seconds (0 = never expires)
+    #[serde(default = "default_response_cache_ttl")]
+    pub ttl_seconds: u64,
+}
+
+fn default_response_cache_dir() -> std::string::String {
+    std::string::String::from(".rigger/cache/responses")
+}
+
+fn default_response_cache_ttl() -> u64 {
+    7 * 24 * 60 * 60
+}
+
+/// Artifact search configuration.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct SearchConfig {
+    /// Re-rank vector hits with the chat model by default (costs one LLM call per search)
+    #[serde(default)]
+    pub rerank: bool,
+
+    /// Number of vector hits handed to the re-ranker
+    #[serde(default = "default_rerank_candidates")]
+    pub rerank_candidates: usize,
+}
+
+fn default_rerank_candidates() -> usize {
+    20
+}
+
+/// Orchestration graph configuration.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct OrchestrationConfig {
+    /// Minimum comprehension test grade (0.0-1.0) for a task to pass without re-enhancement
+    #[serde(default = "default_pass_threshold")]
+    pub pass_threshold: f64,
+
+    /// Directory each `rig do` run is recorded to for `rig replay` (unset: TASK_ORCHESTRATOR_RUN_LOG_DIR, else no recording)
+    #[serde(default, skip_serializing_if = "std::option::Option::is_none")]
+    pub run_log_dir: std::option::Option<std::string::String>,
+
+    /// Minimum router confidence (0.0-1.0) to commit to enhance or decompose (0.0 never falls back)
+    #[serde(default)]
+    pub router_confidence_threshold: f64,
+
+    /// Route taken below the confidence threshold; one of `ROUTER_ROUTES`
+    #[serde(default = "default_router_fallback_route")]
+    pub router_fallback_route: std::string::String,
+}
+
+/// Routes the orchestration graph can take after the semantic router.
+///
+/// "clarify" stops the run before any provider call so the task can be
+/// given more detail instead of being routed on a guess.
+pub const ROUTER_ROUTES: &[&str] = &["enhance", "decompose", "clarify"];
+
+fn default_pass_threshold() -> f64 {
+    0.7
+}
+
+fn default_router_fallback_route() -> std::string::String {
+    std::string::String::from("enhance")
+}
+
+impl OrchestrationConfig {
+    /// Checks that `pass_threshold` and `router_confidence_threshold` are
+    /// between 0.0 and 1.0 and that `router_fallback_route` is one of `ROUTER_ROUTES`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::OutOfRange` for the first setting that is not.
+    pub fn validate(&self) -> std::result::Result<(), ConfigError> {
+        for (field, value) in [
+            ("orchestration.pass_threshold", self.pass_threshold),
+            ("orchestration.router_confidence_threshold", self.router_confidence_threshold),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                return std::result::Result::Err(ConfigError::OutOfRange {
+                    field: std::string::String::from(field),
+                    value: value.to_string(),
+                    range: std::string::String::from("0.0 to 1.0"),
+                });
+            }
+        }
+        if !ROUTER_ROUTES.contains(&self.router_fallback_route.as_str()) {
+            return std::result::Result::Err(ConfigError::OutOfRange {
+                field: std::string::String::from("orchestration.router_fallback_route"),
+                value: self.router_fallback_route.clone(),
+                range: std::format!("one of {}", ROUTER_ROUTES.join(", ")),
+            });
+        }
+        std::result::Result::Ok(())
+    }
+}
+
 /// TUI-specific configuration.
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
 pub struct TuiConfig {
@@ -137,6 +320,29 @@ pub struct TuiConfig {
     /// Show notification panel
     #[serde(default = "default_true")]
     pub show_notifications: bool,
+
+    /// Chat turns kept per project before older ones are folded into a summary (0 keeps all)
+    #[serde(default = "default_chat_history_turns")]
+    pub chat_history_turns: usize,
+}
+
+/// Server settings shared by `rig grpc` and `rig server` (MCP).
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
+pub struct ServerConfig {
+    /// Environment variable holding the bearer token clients must present.
+    /// If None, the servers accept unauthenticated requests.
+    #[serde(default, skip_serializing_if = "std::option::Option::is_none")]
+    pub auth_token_env: std::option::Option<std::string::String>,
+}
+
+/// Token prices for one model, in dollars per 1,000 tokens.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct ModelPricing {
+    /// Cost per 1,000 input (prompt) tokens
+    pub input_per_1k: f64,
+
+    /// Cost per 1,000 output (generated) tokens
+    pub output_per_1k: f64,
 }
 
 fn default_theme() -> std::string::String {
@@ -151,6 +357,10 @@ fn default_refresh_interval() -> u64 {
     30000
 }
 
+fn default_chat_history_turns() -> usize {
+    40
+}
+
 fn default_true() -> bool {
     true
 }
@@ -170,8 +380,42 @@ impl Default for PerformanceConfig {
         Self {
             enable_metrics: true,
             metrics_file: default_metrics_file(),
+            metrics_max_bytes: std::option::Option::None,
             cache_embeddings: true,
             max_concurrent_tasks: default_max_concurrent(),
+            max_tokens_per_run: std::option::Option::None,
+            max_cost_per_run: std::option::Option::None,
+            response_cache: ResponseCacheConfig::default(),
+        }
+    }
+}
+
+impl Default for ResponseCacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            dir: default_response_cache_dir(),
+            ttl_seconds: default_response_cache_ttl(),
+        }
+    }
+}
+
+impl Default for SearchConfig {
+    fn default() -> Self {
+        Self {
+            rerank: false,
+            rerank_candidates: default_rerank_candidates(),
+        }
+    }
+}
+
+impl Default for OrchestrationConfig {
+    fn default() -> Self {
+        Self {
+            pass_threshold: default_pass_threshold(),
+            run_log_dir: std::option::Option::None,
+            router_confidence_threshold: 0.0,
+            router_fallback_route: default_router_fallback_route(),
         }
     }
 }
@@ -183,6 +427,7 @@ impl Default for TuiConfig {
             layout: default_layout(),
             auto_refresh_interval_ms: default_refresh_interval(),
             show_notifications: true,
+            chat_history_turns: default_chat_history_turns(),
         }
     }
 }
@@ -199,6 +444,7 @@ impl Default for RiggerConfig {
             timeout_seconds: 120,
             max_retries: 2,
             default_model: std::string::String::from("llama3.2"),
+            auto_pull: false,
         });
 
         Self {
@@ -208,12 +454,25 @@ impl Default for RiggerConfig {
             task_slots: TaskSlotConfig::default(),
             performance: PerformanceConfig::default(),
             tui: TuiConfig::default(),
+            server: ServerConfig::default(),
+            pricing: std::collections::HashMap::new(),
+            offline: false,
+            sampling: SamplingConfig::default(),
+            search: SearchConfig::default(),
+            orchestration: OrchestrationConfig::default(),
         }
     }
 }
 
 impl RiggerConfig {
-    /// Load configuration with automatic migration from legacy formats.
+    /// Load configuration with automatic migration, then apply the local override.
+    ///
+    /// If the override file next to `path` exists (`config.local.json` for
+    /// `config.json`, see `local_override_path`), it is deep-merged over the
+    /// loaded config with `merge::merge_values`: objects, including the
+    /// provider map and task slots, merge key by key, `null` keeps the base
+    /// value, and any other value in the override replaces it. Fields the
+    /// override leaves out keep their base values.
     ///
     /// # Arguments
     ///
@@ -222,7 +481,63 @@ impl RiggerConfig {
     /// # Returns
     ///
     /// Loaded configuration or error.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::ParseError` if the override is not valid JSON or
+    /// the merged config does not deserialize.
     pub fn load_with_migration(path: &str) -> std::result::Result<Self, ConfigError> {
+        let config = Self::load_base_with_migration(path)?;
+
+        let local_path = Self::local_override_path(path);
+        if !local_path.exists() {
+            return std::result::Result::Ok(config);
+        }
+        let content = std::fs::read_to_string(&local_path).map_err(|e| ConfigError::FileNotFound {
+            path: local_path.display().to_string(),
+            error: e.to_string(),
+        })?;
+        let parse_error = |e: serde_json::Error| ConfigError::ParseError {
+            message: std::format!("{}: {}", local_path.display(), e),
+        };
+        let overlay: serde_json::Value = serde_json::from_str(&content).map_err(parse_error)?;
+        let base = serde_json::to_value(&config).map_err(parse_error)?;
+        serde_json::from_value(merge::merge_values(base, overlay)).map_err(parse_error)
+    }
+
+    /// Path of the local override for the config at `path`: `<stem>.local.<ext>` in the same directory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rigger_core::RiggerConfig;
+    ///
+    /// let local = RiggerConfig::local_override_path(".rigger/config.json");
+    /// assert_eq!(local, std::path::PathBuf::from(".rigger/config.local.json"));
+    /// ```
+    pub fn local_override_path(path: &str) -> std::path::PathBuf {
+        let path = std::path::Path::new(path);
+        let stem = path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+        let name = match path.extension() {
+            std::option::Option::Some(ext) => std::format!("{}.local.{}", stem, ext.to_string_lossy()),
+            std::option::Option::None => std::format!("{}.local", stem),
+        };
+        path.with_file_name(name)
+    }
+
+    /// Load configuration with automatic migration from legacy formats, ignoring any local override.
+    ///
+    /// Use this when the config will be saved back to `path`, so the
+    /// override's values are not written into the base file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to config.json file
+    ///
+    /// # Returns
+    ///
+    /// Loaded configuration or error.
+    pub fn load_base_with_migration(path: &str) -> std::result::Result<Self, ConfigError> {
         let path_buf = std::path::Path::new(path);
 
         // If file doesn't exist, return default config
@@ -274,8 +589,13 @@ impl RiggerConfig {
     ///
     /// Checks that:
     /// - All task slots reference existing providers
+    /// - Each enabled slot's model advertises the capability the slot needs
+    ///   (chat, embedding for `embedding`, vision for `vision`)
     /// - Base URLs are valid
     /// - API keys are available for enabled providers
+    /// - In offline mode, every provider's base URL is localhost or a private IP
+    /// - `orchestration.pass_threshold` and `router_confidence_threshold` are
+    ///   between 0.0 and 1.0, and `router_fallback_route` is a known route
     ///
     /// # Returns
     ///
@@ -283,23 +603,36 @@ impl RiggerConfig {
     pub fn validate(&self) -> std::result::Result<(), std::vec::Vec<ConfigError>> {
         let mut errors = std::vec::Vec::new();
 
-        // Validate all task slots reference existing providers
+        // Validate all task slots reference existing providers whose models fit the slot
         let provider_names: std::vec::Vec<std::string::String> = self.providers.keys().cloned().collect();
 
-        for (slot_name, slot) in [
-            (std::string::String::from("main"), &self.task_slots.main),
-            (std::string::String::from("research"), &self.task_slots.research),
-            (std::string::String::from("fallback"), &self.task_slots.fallback),
-            (std::string::String::from("embedding"), &self.task_slots.embedding),
-            (std::string::String::from("vision"), &self.task_slots.vision),
-            (std::string::String::from("chat_agent"), &self.task_slots.chat_agent),
+        for (slot_name, slot, required) in [
+            (std::string::String::from("main"), &self.task_slots.main, Capability::Chat),
+            (std::string::String::from("research"), &self.task_slots.research, Capability::Chat),
+            (std::string::String::from("fallback"), &self.task_slots.fallback, Capability::Chat),
+            (std::string::String::from("embedding"), &self.task_slots.embedding, Capability::Embedding),
+            (std::string::String::from("vision"), &self.task_slots.vision, Capability::Vision),
+            (std::string::String::from("chat_agent"), &self.task_slots.chat_agent, Capability::Chat),
         ] {
-            if slot.enabled && !self.providers.contains_key(&slot.provider) {
-                errors.push(ConfigError::UnknownProvider {
+            if !slot.enabled {
+                continue;
+            }
+            match self.providers.get(&slot.provider) {
+                std::option::Option::None => errors.push(ConfigError::UnknownProvider {
                     slot: slot_name,
                     provider: slot.provider.clone(),
                     available: provider_names.clone(),
-                });
+                }),
+                // Catch e.g. a chat-only model in the embedding slot before it fails at runtime
+                std::option::Option::Some(provider) if !provider.model_capabilities(&slot.model).supports(required) => {
+                    errors.push(ConfigError::CapabilityMismatch {
+                        slot: slot_name,
+                        provider: slot.provider.clone(),
+                        model: slot.model.clone(),
+                        capability: required,
+                    });
+                }
+                std::option::Option::Some(_) => {}
             }
         }
 
@@ -314,10 +647,277 @@ impl RiggerConfig {
             }
         }
 
+        // In offline mode every provider must be reachable without leaving the network
+        if self.offline {
+            for (name, provider) in &self.providers {
+                if !network::is_local_url(&provider.base_url) {
+                    errors.push(ConfigError::OfflineViolation {
+                        provider: name.clone(),
+                        url: provider.base_url.clone(),
+                    });
+                }
+            }
+        }
+
+        if let std::result::Result::Err(error) = self.orchestration.validate() {
+            errors.push(error);
+        }
+
         if errors.is_empty() {
             std::result::Result::Ok(())
         } else {
             std::result::Result::Err(errors)
         }
     }
+
+    /// Save configuration as pretty-printed v3.0 JSON.
+    ///
+    /// If `path` already holds a config of a different detected version (or
+    /// one that is not valid JSON), it is first copied to
+    /// `<path>.bak.<unix-timestamp>` so the original can still be diffed or
+    /// restored after the upgrade.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to config.json file
+    ///
+    /// # Returns
+    ///
+    /// The backup path if a backup was written.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::WriteError` if the backup or the config cannot be written.
+    pub fn save_to_file(&self, path: &str) -> std::result::Result<std::option::Option<std::path::PathBuf>, ConfigError> {
+        let path_buf = std::path::Path::new(path);
+        let write_error = |e: std::io::Error| ConfigError::WriteError {
+            path: path.to_string(),
+            error: e.to_string(),
+        };
+
+        let backup_path = match std::fs::read_to_string(path_buf) {
+            std::result::Result::Ok(existing) => {
+                let existing_version = serde_json::from_str::<serde_json::Value>(&existing)
+                    .map(|raw| Self::detect_version(&raw))
+                    .unwrap_or(ConfigVersion::Unknown);
+                if existing_version == ConfigVersion::V3 {
+                    std::option::Option::None
+                } else {
+                    let timestamp = std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .map(|elapsed| elapsed.as_secs())
+                        .unwrap_or(0);
+                    let backup = std::path::PathBuf::from(std::format!("{}.bak.{}", path, timestamp));
+                    std::fs::write(&backup, existing).map_err(write_error)?;
+                    std::option::Option::Some(backup)
+                }
+            }
+            std::result::Result::Err(_) => std::option::Option::None,
+        };
+
+        if let std::option::Option::Some(parent) = path_buf.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(write_error)?;
+        }
+
+        let json = serde_json::to_string_pretty(self).map_err(|e| ConfigError::WriteError {
+            path: path.to_string(),
+            error: e.to_string(),
+        })?;
+        std::fs::write(path_buf, json).map_err(write_error)?;
+
+        std::result::Result::Ok(backup_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn temp_config_path(name: &str) -> std::path::PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(std::format!("rigger_core_{}_{}_{}", name, std::process::id(), nanos));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join("config.json")
+    }
+
+    #[test]
+    fn test_local_override_changes_only_overridden_fields() {
+        // Test: Validates config.local.json replaces just the fields it names, including inside task slots and the provider map.
+        // Justification: Sub-projects must be able to switch the main model without copying the rest of the config.
+        let path = temp_config_path("local_override");
+        let base = r#"{
+            "version": "3.0",
+            "providers": {
+                "ollama": {"type": "Ollama", "base_url": "http://localhost:11434", "default_model": "llama3.2", "timeout_seconds": 120},
+                "openai": {"type": "OpenAI", "base_url": "https://api.openai.com/v1", "api_key_env": "OPENAI_API_KEY", "default_model": "gpt-4o"}
+            },
+            "task_slots": {
+                "main": {"provider": "ollama", "model": "llama3.2", "description": "Primary"},
+                "research": {"provider": "openai", "model": "gpt-4o", "description": "Research"}
+            },
+            "performance": {"max_concurrent_tasks": 8}
+        }"#;
+        std::fs::write(&path, base).unwrap();
+        let local = r#"{
+            "task_slots": {"main": {"model": "qwen2.5-coder"}},
+            "providers": {"ollama": {"timeout_seconds": 300}}
+        }"#;
+        std::fs::write(path.with_file_name("config.local.json"), local).unwrap();
+
+        let path = path.to_str().unwrap();
+        let merged = super::RiggerConfig::load_with_migration(path).unwrap();
+        let base = super::RiggerConfig::load_base_with_migration(path).unwrap();
+
+        std::assert_eq!(merged.task_slots.main.model, "qwen2.5-coder");
+        std::assert_eq!(merged.providers["ollama"].timeout_seconds, 300);
+        std::assert_eq!(base.task_slots.main.model, "llama3.2");
+        std::assert_eq!(base.providers["ollama"].timeout_seconds, 120);
+
+        // Everything the override does not name is the base config, unchanged
+        let mut expected = serde_json::to_value(&base).unwrap();
+        expected["task_slots"]["main"]["model"] = serde_json::json!("qwen2.5-coder");
+        expected["providers"]["ollama"]["timeout_seconds"] = serde_json::json!(300);
+        std::assert_eq!(serde_json::to_value(&merged).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_validate_rejects_pass_threshold_outside_unit_range() {
+        // Test: Validates orchestration.pass_threshold must be a grade between 0.0 and 1.0, and defaults to 0.7.
+        // Justification: A threshold above 1.0 fails every comprehension test and loops the graph until its step limit.
+        let mut config = super::RiggerConfig::default();
+        std::assert_eq!(config.orchestration.pass_threshold, 0.7);
+        std::assert!(config.orchestration.validate().is_ok());
+
+        config.orchestration.pass_threshold = 1.5;
+        let errors = config.validate().unwrap_err();
+
+        std::assert!(errors.iter().any(|e| std::matches!(
+            e,
+            super::ConfigError::OutOfRange { field, .. } if field == "orchestration.pass_threshold"
+        )));
+        config.orchestration.pass_threshold = -0.1;
+        std::assert!(config.orchestration.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_checks_router_settings() {
+        // Test: Validates the router threshold and fallback route default to 0.0 / "enhance", accept "clarify", and reject unknown values.
+        // Justification: A misspelled route would otherwise only fail once `rig do` builds the router.
+        let mut config: super::RiggerConfig =
+            serde_json::from_str(r#"{"version": "3.0", "orchestration": {"router_fallback_route": "clarify", "router_confidence_threshold": 0.25}}"#)
+                .unwrap();
+        std::assert!(config.orchestration.validate().is_ok());
+        std::assert_eq!(super::OrchestrationConfig::default().router_fallback_route, "enhance");
+        std::assert_eq!(super::OrchestrationConfig::default().router_confidence_threshold, 0.0);
+
+        config.orchestration.router_fallback_route = std::string::String::from("review");
+        std::assert!(std::matches!(
+            config.orchestration.validate(),
+            std::result::Result::Err(super::ConfigError::OutOfRange { field, .. }) if field == "orchestration.router_fallback_route"
+        ));
+        config.orchestration.router_fallback_route = std::string::String::from("enhance");
+        config.orchestration.router_confidence_threshold = 1.5;
+        std::assert!(std::matches!(
+            config.orchestration.validate(),
+            std::result::Result::Err(super::ConfigError::OutOfRange { field, .. }) if field == "orchestration.router_confidence_threshold"
+        ));
+    }
+
+    #[test]
+    fn test_save_backs_up_legacy_config_on_upgrade() {
+        // Test: Validates saving over a v0 config first writes config.json.bak.<ts> with the original content.
+        // Justification: Without the backup the pre-migration config cannot be diffed or restored.
+        let path = temp_config_path("upgrade");
+        let legacy = r#"{"provider": "ollama", "model": {"main": "llama3.2"}}"#;
+        std::fs::write(&path, legacy).unwrap();
+
+        let config = super::RiggerConfig::load_with_migration(path.to_str().unwrap()).unwrap();
+        let backup = config.save_to_file(path.to_str().unwrap()).unwrap().unwrap();
+
+        std::assert!(backup.file_name().unwrap().to_str().unwrap().starts_with("config.json.bak."));
+        std::assert_eq!(std::fs::read_to_string(&backup).unwrap(), legacy);
+        let saved: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        std::assert_eq!(super::RiggerConfig::detect_version(&saved), super::ConfigVersion::V3);
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_save_over_current_version_writes_no_backup() {
+        // Test: Validates re-saving a v3 config does not create backups.
+        // Justification: Routine saves from the TUI editor should not litter the config directory.
+        let path = temp_config_path("resave");
+        let config = super::RiggerConfig::default();
+
+        std::assert!(config.save_to_file(path.to_str().unwrap()).unwrap().is_none());
+        std::assert!(config.save_to_file(path.to_str().unwrap()).unwrap().is_none());
+        std::assert_eq!(std::fs::read_dir(path.parent().unwrap()).unwrap().count(), 1);
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_offline_mode_rejects_cloud_provider() {
+        // Test: Validates offline validation flags a cloud provider and accepts Ollama on localhost.
+        // Justification: Air-gapped installs rely on validate() to catch a misconfigured provider before any run.
+        let mut config = super::RiggerConfig {
+            offline: true,
+            ..std::default::Default::default()
+        };
+        std::assert!(config.validate().is_ok(), "default Ollama on localhost is allowed offline");
+
+        let mut openai = config.providers.get("ollama").unwrap().clone();
+        openai.provider_type = super::ProviderType::OpenAI;
+        openai.base_url = std::string::String::from("https://api.openai.com/v1");
+        config.providers.insert(std::string::String::from("openai"), openai);
+
+        let errors = config.validate().unwrap_err();
+        std::assert_eq!(errors.len(), 1);
+        std::assert!(std::matches!(
+            &errors[0],
+            super::ConfigError::OfflineViolation { provider, .. } if provider == "openai"
+        ));
+
+        config.offline = false;
+        std::assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_embedding_slot_on_chat_model_is_rejected() {
+        // Test: Validates an embedding slot pointing at a chat-only Ollama model yields CapabilityMismatch.
+        // Justification: This misconfiguration otherwise surfaces as cryptic errors from the embedding API.
+        let mut config = super::RiggerConfig::default();
+        std::assert!(config.validate().is_ok(), "default nomic-embed-text supports embeddings");
+
+        config.task_slots.embedding.model = std::string::String::from("llama3.2");
+
+        let errors = config.validate().unwrap_err();
+        std::assert_eq!(errors.len(), 1);
+        std::assert!(std::matches!(
+            &errors[0],
+            super::ConfigError::CapabilityMismatch { slot, model, capability: super::Capability::Embedding, .. }
+                if slot == "embedding" && model == "llama3.2"
+        ));
+    }
+
+    #[test]
+    fn test_vision_slot_on_text_model_is_rejected() {
+        // Test: Validates an enabled vision slot on a text-only model is flagged, a vision model passes, and a disabled slot is skipped.
+        // Justification: Image analysis silently fails on text models; disabled slots must not block startup.
+        let mut config = super::RiggerConfig::default();
+        config.task_slots.vision.model = std::string::String::from("llama3.2");
+        std::assert!(config.validate().is_ok(), "disabled vision slot is not checked");
+
+        config.task_slots.vision.enabled = true;
+        let errors = config.validate().unwrap_err();
+        std::assert!(std::matches!(
+            &errors[..],
+            [super::ConfigError::CapabilityMismatch { slot, capability: super::Capability::Vision, .. }] if slot == "vision"
+        ));
+        std::assert!(errors[0].to_string().contains("requires vision support"));
+
+        config.task_slots.vision.model = std::string::String::from("llama3.2-vision");
+        std::assert!(config.validate().is_ok());
+    }
 }
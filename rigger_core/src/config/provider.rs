@@ -4,6 +4,9 @@
 //! configuration including API keys, base URLs, timeouts, and retry policies.
 //!
 //! Revision History
+//! - 2026-10-17T14:50:00Z @AI: Add auto_pull for downloading missing Ollama models.
+//! - 2026-10-17T01:50:00Z @AI: Add model_capabilities lookup.
+//! - 2026-10-17T01:30:00Z @AI: Add ProviderType::OpenAiCompatible for local OpenAI-API servers; its API key is optional.
 //! - 2025-12-03T07:55:00Z @AI: Create ProviderConfig for rigger_core (Phase 2.2 of CONFIG-MODERN-20251203).
 
 /// Configuration for a single LLM provider.
@@ -24,6 +27,7 @@
 ///     timeout_seconds: 120,
 ///     max_retries: 2,
 ///     default_model: "llama3.2".to_string(),
+///     auto_pull: false,
 /// };
 ///
 /// // No API key needed for Ollama
@@ -54,6 +58,11 @@ pub struct ProviderConfig {
 
     /// Default model to use if not specified in task slot
     pub default_model: std::string::String,
+
+    /// Ollama only: when a request reports the model missing, pull it via
+    /// `/api/pull` and retry once. Off by default to avoid surprise downloads.
+    #[serde(default)]
+    pub auto_pull: bool,
 }
 
 fn default_timeout() -> u64 {
@@ -81,6 +90,10 @@ pub enum ProviderType {
     Groq,
     /// Cohere (embeddings and generation)
     Cohere,
+    /// Any server speaking the OpenAI API at `base_url` (vLLM, LM Studio,
+    /// llamafile). The API key is optional: if `api_key_env` is unset or
+    /// names an unset variable, requests are sent without a key.
+    OpenAiCompatible,
     /// Custom provider (user-defined)
     #[serde(untagged)]
     Custom(std::string::String),
@@ -92,13 +105,17 @@ impl ProviderConfig {
     /// # Returns
     ///
     /// - `Ok(Some(key))` if key is available
-    /// - `Ok(None)` if provider doesn't need a key
+    /// - `Ok(None)` if provider doesn't need a key, or the key is optional
+    ///   (`OpenAiCompatible`) and not set
     /// - `Err` if key is required but not set
     pub fn get_api_key(&self) -> std::result::Result<std::option::Option<std::string::String>, crate::config::error::ConfigError> {
         match &self.api_key_env {
             std::option::Option::Some(env_var) => {
                 match std::env::var(env_var) {
                     std::result::Result::Ok(key) => std::result::Result::Ok(std::option::Option::Some(key)),
+                    std::result::Result::Err(_) if self.provider_type == ProviderType::OpenAiCompatible => {
+                        std::result::Result::Ok(std::option::Option::None)
+                    }
                     std::result::Result::Err(_) => std::result::Result::Err(crate::config::error::ConfigError::MissingApiKey {
                         provider: self.provider_type.clone(),
                         env_var: env_var.clone(),
@@ -109,6 +126,13 @@ impl ProviderConfig {
         }
     }
 
+    /// Capabilities of `model` when served by this provider.
+    ///
+    /// See `capability::model_capabilities` for how they are derived.
+    pub fn model_capabilities(&self, model: &str) -> crate::config::capability::ModelCapabilities {
+        crate::config::capability::model_capabilities(&self.provider_type, model)
+    }
+
     /// Check if API key is available without retrieving it.
     ///
     /// # Returns
@@ -116,6 +140,7 @@ impl ProviderConfig {
     /// `true` if key is set or not required, `false` if required but missing.
     pub fn has_api_key(&self) -> bool {
         match &self.api_key_env {
+            std::option::Option::Some(_) if self.provider_type == ProviderType::OpenAiCompatible => true,
             std::option::Option::Some(env_var) => std::env::var(env_var).is_ok(),
             std::option::Option::None => true, // No key required
         }
@@ -152,6 +177,7 @@ impl std::fmt::Display for ProviderType {
             ProviderType::Mistral => write!(f, "Mistral"),
             ProviderType::Groq => write!(f, "Groq"),
             ProviderType::Cohere => write!(f, "Cohere"),
+            ProviderType::OpenAiCompatible => write!(f, "OpenAI-compatible"),
             ProviderType::Custom(name) => write!(f, "{}", name),
         }
     }
@@ -181,6 +207,7 @@ mod tests {
             timeout_seconds: 60,
             max_retries: 3,
             default_model: std::string::String::from("gpt-4o-mini"),
+            auto_pull: false,
         };
 
         let masked = provider.get_masked_api_key();
@@ -198,8 +225,49 @@ mod tests {
             timeout_seconds: 120,
             max_retries: 2,
             default_model: std::string::String::from("llama3.2"),
+            auto_pull: false,
         };
 
         std::assert!(provider.has_api_key());
     }
+
+    #[test]
+    fn test_auto_pull_defaults_off() {
+        // Test: Validates auto_pull is false when absent and read when set.
+        // Justification: Existing configs must not start multi-gigabyte model downloads after an upgrade.
+        let json = serde_json::json!({
+            "type": "Ollama",
+            "base_url": "http://localhost:11434",
+            "default_model": "llama3.2"
+        });
+        let provider: ProviderConfig = serde_json::from_value(json.clone()).unwrap();
+        std::assert!(!provider.auto_pull);
+
+        let mut enabled = json;
+        enabled["auto_pull"] = serde_json::json!(true);
+        let provider: ProviderConfig = serde_json::from_value(enabled).unwrap();
+        std::assert!(provider.auto_pull);
+    }
+
+    #[test]
+    fn test_openai_compatible_key_is_optional() {
+        // Test: Validates an OpenAI-compatible provider deserializes with its base URL and treats an unset key variable as no key.
+        // Justification: Local servers like vLLM and LM Studio usually ignore the key, so a missing one must not be an error.
+        let provider: ProviderConfig = serde_json::from_value(serde_json::json!({
+            "type": "OpenAiCompatible",
+            "base_url": "http://localhost:1234/v1",
+            "api_key_env": "RIGGER_TEST_UNSET_COMPAT_KEY",
+            "default_model": "qwen2.5-7b-instruct"
+        }))
+        .unwrap();
+
+        std::assert_eq!(provider.provider_type, ProviderType::OpenAiCompatible);
+        std::assert_eq!(provider.base_url, "http://localhost:1234/v1");
+        std::assert_eq!(provider.get_api_key().unwrap(), std::option::Option::None);
+        std::assert!(provider.has_api_key());
+
+        let mut openai = provider.clone();
+        openai.provider_type = ProviderType::OpenAI;
+        std::assert!(openai.get_api_key().is_err());
+    }
 }
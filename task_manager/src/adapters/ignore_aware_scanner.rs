@@ -6,6 +6,8 @@
 //! fingerprints for incremental scanning.
 //!
 //! Revision History
+//! - 2026-10-18T00:10:00Z @AI: List images when ScanConfig::include_images is set, in addition to allowlisted extensions.
+//! - 2026-10-16T12:50:00Z @AI: List explicitly included image files with empty content for OCR consumers.
 //! - 2025-11-30T19:30:00Z @AI: Initial IgnoreAwareScanner adapter for Phase 2 artifact generator.
 
 /// Gitignore-aware directory scanner using the ignore crate.
@@ -56,6 +58,13 @@ impl IgnoreAwareScanner {
         content[..check_len].contains(&0)
     }
 
+    /// Checks if a file extension denotes an image that OCR can transcribe.
+    fn is_image_extension(extension: &str) -> bool {
+        ["png", "jpg", "jpeg", "gif", "webp"]
+            .iter()
+            .any(|ext| ext.eq_ignore_ascii_case(extension))
+    }
+
     /// Counts lines in content and returns (line_count, last_line_length).
     fn count_lines(content: &str) -> (usize, usize) {
         if content.is_empty() {
@@ -164,8 +173,9 @@ impl crate::ports::directory_scanner_port::DirectoryScannerPort for IgnoreAwareS
                         .unwrap_or("")
                         .to_string();
 
-                    // Filter by extension
-                    if !Self::is_extension_allowed(&extension, &config.include_extensions) {
+                    // Filter by extension; include_images lets images through on top of the allowlist
+                    let listed_image = config.include_images && Self::is_image_extension(&extension);
+                    if !listed_image && !Self::is_extension_allowed(&extension, &config.include_extensions) {
                         result.stats.files_skipped += 1;
                         continue;
                     }
@@ -204,6 +214,33 @@ impl crate::ports::directory_scanner_port::DirectoryScannerPort for IgnoreAwareS
                         }
                     };
 
+                    // Image files requested by include_images or by extension are listed
+                    // without text content; consumers with OCR support transcribe them
+                    if Self::is_image_extension(&extension) && (listed_image || !config.include_extensions.is_empty()) {
+                        let hash: u64 = content_bytes.iter().fold(0u64, |acc, b| acc.wrapping_add(*b as u64));
+                        let relative_path = path
+                            .strip_prefix(source_path)
+                            .map(|p| p.display().to_string())
+                            .unwrap_or_else(|_| path.display().to_string());
+
+                        result.files.push(crate::domain::scan_config::ScannedFile {
+                            path: relative_path,
+                            absolute_path: path.display().to_string(),
+                            content: String::new(),
+                            extension,
+                            size_bytes,
+                            fingerprint: crate::domain::scan_config::FileFingerprint::new(
+                                std::format!("{:016x}", hash),
+                                Self::get_mtime(path),
+                                size_bytes,
+                            ),
+                            line_count: 0,
+                        });
+                        result.stats.total_bytes += size_bytes;
+                        result.stats.files_scanned += 1;
+                        continue;
+                    }
+
                     // Skip binary files
                     if Self::is_binary_content(&content_bytes) {
                         result.stats.files_skipped += 1;
@@ -530,6 +567,48 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_scan_lists_included_images_without_content() {
+        // Test: Images whose extension is explicitly included are listed with empty content.
+        // Justification: OCR consumers need image paths, which the binary filter would otherwise drop.
+        let dir = std::env::temp_dir().join(std::format!("scanner_images_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("scan.png"), b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR").unwrap();
+        std::fs::write(dir.join("notes.md"), "# Notes").unwrap();
+
+        let scanner = IgnoreAwareScanner::new();
+        let mut config = crate::domain::scan_config::ScanConfig::new(dir.display().to_string());
+        let default_result = scanner.scan(&config).await.unwrap();
+        config.include_extensions.push(String::from("png"));
+        let ocr_result = scanner.scan(&config).await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        std::assert_eq!(default_result.files.len(), 1);
+        let image = ocr_result.files.iter().find(|f| f.extension == "png").unwrap();
+        std::assert!(image.content.is_empty());
+        std::assert_eq!(image.size_bytes, 16);
+    }
+
+    #[tokio::test]
+    async fn test_include_images_keeps_text_files() {
+        // Test: Validates include_images lists images alongside the default text files.
+        // Justification: Adding images must not turn an OCR scan into an image-only scan.
+        let dir = std::env::temp_dir().join(std::format!("scanner_with_images_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("scan.png"), b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR").unwrap();
+        std::fs::write(dir.join("notes.md"), "# Notes").unwrap();
+        std::fs::write(dir.join("main.rs"), "fn main() {}").unwrap();
+
+        let scanner = IgnoreAwareScanner::new();
+        let config = crate::domain::scan_config::ScanConfig::new(dir.display().to_string()).with_images(true);
+        let result = scanner.scan(&config).await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let mut extensions: std::vec::Vec<&str> = result.files.iter().map(|f| f.extension.as_str()).collect();
+        extensions.sort();
+        std::assert_eq!(extensions, ["md", "png", "rs"]);
+    }
+
     #[tokio::test]
     async fn test_find_deleted_files_none_deleted() {
         // Test: No files deleted returns empty vec.
@@ -8,6 +8,9 @@
 //! use, consider replacing with a persistent storage adapter (e.g., database).
 //!
 //! Revision History
+//! - 2026-10-17T11:50:00Z @AI: Support the ByIdempotencyKey filter and reject a second task with the same idempotency key, like the SQLite unique index.
+//! - 2026-10-17T11:30:00Z @AI: Test keyset pagination through the default TaskRepositoryPort::find_after().
+//! - 2026-10-17T10:10:00Z @AI: Sort by priority urgency for TaskSortKey::Priority.
 //! - 2025-11-06T18:14:00Z @AI: Rewrite to implement HEXSER Repository and QueryRepository traits.
 //! - 2025-11-06T18:00:00Z @AI: Initial InMemoryTaskAdapter implementation.
 
@@ -64,6 +67,13 @@ impl hexser::ports::Repository<crate::domain::task::Task> for InMemoryTaskAdapte
         entity: crate::domain::task::Task,
     ) -> hexser::HexResult<()> {
         let mut tasks = self.tasks.lock();
+        if let std::option::Option::Some(key) = &entity.idempotency_key
+            && tasks.values().any(|task| task.id != entity.id && task.idempotency_key.as_ref() == std::option::Option::Some(key))
+        {
+            return std::result::Result::Err(hexser::error::hex_error::Hexserror::Adapter(
+                hexser::error::adapter_error::mapping_failure(std::format!("Idempotency key {} is already used by another task", key).as_str()),
+            ));
+        }
         tasks.insert(entity.id.clone(), entity);
         std::result::Result::Ok(())
     }
@@ -100,6 +110,9 @@ impl hexser::ports::repository::QueryRepository<crate::domain::task::Task>
                     })
                     .cloned()
             }
+            crate::ports::task_repository_port::TaskFilter::ByIdempotencyKey(key) => {
+                tasks.values().find(|task| task.idempotency_key.as_ref() == std::option::Option::Some(key)).cloned()
+            }
             crate::ports::task_repository_port::TaskFilter::All => {
                 tasks.values().next().cloned()
             }
@@ -139,6 +152,13 @@ impl hexser::ports::repository::QueryRepository<crate::domain::task::Task>
                     .cloned()
                     .collect()
             }
+            crate::ports::task_repository_port::TaskFilter::ByIdempotencyKey(key) => {
+                tasks
+                    .values()
+                    .filter(|task| task.idempotency_key.as_ref() == std::option::Option::Some(key))
+                    .cloned()
+                    .collect()
+            }
             crate::ports::task_repository_port::TaskFilter::All => {
                 tasks.values().cloned().collect()
             }
@@ -180,6 +200,9 @@ impl hexser::ports::repository::QueryRepository<crate::domain::task::Task>
                             }
                         });
                     }
+                    crate::ports::task_repository_port::TaskSortKey::Priority => {
+                        filtered.sort_by_key(|task| task.priority);
+                    }
                     crate::ports::task_repository_port::TaskSortKey::SortOrder => {
                         filtered.sort_by(|a, b| {
                             match (&a.sort_order, &b.sort_order) {
@@ -342,4 +365,65 @@ mod tests {
         assert_eq!(sorted[1].title, "Bob");
         assert_eq!(sorted[2].title, "Charlie");
     }
+
+    #[test]
+    fn test_find_with_priority_sorting() {
+        // Test: Validates TaskSortKey::Priority orders by urgency, with High first when descending.
+        // Justification: Sorting the stored names lexically would rank Low above Medium.
+        let mut adapter = InMemoryTaskAdapter::new();
+
+        for (id, priority) in [
+            ("low", std::option::Option::Some(crate::domain::priority::Priority::Low)),
+            ("high", std::option::Option::Some(crate::domain::priority::Priority::High)),
+            ("none", std::option::Option::None),
+            ("medium", std::option::Option::Some(crate::domain::priority::Priority::Medium)),
+        ] {
+            let mut task = create_test_task(id, id, crate::domain::task_status::TaskStatus::Todo);
+            task.priority = priority;
+            adapter.save(task).unwrap();
+        }
+
+        let sorted = adapter.find(
+            &crate::ports::task_repository_port::TaskFilter::All,
+            hexser::ports::repository::FindOptions {
+                sort: std::option::Option::Some(vec![
+                    hexser::ports::repository::Sort {
+                        key: crate::ports::task_repository_port::TaskSortKey::Priority,
+                        direction: hexser::ports::repository::Direction::Desc,
+                    }
+                ]),
+                limit: std::option::Option::None,
+                offset: std::option::Option::None,
+            }
+        ).unwrap();
+
+        let ids: std::vec::Vec<&str> = sorted.iter().map(|task| task.id.as_str()).collect();
+        assert_eq!(ids, ["high", "medium", "low", "none"]);
+    }
+
+    #[test]
+    fn test_find_after_pages_without_gaps_or_duplicates() {
+        // Test: Validates the default find_after() pages by sort value then ID, continuing correctly
+        // after a task is inserted before the cursor, with a decodable cursor between pages.
+        // Justification: Adapters without a keyset query rely on the default implementation.
+        use crate::ports::task_repository_port::TaskRepositoryPort;
+        let mut adapter = InMemoryTaskAdapter::new();
+        for (id, title) in [("t1", "Bravo"), ("t2", "Delta"), ("t3", "Bravo"), ("t4", "Echo"), ("t5", "Charlie")] {
+            adapter.save(create_test_task(id, title, crate::domain::task_status::TaskStatus::Todo)).unwrap();
+        }
+        let sort = hexser::ports::repository::Sort {
+            key: crate::ports::task_repository_port::TaskSortKey::Title,
+            direction: hexser::ports::repository::Direction::Asc,
+        };
+
+        let first = adapter.find_after(&crate::ports::task_repository_port::TaskFilter::All, &sort, None, 3).unwrap();
+        adapter.save(create_test_task("t0", "Alpha", crate::domain::task_status::TaskStatus::Todo)).unwrap();
+        let token = first.next_cursor.unwrap().encode();
+        let cursor = crate::ports::task_repository_port::TaskCursor::decode(&token).unwrap();
+        let second = adapter.find_after(&crate::ports::task_repository_port::TaskFilter::All, &sort, Some(&cursor), 3).unwrap();
+
+        let ids: std::vec::Vec<&str> = first.tasks.iter().chain(second.tasks.iter()).map(|task| task.id.as_str()).collect();
+        assert_eq!(ids, ["t1", "t3", "t5", "t2", "t4"]);
+        assert!(second.next_cursor.is_none());
+    }
 }
@@ -13,6 +13,17 @@
 //! embeddings and similarity search using cosine distance.
 //!
 //! Revision History
+//! - 2026-10-18T03:50:00Z @AI: In-memory databases no longer get a stand-in artifacts_vec table; tests create it. Add pool().
+//! - 2026-10-17T23:20:00Z @AI: Add replace_embeddings_async deleting and re-saving a batch's embeddings in one transaction.
+//! - 2026-10-17T23:10:00Z @AI: delete_where_async skips the vector index when artifacts_vec was never created.
+//! - 2026-10-17T21:00:00Z @AI: Add save_in_transaction so callers can save artifacts atomically with other writes.
+//! - 2026-10-17T18:30:00Z @AI: Implement find_similar_filtered, applying source type, extension, and language filters in the WHERE clause of the vector scan.
+//! - 2026-10-17T05:50:00Z @AI: Add connect_and_init_with to size the pool from settings, with WAL and a busy timeout.
+//! - 2026-10-17T05:30:00Z @AI: Create and upgrade the artifacts table through versioned migrations.
+//! - 2026-10-16T22:30:00Z @AI: Persist embedding_model and embedding_dimension columns (backfilled from stored vectors); add embedding_spaces_async() and reset_embedding_index_async() for dimension validation and re-embedding.
+//! - 2026-10-16T14:50:00Z @AI: Add backfill_citation_metadata() run on init so pre-existing artifacts gain source_url/source_path/page citation fields; decode NULL optional columns as None.
+//! - 2026-10-16T13:50:00Z @AI: Add delete_where_async() backing QueryRepository::delete_where; removes artifact rows and their artifacts_vec (ANN index) embeddings in one transaction; replaces unused delete_async().
+//! - 2026-10-16T13:30:00Z @AI: Bind LIMIT/OFFSET as parameters with an id tie-breaker for stable pagination; add count_async() backing QueryRepository::count; create a plain artifacts_vec table for in-memory databases.
 //! - 2025-11-30T10:30:00Z @AI: Add support for Image and PDF artifact types with binary storage. Updated row_to_artifact() to handle new ArtifactType variants (Image, PDF) and extract optional binary fields (binary_content, mime_type, source_url, page_number). Uses try_get() for backward compatibility with older schemas.
 //! - 2025-11-29T14:30:00Z @AI: Add public async search_similar() method for semantic artifact search. Takes query embedding, limit, and similarity threshold (0.0-1.0). Returns (Artifact, similarity_score) tuples sorted by similarity. Converts cosine distance to similarity score (1.0 - distance) for threshold comparison.
 //! - 2025-11-29T09:00:00Z @AI: Add create_if_missing(true) to SqliteConnectOptions to ensure database file is created when it doesn't exist.
@@ -25,120 +36,82 @@ pub struct SqliteArtifactAdapter {
 }
 
 impl SqliteArtifactAdapter {
+    /// Scope under which this adapter's schema versions are recorded in `schema_migrations`.
+    const MIGRATION_SCOPE: &'static str = "artifacts";
+
+    /// Schema history of the artifacts table. The `artifacts_vec` index is not
+    /// migrated: its kind depends on whether sqlite-vec is loaded.
+    const MIGRATIONS: &'static [crate::infrastructure::sqlite_migrations::migration::Migration] = &[
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 1,
+            description: "Create artifacts table",
+            steps: &[crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute(
+                "CREATE TABLE IF NOT EXISTS artifacts (
+                    id TEXT PRIMARY KEY,
+                    project_id TEXT NOT NULL,
+                    source_id TEXT NOT NULL,
+                    source_type TEXT NOT NULL,
+                    content TEXT NOT NULL,
+                    metadata TEXT NULL,
+                    created_at TEXT NOT NULL
+                )",
+            )],
+        },
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 2,
+            description: "Add image and PDF columns",
+            steps: &[
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn { table: "artifacts", column: "binary_content", definition: "TEXT NULL" },
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn { table: "artifacts", column: "mime_type", definition: "TEXT NULL" },
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn { table: "artifacts", column: "source_url", definition: "TEXT NULL" },
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn { table: "artifacts", column: "page_number", definition: "INTEGER NULL" },
+            ],
+        },
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 3,
+            description: "Add embedding model and dimension",
+            steps: &[
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn { table: "artifacts", column: "embedding_model", definition: "TEXT NULL" },
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn { table: "artifacts", column: "embedding_dimension", definition: "INTEGER NULL" },
+            ],
+        },
+    ];
+
     /// Creates a new adapter from an existing SQLite pool.
     pub fn new(pool: sqlx::Pool<sqlx::Sqlite>) -> Self {
         SqliteArtifactAdapter { pool }
     }
 
+    /// Returns a reference to the underlying SQLite pool.
+    pub fn pool(&self) -> &sqlx::Pool<sqlx::Sqlite> {
+        &self.pool
+    }
+
     /// Asynchronously connects to the provided database URL and ensures the schema exists.
     ///
+    /// Uses the default pool settings; see `connect_and_init_with`.
+    pub async fn connect_and_init(database_url: &str) -> std::result::Result<Self, std::string::String> {
+        Self::connect_and_init_with(
+            database_url,
+            &crate::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings::default(),
+        )
+        .await
+    }
+
+    /// Connects with a pool sized by `settings` and ensures the schema exists.
+    ///
     /// This method loads the sqlite-vec extension for vector similarity search.
     /// The extension is embedded in the binary and extracted at runtime,
     /// ensuring RAG features are always available regardless of working directory.
-    pub async fn connect_and_init(database_url: &str) -> std::result::Result<Self, std::string::String> {
-        // For in-memory databases in tests, skip extension loading
-        let pool = if database_url.contains(":memory:") {
-            let connect_options = database_url
-                .parse::<sqlx::sqlite::SqliteConnectOptions>()
-                .map_err(|e| std::format!("Failed to parse database URL: {:?}", e))?
-                .create_if_missing(true)
-                .optimize_on_close(false, std::option::Option::None);
-
-            sqlx::sqlite::SqlitePoolOptions::new()
-                .max_connections(1)
-                .connect_with(connect_options)
-                .await
-                .map_err(|e| std::format!("Failed to connect SQLite: {:?}", e))?
-        } else {
-            // Try embedded extension first (always available, bundled in binary)
-            let mut extension_paths = std::vec![];
+    /// In-memory databases skip the extension and get no `artifacts_vec` index;
+    /// tests that need one create a plain table through `pool()`.
+    pub async fn connect_and_init_with(
+        database_url: &str,
+        settings: &crate::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings,
+    ) -> std::result::Result<Self, std::string::String> {
+        let (pool, _) = crate::infrastructure::sqlite_pool::connector::connect_with_sqlite_vec(database_url, settings).await?;
 
-            if let std::result::Result::Ok(embedded_path) = crate::adapters::embedded_sqlite_vec::get_extension_path_for_sqlite() {
-                extension_paths.push(embedded_path);
-            }
-
-            // Fallback paths (for development or custom installations)
-            let abs_path = std::env::current_dir()
-                .ok()
-                .and_then(|p| p.join(".rigger/lib/vec0").to_str().map(|s| s.to_string()))
-                .unwrap_or_default();
-
-            extension_paths.push(std::string::String::from("vec0"));              // System-wide install
-            extension_paths.push(std::string::String::from(".rigger/lib/vec0"));  // Local install
-            extension_paths.push(abs_path);                                        // Absolute path
-
-            let mut pool_result = std::option::Option::None;
-
-            for ext_path in &extension_paths {
-                if ext_path.is_empty() {
-                    continue;
-                }
-
-                let connect_options = database_url
-                    .parse::<sqlx::sqlite::SqliteConnectOptions>()
-                    .map_err(|e| std::format!("Failed to parse database URL: {:?}", e))?
-                    .create_if_missing(true)
-                    .optimize_on_close(false, std::option::Option::None)
-                    .extension(ext_path.clone());
-
-                if let std::result::Result::Ok(p) = sqlx::sqlite::SqlitePoolOptions::new()
-                    .max_connections(1)
-                    .connect_with(connect_options)
-                    .await
-                {
-                    pool_result = std::option::Option::Some(p);
-                    break;
-                }
-            }
-
-            match pool_result {
-                std::option::Option::Some(p) => p,
-                std::option::Option::None => {
-                    // Fall back to connection without extension
-                    let connect_options = database_url
-                        .parse::<sqlx::sqlite::SqliteConnectOptions>()
-                        .map_err(|e| std::format!("Failed to parse database URL: {:?}", e))?
-                        .create_if_missing(true)
-                        .optimize_on_close(false, std::option::Option::None);
-
-                    sqlx::sqlite::SqlitePoolOptions::new()
-                        .max_connections(1)
-                        .connect_with(connect_options)
-                        .await
-                        .map_err(|e| std::format!("Failed to connect to SQLite: {:?}", e))?
-                }
-            }
-        };
-
-        // Ensure artifacts table exists
-        sqlx::query(
-            "CREATE TABLE IF NOT EXISTS artifacts (
-                id TEXT PRIMARY KEY,
-                project_id TEXT NOT NULL,
-                source_id TEXT NOT NULL,
-                source_type TEXT NOT NULL,
-                content TEXT NOT NULL,
-                metadata TEXT NULL,
-                created_at TEXT NOT NULL,
-                binary_content TEXT NULL,
-                mime_type TEXT NULL,
-                source_url TEXT NULL,
-                page_number INTEGER NULL
-            )"
-        )
-        .execute(&pool)
-        .await
-        .map_err(|e| std::format!("Failed to create artifacts table: {:?}", e))?;
-
-        // Add new columns if they don't exist (for existing databases)
-        let _ = sqlx::query("ALTER TABLE artifacts ADD COLUMN binary_content TEXT NULL")
-            .execute(&pool).await;
-        let _ = sqlx::query("ALTER TABLE artifacts ADD COLUMN mime_type TEXT NULL")
-            .execute(&pool).await;
-        let _ = sqlx::query("ALTER TABLE artifacts ADD COLUMN source_url TEXT NULL")
-            .execute(&pool).await;
-        let _ = sqlx::query("ALTER TABLE artifacts ADD COLUMN page_number INTEGER NULL")
-            .execute(&pool).await;
+        Self::migrate(&pool).await?;
 
         // Ensure artifacts_vec virtual table exists with correct dimensions
         // Note: nomic-embed-text produces 768-dimensional embeddings
@@ -158,7 +131,88 @@ impl SqliteArtifactAdapter {
             }
         }
 
-        std::result::Result::Ok(SqliteArtifactAdapter { pool })
+        let adapter = SqliteArtifactAdapter { pool };
+
+        // Give artifacts stored before citation metadata existed a best-effort source reference
+        adapter
+            .backfill_citation_metadata()
+            .await
+            .map_err(|e| std::format!("Failed to backfill citation metadata: {:?}", e))?;
+        adapter.backfill_embedding_dimension().await;
+
+        std::result::Result::Ok(adapter)
+    }
+
+    /// Applies the pending schema migrations of this adapter.
+    ///
+    /// # Returns
+    ///
+    /// The versions applied; empty when the schema was already current.
+    pub async fn migrate(pool: &sqlx::Pool<sqlx::Sqlite>) -> std::result::Result<std::vec::Vec<i64>, std::string::String> {
+        crate::infrastructure::sqlite_migrations::migrator::run_pending(pool, Self::MIGRATION_SCOPE, Self::MIGRATIONS).await
+    }
+
+    /// Backfills citation fields on artifacts that predate citation metadata.
+    ///
+    /// Best effort and idempotent: URL source IDs are copied into
+    /// `source_url`, other sources get `"source_path"` in their JSON metadata,
+    /// and a `"page"` recorded in metadata is copied into `page_number`.
+    /// Metadata that is not a JSON object is left untouched.
+    ///
+    /// # Returns
+    ///
+    /// The number of rows updated.
+    pub async fn backfill_citation_metadata(&self) -> hexser::HexResult<u64> {
+        let statements = [
+            "UPDATE artifacts SET source_url = source_id
+             WHERE source_url IS NULL AND (source_id LIKE 'http://%' OR source_id LIKE 'https://%')",
+            "UPDATE artifacts SET metadata = json_set(COALESCE(metadata, '{}'), '$.source_path', source_id)
+             WHERE source_url IS NULL
+               AND (metadata IS NULL
+                    OR (json_valid(metadata) AND json_type(metadata) = 'object'
+                        AND json_extract(metadata, '$.source_path') IS NULL))",
+            "UPDATE artifacts SET page_number = json_extract(metadata, '$.page')
+             WHERE page_number IS NULL AND json_valid(metadata)
+               AND json_type(metadata, '$.page') = 'integer'",
+        ];
+
+        let mut updated = 0;
+        for statement in statements {
+            updated += sqlx::query(statement)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| {
+                    hexser::error::hex_error::Hexserror::Adapter(
+                        hexser::error::adapter_error::connection_failed(
+                            "SQLite",
+                            std::format!("citation backfill failed: {:?}", e).as_str()
+                        )
+                    )
+                })?
+                .rows_affected();
+        }
+
+        std::result::Result::Ok(updated)
+    }
+
+    /// Records the dimension of stored embeddings that predate the
+    /// `embedding_dimension` column.
+    ///
+    /// Best effort: uses sqlite-vec's `vec_length` when the extension is
+    /// loaded and falls back to the JSON array length of plain-table
+    /// embeddings. The model of these artifacts stays unknown.
+    async fn backfill_embedding_dimension(&self) {
+        for length_fn in ["vec_length", "json_array_length"] {
+            let statement = std::format!(
+                "UPDATE artifacts SET embedding_dimension =
+                   (SELECT {}(v.embedding) FROM artifacts_vec v WHERE v.artifact_id = artifacts.id)
+                 WHERE embedding_dimension IS NULL",
+                length_fn
+            );
+            if sqlx::query(&statement).execute(&self.pool).await.is_ok() {
+                return;
+            }
+        }
     }
 
     fn block_on<T>(fut: impl std::future::Future<Output = T>) -> T {
@@ -183,6 +237,25 @@ impl SqliteArtifactAdapter {
     }
 
     pub async fn save_async(&self, entity: crate::domain::artifact::Artifact) -> hexser::HexResult<()> {
+        let mut conn = self.pool.acquire().await.map_err(|e| {
+            hexser::error::hex_error::Hexserror::Adapter(
+                hexser::error::adapter_error::connection_failed("SQLite", std::format!("{:?}", e).as_str())
+            )
+        })?;
+        Self::write_artifact(&mut conn, entity).await
+    }
+
+    /// Saves an artifact and its embedding inside a caller's transaction, so
+    /// they commit or roll back with the caller's other writes.
+    pub async fn save_in_transaction(
+        tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+        entity: crate::domain::artifact::Artifact,
+    ) -> hexser::HexResult<()> {
+        Self::write_artifact(&mut **tx, entity).await
+    }
+
+    /// Upserts the artifact row and its vector index entry on one connection.
+    async fn write_artifact(conn: &mut sqlx::SqliteConnection, entity: crate::domain::artifact::Artifact) -> hexser::HexResult<()> {
         let source_type_str = std::format!("{:?}", entity.source_type);
         let created_at = entity.created_at.to_rfc3339();
 
@@ -197,14 +270,15 @@ impl SqliteArtifactAdapter {
 
         // Insert into artifacts table
         sqlx::query(
-            "INSERT INTO artifacts (id, project_id, source_id, source_type, content, metadata, created_at, binary_content, mime_type, source_url, page_number)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+            "INSERT INTO artifacts (id, project_id, source_id, source_type, content, metadata, created_at, binary_content, mime_type, source_url, page_number, embedding_model, embedding_dimension)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
              ON CONFLICT(id) DO UPDATE SET
                project_id=excluded.project_id, source_id=excluded.source_id,
                source_type=excluded.source_type, content=excluded.content,
                metadata=excluded.metadata, created_at=excluded.created_at,
                binary_content=excluded.binary_content, mime_type=excluded.mime_type,
-               source_url=excluded.source_url, page_number=excluded.page_number"
+               source_url=excluded.source_url, page_number=excluded.page_number,
+               embedding_model=excluded.embedding_model, embedding_dimension=excluded.embedding_dimension"
         )
         .bind(&entity.id)
         .bind(&entity.project_id)
@@ -217,7 +291,9 @@ impl SqliteArtifactAdapter {
         .bind(&entity.mime_type)
         .bind(&entity.source_url)
         .bind(entity.page_number.map(|p| p as i64))
-        .execute(&self.pool)
+        .bind(&entity.embedding_model)
+        .bind(entity.embedding_dimension.map(|d| d as i64))
+        .execute(&mut *conn)
         .await
         .map_err(|e| {
             let msg = std::format!("sqlx error inserting artifact: {:?}", e);
@@ -234,7 +310,7 @@ impl SqliteArtifactAdapter {
         )
         .bind(&entity.id)
         .bind(&embedding_json)
-        .execute(&self.pool)
+        .execute(&mut *conn)
         .await
         {
             std::result::Result::Ok(_) => {
@@ -287,12 +363,19 @@ impl SqliteArtifactAdapter {
         };
 
         // Extract optional binary fields (may not exist in older schemas)
-        let binary_content: std::option::Option<String> = sqlx::Row::try_get(&row, "binary_content").ok();
-        let mime_type: std::option::Option<String> = sqlx::Row::try_get(&row, "mime_type").ok();
-        let source_url: std::option::Option<String> = sqlx::Row::try_get(&row, "source_url").ok();
-        let page_number: std::option::Option<u32> = sqlx::Row::try_get::<i64, _>(&row, "page_number")
+        // Decode as Option so NULL columns stay None instead of defaulting (e.g., page 0)
+        let binary_content: std::option::Option<String> = sqlx::Row::try_get(&row, "binary_content").ok().flatten();
+        let mime_type: std::option::Option<String> = sqlx::Row::try_get(&row, "mime_type").ok().flatten();
+        let source_url: std::option::Option<String> = sqlx::Row::try_get(&row, "source_url").ok().flatten();
+        let page_number: std::option::Option<u32> = sqlx::Row::try_get::<std::option::Option<i64>, _>(&row, "page_number")
             .ok()
+            .flatten()
             .map(|v| v as u32);
+        let embedding_model: std::option::Option<String> = sqlx::Row::try_get(&row, "embedding_model").ok().flatten();
+        let embedding_dimension: std::option::Option<usize> = sqlx::Row::try_get::<std::option::Option<i64>, _>(&row, "embedding_dimension")
+            .ok()
+            .flatten()
+            .map(|v| v as usize);
 
         std::result::Result::Ok(crate::domain::artifact::Artifact {
             id,
@@ -307,6 +390,8 @@ impl SqliteArtifactAdapter {
             mime_type,
             source_url,
             page_number,
+            embedding_model,
+            embedding_dimension,
         })
     }
 
@@ -318,7 +403,7 @@ impl SqliteArtifactAdapter {
             crate::ports::artifact_repository_port::ArtifactFilter::ById(id) => {
                 // Fetch artifact metadata
                 let row_opt = sqlx::query(
-                    "SELECT id, project_id, source_id, source_type, content, metadata, created_at, binary_content, mime_type, source_url, page_number
+                    "SELECT id, project_id, source_id, source_type, content, metadata, created_at, binary_content, mime_type, source_url, page_number, embedding_model, embedding_dimension
                      FROM artifacts WHERE id = ?1"
                 )
                 .bind(id)
@@ -370,32 +455,14 @@ impl SqliteArtifactAdapter {
         opts: hexser::ports::repository::FindOptions<crate::ports::artifact_repository_port::ArtifactSortKey>,
     ) -> hexser::HexResult<std::vec::Vec<crate::domain::artifact::Artifact>> {
         let mut query_str = String::from(
-            "SELECT a.id, a.project_id, a.source_id, a.source_type, a.content, a.metadata, a.created_at, a.binary_content, a.mime_type, a.source_url, a.page_number, v.embedding
+            "SELECT a.id, a.project_id, a.source_id, a.source_type, a.content, a.metadata, a.created_at, a.binary_content, a.mime_type, a.source_url, a.page_number, a.embedding_model, a.embedding_dimension, v.embedding
              FROM artifacts a
              LEFT JOIN artifacts_vec v ON a.id = v.artifact_id"
         );
-        let mut bind_values: std::vec::Vec<String> = std::vec::Vec::new();
 
         // WHERE clause
-        match filter {
-            crate::ports::artifact_repository_port::ArtifactFilter::ById(id) => {
-                query_str.push_str(" WHERE a.id = ?1");
-                bind_values.push(id.clone());
-            }
-            crate::ports::artifact_repository_port::ArtifactFilter::ByProjectId(project_id) => {
-                query_str.push_str(" WHERE a.project_id = ?1");
-                bind_values.push(project_id.clone());
-            }
-            crate::ports::artifact_repository_port::ArtifactFilter::BySourceId(source_id) => {
-                query_str.push_str(" WHERE a.source_id = ?1");
-                bind_values.push(source_id.clone());
-            }
-            crate::ports::artifact_repository_port::ArtifactFilter::BySourceType(source_type) => {
-                query_str.push_str(" WHERE a.source_type = ?1");
-                bind_values.push(std::format!("{:?}", source_type));
-            }
-            crate::ports::artifact_repository_port::ArtifactFilter::All => {}
-        }
+        let (where_clause, filter_value) = Self::filter_clause(filter);
+        query_str.push_str(where_clause);
 
         // ORDER BY
         if let std::option::Option::Some(sort_specs) = opts.sort {
@@ -409,23 +476,27 @@ impl SqliteArtifactAdapter {
                 parts.push(std::format!("{} {}", col, dir));
             }
             if !parts.is_empty() {
+                // Tie-break on id so pages never overlap or skip equal sort keys
+                parts.push(String::from("a.id ASC"));
                 query_str.push_str(" ORDER BY ");
                 query_str.push_str(&parts.join(", "));
             }
         }
 
-        // LIMIT/OFFSET
-        if let std::option::Option::Some(lim) = opts.limit {
-            query_str.push_str(&std::format!(" LIMIT {}", lim));
-        }
-        if let std::option::Option::Some(off) = opts.offset {
-            query_str.push_str(&std::format!(" OFFSET {}", off));
+        // LIMIT/OFFSET (SQLite requires a LIMIT before OFFSET; -1 means unbounded)
+        if opts.limit.is_some() || opts.offset.is_some() {
+            query_str.push_str(" LIMIT ? OFFSET ?");
         }
 
         let mut query = sqlx::query(&query_str);
-        for val in bind_values {
+        if let std::option::Option::Some(val) = filter_value {
             query = query.bind(val);
         }
+        if opts.limit.is_some() || opts.offset.is_some() {
+            query = query
+                .bind(opts.limit.map(|l| l as i64).unwrap_or(-1))
+                .bind(opts.offset.unwrap_or(0) as i64);
+        }
 
         let rows = query
             .fetch_all(&self.pool)
@@ -460,28 +531,177 @@ impl SqliteArtifactAdapter {
         std::result::Result::Ok(artifacts)
     }
 
-    async fn delete_async(&self, id: &str) -> hexser::HexResult<()> {
-        // Delete from artifacts table (CASCADE will handle artifacts_vec if using foreign key)
-        sqlx::query("DELETE FROM artifacts WHERE id = ?1")
-            .bind(id)
-            .execute(&self.pool)
+    /// Counts artifacts matching a filter with a COUNT query.
+    pub async fn count_async(
+        &self,
+        filter: &crate::ports::artifact_repository_port::ArtifactFilter,
+    ) -> hexser::HexResult<usize> {
+        let (where_clause, filter_value) = Self::filter_clause(filter);
+        let query_str = std::format!("SELECT COUNT(*) FROM artifacts a{}", where_clause);
+
+        let mut query = sqlx::query_scalar::<_, i64>(&query_str);
+        if let std::option::Option::Some(val) = filter_value {
+            query = query.bind(val);
+        }
+
+        let count = query
+            .fetch_one(&self.pool)
             .await
             .map_err(|e| {
                 hexser::error::hex_error::Hexserror::Adapter(
                     hexser::error::adapter_error::connection_failed(
                         "SQLite",
-                        std::format!("delete query failed: {:?}", e).as_str()
+                        std::format!("count query failed: {:?}", e).as_str()
                     )
                 )
             })?;
 
-        // Delete from artifacts_vec table (ignore error if table doesn't exist)
-        let _ = sqlx::query("DELETE FROM artifacts_vec WHERE artifact_id = ?1")
-            .bind(id)
-            .execute(&self.pool)
-            .await;
+        std::result::Result::Ok(count as usize)
+    }
 
-        std::result::Result::Ok(())
+    /// Builds the WHERE clause and its bound value for an artifact filter.
+    fn filter_clause(
+        filter: &crate::ports::artifact_repository_port::ArtifactFilter,
+    ) -> (&'static str, std::option::Option<String>) {
+        match filter {
+            crate::ports::artifact_repository_port::ArtifactFilter::ById(id) => {
+                (" WHERE a.id = ?", std::option::Option::Some(id.clone()))
+            }
+            crate::ports::artifact_repository_port::ArtifactFilter::ByProjectId(project_id) => {
+                (" WHERE a.project_id = ?", std::option::Option::Some(project_id.clone()))
+            }
+            crate::ports::artifact_repository_port::ArtifactFilter::BySourceId(source_id) => {
+                (" WHERE a.source_id = ?", std::option::Option::Some(source_id.clone()))
+            }
+            crate::ports::artifact_repository_port::ArtifactFilter::BySourceType(source_type) => {
+                (" WHERE a.source_type = ?", std::option::Option::Some(std::format!("{:?}", source_type)))
+            }
+            crate::ports::artifact_repository_port::ArtifactFilter::All => ("", std::option::Option::None),
+        }
+    }
+
+    /// Builds the WHERE clause of a filtered similarity search.
+    ///
+    /// Placeholders are numbered from `first_bind` (`?1` is the query
+    /// embedding), so one extension parameter can be used twice. Extensions
+    /// match the lowercased end of `source_id`; an unknown language matches
+    /// nothing.
+    ///
+    /// # Returns
+    ///
+    /// The clause (empty if the filter is empty) and its values in bind order.
+    fn search_filter_clause(
+        filter: &crate::ports::artifact_repository_port::ArtifactSearchFilter,
+        first_bind: usize,
+    ) -> (String, std::vec::Vec<String>) {
+        let mut conditions: std::vec::Vec<String> = std::vec::Vec::new();
+        let mut values: std::vec::Vec<String> = std::vec::Vec::new();
+        let next_bind = |values: &mut std::vec::Vec<String>, value: String| {
+            values.push(value);
+            first_bind + values.len() - 1
+        };
+
+        if let std::option::Option::Some(project_id) = &filter.project_id {
+            let idx = next_bind(&mut values, project_id.clone());
+            conditions.push(std::format!("a.project_id = ?{}", idx));
+        }
+        if !filter.source_types.is_empty() {
+            let placeholders: std::vec::Vec<String> = filter
+                .source_types
+                .iter()
+                .map(|source_type| std::format!("?{}", next_bind(&mut values, std::format!("{:?}", source_type))))
+                .collect();
+            conditions.push(std::format!("a.source_type IN ({})", placeholders.join(", ")));
+        }
+
+        let extension_condition = |values: &mut std::vec::Vec<String>, extensions: &[String]| {
+            let alternatives: std::vec::Vec<String> = extensions
+                .iter()
+                .map(|extension| {
+                    let idx = next_bind(values, std::format!(".{}", extension));
+                    std::format!("substr(lower(a.source_id), -length(?{idx})) = ?{idx}", idx = idx)
+                })
+                .collect();
+            std::format!("({})", alternatives.join(" OR "))
+        };
+        if !filter.extensions.is_empty() {
+            conditions.push(extension_condition(&mut values, &filter.extensions));
+        }
+        if let std::option::Option::Some(language) = &filter.language {
+            match crate::ports::artifact_repository_port::extensions_for_language(language) {
+                std::option::Option::Some(extensions) => {
+                    let extensions: std::vec::Vec<String> = extensions.iter().map(|e| String::from(*e)).collect();
+                    conditions.push(extension_condition(&mut values, &extensions));
+                }
+                std::option::Option::None => conditions.push(String::from("0")),
+            }
+        }
+
+        if conditions.is_empty() {
+            return (String::new(), values);
+        }
+        (std::format!(" WHERE {}", conditions.join(" AND ")), values)
+    }
+
+    /// Deletes artifacts matching a filter along with their embeddings.
+    ///
+    /// Rows are removed from `artifacts` and from the `artifacts_vec` vector
+    /// index inside one transaction, so no orphaned embeddings remain. When
+    /// the index could not be created (sqlite-vec failed to load), only the
+    /// artifact rows are removed.
+    ///
+    /// # Returns
+    ///
+    /// The number of artifacts deleted.
+    pub async fn delete_where_async(
+        &self,
+        filter: &crate::ports::artifact_repository_port::ArtifactFilter,
+    ) -> hexser::HexResult<u64> {
+        let (where_clause, filter_value) = Self::filter_clause(filter);
+        let map_err = |e: sqlx::Error| {
+            hexser::error::hex_error::Hexserror::Adapter(
+                hexser::error::adapter_error::connection_failed(
+                    "SQLite",
+                    std::format!("delete query failed: {:?}", e).as_str()
+                )
+            )
+        };
+
+        let mut tx = self.pool.begin().await.map_err(map_err)?;
+
+        let vec_index: std::option::Option<String> = sqlx::query_scalar(
+            "SELECT name FROM sqlite_master WHERE name = 'artifacts_vec'"
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(map_err)?;
+
+        // Remove embeddings first while the matching artifact IDs still exist
+        if vec_index.is_some() {
+            let vec_sql = std::format!(
+                "DELETE FROM artifacts_vec WHERE artifact_id IN (SELECT a.id FROM artifacts a{})",
+                where_clause
+            );
+            let mut vec_query = sqlx::query(&vec_sql);
+            if let std::option::Option::Some(val) = &filter_value {
+                vec_query = vec_query.bind(val.clone());
+            }
+            vec_query.execute(&mut *tx).await.map_err(map_err)?;
+        }
+
+        let artifacts_sql = std::format!(
+            "DELETE FROM artifacts WHERE id IN (SELECT a.id FROM artifacts a{})",
+            where_clause
+        );
+        let mut artifacts_query = sqlx::query(&artifacts_sql);
+        if let std::option::Option::Some(val) = filter_value {
+            artifacts_query = artifacts_query.bind(val);
+        }
+        let deleted = artifacts_query.execute(&mut *tx).await.map_err(map_err)?.rows_affected();
+
+        tx.commit().await.map_err(map_err)?;
+
+        std::result::Result::Ok(deleted)
     }
 
     /// Searches for artifacts similar to the given query embedding using vector similarity.
@@ -507,7 +727,7 @@ impl SqliteArtifactAdapter {
 
         // Build similarity search query using vec_distance_cosine
         let query_str = std::format!(
-            "SELECT a.id, a.project_id, a.source_id, a.source_type, a.content, a.metadata, a.created_at, a.binary_content, a.mime_type, a.source_url, a.page_number,
+            "SELECT a.id, a.project_id, a.source_id, a.source_type, a.content, a.metadata, a.created_at, a.binary_content, a.mime_type, a.source_url, a.page_number, a.embedding_model, a.embedding_dimension,
                     vec_distance_cosine(v.embedding, ?1) as distance
              FROM artifacts a
              JOIN artifacts_vec v ON a.id = v.artifact_id
@@ -544,6 +764,136 @@ impl SqliteArtifactAdapter {
 
         std::result::Result::Ok(results)
     }
+
+    /// Lists the distinct embedding models and dimensions of stored artifacts.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - Optional project ID to scope the listing
+    ///
+    /// # Returns
+    ///
+    /// One entry per (model, dimension) pair, largest group first. Artifacts
+    /// without a recorded dimension are skipped.
+    pub async fn embedding_spaces_async(
+        &self,
+        project_id: std::option::Option<&str>,
+    ) -> std::result::Result<std::vec::Vec<crate::ports::artifact_repository_port::EmbeddingSpace>, std::string::String> {
+        let mut query_str = String::from(
+            "SELECT embedding_model, embedding_dimension, COUNT(*) AS artifacts
+             FROM artifacts
+             WHERE embedding_dimension IS NOT NULL"
+        );
+        if project_id.is_some() {
+            query_str.push_str(" AND project_id = ?1");
+        }
+        query_str.push_str(" GROUP BY embedding_model, embedding_dimension ORDER BY artifacts DESC, embedding_dimension ASC");
+
+        let mut query = sqlx::query(&query_str);
+        if let std::option::Option::Some(project_id) = project_id {
+            query = query.bind(project_id);
+        }
+
+        let rows = query
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| std::format!("Embedding space query failed: {:?}", e))?;
+
+        std::result::Result::Ok(rows
+            .iter()
+            .map(|row| crate::ports::artifact_repository_port::EmbeddingSpace {
+                model: sqlx::Row::get(row, "embedding_model"),
+                dimension: sqlx::Row::get::<i64, _>(row, "embedding_dimension") as usize,
+                artifacts: sqlx::Row::get::<i64, _>(row, "artifacts") as usize,
+            })
+            .collect())
+    }
+
+    /// Removes all stored embeddings and recreates the vector index for
+    /// `dimension`-sized vectors.
+    ///
+    /// A sqlite-vec `vec0` table has a fixed dimension, so it is dropped and
+    /// created again; a plain `artifacts_vec` table (as tests create) is just
+    /// emptied, and a missing one is left alone. Every artifact's recorded model and dimension are
+    /// cleared until its embedding is saved again.
+    ///
+    /// # Arguments
+    ///
+    /// * `dimension` - Dimension of the embeddings about to be saved
+    pub async fn reset_embedding_index_async(&self, dimension: usize) -> std::result::Result<(), std::string::String> {
+        let table_sql: std::option::Option<String> = sqlx::query_scalar(
+            "SELECT sql FROM sqlite_master WHERE name = 'artifacts_vec'"
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| std::format!("Failed to inspect artifacts_vec: {:?}", e))?;
+
+        if table_sql.as_deref().is_some_and(|sql| sql.contains("vec0")) {
+            sqlx::query("DROP TABLE artifacts_vec")
+                .execute(&self.pool)
+                .await
+                .map_err(|e| std::format!("Failed to drop artifacts_vec: {:?}", e))?;
+            sqlx::query(&std::format!(
+                "CREATE VIRTUAL TABLE artifacts_vec USING vec0(
+                    artifact_id TEXT PRIMARY KEY,
+                    embedding FLOAT[{}]
+                )",
+                dimension
+            ))
+            .execute(&self.pool)
+            .await
+            .map_err(|e| std::format!("Failed to create {}-dimensional artifacts_vec: {:?}", dimension, e))?;
+        } else if table_sql.is_some() {
+            sqlx::query("DELETE FROM artifacts_vec")
+                .execute(&self.pool)
+                .await
+                .map_err(|e| std::format!("Failed to clear artifacts_vec: {:?}", e))?;
+        }
+
+        sqlx::query("UPDATE artifacts SET embedding_model = NULL, embedding_dimension = NULL")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| std::format!("Failed to clear embedding dimensions: {:?}", e))?;
+
+        std::result::Result::Ok(())
+    }
+
+    /// Deletes the vector index entries of `artifacts` and saves them with
+    /// their new embeddings, all in one transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the batch unchanged, if any write fails.
+    pub async fn replace_embeddings_async(
+        &self,
+        artifacts: std::vec::Vec<crate::domain::artifact::Artifact>,
+    ) -> std::result::Result<(), std::string::String> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| std::format!("Failed to begin transaction: {:?}", e))?;
+        let vec_index: std::option::Option<String> = sqlx::query_scalar(
+            "SELECT name FROM sqlite_master WHERE name = 'artifacts_vec'"
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| std::format!("Failed to inspect artifacts_vec: {:?}", e))?;
+
+        for artifact in artifacts {
+            if vec_index.is_some() {
+                sqlx::query("DELETE FROM artifacts_vec WHERE artifact_id = ?1")
+                    .bind(&artifact.id)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| std::format!("Failed to delete embedding of {}: {:?}", artifact.id, e))?;
+            }
+            let id = artifact.id.clone();
+            Self::write_artifact(&mut *tx, artifact)
+                .await
+                .map_err(|e| std::format!("Failed to save artifact {}: {}", id, e))?;
+        }
+
+        tx.commit().await
+            .map_err(|e| std::format!("Failed to commit re-embedded artifacts: {:?}", e))
+    }
 }
 
 // Implement HEXSER Repository trait
@@ -569,6 +919,14 @@ impl hexser::ports::repository::QueryRepository<crate::domain::artifact::Artifac
     ) -> hexser::HexResult<std::vec::Vec<crate::domain::artifact::Artifact>> {
         Self::block_on(self.find_async(filter, options))
     }
+
+    fn count(&self, filter: &Self::Filter) -> hexser::HexResult<u64> {
+        Self::block_on(self.count_async(filter)).map(|count| count as u64)
+    }
+
+    fn delete_where(&mut self, filter: &Self::Filter) -> hexser::HexResult<u64> {
+        Self::block_on(self.delete_where_async(filter))
+    }
 }
 
 // Implement ArtifactRepositoryPort with vector similarity search
@@ -579,6 +937,17 @@ impl crate::ports::artifact_repository_port::ArtifactRepositoryPort for SqliteAr
         limit: usize,
         threshold: std::option::Option<f32>,
         project_id: std::option::Option<String>,
+    ) -> std::result::Result<std::vec::Vec<crate::ports::artifact_repository_port::SimilarArtifact>, std::string::String> {
+        let filter = crate::ports::artifact_repository_port::ArtifactSearchFilter::default().with_project(project_id);
+        self.find_similar_filtered(query_embedding, limit, threshold, &filter)
+    }
+
+    fn find_similar_filtered(
+        &self,
+        query_embedding: &[f32],
+        limit: usize,
+        threshold: std::option::Option<f32>,
+        filter: &crate::ports::artifact_repository_port::ArtifactSearchFilter,
     ) -> std::result::Result<std::vec::Vec<crate::ports::artifact_repository_port::SimilarArtifact>, std::string::String> {
         Self::block_on(async {
             // Serialize query embedding to JSON
@@ -589,17 +958,15 @@ impl crate::ports::artifact_repository_port::ArtifactRepositoryPort for SqliteAr
             // Note: We don't select v.embedding because virtual tables return it as BLOB,
             // and we don't need the embedding vector in search results (only distance)
             let mut query_str = String::from(
-                "SELECT a.id, a.project_id, a.source_id, a.source_type, a.content, a.metadata, a.created_at,
+                "SELECT a.id, a.project_id, a.source_id, a.source_type, a.content, a.metadata, a.created_at, a.embedding_model, a.embedding_dimension,
                         vec_distance_cosine(v.embedding, ?1) as distance
                  FROM artifacts a
                  JOIN artifacts_vec v ON a.id = v.artifact_id"
             );
 
-            let mut bind_idx = 2;
-            if project_id.is_some() {
-                query_str.push_str(&std::format!(" WHERE a.project_id = ?{}", bind_idx));
-                bind_idx += 1;
-            }
+            // Metadata filters narrow the rows before they are ranked
+            let (where_clause, filter_values) = Self::search_filter_clause(filter, 2);
+            query_str.push_str(&where_clause);
 
             query_str.push_str(" ORDER BY distance ASC");
             query_str.push_str(&std::format!(" LIMIT {}", limit));
@@ -610,8 +977,8 @@ impl crate::ports::artifact_repository_port::ArtifactRepositoryPort for SqliteAr
             // Execute query with bindings
             let mut query = sqlx::query(&query_str);
             query = query.bind(&query_json);
-            if let std::option::Option::Some(proj_id) = &project_id {
-                query = query.bind(proj_id);
+            for value in &filter_values {
+                query = query.bind(value);
             }
 
             let rows = query
@@ -644,6 +1011,21 @@ impl crate::ports::artifact_repository_port::ArtifactRepositoryPort for SqliteAr
             std::result::Result::Ok(results)
         })
     }
+
+    fn embedding_spaces(
+        &self,
+        project_id: std::option::Option<String>,
+    ) -> std::result::Result<std::vec::Vec<crate::ports::artifact_repository_port::EmbeddingSpace>, std::string::String> {
+        Self::block_on(self.embedding_spaces_async(project_id.as_deref()))
+    }
+
+    fn reset_embedding_index(&mut self, dimension: usize) -> std::result::Result<(), std::string::String> {
+        Self::block_on(self.reset_embedding_index_async(dimension))
+    }
+
+    fn replace_embeddings(&mut self, artifacts: std::vec::Vec<crate::domain::artifact::Artifact>) -> std::result::Result<(), std::string::String> {
+        Self::block_on(self.replace_embeddings_async(artifacts))
+    }
 }
 
 #[cfg(test)]
@@ -653,12 +1035,39 @@ mod tests {
     use hexser::ports::repository::QueryRepository;
     use crate::ports::artifact_repository_port::ArtifactRepositoryPort;
 
+    /// Connects to an in-memory database with a plain `artifacts_vec` table standing in for sqlite-vec.
+    async fn in_memory_adapter() -> SqliteArtifactAdapter {
+        let adapter = SqliteArtifactAdapter::connect_and_init("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE IF NOT EXISTS artifacts_vec (artifact_id TEXT PRIMARY KEY, embedding TEXT)")
+            .execute(adapter.pool())
+            .await
+            .unwrap();
+        adapter
+    }
+
     #[tokio::test]
-    async fn test_save_and_find_artifact() {
+    async fn test_in_memory_database_has_no_stand_in_vector_index() {
+        // Test: Validates connect_and_init leaves in-memory databases without an artifacts_vec table, and resets skip it.
+        // Justification: A plain table created outside tests would let vector code paths pass without sqlite-vec.
         let adapter = SqliteArtifactAdapter::connect_and_init("sqlite::memory:")
             .await
             .unwrap();
 
+        let tables: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM sqlite_master WHERE name = 'artifacts_vec'")
+            .fetch_one(adapter.pool())
+            .await
+            .unwrap();
+
+        assert_eq!(tables, 0);
+        adapter.reset_embedding_index_async(4).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_save_and_find_artifact() {
+        let adapter = in_memory_adapter().await;
+
         let artifact = crate::domain::artifact::Artifact::new(
             String::from("proj-1"),
             String::from("prd-123"),
@@ -677,6 +1086,336 @@ mod tests {
         assert_eq!(found.unwrap().content, "Test content for RAG system");
     }
 
+    #[tokio::test]
+    async fn test_paginated_find_is_stable_across_pages() {
+        // Test: Validates consecutive pages cover every artifact exactly once.
+        // Justification: Paging must not duplicate or skip rows, even with equal timestamps.
+        let adapter = in_memory_adapter().await;
+        let created_at = chrono::Utc::now();
+        for i in 0..7 {
+            let mut artifact = crate::domain::artifact::Artifact::new(
+                String::from("proj-1"),
+                std::format!("src-{}", i),
+                crate::domain::artifact::ArtifactType::File,
+                std::format!("Chunk {}", i),
+                vec![0.1, 0.2],
+                Option::None,
+            );
+            artifact.created_at = created_at;
+            adapter.save_async(artifact).await.unwrap();
+        }
+
+        let filter = crate::ports::artifact_repository_port::ArtifactFilter::ByProjectId(String::from("proj-1"));
+        let mut seen: std::vec::Vec<String> = std::vec::Vec::new();
+        for page in 0..3u64 {
+            let opts = hexser::ports::repository::FindOptions {
+                sort: Option::Some(vec![hexser::ports::repository::Sort {
+                    key: crate::ports::artifact_repository_port::ArtifactSortKey::CreatedAt,
+                    direction: hexser::ports::repository::Direction::Desc,
+                }]),
+                limit: Option::Some(3),
+                offset: Option::Some(page * 3),
+            };
+            let artifacts = adapter.find_async(&filter, opts).await.unwrap();
+            seen.extend(artifacts.into_iter().map(|a| a.id));
+        }
+
+        let mut unique = seen.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(seen.len(), 7);
+        assert_eq!(unique.len(), 7);
+        assert_eq!(adapter.count_async(&filter).await.unwrap(), 7);
+    }
+
+    #[tokio::test]
+    async fn test_offset_beyond_end_returns_empty_page() {
+        // Test: Validates an offset past the last row yields an empty result, not an error.
+        // Justification: Paging past the end is a normal CLI interaction.
+        let adapter = in_memory_adapter().await;
+        let artifact = crate::domain::artifact::Artifact::new(
+            String::from("proj-1"),
+            String::from("src-1"),
+            crate::domain::artifact::ArtifactType::PRD,
+            String::from("Only artifact"),
+            vec![0.1],
+            Option::None,
+        );
+        adapter.save_async(artifact).await.unwrap();
+
+        let opts = hexser::ports::repository::FindOptions {
+            sort: Option::None,
+            limit: Option::Some(20),
+            offset: Option::Some(40),
+        };
+        let page = adapter
+            .find_async(&crate::ports::artifact_repository_port::ArtifactFilter::All, opts)
+            .await
+            .unwrap();
+
+        assert!(page.is_empty());
+        assert_eq!(
+            adapter.count_async(&crate::ports::artifact_repository_port::ArtifactFilter::All).await.unwrap(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_removes_artifact_and_embedding() {
+        // Test: Validates deleting an artifact also removes its vector index entry.
+        // Justification: Orphaned embeddings would keep surfacing deleted content in search.
+        let adapter = in_memory_adapter().await;
+        let artifact = crate::domain::artifact::Artifact::new(
+            String::from("proj-1"),
+            String::from("src-1"),
+            crate::domain::artifact::ArtifactType::File,
+            String::from("Stale knowledge"),
+            vec![0.1, 0.2],
+            Option::None,
+        );
+        let id = artifact.id.clone();
+        adapter.save_async(artifact).await.unwrap();
+
+        let deleted = adapter
+            .delete_where_async(&crate::ports::artifact_repository_port::ArtifactFilter::ById(id.clone()))
+            .await
+            .unwrap();
+        let remaining_vec: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM artifacts_vec WHERE artifact_id = ?1")
+            .bind(&id)
+            .fetch_one(&adapter.pool)
+            .await
+            .unwrap();
+
+        assert_eq!(deleted, 1);
+        assert!(adapter
+            .find_one_async(&crate::ports::artifact_repository_port::ArtifactFilter::ById(id))
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(remaining_vec, 0);
+    }
+
+    #[tokio::test]
+    async fn test_replace_embeddings_rolls_back_the_whole_batch() {
+        // Test: Validates a batch whose last save fails keeps every artifact's previous embedding.
+        // Justification: A failure mid-batch during reembed must not leave artifacts without embeddings.
+        let adapter = in_memory_adapter().await;
+        let mut batch = std::vec::Vec::new();
+        for source in ["src-a", "src-b"] {
+            let artifact = crate::domain::artifact::Artifact::new(
+                String::from("proj-1"),
+                String::from(source),
+                crate::domain::artifact::ArtifactType::File,
+                String::from("Content"),
+                vec![0.1, 0.2],
+                Option::None,
+            );
+            adapter.save_async(artifact.clone()).await.unwrap();
+            batch.push(crate::domain::artifact::Artifact { embedding: vec![0.5, 0.6, 0.7], ..artifact });
+        }
+        let embedding_of = |id: String| {
+            let pool = adapter.pool.clone();
+            async move {
+                sqlx::query_scalar::<_, String>("SELECT embedding FROM artifacts_vec WHERE artifact_id = ?1")
+                    .bind(id)
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap()
+            }
+        };
+
+        sqlx::query(&std::format!(
+            "CREATE TRIGGER fail_second BEFORE INSERT ON artifacts WHEN NEW.id = '{}' BEGIN SELECT RAISE(ABORT, 'disk full'); END",
+            batch[1].id
+        ))
+        .execute(&adapter.pool)
+        .await
+        .unwrap();
+        assert!(adapter.replace_embeddings_async(batch.clone()).await.is_err());
+        assert_eq!(embedding_of(batch[0].id.clone()).await, "[0.1,0.2]");
+
+        sqlx::query("DROP TRIGGER fail_second").execute(&adapter.pool).await.unwrap();
+        adapter.replace_embeddings_async(batch.clone()).await.unwrap();
+        assert_eq!(embedding_of(batch[0].id.clone()).await, "[0.5,0.6,0.7]");
+        assert_eq!(embedding_of(batch[1].id.clone()).await, "[0.5,0.6,0.7]");
+    }
+
+    #[tokio::test]
+    async fn test_delete_without_vector_index_removes_artifacts() {
+        // Test: Validates deleting still works when the artifacts_vec index does not exist.
+        // Justification: Without sqlite-vec the index is never created, and purge used to fail on the missing table.
+        let adapter = in_memory_adapter().await;
+        let artifact = crate::domain::artifact::Artifact::new(
+            String::from("proj-1"),
+            String::from("src-1"),
+            crate::domain::artifact::ArtifactType::File,
+            String::from("Stale knowledge"),
+            vec![0.1, 0.2],
+            Option::None,
+        );
+        adapter.save_async(artifact).await.unwrap();
+        sqlx::query("DROP TABLE artifacts_vec").execute(&adapter.pool).await.unwrap();
+
+        let deleted = adapter
+            .delete_where_async(&crate::ports::artifact_repository_port::ArtifactFilter::ByProjectId(String::from("proj-1")))
+            .await
+            .unwrap();
+
+        assert_eq!(deleted, 1);
+        assert_eq!(
+            adapter.count_async(&crate::ports::artifact_repository_port::ArtifactFilter::All).await.unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_by_project_scopes_to_one_project() {
+        // Test: Validates purging a project leaves other projects' artifacts and embeddings intact.
+        // Justification: Purge must never cross project boundaries.
+        let adapter = in_memory_adapter().await;
+        for project in ["proj-a", "proj-a", "proj-b"] {
+            let artifact = crate::domain::artifact::Artifact::new(
+                String::from(project),
+                String::from("src"),
+                crate::domain::artifact::ArtifactType::PRD,
+                String::from("Content"),
+                vec![0.3],
+                Option::None,
+            );
+            adapter.save_async(artifact).await.unwrap();
+        }
+
+        let deleted = adapter
+            .delete_where_async(&crate::ports::artifact_repository_port::ArtifactFilter::ByProjectId(String::from("proj-a")))
+            .await
+            .unwrap();
+        let remaining_vec: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM artifacts_vec")
+            .fetch_one(&adapter.pool)
+            .await
+            .unwrap();
+
+        assert_eq!(deleted, 2);
+        assert_eq!(
+            adapter.count_async(&crate::ports::artifact_repository_port::ArtifactFilter::ByProjectId(String::from("proj-a"))).await.unwrap(),
+            0
+        );
+        assert_eq!(
+            adapter.count_async(&crate::ports::artifact_repository_port::ArtifactFilter::ByProjectId(String::from("proj-b"))).await.unwrap(),
+            1
+        );
+        assert_eq!(remaining_vec, 1);
+    }
+
+    #[tokio::test]
+    async fn test_backfill_citation_metadata_for_legacy_rows() {
+        // Test: Validates artifacts stored without citation fields gain a source reference.
+        // Justification: Search results over pre-existing knowledge bases must still be attributable.
+        let adapter = in_memory_adapter().await;
+        for (id, source_id, metadata) in [
+            ("legacy-file", "docs/guide.md", Option::None),
+            ("legacy-web", "https://example.com/faq", Option::None),
+            ("legacy-pdf", "spec.pdf", Option::Some(r#"{"page":3}"#)),
+        ] {
+            sqlx::query(
+                "INSERT INTO artifacts (id, project_id, source_id, source_type, content, metadata, created_at)
+                 VALUES (?1, 'proj', ?2, 'File', 'text', ?3, '2025-01-01T00:00:00Z')"
+            )
+            .bind(id)
+            .bind(source_id)
+            .bind(metadata)
+            .execute(&adapter.pool)
+            .await
+            .unwrap();
+        }
+
+        let updated = adapter.backfill_citation_metadata().await.unwrap();
+        let mut found = std::vec::Vec::new();
+        for id in ["legacy-file", "legacy-web", "legacy-pdf"] {
+            let filter = crate::ports::artifact_repository_port::ArtifactFilter::ById(String::from(id));
+            found.push(adapter.find_one_async(&filter).await.unwrap().unwrap());
+        }
+        let (file, web, pdf) = (&found[0], &found[1], &found[2]);
+
+        assert!(updated > 0);
+        assert!(file.metadata.as_ref().unwrap().contains("docs/guide.md"));
+        assert_eq!(web.citation(), "https://example.com/faq");
+        assert_eq!(pdf.page_number, Option::Some(3));
+        assert_eq!(pdf.citation(), "spec.pdf, page 3");
+        assert_eq!(adapter.backfill_citation_metadata().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_embedding_spaces_group_by_model_and_dimension() {
+        // Test: Validates stored model/dimension pairs are reported per project, legacy vectors get a backfilled dimension, and a reset clears them.
+        // Justification: Search validation and re-embedding both depend on knowing what vectors are stored.
+        let adapter = in_memory_adapter().await;
+        for (project, model, embedding) in [
+            ("proj-a", "nomic-embed-text", vec![0.1, 0.2, 0.3]),
+            ("proj-a", "nomic-embed-text", vec![0.3, 0.2, 0.1]),
+            ("proj-b", "all-minilm", vec![0.5, 0.5]),
+        ] {
+            let artifact = crate::domain::artifact::Artifact::new(
+                String::from(project),
+                String::from("src"),
+                crate::domain::artifact::ArtifactType::File,
+                String::from("Content"),
+                embedding,
+                Option::None,
+            )
+            .with_embedding_model(Option::Some(String::from(model)));
+            adapter.save_async(artifact).await.unwrap();
+        }
+        sqlx::query("INSERT INTO artifacts (id, project_id, source_id, source_type, content, created_at) VALUES ('legacy', 'proj-b', 'src', 'File', 'old', '2025-01-01T00:00:00Z')")
+            .execute(&adapter.pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO artifacts_vec (artifact_id, embedding) VALUES ('legacy', '[0.1,0.2,0.3,0.4]')")
+            .execute(&adapter.pool)
+            .await
+            .unwrap();
+        adapter.backfill_embedding_dimension().await;
+
+        let all = adapter.embedding_spaces_async(Option::None).await.unwrap();
+        let proj_b = adapter.embedding_spaces_async(Option::Some("proj-b")).await.unwrap();
+
+        assert_eq!(all[0].model.as_deref(), Option::Some("nomic-embed-text"));
+        assert_eq!((all[0].dimension, all[0].artifacts), (3, 2));
+        assert_eq!(proj_b.len(), 2);
+        assert!(proj_b.iter().any(|space| space.model.is_none() && space.dimension == 4));
+        assert!(proj_b.iter().any(|space| space.model_name() == "all-minilm" && space.dimension == 2));
+
+        adapter.reset_embedding_index_async(5).await.unwrap();
+        assert!(adapter.embedding_spaces_async(Option::None).await.unwrap().is_empty());
+        assert_eq!(adapter.count_async(&crate::ports::artifact_repository_port::ArtifactFilter::All).await.unwrap(), 4);
+    }
+
+    #[test]
+    fn test_search_filter_clause_combines_filters_with_and() {
+        // Test: Validates each filter becomes one AND-ed condition, values within a filter are OR-ed, and binds start after the query embedding.
+        // Justification: The filters must narrow the vector scan itself, and a misnumbered placeholder would bind the wrong value.
+        let filter = crate::ports::artifact_repository_port::ArtifactSearchFilter::default()
+            .with_project(Option::Some(String::from("proj-1")))
+            .with_source_type(crate::domain::artifact::ArtifactType::File)
+            .with_source_type(crate::domain::artifact::ArtifactType::WebResearch)
+            .with_extension("md")
+            .with_language("rust");
+
+        let (clause, values) = SqliteArtifactAdapter::search_filter_clause(&filter, 2);
+
+        assert_eq!(
+            clause,
+            " WHERE a.project_id = ?2 AND a.source_type IN (?3, ?4) \
+             AND (substr(lower(a.source_id), -length(?5)) = ?5) \
+             AND (substr(lower(a.source_id), -length(?6)) = ?6)"
+        );
+        assert_eq!(values, ["proj-1", "File", "WebResearch", ".md", ".rs"]);
+
+        let empty = crate::ports::artifact_repository_port::ArtifactSearchFilter::default();
+        assert_eq!(SqliteArtifactAdapter::search_filter_clause(&empty, 2), (String::new(), std::vec::Vec::new()));
+        let unknown = crate::ports::artifact_repository_port::ArtifactSearchFilter::default().with_language("klingon");
+        assert_eq!(SqliteArtifactAdapter::search_filter_clause(&unknown, 2).0, " WHERE 0");
+    }
+
     #[tokio::test]
     #[ignore] // Ignored: Vector search requires sqlite-vec extension not available in :memory: databases
     async fn test_find_similar() {
@@ -5,6 +5,7 @@
 //! including management of the persona_tools junction table for tool enablement.
 //!
 //! Revision History
+//! - 2026-10-17T00:10:00Z @AI: Persist tool_call_limits in persona_tools.max_calls.
 //! - 2025-11-26T09:20:00Z @AI: Add project_id field to all persona SQL queries for project-scoped persona support.
 //! - 2025-11-26T08:10:00Z @AI: Initial Persona repository implementation for Phase 3 persona management.
 
@@ -66,10 +67,11 @@ impl hexser::ports::Repository<crate::domain::persona::Persona> for crate::adapt
             // Insert new persona_tools entries
             for tool_id in &entity.enabled_tools {
                 sqlx::query(
-                    "INSERT INTO persona_tools (persona_id, tool_id, enabled) VALUES (?1, ?2, 1)"
+                    "INSERT INTO persona_tools (persona_id, tool_id, enabled, max_calls) VALUES (?1, ?2, 1, ?3)"
                 )
                 .bind(&entity.id)
                 .bind(tool_id)
+                .bind(entity.tool_call_limit(tool_id))
                 .execute(self.pool())
                 .await
                 .map_err(|e| {
@@ -304,7 +306,7 @@ async fn row_to_persona(
         .with_timezone(&chrono::Utc);
 
     // Load enabled tools from persona_tools junction table
-    let tool_rows = sqlx::query("SELECT tool_id FROM persona_tools WHERE persona_id = ?1 AND enabled = 1 ORDER BY tool_id")
+    let tool_rows = sqlx::query("SELECT tool_id, max_calls FROM persona_tools WHERE persona_id = ?1 AND enabled = 1 ORDER BY tool_id")
         .bind(&id)
         .fetch_all(pool)
         .await
@@ -318,6 +320,13 @@ async fn row_to_persona(
         })?;
 
     let enabled_tools: std::vec::Vec<String> = tool_rows.iter().map(|r| sqlx::Row::get(r, 0)).collect();
+    let mut tool_call_limits = std::collections::HashMap::new();
+    for r in &tool_rows {
+        let max_calls: std::option::Option<u32> = sqlx::Row::get(r, 1);
+        if let std::option::Option::Some(max_calls) = max_calls {
+            tool_call_limits.insert(sqlx::Row::get::<String, _>(r, 0), max_calls);
+        }
+    }
 
     std::result::Result::Ok(std::option::Option::Some(crate::domain::persona::Persona {
         id,
@@ -328,6 +337,7 @@ async fn row_to_persona(
         llm_provider,
         llm_model,
         enabled_tools,
+        tool_call_limits,
         is_default,
         created_at,
         updated_at,
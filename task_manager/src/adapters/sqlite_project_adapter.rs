@@ -11,6 +11,9 @@
 //! prd_ids list field.
 //!
 //! Revision History
+//! - 2026-10-17T05:50:00Z @AI: Add connect_and_init_with to size the pool from settings, with WAL and a busy timeout.
+//! - 2026-10-17T05:30:00Z @AI: Create and upgrade the projects table through versioned migrations.
+//! - 2026-10-16T16:30:00Z @AI: Persist project settings in a settings_json column with migration.
 //! - 2025-11-30T20:00:00Z @AI: Add ALTER TABLE migration for prd_ids_json column. Handles databases created by SqliteTaskAdapter that don't have this column.
 //! - 2025-11-24T05:00:00Z @AI: Initial SqliteProjectAdapter implementation for Phase 1 TUI project architecture.
 
@@ -21,41 +24,81 @@ pub struct SqliteProjectAdapter {
 }
 
 impl SqliteProjectAdapter {
+    /// Scope under which this adapter's schema versions are recorded in `schema_migrations`.
+    const MIGRATION_SCOPE: &'static str = "projects";
+
+    /// Schema history of the projects table. Append new migrations; never edit shipped ones.
+    const MIGRATIONS: &'static [crate::infrastructure::sqlite_migrations::migration::Migration] = &[
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 1,
+            description: "Create projects table",
+            steps: &[crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute(
+                "CREATE TABLE IF NOT EXISTS projects (
+                    id TEXT PRIMARY KEY,
+                    name TEXT NOT NULL,
+                    description TEXT NULL,
+                    created_at TEXT NOT NULL
+                )",
+            )],
+        },
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 2,
+            description: "Add prd_ids_json",
+            // Databases created by SqliteTaskAdapter may predate this column
+            steps: &[crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn {
+                table: "projects",
+                column: "prd_ids_json",
+                definition: "TEXT NULL",
+            }],
+        },
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 3,
+            description: "Add project settings",
+            steps: &[crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn {
+                table: "projects",
+                column: "settings_json",
+                definition: "TEXT NULL",
+            }],
+        },
+    ];
+
     /// Creates a new adapter from an existing SQLite pool.
     pub fn new(pool: sqlx::Pool<sqlx::Sqlite>) -> Self {
         SqliteProjectAdapter { pool }
     }
 
     /// Asynchronously connects to the provided database URL and ensures the schema exists.
+    ///
+    /// Uses the default pool settings; see `connect_and_init_with`.
     pub async fn connect_and_init(database_url: &str) -> std::result::Result<Self, std::string::String> {
-        let pool = sqlx::sqlite::SqlitePoolOptions::new()
-            .max_connections(1)
-            .connect(database_url)
-            .await
-            .map_err(|e| std::format!("Failed to connect SQLite: {:?}", e))?;
-
-        // Ensure projects table exists
-        sqlx::query(
-            "CREATE TABLE IF NOT EXISTS projects (
-                id TEXT PRIMARY KEY,
-                name TEXT NOT NULL,
-                description TEXT NULL,
-                created_at TEXT NOT NULL,
-                prd_ids_json TEXT NULL
-            )"
+        Self::connect_and_init_with(
+            database_url,
+            &crate::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings::default(),
         )
-        .execute(&pool)
         .await
-        .map_err(|e| std::format!("Failed to create projects schema: {:?}", e))?;
+    }
+
+    /// Connects with a pool sized by `settings` and ensures the schema exists.
+    pub async fn connect_and_init_with(
+        database_url: &str,
+        settings: &crate::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings,
+    ) -> std::result::Result<Self, std::string::String> {
+        let pool = crate::infrastructure::sqlite_pool::connector::connect(database_url, settings, std::option::Option::None).await?;
 
-        // Migration: Add prd_ids_json column if missing (for databases created by SqliteTaskAdapter)
-        let _ = sqlx::query("ALTER TABLE projects ADD COLUMN prd_ids_json TEXT NULL")
-            .execute(&pool)
-            .await; // Ignore error if column already exists
+        Self::migrate(&pool).await?;
 
         std::result::Result::Ok(SqliteProjectAdapter { pool })
     }
 
+    /// Applies the pending schema migrations of this adapter.
+    ///
+    /// # Returns
+    ///
+    /// The versions applied; empty when the schema was already current.
+    pub async fn migrate(pool: &sqlx::Pool<sqlx::Sqlite>) -> std::result::Result<std::vec::Vec<i64>, std::string::String> {
+        crate::infrastructure::sqlite_migrations::migrator::run_pending(pool, Self::MIGRATION_SCOPE, Self::MIGRATIONS).await
+    }
+
     fn block_on<T>(fut: impl std::future::Future<Output = T>) -> T {
         // Build a current-thread runtime for synchronous trait methods.
         let rt = tokio::runtime::Builder::new_current_thread()
@@ -76,20 +119,28 @@ impl SqliteProjectAdapter {
             })?)
         };
 
+        let settings_json = serde_json::to_string(&entity.settings).map_err(|e| {
+            hexser::error::hex_error::Hexserror::Adapter(
+                hexser::error::adapter_error::mapping_failure(std::format!("Failed to serialize settings to JSON: {:?}", e).as_str())
+            )
+        })?;
+
         let created_at = entity.created_at.to_rfc3339();
 
         sqlx::query(
-            "INSERT INTO projects (id, name, description, created_at, prd_ids_json)
-             VALUES (?1, ?2, ?3, ?4, ?5)
+            "INSERT INTO projects (id, name, description, created_at, prd_ids_json, settings_json)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
              ON CONFLICT(id) DO UPDATE SET
                name=excluded.name, description=excluded.description,
-               created_at=excluded.created_at, prd_ids_json=excluded.prd_ids_json"
+               created_at=excluded.created_at, prd_ids_json=excluded.prd_ids_json,
+               settings_json=excluded.settings_json"
         )
         .bind(entity.id)
         .bind(entity.name)
         .bind(entity.description)
         .bind(created_at)
         .bind(prd_ids_json)
+        .bind(settings_json)
         .execute(&self.pool)
         .await
         .map_err(|e| {
@@ -108,6 +159,7 @@ impl SqliteProjectAdapter {
         let description: Option<String> = sqlx::Row::get(&row, "description");
         let created_at_str: String = sqlx::Row::get(&row, "created_at");
         let prd_ids_json_opt: Option<String> = sqlx::Row::get(&row, "prd_ids_json");
+        let settings_json_opt: Option<String> = sqlx::Row::get(&row, "settings_json");
 
         let created_at = chrono::DateTime::parse_from_rfc3339(&created_at_str)
             .map_err(|e| {
@@ -132,12 +184,26 @@ impl SqliteProjectAdapter {
             std::option::Option::None => std::vec::Vec::new(),
         };
 
+        let settings = match settings_json_opt {
+            std::option::Option::Some(json_str) => {
+                serde_json::from_str(&json_str).map_err(|e| {
+                    hexser::error::hex_error::Hexserror::Adapter(
+                        hexser::error::adapter_error::mapping_failure(
+                            std::format!("Failed to deserialize settings: {:?}", e).as_str()
+                        )
+                    )
+                })?
+            }
+            std::option::Option::None => crate::domain::project_settings::ProjectSettings::default(),
+        };
+
         std::result::Result::Ok(crate::domain::project::Project {
             id,
             name,
             description,
             created_at,
             prd_ids,
+            settings,
         })
     }
 
@@ -147,7 +213,7 @@ impl SqliteProjectAdapter {
     ) -> hexser::HexResult<std::option::Option<crate::domain::project::Project>> {
         match filter {
             crate::ports::project_repository_port::ProjectFilter::ById(id) => {
-                let row = sqlx::query("SELECT id, name, description, created_at, prd_ids_json FROM projects WHERE id = ?1")
+                let row = sqlx::query("SELECT id, name, description, created_at, prd_ids_json, settings_json FROM projects WHERE id = ?1")
                     .bind(id)
                     .fetch_optional(&self.pool)
                     .await
@@ -179,7 +245,7 @@ impl SqliteProjectAdapter {
         filter: &crate::ports::project_repository_port::ProjectFilter,
         opts: hexser::ports::repository::FindOptions<crate::ports::project_repository_port::ProjectSortKey>,
     ) -> hexser::HexResult<std::vec::Vec<crate::domain::project::Project>> {
-        let mut query_str = String::from("SELECT id, name, description, created_at, prd_ids_json FROM projects");
+        let mut query_str = String::from("SELECT id, name, description, created_at, prd_ids_json, settings_json FROM projects");
         let mut bind_values: std::vec::Vec<String> = std::vec::Vec::new();
 
         match filter {
@@ -304,10 +370,11 @@ mod tests {
         // Test: Validates basic save and retrieval operations.
         // Justification: Core repository functionality must persist and retrieve entities.
         let adapter = setup_test_db().await;
-        let project = crate::domain::project::Project::new(
+        let mut project = crate::domain::project::Project::new(
             String::from("test-project"),
             std::option::Option::Some(String::from("Test description")),
         );
+        project.settings.default_priority = std::option::Option::Some(crate::domain::priority::Priority::Medium);
         let project_id = project.id.clone();
 
         adapter.save_async(project.clone()).await.expect("Failed to save project");
@@ -320,6 +387,7 @@ mod tests {
         std::assert_eq!(found_project.id, project_id);
         std::assert_eq!(found_project.name, "test-project");
         std::assert_eq!(found_project.description, std::option::Option::Some(String::from("Test description")));
+        std::assert_eq!(found_project.settings, project.settings);
     }
 
     #[tokio::test]
@@ -12,6 +12,22 @@
 //! enhancement and comprehension test lists.
 //!
 //! Revision History
+//! - 2026-10-17T21:00:00Z @AI: Add save_in_transaction so callers can save tasks atomically with other writes.
+//! - 2026-10-17T16:50:00Z @AI: Persist task estimates in an estimate column (migration 14).
+//! - 2026-10-17T16:30:00Z @AI: Persist informational task links in a links_json column (migration 13).
+//! - 2026-10-17T11:50:00Z @AI: Add idempotency_key column with a unique index (migration 12) and the ByIdempotencyKey filter.
+//! - 2026-10-17T11:30:00Z @AI: Add find_after_async() for keyset pagination and override TaskRepositoryPort::find_after() with it. Base SELECT per filter factored into select_sql().
+//! - 2026-10-17T10:10:00Z @AI: Sort by priority rank for TaskSortKey::Priority.
+//! - 2026-10-17T05:50:00Z @AI: Add connect_and_init_with to size the pool from settings, with WAL and a busy timeout.
+//! - 2026-10-17T05:30:00Z @AI: Replace ad hoc CREATE/ALTER statements with versioned migrations recorded in schema_migrations.
+//! - 2026-10-17T03:50:00Z @AI: Persist the source transcript utterance in a source_utterance_json column with migration.
+//! - 2026-10-17T03:10:00Z @AI: Persist task time tracking in started_at and time_spent_seconds columns with migrations.
+//! - 2026-10-17T00:10:00Z @AI: Add persona_tools.max_calls column (with migration) for per-conversation tool call limits.
+//! - 2026-10-16T23:50:00Z @AI: Register chat agent tools (search_tasks, get_task_details, search_artifacts) in agent_tools so personas can allow them.
+//! - 2026-10-16T19:50:00Z @AI: Wrap save/find operations in `db` tracing spans with latency_ms.
+//! - 2026-10-16T15:50:00Z @AI: Persist task checklist items in a checklist_json column with migration.
+//! - 2026-10-16T12:10:00Z @AI: Persist task priority, tags, and revision history. Added priority, tags_json, and revisions_json columns with migrations, updated SELECT/INSERT queries, and row_to_task() mapping.
+//! - 2026-10-16T11:10:00Z @AI: Add source_section column for incremental PRD re-parsing. Added source_section TEXT NULL to tasks table schema, migration for existing databases, updated SELECT/INSERT queries, and row_to_task() mapping.
 //! - 2025-11-30T21:30:00Z @AI: Add sort_order column for manual task prioritization. Added sort_order INTEGER NULL to tasks table schema, migration for existing databases, updated SELECT/INSERT queries, and row_to_task() mapping. Enables drag-and-drop style reordering of tasks in TODO column.
 //! - 2025-11-30T20:00:00Z @AI: Fix projects table schema mismatch. Added prd_ids_json column to projects table schema for SqliteProjectAdapter compatibility. Added ALTER TABLE migration to add column to existing databases that were created without it.
 //! - 2025-11-29T18:00:00Z @AI: Add prds table to schema initialization. Previously prds table was created on-demand during PRD processing, causing PRDs to not persist across sessions. Now created in connect_and_init() with foreign key to projects table for proper Project→PRD→Task linkage.
@@ -43,6 +59,284 @@ pub struct SqliteTaskAdapter {
 }
 
 impl SqliteTaskAdapter {
+    /// Scope under which this adapter's schema versions are recorded in `schema_migrations`.
+    const MIGRATION_SCOPE: &'static str = "tasks";
+
+    /// Schema history of the tables this adapter owns. Append new migrations; never edit shipped ones.
+    const MIGRATIONS: &'static [crate::infrastructure::sqlite_migrations::migration::Migration] = &[
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 1,
+            description: "Create tasks table",
+            steps: &[crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute(
+                "CREATE TABLE IF NOT EXISTS tasks (
+                    id TEXT PRIMARY KEY,
+                    title TEXT NOT NULL,
+                    due_date TEXT NULL,
+                    status TEXT NOT NULL,
+                    source_transcript_id TEXT NULL,
+                    source_prd_id TEXT NULL,
+                    parent_task_id TEXT NULL,
+                    subtask_ids_json TEXT NULL,
+                    created_at TEXT NOT NULL,
+                    updated_at TEXT NOT NULL,
+                    enhancements_json TEXT NULL,
+                    comprehension_tests_json TEXT NULL,
+                    complexity INTEGER NULL,
+                    reasoning TEXT NULL,
+                    context_files_json TEXT NULL,
+                    dependencies_json TEXT NULL
+                )",
+            )],
+        },
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 2,
+            description: "Create projects and prds tables",
+            steps: &[
+                // prd_ids_json is included for SqliteProjectAdapter compatibility
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute(
+                    "CREATE TABLE IF NOT EXISTS projects (
+                        id TEXT PRIMARY KEY,
+                        name TEXT NOT NULL UNIQUE,
+                        description TEXT NOT NULL DEFAULT '',
+                        created_at TEXT NOT NULL,
+                        updated_at TEXT NOT NULL,
+                        prd_ids_json TEXT NULL
+                    )",
+                ),
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn {
+                    table: "projects",
+                    column: "prd_ids_json",
+                    definition: "TEXT NULL",
+                },
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute(
+                    "CREATE TABLE IF NOT EXISTS prds (
+                        id TEXT PRIMARY KEY,
+                        project_id TEXT NOT NULL,
+                        title TEXT NOT NULL,
+                        objectives_json TEXT NULL,
+                        tech_stack_json TEXT NULL,
+                        constraints_json TEXT NULL,
+                        raw_content TEXT NOT NULL DEFAULT '',
+                        created_at TEXT NOT NULL,
+                        FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+                    )",
+                ),
+            ],
+        },
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 3,
+            description: "Create persona tables",
+            steps: &[
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute(
+                    "CREATE TABLE IF NOT EXISTS personas (
+                        id TEXT PRIMARY KEY,
+                        project_id TEXT,
+                        name TEXT NOT NULL,
+                        role TEXT NOT NULL,
+                        description TEXT NOT NULL,
+                        llm_provider TEXT,
+                        llm_model TEXT,
+                        is_default BOOLEAN NOT NULL DEFAULT 0,
+                        created_at TEXT NOT NULL,
+                        updated_at TEXT NOT NULL,
+                        FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
+                        UNIQUE(project_id, name)
+                    )",
+                ),
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute(
+                    "CREATE TABLE IF NOT EXISTS agent_tools (
+                        id TEXT PRIMARY KEY,
+                        name TEXT NOT NULL,
+                        description TEXT NOT NULL,
+                        category TEXT NOT NULL,
+                        risk_level TEXT NOT NULL,
+                        is_default BOOLEAN NOT NULL DEFAULT 0
+                    )",
+                ),
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute(
+                    "CREATE TABLE IF NOT EXISTS persona_tools (
+                        persona_id TEXT NOT NULL,
+                        tool_id TEXT NOT NULL,
+                        enabled BOOLEAN NOT NULL DEFAULT 1,
+                        PRIMARY KEY (persona_id, tool_id),
+                        FOREIGN KEY (persona_id) REFERENCES personas(id) ON DELETE CASCADE,
+                        FOREIGN KEY (tool_id) REFERENCES agent_tools(id) ON DELETE CASCADE
+                    )",
+                ),
+            ],
+        },
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 4,
+            description: "Create artifacts and task_artifacts tables",
+            steps: &[
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute(
+                    "CREATE TABLE IF NOT EXISTS artifacts (
+                        id TEXT PRIMARY KEY,
+                        project_id TEXT NOT NULL,
+                        source_id TEXT NOT NULL,
+                        source_type TEXT NOT NULL,
+                        content TEXT NOT NULL,
+                        metadata TEXT NULL,
+                        created_at TEXT NOT NULL,
+                        FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+                    )",
+                ),
+                // Links tasks to their most relevant PRD artifact chunks via semantic similarity
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute(
+                    "CREATE TABLE IF NOT EXISTS task_artifacts (
+                        task_id TEXT NOT NULL,
+                        artifact_id TEXT NOT NULL,
+                        relevance_score REAL NOT NULL DEFAULT 0.0,
+                        created_at TEXT NOT NULL,
+                        PRIMARY KEY (task_id, artifact_id),
+                        FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE,
+                        FOREIGN KEY (artifact_id) REFERENCES artifacts(id) ON DELETE CASCADE
+                    )",
+                ),
+            ],
+        },
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 5,
+            description: "Add task description, completion summary, and agent_persona",
+            steps: &[
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn {
+                    table: "tasks",
+                    column: "description",
+                    definition: "TEXT NOT NULL DEFAULT ''",
+                },
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn {
+                    table: "tasks",
+                    column: "completion_summary",
+                    definition: "TEXT NULL",
+                },
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn {
+                    table: "tasks",
+                    column: "agent_persona",
+                    definition: "TEXT NULL",
+                },
+                // Databases from before the rename keep their data in the old assignee column
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::ExecuteIfColumn {
+                    table: "tasks",
+                    column: "assignee",
+                    sql: "UPDATE tasks SET agent_persona = assignee WHERE agent_persona IS NULL AND assignee IS NOT NULL",
+                },
+            ],
+        },
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 6,
+            description: "Add sort_order and source_section",
+            steps: &[
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn {
+                    table: "tasks",
+                    column: "sort_order",
+                    definition: "INTEGER NULL",
+                },
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn {
+                    table: "tasks",
+                    column: "source_section",
+                    definition: "TEXT NULL",
+                },
+            ],
+        },
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 7,
+            description: "Add persona tool call limits",
+            steps: &[crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn {
+                table: "persona_tools",
+                column: "max_calls",
+                definition: "INTEGER NULL",
+            }],
+        },
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 8,
+            description: "Add priority, tags, and revision history",
+            steps: &[
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn {
+                    table: "tasks",
+                    column: "priority",
+                    definition: "TEXT NULL",
+                },
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn {
+                    table: "tasks",
+                    column: "tags_json",
+                    definition: "TEXT NULL",
+                },
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn {
+                    table: "tasks",
+                    column: "revisions_json",
+                    definition: "TEXT NULL",
+                },
+            ],
+        },
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 9,
+            description: "Add checklist",
+            steps: &[crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn {
+                table: "tasks",
+                column: "checklist_json",
+                definition: "TEXT NULL",
+            }],
+        },
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 10,
+            description: "Add time tracking",
+            steps: &[
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn {
+                    table: "tasks",
+                    column: "started_at",
+                    definition: "TEXT NULL",
+                },
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn {
+                    table: "tasks",
+                    column: "time_spent_seconds",
+                    definition: "INTEGER NOT NULL DEFAULT 0",
+                },
+            ],
+        },
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 11,
+            description: "Add source utterance",
+            steps: &[crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn {
+                table: "tasks",
+                column: "source_utterance_json",
+                definition: "TEXT NULL",
+            }],
+        },
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 12,
+            description: "Add idempotency key",
+            steps: &[
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn {
+                    table: "tasks",
+                    column: "idempotency_key",
+                    definition: "TEXT NULL",
+                },
+                // NULLs are distinct in a unique index, so tasks without a key are unaffected
+                crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute(
+                    "CREATE UNIQUE INDEX IF NOT EXISTS idx_tasks_idempotency_key ON tasks(idempotency_key)",
+                ),
+            ],
+        },
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 13,
+            description: "Add task links",
+            steps: &[crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn {
+                table: "tasks",
+                column: "links_json",
+                definition: "TEXT NULL",
+            }],
+        },
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 14,
+            description: "Add task estimate",
+            steps: &[crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn {
+                table: "tasks",
+                column: "estimate",
+                definition: "REAL NULL",
+            }],
+        },
+    ];
+
     /// Creates a new adapter from an existing SQLite pool.
     pub fn new(pool: sqlx::Pool<sqlx::Sqlite>) -> Self {
         SqliteTaskAdapter { pool }
@@ -55,193 +349,37 @@ impl SqliteTaskAdapter {
 
     /// Asynchronously connects to the provided database URL and ensures the schema exists.
     ///
-    /// This method loads the sqlite-vec extension for vector similarity search support.
-    /// The extension is embedded in the binary and extracted to a temp directory at runtime,
-    /// ensuring RAG features are always available regardless of working directory.
+    /// Uses the default pool settings; see `connect_and_init_with`.
     pub async fn connect_and_init(database_url: &str) -> std::result::Result<Self, std::string::String> {
-        // Try embedded extension first (always available, bundled in binary)
-        let mut extension_paths = std::vec![];
-
-        if let std::result::Result::Ok(embedded_path) = crate::adapters::embedded_sqlite_vec::get_extension_path_for_sqlite() {
-            extension_paths.push(embedded_path);
-        }
-
-        // Fallback paths (for development or custom installations)
-        let abs_path = std::env::current_dir()
-            .ok()
-            .and_then(|p| p.join(".rigger/lib/vec0").to_str().map(|s| s.to_string()))
-            .unwrap_or_default();
-
-        extension_paths.push(std::string::String::from("vec0"));              // System-wide install
-        extension_paths.push(std::string::String::from(".rigger/lib/vec0"));  // Local install
-        extension_paths.push(abs_path);                                        // Absolute path
-
-        let mut pool = std::option::Option::None;
-
-        for ext_path in &extension_paths {
-            if ext_path.is_empty() {
-                continue;
-            }
-
-            let connect_options = database_url
-                .parse::<sqlx::sqlite::SqliteConnectOptions>()
-                .map_err(|e| std::format!("Failed to parse database URL: {:?}", e))?
-                .create_if_missing(true)
-                .optimize_on_close(false, std::option::Option::None)
-                .extension(ext_path.clone());
-
-            if let std::result::Result::Ok(p) = sqlx::sqlite::SqlitePoolOptions::new()
-                .max_connections(1)
-                .connect_with(connect_options)
-                .await
-            {
-                pool = std::option::Option::Some(p);
-                break;
-            }
-        }
-
-        let pool = match pool {
-            std::option::Option::Some(p) => p,
-            std::option::Option::None => {
-                // Fall back to connection without extension (RAG features will be disabled)
-                eprintln!("Warning: sqlite-vec extension not available. RAG features disabled.");
-                eprintln!("To enable RAG: Install vec0.dylib to .rigger/lib/ directory");
-
-                let connect_options = database_url
-                    .parse::<sqlx::sqlite::SqliteConnectOptions>()
-                    .map_err(|e| std::format!("Failed to parse database URL: {:?}", e))?
-                    .create_if_missing(true)
-                    .optimize_on_close(false, std::option::Option::None);
-
-                sqlx::sqlite::SqlitePoolOptions::new()
-                    .max_connections(1)
-                    .connect_with(connect_options)
-                    .await
-                    .map_err(|e| std::format!("Failed to connect to SQLite: {:?}", e))?
-            }
-        };
-        // Ensure schema
-        sqlx::query(
-            "CREATE TABLE IF NOT EXISTS tasks (\n                id TEXT PRIMARY KEY,\n                title TEXT NOT NULL,\n                description TEXT NOT NULL DEFAULT '',\n                agent_persona TEXT NULL,\n                due_date TEXT NULL,\n                status TEXT NOT NULL,\n                source_transcript_id TEXT NULL,\n                source_prd_id TEXT NULL,\n                parent_task_id TEXT NULL,\n                subtask_ids_json TEXT NULL,\n                created_at TEXT NOT NULL,\n                updated_at TEXT NOT NULL,\n                enhancements_json TEXT NULL,\n                comprehension_tests_json TEXT NULL,\n                complexity INTEGER NULL,\n                reasoning TEXT NULL,\n                context_files_json TEXT NULL,\n                dependencies_json TEXT NULL,\n                sort_order INTEGER NULL\n            )"
-        )
-        .execute(&pool)
-        .await
-        .map_err(|e| std::format!("Failed to create schema: {:?}", e))?;
-
-        // Add description column if it doesn't exist (migration for existing databases)
-        let _ = sqlx::query("ALTER TABLE tasks ADD COLUMN description TEXT NOT NULL DEFAULT ''")
-            .execute(&pool)
-            .await; // Ignore error if column already exists
-
-        // Add completion_summary column if it doesn't exist (migration for existing databases)
-        let _ = sqlx::query("ALTER TABLE tasks ADD COLUMN completion_summary TEXT NULL")
-            .execute(&pool)
-            .await; // Ignore error if column already exists
-
-        // Rename assignee to agent_persona (migration for existing databases)
-        // Note: SQLite doesn't support RENAME COLUMN directly in older versions,
-        // so we add the new column and copy data if old column exists
-        let _ = sqlx::query("ALTER TABLE tasks ADD COLUMN agent_persona TEXT NULL")
-            .execute(&pool)
-            .await; // Ignore error if column already exists
-        let _ = sqlx::query("UPDATE tasks SET agent_persona = assignee WHERE agent_persona IS NULL AND assignee IS NOT NULL")
-            .execute(&pool)
-            .await; // Migrate data from old column
-
-        // Add sort_order column for manual task prioritization (migration for existing databases)
-        let _ = sqlx::query("ALTER TABLE tasks ADD COLUMN sort_order INTEGER NULL")
-            .execute(&pool)
-            .await; // Ignore error if column already exists
-
-        // Create projects table (Phase 4: Project-scoped persona management)
-        // Note: prd_ids_json added for SqliteProjectAdapter compatibility
-        sqlx::query(
-            "CREATE TABLE IF NOT EXISTS projects (
-                id TEXT PRIMARY KEY,
-                name TEXT NOT NULL UNIQUE,
-                description TEXT NOT NULL DEFAULT '',
-                created_at TEXT NOT NULL,
-                updated_at TEXT NOT NULL,
-                prd_ids_json TEXT NULL
-            )"
-        )
-        .execute(&pool)
-        .await
-        .map_err(|e| std::format!("Failed to create projects table: {:?}", e))?;
-
-        // Migration: Add prd_ids_json column if missing (for existing databases)
-        let _ = sqlx::query("ALTER TABLE projects ADD COLUMN prd_ids_json TEXT NULL")
-            .execute(&pool)
-            .await; // Ignore error if column already exists
-
-        // Create prds table (Phase 4: PRD-to-Project-to-Task linkage)
-        sqlx::query(
-            "CREATE TABLE IF NOT EXISTS prds (
-                id TEXT PRIMARY KEY,
-                project_id TEXT NOT NULL,
-                title TEXT NOT NULL,
-                objectives_json TEXT NULL,
-                tech_stack_json TEXT NULL,
-                constraints_json TEXT NULL,
-                raw_content TEXT NOT NULL DEFAULT '',
-                created_at TEXT NOT NULL,
-                FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
-            )"
+        Self::connect_and_init_with(
+            database_url,
+            &crate::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings::default(),
         )
-        .execute(&pool)
         .await
-        .map_err(|e| std::format!("Failed to create prds table: {:?}", e))?;
-
-        // Create personas table (Phase 3: Persona Management)
-        sqlx::query(
-            "CREATE TABLE IF NOT EXISTS personas (
-                id TEXT PRIMARY KEY,
-                project_id TEXT,
-                name TEXT NOT NULL,
-                role TEXT NOT NULL,
-                description TEXT NOT NULL,
-                llm_provider TEXT,
-                llm_model TEXT,
-                is_default BOOLEAN NOT NULL DEFAULT 0,
-                created_at TEXT NOT NULL,
-                updated_at TEXT NOT NULL,
-                FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
-                UNIQUE(project_id, name)
-            )"
-        )
-        .execute(&pool)
-        .await
-        .map_err(|e| std::format!("Failed to create personas table: {:?}", e))?;
-
-        // Create agent_tools table (Phase 3: Persona Management)
-        sqlx::query(
-            "CREATE TABLE IF NOT EXISTS agent_tools (
-                id TEXT PRIMARY KEY,
-                name TEXT NOT NULL,
-                description TEXT NOT NULL,
-                category TEXT NOT NULL,
-                risk_level TEXT NOT NULL,
-                is_default BOOLEAN NOT NULL DEFAULT 0
-            )"
-        )
-        .execute(&pool)
-        .await
-        .map_err(|e| std::format!("Failed to create agent_tools table: {:?}", e))?;
+    }
 
-        // Create persona_tools junction table (Phase 3: Persona Management)
-        sqlx::query(
-            "CREATE TABLE IF NOT EXISTS persona_tools (
-                persona_id TEXT NOT NULL,
-                tool_id TEXT NOT NULL,
-                enabled BOOLEAN NOT NULL DEFAULT 1,
-                PRIMARY KEY (persona_id, tool_id),
-                FOREIGN KEY (persona_id) REFERENCES personas(id) ON DELETE CASCADE,
-                FOREIGN KEY (tool_id) REFERENCES agent_tools(id) ON DELETE CASCADE
-            )"
-        )
-        .execute(&pool)
-        .await
-        .map_err(|e| std::format!("Failed to create persona_tools table: {:?}", e))?;
+    /// Connects with a pool sized by `settings` and ensures the schema exists.
+    ///
+    /// This method loads the sqlite-vec extension for vector similarity search support.
+    /// The extension is embedded in the binary and extracted to a temp directory at runtime,
+    /// ensuring RAG features are always available regardless of working directory.
+    /// File databases are opened in WAL mode with the configured busy timeout.
+    /// Other adapters on the same database can share the pool via `pool()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection, a migration, or tool seeding fails.
+    pub async fn connect_and_init_with(
+        database_url: &str,
+        settings: &crate::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings,
+    ) -> std::result::Result<Self, std::string::String> {
+        let (pool, vec_loaded) = crate::infrastructure::sqlite_pool::connector::connect_with_sqlite_vec(database_url, settings).await?;
+        if !vec_loaded && !database_url.contains(":memory:") {
+            // RAG features will be disabled
+            eprintln!("Warning: sqlite-vec extension not available. RAG features disabled.");
+            eprintln!("To enable RAG: Install vec0.dylib to .rigger/lib/ directory");
+        }
+        Self::migrate(&pool).await?;
 
         // Seed agent_tools table with default tools (idempotent)
         let tool_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM agent_tools")
@@ -273,6 +411,17 @@ impl SqliteTaskAdapter {
             .map_err(|e| std::format!("Failed to seed agent_tools: {:?}", e))?;
         }
 
+        // Chat agent tools, added after the initial seed (idempotent for existing databases)
+        sqlx::query(
+            "INSERT OR IGNORE INTO agent_tools (id, name, description, category, risk_level, is_default) VALUES
+                ('search_tasks', 'Search Tasks', 'Keyword search over project tasks', 'Database', 'Safe', 0),
+                ('get_task_details', 'Get Task Details', 'Read a task with its subtasks and history', 'Database', 'Safe', 0),
+                ('search_artifacts', 'Search Artifacts', 'Semantic search over ingested knowledge artifacts', 'Research', 'Safe', 0)"
+        )
+        .execute(&pool)
+        .await
+        .map_err(|e| std::format!("Failed to register chat agent tools: {:?}", e))?;
+
         // Seed standard personas with role-appropriate tool permissions (idempotent)
         let persona_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM personas")
             .fetch_one(&pool)
@@ -411,23 +560,6 @@ impl SqliteTaskAdapter {
             ).execute(&pool).await.map_err(|e| std::format!("Failed to link architect tools: {:?}", e))?;
         }
 
-        // Create artifacts table for RAG knowledge storage (Phase 2: RAG)
-        sqlx::query(
-            "CREATE TABLE IF NOT EXISTS artifacts (
-                id TEXT PRIMARY KEY,
-                project_id TEXT NOT NULL,
-                source_id TEXT NOT NULL,
-                source_type TEXT NOT NULL,
-                content TEXT NOT NULL,
-                metadata TEXT NULL,
-                created_at TEXT NOT NULL,
-                FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
-            )"
-        )
-        .execute(&pool)
-        .await
-        .map_err(|e| std::format!("Failed to create artifacts table: {:?}", e))?;
-
         // Create artifacts_vec virtual table for vector similarity search (Phase 2: RAG)
         // Using vec0 module with 768-dimensional embeddings (nomic-embed-text)
         // This will silently fail if sqlite-vec extension is not available
@@ -440,26 +572,26 @@ impl SqliteTaskAdapter {
         .execute(&pool)
         .await;
 
-        // Create task_artifacts junction table for task-to-artifact relationships (Phase 3: RAG)
-        // Links tasks to their most relevant PRD artifact chunks via semantic similarity
-        sqlx::query(
-            "CREATE TABLE IF NOT EXISTS task_artifacts (
-                task_id TEXT NOT NULL,
-                artifact_id TEXT NOT NULL,
-                relevance_score REAL NOT NULL DEFAULT 0.0,
-                created_at TEXT NOT NULL,
-                PRIMARY KEY (task_id, artifact_id),
-                FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE,
-                FOREIGN KEY (artifact_id) REFERENCES artifacts(id) ON DELETE CASCADE
-            )"
-        )
-        .execute(&pool)
-        .await
-        .map_err(|e| std::format!("Failed to create task_artifacts table: {:?}", e))?;
-
         std::result::Result::Ok(SqliteTaskAdapter { pool })
     }
 
+    /// Applies the pending schema migrations of this adapter.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - Database to migrate
+    ///
+    /// # Returns
+    ///
+    /// The versions applied; empty when the schema was already current.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a migration fails; it is rolled back and retried on the next connect.
+    pub async fn migrate(pool: &sqlx::Pool<sqlx::Sqlite>) -> std::result::Result<std::vec::Vec<i64>, std::string::String> {
+        crate::infrastructure::sqlite_migrations::migrator::run_pending(pool, Self::MIGRATION_SCOPE, Self::MIGRATIONS).await
+    }
+
     pub(crate) fn block_on<T>(fut: impl std::future::Future<Output = T>) -> T {
         // Use the current runtime handle if available, otherwise create a new one.
         // This prevents "Cannot start a runtime from within a runtime" errors.
@@ -480,6 +612,23 @@ impl SqliteTaskAdapter {
     }
 
     pub async fn save_async(&self, entity: crate::domain::task::Task) -> hexser::HexResult<()> {
+        let span = tracing::debug_span!("db", operation = "save_task", task_id = %entity.id, latency_ms = tracing::field::Empty);
+        crate::infrastructure::telemetry::timed(span, Self::write_task(&self.pool, entity)).await
+    }
+
+    /// Saves a task inside a caller's transaction, so it commits or rolls back with the caller's other writes.
+    pub async fn save_in_transaction(
+        tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+        entity: crate::domain::task::Task,
+    ) -> hexser::HexResult<()> {
+        Self::write_task(&mut **tx, entity).await
+    }
+
+    /// Upserts a task row through `executor`; `save_async` wraps this in a `db` span.
+    async fn write_task<'e, E>(executor: E, entity: crate::domain::task::Task) -> hexser::HexResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
         let enhancements_json = match entity.enhancements {
             std::option::Option::Some(list) => {
                 std::option::Option::Some(serde_json::to_string(&list).map_err(|e| {
@@ -527,15 +676,69 @@ impl SqliteTaskAdapter {
                 )
             })?)
         };
+        let priority_str = match entity.priority {
+            std::option::Option::Some(priority) => std::option::Option::Some(serde_json::to_string(&priority).map_err(|e| {
+                hexser::error::hex_error::Hexserror::Adapter(
+                    hexser::error::adapter_error::mapping_failure(std::format!("Failed to serialize priority to JSON: {:?}", e).as_str())
+                )
+            })?),
+            std::option::Option::None => std::option::Option::None,
+        };
+        let tags_json = if entity.tags.is_empty() {
+            std::option::Option::None
+        } else {
+            std::option::Option::Some(serde_json::to_string(&entity.tags).map_err(|e| {
+                hexser::error::hex_error::Hexserror::Adapter(
+                    hexser::error::adapter_error::mapping_failure(std::format!("Failed to serialize tags to JSON: {:?}", e).as_str())
+                )
+            })?)
+        };
+        let revisions_json = if entity.revisions.is_empty() {
+            std::option::Option::None
+        } else {
+            std::option::Option::Some(serde_json::to_string(&entity.revisions).map_err(|e| {
+                hexser::error::hex_error::Hexserror::Adapter(
+                    hexser::error::adapter_error::mapping_failure(std::format!("Failed to serialize revisions to JSON: {:?}", e).as_str())
+                )
+            })?)
+        };
+        let checklist_json = if entity.checklist.is_empty() {
+            std::option::Option::None
+        } else {
+            std::option::Option::Some(serde_json::to_string(&entity.checklist).map_err(|e| {
+                hexser::error::hex_error::Hexserror::Adapter(
+                    hexser::error::adapter_error::mapping_failure(std::format!("Failed to serialize checklist to JSON: {:?}", e).as_str())
+                )
+            })?)
+        };
+        let source_utterance_json = match entity.source_utterance {
+            std::option::Option::Some(ref utterance) => std::option::Option::Some(serde_json::to_string(utterance).map_err(|e| {
+                hexser::error::hex_error::Hexserror::Adapter(
+                    hexser::error::adapter_error::mapping_failure(std::format!("Failed to serialize source utterance to JSON: {:?}", e).as_str())
+                )
+            })?),
+            std::option::Option::None => std::option::Option::None,
+        };
+        let links_json = if entity.links.is_empty() {
+            std::option::Option::None
+        } else {
+            std::option::Option::Some(serde_json::to_string(&entity.links).map_err(|e| {
+                hexser::error::hex_error::Hexserror::Adapter(
+                    hexser::error::adapter_error::mapping_failure(std::format!("Failed to serialize links to JSON: {:?}", e).as_str())
+                )
+            })?)
+        };
         let created_at = entity.created_at.to_rfc3339();
         let updated_at = entity.updated_at.to_rfc3339();
+        let started_at = entity.started_at.map(|t| t.to_rfc3339());
+        let time_spent_seconds = i64::try_from(entity.time_spent_seconds).unwrap_or(i64::MAX);
         let status_str = serde_json::to_string(&entity.status).map_err(|e| {
                     hexser::error::hex_error::Hexserror::Adapter(
                         hexser::error::adapter_error::mapping_failure(std::format!("Failed to serialize status to JSON: {:?}", e).as_str())
                     )
                 })?;
         sqlx::query(
-            "INSERT INTO tasks (id, title, description, agent_persona, due_date, status, source_transcript_id, source_prd_id, parent_task_id, subtask_ids_json, created_at, updated_at, enhancements_json, comprehension_tests_json, complexity, reasoning, context_files_json, dependencies_json, completion_summary, sort_order)\n             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20)\n             ON CONFLICT(id) DO UPDATE SET\n               title=excluded.title, description=excluded.description, agent_persona=excluded.agent_persona, due_date=excluded.due_date, status=excluded.status,\n               source_transcript_id=excluded.source_transcript_id, source_prd_id=excluded.source_prd_id, parent_task_id=excluded.parent_task_id, subtask_ids_json=excluded.subtask_ids_json,\n               created_at=excluded.created_at, updated_at=excluded.updated_at,\n               enhancements_json=excluded.enhancements_json, comprehension_tests_json=excluded.comprehension_tests_json,\n               complexity=excluded.complexity, reasoning=excluded.reasoning, context_files_json=excluded.context_files_json, dependencies_json=excluded.dependencies_json, completion_summary=excluded.completion_summary, sort_order=excluded.sort_order"
+            "INSERT INTO tasks (id, title, description, agent_persona, due_date, status, source_transcript_id, source_prd_id, parent_task_id, subtask_ids_json, created_at, updated_at, enhancements_json, comprehension_tests_json, complexity, reasoning, context_files_json, dependencies_json, completion_summary, sort_order, source_section, priority, tags_json, revisions_json, checklist_json, started_at, time_spent_seconds, source_utterance_json, idempotency_key, links_json, estimate)\n             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28, ?29, ?30, ?31)\n             ON CONFLICT(id) DO UPDATE SET\n               title=excluded.title, description=excluded.description, agent_persona=excluded.agent_persona, due_date=excluded.due_date, status=excluded.status,\n               source_transcript_id=excluded.source_transcript_id, source_prd_id=excluded.source_prd_id, parent_task_id=excluded.parent_task_id, subtask_ids_json=excluded.subtask_ids_json,\n               created_at=excluded.created_at, updated_at=excluded.updated_at,\n               enhancements_json=excluded.enhancements_json, comprehension_tests_json=excluded.comprehension_tests_json,\n               complexity=excluded.complexity, reasoning=excluded.reasoning, context_files_json=excluded.context_files_json, dependencies_json=excluded.dependencies_json, completion_summary=excluded.completion_summary, sort_order=excluded.sort_order, source_section=excluded.source_section,\n               priority=excluded.priority, tags_json=excluded.tags_json, revisions_json=excluded.revisions_json, checklist_json=excluded.checklist_json,\n               started_at=excluded.started_at, time_spent_seconds=excluded.time_spent_seconds, source_utterance_json=excluded.source_utterance_json, idempotency_key=excluded.idempotency_key, links_json=excluded.links_json, estimate=excluded.estimate"
         )
         .bind(entity.id)
         .bind(entity.title)
@@ -557,7 +760,18 @@ impl SqliteTaskAdapter {
         .bind(dependencies_json)
         .bind(entity.completion_summary)
         .bind(entity.sort_order)
-        .execute(&self.pool)
+        .bind(entity.source_section)
+        .bind(priority_str)
+        .bind(tags_json)
+        .bind(revisions_json)
+        .bind(checklist_json)
+        .bind(started_at)
+        .bind(time_spent_seconds)
+        .bind(source_utterance_json)
+        .bind(entity.idempotency_key)
+        .bind(links_json)
+        .bind(entity.estimate.map(f64::from))
+        .execute(executor)
         .await
         .map_err(|e| {
             let msg = std::format!("sqlx error: {:?}", e);
@@ -571,11 +785,20 @@ impl SqliteTaskAdapter {
     pub async fn find_one_async(
         &self,
         filter: &crate::ports::task_repository_port::TaskFilter,
+    ) -> hexser::HexResult<std::option::Option<crate::domain::task::Task>> {
+        let span = tracing::debug_span!("db", operation = "find_task", filter = ?filter, latency_ms = tracing::field::Empty);
+        crate::infrastructure::telemetry::timed(span, self.query_one(filter)).await
+    }
+
+    /// Selects the first task matching `filter`; `find_one_async` wraps this in a `db` span.
+    async fn query_one(
+        &self,
+        filter: &crate::ports::task_repository_port::TaskFilter,
     ) -> hexser::HexResult<std::option::Option<crate::domain::task::Task>> {
         match filter {
             crate::ports::task_repository_port::TaskFilter::ById(id) => {
                 let row = sqlx::query(
-                    "SELECT id, title, description, agent_persona, due_date, status, source_transcript_id, source_prd_id, parent_task_id, subtask_ids_json, created_at, updated_at, enhancements_json, comprehension_tests_json, complexity, reasoning, context_files_json, dependencies_json, completion_summary, sort_order FROM tasks WHERE id = ?1"
+                    "SELECT id, title, description, agent_persona, due_date, status, source_transcript_id, source_prd_id, parent_task_id, subtask_ids_json, created_at, updated_at, enhancements_json, comprehension_tests_json, complexity, reasoning, context_files_json, dependencies_json, completion_summary, sort_order, source_section, priority, tags_json, revisions_json, checklist_json, started_at, time_spent_seconds, source_utterance_json, idempotency_key, links_json, estimate FROM tasks WHERE id = ?1"
                 )
                 .bind(id)
                 .fetch_optional(&self.pool)
@@ -590,7 +813,7 @@ impl SqliteTaskAdapter {
             crate::ports::task_repository_port::TaskFilter::ByStatus(status) => {
                 let status_str = serde_json::to_string(status).map_err(|e| hexser::error::hex_error::Hexserror::Adapter(hexser::error::adapter_error::mapping_failure(std::format!("serde error: {:?}", e).as_str())))?;
                 let row = sqlx::query(
-                    "SELECT id, title, description, agent_persona, due_date, status, source_transcript_id, source_prd_id, parent_task_id, subtask_ids_json, created_at, updated_at, enhancements_json, comprehension_tests_json, complexity, reasoning, context_files_json, dependencies_json, completion_summary, sort_order FROM tasks WHERE status = ?1 LIMIT 1"
+                    "SELECT id, title, description, agent_persona, due_date, status, source_transcript_id, source_prd_id, parent_task_id, subtask_ids_json, created_at, updated_at, enhancements_json, comprehension_tests_json, complexity, reasoning, context_files_json, dependencies_json, completion_summary, sort_order, source_section, priority, tags_json, revisions_json, checklist_json, started_at, time_spent_seconds, source_utterance_json, idempotency_key, links_json, estimate FROM tasks WHERE status = ?1 LIMIT 1"
                 )
                 .bind(status_str)
                 .fetch_optional(&self.pool)
@@ -600,7 +823,7 @@ impl SqliteTaskAdapter {
             }
             crate::ports::task_repository_port::TaskFilter::ByAgentPersona(assignee) => {
                 let row = sqlx::query(
-                    "SELECT id, title, description, agent_persona, due_date, status, source_transcript_id, source_prd_id, parent_task_id, subtask_ids_json, created_at, updated_at, enhancements_json, comprehension_tests_json, complexity, reasoning, context_files_json, dependencies_json, completion_summary, sort_order FROM tasks WHERE agent_persona = ?1 LIMIT 1"
+                    "SELECT id, title, description, agent_persona, due_date, status, source_transcript_id, source_prd_id, parent_task_id, subtask_ids_json, created_at, updated_at, enhancements_json, comprehension_tests_json, complexity, reasoning, context_files_json, dependencies_json, completion_summary, sort_order, source_section, priority, tags_json, revisions_json, checklist_json, started_at, time_spent_seconds, source_utterance_json, idempotency_key, links_json, estimate FROM tasks WHERE agent_persona = ?1 LIMIT 1"
                 )
                 .bind(assignee)
                 .fetch_optional(&self.pool)
@@ -608,9 +831,19 @@ impl SqliteTaskAdapter {
                 .map_err(|e| { let msg = std::format!("sqlx error: {:?}", e); hexser::error::hex_error::Hexserror::Adapter(hexser::error::adapter_error::connection_failed("SQLite", msg.as_str())) })?;
                 if let std::option::Option::Some(r) = row { std::result::Result::Ok(std::option::Option::Some(Self::row_to_task(&r)?)) } else { std::result::Result::Ok(std::option::Option::None) }
             }
+            crate::ports::task_repository_port::TaskFilter::ByIdempotencyKey(key) => {
+                let row = sqlx::query(
+                    "SELECT id, title, description, agent_persona, due_date, status, source_transcript_id, source_prd_id, parent_task_id, subtask_ids_json, created_at, updated_at, enhancements_json, comprehension_tests_json, complexity, reasoning, context_files_json, dependencies_json, completion_summary, sort_order, source_section, priority, tags_json, revisions_json, checklist_json, started_at, time_spent_seconds, source_utterance_json, idempotency_key, links_json, estimate FROM tasks WHERE idempotency_key = ?1"
+                )
+                .bind(key)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| { let msg = std::format!("sqlx error: {:?}", e); hexser::error::hex_error::Hexserror::Adapter(hexser::error::adapter_error::connection_failed("SQLite", msg.as_str())) })?;
+                if let std::option::Option::Some(r) = row { std::result::Result::Ok(std::option::Option::Some(Self::row_to_task(&r)?)) } else { std::result::Result::Ok(std::option::Option::None) }
+            }
             crate::ports::task_repository_port::TaskFilter::All => {
                 let row = sqlx::query(
-                    "SELECT id, title, description, agent_persona, due_date, status, source_transcript_id, source_prd_id, parent_task_id, subtask_ids_json, created_at, updated_at, enhancements_json, comprehension_tests_json, complexity, reasoning, context_files_json, dependencies_json, completion_summary, sort_order FROM tasks LIMIT 1"
+                    "SELECT id, title, description, agent_persona, due_date, status, source_transcript_id, source_prd_id, parent_task_id, subtask_ids_json, created_at, updated_at, enhancements_json, comprehension_tests_json, complexity, reasoning, context_files_json, dependencies_json, completion_summary, sort_order, source_section, priority, tags_json, revisions_json, checklist_json, started_at, time_spent_seconds, source_utterance_json, idempotency_key, links_json, estimate FROM tasks LIMIT 1"
                 )
                 .fetch_optional(&self.pool)
                 .await
@@ -624,14 +857,19 @@ impl SqliteTaskAdapter {
         &self,
         filter: &crate::ports::task_repository_port::TaskFilter,
         opts: hexser::ports::repository::FindOptions<crate::ports::task_repository_port::TaskSortKey>,
+    ) -> hexser::HexResult<std::vec::Vec<crate::domain::task::Task>> {
+        let span = tracing::debug_span!("db", operation = "find_tasks", filter = ?filter, latency_ms = tracing::field::Empty);
+        crate::infrastructure::telemetry::timed(span, self.query_many(filter, opts)).await
+    }
+
+    /// Selects tasks matching `filter` with sorting and paging; `find_async` wraps this in a `db` span.
+    async fn query_many(
+        &self,
+        filter: &crate::ports::task_repository_port::TaskFilter,
+        opts: hexser::ports::repository::FindOptions<crate::ports::task_repository_port::TaskSortKey>,
     ) -> hexser::HexResult<std::vec::Vec<crate::domain::task::Task>> {
         // Base SQL and bind flag
-        let mut sql = match filter {
-            crate::ports::task_repository_port::TaskFilter::ById(_) => "SELECT id, title, description, agent_persona, due_date, status, source_transcript_id, source_prd_id, parent_task_id, subtask_ids_json, created_at, updated_at, enhancements_json, comprehension_tests_json, complexity, reasoning, context_files_json, dependencies_json, completion_summary, sort_order FROM tasks WHERE id = ?1".to_string(),
-            crate::ports::task_repository_port::TaskFilter::ByStatus(_) => "SELECT id, title, description, agent_persona, due_date, status, source_transcript_id, source_prd_id, parent_task_id, subtask_ids_json, created_at, updated_at, enhancements_json, comprehension_tests_json, complexity, reasoning, context_files_json, dependencies_json, completion_summary, sort_order FROM tasks WHERE status = ?1".to_string(),
-            crate::ports::task_repository_port::TaskFilter::ByAgentPersona(_) => "SELECT id, title, description, agent_persona, due_date, status, source_transcript_id, source_prd_id, parent_task_id, subtask_ids_json, created_at, updated_at, enhancements_json, comprehension_tests_json, complexity, reasoning, context_files_json, dependencies_json, completion_summary, sort_order FROM tasks WHERE agent_persona = ?1".to_string(),
-            crate::ports::task_repository_port::TaskFilter::All => "SELECT id, title, description, agent_persona, due_date, status, source_transcript_id, source_prd_id, parent_task_id, subtask_ids_json, created_at, updated_at, enhancements_json, comprehension_tests_json, complexity, reasoning, context_files_json, dependencies_json, completion_summary, sort_order FROM tasks".to_string(),
-        };
+        let mut sql = Self::select_sql(filter);
 
         // ORDER BY
         if let std::option::Option::Some(sort_specs) = opts.sort {
@@ -644,6 +882,10 @@ impl SqliteTaskAdapter {
                     crate::ports::task_repository_port::TaskSortKey::Title => "title",
                     crate::ports::task_repository_port::TaskSortKey::DueDate => "due_date",
                     crate::ports::task_repository_port::TaskSortKey::SortOrder => "sort_order",
+                    // Priority is stored as a JSON string, so rank it instead of sorting by name
+                    crate::ports::task_repository_port::TaskSortKey::Priority => {
+                        "CASE priority WHEN '\"High\"' THEN 2 WHEN '\"Medium\"' THEN 1 WHEN '\"Low\"' THEN 0 ELSE -1 END"
+                    }
                 };
                 let dir = if s.direction == hexser::ports::repository::Direction::Desc { "DESC" } else { "ASC" };
                 parts.push(std::format!("{} {}", col, dir));
@@ -687,6 +929,13 @@ impl SqliteTaskAdapter {
                     .await
                     .map_err(|e| { let msg = std::format!("sqlx error: {:?}", e); hexser::error::hex_error::Hexserror::Adapter(hexser::error::adapter_error::connection_failed("SQLite", msg.as_str())) })?;
             }
+            crate::ports::task_repository_port::TaskFilter::ByIdempotencyKey(key) => {
+                rows = sqlx::query(sql.as_str())
+                    .bind(key)
+                    .fetch_all(&self.pool)
+                    .await
+                    .map_err(|e| { let msg = std::format!("sqlx error: {:?}", e); hexser::error::hex_error::Hexserror::Adapter(hexser::error::adapter_error::connection_failed("SQLite", msg.as_str())) })?;
+            }
             crate::ports::task_repository_port::TaskFilter::All => {
                 rows = sqlx::query(sql.as_str())
                     .fetch_all(&self.pool)
@@ -699,6 +948,98 @@ impl SqliteTaskAdapter {
         std::result::Result::Ok(out)
     }
 
+    /// Returns the SELECT statement for `filter`, binding its value (if any) as ?1.
+    fn select_sql(filter: &crate::ports::task_repository_port::TaskFilter) -> String {
+        match filter {
+            crate::ports::task_repository_port::TaskFilter::ById(_) => "SELECT id, title, description, agent_persona, due_date, status, source_transcript_id, source_prd_id, parent_task_id, subtask_ids_json, created_at, updated_at, enhancements_json, comprehension_tests_json, complexity, reasoning, context_files_json, dependencies_json, completion_summary, sort_order, source_section, priority, tags_json, revisions_json, checklist_json, started_at, time_spent_seconds, source_utterance_json, idempotency_key, links_json, estimate FROM tasks WHERE id = ?1".to_string(),
+            crate::ports::task_repository_port::TaskFilter::ByStatus(_) => "SELECT id, title, description, agent_persona, due_date, status, source_transcript_id, source_prd_id, parent_task_id, subtask_ids_json, created_at, updated_at, enhancements_json, comprehension_tests_json, complexity, reasoning, context_files_json, dependencies_json, completion_summary, sort_order, source_section, priority, tags_json, revisions_json, checklist_json, started_at, time_spent_seconds, source_utterance_json, idempotency_key, links_json, estimate FROM tasks WHERE status = ?1".to_string(),
+            crate::ports::task_repository_port::TaskFilter::ByAgentPersona(_) => "SELECT id, title, description, agent_persona, due_date, status, source_transcript_id, source_prd_id, parent_task_id, subtask_ids_json, created_at, updated_at, enhancements_json, comprehension_tests_json, complexity, reasoning, context_files_json, dependencies_json, completion_summary, sort_order, source_section, priority, tags_json, revisions_json, checklist_json, started_at, time_spent_seconds, source_utterance_json, idempotency_key, links_json, estimate FROM tasks WHERE agent_persona = ?1".to_string(),
+            crate::ports::task_repository_port::TaskFilter::ByIdempotencyKey(_) => "SELECT id, title, description, agent_persona, due_date, status, source_transcript_id, source_prd_id, parent_task_id, subtask_ids_json, created_at, updated_at, enhancements_json, comprehension_tests_json, complexity, reasoning, context_files_json, dependencies_json, completion_summary, sort_order, source_section, priority, tags_json, revisions_json, checklist_json, started_at, time_spent_seconds, source_utterance_json, idempotency_key, links_json, estimate FROM tasks WHERE idempotency_key = ?1".to_string(),
+            crate::ports::task_repository_port::TaskFilter::All => "SELECT id, title, description, agent_persona, due_date, status, source_transcript_id, source_prd_id, parent_task_id, subtask_ids_json, created_at, updated_at, enhancements_json, comprehension_tests_json, complexity, reasoning, context_files_json, dependencies_json, completion_summary, sort_order, source_section, priority, tags_json, revisions_json, checklist_json, started_at, time_spent_seconds, source_utterance_json, idempotency_key, links_json, estimate FROM tasks".to_string(),
+        }
+    }
+
+    /// SQL expression matching TaskSortKey::cursor_value() for `key`.
+    fn cursor_sort_sql(key: &crate::ports::task_repository_port::TaskSortKey) -> String {
+        match key {
+            crate::ports::task_repository_port::TaskSortKey::CreatedAt => "created_at".to_string(),
+            crate::ports::task_repository_port::TaskSortKey::UpdatedAt => "updated_at".to_string(),
+            crate::ports::task_repository_port::TaskSortKey::Status => "trim(status, '\"')".to_string(),
+            crate::ports::task_repository_port::TaskSortKey::Title => "title".to_string(),
+            crate::ports::task_repository_port::TaskSortKey::DueDate => "COALESCE(due_date, '')".to_string(),
+            crate::ports::task_repository_port::TaskSortKey::SortOrder => std::format!(
+                "COALESCE(printf('%011d', sort_order + {}), '')",
+                crate::ports::task_repository_port::SORT_ORDER_CURSOR_OFFSET
+            ),
+            crate::ports::task_repository_port::TaskSortKey::Priority => {
+                "CASE priority WHEN '\"High\"' THEN '2' WHEN '\"Medium\"' THEN '1' WHEN '\"Low\"' THEN '0' ELSE '' END".to_string()
+            }
+        }
+    }
+
+    /// Returns up to `limit` tasks matching `filter` after `cursor` (see TaskRepositoryPort::find_after()).
+    ///
+    /// Runs a keyset query: `(sort value, id)` is compared with the cursor in
+    /// SQL, so the cost does not grow with the page number.
+    ///
+    /// # Errors
+    ///
+    /// Returns an adapter error if the query fails or a row cannot be mapped.
+    pub async fn find_after_async(
+        &self,
+        filter: &crate::ports::task_repository_port::TaskFilter,
+        sort: &hexser::ports::repository::Sort<crate::ports::task_repository_port::TaskSortKey>,
+        cursor: std::option::Option<&crate::ports::task_repository_port::TaskCursor>,
+        limit: u32,
+    ) -> hexser::HexResult<crate::ports::task_repository_port::TaskPage> {
+        let span = tracing::debug_span!("db", operation = "find_tasks_after", filter = ?filter, latency_ms = tracing::field::Empty);
+        crate::infrastructure::telemetry::timed(span, self.query_after(filter, sort, cursor, limit)).await
+    }
+
+    /// Runs the keyset query; `find_after_async` wraps this in a `db` span.
+    async fn query_after(
+        &self,
+        filter: &crate::ports::task_repository_port::TaskFilter,
+        sort: &hexser::ports::repository::Sort<crate::ports::task_repository_port::TaskSortKey>,
+        cursor: std::option::Option<&crate::ports::task_repository_port::TaskCursor>,
+        limit: u32,
+    ) -> hexser::HexResult<crate::ports::task_repository_port::TaskPage> {
+        let sort_sql = Self::cursor_sort_sql(&sort.key);
+        let (dir, op) = if sort.direction == hexser::ports::repository::Direction::Desc { ("DESC", "<") } else { ("ASC", ">") };
+
+        let mut sql = Self::select_sql(filter);
+        if cursor.is_some() {
+            let joiner = if matches!(filter, crate::ports::task_repository_port::TaskFilter::All) { " WHERE " } else { " AND " };
+            // Unnumbered placeholders continue after the filter's ?1
+            sql.push_str(std::format!("{}({}, id) {} (?, ?)", joiner, sort_sql, op).as_str());
+        }
+        // One extra row tells whether another page follows
+        sql.push_str(std::format!(" ORDER BY {} {}, id {} LIMIT {}", sort_sql, dir, dir, u64::from(limit) + 1).as_str());
+
+        let mut query = sqlx::query(sql.as_str());
+        query = match filter {
+            crate::ports::task_repository_port::TaskFilter::ById(id) => query.bind(id.clone()),
+            crate::ports::task_repository_port::TaskFilter::ByStatus(status) => {
+                let status_str = serde_json::to_string(status).map_err(|e| hexser::error::hex_error::Hexserror::Adapter(hexser::error::adapter_error::mapping_failure(std::format!("serde error: {:?}", e).as_str())))?;
+                query.bind(status_str)
+            }
+            crate::ports::task_repository_port::TaskFilter::ByAgentPersona(assignee) => query.bind(assignee.clone()),
+            crate::ports::task_repository_port::TaskFilter::ByIdempotencyKey(key) => query.bind(key.clone()),
+            crate::ports::task_repository_port::TaskFilter::All => query,
+        };
+        if let std::option::Option::Some(cursor) = cursor {
+            query = query.bind(cursor.sort_value.clone()).bind(cursor.id.clone());
+        }
+        let rows = query
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| { let msg = std::format!("sqlx error: {:?}", e); hexser::error::hex_error::Hexserror::Adapter(hexser::error::adapter_error::connection_failed("SQLite", msg.as_str())) })?;
+
+        let mut tasks: std::vec::Vec<crate::domain::task::Task> = std::vec::Vec::new();
+        for r in rows.iter() { tasks.push(Self::row_to_task(r)?); }
+        std::result::Result::Ok(crate::ports::task_repository_port::TaskPage::from_rows(tasks, &sort.key, limit))
+    }
+
     fn row_to_task(
         row: &sqlx::sqlite::SqliteRow,
     ) -> hexser::HexResult<crate::domain::task::Task> {
@@ -755,6 +1096,59 @@ impl SqliteTaskAdapter {
         };
         let completion_summary: std::option::Option<String> = sqlx::Row::get(row, 18);
         let sort_order: std::option::Option<i32> = sqlx::Row::get(row, 19);
+        let source_section: std::option::Option<String> = sqlx::Row::get(row, 20);
+        let priority_str: std::option::Option<String> = sqlx::Row::get(row, 21);
+        let priority: std::option::Option<crate::domain::priority::Priority> = match priority_str {
+            std::option::Option::Some(s) => {
+                std::option::Option::Some(serde_json::from_str(s.as_str()).map_err(|e| hexser::error::hex_error::Hexserror::Adapter(hexser::error::adapter_error::mapping_failure(std::format!("serde error: {:?}", e).as_str())))?)
+            }
+            std::option::Option::None => std::option::Option::None,
+        };
+        let tags_json: std::option::Option<String> = sqlx::Row::get(row, 22);
+        let tags: std::vec::Vec<String> = match tags_json {
+            std::option::Option::Some(s) => {
+                serde_json::from_str(s.as_str()).map_err(|e| hexser::error::hex_error::Hexserror::Adapter(hexser::error::adapter_error::mapping_failure(std::format!("serde error: {:?}", e).as_str())))?
+            }
+            std::option::Option::None => std::vec::Vec::new(),
+        };
+        let revisions_json: std::option::Option<String> = sqlx::Row::get(row, 23);
+        let revisions: std::vec::Vec<crate::domain::task_revision::TaskRevision> = match revisions_json {
+            std::option::Option::Some(s) => {
+                serde_json::from_str(s.as_str()).map_err(|e| hexser::error::hex_error::Hexserror::Adapter(hexser::error::adapter_error::mapping_failure(std::format!("serde error: {:?}", e).as_str())))?
+            }
+            std::option::Option::None => std::vec::Vec::new(),
+        };
+        let checklist_json: std::option::Option<String> = sqlx::Row::get(row, 24);
+        let checklist: std::vec::Vec<crate::domain::checklist_item::ChecklistItem> = match checklist_json {
+            std::option::Option::Some(s) => {
+                serde_json::from_str(s.as_str()).map_err(|e| hexser::error::hex_error::Hexserror::Adapter(hexser::error::adapter_error::mapping_failure(std::format!("serde error: {:?}", e).as_str())))?
+            }
+            std::option::Option::None => std::vec::Vec::new(),
+        };
+        let started_at_str: std::option::Option<String> = sqlx::Row::get(row, 25);
+        let started_at = match started_at_str {
+            std::option::Option::Some(s) => std::option::Option::Some(
+                chrono::DateTime::parse_from_rfc3339(s.as_str()).map_err(|e| hexser::error::hex_error::Hexserror::Adapter(hexser::error::adapter_error::mapping_failure(std::format!("time parse: {:?}", e).as_str())))?.with_timezone(&chrono::Utc),
+            ),
+            std::option::Option::None => std::option::Option::None,
+        };
+        let time_spent_seconds: i64 = sqlx::Row::get(row, 26);
+        let source_utterance_json: std::option::Option<String> = sqlx::Row::get(row, 27);
+        let source_utterance = match source_utterance_json {
+            std::option::Option::Some(s) => std::option::Option::Some(
+                serde_json::from_str(s.as_str()).map_err(|e| hexser::error::hex_error::Hexserror::Adapter(hexser::error::adapter_error::mapping_failure(std::format!("serde error: {:?}", e).as_str())))?,
+            ),
+            std::option::Option::None => std::option::Option::None,
+        };
+        let idempotency_key: std::option::Option<String> = sqlx::Row::get(row, 28);
+        let links_json: std::option::Option<String> = sqlx::Row::get(row, 29);
+        let links: std::vec::Vec<crate::domain::task_link::TaskLink> = match links_json {
+            std::option::Option::Some(s) => {
+                serde_json::from_str(s.as_str()).map_err(|e| hexser::error::hex_error::Hexserror::Adapter(hexser::error::adapter_error::mapping_failure(std::format!("serde error: {:?}", e).as_str())))?
+            }
+            std::option::Option::None => std::vec::Vec::new(),
+        };
+        let estimate: std::option::Option<f64> = sqlx::Row::get(row, 30);
         std::result::Result::Ok(crate::domain::task::Task {
             id,
             title,
@@ -776,6 +1170,17 @@ impl SqliteTaskAdapter {
             context_files,
             dependencies,
             sort_order,
+            source_section,
+            priority,
+            tags,
+            revisions,
+            checklist,
+            started_at,
+            time_spent_seconds: u64::try_from(time_spent_seconds).unwrap_or(0),
+            source_utterance,
+            idempotency_key,
+            links,
+            estimate: estimate.map(|points| points as f32),
         })
     }
 
@@ -916,8 +1321,17 @@ impl hexser::ports::repository::QueryRepository<crate::domain::task::Task> for S
     }
 }
 
-// Marker trait implementation
-impl crate::ports::task_repository_port::TaskRepositoryPort for SqliteTaskAdapter {}
+impl crate::ports::task_repository_port::TaskRepositoryPort for SqliteTaskAdapter {
+    fn find_after(
+        &self,
+        filter: &crate::ports::task_repository_port::TaskFilter,
+        sort: &hexser::ports::repository::Sort<crate::ports::task_repository_port::TaskSortKey>,
+        cursor: std::option::Option<&crate::ports::task_repository_port::TaskCursor>,
+        limit: u32,
+    ) -> hexser::HexResult<crate::ports::task_repository_port::TaskPage> {
+        SqliteTaskAdapter::block_on(self.find_after_async(filter, sort, cursor, limit))
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -931,6 +1345,28 @@ mod tests {
         };
         let mut task = crate::domain::task::Task::from_action_item(&action, std::option::Option::None);
         task.id = std::string::String::from("t1");
+        task.source_section = std::option::Option::Some(std::string::String::from("Rigger > Objectives > Enable task decomposition"));
+        task.priority = std::option::Option::Some(crate::domain::priority::Priority::High);
+        task.tags.push(std::string::String::from("triage:decompose"));
+        task.revisions.push(crate::domain::task_revision::TaskRevision {
+            revision_id: std::string::String::from("r1"),
+            task_id: std::string::String::from("t1"),
+            timestamp: chrono::Utc::now(),
+            change_description: std::string::String::from("Auto-triage"),
+            previous_state_json: std::option::Option::None,
+        });
+        task.checklist.push(crate::domain::checklist_item::ChecklistItem {
+            description: std::string::String::from("Write migration"),
+            completed: true,
+        });
+        task.started_at = std::option::Option::Some(chrono::Utc::now());
+        task.time_spent_seconds = 5400;
+        task.source_transcript_id = std::option::Option::Some(std::string::String::from("standup-2026-03-02"));
+        task.source_utterance = std::option::Option::Some(crate::domain::transcript_utterance::TranscriptUtterance {
+            line: 4,
+            speaker: std::option::Option::Some(std::string::String::from("John")),
+            text: std::string::String::from("I'll write the migration today."),
+        });
         let repo = adapter;
         // Save using async internal API to avoid nested runtime block_on
         super::SqliteTaskAdapter::save_async(&repo, task.clone()).await.unwrap();
@@ -944,6 +1380,17 @@ mod tests {
         let t = got.unwrap();
         std::assert_eq!(t.title, std::string::String::from("SQLite Task"));
         std::assert_eq!(t.agent_persona, std::option::Option::Some(std::string::String::from("Backend Developer")));
+        std::assert_eq!(t.source_section.as_deref(), std::option::Option::Some("Rigger > Objectives > Enable task decomposition"));
+        std::assert_eq!(t.priority, std::option::Option::Some(crate::domain::priority::Priority::High));
+        std::assert_eq!(t.tags, std::vec![std::string::String::from("triage:decompose")]);
+        std::assert_eq!(t.revisions.len(), 1);
+        std::assert_eq!(t.revisions[0].change_description, "Auto-triage");
+        std::assert_eq!(t.checklist.len(), 1);
+        std::assert!(t.checklist[0].completed);
+        std::assert_eq!(t.started_at.map(|d| d.timestamp()), task.started_at.map(|d| d.timestamp()));
+        std::assert_eq!(t.time_spent_seconds, 5400);
+        std::assert_eq!(t.source_transcript_id.as_deref(), std::option::Option::Some("standup-2026-03-02"));
+        std::assert_eq!(t.source_utterance, task.source_utterance);
     }
 
     #[tokio::test]
@@ -1023,6 +1470,61 @@ mod tests {
         std::assert_eq!(result[1].title, std::string::String::from("Charlie"));
     }
 
+    #[tokio::test]
+    async fn test_find_after_is_stable_when_a_task_is_inserted_mid_iteration() {
+        // Test: Validates keyset pages cover every task exactly once, in order, although a task sorting
+        // before the cursor is inserted between pages (offset paging would repeat a task here).
+        // Justification: Paging through a live task list must neither skip nor duplicate tasks.
+        let repo = super::SqliteTaskAdapter::connect_and_init("sqlite::memory:").await.unwrap();
+        let task = |id: &str, title: &str| {
+            let action = transcript_extractor::domain::action_item::ActionItem {
+                title: std::string::String::from(title),
+                assignee: std::option::Option::None,
+                due_date: std::option::Option::None,
+            };
+            let mut t = crate::domain::task::Task::from_action_item(&action, std::option::Option::None);
+            t.id = std::string::String::from(id);
+            t
+        };
+        // Two tasks share a title, so the ID tie-break decides their order
+        for (id, title) in [("t1", "Bravo"), ("t2", "Delta"), ("t3", "Bravo"), ("t4", "Echo"), ("t5", "Charlie")] {
+            super::SqliteTaskAdapter::save_async(&repo, task(id, title)).await.unwrap();
+        }
+        let sort = hexser::ports::repository::Sort {
+            key: crate::ports::task_repository_port::TaskSortKey::Title,
+            direction: hexser::ports::repository::Direction::Asc,
+        };
+
+        let mut seen: std::vec::Vec<std::string::String> = std::vec::Vec::new();
+        let mut cursor: std::option::Option<crate::ports::task_repository_port::TaskCursor> = std::option::Option::None;
+        loop {
+            let page = repo
+                .find_after_async(&crate::ports::task_repository_port::TaskFilter::All, &sort, cursor.as_ref(), 2)
+                .await
+                .unwrap();
+            seen.extend(page.tasks.iter().map(|t| t.id.clone()));
+            if seen.len() == 2 {
+                super::SqliteTaskAdapter::save_async(&repo, task("t0", "Alpha")).await.unwrap();
+            }
+            match page.next_cursor {
+                std::option::Option::Some(next) => cursor = std::option::Option::Some(next),
+                std::option::Option::None => break,
+            }
+        }
+
+        std::assert_eq!(seen, ["t1", "t3", "t5", "t2", "t4"]);
+
+        // Descending order runs the same keyset backwards
+        let desc = hexser::ports::repository::Sort { direction: hexser::ports::repository::Direction::Desc, ..sort };
+        let first = repo.find_after_async(&crate::ports::task_repository_port::TaskFilter::All, &desc, std::option::Option::None, 3).await.unwrap();
+        let ids: std::vec::Vec<&str> = first.tasks.iter().map(|t| t.id.as_str()).collect();
+        std::assert_eq!(ids, ["t4", "t2", "t5"]);
+        let rest = repo.find_after_async(&crate::ports::task_repository_port::TaskFilter::All, &desc, first.next_cursor.as_ref(), 3).await.unwrap();
+        let ids: std::vec::Vec<&str> = rest.tasks.iter().map(|t| t.id.as_str()).collect();
+        std::assert_eq!(ids, ["t3", "t1", "t0"]);
+        std::assert!(rest.next_cursor.is_none());
+    }
+
     #[tokio::test]
     async fn test_sqlite_adapter_update_flow() {
         let repo = super::SqliteTaskAdapter::connect_and_init("sqlite::memory:").await.unwrap();
@@ -1053,4 +1555,129 @@ mod tests {
         std::assert_eq!(got.agent_persona, std::option::Option::Some(std::string::String::from("QA Engineer")));
     }
 
+    #[tokio::test]
+    async fn test_idempotency_key_is_unique_and_queryable() {
+        // Test: Validates a task is found by its idempotency key and a second task with the same key is rejected.
+        // Justification: The unique index is the backstop that keeps retried imports from inserting duplicates.
+        let repo = super::SqliteTaskAdapter::connect_and_init("sqlite::memory:").await.unwrap();
+        let action = transcript_extractor::domain::action_item::ActionItem { title: std::string::String::from("Draft launch plan"), assignee: None, due_date: None };
+        let mut first = crate::domain::task::Task::from_action_item(&action, None);
+        first.idempotency_key = std::option::Option::Some(std::string::String::from("key-1"));
+        let mut second = crate::domain::task::Task::from_action_item(&action, None);
+        second.idempotency_key = first.idempotency_key.clone();
+        let unkeyed = crate::domain::task::Task::from_action_item(&action, None);
+
+        super::SqliteTaskAdapter::save_async(&repo, first.clone()).await.unwrap();
+        super::SqliteTaskAdapter::save_async(&repo, first.clone()).await.unwrap();
+        std::assert!(super::SqliteTaskAdapter::save_async(&repo, second).await.is_err());
+        super::SqliteTaskAdapter::save_async(&repo, unkeyed.clone()).await.unwrap();
+        super::SqliteTaskAdapter::save_async(&repo, crate::domain::task::Task::from_action_item(&action, None)).await.unwrap();
+
+        let found = super::SqliteTaskAdapter::find_one_async(
+            &repo,
+            &crate::ports::task_repository_port::TaskFilter::ByIdempotencyKey(std::string::String::from("key-1")),
+        ).await.unwrap().unwrap();
+        std::assert_eq!(found.id, first.id);
+        std::assert_eq!(found.idempotency_key.as_deref(), std::option::Option::Some("key-1"));
+    }
+
+    #[tokio::test]
+    async fn test_links_round_trip_with_their_types() {
+        // Test: Validates blocking dependencies and relates-to/duplicates links are stored and loaded with their types.
+        // Justification: `rig link` and `rig show` rely on the link type surviving a save.
+        let repo = super::SqliteTaskAdapter::connect_and_init("sqlite::memory:").await.unwrap();
+        let action = transcript_extractor::domain::action_item::ActionItem { title: std::string::String::from("Build API"), assignee: None, due_date: None };
+        let mut task = crate::domain::task::Task::from_action_item(&action, None);
+        task.add_link("schema", crate::domain::task_link::LinkType::Blocks);
+        task.add_link("docs", crate::domain::task_link::LinkType::RelatesTo);
+        task.add_link("old-api", crate::domain::task_link::LinkType::Duplicates);
+        super::SqliteTaskAdapter::save_async(&repo, task.clone()).await.unwrap();
+
+        let got = super::SqliteTaskAdapter::find_one_async(
+            &repo,
+            &crate::ports::task_repository_port::TaskFilter::ById(task.id.clone()),
+        ).await.unwrap().unwrap();
+
+        std::assert_eq!(got.dependencies, task.dependencies);
+        std::assert_eq!(got.links, task.links);
+        std::assert_eq!(got.all_links(), task.all_links());
+    }
+
+    #[tokio::test]
+    async fn test_estimate_round_trips() {
+        // Test: Validates a fractional estimate and a missing estimate both survive a save.
+        // Justification: `rig plan` tells unestimated tasks apart from estimated ones by this field.
+        let repo = super::SqliteTaskAdapter::connect_and_init("sqlite::memory:").await.unwrap();
+        let action = transcript_extractor::domain::action_item::ActionItem { title: std::string::String::from("Build API"), assignee: None, due_date: None };
+        let mut estimated = crate::domain::task::Task::from_action_item(&action, None);
+        estimated.estimate = Some(2.5);
+        let unestimated = crate::domain::task::Task::from_action_item(&action, None);
+        super::SqliteTaskAdapter::save_async(&repo, estimated.clone()).await.unwrap();
+        super::SqliteTaskAdapter::save_async(&repo, unestimated.clone()).await.unwrap();
+
+        for (task, expected) in [(estimated, Some(2.5)), (unestimated, None)] {
+            let got = super::SqliteTaskAdapter::find_one_async(
+                &repo,
+                &crate::ports::task_repository_port::TaskFilter::ById(task.id.clone()),
+            ).await.unwrap().unwrap();
+            std::assert_eq!(got.estimate, expected);
+        }
+    }
+
+    async fn task_columns(pool: &sqlx::Pool<sqlx::Sqlite>) -> std::vec::Vec<String> {
+        let mut columns: std::vec::Vec<String> = sqlx::query_scalar("SELECT name FROM pragma_table_info('tasks')")
+            .fetch_all(pool)
+            .await
+            .unwrap();
+        columns.sort();
+        columns
+    }
+
+    #[tokio::test]
+    async fn test_migrations_build_full_schema_on_empty_database() {
+        // Test: Validates migrating an empty database records every version, creates every column the adapter reads, and re-running is a no-op.
+        // Justification: Fresh installs must get the current schema, and every later connect must leave it untouched.
+        let adapter = super::SqliteTaskAdapter::connect_and_init("sqlite::memory:").await.unwrap();
+
+        let versions = crate::infrastructure::sqlite_migrations::migrator::applied_versions(adapter.pool(), "tasks").await.unwrap();
+        std::assert_eq!(versions, (1..=super::SqliteTaskAdapter::MIGRATIONS.len() as i64).collect::<std::vec::Vec<_>>());
+        let columns = task_columns(adapter.pool()).await;
+        for expected in ["description", "agent_persona", "completion_summary", "priority", "checklist_json", "time_spent_seconds", "source_utterance_json", "idempotency_key", "links_json", "estimate"] {
+            std::assert!(columns.iter().any(|c| c == expected), "missing column {}", expected);
+        }
+        std::assert!(super::SqliteTaskAdapter::migrate(adapter.pool()).await.unwrap().is_empty());
+        std::assert_eq!(task_columns(adapter.pool()).await, columns);
+    }
+
+    #[tokio::test]
+    async fn test_migrations_upgrade_database_at_intermediate_version() {
+        // Test: Validates a database stopped at version 5, holding a legacy assignee column and a task, upgrades to the full schema and keeps its data.
+        // Justification: Pulling an update onto an older .rigger database used to fail with "no such column".
+        let pool = sqlx::sqlite::SqlitePoolOptions::new().max_connections(1).connect("sqlite::memory:").await.unwrap();
+        crate::infrastructure::sqlite_migrations::migrator::run_pending(&pool, "tasks", &super::SqliteTaskAdapter::MIGRATIONS[..4])
+            .await
+            .unwrap();
+        sqlx::query("ALTER TABLE tasks ADD COLUMN assignee TEXT NULL").execute(&pool).await.unwrap();
+        sqlx::query("INSERT INTO tasks (id, title, status, created_at, updated_at, assignee) VALUES ('legacy', 'Legacy task', '\"Todo\"', ?1, ?1, 'QA Engineer')")
+            .bind(chrono::Utc::now().to_rfc3339())
+            .execute(&pool)
+            .await
+            .unwrap();
+        crate::infrastructure::sqlite_migrations::migrator::run_pending(&pool, "tasks", &super::SqliteTaskAdapter::MIGRATIONS[..5])
+            .await
+            .unwrap();
+
+        let applied = super::SqliteTaskAdapter::migrate(&pool).await.unwrap();
+        let adapter = super::SqliteTaskAdapter::new(pool);
+        let task = adapter
+            .find_one_async(&crate::ports::task_repository_port::TaskFilter::ById(std::string::String::from("legacy")))
+            .await
+            .unwrap()
+            .unwrap();
+
+        std::assert_eq!(applied, (6..=super::SqliteTaskAdapter::MIGRATIONS.len() as i64).collect::<std::vec::Vec<_>>());
+        std::assert_eq!(task.agent_persona.as_deref(), std::option::Option::Some("QA Engineer"));
+        std::assert_eq!(task.time_spent_seconds, 0);
+        std::assert!(super::SqliteTaskAdapter::migrate(adapter.pool()).await.unwrap().is_empty());
+    }
 }
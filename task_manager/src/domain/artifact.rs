@@ -6,6 +6,8 @@
 //! generation (RAG) by providing relevant context to LLM agents.
 //!
 //! Revision History
+//! - 2026-10-16T22:30:00Z @AI: Record embedding_model and embedding_dimension with each artifact; add with_embedding_model().
+//! - 2026-10-16T14:50:00Z @AI: Add citation() building a source reference (path + line range, or URL + page/anchor) from artifact fields and metadata.
 //! - 2025-11-30T10:00:00Z @AI: Add Image and PDF artifact types with binary storage support. Added binary_content (base64), mime_type, source_url, and page_number fields for vision-capable LLM processing. Images and PDFs can now be stored with their base64 content for re-processing and audit trails.
 //! - 2025-11-28T19:00:00Z @AI: Initial Artifact entity creation for Phase 1 of RAG implementation.
 
@@ -30,6 +32,8 @@
 /// * `mime_type` - MIME type of binary content (e.g., "image/png", "application/pdf").
 /// * `source_url` - Original URL or path where media was sourced.
 /// * `page_number` - For multi-page PDFs, the page number this artifact represents.
+/// * `embedding_model` - Name of the model that produced the embedding, if known.
+/// * `embedding_dimension` - Number of dimensions of the embedding, if known.
 ///
 /// # Examples
 ///
@@ -89,6 +93,16 @@ pub struct Artifact {
     /// For multi-page PDFs, the page number this artifact represents (1-indexed).
     /// Allows correlating multiple artifacts to the same source PDF.
     pub page_number: std::option::Option<u32>,
+
+    /// Name of the embedding model that produced `embedding` (e.g., "nomic-embed-text").
+    /// None for artifacts stored before the model was recorded.
+    #[serde(default)]
+    pub embedding_model: std::option::Option<String>,
+
+    /// Number of dimensions of `embedding` when it was stored.
+    /// Kept separately because search results omit the embedding vector itself.
+    #[serde(default)]
+    pub embedding_dimension: std::option::Option<usize>,
 }
 
 /// Enumerates the types of sources from which artifacts can be extracted.
@@ -182,6 +196,7 @@ impl Artifact {
             source_id,
             source_type,
             content,
+            embedding_dimension: std::option::Option::Some(embedding.len()),
             embedding,
             metadata,
             created_at: chrono::Utc::now(),
@@ -189,6 +204,7 @@ impl Artifact {
             mime_type: std::option::Option::None,
             source_url: std::option::Option::None,
             page_number: std::option::Option::None,
+            embedding_model: std::option::Option::None,
         }
     }
 
@@ -250,6 +266,7 @@ impl Artifact {
             source_id,
             source_type,
             content,
+            embedding_dimension: std::option::Option::Some(embedding.len()),
             embedding,
             metadata: std::option::Option::None,
             created_at: chrono::Utc::now(),
@@ -257,6 +274,7 @@ impl Artifact {
             mime_type: std::option::Option::Some(mime_type),
             source_url: std::option::Option::Some(source_url),
             page_number,
+            embedding_model: std::option::Option::None,
         }
     }
 
@@ -287,6 +305,98 @@ impl Artifact {
     pub fn embedding_dim(&self) -> usize {
         self.embedding.len()
     }
+
+    /// Records the embedding model that produced this artifact's embedding.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - Embedding model name, or None if unknown
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use task_manager::domain::artifact::{Artifact, ArtifactType};
+    /// let artifact = Artifact::new(
+    ///     std::string::String::from("proj-1"),
+    ///     std::string::String::from("src-1"),
+    ///     ArtifactType::File,
+    ///     std::string::String::from("Content"),
+    ///     std::vec![0.0; 768],
+    ///     std::option::Option::None,
+    /// )
+    /// .with_embedding_model(std::option::Option::Some(std::string::String::from("nomic-embed-text")));
+    ///
+    /// std::assert_eq!(artifact.embedding_model.as_deref(), std::option::Option::Some("nomic-embed-text"));
+    /// std::assert_eq!(artifact.embedding_dimension, std::option::Option::Some(768));
+    /// ```
+    pub fn with_embedding_model(mut self, model: std::option::Option<String>) -> Self {
+        self.embedding_model = model;
+        self
+    }
+
+    /// Returns a human-readable reference to where this artifact came from.
+    ///
+    /// Web and media sources cite their URL, with `#page=N` for PDF pages or
+    /// `#anchor` when the metadata records an `"anchor"`. File sources cite
+    /// the `"source_path"` from metadata (falling back to `source_id`) plus
+    /// `:start-end` when `"line_start"`/`"line_end"` are recorded, or
+    /// `, page N` for PDF pages. Missing metadata degrades gracefully to the
+    /// bare source identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use task_manager::domain::artifact::{Artifact, ArtifactType};
+    /// let artifact = Artifact::new(
+    ///     std::string::String::from("proj-1"),
+    ///     std::string::String::from("src/auth.rs"),
+    ///     ArtifactType::File,
+    ///     std::string::String::from("fn login() {}"),
+    ///     std::vec![0.1],
+    ///     std::option::Option::Some(std::string::String::from(r#"{"line_start":10,"line_end":24}"#)),
+    /// );
+    ///
+    /// std::assert_eq!(artifact.citation(), "src/auth.rs:10-24");
+    /// ```
+    pub fn citation(&self) -> String {
+        let metadata: serde_json::Value = self
+            .metadata
+            .as_deref()
+            .and_then(|m| serde_json::from_str(m).ok())
+            .unwrap_or(serde_json::Value::Null);
+
+        if let std::option::Option::Some(url) = &self.source_url {
+            if let std::option::Option::Some(page) = self.page_number {
+                return std::format!("{}#page={}", url, page);
+            }
+            if let std::option::Option::Some(anchor) = metadata.get("anchor").and_then(|a| a.as_str()) {
+                return std::format!("{}#{}", url, anchor.trim_start_matches('#'));
+            }
+            return url.clone();
+        }
+
+        let path = metadata
+            .get("source_path")
+            .and_then(|p| p.as_str())
+            .unwrap_or(self.source_id.as_str());
+        let page = self
+            .page_number
+            .or_else(|| metadata.get("page").and_then(|p| p.as_u64()).map(|p| p as u32));
+
+        if let std::option::Option::Some(page) = page {
+            return std::format!("{}, page {}", path, page);
+        }
+
+        let line_start = metadata.get("line_start").and_then(|l| l.as_u64());
+        let line_end = metadata.get("line_end").and_then(|l| l.as_u64());
+        match (line_start, line_end) {
+            (std::option::Option::Some(start), std::option::Option::Some(end)) if start != end => {
+                std::format!("{}:{}-{}", path, start, end)
+            }
+            (std::option::Option::Some(start), _) => std::format!("{}:{}", path, start),
+            _ => String::from(path),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -423,4 +533,49 @@ mod tests {
         assert_ne!(ArtifactType::Image, ArtifactType::PRD);
         assert_ne!(ArtifactType::PDF, ArtifactType::File);
     }
+
+    #[test]
+    fn test_citation_for_web_and_pdf_sources() {
+        // Test: Verifies URL citations carry the PDF page or metadata anchor.
+        // Justification: Agents must be able to point at the exact page or section they used.
+        let mut web = Artifact::new(
+            String::from("proj"),
+            String::from("https://docs.example.com/auth"),
+            ArtifactType::WebResearch,
+            String::from("OAuth flow"),
+            vec![0.1],
+            Option::Some(String::from(r##"{"anchor":"#tokens"}"##)),
+        );
+        web.source_url = Option::Some(String::from("https://docs.example.com/auth"));
+        let pdf = Artifact::new_media(
+            String::from("proj"),
+            String::from("spec.pdf"),
+            ArtifactType::PDF,
+            String::from("Page text"),
+            vec![0.1],
+            String::from("JVBERi0="),
+            String::from("application/pdf"),
+            String::from("https://example.com/spec.pdf"),
+            Option::Some(4),
+        );
+
+        assert_eq!(web.citation(), "https://docs.example.com/auth#tokens");
+        assert_eq!(pdf.citation(), "https://example.com/spec.pdf#page=4");
+    }
+
+    #[test]
+    fn test_citation_falls_back_to_source_id() {
+        // Test: Verifies artifacts without citation metadata still cite their source.
+        // Justification: Artifacts ingested before citation metadata existed must remain attributable.
+        let artifact = Artifact::new(
+            String::from("proj"),
+            String::from("docs/guide.md"),
+            ArtifactType::PRD,
+            String::from("Guide"),
+            vec![0.1],
+            Option::Some(String::from("not json")),
+        );
+
+        assert_eq!(artifact.citation(), "docs/guide.md");
+    }
 }
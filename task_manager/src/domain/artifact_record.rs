@@ -0,0 +1,201 @@
+//! Defines ArtifactRecord, one line of an artifact JSONL export.
+//!
+//! `rig artifacts export` writes one record per line so other vector stores
+//! can load the knowledge base without reading rig's database. A record
+//! spells out the embedding model and dimension next to the vector and
+//! groups where the content came from under `source`. `into_artifact()`
+//! checks that the declared dimension matches the vector before a record is
+//! imported.
+//!
+//! Revision History
+//! - 2026-10-17T17:50:00Z @AI: Initial ArtifactRecord for JSONL artifact export/import.
+
+/// One exported artifact: content, embedding, and source metadata.
+///
+/// # Fields
+///
+/// * `id` - Artifact ID; importing a record with an existing ID replaces that artifact.
+/// * `content` - The text that was embedded.
+/// * `embedding` - The embedding vector.
+/// * `model` - Embedding model that produced the vector, if known.
+/// * `dimension` - Number of dimensions of `embedding`.
+/// * `source` - Where the content came from.
+/// * `created_at` - UTC timestamp when the artifact was created.
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::domain::artifact_record::ArtifactRecord;
+/// let artifact = task_manager::domain::artifact::Artifact::new(
+///     std::string::String::from("proj-1"),
+///     std::string::String::from("docs/setup.md"),
+///     task_manager::domain::artifact::ArtifactType::File,
+///     std::string::String::from("Run rig init first."),
+///     std::vec![0.5, 0.25],
+///     std::option::Option::None,
+/// );
+///
+/// let line = ArtifactRecord::from_artifact(&artifact).to_json_line().unwrap();
+/// let record = ArtifactRecord::from_json_line(&line).unwrap();
+/// std::assert_eq!(record.dimension, 2);
+/// std::assert_eq!(record.into_artifact().unwrap().embedding, std::vec![0.5, 0.25]);
+/// ```
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct ArtifactRecord {
+    /// Artifact ID.
+    pub id: String,
+
+    /// The text that was embedded.
+    pub content: String,
+
+    /// The embedding vector.
+    pub embedding: std::vec::Vec<f32>,
+
+    /// Embedding model that produced the vector, if known.
+    #[serde(default)]
+    pub model: std::option::Option<String>,
+
+    /// Number of dimensions of `embedding`.
+    pub dimension: usize,
+
+    /// Where the content came from.
+    pub source: ArtifactSource,
+
+    /// UTC timestamp when the artifact was created.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Source metadata of an exported artifact.
+///
+/// Binary content (base64 images and PDF pages) is only written when present,
+/// so text-only exports stay small.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct ArtifactSource {
+    /// The project the artifact belongs to.
+    pub project_id: String,
+
+    /// Identifier of the source (PRD ID, file path, task ID, URL).
+    pub source_id: String,
+
+    /// Kind of source.
+    pub source_type: crate::domain::artifact::ArtifactType,
+
+    /// Original URL or file path, if recorded.
+    #[serde(default, skip_serializing_if = "std::option::Option::is_none")]
+    pub url: std::option::Option<String>,
+
+    /// Page of a multi-page PDF (1-indexed), if any.
+    #[serde(default, skip_serializing_if = "std::option::Option::is_none")]
+    pub page_number: std::option::Option<u32>,
+
+    /// The artifact's JSON metadata (line ranges, sections, citations), if any.
+    #[serde(default, skip_serializing_if = "std::option::Option::is_none")]
+    pub metadata: std::option::Option<String>,
+
+    /// MIME type of `binary_content`.
+    #[serde(default, skip_serializing_if = "std::option::Option::is_none")]
+    pub mime_type: std::option::Option<String>,
+
+    /// Base64-encoded image or PDF page.
+    #[serde(default, skip_serializing_if = "std::option::Option::is_none")]
+    pub binary_content: std::option::Option<String>,
+}
+
+impl ArtifactRecord {
+    /// Builds the record for an artifact.
+    ///
+    /// The dimension is the length of the stored vector, so it always
+    /// describes the embedding actually written.
+    pub fn from_artifact(artifact: &crate::domain::artifact::Artifact) -> Self {
+        ArtifactRecord {
+            id: artifact.id.clone(),
+            content: artifact.content.clone(),
+            embedding: artifact.embedding.clone(),
+            model: artifact.embedding_model.clone(),
+            dimension: artifact.embedding.len(),
+            source: ArtifactSource {
+                project_id: artifact.project_id.clone(),
+                source_id: artifact.source_id.clone(),
+                source_type: artifact.source_type,
+                url: artifact.source_url.clone(),
+                page_number: artifact.page_number,
+                metadata: artifact.metadata.clone(),
+                mime_type: artifact.mime_type.clone(),
+                binary_content: artifact.binary_content.clone(),
+            },
+            created_at: artifact.created_at,
+        }
+    }
+
+    /// Converts the record back into an artifact.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Artifact)` - The artifact, with its ID and creation time kept.
+    /// * `Err(String)` - `dimension` doesn't match the length of `embedding`.
+    pub fn into_artifact(self) -> std::result::Result<crate::domain::artifact::Artifact, String> {
+        if self.embedding.len() != self.dimension {
+            return std::result::Result::Err(std::format!(
+                "Artifact {} declares {} dimensions but its embedding has {}",
+                self.id,
+                self.dimension,
+                self.embedding.len()
+            ));
+        }
+        std::result::Result::Ok(crate::domain::artifact::Artifact {
+            id: self.id,
+            project_id: self.source.project_id,
+            source_id: self.source.source_id,
+            source_type: self.source.source_type,
+            content: self.content,
+            embedding: self.embedding,
+            metadata: self.source.metadata,
+            created_at: self.created_at,
+            binary_content: self.source.binary_content,
+            mime_type: self.source.mime_type,
+            source_url: self.source.url,
+            page_number: self.source.page_number,
+            embedding_model: self.model,
+            embedding_dimension: std::option::Option::Some(self.dimension),
+        })
+    }
+
+    /// Serializes the record as a single JSON line, without the trailing newline.
+    pub fn to_json_line(&self) -> std::result::Result<String, String> {
+        serde_json::to_string(self).map_err(|e| std::format!("Failed to serialize artifact {}: {}", self.id, e))
+    }
+
+    /// Parses one line of an export.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ArtifactRecord)` - The parsed record.
+    /// * `Err(String)` - The line is not an artifact record.
+    pub fn from_json_line(line: &str) -> std::result::Result<Self, String> {
+        serde_json::from_str(line).map_err(|e| std::format!("Invalid artifact record: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_into_artifact_rejects_dimension_mismatch() {
+        // Test: Validates a record whose declared dimension differs from its vector length is refused, naming the artifact.
+        // Justification: Hand-edited or externally produced files must not put wrong-sized vectors into the index.
+        let artifact = crate::domain::artifact::Artifact::new(
+            std::string::String::from("proj-1"),
+            std::string::String::from("prd-1"),
+            crate::domain::artifact::ArtifactType::PRD,
+            std::string::String::from("Checkout must support one click."),
+            std::vec![0.1, 0.2, 0.3],
+            std::option::Option::None,
+        );
+        let mut record = super::ArtifactRecord::from_artifact(&artifact);
+        record.dimension = 4;
+
+        let err = record.into_artifact().expect_err("mismatched dimension must fail");
+
+        std::assert!(err.contains(&artifact.id), "{}", err);
+        std::assert!(err.contains("declares 4 dimensions but its embedding has 3"), "{}", err);
+    }
+}
@@ -3,8 +3,12 @@
 //! ComprehensionTest captures a check of understanding for a task, including
 //! the question, optional multiple-choice options, and the correct answer.
 //! Tests are attached to tasks and used by the orchestrator to decide routing.
+//! Each test records a hash of the description it was generated from, so an
+//! edited description can be detected and the test regenerated.
 //!
 //! Revision History
+//! - 2026-10-17T07:10:00Z @AI: Add key_points() splitting the expected answer into the points an answer is graded on.
+//! - 2026-10-17T06:50:00Z @AI: Add description_hash and stale with hash_description() and is_current_for() for regeneration on description changes.
 //! - 2025-11-23T14:40:00Z @AI: Add schemars::JsonSchema derive for Rig Extractor integration (Phase 1 Sprint 2).
 //! - 2025-11-12T20:28:00Z @AI: Add ComprehensionTest struct to support orchestration Phase 1.
 
@@ -23,6 +27,8 @@
 /// * `question` - The test question prompt.
 /// * `options` - Optional list of answer choices for multiple-choice tests.
 /// * `correct_answer` - The correct answer or rubric.
+/// * `description_hash` - Hash of the task description the test was generated from, if recorded.
+/// * `stale` - Whether the task changed since the test was generated; the orchestrator regenerates stale tests.
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema, hexser::HexEntity)]
 pub struct ComprehensionTest {
     /// Unique identifier for this test.
@@ -45,6 +51,89 @@ pub struct ComprehensionTest {
 
     /// The correct answer or rubric.
     pub correct_answer: String,
+
+    /// Hash of the task description the test was generated from (see `hash_description`).
+    #[serde(default)]
+    pub description_hash: Option<String>,
+
+    /// Set when the task's description changed materially after generation.
+    #[serde(default)]
+    pub stale: bool,
+}
+
+impl ComprehensionTest {
+    /// Hashes a task description for staleness checks.
+    ///
+    /// Whitespace is normalized first (runs collapsed, ends trimmed), so
+    /// reformatting a description is not a material change. The hash is
+    /// 64-bit FNV-1a, which is stable across builds and safe to persist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use task_manager::domain::comprehension_test::ComprehensionTest;
+    /// std::assert_eq!(
+    ///     ComprehensionTest::hash_description("Add  login\n"),
+    ///     ComprehensionTest::hash_description("Add login"),
+    /// );
+    /// std::assert_ne!(
+    ///     ComprehensionTest::hash_description("Add login"),
+    ///     ComprehensionTest::hash_description("Add logout"),
+    /// );
+    /// ```
+    pub fn hash_description(description: &str) -> String {
+        let normalized = description.split_whitespace().collect::<std::vec::Vec<_>>().join(" ");
+        let hash = normalized
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3));
+        std::format!("{:016x}", hash)
+    }
+
+    /// Splits `correct_answer` into the key points an answer is graded on.
+    ///
+    /// Points are separated by semicolons, line breaks, or sentence ends;
+    /// list markers (`-`, `*`) are stripped and blank points dropped. An
+    /// answer written as one phrase is a single key point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use task_manager::domain::comprehension_test::ComprehensionTest;
+    /// let test = ComprehensionTest {
+    ///     test_id: std::string::String::from("ct-1"),
+    ///     task_id: std::string::String::from("t-1"),
+    ///     timestamp: chrono::Utc::now(),
+    ///     test_type: std::string::String::from("short_answer"),
+    ///     question: std::string::String::from("What does the endpoint need?"),
+    ///     options: None,
+    ///     correct_answer: std::string::String::from("Validate the token; rate limit requests.\n- Log failures"),
+    ///     description_hash: None,
+    ///     stale: false,
+    /// };
+    /// std::assert_eq!(test.key_points(), ["Validate the token", "rate limit requests", "Log failures"]);
+    /// ```
+    pub fn key_points(&self) -> std::vec::Vec<String> {
+        self.correct_answer
+            .split(['\n', ';'])
+            .flat_map(|line| line.split(". "))
+            .map(|point| point.trim().trim_start_matches(['-', '*']).trim().trim_end_matches('.').trim())
+            .filter(|point| !point.is_empty())
+            .map(std::string::String::from)
+            .collect()
+    }
+
+    /// Returns whether the test still matches a task with `description`.
+    ///
+    /// A test is current unless it was marked stale or records the hash of a
+    /// different description. Tests without a recorded hash (generated before
+    /// hashes existed) count as current until marked stale.
+    pub fn is_current_for(&self, description: &str) -> bool {
+        !self.stale
+            && self
+                .description_hash
+                .as_deref()
+                .is_none_or(|hash| hash == Self::hash_description(description))
+    }
 }
 
 #[cfg(test)]
@@ -62,6 +151,8 @@ mod tests {
                 std::string::String::from("4"),
             ])),
             correct_answer: std::string::String::from("4"),
+            description_hash: std::option::Option::None,
+            stale: false,
         };
         std::assert_eq!(t.test_id, std::string::String::from("ct1"));
         std::assert_eq!(t.task_id, std::string::String::from("t1"));
@@ -70,4 +161,31 @@ mod tests {
         std::assert!(t.options.is_some());
         std::assert_eq!(t.correct_answer, std::string::String::from("4"));
     }
+
+    #[test]
+    fn test_is_current_for_tracks_stale_flag_and_description_hash() {
+        // Test: Validates a test is current only while unmarked and generated from the same description.
+        // Justification: The orchestrator relies on this to decide whether to regenerate a test.
+        let mut test = super::ComprehensionTest {
+            test_id: std::string::String::from("ct1"),
+            task_id: std::string::String::from("t1"),
+            timestamp: chrono::Utc::now(),
+            test_type: std::string::String::from("short_answer"),
+            question: std::string::String::from("What is built?"),
+            options: std::option::Option::None,
+            correct_answer: std::string::String::from("Login"),
+            description_hash: std::option::Option::Some(super::ComprehensionTest::hash_description("Build login")),
+            stale: false,
+        };
+
+        std::assert!(test.is_current_for("Build   login "));
+        std::assert!(!test.is_current_for("Build logout"));
+
+        test.stale = true;
+        std::assert!(!test.is_current_for("Build login"));
+
+        test.stale = false;
+        test.description_hash = std::option::Option::None;
+        std::assert!(test.is_current_for("anything"));
+    }
 }
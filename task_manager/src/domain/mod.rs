@@ -6,6 +6,14 @@
 //! sorting/ordering utilities.
 //!
 //! Revision History
+//! - 2026-10-17T17:50:00Z @AI: Add artifact_record module for JSONL artifact export/import.
+//! - 2026-10-17T16:30:00Z @AI: Add task_link module for labelled task links (blocks / relates-to / duplicates).
+//! - 2026-10-17T10:50:00Z @AI: Add task_manager_error module for typed use case errors.
+//! - 2026-10-17T05:10:00Z @AI: Add project_bundle module for portable project export/import.
+//! - 2026-10-17T03:50:00Z @AI: Add transcript_utterance module for task-to-transcript linkage.
+//! - 2026-10-16T16:30:00Z @AI: Add project_settings module for project-scoped task defaults.
+//! - 2026-10-16T12:10:00Z @AI: Add priority module for task urgency levels.
+//! - 2026-10-16T11:30:00Z @AI: Add prd_requirement module for structured PRD table rows.
 //! - 2025-11-30T18:30:00Z @AI: Add scan_config module for artifact generator directory scanning configuration.
 //! - 2025-11-28T19:00:00Z @AI: Add artifact module for RAG knowledge storage entity.
 //! - 2025-11-26T07:10:00Z @AI: Add agent_tool and persona modules for Phase 1 persona management with agent tool configuration.
@@ -18,17 +26,25 @@
 
 pub mod task;
 pub mod task_status;
+pub mod priority;
 pub mod task_revision;
+pub mod task_link;
+pub mod task_manager_error;
 pub mod checklist_item;
+pub mod transcript_utterance;
 pub mod task_sort_key;
 pub mod sort_order;
 pub mod enhancement;
 pub mod comprehension_test;
 pub mod prd;
+pub mod prd_requirement;
 pub mod project;
+pub mod project_settings;
 pub mod project_context;
+pub mod project_bundle;
 pub mod services;
 pub mod agent_tool;
 pub mod persona;
 pub mod artifact;
+pub mod artifact_record;
 pub mod scan_config;
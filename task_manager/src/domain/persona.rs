@@ -5,6 +5,7 @@
 //! to control what the agent can do when executing that task.
 //!
 //! Revision History
+//! - 2026-10-17T00:10:00Z @AI: Add tool_call_limits with tool_call_limit/set_tool_call_limit for per-conversation tool call caps.
 //! - 2025-11-26T09:00:00Z @AI: Add project_id to scope personas to projects. Personas are now project-specific, enabling per-project agent teams. Updated all constructors and validation.
 //! - 2025-11-26T07:05:00Z @AI: Initial Persona entity with tool management and validation methods.
 
@@ -24,6 +25,7 @@
 /// * `llm_provider` - Optional LLM provider override (e.g., "ollama", "rig", "candle").
 /// * `llm_model` - Optional LLM model override (e.g., "llama3.1", "gpt-4o").
 /// * `enabled_tools` - List of tool IDs this persona can use.
+/// * `tool_call_limits` - Maximum calls per conversation for individual tools.
 /// * `is_default` - Whether this is the default persona for new tasks in this project.
 /// * `created_at` - UTC timestamp when persona was created.
 /// * `updated_at` - UTC timestamp of last modification.
@@ -68,6 +70,10 @@ pub struct Persona {
     /// List of tool IDs this persona is permitted to use.
     pub enabled_tools: std::vec::Vec<String>,
 
+    /// Maximum calls per conversation, keyed by tool ID; unlisted tools are unlimited.
+    #[serde(default)]
+    pub tool_call_limits: std::collections::HashMap<String, u32>,
+
     /// Whether this persona is the default for new tasks (only one can be true).
     pub is_default: bool,
 
@@ -116,6 +122,7 @@ impl Persona {
             llm_provider: std::option::Option::None,
             llm_model: std::option::Option::None,
             enabled_tools: std::vec::Vec::new(),
+            tool_call_limits: std::collections::HashMap::new(),
             is_default: false,
             created_at: now,
             updated_at: now,
@@ -295,6 +302,50 @@ impl Persona {
         }
     }
 
+    /// Returns the per-conversation call limit for a tool, if one is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `tool_id` - The tool ID to look up.
+    pub fn tool_call_limit(&self, tool_id: &str) -> std::option::Option<u32> {
+        self.tool_call_limits.get(tool_id).copied()
+    }
+
+    /// Sets or clears the per-conversation call limit for a tool.
+    ///
+    /// # Arguments
+    ///
+    /// * `tool_id` - The tool ID to limit.
+    /// * `limit` - Maximum calls per conversation, or `None` for unlimited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use task_manager::domain::persona::Persona;
+    /// let mut persona = Persona::new(
+    ///     String::from("p1"),
+    ///     std::option::Option::None,
+    ///     String::from("ResearchBot"),
+    ///     String::from("Research Assistant"),
+    ///     String::from("Searches the web sparingly"),
+    /// );
+    ///
+    /// persona.set_tool_call_limit(String::from("web_search"), std::option::Option::Some(3));
+    /// std::assert_eq!(persona.tool_call_limit("web_search"), std::option::Option::Some(3));
+    /// std::assert_eq!(persona.tool_call_limit("code_read"), std::option::Option::None);
+    /// ```
+    pub fn set_tool_call_limit(&mut self, tool_id: String, limit: std::option::Option<u32>) {
+        match limit {
+            std::option::Option::Some(limit) => {
+                self.tool_call_limits.insert(tool_id, limit);
+            }
+            std::option::Option::None => {
+                self.tool_call_limits.remove(&tool_id);
+            }
+        }
+        self.updated_at = chrono::Utc::now();
+    }
+
     /// Resets tools to the default safe tool set.
     ///
     /// # Examples
@@ -5,6 +5,9 @@
 //! as the source of truth for task generation in Rigger workflows.
 //!
 //! Revision History
+//! - 2026-10-17T23:40:00Z @AI: Scope section keys by project ID as well as PRD title.
+//! - 2026-10-16T11:30:00Z @AI: Add requirements field holding structured rows parsed from markdown tables.
+//! - 2026-10-16T11:10:00Z @AI: Add section_keys(), section_key_prefix(), and resolve_section_key() for stable task-to-section matching across PRD re-parses.
 //! - 2025-11-24T05:00:00Z @AI: Add project_id field to link PRD to Project entity for Phase 1 TUI project architecture.
 //! - 2025-11-22T16:00:00Z @AI: Initial PRD entity creation for Rigger Phase 0.
 
@@ -23,6 +26,7 @@
 /// * `tech_stack` - List of technologies/frameworks from ## Tech Stack section.
 /// * `constraints` - List of constraints/requirements from ## Constraints section.
 /// * `raw_content` - The original markdown content for reference.
+/// * `requirements` - Structured requirement rows parsed from markdown tables.
 /// * `created_at` - UTC timestamp when PRD was created.
 ///
 /// # Examples
@@ -45,6 +49,7 @@
 ///         std::string::String::from("Must compile with Rust 2024 edition"),
 ///     ],
 ///     raw_content: std::string::String::from("# Sample PRD\n\n## Objectives\n..."),
+///     requirements: std::vec::Vec::new(),
 ///     created_at: chrono::Utc::now(),
 /// };
 ///
@@ -74,6 +79,10 @@ pub struct PRD {
     /// The original markdown content for reference and debugging.
     pub raw_content: String,
 
+    /// Structured requirement rows parsed from markdown tables.
+    #[serde(default)]
+    pub requirements: std::vec::Vec<crate::domain::prd_requirement::PrdRequirement>,
+
     /// UTC timestamp when this PRD was created.
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
@@ -131,9 +140,118 @@ impl PRD {
             tech_stack,
             constraints,
             raw_content,
+            requirements: std::vec::Vec::new(),
             created_at: chrono::Utc::now(),
         }
     }
+
+    /// Returns the prefix shared by every section key of this PRD.
+    ///
+    /// Section keys are scoped by project and PRD title so tasks from
+    /// different PRDs, or from same-titled PRDs of other projects, never
+    /// match each other during re-parsing.
+    pub fn section_key_prefix(&self) -> String {
+        std::format!("{} / {} > ", self.project_id.trim(), self.title.trim())
+    }
+
+    /// Returns stable keys for every objective, tech stack item, and constraint.
+    ///
+    /// Keys have the form `"<project ID> / <PRD title> > <Section> > <item>"`. Unlike the PRD
+    /// `id`, which is regenerated on every parse, a key stays the same as long
+    /// as the item text is unchanged.
+    ///
+    /// # Returns
+    ///
+    /// The section keys in document order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use task_manager::domain::prd::PRD;
+    /// let prd = PRD::new(
+    ///     std::string::String::from("project-123"),
+    ///     std::string::String::from("Build Rigger"),
+    ///     std::vec![std::string::String::from("Enable task decomposition")],
+    ///     std::vec![],
+    ///     std::vec![],
+    ///     std::string::String::new(),
+    /// );
+    ///
+    /// std::assert_eq!(prd.section_keys(), std::vec!["project-123 / Build Rigger > Objectives > Enable task decomposition"]);
+    /// ```
+    pub fn section_keys(&self) -> std::vec::Vec<String> {
+        let prefix = self.section_key_prefix();
+        let sections: [(&str, &std::vec::Vec<String>); 3] = [
+            ("Objectives", &self.objectives),
+            ("Tech Stack", &self.tech_stack),
+            ("Constraints", &self.constraints),
+        ];
+        sections
+            .iter()
+            .flat_map(|(heading, items)| {
+                let prefix = &prefix;
+                items.iter().map(move |item| std::format!("{}{} > {}", prefix, heading, item.trim()))
+            })
+            .collect()
+    }
+
+    /// Resolves a free-text section reference (e.g., from an LLM) to a section key.
+    ///
+    /// Matching ignores case and punctuation. An exact match against a full key
+    /// (with or without the project scope) or bare item text wins; otherwise the first item whose words contain, or
+    /// are contained in, the reference is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - Section reference such as an objective's text.
+    ///
+    /// # Returns
+    ///
+    /// The matching section key, or None if nothing matches.
+    pub fn resolve_section_key(&self, reference: &str) -> std::option::Option<String> {
+        let wanted = Self::normalize_section_text(reference);
+        if wanted.is_empty() {
+            return std::option::Option::None;
+        }
+        let prefix = self.section_key_prefix();
+        let project_scope = std::format!("{} / ", self.project_id.trim());
+        let candidates: std::vec::Vec<(String, String, String, String)> = self
+            .section_keys()
+            .into_iter()
+            .map(|key| {
+                let item = key
+                    .strip_prefix(prefix.as_str())
+                    .and_then(|rest| rest.split_once(" > "))
+                    .map(|(_, item)| Self::normalize_section_text(item))
+                    .unwrap_or_default();
+                let unscoped = Self::normalize_section_text(key.strip_prefix(project_scope.as_str()).unwrap_or(&key));
+                (Self::normalize_section_text(&key), unscoped, item, key)
+            })
+            .collect();
+
+        candidates
+            .iter()
+            .find(|(key, unscoped, item, _)| *key == wanted || *unscoped == wanted || *item == wanted)
+            .or_else(|| {
+                candidates
+                    .iter()
+                    .find(|(_, _, item, _)| {
+                        // Pad with spaces so containment only matches whole words.
+                        let (item, wanted) = (std::format!(" {} ", item), std::format!(" {} ", wanted));
+                        !item.trim().is_empty() && (item.contains(&wanted) || wanted.contains(&item))
+                    })
+            })
+            .map(|(_, _, _, key)| key.clone())
+    }
+
+    /// Lowercases text and collapses everything except letters and digits to single spaces.
+    fn normalize_section_text(text: &str) -> String {
+        text.to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .collect::<std::vec::Vec<&str>>()
+            .join(" ")
+    }
 }
 
 #[cfg(test)]
@@ -228,4 +346,58 @@ mod tests {
 
         std::assert_ne!(prd1.id, prd2.id);
     }
+
+    #[test]
+    fn test_section_keys_cover_all_sections() {
+        // Test: Validates that every objective, tech stack item, and constraint yields a scoped key.
+        // Justification: Incremental re-parsing matches tasks to sections through these keys.
+        let prd = super::PRD::new(
+            std::string::String::from("project-1"),
+            std::string::String::from("Rigger"),
+            std::vec![std::string::String::from("Enable task decomposition")],
+            std::vec![std::string::String::from("Rust")],
+            std::vec![std::string::String::from("No unsafe code")],
+            std::string::String::new(),
+        );
+
+        std::assert_eq!(
+            prd.section_keys(),
+            std::vec![
+                "project-1 / Rigger > Objectives > Enable task decomposition",
+                "project-1 / Rigger > Tech Stack > Rust",
+                "project-1 / Rigger > Constraints > No unsafe code",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_section_key_tolerates_llm_variations() {
+        // Test: Validates exact, case/punctuation-insensitive, and partial section references.
+        // Justification: LLMs rarely echo the section text verbatim.
+        let prd = super::PRD::new(
+            std::string::String::from("project-1"),
+            std::string::String::from("Rigger"),
+            std::vec![
+                std::string::String::from("Enable task decomposition"),
+                std::string::String::from("Support multiple LLM providers"),
+            ],
+            std::vec![],
+            std::vec![],
+            std::string::String::new(),
+        );
+
+        std::assert_eq!(
+            prd.resolve_section_key("Rigger > Objectives > Enable task decomposition").as_deref(),
+            std::option::Option::Some("project-1 / Rigger > Objectives > Enable task decomposition")
+        );
+        std::assert_eq!(
+            prd.resolve_section_key("support multiple LLM providers.").as_deref(),
+            std::option::Option::Some("project-1 / Rigger > Objectives > Support multiple LLM providers")
+        );
+        std::assert_eq!(
+            prd.resolve_section_key("Objective: Enable task decomposition for agents").as_deref(),
+            std::option::Option::Some("project-1 / Rigger > Objectives > Enable task decomposition")
+        );
+        std::assert!(prd.resolve_section_key("Unrelated text").is_none());
+    }
 }
@@ -0,0 +1,96 @@
+//! Defines the PrdRequirement value object for tabular PRD requirements.
+//!
+//! PRDs often list requirements and acceptance criteria in GitHub-flavored
+//! markdown tables. PrdRequirement keeps each table row as structured data:
+//! the well-known columns (ID, description, priority) are lifted into typed
+//! fields and every cell remains available by its column header.
+//!
+//! Revision History
+//! - 2026-10-16T11:30:00Z @AI: Initial PrdRequirement for markdown table parsing.
+
+/// A single requirement row parsed from a markdown table in a PRD.
+///
+/// # Fields
+///
+/// * `id` - Optional requirement identifier (e.g., "REQ-1") from an ID column.
+/// * `description` - Requirement or acceptance criterion text.
+/// * `priority` - Optional priority from a priority column (e.g., "High", "Must").
+/// * `section` - Optional heading of the PRD section containing the table.
+/// * `fields` - Every non-empty cell keyed by its column header, in header order.
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::domain::prd_requirement::PrdRequirement;
+/// let requirement = PrdRequirement::new(std::string::String::from("Users can log in with SSO"));
+/// assert_eq!(requirement.description, "Users can log in with SSO");
+/// assert!(requirement.id.is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct PrdRequirement {
+    /// Optional requirement identifier from an ID column.
+    pub id: std::option::Option<String>,
+
+    /// Requirement or acceptance criterion text.
+    pub description: String,
+
+    /// Optional priority from a priority column.
+    pub priority: std::option::Option<String>,
+
+    /// Optional heading of the PRD section containing the table.
+    pub section: std::option::Option<String>,
+
+    /// Every non-empty cell keyed by its column header, in header order.
+    pub fields: std::vec::Vec<(String, String)>,
+}
+
+impl PrdRequirement {
+    /// Creates a requirement with only a description.
+    ///
+    /// # Arguments
+    ///
+    /// * `description` - Requirement text.
+    ///
+    /// # Returns
+    ///
+    /// A new PrdRequirement with no ID, priority, section, or extra fields.
+    pub fn new(description: String) -> Self {
+        PrdRequirement {
+            id: std::option::Option::None,
+            description,
+            priority: std::option::Option::None,
+            section: std::option::Option::None,
+            fields: std::vec::Vec::new(),
+        }
+    }
+
+    /// Returns the cell value for a column header (case-insensitive).
+    ///
+    /// # Arguments
+    ///
+    /// * `header` - Column header as written in the table.
+    ///
+    /// # Returns
+    ///
+    /// The cell text, or None if the column is absent or the cell was empty.
+    pub fn field(&self, header: &str) -> std::option::Option<&str> {
+        self.fields
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(header.trim()))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_field_lookup_is_case_insensitive() {
+        // Test: Validates lookup of arbitrary columns by header.
+        // Justification: Tables carry columns beyond ID/description/priority (e.g., Owner).
+        let mut requirement = super::PrdRequirement::new(std::string::String::from("Export to CSV"));
+        requirement.fields.push((std::string::String::from("Owner"), std::string::String::from("Data team")));
+
+        std::assert_eq!(requirement.field("owner"), std::option::Option::Some("Data team"));
+        std::assert!(requirement.field("Status").is_none());
+    }
+}
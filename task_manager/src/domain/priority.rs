@@ -0,0 +1,156 @@
+//! Defines the Priority enum for task urgency levels.
+//!
+//! Priority expresses how urgently a task should be worked on. It is either
+//! supplied explicitly when a task is created or assigned by auto-triage
+//! from the task's complexity score. Priorities are totally ordered by
+//! urgency (`High > Medium > Low`), so sorting by priority follows urgency
+//! rather than the alphabetical order of the names.
+//!
+//! Revision History
+//! - 2026-10-17T10:10:00Z @AI: Add urgency ordering, Display, and case-insensitive FromStr with aliases.
+//! - 2026-10-16T12:10:00Z @AI: Initial Priority enum for task triage.
+
+/// Represents the urgency level of a task.
+///
+/// # Variants
+///
+/// * `High` - Task should be addressed first.
+/// * `Medium` - Task has normal urgency.
+/// * `Low` - Task can be deferred.
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::domain::priority::Priority;
+/// let priority = Priority::High;
+/// assert_eq!(priority.as_str(), "high");
+/// assert!(priority > Priority::Low);
+/// assert_eq!("P0".parse::<Priority>(), Ok(Priority::High));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum Priority {
+    /// Task should be addressed first.
+    High,
+
+    /// Task has normal urgency.
+    Medium,
+
+    /// Task can be deferred.
+    Low,
+}
+
+impl Priority {
+    /// Returns the lowercase name of the priority (e.g., "high").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Priority::High => "high",
+            Priority::Medium => "medium",
+            Priority::Low => "low",
+        }
+    }
+
+    /// Returns the urgency rank of the priority; higher ranks are more urgent.
+    pub fn rank(&self) -> u8 {
+        match self {
+            Priority::High => 2,
+            Priority::Medium => 1,
+            Priority::Low => 0,
+        }
+    }
+}
+
+impl std::cmp::Ord for Priority {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+impl std::cmp::PartialOrd for Priority {
+    fn partial_cmp(&self, other: &Self) -> std::option::Option<std::cmp::Ordering> {
+        std::option::Option::Some(self.cmp(other))
+    }
+}
+
+impl std::fmt::Display for Priority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for Priority {
+    type Err = String;
+
+    /// Parses a priority name case-insensitively.
+    ///
+    /// Besides the names themselves, accepts the aliases `hi`/`h`, `med`/`mid`/
+    /// `m`/`normal`, and `lo`/`l`, and the P-levels `p0`/`p1` (high), `p2`
+    /// (medium), and `p3` (low).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error listing the valid values if `s` is not recognized.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "high" | "hi" | "h" | "p0" | "p1" => std::result::Result::Ok(Priority::High),
+            "medium" | "med" | "mid" | "m" | "normal" | "p2" => std::result::Result::Ok(Priority::Medium),
+            "low" | "lo" | "l" | "p3" => std::result::Result::Ok(Priority::Low),
+            _ => std::result::Result::Err(std::format!(
+                "Invalid priority: '{}'. Valid values: high, medium, low (aliases: hi, med, lo, p0-p3)",
+                s
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_priority_serde_roundtrip() {
+        // Test: Validates Priority serializes and deserializes without loss.
+        // Justification: Priority is persisted as JSON alongside the task.
+        let json = serde_json::to_string(&super::Priority::Medium).unwrap();
+        let parsed: super::Priority = serde_json::from_str(&json).unwrap();
+
+        std::assert_eq!(parsed, super::Priority::Medium);
+        std::assert_eq!(parsed.as_str(), "medium");
+    }
+
+    #[test]
+    fn test_high_sorts_before_low() {
+        // Test: Validates priorities order by urgency, not by name, and sort most urgent first in descending order.
+        // Justification: Lexical order would put "high" < "low" < "medium", burying medium tasks below low ones.
+        std::assert!(super::Priority::High > super::Priority::Medium);
+        std::assert!(super::Priority::Medium > super::Priority::Low);
+
+        let mut priorities = std::vec![super::Priority::Low, super::Priority::High, super::Priority::Medium];
+        priorities.sort_by(|a, b| b.cmp(a));
+
+        std::assert_eq!(priorities, [super::Priority::High, super::Priority::Medium, super::Priority::Low]);
+    }
+
+    #[test]
+    fn test_parse_accepts_aliases_case_insensitively() {
+        // Test: Validates names, short aliases, and P-levels parse regardless of case, and Display round-trips.
+        // Justification: Users type priorities in CLI flags and imported files in many spellings.
+        for (input, expected) in [
+            ("High", super::Priority::High),
+            ("HI", super::Priority::High),
+            ("p0", super::Priority::High),
+            ("P1", super::Priority::High),
+            ("medium", super::Priority::Medium),
+            ("Med", super::Priority::Medium),
+            ("p2", super::Priority::Medium),
+            ("LOW", super::Priority::Low),
+            ("lo", super::Priority::Low),
+            ("p3", super::Priority::Low),
+        ] {
+            std::assert_eq!(input.parse::<super::Priority>(), std::result::Result::Ok(expected), "input {}", input);
+        }
+        std::assert!("urgent".parse::<super::Priority>().is_err());
+        std::assert!("p4".parse::<super::Priority>().is_err());
+
+        for priority in [super::Priority::High, super::Priority::Medium, super::Priority::Low] {
+            std::assert_eq!(priority.to_string().parse::<super::Priority>(), std::result::Result::Ok(priority));
+        }
+    }
+}
@@ -5,6 +5,7 @@
 //! and context for filtering and navigation in the TUI.
 //!
 //! Revision History
+//! - 2026-10-16T16:30:00Z @AI: Add settings field holding project-scoped task defaults.
 //! - 2025-11-24T05:00:00Z @AI: Initial Project entity creation for Phase 1 of TUI project architecture refactoring.
 
 /// Represents a project as the top-level organizational context.
@@ -20,6 +21,7 @@
 /// * `description` - Optional longer description of the project's purpose.
 /// * `created_at` - UTC timestamp when project was created.
 /// * `prd_ids` - List of PRD IDs that belong to this project.
+/// * `settings` - Project-scoped defaults applied to new tasks.
 ///
 /// # Examples
 ///
@@ -50,6 +52,10 @@ pub struct Project {
 
     /// List of PRD IDs that belong to this project.
     pub prd_ids: std::vec::Vec<String>,
+
+    /// Project-scoped defaults (assignee, priority) applied to new tasks.
+    #[serde(default)]
+    pub settings: crate::domain::project_settings::ProjectSettings,
 }
 
 impl Project {
@@ -87,6 +93,7 @@ impl Project {
             description,
             created_at: chrono::Utc::now(),
             prd_ids: std::vec::Vec::new(),
+            settings: crate::domain::project_settings::ProjectSettings::default(),
         }
     }
 
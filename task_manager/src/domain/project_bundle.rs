@@ -0,0 +1,358 @@
+//! Defines ProjectBundle, a portable snapshot of a single project.
+//!
+//! A bundle carries everything `rig export` writes for one project: the
+//! project, its PRDs, its tasks (checklists and revisions are embedded in each
+//! task), its artifacts with their embeddings, and the codebase context. It is
+//! serialized as one versioned JSON document. Before a bundle is imported,
+//! `with_fresh_ids()` gives every entity a new ID and rewrites the references
+//! between them, so importing never collides with existing rows.
+//!
+//! Revision History
+//! - 2026-10-17T21:00:00Z @AI: Re-derive task idempotency keys on import, scoped to the new project, so an import never collides with the tasks it was exported from.
+//! - 2026-10-17T16:30:00Z @AI: Remap task links (relates-to / duplicates) on import like dependencies.
+//! - 2026-10-17T05:10:00Z @AI: Initial ProjectBundle with versioned JSON encoding and ID remapping.
+
+/// A self-contained, versioned export of one project.
+///
+/// # Fields
+///
+/// * `format_version` - Bundle format version; readers reject newer versions.
+/// * `exported_at` - UTC timestamp when the bundle was written.
+/// * `project` - The exported project.
+/// * `project_context` - Codebase context saved next to the database, if any.
+/// * `prds` - PRDs belonging to the project.
+/// * `tasks` - Tasks generated from those PRDs, plus their subtasks.
+/// * `artifacts` - The project's artifacts, embeddings included.
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::domain::project_bundle::ProjectBundle;
+/// let project = task_manager::domain::project::Project::new(std::string::String::from("Rigger"), std::option::Option::None);
+/// let bundle = ProjectBundle::new(project, std::vec::Vec::new(), std::vec::Vec::new(), std::vec::Vec::new(), std::option::Option::None);
+///
+/// let json = bundle.to_json().unwrap();
+/// let restored = ProjectBundle::from_json(&json).unwrap();
+/// std::assert_eq!(restored.format_version, ProjectBundle::FORMAT_VERSION);
+/// std::assert_eq!(restored.project.name, "Rigger");
+/// ```
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ProjectBundle {
+    /// Bundle format version.
+    pub format_version: u32,
+
+    /// UTC timestamp when the bundle was written.
+    pub exported_at: chrono::DateTime<chrono::Utc>,
+
+    /// The exported project.
+    pub project: crate::domain::project::Project,
+
+    /// Codebase context saved next to the database, if any.
+    #[serde(default)]
+    pub project_context: std::option::Option<crate::domain::project_context::ProjectContext>,
+
+    /// PRDs belonging to the project.
+    #[serde(default)]
+    pub prds: std::vec::Vec<crate::domain::prd::PRD>,
+
+    /// Tasks generated from the project's PRDs, plus their subtasks.
+    #[serde(default)]
+    pub tasks: std::vec::Vec<crate::domain::task::Task>,
+
+    /// The project's artifacts, embeddings included.
+    #[serde(default)]
+    pub artifacts: std::vec::Vec<crate::domain::artifact::Artifact>,
+}
+
+impl ProjectBundle {
+    /// Format version written by this build.
+    pub const FORMAT_VERSION: u32 = 1;
+
+    /// Assembles a bundle, keeping only the tasks that belong to the project.
+    ///
+    /// A task belongs to the project when its `source_prd_id` names one of
+    /// the project's PRDs, or when its parent task belongs to the project.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The project to export
+    /// * `prds` - The project's PRDs
+    /// * `tasks` - Candidate tasks (typically every task in the database)
+    /// * `artifacts` - The project's artifacts
+    /// * `project_context` - Codebase context to include, if any
+    ///
+    /// # Returns
+    ///
+    /// A bundle stamped with the current format version and time.
+    pub fn new(
+        project: crate::domain::project::Project,
+        prds: std::vec::Vec<crate::domain::prd::PRD>,
+        tasks: std::vec::Vec<crate::domain::task::Task>,
+        artifacts: std::vec::Vec<crate::domain::artifact::Artifact>,
+        project_context: std::option::Option<crate::domain::project_context::ProjectContext>,
+    ) -> Self {
+        let prd_ids: std::collections::HashSet<&str> = prds
+            .iter()
+            .map(|prd| prd.id.as_str())
+            .chain(project.prd_ids.iter().map(String::as_str))
+            .collect();
+        let mut member_ids: std::collections::HashSet<String> = tasks
+            .iter()
+            .filter(|task| task.source_prd_id.as_deref().is_some_and(|id| prd_ids.contains(id)))
+            .map(|task| task.id.clone())
+            .collect();
+
+        // Pull in subtasks level by level until no new task joins
+        loop {
+            let joined: std::vec::Vec<String> = tasks
+                .iter()
+                .filter(|task| !member_ids.contains(&task.id))
+                .filter(|task| task.parent_task_id.as_ref().is_some_and(|parent| member_ids.contains(parent)))
+                .map(|task| task.id.clone())
+                .collect();
+            if joined.is_empty() {
+                break;
+            }
+            member_ids.extend(joined);
+        }
+
+        let tasks = tasks.into_iter().filter(|task| member_ids.contains(&task.id)).collect();
+        ProjectBundle {
+            format_version: Self::FORMAT_VERSION,
+            exported_at: chrono::Utc::now(),
+            project,
+            project_context,
+            prds,
+            tasks,
+            artifacts,
+        }
+    }
+
+    /// Serializes the bundle as pretty-printed JSON.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The JSON document.
+    /// * `Err(String)` - Serialization failed.
+    pub fn to_json(&self) -> std::result::Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| std::format!("Failed to serialize bundle: {}", e))
+    }
+
+    /// Parses a bundle written by this or an earlier format version.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The bundle document.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ProjectBundle)` - The parsed bundle.
+    /// * `Err(String)` - The document is not a bundle, or was written by a newer format version.
+    pub fn from_json(json: &str) -> std::result::Result<Self, String> {
+        let bundle: ProjectBundle = serde_json::from_str(json).map_err(|e| std::format!("Invalid project bundle: {}", e))?;
+        if bundle.format_version > Self::FORMAT_VERSION {
+            return std::result::Result::Err(std::format!(
+                "Bundle format version {} is newer than the supported version {}; upgrade rig to import it",
+                bundle.format_version,
+                Self::FORMAT_VERSION
+            ));
+        }
+        std::result::Result::Ok(bundle)
+    }
+
+    /// Gives every entity a fresh ID and rewrites the references between them.
+    ///
+    /// Project, PRD, task, and artifact IDs are replaced; PRD and artifact
+    /// project links, task dependencies and links, parent and subtask links, PRD
+    /// sources, revision task IDs, and artifact sources pointing at a bundled
+    /// PRD or task follow the new IDs. Task references that point outside
+    /// the bundle are dropped, since they would dangle in the target
+    /// database. Artifact sources that are not entity IDs (file paths, URLs)
+    /// are kept as-is. Task idempotency keys are re-derived from the old key
+    /// and the new project ID: they stay unique within the bundle but no
+    /// longer match the exported tasks, which may live in the same database.
+    ///
+    /// # Returns
+    ///
+    /// The bundle with remapped IDs.
+    pub fn with_fresh_ids(mut self) -> Self {
+        let mut ids: std::collections::HashMap<String, String> = std::collections::HashMap::new();
+        let mut fresh = |id: &mut String| {
+            let new_id = uuid::Uuid::new_v4().to_string();
+            ids.insert(std::mem::replace(id, new_id.clone()), new_id);
+        };
+        fresh(&mut self.project.id);
+        self.prds.iter_mut().for_each(|prd| fresh(&mut prd.id));
+        self.tasks.iter_mut().for_each(|task| fresh(&mut task.id));
+        self.artifacts.iter_mut().for_each(|artifact| fresh(&mut artifact.id));
+
+        let remap = |id: &String| ids.get(id).cloned();
+        let project_id = self.project.id.clone();
+        self.project.prd_ids = self.project.prd_ids.iter().filter_map(remap).collect();
+        for prd in &mut self.prds {
+            prd.project_id = project_id.clone();
+        }
+        for task in &mut self.tasks {
+            task.source_prd_id = task.source_prd_id.as_ref().and_then(remap);
+            task.parent_task_id = task.parent_task_id.as_ref().and_then(remap);
+            task.subtask_ids = task.subtask_ids.iter().filter_map(remap).collect();
+            task.dependencies = task.dependencies.iter().filter_map(remap).collect();
+            task.links = task
+                .links
+                .iter()
+                .filter_map(|link| {
+                    remap(&link.task_id).map(|task_id| crate::domain::task_link::TaskLink { task_id, link_type: link.link_type })
+                })
+                .collect();
+            for revision in &mut task.revisions {
+                revision.task_id = task.id.clone();
+            }
+            task.idempotency_key = task
+                .idempotency_key
+                .as_ref()
+                .map(|key| crate::domain::task::Task::idempotency_key_for(&std::format!("import:{}", project_id), key));
+        }
+        for artifact in &mut self.artifacts {
+            artifact.project_id = project_id.clone();
+            if let std::option::Option::Some(source_id) = remap(&artifact.source_id) {
+                artifact.source_id = source_id;
+            }
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn prd(id: &str, project_id: &str) -> crate::domain::prd::PRD {
+        crate::domain::prd::PRD {
+            id: std::string::String::from(id),
+            project_id: std::string::String::from(project_id),
+            title: std::format!("PRD {}", id),
+            objectives: std::vec::Vec::new(),
+            tech_stack: std::vec::Vec::new(),
+            constraints: std::vec::Vec::new(),
+            raw_content: std::string::String::new(),
+            requirements: std::vec::Vec::new(),
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    fn task(title: &str, prd_id: std::option::Option<&str>) -> crate::domain::task::Task {
+        let mut task = crate::domain::task::Task::from_action_item(
+            &transcript_extractor::domain::action_item::ActionItem {
+                title: std::string::String::from(title),
+                assignee: std::option::Option::None,
+                due_date: std::option::Option::None,
+            },
+            std::option::Option::None,
+        );
+        task.source_prd_id = prd_id.map(std::string::String::from);
+        task
+    }
+
+    #[test]
+    fn test_new_keeps_project_tasks_and_their_subtasks() {
+        // Test: Validates the bundle keeps PRD tasks and their subtasks but drops other projects' tasks.
+        // Justification: Subtasks carry no source_prd_id, yet losing them would break the exported hierarchy.
+        let project = crate::domain::project::Project::new(std::string::String::from("Alpha"), std::option::Option::None);
+        let parent = task("Build API", std::option::Option::Some("prd-1"));
+        let mut child = task("Write handlers", std::option::Option::None);
+        child.parent_task_id = std::option::Option::Some(parent.id.clone());
+        let other = task("Other project work", std::option::Option::Some("prd-9"));
+
+        let bundle = super::ProjectBundle::new(
+            project.clone(),
+            std::vec![prd("prd-1", &project.id)],
+            std::vec![parent, child, other],
+            std::vec::Vec::new(),
+            std::option::Option::None,
+        );
+
+        let titles: std::vec::Vec<&str> = bundle.tasks.iter().map(|t| t.title.as_str()).collect();
+        std::assert_eq!(titles, std::vec!["Build API", "Write handlers"]);
+    }
+
+    #[test]
+    fn test_with_fresh_ids_preserves_internal_references() {
+        // Test: Validates remapping replaces every ID while dependencies, parent links, PRD and artifact links follow.
+        // Justification: Import relies on remapping to avoid collisions without breaking the task graph.
+        let project = crate::domain::project::Project::new(std::string::String::from("Alpha"), std::option::Option::None);
+        let mut parent = task("Build API", std::option::Option::Some("prd-1"));
+        let mut child = task("Write handlers", std::option::Option::Some("prd-1"));
+        child.parent_task_id = std::option::Option::Some(parent.id.clone());
+        child.dependencies = std::vec![parent.id.clone(), std::string::String::from("outside-task")];
+        parent.subtask_ids = std::vec![child.id.clone()];
+        let artifact = crate::domain::artifact::Artifact::new(
+            project.id.clone(),
+            std::string::String::from("prd-1"),
+            crate::domain::artifact::ArtifactType::PRD,
+            std::string::String::from("chunk"),
+            std::vec![0.5, 0.25],
+            std::option::Option::None,
+        );
+        let old_ids = std::vec![project.id.clone(), parent.id.clone(), child.id.clone(), artifact.id.clone()];
+
+        let bundle = super::ProjectBundle::new(
+            project.clone(),
+            std::vec![prd("prd-1", &project.id)],
+            std::vec![parent, child],
+            std::vec![artifact],
+            std::option::Option::None,
+        )
+        .with_fresh_ids();
+
+        let (parent, child) = (&bundle.tasks[0], &bundle.tasks[1]);
+        let prd_id = &bundle.prds[0].id;
+        std::assert!(!old_ids.contains(&bundle.project.id) && !old_ids.contains(&parent.id) && prd_id != "prd-1");
+        std::assert_eq!(bundle.prds[0].project_id, bundle.project.id);
+        std::assert_eq!(child.parent_task_id.as_ref(), std::option::Option::Some(&parent.id));
+        std::assert_eq!(child.dependencies, std::vec![parent.id.clone()]);
+        std::assert_eq!(parent.subtask_ids, std::vec![child.id.clone()]);
+        std::assert_eq!(child.source_prd_id.as_ref(), std::option::Option::Some(prd_id));
+        std::assert_eq!(&bundle.artifacts[0].source_id, prd_id);
+        std::assert_eq!(bundle.artifacts[0].project_id, bundle.project.id);
+        std::assert_eq!(bundle.artifacts[0].embedding, std::vec![0.5, 0.25]);
+    }
+
+    #[test]
+    fn test_with_fresh_ids_rederives_idempotency_keys() {
+        // Test: Validates remapped tasks get new idempotency keys that differ per import and stay distinct per task.
+        // Justification: Copied keys hit the unique index when a bundle is imported into its source database.
+        let project = crate::domain::project::Project::new(std::string::String::from("Alpha"), std::option::Option::None);
+        let mut keyed = task("Build API", std::option::Option::Some("prd-1"));
+        keyed.idempotency_key = std::option::Option::Some(std::string::String::from("key-1"));
+        let mut other = task("Write handlers", std::option::Option::Some("prd-1"));
+        other.idempotency_key = std::option::Option::Some(std::string::String::from("key-2"));
+        let unkeyed = task("Deploy", std::option::Option::Some("prd-1"));
+        let bundle = super::ProjectBundle::new(
+            project.clone(),
+            std::vec![prd("prd-1", &project.id)],
+            std::vec![keyed, other, unkeyed],
+            std::vec::Vec::new(),
+            std::option::Option::None,
+        );
+
+        let first = bundle.clone().with_fresh_ids();
+        let second = bundle.with_fresh_ids();
+
+        let key = |bundle: &super::ProjectBundle, index: usize| bundle.tasks[index].idempotency_key.clone();
+        std::assert!(key(&first, 0).is_some() && key(&first, 0).as_deref() != std::option::Option::Some("key-1"));
+        std::assert_ne!(key(&first, 0), key(&first, 1));
+        std::assert_ne!(key(&first, 0), key(&second, 0));
+        std::assert_eq!(key(&first, 2), std::option::Option::None);
+    }
+
+    #[test]
+    fn test_from_json_rejects_newer_format() {
+        // Test: Validates a bundle from a newer format version is refused with a clear error.
+        // Justification: Silently importing an unknown format could lose data.
+        let project = crate::domain::project::Project::new(std::string::String::from("Alpha"), std::option::Option::None);
+        let mut bundle = super::ProjectBundle::new(project, std::vec::Vec::new(), std::vec::Vec::new(), std::vec::Vec::new(), std::option::Option::None);
+        bundle.format_version = super::ProjectBundle::FORMAT_VERSION + 1;
+
+        let error = super::ProjectBundle::from_json(&bundle.to_json().unwrap()).unwrap_err();
+        std::assert!(error.contains("newer than the supported version"), "{}", error);
+        std::assert!(super::ProjectBundle::from_json("{}").is_err());
+    }
+}
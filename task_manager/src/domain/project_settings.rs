@@ -0,0 +1,95 @@
+//! Defines the ProjectSettings value object for project-scoped task defaults.
+//!
+//! ProjectSettings holds values applied to tasks that enter a project without
+//! them, such as tasks produced by PRD parsing or transcript extraction that
+//! leave the assignee or priority empty.
+//!
+//! Revision History
+//! - 2026-10-16T16:30:00Z @AI: Initial ProjectSettings with default assignee and priority.
+
+/// Project-scoped defaults applied to newly created tasks.
+///
+/// # Fields
+///
+/// * `default_assignee` - Assignee (agent persona) used when a task has none.
+/// * `default_priority` - Priority used when a task has none.
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::domain::project_settings::ProjectSettings;
+/// let settings = ProjectSettings {
+///     default_assignee: std::option::Option::Some(std::string::String::from("Backend Developer")),
+///     default_priority: std::option::Option::None,
+/// };
+/// let action = transcript_extractor::domain::action_item::ActionItem {
+///     title: std::string::String::from("Add rate limiting"),
+///     assignee: std::option::Option::None,
+///     due_date: std::option::Option::None,
+/// };
+/// let mut task = task_manager::domain::task::Task::from_action_item(&action, std::option::Option::None);
+/// settings.apply_defaults(&mut task);
+/// assert_eq!(task.agent_persona.as_deref(), Some("Backend Developer"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct ProjectSettings {
+    /// Assignee (agent persona) used when a task has none.
+    #[serde(default)]
+    pub default_assignee: std::option::Option<String>,
+
+    /// Priority used when a task has none.
+    #[serde(default)]
+    pub default_priority: std::option::Option<crate::domain::priority::Priority>,
+}
+
+impl ProjectSettings {
+    /// Fills the task's empty assignee and priority from the project defaults.
+    ///
+    /// Values already present on the task are never overridden. A blank
+    /// assignee counts as empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `task` - The task to fill in.
+    pub fn apply_defaults(&self, task: &mut crate::domain::task::Task) {
+        let has_assignee = task
+            .agent_persona
+            .as_deref()
+            .is_some_and(|assignee| !assignee.trim().is_empty());
+        if !has_assignee && self.default_assignee.is_some() {
+            task.agent_persona = self.default_assignee.clone();
+        }
+
+        if task.priority.is_none() {
+            task.priority = self.default_priority;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn task(assignee: std::option::Option<&str>) -> crate::domain::task::Task {
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Add rate limiting"),
+            assignee: assignee.map(std::string::String::from),
+            due_date: std::option::Option::None,
+        };
+        crate::domain::task::Task::from_action_item(&action, std::option::Option::None)
+    }
+
+    #[test]
+    fn test_apply_defaults_fills_blank_assignee() {
+        // Test: Validates a whitespace-only assignee is replaced by the default.
+        // Justification: LLM extraction sometimes emits "" instead of omitting the field.
+        let settings = super::ProjectSettings {
+            default_assignee: std::option::Option::Some(std::string::String::from("QA Engineer")),
+            default_priority: std::option::Option::Some(crate::domain::priority::Priority::Low),
+        };
+        let mut task = task(std::option::Option::Some("  "));
+
+        settings.apply_defaults(&mut task);
+
+        std::assert_eq!(task.agent_persona.as_deref(), std::option::Option::Some("QA Engineer"));
+        std::assert_eq!(task.priority, std::option::Option::Some(crate::domain::priority::Priority::Low));
+    }
+}
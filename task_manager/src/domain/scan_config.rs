@@ -11,6 +11,7 @@
 //! - `ContentChunk` pairs content with its exact source location
 //!
 //! Revision History
+//! - 2026-10-18T00:10:00Z @AI: Add include_images so OCR scans list images without narrowing the extension allowlist.
 //! - 2025-11-30T19:00:00Z @AI: Add SourceLocation, FileFingerprint, ContentChunk for incremental rescan support.
 //! - 2025-11-30T18:30:00Z @AI: Initial scan_config module for Phase 1 artifact generator.
 
@@ -75,6 +76,7 @@ impl std::default::Default for ChunkStrategy {
 /// * `max_file_size` - Skip files larger than this (bytes).
 /// * `chunk_strategy` - How to split file content into artifacts.
 /// * `respect_gitignore` - Whether to honor .gitignore patterns (default: true).
+/// * `include_images` - Also list image files, on top of `include_extensions` (default: false).
 ///
 /// # Examples
 ///
@@ -109,6 +111,11 @@ pub struct ScanConfig {
 
     /// Whether to respect .gitignore patterns.
     pub respect_gitignore: bool,
+
+    /// Whether to also list image files (png, jpg, jpeg, gif, webp) for OCR,
+    /// in addition to the files `include_extensions` allows.
+    #[serde(default)]
+    pub include_images: bool,
 }
 
 impl ScanConfig {
@@ -145,6 +152,7 @@ impl ScanConfig {
             max_file_size: Self::DEFAULT_MAX_FILE_SIZE,
             chunk_strategy: ChunkStrategy::Paragraph,
             respect_gitignore: true,
+            include_images: false,
         }
     }
 
@@ -236,6 +244,7 @@ impl ScanConfig {
             max_file_size: Self::DEFAULT_MAX_FILE_SIZE,
             chunk_strategy: ChunkStrategy::FixedSize(2000),
             respect_gitignore: true,
+            include_images: false,
         }
     }
 
@@ -262,6 +271,7 @@ impl ScanConfig {
             max_file_size: Self::DEFAULT_MAX_FILE_SIZE,
             chunk_strategy: ChunkStrategy::Paragraph,
             respect_gitignore: true,
+            include_images: false,
         }
     }
 
@@ -293,6 +303,20 @@ impl ScanConfig {
         self
     }
 
+    /// Also lists image files alongside the included extensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` - Whether image files are listed for OCR.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_images(mut self, include: bool) -> Self {
+        self.include_images = include;
+        self
+    }
+
     /// Sets the chunk strategy.
     ///
     /// # Arguments
@@ -0,0 +1,240 @@
+//! Blocked-task detection domain service.
+//!
+//! A task is blocked when it is open and waits on at least one dependency
+//! that is not finished. Blocked is derived from the dependency graph each
+//! time it is asked for, never stored as a status, so a task unblocks itself
+//! as soon as its last dependency completes.
+//!
+//! Open means not completed, archived, errored, decomposed (its work lives
+//! in the subtasks), or through orchestration. A dependency is finished when
+//! it is completed or through orchestration. An archived dependency is a
+//! cancelled one: it will never finish, so its dependents are reported as
+//! blocked by a cancelled task and need someone to drop the dependency or
+//! revive the work. Dependency IDs that match no task are ignored so a
+//! deleted task cannot block its dependents forever.
+//!
+//! Only `blocks` links (a task's `dependencies`) are considered; relates-to
+//! and duplicates links are informational and never block.
+//!
+//! Revision History
+//! - 2026-10-17T16:30:00Z @AI: Document that only blocks links count; test that relates-to/duplicates links never block.
+//! - 2026-10-17T02:50:00Z @AI: Create derived blocked-task detection with blocked-by-cancelled reporting.
+
+/// Why a dependency blocks its dependent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BlockReason {
+    /// The dependency is still to be done (or errored and needs a retry)
+    Incomplete,
+    /// The dependency was archived and will never be done
+    Cancelled,
+}
+
+/// A dependency that keeps a task from starting.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct Blocker {
+    /// ID of the blocking dependency
+    pub task_id: String,
+    /// Why it blocks
+    pub reason: BlockReason,
+}
+
+/// Blocking state of every task, derived from the dependency graph.
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::domain::services::blocked_tasks::{BlockReason, BlockedTasks};
+/// # use task_manager::domain::task::Task;
+/// # use task_manager::domain::task_status::TaskStatus;
+/// # use transcript_extractor::domain::action_item::ActionItem;
+/// let item = |title: &str| ActionItem { title: title.to_string(), assignee: None, due_date: None };
+///
+/// let mut schema = Task::from_action_item(&item("Design schema"), None);
+/// schema.id = std::string::String::from("schema");
+/// let mut api = Task::from_action_item(&item("Build API"), None);
+/// api.id = std::string::String::from("api");
+/// api.dependencies = std::vec![std::string::String::from("schema")];
+///
+/// let mut tasks = std::vec![api, schema];
+/// assert!(BlockedTasks::new(&tasks).is_blocked(&tasks[0]));
+///
+/// tasks[1].status = TaskStatus::Archived;
+/// let blockers = BlockedTasks::new(&tasks).blockers(&tasks[0]);
+/// assert_eq!(blockers[0].reason, BlockReason::Cancelled);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BlockedTasks<'a> {
+    graph: crate::domain::services::dependency_graph::DependencyGraph,
+    statuses: std::collections::HashMap<&'a str, &'a crate::domain::task_status::TaskStatus>,
+}
+
+impl<'a> BlockedTasks<'a> {
+    /// Indexes the dependencies and statuses of all tasks of a project.
+    ///
+    /// # Arguments
+    ///
+    /// * `tasks` - All tasks of the project, so dependency statuses can be resolved
+    pub fn new(tasks: &'a [crate::domain::task::Task]) -> Self {
+        BlockedTasks {
+            graph: crate::domain::services::dependency_graph::DependencyGraph::new(tasks),
+            statuses: tasks.iter().map(|task| (task.id.as_str(), &task.status)).collect(),
+        }
+    }
+
+    /// Returns the unfinished dependencies of a task, in declaration order.
+    ///
+    /// Tasks in a dependency cycle block each other.
+    pub fn blockers(&self, task: &crate::domain::task::Task) -> std::vec::Vec<Blocker> {
+        self.graph
+            .dependencies(&task.id)
+            .iter()
+            .filter_map(|dependency| {
+                let status = self.statuses.get(dependency.as_str())?;
+                let reason = match status {
+                    crate::domain::task_status::TaskStatus::Archived => BlockReason::Cancelled,
+                    status if is_finished(status) => return std::option::Option::None,
+                    _ => BlockReason::Incomplete,
+                };
+                std::option::Option::Some(Blocker { task_id: dependency.clone(), reason })
+            })
+            .collect()
+    }
+
+    /// Returns true if the task is open and waits on an unfinished dependency.
+    pub fn is_blocked(&self, task: &crate::domain::task::Task) -> bool {
+        is_open(&task.status) && !self.blockers(task).is_empty()
+    }
+
+    /// Returns the blocked tasks among `tasks` with their blockers, in input order.
+    ///
+    /// # Arguments
+    ///
+    /// * `tasks` - Tasks to check; usually the project's tasks or a filtered subset
+    pub fn blocked<'t>(
+        &self,
+        tasks: &'t [crate::domain::task::Task],
+    ) -> std::vec::Vec<(&'t crate::domain::task::Task, std::vec::Vec<Blocker>)> {
+        tasks
+            .iter()
+            .filter(|task| is_open(&task.status))
+            .filter_map(|task| {
+                let blockers = self.blockers(task);
+                (!blockers.is_empty()).then_some((task, blockers))
+            })
+            .collect()
+    }
+}
+
+/// Returns true if work on a task in this status is still to be done.
+pub fn is_open(status: &crate::domain::task_status::TaskStatus) -> bool {
+    !is_finished(status)
+        && !std::matches!(
+            status,
+            crate::domain::task_status::TaskStatus::Archived
+                | crate::domain::task_status::TaskStatus::Errored
+                | crate::domain::task_status::TaskStatus::Decomposed
+        )
+}
+
+/// Returns true if a task in this status no longer blocks its dependents.
+pub fn is_finished(status: &crate::domain::task_status::TaskStatus) -> bool {
+    std::matches!(
+        status,
+        crate::domain::task_status::TaskStatus::Completed | crate::domain::task_status::TaskStatus::OrchestrationComplete
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    fn task(id: &str, dependencies: &[&str]) -> crate::domain::task::Task {
+        let item = transcript_extractor::domain::action_item::ActionItem {
+            title: std::format!("Task {}", id),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let mut task = crate::domain::task::Task::from_action_item(&item, std::option::Option::None);
+        task.id = std::string::String::from(id);
+        task.dependencies = dependencies.iter().map(|d| std::string::String::from(*d)).collect();
+        task
+    }
+
+    #[test]
+    fn test_task_waiting_on_open_dependency_is_blocked() {
+        // Test: Validates an open task with an unfinished dependency is blocked and names that dependency.
+        // Justification: The blocking task IDs are what the user needs to unblock the work.
+        let mut errored = task("errored", &[]);
+        errored.status = crate::domain::task_status::TaskStatus::Errored;
+        let tasks = std::vec![task("api", &["schema", "errored"]), task("schema", &[]), errored];
+
+        let blocked = super::BlockedTasks::new(&tasks);
+
+        std::assert!(blocked.is_blocked(&tasks[0]));
+        std::assert_eq!(
+            blocked.blockers(&tasks[0]),
+            std::vec![
+                super::Blocker { task_id: std::string::String::from("schema"), reason: super::BlockReason::Incomplete },
+                super::Blocker { task_id: std::string::String::from("errored"), reason: super::BlockReason::Incomplete },
+            ]
+        );
+        let ids: std::vec::Vec<&str> = blocked.blocked(&tasks).iter().map(|(t, _)| t.id.as_str()).collect();
+        std::assert_eq!(ids, std::vec!["api"]);
+    }
+
+    #[test]
+    fn test_finished_missing_or_closed_tasks_are_not_blocked() {
+        // Test: Validates finished and deleted dependencies don't block, and closed tasks are never reported as blocked.
+        // Justification: A blocked list full of done work or dangling IDs would hide the tasks that really need attention.
+        let mut done = task("done", &[]);
+        done.status = crate::domain::task_status::TaskStatus::Completed;
+        let mut closed = task("closed", &["open"]);
+        closed.status = crate::domain::task_status::TaskStatus::Completed;
+        let tasks = std::vec![task("ready", &["done", "deleted-task"]), task("open", &[]), done, closed];
+
+        let blocked = super::BlockedTasks::new(&tasks);
+
+        std::assert!(!blocked.is_blocked(&tasks[0]));
+        std::assert!(blocked.blockers(&tasks[0]).is_empty());
+        std::assert!(!blocked.is_blocked(&tasks[3]));
+        std::assert!(blocked.blocked(&tasks).is_empty());
+    }
+
+    #[test]
+    fn test_dependency_on_cancelled_task_is_blocked_by_cancelled() {
+        // Test: Validates an archived dependency blocks its dependent with the Cancelled reason.
+        // Justification: Cancelled work never finishes, so the user must be told to intervene instead of waiting.
+        let mut cancelled = task("cancelled", &[]);
+        cancelled.status = crate::domain::task_status::TaskStatus::Archived;
+        let tasks = std::vec![task("dependent", &["cancelled"]), cancelled];
+
+        let blocked = super::BlockedTasks::new(&tasks).blocked(&tasks);
+
+        std::assert_eq!(blocked.len(), 1);
+        std::assert_eq!(blocked[0].0.id, "dependent");
+        std::assert_eq!(
+            blocked[0].1,
+            std::vec![super::Blocker { task_id: std::string::String::from("cancelled"), reason: super::BlockReason::Cancelled }]
+        );
+    }
+
+    #[test]
+    fn test_only_blocks_links_affect_blocked_status() {
+        // Test: Validates relates-to and duplicates links to open tasks neither block nor appear as blockers, while a blocks link does.
+        // Justification: Informational links must not hold up scheduling; only blocks edges mean "wait for this".
+        let mut related = task("related", &[]);
+        related.add_link("open", crate::domain::task_link::LinkType::RelatesTo);
+        related.add_link("open", crate::domain::task_link::LinkType::Duplicates);
+        let mut dependent = task("dependent", &[]);
+        dependent.add_link("open", crate::domain::task_link::LinkType::Blocks);
+        let tasks = std::vec![related, dependent, task("open", &[])];
+
+        let blocked = super::BlockedTasks::new(&tasks);
+
+        std::assert!(!blocked.is_blocked(&tasks[0]));
+        std::assert!(blocked.blockers(&tasks[0]).is_empty());
+        std::assert_eq!(tasks[0].all_links().len(), 2, "informational links are still stored");
+        std::assert!(blocked.is_blocked(&tasks[1]));
+        let ids: std::vec::Vec<&str> = blocked.blocked(&tasks).iter().map(|(t, _)| t.id.as_str()).collect();
+        std::assert_eq!(ids, std::vec!["dependent"]);
+    }
+}
@@ -0,0 +1,158 @@
+//! Capacity-based planning domain service.
+//!
+//! Fills a sprint of a given capacity (in estimate points) from the ready
+//! tasks: open and unblocked, as for `next_task`. Candidates are taken
+//! greedily by priority (High, then Medium or none, then Low) and, within a
+//! priority, smallest estimate first; a task that does not fit in what is
+//! left is deferred and the next one is tried. Tasks without an estimate
+//! cannot be sized, so they are reported separately as unestimated.
+//!
+//! Revision History
+//! - 2026-10-17T16:50:00Z @AI: Initial greedy capacity plan over ready tasks.
+
+/// Tasks selected for, and left out of, a sprint of fixed capacity.
+#[derive(Debug, Clone)]
+pub struct CapacityPlan<'a> {
+    /// Capacity the plan was filled to, in estimate points
+    pub capacity: f32,
+
+    /// Selected tasks, in the order they were picked
+    pub selected: std::vec::Vec<&'a crate::domain::task::Task>,
+
+    /// Ready, estimated tasks that did not fit in the remaining capacity
+    pub deferred: std::vec::Vec<&'a crate::domain::task::Task>,
+
+    /// Ready tasks without an estimate, in recommendation order
+    pub unestimated: std::vec::Vec<&'a crate::domain::task::Task>,
+}
+
+impl CapacityPlan<'_> {
+    /// Returns the summed estimate of the selected tasks.
+    pub fn planned_points(&self) -> f32 {
+        self.selected.iter().filter_map(|task| task.estimate).sum()
+    }
+}
+
+/// Selects ready tasks whose estimates sum to at most `capacity`.
+///
+/// # Arguments
+///
+/// * `tasks` - All tasks of the project, so dependency statuses can be resolved
+/// * `capacity` - Points available in the sprint
+/// * `assignee` - Only plan tasks assigned to this persona (case-insensitive)
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::domain::services::capacity_plan::plan_capacity;
+/// # use task_manager::domain::task::Task;
+/// # use transcript_extractor::domain::action_item::ActionItem;
+/// let sized = |title: &str, estimate: f32| {
+///     let mut task = Task::from_action_item(&ActionItem { title: title.to_string(), assignee: None, due_date: None }, None);
+///     task.estimate = Some(estimate);
+///     task
+/// };
+/// let tasks = std::vec![sized("Build API", 5.0), sized("Write docs", 2.0), sized("Fix login", 3.0)];
+///
+/// let plan = plan_capacity(&tasks, 5.0, None);
+/// assert_eq!(plan.planned_points(), 5.0);
+/// assert_eq!(plan.deferred.len(), 1);
+/// ```
+pub fn plan_capacity<'a>(
+    tasks: &'a [crate::domain::task::Task],
+    capacity: f32,
+    assignee: std::option::Option<&str>,
+) -> CapacityPlan<'a> {
+    let (mut estimated, unestimated): (std::vec::Vec<_>, std::vec::Vec<_>) =
+        crate::domain::services::next_task::ready_tasks(tasks, assignee)
+            .into_iter()
+            .partition(|task| task.estimate.is_some());
+
+    // Stable sort keeps the recommendation order among equal priority and estimate
+    estimated.sort_by(|a, b| {
+        crate::domain::services::next_task::priority_rank(a)
+            .cmp(&crate::domain::services::next_task::priority_rank(b))
+            .then_with(|| a.estimate.unwrap_or(0.0).total_cmp(&b.estimate.unwrap_or(0.0)))
+    });
+
+    let mut remaining = capacity;
+    let mut selected = std::vec::Vec::new();
+    let mut deferred = std::vec::Vec::new();
+    for task in estimated {
+        let estimate = task.estimate.unwrap_or(0.0);
+        if estimate <= remaining {
+            remaining -= estimate;
+            selected.push(task);
+        } else {
+            deferred.push(task);
+        }
+    }
+
+    CapacityPlan {
+        capacity,
+        selected,
+        deferred,
+        unestimated,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn task(id: &str, priority: crate::domain::priority::Priority, estimate: std::option::Option<f32>) -> crate::domain::task::Task {
+        let item = transcript_extractor::domain::action_item::ActionItem {
+            title: std::format!("Task {}", id),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let mut task = crate::domain::task::Task::from_action_item(&item, std::option::Option::None);
+        task.id = std::string::String::from(id);
+        task.priority = std::option::Option::Some(priority);
+        task.estimate = estimate;
+        task
+    }
+
+    fn ids(tasks: &[&crate::domain::task::Task]) -> std::vec::Vec<String> {
+        tasks.iter().map(|task| task.id.clone()).collect()
+    }
+
+    #[test]
+    fn test_selection_respects_capacity_in_priority_order() {
+        // Test: Validates tasks are picked by priority then smallest estimate, skipping ones that no longer fit, without exceeding capacity.
+        // Justification: The plan must never commit more points than the sprint has.
+        let tasks = std::vec![
+            task("low-small", crate::domain::priority::Priority::Low, std::option::Option::Some(1.0)),
+            task("high-big", crate::domain::priority::Priority::High, std::option::Option::Some(5.0)),
+            task("high-small", crate::domain::priority::Priority::High, std::option::Option::Some(2.0)),
+            task("medium-big", crate::domain::priority::Priority::Medium, std::option::Option::Some(3.0)),
+            task("medium-small", crate::domain::priority::Priority::Medium, std::option::Option::Some(0.5)),
+        ];
+
+        let plan = super::plan_capacity(&tasks, 8.5, std::option::Option::None);
+
+        std::assert_eq!(ids(&plan.selected), ["high-small", "high-big", "medium-small", "low-small"]);
+        std::assert_eq!(ids(&plan.deferred), ["medium-big"]);
+        std::assert_eq!(plan.planned_points(), 8.5);
+    }
+
+    #[test]
+    fn test_unestimated_and_blocked_tasks_are_not_selected() {
+        // Test: Validates tasks without an estimate are reported as unestimated, and blocked or finished tasks are left out entirely.
+        // Justification: Unsized work cannot be fitted to capacity, but the planner must still surface it.
+        let mut blocked = task("blocked", crate::domain::priority::Priority::High, std::option::Option::Some(1.0));
+        blocked.dependencies.push(std::string::String::from("unsized"));
+        let mut done = task("done", crate::domain::priority::Priority::High, std::option::Option::Some(1.0));
+        done.status = crate::domain::task_status::TaskStatus::Completed;
+        let tasks = std::vec![
+            blocked,
+            done,
+            task("unsized", crate::domain::priority::Priority::High, std::option::Option::None),
+            task("sized", crate::domain::priority::Priority::Low, std::option::Option::Some(2.0)),
+        ];
+
+        let plan = super::plan_capacity(&tasks, 10.0, std::option::Option::None);
+
+        std::assert_eq!(ids(&plan.selected), ["sized"]);
+        std::assert_eq!(ids(&plan.unestimated), ["unsized"]);
+        std::assert!(plan.deferred.is_empty());
+    }
+}
@@ -6,6 +6,7 @@
 //! provides a valid execution order for tasks with dependencies.
 //!
 //! Revision History
+//! - 2026-10-17T02:30:00Z @AI: Add dependencies() accessor for next-task recommendation.
 //! - 2025-11-23T15:50:00Z @AI: Create DependencyGraph for Phase 2 Sprint 5 Task 2.4.
 
 /// Directed graph of task dependencies with cycle detection and topological sorting.
@@ -98,6 +99,13 @@ impl DependencyGraph {
         DependencyGraph { adjacency }
     }
 
+    /// Returns the IDs of the tasks `task_id` depends on.
+    ///
+    /// Unknown task IDs have no dependencies.
+    pub fn dependencies(&self, task_id: &str) -> &[String] {
+        self.adjacency.get(task_id).map(std::vec::Vec::as_slice).unwrap_or(&[])
+    }
+
     /// Detects cycles in the dependency graph using DFS.
     ///
     /// Returns a list of cycles, where each cycle is represented as a vector
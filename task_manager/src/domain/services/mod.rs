@@ -5,8 +5,18 @@
 //! These services are stateless and operate on Task entities.
 //!
 //! Revision History
+//! - 2026-10-17T16:50:00Z @AI: Add capacity_plan module for estimate-based sprint planning.
+//! - 2026-10-17T03:30:00Z @AI: Add task_stats module for velocity and cycle-time reporting.
+//! - 2026-10-17T02:50:00Z @AI: Add blocked_tasks module for derived blocked-task detection.
+//! - 2026-10-17T02:30:00Z @AI: Add next_task module for dependency-aware task recommendation.
+//! - 2026-10-16T11:10:00Z @AI: Add prd_task_differ module for incremental PRD re-parsing.
 //! - 2025-11-23T15:35:00Z @AI: Create services module for Phase 2 Sprint 5.
 
 pub mod complexity_scorer;
 pub mod dependency_graph;
 pub mod triage_service;
+pub mod prd_task_differ;
+pub mod next_task;
+pub mod blocked_tasks;
+pub mod task_stats;
+pub mod capacity_plan;
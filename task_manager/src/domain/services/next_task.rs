@@ -0,0 +1,203 @@
+//! Next-task recommendation domain service.
+//!
+//! Picks the task to work on next: the highest-priority open task that is
+//! not blocked (see `blocked_tasks` for what open and blocked mean). Tasks
+//! waiting on a cancelled dependency, and tasks in a dependency cycle, never
+//! become ready. Relates-to and duplicates links are informational and do
+//! not delay a task.
+//!
+//! Ranking: High, then Medium (or no priority), then Low; ties go to the
+//! earliest due date (tasks without a parseable `YYYY-MM-DD` due date last),
+//! then the earliest creation time.
+//!
+//! Revision History
+//! - 2026-10-17T16:50:00Z @AI: Share priority_rank() with capacity_plan.
+//! - 2026-10-17T16:30:00Z @AI: Test that informational links do not keep a task from being recommended.
+//! - 2026-10-17T02:50:00Z @AI: Derive blocking from blocked_tasks; archived dependencies now block as cancelled.
+//! - 2026-10-17T02:30:00Z @AI: Create next-task recommendation over the dependency graph.
+
+/// Returns the open, unblocked tasks in recommendation order.
+///
+/// # Arguments
+///
+/// * `tasks` - All tasks of the project, so dependency statuses can be resolved
+/// * `assignee` - Only consider tasks assigned to this persona (case-insensitive)
+///
+/// # Returns
+///
+/// Ready tasks, best first. Empty if every open task is blocked.
+pub fn ready_tasks<'a>(
+    tasks: &'a [crate::domain::task::Task],
+    assignee: std::option::Option<&str>,
+) -> std::vec::Vec<&'a crate::domain::task::Task> {
+    let blocked = crate::domain::services::blocked_tasks::BlockedTasks::new(tasks);
+
+    let mut ready: std::vec::Vec<&crate::domain::task::Task> = tasks
+        .iter()
+        .filter(|task| crate::domain::services::blocked_tasks::is_open(&task.status))
+        .filter(|task| match assignee {
+            std::option::Option::Some(assignee) => task
+                .agent_persona
+                .as_deref()
+                .is_some_and(|persona| persona.eq_ignore_ascii_case(assignee)),
+            std::option::Option::None => true,
+        })
+        .filter(|task| !blocked.is_blocked(task))
+        .collect();
+
+    ready.sort_by(|a, b| {
+        priority_rank(a)
+            .cmp(&priority_rank(b))
+            .then_with(|| due_date_key(a).cmp(&due_date_key(b)))
+            .then_with(|| a.created_at.cmp(&b.created_at))
+    });
+    ready
+}
+
+/// Returns the task to work on next, or None if no open task is unblocked.
+///
+/// # Arguments
+///
+/// * `tasks` - All tasks of the project
+/// * `assignee` - Only consider tasks assigned to this persona (case-insensitive)
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::domain::services::next_task::recommend_next;
+/// # use task_manager::domain::task::Task;
+/// # use task_manager::domain::task_status::TaskStatus;
+/// # use transcript_extractor::domain::action_item::ActionItem;
+/// let item = |title: &str| ActionItem { title: title.to_string(), assignee: None, due_date: None };
+///
+/// let mut schema = Task::from_action_item(&item("Design schema"), None);
+/// schema.id = std::string::String::from("schema");
+/// let mut api = Task::from_action_item(&item("Build API"), None);
+/// api.id = std::string::String::from("api");
+/// api.dependencies = std::vec![std::string::String::from("schema")];
+///
+/// let mut tasks = std::vec![api, schema];
+/// assert_eq!(recommend_next(&tasks, None).unwrap().id, "schema");
+///
+/// tasks[1].status = TaskStatus::Completed;
+/// assert_eq!(recommend_next(&tasks, None).unwrap().id, "api");
+/// ```
+pub fn recommend_next<'a>(
+    tasks: &'a [crate::domain::task::Task],
+    assignee: std::option::Option<&str>,
+) -> std::option::Option<&'a crate::domain::task::Task> {
+    ready_tasks(tasks, assignee).into_iter().next()
+}
+
+/// Sort rank of a task's priority; lower ranks come first.
+pub(crate) fn priority_rank(task: &crate::domain::task::Task) -> u8 {
+    match task.priority {
+        std::option::Option::Some(crate::domain::priority::Priority::High) => 0,
+        std::option::Option::Some(crate::domain::priority::Priority::Medium) | std::option::Option::None => 1,
+        std::option::Option::Some(crate::domain::priority::Priority::Low) => 2,
+    }
+}
+
+/// Sort key of a task's due date; tasks without a parseable date sort last.
+fn due_date_key(task: &crate::domain::task::Task) -> (bool, std::option::Option<chrono::NaiveDate>) {
+    let due = task
+        .due_date
+        .as_deref()
+        .and_then(|due| chrono::NaiveDate::parse_from_str(due, "%Y-%m-%d").ok());
+    (due.is_none(), due)
+}
+
+#[cfg(test)]
+mod tests {
+    fn task(id: &str, priority: crate::domain::priority::Priority, dependencies: &[&str]) -> crate::domain::task::Task {
+        let item = transcript_extractor::domain::action_item::ActionItem {
+            title: std::format!("Task {}", id),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let mut task = crate::domain::task::Task::from_action_item(&item, std::option::Option::None);
+        task.id = std::string::String::from(id);
+        task.priority = std::option::Option::Some(priority);
+        task.dependencies = dependencies.iter().map(|d| std::string::String::from(*d)).collect();
+        task
+    }
+
+    #[test]
+    fn test_recommends_highest_priority_unblocked_task() {
+        // Test: Validates blocked, finished, and errored tasks are skipped and the best unblocked task is chosen.
+        // Justification: A high-priority task waiting on an open dependency must not be recommended over work that can start now.
+        let mut done = task("done", crate::domain::priority::Priority::High, &[]);
+        done.status = crate::domain::task_status::TaskStatus::Completed;
+        let mut failed = task("failed", crate::domain::priority::Priority::High, &[]);
+        failed.status = crate::domain::task_status::TaskStatus::Errored;
+        let tasks = std::vec![
+            task("blocked", crate::domain::priority::Priority::High, &["open-dep"]),
+            task("open-dep", crate::domain::priority::Priority::Low, &[]),
+            task("unblocked", crate::domain::priority::Priority::High, &["done", "deleted-task"]),
+            task("medium", crate::domain::priority::Priority::Medium, &[]),
+            task("cycle-a", crate::domain::priority::Priority::High, &["cycle-b"]),
+            task("cycle-b", crate::domain::priority::Priority::High, &["cycle-a"]),
+            done,
+            failed,
+        ];
+
+        let ready: std::vec::Vec<&str> = super::ready_tasks(&tasks, std::option::Option::None)
+            .iter()
+            .map(|t| t.id.as_str())
+            .collect();
+
+        std::assert_eq!(ready, std::vec!["unblocked", "medium", "open-dep"]);
+        std::assert_eq!(super::recommend_next(&tasks, std::option::Option::None).unwrap().id, "unblocked");
+    }
+
+    #[test]
+    fn test_informational_links_do_not_delay_recommendation() {
+        // Test: Validates a task that only relates to or duplicates an open task is still recommended first.
+        // Justification: Only blocks links are scheduling-relevant.
+        let mut linked = task("linked", crate::domain::priority::Priority::High, &[]);
+        linked.add_link("open", crate::domain::task_link::LinkType::RelatesTo);
+        linked.add_link("open", crate::domain::task_link::LinkType::Duplicates);
+        let tasks = std::vec![linked, task("open", crate::domain::priority::Priority::Low, &[])];
+
+        std::assert_eq!(super::recommend_next(&tasks, std::option::Option::None).unwrap().id, "linked");
+    }
+
+    #[test]
+    fn test_ties_break_by_due_date_then_creation_time() {
+        // Test: Validates equal-priority tasks order by earliest due date, undated last, then by creation time.
+        // Justification: Deterministic tie-breaking keeps `rig next` from flipping between equally urgent tasks.
+        let base = chrono::Utc::now();
+        let mut later_due = task("later-due", crate::domain::priority::Priority::High, &[]);
+        later_due.due_date = std::option::Option::Some(std::string::String::from("2030-06-01"));
+        later_due.created_at = base;
+        let mut sooner_due = task("sooner-due", crate::domain::priority::Priority::High, &[]);
+        sooner_due.due_date = std::option::Option::Some(std::string::String::from("2030-01-15"));
+        sooner_due.created_at = base + chrono::Duration::hours(2);
+        let mut undated_old = task("undated-old", crate::domain::priority::Priority::High, &[]);
+        undated_old.created_at = base - chrono::Duration::days(3);
+        let mut undated_new = task("undated-new", crate::domain::priority::Priority::High, &[]);
+        undated_new.created_at = base - chrono::Duration::days(1);
+        let tasks = std::vec![undated_new, later_due, undated_old, sooner_due];
+
+        let ready: std::vec::Vec<&str> = super::ready_tasks(&tasks, std::option::Option::None)
+            .iter()
+            .map(|t| t.id.as_str())
+            .collect();
+
+        std::assert_eq!(ready, std::vec!["sooner-due", "later-due", "undated-old", "undated-new"]);
+    }
+
+    #[test]
+    fn test_assignee_scopes_recommendation() {
+        // Test: Validates --assignee limits candidates to that persona's tasks, case-insensitively.
+        // Justification: `rig next --assignee me` must not suggest someone else's higher-priority task.
+        let mut mine = task("mine", crate::domain::priority::Priority::Low, &[]);
+        mine.agent_persona = std::option::Option::Some(std::string::String::from("Backend Dev"));
+        let mut theirs = task("theirs", crate::domain::priority::Priority::High, &[]);
+        theirs.agent_persona = std::option::Option::Some(std::string::String::from("Designer"));
+        let tasks = std::vec![mine, theirs];
+
+        std::assert_eq!(super::recommend_next(&tasks, std::option::Option::Some("backend dev")).unwrap().id, "mine");
+        std::assert!(super::recommend_next(&tasks, std::option::Option::Some("QA")).is_none());
+    }
+}
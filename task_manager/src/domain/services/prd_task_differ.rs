@@ -0,0 +1,333 @@
+//! PrdTaskDiffer domain service for incremental PRD re-parsing.
+//!
+//! Re-running task generation on an edited PRD must not duplicate the tasks
+//! created by the previous run. This service matches freshly parsed tasks
+//! against the existing tasks of the same PRD, identified by project and PRD
+//! title (by normalized task title first, then
+//! by `source_section` key) and classifies them as added, updated, unchanged,
+//! or removed. Removed tasks are only flagged; deleting them is left to the caller.
+//!
+//! Revision History
+//! - 2026-10-17T23:40:00Z @AI: Match existing tasks only within the PRD's project, not by PRD title alone.
+//! - 2026-10-16T11:10:00Z @AI: Create PrdTaskDiffer for incremental PRD re-parsing.
+
+/// Result of diffing freshly parsed PRD tasks against existing tasks.
+///
+/// # Fields
+///
+/// * `added` - Parsed tasks with no existing counterpart; ready to insert.
+/// * `updated` - Existing tasks with content refreshed from the PRD; ready to save.
+/// * `unchanged` - Existing tasks that need no write.
+/// * `removed` - Existing tasks whose source section no longer exists in the PRD.
+#[derive(Debug, Clone, Default)]
+pub struct PrdTaskDiff {
+    /// Parsed tasks with no existing counterpart.
+    pub added: std::vec::Vec<crate::domain::task::Task>,
+
+    /// Existing tasks with content refreshed from the re-parsed PRD.
+    pub updated: std::vec::Vec<crate::domain::task::Task>,
+
+    /// Existing tasks that need no write.
+    pub unchanged: std::vec::Vec<crate::domain::task::Task>,
+
+    /// Existing tasks whose source section was removed from the PRD.
+    pub removed: std::vec::Vec<crate::domain::task::Task>,
+}
+
+impl PrdTaskDiff {
+    /// Returns a one-line added/updated/removed summary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use task_manager::domain::services::prd_task_differ::PrdTaskDiff;
+    /// let diff = PrdTaskDiff::default();
+    /// assert_eq!(diff.summary(), "0 added, 0 updated, 0 removed (0 unchanged)");
+    /// ```
+    pub fn summary(&self) -> String {
+        std::format!(
+            "{} added, {} updated, {} removed ({} unchanged)",
+            self.added.len(),
+            self.updated.len(),
+            self.removed.len(),
+            self.unchanged.len()
+        )
+    }
+}
+
+/// Domain service that diffs re-parsed PRD tasks against existing tasks.
+///
+/// Only top-level tasks whose `source_section` carries the PRD's section key
+/// prefix (its project ID and title) are considered existing tasks of the
+/// PRD; subtasks, tasks from other PRDs, and tasks from a same-titled PRD of
+/// another project are ignored.
+///
+/// # Matching Logic
+///
+/// 1. **Title**: a parsed task matches an existing task with the same title,
+///    ignoring case and punctuation.
+/// 2. **Section**: remaining parsed tasks match remaining existing tasks with
+///    the same `source_section`, in order.
+///
+/// A matched task keeps its ID, status, timestamps, subtasks, persona, and
+/// sort order; its title, description, complexity, and section are refreshed.
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::domain::services::prd_task_differ::PrdTaskDiffer;
+/// # use task_manager::domain::prd::PRD;
+/// let prd = PRD::new(
+///     std::string::String::from("project-1"),
+///     std::string::String::from("Rigger"),
+///     std::vec![std::string::String::from("Enable task decomposition")],
+///     std::vec![],
+///     std::vec![],
+///     std::string::String::new(),
+/// );
+///
+/// let diff = PrdTaskDiffer::new().diff(&[], std::vec::Vec::new(), &prd);
+/// assert!(diff.added.is_empty());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PrdTaskDiffer;
+
+impl PrdTaskDiffer {
+    /// Creates a new PrdTaskDiffer.
+    pub fn new() -> Self {
+        PrdTaskDiffer
+    }
+
+    /// Diffs freshly parsed tasks against existing tasks for the same PRD.
+    ///
+    /// # Arguments
+    ///
+    /// * `existing` - All stored tasks; tasks not belonging to this PRD are ignored
+    /// * `parsed` - Tasks generated from the edited PRD
+    /// * `prd` - The edited PRD
+    ///
+    /// # Returns
+    ///
+    /// A PrdTaskDiff classifying every parsed task and every existing task of the PRD.
+    pub fn diff(
+        &self,
+        existing: &[crate::domain::task::Task],
+        parsed: std::vec::Vec<crate::domain::task::Task>,
+        prd: &crate::domain::prd::PRD,
+    ) -> PrdTaskDiff {
+        let prefix = prd.section_key_prefix();
+        let candidates: std::vec::Vec<&crate::domain::task::Task> = existing
+            .iter()
+            .filter(|task| task.parent_task_id.is_none())
+            .filter(|task| task.source_section.as_deref().is_some_and(|section| section.starts_with(prefix.as_str())))
+            .collect();
+
+        let mut candidate_matched = std::vec![false; candidates.len()];
+        let mut parsed_match: std::vec::Vec<std::option::Option<usize>> = std::vec![std::option::Option::None; parsed.len()];
+
+        // Pass 1: match by normalized title
+        for (parsed_idx, task) in parsed.iter().enumerate() {
+            let title = Self::normalize_title(&task.title);
+            if let std::option::Option::Some(candidate_idx) = (0..candidates.len())
+                .find(|&idx| !candidate_matched[idx] && Self::normalize_title(&candidates[idx].title) == title)
+            {
+                candidate_matched[candidate_idx] = true;
+                parsed_match[parsed_idx] = std::option::Option::Some(candidate_idx);
+            }
+        }
+
+        // Pass 2: match remaining tasks by source section
+        for (parsed_idx, task) in parsed.iter().enumerate() {
+            if parsed_match[parsed_idx].is_some() || task.source_section.is_none() {
+                continue;
+            }
+            if let std::option::Option::Some(candidate_idx) = (0..candidates.len())
+                .find(|&idx| !candidate_matched[idx] && candidates[idx].source_section == task.source_section)
+            {
+                candidate_matched[candidate_idx] = true;
+                parsed_match[parsed_idx] = std::option::Option::Some(candidate_idx);
+            }
+        }
+
+        let mut diff = PrdTaskDiff::default();
+        for (task, matched) in parsed.into_iter().zip(parsed_match) {
+            match matched {
+                std::option::Option::Some(candidate_idx) => {
+                    let current = candidates[candidate_idx];
+                    if Self::content_changed(current, &task) {
+                        let mut refreshed = current.clone();
+                        refreshed.title = task.title;
+                        refreshed.description = task.description;
+                        refreshed.complexity = task.complexity;
+                        refreshed.source_section = task.source_section;
+                        refreshed.source_prd_id = task.source_prd_id;
+                        refreshed.updated_at = chrono::Utc::now();
+                        diff.updated.push(refreshed);
+                    } else {
+                        diff.unchanged.push(current.clone());
+                    }
+                }
+                std::option::Option::None => diff.added.push(task),
+            }
+        }
+
+        let current_sections = prd.section_keys();
+        for (candidate_idx, candidate) in candidates.into_iter().enumerate() {
+            if candidate_matched[candidate_idx] {
+                continue;
+            }
+            let section_exists = candidate
+                .source_section
+                .as_ref()
+                .is_some_and(|section| current_sections.contains(section));
+            if section_exists {
+                diff.unchanged.push(candidate.clone());
+            } else {
+                diff.removed.push(candidate.clone());
+            }
+        }
+
+        diff
+    }
+
+    /// Returns true if any PRD-derived field differs between the stored and parsed task.
+    fn content_changed(current: &crate::domain::task::Task, parsed: &crate::domain::task::Task) -> bool {
+        current.title != parsed.title
+            || current.description != parsed.description
+            || current.complexity != parsed.complexity
+            || current.source_section != parsed.source_section
+    }
+
+    /// Lowercases a title and collapses everything except letters and digits to single spaces.
+    fn normalize_title(title: &str) -> String {
+        title
+            .to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .collect::<std::vec::Vec<&str>>()
+            .join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn prd_from_markdown(markdown: &str) -> crate::domain::prd::PRD {
+        crate::infrastructure::markdown_parsers::prd_parser::parse_prd_markdown("project-1", markdown).unwrap()
+    }
+
+    fn parsed_task(prd: &crate::domain::prd::PRD, title: &str, description: &str, section: &str) -> crate::domain::task::Task {
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from(title),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let mut task = crate::domain::task::Task::from_action_item(&action, std::option::Option::None);
+        task.description = std::string::String::from(description);
+        task.source_prd_id = std::option::Option::Some(prd.id.clone());
+        task.source_section = prd.resolve_section_key(section);
+        task
+    }
+
+    const ORIGINAL_PRD: &str = "# Rigger\n\n## Objectives\n- Enable task decomposition\n- Support multiple LLM providers\n- Export tasks to Jira\n";
+    const EDITED_PRD: &str = "# Rigger\n\n## Objectives\n- Enable task decomposition\n- Support multiple LLM providers\n- Add offline mode\n";
+
+    #[test]
+    fn test_diff_original_against_edited_prd() {
+        // Test: Validates added/updated/unchanged/removed classification across a PRD edit.
+        // Justification: Re-parsing an edited PRD must update in place instead of duplicating tasks.
+        let original = prd_from_markdown(ORIGINAL_PRD);
+        let mut existing = std::vec![
+            parsed_task(&original, "Build decomposition engine", "Split tasks.", "Enable task decomposition"),
+            parsed_task(&original, "Add provider abstraction", "Support Ollama and OpenAI.", "Support multiple LLM providers"),
+            parsed_task(&original, "Build Jira exporter", "Push tasks to Jira.", "Export tasks to Jira"),
+        ];
+        existing[0].status = crate::domain::task_status::TaskStatus::InProgress;
+
+        let edited = prd_from_markdown(EDITED_PRD);
+        let parsed = std::vec![
+            parsed_task(&edited, "Build decomposition engine", "Split tasks.", "Enable task decomposition"),
+            parsed_task(&edited, "Add provider abstraction layer", "Support Ollama, OpenAI, and Anthropic.", "Support multiple LLM providers"),
+            parsed_task(&edited, "Cache models for offline use", "Work without network.", "Add offline mode"),
+        ];
+
+        let diff = super::PrdTaskDiffer::new().diff(&existing, parsed, &edited);
+
+        std::assert_eq!(diff.unchanged.len(), 1);
+        std::assert_eq!(diff.unchanged[0].id, existing[0].id);
+        std::assert_eq!(diff.unchanged[0].status, crate::domain::task_status::TaskStatus::InProgress);
+
+        std::assert_eq!(diff.updated.len(), 1);
+        std::assert_eq!(diff.updated[0].id, existing[1].id);
+        std::assert_eq!(diff.updated[0].title, "Add provider abstraction layer");
+        std::assert_eq!(diff.updated[0].source_prd_id, std::option::Option::Some(edited.id.clone()));
+
+        std::assert_eq!(diff.added.len(), 1);
+        std::assert_eq!(diff.added[0].title, "Cache models for offline use");
+
+        std::assert_eq!(diff.removed.len(), 1);
+        std::assert_eq!(diff.removed[0].id, existing[2].id);
+        std::assert_eq!(diff.summary(), "1 added, 1 updated, 1 removed (1 unchanged)");
+    }
+
+    #[test]
+    fn test_reparse_of_unchanged_prd_is_a_no_op() {
+        // Test: Validates that re-parsing an identical PRD produces no writes.
+        // Justification: Running `rig parse` twice must not create duplicates.
+        let prd = prd_from_markdown(ORIGINAL_PRD);
+        let existing = std::vec![
+            parsed_task(&prd, "Build decomposition engine", "Split tasks.", "Enable task decomposition"),
+            parsed_task(&prd, "Build Jira exporter", "Push tasks to Jira.", "Export tasks to Jira"),
+        ];
+        let parsed = std::vec![
+            parsed_task(&prd, "build decomposition engine.", "Split tasks.", "Enable task decomposition"),
+            parsed_task(&prd, "Build Jira exporter", "Push tasks to Jira.", "Export tasks to Jira"),
+        ];
+
+        let diff = super::PrdTaskDiffer::new().diff(&existing, parsed, &prd);
+
+        std::assert!(diff.added.is_empty());
+        std::assert!(diff.removed.is_empty());
+        std::assert_eq!(diff.updated.len(), 1, "Title casing change is an update, not a new task");
+        std::assert_eq!(diff.unchanged.len(), 1);
+    }
+
+    #[test]
+    fn test_tasks_from_other_prds_and_subtasks_are_ignored() {
+        // Test: Validates that only top-level tasks of the same PRD are diffed.
+        // Justification: Subtasks and other PRDs' tasks must never be flagged as removed.
+        let prd = prd_from_markdown(ORIGINAL_PRD);
+        let other = prd_from_markdown("# Other Product\n\n## Objectives\n- Export tasks to Jira\n");
+        let mut subtask = parsed_task(&prd, "Write Jira client", "HTTP client.", "Remove me");
+        subtask.source_section = std::option::Option::Some(std::string::String::from("project-1 / Rigger > Objectives > Old objective"));
+        subtask.parent_task_id = std::option::Option::Some(std::string::String::from("parent-1"));
+        let existing = std::vec![
+            parsed_task(&other, "Build Jira exporter", "Push tasks to Jira.", "Export tasks to Jira"),
+            subtask,
+        ];
+
+        let diff = super::PrdTaskDiffer::new().diff(&existing, std::vec::Vec::new(), &prd);
+
+        std::assert!(diff.removed.is_empty());
+        std::assert!(diff.unchanged.is_empty());
+    }
+
+    #[test]
+    fn test_same_titled_prd_of_another_project_is_ignored() {
+        // Test: Validates tasks of a same-titled PRD in another project are neither matched nor removed.
+        // Justification: Keying on PRD title alone diffed unrelated projects' PRDs against each other.
+        let prd = prd_from_markdown(ORIGINAL_PRD);
+        let other_project = crate::infrastructure::markdown_parsers::prd_parser::parse_prd_markdown("project-2", ORIGINAL_PRD).unwrap();
+        let existing = std::vec![
+            parsed_task(&other_project, "Build decomposition engine", "Split tasks.", "Enable task decomposition"),
+            parsed_task(&other_project, "Build Jira exporter", "Push tasks to Jira.", "Export tasks to Jira"),
+        ];
+        let parsed = std::vec![parsed_task(&prd, "Build decomposition engine", "Split tasks.", "Enable task decomposition")];
+
+        let diff = super::PrdTaskDiffer::new().diff(&existing, parsed, &prd);
+
+        std::assert_eq!(diff.added.len(), 1);
+        std::assert!(diff.updated.is_empty());
+        std::assert!(diff.unchanged.is_empty());
+        std::assert!(diff.removed.is_empty());
+    }
+}
@@ -0,0 +1,227 @@
+//! Task statistics domain service for sprint reviews.
+//!
+//! Summarizes a set of tasks: current counts by status, how many tasks were
+//! completed per day over a window, and the average cycle time from creation
+//! to completion. A task's completion time is taken from its revision history
+//! (the latest status change into Completed), so completed tasks without such
+//! a revision are left out of velocity and cycle time and counted separately.
+//!
+//! Revision History
+//! - 2026-10-17T03:30:00Z @AI: Create task stats with status counts, velocity, and cycle time.
+
+/// Statistics over the tasks selected for a report.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct TaskStats {
+    /// First day of the window (inclusive)
+    pub window_start: std::option::Option<chrono::NaiveDate>,
+    /// Last day of the window (inclusive)
+    pub window_end: chrono::NaiveDate,
+    /// Number of days in the window, at least 1
+    pub window_days: i64,
+    /// Number of selected tasks
+    pub total: usize,
+    /// Current number of tasks per status, keyed by status name
+    pub by_status: std::collections::BTreeMap<String, usize>,
+    /// Tasks whose completion revision falls in the window
+    pub completed_in_window: usize,
+    /// Completed tasks per day over the window
+    pub velocity_per_day: f64,
+    /// Mean time from creation to completion of tasks completed in the window
+    pub average_cycle_time_seconds: std::option::Option<f64>,
+    /// Completed tasks with no completion revision, so no known completion time
+    pub completed_without_history: usize,
+}
+
+/// Returns when a completed task was completed, from its revision history.
+///
+/// # Returns
+///
+/// The timestamp of the latest revision moving the task to Completed, or
+/// None if the task is not completed or has no such revision.
+pub fn completed_at(task: &crate::domain::task::Task) -> std::option::Option<chrono::DateTime<chrono::Utc>> {
+    if task.status != crate::domain::task_status::TaskStatus::Completed {
+        return std::option::Option::None;
+    }
+    task.revisions
+        .iter()
+        .rev()
+        .find(|revision| revision.new_status() == std::option::Option::Some(crate::domain::task_status::TaskStatus::Completed))
+        .map(|revision| revision.timestamp)
+}
+
+/// Computes statistics over a project's tasks.
+///
+/// # Arguments
+///
+/// * `tasks` - All tasks of the project
+/// * `since` - First day of the window; defaults to the day the oldest selected task was created
+/// * `assignee` - Only count tasks assigned to this persona (case-insensitive)
+/// * `today` - Last day of the window
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::domain::services::task_stats::compute;
+/// let today = chrono::NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+/// let stats = compute(&[], None, None, today);
+/// assert_eq!(stats.total, 0);
+/// assert_eq!(stats.velocity_per_day, 0.0);
+/// assert_eq!(stats.average_cycle_time_seconds, None);
+/// ```
+pub fn compute(
+    tasks: &[crate::domain::task::Task],
+    since: std::option::Option<chrono::NaiveDate>,
+    assignee: std::option::Option<&str>,
+    today: chrono::NaiveDate,
+) -> TaskStats {
+    let selected: std::vec::Vec<&crate::domain::task::Task> = tasks
+        .iter()
+        .filter(|task| match assignee {
+            std::option::Option::Some(assignee) => task
+                .agent_persona
+                .as_deref()
+                .is_some_and(|persona| persona.eq_ignore_ascii_case(assignee)),
+            std::option::Option::None => true,
+        })
+        .collect();
+
+    let mut by_status = std::collections::BTreeMap::new();
+    for task in &selected {
+        *by_status.entry(std::format!("{:?}", task.status)).or_insert(0) += 1;
+    }
+
+    let window_start = since.or_else(|| selected.iter().map(|task| task.created_at.date_naive()).min());
+    let window_days = match window_start {
+        std::option::Option::Some(start) => ((today - start).num_days() + 1).max(1),
+        std::option::Option::None => 1,
+    };
+
+    let mut completed_in_window = 0;
+    let mut completed_without_history = 0;
+    let mut cycle_time_total = 0.0;
+    for task in &selected {
+        if task.status != crate::domain::task_status::TaskStatus::Completed {
+            continue;
+        }
+        let std::option::Option::Some(completed) = completed_at(task) else {
+            completed_without_history += 1;
+            continue;
+        };
+        let day = completed.date_naive();
+        if window_start.is_some_and(|start| day < start) || day > today {
+            continue;
+        }
+        completed_in_window += 1;
+        cycle_time_total += (completed - task.created_at).num_seconds().max(0) as f64;
+    }
+
+    TaskStats {
+        window_start,
+        window_end: today,
+        window_days,
+        total: selected.len(),
+        by_status,
+        completed_in_window,
+        velocity_per_day: completed_in_window as f64 / window_days as f64,
+        average_cycle_time_seconds: (completed_in_window > 0).then(|| cycle_time_total / completed_in_window as f64),
+        completed_without_history,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn at(day: u32, hour: u32) -> chrono::DateTime<chrono::Utc> {
+        chrono::NaiveDate::from_ymd_opt(2026, 3, day).unwrap().and_hms_opt(hour, 0, 0).unwrap().and_utc()
+    }
+
+    fn task(id: &str, assignee: &str, created: chrono::DateTime<chrono::Utc>) -> crate::domain::task::Task {
+        let item = transcript_extractor::domain::action_item::ActionItem {
+            title: std::format!("Task {}", id),
+            assignee: std::option::Option::Some(std::string::String::from(assignee)),
+            due_date: std::option::Option::None,
+        };
+        let mut task = crate::domain::task::Task::from_action_item(&item, std::option::Option::None);
+        task.id = std::string::String::from(id);
+        task.created_at = created;
+        task
+    }
+
+    fn complete(mut task: crate::domain::task::Task, at: chrono::DateTime<chrono::Utc>) -> crate::domain::task::Task {
+        task.revisions.push(crate::domain::task_revision::TaskRevision {
+            revision_id: uuid::Uuid::new_v4().to_string(),
+            task_id: task.id.clone(),
+            timestamp: at,
+            change_description: std::string::String::from("Status: InProgress -> Completed"),
+            previous_state_json: std::option::Option::Some(std::string::String::from(r#"{"status":"InProgress"}"#)),
+        });
+        task.status = crate::domain::task_status::TaskStatus::Completed;
+        task
+    }
+
+    fn seeded() -> std::vec::Vec<crate::domain::task::Task> {
+        let mut untracked = task("untracked", "Backend Dev", at(2, 0));
+        untracked.status = crate::domain::task_status::TaskStatus::Completed;
+        let mut open = task("open", "Designer", at(4, 0));
+        open.status = crate::domain::task_status::TaskStatus::InProgress;
+        std::vec![
+            // 48h cycle, completed in window
+            complete(task("two-days", "Backend Dev", at(1, 0)), at(3, 0)),
+            // 12h cycle, completed in window
+            complete(task("half-day", "Designer", at(2, 0)), at(2, 12)),
+            // Completed before the window
+            complete(task("old", "Backend Dev", at(1, 0)), at(1, 6)),
+            untracked,
+            open,
+            task("todo", "Backend Dev", at(5, 0)),
+        ]
+    }
+
+    #[test]
+    fn test_velocity_and_cycle_time_over_window() {
+        // Test: Validates velocity and mean cycle time count only tasks completed in the window with a completion revision.
+        // Justification: These are the numbers reported in sprint reviews; they must match a hand count.
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+        let since = chrono::NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+
+        let stats = super::compute(&seeded(), std::option::Option::Some(since), std::option::Option::None, today);
+
+        std::assert_eq!(stats.total, 6);
+        std::assert_eq!(stats.window_days, 9);
+        std::assert_eq!(stats.by_status.get("Completed"), std::option::Option::Some(&4));
+        std::assert_eq!(stats.by_status.get("InProgress"), std::option::Option::Some(&1));
+        std::assert_eq!(stats.by_status.get("Todo"), std::option::Option::Some(&1));
+        std::assert_eq!(stats.completed_in_window, 2);
+        std::assert!((stats.velocity_per_day - 2.0 / 9.0).abs() < 1e-9);
+        std::assert_eq!(stats.average_cycle_time_seconds, std::option::Option::Some(30.0 * 3600.0));
+        std::assert_eq!(stats.completed_without_history, 1);
+    }
+
+    #[test]
+    fn test_default_window_and_assignee_filter() {
+        // Test: Validates the window defaults to the oldest task's creation day and --assignee narrows every figure.
+        // Justification: `rig stats --assignee` is used for per-person reviews without picking a start date.
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+
+        let stats = super::compute(&seeded(), std::option::Option::None, std::option::Option::Some("backend dev"), today);
+
+        std::assert_eq!(stats.window_start, chrono::NaiveDate::from_ymd_opt(2026, 3, 1));
+        std::assert_eq!(stats.window_days, 10);
+        std::assert_eq!(stats.total, 4);
+        std::assert_eq!(stats.completed_in_window, 2);
+        std::assert!((stats.velocity_per_day - 0.2).abs() < 1e-9);
+        // (48h + 6h) / 2
+        std::assert_eq!(stats.average_cycle_time_seconds, std::option::Option::Some(27.0 * 3600.0));
+    }
+
+    #[test]
+    fn test_reopened_task_uses_latest_completion() {
+        // Test: Validates completed_at() takes the latest move to Completed and ignores unfinished tasks.
+        // Justification: A task reopened and completed again finished at the second completion.
+        let mut task = complete(task("reopened", "Backend Dev", at(1, 0)), at(2, 0));
+        task.status = crate::domain::task_status::TaskStatus::InProgress;
+        std::assert_eq!(super::completed_at(&task), std::option::Option::None);
+
+        let task = complete(task, at(4, 0));
+        std::assert_eq!(super::completed_at(&task), std::option::Option::Some(at(4, 0)));
+    }
+}
@@ -3,9 +3,11 @@
 //! This service uses ComplexityScorer to analyze tasks and make routing decisions
 //! for the orchestration pipeline. High-complexity tasks (score >= 7) are routed
 //! to decomposition for breakdown into subtasks, while simpler tasks go through
-//! the standard enhancement flow.
+//! the standard enhancement flow. On task creation, auto_triage() also
+//! derives an initial priority and triage label from the same score.
 //!
 //! Revision History
+//! - 2026-10-16T12:10:00Z @AI: Add auto_triage() for new tasks. Assigns a complexity-derived priority when none is set, adds a triage label, and records the decision with its reason in the task's revision history.
 //! - 2025-11-23T16:00:00Z @AI: Create TriageService for Phase 2 Sprint 5 Task 2.5.
 
 /// Routing decision for task orchestration.
@@ -37,6 +39,35 @@ pub enum TriageDecision {
     Decompose,
 }
 
+impl TriageDecision {
+    /// Returns the task label recorded for this decision (e.g., "triage:decompose").
+    pub fn label(&self) -> &'static str {
+        match self {
+            TriageDecision::Enhance => "triage:enhance",
+            TriageDecision::Decompose => "triage:decompose",
+        }
+    }
+}
+
+/// Result of triaging a single task.
+///
+/// # Fields
+///
+/// * `decision` - Routing decision derived from the complexity score.
+/// * `complexity_score` - ComplexityScorer score (1-10).
+/// * `priority` - Priority suggested by the score: High (>= 7), Medium (5-6), Low (< 5).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TriageAssessment {
+    /// Routing decision derived from the complexity score.
+    pub decision: TriageDecision,
+
+    /// ComplexityScorer score (1-10).
+    pub complexity_score: u8,
+
+    /// Priority suggested by the complexity score.
+    pub priority: crate::domain::priority::Priority,
+}
+
 /// Domain service for intelligent task triage and routing.
 ///
 /// TriageService analyzes tasks using ComplexityScorer and makes routing
@@ -143,6 +174,95 @@ impl TriageService {
             TriageDecision::Enhance
         }
     }
+
+    /// Scores a task and derives its routing decision and suggested priority.
+    ///
+    /// # Arguments
+    ///
+    /// * `task` - The task to assess
+    ///
+    /// # Returns
+    ///
+    /// A TriageAssessment with the decision, score, and suggested priority.
+    pub fn assess_task(&self, task: &crate::domain::task::Task) -> TriageAssessment {
+        let complexity_score = self.complexity_scorer.score_task(task);
+        let (decision, priority) = if complexity_score >= 7 {
+            (TriageDecision::Decompose, crate::domain::priority::Priority::High)
+        } else if complexity_score >= 5 {
+            (TriageDecision::Enhance, crate::domain::priority::Priority::Medium)
+        } else {
+            (TriageDecision::Enhance, crate::domain::priority::Priority::Low)
+        };
+
+        TriageAssessment { decision, complexity_score, priority }
+    }
+
+    /// Triages a newly created task in place.
+    ///
+    /// Sets the suggested priority only when the task has none, adds the
+    /// decision's triage label unless a triage label is already present, and
+    /// appends a TaskRevision explaining the decision. An explicit priority is
+    /// never overridden.
+    ///
+    /// # Arguments
+    ///
+    /// * `task` - The task to triage
+    ///
+    /// # Returns
+    ///
+    /// The TriageAssessment that was applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use task_manager::domain::services::triage_service::TriageService;
+    /// # use task_manager::domain::services::complexity_scorer::ComplexityScorer;
+    /// # use task_manager::domain::priority::Priority;
+    /// # use task_manager::domain::task::Task;
+    /// # use transcript_extractor::domain::action_item::ActionItem;
+    /// let triage = TriageService::new(ComplexityScorer::new());
+    /// let action = ActionItem {
+    ///     title: std::string::String::from("Fix typo"),
+    ///     assignee: Some(std::string::String::from("Alice")),
+    ///     due_date: Some(std::string::String::from("2025-12-01")),
+    /// };
+    /// let mut task = Task::from_action_item(&action, None);
+    ///
+    /// triage.auto_triage(&mut task);
+    /// assert_eq!(task.priority, Some(Priority::Low));
+    /// assert!(task.tags.contains(&std::string::String::from("triage:enhance")));
+    /// assert_eq!(task.revisions.len(), 1);
+    /// ```
+    pub fn auto_triage(&self, task: &mut crate::domain::task::Task) -> TriageAssessment {
+        let assessment = self.assess_task(task);
+        let label = assessment.decision.label();
+
+        let priority_note = match task.priority {
+            std::option::Option::Some(explicit) => std::format!("kept explicit priority {}", explicit.as_str()),
+            std::option::Option::None => {
+                task.priority = std::option::Option::Some(assessment.priority);
+                std::format!("set priority {}", assessment.priority.as_str())
+            }
+        };
+        if !task.tags.iter().any(|tag| tag.starts_with("triage:")) {
+            task.tags.push(std::string::String::from(label));
+        }
+
+        task.revisions.push(crate::domain::task_revision::TaskRevision {
+            revision_id: uuid::Uuid::new_v4().to_string(),
+            task_id: task.id.clone(),
+            timestamp: chrono::Utc::now(),
+            change_description: std::format!(
+                "Auto-triage: labeled {} and {} (complexity score {}/10, decompose threshold 7)",
+                label,
+                priority_note,
+                assessment.complexity_score
+            ),
+            previous_state_json: std::option::Option::None,
+        });
+
+        assessment
+    }
 }
 
 #[cfg(test)]
@@ -240,4 +360,48 @@ mod tests {
         let cloned = decision.clone();
         std::assert_eq!(decision, cloned);
     }
+
+    #[test]
+    fn test_auto_triage_fills_missing_priority() {
+        // Test: Validates auto-triage assigns a priority, label, and revision to a complex task.
+        // Justification: New tasks without a priority must be triaged automatically.
+        let triage = TriageService::new(crate::domain::services::complexity_scorer::ComplexityScorer::new());
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Refactor the payment processing system for PCI compliance"),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let mut task = crate::domain::task::Task::from_action_item(&action, std::option::Option::None);
+
+        let assessment = triage.auto_triage(&mut task);
+
+        std::assert_eq!(assessment.decision, TriageDecision::Decompose);
+        std::assert_eq!(task.priority, std::option::Option::Some(crate::domain::priority::Priority::High));
+        std::assert_eq!(task.tags, std::vec![std::string::String::from("triage:decompose")]);
+        std::assert_eq!(task.revisions.len(), 1);
+        std::assert_eq!(task.revisions[0].task_id, task.id);
+        std::assert!(task.revisions[0].change_description.contains("set priority high"));
+        std::assert!(task.revisions[0].change_description.contains("complexity score 8/10"));
+    }
+
+    #[test]
+    fn test_auto_triage_keeps_explicit_priority() {
+        // Test: Validates auto-triage never overrides an explicitly provided priority.
+        // Justification: User-supplied priorities take precedence over heuristics.
+        let triage = TriageService::new(crate::domain::services::complexity_scorer::ComplexityScorer::new());
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Fix typo"),
+            assignee: std::option::Option::Some(std::string::String::from("Alice")),
+            due_date: std::option::Option::Some(std::string::String::from("2025-12-01")),
+        };
+        let mut task = crate::domain::task::Task::from_action_item(&action, std::option::Option::None);
+        task.priority = std::option::Option::Some(crate::domain::priority::Priority::High);
+
+        let assessment = triage.auto_triage(&mut task);
+
+        std::assert_eq!(assessment.priority, crate::domain::priority::Priority::Low);
+        std::assert_eq!(task.priority, std::option::Option::Some(crate::domain::priority::Priority::High));
+        std::assert_eq!(task.tags, std::vec![std::string::String::from("triage:enhance")]);
+        std::assert!(task.revisions[0].change_description.contains("kept explicit priority high"));
+    }
 }
@@ -5,6 +5,16 @@
 //! links back to the source transcript for traceability.
 //!
 //! Revision History
+//! - 2026-10-18T01:50:00Z @AI: Add utterance_idempotency_key so transcript_processor keys tasks like create().
+//! - 2026-10-17T16:50:00Z @AI: Add estimate field (story points) for capacity-based planning.
+//! - 2026-10-17T16:30:00Z @AI: Add links field for informational relates-to/duplicates links, with add_link() and all_links(); dependencies hold the blocking edges.
+//! - 2026-10-17T11:50:00Z @AI: Add idempotency_key field with idempotency_key_for() and transcript_idempotency_key() deriving it from the task's source.
+//! - 2026-10-17T06:50:00Z @AI: Add set_description(), mark_comprehension_tests_stale(), and needs_comprehension_test() for test regeneration.
+//! - 2026-10-17T03:50:00Z @AI: Add source_utterance field holding the transcript line a task was extracted from.
+//! - 2026-10-17T03:10:00Z @AI: Add started_at and time_spent_seconds with track_status_time() accumulating time spent in InProgress.
+//! - 2026-10-16T15:50:00Z @AI: Add checklist field holding the task's sub-items and their completion state.
+//! - 2026-10-16T12:10:00Z @AI: Add priority, tags, and revisions fields for auto-triage on task creation.
+//! - 2026-10-16T11:10:00Z @AI: Add source_section field holding the stable PRD section key a task was generated from, enabling incremental PRD re-parsing.
 //! - 2025-11-30T21:30:00Z @AI: Add sort_order field for manual task prioritization within TODO column. Lower values appear first, None values sort by created_at.
 //! - 2025-11-29T15:00:00Z @AI: Rename assignee to agent_persona for better LLM inference. Field name "assignee" caused LLMs to default to placeholder human names (Alice, Bob, Charlie). New name primes LLM to produce role-based outputs (Backend Architect, Security Analyst, etc.).
 //! - 2025-11-26T09:45:00Z @AI: Add completion_summary field to store LLM's summary when task is completed.
@@ -31,6 +41,7 @@
 /// * `due_date` - Optional deadline in string format.
 /// * `status` - Current lifecycle status of the task.
 /// * `source_transcript_id` - Optional link to the originating transcript.
+/// * `source_utterance` - Optional transcript line (number and text) the task was extracted from.
 /// * `source_prd_id` - Optional link to the PRD that generated this task.
 /// * `parent_task_id` - Optional parent task ID for subtask hierarchies.
 /// * `subtask_ids` - List of subtask IDs if this task was decomposed.
@@ -40,7 +51,18 @@
 /// * `reasoning` - Optional LLM's chain-of-thought explanation for enhancements.
 /// * `completion_summary` - Optional LLM-generated summary of what was done when completing the task.
 /// * `context_files` - List of relevant codebase files for context engineering.
-/// * `dependencies` - List of task IDs this task depends on.
+/// * `dependencies` - IDs of the tasks that block this one (its `blocks` links).
+/// * `links` - Informational links (relates-to, duplicates) that do not affect scheduling.
+/// * `estimate` - Optional effort estimate in story points.
+/// * `sort_order` - Optional manual ordering within the TODO column.
+/// * `source_section` - Optional stable key of the PRD section this task was generated from.
+/// * `priority` - Optional urgency level, explicit or assigned by auto-triage.
+/// * `tags` - Free-form labels (e.g., the "triage:decompose" label set by auto-triage).
+/// * `revisions` - Revision history entries recorded for this task.
+/// * `checklist` - Sub-items tracking granular progress on the task.
+/// * `started_at` - When the task last entered InProgress, while it is still there.
+/// * `time_spent_seconds` - Time spent in InProgress over all finished work intervals.
+/// * `idempotency_key` - Optional key identifying the source item, so a retried import does not create it twice.
 ///
 /// # Examples
 ///
@@ -112,12 +134,56 @@ pub struct Task {
     /// List of relevant codebase files for context engineering.
     pub context_files: std::vec::Vec<String>,
 
-    /// List of task IDs this task depends on.
+    /// IDs of the tasks that block this one; the only links that affect scheduling.
     pub dependencies: std::vec::Vec<String>,
 
     /// Optional sort order for manual prioritization within TODO column.
     /// Lower values appear first. Tasks without sort_order use created_at for ordering.
     pub sort_order: std::option::Option<i32>,
+
+    /// Optional stable key of the PRD section this task was generated from.
+    /// Used to match tasks across PRD re-parses (see `PRD::section_keys`).
+    pub source_section: std::option::Option<String>,
+
+    /// Optional urgency level, explicit or assigned by auto-triage.
+    #[serde(default)]
+    pub priority: std::option::Option<crate::domain::priority::Priority>,
+
+    /// Free-form labels attached to the task.
+    #[serde(default)]
+    pub tags: std::vec::Vec<String>,
+
+    /// Revision history entries recorded for this task, oldest first.
+    #[serde(default)]
+    pub revisions: std::vec::Vec<crate::domain::task_revision::TaskRevision>,
+
+    /// Sub-items tracking granular progress on the task, in display order.
+    #[serde(default)]
+    pub checklist: std::vec::Vec<crate::domain::checklist_item::ChecklistItem>,
+
+    /// Start of the current InProgress interval; None while the task is not in progress.
+    #[serde(default)]
+    pub started_at: std::option::Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Seconds spent in InProgress over all finished intervals, so pausing and resuming sums up.
+    #[serde(default)]
+    pub time_spent_seconds: u64,
+
+    /// Transcript line this task was extracted from, alongside `source_transcript_id`.
+    #[serde(default)]
+    pub source_utterance: std::option::Option<crate::domain::transcript_utterance::TranscriptUtterance>,
+
+    /// Key of the source item this task was created from; unique among tasks (see `idempotency_key_for`).
+    #[serde(default)]
+    pub idempotency_key: std::option::Option<String>,
+
+    /// Relates-to and duplicates links from this task; blocking links live in `dependencies`.
+    #[serde(default)]
+    pub links: std::vec::Vec<crate::domain::task_link::TaskLink>,
+
+    /// Optional effort estimate in story points, used by capacity planning.
+    #[serde(default)]
+    pub estimate: std::option::Option<f32>,
 }
 
 impl Task {
@@ -177,7 +243,221 @@ impl Task {
             context_files: std::vec::Vec::new(),
             dependencies: std::vec::Vec::new(),
             sort_order: std::option::Option::None,
+            source_section: std::option::Option::None,
+            priority: std::option::Option::None,
+            tags: std::vec::Vec::new(),
+            revisions: std::vec::Vec::new(),
+            checklist: std::vec::Vec::new(),
+            started_at: std::option::Option::None,
+            time_spent_seconds: 0,
+            source_utterance: std::option::Option::None,
+            idempotency_key: std::option::Option::None,
+            links: std::vec::Vec::new(),
+            estimate: std::option::Option::None,
+        }
+    }
+
+    /// Derives an idempotency key from a source and the part of it a task came from.
+    ///
+    /// The key is the hex SHA-256 of both, so retrying an import of the same
+    /// source part yields the same key.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Identifies the source document, e.g. `prd:<title>` or `transcript:<id>`.
+    /// * `part` - Identifies the item within it, e.g. a PRD section key or an utterance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use task_manager::domain::task::Task;
+    /// let key = Task::idempotency_key_for("prd:Rigger", "Objectives > Enable task decomposition");
+    /// assert_eq!(key, Task::idempotency_key_for("prd:Rigger", "Objectives > Enable task decomposition"));
+    /// assert_ne!(key, Task::idempotency_key_for("prd:Rigger", "Objectives > Ship the TUI"));
+    /// ```
+    pub fn idempotency_key_for(source: &str, part: &str) -> String {
+        // The separator keeps ("ab", "c") and ("a", "bc") apart
+        let mut hasher = <sha2::Sha256 as sha2::Digest>::new_with_prefix(source.as_bytes());
+        sha2::Digest::update(&mut hasher, [0u8]);
+        sha2::Digest::update(&mut hasher, part.as_bytes());
+        sha2::Digest::finalize(hasher).iter().map(|byte| std::format!("{:02x}", byte)).collect()
+    }
+
+    /// Returns the idempotency key of a task extracted from a transcript.
+    ///
+    /// Derived from `source_transcript_id` and `source_utterance`; None unless both are set.
+    pub fn transcript_idempotency_key(&self) -> std::option::Option<String> {
+        let transcript_id = self.source_transcript_id.as_ref()?;
+        let utterance = self.source_utterance.as_ref()?;
+        std::option::Option::Some(Self::utterance_idempotency_key(transcript_id, utterance))
+    }
+
+    /// Returns the idempotency key of an item said at `utterance` of transcript `transcript_id`.
+    ///
+    /// Shared with other transcript pipelines so they key their tasks the same way.
+    pub fn utterance_idempotency_key(
+        transcript_id: &str,
+        utterance: &crate::domain::transcript_utterance::TranscriptUtterance,
+    ) -> String {
+        Self::idempotency_key_for(&std::format!("transcript:{}", transcript_id), &utterance.to_string())
+    }
+
+    /// Updates time tracking for a status change that is about to happen.
+    ///
+    /// Entering InProgress starts an interval at `now`; leaving it adds the
+    /// interval's length to `time_spent_seconds` and clears `started_at`.
+    /// Call this before assigning the new status.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_status` - The status the task is moving to.
+    /// * `now` - Time of the change.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use task_manager::domain::task::Task;
+    /// # use task_manager::domain::task_status::TaskStatus;
+    /// # use transcript_extractor::domain::action_item::ActionItem;
+    /// let action = ActionItem { title: std::string::String::from("Write docs"), assignee: None, due_date: None };
+    /// let mut task = Task::from_action_item(&action, None);
+    /// let start = chrono::Utc::now();
+    ///
+    /// task.track_status_time(&TaskStatus::InProgress, start);
+    /// task.status = TaskStatus::InProgress;
+    /// task.track_status_time(&TaskStatus::Completed, start + chrono::Duration::minutes(5));
+    /// task.status = TaskStatus::Completed;
+    ///
+    /// assert_eq!(task.time_spent_seconds, 300);
+    /// assert!(task.started_at.is_none());
+    /// ```
+    pub fn track_status_time(
+        &mut self,
+        new_status: &crate::domain::task_status::TaskStatus,
+        now: chrono::DateTime<chrono::Utc>,
+    ) {
+        let was_active = self.status == crate::domain::task_status::TaskStatus::InProgress;
+        let is_active = *new_status == crate::domain::task_status::TaskStatus::InProgress;
+
+        if was_active && !is_active {
+            if let std::option::Option::Some(started_at) = self.started_at.take() {
+                self.time_spent_seconds += (now - started_at).num_seconds().max(0) as u64;
+            }
+        } else if !was_active && is_active {
+            self.started_at = std::option::Option::Some(now);
+        }
+    }
+
+    /// Returns the total time spent, including the interval still running at `now`.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - Time to measure a running interval up to.
+    pub fn time_spent_at(&self, now: chrono::DateTime<chrono::Utc>) -> u64 {
+        let running = self
+            .started_at
+            .map_or(0, |started_at| (now - started_at).num_seconds().max(0) as u64);
+        self.time_spent_seconds + running
+    }
+
+    /// Replaces the description, marking comprehension tests stale on a material change.
+    ///
+    /// A change is material when the hash from
+    /// ComprehensionTest::hash_description() differs, so whitespace-only
+    /// edits keep the tests current.
+    ///
+    /// # Arguments
+    ///
+    /// * `description` - The new description.
+    ///
+    /// # Returns
+    ///
+    /// The number of comprehension tests newly marked stale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use task_manager::domain::task::Task;
+    /// # use transcript_extractor::domain::action_item::ActionItem;
+    /// let action = ActionItem { title: std::string::String::from("Add login"), assignee: None, due_date: None };
+    /// let mut task = Task::from_action_item(&action, None);
+    /// task.set_description(std::string::String::from("Email and password"));
+    /// assert_eq!(task.description, "Email and password");
+    /// ```
+    pub fn set_description(&mut self, description: String) -> usize {
+        let hash = crate::domain::comprehension_test::ComprehensionTest::hash_description;
+        let marked = if hash(&self.description) != hash(&description) {
+            self.mark_comprehension_tests_stale()
+        } else {
+            0
+        };
+        self.description = description;
+        marked
+    }
+
+    /// Adds a link from this task to `task_id` unless it already exists.
+    ///
+    /// A `Blocks` link means `task_id` blocks this task and is added to
+    /// `dependencies`; other types are added to `links`.
+    ///
+    /// # Returns
+    ///
+    /// True if the link was added, false if it was already there.
+    pub fn add_link(&mut self, task_id: &str, link_type: crate::domain::task_link::LinkType) -> bool {
+        if link_type.affects_scheduling() {
+            if self.dependencies.iter().any(|id| id == task_id) {
+                return false;
+            }
+            self.dependencies.push(task_id.to_string());
+            return true;
         }
+        let link = crate::domain::task_link::TaskLink {
+            task_id: task_id.to_string(),
+            link_type,
+        };
+        if self.links.contains(&link) {
+            return false;
+        }
+        self.links.push(link);
+        true
+    }
+
+    /// Returns every link of this task: blocking dependencies first, then informational links.
+    pub fn all_links(&self) -> std::vec::Vec<crate::domain::task_link::TaskLink> {
+        self.dependencies
+            .iter()
+            .map(|id| crate::domain::task_link::TaskLink {
+                task_id: id.clone(),
+                link_type: crate::domain::task_link::LinkType::Blocks,
+            })
+            .chain(self.links.iter().cloned())
+            .collect()
+    }
+
+    /// Marks every comprehension test stale so the orchestrator regenerates it.
+    ///
+    /// # Returns
+    ///
+    /// The number of tests that were not stale before.
+    pub fn mark_comprehension_tests_stale(&mut self) -> usize {
+        let mut marked = 0;
+        for test in self.comprehension_tests.iter_mut().flatten().filter(|test| !test.stale) {
+            test.stale = true;
+            marked += 1;
+        }
+        marked
+    }
+
+    /// Returns whether the orchestrator should generate a comprehension test.
+    ///
+    /// True when the task has no test that is current for its description
+    /// (see ComprehensionTest::is_current_for()).
+    pub fn needs_comprehension_test(&self) -> bool {
+        !self
+            .comprehension_tests
+            .iter()
+            .flatten()
+            .any(|test| test.is_current_for(&self.description))
     }
 }
 
@@ -243,4 +523,32 @@ mod tests {
 
         assert_ne!(task1.id, task2.id);
     }
+
+    #[test]
+    fn test_add_link_routes_blocks_to_dependencies() {
+        // Test: Validates blocks links land in dependencies, other types in links, duplicates are ignored, and all_links lists both.
+        // Justification: Scheduling reads only dependencies, so a relates-to link must never end up there.
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Link test"),
+            assignee: None,
+            due_date: None,
+        };
+        let mut task = Task::from_action_item(&action, None);
+
+        assert!(task.add_link("schema", crate::domain::task_link::LinkType::Blocks));
+        assert!(task.add_link("docs", crate::domain::task_link::LinkType::RelatesTo));
+        assert!(!task.add_link("schema", crate::domain::task_link::LinkType::Blocks));
+        assert!(task.add_link("schema", crate::domain::task_link::LinkType::Duplicates));
+
+        assert_eq!(task.dependencies, std::vec![std::string::String::from("schema")]);
+        let types: std::vec::Vec<_> = task.all_links().into_iter().map(|link| (link.task_id, link.link_type)).collect();
+        assert_eq!(
+            types,
+            std::vec![
+                (std::string::String::from("schema"), crate::domain::task_link::LinkType::Blocks),
+                (std::string::String::from("docs"), crate::domain::task_link::LinkType::RelatesTo),
+                (std::string::String::from("schema"), crate::domain::task_link::LinkType::Duplicates),
+            ]
+        );
+    }
 }
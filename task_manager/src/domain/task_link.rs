@@ -0,0 +1,126 @@
+//! Labelled links between tasks.
+//!
+//! Tasks are connected by three kinds of edge. `blocks` is the scheduling
+//! edge: it is stored in the blocked task's `dependencies`, and only it feeds
+//! the dependency graph, blocked detection, and `rig next`. `relates-to` and
+//! `duplicates` are informational: they are stored in the task's `links`,
+//! displayed with it, and never hold up work.
+//!
+//! Revision History
+//! - 2026-10-17T16:30:00Z @AI: Initial LinkType (blocks / relates-to / duplicates) and TaskLink.
+
+/// Kind of edge between two tasks.
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::domain::task_link::LinkType;
+/// assert_eq!("relates_to".parse::<LinkType>(), Ok(LinkType::RelatesTo));
+/// assert!(LinkType::Blocks.affects_scheduling());
+/// assert!(!LinkType::Duplicates.affects_scheduling());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LinkType {
+    /// The source task must finish before the target can start.
+    Blocks,
+
+    /// The tasks touch the same area; informational only.
+    RelatesTo,
+
+    /// The source task repeats the target; informational only.
+    Duplicates,
+}
+
+impl LinkType {
+    /// Returns the name used on the command line and in JSON (e.g., "relates-to").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LinkType::Blocks => "blocks",
+            LinkType::RelatesTo => "relates-to",
+            LinkType::Duplicates => "duplicates",
+        }
+    }
+
+    /// Returns true if links of this type hold up the linked task.
+    pub fn affects_scheduling(&self) -> bool {
+        std::matches!(self, LinkType::Blocks)
+    }
+}
+
+impl std::fmt::Display for LinkType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for LinkType {
+    type Err = String;
+
+    /// Parses a link type case-insensitively, accepting `-`, `_`, or no separator.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error listing the valid values if `s` is not recognized.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace(['_', ' '], "-").as_str() {
+            "blocks" | "block" => std::result::Result::Ok(LinkType::Blocks),
+            "relates-to" | "relatesto" | "relates" | "related" => std::result::Result::Ok(LinkType::RelatesTo),
+            "duplicates" | "duplicate" | "dup" => std::result::Result::Ok(LinkType::Duplicates),
+            _ => std::result::Result::Err(std::format!(
+                "Invalid link type: '{}'. Valid values: blocks, relates-to, duplicates",
+                s
+            )),
+        }
+    }
+}
+
+/// A labelled edge from the task holding it to `task_id`.
+///
+/// For `Blocks`, `task_id` blocks the holding task; for the informational
+/// types, the holding task relates to or duplicates `task_id`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Deserialize, serde::Serialize)]
+pub struct TaskLink {
+    /// ID of the linked task
+    pub task_id: String,
+
+    /// Kind of link
+    #[serde(rename = "type")]
+    pub link_type: LinkType,
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_parse_accepts_separator_variants() {
+        // Test: Validates link types parse case-insensitively with dash, underscore, or no separator, and Display round-trips.
+        // Justification: Users type `--type relates_to` as often as `relates-to`.
+        for (input, expected) in [
+            ("blocks", super::LinkType::Blocks),
+            ("Relates-To", super::LinkType::RelatesTo),
+            ("relates_to", super::LinkType::RelatesTo),
+            ("relatesto", super::LinkType::RelatesTo),
+            ("DUPLICATES", super::LinkType::Duplicates),
+        ] {
+            let parsed: super::LinkType = input.parse().unwrap();
+            std::assert_eq!(parsed, expected, "input: {}", input);
+            std::assert_eq!(parsed.to_string().parse::<super::LinkType>().unwrap(), parsed);
+        }
+        std::assert!("depends".parse::<super::LinkType>().unwrap_err().contains("relates-to"));
+    }
+
+    #[test]
+    fn test_link_serializes_with_kebab_case_type() {
+        // Test: Validates a TaskLink round-trips through JSON with the CLI's type names.
+        // Justification: Links are persisted as JSON and appear in exported bundles.
+        let link = super::TaskLink {
+            task_id: std::string::String::from("task-1"),
+            link_type: super::LinkType::RelatesTo,
+        };
+
+        let json = serde_json::to_string(&link).unwrap();
+
+        std::assert_eq!(json, r#"{"task_id":"task-1","type":"relates-to"}"#);
+        std::assert_eq!(serde_json::from_str::<super::TaskLink>(&json).unwrap(), link);
+    }
+}
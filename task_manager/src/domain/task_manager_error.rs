@@ -0,0 +1,107 @@
+//! Defines the TaskManagerError returned by the task_manager use cases.
+//!
+//! Each variant names a failure kind callers can match on (e.g. the CLI maps
+//! them to exit codes), while repository failures keep the underlying HEXSER
+//! error as their source.
+//!
+//! Revision History
+//! - 2026-10-17T11:10:00Z @AI: ValidationFailed carries FieldErrors naming the offending field.
+//! - 2026-10-17T10:50:00Z @AI: Initial TaskManagerError with NotFound, InvalidTransition, NothingToUndo, ValidationFailed, and Storage.
+
+/// Failure of a task management operation.
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::domain::task_manager_error::TaskManagerError;
+/// let error = TaskManagerError::NotFound(std::string::String::from("task-123"));
+/// assert_eq!(error.to_string(), "Task with ID task-123 not found");
+/// ```
+#[derive(Debug, thiserror::Error)]
+pub enum TaskManagerError {
+    /// No task has the given ID.
+    #[error("Task with ID {0} not found")]
+    NotFound(std::string::String),
+
+    /// The task lifecycle does not allow the requested status change.
+    #[error("Invalid transition from {from:?} to {to:?}: {reason}")]
+    InvalidTransition {
+        from: crate::domain::task_status::TaskStatus,
+        to: crate::domain::task_status::TaskStatus,
+        reason: std::string::String,
+    },
+
+    /// The task has no status change left to undo.
+    #[error("No status change to undo for task {0}")]
+    NothingToUndo(std::string::String),
+
+    /// The task failed validation; every problem found is listed.
+    #[error("Validation failed: {}", errors.iter().map(FieldError::to_string).collect::<std::vec::Vec<_>>().join("; "))]
+    ValidationFailed {
+        errors: std::vec::Vec<FieldError>,
+    },
+
+    /// The repository failed while performing `operation`. The HEXSER error
+    /// is boxed because it is much larger than the other variants.
+    #[error("Failed to {operation}: {source}")]
+    Storage {
+        operation: std::string::String,
+        #[source]
+        source: std::boxed::Box<hexser::Hexserror>,
+    },
+}
+
+/// One validation problem with a task field.
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::domain::task_manager_error::FieldError;
+/// let error = FieldError::new("title", "must not be empty");
+/// assert_eq!(error.to_string(), "title: must not be empty");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    /// Name of the invalid field, e.g. "due_date".
+    pub field: std::string::String,
+
+    /// What is wrong with the field's value.
+    pub message: std::string::String,
+}
+
+impl FieldError {
+    /// Creates a FieldError for `field`.
+    pub fn new(field: &str, message: &str) -> Self {
+        FieldError { field: std::string::String::from(field), message: std::string::String::from(message) }
+    }
+}
+
+impl std::fmt::Display for FieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+impl TaskManagerError {
+    /// Wraps a repository error, naming the operation that failed (e.g. "save task").
+    pub fn storage(operation: &str) -> impl FnOnce(hexser::Hexserror) -> Self + '_ {
+        move |source| TaskManagerError::Storage {
+            operation: std::string::String::from(operation),
+            source: std::boxed::Box::new(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_storage_preserves_source_error() {
+        // Test: Validates Storage keeps the repository error as its source and names the operation.
+        // Justification: Callers need the underlying cause for logs while matching on the kind.
+        let error = super::TaskManagerError::storage("save task")(hexser::Hexserror::adapter("E_DB", "disk full"));
+
+        std::assert!(error.to_string().starts_with("Failed to save task: "));
+        let source = std::error::Error::source(&error).expect("source preserved");
+        std::assert!(source.to_string().contains("disk full"));
+    }
+}
@@ -5,6 +5,9 @@
 //! links to its parent task and stores the previous state as JSON.
 //!
 //! Revision History
+//! - 2026-10-18T04:50:00Z @AI: Add status_change_to_undo() returning the revision an undo reverts.
+//! - 2026-10-17T03:30:00Z @AI: Add new_status() reading the status a status-change revision moved to.
+//! - 2026-10-16T17:30:00Z @AI: Add previous_status() and status_to_restore() for undoing status changes.
 //! - 2025-11-06T18:14:00Z @AI: Add HexEntity derive for HEXSER framework alignment.
 //! - 2025-11-06T17:41:00Z @AI: Initial TaskRevision struct definition.
 
@@ -56,6 +59,98 @@ pub struct TaskRevision {
     pub previous_state_json: Option<String>,
 }
 
+impl TaskRevision {
+    /// Prefix of the change description recorded when a status change is undone.
+    pub const UNDO_STATUS_CHANGE: &'static str = "Undo status change";
+
+    /// Returns the status recorded in `previous_state_json`, if any.
+    ///
+    /// Status changes store a snapshot such as `{"status":"Todo"}`. Revisions
+    /// without a snapshot, or whose snapshot has no valid status, return None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use task_manager::domain::task_revision::TaskRevision;
+    /// let revision = TaskRevision {
+    ///     revision_id: std::string::String::from("r1"),
+    ///     task_id: std::string::String::from("task-123"),
+    ///     timestamp: chrono::Utc::now(),
+    ///     change_description: std::string::String::from("Status: Todo -> InProgress"),
+    ///     previous_state_json: Some(std::string::String::from(r#"{"status":"Todo"}"#)),
+    /// };
+    /// assert_eq!(revision.previous_status(), Some(task_manager::domain::task_status::TaskStatus::Todo));
+    /// ```
+    pub fn previous_status(&self) -> std::option::Option<crate::domain::task_status::TaskStatus> {
+        let json = self.previous_state_json.as_deref()?;
+        let value: serde_json::Value = serde_json::from_str(json).ok()?;
+        serde_json::from_value(value.get("status")?.clone()).ok()
+    }
+
+    /// Returns the status a status change (or its undo) moved the task to.
+    ///
+    /// Status change descriptions end in "<from> -> <to>" (see
+    /// ManageTaskUseCase::change_status()); other revisions return None.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use task_manager::domain::task_revision::TaskRevision;
+    /// # use task_manager::domain::task_status::TaskStatus;
+    /// let revision = TaskRevision {
+    ///     revision_id: uuid::Uuid::new_v4().to_string(),
+    ///     task_id: std::string::String::from("task-123"),
+    ///     timestamp: chrono::Utc::now(),
+    ///     change_description: std::string::String::from("Status: InProgress -> Completed"),
+    ///     previous_state_json: Some(std::string::String::from(r#"{"status":"InProgress"}"#)),
+    /// };
+    ///
+    /// assert_eq!(revision.new_status(), Some(TaskStatus::Completed));
+    /// ```
+    pub fn new_status(&self) -> std::option::Option<crate::domain::task_status::TaskStatus> {
+        let (_, status) = self.change_description.rsplit_once(" -> ")?;
+        serde_json::from_value(serde_json::Value::String(std::string::String::from(status.trim()))).ok()
+    }
+
+    /// Finds the status change an undo should revert, walking revisions newest first.
+    ///
+    /// Each undo revision cancels the status change before it, so repeated
+    /// undos step further back through the history.
+    ///
+    /// # Arguments
+    ///
+    /// * `revisions` - The task's revisions, oldest first.
+    ///
+    /// # Returns
+    ///
+    /// The most recent status-change revision not already undone, or None if
+    /// there is nothing left to undo.
+    pub fn status_change_to_undo(revisions: &[TaskRevision]) -> std::option::Option<&TaskRevision> {
+        let mut undone = 0;
+        for revision in revisions.iter().rev() {
+            if revision.change_description.starts_with(Self::UNDO_STATUS_CHANGE) {
+                undone += 1;
+            } else if revision.previous_status().is_some() {
+                if undone == 0 {
+                    return std::option::Option::Some(revision);
+                }
+                undone -= 1;
+            }
+        }
+        std::option::Option::None
+    }
+
+    /// Finds the status an undo should restore: the status before the change
+    /// returned by status_change_to_undo().
+    ///
+    /// # Arguments
+    ///
+    /// * `revisions` - The task's revisions, oldest first.
+    pub fn status_to_restore(revisions: &[TaskRevision]) -> std::option::Option<crate::domain::task_status::TaskStatus> {
+        Self::status_change_to_undo(revisions).and_then(TaskRevision::previous_status)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -116,4 +211,52 @@ mod tests {
         assert_eq!(revision.task_id, cloned.task_id);
         assert_eq!(revision.change_description, cloned.change_description);
     }
+
+    #[test]
+    fn test_previous_status_ignores_non_status_snapshots() {
+        // Test: Validates previous_status() only returns a status from a status snapshot.
+        // Justification: Triage and creation revisions must not be mistaken for undoable status changes.
+        let mut revision = TaskRevision {
+            revision_id: uuid::Uuid::new_v4().to_string(),
+            task_id: std::string::String::from("task-undo"),
+            timestamp: chrono::Utc::now(),
+            change_description: std::string::String::from("Auto-triage"),
+            previous_state_json: Some(std::string::String::from(r#"{"priority":"high"}"#)),
+        };
+        assert_eq!(revision.previous_status(), None);
+
+        revision.previous_state_json = Some(std::string::String::from(r#"{"status":"Completed"}"#));
+        assert_eq!(revision.previous_status(), Some(crate::domain::task_status::TaskStatus::Completed));
+    }
+
+    #[test]
+    fn test_status_to_restore_skips_undone_changes() {
+        // Test: Validates each undo revision cancels one earlier status change.
+        // Justification: Pressing undo twice must step back two changes, not toggle.
+        let revision = |description: &str, previous: Option<&str>| TaskRevision {
+            revision_id: uuid::Uuid::new_v4().to_string(),
+            task_id: std::string::String::from("task-undo"),
+            timestamp: chrono::Utc::now(),
+            change_description: std::string::String::from(description),
+            previous_state_json: previous.map(std::string::String::from),
+        };
+        let mut revisions = std::vec![
+            revision("Task created", None),
+            revision("Status: Todo -> InProgress", Some(r#"{"status":"Todo"}"#)),
+            revision("Status: InProgress -> Completed", Some(r#"{"status":"InProgress"}"#)),
+        ];
+        assert_eq!(
+            TaskRevision::status_to_restore(&revisions),
+            Some(crate::domain::task_status::TaskStatus::InProgress)
+        );
+
+        revisions.push(revision("Undo status change: Completed -> InProgress", None));
+        assert_eq!(
+            TaskRevision::status_to_restore(&revisions),
+            Some(crate::domain::task_status::TaskStatus::Todo)
+        );
+
+        revisions.push(revision("Undo status change: InProgress -> Todo", None));
+        assert_eq!(TaskRevision::status_to_restore(&revisions), None);
+    }
 }
@@ -5,6 +5,9 @@
 //! progress and filtering tasks by their current status.
 //!
 //! Revision History
+//! - 2026-10-18T03:20:00Z @AI: Add ALL, as_str(), and FromStr so command-line flags accept every status.
+//! - 2026-10-17T10:30:00Z @AI: Enforce a lifecycle state machine in can_transition_to(): completion only from active work, no leaving Completed/Archived except by reopening. Add is_terminal().
+//! - 2026-10-16T17:30:00Z @AI: Add can_transition_to() for validating manual status changes.
 //! - 2025-11-24T18:00:00Z @AI: Add Errored variant for task failure tracking. Enables tracking tasks that encountered errors during execution or orchestration.
 //! - 2025-11-23T15:30:00Z @AI: Add PendingDecomposition and Decomposed variants for Phase 2 Sprint 4 Task 2.2.
 //! - 2025-11-12T20:28:00Z @AI: Add PendingEnhancement, PendingComprehensionTest, PendingFollowOn, OrchestrationComplete variants.
@@ -69,6 +72,105 @@ pub enum TaskStatus {
     Errored,
 }
 
+impl TaskStatus {
+    /// Returns true if a task in this status may be moved to `target`.
+    ///
+    /// The lifecycle is Todo -> InProgress -> Completed, with the orchestrator's
+    /// pending states in between. Any open task may be archived (cancelled),
+    /// and work can be paused back to Todo. Tasks complete only from
+    /// InProgress or OrchestrationComplete; decomposed tasks complete through
+    /// their subtasks. Completed and Archived tasks are terminal: a completed
+    /// task may still be archived, but neither moves anywhere else except by
+    /// an explicit reopen (see ManageTaskUseCase::reopen()). Moving to the
+    /// current status is not a transition.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The requested status.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use task_manager::domain::task_status::TaskStatus;
+    /// assert!(TaskStatus::Todo.can_transition_to(&TaskStatus::InProgress));
+    /// assert!(TaskStatus::InProgress.can_transition_to(&TaskStatus::Completed));
+    /// assert!(!TaskStatus::Archived.can_transition_to(&TaskStatus::Completed));
+    /// assert!(!TaskStatus::Completed.can_transition_to(&TaskStatus::InProgress));
+    /// ```
+    pub fn can_transition_to(&self, target: &TaskStatus) -> bool {
+        match (self, target) {
+            (current, target) if current == target => false,
+            (TaskStatus::Completed, TaskStatus::Archived) => true,
+            (TaskStatus::Completed | TaskStatus::Archived, _) => false,
+            (_, TaskStatus::Archived) => true,
+            (TaskStatus::InProgress | TaskStatus::OrchestrationComplete, TaskStatus::Completed) => true,
+            (_, TaskStatus::Completed) => false,
+            _ => true,
+        }
+    }
+
+    /// Returns true for Completed and Archived, which only a reopen can leave.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, TaskStatus::Completed | TaskStatus::Archived)
+    }
+
+    /// Every status, in lifecycle order.
+    pub const ALL: [TaskStatus; 11] = [
+        TaskStatus::Todo,
+        TaskStatus::InProgress,
+        TaskStatus::PendingEnhancement,
+        TaskStatus::PendingComprehensionTest,
+        TaskStatus::PendingFollowOn,
+        TaskStatus::PendingDecomposition,
+        TaskStatus::Decomposed,
+        TaskStatus::OrchestrationComplete,
+        TaskStatus::Completed,
+        TaskStatus::Archived,
+        TaskStatus::Errored,
+    ];
+
+    /// Returns the snake_case name used on the command line (e.g. "in_progress").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskStatus::Todo => "todo",
+            TaskStatus::InProgress => "in_progress",
+            TaskStatus::PendingEnhancement => "pending_enhancement",
+            TaskStatus::PendingComprehensionTest => "pending_comprehension_test",
+            TaskStatus::PendingFollowOn => "pending_follow_on",
+            TaskStatus::PendingDecomposition => "pending_decomposition",
+            TaskStatus::Decomposed => "decomposed",
+            TaskStatus::OrchestrationComplete => "orchestration_complete",
+            TaskStatus::Completed => "completed",
+            TaskStatus::Archived => "archived",
+            TaskStatus::Errored => "errored",
+        }
+    }
+}
+
+impl std::str::FromStr for TaskStatus {
+    type Err = String;
+
+    /// Parses a status name case-insensitively.
+    ///
+    /// Accepts the snake_case names of `as_str()`, with the underscores
+    /// replaced by hyphens or left out (`in-progress`, `inprogress`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error listing the valid values if `s` is not recognized.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let name = s.trim().to_lowercase().replace('-', "_");
+        TaskStatus::ALL
+            .iter()
+            .find(|status| status.as_str() == name || status.as_str().replace('_', "") == name)
+            .cloned()
+            .ok_or_else(|| {
+                let valid: std::vec::Vec<&str> = TaskStatus::ALL.iter().map(TaskStatus::as_str).collect();
+                std::format!("Invalid status: '{}'. Valid values: {}", s, valid.join(", "))
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -111,4 +213,67 @@ mod tests {
         let decomposed = TaskStatus::Decomposed;
         assert_eq!(decomposed.clone(), TaskStatus::Decomposed);
     }
+
+    #[test]
+    fn test_can_transition_to_allows_lifecycle_moves() {
+        // Test: Validates every transition the lifecycle permits.
+        // Justification: Manual status changes and the time-tracking commands rely on these moves.
+        let valid = [
+            (TaskStatus::Todo, TaskStatus::InProgress),
+            (TaskStatus::InProgress, TaskStatus::Completed),
+            (TaskStatus::InProgress, TaskStatus::Todo),
+            (TaskStatus::Todo, TaskStatus::PendingEnhancement),
+            (TaskStatus::PendingDecomposition, TaskStatus::Decomposed),
+            (TaskStatus::OrchestrationComplete, TaskStatus::Completed),
+            (TaskStatus::Errored, TaskStatus::Todo),
+            (TaskStatus::Errored, TaskStatus::InProgress),
+            (TaskStatus::Todo, TaskStatus::Archived),
+            (TaskStatus::InProgress, TaskStatus::Archived),
+            (TaskStatus::Decomposed, TaskStatus::Archived),
+            (TaskStatus::Completed, TaskStatus::Archived),
+        ];
+
+        for (from, to) in valid {
+            assert!(from.can_transition_to(&to), "{:?} -> {:?} should be allowed", from, to);
+        }
+    }
+
+    #[test]
+    fn test_can_transition_to_rejects_invalid_moves() {
+        // Test: Validates terminal states cannot be left, completion requires active work, and no-op changes are rejected.
+        // Justification: A cancelled (archived) task must not jump straight to Completed; reopening is explicit.
+        let invalid = [
+            (TaskStatus::Archived, TaskStatus::Completed),
+            (TaskStatus::Archived, TaskStatus::Todo),
+            (TaskStatus::Archived, TaskStatus::InProgress),
+            (TaskStatus::Completed, TaskStatus::Todo),
+            (TaskStatus::Completed, TaskStatus::InProgress),
+            (TaskStatus::Todo, TaskStatus::Completed),
+            (TaskStatus::Errored, TaskStatus::Completed),
+            (TaskStatus::Decomposed, TaskStatus::Completed),
+            (TaskStatus::InProgress, TaskStatus::InProgress),
+            (TaskStatus::Archived, TaskStatus::Archived),
+        ];
+
+        for (from, to) in invalid {
+            assert!(!from.can_transition_to(&to), "{:?} -> {:?} should be rejected", from, to);
+        }
+        assert!(TaskStatus::Completed.is_terminal());
+        assert!(TaskStatus::Archived.is_terminal());
+        assert!(!TaskStatus::Errored.is_terminal());
+    }
+
+    #[test]
+    fn test_from_str_accepts_every_status() {
+        // Test: Validates every status parses from its name, case-insensitively and with hyphens or no separator.
+        // Justification: Command-line flags used to accept only four hand-listed statuses.
+        for status in TaskStatus::ALL {
+            assert_eq!(status.as_str().parse::<TaskStatus>(), Ok(status.clone()));
+            assert_eq!(status.as_str().to_uppercase().replace('_', "-").parse::<TaskStatus>(), Ok(status.clone()));
+        }
+        assert_eq!("InProgress".parse::<TaskStatus>(), Ok(TaskStatus::InProgress));
+
+        let error = "done".parse::<TaskStatus>().unwrap_err();
+        assert!(error.contains("pending_decomposition"), "error: {}", error);
+    }
 }
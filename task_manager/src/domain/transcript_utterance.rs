@@ -0,0 +1,48 @@
+//! Defines the TranscriptUtterance value object linking a task to its source.
+//!
+//! A TranscriptUtterance records where in a meeting transcript an action item
+//! was said: the line number and the utterance text itself, so the snippet can
+//! be shown without keeping the whole transcript around.
+//!
+//! Revision History
+//! - 2026-10-17T03:50:00Z @AI: Initial TranscriptUtterance value object.
+
+/// The transcript line an action item was extracted from.
+///
+/// # Fields
+///
+/// * `line` - 1-based line number in the raw transcript.
+/// * `speaker` - Speaker of the line, when it carries a `Speaker:` prefix.
+/// * `text` - The utterance text, without the speaker prefix.
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::domain::transcript_utterance::TranscriptUtterance;
+/// let utterance = TranscriptUtterance {
+///     line: 3,
+///     speaker: Some(std::string::String::from("John")),
+///     text: std::string::String::from("I'll finish the API documentation by Friday."),
+/// };
+/// assert_eq!(utterance.to_string(), "line 3, John: I'll finish the API documentation by Friday.");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct TranscriptUtterance {
+    /// 1-based line number in the raw transcript.
+    pub line: usize,
+
+    /// Speaker of the line, if the transcript is diarized.
+    pub speaker: Option<String>,
+
+    /// The utterance text without the speaker prefix.
+    pub text: String,
+}
+
+impl std::fmt::Display for TranscriptUtterance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.speaker {
+            std::option::Option::Some(ref speaker) => write!(f, "line {}, {}: {}", self.line, speaker, self.text),
+            std::option::Option::None => write!(f, "line {}: {}", self.line, self.text),
+        }
+    }
+}
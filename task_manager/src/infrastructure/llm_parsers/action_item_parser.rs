@@ -3,15 +3,19 @@
 //! This infrastructure component parses possibly noisy JSON responses from LLM
 //! providers into structured ExtractedActionItem DTOs. It handles common schema
 //! variations and provides fallback alias mapping when strict deserialization fails.
+//! The alias map is a `FieldAliases`, so callers can add their own field names.
 //!
 //! Revision History
+//! - 2026-10-17T22:50:00Z @AI: Drive the property tests with proptest strategies.
+//! - 2026-10-17T06:30:00Z @AI: Add parse_action_items_with_aliases; normalize keys and resolve them through FieldAliases.
+//! - 2026-10-17T06:10:00Z @AI: Extract via json_repair, trim strict-path items and skip blank titles, add property tests.
 //! - 2025-11-23T21:00:00Z @AI: Refactor from utils/ to infrastructure/llm_parsers/ (HEXSER compliance).
 //! - 2025-11-08T08:38:10Z @AI: Introduce tolerant parser shared for all adapters.
 
 /// Parses a possibly noisy LLM response into a list of extracted action items.
 ///
 /// This parser handles:
-/// - JSON arrays embedded in text (extracts first [...] found)
+/// - JSON arrays embedded in text, with the syntax noise listed in `json_repair`
 /// - Field name aliases (e.g., "task" vs "title", "owner" vs "assignee")
 /// - Missing optional fields with graceful degradation
 /// - Normalization (e.g., assignee → first name only)
@@ -39,41 +43,91 @@ pub fn parse_action_items_tolerant(
     std::vec::Vec<crate::infrastructure::dtos::extracted_action_item::ExtractedActionItem>,
     std::string::String,
 > {
-    // Try to find JSON array in response (model might include extra text)
-    let json_start = response_text
-        .find('[')
-        .ok_or_else(|| std::string::String::from("No JSON array found in response"))?;
-    let json_end = response_text
-        .rfind(']')
-        .ok_or_else(|| std::string::String::from("No JSON array found in response"))?;
-
-    let json_str = &response_text[json_start..=json_end];
-
-    // First, try strict deserialization into the expected schema.
-    let strict: std::result::Result<
+    parse_action_items_with_aliases(
+        response_text,
+        &crate::infrastructure::llm_parsers::field_aliases::FieldAliases::default(),
+    )
+}
+
+/// Parses a possibly noisy LLM response, resolving field names through `aliases`.
+///
+/// Keys are trimmed and lowercased before alias resolution. Responses that
+/// use exactly the canonical keys are deserialized strictly; any other key
+/// names go through the alias map.
+///
+/// # Arguments
+///
+/// * `response_text` - Raw text from LLM that should contain a JSON array
+/// * `aliases` - Accepted key names per field, built-in plus custom
+///
+/// # Returns
+///
+/// Returns a Vec of ExtractedActionItem if parsing succeeds, or an error string.
+///
+/// # Examples
+///
+/// ```
+/// use task_manager::infrastructure::llm_parsers::action_item_parser::parse_action_items_with_aliases;
+/// use task_manager::infrastructure::llm_parsers::field_aliases::FieldAliases;
+///
+/// let aliases = FieldAliases::default().with_alias("assignee", "driver").unwrap();
+/// let items = parse_action_items_with_aliases(r#"[{"Title": "Ship", "Driver": "Dana"}]"#, &aliases).unwrap();
+/// assert_eq!(items[0].assignee.as_deref(), Some("Dana"));
+/// ```
+pub fn parse_action_items_with_aliases(
+    response_text: &str,
+    aliases: &crate::infrastructure::llm_parsers::field_aliases::FieldAliases,
+) -> std::result::Result<
+    std::vec::Vec<crate::infrastructure::dtos::extracted_action_item::ExtractedActionItem>,
+    std::string::String,
+> {
+    // Locate and repair the array (the model might include extra text or sloppy syntax).
+    // Arrays without objects, like a "[1]" in the preamble, are not the payload.
+    let arr = crate::infrastructure::llm_parsers::json_repair::parse_first(response_text, '[', |value| match value {
+        serde_json::Value::Array(a) if a.iter().any(serde_json::Value::is_object) => std::result::Result::Ok(a),
+        _ => std::result::Result::Err(std::string::String::from("No action items found in response")),
+    })?;
+
+    // First, try strict deserialization when every key is a canonical one;
+    // otherwise serde would silently drop aliased fields such as "owner".
+    let canonical_only = arr.iter().all(|item| {
+        item.as_object().is_some_and(|obj| {
+            obj.keys().all(|key| crate::infrastructure::llm_parsers::field_aliases::FieldAliases::FIELDS.contains(&key.as_str()))
+        })
+    });
+    let strict: std::option::Option<
         std::vec::Vec<crate::infrastructure::dtos::extracted_action_item::ExtractedActionItem>,
-        serde_json::Error,
-    > = serde_json::from_str(json_str);
-    if let std::result::Result::Ok(items) = strict {
+    > = if canonical_only {
+        serde_json::from_value(serde_json::Value::Array(arr.clone())).ok()
+    } else {
+        std::option::Option::None
+    };
+    if let std::option::Option::Some(items) = strict {
+        // Same cleanup as the alias path, so blank titles never get through
+        let items: std::vec::Vec<_> = items.into_iter().filter_map(sanitize).collect();
         if items.is_empty() {
-            return std::result::Result::Err(std::string::String::from("No action items found in response"));
+            return std::result::Result::Err(std::string::String::from(
+                "Failed to parse LLM response as JSON: no valid items after alias mapping",
+            ));
         }
         return std::result::Result::Ok(items);
     }
 
-    // Fallback: parse loosely and map common alias fields to the schema.
-    let value: serde_json::Value = serde_json::from_str(json_str)
-        .map_err(|e| std::format!("Failed to parse LLM response as JSON: {}", e))?;
-    let arr = match value {
-        serde_json::Value::Array(a) => a,
-        _ => return std::result::Result::Err(std::string::String::from("Top-level JSON is not an array")),
-    };
-
+    // Fallback: map common alias fields to the schema.
     let mut out: std::vec::Vec<crate::infrastructure::dtos::extracted_action_item::ExtractedActionItem> = std::vec::Vec::new();
 
     for (idx, item) in arr.into_iter().enumerate() {
-        let obj = match item {
-            serde_json::Value::Object(m) => m,
+        // Normalize keys before alias resolution; the first of two colliding keys wins
+        let obj: serde_json::Map<std::string::String, serde_json::Value> = match item {
+            serde_json::Value::Object(m) => {
+                let mut normalized = serde_json::Map::new();
+                for (key, value) in m {
+                    normalized
+                        .entry(crate::infrastructure::llm_parsers::field_aliases::FieldAliases::normalize_key(&key))
+                        .or_insert(value);
+                }
+                normalized
+            }
             _ => {
                 // Skip non-object entries
                 println!("[Parser] Skipping non-object at index {} in LLM JSON array", idx);
@@ -84,10 +138,10 @@ pub fn parse_action_items_tolerant(
         // Helper to extract a string value from multiple candidate keys
         fn extract_string(
             m: &serde_json::Map<std::string::String, serde_json::Value>,
-            keys: &[&str],
+            keys: &[std::string::String],
         ) -> std::option::Option<std::string::String> {
             for k in keys {
-                if let std::option::Option::Some(v) = m.get(*k) {
+                if let std::option::Option::Some(v) = m.get(k) {
                     match v {
                         serde_json::Value::String(s) => {
                             if !s.trim().is_empty() {
@@ -107,26 +161,15 @@ pub fn parse_action_items_tolerant(
             std::option::Option::None
         }
 
-        // The model may use aliases; try a set of common variants.
-        let title = extract_string(
-            &obj,
-            &[
-                "title",
-                "task",
-                "action",
-                "item",
-                "summary",
-                "description",
-                "name",
-            ],
-        );
+        // The model may use aliases; try the built-in and configured variants.
+        let title = extract_string(&obj, aliases.title());
 
         // If we still don't have a title, attempt to synthesize from other fields.
         let title = match title {
             std::option::Option::Some(t) => t,
             std::option::Option::None => {
                 // Combine any present fields to form a basic title; if nothing usable, skip.
-                let synthesized = extract_string(&obj, &["content", "text", "details"]);
+                let synthesized = extract_string(&obj, aliases.title_fallbacks());
                 match synthesized {
                     std::option::Option::Some(s) if !s.is_empty() => s,
                     _ => {
@@ -138,18 +181,7 @@ pub fn parse_action_items_tolerant(
         };
 
         // Assignee aliases
-        let mut assignee = extract_string(
-            &obj,
-            &[
-                "assignee",
-                "owner",
-                "assigned_to",
-                "responsible",
-                "who",
-                "person",
-                "assignee_name",
-            ],
-        );
+        let mut assignee = extract_string(&obj, aliases.assignee());
         // Normalize assignee to first word (first name) if present
         if let std::option::Option::Some(a) = assignee.clone() {
             let first = a.split_whitespace().next().unwrap_or("").to_string();
@@ -157,17 +189,7 @@ pub fn parse_action_items_tolerant(
         }
 
         // Due date aliases (keep as-is; upstream may validate/normalize)
-        let due_date = extract_string(
-            &obj,
-            &[
-                "due_date",
-                "dueDate",
-                "due",
-                "deadline",
-                "date",
-                "due_by",
-            ],
-        );
+        let due_date = extract_string(&obj, aliases.due_date());
 
         out.push(crate::infrastructure::dtos::extracted_action_item::ExtractedActionItem {
             title,
@@ -184,6 +206,26 @@ pub fn parse_action_items_tolerant(
     std::result::Result::Ok(out)
 }
 
+/// Trims a strictly deserialized item; drops it if the title is blank.
+///
+/// Blank optional fields become `None`, matching the alias path.
+fn sanitize(
+    item: crate::infrastructure::dtos::extracted_action_item::ExtractedActionItem,
+) -> std::option::Option<crate::infrastructure::dtos::extracted_action_item::ExtractedActionItem> {
+    let non_blank = |value: std::option::Option<std::string::String>| {
+        value.map(|v| v.trim().to_string()).filter(|v| !v.is_empty())
+    };
+    let title = item.title.trim().to_string();
+    if title.is_empty() {
+        return std::option::Option::None;
+    }
+    std::option::Option::Some(crate::infrastructure::dtos::extracted_action_item::ExtractedActionItem {
+        title,
+        assignee: non_blank(item.assignee),
+        due_date: non_blank(item.due_date),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -240,4 +282,295 @@ mod tests {
         assert_eq!(items.len(), 1); // First entry skipped, second entry parsed
         assert_eq!(items[0].title, "Valid task");
     }
+
+    #[test]
+    fn test_blank_strict_title_is_skipped_and_fields_trimmed() {
+        // Test: Validates the strict path trims fields and drops items whose title is blank.
+        // Justification: Strict deserialization used to pass blank titles and padded fields straight through.
+        let json = "[{\"title\": \"  \", \"assignee\": \"Ann\"}, {\"title\": \" Ship \", \"assignee\": \" \", \"due_date\": \"2025-12-01 \"}]";
+        let items = parse_action_items_tolerant(json).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].title, "Ship");
+        assert_eq!(items[0].assignee, None);
+        assert_eq!(items[0].due_date, Some("2025-12-01".to_string()));
+    }
+
+    #[test]
+    fn test_custom_alias_maps_onto_canonical_field() {
+        // Test: Validates a configured alias maps a non-standard, differently cased key onto the canonical field.
+        // Justification: Users add field names for their models through config instead of editing the parser.
+        let aliases = crate::infrastructure::llm_parsers::field_aliases::FieldAliases::from_json(
+            r#"{"title": ["headline"], "assignee": ["driver"]}"#,
+        )
+        .unwrap();
+        let json = r#"[{" Headline ": "Ship the release", "DRIVER": "Dana Scully", "Deadline": "2025-12-01"}]"#;
+
+        let items = parse_action_items_with_aliases(json, &aliases).unwrap();
+        assert_eq!(items[0].title, "Ship the release");
+        assert_eq!(items[0].assignee, Some("Dana".to_string()));
+        assert_eq!(items[0].due_date, Some("2025-12-01".to_string()));
+
+        // Without the alias the item has no title
+        assert!(parse_action_items_tolerant(json).is_err());
+    }
+
+    #[test]
+    fn test_builtin_aliases_still_apply_with_custom_ones() {
+        // Test: Validates built-in aliases keep working next to custom ones, including alongside a canonical title.
+        // Justification: A title plus "owner" used to deserialize strictly and silently drop the owner.
+        let aliases = crate::infrastructure::llm_parsers::field_aliases::FieldAliases::default()
+            .with_alias("assignee", "driver")
+            .unwrap();
+        let json = r#"[{"title": "Fix bug", "owner": "Bob"}, {"Task": "Write docs", "dueDate": "2025-11-30"}]"#;
+
+        let items = parse_action_items_with_aliases(json, &aliases).unwrap();
+        assert_eq!(items[0].title, "Fix bug");
+        assert_eq!(items[0].assignee, Some("Bob".to_string()));
+        assert_eq!(items[1].title, "Write docs");
+        assert_eq!(items[1].due_date, Some("2025-11-30".to_string()));
+    }
+
+    // Property tests: cases come from proptest strategies, and a failing
+    // case is shrunk and printed with the response it produced. Raise the
+    // case count locally when changing the parser.
+
+    /// Characters that need escaping: quotes, backslashes, brackets, control characters, and non-ASCII.
+    const NOISY_CHARS: &[char] = &['a', 'b', 'Z', '7', ' ', ' ', '"', '\\', '\n', '\t', '[', ']', '{', '}', ',', ':', 'é', '✓'];
+
+    proptest::prop_compose! {
+        /// Text made of `NOISY_CHARS` that does not end in whitespace.
+        fn noisy_text()(
+            body in proptest::collection::vec(proptest::sample::select(NOISY_CHARS), 0..10),
+            last in proptest::sample::select(vec!['x', 'y', 'é']),
+        ) -> String {
+            body.into_iter().chain(std::iter::once(last)).collect()
+        }
+    }
+
+    proptest::prop_compose! {
+        fn word()(chars in proptest::collection::vec(proptest::sample::select(vec!['a', 'k', 'M', 'o', 'ü']), 1..9)) -> String {
+            chars.into_iter().collect()
+        }
+    }
+
+    proptest::prop_compose! {
+        /// An item as written in the response, or None for one with a blank title.
+        fn entry()(
+            blank in proptest::bool::weighted(0.1),
+            title in noisy_text(),
+            assignee in proptest::option::weighted(0.6, word()),
+            day in proptest::option::of(1..10u32),
+        ) -> Option<crate::infrastructure::dtos::extracted_action_item::ExtractedActionItem> {
+            if blank {
+                return None;
+            }
+            Some(crate::infrastructure::dtos::extracted_action_item::ExtractedActionItem {
+                title,
+                assignee,
+                due_date: day.map(|d| std::format!("2025-12-0{}", d)),
+            })
+        }
+    }
+
+    proptest::prop_compose! {
+        /// A response array and the items the parser must return for it.
+        fn generated_items()(
+            aliases in proptest::bool::weighted(0.3),
+            entries in proptest::collection::vec(entry(), 1..5),
+        ) -> (serde_json::Value, Vec<crate::infrastructure::dtos::extracted_action_item::ExtractedActionItem>) {
+            let mut values = Vec::new();
+            let mut expected = Vec::new();
+            for entry in entries {
+                let mut obj = serde_json::Map::new();
+                let Some(item) = entry else {
+                    // Blank title: must be skipped, never returned
+                    obj.insert(String::from(if aliases { "task" } else { "title" }), serde_json::json!(" \n "));
+                    values.push(serde_json::Value::Object(obj));
+                    continue;
+                };
+                obj.insert(String::from(if aliases { "task" } else { "title" }), serde_json::json!(item.title));
+                if let Some(a) = &item.assignee {
+                    obj.insert(String::from(if aliases { "owner" } else { "assignee" }), serde_json::json!(a));
+                }
+                if let Some(d) = &item.due_date {
+                    obj.insert(String::from(if aliases { "deadline" } else { "due_date" }), serde_json::json!(d));
+                }
+                values.push(serde_json::Value::Object(obj));
+                expected.push(crate::infrastructure::dtos::extracted_action_item::ExtractedActionItem {
+                    title: item.title.trim().to_string(),
+                    ..item
+                });
+            }
+            if expected.is_empty() {
+                values.push(serde_json::json!({"title": "Fallback"}));
+                expected.push(crate::infrastructure::dtos::extracted_action_item::ExtractedActionItem {
+                    title: String::from("Fallback"),
+                    assignee: None,
+                    due_date: None,
+                });
+            }
+            (serde_json::Value::Array(values), expected)
+        }
+    }
+
+    /// Noise json_repair guarantees to recover from.
+    #[derive(Debug, Clone)]
+    struct RecoverableNoise {
+        /// Whether escaped newlines and tabs in strings become raw control characters.
+        raw_controls: bool,
+        /// Text inserted before each successive closing bracket (a trailing comma, maybe with a newline).
+        trailing_commas: Vec<Option<&'static str>>,
+        prefix: &'static str,
+        suffix: &'static str,
+        /// Whether the JSON is fenced as a code block.
+        fenced: bool,
+    }
+
+    proptest::prop_compose! {
+        fn recoverable_noise()(
+            raw_controls in proptest::bool::ANY,
+            trailing_commas in proptest::collection::vec(
+                proptest::option::weighted(0.3, proptest::sample::select(vec![",", ",\n"])),
+                0..24,
+            ),
+            prefix in proptest::sample::select(vec!["", "Here are the action items [draft]:\n", "Sure! {see below}\n", "Result [1]:\n"]),
+            suffix in proptest::sample::select(vec!["", "\nLet me know [if] you need more.", "\n}", "\n]"]),
+            fenced in proptest::bool::weighted(0.4),
+        ) -> RecoverableNoise {
+            RecoverableNoise { raw_controls, trailing_commas, prefix, suffix, fenced }
+        }
+    }
+
+    /// Applies the noise patterns json_repair guarantees to recover.
+    fn add_recoverable_noise(noise: &RecoverableNoise, json: &str) -> String {
+        let mut out = String::new();
+        let mut in_string = false;
+        let mut chars = json.chars();
+        let mut last_significant = ' ';
+        let mut closing = 0;
+        while let Some(c) = chars.next() {
+            if in_string {
+                if c == '\\' {
+                    let next = chars.next().unwrap();
+                    match next {
+                        'n' if noise.raw_controls => out.push('\n'),
+                        't' if noise.raw_controls => out.push('\t'),
+                        _ => {
+                            out.push(c);
+                            out.push(next);
+                        }
+                    }
+                    continue;
+                }
+                if c == '"' {
+                    in_string = false;
+                }
+                out.push(c);
+                continue;
+            }
+            if (c == ']' || c == '}') && !std::matches!(last_significant, '[' | '{' | ',') {
+                if let Some(comma) = noise.trailing_commas.get(closing).copied().flatten() {
+                    out.push_str(comma);
+                }
+                closing += 1;
+            }
+            if c == '"' {
+                in_string = true;
+            }
+            out.push(c);
+            last_significant = c;
+        }
+        if noise.fenced {
+            std::format!("{}```json\n{}\n```{}", noise.prefix, out, noise.suffix)
+        } else {
+            std::format!("{}{}{}", noise.prefix, out, noise.suffix)
+        }
+    }
+
+    fn render(value: &serde_json::Value, pretty: bool) -> String {
+        if pretty {
+            serde_json::to_string_pretty(value).unwrap()
+        } else {
+            serde_json::to_string(value).unwrap()
+        }
+    }
+
+    fn item_keys(
+        items: &[crate::infrastructure::dtos::extracted_action_item::ExtractedActionItem],
+    ) -> Vec<(String, Option<String>, Option<String>)> {
+        items.iter().map(|i| (i.title.clone(), i.assignee.clone(), i.due_date.clone())).collect()
+    }
+
+    /// How a corrupted response is damaged.
+    #[derive(Debug, Clone, Copy)]
+    enum Corruption {
+        /// Cut off before the array's closing bracket.
+        Truncate,
+        RemoveQuote,
+        InsertQuote,
+    }
+
+    proptest::proptest! {
+        #![proptest_config(proptest::test_runner::Config::with_cases(256))]
+
+        #[test]
+        fn property_recoverable_noise_yields_the_original_items(
+            (value, expected) in generated_items(),
+            pretty in proptest::bool::ANY,
+            noise in recoverable_noise(),
+        ) {
+            // Test: Validates every generated response with guaranteed-recoverable noise parses to exactly the generated items.
+            // Justification: Trailing commas and raw newlines used to fail whole responses or garble fields.
+            let response = add_recoverable_noise(&noise, &render(&value, pretty));
+
+            let result = parse_action_items_tolerant(&response);
+            proptest::prop_assert!(result.is_ok(), "{:?} -> {:?}", response, result);
+            proptest::prop_assert_eq!(item_keys(&result.unwrap()), item_keys(&expected), "{:?}", response);
+        }
+
+        #[test]
+        fn property_corrupted_responses_never_yield_corrupt_items(
+            (value, expected) in generated_items(),
+            pretty in proptest::bool::ANY,
+            noise in recoverable_noise(),
+            corruption in proptest::prop_oneof![
+                2 => proptest::strategy::Just(Corruption::Truncate),
+                1 => proptest::strategy::Just(Corruption::RemoveQuote),
+                1 => proptest::strategy::Just(Corruption::InsertQuote),
+            ],
+            at in proptest::prelude::any::<proptest::sample::Index>(),
+        ) {
+            // Test: Validates truncated responses fail, and responses with a stray or missing quote either fail or yield only well-formed items.
+            // Justification: A recoverable-looking response must never produce a silently garbled entity.
+            let mut chars: Vec<char> = add_recoverable_noise(&noise, &render(&value, pretty)).chars().collect();
+            match corruption {
+                Corruption::Truncate => {
+                    let end = chars.iter().rposition(|c| *c == ']').unwrap();
+                    chars.truncate(at.index(end));
+                }
+                Corruption::RemoveQuote => {
+                    let quotes: Vec<usize> = (0..chars.len()).filter(|i| chars[*i] == '"').collect();
+                    chars.remove(quotes[at.index(quotes.len())]);
+                }
+                Corruption::InsertQuote => {
+                    let at = at.index(chars.len());
+                    chars.insert(at, '"');
+                }
+            }
+            let response: String = chars.into_iter().collect();
+
+            if let Ok(items) = parse_action_items_tolerant(&response) {
+                for item in &items {
+                    proptest::prop_assert!(!item.title.is_empty() && item.title.trim() == item.title, "{:?} -> {:?}", response, item);
+                    proptest::prop_assert!(
+                        item.assignee.as_deref().is_none_or(|a| !a.is_empty() && !a.contains(char::is_whitespace)),
+                        "{:?} -> {:?}", response, item
+                    );
+                    if std::matches!(corruption, Corruption::Truncate) {
+                        proptest::prop_assert!(expected.iter().any(|e| e.title == item.title), "{:?} -> {:?}", response, item);
+                    }
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,170 @@
+//! Defines FieldAliases, the key names the action item parser accepts for each field.
+//!
+//! Models name fields differently ("task" vs "title", "owner" vs "assignee").
+//! The built-in aliases cover the common variants; users add their own from a
+//! JSON file instead of editing the parser:
+//!
+//! ```json
+//! { "title": ["headline"], "assignee": ["driver", "dri"], "due_date": ["eta"] }
+//! ```
+//!
+//! Keys are normalized (trimmed and lowercased) on both sides before they are
+//! compared, so `"Owner "` in a response matches the `owner` alias.
+//!
+//! Revision History
+//! - 2026-10-17T06:30:00Z @AI: Initial FieldAliases with built-in aliases and JSON file loading.
+
+/// Built-in title aliases, in priority order.
+const TITLE_ALIASES: &[&str] = &["title", "task", "action", "item", "summary", "description", "name"];
+
+/// Keys a title is synthesized from when no title alias is present.
+const TITLE_FALLBACKS: &[&str] = &["content", "text", "details"];
+
+/// Built-in assignee aliases, in priority order.
+const ASSIGNEE_ALIASES: &[&str] = &["assignee", "owner", "assigned_to", "responsible", "who", "person", "assignee_name"];
+
+/// Built-in due date aliases, in priority order.
+const DUE_DATE_ALIASES: &[&str] = &["due_date", "duedate", "due", "deadline", "date", "due_by"];
+
+/// Accepted key names for each action item field.
+///
+/// Custom aliases are tried after the built-in ones, so they can add names
+/// but not shadow the standard ones.
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::infrastructure::llm_parsers::field_aliases::FieldAliases;
+/// let aliases = FieldAliases::default().with_alias("assignee", " DRI ").unwrap();
+/// std::assert!(aliases.assignee().iter().any(|alias| alias == "dri"));
+/// std::assert!(FieldAliases::default().with_alias("priority", "prio").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldAliases {
+    title: std::vec::Vec<String>,
+    title_fallbacks: std::vec::Vec<String>,
+    assignee: std::vec::Vec<String>,
+    due_date: std::vec::Vec<String>,
+}
+
+impl FieldAliases {
+    /// Canonical field names that aliases can be added for.
+    pub const FIELDS: &'static [&'static str] = &["title", "assignee", "due_date"];
+
+    /// Normalizes a key for comparison: trimmed and lowercased.
+    pub fn normalize_key(key: &str) -> String {
+        key.trim().to_lowercase()
+    }
+
+    /// Adds an alias for a canonical field.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `field` is not one of `FIELDS` or the alias is blank.
+    pub fn with_alias(mut self, field: &str, alias: &str) -> std::result::Result<Self, String> {
+        let alias = Self::normalize_key(alias);
+        if alias.is_empty() {
+            return std::result::Result::Err(std::format!("Blank alias for field '{}'", field));
+        }
+        let aliases = match Self::normalize_key(field).as_str() {
+            "title" => &mut self.title,
+            "assignee" => &mut self.assignee,
+            "due_date" => &mut self.due_date,
+            other => {
+                return std::result::Result::Err(std::format!(
+                    "Unknown field '{}' in field aliases; expected one of: {}",
+                    other,
+                    Self::FIELDS.join(", ")
+                ))
+            }
+        };
+        if !aliases.contains(&alias) {
+            aliases.push(alias);
+        }
+        std::result::Result::Ok(self)
+    }
+
+    /// Adds the aliases in a JSON object mapping fields to alias lists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is malformed, not an object of string
+    /// arrays, or names an unknown field.
+    pub fn from_json(json: &str) -> std::result::Result<Self, String> {
+        let custom: std::collections::BTreeMap<String, std::vec::Vec<String>> = serde_json::from_str(json)
+            .map_err(|e| std::format!("Invalid field aliases (expected {{\"field\": [\"alias\", ...]}}): {}", e))?;
+        let mut aliases = Self::default();
+        for (field, names) in &custom {
+            for name in names {
+                aliases = aliases.with_alias(field, name)?;
+            }
+        }
+        std::result::Result::Ok(aliases)
+    }
+
+    /// Loads custom aliases from a JSON file; see `from_json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is invalid.
+    pub fn from_file(path: &std::path::Path) -> std::result::Result<Self, String> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| std::format!("Failed to read field aliases {}: {}", path.display(), e))?;
+        Self::from_json(&json).map_err(|e| std::format!("{}: {}", path.display(), e))
+    }
+
+    /// Normalized title aliases, in priority order.
+    pub fn title(&self) -> &[String] {
+        &self.title
+    }
+
+    /// Normalized keys a title is synthesized from when no title alias is present.
+    pub fn title_fallbacks(&self) -> &[String] {
+        &self.title_fallbacks
+    }
+
+    /// Normalized assignee aliases, in priority order.
+    pub fn assignee(&self) -> &[String] {
+        &self.assignee
+    }
+
+    /// Normalized due date aliases, in priority order.
+    pub fn due_date(&self) -> &[String] {
+        &self.due_date
+    }
+}
+
+impl std::default::Default for FieldAliases {
+    fn default() -> Self {
+        let owned = |names: &[&str]| names.iter().map(|name| name.to_string()).collect();
+        FieldAliases {
+            title: owned(TITLE_ALIASES),
+            title_fallbacks: owned(TITLE_FALLBACKS),
+            assignee: owned(ASSIGNEE_ALIASES),
+            due_date: owned(DUE_DATE_ALIASES),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_from_json_appends_normalized_aliases() {
+        // Test: Validates aliases from JSON are normalized and appended after the built-in ones.
+        // Justification: Custom names must extend, not replace, the aliases every model relies on.
+        let aliases = super::FieldAliases::from_json(r#"{"Assignee": [" Driver ", "owner"], "due_date": ["ETA"]}"#).unwrap();
+
+        std::assert_eq!(aliases.assignee().last().unwrap(), "driver");
+        std::assert_eq!(aliases.assignee().iter().filter(|alias| *alias == "owner").count(), 1);
+        std::assert_eq!(aliases.due_date().last().unwrap(), "eta");
+        std::assert_eq!(aliases.title(), super::FieldAliases::default().title());
+    }
+
+    #[test]
+    fn test_from_json_rejects_unknown_fields_and_bad_shapes() {
+        // Test: Validates a typo in a field name or a non-list value fails loading with a clear message.
+        // Justification: A silently ignored alias file would look like the parser ignoring the config.
+        std::assert!(super::FieldAliases::from_json(r#"{"titel": ["headline"]}"#).unwrap_err().contains("Unknown field 'titel'"));
+        std::assert!(super::FieldAliases::from_json(r#"{"title": "headline"}"#).unwrap_err().contains("Invalid field aliases"));
+    }
+}
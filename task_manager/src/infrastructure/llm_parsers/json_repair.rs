@@ -0,0 +1,208 @@
+//! Locates and repairs the JSON payload in a noisy LLM response.
+//!
+//! Shared by the tolerant parsers. The noise patterns below are guaranteed
+//! to be recovered exactly, i.e. the repaired payload parses to the value
+//! the model meant:
+//!
+//! - Prose before and after the payload, including prose that itself
+//!   contains brackets or braces (`Here are the items [draft]: ...`).
+//! - Markdown code fences around the payload.
+//! - Trailing commas before a closing `]` or `}`.
+//! - Raw newlines, carriage returns, tabs, and other control characters
+//!   inside string values.
+//! - Extra whitespace between tokens.
+//!
+//! Anything else is rejected with an error rather than guessed at: unescaped
+//! double quotes inside strings, single-quoted strings, comments, and
+//! truncated output.
+//!
+//! Revision History
+//! - 2026-10-17T06:10:00Z @AI: Initial JSON extraction and repair for the tolerant parsers.
+
+/// How many opening brackets in a response are tried as payload candidates.
+const MAX_CANDIDATES: usize = 64;
+
+/// Finds the first JSON payload in `response_text` that `convert` accepts.
+///
+/// Every `open` character (`[` or `{`) starts a candidate: the balanced span
+/// from it is repaired and parsed, and the first candidate that parses and
+/// converts wins. Later candidates are only tried when earlier ones fail, so
+/// a bracket in the model's preamble doesn't hide the real payload.
+///
+/// # Arguments
+///
+/// * `response_text` - Raw model output
+/// * `open` - `'['` for an array payload, `'{'` for an object payload
+/// * `convert` - Turns a parsed candidate into the caller's result
+///
+/// # Errors
+///
+/// Returns the error of the first candidate that parsed but failed to
+/// convert; otherwise the parse error of the first candidate; otherwise
+/// "No JSON array (or object) found in response".
+///
+/// # Examples
+///
+/// ```
+/// use task_manager::infrastructure::llm_parsers::json_repair::parse_first;
+///
+/// let response = "Sure [draft]:\n```json\n[{\"title\": \"Ship it\",},]\n```";
+/// let value = parse_first(response, '[', |value| std::result::Result::Ok(value)).unwrap();
+/// assert_eq!(value[0]["title"], "Ship it");
+/// ```
+pub fn parse_first<T>(
+    response_text: &str,
+    open: char,
+    mut convert: impl FnMut(serde_json::Value) -> std::result::Result<T, std::string::String>,
+) -> std::result::Result<T, std::string::String> {
+    let kind = if open == '[' { "array" } else { "object" };
+    let mut parse_error: std::option::Option<std::string::String> = std::option::Option::None;
+    let mut convert_error: std::option::Option<std::string::String> = std::option::Option::None;
+
+    for (start, _) in response_text.match_indices(open).take(MAX_CANDIDATES) {
+        let candidate = match balanced_span(&response_text[start..]) {
+            std::option::Option::Some(span) => span,
+            std::option::Option::None => {
+                parse_error.get_or_insert_with(|| std::format!("Failed to parse LLM response as JSON: unterminated JSON {}", kind));
+                continue;
+            }
+        };
+        match serde_json::from_str::<serde_json::Value>(&repair(candidate)) {
+            std::result::Result::Ok(value) => match convert(value) {
+                std::result::Result::Ok(result) => return std::result::Result::Ok(result),
+                std::result::Result::Err(e) => {
+                    convert_error.get_or_insert(e);
+                }
+            },
+            std::result::Result::Err(e) => {
+                parse_error.get_or_insert_with(|| std::format!("Failed to parse LLM response as JSON: {}", e));
+            }
+        }
+    }
+
+    std::result::Result::Err(
+        convert_error
+            .or(parse_error)
+            .unwrap_or_else(|| std::format!("No JSON {} found in response", kind)),
+    )
+}
+
+/// Repairs the recoverable noise in a JSON candidate.
+///
+/// Escapes control characters inside strings and drops commas that directly
+/// precede a closing bracket. Valid JSON is returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use task_manager::infrastructure::llm_parsers::json_repair::repair;
+///
+/// assert_eq!(repair("{\"a\": \"line\nbreak\", }"), "{\"a\": \"line\\nbreak\" }");
+/// ```
+pub fn repair(candidate: &str) -> std::string::String {
+    let chars: std::vec::Vec<char> = candidate.chars().collect();
+    let mut out = std::string::String::with_capacity(candidate.len());
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, &c) in chars.iter().enumerate() {
+        if in_string {
+            if escaped {
+                escaped = false;
+                out.push(c);
+            } else if c == '\\' {
+                escaped = true;
+                out.push(c);
+            } else if c == '"' {
+                in_string = false;
+                out.push(c);
+            } else if (c as u32) < 0x20 {
+                match c {
+                    '\n' => out.push_str("\\n"),
+                    '\r' => out.push_str("\\r"),
+                    '\t' => out.push_str("\\t"),
+                    _ => out.push_str(&std::format!("\\u{:04x}", c as u32)),
+                }
+            } else {
+                out.push(c);
+            }
+        } else if c == '"' {
+            in_string = true;
+            out.push(c);
+        } else if c == ',' && std::matches!(chars[i + 1..].iter().find(|n| !n.is_whitespace()), std::option::Option::Some(']') | std::option::Option::Some('}')) {
+            // Trailing comma: drop it
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Returns the prefix of `text` up to the bracket closing its first character.
+///
+/// Strings are skipped, so brackets inside values don't count. Returns
+/// `None` when the text ends first (truncated output).
+fn balanced_span(text: &str) -> std::option::Option<&str> {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, c) in text.char_indices() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '[' | '{' => depth += 1,
+            ']' | '}' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return std::option::Option::Some(&text[..i + c.len_utf8()]);
+                }
+            }
+            _ => {}
+        }
+    }
+    std::option::Option::None
+}
+
+#[cfg(test)]
+mod tests {
+    fn first_value(response: &str, open: char) -> std::result::Result<serde_json::Value, std::string::String> {
+        super::parse_first(response, open, std::result::Result::Ok)
+    }
+
+    #[test]
+    fn test_repair_escapes_control_characters_and_drops_trailing_commas() {
+        // Test: Validates raw control characters in strings are escaped and trailing commas removed, but commas inside strings kept.
+        // Justification: These are the two syntax errors models produce most often.
+        let repaired = super::repair("[{\"title\": \"a,]\tb\u{1}\",\n},\n]");
+
+        std::assert_eq!(repaired, "[{\"title\": \"a,]\\tb\\u0001\"\n}\n]");
+        std::assert_eq!(serde_json::from_str::<serde_json::Value>(&repaired).unwrap()[0]["title"], "a,]\tb\u{1}");
+    }
+
+    #[test]
+    fn test_skips_bracketed_prose_before_the_payload() {
+        // Test: Validates a bracket in the preamble and a stray bracket after the payload don't hide the real array.
+        // Justification: Taking the first '[' to the last ']' used to swallow prose and fail the whole response.
+        let value = first_value("Items [draft]:\n[{\"title\": \"Ship\"}]\nSee [1].", '[').unwrap();
+
+        std::assert_eq!(value, serde_json::json!([{"title": "Ship"}]));
+    }
+
+    #[test]
+    fn test_truncated_or_missing_payload_is_an_error() {
+        // Test: Validates truncated output and responses without a payload fail with a clear message.
+        // Justification: Truncation must never yield a partial entity.
+        std::assert!(first_value("[{\"title\": \"Ship\"}, {\"title\": \"Te", '[').unwrap_err().contains("unterminated JSON array"));
+        std::assert_eq!(first_value("no json here", '{').unwrap_err(), "No JSON object found in response");
+    }
+}
@@ -4,6 +4,10 @@
 //! applying tolerant parsing strategies with alias mapping and field normalization.
 //!
 //! Revision History
+//! - 2026-10-17T06:30:00Z @AI: Add field_aliases for configurable action item field names.
+//! - 2026-10-17T06:10:00Z @AI: Add json_repair for payload extraction and syntax repair.
 //! - 2025-11-23T21:21:00Z @AI: Create llm_parsers module (HEXSER compliance).
 
 pub mod action_item_parser;
+pub mod json_repair;
+pub mod field_aliases;
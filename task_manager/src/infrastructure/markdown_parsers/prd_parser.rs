@@ -2,9 +2,11 @@
 //!
 //! This parser extracts structured sections from PRD markdown files including
 //! Objectives, Tech Stack, and Constraints. It handles standard markdown
-//! formatting with ## headers and bullet point lists.
+//! formatting with ## headers and bullet point lists, and maps rows of
+//! GitHub-flavored markdown tables to structured requirements.
 //!
 //! Revision History
+//! - 2026-10-16T11:30:00Z @AI: Parse GitHub-flavored markdown tables into PrdRequirement rows attached to PRD.requirements; tolerate missing cells and misaligned pipes.
 //! - 2025-11-24T05:00:00Z @AI: Add project_id parameter to parse_prd_markdown for Phase 1 TUI project architecture.
 //! - 2025-11-23T22:15:00Z @AI: Fix doctest example formatting (HEXSER refactoring).
 //! - 2025-11-22T16:05:00Z @AI: Initial PRD markdown parser for Rigger Phase 0.
@@ -13,7 +15,8 @@
 ///
 /// Extracts sections marked with ## Objectives, ## Tech Stack, and ## Constraints.
 /// Each section's content is parsed as bullet points (lines starting with -, *, or numbers).
-/// The first # header is used as the title.
+/// The first # header is used as the title. Markdown tables anywhere in the
+/// document become structured requirements (see `parse_requirement_tables`).
 ///
 /// # Arguments
 ///
@@ -59,14 +62,202 @@ pub fn parse_prd_markdown(project_id: &str, content: &str) -> std::result::Resul
     let tech_stack = extract_section(&lines, "## Tech Stack");
     let constraints = extract_section(&lines, "## Constraints");
 
-    std::result::Result::Ok(crate::domain::prd::PRD::new(
+    let mut prd = crate::domain::prd::PRD::new(
         project_id.to_string(),
         title,
         objectives,
         tech_stack,
         constraints,
         content.to_string(),
-    ))
+    );
+    prd.requirements = parse_requirement_tables(content);
+
+    std::result::Result::Ok(prd)
+}
+
+/// Parses every GitHub-flavored markdown table in the content into requirements.
+///
+/// A table is a header row containing pipes followed by a delimiter row
+/// (e.g., `|---|:---:|`). Each body row becomes one PrdRequirement: ID,
+/// description, and priority columns are recognized by common header names,
+/// and every cell is kept in `fields`. Rows with fewer cells than headers are
+/// padded; surplus cells from stray pipes are folded into the last column.
+///
+/// # Arguments
+///
+/// * `content` - The raw markdown content.
+///
+/// # Returns
+///
+/// Requirements in document order, each tagged with its nearest heading.
+///
+/// # Examples
+///
+/// ```
+/// use task_manager::infrastructure::markdown_parsers::prd_parser::parse_requirement_tables;
+///
+/// let markdown = "## Acceptance Criteria\n| ID | Requirement | Priority |\n|----|-------------|----------|\n| R1 | Users can export CSV | High |\n";
+/// let requirements = parse_requirement_tables(markdown);
+/// assert_eq!(requirements.len(), 1);
+/// assert_eq!(requirements[0].id.as_deref(), Some("R1"));
+/// assert_eq!(requirements[0].priority.as_deref(), Some("High"));
+/// ```
+pub fn parse_requirement_tables(content: &str) -> std::vec::Vec<crate::domain::prd_requirement::PrdRequirement> {
+    let lines: std::vec::Vec<&str> = content.lines().collect();
+    let mut requirements = std::vec::Vec::new();
+    let mut section: std::option::Option<String> = std::option::Option::None;
+    let mut idx = 0;
+
+    while idx < lines.len() {
+        let trimmed = lines[idx].trim();
+
+        // Track the nearest heading so requirements know their section
+        if trimmed.starts_with('#') {
+            section = std::option::Option::Some(trimmed.trim_start_matches('#').trim().to_string());
+            idx += 1;
+            continue;
+        }
+
+        let starts_table = trimmed.contains('|') && lines.get(idx + 1).is_some_and(|next| is_delimiter_row(next));
+        if !starts_table {
+            idx += 1;
+            continue;
+        }
+
+        let headers = split_table_row(trimmed);
+        idx += 2;
+        while idx < lines.len() && lines[idx].contains('|') {
+            if let std::option::Option::Some(requirement) = build_requirement(&headers, split_table_row(lines[idx]), section.as_deref()) {
+                requirements.push(requirement);
+            }
+            idx += 1;
+        }
+    }
+
+    requirements
+}
+
+/// Splits a table row into trimmed cells, honoring escaped pipes (`\|`).
+fn split_table_row(line: &str) -> std::vec::Vec<String> {
+    let trimmed = line.trim();
+    let trimmed = trimmed.strip_prefix('|').unwrap_or(trimmed);
+    let trimmed = if trimmed.ends_with('|') && !trimmed.ends_with("\\|") {
+        &trimmed[..trimmed.len() - 1]
+    } else {
+        trimmed
+    };
+
+    let mut cells = std::vec::Vec::new();
+    let mut current = String::new();
+    let mut chars = trimmed.chars().peekable();
+    while let std::option::Option::Some(ch) = chars.next() {
+        match ch {
+            '\\' if chars.peek() == std::option::Option::Some(&'|') => {
+                current.push('|');
+                chars.next();
+            }
+            '|' => cells.push(std::mem::take(&mut current).trim().to_string()),
+            _ => current.push(ch),
+        }
+    }
+    cells.push(current.trim().to_string());
+    cells
+}
+
+/// Returns true for a table delimiter row such as `|---|:--:|---:|`.
+fn is_delimiter_row(line: &str) -> bool {
+    let trimmed = line.trim();
+    if !trimmed.contains('-') {
+        return false;
+    }
+    let cells = split_table_row(trimmed);
+    cells.iter().any(|cell| cell.contains('-'))
+        && cells
+            .iter()
+            .all(|cell| cell.chars().all(|c| c == '-' || c == ':' || c.is_whitespace()))
+}
+
+/// Column roles recognized in requirement tables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColumnRole {
+    Id,
+    Description,
+    Priority,
+    Other,
+}
+
+/// Classifies a column header by common requirement-table naming.
+fn classify_column(header: &str) -> ColumnRole {
+    let normalized = header
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric() && c != '#')
+        .filter(|word| !word.is_empty())
+        .collect::<std::vec::Vec<&str>>()
+        .join(" ");
+
+    match normalized.as_str() {
+        "id" | "#" | "req" | "req id" | "req #" | "requirement id" | "ref" | "key" | "no" => ColumnRole::Id,
+        "description" | "requirement" | "requirements" | "acceptance criteria" | "acceptance criterion"
+        | "criteria" | "criterion" | "details" | "summary" | "title" | "feature" | "user story" | "story" => ColumnRole::Description,
+        "priority" | "prio" | "moscow" | "importance" | "severity" => ColumnRole::Priority,
+        _ => ColumnRole::Other,
+    }
+}
+
+/// Maps one table row onto the headers, returning None for rows without descriptive text.
+fn build_requirement(
+    headers: &[String],
+    mut cells: std::vec::Vec<String>,
+    section: std::option::Option<&str>,
+) -> std::option::Option<crate::domain::prd_requirement::PrdRequirement> {
+    // Fold surplus cells (stray pipes) into the last column; pad missing cells.
+    if !headers.is_empty() && cells.len() > headers.len() {
+        let surplus = cells.split_off(headers.len());
+        if let std::option::Option::Some(last) = cells.last_mut() {
+            let joined: std::vec::Vec<String> = std::iter::once(std::mem::take(last))
+                .chain(surplus)
+                .filter(|cell| !cell.is_empty())
+                .collect();
+            *last = joined.join(" | ");
+        }
+    }
+    cells.resize(headers.len().max(cells.len()), String::new());
+
+    let columns: std::vec::Vec<(String, ColumnRole, String)> = cells
+        .into_iter()
+        .enumerate()
+        .map(|(idx, value)| {
+            let header = headers
+                .get(idx)
+                .filter(|header| !header.is_empty())
+                .cloned()
+                .unwrap_or_else(|| std::format!("Column {}", idx + 1));
+            let role = classify_column(&header);
+            (header, role, value)
+        })
+        .collect();
+
+    let value_for = |role: ColumnRole| {
+        columns
+            .iter()
+            .find(|(_, column_role, value)| *column_role == role && !value.is_empty())
+            .map(|(_, _, value)| value.clone())
+    };
+
+    // Fall back to the first free-text cell when no description column is recognized
+    let description = value_for(ColumnRole::Description).or_else(|| value_for(ColumnRole::Other))?;
+
+    std::option::Option::Some(crate::domain::prd_requirement::PrdRequirement {
+        id: value_for(ColumnRole::Id),
+        description,
+        priority: value_for(ColumnRole::Priority),
+        section: section.map(std::string::String::from),
+        fields: columns
+            .into_iter()
+            .filter(|(_, _, value)| !value.is_empty())
+            .map(|(header, _, value)| (header, value))
+            .collect(),
+    })
 }
 
 /// Extracts the title from the first # header in the markdown.
@@ -318,4 +509,78 @@ mod tests {
 
         std::assert_eq!(prd.raw_content, markdown);
     }
+
+    #[test]
+    fn test_parse_prd_with_requirements_table() {
+        // Test: Validates that each table row becomes a structured requirement.
+        // Justification: Acceptance criteria tables must not be lost as flattened text.
+        let markdown = r#"
+# Checkout Revamp
+
+## Objectives
+- Reduce checkout abandonment
+
+## Acceptance Criteria
+
+| Req ID | Description                         | Priority | Owner    |
+|:-------|:------------------------------------|:--------:|----------|
+| AC-1   | Guest users can check out           | High     | Payments |
+| AC-2   | Saved cards are offered \| masked  | Medium   |          |
+| AC-3   | Order confirmation email is sent    |
+AC-4 | Address autocomplete | Low | Growth | extra note |
+
+## Constraints
+- PCI compliance
+        "#;
+
+        let prd = super::parse_prd_markdown("project-1", markdown).unwrap();
+
+        std::assert_eq!(prd.objectives.len(), 1);
+        std::assert_eq!(prd.constraints.len(), 1);
+        std::assert_eq!(prd.requirements.len(), 4);
+
+        let first = &prd.requirements[0];
+        std::assert_eq!(first.id.as_deref(), std::option::Option::Some("AC-1"));
+        std::assert_eq!(first.description, "Guest users can check out");
+        std::assert_eq!(first.priority.as_deref(), std::option::Option::Some("High"));
+        std::assert_eq!(first.section.as_deref(), std::option::Option::Some("Acceptance Criteria"));
+        std::assert_eq!(first.field("owner"), std::option::Option::Some("Payments"));
+
+        // Escaped pipe stays in the cell; empty Owner cell is omitted from fields
+        std::assert_eq!(prd.requirements[1].description, "Saved cards are offered | masked");
+        std::assert!(prd.requirements[1].field("Owner").is_none());
+
+        // Missing cells are tolerated
+        std::assert_eq!(prd.requirements[2].id.as_deref(), std::option::Option::Some("AC-3"));
+        std::assert!(prd.requirements[2].priority.is_none());
+
+        // Misaligned row without outer pipes and with a surplus cell
+        std::assert_eq!(prd.requirements[3].id.as_deref(), std::option::Option::Some("AC-4"));
+        std::assert_eq!(prd.requirements[3].priority.as_deref(), std::option::Option::Some("Low"));
+        std::assert_eq!(prd.requirements[3].field("Owner"), std::option::Option::Some("Growth | extra note"));
+    }
+
+    #[test]
+    fn test_table_without_description_column_uses_first_text_cell() {
+        // Test: Validates fallback when headers use unrecognized names.
+        // Justification: Teams name columns freely; rows must still yield a description.
+        let markdown = "| Key | Capability | MoSCoW |\n| --- | --- | --- |\n| K1 | Bulk import | Must |\n|  |  |  |\n";
+        let requirements = super::parse_requirement_tables(markdown);
+
+        std::assert_eq!(requirements.len(), 1, "Blank rows are skipped");
+        std::assert_eq!(requirements[0].id.as_deref(), std::option::Option::Some("K1"));
+        std::assert_eq!(requirements[0].description, "Bulk import");
+        std::assert_eq!(requirements[0].priority.as_deref(), std::option::Option::Some("Must"));
+        std::assert!(requirements[0].section.is_none());
+    }
+
+    #[test]
+    fn test_pipes_without_delimiter_row_are_not_tables() {
+        // Test: Validates that prose containing pipes is not parsed as a table.
+        // Justification: Only GitHub-flavored tables (header + delimiter row) carry structure.
+        let markdown = "# Title\n\nUse a | b for alternatives.\nAnother | line\n";
+        let prd = super::parse_prd_markdown("project-1", markdown).unwrap();
+
+        std::assert!(prd.requirements.is_empty());
+    }
 }
@@ -9,11 +9,20 @@
 //! - `markdown_parsers`: Parse markdown documents into domain entities
 //! - `schemas`: Generate JSON schemas for external API configuration
 //! - `dtos`: Data Transfer Objects for boundary crossing
+//! - `telemetry`: Timing helper for tracing spans
+//! - `sqlite_migrations`: Versioned schema migrations for the SQLite adapters
+//! - `sqlite_pool`: Pool sizing, WAL, and busy timeout for the SQLite adapters
 //!
 //! Revision History
+//! - 2026-10-17T05:50:00Z @AI: Add sqlite_pool module for configurable adapter pools.
+//! - 2026-10-17T05:30:00Z @AI: Add sqlite_migrations module for versioned SQLite schemas.
+//! - 2026-10-16T19:50:00Z @AI: Add telemetry module for timed tracing spans.
 //! - 2025-11-23T21:20:00Z @AI: Create infrastructure layer (HEXSER compliance refactoring).
 
 pub mod llm_parsers;
 pub mod markdown_parsers;
 pub mod schemas;
 pub mod dtos;
+pub mod telemetry;
+pub mod sqlite_migrations;
+pub mod sqlite_pool;
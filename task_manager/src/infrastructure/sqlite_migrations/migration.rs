@@ -0,0 +1,47 @@
+//! Defines Migration, one numbered schema version of an adapter.
+//!
+//! Revision History
+//! - 2026-10-17T05:30:00Z @AI: Initial Migration definition.
+
+/// A numbered schema change, applied in one transaction.
+///
+/// Versions are positive and strictly increasing within a scope. A
+/// migration that has shipped must never be edited; schema changes go into
+/// a new migration appended to the adapter's list.
+///
+/// # Fields
+///
+/// * `version` - Version number, unique within the adapter's scope.
+/// * `description` - Short summary recorded in `schema_migrations`.
+/// * `steps` - Idempotent statements making up the change.
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::infrastructure::sqlite_migrations::migration::Migration;
+/// # use task_manager::infrastructure::sqlite_migrations::migration_step::MigrationStep;
+/// const MIGRATIONS: &[Migration] = &[
+///     Migration {
+///         version: 1,
+///         description: "Create notes table",
+///         steps: &[MigrationStep::Execute("CREATE TABLE IF NOT EXISTS notes (id TEXT PRIMARY KEY)")],
+///     },
+///     Migration {
+///         version: 2,
+///         description: "Add note body",
+///         steps: &[MigrationStep::AddColumn { table: "notes", column: "body", definition: "TEXT NULL" }],
+///     },
+/// ];
+/// std::assert_eq!(MIGRATIONS.last().unwrap().version, 2);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    /// Version number, unique within the adapter's scope.
+    pub version: i64,
+
+    /// Short summary recorded in `schema_migrations`.
+    pub description: &'static str,
+
+    /// Idempotent statements making up the change.
+    pub steps: &'static [crate::infrastructure::sqlite_migrations::migration_step::MigrationStep],
+}
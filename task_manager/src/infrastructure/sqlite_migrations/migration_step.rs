@@ -0,0 +1,45 @@
+//! Defines MigrationStep, one idempotent schema change within a migration.
+//!
+//! Databases created before versioned migrations existed already contain
+//! some of the tables and columns a migration adds. Steps are therefore
+//! written so that re-applying them to such a database changes nothing:
+//! plain statements use `IF NOT EXISTS` / `OR IGNORE`, and column additions
+//! check the table first since SQLite has no `ADD COLUMN IF NOT EXISTS`.
+//!
+//! Revision History
+//! - 2026-10-17T05:30:00Z @AI: Initial MigrationStep with Execute, AddColumn, and ExecuteIfColumn.
+
+/// One idempotent schema change.
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::infrastructure::sqlite_migrations::migration_step::MigrationStep;
+/// let step = MigrationStep::AddColumn { table: "tasks", column: "priority", definition: "TEXT NULL" };
+/// std::assert!(std::matches!(step, MigrationStep::AddColumn { column: "priority", .. }));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationStep {
+    /// Runs a statement that is idempotent on its own (`CREATE TABLE IF NOT EXISTS`, `INSERT OR IGNORE`, ...).
+    Execute(&'static str),
+
+    /// Adds a column unless the table already has it.
+    AddColumn {
+        /// Table to alter.
+        table: &'static str,
+        /// Name of the new column.
+        column: &'static str,
+        /// Type and constraints, e.g. `TEXT NULL`.
+        definition: &'static str,
+    },
+
+    /// Runs a statement only when the table has a given column, e.g. to copy data out of a legacy column.
+    ExecuteIfColumn {
+        /// Table to inspect.
+        table: &'static str,
+        /// Column that must exist.
+        column: &'static str,
+        /// Statement to run.
+        sql: &'static str,
+    },
+}
@@ -0,0 +1,262 @@
+//! Applies pending migrations and records them in `schema_migrations`.
+//!
+//! Each migration runs in its own transaction together with the row that
+//! records it, so a failing migration leaves neither partial schema changes
+//! nor a version entry behind, and the next start retries it. Versions
+//! already recorded for the scope are skipped, which makes running the
+//! migrator on every connect a no-op once the schema is current.
+//!
+//! Revision History
+//! - 2026-10-17T05:30:00Z @AI: Initial migrator with per-scope version tracking.
+
+/// Applies the migrations of `scope` that have not been applied yet, in version order.
+///
+/// # Arguments
+///
+/// * `pool` - Database to migrate
+/// * `scope` - Name under which the adapter's versions are recorded (e.g. "tasks")
+/// * `migrations` - The adapter's migrations, in strictly increasing version order
+///
+/// # Returns
+///
+/// * `Ok(Vec<i64>)` - Versions applied by this call; empty when the schema was already current.
+/// * `Err(String)` - The migration list is out of order, or a migration failed (and was rolled back).
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::infrastructure::sqlite_migrations::migration::Migration;
+/// # use task_manager::infrastructure::sqlite_migrations::migration_step::MigrationStep;
+/// # async fn example() {
+/// const MIGRATIONS: &[Migration] = &[Migration {
+///     version: 1,
+///     description: "Create notes table",
+///     steps: &[MigrationStep::Execute("CREATE TABLE IF NOT EXISTS notes (id TEXT PRIMARY KEY)")],
+/// }];
+/// let pool = sqlx::sqlite::SqlitePoolOptions::new().max_connections(1).connect("sqlite::memory:").await.unwrap();
+/// let migrator = task_manager::infrastructure::sqlite_migrations::migrator::run_pending;
+///
+/// std::assert_eq!(migrator(&pool, "notes", MIGRATIONS).await.unwrap(), std::vec![1]);
+/// std::assert!(migrator(&pool, "notes", MIGRATIONS).await.unwrap().is_empty());
+/// # }
+/// ```
+pub async fn run_pending(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    scope: &str,
+    migrations: &[crate::infrastructure::sqlite_migrations::migration::Migration],
+) -> std::result::Result<std::vec::Vec<i64>, String> {
+    if let std::option::Option::Some(pair) = migrations.windows(2).find(|pair| pair[0].version >= pair[1].version) {
+        return std::result::Result::Err(std::format!(
+            "Migrations for '{}' are out of order: version {} is followed by {}",
+            scope, pair[0].version, pair[1].version
+        ));
+    }
+
+    let applied = applied_versions(pool, scope).await?;
+    let mut newly_applied = std::vec::Vec::new();
+    for migration in migrations.iter().filter(|migration| !applied.contains(&migration.version)) {
+        if apply(pool, scope, migration).await? {
+            newly_applied.push(migration.version);
+        }
+    }
+    std::result::Result::Ok(newly_applied)
+}
+
+/// Returns the versions recorded for a scope, ascending.
+///
+/// Creates the `schema_migrations` table if it doesn't exist yet.
+///
+/// # Arguments
+///
+/// * `pool` - Database to inspect
+/// * `scope` - Adapter scope
+///
+/// # Errors
+///
+/// Returns an error if the table cannot be created or queried.
+pub async fn applied_versions(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    scope: &str,
+) -> std::result::Result<std::vec::Vec<i64>, String> {
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS schema_migrations (
+            scope TEXT NOT NULL,
+            version INTEGER NOT NULL,
+            description TEXT NOT NULL,
+            applied_at TEXT NOT NULL,
+            PRIMARY KEY (scope, version)
+        )"
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| std::format!("Failed to create schema_migrations table: {:?}", e))?;
+
+    sqlx::query_scalar("SELECT version FROM schema_migrations WHERE scope = ?1 ORDER BY version ASC")
+        .bind(scope)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| std::format!("Failed to read applied migrations for '{}': {:?}", scope, e))
+}
+
+/// Applies one migration and records it, in a single transaction.
+///
+/// # Returns
+///
+/// * `Ok(true)` - The migration was applied.
+/// * `Ok(false)` - Another connection recorded it first; nothing was changed.
+/// * `Err(String)` - A step failed; the transaction was rolled back.
+async fn apply(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    scope: &str,
+    migration: &crate::infrastructure::sqlite_migrations::migration::Migration,
+) -> std::result::Result<bool, String> {
+    let failed = |e: sqlx::Error| {
+        std::format!("Migration {} v{} ({}) failed: {:?}", scope, migration.version, migration.description, e)
+    };
+    let mut tx = pool.begin().await.map_err(failed)?;
+
+    let recorded: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM schema_migrations WHERE scope = ?1 AND version = ?2")
+        .bind(scope)
+        .bind(migration.version)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(failed)?;
+    if recorded > 0 {
+        return std::result::Result::Ok(false);
+    }
+
+    for step in migration.steps {
+        match *step {
+            crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute(sql) => {
+                sqlx::query(sql).execute(&mut *tx).await.map_err(failed)?;
+            }
+            crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn { table, column, definition } => {
+                if !has_column(&mut tx, table, column).await.map_err(failed)? {
+                    sqlx::query(&std::format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition))
+                        .execute(&mut *tx)
+                        .await
+                        .map_err(failed)?;
+                }
+            }
+            crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::ExecuteIfColumn { table, column, sql } => {
+                if has_column(&mut tx, table, column).await.map_err(failed)? {
+                    sqlx::query(sql).execute(&mut *tx).await.map_err(failed)?;
+                }
+            }
+        }
+    }
+
+    sqlx::query("INSERT INTO schema_migrations (scope, version, description, applied_at) VALUES (?1, ?2, ?3, ?4)")
+        .bind(scope)
+        .bind(migration.version)
+        .bind(migration.description)
+        .bind(chrono::Utc::now().to_rfc3339())
+        .execute(&mut *tx)
+        .await
+        .map_err(failed)?;
+    tx.commit().await.map_err(failed)?;
+    std::result::Result::Ok(true)
+}
+
+/// Returns whether `table` has a column named `column`.
+async fn has_column(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    table: &str,
+    column: &str,
+) -> std::result::Result<bool, sqlx::Error> {
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM pragma_table_info(?1) WHERE name = ?2")
+        .bind(table)
+        .bind(column)
+        .fetch_one(&mut **tx)
+        .await?;
+    std::result::Result::Ok(count > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    const NOTES_MIGRATIONS: &[crate::infrastructure::sqlite_migrations::migration::Migration] = &[
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 1,
+            description: "Create notes table",
+            steps: &[crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute(
+                "CREATE TABLE IF NOT EXISTS notes (id TEXT PRIMARY KEY, title TEXT NOT NULL)",
+            )],
+        },
+        crate::infrastructure::sqlite_migrations::migration::Migration {
+            version: 2,
+            description: "Add note body",
+            steps: &[crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::AddColumn {
+                table: "notes",
+                column: "body",
+                definition: "TEXT NOT NULL DEFAULT ''",
+            }],
+        },
+    ];
+
+    async fn memory_pool() -> sqlx::Pool<sqlx::Sqlite> {
+        sqlx::sqlite::SqlitePoolOptions::new().max_connections(1).connect("sqlite::memory:").await.unwrap()
+    }
+
+    async fn columns(pool: &sqlx::Pool<sqlx::Sqlite>, table: &str) -> std::vec::Vec<String> {
+        sqlx::query_scalar("SELECT name FROM pragma_table_info(?1) ORDER BY cid")
+            .bind(table)
+            .fetch_all(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_legacy_database_without_history_upgrades() {
+        // Test: Validates a database created before migrations existed, already holding a later column, upgrades without errors.
+        // Justification: Existing .rigger databases have no schema_migrations rows but partially current schemas.
+        let pool = memory_pool().await;
+        sqlx::query("CREATE TABLE notes (id TEXT PRIMARY KEY, title TEXT NOT NULL, body TEXT NOT NULL DEFAULT '')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let applied = super::run_pending(&pool, "notes", NOTES_MIGRATIONS).await.unwrap();
+
+        std::assert_eq!(applied, std::vec![1, 2]);
+        std::assert_eq!(columns(&pool, "notes").await, std::vec!["id", "title", "body"]);
+    }
+
+    #[tokio::test]
+    async fn test_failed_migration_rolls_back() {
+        // Test: Validates a migration whose second step fails leaves neither its first step nor a version record behind.
+        // Justification: Migrations must be transactional so a retry starts from a clean state.
+        let pool = memory_pool().await;
+        const BROKEN: &[crate::infrastructure::sqlite_migrations::migration::Migration] = &[
+            crate::infrastructure::sqlite_migrations::migration::Migration {
+                version: 1,
+                description: "Half broken",
+                steps: &[
+                    crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute("CREATE TABLE drafts (id TEXT)"),
+                    crate::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute("ALTER TABLE missing ADD COLUMN x TEXT"),
+                ],
+            },
+        ];
+
+        let error = super::run_pending(&pool, "drafts", BROKEN).await.unwrap_err();
+
+        std::assert!(error.contains("drafts v1 (Half broken) failed"), "{}", error);
+        std::assert!(columns(&pool, "drafts").await.is_empty());
+        std::assert!(super::applied_versions(&pool, "drafts").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_scopes_are_tracked_independently_and_order_is_enforced() {
+        // Test: Validates versions are recorded per scope and an out-of-order list is rejected before anything runs.
+        // Justification: Several adapters share tasks.db; a typo in a version number must not corrupt it.
+        let pool = memory_pool().await;
+        super::run_pending(&pool, "notes", &NOTES_MIGRATIONS[..1]).await.unwrap();
+
+        std::assert_eq!(super::applied_versions(&pool, "notes").await.unwrap(), std::vec![1]);
+        std::assert!(super::applied_versions(&pool, "other").await.unwrap().is_empty());
+
+        let reversed = [NOTES_MIGRATIONS[1], NOTES_MIGRATIONS[0]];
+        let error = super::run_pending(&pool, "notes", &reversed).await.unwrap_err();
+        std::assert!(error.contains("out of order"), "{}", error);
+        std::assert_eq!(columns(&pool, "notes").await, std::vec!["id", "title"]);
+    }
+}
@@ -0,0 +1,13 @@
+//! Versioned schema migrations for the SQLite adapters.
+//!
+//! Each adapter declares an ordered list of `Migration`s under its own scope
+//! and runs `migrator::run_pending()` when it connects. Applied versions are
+//! recorded per scope in a shared `schema_migrations` table, so adapters
+//! sharing `.rigger/tasks.db` track their schemas independently.
+//!
+//! Revision History
+//! - 2026-10-17T05:30:00Z @AI: Create sqlite_migrations module with Migration, MigrationStep, and migrator.
+
+pub mod migration;
+pub mod migration_step;
+pub mod migrator;
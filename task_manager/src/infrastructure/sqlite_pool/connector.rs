@@ -0,0 +1,183 @@
+//! Opens SQLite pools sized and configured by SqlitePoolSettings.
+//!
+//! File databases are opened in WAL mode so readers never wait on a writer,
+//! and every connection gets a busy timeout so concurrent writers queue for
+//! the lock instead of failing with "database is locked". In-memory
+//! databases are kept to a single connection: their connections share one
+//! cache with table-level locks that the busy timeout does not cover.
+//!
+//! Revision History
+//! - 2026-10-17T05:50:00Z @AI: Initial connector with WAL, busy timeout, and sqlite-vec lookup.
+
+/// Opens a pool for `database_url`, optionally loading a SQLite extension on every connection.
+///
+/// # Arguments
+///
+/// * `database_url` - SQLite URL, e.g. `sqlite:.rigger/tasks.db` or `sqlite::memory:`
+/// * `settings` - Pool size and busy timeout
+/// * `extension` - Path or name of an extension to load, if any
+///
+/// # Errors
+///
+/// Returns an error if the URL cannot be parsed or the first connection fails
+/// (including failing to load the extension).
+///
+/// # Examples
+///
+/// ```
+/// # async fn example() {
+/// let settings = task_manager::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings::default();
+/// let pool = task_manager::infrastructure::sqlite_pool::connector::connect("sqlite::memory:", &settings, std::option::Option::None)
+///     .await
+///     .unwrap();
+/// std::assert_eq!(pool.options().get_max_connections(), 1);
+/// # }
+/// ```
+pub async fn connect(
+    database_url: &str,
+    settings: &crate::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings,
+    extension: std::option::Option<&str>,
+) -> std::result::Result<sqlx::Pool<sqlx::Sqlite>, String> {
+    let in_memory = database_url.contains(":memory:");
+    let mut connect_options = database_url
+        .parse::<sqlx::sqlite::SqliteConnectOptions>()
+        .map_err(|e| std::format!("Failed to parse database URL: {:?}", e))?
+        .create_if_missing(true)
+        .optimize_on_close(false, std::option::Option::None)
+        .busy_timeout(settings.busy_timeout);
+    if !in_memory {
+        connect_options = connect_options.journal_mode(sqlx::sqlite::SqliteJournalMode::Wal);
+    }
+    if let std::option::Option::Some(extension) = extension {
+        connect_options = connect_options.extension(extension.to_string());
+    }
+
+    sqlx::sqlite::SqlitePoolOptions::new()
+        .max_connections(if in_memory { 1 } else { settings.pool_size.max(1) })
+        .connect_with(connect_options)
+        .await
+        .map_err(|e| std::format!("Failed to connect to SQLite: {:?}", e))
+}
+
+/// Opens a pool with the sqlite-vec extension loaded, falling back to a plain pool.
+///
+/// Tries the extension embedded in the binary first, then `vec0` on the
+/// system library path and in `.rigger/lib`. In-memory databases skip the
+/// lookup.
+///
+/// # Returns
+///
+/// The pool, and whether sqlite-vec was loaded.
+///
+/// # Errors
+///
+/// Returns an error if even the plain connection fails.
+pub async fn connect_with_sqlite_vec(
+    database_url: &str,
+    settings: &crate::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings,
+) -> std::result::Result<(sqlx::Pool<sqlx::Sqlite>, bool), String> {
+    if database_url.contains(":memory:") {
+        let pool = connect(database_url, settings, std::option::Option::None).await?;
+        return std::result::Result::Ok((pool, false));
+    }
+
+    for extension in sqlite_vec_candidates() {
+        if let std::result::Result::Ok(pool) = connect(database_url, settings, std::option::Option::Some(&extension)).await {
+            return std::result::Result::Ok((pool, true));
+        }
+    }
+
+    let pool = connect(database_url, settings, std::option::Option::None).await?;
+    std::result::Result::Ok((pool, false))
+}
+
+/// Locations to try for sqlite-vec, most preferred first.
+fn sqlite_vec_candidates() -> std::vec::Vec<String> {
+    let mut candidates = std::vec::Vec::new();
+
+    // Embedded extension first (always available, bundled in binary)
+    if let std::result::Result::Ok(embedded_path) = crate::adapters::embedded_sqlite_vec::get_extension_path_for_sqlite() {
+        candidates.push(embedded_path);
+    }
+
+    // Fallback paths (for development or custom installations)
+    candidates.push(String::from("vec0"));
+    candidates.push(String::from(".rigger/lib/vec0"));
+    if let std::option::Option::Some(abs_path) = std::env::current_dir()
+        .ok()
+        .and_then(|p| p.join(".rigger/lib/vec0").to_str().map(|s| s.to_string()))
+    {
+        candidates.push(abs_path);
+    }
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    fn temp_db_url(name: &str) -> (std::path::PathBuf, String) {
+        let dir = std::env::temp_dir().join(std::format!("{}_{}", name, uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let url = std::format!("sqlite:{}", dir.join("tasks.db").display());
+        (dir, url)
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_more_concurrent_queries_than_connections_do_not_lock() {
+        // Test: Validates pool_size + N concurrent writes and reads against one file database all succeed.
+        // Justification: Concurrent orchestration used to fail with "database is locked" under contention.
+        let (dir, url) = temp_db_url("rigger_pool_test");
+        let settings = crate::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings::default().with_pool_size(3);
+        let pool = super::connect(&url, &settings, std::option::Option::None).await.unwrap();
+        sqlx::query("CREATE TABLE events (id INTEGER PRIMARY KEY, worker INTEGER NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let queries = settings.pool_size as usize + 13;
+        let mut handles = std::vec::Vec::new();
+        for worker in 0..queries {
+            let pool = pool.clone();
+            handles.push(tokio::spawn(async move {
+                let mut tx = pool.begin().await?;
+                sqlx::query("INSERT INTO events (worker) VALUES (?1)").bind(worker as i64).execute(&mut *tx).await?;
+                let _: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM events").fetch_one(&mut *tx).await?;
+                tx.commit().await
+            }));
+        }
+
+        let finished = tokio::time::timeout(std::time::Duration::from_secs(20), async {
+            for handle in handles {
+                if let std::result::Result::Err(e) = handle.await.unwrap() {
+                    std::panic!("concurrent query failed: {:?}", e);
+                }
+            }
+        })
+        .await;
+        std::assert!(finished.is_ok(), "concurrent queries did not finish in time");
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM events").fetch_one(&pool).await.unwrap();
+        std::assert_eq!(count as usize, queries);
+        let journal_mode: String = sqlx::query_scalar("PRAGMA journal_mode").fetch_one(&pool).await.unwrap();
+        std::assert_eq!(journal_mode, "wal");
+        std::assert_eq!(pool.options().get_max_connections(), 3);
+
+        pool.close().await;
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[tokio::test]
+    async fn test_pool_size_is_clamped_and_memory_uses_one_connection() {
+        // Test: Validates a zero pool size still opens one connection and in-memory databases ignore pool_size.
+        // Justification: Several connections to sqlite::memory: share a cache whose table locks ignore the busy timeout.
+        let (dir, url) = temp_db_url("rigger_pool_clamp_test");
+        let settings = crate::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings::default().with_pool_size(0);
+        let pool = super::connect(&url, &settings, std::option::Option::None).await.unwrap();
+        std::assert_eq!(pool.options().get_max_connections(), 1);
+        pool.close().await;
+        let _ = std::fs::remove_dir_all(dir);
+
+        let settings = settings.with_pool_size(8);
+        let memory = super::connect("sqlite::memory:", &settings, std::option::Option::None).await.unwrap();
+        std::assert_eq!(memory.options().get_max_connections(), 1);
+    }
+}
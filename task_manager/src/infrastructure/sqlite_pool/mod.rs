@@ -0,0 +1,12 @@
+//! Shared connection pool construction for the SQLite adapters.
+//!
+//! Every adapter that opens its own database goes through `connector`, so
+//! pool size, WAL journaling, and the busy timeout are configured in one
+//! place. Adapters that share a database can also be built from one pool
+//! with their `new` constructors.
+//!
+//! Revision History
+//! - 2026-10-17T05:50:00Z @AI: Initial sqlite_pool module with settings and connector.
+
+pub mod sqlite_pool_settings;
+pub mod connector;
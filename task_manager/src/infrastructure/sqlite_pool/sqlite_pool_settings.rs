@@ -0,0 +1,59 @@
+//! Defines SqlitePoolSettings, the sizing and locking behaviour of an adapter's pool.
+//!
+//! Revision History
+//! - 2026-10-17T05:50:00Z @AI: Initial SqlitePoolSettings with pool size and busy timeout.
+
+/// Default number of pooled connections, matching `DatabaseConfig::pool_size`.
+pub const DEFAULT_POOL_SIZE: u32 = 5;
+
+/// Default time a connection waits on a locked database before failing.
+pub const DEFAULT_BUSY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// How many connections an adapter pool holds and how long each waits for a lock.
+///
+/// # Fields
+///
+/// * `pool_size` - Maximum number of open connections; at least 1.
+/// * `busy_timeout` - How long a statement retries while another connection holds the write lock.
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings;
+/// let settings = SqlitePoolSettings::default().with_pool_size(8);
+/// std::assert_eq!(settings.pool_size, 8);
+/// std::assert_eq!(SqlitePoolSettings::default().with_pool_size(0).pool_size, 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SqlitePoolSettings {
+    /// Maximum number of open connections; at least 1.
+    pub pool_size: u32,
+
+    /// How long a statement retries while another connection holds the write lock.
+    pub busy_timeout: std::time::Duration,
+}
+
+impl SqlitePoolSettings {
+    /// Returns these settings with a different pool size, clamped to at least 1.
+    ///
+    /// Takes `usize` so `DatabaseConfig::pool_size` can be passed as is.
+    pub fn with_pool_size(mut self, pool_size: usize) -> Self {
+        self.pool_size = u32::try_from(pool_size).unwrap_or(u32::MAX).max(1);
+        self
+    }
+
+    /// Returns these settings with a different busy timeout.
+    pub fn with_busy_timeout(mut self, busy_timeout: std::time::Duration) -> Self {
+        self.busy_timeout = busy_timeout;
+        self
+    }
+}
+
+impl std::default::Default for SqlitePoolSettings {
+    fn default() -> Self {
+        SqlitePoolSettings {
+            pool_size: DEFAULT_POOL_SIZE,
+            busy_timeout: DEFAULT_BUSY_TIMEOUT,
+        }
+    }
+}
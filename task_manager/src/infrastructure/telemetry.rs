@@ -0,0 +1,42 @@
+//! Span timing helper shared by the orchestrator, persistence, and CLI crates.
+//!
+//! Spans that time an operation declare an empty `latency_ms` field; `timed`
+//! runs the operation inside the span and fills the field in when it
+//! finishes, so a JSON subscriber reports the latency on the span's close
+//! event alongside its other fields (task_id, node, provider, ...).
+//!
+//! Revision History
+//! - 2026-10-16T19:50:00Z @AI: Initial timed() helper for structured tracing spans.
+
+/// Runs `future` inside `span` and records its duration as `latency_ms`.
+///
+/// The span must declare `latency_ms = tracing::field::Empty`; fields not
+/// declared when a span is created are silently ignored by `tracing`.
+///
+/// # Arguments
+///
+/// * `span` - Span to enter while the future runs
+/// * `future` - Operation to time
+///
+/// # Returns
+///
+/// The future's output.
+///
+/// # Examples
+///
+/// ```
+/// # async fn example() {
+/// let span = tracing::info_span!("db", operation = "ping", latency_ms = tracing::field::Empty);
+/// let answer = task_manager::infrastructure::telemetry::timed(span, async { 42 }).await;
+/// assert_eq!(answer, 42);
+/// # }
+/// ```
+pub async fn timed<F>(span: tracing::Span, future: F) -> F::Output
+where
+    F: std::future::Future,
+{
+    let started = std::time::Instant::now();
+    let output = tracing::Instrument::instrument(future, span.clone()).await;
+    span.record("latency_ms", started.elapsed().as_millis() as u64);
+    output
+}
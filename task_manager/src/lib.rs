@@ -20,6 +20,7 @@
 //! - Task extensions: Support for task hierarchies and PRD linkage
 //!
 //! Revision History
+//! - 2026-10-18T05:10:00Z @AI: Add test_support module (feature `test-support`) with a shared mock HTTP server.
 //! - 2025-11-23T21:30:00Z @AI: Replace utils with infrastructure module (HEXSER compliance refactoring).
 //! - 2025-11-22T16:25:00Z @AI: Add Rigger entities (PRD, ProjectContext) and update documentation for Phase 0.
 //! - 2025-11-08T08:39:00Z @AI: Expose utils module with tolerant parser for shared use across crates.
@@ -30,3 +31,5 @@ pub mod ports;
 pub mod adapters;
 pub mod use_cases;
 pub mod infrastructure;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
@@ -6,6 +6,9 @@
 //! persistence operations plus semantic search via embeddings.
 //!
 //! Revision History
+//! - 2026-10-17T23:20:00Z @AI: Add replace_embeddings() for atomically re-saving a batch of re-embedded artifacts.
+//! - 2026-10-17T18:30:00Z @AI: Add ArtifactSearchFilter and find_similar_filtered() for metadata-filtered similarity search.
+//! - 2026-10-16T22:30:00Z @AI: Add EmbeddingSpace, embedding_spaces(), and reset_embedding_index() for embedding dimension validation and re-embedding.
 //! - 2025-11-28T19:05:00Z @AI: Initial ArtifactRepositoryPort trait definition for Phase 1 RAG implementation.
 
 /// Filter criteria for querying artifacts.
@@ -63,6 +66,139 @@ pub enum ArtifactSortKey {
     SourceType,
 }
 
+/// Metadata filter applied to a similarity search before ranking.
+///
+/// Every set field must match (AND). Within `source_types` or `extensions`
+/// any listed value matches (OR). Extensions are compared against the end of
+/// the artifact's `source_id` (its file path or URL), case-insensitively; a
+/// `language` stands for its usual file extensions (see
+/// `extensions_for_language`).
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::ports::artifact_repository_port::ArtifactSearchFilter;
+/// # use task_manager::domain::artifact::{Artifact, ArtifactType};
+/// let filter = ArtifactSearchFilter::default()
+///     .with_source_type(ArtifactType::File)
+///     .with_extension(".MD");
+///
+/// let doc = Artifact::new(
+///     std::string::String::from("proj-1"),
+///     std::string::String::from("docs/setup.md"),
+///     ArtifactType::File,
+///     std::string::String::from("Run rig init first."),
+///     std::vec![0.1],
+///     std::option::Option::None,
+/// );
+/// std::assert_eq!(filter.extensions, std::vec![std::string::String::from("md")]);
+/// std::assert!(filter.matches(&doc));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ArtifactSearchFilter {
+    /// Only artifacts of this project.
+    pub project_id: std::option::Option<String>,
+
+    /// Only artifacts of one of these source types; empty means any.
+    pub source_types: std::vec::Vec<crate::domain::artifact::ArtifactType>,
+
+    /// Only artifacts whose source ends in one of these extensions (lowercase, no dot); empty means any.
+    pub extensions: std::vec::Vec<String>,
+
+    /// Only artifacts whose source has an extension of this language.
+    pub language: std::option::Option<String>,
+}
+
+impl ArtifactSearchFilter {
+    /// Restricts the search to one project.
+    pub fn with_project(mut self, project_id: std::option::Option<String>) -> Self {
+        self.project_id = project_id;
+        self
+    }
+
+    /// Adds an accepted source type.
+    pub fn with_source_type(mut self, source_type: crate::domain::artifact::ArtifactType) -> Self {
+        if !self.source_types.contains(&source_type) {
+            self.source_types.push(source_type);
+        }
+        self
+    }
+
+    /// Adds an accepted file extension; a leading dot and case are ignored.
+    pub fn with_extension(mut self, extension: &str) -> Self {
+        let extension = extension.trim().trim_start_matches('.').to_lowercase();
+        if !extension.is_empty() && !self.extensions.contains(&extension) {
+            self.extensions.push(extension);
+        }
+        self
+    }
+
+    /// Restricts the search to sources written in `language` (e.g., "rust").
+    pub fn with_language(mut self, language: &str) -> Self {
+        self.language = std::option::Option::Some(language.trim().to_lowercase());
+        self
+    }
+
+    /// Returns true if `artifact` passes every set filter.
+    ///
+    /// An unknown `language` matches nothing.
+    pub fn matches(&self, artifact: &crate::domain::artifact::Artifact) -> bool {
+        if let std::option::Option::Some(project_id) = &self.project_id {
+            if artifact.project_id != *project_id {
+                return false;
+            }
+        }
+        if !self.source_types.is_empty() && !self.source_types.contains(&artifact.source_type) {
+            return false;
+        }
+        let source = artifact.source_id.to_lowercase();
+        let has_extension = |extension: &str| source.ends_with(&std::format!(".{}", extension));
+        if !self.extensions.is_empty() && !self.extensions.iter().any(|extension| has_extension(extension)) {
+            return false;
+        }
+        if let std::option::Option::Some(language) = &self.language {
+            let extensions = extensions_for_language(language).unwrap_or(&[]);
+            if !extensions.iter().any(|extension| has_extension(extension)) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Returns the file extensions of a programming or markup language, or None
+/// if the language is not known.
+///
+/// Names are matched case-insensitively; common aliases ("js", "c++",
+/// "golang") are accepted.
+pub fn extensions_for_language(language: &str) -> std::option::Option<&'static [&'static str]> {
+    let extensions: &'static [&'static str] = match language.trim().to_lowercase().as_str() {
+        "rust" | "rs" => &["rs"],
+        "python" | "py" => &["py", "pyi"],
+        "javascript" | "js" => &["js", "jsx", "mjs", "cjs"],
+        "typescript" | "ts" => &["ts", "tsx", "mts", "cts"],
+        "go" | "golang" => &["go"],
+        "java" => &["java"],
+        "kotlin" => &["kt", "kts"],
+        "swift" => &["swift"],
+        "c" => &["c", "h"],
+        "cpp" | "c++" => &["cpp", "cc", "cxx", "hpp", "hh", "hxx"],
+        "csharp" | "c#" | "cs" => &["cs"],
+        "ruby" | "rb" => &["rb"],
+        "php" => &["php"],
+        "shell" | "bash" | "sh" => &["sh", "bash", "zsh"],
+        "sql" => &["sql"],
+        "html" => &["html", "htm"],
+        "css" => &["css", "scss", "sass", "less"],
+        "markdown" | "md" => &["md", "markdown"],
+        "json" => &["json"],
+        "yaml" | "yml" => &["yaml", "yml"],
+        "toml" => &["toml"],
+        _ => return std::option::Option::None,
+    };
+    std::option::Option::Some(extensions)
+}
+
 /// Result structure for similarity search queries.
 ///
 /// SimilarArtifact pairs an artifact with its similarity score (distance)
@@ -101,6 +237,41 @@ pub struct SimilarArtifact {
     pub distance: f32,
 }
 
+/// A group of stored artifacts sharing an embedding model and dimension.
+///
+/// Vectors are only comparable within one embedding space; searching with a
+/// query embedding of a different dimension yields meaningless distances.
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::ports::artifact_repository_port::EmbeddingSpace;
+/// let space = EmbeddingSpace {
+///     model: std::option::Option::Some(std::string::String::from("nomic-embed-text")),
+///     dimension: 768,
+///     artifacts: 42,
+/// };
+/// std::assert_eq!(space.model_name(), "nomic-embed-text");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbeddingSpace {
+    /// Embedding model name, or None for artifacts stored before it was recorded.
+    pub model: std::option::Option<String>,
+
+    /// Number of dimensions of the stored embeddings.
+    pub dimension: usize,
+
+    /// Number of artifacts in this space.
+    pub artifacts: usize,
+}
+
+impl EmbeddingSpace {
+    /// Returns the model name, or "unknown model" if it was not recorded.
+    pub fn model_name(&self) -> &str {
+        self.model.as_deref().unwrap_or("unknown model")
+    }
+}
+
 /// Port (interface) for artifact persistence, retrieval, and similarity search.
 ///
 /// ArtifactRepositoryPort extends HEXSER's standard Repository and QueryRepository
@@ -120,6 +291,9 @@ pub struct SimilarArtifact {
 /// # RAG Operations
 ///
 /// - `find_similar(query_embedding, limit, threshold, project_id)` - Semantic search
+/// - `find_similar_filtered(query_embedding, limit, threshold, filter)` - Semantic search over artifacts matching a metadata filter
+/// - `embedding_spaces(project_id)` - Stored embedding models and dimensions
+/// - `reset_embedding_index(dimension)` - Clear the vector index before re-embedding
 ///
 /// # Examples
 ///
@@ -202,4 +376,101 @@ pub trait ArtifactRepositoryPort:
         threshold: std::option::Option<f32>,
         project_id: std::option::Option<String>,
     ) -> std::result::Result<std::vec::Vec<SimilarArtifact>, String>;
+
+    /// Finds artifacts similar to the query embedding among those matching
+    /// `filter`.
+    ///
+    /// The filter narrows the candidates before ranking, so `limit` results
+    /// are returned whenever that many matching artifacts are in range. The
+    /// default implementation ranks every artifact of the filter's project
+    /// and keeps the matching ones; adapters backed by a vector index should
+    /// override it to apply the filter inside the scan.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_embedding` - The vector representation of the search query.
+    /// * `limit` - Maximum number of results to return.
+    /// * `threshold` - Optional maximum distance threshold.
+    /// * `filter` - Metadata the artifacts must match.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `find_similar`.
+    fn find_similar_filtered(
+        &self,
+        query_embedding: &[f32],
+        limit: usize,
+        threshold: std::option::Option<f32>,
+        filter: &ArtifactSearchFilter,
+    ) -> std::result::Result<std::vec::Vec<SimilarArtifact>, String> {
+        let hits = self.find_similar(query_embedding, usize::MAX, threshold, filter.project_id.clone())?;
+        std::result::Result::Ok(hits.into_iter().filter(|hit| filter.matches(&hit.artifact)).take(limit).collect())
+    }
+
+    /// Lists the embedding models and dimensions of stored artifacts.
+    ///
+    /// Artifacts whose dimension was never recorded are not included. The
+    /// default implementation reports no spaces, which skips dimension
+    /// validation for repositories that do not track it.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - Optional project ID to scope the listing.
+    ///
+    /// # Returns
+    ///
+    /// One entry per distinct (model, dimension) pair, largest group first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    fn embedding_spaces(
+        &self,
+        _project_id: std::option::Option<String>,
+    ) -> std::result::Result<std::vec::Vec<EmbeddingSpace>, String> {
+        std::result::Result::Ok(std::vec::Vec::new())
+    }
+
+    /// Removes every stored embedding and prepares the vector index for
+    /// embeddings of `dimension`.
+    ///
+    /// Called before re-embedding all artifacts under a new model; artifact
+    /// rows are kept and their embeddings are written again by `save`. The
+    /// default implementation does nothing, for repositories without a
+    /// fixed-dimension index.
+    ///
+    /// # Arguments
+    ///
+    /// * `dimension` - Dimension of the embeddings about to be saved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index cannot be recreated.
+    fn reset_embedding_index(&mut self, _dimension: usize) -> std::result::Result<(), String> {
+        std::result::Result::Ok(())
+    }
+
+    /// Replaces the stored embeddings of a batch of artifacts.
+    ///
+    /// Each artifact's vector index entry is deleted and the artifact saved
+    /// with its new embedding, model, and dimension. Implementations backed
+    /// by a transactional store do the whole batch in one transaction, so a
+    /// failure leaves every artifact of the batch as it was. The default
+    /// implementation saves the artifacts one by one.
+    ///
+    /// # Arguments
+    ///
+    /// * `artifacts` - Artifacts carrying their new embeddings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any artifact cannot be saved.
+    fn replace_embeddings(&mut self, artifacts: std::vec::Vec<crate::domain::artifact::Artifact>) -> std::result::Result<(), String> {
+        for artifact in artifacts {
+            let id = artifact.id.clone();
+            self.save(artifact)
+                .map_err(|e| std::format!("Failed to save artifact {}: {}", id, e))?;
+        }
+        std::result::Result::Ok(())
+    }
 }
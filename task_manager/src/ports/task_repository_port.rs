@@ -3,8 +3,13 @@
 //! This port represents the interface for persisting and querying tasks using
 //! the HEXSER framework's Repository pattern. It extends the standard HEXSER
 //! Repository and QueryRepository traits to provide type-safe persistence operations.
+//! Besides offset paging through `find()`, it offers keyset (cursor) paging
+//! through `find_after()`, which stays stable while tasks are added.
 //!
 //! Revision History
+//! - 2026-10-17T11:50:00Z @AI: Add ByIdempotencyKey filter.
+//! - 2026-10-17T11:30:00Z @AI: Add keyset pagination: TaskCursor, TaskPage, TaskSortKey::cursor_value(), and find_after() with a default implementation over find().
+//! - 2026-10-17T10:10:00Z @AI: Add Priority sort key ordered by urgency.
 //! - 2025-11-30T21:30:00Z @AI: Add SortOrder sort key for manual task prioritization within TODO column.
 //! - 2025-11-29T15:30:00Z @AI: Rename ByAssignee filter variant to ByAgentPersona for better LLM inference alignment.
 //! - 2025-11-06T18:14:00Z @AI: Refactor to use HEXSER Repository pattern with filters and sort keys.
@@ -26,6 +31,9 @@ pub enum TaskFilter {
     /// Filter by assignee persona/role.
     ByAgentPersona(String),
 
+    /// Filter by idempotency key; at most one task has a given key.
+    ByIdempotencyKey(String),
+
     /// Return all tasks (no filtering).
     All,
 }
@@ -54,6 +62,137 @@ pub enum TaskSortKey {
 
     /// Sort by manual sort order (for TODO column prioritization).
     SortOrder,
+
+    /// Sort by priority urgency (Low < Medium < High); tasks without a
+    /// priority sort below Low.
+    Priority,
+}
+
+impl TaskSortKey {
+    /// Returns the task's value for this key as a string that orders the same
+    /// way as the key (byte-wise), for keyset pagination.
+    ///
+    /// Timestamps use the stored RFC 3339 form, statuses their variant name,
+    /// sort orders a zero-padded offset, and priorities their urgency rank.
+    /// A missing due date, sort order, or priority is the empty string, so
+    /// those tasks come first in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use task_manager::ports::task_repository_port::TaskSortKey;
+    /// let action = transcript_extractor::domain::action_item::ActionItem {
+    ///     title: std::string::String::from("Write docs"),
+    ///     assignee: None,
+    ///     due_date: Some(std::string::String::from("2026-11-30")),
+    /// };
+    /// let task = task_manager::domain::task::Task::from_action_item(&action, None);
+    /// assert_eq!(TaskSortKey::Title.cursor_value(&task), "Write docs");
+    /// assert_eq!(TaskSortKey::DueDate.cursor_value(&task), "2026-11-30");
+    /// ```
+    pub fn cursor_value(&self, task: &crate::domain::task::Task) -> String {
+        match self {
+            TaskSortKey::CreatedAt => task.created_at.to_rfc3339(),
+            TaskSortKey::UpdatedAt => task.updated_at.to_rfc3339(),
+            TaskSortKey::Status => std::format!("{:?}", task.status),
+            TaskSortKey::Title => task.title.clone(),
+            TaskSortKey::DueDate => task.due_date.clone().unwrap_or_default(),
+            TaskSortKey::SortOrder => task
+                .sort_order
+                .map(|order| std::format!("{:011}", i64::from(order) + SORT_ORDER_CURSOR_OFFSET))
+                .unwrap_or_default(),
+            TaskSortKey::Priority => task.priority.map(|priority| priority.rank().to_string()).unwrap_or_default(),
+        }
+    }
+}
+
+/// Added to sort orders in cursor values so negative orders still pad and compare correctly.
+pub(crate) const SORT_ORDER_CURSOR_OFFSET: i64 = 1 << 31;
+
+/// Position after the last task of a page, for keyset pagination.
+///
+/// Holds the last task's sort value (see TaskSortKey::cursor_value()) and its
+/// ID, which breaks ties. The next page starts strictly after this pair, so
+/// tasks inserted before it cannot shift later pages. Encoded as an opaque
+/// hex token for use on the command line.
+///
+/// # Examples
+///
+/// ```
+/// # use task_manager::ports::task_repository_port::TaskCursor;
+/// let cursor = TaskCursor { sort_value: std::string::String::from("Write docs"), id: std::string::String::from("task-1") };
+/// let token = cursor.encode();
+/// assert_eq!(TaskCursor::decode(&token), Ok(cursor));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TaskCursor {
+    /// Sort value of the last task returned.
+    pub sort_value: String,
+
+    /// ID of the last task returned.
+    pub id: String,
+}
+
+impl TaskCursor {
+    /// Creates the cursor positioned after `task` for `key`.
+    pub fn after(task: &crate::domain::task::Task, key: &TaskSortKey) -> Self {
+        TaskCursor { sort_value: key.cursor_value(task), id: task.id.clone() }
+    }
+
+    /// Encodes the cursor as a hex token.
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_string(self).unwrap_or_default();
+        json.bytes().map(|byte| std::format!("{:02x}", byte)).collect()
+    }
+
+    /// Decodes a token produced by `encode`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the token is not a valid cursor.
+    pub fn decode(token: &str) -> std::result::Result<Self, String> {
+        let invalid = || std::format!("Invalid cursor: '{}'", token);
+        if !token.len().is_multiple_of(2) || !token.is_ascii() {
+            return std::result::Result::Err(invalid());
+        }
+        let bytes = (0..token.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&token[i..i + 2], 16))
+            .collect::<std::result::Result<std::vec::Vec<u8>, _>>()
+            .map_err(|_| invalid())?;
+        serde_json::from_slice(&bytes).map_err(|_| invalid())
+    }
+
+    /// Returns true if this cursor comes before `task` when sorting by `key` in `direction`.
+    pub fn precedes(&self, task: &crate::domain::task::Task, key: &TaskSortKey, direction: hexser::ports::repository::Direction) -> bool {
+        let position = (key.cursor_value(task), task.id.as_str());
+        let cursor = (self.sort_value.clone(), self.id.as_str());
+        match direction {
+            hexser::ports::repository::Direction::Asc => position > cursor,
+            hexser::ports::repository::Direction::Desc => position < cursor,
+        }
+    }
+}
+
+/// One page of tasks from `TaskRepositoryPort::find_after()`.
+#[derive(Debug, Clone)]
+pub struct TaskPage {
+    /// Tasks on this page, in sort order.
+    pub tasks: std::vec::Vec<crate::domain::task::Task>,
+
+    /// Cursor for the next page, or None if this is the last page.
+    pub next_cursor: std::option::Option<TaskCursor>,
+}
+
+impl TaskPage {
+    /// Builds a page from up to `limit + 1` sorted rows; an extra row means another page follows.
+    pub fn from_rows(mut rows: std::vec::Vec<crate::domain::task::Task>, key: &TaskSortKey, limit: u32) -> Self {
+        let limit = limit as usize;
+        let has_more = rows.len() > limit;
+        rows.truncate(limit);
+        let next_cursor = if has_more { rows.last().map(|task| TaskCursor::after(task, key)) } else { std::option::Option::None };
+        TaskPage { tasks: rows, next_cursor }
+    }
 }
 
 /// Port (interface) for task persistence and retrieval operations.
@@ -76,6 +215,13 @@ pub enum TaskSortKey {
 ///
 /// // Query tasks using HEXSER find() with filters and options
 /// let filtered = repo.find(&TaskFilter::All, hexser::ports::repository::FindOptions::default()).unwrap();
+///
+/// // Page through tasks by title, 20 at a time
+/// let by_title = hexser::ports::repository::Sort { key: TaskSortKey::Title, direction: hexser::ports::repository::Direction::Asc };
+/// let first = repo.find_after(&TaskFilter::All, &by_title, None, 20).unwrap();
+/// if let Some(cursor) = first.next_cursor {
+///     let second = repo.find_after(&TaskFilter::All, &by_title, Some(&cursor), 20).unwrap();
+/// }
 /// # }
 /// ```
 pub trait TaskRepositoryPort:
@@ -88,5 +234,33 @@ pub trait TaskRepositoryPort:
     + Send
     + Sync
 {
-    // Marker trait - all methods provided by HEXSER Repository traits
+    /// Returns up to `limit` tasks matching `filter` that sort after `cursor`.
+    ///
+    /// Tasks are ordered by `sort` and then by ID, so every task has a unique
+    /// position. Pass None to start from the beginning, then the returned
+    /// `next_cursor` until it is None. Unlike offset paging, inserting or
+    /// deleting tasks before the cursor neither skips nor repeats tasks.
+    ///
+    /// The default implementation loads every matching task through find()
+    /// and pages in memory; database adapters should override it with a
+    /// keyset query.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying query fails.
+    fn find_after(
+        &self,
+        filter: &TaskFilter,
+        sort: &hexser::ports::repository::Sort<TaskSortKey>,
+        cursor: std::option::Option<&TaskCursor>,
+        limit: u32,
+    ) -> hexser::HexResult<TaskPage> {
+        let mut tasks = self.find(filter, hexser::ports::repository::FindOptions::default())?;
+        tasks.retain(|task| cursor.is_none_or(|cursor| cursor.precedes(task, &sort.key, sort.direction)));
+        tasks.sort_by_cached_key(|task| (sort.key.cursor_value(task), task.id.clone()));
+        if sort.direction == hexser::ports::repository::Direction::Desc {
+            tasks.reverse();
+        }
+        std::result::Result::Ok(TaskPage::from_rows(tasks, &sort.key, limit))
+    }
 }
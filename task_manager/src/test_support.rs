@@ -0,0 +1,98 @@
+//! Test helpers shared by the workspace's crates.
+//!
+//! Compiled for this crate's own tests and, behind the `test-support`
+//! feature, for the tests of crates that enable it as a dev-dependency.
+//! Nothing here is part of the production API.
+//!
+//! Revision History
+//! - 2026-10-18T05:10:00Z @AI: Initial one-shot mock HTTP server shared by adapter tests.
+
+/// Serves a single HTTP connection on a random local port.
+///
+/// The server reads the whole request (headers and the body announced by
+/// `Content-Length`), passes it to `respond`, and answers with the returned
+/// status line (e.g. "200 OK") and JSON body before closing the connection.
+///
+/// # Returns
+///
+/// The base URL (`http://127.0.0.1:<port>`) and a handle resolving to the raw
+/// request received.
+pub async fn mock_http_server<F>(respond: F) -> (std::string::String, tokio::task::JoinHandle<std::string::String>)
+where
+    F: FnOnce(&str) -> (&'static str, std::string::String) + std::marker::Send + 'static,
+{
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = std::format!("http://{}", listener.local_addr().unwrap());
+    let handle = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let request = read_request(&mut socket).await;
+        let (status, body) = respond(&request);
+        let response = std::format!(
+            "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+        tokio::io::AsyncWriteExt::write_all(&mut socket, response.as_bytes()).await.unwrap();
+        request
+    });
+    (base_url, handle)
+}
+
+/// Reads until the headers and the declared body length have arrived, or the peer closes.
+async fn read_request(socket: &mut tokio::net::TcpStream) -> std::string::String {
+    let mut request = std::vec::Vec::new();
+    let mut buf = [0u8; 4096];
+    loop {
+        let n = tokio::io::AsyncReadExt::read(socket, &mut buf).await.unwrap();
+        request.extend_from_slice(&buf[..n]);
+        if n == 0 {
+            break;
+        }
+        let text = std::string::String::from_utf8_lossy(&request);
+        if let std::option::Option::Some(header_end) = text.find("\r\n\r\n") {
+            let content_length = text[..header_end]
+                .lines()
+                .find_map(|line| line.to_lowercase().strip_prefix("content-length:").map(|v| v.trim().parse::<usize>().unwrap_or(0)))
+                .unwrap_or(0);
+            if request.len() >= header_end + 4 + content_length {
+                break;
+            }
+        }
+    }
+    std::string::String::from_utf8_lossy(&request).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn test_mock_http_server_reads_body_and_answers() {
+        // Test: Validates the server waits for the declared body and returns the responder's status and body.
+        // Justification: Adapter tests assert on request bodies, so a partial read would make them flaky.
+        let (base_url, server) = super::mock_http_server(|request| {
+            if request.starts_with("POST /echo ") {
+                ("201 Created", std::string::String::from(r#"{"ok": true}"#))
+            } else {
+                ("404 Not Found", std::string::String::from("{}"))
+            }
+        })
+        .await;
+
+        let response = send_post(&base_url, "/echo", r#"{"hello": "world"}"#).await;
+        let request = server.await.unwrap();
+
+        std::assert!(response.starts_with("HTTP/1.1 201 Created"));
+        std::assert!(response.ends_with(r#"{"ok": true}"#));
+        std::assert!(request.ends_with(r#"{"hello": "world"}"#));
+    }
+
+    /// Sends a minimal POST over a raw socket and returns the raw response.
+    async fn send_post(base_url: &str, path: &str, body: &str) -> std::string::String {
+        let mut socket = tokio::net::TcpStream::connect(base_url.trim_start_matches("http://")).await.unwrap();
+        let request = std::format!("POST {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}", path, body.len(), body);
+        tokio::io::AsyncWriteExt::write_all(&mut socket, request.as_bytes()).await.unwrap();
+        let mut response = std::vec::Vec::new();
+        tokio::io::AsyncReadExt::read_to_end(&mut socket, &mut response).await.unwrap();
+        std::string::String::from_utf8_lossy(&response).to_string()
+    }
+}
@@ -1,14 +1,33 @@
 //! Defines the ManageTaskUseCase for task lifecycle management operations.
 //!
-//! This use case provides operations for managing existing tasks, including updating
-//! their status, querying with sorting, and retrieving revision history. It demonstrates
+//! This use case provides operations for managing tasks, including creating them
+//! (with auto-triage), updating their status or description, reopening finished
+//! tasks, querying with sorting,
+//! and retrieving revision history. It demonstrates
 //! separation of concerns by delegating persistence to the repository port.
 //!
 //! Revision History
+//! - 2026-10-18T04:50:00Z @AI: undo_status_change() only reverts a change the task is still in, or else a reverse move the lifecycle allows.
+//! - 2026-10-18T02:20:00Z @AI: Add refresh_from_prd() for tasks updated by an incremental PRD re-parse; it validates the task and records the previous content as a revision.
+//! - 2026-10-18T01:50:00Z @AI: create() returns the existing task when its save loses a race on the idempotency key; fix the broken create() doc paragraph.
+//! - 2026-10-17T19:40:00Z @AI: Move MAX_TITLE_LENGTH above the ManageTaskUseCase docs so they attach to the struct again.
+//! - 2026-10-17T16:50:00Z @AI: Reject negative or non-finite estimates in create().
+//! - 2026-10-17T11:50:00Z @AI: Make create() idempotent: a task whose idempotency key (explicit, or derived from its transcript utterance) already exists returns the existing task instead of inserting a duplicate.
+//! - 2026-10-17T11:10:00Z @AI: Validate title (non-blank, at most MAX_TITLE_LENGTH characters) and due date (YYYY-MM-DD) in create(), reporting every problem at once.
+//! - 2026-10-17T10:50:00Z @AI: Return TaskManagerError instead of strings; create() rejects blank titles with ValidationFailed.
+//! - 2026-10-17T10:30:00Z @AI: Enforce TaskStatus::can_transition_to() in update_task_status() as well as change_status(), with an invalid-transition error; add reopen() for completed and archived tasks.
+//! - 2026-10-17T06:50:00Z @AI: Add update_description(), which marks comprehension tests stale when the description changes materially.
+//! - 2026-10-17T03:10:00Z @AI: Track time spent in InProgress on every status change (Task::track_status_time()).
+//! - 2026-10-16T17:30:00Z @AI: Add change_status() (validated, recorded as a revision) and undo_status_change().
+//! - 2026-10-16T16:30:00Z @AI: Apply project default assignee/priority in create() via with_project_settings().
+//! - 2026-10-16T12:10:00Z @AI: Add create() which auto-triages new tasks via TriageService before persisting them.
 //! - 2025-11-15T07:34:00Z @AI: Add no-run SQLite integration doc example demonstrating ManageTaskUseCase with SqliteTaskAdapter.
 //! - 2025-11-06T18:30:00Z @AI: Refactor to use generic concrete repository type (HEXSER pattern).
 //! - 2025-11-06T17:41:00Z @AI: Initial ManageTaskUseCase implementation.
 
+/// Maximum number of characters in a task title accepted by create().
+pub const MAX_TITLE_LENGTH: usize = 200;
+
 /// Use case for managing task lifecycle and queries.
 ///
 /// ManageTaskUseCase provides operations for updating task status and retrieving
@@ -80,6 +99,8 @@ where
     R: crate::ports::task_repository_port::TaskRepositoryPort,
 {
     task_repo: R,
+    triage_service: crate::domain::services::triage_service::TriageService,
+    project_settings: crate::domain::project_settings::ProjectSettings,
 }
 
 impl<R> ManageTaskUseCase<R>
@@ -105,13 +126,166 @@ where
     /// let use_case = ManageTaskUseCase::new(repo);
     /// ```
     pub fn new(task_repo: R) -> Self {
-        ManageTaskUseCase { task_repo }
+        ManageTaskUseCase {
+            task_repo,
+            triage_service: crate::domain::services::triage_service::TriageService::new(
+                crate::domain::services::complexity_scorer::ComplexityScorer::new(),
+            ),
+            project_settings: crate::domain::project_settings::ProjectSettings::default(),
+        }
+    }
+
+    /// Sets the project defaults applied by create().
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - Project-scoped default assignee and priority.
+    ///
+    /// # Returns
+    ///
+    /// The use case with the project settings applied.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use task_manager::use_cases::manage_task::ManageTaskUseCase;
+    /// # use task_manager::adapters::in_memory_task_adapter::InMemoryTaskAdapter;
+    /// let settings = task_manager::domain::project_settings::ProjectSettings {
+    ///     default_assignee: std::option::Option::Some(std::string::String::from("Backend Developer")),
+    ///     default_priority: std::option::Option::None,
+    /// };
+    /// let use_case = ManageTaskUseCase::new(InMemoryTaskAdapter::new()).with_project_settings(settings);
+    /// ```
+    pub fn with_project_settings(mut self, settings: crate::domain::project_settings::ProjectSettings) -> Self {
+        self.project_settings = settings;
+        self
+    }
+
+    /// Creates a new task after auto-triaging it.
+    ///
+    /// Validates the task first (see validate_new_task()), reporting every
+    /// problem at once. A task with an idempotency key is created at most
+    /// once: if a task with the same key exists, it is returned unchanged and
+    /// nothing is saved, so a retried import is a no-op. Tasks from a
+    /// transcript without a key get one from Task::transcript_idempotency_key().
+    ///
+    /// Fills an empty assignee or priority from the project settings, then
+    /// runs TriageService::auto_triage() so the task receives an initial
+    /// priority (only when still unset), a triage label, and a revision entry
+    /// explaining the decision, then persists it with save(). If the save
+    /// fails because a concurrent create stored the same key first (the
+    /// repository's unique constraint), that task is returned instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `task` - The new task to create.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Task)` - The triaged task as persisted, or the existing task with the same idempotency key.
+    /// * `Err(TaskManagerError::ValidationFailed)` - If the title or due date is invalid.
+    /// * `Err(TaskManagerError::Storage)` - If persistence fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use task_manager::use_cases::manage_task::ManageTaskUseCase;
+    /// # use task_manager::adapters::in_memory_task_adapter::InMemoryTaskAdapter;
+    /// let mut use_case = ManageTaskUseCase::new(InMemoryTaskAdapter::new());
+    /// let action = transcript_extractor::domain::action_item::ActionItem {
+    ///     title: std::string::String::from("Write release notes"),
+    ///     assignee: std::option::Option::None,
+    ///     due_date: std::option::Option::None,
+    /// };
+    /// let task = task_manager::domain::task::Task::from_action_item(&action, std::option::Option::None);
+    /// let created = use_case.create(task).unwrap();
+    /// std::assert!(created.priority.is_some());
+    /// ```
+    pub fn create(
+        &mut self,
+        mut task: crate::domain::task::Task,
+    ) -> std::result::Result<crate::domain::task::Task, crate::domain::task_manager_error::TaskManagerError> {
+        let errors = Self::validate_new_task(&task);
+        if !errors.is_empty() {
+            return std::result::Result::Err(crate::domain::task_manager_error::TaskManagerError::ValidationFailed { errors });
+        }
+        if task.idempotency_key.is_none() {
+            task.idempotency_key = task.transcript_idempotency_key();
+        }
+        if let std::option::Option::Some(key) = &task.idempotency_key {
+            let existing = self
+                .task_repo
+                .find_one(&crate::ports::task_repository_port::TaskFilter::ByIdempotencyKey(key.clone()))
+                .map_err(crate::domain::task_manager_error::TaskManagerError::storage("look up idempotency key"))?;
+            if let std::option::Option::Some(existing) = existing {
+                return std::result::Result::Ok(existing);
+            }
+        }
+        self.project_settings.apply_defaults(&mut task);
+        self.triage_service.auto_triage(&mut task);
+
+        if let std::result::Result::Err(error) = self.task_repo.save(task.clone()) {
+            // A concurrent create may have inserted the same key between the lookup and the save
+            if let std::option::Option::Some(key) = &task.idempotency_key
+                && let std::result::Result::Ok(std::option::Option::Some(existing)) = self
+                    .task_repo
+                    .find_one(&crate::ports::task_repository_port::TaskFilter::ByIdempotencyKey(key.clone()))
+                && existing.id != task.id
+            {
+                return std::result::Result::Ok(existing);
+            }
+            return std::result::Result::Err(crate::domain::task_manager_error::TaskManagerError::storage("save task")(error));
+        }
+
+        std::result::Result::Ok(task)
+    }
+
+    /// Checks a new task's fields, returning one FieldError per problem.
+    ///
+    /// The title must not be blank and may have at most MAX_TITLE_LENGTH
+    /// characters; a due date, if set, must be a valid YYYY-MM-DD date; an
+    /// estimate, if set, must be a finite, non-negative number of points.
+    fn validate_new_task(task: &crate::domain::task::Task) -> std::vec::Vec<crate::domain::task_manager_error::FieldError> {
+        let mut errors = std::vec::Vec::new();
+
+        let title_length = task.title.trim().chars().count();
+        if title_length == 0 {
+            errors.push(crate::domain::task_manager_error::FieldError::new("title", "must not be empty"));
+        } else if title_length > MAX_TITLE_LENGTH {
+            errors.push(crate::domain::task_manager_error::FieldError::new(
+                "title",
+                &std::format!("must be at most {} characters (got {})", MAX_TITLE_LENGTH, title_length),
+            ));
+        }
+
+        if let std::option::Option::Some(due_date) = &task.due_date
+            && chrono::NaiveDate::parse_from_str(due_date.trim(), "%Y-%m-%d").is_err()
+        {
+            errors.push(crate::domain::task_manager_error::FieldError::new(
+                "due_date",
+                &std::format!("'{}' is not a valid date (expected YYYY-MM-DD)", due_date),
+            ));
+        }
+
+        if let std::option::Option::Some(estimate) = task.estimate
+            && !(estimate.is_finite() && estimate >= 0.0)
+        {
+            errors.push(crate::domain::task_manager_error::FieldError::new(
+                "estimate",
+                &std::format!("must be a non-negative number of points (got {})", estimate),
+            ));
+        }
+
+        errors
     }
 
     /// Updates the status of a task.
     ///
     /// This method retrieves the task using HEXSER's find_one(), updates its status
-    /// and updated_at timestamp, then persists the changes using save().
+    /// and updated_at timestamp, then persists the changes using save(). Moving
+    /// into or out of InProgress starts or closes a time-tracking interval.
+    /// Transitions that TaskStatus::can_transition_to() disallows are rejected;
+    /// use reopen() to bring back a completed or archived task.
     ///
     /// # Arguments
     ///
@@ -121,7 +295,7 @@ where
     /// # Returns
     ///
     /// * `Ok(())` - Status successfully updated.
-    /// * `Err(String)` - Error message if update fails or task not found.
+    /// * `Err(TaskManagerError)` - NotFound, InvalidTransition, or Storage.
     ///
     /// # Examples
     ///
@@ -137,7 +311,7 @@ where
         &mut self,
         task_id: &str,
         new_status: crate::domain::task_status::TaskStatus,
-    ) -> std::result::Result<(), std::string::String> {
+    ) -> std::result::Result<(), crate::domain::task_manager_error::TaskManagerError> {
         // Retrieve the existing task using HEXSER's find_one()
         let filter = crate::ports::task_repository_port::TaskFilter::ById(
             task_id.to_string()
@@ -145,24 +319,306 @@ where
 
         let task_option = self.task_repo
             .find_one(&filter)
-            .map_err(|e| std::format!("Failed to find task: {:?}", e))?;
+            .map_err(crate::domain::task_manager_error::TaskManagerError::storage("find task"))?;
 
         let mut task = task_option.ok_or_else(|| {
-            std::format!("Task with ID {} not found", task_id)
+            crate::domain::task_manager_error::TaskManagerError::NotFound(task_id.to_string())
         })?;
+        Self::check_transition(&task, &new_status)?;
 
-        // Update the task's status and timestamp
+        // Update time tracking, the task's status, and timestamp
+        let now = chrono::Utc::now();
+        task.track_status_time(&new_status, now);
         task.status = new_status;
-        task.updated_at = chrono::Utc::now();
+        task.updated_at = now;
 
         // Persist the updated task using HEXSER's save()
         self.task_repo
             .save(task)
-            .map_err(|e| std::format!("Failed to save task: {:?}", e))?;
+            .map_err(crate::domain::task_manager_error::TaskManagerError::storage("save task"))?;
 
         std::result::Result::Ok(())
     }
 
+    /// Changes a task's status after validating the transition.
+    ///
+    /// Unlike update_task_status(), this rejects transitions that
+    /// TaskStatus::can_transition_to() disallows and records a revision holding
+    /// the previous status so the change can be undone. Time spent in
+    /// InProgress is tracked as for update_task_status().
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The unique ID of the task to update.
+    /// * `new_status` - The requested status.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Task)` - The task as persisted.
+    /// * `Err(TaskManagerError)` - NotFound, InvalidTransition, or Storage.
+    pub fn change_status(
+        &mut self,
+        task_id: &str,
+        new_status: crate::domain::task_status::TaskStatus,
+    ) -> std::result::Result<crate::domain::task::Task, crate::domain::task_manager_error::TaskManagerError> {
+        let mut task = self.find_task(task_id)?;
+        Self::check_transition(&task, &new_status)?;
+
+        let previous_state = serde_json::json!({ "status": task.status });
+        let description = std::format!("Status: {:?} -> {:?}", task.status, new_status);
+        self.apply_status(
+            &mut task,
+            new_status,
+            description,
+            std::option::Option::Some(previous_state.to_string()),
+        )?;
+
+        std::result::Result::Ok(task)
+    }
+
+    /// Reopens a completed or archived task, moving it back to Todo.
+    ///
+    /// This is the only way out of a terminal status. The change is recorded
+    /// as a revision holding the previous status, so undo_status_change() can
+    /// revert it.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The unique ID of the task to reopen.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Task)` - The task as persisted.
+    /// * `Err(TaskManagerError)` - NotFound, InvalidTransition if the task is not completed or archived, or Storage.
+    pub fn reopen(
+        &mut self,
+        task_id: &str,
+    ) -> std::result::Result<crate::domain::task::Task, crate::domain::task_manager_error::TaskManagerError> {
+        let mut task = self.find_task(task_id)?;
+
+        if !task.status.is_terminal() {
+            return std::result::Result::Err(crate::domain::task_manager_error::TaskManagerError::InvalidTransition {
+                from: task.status,
+                to: crate::domain::task_status::TaskStatus::Todo,
+                reason: std::string::String::from("only completed or archived tasks can be reopened"),
+            });
+        }
+
+        let previous_state = serde_json::json!({ "status": task.status });
+        let description = std::format!("Reopened: {:?} -> {:?}", task.status, crate::domain::task_status::TaskStatus::Todo);
+        self.apply_status(
+            &mut task,
+            crate::domain::task_status::TaskStatus::Todo,
+            description,
+            std::option::Option::Some(previous_state.to_string()),
+        )?;
+
+        std::result::Result::Ok(task)
+    }
+
+    /// Restores the status in place before the task's last status change.
+    ///
+    /// The restore is recorded as an undo revision, so calling this again
+    /// steps further back (see TaskRevision::status_change_to_undo()).
+    ///
+    /// Undo reverts exactly the recorded change, so it is allowed whenever
+    /// the task is still in the status that change set, even where
+    /// TaskStatus::can_transition_to() forbids the reverse move (e.g.
+    /// Completed back to InProgress after an accidental completion, or
+    /// Todo back to Archived after a reopen). If the status has moved on
+    /// since, for instance through update_task_status(), which records no
+    /// revision, the restore must be a transition the lifecycle allows.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The unique ID of the task to update.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Task)` - The task as persisted.
+    /// * `Err(TaskManagerError)` - NotFound, NothingToUndo, InvalidTransition, or Storage.
+    pub fn undo_status_change(
+        &mut self,
+        task_id: &str,
+    ) -> std::result::Result<crate::domain::task::Task, crate::domain::task_manager_error::TaskManagerError> {
+        let mut task = self.find_task(task_id)?;
+
+        let change = crate::domain::task_revision::TaskRevision::status_change_to_undo(&task.revisions)
+            .ok_or_else(|| crate::domain::task_manager_error::TaskManagerError::NothingToUndo(task_id.to_string()))?;
+        let restored = change
+            .previous_status()
+            .ok_or_else(|| crate::domain::task_manager_error::TaskManagerError::NothingToUndo(task_id.to_string()))?;
+        if change.new_status().as_ref() != std::option::Option::Some(&task.status) && !task.status.can_transition_to(&restored) {
+            return std::result::Result::Err(crate::domain::task_manager_error::TaskManagerError::InvalidTransition {
+                from: task.status.clone(),
+                to: restored,
+                reason: std::string::String::from("the status changed since the change being undone"),
+            });
+        }
+
+        let description = std::format!(
+            "{}: {:?} -> {:?}",
+            crate::domain::task_revision::TaskRevision::UNDO_STATUS_CHANGE,
+            task.status,
+            restored
+        );
+        self.apply_status(&mut task, restored, description, std::option::Option::None)?;
+
+        std::result::Result::Ok(task)
+    }
+
+    /// Updates a task's description and records the change as a revision.
+    ///
+    /// When the description changes materially (its content hash differs, see
+    /// ComprehensionTest::hash_description()), the task's comprehension tests
+    /// are marked stale so the orchestrator regenerates them on its next run.
+    /// Whitespace-only edits are saved without touching the tests.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The unique ID of the task to update.
+    /// * `description` - The new description.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Task)` - The task as persisted.
+    /// * `Err(TaskManagerError)` - NotFound or Storage.
+    pub fn update_description(
+        &mut self,
+        task_id: &str,
+        description: &str,
+    ) -> std::result::Result<crate::domain::task::Task, crate::domain::task_manager_error::TaskManagerError> {
+        let mut task = self.find_task(task_id)?;
+
+        let previous_state = serde_json::json!({ "description": task.description });
+        let marked_stale = task.set_description(std::string::String::from(description));
+        let change_description = if marked_stale > 0 {
+            std::format!("Description updated; {} comprehension test(s) marked stale", marked_stale)
+        } else {
+            std::string::String::from("Description updated")
+        };
+
+        let now = chrono::Utc::now();
+        Self::push_revision(&mut task, now, change_description, std::option::Option::Some(previous_state.to_string()));
+        task.updated_at = now;
+
+        self.task_repo
+            .save(task.clone())
+            .map_err(crate::domain::task_manager_error::TaskManagerError::storage("save task"))?;
+
+        std::result::Result::Ok(task)
+    }
+
+    /// Saves a task whose PRD-derived fields were refreshed by an incremental re-parse.
+    ///
+    /// The task is validated as in create(), and the stored title, description,
+    /// complexity, and source section are recorded in a revision so the refresh
+    /// appears in the task's history like any other edit.
+    ///
+    /// # Arguments
+    ///
+    /// * `task` - The stored task with its PRD-derived fields refreshed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Task)` - The task as persisted.
+    /// * `Err(TaskManagerError)` - ValidationFailed, NotFound, or Storage.
+    pub fn refresh_from_prd(
+        &mut self,
+        mut task: crate::domain::task::Task,
+    ) -> std::result::Result<crate::domain::task::Task, crate::domain::task_manager_error::TaskManagerError> {
+        let errors = Self::validate_new_task(&task);
+        if !errors.is_empty() {
+            return std::result::Result::Err(crate::domain::task_manager_error::TaskManagerError::ValidationFailed { errors });
+        }
+        let stored = self.find_task(&task.id)?;
+
+        let previous_state = serde_json::json!({
+            "title": stored.title,
+            "description": stored.description,
+            "complexity": stored.complexity,
+            "source_section": stored.source_section,
+        });
+        let now = chrono::Utc::now();
+        Self::push_revision(
+            &mut task,
+            now,
+            std::string::String::from("Refreshed from re-parsed PRD"),
+            std::option::Option::Some(previous_state.to_string()),
+        );
+        task.updated_at = now;
+
+        self.task_repo
+            .save(task.clone())
+            .map_err(crate::domain::task_manager_error::TaskManagerError::storage("save task"))?;
+
+        std::result::Result::Ok(task)
+    }
+
+    /// Loads a task by ID, failing if it does not exist.
+    fn find_task(&self, task_id: &str) -> std::result::Result<crate::domain::task::Task, crate::domain::task_manager_error::TaskManagerError> {
+        let filter = crate::ports::task_repository_port::TaskFilter::ById(task_id.to_string());
+        self.task_repo
+            .find_one(&filter)
+            .map_err(crate::domain::task_manager_error::TaskManagerError::storage("find task"))?
+            .ok_or_else(|| crate::domain::task_manager_error::TaskManagerError::NotFound(task_id.to_string()))
+    }
+
+    /// Fails with InvalidTransition if `task` may not move to `new_status`.
+    fn check_transition(
+        task: &crate::domain::task::Task,
+        new_status: &crate::domain::task_status::TaskStatus,
+    ) -> std::result::Result<(), crate::domain::task_manager_error::TaskManagerError> {
+        if task.status.can_transition_to(new_status) {
+            return std::result::Result::Ok(());
+        }
+        let reason = if task.status.is_terminal() {
+            "the task is closed; reopen it first"
+        } else {
+            "not allowed by the task lifecycle"
+        };
+        std::result::Result::Err(crate::domain::task_manager_error::TaskManagerError::InvalidTransition {
+            from: task.status.clone(),
+            to: new_status.clone(),
+            reason: std::string::String::from(reason),
+        })
+    }
+
+    /// Sets the status, appends a revision describing the change, and persists the task.
+    fn apply_status(
+        &mut self,
+        task: &mut crate::domain::task::Task,
+        new_status: crate::domain::task_status::TaskStatus,
+        change_description: std::string::String,
+        previous_state_json: std::option::Option<std::string::String>,
+    ) -> std::result::Result<(), crate::domain::task_manager_error::TaskManagerError> {
+        let now = chrono::Utc::now();
+        Self::push_revision(task, now, change_description, previous_state_json);
+        task.track_status_time(&new_status, now);
+        task.status = new_status;
+        task.updated_at = now;
+
+        self.task_repo
+            .save(task.clone())
+            .map_err(crate::domain::task_manager_error::TaskManagerError::storage("save task"))
+    }
+
+    /// Appends a revision entry describing a change made at `now`.
+    fn push_revision(
+        task: &mut crate::domain::task::Task,
+        now: chrono::DateTime<chrono::Utc>,
+        change_description: std::string::String,
+        previous_state_json: std::option::Option<std::string::String>,
+    ) {
+        task.revisions.push(crate::domain::task_revision::TaskRevision {
+            revision_id: uuid::Uuid::new_v4().to_string(),
+            task_id: task.id.clone(),
+            timestamp: now,
+            change_description,
+            previous_state_json,
+        });
+    }
+
     /// Retrieves all tasks sorted by the specified criteria using HEXSER patterns.
     ///
     /// # Arguments
@@ -173,7 +629,7 @@ where
     /// # Returns
     ///
     /// * `Ok(Vec<Task>)` - All tasks sorted as requested.
-    /// * `Err(String)` - Error message if retrieval fails.
+    /// * `Err(TaskManagerError::Storage)` - If retrieval fails.
     ///
     /// # Examples
     ///
@@ -192,7 +648,7 @@ where
         &self,
         sort_key: crate::ports::task_repository_port::TaskSortKey,
         direction: hexser::ports::repository::Direction,
-    ) -> std::result::Result<Vec<crate::domain::task::Task>, std::string::String> {
+    ) -> std::result::Result<Vec<crate::domain::task::Task>, crate::domain::task_manager_error::TaskManagerError> {
         let filter = crate::ports::task_repository_port::TaskFilter::All;
         let opts = hexser::ports::repository::FindOptions {
             sort: std::option::Option::Some(vec![
@@ -207,7 +663,7 @@ where
 
         self.task_repo
             .find(&filter, opts)
-            .map_err(|e| std::format!("Failed to retrieve tasks: {:?}", e))
+            .map_err(crate::domain::task_manager_error::TaskManagerError::storage("retrieve tasks"))
     }
 
 }
@@ -254,6 +710,9 @@ mod tests {
                 crate::ports::task_repository_port::TaskFilter::ById(id) => {
                     self.tasks.get(id).cloned()
                 }
+                crate::ports::task_repository_port::TaskFilter::ByIdempotencyKey(key) => {
+                    self.tasks.values().find(|t| t.idempotency_key.as_ref() == Some(key)).cloned()
+                }
                 _ => self.tasks.values().next().cloned(),
             };
             std::result::Result::Ok(result)
@@ -302,6 +761,592 @@ mod tests {
         assert_eq!(updated_task.status, crate::domain::task_status::TaskStatus::InProgress);
     }
 
+    #[test]
+    fn test_create_auto_triages_missing_priority() {
+        // Test: Validates that create() fills in priority, triage label, and a revision entry.
+        // Justification: Tasks entering the system without a priority must be triaged automatically.
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Migrate user database to Postgres"),
+            assignee: None,
+            due_date: None,
+        };
+        let task = crate::domain::task::Task::from_action_item(&action, None);
+        let mut use_case = ManageTaskUseCase::new(MockRepo::new());
+
+        let created = use_case.create(task).unwrap();
+
+        assert_eq!(created.priority, Some(crate::domain::priority::Priority::High));
+        assert!(created.tags.contains(&std::string::String::from("triage:decompose")));
+        assert_eq!(created.revisions.len(), 1);
+        assert!(created.revisions[0].change_description.starts_with("Auto-triage:"));
+
+        let filter = crate::ports::task_repository_port::TaskFilter::ById(created.id.clone());
+        let stored = use_case.task_repo.find_one(&filter).unwrap().unwrap();
+        assert_eq!(stored.priority, Some(crate::domain::priority::Priority::High));
+    }
+
+    #[test]
+    fn test_create_keeps_explicit_priority() {
+        // Test: Validates that create() never overrides an explicitly provided priority.
+        // Justification: Auto-triage only fills gaps; user intent wins.
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Migrate user database to Postgres"),
+            assignee: None,
+            due_date: None,
+        };
+        let mut task = crate::domain::task::Task::from_action_item(&action, None);
+        task.priority = Some(crate::domain::priority::Priority::Low);
+        let mut use_case = ManageTaskUseCase::new(MockRepo::new());
+
+        let created = use_case.create(task).unwrap();
+
+        assert_eq!(created.priority, Some(crate::domain::priority::Priority::Low));
+        assert!(created.revisions[0].change_description.contains("kept explicit priority low"));
+    }
+
+    #[test]
+    fn test_create_applies_project_defaults_to_empty_fields() {
+        // Test: Validates project defaults fill a missing assignee and priority.
+        // Justification: Parsed tasks without an owner should land with the project default, not "Unassigned".
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Update README"),
+            assignee: None,
+            due_date: None,
+        };
+        let task = crate::domain::task::Task::from_action_item(&action, None);
+        let settings = crate::domain::project_settings::ProjectSettings {
+            default_assignee: Some(std::string::String::from("Technical Writer")),
+            default_priority: Some(crate::domain::priority::Priority::High),
+        };
+        let mut use_case = ManageTaskUseCase::new(MockRepo::new()).with_project_settings(settings);
+
+        let created = use_case.create(task).unwrap();
+
+        assert_eq!(created.agent_persona.as_deref(), Some("Technical Writer"));
+        assert_eq!(created.priority, Some(crate::domain::priority::Priority::High));
+    }
+
+    #[test]
+    fn test_create_project_defaults_never_override_provided_values() {
+        // Test: Validates provided assignee and priority survive project defaults.
+        // Justification: Defaults only fill gaps; explicit values always win.
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Update README"),
+            assignee: Some(std::string::String::from("Backend Developer")),
+            due_date: None,
+        };
+        let mut task = crate::domain::task::Task::from_action_item(&action, None);
+        task.priority = Some(crate::domain::priority::Priority::Low);
+        let settings = crate::domain::project_settings::ProjectSettings {
+            default_assignee: Some(std::string::String::from("Technical Writer")),
+            default_priority: Some(crate::domain::priority::Priority::High),
+        };
+        let mut use_case = ManageTaskUseCase::new(MockRepo::new()).with_project_settings(settings);
+
+        let created = use_case.create(task).unwrap();
+
+        assert_eq!(created.agent_persona.as_deref(), Some("Backend Developer"));
+        assert_eq!(created.priority, Some(crate::domain::priority::Priority::Low));
+    }
+
+    #[test]
+    fn test_change_status_records_revision_and_undo_restores_it() {
+        // Test: Validates change_status() records the previous status and undo_status_change() restores it.
+        // Justification: The TUI undo key depends on status changes being reversible from revision history.
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Review pull request"),
+            assignee: None,
+            due_date: None,
+        };
+        let task = crate::domain::task::Task::from_action_item(&action, None);
+        let task_id = task.id.clone();
+        let mut repo = MockRepo::new();
+        repo.save(task).unwrap();
+        let mut use_case = ManageTaskUseCase::new(repo);
+
+        let changed = use_case
+            .change_status(&task_id, crate::domain::task_status::TaskStatus::InProgress)
+            .unwrap();
+        assert_eq!(changed.status, crate::domain::task_status::TaskStatus::InProgress);
+        assert_eq!(changed.revisions.last().unwrap().previous_status(), Some(crate::domain::task_status::TaskStatus::Todo));
+
+        let restored = use_case.undo_status_change(&task_id).unwrap();
+        assert_eq!(restored.status, crate::domain::task_status::TaskStatus::Todo);
+        assert!(matches!(
+            use_case.undo_status_change(&task_id),
+            Err(crate::domain::task_manager_error::TaskManagerError::NothingToUndo(_))
+        ));
+    }
+
+    #[test]
+    fn test_change_status_rejects_invalid_transition() {
+        // Test: Validates an invalid transition is refused and the task is left untouched.
+        // Justification: Archived (cancelled) tasks must be reopened before other changes.
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Old spike"),
+            assignee: None,
+            due_date: None,
+        };
+        let mut task = crate::domain::task::Task::from_action_item(&action, None);
+        task.status = crate::domain::task_status::TaskStatus::Archived;
+        let task_id = task.id.clone();
+        let mut repo = MockRepo::new();
+        repo.save(task).unwrap();
+        let mut use_case = ManageTaskUseCase::new(repo);
+
+        let result = use_case.change_status(&task_id, crate::domain::task_status::TaskStatus::Completed);
+
+        assert!(matches!(result, Err(crate::domain::task_manager_error::TaskManagerError::InvalidTransition { .. })));
+        let filter = crate::ports::task_repository_port::TaskFilter::ById(task_id);
+        let stored = use_case.task_repo.find_one(&filter).unwrap().unwrap();
+        assert_eq!(stored.status, crate::domain::task_status::TaskStatus::Archived);
+        assert!(stored.revisions.is_empty());
+    }
+
+    #[test]
+    fn test_update_task_status_rejects_invalid_transition() {
+        // Test: Validates update_task_status() enforces the same state machine as change_status().
+        // Justification: Callers must not be able to bypass validation, e.g. completing a cancelled task.
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Dropped idea"),
+            assignee: None,
+            due_date: None,
+        };
+        let mut task = crate::domain::task::Task::from_action_item(&action, None);
+        task.status = crate::domain::task_status::TaskStatus::Archived;
+        let task_id = task.id.clone();
+        let mut repo = MockRepo::new();
+        repo.save(task).unwrap();
+        let mut use_case = ManageTaskUseCase::new(repo);
+
+        let result = use_case.update_task_status(&task_id, crate::domain::task_status::TaskStatus::Completed);
+
+        assert!(matches!(result, Err(crate::domain::task_manager_error::TaskManagerError::InvalidTransition { .. })));
+        let filter = crate::ports::task_repository_port::TaskFilter::ById(task_id);
+        let stored = use_case.task_repo.find_one(&filter).unwrap().unwrap();
+        assert_eq!(stored.status, crate::domain::task_status::TaskStatus::Archived);
+    }
+
+    #[test]
+    fn test_reopen_moves_terminal_tasks_back_to_todo() {
+        // Test: Validates reopen() brings completed and archived tasks back to Todo and refuses open tasks.
+        // Justification: Reopening is the only legitimate way out of a terminal status, and must be undoable.
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Ship release notes"),
+            assignee: None,
+            due_date: None,
+        };
+        let mut repo = MockRepo::new();
+        let mut ids = std::vec::Vec::new();
+        for status in [
+            crate::domain::task_status::TaskStatus::Completed,
+            crate::domain::task_status::TaskStatus::Archived,
+            crate::domain::task_status::TaskStatus::InProgress,
+        ] {
+            let mut task = crate::domain::task::Task::from_action_item(&action, None);
+            task.status = status;
+            ids.push(task.id.clone());
+            repo.save(task).unwrap();
+        }
+        let mut use_case = ManageTaskUseCase::new(repo);
+
+        for id in &ids[..2] {
+            let reopened = use_case.reopen(id).unwrap();
+            assert_eq!(reopened.status, crate::domain::task_status::TaskStatus::Todo);
+            assert!(reopened.revisions.last().unwrap().change_description.starts_with("Reopened"));
+        }
+        assert!(matches!(
+            use_case.reopen(&ids[2]),
+            Err(crate::domain::task_manager_error::TaskManagerError::InvalidTransition { .. })
+        ));
+
+        let undone = use_case.undo_status_change(&ids[0]).unwrap();
+        assert_eq!(undone.status, crate::domain::task_status::TaskStatus::Completed);
+    }
+
+    #[test]
+    fn test_undo_reverts_only_a_change_the_task_is_still_in() {
+        // Test: Validates undo may move Completed back to InProgress right after the completion, but not once the status moved on without a revision.
+        // Justification: Undo bypasses can_transition_to() only to revert the recorded change; a stale revision must not reopen a closed task.
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Publish changelog"),
+            assignee: None,
+            due_date: None,
+        };
+        let mut repo = MockRepo::new();
+        let mut ids = std::vec::Vec::new();
+        for _ in 0..2 {
+            let mut task = crate::domain::task::Task::from_action_item(&action, None);
+            task.status = crate::domain::task_status::TaskStatus::InProgress;
+            ids.push(task.id.clone());
+            repo.save(task).unwrap();
+        }
+        let mut use_case = ManageTaskUseCase::new(repo);
+
+        use_case.change_status(&ids[0], crate::domain::task_status::TaskStatus::Completed).unwrap();
+        let undone = use_case.undo_status_change(&ids[0]).unwrap();
+        assert_eq!(undone.status, crate::domain::task_status::TaskStatus::InProgress);
+
+        // Archiving through update_task_status() leaves the Completed revision as the one to undo
+        use_case.change_status(&ids[1], crate::domain::task_status::TaskStatus::Completed).unwrap();
+        use_case.update_task_status(&ids[1], crate::domain::task_status::TaskStatus::Archived).unwrap();
+        assert!(matches!(
+            use_case.undo_status_change(&ids[1]),
+            Err(crate::domain::task_manager_error::TaskManagerError::InvalidTransition { .. })
+        ));
+        let filter = crate::ports::task_repository_port::TaskFilter::ById(ids[1].clone());
+        let stored = use_case.task_repo.find_one(&filter).unwrap().unwrap();
+        assert_eq!(stored.status, crate::domain::task_status::TaskStatus::Archived);
+    }
+
+    #[test]
+    fn test_missing_task_returns_not_found() {
+        // Test: Validates operations on an unknown task ID return NotFound carrying the ID.
+        // Justification: Callers (e.g. the CLI exit codes) distinguish a missing task from other failures.
+        let mut use_case = ManageTaskUseCase::new(MockRepo::new());
+
+        let result = use_case.change_status("missing", crate::domain::task_status::TaskStatus::InProgress);
+
+        match result {
+            Err(crate::domain::task_manager_error::TaskManagerError::NotFound(id)) => assert_eq!(id, "missing"),
+            other => panic!("expected NotFound, got {:?}", other),
+        }
+        assert!(matches!(
+            use_case.update_task_status("missing", crate::domain::task_status::TaskStatus::Completed),
+            Err(crate::domain::task_manager_error::TaskManagerError::NotFound(_))
+        ));
+    }
+
+    fn task_titled(title: &str, due_date: Option<&str>) -> crate::domain::task::Task {
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from(title),
+            assignee: None,
+            due_date: due_date.map(std::string::String::from),
+        };
+        crate::domain::task::Task::from_action_item(&action, None)
+    }
+
+    fn create_errors(task: crate::domain::task::Task) -> std::vec::Vec<crate::domain::task_manager_error::FieldError> {
+        let mut use_case = ManageTaskUseCase::new(MockRepo::new());
+        let result = use_case.create(task);
+        assert!(use_case.task_repo.tasks.is_empty(), "an invalid task must not be stored");
+        match result {
+            Err(crate::domain::task_manager_error::TaskManagerError::ValidationFailed { errors }) => errors,
+            other => panic!("expected ValidationFailed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_create_rejects_blank_title() {
+        // Test: Validates create() returns ValidationFailed for a whitespace-only title and stores nothing.
+        // Justification: Validation failures must be distinguishable from storage errors.
+        let errors = create_errors(task_titled("   ", None));
+
+        assert_eq!(errors, [crate::domain::task_manager_error::FieldError::new("title", "must not be empty")]);
+    }
+
+    #[test]
+    fn test_create_rejects_overlong_title() {
+        // Test: Validates a title one character over MAX_TITLE_LENGTH is rejected while one at the limit passes.
+        // Justification: Pasted descriptions in the title field break list and board layouts.
+        let errors = create_errors(task_titled(&"x".repeat(MAX_TITLE_LENGTH + 1), None));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "title");
+
+        let mut use_case = ManageTaskUseCase::new(MockRepo::new());
+        assert!(use_case.create(task_titled(&"x".repeat(MAX_TITLE_LENGTH), None)).is_ok());
+    }
+
+    #[test]
+    fn test_create_rejects_unparseable_due_date() {
+        // Test: Validates due dates that are not YYYY-MM-DD calendar dates are rejected.
+        // Justification: Free-text or impossible dates cannot be sorted or shown as overdue.
+        for due in ["next friday", "2026-02-30", "30/11/2026"] {
+            let errors = create_errors(task_titled("Ship release", Some(due)));
+            assert_eq!(errors.len(), 1, "due date {}", due);
+            assert_eq!(errors[0].field, "due_date");
+        }
+    }
+
+    #[test]
+    fn test_create_rejects_negative_estimate() {
+        // Test: Validates negative and NaN estimates are rejected while zero is accepted.
+        // Justification: Capacity planning sums estimates; a negative one would free up capacity that does not exist.
+        for estimate in [-1.0, f32::NAN] {
+            let mut task = task_titled("Ship release", None);
+            task.estimate = Some(estimate);
+            let errors = create_errors(task);
+            assert_eq!(errors.len(), 1, "estimate {}", estimate);
+            assert_eq!(errors[0].field, "estimate");
+        }
+
+        let mut task = task_titled("Ship release", None);
+        task.estimate = Some(0.0);
+        assert!(ManageTaskUseCase::new(MockRepo::new()).create(task).is_ok());
+    }
+
+    #[test]
+    fn test_create_reports_all_problems_together_and_accepts_valid_task() {
+        // Test: Validates several invalid fields are reported in one error, and a valid task is created.
+        // Justification: The CLI shows every problem at once instead of making the user fix them one by one.
+        let errors = create_errors(task_titled("", Some("someday")));
+        let fields: std::vec::Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, ["title", "due_date"]);
+
+        let mut use_case = ManageTaskUseCase::new(MockRepo::new());
+        let created = use_case.create(task_titled("Ship release", Some("2026-11-30"))).unwrap();
+        assert_eq!(created.due_date.as_deref(), Some("2026-11-30"));
+        assert_eq!(use_case.task_repo.tasks.len(), 1);
+    }
+
+    #[test]
+    fn test_create_with_same_idempotency_key_returns_existing_task() {
+        // Test: Validates two creates with the same idempotency key store one task and return the same ID.
+        // Justification: A PRD parse retried after a partial failure must not duplicate the tasks already created.
+        let key = crate::domain::task::Task::idempotency_key_for("prd:Rigger", "Objectives > Enable task decomposition");
+        let mut use_case = ManageTaskUseCase::new(MockRepo::new());
+        let mut first = task_titled("Design decomposition API", None);
+        first.idempotency_key = Some(key.clone());
+        let mut retry = task_titled("Design the decomposition API", None);
+        retry.idempotency_key = Some(key.clone());
+
+        let created = use_case.create(first).unwrap();
+        let again = use_case.create(retry).unwrap();
+
+        assert_eq!(again.id, created.id);
+        assert_eq!(again.title, "Design decomposition API");
+        assert_eq!(use_case.task_repo.tasks.len(), 1);
+        let other = use_case.create(task_titled("Design decomposition API", None)).unwrap();
+        assert_ne!(other.id, created.id, "tasks without a key are never deduplicated");
+    }
+
+    /// Repository whose key lookups miss a task another writer just stored, while save() still enforces unique keys.
+    struct RacingRepo {
+        tasks: std::sync::Mutex<std::collections::HashMap<String, crate::domain::task::Task>>,
+        stale_key_lookups: std::sync::atomic::AtomicUsize,
+    }
+
+    impl hexser::ports::Repository<crate::domain::task::Task> for RacingRepo {
+        fn save(&mut self, entity: crate::domain::task::Task) -> hexser::HexResult<()> {
+            let mut tasks = self.tasks.lock().unwrap();
+            if entity.idempotency_key.is_some()
+                && tasks.values().any(|task| task.id != entity.id && task.idempotency_key == entity.idempotency_key)
+            {
+                return std::result::Result::Err(hexser::Hexserror::adapter("E_UNIQUE", "UNIQUE constraint failed: tasks.idempotency_key"));
+            }
+            tasks.insert(entity.id.clone(), entity);
+            std::result::Result::Ok(())
+        }
+    }
+
+    impl hexser::ports::repository::QueryRepository<crate::domain::task::Task> for RacingRepo {
+        type Filter = crate::ports::task_repository_port::TaskFilter;
+        type SortKey = crate::ports::task_repository_port::TaskSortKey;
+
+        fn find_one(&self, filter: &Self::Filter) -> hexser::HexResult<std::option::Option<crate::domain::task::Task>> {
+            let tasks = self.tasks.lock().unwrap();
+            let result = match filter {
+                crate::ports::task_repository_port::TaskFilter::ByIdempotencyKey(key) => {
+                    let stale = self
+                        .stale_key_lookups
+                        .fetch_update(std::sync::atomic::Ordering::SeqCst, std::sync::atomic::Ordering::SeqCst, |n| n.checked_sub(1))
+                        .is_ok();
+                    if stale {
+                        None
+                    } else {
+                        tasks.values().find(|t| t.idempotency_key.as_ref() == Some(key)).cloned()
+                    }
+                }
+                crate::ports::task_repository_port::TaskFilter::ById(id) => tasks.get(id).cloned(),
+                _ => None,
+            };
+            std::result::Result::Ok(result)
+        }
+
+        fn find(
+            &self,
+            _filter: &Self::Filter,
+            _opts: hexser::ports::repository::FindOptions<Self::SortKey>,
+        ) -> hexser::HexResult<std::vec::Vec<crate::domain::task::Task>> {
+            std::result::Result::Ok(self.tasks.lock().unwrap().values().cloned().collect())
+        }
+    }
+
+    impl crate::ports::task_repository_port::TaskRepositoryPort for RacingRepo {}
+
+    #[test]
+    fn test_create_losing_a_race_on_the_idempotency_key_returns_the_winner() {
+        // Test: Validates a create whose save hits the unique key constraint returns the task that won the race.
+        // Justification: Two concurrent imports of the same item must both succeed with one task, not fail with a storage error.
+        let key = crate::domain::task::Task::idempotency_key_for("prd:Rigger", "Objectives > Sync");
+        let mut winner = task_titled("Design sync API", None);
+        winner.idempotency_key = Some(key.clone());
+        let mut repo = RacingRepo {
+            tasks: std::sync::Mutex::new(std::collections::HashMap::new()),
+            stale_key_lookups: std::sync::atomic::AtomicUsize::new(1),
+        };
+        repo.save(winner.clone()).unwrap();
+        let mut use_case = ManageTaskUseCase::new(repo);
+        let mut loser = task_titled("Design the sync API", None);
+        loser.idempotency_key = Some(key);
+
+        let created = use_case.create(loser).unwrap();
+
+        assert_eq!(created.id, winner.id);
+        assert_eq!(use_case.task_repo.tasks.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_create_derives_idempotency_key_from_transcript_utterance() {
+        // Test: Validates tasks from the same transcript utterance are created once, while other utterances are not affected.
+        // Justification: Re-processing a transcript must not duplicate its action items.
+        let from_line = |line: usize| {
+            let mut task = task_titled("Finish API docs", None);
+            task.source_transcript_id = Some(std::string::String::from("transcript-1"));
+            task.source_utterance = Some(crate::domain::transcript_utterance::TranscriptUtterance {
+                line,
+                speaker: Some(std::string::String::from("John")),
+                text: std::string::String::from("I'll finish the API docs."),
+            });
+            task
+        };
+        let mut use_case = ManageTaskUseCase::new(MockRepo::new());
+
+        let created = use_case.create(from_line(3)).unwrap();
+        let again = use_case.create(from_line(3)).unwrap();
+        let later = use_case.create(from_line(9)).unwrap();
+
+        assert_eq!(created.idempotency_key, from_line(3).transcript_idempotency_key());
+        assert_eq!(again.id, created.id);
+        assert_ne!(later.id, created.id);
+        assert_eq!(use_case.task_repo.tasks.len(), 2);
+    }
+
+    #[test]
+    fn test_time_spent_sums_paused_and_resumed_intervals() {
+        // Test: Validates start -> stop -> start -> complete accumulates exactly both InProgress intervals.
+        // Justification: Pausing must neither lose the first interval nor count the paused time.
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Migrate billing"),
+            assignee: None,
+            due_date: None,
+        };
+        let task = crate::domain::task::Task::from_action_item(&action, None);
+        let task_id = task.id.clone();
+        let mut repo = MockRepo::new();
+        repo.save(task).unwrap();
+        let mut use_case = ManageTaskUseCase::new(repo);
+        // Simulates `seconds` of work by moving the running interval's start into the past.
+        let work_for = |use_case: &mut ManageTaskUseCase<MockRepo>, seconds: i64| {
+            let task = use_case.task_repo.tasks.get_mut(&task_id).unwrap();
+            task.started_at = task.started_at.map(|started_at| started_at - chrono::Duration::seconds(seconds));
+        };
+
+        use_case.change_status(&task_id, crate::domain::task_status::TaskStatus::InProgress).unwrap();
+        work_for(&mut use_case, 1200);
+        let paused = use_case.change_status(&task_id, crate::domain::task_status::TaskStatus::Todo).unwrap();
+        assert_eq!(paused.time_spent_seconds, 1200);
+        assert!(paused.started_at.is_none());
+
+        let resumed = use_case.change_status(&task_id, crate::domain::task_status::TaskStatus::InProgress).unwrap();
+        assert!(resumed.started_at.is_some());
+        work_for(&mut use_case, 300);
+        use_case
+            .update_task_status(&task_id, crate::domain::task_status::TaskStatus::Completed)
+            .unwrap();
+
+        let filter = crate::ports::task_repository_port::TaskFilter::ById(task_id.clone());
+        let completed = use_case.task_repo.find_one(&filter).unwrap().unwrap();
+        assert_eq!(completed.time_spent_seconds, 1500);
+        assert!(completed.started_at.is_none());
+    }
+
+    fn task_with_test(description: &str) -> crate::domain::task::Task {
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Add login"),
+            assignee: None,
+            due_date: None,
+        };
+        let mut task = crate::domain::task::Task::from_action_item(&action, None);
+        task.description = std::string::String::from(description);
+        task.comprehension_tests = Some(std::vec![crate::domain::comprehension_test::ComprehensionTest {
+            test_id: std::string::String::from("ct-1"),
+            task_id: task.id.clone(),
+            timestamp: chrono::Utc::now(),
+            test_type: std::string::String::from("short_answer"),
+            question: std::string::String::from("Which credentials are accepted?"),
+            options: None,
+            correct_answer: std::string::String::from("Email and password"),
+            description_hash: Some(crate::domain::comprehension_test::ComprehensionTest::hash_description(description)),
+            stale: false,
+        }]);
+        task
+    }
+
+    #[test]
+    fn test_refresh_from_prd_records_previous_content() {
+        // Test: Validates a PRD refresh is saved with a revision holding the stored content, and unknown tasks are refused.
+        // Justification: Incremental `rig parse` used to save refreshed tasks directly, leaving no history of the edit.
+        let task = task_titled("Build sync engine", None);
+        let task_id = task.id.clone();
+        let mut repo = MockRepo::new();
+        repo.save(task.clone()).unwrap();
+        let mut use_case = ManageTaskUseCase::new(repo);
+
+        let mut refreshed = task.clone();
+        refreshed.title = std::string::String::from("Build offline sync engine");
+        let saved = use_case.refresh_from_prd(refreshed).unwrap();
+
+        assert_eq!(saved.title, "Build offline sync engine");
+        let revision = saved.revisions.last().unwrap();
+        assert_eq!(revision.change_description, "Refreshed from re-parsed PRD");
+        assert!(revision.previous_state_json.as_deref().unwrap().contains("Build sync engine"));
+        assert_eq!(use_case.find_task(&task_id).unwrap().title, "Build offline sync engine");
+        assert!(matches!(
+            use_case.refresh_from_prd(task_titled("Unknown", None)),
+            Err(crate::domain::task_manager_error::TaskManagerError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_update_description_marks_comprehension_test_stale() {
+        // Test: Validates a material description change marks the task's comprehension test stale.
+        // Justification: A test generated for the old description no longer checks the right thing.
+        let task = task_with_test("Accept email and password");
+        let task_id = task.id.clone();
+        let mut repo = MockRepo::new();
+        repo.save(task).unwrap();
+        let mut use_case = ManageTaskUseCase::new(repo);
+
+        let updated = use_case.update_description(&task_id, "Accept email, password, and SSO").unwrap();
+
+        assert!(updated.comprehension_tests.as_ref().unwrap()[0].stale);
+        assert!(updated.needs_comprehension_test());
+        assert_eq!(updated.description, "Accept email, password, and SSO");
+        assert_eq!(
+            updated.revisions.last().unwrap().change_description,
+            "Description updated; 1 comprehension test(s) marked stale"
+        );
+    }
+
+    #[test]
+    fn test_update_description_without_material_change_keeps_test_current() {
+        // Test: Validates an identical or whitespace-only description update leaves the test current.
+        // Justification: Regenerating tests costs an LLM call and must not happen for no-op edits.
+        let task = task_with_test("Accept email and password");
+        let task_id = task.id.clone();
+        let mut repo = MockRepo::new();
+        repo.save(task).unwrap();
+        let mut use_case = ManageTaskUseCase::new(repo);
+
+        use_case.update_description(&task_id, "Accept email and password").unwrap();
+        let updated = use_case.update_description(&task_id, "Accept  email and password\n").unwrap();
+
+        assert!(!updated.comprehension_tests.as_ref().unwrap()[0].stale);
+        assert!(!updated.needs_comprehension_test());
+        assert_eq!(updated.revisions.last().unwrap().change_description, "Description updated");
+    }
+
     #[test]
     fn test_get_sorted_tasks() {
         // Test: Validates that the ManageTaskUseCase correctly retrieves tasks with sorting applied.
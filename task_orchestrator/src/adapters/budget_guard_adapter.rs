@@ -0,0 +1,92 @@
+//! Budget-enforcing decorator for the orchestrator's LLM ports.
+//!
+//! `BudgetGuardAdapter` wraps an enhancement or comprehension-test port and
+//! checks the run's `TokenBudget` before every call it forwards. When the
+//! next call would exceed the budget the call is never made and the node
+//! fails with the `BudgetExceeded` message, which halts the flow. The wrapped
+//! adapter is responsible for recording each call's token usage in the same
+//! budget.
+//!
+//! Revision History
+//! - 2026-10-18T02:30:00Z @AI: Check the budget before answer_comprehension_test as well.
+//! - 2026-10-16T20:50:00Z @AI: Initial budget guard for TaskEnhancementPort and ComprehensionTestPort.
+
+/// Port decorator that refuses provider calls once the run's budget is spent.
+///
+/// # Examples
+///
+/// ```
+/// # fn example(inner: std::sync::Arc<dyn task_orchestrator::ports::task_enhancement_port::TaskEnhancementPort>) {
+/// use task_orchestrator::adapters::budget_guard_adapter::BudgetGuardAdapter;
+/// use task_orchestrator::domain::token_budget::TokenBudget;
+///
+/// let budget = std::sync::Arc::new(TokenBudget::new(std::option::Option::Some(50_000), std::option::Option::None, std::collections::HashMap::new()));
+/// let guarded: std::sync::Arc<dyn task_orchestrator::ports::task_enhancement_port::TaskEnhancementPort> =
+///     std::sync::Arc::new(BudgetGuardAdapter::new(inner, budget));
+/// # }
+/// ```
+pub struct BudgetGuardAdapter<P: ?Sized> {
+    inner: std::sync::Arc<P>,
+    budget: std::sync::Arc<crate::domain::token_budget::TokenBudget>,
+}
+
+impl<P: ?Sized> BudgetGuardAdapter<P> {
+    /// Wraps `inner` so every call is checked against `budget` first.
+    pub fn new(inner: std::sync::Arc<P>, budget: std::sync::Arc<crate::domain::token_budget::TokenBudget>) -> Self {
+        Self { inner, budget }
+    }
+
+    /// Returns the budget calls are checked against.
+    pub fn budget(&self) -> &std::sync::Arc<crate::domain::token_budget::TokenBudget> {
+        &self.budget
+    }
+
+    /// Checks the budget, logging the refusal if the call would exceed it.
+    fn check(&self, task_id: &str) -> std::result::Result<(), std::string::String> {
+        self.budget.check().map_err(|exceeded| {
+            tracing::warn!(
+                task_id,
+                tokens_used = exceeded.tokens_used,
+                cost_used = exceeded.cost_used,
+                "token budget exceeded; halting run"
+            );
+            exceeded.to_string()
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::ports::task_enhancement_port::TaskEnhancementPort
+    for BudgetGuardAdapter<dyn crate::ports::task_enhancement_port::TaskEnhancementPort>
+{
+    async fn generate_enhancement(
+        &self,
+        task: &task_manager::domain::task::Task,
+    ) -> std::result::Result<task_manager::domain::enhancement::Enhancement, std::string::String> {
+        self.check(&task.id)?;
+        self.inner.generate_enhancement(task).await
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::ports::comprehension_test_port::ComprehensionTestPort
+    for BudgetGuardAdapter<dyn crate::ports::comprehension_test_port::ComprehensionTestPort>
+{
+    async fn generate_comprehension_test(
+        &self,
+        task: &task_manager::domain::task::Task,
+        test_type: &str,
+    ) -> std::result::Result<task_manager::domain::comprehension_test::ComprehensionTest, std::string::String> {
+        self.check(&task.id)?;
+        self.inner.generate_comprehension_test(task, test_type).await
+    }
+
+    async fn answer_comprehension_test(
+        &self,
+        task: &task_manager::domain::task::Task,
+        test: &task_manager::domain::comprehension_test::ComprehensionTest,
+    ) -> std::result::Result<std::option::Option<std::string::String>, std::string::String> {
+        self.check(&task.id)?;
+        self.inner.answer_comprehension_test(task, test).await
+    }
+}
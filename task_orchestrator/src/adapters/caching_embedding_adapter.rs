@@ -0,0 +1,221 @@
+//! Caching decorator for the EmbeddingPort.
+//!
+//! `CachingEmbeddingAdapter` wraps an embedding adapter and stores every
+//! vector it produces in a `ResponseCache`, keyed by the model, its
+//! dimension, and the exact text (`CacheKey::for_embedding`). Texts that were
+//! embedded before are served from disk; only the misses of a batch go to the
+//! wrapped adapter, in one call. An edited task has different text and is
+//! simply re-embedded. Unreadable entries are treated as misses.
+//!
+//! All-zero vectors are returned but never stored: adapters such as
+//! `RigEmbeddingAdapter` fall back to them when the provider is unreachable,
+//! and caching one would pin the failure for that text permanently.
+//!
+//! Revision History
+//! - 2026-10-18T00:50:00Z @AI: Do not cache all-zero vectors from provider fallbacks.
+//! - 2026-10-17T17:30:00Z @AI: Initial on-disk embedding cache for task similarity.
+
+/// EmbeddingPort decorator that reuses previously computed vectors.
+///
+/// # Examples
+///
+/// ```
+/// # fn example(inner: std::sync::Arc<dyn task_orchestrator::ports::embedding_port::EmbeddingPort + Send + Sync>) {
+/// use task_orchestrator::adapters::caching_embedding_adapter::CachingEmbeddingAdapter;
+/// use task_orchestrator::infrastructure::response_cache::ResponseCache;
+///
+/// let cache = std::sync::Arc::new(ResponseCache::new(".rigger/cache/embeddings", std::option::Option::None));
+/// let cached: std::sync::Arc<dyn task_orchestrator::ports::embedding_port::EmbeddingPort> =
+///     std::sync::Arc::new(CachingEmbeddingAdapter::new(inner, cache));
+/// # }
+/// ```
+pub struct CachingEmbeddingAdapter {
+    inner: std::sync::Arc<dyn crate::ports::embedding_port::EmbeddingPort + std::marker::Send + std::marker::Sync>,
+    cache: std::sync::Arc<crate::infrastructure::response_cache::ResponseCache>,
+}
+
+impl CachingEmbeddingAdapter {
+    /// Wraps `inner` so its vectors are stored in and served from `cache`.
+    pub fn new(
+        inner: std::sync::Arc<dyn crate::ports::embedding_port::EmbeddingPort + std::marker::Send + std::marker::Sync>,
+        cache: std::sync::Arc<crate::infrastructure::response_cache::ResponseCache>,
+    ) -> Self {
+        Self { inner, cache }
+    }
+
+    async fn key(&self, text: &str) -> crate::infrastructure::response_cache::CacheKey {
+        let model = self.inner.embedding_model().unwrap_or_default();
+        crate::infrastructure::response_cache::CacheKey::for_embedding(&model, self.inner.embedding_dimension().await, text)
+    }
+
+    fn lookup(&self, key: &crate::infrastructure::response_cache::CacheKey) -> std::option::Option<std::vec::Vec<f32>> {
+        let cached = self.cache.get(key)?;
+        match serde_json::from_str(&cached) {
+            std::result::Result::Ok(vector) => std::option::Option::Some(vector),
+            std::result::Result::Err(e) => {
+                tracing::warn!(key = key.as_str(), error = %e, "ignoring unreadable embedding cache entry");
+                std::option::Option::None
+            }
+        }
+    }
+
+    fn store(&self, key: &crate::infrastructure::response_cache::CacheKey, vector: &[f32]) {
+        match serde_json::to_string(vector) {
+            std::result::Result::Ok(json) => self.cache.put(key, &json),
+            std::result::Result::Err(e) => tracing::warn!(key = key.as_str(), error = %e, "failed to encode embedding for cache"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::ports::embedding_port::EmbeddingPort for CachingEmbeddingAdapter {
+    async fn generate_embedding(&self, text: &str) -> std::result::Result<std::vec::Vec<f32>, std::string::String> {
+        let mut vectors = self.generate_embeddings(&[text]).await?;
+        vectors.pop().ok_or_else(|| std::string::String::from("Embedding adapter returned no vector"))
+    }
+
+    async fn generate_embeddings(
+        &self,
+        texts: &[&str],
+    ) -> std::result::Result<std::vec::Vec<std::vec::Vec<f32>>, std::string::String> {
+        let mut keys = std::vec::Vec::with_capacity(texts.len());
+        let mut vectors: std::vec::Vec<std::option::Option<std::vec::Vec<f32>>> = std::vec::Vec::with_capacity(texts.len());
+        for text in texts {
+            let key = self.key(text).await;
+            vectors.push(self.lookup(&key));
+            keys.push(key);
+        }
+
+        let misses: std::vec::Vec<usize> = (0..texts.len()).filter(|&i| vectors[i].is_none()).collect();
+        tracing::debug!(hits = texts.len() - misses.len(), misses = misses.len(), "embedding cache lookup");
+        if !misses.is_empty() {
+            let miss_texts: std::vec::Vec<&str> = misses.iter().map(|&i| texts[i]).collect();
+            let computed = self.inner.generate_embeddings(&miss_texts).await?;
+            if computed.len() != misses.len() {
+                return std::result::Result::Err(std::format!(
+                    "Embedding adapter returned {} vectors for {} texts",
+                    computed.len(),
+                    misses.len()
+                ));
+            }
+            for (i, vector) in misses.into_iter().zip(computed) {
+                if vector.iter().all(|value| *value == 0.0) {
+                    tracing::debug!(key = keys[i].as_str(), "not caching zero embedding vector");
+                } else {
+                    self.store(&keys[i], &vector);
+                }
+                vectors[i] = std::option::Option::Some(vector);
+            }
+        }
+
+        std::result::Result::Ok(vectors.into_iter().flatten().collect())
+    }
+
+    async fn embedding_dimension(&self) -> usize {
+        self.inner.embedding_dimension().await
+    }
+
+    fn embedding_model(&self) -> std::option::Option<std::string::String> {
+        self.inner.embedding_model()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    /// Embeds every text as its length and counts the texts it was asked to embed.
+    struct CountingEmbedder {
+        embedded: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::ports::embedding_port::EmbeddingPort for CountingEmbedder {
+        async fn generate_embedding(&self, text: &str) -> std::result::Result<std::vec::Vec<f32>, String> {
+            self.embedded.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            std::result::Result::Ok(std::vec![text.len() as f32, 1.0])
+        }
+
+        async fn generate_embeddings(&self, texts: &[&str]) -> std::result::Result<std::vec::Vec<std::vec::Vec<f32>>, String> {
+            let mut vectors = std::vec::Vec::new();
+            for text in texts {
+                vectors.push(self.generate_embedding(text).await?);
+            }
+            std::result::Result::Ok(vectors)
+        }
+
+        async fn embedding_dimension(&self) -> usize {
+            2
+        }
+    }
+
+    /// Returns zero vectors, like a provider fallback, until `available` is set.
+    struct RecoveringEmbedder {
+        available: std::sync::atomic::AtomicBool,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::ports::embedding_port::EmbeddingPort for RecoveringEmbedder {
+        async fn generate_embedding(&self, _text: &str) -> std::result::Result<std::vec::Vec<f32>, String> {
+            if self.available.load(std::sync::atomic::Ordering::SeqCst) {
+                std::result::Result::Ok(std::vec![0.5, 0.5])
+            } else {
+                std::result::Result::Ok(std::vec![0.0, 0.0])
+            }
+        }
+
+        async fn generate_embeddings(&self, texts: &[&str]) -> std::result::Result<std::vec::Vec<std::vec::Vec<f32>>, String> {
+            let mut vectors = std::vec::Vec::new();
+            for text in texts {
+                vectors.push(self.generate_embedding(text).await?);
+            }
+            std::result::Result::Ok(vectors)
+        }
+
+        async fn embedding_dimension(&self) -> usize {
+            2
+        }
+    }
+
+    #[tokio::test]
+    async fn test_zero_vectors_are_not_cached() {
+        // Test: Validates a zero-vector fallback is returned but not stored, so the text is embedded again once the provider recovers.
+        // Justification: Caching the fallback would leave the text without a usable embedding forever.
+        let dir = std::env::temp_dir().join(std::format!("rigger_embedding_cache_{}", uuid::Uuid::new_v4()));
+        let inner = std::sync::Arc::new(RecoveringEmbedder {
+            available: std::sync::atomic::AtomicBool::new(false),
+        });
+        let adapter = super::CachingEmbeddingAdapter::new(
+            inner.clone(),
+            std::sync::Arc::new(crate::infrastructure::response_cache::ResponseCache::new(&dir, std::option::Option::None)),
+        );
+
+        let down = crate::ports::embedding_port::EmbeddingPort::generate_embedding(&adapter, "task").await.unwrap();
+        inner.available.store(true, std::sync::atomic::Ordering::SeqCst);
+        let recovered = crate::ports::embedding_port::EmbeddingPort::generate_embedding(&adapter, "task").await.unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        std::assert_eq!(down, std::vec![0.0, 0.0]);
+        std::assert_eq!(recovered, std::vec![0.5, 0.5]);
+    }
+
+    #[tokio::test]
+    async fn test_only_uncached_texts_reach_the_wrapped_adapter() {
+        // Test: Validates a second batch embeds only its new text and returns cached vectors in input order.
+        // Justification: Re-embedding every task on each `rig show --similar` would repeat provider calls for unchanged tasks.
+        let dir = std::env::temp_dir().join(std::format!("rigger_embedding_cache_{}", uuid::Uuid::new_v4()));
+        let inner = std::sync::Arc::new(CountingEmbedder {
+            embedded: std::sync::atomic::AtomicUsize::new(0),
+        });
+        let adapter = super::CachingEmbeddingAdapter::new(
+            inner.clone(),
+            std::sync::Arc::new(crate::infrastructure::response_cache::ResponseCache::new(&dir, std::option::Option::None)),
+        );
+
+        let first = crate::ports::embedding_port::EmbeddingPort::generate_embeddings(&adapter, &["a", "bb"]).await.unwrap();
+        let second = crate::ports::embedding_port::EmbeddingPort::generate_embeddings(&adapter, &["bb", "ccc", "a"]).await.unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        std::assert_eq!(first, std::vec![std::vec![1.0, 1.0], std::vec![2.0, 1.0]]);
+        std::assert_eq!(second, std::vec![std::vec![2.0, 1.0], std::vec![3.0, 1.0], std::vec![1.0, 1.0]]);
+        std::assert_eq!(inner.embedded.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+}
@@ -0,0 +1,723 @@
+//! JSONL file metrics collector adapter.
+//!
+//! Appends each inference metric as one JSON line to the file named by
+//! `performance.metrics_file`. The path is a template: `{project}` is replaced
+//! with the project name and `{timestamp}` with the collector's start time, so
+//! concurrent runs of different projects write to distinct files. Missing
+//! directories are created on first write. With a size cap, a file that would
+//! grow past it is rotated to `<stem>.<n>.<ext>` and writing continues in a
+//! fresh file at the configured path. Retry counters are kept in memory.
+//!
+//! All file access goes through one writer thread fed by an mpsc channel, so
+//! concurrent callers can never interleave partial records: each record is a
+//! single whole-line write. The writer fsyncs every `SYNC_EVERY_RECORDS`
+//! records, after `SYNC_INTERVAL` without one, and before shutting down.
+//!
+//! `tail_metrics` reads the newest records without scanning from the top:
+//! it walks the files backwards in fixed-size chunks, newest file first.
+//! `run_paths` finds the files every run has written for a template, so
+//! readers can cover runs whose `{timestamp}` they do not know.
+//!
+//! Revision History
+//! - 2026-10-17T22:00:00Z @AI: Add run_paths listing the files written for a template across runs.
+//! - 2026-10-17T09:10:00Z @AI: Add tail_metrics for reading the last N records and records since a timestamp.
+//! - 2026-10-17T08:50:00Z @AI: Serialize appends through a single writer thread and fsync periodically.
+//! - 2026-10-17T08:30:00Z @AI: Initial JSONL metrics collector with path templates and size-based rotation.
+
+/// Placeholder in the metrics file template replaced by the project name.
+pub const PROJECT_PLACEHOLDER: &str = "{project}";
+
+/// Placeholder in the metrics file template replaced by the collector's start time.
+pub const TIMESTAMP_PLACEHOLDER: &str = "{timestamp}";
+
+/// How `{timestamp}` is rendered in metrics file names.
+const TIMESTAMP_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+/// Records appended between fsyncs.
+const SYNC_EVERY_RECORDS: usize = 64;
+
+/// Longest time appended records stay unsynced.
+const SYNC_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// Bytes read per step when scanning a metrics file backwards.
+const TAIL_CHUNK_BYTES: u64 = 64 * 1024;
+
+/// Resolves a metrics file template to a concrete path.
+///
+/// Characters of the project name other than ASCII letters, digits, `-`, `_`
+/// and `.` are replaced with `_` so a name cannot escape the directory. The
+/// timestamp is rendered as `YYYYMMDDTHHMMSSZ`.
+///
+/// # Examples
+///
+/// ```
+/// # use task_orchestrator::adapters::jsonl_metrics_collector::resolve_metrics_path;
+/// let started_at = chrono::DateTime::parse_from_rfc3339("2026-10-17T08:30:00Z").unwrap().with_timezone(&chrono::Utc);
+/// let path = resolve_metrics_path(".rigger/metrics/{project}-{timestamp}.jsonl", "billing api", started_at);
+/// std::assert_eq!(path, std::path::PathBuf::from(".rigger/metrics/billing_api-20261017T083000Z.jsonl"));
+/// ```
+pub fn resolve_metrics_path(template: &str, project: &str, started_at: chrono::DateTime<chrono::Utc>) -> std::path::PathBuf {
+    std::path::PathBuf::from(
+        template
+            .replace(PROJECT_PLACEHOLDER, &file_safe_project(project))
+            .replace(TIMESTAMP_PLACEHOLDER, &started_at.format(TIMESTAMP_FORMAT).to_string()),
+    )
+}
+
+/// Returns the current file of every run written from `template` for `project`, oldest run first.
+///
+/// Without `{timestamp}` in the template every run shares one file, which
+/// is returned whether or not it exists yet. Otherwise the directory is
+/// listed for files whose name matches the template with any start time;
+/// `{timestamp}` is only matched in the file name.
+pub fn run_paths(template: &str, project: &str) -> std::vec::Vec<std::path::PathBuf> {
+    let resolved = template.replace(PROJECT_PLACEHOLDER, &file_safe_project(project));
+    if !resolved.contains(TIMESTAMP_PLACEHOLDER) {
+        return std::vec![std::path::PathBuf::from(resolved)];
+    }
+    let resolved = std::path::PathBuf::from(resolved);
+    let std::option::Option::Some((prefix, suffix)) = resolved
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.split_once(TIMESTAMP_PLACEHOLDER))
+    else {
+        return std::vec::Vec::new();
+    };
+    let dir = match resolved.parent() {
+        std::option::Option::Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+        _ => std::path::PathBuf::from("."),
+    };
+    let std::result::Result::Ok(entries) = std::fs::read_dir(&dir) else {
+        return std::vec::Vec::new();
+    };
+
+    let mut names: std::vec::Vec<String> = entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| {
+            name.strip_prefix(prefix)
+                .and_then(|rest| rest.strip_suffix(suffix))
+                .is_some_and(|stamp| chrono::NaiveDateTime::parse_from_str(stamp, TIMESTAMP_FORMAT).is_ok())
+        })
+        .collect();
+    // The timestamp format sorts chronologically
+    names.sort();
+    names.into_iter().map(|name| dir.join(name)).collect()
+}
+
+/// Replaces characters of a project name that are unsafe in a file name.
+fn file_safe_project(project: &str) -> String {
+    let project: String = project
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.' { c } else { '_' })
+        .collect();
+    if project.is_empty() || project.chars().all(|c| c == '.') { String::from("default") } else { project }
+}
+
+/// JSONL file implementation of MetricsCollectorPort.
+///
+/// The writer thread starts on first use and stops once the collector is
+/// dropped, after writing and syncing everything already recorded.
+///
+/// # Examples
+///
+/// ```no_run
+/// use task_orchestrator::adapters::jsonl_metrics_collector::JsonlMetricsCollector;
+///
+/// let collector = JsonlMetricsCollector::new(".rigger/metrics/{project}.jsonl", "billing")
+///     .with_max_bytes(10 * 1024 * 1024);
+/// std::assert!(collector.path().ends_with("billing.jsonl"));
+/// ```
+#[derive(Debug)]
+pub struct JsonlMetricsCollector {
+    path: std::path::PathBuf,
+    max_bytes: std::option::Option<u64>,
+    writer: std::sync::OnceLock<std::sync::mpsc::Sender<WriterCommand>>,
+    retries: tokio::sync::RwLock<std::collections::BTreeMap<String, crate::domain::performance_metrics::RetryCounters>>,
+}
+
+impl JsonlMetricsCollector {
+    /// Creates a collector writing to `template` resolved for `project`, started now.
+    pub fn new(template: &str, project: &str) -> Self {
+        Self {
+            path: resolve_metrics_path(template, project, chrono::Utc::now()),
+            max_bytes: std::option::Option::None,
+            writer: std::sync::OnceLock::new(),
+            retries: tokio::sync::RwLock::new(std::collections::BTreeMap::new()),
+        }
+    }
+
+    /// Creates a collector from the `performance` config section for `project`.
+    pub fn from_config(config: &crate::infrastructure::config::PerformanceConfig, project: &str) -> Self {
+        let collector = Self::new(&config.metrics_file, project);
+        match config.metrics_max_bytes {
+            std::option::Option::Some(max_bytes) => collector.with_max_bytes(max_bytes),
+            std::option::Option::None => collector,
+        }
+    }
+
+    /// Rotates the file once an append would take it past `max_bytes`.
+    pub fn with_max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = std::option::Option::Some(max_bytes);
+        self
+    }
+
+    /// Path of the file currently being written.
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+
+    /// Rotated files, oldest first, followed by the current file if it exists.
+    pub fn files(&self) -> std::vec::Vec<std::path::PathBuf> {
+        metrics_files(&self.path)
+    }
+
+    /// Sends `command` to the writer thread, starting it on first use, and waits for its reply.
+    async fn send(
+        &self,
+        command: impl FnOnce(tokio::sync::oneshot::Sender<std::result::Result<(), String>>) -> WriterCommand,
+    ) -> std::result::Result<(), String> {
+        let writer = self.writer.get_or_init(|| {
+            let (tx, rx) = std::sync::mpsc::channel();
+            let file_writer = MetricsFileWriter::new(self.path.clone(), self.max_bytes);
+            // Without the thread, every send below fails with "metrics writer stopped"
+            let _ = std::thread::Builder::new()
+                .name(String::from("metrics-writer"))
+                .spawn(move || file_writer.run(rx));
+            tx
+        });
+        let (done, reply) = tokio::sync::oneshot::channel();
+        writer
+            .send(command(done))
+            .map_err(|_| String::from("Failed to record metric: metrics writer stopped"))?;
+        reply
+            .await
+            .map_err(|_| String::from("Failed to record metric: metrics writer stopped"))?
+    }
+
+    /// Reads every metric from the rotated and current files, oldest first, once pending writes are on disk.
+    async fn read_all(&self) -> std::result::Result<std::vec::Vec<crate::domain::performance_metrics::InferenceMetrics>, String> {
+        self.send(|done| WriterCommand::Flush { done }).await?;
+        let mut metrics = std::vec::Vec::new();
+        for file in self.files() {
+            let content = std::fs::read_to_string(&file)
+                .map_err(|e| std::format!("Failed to read metrics file {}: {}", file.display(), e))?;
+            for line in content.lines().filter(|line| !line.trim().is_empty()) {
+                let metric = serde_json::from_str(line)
+                    .map_err(|e| std::format!("Malformed metrics line in {}: {}", file.display(), e))?;
+                metrics.push(metric);
+            }
+        }
+        std::result::Result::Ok(metrics)
+    }
+}
+
+/// Work for the writer thread; each command replies on `done` once handled.
+#[derive(Debug)]
+enum WriterCommand {
+    /// Appends one serialized record, newline included.
+    Append {
+        line: String,
+        done: tokio::sync::oneshot::Sender<std::result::Result<(), String>>,
+    },
+
+    /// Syncs everything appended so far.
+    Flush {
+        done: tokio::sync::oneshot::Sender<std::result::Result<(), String>>,
+    },
+
+    /// Removes the current and rotated files.
+    Clear {
+        done: tokio::sync::oneshot::Sender<std::result::Result<(), String>>,
+    },
+}
+
+/// Owns the metrics file on the writer thread.
+struct MetricsFileWriter {
+    path: std::path::PathBuf,
+    max_bytes: std::option::Option<u64>,
+    file: std::option::Option<std::fs::File>,
+    unsynced: usize,
+    last_sync: std::time::Instant,
+}
+
+impl MetricsFileWriter {
+    fn new(path: std::path::PathBuf, max_bytes: std::option::Option<u64>) -> Self {
+        Self {
+            path,
+            max_bytes,
+            file: std::option::Option::None,
+            unsynced: 0,
+            last_sync: std::time::Instant::now(),
+        }
+    }
+
+    /// Handles commands until every sender is dropped, syncing on idle and on exit.
+    fn run(mut self, commands: std::sync::mpsc::Receiver<WriterCommand>) {
+        loop {
+            match commands.recv_timeout(SYNC_INTERVAL) {
+                std::result::Result::Ok(WriterCommand::Append { line, done }) => {
+                    let mut result = self.append(&line);
+                    if result.is_ok() && (self.unsynced >= SYNC_EVERY_RECORDS || self.last_sync.elapsed() >= SYNC_INTERVAL) {
+                        result = self.sync();
+                    }
+                    let _ = done.send(result);
+                }
+                std::result::Result::Ok(WriterCommand::Flush { done }) => {
+                    let _ = done.send(self.sync());
+                }
+                std::result::Result::Ok(WriterCommand::Clear { done }) => {
+                    let _ = done.send(self.clear());
+                }
+                std::result::Result::Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {
+                    if let std::result::Result::Err(e) = self.sync() {
+                        tracing::warn!(error = %e, "failed to sync metrics file");
+                    }
+                }
+                std::result::Result::Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => {
+                    if let std::result::Result::Err(e) = self.sync() {
+                        tracing::warn!(error = %e, "failed to sync metrics file");
+                    }
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Writes `line` in one call, rotating first if it would take the file past the cap.
+    fn append(&mut self, line: &str) -> std::result::Result<(), String> {
+        self.rotate_if_needed(line.len() as u64)?;
+        if self.file.is_none() {
+            if let std::option::Option::Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)
+                    .map_err(|e| std::format!("Failed to create metrics directory {}: {}", parent.display(), e))?;
+            }
+            let file = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)
+                .map_err(|e| std::format!("Failed to open metrics file {}: {}", self.path.display(), e))?;
+            self.file = std::option::Option::Some(file);
+        }
+        let file = self.file.as_mut().expect("metrics file opened above");
+        std::io::Write::write_all(file, line.as_bytes())
+            .map_err(|e| std::format!("Failed to write metrics file {}: {}", self.path.display(), e))?;
+        self.unsynced += 1;
+        std::result::Result::Ok(())
+    }
+
+    /// Moves the current file to the next free rotated path if appending `incoming` bytes would exceed the cap.
+    fn rotate_if_needed(&mut self, incoming: u64) -> std::result::Result<(), String> {
+        let std::option::Option::Some(max_bytes) = self.max_bytes else {
+            return std::result::Result::Ok(());
+        };
+        let current = match std::fs::metadata(&self.path) {
+            std::result::Result::Ok(metadata) => metadata.len(),
+            std::result::Result::Err(_) => return std::result::Result::Ok(()),
+        };
+        // An empty file always takes the record, even one larger than the cap
+        if current == 0 || current + incoming <= max_bytes {
+            return std::result::Result::Ok(());
+        }
+        self.sync()?;
+        self.file = std::option::Option::None;
+        let next = (1..).map(|n| rotated_path(&self.path, n)).find(|path| !path.exists()).unwrap_or_default();
+        std::fs::rename(&self.path, &next)
+            .map_err(|e| std::format!("Failed to rotate metrics file {}: {}", self.path.display(), e))
+    }
+
+    /// Flushes appended records to disk.
+    fn sync(&mut self) -> std::result::Result<(), String> {
+        if self.unsynced > 0 && let std::option::Option::Some(file) = &self.file {
+            file.sync_data()
+                .map_err(|e| std::format!("Failed to sync metrics file {}: {}", self.path.display(), e))?;
+        }
+        self.unsynced = 0;
+        self.last_sync = std::time::Instant::now();
+        std::result::Result::Ok(())
+    }
+
+    fn clear(&mut self) -> std::result::Result<(), String> {
+        self.file = std::option::Option::None;
+        self.unsynced = 0;
+        for file in metrics_files(&self.path) {
+            std::fs::remove_file(&file)
+                .map_err(|e| std::format!("Failed to remove metrics file {}: {}", file.display(), e))?;
+        }
+        std::result::Result::Ok(())
+    }
+}
+
+/// Rotated files of `path`, oldest first, followed by `path` itself if it exists.
+fn metrics_files(path: &std::path::Path) -> std::vec::Vec<std::path::PathBuf> {
+    let mut files: std::vec::Vec<std::path::PathBuf> =
+        (1..).map(|n| rotated_path(path, n)).take_while(|rotated| rotated.exists()).collect();
+    if path.exists() {
+        files.push(path.to_path_buf());
+    }
+    files
+}
+
+/// Path of the `n`th rotated file: `metrics.jsonl` becomes `metrics.<n>.jsonl`.
+fn rotated_path(path: &std::path::Path, n: u32) -> std::path::PathBuf {
+    let stem = path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+    let name = match path.extension() {
+        std::option::Option::Some(ext) => std::format!("{}.{}.{}", stem, n, ext.to_string_lossy()),
+        std::option::Option::None => std::format!("{}.{}", stem, n),
+    };
+    path.with_file_name(name)
+}
+
+/// Reads the newest metrics from a metrics file and its rotations.
+///
+/// Files are scanned from the end, newest first, and the scan stops at the
+/// first record older than `since` or once `limit` records are collected,
+/// so the cost depends on how much is returned rather than on the file size.
+/// Records are appended in time order, which is what makes stopping at an
+/// older record safe. Lines that do not parse as a metric are skipped.
+///
+/// # Arguments
+///
+/// * `path` - The metrics file (rotated files next to it are read too)
+/// * `since` - Only return records at or after this time
+/// * `limit` - Only return the last `limit` records
+///
+/// # Returns
+///
+/// The matching records, oldest first.
+///
+/// # Errors
+///
+/// Returns an error if a file exists but cannot be read.
+pub fn tail_metrics(
+    path: &std::path::Path,
+    since: std::option::Option<chrono::DateTime<chrono::Utc>>,
+    limit: std::option::Option<usize>,
+) -> std::result::Result<std::vec::Vec<crate::domain::performance_metrics::InferenceMetrics>, String> {
+    tail_metrics_in_chunks(path, since, limit, TAIL_CHUNK_BYTES)
+}
+
+fn tail_metrics_in_chunks(
+    path: &std::path::Path,
+    since: std::option::Option<chrono::DateTime<chrono::Utc>>,
+    limit: std::option::Option<usize>,
+    chunk_bytes: u64,
+) -> std::result::Result<std::vec::Vec<crate::domain::performance_metrics::InferenceMetrics>, String> {
+    let mut metrics = std::vec::Vec::new();
+    if limit == std::option::Option::Some(0) {
+        return std::result::Result::Ok(metrics);
+    }
+    for file in metrics_files(path).iter().rev() {
+        let flow = for_each_line_reversed(file, chunk_bytes, |line| {
+            let metric: crate::domain::performance_metrics::InferenceMetrics = match serde_json::from_str(line) {
+                std::result::Result::Ok(metric) => metric,
+                std::result::Result::Err(e) => {
+                    tracing::debug!(file = %file.display(), error = %e, "skipping malformed metrics line");
+                    return std::ops::ControlFlow::Continue(());
+                }
+            };
+            if since.is_some_and(|since| metric.timestamp < since) {
+                return std::ops::ControlFlow::Break(());
+            }
+            metrics.push(metric);
+            if limit.is_some_and(|limit| metrics.len() >= limit) {
+                return std::ops::ControlFlow::Break(());
+            }
+            std::ops::ControlFlow::Continue(())
+        })?;
+        if flow.is_break() {
+            break;
+        }
+    }
+    metrics.reverse();
+    std::result::Result::Ok(metrics)
+}
+
+/// Calls `visit` with each non-empty line of `path`, last line first, reading `chunk_bytes` at a time.
+fn for_each_line_reversed(
+    path: &std::path::Path,
+    chunk_bytes: u64,
+    mut visit: impl FnMut(&str) -> std::ops::ControlFlow<()>,
+) -> std::result::Result<std::ops::ControlFlow<()>, String> {
+    let read_error = |e: std::io::Error| std::format!("Failed to read metrics file {}: {}", path.display(), e);
+    let mut file = std::fs::File::open(path).map_err(read_error)?;
+    let mut pos = file.metadata().map_err(read_error)?.len();
+    let mut visit_line = |bytes: &[u8]| {
+        let line = String::from_utf8_lossy(bytes);
+        if line.trim().is_empty() { std::ops::ControlFlow::Continue(()) } else { visit(&line) }
+    };
+
+    // Bytes of the line that straddles the previous chunk's start
+    let mut carry = std::vec::Vec::new();
+    while pos > 0 {
+        let start = pos.saturating_sub(chunk_bytes);
+        let mut chunk = std::vec![0; (pos - start) as usize];
+        std::io::Seek::seek(&mut file, std::io::SeekFrom::Start(start)).map_err(read_error)?;
+        std::io::Read::read_exact(&mut file, &mut chunk).map_err(read_error)?;
+        chunk.extend_from_slice(&carry);
+        pos = start;
+
+        let mut end = chunk.len();
+        while let std::option::Option::Some(newline) = chunk[..end].iter().rposition(|byte| *byte == b'\n') {
+            if visit_line(&chunk[newline + 1..end]).is_break() {
+                return std::result::Result::Ok(std::ops::ControlFlow::Break(()));
+            }
+            end = newline;
+        }
+        chunk.truncate(end);
+        carry = chunk;
+    }
+    std::result::Result::Ok(visit_line(&carry))
+}
+
+#[async_trait::async_trait]
+impl crate::ports::metrics_collector_port::MetricsCollectorPort for JsonlMetricsCollector {
+    async fn record_metric(
+        &self,
+        metric: crate::domain::performance_metrics::InferenceMetrics,
+    ) -> std::result::Result<(), String> {
+        let mut line = serde_json::to_string(&metric).map_err(|e| std::format!("Failed to serialize metric: {}", e))?;
+        line.push('\n');
+        self.send(|done| WriterCommand::Append { line, done }).await
+    }
+
+    async fn get_all_metrics(
+        &self,
+    ) -> std::result::Result<std::vec::Vec<crate::domain::performance_metrics::InferenceMetrics>, String> {
+        self.read_all().await
+    }
+
+    async fn get_metrics_by_provider(
+        &self,
+        provider: &str,
+    ) -> std::result::Result<std::vec::Vec<crate::domain::performance_metrics::InferenceMetrics>, String> {
+        let metrics = self.get_all_metrics().await?;
+        std::result::Result::Ok(metrics.into_iter().filter(|m| m.provider == provider).collect())
+    }
+
+    async fn get_metrics_by_operation(
+        &self,
+        operation_type: &str,
+    ) -> std::result::Result<std::vec::Vec<crate::domain::performance_metrics::InferenceMetrics>, String> {
+        let metrics = self.get_all_metrics().await?;
+        std::result::Result::Ok(metrics.into_iter().filter(|m| m.operation_type == operation_type).collect())
+    }
+
+    async fn get_metrics_by_role(
+        &self,
+        role: crate::domain::model_role::ModelRole,
+    ) -> std::result::Result<std::vec::Vec<crate::domain::performance_metrics::InferenceMetrics>, String> {
+        let metrics = self.get_all_metrics().await?;
+        std::result::Result::Ok(metrics.into_iter().filter(|m| m.role == std::option::Option::Some(role)).collect())
+    }
+
+    async fn record_retry(
+        &self,
+        provider: &str,
+        event: crate::domain::performance_metrics::RetryEvent,
+    ) -> std::result::Result<(), String> {
+        let mut retries = self.retries.write().await;
+        retries.entry(String::from(provider)).or_default().record(event);
+        std::result::Result::Ok(())
+    }
+
+    async fn get_retry_counters(
+        &self,
+    ) -> std::result::Result<std::collections::BTreeMap<String, crate::domain::performance_metrics::RetryCounters>, String> {
+        std::result::Result::Ok(self.retries.read().await.clone())
+    }
+
+    async fn clear_metrics(&self) -> std::result::Result<(), String> {
+        self.send(|done| WriterCommand::Clear { done }).await?;
+        self.retries.write().await.clear();
+        std::result::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn metric(provider: &str) -> crate::domain::performance_metrics::InferenceMetrics {
+        crate::domain::performance_metrics::InferenceMetrics::new(
+            String::from("enhancement"),
+            String::from(provider),
+            String::from("llama3.1"),
+            std::option::Option::None,
+        )
+    }
+
+    fn scratch_dir() -> std::path::PathBuf {
+        std::env::temp_dir().join(std::format!("jsonl_metrics_{}", uuid::Uuid::new_v4()))
+    }
+
+    #[test]
+    fn test_template_gives_each_project_its_own_path() {
+        // Test: Validates {project} and {timestamp} substitution yields distinct paths per project and per start time.
+        // Justification: Concurrent runs of different projects must not write to the same metrics file.
+        let template = ".rigger/metrics/{project}-{timestamp}.jsonl";
+        let started_at = chrono::DateTime::parse_from_rfc3339("2026-10-17T08:30:00Z").unwrap().with_timezone(&chrono::Utc);
+
+        let billing = super::resolve_metrics_path(template, "billing", started_at);
+        let search = super::resolve_metrics_path(template, "search", started_at);
+        let later = super::resolve_metrics_path(template, "billing", started_at + chrono::Duration::seconds(1));
+
+        std::assert_eq!(billing, std::path::PathBuf::from(".rigger/metrics/billing-20261017T083000Z.jsonl"));
+        std::assert_ne!(billing, search);
+        std::assert_ne!(billing, later);
+        std::assert_eq!(
+            super::resolve_metrics_path("{project}.jsonl", "../etc", started_at),
+            std::path::PathBuf::from(".._etc.jsonl")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rotation_starts_a_new_file_past_the_size_cap() {
+        // Test: Validates a missing directory is created and an append past the cap rotates the file, keeping every record.
+        // Justification: Long-running projects must not grow one metrics file without bound or lose records on rotation.
+        let dir = scratch_dir();
+        let template = dir.join("nested").join("{project}.jsonl");
+        let line_len = serde_json::to_string(&metric("ollama")).unwrap().len() as u64 + 1;
+        let collector = super::JsonlMetricsCollector::new(template.to_str().unwrap(), "billing").with_max_bytes(line_len * 2);
+
+        for _ in 0..3 {
+            crate::ports::metrics_collector_port::MetricsCollectorPort::record_metric(&collector, metric("ollama"))
+                .await
+                .unwrap();
+        }
+
+        let rotated = dir.join("nested").join("billing.1.jsonl");
+        std::assert_eq!(collector.files(), [rotated.clone(), collector.path().to_path_buf()]);
+        std::assert_eq!(std::fs::read_to_string(&rotated).unwrap().lines().count(), 2);
+        std::assert_eq!(std::fs::read_to_string(collector.path()).unwrap().lines().count(), 1);
+        let all = crate::ports::metrics_collector_port::MetricsCollectorPort::get_all_metrics(&collector).await.unwrap();
+        std::assert_eq!(all.len(), 3);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+    async fn test_concurrent_records_are_written_as_whole_lines() {
+        // Test: Validates many tasks recording large metrics at once, across rotations, leave only complete, parseable lines.
+        // Justification: Interleaved appends under max_concurrent_tasks corrupted the metrics file.
+        let dir = scratch_dir();
+        let collector = std::sync::Arc::new(
+            super::JsonlMetricsCollector::new(dir.join("{project}.jsonl").to_str().unwrap(), "billing").with_max_bytes(256 * 1024),
+        );
+
+        let mut handles = std::vec::Vec::new();
+        for task in 0..200 {
+            let collector = collector.clone();
+            handles.push(tokio::spawn(async move {
+                let mut record = metric(&std::format!("provider-{}", task % 4));
+                record.error = std::option::Option::Some("x".repeat(4096 + task));
+                crate::ports::metrics_collector_port::MetricsCollectorPort::record_metric(collector.as_ref(), record)
+                    .await
+                    .unwrap();
+            }));
+        }
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        // Every record_metric returned after the writer appended its line
+        let files = collector.files();
+        std::assert!(files.len() > 1, "expected rotation");
+        let mut lines = 0;
+        for file in &files {
+            for line in std::fs::read_to_string(file).unwrap().lines() {
+                let parsed: crate::domain::performance_metrics::InferenceMetrics =
+                    serde_json::from_str(line).unwrap_or_else(|e| panic!("corrupt line in {}: {}", file.display(), e));
+                std::assert!(parsed.error.unwrap().len() >= 4096);
+                lines += 1;
+            }
+        }
+        std::assert_eq!(lines, 200);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    fn fixture(dir: &std::path::Path) -> std::path::PathBuf {
+        let path = dir.join("metrics.jsonl");
+        let start = chrono::DateTime::parse_from_rfc3339("2026-10-17T09:00:00Z").unwrap().with_timezone(&chrono::Utc);
+        let mut lines = std::vec::Vec::new();
+        for minute in 0..6 {
+            let mut record = metric(&std::format!("provider-{}", minute));
+            record.timestamp = start + chrono::Duration::minutes(minute);
+            lines.push(serde_json::to_string(&record).unwrap());
+            if minute == 3 {
+                lines.push(String::from(r#"{"id": "torn", "timestamp": "2026-10-17T09:03:30Z", "operation_"#));
+            }
+        }
+        std::fs::create_dir_all(dir).unwrap();
+        std::fs::write(&path, lines.join("\n") + "\n").unwrap();
+        path
+    }
+
+    fn providers(metrics: &[crate::domain::performance_metrics::InferenceMetrics]) -> std::vec::Vec<&str> {
+        metrics.iter().map(|m| m.provider.as_str()).collect()
+    }
+
+    #[test]
+    fn test_tail_returns_last_records_skipping_corrupt_line() {
+        // Test: Validates tail-N returns the last N records oldest first, skipping a torn line, whatever the chunk size.
+        // Justification: Reading backwards must reassemble lines that straddle chunk boundaries and tolerate corrupt records.
+        let dir = scratch_dir();
+        let path = fixture(&dir);
+
+        for chunk_bytes in [7, 100, super::TAIL_CHUNK_BYTES] {
+            let last = super::tail_metrics_in_chunks(&path, std::option::Option::None, std::option::Option::Some(3), chunk_bytes).unwrap();
+            std::assert_eq!(providers(&last), ["provider-3", "provider-4", "provider-5"], "chunk {}", chunk_bytes);
+            let all = super::tail_metrics_in_chunks(&path, std::option::Option::None, std::option::Option::None, chunk_bytes).unwrap();
+            std::assert_eq!(all.len(), 6, "chunk {}", chunk_bytes);
+        }
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_since_filters_older_records_across_rotations() {
+        // Test: Validates --since returns only records at or after the timestamp, reading into rotated files when needed.
+        // Justification: Recent records may span the current file and its rotations.
+        let dir = scratch_dir();
+        let path = fixture(&dir);
+        std::fs::rename(&path, dir.join("metrics.1.jsonl")).unwrap();
+        let mut newest = metric("provider-6");
+        newest.timestamp = chrono::DateTime::parse_from_rfc3339("2026-10-17T09:06:00Z").unwrap().with_timezone(&chrono::Utc);
+        std::fs::write(&path, serde_json::to_string(&newest).unwrap() + "\n").unwrap();
+
+        let since = chrono::DateTime::parse_from_rfc3339("2026-10-17T09:04:00Z").unwrap().with_timezone(&chrono::Utc);
+        let recent = super::tail_metrics(&path, std::option::Option::Some(since), std::option::Option::None).unwrap();
+        std::assert_eq!(providers(&recent), ["provider-4", "provider-5", "provider-6"]);
+
+        let last_two = super::tail_metrics(&path, std::option::Option::Some(since), std::option::Option::Some(2)).unwrap();
+        std::assert_eq!(providers(&last_two), ["provider-5", "provider-6"]);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_run_paths_finds_each_runs_file_for_the_project() {
+        // Test: Validates run_paths lists every run's file for the project in start order, skipping other projects and rotations.
+        // Justification: `rig metrics` must read all runs of a templated metrics file without knowing their start times.
+        let dir = scratch_dir();
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in [
+            "billing-20261017T093000Z.jsonl",
+            "billing-20261017T083000Z.jsonl",
+            "billing-20261017T083000Z.1.jsonl",
+            "search-20261017T083000Z.jsonl",
+        ] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+        let template = dir.join("{project}-{timestamp}.jsonl");
+
+        std::assert_eq!(
+            super::run_paths(template.to_str().unwrap(), "billing"),
+            [dir.join("billing-20261017T083000Z.jsonl"), dir.join("billing-20261017T093000Z.jsonl")]
+        );
+        std::assert_eq!(
+            super::run_paths(dir.join("{project}.jsonl").to_str().unwrap(), "billing"),
+            [dir.join("billing.jsonl")]
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
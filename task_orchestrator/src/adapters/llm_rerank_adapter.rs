@@ -0,0 +1,148 @@
+//! LLM-backed adapter implementing RerankPort.
+//!
+//! `LlmRerankAdapter` sends the query and numbered passages to a chat model
+//! through the LLMAgentPort and asks for a JSON array with one relevance
+//! score (0-10) per passage. Passages are truncated to keep the prompt, and
+//! so the token cost, bounded. An answer that is not exactly one number per
+//! passage is an error, so the caller can fall back to vector order.
+//!
+//! Revision History
+//! - 2026-10-17T18:10:00Z @AI: Initial LLM re-ranking adapter over LLMAgentPort.
+
+/// Characters of each passage shown to the model.
+const MAX_PASSAGE_CHARS: usize = 1200;
+
+/// Instructions sent as the system message.
+const RERANK_PREAMBLE: &str = "You rank search results. Given a query and numbered passages, \
+    rate how well each passage answers the query from 0 (irrelevant) to 10 (answers it directly). \
+    Reply with only a JSON array of numbers, one per passage, in passage order, e.g. [7, 0, 10].";
+
+/// RerankPort adapter that asks a chat model to score passages.
+pub struct LlmRerankAdapter {
+    agent: std::sync::Arc<dyn crate::ports::llm_agent_port::LLMAgentPort + std::marker::Send + std::marker::Sync>,
+}
+
+impl LlmRerankAdapter {
+    /// Creates an adapter scoring passages with `agent`.
+    pub fn new(
+        agent: std::sync::Arc<dyn crate::ports::llm_agent_port::LLMAgentPort + std::marker::Send + std::marker::Sync>,
+    ) -> Self {
+        Self { agent }
+    }
+
+    /// Builds the user message: the query followed by the numbered, truncated passages.
+    fn build_prompt(query: &str, passages: &[&str]) -> String {
+        let mut prompt = std::format!("Query: {}\n\nPassages:\n", query);
+        for (index, passage) in passages.iter().enumerate() {
+            let truncated: String = passage.chars().take(MAX_PASSAGE_CHARS).collect();
+            prompt.push_str(&std::format!("\n[{}]\n{}\n", index + 1, truncated.trim()));
+        }
+        prompt.push_str(&std::format!("\nReply with a JSON array of {} scores.", passages.len()));
+        prompt
+    }
+
+    /// Reads the scores from the model's reply.
+    ///
+    /// The first `[` ... last `]` span is parsed, so prose or a code fence
+    /// around the array is tolerated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no array is found, it isn't numbers, any score is
+    /// not finite, or the count differs from `expected`.
+    fn parse_scores(reply: &str, expected: usize) -> std::result::Result<std::vec::Vec<f32>, String> {
+        let (start, end) = match (reply.find('['), reply.rfind(']')) {
+            (std::option::Option::Some(start), std::option::Option::Some(end)) if start < end => (start, end),
+            _ => return std::result::Result::Err(std::format!("Re-rank reply has no JSON array: {}", reply.trim())),
+        };
+        let scores: std::vec::Vec<f32> = serde_json::from_str(&reply[start..=end])
+            .map_err(|e| std::format!("Re-rank reply is not an array of numbers: {}", e))?;
+        if scores.len() != expected {
+            return std::result::Result::Err(std::format!(
+                "Re-rank reply has {} scores for {} passages",
+                scores.len(),
+                expected
+            ));
+        }
+        if scores.iter().any(|score| !score.is_finite()) {
+            return std::result::Result::Err(String::from("Re-rank reply contains a non-finite score"));
+        }
+        std::result::Result::Ok(scores)
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::ports::rerank_port::RerankPort for LlmRerankAdapter {
+    async fn score(&self, query: &str, passages: &[&str]) -> std::result::Result<std::vec::Vec<f32>, std::string::String> {
+        if passages.is_empty() {
+            return std::result::Result::Ok(std::vec::Vec::new());
+        }
+
+        let messages = std::vec![
+            crate::ports::llm_agent_port::AgentMessage {
+                role: crate::ports::llm_agent_port::AgentRole::System,
+                content: std::string::String::from(RERANK_PREAMBLE),
+            },
+            crate::ports::llm_agent_port::AgentMessage {
+                role: crate::ports::llm_agent_port::AgentRole::User,
+                content: Self::build_prompt(query, passages),
+            },
+        ];
+        let mut stream = self.agent.chat_with_tools(messages).await?;
+
+        let mut reply = std::string::String::new();
+        while let std::option::Option::Some(token) = stream.recv().await {
+            match token {
+                crate::ports::llm_agent_port::StreamToken::Content(text) => reply.push_str(&text),
+                crate::ports::llm_agent_port::StreamToken::Done => break,
+                crate::ports::llm_agent_port::StreamToken::Error(e) => {
+                    return std::result::Result::Err(std::format!("Re-rank call failed: {}", e));
+                }
+                _ => {}
+            }
+        }
+
+        Self::parse_scores(&reply, passages.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    /// Chat agent that streams a fixed reply.
+    struct ScriptedAgent {
+        reply: &'static str,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::ports::llm_agent_port::LLMAgentPort for ScriptedAgent {
+        async fn chat_with_tools(
+            &self,
+            _messages: std::vec::Vec<crate::ports::llm_agent_port::AgentMessage>,
+        ) -> std::result::Result<tokio::sync::mpsc::Receiver<crate::ports::llm_agent_port::StreamToken>, std::string::String> {
+            let (sender, receiver) = tokio::sync::mpsc::channel(4);
+            sender.send(crate::ports::llm_agent_port::StreamToken::Content(std::string::String::from(self.reply))).await.unwrap();
+            sender.send(crate::ports::llm_agent_port::StreamToken::Done).await.unwrap();
+            std::result::Result::Ok(receiver)
+        }
+
+        async fn cancel_stream(&self) -> std::result::Result<(), std::string::String> {
+            std::result::Result::Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scores_are_read_from_fenced_reply_and_count_is_checked() {
+        // Test: Validates an array wrapped in a code fence is parsed, and a reply with the wrong number of scores is an error.
+        // Justification: Models often add prose or fences; a short answer must not silently drop passages.
+        let fenced = super::LlmRerankAdapter::new(std::sync::Arc::new(ScriptedAgent {
+            reply: "Here you go:\n```json\n[2, 9.5, 0]\n```",
+        }));
+        let short = super::LlmRerankAdapter::new(std::sync::Arc::new(ScriptedAgent { reply: "[4, 1]" }));
+
+        let scores = crate::ports::rerank_port::RerankPort::score(&fenced, "query", &["a", "b", "c"]).await.unwrap();
+        let err = crate::ports::rerank_port::RerankPort::score(&short, "query", &["a", "b", "c"]).await.unwrap_err();
+
+        std::assert_eq!(scores, std::vec![2.0, 9.5, 0.0]);
+        std::assert!(err.contains("2 scores for 3 passages"), "{}", err);
+    }
+}
@@ -5,6 +5,7 @@
 //! process terminates (use database adapter for persistence).
 //!
 //! Revision History
+//! - 2026-10-17T02:10:00Z @AI: Keep per-provider retry counters in memory.
 //! - 2025-11-24T01:10:00Z @AI: Create in-memory metrics collector for Phase 5 Sprint 12 Task 5.11.
 
 /// In-memory metrics collector adapter.
@@ -39,6 +40,7 @@
 #[derive(Debug, Clone)]
 pub struct MemoryMetricsCollector {
     metrics: std::sync::Arc<tokio::sync::RwLock<std::vec::Vec<crate::domain::performance_metrics::InferenceMetrics>>>,
+    retries: std::sync::Arc<tokio::sync::RwLock<std::collections::BTreeMap<String, crate::domain::performance_metrics::RetryCounters>>>,
 }
 
 impl MemoryMetricsCollector {
@@ -54,6 +56,7 @@ impl MemoryMetricsCollector {
     pub fn new() -> Self {
         Self {
             metrics: std::sync::Arc::new(tokio::sync::RwLock::new(std::vec::Vec::new())),
+            retries: std::sync::Arc::new(tokio::sync::RwLock::new(std::collections::BTreeMap::new())),
         }
     }
 
@@ -139,9 +142,27 @@ impl crate::ports::metrics_collector_port::MetricsCollectorPort for MemoryMetric
         std::result::Result::Ok(filtered)
     }
 
+    async fn record_retry(
+        &self,
+        provider: &str,
+        event: crate::domain::performance_metrics::RetryEvent,
+    ) -> std::result::Result<(), String> {
+        let mut retries = self.retries.write().await;
+        retries.entry(provider.to_string()).or_default().record(event);
+        std::result::Result::Ok(())
+    }
+
+    async fn get_retry_counters(
+        &self,
+    ) -> std::result::Result<std::collections::BTreeMap<String, crate::domain::performance_metrics::RetryCounters>, String> {
+        let retries = self.retries.read().await;
+        std::result::Result::Ok(retries.clone())
+    }
+
     async fn clear_metrics(&self) -> std::result::Result<(), String> {
         let mut metrics = self.metrics.write().await;
         metrics.clear();
+        self.retries.write().await.clear();
         std::result::Result::Ok(())
     }
 }
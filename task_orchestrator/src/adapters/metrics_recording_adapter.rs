@@ -0,0 +1,117 @@
+//! Metrics-recording decorator for the orchestrator's LLM ports.
+//!
+//! `MetricsRecordingAdapter` wraps an enhancement or comprehension-test port
+//! and records one `InferenceMetrics` per forwarded call in a metrics
+//! collector: the operation, provider, model, role, how long the call took,
+//! and whether it failed. Token counts are left unset because the ports do
+//! not report them. A collector that fails to record is only logged; the
+//! call's result is returned unchanged.
+//!
+//! Revision History
+//! - 2026-10-18T02:30:00Z @AI: Record answer_comprehension_test calls as comprehension_answer.
+//! - 2026-10-17T21:50:00Z @AI: Initial metrics recording for TaskEnhancementPort and ComprehensionTestPort.
+
+/// Port decorator that records each call's latency and outcome.
+///
+/// # Examples
+///
+/// ```
+/// # fn example(
+/// #     inner: std::sync::Arc<dyn task_orchestrator::ports::task_enhancement_port::TaskEnhancementPort>,
+/// #     collector: std::sync::Arc<dyn task_orchestrator::ports::metrics_collector_port::MetricsCollectorPort>,
+/// # ) {
+/// use task_orchestrator::adapters::metrics_recording_adapter::MetricsRecordingAdapter;
+///
+/// let recorded: std::sync::Arc<dyn task_orchestrator::ports::task_enhancement_port::TaskEnhancementPort> =
+///     std::sync::Arc::new(MetricsRecordingAdapter::new(inner, collector, "ollama", "llama3.1"));
+/// # }
+/// ```
+pub struct MetricsRecordingAdapter<P: ?Sized> {
+    inner: std::sync::Arc<P>,
+    collector: std::sync::Arc<dyn crate::ports::metrics_collector_port::MetricsCollectorPort>,
+    provider: std::string::String,
+    model: std::string::String,
+}
+
+impl<P: ?Sized> MetricsRecordingAdapter<P> {
+    /// Wraps `inner` so every call is recorded in `collector` under `provider` and `model`.
+    pub fn new(
+        inner: std::sync::Arc<P>,
+        collector: std::sync::Arc<dyn crate::ports::metrics_collector_port::MetricsCollectorPort>,
+        provider: &str,
+        model: &str,
+    ) -> Self {
+        Self {
+            inner,
+            collector,
+            provider: std::string::String::from(provider),
+            model: std::string::String::from(model),
+        }
+    }
+
+    /// Records a finished call; failures to record are only logged.
+    async fn record<T>(
+        &self,
+        operation: &str,
+        role: crate::domain::model_role::ModelRole,
+        started: std::time::Instant,
+        result: &std::result::Result<T, std::string::String>,
+    ) {
+        let mut metric = crate::domain::performance_metrics::InferenceMetrics::new(
+            std::string::String::from(operation),
+            self.provider.clone(),
+            self.model.clone(),
+            std::option::Option::Some(role),
+        );
+        metric.duration_ms = std::option::Option::Some(started.elapsed().as_millis() as u64);
+        match result {
+            std::result::Result::Ok(_) => metric.success = true,
+            std::result::Result::Err(error) => metric.record_failure(error.clone()),
+        }
+        if let std::result::Result::Err(e) = self.collector.record_metric(metric).await {
+            tracing::warn!(error = %e, operation, "failed to record inference metric");
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::ports::task_enhancement_port::TaskEnhancementPort
+    for MetricsRecordingAdapter<dyn crate::ports::task_enhancement_port::TaskEnhancementPort>
+{
+    async fn generate_enhancement(
+        &self,
+        task: &task_manager::domain::task::Task,
+    ) -> std::result::Result<task_manager::domain::enhancement::Enhancement, std::string::String> {
+        let started = std::time::Instant::now();
+        let result = self.inner.generate_enhancement(task).await;
+        self.record("enhancement", crate::domain::model_role::ModelRole::Enhancer, started, &result).await;
+        result
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::ports::comprehension_test_port::ComprehensionTestPort
+    for MetricsRecordingAdapter<dyn crate::ports::comprehension_test_port::ComprehensionTestPort>
+{
+    async fn generate_comprehension_test(
+        &self,
+        task: &task_manager::domain::task::Task,
+        test_type: &str,
+    ) -> std::result::Result<task_manager::domain::comprehension_test::ComprehensionTest, std::string::String> {
+        let started = std::time::Instant::now();
+        let result = self.inner.generate_comprehension_test(task, test_type).await;
+        self.record("comprehension_test", crate::domain::model_role::ModelRole::Tester, started, &result).await;
+        result
+    }
+
+    async fn answer_comprehension_test(
+        &self,
+        task: &task_manager::domain::task::Task,
+        test: &task_manager::domain::comprehension_test::ComprehensionTest,
+    ) -> std::result::Result<std::option::Option<std::string::String>, std::string::String> {
+        let started = std::time::Instant::now();
+        let result = self.inner.answer_comprehension_test(task, test).await;
+        self.record("comprehension_answer", crate::domain::model_role::ModelRole::Tester, started, &result).await;
+        result
+    }
+}
@@ -23,7 +23,33 @@
 //!
 //! See docs/MLX_RESEARCH.md for detailed benchmarks.
 //!
+//! # Crash Recovery
+//!
+//! Each request runs in its own Python process. If that process crashes
+//! (nonzero exit, killed by a signal, or a broken pipe), the request is
+//! replayed in a fresh process up to `RestartPolicy::max_restarts` times with
+//! doubling backoff computed by the shared `RetryPolicy`; `with_retry_policy`
+//! takes restart count, backoff, and timeout from a provider's policy.
+//! `MLX_MAX_RESTARTS` overrides the default retry count. When every attempt
+//! fails, the error includes the tail of the last stderr output.
+//! With `with_metrics_collector`, each restart, each recovery, and each
+//! request that runs out of restarts is recorded as a retry event for "mlx".
+//! With `with_budget`, each completed generation is recorded in the run's
+//! `TokenBudget`.
+//!
+//! # Limits
+//!
+//! Each attempt has a deadline (`with_timeout`) and a stdout byte cap
+//! (`with_max_output_bytes`). A child that exceeds either is killed and
+//! reaped, and the request fails with `MlxError::Timeout` or
+//! `MlxError::OutputTooLarge` without a restart.
+//!
 //! Revision History
+//! - 2026-10-17T21:20:00Z @AI: Add with_budget; record each generation's estimated token usage in the run's budget.
+//! - 2026-10-17T15:10:00Z @AI: Compute restart backoff with the shared RetryPolicy; add with_retry_policy.
+//! - 2026-10-17T02:10:00Z @AI: Record restart attempts, recoveries, and exhausted restarts as retry events via an optional metrics collector.
+//! - 2026-10-17T01:10:00Z @AI: Add per-attempt timeout and stdout byte cap; kill and reap the child when either fires; structured MlxError.
+//! - 2026-10-17T00:50:00Z @AI: Supervise the subprocess: restart with backoff on crash, replay the request, report the stderr tail on persistent failure.
 //! - 2025-11-24T00:00:00Z @AI: Create MLX subprocess adapter for Phase 5 Sprint 11 Task 5.8.
 
 /// MLX subprocess adapter for Apple Silicon optimization.
@@ -45,10 +71,157 @@
 ///     std::println!("MLX not available, using Ollama fallback");
 /// }
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct MlxSubprocessAdapter {
     model_name: String,
     python_path: String,
+    restart_policy: RestartPolicy,
+    timeout: std::time::Duration,
+    max_output_bytes: usize,
+    metrics: std::option::Option<std::sync::Arc<dyn crate::ports::metrics_collector_port::MetricsCollectorPort>>,
+    budget: std::option::Option<std::sync::Arc<crate::domain::token_budget::TokenBudget>>,
+}
+
+impl std::fmt::Debug for MlxSubprocessAdapter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MlxSubprocessAdapter")
+            .field("model_name", &self.model_name)
+            .field("python_path", &self.python_path)
+            .field("restart_policy", &self.restart_policy)
+            .field("timeout", &self.timeout)
+            .field("max_output_bytes", &self.max_output_bytes)
+            .field("metrics", &self.metrics.is_some())
+            .field("budget", &self.budget)
+            .finish()
+    }
+}
+
+/// Default deadline for one subprocess attempt, including model loading.
+pub const DEFAULT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(300);
+
+/// Default cap on the stdout bytes read from one subprocess attempt.
+pub const DEFAULT_MAX_OUTPUT_BYTES: usize = 1024 * 1024;
+
+/// Most stderr bytes kept from one attempt; older output is discarded.
+const STDERR_BUFFER_BYTES: usize = 64 * 1024;
+
+/// Error type for MLX subprocess generation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MlxError {
+    /// Python could not be started
+    Spawn(String),
+    /// The subprocess kept crashing until the restart policy was exhausted
+    Crashed {
+        /// Attempts made, including the first
+        attempts: u32,
+        /// Exit status (or I/O error) of the last attempt
+        status: String,
+        /// Last lines of the last attempt's stderr
+        stderr_tail: String,
+    },
+    /// The subprocess did not finish within the timeout and was killed
+    Timeout(std::time::Duration),
+    /// The subprocess wrote more than the stdout cap and was killed
+    OutputTooLarge(usize),
+    /// The subprocess output was not valid UTF-8
+    InvalidOutput(String),
+}
+
+impl std::fmt::Display for MlxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MlxError::Spawn(msg) => write!(f, "Failed to execute Python: {}", msg),
+            MlxError::Crashed { attempts, status, stderr_tail } => write!(
+                f,
+                "MLX generation failed after {} attempt(s), last {}. stderr tail:\n{}",
+                attempts, status, stderr_tail
+            ),
+            MlxError::Timeout(after) => write!(f, "MLX generation timed out after {:?}; subprocess killed", after),
+            MlxError::OutputTooLarge(limit) => write!(f, "MLX output exceeded {} bytes; subprocess killed", limit),
+            MlxError::InvalidOutput(msg) => write!(f, "Invalid UTF-8 output: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for MlxError {}
+
+/// How often a crashed MLX subprocess is restarted before a request fails.
+///
+/// # Examples
+///
+/// ```
+/// use task_orchestrator::adapters::mlx_subprocess_adapter::{MlxSubprocessAdapter, RestartPolicy};
+///
+/// let adapter = MlxSubprocessAdapter::new(String::from("mlx-community/Phi-3-mini-4k-instruct"))
+///     .with_restart_policy(RestartPolicy {
+///         max_restarts: 4,
+///         ..RestartPolicy::default()
+///     });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RestartPolicy {
+    /// Restarts allowed per request after the first attempt (0 disables recovery)
+    pub max_restarts: u32,
+
+    /// Wait before the first restart; doubles after each further crash
+    pub initial_backoff: std::time::Duration,
+
+    /// Upper bound for the wait between restarts
+    pub max_backoff: std::time::Duration,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            max_restarts: 2,
+            initial_backoff: std::time::Duration::from_millis(500),
+            max_backoff: std::time::Duration::from_secs(8),
+        }
+    }
+}
+
+impl RestartPolicy {
+    /// Returns the wait before restart number `restart` (1-based).
+    pub fn backoff(&self, restart: u32) -> std::time::Duration {
+        crate::infrastructure::retry_policy::RetryPolicy {
+            base_delay: self.initial_backoff,
+            max_delay: self.max_backoff,
+            jitter: 0.0,
+            ..crate::infrastructure::retry_policy::RetryPolicy::default()
+        }
+        .delay_for(restart)
+    }
+}
+
+impl From<&crate::infrastructure::retry_policy::RetryPolicy> for RestartPolicy {
+    fn from(policy: &crate::infrastructure::retry_policy::RetryPolicy) -> Self {
+        Self {
+            max_restarts: policy.max_retries,
+            initial_backoff: policy.base_delay,
+            max_backoff: policy.max_delay,
+        }
+    }
+}
+
+/// Number of trailing stderr lines included in a persistent failure error.
+const STDERR_TAIL_LINES: usize = 20;
+
+/// Why a single subprocess attempt failed.
+#[derive(Debug)]
+enum AttemptFailure {
+    /// The process crashed or its pipes broke; a restart may succeed
+    Crashed { status: String, stderr: String },
+
+    /// Retrying cannot help (e.g. the interpreter does not exist, or a limit fired)
+    Fatal(MlxError),
+}
+
+/// Result of reading a subprocess's stdout up to the byte cap.
+enum CappedOutput {
+    /// The stream closed within the cap
+    Complete(std::vec::Vec<u8>),
+    /// The stream exceeded the cap; reading stopped
+    TooLarge,
 }
 
 /// Internal struct for parsing enhancement responses from LLM.
@@ -83,12 +256,81 @@ impl MlxSubprocessAdapter {
         let python_path = std::env::var("PYTHON_PATH")
             .unwrap_or_else(|_| Self::detect_python_path());
 
+        let restart_policy = RestartPolicy {
+            max_restarts: std::env::var("MLX_MAX_RESTARTS")
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(RestartPolicy::default().max_restarts),
+            ..RestartPolicy::default()
+        };
+
         Self {
             model_name,
             python_path,
+            restart_policy,
+            timeout: DEFAULT_TIMEOUT,
+            max_output_bytes: DEFAULT_MAX_OUTPUT_BYTES,
+            metrics: std::option::Option::None,
+            budget: std::option::Option::None,
+        }
+    }
+
+    /// Records restarts and their outcomes as "mlx" retry events in `collector`.
+    pub fn with_metrics_collector(
+        mut self,
+        collector: std::sync::Arc<dyn crate::ports::metrics_collector_port::MetricsCollectorPort>,
+    ) -> Self {
+        self.metrics = std::option::Option::Some(collector);
+        self
+    }
+
+    /// Records the usage of every completed generation in `budget`.
+    pub fn with_budget(mut self, budget: std::sync::Arc<crate::domain::token_budget::TokenBudget>) -> Self {
+        self.budget = std::option::Option::Some(budget);
+        self
+    }
+
+    /// Records a completed generation in the run's budget, if one is set.
+    fn record_usage(&self, operation: &str, prompt: &str, response: &str) {
+        if let std::option::Option::Some(budget) = &self.budget {
+            budget.record_exchange(operation, "mlx", &self.model_name, prompt, response);
         }
     }
 
+    /// Sets the deadline for one subprocess attempt.
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the most stdout bytes accepted from one subprocess attempt.
+    pub fn with_max_output_bytes(mut self, max_output_bytes: usize) -> Self {
+        self.max_output_bytes = max_output_bytes;
+        self
+    }
+
+    /// Sets how crashed subprocesses are restarted.
+    pub fn with_restart_policy(mut self, restart_policy: RestartPolicy) -> Self {
+        self.restart_policy = restart_policy;
+        self
+    }
+
+    /// Takes the restart count, backoff, and per-attempt timeout from a provider's retry policy.
+    pub fn with_retry_policy(self, retry_policy: crate::infrastructure::retry_policy::RetryPolicy) -> Self {
+        self.with_restart_policy(RestartPolicy::from(&retry_policy))
+            .with_timeout(retry_policy.timeout)
+    }
+
+    /// Overrides the Python executable used to run MLX.
+    ///
+    /// # Arguments
+    ///
+    /// * `python_path` - Interpreter path or name on `PATH`
+    pub fn with_python_path(mut self, python_path: String) -> Self {
+        self.python_path = python_path;
+        self
+    }
+
     /// Detects the Python executable path.
     ///
     /// Tries `python3` first, then falls back to `python`.
@@ -200,11 +442,13 @@ impl MlxSubprocessAdapter {
     /// # Errors
     ///
     /// Returns error if:
-    /// - Python execution fails
-    /// - mlx-lm is not installed
-    /// - Model loading fails
-    /// - Generation fails
-    async fn generate_text(&self, prompt: &str, max_tokens: usize) -> std::result::Result<String, String> {
+    /// - Python cannot be started (`MlxError::Spawn`)
+    /// - The subprocess still fails after the restart policy is exhausted
+    ///   (`MlxError::Crashed`: missing mlx-lm, model loading or generation failures)
+    /// - An attempt exceeds the timeout or stdout cap (`MlxError::Timeout`,
+    ///   `MlxError::OutputTooLarge`)
+    /// - The output is not valid UTF-8 (`MlxError::InvalidOutput`)
+    async fn generate_text(&self, prompt: &str, max_tokens: usize) -> std::result::Result<String, MlxError> {
         // Escape prompt for Python string (basic escaping)
         let escaped_prompt = prompt.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n");
 
@@ -237,23 +481,206 @@ except Exception as e:
             max_tokens = max_tokens
         );
 
-        // Execute Python script via tokio async subprocess
-        let output = tokio::process::Command::new(&self.python_path)
+        self.run_supervised(&script).await
+    }
+
+    /// Runs a script, replaying it in a fresh subprocess after each crash.
+    ///
+    /// # Errors
+    ///
+    /// Returns the last exit status and stderr tail once `max_restarts`
+    /// restarts have failed, or immediately for failures a restart cannot fix.
+    async fn run_supervised(&self, script: &str) -> std::result::Result<String, MlxError> {
+        let mut attempt: u32 = 0;
+
+        loop {
+            attempt += 1;
+            let (status, stderr) = match self.run_once(script).await {
+                std::result::Result::Ok(stdout) => {
+                    if attempt > 1 {
+                        tracing::info!(attempt, "MLX subprocess recovered after restart");
+                        self.record_retry(crate::domain::performance_metrics::RetryEvent::SucceededAfterRetry).await;
+                    }
+                    return std::result::Result::Ok(stdout);
+                }
+                std::result::Result::Err(AttemptFailure::Fatal(error)) => {
+                    return std::result::Result::Err(error);
+                }
+                std::result::Result::Err(AttemptFailure::Crashed { status, stderr }) => (status, stderr),
+            };
+
+            if attempt > self.restart_policy.max_restarts {
+                self.record_retry(crate::domain::performance_metrics::RetryEvent::Exhausted).await;
+                return std::result::Result::Err(MlxError::Crashed {
+                    attempts: attempt,
+                    status,
+                    stderr_tail: Self::stderr_tail(&stderr),
+                });
+            }
+
+            let backoff = self.restart_policy.backoff(attempt);
+            tracing::warn!(attempt, %status, backoff_ms = backoff.as_millis() as u64, "MLX subprocess crashed; restarting");
+            self.record_retry(crate::domain::performance_metrics::RetryEvent::Attempt).await;
+            tokio::time::sleep(backoff).await;
+        }
+    }
+
+    /// Records a retry event for "mlx" if a metrics collector is set; failures are only logged.
+    async fn record_retry(&self, event: crate::domain::performance_metrics::RetryEvent) {
+        let std::option::Option::Some(metrics) = &self.metrics else {
+            return;
+        };
+        if let std::result::Result::Err(e) = metrics.record_retry("mlx", event).await {
+            tracing::warn!(error = %e, "failed to record MLX retry event");
+        }
+    }
+
+    /// Runs a script once in a new Python subprocess.
+    ///
+    /// Kills and reaps the child if it outlives the timeout or exceeds the
+    /// stdout cap.
+    async fn run_once(&self, script: &str) -> std::result::Result<String, AttemptFailure> {
+        let deadline = tokio::time::Instant::now() + self.timeout;
+        let mut child = match tokio::process::Command::new(&self.python_path)
             .arg("-c")
-            .arg(&script)
-            .output()
-            .await
-            .map_err(|e| std::format!("Failed to execute Python: {}", e))?;
+            .arg(script)
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+        {
+            std::result::Result::Ok(child) => child,
+            std::result::Result::Err(e) if std::matches!(
+                e.kind(),
+                std::io::ErrorKind::NotFound | std::io::ErrorKind::PermissionDenied
+            ) => {
+                return std::result::Result::Err(AttemptFailure::Fatal(MlxError::Spawn(e.to_string())));
+            }
+            std::result::Result::Err(e) => {
+                return std::result::Result::Err(AttemptFailure::Crashed {
+                    status: std::format!("I/O error: {}", e),
+                    stderr: String::new(),
+                });
+            }
+        };
+
+        // Drain stderr concurrently so a chatty child never blocks on a full pipe
+        let stderr_task = child.stderr.take().map(|stderr| tokio::spawn(Self::read_stderr(stderr)));
+        let stdout = match child.stdout.take() {
+            std::option::Option::Some(stdout) => stdout,
+            std::option::Option::None => {
+                Self::kill_and_reap(&mut child).await;
+                return std::result::Result::Err(AttemptFailure::Crashed {
+                    status: String::from("I/O error: stdout was not captured"),
+                    stderr: String::new(),
+                });
+            }
+        };
+
+        let read = tokio::time::timeout_at(deadline, Self::read_capped(stdout, self.max_output_bytes)).await;
+        let stdout = match read {
+            std::result::Result::Err(_) => {
+                Self::kill_and_reap(&mut child).await;
+                return std::result::Result::Err(AttemptFailure::Fatal(MlxError::Timeout(self.timeout)));
+            }
+            std::result::Result::Ok(std::result::Result::Ok(CappedOutput::TooLarge)) => {
+                Self::kill_and_reap(&mut child).await;
+                return std::result::Result::Err(AttemptFailure::Fatal(MlxError::OutputTooLarge(self.max_output_bytes)));
+            }
+            std::result::Result::Ok(std::result::Result::Err(e)) => {
+                Self::kill_and_reap(&mut child).await;
+                return std::result::Result::Err(AttemptFailure::Crashed {
+                    status: std::format!("I/O error: {}", e),
+                    stderr: Self::join_stderr(stderr_task).await,
+                });
+            }
+            std::result::Result::Ok(std::result::Result::Ok(CappedOutput::Complete(bytes))) => bytes,
+        };
+
+        let status = match tokio::time::timeout_at(deadline, child.wait()).await {
+            std::result::Result::Err(_) => {
+                Self::kill_and_reap(&mut child).await;
+                return std::result::Result::Err(AttemptFailure::Fatal(MlxError::Timeout(self.timeout)));
+            }
+            std::result::Result::Ok(std::result::Result::Err(e)) => {
+                return std::result::Result::Err(AttemptFailure::Crashed {
+                    status: std::format!("I/O error: {}", e),
+                    stderr: Self::join_stderr(stderr_task).await,
+                });
+            }
+            std::result::Result::Ok(std::result::Result::Ok(status)) => status,
+        };
+
+        if !status.success() {
+            return std::result::Result::Err(AttemptFailure::Crashed {
+                status: status.to_string(),
+                stderr: Self::join_stderr(stderr_task).await,
+            });
+        }
+
+        String::from_utf8(stdout)
+            .map_err(|e| AttemptFailure::Fatal(MlxError::InvalidOutput(e.to_string())))
+    }
 
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            return std::result::Result::Err(std::format!("MLX generation failed: {}", stderr));
+    /// Kills the child and waits for it so it does not linger as a zombie.
+    async fn kill_and_reap(child: &mut tokio::process::Child) {
+        // `kill` sends SIGKILL and then waits for the exit status
+        if let std::result::Result::Err(e) = child.kill().await {
+            tracing::warn!(error = %e, "failed to kill MLX subprocess");
         }
+    }
+
+    /// Reads stdout until EOF, stopping as soon as more than `cap` bytes arrive.
+    async fn read_capped(
+        mut stdout: tokio::process::ChildStdout,
+        cap: usize,
+    ) -> std::io::Result<CappedOutput> {
+        let mut output = std::vec::Vec::new();
+        let mut chunk = [0u8; 8192];
+        loop {
+            let read = tokio::io::AsyncReadExt::read(&mut stdout, &mut chunk).await?;
+            if read == 0 {
+                return std::result::Result::Ok(CappedOutput::Complete(output));
+            }
+            if output.len() + read > cap {
+                return std::result::Result::Ok(CappedOutput::TooLarge);
+            }
+            output.extend_from_slice(&chunk[..read]);
+        }
+    }
+
+    /// Reads stderr until EOF, keeping only the last `STDERR_BUFFER_BYTES`.
+    async fn read_stderr(mut stderr: tokio::process::ChildStderr) -> String {
+        let mut buffer = std::vec::Vec::new();
+        let mut chunk = [0u8; 8192];
+        while let std::result::Result::Ok(read) = tokio::io::AsyncReadExt::read(&mut stderr, &mut chunk).await {
+            if read == 0 {
+                break;
+            }
+            buffer.extend_from_slice(&chunk[..read]);
+            if buffer.len() > STDERR_BUFFER_BYTES {
+                buffer.drain(..buffer.len() - STDERR_BUFFER_BYTES);
+            }
+        }
+        String::from_utf8_lossy(&buffer).into_owned()
+    }
 
-        let result = String::from_utf8(output.stdout)
-            .map_err(|e| std::format!("Invalid UTF-8 output: {}", e))?;
+    /// Waits for the stderr reader spawned by `run_once`.
+    async fn join_stderr(task: std::option::Option<tokio::task::JoinHandle<String>>) -> String {
+        match task {
+            std::option::Option::Some(task) => task.await.unwrap_or_default(),
+            std::option::Option::None => String::new(),
+        }
+    }
 
-        std::result::Result::Ok(result)
+    /// Returns the last `STDERR_TAIL_LINES` lines of stderr output.
+    fn stderr_tail(stderr: &str) -> String {
+        let lines: std::vec::Vec<&str> = stderr.trim_end().lines().collect();
+        if lines.is_empty() {
+            return String::from("(empty)");
+        }
+        lines[lines.len().saturating_sub(STDERR_TAIL_LINES)..].join("\n")
     }
 }
 
@@ -280,7 +707,8 @@ JSON Response:"#,
         );
 
         // Generate enhancement via MLX
-        let response = self.generate_text(&prompt, 256).await?;
+        let response = self.generate_text(&prompt, 256).await.map_err(|e| e.to_string())?;
+        self.record_usage("enhancement", &prompt, &response);
 
         // Parse JSON response (use same pattern as OllamaEnhancementAdapter)
         let parsed = Self::parse_enhancement_from_response(&response)?;
@@ -321,7 +749,8 @@ JSON Array:"#,
         );
 
         // Generate decomposition via MLX (Orca-2 optimized)
-        let response = self.generate_text(&prompt, 512).await?;
+        let response = self.generate_text(&prompt, 512).await.map_err(|e| e.to_string())?;
+        self.record_usage("decomposition", &prompt, &response);
 
         // Parse JSON array into subtasks (use same pattern as RigTaskDecompositionAdapter)
         let extractions = Self::parse_subtasks_from_response(&response)?;
@@ -357,6 +786,27 @@ mod tests {
         std::assert!(!adapter.python_path.is_empty());
     }
 
+    #[test]
+    fn test_retry_policy_sets_restarts_backoff_and_timeout() {
+        // Test: Validates with_retry_policy maps retries, delays, and timeout, and restart backoff doubles up to the cap.
+        // Justification: MLX must follow the provider's configured limits like every other adapter.
+        let policy = crate::infrastructure::retry_policy::RetryPolicy {
+            max_retries: 5,
+            base_delay: std::time::Duration::from_millis(100),
+            max_delay: std::time::Duration::from_millis(300),
+            timeout: std::time::Duration::from_secs(30),
+            ..crate::infrastructure::retry_policy::RetryPolicy::default()
+        };
+
+        let adapter = MlxSubprocessAdapter::new(String::from("fake-model")).with_retry_policy(policy);
+
+        std::assert_eq!(adapter.restart_policy.max_restarts, 5);
+        std::assert_eq!(adapter.timeout, std::time::Duration::from_secs(30));
+        std::assert_eq!(adapter.restart_policy.backoff(1), std::time::Duration::from_millis(100));
+        std::assert_eq!(adapter.restart_policy.backoff(2), std::time::Duration::from_millis(200));
+        std::assert_eq!(adapter.restart_policy.backoff(3), std::time::Duration::from_millis(300));
+    }
+
     #[test]
     fn test_is_available_detects_platform() {
         // Test: Validates platform detection logic.
@@ -377,6 +827,211 @@ mod tests {
         }
     }
 
+    /// Writes an executable fake Python that crashes `crashes` times, then
+    /// prints an enhancement JSON. Returns the script path.
+    #[cfg(unix)]
+    fn write_fake_python(dir: &std::path::Path, crashes: usize) -> std::path::PathBuf {
+        let script = dir.join("fake_python.sh");
+        let body = std::format!(
+            r#"#!/bin/sh
+count_file="{dir}/attempts"
+count=$(cat "$count_file" 2>/dev/null || echo 0)
+count=$((count + 1))
+echo "$count" > "$count_file"
+if [ "$count" -le {crashes} ]; then
+  echo "loading model" >&2
+  echo "Segmentation fault: metal kernel crashed on attempt $count" >&2
+  exit 139
+fi
+printf '{{"enhancement_type": "clarify", "content": "recovered"}}'
+"#,
+            dir = dir.display(),
+            crashes = crashes
+        );
+        std::fs::write(&script, body).unwrap();
+        std::fs::set_permissions(&script, std::os::unix::fs::PermissionsExt::from_mode(0o755)).unwrap();
+        script
+    }
+
+    #[cfg(unix)]
+    fn fake_adapter(dir: &std::path::Path, crashes: usize, max_restarts: u32) -> MlxSubprocessAdapter {
+        MlxSubprocessAdapter::new(String::from("fake-model"))
+            .with_python_path(write_fake_python(dir, crashes).display().to_string())
+            .with_restart_policy(RestartPolicy {
+                max_restarts,
+                initial_backoff: std::time::Duration::from_millis(1),
+                max_backoff: std::time::Duration::from_millis(4),
+            })
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_recovers_after_subprocess_crash() {
+        // Test: Validates a subprocess that exits nonzero once is restarted and the request replayed successfully.
+        // Justification: A single MLX crash must not fail the whole orchestration run.
+        let dir = std::env::temp_dir().join(std::format!("mlx_recover_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let adapter = fake_adapter(&dir, 1, 2);
+
+        let task = task_manager::domain::task::Task::from_action_item(
+            &transcript_extractor::domain::action_item::ActionItem {
+                title: String::from("Write docs"),
+                assignee: std::option::Option::None,
+                due_date: std::option::Option::None,
+            },
+            std::option::Option::None,
+        );
+        let result = crate::ports::task_enhancement_port::TaskEnhancementPort::generate_enhancement(&adapter, &task).await;
+
+        let attempts = std::fs::read_to_string(dir.join("attempts")).unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+        std::assert_eq!(result.unwrap().content, "recovered");
+        std::assert_eq!(attempts.trim(), "2");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_completed_generation_is_recorded_in_budget() {
+        // Test: Validates an enhancement generated through MLX adds one call with nonzero tokens to the run's budget.
+        // Justification: A run budget cannot stop MLX runs unless the adapter reports what each call used.
+        let dir = std::env::temp_dir().join(std::format!("mlx_budget_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let budget = std::sync::Arc::new(crate::domain::token_budget::TokenBudget::new(
+            std::option::Option::None,
+            std::option::Option::None,
+            std::collections::HashMap::new(),
+        ));
+        let adapter = fake_adapter(&dir, 0, 0).with_budget(budget.clone());
+        let task = task_manager::domain::task::Task::from_action_item(
+            &transcript_extractor::domain::action_item::ActionItem {
+                title: String::from("Write docs"),
+                assignee: std::option::Option::None,
+                due_date: std::option::Option::None,
+            },
+            std::option::Option::None,
+        );
+
+        let result = crate::ports::task_enhancement_port::TaskEnhancementPort::generate_enhancement(&adapter, &task).await;
+
+        std::fs::remove_dir_all(&dir).ok();
+        std::assert!(result.is_ok());
+        let usage = budget.usage();
+        let model = &usage.models()["fake-model"];
+        std::assert_eq!(model.calls, 1);
+        std::assert!(model.input_tokens > 0 && model.output_tokens > 0);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_persistent_crash_reports_stderr_tail() {
+        // Test: Validates that once restarts are exhausted the error names the attempts and includes stderr.
+        // Justification: Users need the subprocess's own error output to diagnose a broken MLX install.
+        let dir = std::env::temp_dir().join(std::format!("mlx_persistent_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let adapter = fake_adapter(&dir, 10, 2);
+
+        let error = adapter.generate_text("hello", 8).await.unwrap_err();
+
+        let attempts = std::fs::read_to_string(dir.join("attempts")).unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+        std::assert_eq!(attempts.trim(), "3");
+        std::assert!(std::matches!(&error, MlxError::Crashed { attempts: 3, .. }), "unexpected error: {:?}", error);
+        std::assert!(error.to_string().contains("metal kernel crashed on attempt 3"), "unexpected error: {}", error);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_restarts_are_recorded_as_retry_metrics() {
+        // Test: Validates a fail-then-succeed request and an always-fail request update the "mlx" retry counters.
+        // Justification: Retry counters are how users spot a flaky MLX install, so each loop outcome must be counted once.
+        let collector = std::sync::Arc::new(crate::adapters::memory_metrics_collector::MemoryMetricsCollector::new());
+        let metrics: std::sync::Arc<dyn crate::ports::metrics_collector_port::MetricsCollectorPort> = collector.clone();
+
+        let recover_dir = std::env::temp_dir().join(std::format!("mlx_retry_ok_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&recover_dir).unwrap();
+        let recovering = fake_adapter(&recover_dir, 1, 2).with_metrics_collector(metrics.clone());
+        std::assert!(recovering.generate_text("hello", 8).await.is_ok());
+
+        let fail_dir = std::env::temp_dir().join(std::format!("mlx_retry_fail_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&fail_dir).unwrap();
+        let failing = fake_adapter(&fail_dir, 10, 2).with_metrics_collector(metrics);
+        std::assert!(failing.generate_text("hello", 8).await.is_err());
+
+        std::fs::remove_dir_all(&recover_dir).ok();
+        std::fs::remove_dir_all(&fail_dir).ok();
+        let counters = crate::ports::metrics_collector_port::MetricsCollectorPort::get_retry_counters(collector.as_ref())
+            .await
+            .unwrap();
+        std::assert_eq!(
+            counters["mlx"],
+            crate::domain::performance_metrics::RetryCounters { attempts: 3, successes_after_retry: 1, exhausted_retries: 1 }
+        );
+    }
+
+    /// Writes an executable fake Python that records its PID, then replaces
+    /// itself with `command` so the recorded PID is the process to be killed.
+    #[cfg(unix)]
+    fn write_exec_script(dir: &std::path::Path, command: &str) -> std::path::PathBuf {
+        let script = dir.join("fake_python.sh");
+        std::fs::write(
+            &script,
+            std::format!("#!/bin/sh\necho $$ > \"{}/pid\"\nexec {}\n", dir.display(), command),
+        )
+        .unwrap();
+        std::fs::set_permissions(&script, std::os::unix::fs::PermissionsExt::from_mode(0o755)).unwrap();
+        script
+    }
+
+    /// Returns true if a process with this PID still exists, zombies included.
+    #[cfg(unix)]
+    fn process_exists(dir: &std::path::Path) -> bool {
+        let pid = std::fs::read_to_string(dir.join("pid")).unwrap();
+        std::process::Command::new("kill")
+            .args(["-0", pid.trim()])
+            .stderr(std::process::Stdio::null())
+            .status()
+            .unwrap()
+            .success()
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_timeout_kills_and_reaps_child() {
+        // Test: Validates a subprocess that sleeps past the timeout fails with Timeout and is gone afterwards.
+        // Justification: A hung MLX process must not stall the run or leak as a zombie.
+        let dir = std::env::temp_dir().join(std::format!("mlx_timeout_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let adapter = MlxSubprocessAdapter::new(String::from("fake-model"))
+            .with_python_path(write_exec_script(&dir, "sleep 30").display().to_string())
+            .with_timeout(std::time::Duration::from_millis(300));
+
+        let started = std::time::Instant::now();
+        let error = adapter.generate_text("hello", 8).await.unwrap_err();
+
+        std::assert_eq!(error, MlxError::Timeout(std::time::Duration::from_millis(300)));
+        std::assert!(started.elapsed() < std::time::Duration::from_secs(10));
+        std::assert!(!process_exists(&dir), "timed-out child should be killed and reaped");
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_output_cap_kills_child() {
+        // Test: Validates a subprocess that floods stdout fails with OutputTooLarge and is gone afterwards.
+        // Justification: Unbounded output must not balloon memory, and the writer must not keep running.
+        let dir = std::env::temp_dir().join(std::format!("mlx_output_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let adapter = MlxSubprocessAdapter::new(String::from("fake-model"))
+            .with_python_path(write_exec_script(&dir, "yes flood").display().to_string())
+            .with_max_output_bytes(64 * 1024);
+
+        let error = adapter.generate_text("hello", 8).await.unwrap_err();
+
+        std::assert_eq!(error, MlxError::OutputTooLarge(64 * 1024));
+        std::assert!(!process_exists(&dir), "flooding child should be killed and reaped");
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
     #[tokio::test]
     #[ignore] // Requires MLX-LM installation
     async fn test_generate_text_integration() {
@@ -5,6 +5,16 @@
 //! architecture and are used by the graph nodes to perform work.
 //!
 //! Revision History
+//! - 2026-10-17T21:50:00Z @AI: Add metrics_recording_adapter recording each orchestrator port call.
+//! - 2026-10-17T18:10:00Z @AI: Add llm_rerank_adapter scoring search hits through LLMAgentPort.
+//! - 2026-10-17T17:30:00Z @AI: Add caching_embedding_adapter reusing stored task embeddings.
+//! - 2026-10-17T13:30:00Z @AI: Add sqlite_page_validator_adapter storing ETag/Last-Modified per crawled URL.
+//! - 2026-10-17T13:10:00Z @AI: Add sqlite_crawl_frontier_adapter persisting crawl frontiers for --resume.
+//! - 2026-10-17T08:30:00Z @AI: Add jsonl_metrics_collector writing metrics to a templated, rotating JSONL file.
+//! - 2026-10-17T08:10:00Z @AI: Add recording_adapter and replay_adapter for replayable run logs.
+//! - 2026-10-17T00:30:00Z @AI: Add sqlite_conversation_adapter for persisted chat agent history.
+//! - 2026-10-16T20:50:00Z @AI: Add budget_guard_adapter to enforce per-run token budgets before provider calls.
+//! - 2026-10-16T19:10:00Z @AI: Add reqwest_model_list_adapter for `rig models`.
 //! - 2025-12-03T00:00:00Z @AI: Add rig_agent_adapter for chain-of-thought chat agent implementation.
 //! - 2025-11-30T20:15:00Z @AI: Add reqwest_web_crawler for Phase 3 artifact generator.
 //! - 2025-11-30T11:20:00Z @AI: Add rig_vision_adapter for Phase 5 image processing implementation.
@@ -25,7 +35,18 @@ pub mod provider_factory;
 pub mod mlx_subprocess_adapter;
 pub mod memory_metrics_collector;
 pub mod sqlite_metrics_collector;
+pub mod jsonl_metrics_collector;
 pub mod rig_embedding_adapter;
+pub mod caching_embedding_adapter;
+pub mod llm_rerank_adapter;
 pub mod rig_vision_adapter;
 pub mod reqwest_web_crawler;
+pub mod sqlite_crawl_frontier_adapter;
+pub mod sqlite_page_validator_adapter;
 pub mod rig_agent_adapter;
+pub mod reqwest_model_list_adapter;
+pub mod budget_guard_adapter;
+pub mod metrics_recording_adapter;
+pub mod sqlite_conversation_adapter;
+pub mod recording_adapter;
+pub mod replay_adapter;
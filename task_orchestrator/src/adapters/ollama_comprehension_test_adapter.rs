@@ -2,8 +2,23 @@
 //!
 //! This adapter uses Rig's Completion API to generate comprehension tests via LLM,
 //! with tolerant JSON parsing to handle schema variations and noisy responses.
+//! If Ollama is not running, the fallback test is logged with instructions to
+//! start it and pull the model. With `with_auto_pull(true)`, a model Ollama
+//! has not pulled yet is downloaded and the call retried once. Every call is
+//! timed out and retried under the adapter's `RetryPolicy`. With
+//! `with_budget`, each completed call is recorded in the run's `TokenBudget`.
+//! The same model answers the generated test for CheckTestResultNode to
+//! grade; if that call fails, no answer is returned and the check falls back
+//! to its heuristic.
 //!
 //! Revision History
+//! - 2026-10-18T02:30:00Z @AI: Implement answer_comprehension_test: the agent answers the test from the task and its latest enhancement.
+//! - 2026-10-17T21:20:00Z @AI: Add with_budget; record each call's estimated token usage in the run's budget.
+//! - 2026-10-17T15:10:00Z @AI: Add with_retry_policy; time out and retry calls under the shared RetryPolicy.
+//! - 2026-10-17T14:50:00Z @AI: Add with_auto_pull; pull a missing model and retry once.
+//! - 2026-10-17T14:30:00Z @AI: Add with_base_url; log a friendly error when Ollama refuses the connection.
+//! - 2026-10-17T06:50:00Z @AI: Leave description_hash unset and stale false; ComprehensionTestNode stamps the hash.
+//! - 2026-10-16T19:50:00Z @AI: Time the LLM call in a `provider_call` span; log fallback as a warning.
 //! - 2025-11-23T16:00:00Z @AI: Integrate tolerant parser for robust JSON handling (Phase 1 Sprint 2 Task 1.8).
 //! - 2025-11-23T15:00:00Z @AI: Upgrade to use Rig Extractor with JSON Schema enforcement (Phase 1 Sprint 3).
 //! - 2025-11-23T14:50:00Z @AI: Upgrade to use Rig CompletionModel with real LLM calls (Phase 1 Sprint 2).
@@ -18,12 +33,73 @@
 #[derive(Debug, Clone, hexser::HexAdapter)]
 pub struct OllamaComprehensionTestAdapter {
     model: String,
+    base_url: String,
+    auto_pull: bool,
+    retry_policy: crate::infrastructure::retry_policy::RetryPolicy,
+    budget: std::option::Option<std::sync::Arc<crate::domain::token_budget::TokenBudget>>,
 }
 
+/// Preamble of the test generation agent.
+const PREAMBLE: &str = "You are a comprehension test generator. \
+    Generate a JSON object with the test question, optional multiple choice options, \
+    and the correct answer. Keep your response focused and end with valid JSON.";
+
+/// Preamble of the agent answering a generated test.
+const ANSWER_PREAMBLE: &str = "You are the agent assigned to the task below. \
+    Answer the comprehension question about it in one or two sentences, \
+    using only what the task and its enhancement say. Reply with the answer only.";
+
 impl OllamaComprehensionTestAdapter {
     /// Creates a new adapter instance using the provided model name.
     pub fn new(model: String) -> Self {
-        Self { model }
+        Self {
+            model,
+            base_url: String::from(crate::infrastructure::ollama_errors::DEFAULT_OLLAMA_BASE_URL),
+            auto_pull: false,
+            retry_policy: crate::infrastructure::retry_policy::RetryPolicy::default(),
+            budget: std::option::Option::None,
+        }
+    }
+
+    /// Sets the Ollama server URL (default "http://localhost:11434").
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Pulls the model through Ollama's `/api/pull` and retries once when
+    /// Ollama reports it missing (default false).
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_auto_pull(mut self, auto_pull: bool) -> Self {
+        self.auto_pull = auto_pull;
+        self
+    }
+
+    /// Sets the timeout and retry policy for calls to Ollama.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_retry_policy(mut self, retry_policy: crate::infrastructure::retry_policy::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Records the usage of every completed call in `budget`.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_budget(mut self, budget: std::sync::Arc<crate::domain::token_budget::TokenBudget>) -> Self {
+        self.budget = std::option::Option::Some(budget);
+        self
     }
 
     /// Returns the configured model name.
@@ -31,6 +107,46 @@ impl OllamaComprehensionTestAdapter {
         self.model.as_str()
     }
 
+    /// Returns the Ollama server URL.
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_str()
+    }
+
+    /// Sends `prompt` to Ollama under `preamble` and returns the raw reply;
+    /// the budget records the call as `operation`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the provider error, or instructions to start Ollama if the
+    /// connection is refused.
+    async fn request(&self, preamble: &str, operation: &str, prompt: &str, task_id: &str) -> std::result::Result<String, String> {
+        let client = rig::providers::ollama::Client::from_url(&self.base_url);
+
+        // Create agent with preamble (no tools needed for comprehension tests)
+        let agent = client.agent(&self.model).preamble(preamble).build();
+
+        let response = self
+            .retry_policy
+            .run(|| {
+                crate::infrastructure::ollama_pull::retry_after_pull(self.auto_pull, &self.base_url, &self.model, || {
+                    let span = crate::infrastructure::telemetry::provider_span("ollama", &self.model, task_id);
+                    task_manager::infrastructure::telemetry::timed(span, rig::completion::Prompt::prompt(&agent, prompt))
+                })
+            })
+            .await
+            .map_err(|e| {
+                e.describe(|e| {
+                    crate::infrastructure::ollama_errors::not_running_error(&e, &self.base_url, &self.model)
+                        .unwrap_or_else(|| e.to_string())
+                })
+            })?;
+
+        if let std::option::Option::Some(budget) = &self.budget {
+            budget.record_exchange(operation, "ollama", &self.model, &std::format!("{}\n\n{}", preamble, prompt), &response);
+        }
+        std::result::Result::Ok(response)
+    }
+
     /// Creates a fallback comprehension test when LLM is unavailable.
     fn create_fallback_test(task: &task_manager::domain::task::Task, test_type: &str) -> task_manager::domain::comprehension_test::ComprehensionTest {
         let ts = chrono::Utc::now();
@@ -50,6 +166,8 @@ impl OllamaComprehensionTestAdapter {
                 std::option::Option::None
             },
             correct_answer: std::string::String::from("Complete the task"),
+            description_hash: std::option::Option::None,
+            stale: false,
         }
     }
 
@@ -99,6 +217,35 @@ impl OllamaComprehensionTestAdapter {
 
         prompt
     }
+
+    /// Builds the prompt asking the agent to answer `test` from the task and its latest enhancement.
+    fn build_answer_prompt(
+        task: &task_manager::domain::task::Task,
+        test: &task_manager::domain::comprehension_test::ComprehensionTest,
+    ) -> std::string::String {
+        let mut prompt = std::string::String::new();
+
+        prompt.push_str("# Task\n\n");
+        prompt.push_str(&std::format!("**Title:** {}\n", task.title));
+        if !task.description.is_empty() {
+            prompt.push_str(&std::format!("**Description:** {}\n", task.description));
+        }
+        if let std::option::Option::Some(enhancement) = task.enhancements.as_ref().and_then(|list| list.last()) {
+            prompt.push_str(&std::format!("**Enhancement:** {}\n", enhancement.content));
+        }
+
+        prompt.push_str("\n# Question\n\n");
+        prompt.push_str(&test.question);
+        prompt.push('\n');
+        if let std::option::Option::Some(ref options) = test.options {
+            prompt.push_str("\nAnswer with one of these options:\n");
+            for option in options {
+                prompt.push_str(&std::format!("- {}\n", option));
+            }
+        }
+
+        prompt
+    }
 }
 
 #[async_trait::async_trait]
@@ -111,24 +258,12 @@ impl crate::ports::comprehension_test_port::ComprehensionTestPort for OllamaComp
         // Build prompt
         let prompt = Self::build_prompt(task, test_type);
 
-        // Create Rig Ollama client
-        let client = rig::providers::ollama::Client::new();
-
-        // Create agent with preamble (no tools needed for comprehension tests)
-        let agent = client
-            .agent(&self.model)
-            .preamble(
-                "You are a comprehension test generator. \
-                Generate a JSON object with the test question, optional multiple choice options, \
-                and the correct answer. Keep your response focused and end with valid JSON."
-            )
-            .build();
-
         // Get LLM response
-        let response_text = match rig::completion::Prompt::prompt(&agent, prompt.as_str()).await {
+        let response_text = match self.request(PREAMBLE, "comprehension_test", &prompt, &task.id).await {
             std::result::Result::Ok(resp) => resp,
-            std::result::Result::Err(_e) => {
+            std::result::Result::Err(e) => {
                 // Fallback to deterministic test if LLM unavailable
+                tracing::warn!(task_id = %task.id, error = %e, "comprehension test request failed; using fallback test");
                 return std::result::Result::Ok(Self::create_fallback_test(task, test_type));
             }
         };
@@ -152,6 +287,25 @@ impl crate::ports::comprehension_test_port::ComprehensionTestPort for OllamaComp
 
         std::result::Result::Ok(test)
     }
+
+    async fn answer_comprehension_test(
+        &self,
+        task: &task_manager::domain::task::Task,
+        test: &task_manager::domain::comprehension_test::ComprehensionTest,
+    ) -> std::result::Result<std::option::Option<std::string::String>, std::string::String> {
+        let prompt = Self::build_answer_prompt(task, test);
+        match self.request(ANSWER_PREAMBLE, "comprehension_answer", &prompt, &task.id).await {
+            std::result::Result::Ok(answer) => {
+                let answer = answer.trim();
+                std::result::Result::Ok((!answer.is_empty()).then(|| std::string::String::from(answer)))
+            }
+            std::result::Result::Err(e) => {
+                // Without an answer the check grades the test by its heuristic
+                tracing::warn!(task_id = %task.id, error = %e, "comprehension test answer request failed; grading heuristically");
+                std::result::Result::Ok(std::option::Option::None)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -239,6 +393,34 @@ mod tests {
         std::assert!(prompt.contains("options"), "Prompt should mention options array");
     }
 
+    #[test]
+    fn test_build_answer_prompt_includes_enhancement_and_options() {
+        // Test: Validates the answer prompt carries the task, its latest enhancement, the question, and the options.
+        // Justification: The agent can only answer what the enhancement explained if the prompt includes it.
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Rotate signing keys"),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let mut task = task_manager::domain::task::Task::from_action_item(&action, std::option::Option::None);
+        task.enhancements = std::option::Option::Some(std::vec![task_manager::domain::enhancement::Enhancement {
+            enhancement_id: std::string::String::from("e1"),
+            task_id: task.id.clone(),
+            timestamp: chrono::Utc::now(),
+            enhancement_type: std::string::String::from("rewrite"),
+            content: std::string::String::from("Rotate keys in the vault every 90 days"),
+        }]);
+        let mut test = super::OllamaComprehensionTestAdapter::create_fallback_test(&task, "multiple_choice");
+        test.question = std::string::String::from("How often are keys rotated?");
+
+        let prompt = super::OllamaComprehensionTestAdapter::build_answer_prompt(&task, &test);
+
+        std::assert!(prompt.contains("Rotate signing keys"), "prompt: {}", prompt);
+        std::assert!(prompt.contains("every 90 days"), "prompt: {}", prompt);
+        std::assert!(prompt.contains("How often are keys rotated?"), "prompt: {}", prompt);
+        std::assert!(prompt.contains("- Understand requirements"), "prompt: {}", prompt);
+    }
+
     #[test]
     fn test_create_fallback_test_structure() {
         // Test: Validates fallback test has correct structure.
@@ -258,4 +440,19 @@ mod tests {
         std::assert!(!test.question.is_empty(), "Question should not be empty");
         std::assert!(test.question.len() <= 80, "Question should be under length limit");
     }
+
+    #[tokio::test]
+    async fn test_refused_connection_reports_ollama_not_running() {
+        // Test: Validates a request to a stopped Ollama fails with instructions naming the base URL and model.
+        // Justification: The raw reqwest connection error left new users guessing why the fallback test was used.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = std::format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+        let adapter = super::OllamaComprehensionTestAdapter::new(std::string::String::from("llama3.1")).with_base_url(base_url.clone());
+
+        let error = adapter.request(super::PREAMBLE, "comprehension_test", "prompt", "task-1").await.unwrap_err();
+
+        std::assert!(error.contains(&base_url), "error: {}", error);
+        std::assert!(error.contains("ollama pull llama3.1"), "error: {}", error);
+    }
 }
@@ -3,7 +3,27 @@
 //! This adapter uses Rig's Extractor API with JSON Schema to generate structured
 //! task enhancements via LLM. Schema enforcement ensures reliable, valid output.
 //!
+//! With deterministic sampling configured (temperature 0 or a fixed seed),
+//! responses are served from an optional ResponseCache. Rig's Extractor
+//! cannot set temperature or seed, so in that mode the schema is given to a
+//! plain agent in its preamble and the JSON is parsed from the reply.
+//!
+//! If Ollama is not running, the fallback enhancement is logged with
+//! instructions to start it and pull the model. With `with_auto_pull(true)`,
+//! a model Ollama has not pulled yet is downloaded and the call retried once.
+//! Every call is timed out and retried under the adapter's `RetryPolicy`.
+//! With `with_budget`, each call that reaches the model is recorded in the
+//! run's `TokenBudget`; responses served from the cache are not.
+//!
 //! Revision History
+//! - 2026-10-18T04:40:00Z @AI: Cache a deterministic enhancement reply only once it parses as an EnhancementExtraction.
+//! - 2026-10-17T21:20:00Z @AI: Add with_budget; record each model call's estimated token usage in the run's budget.
+//! - 2026-10-17T15:10:00Z @AI: Add with_retry_policy; time out and retry calls under the shared RetryPolicy.
+//! - 2026-10-17T14:50:00Z @AI: Add with_auto_pull; pull a missing model and retry once.
+//! - 2026-10-17T14:30:00Z @AI: Add with_base_url; log a friendly error when Ollama refuses the connection.
+//! - 2026-10-16T23:30:00Z @AI: Give the tool agent the glob tool.
+//! - 2026-10-16T21:30:00Z @AI: Add with_sampling and with_response_cache; cache deterministic enhancement calls.
+//! - 2026-10-16T19:50:00Z @AI: Time LLM calls in `provider_call` spans; log fallbacks as warnings.
 //! - 2025-11-23T21:00:00Z @AI: Complete Task 4.10 - Add ProjectContext integration test (Phase 4 Sprint 9).
 //! - 2025-11-23 @AI: Integrate FileSystemTool into Agent for project context access (Phase 4 Sprint 9 Task 4.8).
 //! - 2025-11-23T15:05:00Z @AI: Upgrade to use Rig Extractor with JSON Schema enforcement (Phase 1 Sprint 3).
@@ -24,6 +44,22 @@ struct EnhancementExtraction {
     content: String,
 }
 
+/// Preamble of the agent used when the adapter has project file access.
+const TOOL_AGENT_PREAMBLE: &str = "You are a task enhancement assistant with access to project files. \
+    You can read files and list directories to understand the codebase. \
+    Use these tools when they help you provide better, context-aware enhancement suggestions. \
+    Always end your response with a JSON object containing enhancement_type and content fields.";
+
+/// Preamble of the schema-enforced extraction call.
+const EXTRACTOR_PREAMBLE: &str = "You are a task enhancement assistant. \
+    Generate a JSON object with enhancement_type and content fields. \
+    Follow the schema strictly.";
+
+/// JSON schema of `EnhancementExtraction`, used in prompts and cache keys.
+fn enhancement_schema() -> String {
+    serde_json::to_string(&schemars::schema_for!(EnhancementExtraction)).unwrap_or_default()
+}
+
 /// Adapter that generates task enhancements via Ollama LLM using Rig Agent with FileSystemTool.
 ///
 /// Uses Rig's Agent API with FileSystemTool registered, enabling the LLM to read
@@ -31,7 +67,13 @@ struct EnhancementExtraction {
 #[derive(Debug, Clone, hexser::HexAdapter)]
 pub struct OllamaEnhancementAdapter {
     model: String,
+    base_url: String,
+    auto_pull: bool,
+    retry_policy: crate::infrastructure::retry_policy::RetryPolicy,
     project_root: std::option::Option<std::path::PathBuf>,
+    sampling: crate::infrastructure::sampling::SamplingParams,
+    response_cache: std::option::Option<std::sync::Arc<crate::infrastructure::response_cache::ResponseCache>>,
+    budget: std::option::Option<std::sync::Arc<crate::domain::token_budget::TokenBudget>>,
 }
 
 impl OllamaEnhancementAdapter {
@@ -47,7 +89,13 @@ impl OllamaEnhancementAdapter {
     pub fn new(model: String) -> Self {
         Self {
             model,
+            base_url: String::from(crate::infrastructure::ollama_errors::DEFAULT_OLLAMA_BASE_URL),
+            auto_pull: false,
+            retry_policy: crate::infrastructure::retry_policy::RetryPolicy::default(),
             project_root: std::option::Option::None,
+            sampling: crate::infrastructure::sampling::SamplingParams::default(),
+            response_cache: std::option::Option::None,
+            budget: std::option::Option::None,
         }
     }
 
@@ -67,20 +115,146 @@ impl OllamaEnhancementAdapter {
     ) -> Self {
         Self {
             model,
+            base_url: String::from(crate::infrastructure::ollama_errors::DEFAULT_OLLAMA_BASE_URL),
+            auto_pull: false,
+            retry_policy: crate::infrastructure::retry_policy::RetryPolicy::default(),
             project_root: std::option::Option::Some(project_root.as_ref().to_path_buf()),
+            sampling: crate::infrastructure::sampling::SamplingParams::default(),
+            response_cache: std::option::Option::None,
+            budget: std::option::Option::None,
         }
     }
 
+    /// Sets the Ollama server URL (default "http://localhost:11434").
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Pulls the model through Ollama's `/api/pull` and retries once when
+    /// Ollama reports it missing (default false).
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_auto_pull(mut self, auto_pull: bool) -> Self {
+        self.auto_pull = auto_pull;
+        self
+    }
+
+    /// Sets the timeout and retry policy for calls to Ollama.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_retry_policy(mut self, retry_policy: crate::infrastructure::retry_policy::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets the temperature and seed sent with enhancement calls.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_sampling(mut self, sampling: crate::infrastructure::sampling::SamplingParams) -> Self {
+        self.sampling = sampling;
+        self
+    }
+
+    /// Serves repeated deterministic enhancement calls from `cache`; `None` disables caching.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_response_cache(
+        mut self,
+        cache: std::option::Option<std::sync::Arc<crate::infrastructure::response_cache::ResponseCache>>,
+    ) -> Self {
+        self.response_cache = cache;
+        self
+    }
+
+    /// Records the usage of every call that reaches the model in `budget`.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_budget(mut self, budget: std::sync::Arc<crate::domain::token_budget::TokenBudget>) -> Self {
+        self.budget = std::option::Option::Some(budget);
+        self
+    }
+
     /// Returns the configured model name.
     pub fn model(&self) -> &str {
         self.model.as_str()
     }
 
+    /// Returns the Ollama server URL.
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_str()
+    }
+
     /// Returns the project root path if configured.
     pub fn project_root(&self) -> std::option::Option<&std::path::Path> {
         self.project_root.as_deref()
     }
 
+    /// Prompts `agent` through the response cache, timing calls that reach the model.
+    ///
+    /// # Errors
+    ///
+    /// Returns the provider error if the request fails, or instructions to
+    /// start Ollama if the connection is refused.
+    async fn prompt_cached(
+        &self,
+        agent: &rig::agent::Agent<rig::providers::ollama::CompletionModel>,
+        preamble: &str,
+        prompt: &str,
+        task_id: &str,
+    ) -> std::result::Result<String, String> {
+        let key = crate::infrastructure::response_cache::CacheKey::new(
+            "ollama",
+            &self.model,
+            &self.sampling,
+            &std::format!("{}\n\n{}", preamble, prompt),
+            &enhancement_schema(),
+        );
+        let validate = |response: &str| Self::parse_enhancement_from_response(response).map(|_| ());
+        crate::infrastructure::response_cache::cached_call(self.response_cache.as_deref(), &key, &self.sampling, validate, || async {
+            let response = self
+                .retry_policy
+                .run(|| {
+                    crate::infrastructure::ollama_pull::retry_after_pull(self.auto_pull, &self.base_url, &self.model, || {
+                        let span = crate::infrastructure::telemetry::provider_span("ollama", &self.model, task_id);
+                        task_manager::infrastructure::telemetry::timed(span, rig::completion::Prompt::prompt(agent, prompt))
+                    })
+                })
+                .await
+                .map_err(|e| e.describe(|e| self.describe_error(&e)))?;
+            self.record_usage(preamble, prompt, &response);
+            std::result::Result::Ok(response)
+        })
+        .await
+    }
+
+    /// Records a call that reached the model in the run's budget, if one is set.
+    fn record_usage(&self, preamble: &str, prompt: &str, response: &str) {
+        if let std::option::Option::Some(budget) = &self.budget {
+            budget.record_exchange("enhancement", "ollama", &self.model, &std::format!("{}\n\n{}", preamble, prompt), response);
+        }
+    }
+
+    /// Describes a provider error, explaining a refused connection to Ollama.
+    fn describe_error<E: std::error::Error + 'static>(&self, error: &E) -> String {
+        crate::infrastructure::ollama_errors::not_running_error(error, &self.base_url, &self.model)
+            .unwrap_or_else(|| error.to_string())
+    }
+
     /// Creates a fallback enhancement when LLM is unavailable.
     fn create_fallback_enhancement(task: &task_manager::domain::task::Task) -> task_manager::domain::enhancement::Enhancement {
         let ts = chrono::Utc::now();
@@ -149,7 +323,8 @@ impl OllamaEnhancementAdapter {
             prompt.push_str("# Available Tools\n\n");
             prompt.push_str("You have access to the following project file tools:\n");
             prompt.push_str("- `read_file`: Read a file from the project (relative path)\n");
-            prompt.push_str("- `list_directory`: List files in a project directory\n\n");
+            prompt.push_str("- `list_directory`: List files in a project directory\n");
+            prompt.push_str("- `glob`: Find project files matching a pattern such as `src/**/*.rs`\n\n");
             prompt.push_str("**Use these tools** if you need to understand the project structure or read existing code to provide better enhancement suggestions.\n\n");
         }
 
@@ -221,7 +396,7 @@ impl crate::ports::task_enhancement_port::TaskEnhancementPort for OllamaEnhancem
         &self,
         task: &task_manager::domain::task::Task,
     ) -> std::result::Result<task_manager::domain::enhancement::Enhancement, std::string::String> {
-        let client = rig::providers::ollama::Client::new();
+        let client = rig::providers::ollama::Client::from_url(&self.base_url);
 
         // Determine if we have project file access
         let has_file_access = self.project_root.is_some();
@@ -254,45 +429,72 @@ impl crate::ports::task_enhancement_port::TaskEnhancementPort for OllamaEnhancem
             // Use Agent with FileSystemTool
             let read_tool = crate::tools::file_system_tool::ReadFileTool::new(root);
             let list_tool = crate::tools::file_system_tool::ListDirectoryTool::new(root);
+            let glob_tool = crate::tools::file_system_tool::GlobTool::new(root);
 
-            let agent = client
-                .agent(&self.model)
-                .preamble(
-                    "You are a task enhancement assistant with access to project files. \
-                    You can read files and list directories to understand the codebase. \
-                    Use these tools when they help you provide better, context-aware enhancement suggestions. \
-                    Always end your response with a JSON object containing enhancement_type and content fields."
-                )
+            let agent = crate::infrastructure::sampling::ollama_agent(&client, &self.model, &self.sampling)
+                .preamble(TOOL_AGENT_PREAMBLE)
                 .tool(read_tool)
                 .tool(list_tool)
+                .tool(glob_tool)
                 .build();
 
             // Prompt the agent
-            let response = match rig::completion::Prompt::prompt(&agent, prompt.as_str()).await {
+            let response = match self.prompt_cached(&agent, TOOL_AGENT_PREAMBLE, &prompt, &task.id).await {
                 std::result::Result::Ok(resp) => resp,
-                std::result::Result::Err(_e) => {
+                std::result::Result::Err(e) => {
                     // Fallback
+                    tracing::warn!(task_id = %task.id, error = %e, "enhancement request failed; using fallback enhancement");
                     return std::result::Result::Ok(Self::create_fallback_enhancement(task));
                 }
             };
 
             // Parse JSON from response
+            Self::parse_enhancement_from_response(&response)?
+        } else if self.sampling.is_deterministic() {
+            // The Extractor cannot carry temperature/seed, so give the schema to a plain agent
+            let preamble = std::format!(
+                "{}\n\nRespond with only a JSON object matching this schema:\n{}",
+                EXTRACTOR_PREAMBLE,
+                enhancement_schema()
+            );
+            let agent = crate::infrastructure::sampling::ollama_agent(&client, &self.model, &self.sampling)
+                .preamble(&preamble)
+                .build();
+
+            let response = match self.prompt_cached(&agent, &preamble, &prompt, &task.id).await {
+                std::result::Result::Ok(resp) => resp,
+                std::result::Result::Err(e) => {
+                    tracing::warn!(task_id = %task.id, error = %e, "enhancement request failed; using fallback enhancement");
+                    return std::result::Result::Ok(Self::create_fallback_enhancement(task));
+                }
+            };
+
             Self::parse_enhancement_from_response(&response)?
         } else {
             // Use Extractor API (no file access)
             let extractor = client
                 .extractor::<EnhancementExtraction>(&self.model)
-                .preamble(
-                    "You are a task enhancement assistant. \
-                    Generate a JSON object with enhancement_type and content fields. \
-                    Follow the schema strictly."
-                )
+                .preamble(EXTRACTOR_PREAMBLE)
                 .build();
 
-            match rig::extractor::Extractor::extract(&extractor, &prompt).await {
-                std::result::Result::Ok(extraction) => extraction,
-                std::result::Result::Err(_e) => {
+            let extraction = self
+                .retry_policy
+                .run(|| {
+                    crate::infrastructure::ollama_pull::retry_after_pull(self.auto_pull, &self.base_url, &self.model, || {
+                        let span = crate::infrastructure::telemetry::provider_span("ollama", &self.model, &task.id);
+                        task_manager::infrastructure::telemetry::timed(span, rig::extractor::Extractor::extract(&extractor, &prompt))
+                    })
+                })
+                .await;
+            match extraction {
+                std::result::Result::Ok(extraction) => {
+                    self.record_usage(EXTRACTOR_PREAMBLE, &prompt, &serde_json::to_string(&extraction).unwrap_or_default());
+                    extraction
+                }
+                std::result::Result::Err(e) => {
                     // Fallback
+                    let error = e.describe(|e| self.describe_error(&e));
+                    tracing::warn!(task_id = %task.id, error = %error, "enhancement extraction failed; using fallback enhancement");
                     return std::result::Result::Ok(Self::create_fallback_enhancement(task));
                 }
             }
@@ -571,4 +773,21 @@ mod tests {
         // Cleanup
         std::fs::remove_dir_all(&temp_dir).unwrap();
     }
+
+    #[tokio::test]
+    async fn test_refused_connection_reports_ollama_not_running() {
+        // Test: Validates a prompt to a stopped Ollama fails with instructions naming the base URL and model.
+        // Justification: The raw reqwest connection error left new users guessing why enhancement fell back.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = std::format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+        let adapter = super::OllamaEnhancementAdapter::new(std::string::String::from("llama3.1")).with_base_url(base_url.clone());
+        let client = rig::providers::ollama::Client::from_url(adapter.base_url());
+        let agent = crate::infrastructure::sampling::ollama_agent(&client, adapter.model(), &adapter.sampling).build();
+
+        let error = adapter.prompt_cached(&agent, "preamble", "prompt", "task-1").await.unwrap_err();
+
+        std::assert!(error.contains(&base_url), "error: {}", error);
+        std::assert!(error.contains("ollama pull llama3.1"), "error: {}", error);
+    }
 }
@@ -5,7 +5,7 @@
 //! LLM providers (Ollama, OpenAI, Anthropic) based on configuration.
 //!
 //! The factory reads provider configuration from environment variables:
-//! - `TASK_ORCHESTRATOR_PROVIDER`: Provider name ("ollama", "openai", "anthropic", "mlx")
+//! - `TASK_ORCHESTRATOR_PROVIDER`: Provider name ("ollama", "openai", "anthropic", "mlx", "openai-compatible")
 //! - `OLLAMA_MODEL`: Model name for Ollama (default: "llama3.1")
 //! - `OPENAI_MODEL`: Model name for OpenAI (default: "gpt-4")
 //! - `ANTHROPIC_MODEL`: Model name for Anthropic (default: "claude-3-5-sonnet-20241022")
@@ -17,8 +17,52 @@
 //! - `ANTHROPIC_VISION_MODEL`: Vision model for Anthropic (default: "claude-3-5-sonnet-20241022")
 //! - `OPENAI_API_KEY`: API key for OpenAI
 //! - `ANTHROPIC_API_KEY`: API key for Anthropic
+//! - `OPENAI_COMPATIBLE_BASE_URL`: Base URL of an OpenAI-compatible server (default: "http://localhost:8000/v1")
+//! - `OPENAI_COMPATIBLE_MODEL`: Model name for the OpenAI-compatible server (default: "default")
+//! - `OPENAI_COMPATIBLE_API_KEY`: Optional API key for the OpenAI-compatible server
+//!
+//! The "openai-compatible" provider covers vLLM, LM Studio, llamafile, and
+//! other servers that speak the OpenAI API at their own base URL. Chat and
+//! embeddings go through the OpenAI code path against that URL, and the API
+//! key is optional. `with_base_url` and `with_api_key` set both explicitly.
+//!
+//! In offline mode (`with_offline(true)`, driven by `offline` in
+//! `.rigger/config.json`) the factory refuses providers whose endpoint is not
+//! localhost or a private IP, so a misconfigured cloud provider fails when
+//! the factory is built rather than when the first request is sent.
+//!
+//! `with_auto_pull(true)` (from a provider's `auto_pull` in config) lets the
+//! Ollama enhancement, comprehension test, embedding, and chat agent adapters
+//! pull a missing model and retry once.
+//!
+//! `with_retry_policy` (built from a provider's `timeout_seconds` and
+//! `max_retries` in config) sets the timeout and retry policy of every
+//! adapter that calls the provider, including MLX restarts. Those policies
+//! share the provider's circuit breaker (`circuit_breaker::for_provider`), so
+//! during an outage every adapter fails fast once the breaker opens.
+//!
+//! `with_budget` gives the Ollama enhancement and comprehension test adapters
+//! and MLX adapters the run's `TokenBudget`, in which they record each call.
+//!
+//! `with_decomposition_config` sets the decomposition threshold and depth of
+//! Rig decomposition adapters; callers pass the section of the config file
+//! they resolved, and the defaults apply otherwise.
 //!
 //! Revision History
+//! - 2026-10-17T22:40:00Z @AI: Take decomposition settings from with_decomposition_config instead of reading .rigger from the working directory.
+//! - 2026-10-17T21:20:00Z @AI: Add with_budget, passed to the enhancement, comprehension test, and MLX adapters.
+//! - 2026-10-17T18:10:00Z @AI: Add create_rerank_adapter scoring search hits with the chat model.
+//! - 2026-10-17T15:30:00Z @AI: Guard adapter retry policies with the provider's shared circuit breaker.
+//! - 2026-10-17T15:10:00Z @AI: Add with_retry_policy, passed to every provider-calling adapter.
+//! - 2026-10-17T14:50:00Z @AI: Add with_auto_pull, passed to the Ollama enhancement, comprehension, embedding, and agent adapters.
+//! - 2026-10-17T21:40:00Z @AI: Retry policies handed to adapters report retries to the metrics collector.
+//! - 2026-10-17T02:10:00Z @AI: Add with_metrics_collector; MLX adapters report retry events to it.
+//! - 2026-10-17T01:30:00Z @AI: Add "openai-compatible" provider with configurable base URL and optional key; add create_agent_adapter.
+//! - 2026-10-16T22:30:00Z @AI: Add with_embedding_model() overriding the embedding model chosen from the environment.
+//! - 2026-10-16T21:30:00Z @AI: Pass sampling parameters and the response cache to enhancement, PRD parser, and decomposition adapters.
+//! - 2026-10-16T21:10:00Z @AI: Add offline mode refusing providers whose endpoint is not localhost or a private IP.
+//! - 2026-10-16T19:10:00Z @AI: Add model list adapter creation for `rig models`.
+//! - 2026-10-16T11:50:00Z @AI: Apply the decomposition section of .rigger/config.json to Rig decomposition adapters.
 //! - 2025-11-30T11:25:00Z @AI: Add vision adapter creation for Phase 5 image processing implementation.
 //! - 2025-11-28T20:00:00Z @AI: Add embedding adapter creation for Phase 3 RAG implementation (Task 3.2).
 //! - 2025-11-24T00:20:00Z @AI: Add MLX provider support for macOS Apple Silicon optimization (Phase 5 Sprint 11 Task 5.8).
@@ -38,13 +82,48 @@
 /// let factory = ProviderFactory::from_env().unwrap();
 /// let enhancer = factory.create_enhancement_adapter().unwrap();
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ProviderFactory {
     provider: String,
     model: String,
     model_selection: crate::domain::model_role::ModelSelectionStrategy,
+    offline: bool,
+    sampling: crate::infrastructure::sampling::SamplingParams,
+    response_cache: std::option::Option<std::sync::Arc<crate::infrastructure::response_cache::ResponseCache>>,
+    embedding_model: std::option::Option<String>,
+    base_url: std::option::Option<String>,
+    api_key: std::option::Option<String>,
+    metrics_collector: std::option::Option<std::sync::Arc<dyn crate::ports::metrics_collector_port::MetricsCollectorPort>>,
+    auto_pull: bool,
+    retry_policy: std::option::Option<crate::infrastructure::retry_policy::RetryPolicy>,
+    budget: std::option::Option<std::sync::Arc<crate::domain::token_budget::TokenBudget>>,
+    decomposition: crate::infrastructure::config::DecompositionConfig,
 }
 
+impl std::fmt::Debug for ProviderFactory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProviderFactory")
+            .field("provider", &self.provider)
+            .field("model", &self.model)
+            .field("model_selection", &self.model_selection)
+            .field("offline", &self.offline)
+            .field("sampling", &self.sampling)
+            .field("response_cache", &self.response_cache)
+            .field("embedding_model", &self.embedding_model)
+            .field("base_url", &self.base_url)
+            .field("api_key", &self.api_key.as_ref().map(|_| "***"))
+            .field("metrics_collector", &self.metrics_collector.is_some())
+            .field("auto_pull", &self.auto_pull)
+            .field("retry_policy", &self.retry_policy)
+            .field("budget", &self.budget)
+            .field("decomposition", &self.decomposition)
+            .finish()
+    }
+}
+
+/// Base URL used by the "openai-compatible" provider when none is configured (vLLM's default).
+pub const DEFAULT_OPENAI_COMPATIBLE_BASE_URL: &str = "http://localhost:8000/v1";
+
 impl ProviderFactory {
     /// Creates a new ProviderFactory from environment variables.
     ///
@@ -76,14 +155,25 @@ impl ProviderFactory {
                 .unwrap_or_else(|_| "claude-3-5-sonnet-20241022".to_string()),
             "mlx" => std::env::var("MLX_MODEL")
                 .unwrap_or_else(|_| "mlx-community/Phi-3-mini-4k-instruct".to_string()),
+            "openai-compatible" => std::env::var("OPENAI_COMPATIBLE_MODEL")
+                .unwrap_or_else(|_| "default".to_string()),
             _ => {
                 return std::result::Result::Err(hexser::Hexserror::adapter(
                     "UNSUPPORTED_PROVIDER",
-                    &std::format!("Unsupported provider: {}. Supported providers: ollama, openai, anthropic, mlx", provider)
+                    &std::format!("Unsupported provider: {}. Supported providers: ollama, openai, anthropic, mlx, openai-compatible", provider)
                 ))
             }
         };
 
+        let (base_url, api_key) = if provider == "openai-compatible" {
+            (
+                std::env::var("OPENAI_COMPATIBLE_BASE_URL").ok(),
+                std::env::var("OPENAI_COMPATIBLE_API_KEY").ok(),
+            )
+        } else {
+            (std::option::Option::None, std::option::Option::None)
+        };
+
         // Initialize model selection strategy with defaults
         let model_selection = crate::domain::model_role::ModelSelectionStrategy::default();
 
@@ -91,6 +181,17 @@ impl ProviderFactory {
             provider,
             model,
             model_selection,
+            offline: false,
+            sampling: crate::infrastructure::sampling::SamplingParams::default(),
+            response_cache: std::option::Option::None,
+            embedding_model: std::option::Option::None,
+            base_url,
+            api_key,
+            metrics_collector: std::option::Option::None,
+            auto_pull: false,
+            retry_policy: std::option::Option::None,
+            budget: std::option::Option::None,
+            decomposition: crate::infrastructure::config::DecompositionConfig::default(),
         })
     }
 
@@ -98,7 +199,7 @@ impl ProviderFactory {
     ///
     /// # Arguments
     ///
-    /// * `provider` - Provider name ("ollama", "openai", or "anthropic")
+    /// * `provider` - Provider name ("ollama", "openai", "anthropic", "mlx", or "openai-compatible")
     /// * `model` - Model identifier
     ///
     /// # Examples
@@ -111,11 +212,11 @@ impl ProviderFactory {
     pub fn new(provider: &str, model: &str) -> hexser::HexResult<Self> {
         // Validate provider
         match provider {
-            "ollama" | "openai" | "anthropic" | "mlx" => {}
+            "ollama" | "openai" | "anthropic" | "mlx" | "openai-compatible" => {}
             _ => {
                 return std::result::Result::Err(hexser::Hexserror::adapter(
                     "UNSUPPORTED_PROVIDER",
-                    &std::format!("Unsupported provider: {}. Supported providers: ollama, openai, anthropic, mlx", provider)
+                    &std::format!("Unsupported provider: {}. Supported providers: ollama, openai, anthropic, mlx, openai-compatible", provider)
                 ))
             }
         }
@@ -127,9 +228,187 @@ impl ProviderFactory {
             provider: provider.to_string(),
             model: model.to_string(),
             model_selection,
+            offline: false,
+            sampling: crate::infrastructure::sampling::SamplingParams::default(),
+            response_cache: std::option::Option::None,
+            embedding_model: std::option::Option::None,
+            base_url: std::option::Option::None,
+            api_key: std::option::Option::None,
+            metrics_collector: std::option::Option::None,
+            auto_pull: false,
+            retry_policy: std::option::Option::None,
+            budget: std::option::Option::None,
+            decomposition: crate::infrastructure::config::DecompositionConfig::default(),
         })
     }
 
+    /// Enables or disables offline mode.
+    ///
+    /// In offline mode only providers reachable on localhost or a private IP
+    /// may be used: Ollama (localhost:11434) and MLX (in-process) are allowed,
+    /// OpenAI and Anthropic are not, and "openai-compatible" is allowed if its
+    /// base URL is local. Set the base URL before calling this.
+    ///
+    /// # Arguments
+    ///
+    /// * `offline` - Whether to forbid non-local providers
+    ///
+    /// # Errors
+    ///
+    /// Returns an `OFFLINE_VIOLATION` error if offline mode is enabled and the
+    /// provider's endpoint is not local.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use task_orchestrator::adapters::provider_factory::ProviderFactory;
+    ///
+    /// std::assert!(ProviderFactory::new("ollama", "llama3.1").unwrap().with_offline(true).is_ok());
+    /// std::assert!(ProviderFactory::new("openai", "gpt-4").unwrap().with_offline(true).is_err());
+    /// ```
+    pub fn with_offline(mut self, offline: bool) -> hexser::HexResult<Self> {
+        self.offline = offline;
+        let remote_endpoint = self
+            .endpoint()
+            .filter(|endpoint| offline && !rigger_core::config::network::is_local_url(endpoint));
+        if let std::option::Option::Some(endpoint) = remote_endpoint {
+            return std::result::Result::Err(hexser::Hexserror::adapter(
+                "OFFLINE_VIOLATION",
+                &std::format!(
+                    "Provider {} is not allowed in offline mode: {} is not localhost or a private IP",
+                    self.provider, endpoint
+                )
+            ));
+        }
+        std::result::Result::Ok(self)
+    }
+
+    /// Returns true if offline mode is enabled.
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Sets the temperature and seed used by enhancement, PRD parser, and decomposition adapters.
+    pub fn with_sampling(mut self, sampling: crate::infrastructure::sampling::SamplingParams) -> Self {
+        self.sampling = sampling;
+        self
+    }
+
+    /// Sets the response cache used by enhancement, PRD parser, and
+    /// decomposition adapters for deterministic calls; `None` disables caching.
+    pub fn with_response_cache(
+        mut self,
+        cache: std::option::Option<std::sync::Arc<crate::infrastructure::response_cache::ResponseCache>>,
+    ) -> Self {
+        self.response_cache = cache;
+        self
+    }
+
+    /// Sets the base URL of the "openai-compatible" provider, taking
+    /// precedence over `OPENAI_COMPATIBLE_BASE_URL`. Ignored by other providers.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = std::option::Option::Some(base_url.into());
+        self
+    }
+
+    /// Sets the API key of the "openai-compatible" provider, taking
+    /// precedence over `OPENAI_COMPATIBLE_API_KEY`. Ignored by other providers.
+    pub fn with_api_key(mut self, api_key: std::option::Option<String>) -> Self {
+        self.api_key = api_key;
+        self
+    }
+
+    /// Lets Ollama adapters pull a model Ollama reports missing, then retry once.
+    pub fn with_auto_pull(mut self, auto_pull: bool) -> Self {
+        self.auto_pull = auto_pull;
+        self
+    }
+
+    /// Sets the timeout and retry policy of every adapter that calls the
+    /// provider. Without one, adapters keep their own defaults.
+    pub fn with_retry_policy(mut self, retry_policy: crate::infrastructure::retry_policy::RetryPolicy) -> Self {
+        self.retry_policy = std::option::Option::Some(retry_policy);
+        self
+    }
+
+    /// Returns the configured retry policy, or the shared default, guarded
+    /// by the provider's circuit breaker and reporting retries to the
+    /// metrics collector, if one is set.
+    fn retry_policy(&self) -> crate::infrastructure::retry_policy::RetryPolicy {
+        let policy = self
+            .retry_policy
+            .clone()
+            .unwrap_or_default()
+            .with_circuit_breaker(crate::infrastructure::circuit_breaker::for_provider(&self.provider));
+        match &self.metrics_collector {
+            std::option::Option::Some(collector) => policy.with_metrics(&self.provider, collector.clone()),
+            std::option::Option::None => policy,
+        }
+    }
+
+    /// Sets the collector that retry loops (the shared retry policy and MLX
+    /// restarts) report retry events to.
+    pub fn with_metrics_collector(
+        mut self,
+        collector: std::sync::Arc<dyn crate::ports::metrics_collector_port::MetricsCollectorPort>,
+    ) -> Self {
+        self.metrics_collector = std::option::Option::Some(collector);
+        self
+    }
+
+    /// Sets the run budget that enhancement, comprehension test, and MLX
+    /// adapters record each call's token usage in.
+    pub fn with_budget(mut self, budget: std::sync::Arc<crate::domain::token_budget::TokenBudget>) -> Self {
+        self.budget = std::option::Option::Some(budget);
+        self
+    }
+
+    /// Sets the decomposition threshold and depth of Rig decomposition adapters,
+    /// typically the `decomposition` section of the caller's config file.
+    pub fn with_decomposition_config(mut self, decomposition: crate::infrastructure::config::DecompositionConfig) -> Self {
+        self.decomposition = decomposition;
+        self
+    }
+
+    /// Creates an MLX adapter for `model`, reporting retries to the metrics collector if one is set.
+    fn mlx_adapter(&self, model: String) -> crate::adapters::mlx_subprocess_adapter::MlxSubprocessAdapter {
+        let mut adapter = crate::adapters::mlx_subprocess_adapter::MlxSubprocessAdapter::new(model);
+        if let std::option::Option::Some(retry_policy) = &self.retry_policy {
+            adapter = adapter.with_retry_policy(retry_policy.clone());
+        }
+        if let std::option::Option::Some(budget) = &self.budget {
+            adapter = adapter.with_budget(budget.clone());
+        }
+        match &self.metrics_collector {
+            std::option::Option::Some(collector) => adapter.with_metrics_collector(collector.clone()),
+            std::option::Option::None => adapter,
+        }
+    }
+
+    /// Sets the embedding model used by `create_embedding_adapter`, taking
+    /// precedence over `OLLAMA_EMBEDDING_MODEL` / `OPENAI_EMBEDDING_MODEL`.
+    pub fn with_embedding_model(mut self, model: impl Into<String>) -> Self {
+        self.embedding_model = std::option::Option::Some(model.into());
+        self
+    }
+
+    /// Returns the endpoint the factory's adapters send requests to, or
+    /// `None` for providers that run in-process (MLX).
+    fn endpoint(&self) -> std::option::Option<String> {
+        match self.provider.as_str() {
+            "ollama" => std::option::Option::Some(String::from("http://localhost:11434")),
+            "openai" => std::option::Option::Some(String::from("https://api.openai.com/v1")),
+            "anthropic" => std::option::Option::Some(String::from("https://api.anthropic.com")),
+            "openai-compatible" => std::option::Option::Some(self.openai_compatible_base_url()),
+            _ => std::option::Option::None,
+        }
+    }
+
+    /// Returns the configured "openai-compatible" base URL or the default.
+    fn openai_compatible_base_url(&self) -> String {
+        self.base_url.clone().unwrap_or_else(|| String::from(DEFAULT_OPENAI_COMPATIBLE_BASE_URL))
+    }
+
     /// Gets the configured provider name.
     pub fn provider(&self) -> &str {
         &self.provider
@@ -159,7 +438,15 @@ impl ProviderFactory {
             "ollama" => {
                 let adapter = crate::adapters::ollama_enhancement_adapter::OllamaEnhancementAdapter::new(
                     self.model.clone(),
-                );
+                )
+                .with_auto_pull(self.auto_pull)
+                .with_retry_policy(self.retry_policy())
+                .with_sampling(self.sampling)
+                .with_response_cache(self.response_cache.clone());
+                let adapter = match &self.budget {
+                    std::option::Option::Some(budget) => adapter.with_budget(budget.clone()),
+                    std::option::Option::None => adapter,
+                };
                 std::result::Result::Ok(std::sync::Arc::new(adapter))
             }
             "mlx" => {
@@ -171,9 +458,7 @@ impl ProviderFactory {
                     ));
                 }
 
-                let adapter = crate::adapters::mlx_subprocess_adapter::MlxSubprocessAdapter::new(
-                    self.model.clone(),
-                );
+                let adapter = self.mlx_adapter(self.model.clone());
                 std::result::Result::Ok(std::sync::Arc::new(adapter))
             }
             "openai" => {
@@ -191,6 +476,10 @@ impl ProviderFactory {
                     "OpenAI enhancement adapter not yet implemented. Use ollama provider for now."
                 ))
             }
+            "openai-compatible" => std::result::Result::Err(hexser::Hexserror::adapter(
+                "NOT_IMPLEMENTED",
+                "OpenAI-compatible enhancement adapter not yet implemented. Use ollama provider for now."
+            )),
             "anthropic" => {
                 // Verify API key is set
                 std::env::var("ANTHROPIC_API_KEY").map_err(|_| {
@@ -250,7 +539,15 @@ impl ProviderFactory {
             "ollama" => {
                 let adapter = crate::adapters::ollama_enhancement_adapter::OllamaEnhancementAdapter::new(
                     model.to_string(),
-                );
+                )
+                .with_auto_pull(self.auto_pull)
+                .with_retry_policy(self.retry_policy())
+                .with_sampling(self.sampling)
+                .with_response_cache(self.response_cache.clone());
+                let adapter = match &self.budget {
+                    std::option::Option::Some(budget) => adapter.with_budget(budget.clone()),
+                    std::option::Option::None => adapter,
+                };
                 std::result::Result::Ok(std::sync::Arc::new(adapter))
             }
             "mlx" => {
@@ -264,12 +561,10 @@ impl ProviderFactory {
 
                 // For MLX, use MLX-community model naming convention
                 let mlx_model = std::format!("mlx-community/{}", model);
-                let adapter = crate::adapters::mlx_subprocess_adapter::MlxSubprocessAdapter::new(
-                    mlx_model,
-                );
+                let adapter = self.mlx_adapter(mlx_model);
                 std::result::Result::Ok(std::sync::Arc::new(adapter))
             }
-            "openai" | "anthropic" => {
+            "openai" | "anthropic" | "openai-compatible" => {
                 // For now, heterogeneous pipeline only works with Ollama and MLX
                 std::result::Result::Err(hexser::Hexserror::adapter(
                     "NOT_IMPLEMENTED",
@@ -302,7 +597,13 @@ impl ProviderFactory {
             "ollama" => {
                 let adapter = crate::adapters::ollama_comprehension_test_adapter::OllamaComprehensionTestAdapter::new(
                     self.model.clone(),
-                );
+                )
+                .with_auto_pull(self.auto_pull)
+                .with_retry_policy(self.retry_policy());
+                let adapter = match &self.budget {
+                    std::option::Option::Some(budget) => adapter.with_budget(budget.clone()),
+                    std::option::Option::None => adapter,
+                };
                 std::result::Result::Ok(std::sync::Arc::new(adapter))
             }
             "openai" => {
@@ -320,6 +621,10 @@ impl ProviderFactory {
                     "OpenAI comprehension test adapter not yet implemented. Use ollama provider for now."
                 ))
             }
+            "openai-compatible" => std::result::Result::Err(hexser::Hexserror::adapter(
+                "NOT_IMPLEMENTED",
+                "OpenAI-compatible comprehension test adapter not yet implemented. Use ollama provider for now."
+            )),
             "anthropic" => {
                 // Verify API key is set
                 std::env::var("ANTHROPIC_API_KEY").map_err(|_| {
@@ -366,7 +671,10 @@ impl ProviderFactory {
                     self.model.clone(),
                     self.model.clone(),
                     std::vec::Vec::new(), // No personas - caller should query database separately
-                );
+                )
+                .with_sampling(self.sampling)
+                .with_response_cache(self.response_cache.clone())
+                .with_retry_policy(self.retry_policy());
                 std::result::Result::Ok(std::sync::Arc::new(adapter))
             }
             _ => std::result::Result::Err(hexser::Hexserror::adapter(
@@ -396,7 +704,11 @@ impl ProviderFactory {
                 // RigTaskDecompositionAdapter uses Rig internally and can work with any provider
                 let adapter = crate::adapters::rig_task_decomposition_adapter::RigTaskDecompositionAdapter::new(
                     self.model.clone(),
-                );
+                )
+                .with_decomposition_config(self.decomposition)
+                .with_sampling(self.sampling)
+                .with_response_cache(self.response_cache.clone())
+                .with_retry_policy(self.retry_policy());
                 std::result::Result::Ok(std::sync::Arc::new(adapter))
             }
             "mlx" => {
@@ -408,9 +720,7 @@ impl ProviderFactory {
                     ));
                 }
 
-                let adapter = crate::adapters::mlx_subprocess_adapter::MlxSubprocessAdapter::new(
-                    self.model.clone(),
-                );
+                let adapter = self.mlx_adapter(self.model.clone());
                 std::result::Result::Ok(std::sync::Arc::new(adapter))
             }
             _ => std::result::Result::Err(hexser::Hexserror::adapter(
@@ -456,7 +766,11 @@ impl ProviderFactory {
                 // RigTaskDecompositionAdapter uses Rig internally
                 let adapter = crate::adapters::rig_task_decomposition_adapter::RigTaskDecompositionAdapter::new(
                     model.to_string(),
-                );
+                )
+                .with_decomposition_config(self.decomposition)
+                .with_sampling(self.sampling)
+                .with_response_cache(self.response_cache.clone())
+                .with_retry_policy(self.retry_policy());
                 std::result::Result::Ok(std::sync::Arc::new(adapter))
             }
             "mlx" => {
@@ -470,9 +784,7 @@ impl ProviderFactory {
 
                 // For MLX, use MLX-community model naming convention
                 let mlx_model = std::format!("mlx-community/{}", model);
-                let adapter = crate::adapters::mlx_subprocess_adapter::MlxSubprocessAdapter::new(
-                    mlx_model,
-                );
+                let adapter = self.mlx_adapter(mlx_model);
                 std::result::Result::Ok(std::sync::Arc::new(adapter))
             }
             _ => std::result::Result::Err(hexser::Hexserror::adapter(
@@ -486,7 +798,7 @@ impl ProviderFactory {
     ///
     /// This method creates embedding generation adapters for RAG (Retrieval-Augmented
     /// Generation) systems. The embedding model is selected based on the provider
-    /// and can be overridden with environment variables.
+    /// and can be overridden with environment variables or `with_embedding_model`.
     ///
     /// # Returns
     ///
@@ -512,13 +824,16 @@ impl ProviderFactory {
     ) -> hexser::HexResult<std::sync::Arc<dyn crate::ports::embedding_port::EmbeddingPort + std::marker::Send + std::marker::Sync>> {
         match self.provider.as_str() {
             "ollama" => {
-                // Get embedding model from environment or use default
-                let embedding_model = std::env::var("OLLAMA_EMBEDDING_MODEL")
-                    .unwrap_or_else(|_| "nomic-embed-text".to_string());
+                // Get embedding model from the override, environment, or default
+                let embedding_model = self.embedding_model.clone()
+                    .or_else(|| std::env::var("OLLAMA_EMBEDDING_MODEL").ok())
+                    .unwrap_or_else(|| "nomic-embed-text".to_string());
 
                 let adapter = crate::adapters::rig_embedding_adapter::RigEmbeddingAdapter::new_ollama(
                     embedding_model,
-                );
+                )
+                .with_auto_pull(self.auto_pull)
+                .with_retry_policy(self.retry_policy());
                 std::result::Result::Ok(std::sync::Arc::new(adapter))
             }
             "openai" => {
@@ -530,14 +845,30 @@ impl ProviderFactory {
                     )
                 })?;
 
-                // Get embedding model from environment or use default
-                let embedding_model = std::env::var("OPENAI_EMBEDDING_MODEL")
-                    .unwrap_or_else(|_| "text-embedding-3-small".to_string());
+                // Get embedding model from the override, environment, or default
+                let embedding_model = self.embedding_model.clone()
+                    .or_else(|| std::env::var("OPENAI_EMBEDDING_MODEL").ok())
+                    .unwrap_or_else(|| "text-embedding-3-small".to_string());
 
                 let adapter = crate::adapters::rig_embedding_adapter::RigEmbeddingAdapter::new_openai(
                     api_key,
                     embedding_model,
-                );
+                )
+                .with_retry_policy(self.retry_policy());
+                std::result::Result::Ok(std::sync::Arc::new(adapter))
+            }
+            "openai-compatible" => {
+                // Same OpenAI code path against the configured server; the key is optional
+                let embedding_model = self.embedding_model.clone()
+                    .or_else(|| std::env::var("OPENAI_COMPATIBLE_EMBEDDING_MODEL").ok())
+                    .unwrap_or_else(|| self.model.clone());
+
+                let adapter = crate::adapters::rig_embedding_adapter::RigEmbeddingAdapter::new_openai_compatible(
+                    self.openai_compatible_base_url(),
+                    self.api_key.clone(),
+                    embedding_model,
+                )
+                .with_retry_policy(self.retry_policy());
                 std::result::Result::Ok(std::sync::Arc::new(adapter))
             }
             "anthropic" | "mlx" => {
@@ -590,7 +921,8 @@ impl ProviderFactory {
 
                 let adapter = crate::adapters::rig_vision_adapter::RigVisionAdapter::new_ollama(
                     vision_model,
-                );
+                )
+                .with_retry_policy(self.retry_policy());
                 std::result::Result::Ok(std::sync::Arc::new(adapter))
             }
             "openai" => {
@@ -609,7 +941,8 @@ impl ProviderFactory {
                 let adapter = crate::adapters::rig_vision_adapter::RigVisionAdapter::new_openai(
                     api_key,
                     vision_model,
-                );
+                )
+                .with_retry_policy(self.retry_policy());
                 std::result::Result::Ok(std::sync::Arc::new(adapter))
             }
             "anthropic" => {
@@ -628,14 +961,15 @@ impl ProviderFactory {
                 let adapter = crate::adapters::rig_vision_adapter::RigVisionAdapter::new_anthropic(
                     api_key,
                     vision_model,
-                );
+                )
+                .with_retry_policy(self.retry_policy());
                 std::result::Result::Ok(std::sync::Arc::new(adapter))
             }
-            "mlx" => {
-                // MLX is for local inference and doesn't have vision models
+            "mlx" | "openai-compatible" => {
+                // MLX is for local inference and doesn't have vision models; compatible servers vary too much to assume one
                 std::result::Result::Err(hexser::Hexserror::adapter(
                     "NOT_SUPPORTED",
-                    "Vision capability not supported for MLX provider. Use ollama, openai, or anthropic."
+                    &std::format!("Vision capability not supported for provider: {}. Use ollama, openai, or anthropic.", self.provider)
                 ))
             }
             _ => std::result::Result::Err(hexser::Hexserror::adapter(
@@ -644,6 +978,126 @@ impl ProviderFactory {
             )),
         }
     }
+
+    /// Creates an LLMAgentPort chat adapter (without tools) for the configured provider.
+    ///
+    /// "openai-compatible" uses the OpenAI client against the configured base URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Provider has no chat adapter (Anthropic, MLX)
+    /// - Required API keys are missing
+    pub fn create_agent_adapter(
+        &self,
+    ) -> hexser::HexResult<std::sync::Arc<dyn crate::ports::llm_agent_port::LLMAgentPort + std::marker::Send + std::marker::Sync>> {
+        match self.provider.as_str() {
+            "ollama" => {
+                let adapter = crate::adapters::rig_agent_adapter::RigAgentAdapter::new_ollama(
+                    String::from("http://localhost:11434"),
+                    self.model.clone(),
+                )
+                .with_auto_pull(self.auto_pull)
+                .with_retry_policy(self.retry_policy());
+                std::result::Result::Ok(std::sync::Arc::new(adapter))
+            }
+            "openai" => {
+                let api_key = std::env::var("OPENAI_API_KEY").map_err(|_| {
+                    hexser::Hexserror::adapter(
+                        "MISSING_API_KEY",
+                        "OPENAI_API_KEY environment variable is required for OpenAI provider"
+                    )
+                })?;
+
+                let adapter = crate::adapters::rig_agent_adapter::RigAgentAdapter::new_openai(api_key, self.model.clone())
+                    .with_retry_policy(self.retry_policy());
+                std::result::Result::Ok(std::sync::Arc::new(adapter))
+            }
+            "openai-compatible" => {
+                let adapter = crate::adapters::rig_agent_adapter::RigAgentAdapter::new_openai_compatible_with_tools(
+                    self.openai_compatible_base_url(),
+                    self.api_key.clone(),
+                    self.model.clone(),
+                    std::option::Option::None,
+                    std::option::Option::None,
+                    std::option::Option::None,
+                )
+                .with_retry_policy(self.retry_policy());
+                std::result::Result::Ok(std::sync::Arc::new(adapter))
+            }
+            "anthropic" | "mlx" => std::result::Result::Err(hexser::Hexserror::adapter(
+                "NOT_SUPPORTED",
+                &std::format!("Chat agent not supported for provider: {}. Use ollama, openai, or openai-compatible.", self.provider)
+            )),
+            _ => std::result::Result::Err(hexser::Hexserror::adapter(
+                "UNSUPPORTED_PROVIDER",
+                &std::format!("Unsupported provider: {}", self.provider)
+            )),
+        }
+    }
+
+    /// Creates a RerankPort adapter that scores passages with the provider's chat model.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `create_agent_adapter`.
+    pub fn create_rerank_adapter(
+        &self,
+    ) -> hexser::HexResult<std::sync::Arc<dyn crate::ports::rerank_port::RerankPort + std::marker::Send + std::marker::Sync>> {
+        let agent = self.create_agent_adapter()?;
+        std::result::Result::Ok(std::sync::Arc::new(crate::adapters::llm_rerank_adapter::LlmRerankAdapter::new(agent)))
+    }
+
+    /// Creates a ModelListPort adapter for the configured provider.
+    ///
+    /// Unlike the other adapters, the endpoint and key come from the caller
+    /// (typically a `.rigger/config.json` provider entry) so any configured
+    /// provider instance can be queried.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - Provider base URL
+    /// * `api_key` - API key, required for OpenAI and Anthropic, optional for "openai-compatible"
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Provider has no model-list endpoint (MLX)
+    /// - Required API key is missing
+    pub fn create_model_list_adapter(
+        &self,
+        base_url: &str,
+        api_key: std::option::Option<String>,
+    ) -> hexser::HexResult<std::sync::Arc<dyn crate::ports::model_list_port::ModelListPort + std::marker::Send + std::marker::Sync>> {
+        let api = match self.provider.as_str() {
+            "ollama" => crate::adapters::reqwest_model_list_adapter::ModelListApi::Ollama,
+            "openai" | "openai-compatible" => crate::adapters::reqwest_model_list_adapter::ModelListApi::OpenAI,
+            "anthropic" => crate::adapters::reqwest_model_list_adapter::ModelListApi::Anthropic,
+            "mlx" => {
+                return std::result::Result::Err(hexser::Hexserror::adapter(
+                    "NOT_SUPPORTED",
+                    "Model listing not supported for provider: mlx (models are loaded from the Hugging Face cache)"
+                ))
+            }
+            _ => {
+                return std::result::Result::Err(hexser::Hexserror::adapter(
+                    "UNSUPPORTED_PROVIDER",
+                    &std::format!("Unsupported provider: {}", self.provider)
+                ))
+            }
+        };
+
+        let key_optional = std::matches!(self.provider.as_str(), "ollama" | "openai-compatible");
+        if !key_optional && api_key.is_none() {
+            return std::result::Result::Err(hexser::Hexserror::adapter(
+                "MISSING_API_KEY",
+                &std::format!("An API key is required to list models for provider: {}", self.provider)
+            ));
+        }
+
+        let adapter = crate::adapters::reqwest_model_list_adapter::ReqwestModelListAdapter::new(api, base_url, api_key);
+        std::result::Result::Ok(std::sync::Arc::new(adapter))
+    }
 }
 
 #[cfg(test)]
@@ -779,6 +1233,19 @@ mod tests {
         std::assert!(adapter.is_ok());
     }
 
+    #[test]
+    fn test_decomposition_config_comes_from_caller() {
+        // Test: Validates decomposition settings default without a caller config and follow with_decomposition_config.
+        // Justification: The factory must not read whatever .rigger the working directory holds.
+        let factory = ProviderFactory::new("ollama", "llama3.1").unwrap();
+        std::assert_eq!(factory.decomposition, crate::infrastructure::config::DecompositionConfig::default());
+
+        let configured = crate::infrastructure::config::DecompositionConfig { complexity_threshold: 9, max_depth: 1 };
+        let factory = factory.with_decomposition_config(configured);
+        std::assert_eq!(factory.decomposition, configured);
+        std::assert!(factory.create_task_decomposition_adapter().is_ok());
+    }
+
     #[test]
     fn test_heterogeneous_pipeline_uses_different_models() {
         // Test: Validates different roles get different models.
@@ -873,6 +1340,13 @@ mod tests {
         let factory = ProviderFactory::new("ollama", "llama3.1").unwrap();
         let adapter = factory.create_embedding_adapter();
         std::assert!(adapter.is_ok());
+        std::assert_eq!(adapter.unwrap().embedding_model().as_deref(), std::option::Option::Some("all-minilm"));
+
+        let overridden = ProviderFactory::new("ollama", "llama3.1").unwrap()
+            .with_embedding_model("mxbai-embed-large")
+            .create_embedding_adapter()
+            .unwrap();
+        std::assert_eq!(overridden.embedding_model().as_deref(), std::option::Option::Some("mxbai-embed-large"));
 
         // Cleanup
         unsafe {
@@ -953,4 +1427,58 @@ mod tests {
             std::env::remove_var("OLLAMA_VISION_MODEL");
         }
     }
+
+    #[test]
+    fn test_create_model_list_adapter_requirements() {
+        // Test: Validates MLX has no model list and OpenAI needs an API key, while Ollama needs neither.
+        // Justification: `rig models` reports these errors per provider instead of sending doomed requests.
+        let ollama = ProviderFactory::new("ollama", "llama3.1").unwrap();
+        let openai = ProviderFactory::new("openai", "gpt-4").unwrap();
+        let mlx = ProviderFactory::new("mlx", "mlx-community/Phi-3-mini-4k-instruct").unwrap();
+
+        std::assert!(ollama.create_model_list_adapter("http://localhost:11434", std::option::Option::None).is_ok());
+        let missing_key = openai.create_model_list_adapter("https://api.openai.com/v1", std::option::Option::None);
+        std::assert!(missing_key.err().unwrap().to_string().contains("API key"));
+        let unsupported = mlx.create_model_list_adapter("", std::option::Option::None);
+        std::assert!(unsupported.err().unwrap().to_string().contains("not supported"));
+    }
+
+    #[test]
+    fn test_offline_mode_rejects_cloud_provider() {
+        // Test: Validates offline mode refuses OpenAI and Anthropic at construction but accepts Ollama on localhost.
+        // Justification: Air-gapped installs must fail at startup, never at the first outbound request.
+        let openai = ProviderFactory::new("openai", "gpt-4").unwrap().with_offline(true);
+        std::assert!(openai.err().unwrap().to_string().contains("not allowed in offline mode"));
+        std::assert!(ProviderFactory::new("anthropic", "claude-3-5-sonnet-20241022").unwrap().with_offline(true).is_err());
+        std::assert!(ProviderFactory::new("openai", "gpt-4").unwrap().with_offline(false).is_ok());
+
+        let ollama = ProviderFactory::new("ollama", "llama3.1").unwrap().with_offline(true).unwrap();
+        std::assert!(ollama.is_offline());
+        std::assert!(ollama.create_embedding_adapter().is_ok());
+    }
+
+    #[test]
+    fn test_openai_compatible_provider_uses_base_url_and_optional_key() {
+        // Test: Validates "openai-compatible" builds chat, embedding, and model list adapters without a key, and offline mode judges its base URL.
+        // Justification: Local inference servers run at arbitrary URLs, usually without keys, and are exactly what offline mode should allow.
+        let local = ProviderFactory::new("openai-compatible", "qwen2.5-7b-instruct")
+            .unwrap()
+            .with_base_url("http://localhost:1234/v1")
+            .with_api_key(std::option::Option::None);
+
+        std::assert_eq!(local.endpoint().as_deref(), std::option::Option::Some("http://localhost:1234/v1"));
+        std::assert!(local.create_agent_adapter().is_ok());
+        std::assert!(local.create_embedding_adapter().is_ok());
+        std::assert!(local.create_model_list_adapter("http://localhost:1234/v1", std::option::Option::None).is_ok());
+        std::assert!(local.clone().with_offline(true).is_ok());
+
+        let remote = ProviderFactory::new("openai-compatible", "llama-3-70b")
+            .unwrap()
+            .with_base_url("https://inference.example.com/v1")
+            .with_offline(true);
+        std::assert!(remote.err().unwrap().to_string().contains("not allowed in offline mode"));
+
+        let default_url = ProviderFactory::new("openai-compatible", "default").unwrap();
+        std::assert_eq!(default_url.endpoint().as_deref(), std::option::Option::Some(DEFAULT_OPENAI_COMPATIBLE_BASE_URL));
+    }
 }
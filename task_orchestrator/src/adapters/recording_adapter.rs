@@ -0,0 +1,105 @@
+//! Recording decorator for the orchestrator's LLM ports.
+//!
+//! `RecordingAdapter` wraps an enhancement, comprehension-test, or
+//! decomposition port and appends every call's result (errors included) to
+//! the run's `RunRecorder`, so the run can later be replayed without the
+//! provider. Results are passed through unchanged.
+//!
+//! Revision History
+//! - 2026-10-18T02:30:00Z @AI: Record answer_comprehension_test calls.
+//! - 2026-10-17T08:10:00Z @AI: Initial recording adapter for replayable run logs.
+
+/// Port decorator that records every provider response in a RunRecorder.
+///
+/// # Examples
+///
+/// ```
+/// # fn example(
+/// #     inner: std::sync::Arc<dyn task_orchestrator::ports::task_enhancement_port::TaskEnhancementPort>,
+/// #     log: task_orchestrator::domain::run_log::RunLog,
+/// # ) {
+/// use task_orchestrator::adapters::recording_adapter::RecordingAdapter;
+/// use task_orchestrator::domain::run_log::RunRecorder;
+///
+/// let recorder = std::sync::Arc::new(RunRecorder::new(log));
+/// let recorded: std::sync::Arc<dyn task_orchestrator::ports::task_enhancement_port::TaskEnhancementPort> =
+///     std::sync::Arc::new(RecordingAdapter::new(inner, recorder));
+/// # }
+/// ```
+pub struct RecordingAdapter<P: ?Sized> {
+    inner: std::sync::Arc<P>,
+    recorder: std::sync::Arc<crate::domain::run_log::RunRecorder>,
+}
+
+impl<P: ?Sized> RecordingAdapter<P> {
+    /// Wraps `inner` so every call's result is recorded in `recorder`.
+    pub fn new(inner: std::sync::Arc<P>, recorder: std::sync::Arc<crate::domain::run_log::RunRecorder>) -> Self {
+        Self { inner, recorder }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::ports::task_enhancement_port::TaskEnhancementPort
+    for RecordingAdapter<dyn crate::ports::task_enhancement_port::TaskEnhancementPort>
+{
+    async fn generate_enhancement(
+        &self,
+        task: &task_manager::domain::task::Task,
+    ) -> std::result::Result<task_manager::domain::enhancement::Enhancement, std::string::String> {
+        let result = self.inner.generate_enhancement(task).await;
+        self.recorder.record_call(crate::domain::run_log::ProviderCall::Enhancement {
+            task_id: task.id.clone(),
+            result: result.clone(),
+        });
+        result
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::ports::comprehension_test_port::ComprehensionTestPort
+    for RecordingAdapter<dyn crate::ports::comprehension_test_port::ComprehensionTestPort>
+{
+    async fn generate_comprehension_test(
+        &self,
+        task: &task_manager::domain::task::Task,
+        test_type: &str,
+    ) -> std::result::Result<task_manager::domain::comprehension_test::ComprehensionTest, std::string::String> {
+        let result = self.inner.generate_comprehension_test(task, test_type).await;
+        self.recorder.record_call(crate::domain::run_log::ProviderCall::ComprehensionTest {
+            task_id: task.id.clone(),
+            test_type: std::string::String::from(test_type),
+            result: result.clone(),
+        });
+        result
+    }
+
+    async fn answer_comprehension_test(
+        &self,
+        task: &task_manager::domain::task::Task,
+        test: &task_manager::domain::comprehension_test::ComprehensionTest,
+    ) -> std::result::Result<std::option::Option<std::string::String>, std::string::String> {
+        let result = self.inner.answer_comprehension_test(task, test).await;
+        self.recorder.record_call(crate::domain::run_log::ProviderCall::ComprehensionAnswer {
+            task_id: task.id.clone(),
+            result: result.clone(),
+        });
+        result
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::ports::task_decomposition_port::TaskDecompositionPort
+    for RecordingAdapter<dyn crate::ports::task_decomposition_port::TaskDecompositionPort>
+{
+    async fn decompose_task(
+        &self,
+        task: &task_manager::domain::task::Task,
+    ) -> std::result::Result<std::vec::Vec<task_manager::domain::task::Task>, std::string::String> {
+        let result = self.inner.decompose_task(task).await;
+        self.recorder.record_call(crate::domain::run_log::ProviderCall::Decomposition {
+            task_id: task.id.clone(),
+            result: result.clone(),
+        });
+        result
+    }
+}
@@ -0,0 +1,143 @@
+//! Replays the provider responses recorded in a RunLog.
+//!
+//! `ReplayAdapter` implements the enhancement, comprehension-test, and
+//! decomposition ports by handing out the recorded calls in order instead of
+//! calling a provider. Each call must match the next recorded one in kind and
+//! task ID; a mismatch means the decision logic took a different path than
+//! the recorded run, and the call fails with a "Replay diverged" error rather
+//! than returning a response meant for another step.
+//!
+//! Revision History
+//! - 2026-10-18T02:30:00Z @AI: Replay recorded comprehension test answers.
+//! - 2026-10-17T08:10:00Z @AI: Initial replay adapter for recorded run logs.
+
+/// Serves recorded provider responses in call order.
+///
+/// # Examples
+///
+/// ```
+/// # fn example(log: &task_orchestrator::domain::run_log::RunLog) {
+/// use task_orchestrator::adapters::replay_adapter::ReplayAdapter;
+///
+/// let replay = std::sync::Arc::new(ReplayAdapter::new(log.provider_calls.clone()));
+/// let enhancer: std::sync::Arc<dyn task_orchestrator::ports::task_enhancement_port::TaskEnhancementPort> = replay.clone();
+/// let tester: std::sync::Arc<dyn task_orchestrator::ports::comprehension_test_port::ComprehensionTestPort> = replay;
+/// # }
+/// ```
+pub struct ReplayAdapter {
+    calls: std::sync::Mutex<std::collections::VecDeque<crate::domain::run_log::ProviderCall>>,
+}
+
+impl ReplayAdapter {
+    /// Creates an adapter that serves `calls` in order.
+    pub fn new(calls: std::vec::Vec<crate::domain::run_log::ProviderCall>) -> Self {
+        ReplayAdapter { calls: std::sync::Mutex::new(calls.into()) }
+    }
+
+    /// Number of recorded calls not requested yet.
+    pub fn remaining(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Takes the next recorded call, checking it is a `kind` call for `task_id`.
+    fn next(&self, kind: &str, task_id: &str) -> std::result::Result<crate::domain::run_log::ProviderCall, std::string::String> {
+        let mut calls = self.lock();
+        match calls.front() {
+            std::option::Option::Some(call) if call.kind() == kind && call.task_id() == task_id => {
+                std::result::Result::Ok(calls.pop_front().expect("front exists"))
+            }
+            std::option::Option::Some(call) => std::result::Result::Err(std::format!(
+                "Replay diverged: requested {} for task {}, but the next recorded call is {} for task {}",
+                kind,
+                task_id,
+                call.kind(),
+                call.task_id()
+            )),
+            std::option::Option::None => std::result::Result::Err(std::format!(
+                "Replay diverged: requested {} for task {}, but no recorded calls are left",
+                kind, task_id
+            )),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, std::collections::VecDeque<crate::domain::run_log::ProviderCall>> {
+        self.calls.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::ports::task_enhancement_port::TaskEnhancementPort for ReplayAdapter {
+    async fn generate_enhancement(
+        &self,
+        task: &task_manager::domain::task::Task,
+    ) -> std::result::Result<task_manager::domain::enhancement::Enhancement, std::string::String> {
+        match self.next("enhancement", &task.id)? {
+            crate::domain::run_log::ProviderCall::Enhancement { result, .. } => result,
+            _ => unreachable!("next() checked the kind"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::ports::comprehension_test_port::ComprehensionTestPort for ReplayAdapter {
+    async fn generate_comprehension_test(
+        &self,
+        task: &task_manager::domain::task::Task,
+        _test_type: &str,
+    ) -> std::result::Result<task_manager::domain::comprehension_test::ComprehensionTest, std::string::String> {
+        match self.next("comprehension_test", &task.id)? {
+            crate::domain::run_log::ProviderCall::ComprehensionTest { result, .. } => result,
+            _ => unreachable!("next() checked the kind"),
+        }
+    }
+
+    async fn answer_comprehension_test(
+        &self,
+        task: &task_manager::domain::task::Task,
+        _test: &task_manager::domain::comprehension_test::ComprehensionTest,
+    ) -> std::result::Result<std::option::Option<std::string::String>, std::string::String> {
+        match self.next("comprehension_answer", &task.id)? {
+            crate::domain::run_log::ProviderCall::ComprehensionAnswer { result, .. } => result,
+            _ => unreachable!("next() checked the kind"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::ports::task_decomposition_port::TaskDecompositionPort for ReplayAdapter {
+    async fn decompose_task(
+        &self,
+        task: &task_manager::domain::task::Task,
+    ) -> std::result::Result<std::vec::Vec<task_manager::domain::task::Task>, std::string::String> {
+        match self.next("decomposition", &task.id)? {
+            crate::domain::run_log::ProviderCall::Decomposition { result, .. } => result,
+            _ => unreachable!("next() checked the kind"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn test_out_of_order_call_diverges() {
+        // Test: Validates a call that doesn't match the next recorded one fails instead of returning another step's response.
+        // Justification: A replay that silently reuses responses would hide the divergence being debugged.
+        let ai = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Title"),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let task = task_manager::domain::task::Task::from_action_item(&ai, std::option::Option::None);
+        let adapter = super::ReplayAdapter::new(std::vec![crate::domain::run_log::ProviderCall::Decomposition {
+            task_id: task.id.clone(),
+            result: std::result::Result::Ok(std::vec::Vec::new()),
+        }]);
+
+        let error = crate::ports::task_enhancement_port::TaskEnhancementPort::generate_enhancement(&adapter, &task)
+            .await
+            .unwrap_err();
+
+        std::assert!(error.starts_with("Replay diverged: requested enhancement"), "{}", error);
+        std::assert_eq!(adapter.remaining(), 1);
+    }
+}
@@ -0,0 +1,150 @@
+//! HTTP adapter implementing ModelListPort via provider model-list endpoints.
+//!
+//! Ollama lists locally pulled models at `GET /api/tags` (`models[].name`).
+//! OpenAI and Anthropic list models at `GET <base_url>/models` (`data[].id`),
+//! where the configured base URL already includes the API version.
+//!
+//! Revision History
+//! - 2026-10-16T19:10:00Z @AI: Initial reqwest model list adapter for `rig models`.
+
+/// Wire format of a provider's model-list endpoint.
+///
+/// # Variants
+///
+/// * `Ollama` - `GET /api/tags`, no authentication.
+/// * `OpenAI` - `GET /models` with a bearer token.
+/// * `Anthropic` - `GET /models` with `x-api-key` and `anthropic-version` headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModelListApi {
+    /// `GET /api/tags`, no authentication.
+    Ollama,
+
+    /// `GET /models` with a bearer token.
+    OpenAI,
+
+    /// `GET /models` with `x-api-key` and `anthropic-version` headers.
+    Anthropic,
+}
+
+/// Reqwest-based adapter for listing a provider's models.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use task_orchestrator::adapters::reqwest_model_list_adapter::{ModelListApi, ReqwestModelListAdapter};
+/// # use task_orchestrator::ports::model_list_port::ModelListPort;
+/// # async fn example() {
+/// let lister = ReqwestModelListAdapter::new(ModelListApi::Ollama, "http://localhost:11434", None);
+/// let models = lister.list_models().await.unwrap();
+/// # }
+/// ```
+pub struct ReqwestModelListAdapter {
+    api: ModelListApi,
+    base_url: String,
+    api_key: std::option::Option<String>,
+    client: reqwest::Client,
+}
+
+impl ReqwestModelListAdapter {
+    /// Creates a new model list adapter.
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - Wire format of the provider's model-list endpoint
+    /// * `base_url` - Provider base URL (e.g. `http://localhost:11434`, `https://api.openai.com/v1`)
+    /// * `api_key` - API key, if the provider requires one
+    pub fn new(api: ModelListApi, base_url: &str, api_key: std::option::Option<String>) -> Self {
+        Self {
+            api,
+            base_url: std::string::String::from(base_url.trim_end_matches('/')),
+            api_key,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Returns the model-list endpoint URL.
+    pub fn endpoint(&self) -> String {
+        match self.api {
+            ModelListApi::Ollama => std::format!("{}/api/tags", self.base_url),
+            ModelListApi::OpenAI | ModelListApi::Anthropic => std::format!("{}/models", self.base_url),
+        }
+    }
+
+    /// Extracts model IDs from a model-list response body.
+    fn parse_models(&self, body: &serde_json::Value) -> std::result::Result<std::vec::Vec<String>, String> {
+        let (list_key, id_key) = match self.api {
+            ModelListApi::Ollama => ("models", "name"),
+            ModelListApi::OpenAI | ModelListApi::Anthropic => ("data", "id"),
+        };
+        let entries = body
+            .get(list_key)
+            .and_then(|entries| entries.as_array())
+            .ok_or_else(|| std::format!("Unexpected response from {}: missing '{}' array", self.endpoint(), list_key))?;
+
+        let mut models: std::vec::Vec<String> = entries
+            .iter()
+            .filter_map(|entry| entry.get(id_key).and_then(|id| id.as_str()))
+            .map(std::string::String::from)
+            .collect();
+        models.sort();
+        std::result::Result::Ok(models)
+    }
+}
+
+/// Returns the innermost cause of a request error (e.g. "Connection refused").
+fn root_cause(error: &reqwest::Error) -> String {
+    let mut cause: &dyn std::error::Error = error;
+    while let std::option::Option::Some(source) = cause.source() {
+        cause = source;
+    }
+    cause.to_string()
+}
+
+#[async_trait::async_trait]
+impl crate::ports::model_list_port::ModelListPort for ReqwestModelListAdapter {
+    async fn list_models(&self) -> std::result::Result<std::vec::Vec<std::string::String>, std::string::String> {
+        let mut request = self.client.get(self.endpoint());
+        if let std::option::Option::Some(key) = &self.api_key {
+            request = match self.api {
+                ModelListApi::Anthropic => request
+                    .header("x-api-key", key)
+                    .header("anthropic-version", "2023-06-01"),
+                ModelListApi::OpenAI | ModelListApi::Ollama => request.bearer_auth(key),
+            };
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| std::format!("Failed to reach {}: {}", self.endpoint(), root_cause(&e)))?;
+        let status = response.status();
+        if !status.is_success() {
+            return std::result::Result::Err(std::format!("{} returned HTTP {}", self.endpoint(), status));
+        }
+
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| std::format!("Invalid JSON from {}: {}", self.endpoint(), e))?;
+        self.parse_models(&body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_parse_models_per_api() {
+        // Test: Validates Ollama tag lists and OpenAI-style data lists are both parsed and sorted.
+        // Justification: The two response shapes differ; mixing them up yields an empty list.
+        let ollama = super::ReqwestModelListAdapter::new(super::ModelListApi::Ollama, "http://localhost:11434/", std::option::Option::None);
+        let openai = super::ReqwestModelListAdapter::new(super::ModelListApi::OpenAI, "https://api.openai.com/v1", std::option::Option::None);
+
+        let tags = serde_json::json!({"models": [{"name": "llama3.2:latest"}, {"name": "codellama:7b"}]});
+        let data = serde_json::json!({"object": "list", "data": [{"id": "gpt-4o"}, {"id": "gpt-4o-mini"}]});
+
+        std::assert_eq!(ollama.endpoint(), "http://localhost:11434/api/tags");
+        std::assert_eq!(ollama.parse_models(&tags).unwrap(), std::vec!["codellama:7b", "llama3.2:latest"]);
+        std::assert_eq!(openai.parse_models(&data).unwrap(), std::vec!["gpt-4o", "gpt-4o-mini"]);
+        std::assert!(openai.parse_models(&tags).is_err());
+    }
+}
@@ -2,9 +2,27 @@
 //!
 //! This adapter implements WebCrawlerPort using reqwest for HTTP requests and
 //! the scraper crate for HTML parsing and content extraction. It supports
-//! following links within the same domain, rate limiting, and robots.txt.
+//! following links within the crawl scope (same host by default, optionally
+//! subdomains and a path prefix) and per-host pacing: requests to one host are
+//! spaced by the configured delay or the host's robots.txt `Crawl-delay`,
+//! whichever is longer, even when several workers share the crawler.
+//! Responses outside the config's content-type allowlist are skipped; HTML is
+//! converted to markdown and PDFs keep their raw bytes for ingestion.
+//! With a frontier store attached, every visit is saved as it happens so an
+//! interrupted crawl can resume without refetching pages. With a validator
+//! store attached, re-crawls send `If-None-Match` / `If-Modified-Since` and
+//! treat 304 replies as unchanged pages, following their saved links. A
+//! page's validators are only saved once `page_stored` reports its artifacts
+//! were stored, so a failed ingestion is retried on the next crawl.
 //!
 //! Revision History
+//! - 2026-10-18T04:00:00Z @AI: Report each fetched or resumed page through crawl_with_progress.
+//! - 2026-10-17T22:30:00Z @AI: Hold fetched validators until page_stored instead of saving them before ingestion.
+//! - 2026-10-17T13:30:00Z @AI: Send conditional requests from saved ETag/Last-Modified and skip unchanged (304) pages.
+//! - 2026-10-17T13:10:00Z @AI: Persist the crawl frontier through an optional CrawlFrontierPort and resume from it.
+//! - 2026-10-17T12:50:00Z @AI: Pace requests per host with HostPacer, honoring robots.txt Crawl-delay.
+//! - 2026-10-17T12:30:00Z @AI: Scope crawls to the start host, optional subdomains, and a path prefix; record out-of-scope links.
+//! - 2026-10-17T12:10:00Z @AI: Convert HTML to markdown, skip disallowed content types, and keep PDF bytes.
 //! - 2025-11-30T19:50:00Z @AI: Initial ReqwestWebCrawler adapter for Phase 3 artifact generator.
 
 /// Web crawler using reqwest and scraper.
@@ -29,6 +47,69 @@
 pub struct ReqwestWebCrawler {
     /// HTTP client for making requests.
     client: reqwest::Client,
+
+    /// Per-host request pacing shared by every caller of this crawler.
+    pacer: HostPacer,
+
+    /// Where crawl progress is saved for `CrawlConfig::resume` (none = in memory only).
+    frontier: std::option::Option<std::sync::Arc<dyn crate::ports::crawl_frontier_port::CrawlFrontierPort>>,
+
+    /// Where ETag/Last-Modified are saved for conditional re-crawls (none = always refetch).
+    validators: std::option::Option<std::sync::Arc<dyn crate::ports::page_validator_port::PageValidatorPort>>,
+
+    /// Validators of fetched pages waiting for `page_stored`, by normalized URL.
+    pending_validators: std::sync::Mutex<std::collections::HashMap<String, crate::domain::crawl_result::PageValidators>>,
+}
+
+/// Spaces requests to the same host by a minimum delay.
+///
+/// Each call reserves the host's next free slot under a lock and then sleeps
+/// until it, so concurrent callers queue up per host while requests to other
+/// hosts are not delayed. Crawl-delay values read from robots.txt are cached
+/// per host.
+struct HostPacer {
+    /// Earliest instant the next request to each host may start.
+    next_slot: std::sync::Mutex<std::collections::HashMap<String, tokio::time::Instant>>,
+
+    /// robots.txt Crawl-delay per host (`None` when absent or unreadable).
+    robots_delays: std::sync::Mutex<std::collections::HashMap<String, std::option::Option<std::time::Duration>>>,
+}
+
+impl HostPacer {
+    fn new() -> Self {
+        HostPacer {
+            next_slot: std::sync::Mutex::new(std::collections::HashMap::new()),
+            robots_delays: std::sync::Mutex::new(std::collections::HashMap::new()),
+        }
+    }
+
+    /// Waits until a request to `host` may start, at least `delay` after the previous one.
+    async fn wait(&self, host: &str, delay: std::time::Duration) {
+        let slot = {
+            let mut slots = self.next_slot.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+            let now = tokio::time::Instant::now();
+            let slot = slots.get(host).map_or(now, |next| (*next).max(now));
+            slots.insert(host.to_string(), slot + delay);
+            slot
+        };
+        tokio::time::sleep_until(slot).await;
+    }
+
+    /// Returns the cached robots.txt Crawl-delay for `host`, if it has been fetched.
+    fn cached_robots_delay(&self, host: &str) -> std::option::Option<std::option::Option<std::time::Duration>> {
+        self.robots_delays
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(host)
+            .copied()
+    }
+
+    fn cache_robots_delay(&self, host: &str, delay: std::option::Option<std::time::Duration>) {
+        self.robots_delays
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .insert(host.to_string(), delay);
+    }
 }
 
 impl ReqwestWebCrawler {
@@ -39,12 +120,120 @@ impl ReqwestWebCrawler {
             .build()
             .unwrap_or_else(|_| reqwest::Client::new());
 
-        ReqwestWebCrawler { client }
+        Self::with_client(client)
     }
 
     /// Creates a crawler with a custom HTTP client.
     pub fn with_client(client: reqwest::Client) -> Self {
-        ReqwestWebCrawler { client }
+        ReqwestWebCrawler {
+            client,
+            pacer: HostPacer::new(),
+            frontier: std::option::Option::None,
+            validators: std::option::Option::None,
+            pending_validators: std::sync::Mutex::new(std::collections::HashMap::new()),
+        }
+    }
+
+    /// Saves crawl progress to `frontier` so `CrawlConfig::resume` can continue an interrupted crawl.
+    pub fn with_frontier(
+        mut self,
+        frontier: std::sync::Arc<dyn crate::ports::crawl_frontier_port::CrawlFrontierPort>,
+    ) -> Self {
+        self.frontier = std::option::Option::Some(frontier);
+        self
+    }
+
+    /// Saves each stored page's ETag/Last-Modified to `validators` and sends
+    /// them as conditional headers on later crawls, unless `CrawlConfig::force`
+    /// is set. Validators are saved by `page_stored`, not when a page is fetched.
+    pub fn with_validators(
+        mut self,
+        validators: std::sync::Arc<dyn crate::ports::page_validator_port::PageValidatorPort>,
+    ) -> Self {
+        self.validators = std::option::Option::Some(validators);
+        self
+    }
+
+    /// Returns the key a crawl's frontier is saved under (its normalized start URL).
+    pub fn crawl_key(config: &crate::domain::crawl_result::CrawlConfig) -> String {
+        Self::normalize_url(&config.start_url)
+    }
+
+    /// Returns the Crawl-delay that applies to `user_agent` in a robots.txt file.
+    ///
+    /// A group naming the crawler (matched case-insensitively against the
+    /// start of the User-Agent) wins over the `*` group.
+    fn parse_crawl_delay(robots_txt: &str, user_agent: &str) -> std::option::Option<std::time::Duration> {
+        let agent = user_agent.to_lowercase();
+        let mut group_agents: std::vec::Vec<String> = std::vec::Vec::new();
+        let mut in_rules = false;
+        let mut wildcard = std::option::Option::None;
+        let mut specific = std::option::Option::None;
+
+        for line in robots_txt.lines() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            let std::option::Option::Some((field, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+            match field.trim().to_lowercase().as_str() {
+                "user-agent" => {
+                    if in_rules {
+                        group_agents.clear();
+                        in_rules = false;
+                    }
+                    group_agents.push(value.to_lowercase());
+                }
+                "crawl-delay" => {
+                    in_rules = true;
+                    let std::result::Result::Ok(seconds) = value.parse::<f64>() else {
+                        continue;
+                    };
+                    if !seconds.is_finite() || seconds < 0.0 {
+                        continue;
+                    }
+                    let delay = std::option::Option::Some(std::time::Duration::from_secs_f64(seconds));
+                    for name in &group_agents {
+                        if name == "*" {
+                            wildcard = delay;
+                        } else if agent.starts_with(name.as_str()) {
+                            specific = delay;
+                        }
+                    }
+                }
+                _ => in_rules = true,
+            }
+        }
+
+        specific.or(wildcard)
+    }
+
+    /// Returns the host's robots.txt Crawl-delay, fetching robots.txt once per host.
+    async fn robots_crawl_delay(
+        &self,
+        url: &reqwest::Url,
+        host: &str,
+        user_agent: &str,
+    ) -> std::option::Option<std::time::Duration> {
+        if let std::option::Option::Some(cached) = self.pacer.cached_robots_delay(host) {
+            return cached;
+        }
+
+        let mut robots_url = url.clone();
+        robots_url.set_path("/robots.txt");
+        robots_url.set_query(std::option::Option::None);
+        robots_url.set_fragment(std::option::Option::None);
+
+        let response = self.client.get(robots_url).header("User-Agent", user_agent).send().await;
+        let delay = match response {
+            std::result::Result::Ok(response) if response.status().is_success() => match response.text().await {
+                std::result::Result::Ok(body) => Self::parse_crawl_delay(&body, user_agent),
+                std::result::Result::Err(_) => std::option::Option::None,
+            },
+            _ => std::option::Option::None,
+        };
+        self.pacer.cache_robots_delay(host, delay);
+        delay
     }
 
     /// Extracts the base domain from a URL for same-domain checking.
@@ -68,6 +257,26 @@ impl ReqwestWebCrawler {
         }
     }
 
+    /// Returns true if `host` is the start host or, with `include_subdomains`,
+    /// one of its subdomains. A leading "www." on the start host is ignored
+    /// for the subdomain check, so www.example.com also admits docs.example.com.
+    fn host_in_scope(start_host: &str, host: &str, include_subdomains: bool) -> bool {
+        if host == start_host {
+            return true;
+        }
+        if !include_subdomains {
+            return false;
+        }
+        let root = start_host.strip_prefix("www.").unwrap_or(start_host);
+        host == root || host.ends_with(&std::format!(".{}", root))
+    }
+
+    /// Returns true if `path` is `prefix` or lies under it, matching whole segments.
+    fn path_in_scope(path: &str, prefix: &str) -> bool {
+        let prefix = prefix.trim_end_matches('/');
+        prefix.is_empty() || path == prefix || path.starts_with(&std::format!("{}/", prefix))
+    }
+
     /// Resolves a relative URL against a base URL.
     fn resolve_url(base: &str, relative: &str) -> std::option::Option<String> {
         if relative.starts_with("http://") || relative.starts_with("https://") {
@@ -95,6 +304,17 @@ impl crate::ports::web_crawler_port::WebCrawlerPort for ReqwestWebCrawler {
     ) -> std::result::Result<
         crate::ports::web_crawler_port::CrawlResult,
         crate::domain::crawl_result::CrawlError,
+    > {
+        self.crawl_with_progress(config, &|_| {}).await
+    }
+
+    async fn crawl_with_progress(
+        &self,
+        config: &crate::domain::crawl_result::CrawlConfig,
+        on_page: &(dyn Fn(&str) + std::marker::Send + std::marker::Sync),
+    ) -> std::result::Result<
+        crate::ports::web_crawler_port::CrawlResult,
+        crate::domain::crawl_result::CrawlError,
     > {
         let start_time = std::time::Instant::now();
         let mut result = crate::ports::web_crawler_port::CrawlResult::new();
@@ -107,15 +327,52 @@ impl crate::ports::web_crawler_port::WebCrawlerPort for ReqwestWebCrawler {
 
         // Track visited URLs to avoid duplicates
         let mut visited: std::collections::HashSet<String> = std::collections::HashSet::new();
+        let mut external: std::collections::HashSet<String> = std::collections::HashSet::new();
 
         // Queue of URLs to visit: (url, depth)
         let mut queue: std::collections::VecDeque<(String, usize)> =
             std::collections::VecDeque::new();
-        queue.push_back((start_url.clone(), 0));
+
+        // Resume from the saved frontier, or start a fresh one
+        let saved = match (&self.frontier, config.resume) {
+            (std::option::Option::Some(frontier), true) => frontier
+                .load(&start_url)
+                .await
+                .map_err(crate::domain::crawl_result::CrawlError::FrontierError)?,
+            _ => std::option::Option::None,
+        };
+        match saved {
+            std::option::Option::Some(saved) => {
+                visited.extend(saved.visited);
+                queue.extend(saved.queued);
+                for page in saved.pages {
+                    for link in &page.links {
+                        if !self.should_follow(link, &start_url, config) && external.insert(link.clone()) {
+                            result.external_links.push(link.clone());
+                        }
+                    }
+                    result.stats.links_found += page.links.len();
+                    result.stats.total_content_bytes += page.content.len();
+                    result.stats.pages_crawled += 1;
+                    result.stats.pages_resumed += 1;
+                    on_page(&page.url);
+                    result.pages.push(page);
+                }
+            }
+            std::option::Option::None => {
+                if let std::option::Option::Some(frontier) = &self.frontier {
+                    frontier
+                        .reset(&start_url, &start_url)
+                        .await
+                        .map_err(crate::domain::crawl_result::CrawlError::FrontierError)?;
+                }
+                queue.push_back((start_url.clone(), 0));
+            }
+        }
 
         while let std::option::Option::Some((url, depth)) = queue.pop_front() {
-            // Check limits
-            if result.stats.pages_crawled >= config.max_pages {
+            // Check limits (unchanged pages count as crawled)
+            if result.stats.pages_crawled + result.stats.pages_unchanged >= config.max_pages {
                 break;
             }
 
@@ -126,31 +383,73 @@ impl crate::ports::web_crawler_port::WebCrawlerPort for ReqwestWebCrawler {
             }
             visited.insert(normalized.clone());
 
-            // Apply rate limiting
-            if config.rate_limit_ms > 0 && result.stats.pages_crawled > 0 {
-                tokio::time::sleep(std::time::Duration::from_millis(config.rate_limit_ms)).await;
+            // Fetch page (fetch_page paces requests per host)
+            let mut queued_links: std::vec::Vec<(String, usize)> = std::vec::Vec::new();
+            let outcome = self.fetch_page(&url, config).await;
+
+            // An unchanged page's body was not sent, so follow the links saved with its validators
+            let unchanged_links = match (&outcome, &self.validators) {
+                (
+                    std::result::Result::Err(crate::domain::crawl_result::CrawlError::NotModified(_)),
+                    std::option::Option::Some(store),
+                ) => store.get(&normalized).await.ok().flatten().map(|v| v.links).unwrap_or_default(),
+                _ => std::vec::Vec::new(),
+            };
+            let links = match &outcome {
+                std::result::Result::Ok(page) => page.links.as_slice(),
+                std::result::Result::Err(_) => unchanged_links.as_slice(),
+            };
+
+            // Queue in-scope links within the depth limit; record the rest without fetching
+            for link in links {
+                if !self.should_follow(link, &start_url, config) {
+                    if external.insert(link.clone()) {
+                        result.external_links.push(link.clone());
+                    }
+                    continue;
+                }
+                if depth < config.max_depth && !visited.contains(link) {
+                    queued_links.push((link.clone(), depth + 1));
+                }
+            }
+
+            // Save the visit before moving on, so a crash never refetches it
+            if let std::option::Option::Some(frontier) = &self.frontier {
+                frontier
+                    .record_visit(&start_url, &url, &normalized, outcome.as_ref().ok(), &queued_links)
+                    .await
+                    .map_err(crate::domain::crawl_result::CrawlError::FrontierError)?;
             }
 
-            // Fetch page
-            match self.fetch_page(&url, config).await {
+            match outcome {
                 std::result::Result::Ok(page) => {
-                    // Extract and queue links if within depth limit
-                    if depth < config.max_depth {
-                        for link in &page.links {
-                            if !visited.contains(link)
-                                && self.should_follow(link, &start_url, config)
-                            {
-                                queue.push_back((link.clone(), depth + 1));
-                                result.stats.links_followed += 1;
-                            }
-                        }
-                    }
+                    result.stats.links_followed += queued_links.len();
+                    queue.extend(queued_links);
 
                     result.stats.links_found += page.links.len();
                     result.stats.total_content_bytes += page.content.len();
                     result.stats.pages_crawled += 1;
+                    on_page(&page.url);
                     result.pages.push(page);
                 }
+                std::result::Result::Err(crate::domain::crawl_result::CrawlError::NotModified(_)) => {
+                    result.stats.links_followed += queued_links.len();
+                    queue.extend(queued_links);
+
+                    result.stats.links_found += unchanged_links.len();
+                    result.stats.pages_unchanged += 1;
+                    result.unchanged.push(url);
+                }
+                std::result::Result::Err(
+                    e @ crate::domain::crawl_result::CrawlError::UnsupportedContentType { .. },
+                ) => {
+                    result.stats.pages_skipped += 1;
+                    result.skipped.push(crate::ports::web_crawler_port::CrawlPageError::new(
+                        url,
+                        std::option::Option::None,
+                        std::format!("{}", e),
+                    ));
+                }
                 std::result::Result::Err(e) => {
                     result.stats.pages_failed += 1;
                     result.errors.push(crate::ports::web_crawler_port::CrawlPageError::new(
@@ -175,11 +474,36 @@ impl crate::ports::web_crawler_port::WebCrawlerPort for ReqwestWebCrawler {
         crate::domain::crawl_result::CrawledPage,
         crate::domain::crawl_result::CrawlError,
     > {
-        // Build request with user agent
-        let response = self
-            .client
-            .get(url)
-            .header("User-Agent", &config.user_agent)
+        // Wait for this host's next slot
+        let parsed = reqwest::Url::parse(url)
+            .map_err(|_| crate::domain::crawl_result::CrawlError::InvalidUrl(url.to_string()))?;
+        if let std::option::Option::Some(host) = parsed.host_str() {
+            let mut delay = std::time::Duration::from_millis(config.rate_limit_ms);
+            if config.respect_robots_txt
+                && let std::option::Option::Some(robots_delay) =
+                    self.robots_crawl_delay(&parsed, host, &config.user_agent).await
+            {
+                delay = delay.max(robots_delay);
+            }
+            self.pacer.wait(host, delay).await;
+        }
+
+        // Build request with user agent, made conditional if an earlier crawl saved validators
+        let validator_key = Self::normalize_url(url);
+        let saved = match (&self.validators, config.force) {
+            (std::option::Option::Some(store), false) => store.get(&validator_key).await.ok().flatten(),
+            _ => std::option::Option::None,
+        };
+        let mut request = self.client.get(url).header("User-Agent", &config.user_agent);
+        if let std::option::Option::Some(saved) = &saved {
+            if let std::option::Option::Some(etag) = &saved.etag {
+                request = request.header("If-None-Match", etag);
+            }
+            if let std::option::Option::Some(last_modified) = &saved.last_modified {
+                request = request.header("If-Modified-Since", last_modified);
+            }
+        }
+        let response = request
             .send()
             .await
             .map_err(|e| {
@@ -188,6 +512,12 @@ impl crate::ports::web_crawler_port::WebCrawlerPort for ReqwestWebCrawler {
 
         let status = response.status().as_u16();
 
+        if status == 304 {
+            return std::result::Result::Err(crate::domain::crawl_result::CrawlError::NotModified(
+                url.to_string(),
+            ));
+        }
+
         // Check for rate limiting
         if status == 429 {
             return std::result::Result::Err(crate::domain::crawl_result::CrawlError::RateLimited(
@@ -209,29 +539,90 @@ impl crate::ports::web_crawler_port::WebCrawlerPort for ReqwestWebCrawler {
             .and_then(|h| h.to_str().ok())
             .unwrap_or("text/html")
             .to_string();
+        let header = |name: &str| {
+            response.headers().get(name).and_then(|h| h.to_str().ok()).map(String::from)
+        };
+        let etag = header("etag");
+        let last_modified = header("last-modified");
+
+        // Skip before reading the body so binaries are never downloaded
+        let kind = crate::domain::crawl_result::CrawlContentKind::from_content_type(&content_type);
+        if !config.allows_content_type(&content_type) || kind == crate::domain::crawl_result::CrawlContentKind::Other {
+            return std::result::Result::Err(
+                crate::domain::crawl_result::CrawlError::UnsupportedContentType {
+                    url: url.to_string(),
+                    content_type,
+                },
+            );
+        }
 
-        // Get HTML content
-        let html = response.text().await.map_err(|e| {
+        let read_error = |e: reqwest::Error| {
             crate::domain::crawl_result::CrawlError::NetworkError(std::format!(
                 "Failed to read body: {}",
                 e
             ))
-        })?;
+        };
 
-        // Extract content
-        let title = self.extract_title(&html);
-        let content = self.extract_text(&html, config);
-        let links = self.extract_links(&html, url);
-
-        std::result::Result::Ok(crate::domain::crawl_result::CrawledPage {
+        let mut page = crate::domain::crawl_result::CrawledPage {
             url: url.to_string(),
-            title,
-            content,
-            links,
+            title: String::new(),
+            content: String::new(),
+            links: std::vec::Vec::new(),
             depth: 0, // Caller sets this
             status_code: status,
             content_type,
-        })
+            raw_body: std::option::Option::None,
+        };
+
+        match kind {
+            crate::domain::crawl_result::CrawlContentKind::Html => {
+                let html = response.text().await.map_err(read_error)?;
+                page.title = self.extract_title(&html);
+                page.content = self.extract_text(&html, config);
+                page.links = self.extract_links(&html, url);
+            }
+            crate::domain::crawl_result::CrawlContentKind::Text => {
+                page.content = response.text().await.map_err(read_error)?;
+            }
+            _ => {
+                // PDFs are parsed by the ingestion path, which needs the bytes
+                page.raw_body = std::option::Option::Some(response.bytes().await.map_err(read_error)?.to_vec());
+            }
+        }
+
+        if self.validators.is_some() {
+            let validators = crate::domain::crawl_result::PageValidators {
+                etag,
+                last_modified,
+                links: page.links.clone(),
+            };
+            self.pending_validators
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .insert(validator_key, validators);
+        }
+
+        std::result::Result::Ok(page)
+    }
+
+    async fn page_stored(
+        &self,
+        page: &crate::domain::crawl_result::CrawledPage,
+    ) -> std::result::Result<(), crate::domain::crawl_result::CrawlError> {
+        let std::option::Option::Some(store) = &self.validators else {
+            return std::result::Result::Ok(());
+        };
+        let key = Self::normalize_url(&page.url);
+        let pending = self.pending_validators
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .remove(&key);
+        match pending {
+            std::option::Option::Some(validators) => store.put(&key, &validators).await.map_err(|e| {
+                crate::domain::crawl_result::CrawlError::ValidatorError(std::format!("{}: {}", page.url, e))
+            }),
+            std::option::Option::None => std::result::Result::Ok(()),
+        }
     }
 
     fn extract_text(
@@ -239,38 +630,28 @@ impl crate::ports::web_crawler_port::WebCrawlerPort for ReqwestWebCrawler {
         html: &str,
         config: &crate::domain::crawl_result::CrawlConfig,
     ) -> String {
-        let document = scraper::Html::parse_document(html);
-
-        // If content selectors specified, only extract from those
-        let mut text_parts: std::vec::Vec<String> = std::vec::Vec::new();
+        if config.content_selectors.is_empty() {
+            return crate::infrastructure::html_to_markdown::html_to_markdown(html, &config.exclude_selectors);
+        }
 
-        if !config.content_selectors.is_empty() {
-            for selector_str in &config.content_selectors {
-                if let std::result::Result::Ok(selector) = scraper::Selector::parse(selector_str) {
-                    for element in document.select(&selector) {
-                        text_parts.push(element.text().collect::<std::vec::Vec<_>>().join(" "));
+        // Convert only the selected content areas
+        let document = scraper::Html::parse_document(html);
+        let mut parts: std::vec::Vec<String> = std::vec::Vec::new();
+        for selector_str in &config.content_selectors {
+            if let std::result::Result::Ok(selector) = scraper::Selector::parse(selector_str) {
+                for element in document.select(&selector) {
+                    let markdown = crate::infrastructure::html_to_markdown::html_to_markdown(
+                        &element.html(),
+                        &config.exclude_selectors,
+                    );
+                    if !markdown.is_empty() {
+                        parts.push(markdown);
                     }
                 }
             }
-        } else {
-            // Extract all text from body, excluding specified elements
-            if let std::result::Result::Ok(body_selector) = scraper::Selector::parse("body") {
-                for body in document.select(&body_selector) {
-                    // Get all text
-                    let all_text: String = body.text().collect::<std::vec::Vec<_>>().join(" ");
-                    text_parts.push(all_text);
-                }
-            }
         }
 
-        // Clean up whitespace
-        let result = text_parts.join("\n\n");
-        let cleaned: String = result
-            .split_whitespace()
-            .collect::<std::vec::Vec<_>>()
-            .join(" ");
-
-        cleaned
+        parts.join("\n\n")
     }
 
     fn extract_title(&self, html: &str) -> String {
@@ -328,14 +709,17 @@ impl crate::ports::web_crawler_port::WebCrawlerPort for ReqwestWebCrawler {
             return false;
         }
 
-        // Check domain restriction
-        if !config.follow_external {
-            let base_domain = Self::extract_domain(base_url);
-            let url_domain = Self::extract_domain(url);
+        let (std::result::Result::Ok(base), std::result::Result::Ok(target)) =
+            (reqwest::Url::parse(base_url), reqwest::Url::parse(url))
+        else {
+            return false;
+        };
 
-            match (base_domain, url_domain) {
-                (std::option::Option::Some(base), std::option::Option::Some(target)) => {
-                    if base != target {
+        // Check host restriction
+        if !config.follow_external {
+            match (base.host_str(), target.host_str()) {
+                (std::option::Option::Some(base_host), std::option::Option::Some(host)) => {
+                    if !Self::host_in_scope(base_host, host, config.include_subdomains) {
                         return false;
                     }
                 }
@@ -343,6 +727,13 @@ impl crate::ports::web_crawler_port::WebCrawlerPort for ReqwestWebCrawler {
             }
         }
 
+        // Check path restriction
+        if let std::option::Option::Some(prefix) = &config.path_prefix
+            && !Self::path_in_scope(target.path(), prefix)
+        {
+            return false;
+        }
+
         true
     }
 }
@@ -498,6 +889,26 @@ mod tests {
         std::assert!(text.contains("This is a paragraph"));
     }
 
+    #[test]
+    fn test_extract_text_returns_markdown_of_selected_content() {
+        // Test: Validates content selectors limit conversion to the selected element and output is markdown.
+        // Justification: Chunking relies on markdown headings, and selectors must still narrow the content.
+        let crawler = super::ReqwestWebCrawler::new();
+        let html = r#"
+            <html><body>
+                <nav><a href="/">Home</a></nav>
+                <div class="doc"><h2>Install</h2><p>Run <code>cargo add rig</code>.</p></div>
+                <div class="promo"><p>Buy now</p></div>
+            </body></html>
+        "#;
+        let config = crate::domain::crawl_result::CrawlConfig::new(String::from("https://example.com"))
+            .with_content_selector(String::from(".doc"));
+
+        let text = crawler.extract_text(html, &config);
+
+        std::assert_eq!(text, "## Install\n\nRun `cargo add rig`.");
+    }
+
     #[test]
     fn test_should_follow_same_domain() {
         // Test: Validates same-domain link following.
@@ -531,4 +942,377 @@ mod tests {
             &config
         ));
     }
+
+    /// Links on a mock site: same host (in and out of /docs), a subdomain, and another site.
+    const MOCK_SITE: &str = r#"
+        <html><body>
+            <a href="/docs/intro">Intro</a>
+            <a href="/docsearch">Doc search</a>
+            <a href="/blog/post">Blog</a>
+            <a href="https://api.example.com/docs/reference">API reference</a>
+            <a href="https://github.com/example/repo">GitHub</a>
+        </body></html>
+    "#;
+
+    fn followed(config: &crate::domain::crawl_result::CrawlConfig) -> std::vec::Vec<String> {
+        let crawler = super::ReqwestWebCrawler::new();
+        crawler
+            .extract_links(MOCK_SITE, "https://example.com/docs")
+            .into_iter()
+            .filter(|link| crawler.should_follow(link, "https://example.com/docs", config))
+            .collect()
+    }
+
+    #[test]
+    fn test_scope_defaults_to_same_host() {
+        // Test: Validates only same-host links are followed by default.
+        // Justification: Crawls must not wander onto subdomains or other sites unless asked.
+        let config = crate::domain::crawl_result::CrawlConfig::new(String::from("https://example.com/docs"));
+
+        std::assert_eq!(
+            followed(&config),
+            std::vec![
+                String::from("https://example.com/blog/post"),
+                String::from("https://example.com/docs/intro"),
+                String::from("https://example.com/docsearch"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scope_include_subdomains_and_path_prefix() {
+        // Test: Validates include_subdomains admits subdomains and path_prefix keeps only paths under it.
+        // Justification: Users scope crawls to a docs section that may span subdomains.
+        let subdomains = crate::domain::crawl_result::CrawlConfig::new(String::from("https://example.com/docs"))
+            .with_include_subdomains(true);
+        let docs_only = subdomains.clone().with_path_prefix(String::from("/docs/"));
+        let same_host_docs = crate::domain::crawl_result::CrawlConfig::new(String::from("https://example.com/docs"))
+            .with_path_prefix(String::from("/docs"));
+
+        std::assert_eq!(followed(&subdomains).len(), 4);
+        std::assert!(followed(&subdomains).contains(&String::from("https://api.example.com/docs/reference")));
+        std::assert_eq!(
+            followed(&docs_only),
+            std::vec![
+                String::from("https://api.example.com/docs/reference"),
+                String::from("https://example.com/docs/intro"),
+            ]
+        );
+        std::assert_eq!(followed(&same_host_docs), std::vec![String::from("https://example.com/docs/intro")]);
+    }
+
+    #[test]
+    fn test_host_in_scope_ignores_www_for_subdomains() {
+        // Test: Validates a www start host admits sibling subdomains only when subdomains are included.
+        // Justification: Sites commonly start at www. while docs live on another subdomain.
+        std::assert!(super::ReqwestWebCrawler::host_in_scope("www.example.com", "docs.example.com", true));
+        std::assert!(!super::ReqwestWebCrawler::host_in_scope("www.example.com", "docs.example.com", false));
+        std::assert!(!super::ReqwestWebCrawler::host_in_scope("example.com", "badexample.com", true));
+    }
+
+    #[test]
+    fn test_parse_crawl_delay_prefers_named_agent() {
+        // Test: Validates Crawl-delay is read for the wildcard group and overridden by a group naming the crawler.
+        // Justification: Sites set per-bot delays that the crawler must honor.
+        let robots = "User-agent: *\nDisallow: /private\nCrawl-delay: 2\n\nUser-agent: RiggerBot\nCrawl-delay: 0.5\n";
+
+        std::assert_eq!(
+            super::ReqwestWebCrawler::parse_crawl_delay(robots, "RiggerBot/1.0 (Artifact Generator)"),
+            std::option::Option::Some(std::time::Duration::from_millis(500))
+        );
+        std::assert_eq!(
+            super::ReqwestWebCrawler::parse_crawl_delay(robots, "OtherBot/2.0"),
+            std::option::Option::Some(std::time::Duration::from_secs(2))
+        );
+        std::assert_eq!(super::ReqwestWebCrawler::parse_crawl_delay("User-agent: *\nDisallow:\n", "RiggerBot"), std::option::Option::None);
+    }
+
+    #[tokio::test]
+    async fn test_pacer_spaces_same_host_and_runs_other_hosts_in_parallel() {
+        // Test: Validates concurrent requests to one host are spaced by the delay while another host is not held back.
+        // Justification: Concurrent workers must still be polite per host without serializing the whole crawl.
+        let pacer = std::sync::Arc::new(super::HostPacer::new());
+        let delay = std::time::Duration::from_millis(60);
+        let start = tokio::time::Instant::now();
+
+        let mut handles = std::vec::Vec::new();
+        for host in ["a.example.com", "a.example.com", "a.example.com", "b.example.com"] {
+            let pacer = std::sync::Arc::clone(&pacer);
+            handles.push(tokio::spawn(async move {
+                pacer.wait(host, delay).await;
+                (host, tokio::time::Instant::now() - start)
+            }));
+        }
+        let mut same_host = std::vec::Vec::new();
+        let mut other_host = std::time::Duration::ZERO;
+        for handle in handles {
+            let (host, elapsed) = handle.await.unwrap();
+            if host == "a.example.com" {
+                same_host.push(elapsed);
+            } else {
+                other_host = elapsed;
+            }
+        }
+        same_host.sort();
+
+        for pair in same_host.windows(2) {
+            std::assert!(pair[1] - pair[0] >= delay - std::time::Duration::from_millis(5), "gaps: {:?}", same_host);
+        }
+        std::assert!(same_host[2] >= delay * 2);
+        std::assert!(other_host < delay, "other host waited {:?}", other_host);
+    }
+
+    /// Serves each connection's single request with `respond(request)` on a
+    /// local port and returns the base URL. `None` stalls the request forever.
+    async fn spawn_http_server<F>(respond: F) -> String
+    where
+        F: Fn(&str) -> std::option::Option<String> + std::marker::Send + std::marker::Sync + 'static,
+    {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let respond = std::sync::Arc::new(respond);
+        tokio::spawn(async move {
+            while let std::result::Result::Ok((mut socket, _)) = listener.accept().await {
+                let respond = std::sync::Arc::clone(&respond);
+                tokio::spawn(async move {
+                    let mut buf = std::vec![0u8; 4096];
+                    let n = tokio::io::AsyncReadExt::read(&mut socket, &mut buf).await.unwrap_or(0);
+                    let request = String::from_utf8_lossy(&buf[..n]).to_string();
+                    match respond(&request) {
+                        std::option::Option::Some(response) => {
+                            let _ = tokio::io::AsyncWriteExt::write_all(&mut socket, response.as_bytes()).await;
+                        }
+                        std::option::Option::None => std::future::pending::<()>().await,
+                    }
+                });
+            }
+        });
+        std::format!("http://{}", addr)
+    }
+
+    /// Returns the request path, or "/" if the request line is malformed.
+    fn request_path(request: &str) -> String {
+        request.split_whitespace().nth(1).unwrap_or("/").to_string()
+    }
+
+    /// Returns the value of a request header (case-insensitive name).
+    fn request_header(request: &str, name: &str) -> std::option::Option<String> {
+        request.lines().find_map(|line| {
+            let (field, value) = line.split_once(':')?;
+            field.trim().eq_ignore_ascii_case(name).then(|| value.trim().to_string())
+        })
+    }
+
+    /// Builds an HTTP/1.1 response that closes the connection.
+    fn http_response(status: &str, headers: &[(&str, &str)], body: &str) -> String {
+        let extra: String = headers.iter().map(|(name, value)| std::format!("{}: {}\r\n", name, value)).collect();
+        std::format!(
+            "HTTP/1.1 {}\r\nContent-Type: text/html; charset=utf-8\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            extra,
+            body.len(),
+            body
+        )
+    }
+
+    /// HTML page titled with its path, with one link per entry in `links`.
+    fn html_page(path: &str, body: &str, links: &[&str]) -> String {
+        let anchors: String = links.iter().map(|href| std::format!("<a href=\"{}\">{}</a>", href, href)).collect();
+        std::format!("<html><head><title>{}</title></head><body><p>{}</p>{}</body></html>", path, body, anchors)
+    }
+
+    /// Local site for the resume test: "/" links to /p1../p5. Page requests
+    /// past `hold_after` stall forever, simulating a crawl that dies mid-flight.
+    struct TestSite {
+        hits: std::sync::Mutex<std::vec::Vec<String>>,
+        hold_after: std::sync::atomic::AtomicUsize,
+        held: tokio::sync::Notify,
+    }
+
+    async fn spawn_test_site(site: std::sync::Arc<TestSite>) -> String {
+        spawn_http_server(move |request| {
+            let path = request_path(request);
+            if path == "/robots.txt" {
+                return std::option::Option::Some(http_response("404 Not Found", &[], ""));
+            }
+            {
+                let mut hits = site.hits.lock().unwrap();
+                if hits.len() >= site.hold_after.load(std::sync::atomic::Ordering::SeqCst) {
+                    site.held.notify_one();
+                    return std::option::Option::None;
+                }
+                hits.push(path.clone());
+            }
+            let links: &[&str] = if path == "/" { &["/p1", "/p2", "/p3", "/p4", "/p5"] } else { &[] };
+            let body = html_page(&path, &std::format!("Content of {}", path), links);
+            std::option::Option::Some(http_response("200 OK", &[], &body))
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_resumed_crawl_fetches_remaining_pages_exactly_once() {
+        // Test: Validates a crawl interrupted after K pages resumes from the saved frontier, fetching only the rest.
+        // Justification: Large crawls must survive crashes without refetching or duplicating pages.
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        let store = crate::adapters::sqlite_crawl_frontier_adapter::SqliteCrawlFrontierAdapter::new(pool);
+        store.init_schema().await.unwrap();
+        let store: std::sync::Arc<dyn crate::ports::crawl_frontier_port::CrawlFrontierPort> = std::sync::Arc::new(store);
+
+        let pages_before_crash = 3;
+        let site = std::sync::Arc::new(TestSite {
+            hits: std::sync::Mutex::new(std::vec::Vec::new()),
+            hold_after: std::sync::atomic::AtomicUsize::new(pages_before_crash),
+            held: tokio::sync::Notify::new(),
+        });
+        let base = spawn_test_site(std::sync::Arc::clone(&site)).await;
+        let config = crate::domain::crawl_result::CrawlConfig::new(base.clone())
+            .with_max_depth(1)
+            .with_rate_limit(0);
+        let client = reqwest::Client::builder().no_proxy().build().unwrap();
+
+        // First run dies while fetching page K + 1
+        let crawler = std::sync::Arc::new(
+            super::ReqwestWebCrawler::with_client(client.clone()).with_frontier(std::sync::Arc::clone(&store)),
+        );
+        let first_config = config.clone();
+        let first_crawler = std::sync::Arc::clone(&crawler);
+        let first_run = tokio::spawn(async move { first_crawler.crawl(&first_config).await });
+        site.held.notified().await;
+        first_run.abort();
+        std::assert!(first_run.await.is_err());
+        std::assert_eq!(site.hits.lock().unwrap().len(), pages_before_crash);
+
+        // Resume on a fresh crawler with the same store
+        site.hold_after.store(usize::MAX, std::sync::atomic::Ordering::SeqCst);
+        let resumed = super::ReqwestWebCrawler::with_client(client).with_frontier(std::sync::Arc::clone(&store));
+        let result = resumed.crawl(&config.clone().with_resume(true)).await.unwrap();
+
+        let mut hits = site.hits.lock().unwrap().clone();
+        hits.sort();
+        std::assert_eq!(hits, std::vec!["/", "/p1", "/p2", "/p3", "/p4", "/p5"]);
+        std::assert_eq!(result.stats.pages_resumed, pages_before_crash);
+        std::assert_eq!(result.page_count(), 6);
+        let unique: std::collections::HashSet<&str> = result.pages.iter().map(|p| p.url.as_str()).collect();
+        std::assert_eq!(unique.len(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_crawl_with_progress_reports_pages_while_crawling() {
+        // Test: Validates each fetched page is reported before the crawl returns.
+        // Justification: Artifact generation showed no progress until a long crawl had finished.
+        let site = std::sync::Arc::new(TestSite {
+            hits: std::sync::Mutex::new(std::vec::Vec::new()),
+            hold_after: std::sync::atomic::AtomicUsize::new(3),
+            held: tokio::sync::Notify::new(),
+        });
+        let base = spawn_test_site(std::sync::Arc::clone(&site)).await;
+        let config = crate::domain::crawl_result::CrawlConfig::new(base)
+            .with_max_depth(1)
+            .with_rate_limit(0);
+        let crawler = super::ReqwestWebCrawler::with_client(reqwest::Client::builder().no_proxy().build().unwrap());
+        let reported = std::sync::Arc::new(std::sync::Mutex::new(std::vec::Vec::<String>::new()));
+
+        let recorder = std::sync::Arc::clone(&reported);
+        let crawl = tokio::spawn(async move {
+            crawler
+                .crawl_with_progress(&config, &move |url: &str| recorder.lock().unwrap().push(url.to_string()))
+                .await
+        });
+        site.held.notified().await;
+        let reported_mid_crawl = reported.lock().unwrap().len();
+        crawl.abort();
+
+        std::assert_eq!(reported_mid_crawl, 3);
+    }
+
+    #[tokio::test]
+    async fn test_recrawl_skips_unchanged_pages_and_refetches_changed_ones() {
+        // Test: Validates ETag/Last-Modified saved for stored pages make re-crawls skip 304 pages while changed pages are ingested.
+        // Justification: Weekly re-crawls should only re-embed pages that actually changed.
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        let store = crate::adapters::sqlite_page_validator_adapter::SqlitePageValidatorAdapter::new(pool);
+        store.init_schema().await.unwrap();
+        let store: std::sync::Arc<dyn crate::ports::page_validator_port::PageValidatorPort> = std::sync::Arc::new(store);
+
+        // "/" (ETag) and "/stable" (Last-Modified) never change; "/changing" gets a new ETag per version
+        let version = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(1));
+        let served = std::sync::Arc::new(std::sync::Mutex::new(std::vec::Vec::<String>::new()));
+        let base = {
+            let version = std::sync::Arc::clone(&version);
+            let served = std::sync::Arc::clone(&served);
+            spawn_http_server(move |request| {
+                let path = request_path(request);
+                let if_none_match = request_header(request, "If-None-Match");
+                let if_modified_since = request_header(request, "If-Modified-Since");
+                let stable_date = "Wed, 21 Oct 2026 07:28:00 GMT";
+                let v = version.load(std::sync::atomic::Ordering::SeqCst);
+                let (etag, last_modified, body) = match path.as_str() {
+                    "/" => (std::option::Option::Some(String::from("\"root\"")), std::option::Option::None, html_page("/", "Index page", &["/stable", "/changing"])),
+                    "/stable" => (std::option::Option::None, std::option::Option::Some(stable_date), html_page("/stable", "Stable page", &[])),
+                    "/changing" => (std::option::Option::Some(std::format!("\"v{}\"", v)), std::option::Option::None, html_page("/changing", &std::format!("Version {}", v), &[])),
+                    _ => return std::option::Option::Some(http_response("404 Not Found", &[], "")),
+                };
+
+                let unchanged = (etag.is_some() && if_none_match == etag)
+                    || (last_modified.is_some() && if_modified_since.as_deref() == last_modified);
+                if unchanged {
+                    return std::option::Option::Some(http_response("304 Not Modified", &[], ""));
+                }
+                served.lock().unwrap().push(path);
+                let mut headers = std::vec::Vec::new();
+                if let std::option::Option::Some(etag) = &etag {
+                    headers.push(("ETag", etag.as_str()));
+                }
+                if let std::option::Option::Some(date) = last_modified {
+                    headers.push(("Last-Modified", date));
+                }
+                std::option::Option::Some(http_response("200 OK", &headers, &body))
+            })
+            .await
+        };
+
+        let client = reqwest::Client::builder().no_proxy().build().unwrap();
+        let crawler = super::ReqwestWebCrawler::with_client(client).with_validators(store);
+        let config = crate::domain::crawl_result::CrawlConfig::new(base.clone())
+            .with_max_depth(1)
+            .with_rate_limit(0);
+
+        let first = crawler.crawl(&config).await.unwrap();
+        std::assert_eq!(first.page_count(), 3);
+
+        // Nothing is saved until the pages are reported stored, so a failed ingestion is refetched
+        served.lock().unwrap().clear();
+        let unstored = crawler.crawl(&config).await.unwrap();
+        std::assert_eq!(unstored.page_count(), 3);
+        std::assert_eq!(served.lock().unwrap().len(), 3);
+        for page in &unstored.pages {
+            crawler.page_stored(page).await.unwrap();
+        }
+
+        // Only "/changing" changes before the re-crawl
+        version.store(2, std::sync::atomic::Ordering::SeqCst);
+        served.lock().unwrap().clear();
+        let second = crawler.crawl(&config).await.unwrap();
+
+        std::assert_eq!(*served.lock().unwrap(), std::vec![String::from("/changing")]);
+        std::assert_eq!(second.page_count(), 1);
+        std::assert!(second.pages[0].content.contains("Version 2"));
+        std::assert_eq!(second.stats.pages_unchanged, 2);
+        std::assert_eq!(second.unchanged.len(), 2);
+
+        // --force refetches everything
+        served.lock().unwrap().clear();
+        let forced = crawler.crawl(&config.clone().with_force(true)).await.unwrap();
+        std::assert_eq!(forced.page_count(), 3);
+        std::assert_eq!(served.lock().unwrap().len(), 3);
+    }
 }
@@ -4,7 +4,32 @@
 //! that can use tools to answer questions about tasks, PRDs, and artifacts. Implements
 //! the HEXSER port pattern via LLMAgentPort for provider-agnostic agent interactions.
 //!
+//! A persona loaded with `with_persona` replaces the default system prompt with
+//! one built from the persona's name, role, and description, and restricts the
+//! registered tools to the persona's `enabled_tools` (matched against each
+//! tool's name, e.g. `search_tasks`). Without a persona, every registered tool
+//! is available. The persona's `tool_call_limits` cap how often each tool may
+//! run per conversation; `reset_conversation` starts a new count.
+//!
+//! OpenAI-compatible servers (vLLM, LM Studio, llamafile) use the OpenAI
+//! client with their own base URL; their API key is optional.
+//!
+//! A chat against an Ollama server that is not running streams an error
+//! telling the user to start Ollama and pull the model. With
+//! `with_auto_pull(true)`, a model Ollama has not pulled yet is downloaded and
+//! the chat retried once. Every chat request is timed out and retried under
+//! the adapter's `RetryPolicy`.
+//!
 //! Revision History
+//! - 2026-10-18T00:40:00Z @AI: Add agent_tools and tool_call_limit; chat_with_tools takes its limited tools from agent_tools.
+//! - 2026-10-18T00:30:00Z @AI: Add system_prompt accessor so callers can show and check the persona prompt.
+//! - 2026-10-17T15:10:00Z @AI: Add with_retry_policy; time out and retry chat requests under the shared RetryPolicy.
+//! - 2026-10-17T14:50:00Z @AI: Add with_auto_pull; pull a missing Ollama model and retry once.
+//! - 2026-10-17T14:30:00Z @AI: Explain refused Ollama connections in the streamed error.
+//! - 2026-10-17T01:30:00Z @AI: Add new_openai_compatible_with_tools routing chat to an OpenAI-API server at a custom base URL.
+//! - 2026-10-17T00:30:00Z @AI: Include System messages (UI context, earlier-conversation summary) in the prompt as context blocks.
+//! - 2026-10-17T00:10:00Z @AI: Enforce persona tool call limits by wrapping tools in LimitedTool; add reset_conversation.
+//! - 2026-10-16T23:50:00Z @AI: Add with_persona/apply_persona: persona-based system prompt and tool allowlist, falling back to the default with a warning.
 //! - 2025-12-05T00:00:00Z @AI: Add tool registration support - accept tools as parameters and register with agent.
 //! - 2025-12-03T00:00:00Z @AI: Initial RigAgentAdapter for chain-of-thought chat agent implementation.
 
@@ -54,13 +79,33 @@ pub struct RigAgentAdapter {
     search_artifacts_tool: std::option::Option<crate::tools::search_artifacts_tool::SearchArtifactsTool>,
     search_tasks_tool: std::option::Option<crate::tools::search_tasks_tool::SearchTasksTool>,
     get_task_details_tool: std::option::Option<crate::tools::get_task_details_tool::GetTaskDetailsTool>,
+    allowed_tools: std::option::Option<std::vec::Vec<std::string::String>>,
+    tool_call_budget: crate::tools::limited_tool::ToolCallBudget,
+    auto_pull: bool,
+    retry_policy: crate::infrastructure::retry_policy::RetryPolicy,
+}
+
+/// Tools a chat request may call: registered, allowed by the persona, and
+/// counted against the persona's per-conversation call limits.
+pub struct AgentTools {
+    /// Semantic artifact search, if registered and allowed
+    pub search_artifacts: std::option::Option<crate::tools::limited_tool::LimitedTool<crate::tools::search_artifacts_tool::SearchArtifactsTool>>,
+
+    /// Task keyword search, if registered and allowed
+    pub search_tasks: std::option::Option<crate::tools::limited_tool::LimitedTool<crate::tools::search_tasks_tool::SearchTasksTool>>,
+
+    /// Task details lookup, if registered and allowed
+    pub get_task_details: std::option::Option<crate::tools::limited_tool::LimitedTool<crate::tools::get_task_details_tool::GetTaskDetailsTool>>,
 }
 
 /// Enum representing the agent provider backend.
 #[derive(Debug, Clone)]
 enum AgentProvider {
-    /// OpenAI remote agent provider
-    OpenAI { api_key: std::string::String },
+    /// OpenAI agent provider; `base_url` is set for OpenAI-compatible servers
+    OpenAI {
+        api_key: std::string::String,
+        base_url: std::option::Option<std::string::String>,
+    },
 
     /// Ollama local agent provider
     Ollama { base_url: std::string::String },
@@ -85,7 +130,7 @@ impl RigAgentAdapter {
     /// ```
     pub fn new_openai(api_key: std::string::String, model: std::string::String) -> Self {
         Self::new_with_provider(
-            AgentProvider::OpenAI { api_key },
+            AgentProvider::OpenAI { api_key, base_url: std::option::Option::None },
             model,
             Self::default_system_prompt(),
             std::option::Option::None,
@@ -111,7 +156,7 @@ impl RigAgentAdapter {
         get_task_details_tool: std::option::Option<crate::tools::get_task_details_tool::GetTaskDetailsTool>,
     ) -> Self {
         Self::new_with_provider(
-            AgentProvider::OpenAI { api_key },
+            AgentProvider::OpenAI { api_key, base_url: std::option::Option::None },
             model,
             Self::default_system_prompt(),
             search_artifacts_tool,
@@ -173,6 +218,55 @@ impl RigAgentAdapter {
         )
     }
 
+    /// Creates a new RigAgentAdapter for an OpenAI-compatible server, with tools.
+    ///
+    /// Requests go to `<base_url>/chat/completions`. Without an API key the
+    /// `Authorization` header carries an empty bearer token, which local
+    /// servers ignore.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - API base URL including the version (e.g., "http://localhost:8000/v1")
+    /// * `api_key` - Optional API key
+    /// * `model` - The chat model name as the server knows it
+    /// * `search_artifacts_tool` - Optional semantic search tool for artifacts
+    /// * `search_tasks_tool` - Optional keyword search tool for tasks
+    /// * `get_task_details_tool` - Optional task details lookup tool
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use task_orchestrator::adapters::rig_agent_adapter::RigAgentAdapter;
+    /// let adapter = RigAgentAdapter::new_openai_compatible_with_tools(
+    ///     std::string::String::from("http://localhost:1234/v1"),
+    ///     std::option::Option::None,
+    ///     std::string::String::from("qwen2.5-7b-instruct"),
+    ///     std::option::Option::None,
+    ///     std::option::Option::None,
+    ///     std::option::Option::None,
+    /// );
+    /// ```
+    pub fn new_openai_compatible_with_tools(
+        base_url: std::string::String,
+        api_key: std::option::Option<std::string::String>,
+        model: std::string::String,
+        search_artifacts_tool: std::option::Option<crate::tools::search_artifacts_tool::SearchArtifactsTool>,
+        search_tasks_tool: std::option::Option<crate::tools::search_tasks_tool::SearchTasksTool>,
+        get_task_details_tool: std::option::Option<crate::tools::get_task_details_tool::GetTaskDetailsTool>,
+    ) -> Self {
+        Self::new_with_provider(
+            AgentProvider::OpenAI {
+                api_key: api_key.unwrap_or_default(),
+                base_url: std::option::Option::Some(base_url),
+            },
+            model,
+            Self::default_system_prompt(),
+            search_artifacts_tool,
+            search_tasks_tool,
+            get_task_details_tool,
+        )
+    }
+
     /// Creates a new RigAgentAdapter with custom system prompt.
     ///
     /// # Arguments
@@ -200,7 +294,176 @@ impl RigAgentAdapter {
             search_artifacts_tool,
             search_tasks_tool,
             get_task_details_tool,
+            allowed_tools: std::option::Option::None,
+            tool_call_budget: crate::tools::limited_tool::ToolCallBudget::default(),
+            auto_pull: false,
+            retry_policy: crate::infrastructure::retry_policy::RetryPolicy::default(),
+        }
+    }
+
+    /// Loads a persona and applies its system prompt and tool allowlist.
+    ///
+    /// If the persona does not exist or cannot be loaded, a warning is logged
+    /// and the adapter keeps the default prompt and all registered tools.
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - Persona repository to load from
+    /// * `persona_id` - ID of the persona to apply
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use task_orchestrator::adapters::rig_agent_adapter::RigAgentAdapter;
+    /// # async fn example() {
+    /// let repository = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init("sqlite:.rigger/tasks.db")
+    ///     .await
+    ///     .unwrap();
+    /// let adapter = RigAgentAdapter::new_ollama(
+    ///     std::string::String::from("http://localhost:11434"),
+    ///     std::string::String::from("llama3.2"),
+    /// )
+    /// .with_persona(&repository, "persona-qa");
+    /// # }
+    /// ```
+    pub fn with_persona<R>(mut self, repository: &R, persona_id: &str) -> Self
+    where
+        R: task_manager::ports::persona_repository_port::PersonaRepositoryPort + ?Sized,
+    {
+        let filter = task_manager::ports::persona_repository_port::PersonaFilter::ById(std::string::String::from(persona_id));
+        match hexser::ports::repository::QueryRepository::find_one(repository, &filter) {
+            std::result::Result::Ok(std::option::Option::Some(persona)) => self.apply_persona(&persona),
+            std::result::Result::Ok(std::option::Option::None) => {
+                tracing::warn!(persona_id, "persona not found; using the default chat agent persona");
+            }
+            std::result::Result::Err(e) => {
+                tracing::warn!(persona_id, error = %e, "failed to load persona; using the default chat agent persona");
+            }
+        }
+        self
+    }
+
+    /// Applies a persona's system prompt, tool allowlist, and tool call limits.
+    ///
+    /// # Arguments
+    ///
+    /// * `persona` - Persona whose role and enabled tools the agent takes on
+    pub fn apply_persona(&mut self, persona: &task_manager::domain::persona::Persona) {
+        self.allowed_tools = std::option::Option::Some(persona.enabled_tools.clone());
+        self.tool_call_budget.set_limits(persona.tool_call_limits.clone());
+        self.system_prompt = Self::persona_system_prompt(persona, &self.tool_names());
+    }
+
+    /// Pulls a missing Ollama model through `/api/pull` and retries the chat
+    /// once (default false). Ignored by OpenAI providers.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_auto_pull(mut self, auto_pull: bool) -> Self {
+        self.auto_pull = auto_pull;
+        self
+    }
+
+    /// Sets the timeout and retry policy for chat requests.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_retry_policy(mut self, retry_policy: crate::infrastructure::retry_policy::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Returns the per-conversation call limit the persona sets for `tool_name`, if any.
+    pub fn tool_call_limit(&self, tool_name: &str) -> std::option::Option<u32> {
+        self.tool_call_budget.limit(tool_name)
+    }
+
+    /// Returns the tools a chat request may call, wrapped to enforce the
+    /// conversation's call limits. All wrappers share one budget, so the
+    /// counts carry over between requests until `reset_conversation`.
+    pub fn agent_tools(&self) -> AgentTools {
+        AgentTools {
+            search_artifacts: self.limited_tool(&self.search_artifacts_tool),
+            search_tasks: self.limited_tool(&self.search_tasks_tool),
+            get_task_details: self.limited_tool(&self.get_task_details_tool),
+        }
+    }
+
+    /// Starts a new conversation, clearing the tool call counts.
+    pub fn reset_conversation(&self) {
+        self.tool_call_budget.reset();
+    }
+
+    /// Returns the names of the registered tools the agent may use.
+    ///
+    /// Tools not registered with the adapter are never listed, even if the
+    /// persona allows them.
+    pub fn tool_names(&self) -> std::vec::Vec<&'static str> {
+        let registered = [
+            self.search_artifacts_tool
+                .as_ref()
+                .map(|_| <crate::tools::search_artifacts_tool::SearchArtifactsTool as rig::tool::Tool>::NAME),
+            self.search_tasks_tool
+                .as_ref()
+                .map(|_| <crate::tools::search_tasks_tool::SearchTasksTool as rig::tool::Tool>::NAME),
+            self.get_task_details_tool
+                .as_ref()
+                .map(|_| <crate::tools::get_task_details_tool::GetTaskDetailsTool as rig::tool::Tool>::NAME),
+        ];
+        registered
+            .into_iter()
+            .flatten()
+            .filter(|name| self.is_tool_allowed(name))
+            .collect()
+    }
+
+    /// Returns `tool` if it is registered and allowed for the current persona.
+    fn allowed_tool<T: rig::tool::Tool + Clone>(&self, tool: &std::option::Option<T>) -> std::option::Option<T> {
+        tool.clone().filter(|_| self.is_tool_allowed(T::NAME))
+    }
+
+    /// Returns `tool`, if registered and allowed, wrapped to enforce the conversation's call limits.
+    fn limited_tool<T: rig::tool::Tool + Clone>(
+        &self,
+        tool: &std::option::Option<T>,
+    ) -> std::option::Option<crate::tools::limited_tool::LimitedTool<T>> {
+        self.allowed_tool(tool)
+            .map(|tool| crate::tools::limited_tool::LimitedTool::new(tool, self.tool_call_budget.clone()))
+    }
+
+    fn is_tool_allowed(&self, name: &str) -> bool {
+        match &self.allowed_tools {
+            std::option::Option::Some(allowed) => allowed.iter().any(|tool| tool == name),
+            std::option::Option::None => true,
+        }
+    }
+
+    /// Builds the system prompt for a persona.
+    ///
+    /// # Arguments
+    ///
+    /// * `persona` - Persona providing name, role, and description
+    /// * `tool_names` - Tools the persona may use
+    fn persona_system_prompt(persona: &task_manager::domain::persona::Persona, tool_names: &[&str]) -> std::string::String {
+        let mut prompt = std::format!(
+            "You are {}, acting as {} for a software project managed with Rigger.\n\n",
+            persona.name, persona.role
+        );
+        if !persona.description.trim().is_empty() {
+            prompt.push_str(&std::format!("{}\n\n", persona.description.trim()));
         }
+        if tool_names.is_empty() {
+            prompt.push_str("You have no tools available. Answer from the conversation and the context provided.\n\n");
+        } else {
+            prompt.push_str(&std::format!(
+                "You may use only these tools: {}. Do not attempt to call any other tool.\n\n",
+                tool_names.join(", ")
+            ));
+        }
+        prompt.push_str("Stay within your role. Be concise and helpful. Focus on actionable information.");
+        prompt
     }
 
     /// Returns the default system prompt for the Rigger assistant.
@@ -216,6 +479,11 @@ impl RigAgentAdapter {
         )
     }
 
+    /// Returns the system prompt the agent is sent as its preamble.
+    pub fn system_prompt(&self) -> &str {
+        &self.system_prompt
+    }
+
     /// Sets a custom system prompt for the agent.
     ///
     /// # Arguments
@@ -227,6 +495,9 @@ impl RigAgentAdapter {
 
     /// Converts agent messages into a single prompt string for Rig.
     ///
+    /// System messages (UI context, a summary of earlier turns) become
+    /// `Context:` blocks in place; the system prompt itself is the preamble.
+    ///
     /// # Arguments
     ///
     /// * `messages` - The conversation messages
@@ -244,8 +515,9 @@ impl RigAgentAdapter {
         for message in messages {
             match message.role {
                 crate::ports::llm_agent_port::AgentRole::System => {
-                    // System messages are handled by preamble, skip duplicates
-                    continue;
+                    prompt.push_str("Context:\n");
+                    prompt.push_str(&message.content);
+                    prompt.push_str("\n\n");
                 }
                 crate::ports::llm_agent_port::AgentRole::User => {
                     prompt.push_str("User: ");
@@ -286,6 +558,8 @@ impl crate::ports::llm_agent_port::LLMAgentPort for RigAgentAdapter {
         let model = self.model.clone();
         let system_prompt = self.system_prompt.clone();
         let cancel_token_arc = self.cancel_token.clone();
+        let auto_pull = self.auto_pull;
+        let retry_policy = self.retry_policy.clone();
 
         // Create cancellation token for this stream
         let cancel_token = tokio_util::sync::CancellationToken::new();
@@ -294,17 +568,22 @@ impl crate::ports::llm_agent_port::LLMAgentPort for RigAgentAdapter {
             *guard = std::option::Option::Some(cancel_token.clone());
         }
 
-        // Clone tools for async task
-        let search_artifacts_tool = self.search_artifacts_tool.clone();
-        let search_tasks_tool = self.search_tasks_tool.clone();
-        let get_task_details_tool = self.get_task_details_tool.clone();
+        // Clone the tools the persona allows, with its call limits, for async task
+        let AgentTools {
+            search_artifacts: search_artifacts_tool,
+            search_tasks: search_tasks_tool,
+            get_task_details: get_task_details_tool,
+        } = self.agent_tools();
 
         // Spawn background task to stream response
         tokio::spawn(async move {
             // Build Rig client based on provider
             let result: std::result::Result<std::string::String, std::string::String> = match &provider {
-                AgentProvider::OpenAI { api_key } => {
-                    let client = rig::providers::openai::Client::new(api_key);
+                AgentProvider::OpenAI { api_key, base_url } => {
+                    let client = match base_url {
+                        std::option::Option::Some(base_url) => rig::providers::openai::Client::from_url(api_key, base_url),
+                        std::option::Option::None => rig::providers::openai::Client::new(api_key),
+                    };
                     let mut agent_builder = client.agent(&model)
                         .preamble(&system_prompt)
                         .max_tokens(16384) // Increased significantly - llama3.2 can handle much longer responses
@@ -332,10 +611,10 @@ impl crate::ports::llm_agent_port::LLMAgentPort for RigAgentAdapter {
                     let prompt = Self::messages_to_prompt(&messages, &system_prompt);
 
                     // Call agent
-                    match rig::completion::Prompt::prompt(&agent, prompt.as_str()).await {
-                        std::result::Result::Ok(response) => std::result::Result::Ok(response),
-                        std::result::Result::Err(e) => std::result::Result::Err(std::format!("LLM error: {}", e)),
-                    }
+                    retry_policy
+                        .run(|| rig::completion::Prompt::prompt(&agent, prompt.as_str()))
+                        .await
+                        .map_err(|e| e.describe(|e| std::format!("LLM error: {}", e)))
                 }
                 AgentProvider::Ollama { base_url } => {
                     let client = if base_url == "http://localhost:11434" {
@@ -374,11 +653,20 @@ impl crate::ports::llm_agent_port::LLMAgentPort for RigAgentAdapter {
                     // Convert messages to prompt
                     let prompt = Self::messages_to_prompt(&messages, &system_prompt);
 
-                    // Call agent
-                    match rig::completion::Prompt::prompt(&agent, prompt.as_str()).await {
-                        std::result::Result::Ok(response) => std::result::Result::Ok(response),
-                        std::result::Result::Err(e) => std::result::Result::Err(std::format!("LLM error: {}", e)),
-                    }
+                    // Call agent, pulling the model first if it is missing and auto-pull is on
+                    retry_policy
+                        .run(|| {
+                            crate::infrastructure::ollama_pull::retry_after_pull(auto_pull, base_url, &model, || {
+                                rig::completion::Prompt::prompt(&agent, prompt.as_str())
+                            })
+                        })
+                        .await
+                        .map_err(|e| {
+                            e.describe(|e| {
+                                crate::infrastructure::ollama_errors::not_running_error(&e, base_url, &model)
+                                    .unwrap_or_else(|| std::format!("LLM error: {}", e))
+                            })
+                        })
                 }
             };
 
@@ -479,6 +767,102 @@ mod tests {
         std::assert_eq!(adapter.system_prompt, custom_prompt);
     }
 
+    #[test]
+    fn test_messages_to_prompt_includes_system_context() {
+        // Test: Validates System messages are rendered as context blocks ahead of the conversation turns.
+        // Justification: The TUI sends its view context and the summary of trimmed chat history as System messages.
+        let messages = std::vec![
+            crate::ports::llm_agent_port::AgentMessage {
+                role: crate::ports::llm_agent_port::AgentRole::System,
+                content: std::string::String::from("Summary of earlier conversation:\nUser: what is blocked?"),
+            },
+            crate::ports::llm_agent_port::AgentMessage {
+                role: crate::ports::llm_agent_port::AgentRole::User,
+                content: std::string::String::from("And now?"),
+            },
+        ];
+
+        let prompt = RigAgentAdapter::messages_to_prompt(&messages, "system");
+
+        std::assert_eq!(
+            prompt,
+            "Context:\nSummary of earlier conversation:\nUser: what is blocked?\n\nUser: And now?\n\nAssistant:"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_openai_compatible_posts_to_custom_base_url_without_key() {
+        // Test: Validates an OpenAI-compatible agent sends chat requests to its base URL, with an empty bearer token when no key is set.
+        // Justification: Local servers listen on arbitrary URLs and usually run without an API key.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = std::format!("http://{}/v1", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 16384];
+            let n = tokio::io::AsyncReadExt::read(&mut socket, &mut buf).await.unwrap();
+            let request = std::string::String::from_utf8_lossy(&buf[..n]).into_owned();
+            let body = r#"{"id":"1","object":"chat.completion","created":0,"model":"local","choices":[{"index":0,"message":{"role":"assistant","content":"hi"},"finish_reason":"stop"}],"usage":{"prompt_tokens":1,"total_tokens":2}}"#;
+            let response = std::format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            tokio::io::AsyncWriteExt::write_all(&mut socket, response.as_bytes()).await.unwrap();
+            request
+        });
+
+        let adapter = RigAgentAdapter::new_openai_compatible_with_tools(
+            base_url,
+            std::option::Option::None,
+            std::string::String::from("qwen2.5-7b-instruct"),
+            std::option::Option::None,
+            std::option::Option::None,
+            std::option::Option::None,
+        );
+        let _receiver = adapter
+            .chat_with_tools(std::vec![crate::ports::llm_agent_port::AgentMessage {
+                role: crate::ports::llm_agent_port::AgentRole::User,
+                content: std::string::String::from("Hello"),
+            }])
+            .await
+            .unwrap();
+        let request = tokio::time::timeout(std::time::Duration::from_secs(10), server).await.unwrap().unwrap();
+
+        std::assert!(request.starts_with("POST /v1/chat/completions "), "unexpected request: {}", request);
+        let authorization = request
+            .lines()
+            .find(|line| line.to_lowercase().starts_with("authorization:"))
+            .unwrap();
+        std::assert_eq!(authorization.trim().to_lowercase(), "authorization: bearer");
+    }
+
+    #[tokio::test]
+    async fn test_ollama_refused_connection_streams_friendly_error() {
+        // Test: Validates a chat against a stopped Ollama streams instructions naming the base URL and model.
+        // Justification: The raw "LLM error: error sending request" text gave new users no hint that Ollama was down.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = std::format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+        let adapter = RigAgentAdapter::new_ollama(base_url.clone(), std::string::String::from("llama3.2"));
+
+        let mut rx = adapter
+            .chat_with_tools(std::vec![crate::ports::llm_agent_port::AgentMessage {
+                role: crate::ports::llm_agent_port::AgentRole::User,
+                content: std::string::String::from("Hello"),
+            }])
+            .await
+            .unwrap();
+        let token = tokio::time::timeout(std::time::Duration::from_secs(10), rx.recv()).await.unwrap();
+
+        match token {
+            std::option::Option::Some(crate::ports::llm_agent_port::StreamToken::Error(error)) => {
+                std::assert!(error.contains(&base_url), "error: {}", error);
+                std::assert!(error.contains("ollama pull llama3.2"), "error: {}", error);
+            }
+            other => std::panic!("expected an error token, got {:?}", other),
+        }
+    }
+
     #[tokio::test]
     async fn test_chat_with_tools_returns_stream() {
         // Test: Validates that chat_with_tools returns a working receiver.
@@ -524,4 +908,181 @@ mod tests {
         let result = adapter.cancel_stream().await;
         std::assert!(result.is_err());
     }
+
+    fn pm_and_qa_personas() -> (task_manager::domain::persona::Persona, task_manager::domain::persona::Persona) {
+        let mut pm = task_manager::domain::persona::Persona::new(
+            std::string::String::from("persona-pm-chat"),
+            std::option::Option::None,
+            std::string::String::from("Pat"),
+            std::string::String::from("Project Manager"),
+            std::string::String::from("Keeps the plan current and breaks work into tasks."),
+        );
+        pm.enabled_tools = std::vec![
+            std::string::String::from("search_tasks"),
+            std::string::String::from("get_task_details"),
+            std::string::String::from("search_artifacts"),
+        ];
+        let mut qa = task_manager::domain::persona::Persona::new(
+            std::string::String::from("persona-qa-chat"),
+            std::option::Option::None,
+            std::string::String::from("Quinn"),
+            std::string::String::from("QA Reviewer"),
+            std::string::String::from("Reviews finished work against its acceptance criteria."),
+        );
+        qa.enabled_tools = std::vec![std::string::String::from("search_tasks")];
+        qa.set_tool_call_limit(std::string::String::from("search_tasks"), std::option::Option::Some(2));
+        (pm, qa)
+    }
+
+    async fn adapter_with_all_tools() -> RigAgentAdapter {
+        let task_repo: std::sync::Arc<std::sync::Mutex<dyn task_manager::ports::task_repository_port::TaskRepositoryPort + std::marker::Send>> =
+            std::sync::Arc::new(std::sync::Mutex::new(
+                task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init("sqlite::memory:")
+                    .await
+                    .unwrap(),
+            ));
+        let artifact_repo: std::sync::Arc<std::sync::Mutex<dyn task_manager::ports::artifact_repository_port::ArtifactRepositoryPort + std::marker::Send>> =
+            std::sync::Arc::new(std::sync::Mutex::new(
+                task_manager::adapters::sqlite_artifact_adapter::SqliteArtifactAdapter::connect_and_init("sqlite::memory:")
+                    .await
+                    .unwrap(),
+            ));
+        let embeddings = std::sync::Arc::new(crate::adapters::rig_embedding_adapter::RigEmbeddingAdapter::new_ollama(
+            std::string::String::from("nomic-embed-text"),
+        ));
+        RigAgentAdapter::new_ollama_with_tools(
+            std::string::String::from("http://localhost:11434"),
+            std::string::String::from("llama3.2"),
+            std::option::Option::Some(crate::tools::search_artifacts_tool::SearchArtifactsTool::new(
+                embeddings,
+                artifact_repo,
+                std::option::Option::None,
+            )),
+            std::option::Option::Some(crate::tools::search_tasks_tool::SearchTasksTool::new(task_repo.clone(), std::option::Option::None)),
+            std::option::Option::Some(crate::tools::get_task_details_tool::GetTaskDetailsTool::new(task_repo)),
+        )
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_persona_sets_system_prompt_and_filters_tools() {
+        // Test: Validates a loaded persona's role shapes the system prompt and its enabled tools filter the registered tools.
+        // Justification: A QA reviewer must not get the tools a project manager has just because both are registered.
+        let mut db = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init("sqlite::memory:")
+            .await
+            .unwrap();
+        let (pm, qa) = pm_and_qa_personas();
+        hexser::ports::Repository::save(&mut db, pm).unwrap();
+        hexser::ports::Repository::save(&mut db, qa).unwrap();
+
+        let pm_agent = adapter_with_all_tools().await.with_persona(&db, "persona-pm-chat");
+        let qa_agent = adapter_with_all_tools().await.with_persona(&db, "persona-qa-chat");
+
+        std::assert_eq!(pm_agent.tool_names(), std::vec!["search_artifacts", "search_tasks", "get_task_details"]);
+        std::assert_eq!(qa_agent.tool_names(), std::vec!["search_tasks"]);
+        std::assert!(qa_agent.allowed_tool(&qa_agent.get_task_details_tool).is_none());
+        std::assert!(qa_agent.allowed_tool(&qa_agent.search_tasks_tool).is_some());
+        std::assert!(qa_agent.system_prompt.contains("You are Quinn, acting as QA Reviewer"));
+        std::assert!(qa_agent.system_prompt.contains("Reviews finished work against its acceptance criteria."));
+        std::assert!(qa_agent.system_prompt.contains("You may use only these tools: search_tasks."));
+        std::assert!(!qa_agent.system_prompt.contains("get_task_details"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_persona_tool_call_limits_reach_the_agent_tools() {
+        // Test: Validates a persisted persona limit is loaded into the adapter's budget, shared by its wrapped tools, and cleared by reset_conversation.
+        // Justification: The limit configured on the persona must be the one enforced when the agent calls tools.
+        let mut db = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init("sqlite::memory:")
+            .await
+            .unwrap();
+        let (_, qa) = pm_and_qa_personas();
+        hexser::ports::Repository::save(&mut db, qa).unwrap();
+
+        let adapter = adapter_with_all_tools().await.with_persona(&db, "persona-qa-chat");
+
+        std::assert_eq!(adapter.tool_call_budget.limit("search_tasks"), std::option::Option::Some(2));
+        std::assert!(adapter.limited_tool(&adapter.search_tasks_tool).is_some());
+        std::assert!(adapter.tool_call_budget.try_acquire("search_tasks"));
+        std::assert!(adapter.tool_call_budget.try_acquire("search_tasks"));
+        std::assert!(!adapter.tool_call_budget.try_acquire("search_tasks"));
+        adapter.reset_conversation();
+        std::assert_eq!(adapter.tool_call_budget.calls("search_tasks"), 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_missing_persona_falls_back_to_default() {
+        // Test: Validates an unknown persona ID keeps the default prompt and every registered tool.
+        // Justification: A stale persona reference must not leave the chat agent without a prompt or tools.
+        let db = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init("sqlite::memory:")
+            .await
+            .unwrap();
+
+        let adapter = adapter_with_all_tools().await.with_persona(&db, "persona-does-not-exist");
+
+        std::assert_eq!(adapter.system_prompt, RigAgentAdapter::default_system_prompt());
+        std::assert_eq!(adapter.tool_names().len(), 3);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_agent_tools_enforce_persona_limits_through_the_adapter() {
+        // Test: Validates the tools a persona-configured adapter hands to chat stop executing at the limit while other tools keep running.
+        // Justification: The limit must hold on the tools the chat path actually uses, not only on a standalone wrapper.
+        let task_repo: std::sync::Arc<std::sync::Mutex<dyn task_manager::ports::task_repository_port::TaskRepositoryPort + std::marker::Send>> =
+            std::sync::Arc::new(std::sync::Mutex::new(
+                task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init("sqlite::memory:")
+                    .await
+                    .unwrap(),
+            ));
+        let task = task_manager::domain::task::Task::from_action_item(
+            &transcript_extractor::domain::action_item::ActionItem {
+                title: std::string::String::from("Review login flow"),
+                assignee: std::option::Option::None,
+                due_date: std::option::Option::None,
+            },
+            std::option::Option::None,
+        );
+        let task_id = task.id.clone();
+        hexser::ports::Repository::save(&mut *task_repo.lock().unwrap(), task).unwrap();
+        let (mut pm, _) = pm_and_qa_personas();
+        pm.set_tool_call_limit(std::string::String::from("search_tasks"), std::option::Option::Some(1));
+        let mut adapter = RigAgentAdapter::new_ollama_with_tools(
+            std::string::String::from("http://localhost:11434"),
+            std::string::String::from("llama3.2"),
+            std::option::Option::None,
+            std::option::Option::Some(crate::tools::search_tasks_tool::SearchTasksTool::new(task_repo.clone(), std::option::Option::None)),
+            std::option::Option::Some(crate::tools::get_task_details_tool::GetTaskDetailsTool::new(task_repo)),
+        );
+        adapter.apply_persona(&pm);
+
+        let search_args = || crate::tools::search_tasks_tool::SearchTasksArgs {
+            query: std::option::Option::Some(std::string::String::from("login")),
+            status: std::option::Option::None,
+            agent_persona: std::option::Option::None,
+            limit: 5,
+        };
+        let details_args = || crate::tools::get_task_details_tool::GetTaskDetailsArgs { task_id: task_id.clone() };
+
+        let tools = adapter.agent_tools();
+        let search = tools.search_tasks.unwrap();
+        let details = tools.get_task_details.unwrap();
+        let first = rig::tool::Tool::call(&search, search_args()).await.unwrap();
+        std::assert!(std::matches!(first, crate::tools::limited_tool::LimitedOutput::Completed(ref out) if out.contains("Review login flow")));
+        let second = rig::tool::Tool::call(&search, search_args()).await.unwrap();
+        std::assert!(std::matches!(second, crate::tools::limited_tool::LimitedOutput::LimitReached { .. }));
+        for _ in 0..3 {
+            let output = rig::tool::Tool::call(&details, details_args()).await.unwrap();
+            std::assert!(std::matches!(output, crate::tools::limited_tool::LimitedOutput::Completed(_)));
+        }
+
+        // A later request in the same conversation shares the count; a new conversation starts over
+        let next_request = adapter.agent_tools().search_tasks.unwrap();
+        std::assert!(std::matches!(
+            rig::tool::Tool::call(&next_request, search_args()).await.unwrap(),
+            crate::tools::limited_tool::LimitedOutput::LimitReached { .. }
+        ));
+        adapter.reset_conversation();
+        std::assert!(std::matches!(
+            rig::tool::Tool::call(&next_request, search_args()).await.unwrap(),
+            crate::tools::limited_tool::LimitedOutput::Completed(_)
+        ));
+    }
 }
@@ -3,9 +3,22 @@
 //! RigEmbeddingAdapter uses Rig's embedding API to convert text into dense vector
 //! representations for RAG (Retrieval-Augmented Generation) similarity search.
 //! Supports multiple providers (Ollama, OpenAI) with configurable models and
-//! includes fallback logic for LLM unavailability.
+//! includes fallback logic for LLM unavailability. OpenAI-compatible local
+//! servers (vLLM, LM Studio, llamafile) use the OpenAI path with their own
+//! base URL and an optional API key.
+//!
+//! If Ollama is not running, the fallback is logged with instructions to start
+//! it and pull the embedding model. With `with_auto_pull(true)`, an embedding
+//! model Ollama has not pulled yet is downloaded and the call retried once.
+//! Every embedding request is timed out and retried under the adapter's
+//! `RetryPolicy`.
 //!
 //! Revision History
+//! - 2026-10-17T15:10:00Z @AI: Add with_retry_policy; time out and retry embedding requests under the shared RetryPolicy.
+//! - 2026-10-17T14:50:00Z @AI: Add with_auto_pull; pull a missing Ollama model and retry once.
+//! - 2026-10-17T14:30:00Z @AI: Add new_ollama_with_base_url; explain refused Ollama connections and log fallbacks.
+//! - 2026-10-17T01:30:00Z @AI: Add new_openai_compatible for OpenAI-API servers at a custom base URL with an optional key.
+//! - 2026-10-16T22:30:00Z @AI: Report the configured model via EmbeddingPort::embedding_model.
 //! - 2025-11-28T19:45:00Z @AI: Initial RigEmbeddingAdapter for Phase 3 RAG AI integration.
 
 /// Adapter for text embedding generation using Rig's embedding API.
@@ -27,7 +40,7 @@
 /// ```no_run
 /// # use task_orchestrator::adapters::rig_embedding_adapter::RigEmbeddingAdapter;
 /// # use task_orchestrator::ports::embedding_port::EmbeddingPort;
-/// let adapter = RigEmbeddingAdapter::new_ollama(
+/// let adapter = RigEmbeddingAdapter::new_ollama_with_base_url(
 ///     std::string::String::from("http://localhost:11434"),
 ///     std::string::String::from("nomic-embed-text"),
 /// );
@@ -42,15 +55,17 @@ pub struct RigEmbeddingAdapter {
     provider: EmbeddingProvider,
     model: String,
     dimension: usize,
+    auto_pull: bool,
+    retry_policy: crate::infrastructure::retry_policy::RetryPolicy,
 }
 
 /// Enum representing the embedding provider backend.
 #[derive(Debug, Clone)]
 enum EmbeddingProvider {
-    /// Ollama local embedding provider (uses default http://localhost:11434).
-    Ollama,
-    /// OpenAI remote embedding provider.
-    OpenAI { api_key: String },
+    /// Ollama embedding provider at `base_url`.
+    Ollama { base_url: String },
+    /// OpenAI embedding provider; `base_url` is set for OpenAI-compatible servers.
+    OpenAI { api_key: String, base_url: std::option::Option<String> },
 }
 
 impl RigEmbeddingAdapter {
@@ -71,13 +86,25 @@ impl RigEmbeddingAdapter {
     /// );
     /// ```
     pub fn new_ollama(model: String) -> Self {
+        Self::new_ollama_with_base_url(String::from(crate::infrastructure::ollama_errors::DEFAULT_OLLAMA_BASE_URL), model)
+    }
+
+    /// Creates a new RigEmbeddingAdapter with an Ollama server at `base_url`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The Ollama server URL (e.g., "http://localhost:11434")
+    /// * `model` - The embedding model name (e.g., "nomic-embed-text")
+    pub fn new_ollama_with_base_url(base_url: String, model: String) -> Self {
         // nomic-embed-text produces 768-dimensional embeddings
         let dimension = if model.contains("nomic") { 768 } else { 384 };
 
         RigEmbeddingAdapter {
-            provider: EmbeddingProvider::Ollama,
+            provider: EmbeddingProvider::Ollama { base_url },
             model,
             dimension,
+            auto_pull: false,
+            retry_policy: crate::infrastructure::retry_policy::RetryPolicy::default(),
         }
     }
 
@@ -102,12 +129,72 @@ impl RigEmbeddingAdapter {
         let dimension = if model.contains("3-small") { 1536 } else if model.contains("3-large") { 3072 } else { 1536 };
 
         RigEmbeddingAdapter {
-            provider: EmbeddingProvider::OpenAI { api_key },
+            provider: EmbeddingProvider::OpenAI { api_key, base_url: std::option::Option::None },
             model,
             dimension,
+            auto_pull: false,
+            retry_policy: crate::infrastructure::retry_policy::RetryPolicy::default(),
         }
     }
 
+    /// Creates a new RigEmbeddingAdapter for an OpenAI-compatible server.
+    ///
+    /// Requests go to `<base_url>/embeddings`. Without an API key the
+    /// `Authorization` header carries an empty bearer token, which local
+    /// servers ignore.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - API base URL including the version (e.g., "http://localhost:8000/v1")
+    /// * `api_key` - Optional API key
+    /// * `model` - The embedding model name as the server knows it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use task_orchestrator::adapters::rig_embedding_adapter::RigEmbeddingAdapter;
+    /// let adapter = RigEmbeddingAdapter::new_openai_compatible(
+    ///     std::string::String::from("http://localhost:1234/v1"),
+    ///     std::option::Option::None,
+    ///     std::string::String::from("nomic-embed-text-v1.5"),
+    /// );
+    /// ```
+    pub fn new_openai_compatible(base_url: String, api_key: std::option::Option<String>, model: String) -> Self {
+        let dimension = if model.contains("nomic") { 768 } else { 1536 };
+
+        RigEmbeddingAdapter {
+            provider: EmbeddingProvider::OpenAI {
+                api_key: api_key.unwrap_or_default(),
+                base_url: std::option::Option::Some(base_url),
+            },
+            model,
+            dimension,
+            auto_pull: false,
+            retry_policy: crate::infrastructure::retry_policy::RetryPolicy::default(),
+        }
+    }
+
+    /// Pulls a missing Ollama model through `/api/pull` and retries once
+    /// (default false). Ignored by OpenAI providers.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_auto_pull(mut self, auto_pull: bool) -> Self {
+        self.auto_pull = auto_pull;
+        self
+    }
+
+    /// Sets the timeout and retry policy for embedding requests.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_retry_policy(mut self, retry_policy: crate::infrastructure::retry_policy::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
     /// Generates embeddings using the configured provider.
     ///
     /// This is the core embedding generation method that interfaces with the
@@ -118,11 +205,11 @@ impl RigEmbeddingAdapter {
         texts: &[&str],
     ) -> std::result::Result<std::vec::Vec<std::vec::Vec<f32>>, std::string::String> {
         match &self.provider {
-            EmbeddingProvider::Ollama => {
-                self.generate_with_ollama(texts).await
+            EmbeddingProvider::Ollama { base_url } => {
+                self.generate_with_ollama(base_url, texts).await
             }
-            EmbeddingProvider::OpenAI { api_key } => {
-                self.generate_with_openai(api_key, texts).await
+            EmbeddingProvider::OpenAI { api_key, base_url } => {
+                self.generate_with_openai(api_key, base_url.as_deref(), texts).await
             }
         }
     }
@@ -130,12 +217,12 @@ impl RigEmbeddingAdapter {
     /// Generates embeddings using Ollama provider.
     async fn generate_with_ollama(
         &self,
+        base_url: &str,
         texts: &[&str],
     ) -> std::result::Result<std::vec::Vec<std::vec::Vec<f32>>, std::string::String> {
         use rig::embeddings::EmbeddingModel;
 
-        // Create Ollama client (uses default http://localhost:11434)
-        let client = rig::providers::ollama::Client::new();
+        let client = rig::providers::ollama::Client::from_url(base_url);
 
         // Create embedding model
         let embedding_model = client.embedding_model(&self.model);
@@ -143,10 +230,20 @@ impl RigEmbeddingAdapter {
         // Generate embeddings for all texts
         let mut results = std::vec::Vec::new();
         for text in texts {
-            let embeddings = embedding_model
-                .embed_text(text)
+            let embeddings = self
+                .retry_policy
+                .run(|| {
+                    crate::infrastructure::ollama_pull::retry_after_pull(self.auto_pull, base_url, &self.model, || {
+                        embedding_model.embed_text(text)
+                    })
+                })
                 .await
-                .map_err(|e| std::format!("Ollama embedding generation failed: {:?}", e))?;
+                .map_err(|e| {
+                    e.describe(|e| {
+                        crate::infrastructure::ollama_errors::not_running_error(&e, base_url, &self.model)
+                            .unwrap_or_else(|| std::format!("Ollama embedding generation failed: {:?}", e))
+                    })
+                })?;
 
             // Convert f64 to f32
             let vec_f32: std::vec::Vec<f32> = embeddings.vec.iter().map(|&x| x as f32).collect();
@@ -156,16 +253,20 @@ impl RigEmbeddingAdapter {
         std::result::Result::Ok(results)
     }
 
-    /// Generates embeddings using OpenAI provider.
+    /// Generates embeddings using OpenAI provider (or an OpenAI-compatible server).
     async fn generate_with_openai(
         &self,
         api_key: &str,
+        base_url: std::option::Option<&str>,
         texts: &[&str],
     ) -> std::result::Result<std::vec::Vec<std::vec::Vec<f32>>, std::string::String> {
         use rig::embeddings::EmbeddingModel;
 
-        // Create OpenAI client
-        let client = rig::providers::openai::Client::new(api_key);
+        // Create OpenAI client, pointed at the compatible server if configured
+        let client = match base_url {
+            std::option::Option::Some(base_url) => rig::providers::openai::Client::from_url(api_key, base_url),
+            std::option::Option::None => rig::providers::openai::Client::new(api_key),
+        };
 
         // Create embedding model
         let embedding_model = client.embedding_model(&self.model);
@@ -173,10 +274,11 @@ impl RigEmbeddingAdapter {
         // Generate embeddings for all texts
         let mut results = std::vec::Vec::new();
         for text in texts {
-            let embeddings = embedding_model
-                .embed_text(text)
+            let embeddings = self
+                .retry_policy
+                .run(|| embedding_model.embed_text(text))
                 .await
-                .map_err(|e| std::format!("OpenAI embedding generation failed: {:?}", e))?;
+                .map_err(|e| e.describe(|e| std::format!("OpenAI embedding generation failed: {:?}", e)))?;
 
             // Convert f64 to f32
             let vec_f32: std::vec::Vec<f32> = embeddings.vec.iter().map(|&x| x as f32).collect();
@@ -214,8 +316,9 @@ impl crate::ports::embedding_port::EmbeddingPort for RigEmbeddingAdapter {
                     std::result::Result::Ok(embeddings.remove(0))
                 }
             }
-            std::result::Result::Err(_) => {
+            std::result::Result::Err(error) => {
                 // Fallback to zero vector for graceful degradation
+                tracing::warn!(error = %error, "embedding request failed; using zero vector");
                 std::result::Result::Ok(self.create_fallback_embeddings(1).remove(0))
             }
         }
@@ -251,8 +354,9 @@ impl crate::ports::embedding_port::EmbeddingPort for RigEmbeddingAdapter {
                     std::result::Result::Ok(embeddings)
                 }
             }
-            std::result::Result::Err(_) => {
+            std::result::Result::Err(error) => {
                 // Fallback to zero vectors for graceful degradation
+                tracing::warn!(error = %error, "embedding request failed; using zero vectors");
                 std::result::Result::Ok(self.create_fallback_embeddings(texts.len()))
             }
         }
@@ -261,6 +365,10 @@ impl crate::ports::embedding_port::EmbeddingPort for RigEmbeddingAdapter {
     async fn embedding_dimension(&self) -> usize {
         self.dimension
     }
+
+    fn embedding_model(&self) -> std::option::Option<std::string::String> {
+        std::option::Option::Some(self.model.clone())
+    }
 }
 
 #[cfg(test)]
@@ -289,6 +397,56 @@ mod tests {
         assert_eq!(adapter.embedding_dimension().await, 1536);
     }
 
+    #[tokio::test]
+    async fn test_openai_compatible_posts_to_custom_base_url() {
+        // Test: Validates an OpenAI-compatible adapter sends embedding requests to its base URL with the given key.
+        // Justification: Local servers listen on arbitrary URLs; requests sent to api.openai.com would silently fall back to zero vectors.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = std::format!("http://{}/v1", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 8192];
+            let n = tokio::io::AsyncReadExt::read(&mut socket, &mut buf).await.unwrap();
+            let request = String::from_utf8_lossy(&buf[..n]).into_owned();
+            let body = r#"{"object":"list","data":[{"object":"embedding","embedding":[0.5,0.25],"index":0}],"model":"local","usage":{"prompt_tokens":1,"total_tokens":1}}"#;
+            let response = std::format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            tokio::io::AsyncWriteExt::write_all(&mut socket, response.as_bytes()).await.unwrap();
+            request
+        });
+
+        let adapter = RigEmbeddingAdapter::new_openai_compatible(
+            base_url,
+            std::option::Option::Some(String::from("local-key")),
+            String::from("nomic-embed-text-v1.5"),
+        );
+        let embedding = adapter.generate_embedding("hello").await.unwrap();
+        let request = server.await.unwrap();
+
+        std::assert!(request.starts_with("POST /v1/embeddings "), "unexpected request: {}", request);
+        std::assert!(request.to_lowercase().contains("authorization: bearer local-key"));
+        std::assert_eq!(embedding, std::vec![0.5, 0.25]);
+        std::assert_eq!(adapter.embedding_dimension().await, 768);
+    }
+
+    #[tokio::test]
+    async fn test_refused_connection_reports_ollama_not_running() {
+        // Test: Validates embedding against a stopped Ollama fails with instructions naming the base URL and model.
+        // Justification: The raw reqwest connection error left new users guessing why search results were empty.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = std::format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+        let adapter = RigEmbeddingAdapter::new_ollama_with_base_url(base_url.clone(), String::from("nomic-embed-text"));
+
+        let error = adapter.generate_embeddings_internal(&["hello"]).await.unwrap_err();
+
+        std::assert!(error.contains(&base_url), "error: {}", error);
+        std::assert!(error.contains("ollama pull nomic-embed-text"), "error: {}", error);
+    }
+
     #[tokio::test]
     async fn test_empty_text_rejection() {
         // Test: Validates rejection of empty text input.
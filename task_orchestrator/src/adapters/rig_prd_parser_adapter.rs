@@ -1,9 +1,23 @@
 //! Rig-powered PRD parser adapter for task generation.
 //!
 //! This adapter uses Rig's CompletionModel to analyze PRDs and generate
-//! actionable task lists via LLM-based decomposition.
+//! actionable task lists via LLM-based decomposition. Generation and
+//! decomposition calls are timed out and retried under the adapter's
+//! `RetryPolicy`.
 //!
 //! Revision History
+//! - 2026-10-18T04:40:00Z @AI: Cache a deterministic reply only once it is a JSON array of task objects with titles.
+//! - 2026-10-18T02:00:00Z @AI: Re-ask only on schema failures: parse_tasks_from_json() returns TaskParseError, and a failed JSON remediation call is a Transport error returned without re-asking. Document that parse_prd_interactively() does not re-ask.
+//! - 2026-10-17T23:40:00Z @AI: Source section test expects the project-scoped section key.
+//! - 2026-10-17T21:10:00Z @AI: Add with_progress; report each generated task and sub-task (and validation notices) on a PRDGenUpdate channel while parsing and decomposition run.
+//! - 2026-10-17T15:10:00Z @AI: Add with_retry_policy; time out and retry generation and decomposition calls under the shared RetryPolicy.
+//! - 2026-10-17T00:10:00Z @AI: Initialize Persona::tool_call_limits.
+//! - 2026-10-16T21:30:00Z @AI: Apply configured temperature/seed to PRD parsing and decomposition calls and serve deterministic ones from an optional ResponseCache.
+//! - 2026-10-16T20:30:00Z @AI: Record token usage of PRD parsing and decomposition calls as InferenceMetrics, exposed via recorded_metrics() for run cost summaries.
+//! - 2026-10-16T19:50:00Z @AI: Time PRD parsing and decomposition LLM calls in `provider_call` spans; replace eprintln! diagnostics with tracing warnings.
+//! - 2026-10-16T11:30:00Z @AI: Include structured PRD requirements (parsed from markdown tables) in the task generation prompt.
+//! - 2026-10-16T11:10:00Z @AI: Capture source_section for incremental re-parsing. The system prompt asks for an optional source_section naming the PRD bullet a task implements; parse_tasks_from_json() extracts it (with aliases) and resolve_source_sections() normalizes it to the PRD's stable section key.
+//! - 2026-10-16T10:50:00Z @AI: Add bounded schema-violation re-ask loop. parse_prd_to_tasks now routes the LLM response through parse_with_schema_reask(), which sends the validation error and previous response back to the model (default 2 re-asks, configurable via with_max_schema_retries()). Transport errors from the LLM call are returned immediately without re-asking; the final schema error includes the last raw response. Add missing sort_order to test Task fixtures so the test module compiles.
 //! - 2025-11-30T22:00:00Z @AI: Implement two-pass persona assignment. Removed personas entirely from PRD parsing prompt (build_system_prompt now ignores personas parameter) to prevent biasing simpler LLMs into creating tasks FOR personas rather than FROM PRD content. Created assign_persona_to_task() method that uses LLM in a second pass to assign appropriate persona based on generated task's title/description. Personas list shown to LLM only during assignment pass, not during task generation. This allows organic task derivation from PRD requirements without persona influence.
 //! - 2025-11-30T21:15:00Z @AI: Simplify persona prompt to avoid biasing LLM. Previous prompt listed each persona with role and description, causing LLM to create tasks for each persona rather than deriving tasks from PRD content. Changed to minimal "ASSIGNEE OPTIONS: Name1, Name2, ... or Default Agent" format. LLM now focuses on PRD requirements and just picks an assignee from the list.
 //! - 2025-11-29T17:30:00Z @AI: Replace specific authentication example with abstract placeholders in prompts. The JWT/auth example was biasing LLM outputs toward auth-related tasks regardless of PRD content. Changed to SOTA few-shot approach: DESCRIPTION TEMPLATE with labeled sections [WHAT], [WHY], [HOW], [ACCEPTANCE], and RESPONSE FORMAT using <placeholders> for fields. LLM now generates tasks from PRD content without domain bias from concrete examples.
@@ -67,6 +81,23 @@ pub enum PRDGenUpdate {
     Error(String),
 }
 
+/// Why an LLM response could not be turned into tasks.
+#[derive(Debug)]
+enum TaskParseError {
+    /// The response violates the task schema; re-asking the model can fix it.
+    Schema(std::string::String),
+    /// An LLM call made while parsing (JSON remediation) failed.
+    Transport(std::string::String),
+}
+
+impl std::fmt::Display for TaskParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TaskParseError::Schema(message) | TaskParseError::Transport(message) => f.write_str(message),
+        }
+    }
+}
+
 /// Rig-powered adapter for parsing PRDs into tasks.
 ///
 /// RigPRDParserAdapter uses Rig's agent API to send PRD content to
@@ -89,9 +120,18 @@ pub struct RigPRDParserAdapter {
     embedding_port: std::option::Option<std::sync::Arc<dyn crate::ports::embedding_port::EmbeddingPort + std::marker::Send + std::marker::Sync>>,
     artifact_repository: std::option::Option<std::sync::Arc<std::sync::Mutex<dyn task_manager::ports::artifact_repository_port::ArtifactRepositoryPort + std::marker::Send>>>,
     project_id: std::option::Option<std::string::String>,
+    max_schema_retries: usize,
+    usage: std::sync::Arc<std::sync::Mutex<std::vec::Vec<crate::domain::performance_metrics::InferenceMetrics>>>,
+    sampling: crate::infrastructure::sampling::SamplingParams,
+    response_cache: std::option::Option<std::sync::Arc<crate::infrastructure::response_cache::ResponseCache>>,
+    retry_policy: crate::infrastructure::retry_policy::RetryPolicy,
+    progress: std::option::Option<tokio::sync::mpsc::Sender<PRDGenUpdate>>,
 }
 
 impl RigPRDParserAdapter {
+    /// Default number of re-asks after a schema-validation failure.
+    pub const DEFAULT_MAX_SCHEMA_RETRIES: usize = 2;
+
     /// Creates a new RigPRDParserAdapter with the specified models and personas.
     ///
     /// # Arguments
@@ -124,6 +164,12 @@ impl RigPRDParserAdapter {
             embedding_port: std::option::Option::None,
             artifact_repository: std::option::Option::None,
             project_id: std::option::Option::None,
+            max_schema_retries: Self::DEFAULT_MAX_SCHEMA_RETRIES,
+            usage: std::sync::Arc::new(std::sync::Mutex::new(std::vec::Vec::new())),
+            sampling: crate::infrastructure::sampling::SamplingParams::default(),
+            response_cache: std::option::Option::None,
+            retry_policy: crate::infrastructure::retry_policy::RetryPolicy::default(),
+            progress: std::option::Option::None,
         }
     }
 
@@ -174,7 +220,186 @@ impl RigPRDParserAdapter {
             embedding_port: std::option::Option::Some(embedding_port),
             artifact_repository: std::option::Option::Some(artifact_repository),
             project_id,
+            max_schema_retries: Self::DEFAULT_MAX_SCHEMA_RETRIES,
+            usage: std::sync::Arc::new(std::sync::Mutex::new(std::vec::Vec::new())),
+            sampling: crate::infrastructure::sampling::SamplingParams::default(),
+            response_cache: std::option::Option::None,
+            retry_policy: crate::infrastructure::retry_policy::RetryPolicy::default(),
+            progress: std::option::Option::None,
+        }
+    }
+
+    /// Sets how many times a schema-invalid response is sent back to the model for correction.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_schema_retries` - Maximum number of re-asks; 0 disables re-asking.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_max_schema_retries(mut self, max_schema_retries: usize) -> Self {
+        self.max_schema_retries = max_schema_retries;
+        self
+    }
+
+    /// Sets the temperature and seed sent with generation and decomposition calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `sampling` - Sampling parameters; unset values keep the model's defaults
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_sampling(mut self, sampling: crate::infrastructure::sampling::SamplingParams) -> Self {
+        self.sampling = sampling;
+        self
+    }
+
+    /// Serves repeated deterministic calls from `cache` instead of the model; `None` disables caching.
+    ///
+    /// Calls are only cached when the sampling parameters are deterministic
+    /// (see `with_sampling`). Cache hits record no token usage.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_response_cache(
+        mut self,
+        cache: std::option::Option<std::sync::Arc<crate::infrastructure::response_cache::ResponseCache>>,
+    ) -> Self {
+        self.response_cache = cache;
+        self
+    }
+
+    /// Sets the timeout and retry policy for generation and decomposition calls.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_retry_policy(mut self, retry_policy: crate::infrastructure::retry_policy::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Reports progress on `progress` while parsing and decomposition run.
+    ///
+    /// Each generated task and sub-task is sent as a `PRDGenUpdate::TaskGenerated`
+    /// as soon as its response has been validated, and assignee or JSON
+    /// remediation notices as `PRDGenUpdate::ValidationInfo`. The receiver must be
+    /// drained concurrently, or a full channel stalls the parser.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_progress(mut self, progress: tokio::sync::mpsc::Sender<PRDGenUpdate>) -> Self {
+        self.progress = std::option::Option::Some(progress);
+        self
+    }
+
+    /// Returns token usage of the generation and decomposition calls made so far.
+    ///
+    /// Clones of the adapter share one record.
+    pub fn recorded_metrics(&self) -> std::vec::Vec<crate::domain::performance_metrics::InferenceMetrics> {
+        self.usage.lock().map(|usage| usage.clone()).unwrap_or_default()
+    }
+
+    /// Sends a prompt through the response cache and agent, recording the
+    /// token counts Ollama reports for calls that reach the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `agent` - Agent for `self.model_name`
+    /// * `prompt` - Complete prompt text
+    /// * `operation` - Operation type for the recorded metrics (e.g. "prd_parsing"), also
+    ///   identifying the response format in the cache key
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the model answers with a tool call.
+    async fn prompt_recording_usage(
+        &self,
+        agent: &rig::agent::Agent<rig::providers::ollama::CompletionModel>,
+        prompt: &str,
+        operation: &str,
+    ) -> std::result::Result<std::string::String, std::string::String> {
+        let key = crate::infrastructure::response_cache::CacheKey::new("ollama", &self.model_name, &self.sampling, prompt, operation);
+        crate::infrastructure::response_cache::cached_call(self.response_cache.as_deref(), &key, &self.sampling, Self::check_task_array, || {
+            self.send_recording_usage(agent, prompt, operation)
+        })
+        .await
+    }
+
+    /// Checks that a reply holds a JSON array whose task objects all have a title.
+    ///
+    /// This is the schema check a reply must pass before it is cached. It
+    /// does not remediate, so a reply that needs JSON repair is never cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first schema violation found.
+    fn check_task_array(response: &str) -> std::result::Result<(), std::string::String> {
+        let json = Self::extract_json_from_response(response)?;
+        let tasks: std::vec::Vec<serde_json::Value> =
+            serde_json::from_str(&json).map_err(|e| std::format!("Expected JSON array of tasks: {}", e))?;
+        for (idx, task) in tasks.iter().enumerate() {
+            if let std::option::Option::Some(obj) = task.as_object() {
+                Self::extract_string(obj, &["title", "task", "name", "summary", "action", "item"])
+                    .ok_or_else(|| std::format!("Missing 'title' field in task at index {}", idx))?;
+            }
+        }
+        std::result::Result::Ok(())
+    }
+
+    /// Sends a prompt through the agent and records the token counts Ollama reports.
+    async fn send_recording_usage(
+        &self,
+        agent: &rig::agent::Agent<rig::providers::ollama::CompletionModel>,
+        prompt: &str,
+        operation: &str,
+    ) -> std::result::Result<std::string::String, std::string::String> {
+        let mut metrics = crate::domain::performance_metrics::InferenceMetrics::new(
+            std::string::String::from(operation),
+            std::string::String::from("ollama"),
+            self.model_name.clone(),
+            std::option::Option::None,
+        );
+        let started = std::time::Instant::now();
+        let response = self
+            .retry_policy
+            .run(|| async {
+                match rig::completion::Completion::completion(agent, prompt, std::vec::Vec::new()).await {
+                    std::result::Result::Ok(request) => request.send().await,
+                    std::result::Result::Err(e) => std::result::Result::Err(e),
+                }
+            })
+            .await;
+
+        let result = match response {
+            std::result::Result::Ok(response) => {
+                metrics.record_completion(
+                    started.elapsed(),
+                    response.raw_response.prompt_eval_count.unwrap_or(0) as usize,
+                    response.raw_response.eval_count.unwrap_or(0) as usize,
+                );
+                match response.choice.first() {
+                    rig::completion::AssistantContent::Text(text) => std::result::Result::Ok(text.text),
+                    rig::completion::AssistantContent::ToolCall(call) => {
+                        std::result::Result::Err(std::format!("LLM request failed: unexpected tool call '{}'", call.function.name))
+                    }
+                }
+            }
+            std::result::Result::Err(e) => {
+                metrics.record_failure(e.to_string());
+                std::result::Result::Err(std::format!("LLM request failed: {}", e))
+            }
+        };
+
+        if let std::result::Result::Ok(mut usage) = self.usage.lock() {
+            usage.push(metrics);
         }
+        result
     }
 
     /// Parses a PRD interactively with real-time streaming updates.
@@ -183,6 +408,11 @@ impl RigPRDParserAdapter {
     /// that shows LLM thinking, accepts user input mid-generation, and streams
     /// partial task results in real-time.
     ///
+    /// Schema violations are not re-asked here, unlike `parse_prd_to_tasks`: each
+    /// task has already been streamed as a TaskGenerated update, so a corrected
+    /// response would not match what the caller displayed. A schema error is sent
+    /// as `PRDGenUpdate::Error`.
+    ///
     /// # Arguments
     ///
     /// * `prd` - The Product Requirements Document to parse
@@ -423,11 +653,12 @@ impl RigPRDParserAdapter {
 
             // Parse accumulated response into tasks
             match Self::parse_tasks_from_json(&accumulated_response, &prd.project_id, &fallback_model_name, &personas, std::option::Option::Some(&update_tx)).await {
-                std::result::Result::Ok(tasks) => {
+                std::result::Result::Ok(mut tasks) => {
+                    Self::resolve_source_sections(&mut tasks, &prd);
                     let _ = update_tx.send(PRDGenUpdate::Complete(tasks)).await;
                 }
                 std::result::Result::Err(e) => {
-                    let _ = update_tx.send(PRDGenUpdate::Error(e)).await;
+                    let _ = update_tx.send(PRDGenUpdate::Error(e.to_string())).await;
                 }
             }
 
@@ -458,7 +689,8 @@ impl RigPRDParserAdapter {
         - \"title\": string (concise task title, max 100 chars)\n\
         - \"description\": string (DETAILED description - see requirements below)\n\
         - \"priority\": string (must be exactly \"high\", \"medium\", or \"low\")\n\
-        - \"estimated_complexity\": number (integer 1-10, where 10 is most complex)\n\n\
+        - \"estimated_complexity\": number (integer 1-10, where 10 is most complex)\n\
+        Optionally add \"source_section\": string (the exact Objective, Tech Stack, or Constraint bullet this task implements).\n\n\
         DESCRIPTION REQUIREMENTS (CRITICAL - read carefully):\n\
         Descriptions must be thorough and actionable. Each description MUST include:\n\
         1. WHAT: What needs to be built/implemented (specific features, components, or outcomes)\n\
@@ -577,7 +809,7 @@ impl RigPRDParserAdapter {
         let query_embedding = match embedding_port.generate_embedding(&query).await {
             std::result::Result::Ok(emb) => emb,
             std::result::Result::Err(e) => {
-                eprintln!("RAG: Failed to generate query embedding: {}", e);
+                tracing::warn!(prd_id = %prd.id, error = %e, "RAG: failed to generate query embedding");
                 return std::string::String::new();
             }
         };
@@ -586,7 +818,7 @@ impl RigPRDParserAdapter {
         let repo = match artifact_repository.lock() {
             std::result::Result::Ok(r) => r,
             std::result::Result::Err(e) => {
-                eprintln!("RAG: Failed to acquire repository lock: {}", e);
+                tracing::warn!(prd_id = %prd.id, error = %e, "RAG: failed to acquire repository lock");
                 return std::string::String::new();
             }
         };
@@ -599,7 +831,7 @@ impl RigPRDParserAdapter {
         ) {
             std::result::Result::Ok(artifacts) => artifacts,
             std::result::Result::Err(e) => {
-                eprintln!("RAG: Failed to search artifacts: {}", e);
+                tracing::warn!(prd_id = %prd.id, error = %e, "RAG: failed to search artifacts");
                 return std::string::String::new();
             }
         };
@@ -665,6 +897,16 @@ impl RigPRDParserAdapter {
             prompt.push('\n');
         }
 
+        if !prd.requirements.is_empty() {
+            prompt.push_str("## Requirements\n");
+            for requirement in &prd.requirements {
+                let id = requirement.id.as_deref().map(|id| std::format!("[{}] ", id)).unwrap_or_default();
+                let priority = requirement.priority.as_deref().map(|p| std::format!(" (priority: {})", p)).unwrap_or_default();
+                prompt.push_str(&std::format!("- {}{}{}\n", id, requirement.description, priority));
+            }
+            prompt.push('\n');
+        }
+
         prompt.push_str("---\n\n");
         prompt.push_str("GENERATE TASKS: Create a comprehensive task list for this PRD.\n");
         let field_count = if personas.is_empty() { 4 } else { 5 };
@@ -723,8 +965,9 @@ impl RigPRDParserAdapter {
     ///
     /// Uses the configured model for remediation - should be a fast, capable model.
     ///
-    /// Returns (fixed_json, remediation_log) on success, or error with full log on failure.
-    async fn remediate_json_with_llm(malformed_json: &str, model_name: &str) -> std::result::Result<(std::string::String, std::string::String), std::string::String> {
+    /// Returns (fixed_json, remediation_log) on success, or the full log on failure:
+    /// `TaskParseError::Transport` if the remediation call failed, `Schema` otherwise.
+    async fn remediate_json_with_llm(malformed_json: &str, model_name: &str) -> std::result::Result<(std::string::String, std::string::String), TaskParseError> {
         let mut log = std::string::String::from("JSON Remediation Log:\n");
 
         // Step 1: Aggressive text cleanup
@@ -838,7 +1081,7 @@ impl RigPRDParserAdapter {
             std::result::Result::Ok(response) => response,
             std::result::Result::Err(e) => {
                 log.push_str(&std::format!("  ✗ LLM call failed: {}\n", e));
-                return std::result::Result::Err(log);
+                return std::result::Result::Err(TaskParseError::Transport(log));
             }
         };
 
@@ -850,7 +1093,7 @@ impl RigPRDParserAdapter {
             std::result::Result::Ok(cleaned) => cleaned,
             std::result::Result::Err(e) => {
                 log.push_str(&std::format!("  ✗ Could not extract JSON from LLM response: {}\n", e));
-                return std::result::Result::Err(log);
+                return std::result::Result::Err(TaskParseError::Schema(log));
             }
         };
 
@@ -865,7 +1108,7 @@ impl RigPRDParserAdapter {
             log.push_str(&diagnostics);
             log.push_str("\n");
 
-            std::result::Result::Err(log)
+            std::result::Result::Err(TaskParseError::Schema(log))
         }
     }
 
@@ -1094,15 +1337,18 @@ impl RigPRDParserAdapter {
     }
 
     /// Parses LLM response JSON into tasks using tolerant parsing with field aliases.
+    ///
+    /// Fails with `TaskParseError::Transport` only when the JSON remediation call
+    /// fails; every other failure is a `Schema` error.
     async fn parse_tasks_from_json(
         json_str: &str,
         prd_id: &str,
         fallback_model_name: &str,
         personas: &[task_manager::domain::persona::Persona],
         update_tx: std::option::Option<&tokio::sync::mpsc::Sender<PRDGenUpdate>>,
-    ) -> std::result::Result<std::vec::Vec<task_manager::domain::task::Task>, std::string::String> {
+    ) -> std::result::Result<std::vec::Vec<task_manager::domain::task::Task>, TaskParseError> {
         // Extract JSON from response (handles markdown code blocks and extra text)
-        let cleaned_json = Self::extract_json_from_response(json_str)?;
+        let cleaned_json = Self::extract_json_from_response(json_str).map_err(TaskParseError::Schema)?;
 
         // Try to parse JSON response
         let parsed: std::result::Result<serde_json::Value, serde_json::Error> = serde_json::from_str(&cleaned_json);
@@ -1119,7 +1365,13 @@ impl RigPRDParserAdapter {
                 }
 
                 let (remediated, remediation_log) = Self::remediate_json_with_llm(&cleaned_json, fallback_model_name).await
-                    .map_err(|log| std::format!("JSON remediation failed after all attempts.\n\nOriginal parse error: {}\n\n{}", e, log))?;
+                    .map_err(|failure| {
+                        let message = std::format!("JSON remediation failed after all attempts.\n\nOriginal parse error: {}\n\n{}", e, failure);
+                        match failure {
+                            TaskParseError::Transport(_) => TaskParseError::Transport(message),
+                            TaskParseError::Schema(_) => TaskParseError::Schema(message),
+                        }
+                    })?;
 
                 // Remediation succeeded - send success notification
                 if let std::option::Option::Some(tx) = update_tx {
@@ -1131,18 +1383,18 @@ impl RigPRDParserAdapter {
 
                 // Try to parse remediated JSON
                 serde_json::from_str(&remediated)
-                    .map_err(|e2| std::format!(
+                    .map_err(|e2| TaskParseError::Schema(std::format!(
                         "Failed to parse remediated JSON: {}\n\nRemediation log:\n{}\n\nRemediated JSON (first 300 chars):\n{}",
                         e2,
                         remediation_log,
                         if remediated.len() > 300 { &remediated[0..300] } else { &remediated }
-                    ))?
+                    )))?
             }
         };
 
         let tasks_array = parsed
             .as_array()
-            .ok_or_else(|| std::string::String::from("Expected JSON array of tasks"))?;
+            .ok_or_else(|| TaskParseError::Schema(std::string::String::from("Expected JSON array of tasks")))?;
 
         let mut tasks = std::vec::Vec::new();
 
@@ -1151,7 +1403,7 @@ impl RigPRDParserAdapter {
             let obj = match task_value {
                 serde_json::Value::Object(m) => m,
                 _ => {
-                    eprintln!("[PRD Parser] Skipping non-object at index {}", idx);
+                    tracing::warn!(index = idx, "PRD parser: skipping non-object task entry");
                     continue;
                 }
             };
@@ -1160,7 +1412,7 @@ impl RigPRDParserAdapter {
             let title = Self::extract_string(
                 obj,
                 &["title", "task", "name", "summary", "action", "item"]
-            ).ok_or_else(|| std::format!("Missing 'title' field in task at index {}", idx))?;
+            ).ok_or_else(|| TaskParseError::Schema(std::format!("Missing 'title' field in task at index {}", idx)))?;
 
             // Extract description (optional)
             let description = Self::extract_string(
@@ -1198,6 +1450,12 @@ impl RigPRDParserAdapter {
                 &["agent_persona", "assignee", "assigned_to", "owner", "responsible"]
             );
 
+            // Extract source_section (optional); resolved to a PRD section key by the caller
+            let source_section = Self::extract_string(
+                obj,
+                &["source_section", "section", "objective", "source"]
+            );
+
             // Validate and resolve assignee
             let validated_assignee = Self::validate_assignee(&title, llm_assignee.as_deref(), personas, fallback_model_name, update_tx).await;
 
@@ -1217,6 +1475,7 @@ impl RigPRDParserAdapter {
             task.source_prd_id = std::option::Option::Some(prd_id.to_string());
             task.description = description;
             task.complexity = std::option::Option::Some(complexity as u8);
+            task.source_section = source_section;
 
             tasks.push(task);
         }
@@ -1224,6 +1483,120 @@ impl RigPRDParserAdapter {
         std::result::Result::Ok(tasks)
     }
 
+    /// Requests tasks from the LLM and re-asks with the validation error when the response violates the task schema.
+    ///
+    /// The initial prompt is sent via `ask`. If the response cannot be parsed into
+    /// tasks, the validation error and the offending response are sent back with an
+    /// instruction to fix the specific field, up to `max_retries` times. Transport
+    /// failures, whether returned by `ask` or by the JSON remediation call made
+    /// while parsing, are returned immediately without re-asking.
+    ///
+    /// Only `parse_prd_to_tasks` re-asks: `parse_prd_interactively` has already
+    /// streamed a TaskGenerated update per task by the time its response is parsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `prompt` - The original task generation prompt
+    /// * `prd_id` - PRD ID linked to generated tasks
+    /// * `fallback_model_name` - Model used for JSON and assignee remediation
+    /// * `personas` - Available personas for assignee validation
+    /// * `max_retries` - Maximum number of schema re-asks
+    /// * `progress` - Receives validation notices, and one TaskGenerated per task once a response validates
+    /// * `ask` - Sends a prompt to the LLM and returns its raw response
+    ///
+    /// # Returns
+    ///
+    /// The parsed tasks, or an error. A schema error after the final re-ask
+    /// includes the last raw response for debugging.
+    async fn parse_with_schema_reask<F, Fut>(
+        prompt: &str,
+        prd_id: &str,
+        fallback_model_name: &str,
+        personas: &[task_manager::domain::persona::Persona],
+        max_retries: usize,
+        progress: std::option::Option<&tokio::sync::mpsc::Sender<PRDGenUpdate>>,
+        mut ask: F,
+    ) -> std::result::Result<std::vec::Vec<task_manager::domain::task::Task>, std::string::String>
+    where
+        F: FnMut(std::string::String) -> Fut,
+        Fut: std::future::Future<Output = std::result::Result<std::string::String, std::string::String>>,
+    {
+        let mut response = ask(prompt.to_string()).await?;
+        let mut attempt = 0;
+
+        loop {
+            let validation_error = match Self::parse_tasks_from_json(&response, prd_id, fallback_model_name, personas, progress).await {
+                std::result::Result::Ok(tasks) => {
+                    // Only a validated response is reported, so a re-ask never repeats progress
+                    Self::report_generated(progress, &tasks).await;
+                    return std::result::Result::Ok(tasks);
+                }
+                std::result::Result::Err(TaskParseError::Transport(e)) => return std::result::Result::Err(e),
+                std::result::Result::Err(TaskParseError::Schema(e)) => e,
+            };
+
+            if attempt >= max_retries {
+                return std::result::Result::Err(std::format!(
+                    "Task schema validation failed after {} re-ask(s): {}\n\nLast raw response:\n{}",
+                    attempt,
+                    validation_error,
+                    response
+                ));
+            }
+            attempt += 1;
+
+            response = ask(Self::build_schema_reask_prompt(prompt, &response, &validation_error)).await?;
+        }
+    }
+
+    /// Sends one `PRDGenUpdate::TaskGenerated` per task; a missing or closed channel is ignored.
+    async fn report_generated(
+        progress: std::option::Option<&tokio::sync::mpsc::Sender<PRDGenUpdate>>,
+        tasks: &[task_manager::domain::task::Task],
+    ) {
+        let std::option::Option::Some(tx) = progress else {
+            return;
+        };
+        for task in tasks {
+            let _ = tx
+                .send(PRDGenUpdate::TaskGenerated {
+                    title: task.title.clone(),
+                    description: task.description.clone(),
+                    assignee: task.agent_persona.clone(),
+                    priority: std::option::Option::None,
+                    complexity: task.complexity,
+                })
+                .await;
+        }
+    }
+
+    /// Replaces each task's raw LLM section reference with the PRD's stable section key.
+    ///
+    /// References that match no PRD section are cleared so they cannot collide
+    /// with real keys during incremental re-parsing.
+    fn resolve_source_sections(tasks: &mut [task_manager::domain::task::Task], prd: &task_manager::domain::prd::PRD) {
+        for task in tasks.iter_mut() {
+            task.source_section = task
+                .source_section
+                .as_deref()
+                .and_then(|reference| prd.resolve_section_key(reference));
+        }
+    }
+
+    /// Builds the follow-up prompt asking the LLM to correct a schema-invalid response.
+    fn build_schema_reask_prompt(prompt: &str, invalid_response: &str, validation_error: &str) -> std::string::String {
+        std::format!(
+            "{}\n\n\
+            YOUR PREVIOUS RESPONSE FAILED VALIDATION:\n{}\n\n\
+            Previous response:\n{}\n\n\
+            Fix the specific field named in the error and return the complete corrected JSON array. \
+            Keep all other tasks unchanged. START YOUR RESPONSE WITH [ AND END WITH ]",
+            prompt,
+            validation_error,
+            invalid_response
+        )
+    }
+
     /// Decomposes a complex task into 3-5 sub-tasks using LLM.
     ///
     /// This method analyzes a parent task and generates child sub-tasks that break down
@@ -1259,12 +1632,12 @@ impl RigPRDParserAdapter {
 
         // Initialize Rig Ollama client
         let client = rig::providers::ollama::Client::new();
-        let agent = client.agent(&self.model_name).build();
+        let agent = crate::infrastructure::sampling::ollama_agent(&client, &self.model_name, &self.sampling).build();
 
         // Call LLM for decomposition
-        let response = rig::completion::Prompt::prompt(&agent, prompt.as_str())
-            .await
-            .map_err(|e| std::format!("LLM request failed: {}", e))?;
+        let span = crate::infrastructure::telemetry::provider_span("ollama", &self.model_name, &parent_task.id);
+        let response = task_manager::infrastructure::telemetry::timed(span, self.prompt_recording_usage(&agent, prompt.as_str(), "decomposition"))
+            .await?;
 
         // Extract and parse JSON
         let json_text = Self::extract_json_from_response(response.as_str())
@@ -1275,9 +1648,10 @@ impl RigPRDParserAdapter {
             parent_task.source_prd_id.as_deref().unwrap_or(""),
             &self.fallback_model_name,
             &self.personas,
-            std::option::Option::None, // No streaming for decomposition
+            self.progress.as_ref(),
         )
         .await?;
+        Self::report_generated(self.progress.as_ref(), &subtasks).await;
 
         std::result::Result::Ok(subtasks)
     }
@@ -1382,11 +1756,11 @@ impl RigPRDParserAdapter {
                     let _ = tx.send(PRDGenUpdate::ValidationInfo {
                         task_title: std::string::String::from("Sub-task Parsing"),
                         message: std::string::String::from("Remediating JSON..."),
-                    });
+                    }).await;
                 }
 
                 let (remediated, _log) = Self::remediate_json_with_llm(json_text, fallback_model_name).await
-                    .map_err(|log| std::format!("Sub-task JSON remediation failed: {}\n\nOriginal error: {}", log, e))?;
+                    .map_err(|failure| std::format!("Sub-task JSON remediation failed: {}\n\nOriginal error: {}", failure, e))?;
 
                 serde_json::from_str(&remediated)
                     .map_err(|e2| std::format!("Remediated JSON still invalid: {}", e2))?
@@ -1557,15 +1931,28 @@ impl crate::ports::prd_parser_port::PRDParserPort for RigPRDParserAdapter {
 
         // Initialize Rig Ollama client (uses http://localhost:11434 by default)
         let client = rig::providers::ollama::Client::new();
-        let agent = client.agent(&self.model_name).build();
+        let agent = crate::infrastructure::sampling::ollama_agent(&client, &self.model_name, &self.sampling).build();
 
-        // Call LLM via Rig agent
-        let response = rig::completion::Prompt::prompt(&agent, prompt.as_str())
-            .await
-            .map_err(|e| std::format!("LLM request failed: {}", e))?;
+        // Call LLM via Rig agent, re-asking on schema violations
+        let mut tasks = Self::parse_with_schema_reask(
+            &prompt,
+            &prd.id,
+            &self.fallback_model_name,
+            &self.personas,
+            self.max_schema_retries,
+            self.progress.as_ref(),
+            |request| {
+                let agent = &agent;
+                let span = crate::infrastructure::telemetry::provider_span("ollama", &self.model_name, &prd.id);
+                async move {
+                    task_manager::infrastructure::telemetry::timed(span, self.prompt_recording_usage(agent, request.as_str(), "prd_parsing")).await
+                }
+            },
+        )
+        .await?;
 
-        // Parse tasks from JSON response (now async to support remediation)
-        Self::parse_tasks_from_json(response.as_str(), &prd.id, &self.fallback_model_name, &self.personas, std::option::Option::None).await
+        Self::resolve_source_sections(&mut tasks, prd);
+        std::result::Result::Ok(tasks)
     }
 }
 
@@ -1615,6 +2002,27 @@ mod tests {
         std::assert!(prompt.contains("4 required fields")); // No personas = 4 fields
     }
 
+    #[tokio::test]
+    async fn test_build_prompt_includes_table_requirements() {
+        // Test: Validates that structured table requirements reach the LLM prompt.
+        // Justification: Acceptance criteria from PRD tables must inform task generation.
+        let prd = task_manager::infrastructure::markdown_parsers::prd_parser::parse_prd_markdown(
+            "project-123",
+            "# Checkout\n\n| ID | Requirement | Priority |\n|---|---|---|\n| AC-1 | Guest checkout | High |\n",
+        )
+        .unwrap();
+        let adapter = super::RigPRDParserAdapter::new(
+            String::from("llama3.2:latest"),
+            String::from("llama3.2:latest"),
+            std::vec![],
+        );
+
+        let prompt = adapter.build_prompt(&prd, &[]).await;
+
+        std::assert!(prompt.contains("## Requirements"));
+        std::assert!(prompt.contains("- [AC-1] Guest checkout (priority: High)"));
+    }
+
     #[tokio::test]
     async fn test_parse_tasks_from_valid_json() {
         // Test: Validates JSON parsing creates tasks with correct fields.
@@ -1642,6 +2050,32 @@ mod tests {
         std::assert_eq!(tasks[1].title, "Write tests");
     }
 
+    #[tokio::test]
+    async fn test_parse_tasks_resolves_source_section_keys() {
+        // Test: Validates that LLM section references resolve to stable PRD section keys.
+        // Justification: Incremental re-parsing matches tasks to PRD sections through these keys.
+        let prd = task_manager::domain::prd::PRD::new(
+            std::string::String::from("project-123"),
+            std::string::String::from("Rigger"),
+            std::vec![std::string::String::from("Enable task decomposition")],
+            std::vec![],
+            std::vec![],
+            std::string::String::new(),
+        );
+        let json = r#"[
+            {"title": "Build decomposer", "description": "Split tasks", "section": "enable task decomposition"},
+            {"title": "Unrelated", "description": "No match", "source_section": "Something else"},
+            {"title": "No section", "description": "Missing field"}
+        ]"#;
+
+        let mut tasks = super::RigPRDParserAdapter::parse_tasks_from_json(json, &prd.id, "llama3.2:latest", &[], std::option::Option::None).await.unwrap();
+        super::RigPRDParserAdapter::resolve_source_sections(&mut tasks, &prd);
+
+        std::assert_eq!(tasks[0].source_section.as_deref(), std::option::Option::Some("project-123 / Rigger > Objectives > Enable task decomposition"));
+        std::assert!(tasks[1].source_section.is_none());
+        std::assert!(tasks[2].source_section.is_none());
+    }
+
     #[tokio::test]
     async fn test_parse_tasks_from_invalid_json_fails() {
         // Test: Validates parser rejects malformed JSON.
@@ -1737,6 +2171,18 @@ Hope this helps!"#;
         std::assert!(result.unwrap_err().contains("Could not extract JSON array"));
     }
 
+    #[test]
+    fn test_check_task_array_rejects_replies_that_would_fail_parsing() {
+        // Test: Validates only a JSON array of titled task objects passes the check applied before caching.
+        // Justification: A cached reply that fails schema validation would be replayed on every deterministic re-run.
+        std::assert!(super::RigPRDParserAdapter::check_task_array(r#"```json
+[{"title": "Set up CI", "description": "Add a pipeline"}]
+```"#).is_ok());
+        std::assert!(super::RigPRDParserAdapter::check_task_array("Sorry, I cannot help with that.").is_err());
+        let missing_title = super::RigPRDParserAdapter::check_task_array(r#"[{"title": "A"}, {"description": "no title"}]"#);
+        std::assert!(missing_title.unwrap_err().contains("index 1"));
+    }
+
     #[test]
     fn test_extract_json_from_nested_arrays() {
         // Test: Validates extraction finds outermost array boundaries.
@@ -1845,6 +2291,18 @@ Hope this helps!"#;
             reasoning: std::option::Option::None,
             completion_summary: std::option::Option::None,
             context_files: std::vec::Vec::new(),
+            sort_order: std::option::Option::None,
+            source_section: std::option::Option::None,
+            priority: std::option::Option::None,
+            tags: std::vec::Vec::new(),
+            revisions: std::vec::Vec::new(),
+            checklist: std::vec::Vec::new(),
+            started_at: std::option::Option::None,
+            time_spent_seconds: 0,
+            source_utterance: std::option::Option::None,
+            idempotency_key: std::option::Option::None,
+            links: std::vec::Vec::new(),
+            estimate: std::option::Option::None,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
@@ -1973,6 +2431,18 @@ Hope this helps!"#;
             reasoning: std::option::Option::None,
             completion_summary: std::option::Option::None,
             context_files: std::vec::Vec::new(),
+            sort_order: std::option::Option::None,
+            source_section: std::option::Option::None,
+            priority: std::option::Option::None,
+            tags: std::vec::Vec::new(),
+            revisions: std::vec::Vec::new(),
+            checklist: std::vec::Vec::new(),
+            started_at: std::option::Option::None,
+            time_spent_seconds: 0,
+            source_utterance: std::option::Option::None,
+            idempotency_key: std::option::Option::None,
+            links: std::vec::Vec::new(),
+            estimate: std::option::Option::None,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
@@ -1990,6 +2460,7 @@ Hope this helps!"#;
                 created_at: chrono::Utc::now(),
                 updated_at: chrono::Utc::now(),
                 enabled_tools: std::vec::Vec::new(),
+                tool_call_limits: std::collections::HashMap::new(),
             },
             task_manager::domain::persona::Persona {
                 id: std::string::String::from("p2"),
@@ -2003,6 +2474,7 @@ Hope this helps!"#;
                 created_at: chrono::Utc::now(),
                 updated_at: chrono::Utc::now(),
                 enabled_tools: std::vec::Vec::new(),
+                tool_call_limits: std::collections::HashMap::new(),
             },
         ];
 
@@ -2012,4 +2484,130 @@ Hope this helps!"#;
         std::assert!(prompt.contains("Backend Developer"), "Missing first persona");
         std::assert!(prompt.contains("Frontend Developer"), "Missing second persona");
     }
+
+    /// Builds a mock LLM that returns the scripted responses in order and records each prompt.
+    fn scripted_llm(
+        responses: std::vec::Vec<std::result::Result<std::string::String, std::string::String>>,
+        prompts: std::sync::Arc<std::sync::Mutex<std::vec::Vec<std::string::String>>>,
+    ) -> impl FnMut(std::string::String) -> std::future::Ready<std::result::Result<std::string::String, std::string::String>> {
+        let mut responses = responses.into_iter();
+        move |prompt| {
+            prompts.lock().unwrap().push(prompt);
+            std::future::ready(responses.next().expect("mock LLM called more times than scripted"))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_schema_reask_recovers_from_invalid_then_valid_response() {
+        // Test: Validates that a schema-invalid response is re-asked with the validation error.
+        // Justification: A single missing field must not fail the whole `rig parse`.
+        let prompts = std::sync::Arc::new(std::sync::Mutex::new(std::vec::Vec::new()));
+        let llm = scripted_llm(
+            std::vec![
+                std::result::Result::Ok(std::string::String::from(r#"[{"description": "Task without a title"}]"#)),
+                std::result::Result::Ok(std::string::String::from(r#"[{"title": "Setup project", "description": "Initialize workspace"}]"#)),
+            ],
+            prompts.clone(),
+        );
+
+        let tasks = super::RigPRDParserAdapter::parse_with_schema_reask("PROMPT", "prd-1", "llama3.2:latest", &[], 2, std::option::Option::None, llm)
+            .await
+            .unwrap();
+
+        std::assert_eq!(tasks.len(), 1);
+        std::assert_eq!(tasks[0].title, "Setup project");
+        let prompts = prompts.lock().unwrap();
+        std::assert_eq!(prompts.len(), 2);
+        std::assert!(prompts[1].starts_with("PROMPT"));
+        std::assert!(prompts[1].contains("Missing 'title' field in task at index 0"));
+        std::assert!(prompts[1].contains("Task without a title"));
+    }
+
+    #[tokio::test]
+    async fn test_schema_reask_stops_after_cap_with_last_response() {
+        // Test: Validates that re-asking is bounded and the error carries the last raw response.
+        // Justification: A model that never conforms must not loop forever, and failures need debug context.
+        let prompts = std::sync::Arc::new(std::sync::Mutex::new(std::vec::Vec::new()));
+        let llm = scripted_llm(
+            std::vec![
+                std::result::Result::Ok(std::string::String::from(r#"{"tasks": "first"}"#)),
+                std::result::Result::Ok(std::string::String::from(r#"[{"name_missing": "second"}]"#)),
+                std::result::Result::Ok(std::string::String::from(r#"[{"name_missing": "third"}]"#)),
+            ],
+            prompts.clone(),
+        );
+
+        let err = super::RigPRDParserAdapter::parse_with_schema_reask("PROMPT", "prd-1", "llama3.2:latest", &[], 2, std::option::Option::None, llm)
+            .await
+            .unwrap_err();
+
+        std::assert_eq!(prompts.lock().unwrap().len(), 3);
+        std::assert!(err.contains("after 2 re-ask(s)"));
+        std::assert!(err.contains(r#"[{"name_missing": "third"}]"#));
+    }
+
+    #[tokio::test]
+    async fn test_schema_reask_does_not_retry_transport_errors() {
+        // Test: Validates that LLM transport failures are returned without re-asking.
+        // Justification: Re-asking cannot fix an unreachable server and would only delay the error.
+        let prompts = std::sync::Arc::new(std::sync::Mutex::new(std::vec::Vec::new()));
+        let llm = scripted_llm(
+            std::vec![std::result::Result::Err(std::string::String::from("LLM request failed: connection refused"))],
+            prompts.clone(),
+        );
+
+        let err = super::RigPRDParserAdapter::parse_with_schema_reask("PROMPT", "prd-1", "llama3.2:latest", &[], 2, std::option::Option::None, llm)
+            .await
+            .unwrap_err();
+
+        std::assert_eq!(prompts.lock().unwrap().len(), 1);
+        std::assert_eq!(err, "LLM request failed: connection refused");
+    }
+
+    #[tokio::test]
+    async fn test_schema_reask_does_not_retry_failed_json_remediation_calls() {
+        // Test: Validates a failed remediation call while parsing is returned without re-asking.
+        // Justification: The remediation model being unreachable or missing is a transport failure, not a schema violation.
+        let prompts = std::sync::Arc::new(std::sync::Mutex::new(std::vec::Vec::new()));
+        let llm = scripted_llm(
+            std::vec![std::result::Result::Ok(std::string::String::from(r#"[{"title": "Setup" "description": "missing comma"}]"#))],
+            prompts.clone(),
+        );
+
+        let err = super::RigPRDParserAdapter::parse_with_schema_reask("PROMPT", "prd-1", "missing-remediation-model:0b", &[], 2, std::option::Option::None, llm)
+            .await
+            .unwrap_err();
+
+        std::assert_eq!(prompts.lock().unwrap().len(), 1);
+        std::assert!(err.contains("LLM call failed"));
+        std::assert!(!err.contains("re-ask(s)"));
+    }
+
+    #[tokio::test]
+    async fn test_schema_reask_reports_each_task_of_the_validated_response_once() {
+        // Test: Validates one TaskGenerated update per task is sent, and none for a response that was re-asked.
+        // Justification: `rig parse` prints progress from these updates, so each task must appear exactly once.
+        let llm = scripted_llm(
+            std::vec![
+                std::result::Result::Ok(std::string::String::from(r#"[{"title": "Setup project"}, {"description": "untitled"}]"#)),
+                std::result::Result::Ok(std::string::String::from(r#"[{"title": "Setup project"}, {"title": "Write tests"}]"#)),
+            ],
+            std::sync::Arc::new(std::sync::Mutex::new(std::vec::Vec::new())),
+        );
+        let (tx, mut rx) = tokio::sync::mpsc::channel(16);
+
+        let tasks = super::RigPRDParserAdapter::parse_with_schema_reask("PROMPT", "prd-1", "llama3.2:latest", &[], 2, std::option::Option::Some(&tx), llm)
+            .await
+            .unwrap();
+        drop(tx);
+
+        let mut reported = std::vec::Vec::new();
+        while let std::option::Option::Some(update) = rx.recv().await {
+            if let super::PRDGenUpdate::TaskGenerated { title, .. } = update {
+                reported.push(title);
+            }
+        }
+        std::assert_eq!(tasks.len(), 2);
+        std::assert_eq!(reported, ["Setup project", "Write tests"]);
+    }
 }
@@ -3,8 +3,13 @@
 //! RigVisionAdapter provides image and PDF description capabilities using vision-capable
 //! LLMs from multiple providers (Ollama LLaVA, OpenAI GPT-4V, Anthropic Claude 3).
 //! It enables PRD preprocessing by describing embedded images before task generation.
+//! Requests are timed out and retried under the adapter's `RetryPolicy`, so a
+//! rate limit or transient server error is retried with backoff.
 //!
 //! Revision History
+//! - 2026-10-18T03:40:00Z @AI: Pass the validated MIME type from read_image_file() to OCR requests.
+//! - 2026-10-17T15:10:00Z @AI: Add with_retry_policy; send every provider request through the shared RetryPolicy.
+//! - 2026-10-16T12:50:00Z @AI: Add OCR text extraction via extract_text() with a verbatim transcription prompt.
 //! - 2025-11-30T11:15:00Z @AI: Initial RigVisionAdapter for Phase 5 image processing implementation.
 
 /// Adapter for vision-capable LLM interactions.
@@ -40,6 +45,7 @@ pub struct RigVisionAdapter {
     provider: VisionProvider,
     model: String,
     http_client: reqwest::Client,
+    retry_policy: crate::infrastructure::retry_policy::RetryPolicy,
 }
 
 /// Enum representing the vision provider backend.
@@ -79,6 +85,7 @@ impl RigVisionAdapter {
             provider: VisionProvider::Ollama { base_url },
             model,
             http_client: reqwest::Client::new(),
+            retry_policy: crate::infrastructure::retry_policy::RetryPolicy::default(),
         }
     }
 
@@ -105,6 +112,7 @@ impl RigVisionAdapter {
             provider: VisionProvider::OpenAI { api_key },
             model,
             http_client: reqwest::Client::new(),
+            retry_policy: crate::infrastructure::retry_policy::RetryPolicy::default(),
         }
     }
 
@@ -131,9 +139,55 @@ impl RigVisionAdapter {
             provider: VisionProvider::Anthropic { api_key },
             model,
             http_client: reqwest::Client::new(),
+            retry_policy: crate::infrastructure::retry_policy::RetryPolicy::default(),
         }
     }
 
+    /// Sets the timeout and retry policy for vision requests.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_retry_policy(mut self, retry_policy: crate::infrastructure::retry_policy::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sends the request built by `request` under the retry policy and parses the JSON reply.
+    ///
+    /// A non-success status fails with the status and body, so statuses the
+    /// policy lists as retryable (e.g. 429, 503) are retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - Provider name for error messages (e.g. "Ollama")
+    /// * `request` - Builds a fresh request for each attempt
+    async fn send_json<F>(&self, provider: &str, request: F) -> std::result::Result<serde_json::Value, std::string::String>
+    where
+        F: Fn() -> reqwest::RequestBuilder,
+    {
+        self.retry_policy
+            .run(|| async {
+                let response = request()
+                    .send()
+                    .await
+                    .map_err(|e| std::format!("{} vision request failed: {:?}", provider, e))?;
+
+                if !response.status().is_success() {
+                    let status = response.status();
+                    let body = response.text().await.unwrap_or_default();
+                    return std::result::Result::Err(std::format!("{} vision API returned {}: {}", provider, status, body));
+                }
+
+                response
+                    .json::<serde_json::Value>()
+                    .await
+                    .map_err(|e| std::format!("Failed to parse {} response: {:?}", provider, e))
+            })
+            .await
+            .map_err(|e| e.to_string())
+    }
+
     /// Generates a description using Ollama's vision API.
     ///
     /// Ollama's `/api/generate` endpoint accepts images as a base64 array in the
@@ -156,28 +210,9 @@ impl RigVisionAdapter {
 
         let url = std::format!("{}/api/generate", base_url);
 
-        let response = self
-            .http_client
-            .post(&url)
-            .json(&request_body)
-            .send()
-            .await
-            .map_err(|e| std::format!("Ollama vision request failed: {:?}", e))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            return std::result::Result::Err(std::format!(
-                "Ollama vision API returned {}: {}",
-                status,
-                body
-            ));
-        }
-
-        let response_json: serde_json::Value = response
-            .json()
-            .await
-            .map_err(|e| std::format!("Failed to parse Ollama response: {:?}", e))?;
+        let response_json = self
+            .send_json("Ollama", || self.http_client.post(&url).json(&request_body))
+            .await?;
 
         let description = response_json["response"]
             .as_str()
@@ -231,30 +266,15 @@ impl RigVisionAdapter {
             "max_tokens": 1024
         });
 
-        let response = self
-            .http_client
-            .post("https://api.openai.com/v1/chat/completions")
-            .header("Authorization", std::format!("Bearer {}", api_key))
-            .header("Content-Type", "application/json")
-            .json(&request_body)
-            .send()
-            .await
-            .map_err(|e| std::format!("OpenAI vision request failed: {:?}", e))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            return std::result::Result::Err(std::format!(
-                "OpenAI vision API returned {}: {}",
-                status,
-                body
-            ));
-        }
-
-        let response_json: serde_json::Value = response
-            .json()
-            .await
-            .map_err(|e| std::format!("Failed to parse OpenAI response: {:?}", e))?;
+        let response_json = self
+            .send_json("OpenAI", || {
+                self.http_client
+                    .post("https://api.openai.com/v1/chat/completions")
+                    .header("Authorization", std::format!("Bearer {}", api_key))
+                    .header("Content-Type", "application/json")
+                    .json(&request_body)
+            })
+            .await?;
 
         let description = response_json["choices"][0]["message"]["content"]
             .as_str()
@@ -321,31 +341,16 @@ impl RigVisionAdapter {
             ]
         });
 
-        let response = self
-            .http_client
-            .post("https://api.anthropic.com/v1/messages")
-            .header("x-api-key", api_key)
-            .header("anthropic-version", "2023-06-01")
-            .header("Content-Type", "application/json")
-            .json(&request_body)
-            .send()
-            .await
-            .map_err(|e| std::format!("Anthropic vision request failed: {:?}", e))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            return std::result::Result::Err(std::format!(
-                "Anthropic vision API returned {}: {}",
-                status,
-                body
-            ));
-        }
-
-        let response_json: serde_json::Value = response
-            .json()
-            .await
-            .map_err(|e| std::format!("Failed to parse Anthropic response: {:?}", e))?;
+        let response_json = self
+            .send_json("Anthropic", || {
+                self.http_client
+                    .post("https://api.anthropic.com/v1/messages")
+                    .header("x-api-key", api_key)
+                    .header("anthropic-version", "2023-06-01")
+                    .header("Content-Type", "application/json")
+                    .json(&request_body)
+            })
+            .await?;
 
         // Anthropic returns content as an array of blocks
         let description = response_json["content"]
@@ -363,6 +368,29 @@ impl RigVisionAdapter {
         })
     }
 
+    /// Sends an image and prompt to the configured provider.
+    async fn send_to_provider(
+        &self,
+        base64_data: &str,
+        mime_type: &str,
+        prompt: &str,
+    ) -> std::result::Result<crate::ports::vision_port::VisionResponse, std::string::String> {
+        match &self.provider {
+            VisionProvider::Ollama { base_url } => {
+                self.describe_with_ollama(base_url, base64_data, prompt)
+                    .await
+            }
+            VisionProvider::OpenAI { api_key } => {
+                self.describe_with_openai(api_key, base64_data, mime_type, prompt)
+                    .await
+            }
+            VisionProvider::Anthropic { api_key } => {
+                self.describe_with_anthropic(api_key, base64_data, mime_type, prompt)
+                    .await
+            }
+        }
+    }
+
     /// Builds a prompt for image description based on optional context.
     fn build_image_prompt(context: std::option::Option<&str>) -> String {
         match context {
@@ -432,20 +460,7 @@ impl crate::ports::vision_port::VisionPort for RigVisionAdapter {
 
         let prompt = Self::build_image_prompt(context);
 
-        match &self.provider {
-            VisionProvider::Ollama { base_url } => {
-                self.describe_with_ollama(base_url, base64_data, &prompt)
-                    .await
-            }
-            VisionProvider::OpenAI { api_key } => {
-                self.describe_with_openai(api_key, base64_data, mime_type, &prompt)
-                    .await
-            }
-            VisionProvider::Anthropic { api_key } => {
-                self.describe_with_anthropic(api_key, base64_data, mime_type, &prompt)
-                    .await
-            }
-        }
+        self.send_to_provider(base64_data, mime_type, &prompt).await
     }
 
     async fn describe_pdf_page(
@@ -466,22 +481,15 @@ impl crate::ports::vision_port::VisionPort for RigVisionAdapter {
         let prompt = Self::build_pdf_page_prompt(page_number, context);
 
         // PDF pages are rendered as PNG images for vision processing
-        let mime_type = "image/png";
+        self.send_to_provider(page_image_base64, "image/png", &prompt).await
+    }
 
-        match &self.provider {
-            VisionProvider::Ollama { base_url } => {
-                self.describe_with_ollama(base_url, page_image_base64, &prompt)
-                    .await
-            }
-            VisionProvider::OpenAI { api_key } => {
-                self.describe_with_openai(api_key, page_image_base64, mime_type, &prompt)
-                    .await
-            }
-            VisionProvider::Anthropic { api_key } => {
-                self.describe_with_anthropic(api_key, page_image_base64, mime_type, &prompt)
-                    .await
-            }
-        }
+    async fn extract_text(&self, path: &std::path::Path) -> std::result::Result<std::string::String, std::string::String> {
+        let (base64_data, mime_type) = crate::ports::vision_port::read_image_file(path).await?;
+        let response = self
+            .send_to_provider(&base64_data, &mime_type, crate::ports::vision_port::OCR_PROMPT)
+            .await?;
+        std::result::Result::Ok(response.description.trim().to_string())
     }
 
     fn model_name(&self) -> &str {
@@ -590,6 +598,20 @@ mod tests {
         assert!(result.unwrap_err().contains("empty image data"));
     }
 
+    #[tokio::test]
+    async fn test_extract_text_rejects_non_image_file() {
+        // Test: Validates OCR fails before any network call for non-image files.
+        // Justification: Only PNG, JPEG, GIF, and WebP data can be sent for transcription.
+        let adapter = RigVisionAdapter::new_ollama(String::from("llava"));
+        let path = std::env::temp_dir().join(std::format!("ocr_not_image_{}.png", uuid::Uuid::new_v4()));
+        std::fs::write(&path, b"plain text").unwrap();
+
+        let result = adapter.extract_text(&path).await;
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(result.unwrap_err().contains("Unsupported image format"));
+    }
+
     #[tokio::test]
     async fn test_empty_pdf_page_data_rejection() {
         // Test: Validates rejection of empty PDF page data.
@@ -618,6 +640,53 @@ mod tests {
         assert!(result.unwrap_err().contains("Page number must be >= 1"));
     }
 
+    #[tokio::test]
+    async fn test_retryable_status_is_retried_under_policy() {
+        // Test: Validates a 503 from the vision endpoint is retried and the following 200 reply is used.
+        // Justification: A transient server error must not fail PRD image preprocessing outright.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = std::format!("http://{}", listener.local_addr().unwrap());
+        let requests = std::sync::Arc::new(std::sync::atomic::AtomicU32::new(0));
+        let seen = requests.clone();
+        tokio::spawn(async move {
+            while let std::result::Result::Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = [0u8; 8192];
+                let _ = tokio::io::AsyncReadExt::read(&mut socket, &mut buf).await;
+                let (status, body) = if seen.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0 {
+                    ("503 Service Unavailable", "{\"error\":\"busy\"}")
+                } else {
+                    ("200 OK", "{\"response\":\"a tiny image\"}")
+                };
+                let response = std::format!(
+                    "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = tokio::io::AsyncWriteExt::write_all(&mut socket, response.as_bytes()).await;
+            }
+        });
+        let adapter = RigVisionAdapter {
+            provider: VisionProvider::Ollama { base_url },
+            model: String::from("llava"),
+            http_client: reqwest::Client::builder().no_proxy().build().unwrap(),
+            retry_policy: crate::infrastructure::retry_policy::RetryPolicy::default(),
+        }
+        .with_retry_policy(crate::infrastructure::retry_policy::RetryPolicy {
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(2),
+            ..crate::infrastructure::retry_policy::RetryPolicy::default()
+        });
+
+        let response = adapter
+            .describe_image("iVBORw0KGgo=", "image/png", std::option::Option::None)
+            .await
+            .unwrap();
+
+        assert_eq!(response.description, "a tiny image");
+        assert_eq!(requests.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
     #[tokio::test]
     #[ignore] // Ignored: Requires running Ollama with llava model
     async fn test_ollama_image_description() {
@@ -0,0 +1,407 @@
+//! SQLite-backed conversation store adapter.
+//!
+//! Persists chat agent turns to a `conversation_turns` table keyed by session
+//! ID, with tool calls stored as a JSON column. Each session keeps at most
+//! `max_turns` turns: when an append pushes a session past the window, the
+//! oldest turns are folded into a short extractive summary in
+//! `conversation_summaries` and deleted, in the same transaction.
+//!
+//! Schema is created and upgraded by versioned migrations in `connect_and_init()`.
+//!
+//! Revision History
+//! - 2026-10-17T05:50:00Z @AI: Add connect_and_init_with; pools now come from task_manager's shared connector.
+//! - 2026-10-17T05:30:00Z @AI: Create the schema through versioned migrations.
+//! - 2026-10-17T00:30:00Z @AI: Create SQLite conversation store with max-history trimming and summary.
+
+/// Default number of turns kept per session.
+pub const DEFAULT_MAX_TURNS: usize = 40;
+
+/// Longest excerpt of a trimmed turn kept in the summary, in characters.
+const SUMMARY_EXCERPT_CHARS: usize = 160;
+
+/// Longest summary kept per session, in characters. Oldest lines go first.
+const MAX_SUMMARY_CHARS: usize = 4000;
+
+/// SQLite-backed implementation of ConversationStorePort.
+///
+/// # Examples
+///
+/// ```no_run
+/// use task_orchestrator::adapters::sqlite_conversation_adapter::SqliteConversationAdapter;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let store = SqliteConversationAdapter::connect_and_init("sqlite:.rigger/tasks.db")
+///         .await
+///         .unwrap()
+///         .with_max_turns(20);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SqliteConversationAdapter {
+    pool: sqlx::Pool<sqlx::Sqlite>,
+    max_turns: usize,
+}
+
+impl SqliteConversationAdapter {
+    /// Scope under which this adapter's schema versions are recorded in `schema_migrations`.
+    const MIGRATION_SCOPE: &'static str = "conversations";
+
+    /// Schema history of the conversation tables. Append new migrations; never edit shipped ones.
+    const MIGRATIONS: &'static [task_manager::infrastructure::sqlite_migrations::migration::Migration] = &[task_manager::infrastructure::sqlite_migrations::migration::Migration {
+        version: 1,
+        description: "Create conversation tables",
+        steps: &[
+            task_manager::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute(
+                "CREATE TABLE IF NOT EXISTS conversation_turns (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    session_id TEXT NOT NULL,
+                    role TEXT NOT NULL,
+                    content TEXT NOT NULL,
+                    tool_calls_json TEXT NOT NULL DEFAULT '[]',
+                    created_at TEXT NOT NULL
+                )",
+            ),
+            task_manager::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute("CREATE INDEX IF NOT EXISTS idx_conversation_turns_session ON conversation_turns(session_id, id)"),
+            task_manager::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute(
+                "CREATE TABLE IF NOT EXISTS conversation_summaries (
+                    session_id TEXT PRIMARY KEY,
+                    summary TEXT NOT NULL,
+                    updated_at TEXT NOT NULL
+                )",
+            ),
+        ],
+    }];
+
+    /// Creates a new adapter from an existing SQLite pool.
+    ///
+    /// Call `init_schema` before use unless the tables already exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - SQLite connection pool
+    pub fn new(pool: sqlx::Pool<sqlx::Sqlite>) -> Self {
+        Self { pool, max_turns: DEFAULT_MAX_TURNS }
+    }
+
+    /// Sets how many turns each session keeps before older ones are summarized.
+    ///
+    /// `0` keeps every turn.
+    pub fn with_max_turns(mut self, max_turns: usize) -> Self {
+        self.max_turns = max_turns;
+        self
+    }
+
+    /// Connects to a SQLite database and initializes the schema if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `database_url` - SQLite database URL (e.g., "sqlite:.rigger/tasks.db")
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection or schema creation fails.
+    pub async fn connect_and_init(database_url: &str) -> std::result::Result<Self, String> {
+        Self::connect_and_init_with(
+            database_url,
+            &task_manager::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings::default(),
+        )
+        .await
+    }
+
+    /// Connects with a pool sized by `settings` (WAL mode and busy timeout included) and initializes the schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection or schema creation fails.
+    pub async fn connect_and_init_with(
+        database_url: &str,
+        settings: &task_manager::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings,
+    ) -> std::result::Result<Self, String> {
+        let pool = task_manager::infrastructure::sqlite_pool::connector::connect(database_url, settings, std::option::Option::None).await?;
+
+        let adapter = Self::new(pool);
+        adapter.init_schema().await?;
+        std::result::Result::Ok(adapter)
+    }
+
+    /// Applies the pending schema migrations. Idempotent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a statement fails.
+    pub async fn init_schema(&self) -> std::result::Result<(), String> {
+        task_manager::infrastructure::sqlite_migrations::migrator::run_pending(&self.pool, Self::MIGRATION_SCOPE, Self::MIGRATIONS)
+            .await
+            .map(|_| ())
+    }
+
+    fn role_to_str(role: crate::ports::llm_agent_port::AgentRole) -> &'static str {
+        match role {
+            crate::ports::llm_agent_port::AgentRole::System => "system",
+            crate::ports::llm_agent_port::AgentRole::User => "user",
+            crate::ports::llm_agent_port::AgentRole::Assistant => "assistant",
+        }
+    }
+
+    fn role_from_str(role: &str) -> std::result::Result<crate::ports::llm_agent_port::AgentRole, String> {
+        match role {
+            "system" => std::result::Result::Ok(crate::ports::llm_agent_port::AgentRole::System),
+            "user" => std::result::Result::Ok(crate::ports::llm_agent_port::AgentRole::User),
+            "assistant" => std::result::Result::Ok(crate::ports::llm_agent_port::AgentRole::Assistant),
+            other => std::result::Result::Err(std::format!("Unknown conversation role: {}", other)),
+        }
+    }
+
+    /// Converts a database row to a ConversationTurn.
+    fn row_to_turn(row: sqlx::sqlite::SqliteRow) -> std::result::Result<crate::ports::conversation_store_port::ConversationTurn, String> {
+        let session_id: String = sqlx::Row::get(&row, "session_id");
+        let role: String = sqlx::Row::get(&row, "role");
+        let content: String = sqlx::Row::get(&row, "content");
+        let tool_calls_json: String = sqlx::Row::get(&row, "tool_calls_json");
+        let created_at: String = sqlx::Row::get(&row, "created_at");
+
+        std::result::Result::Ok(crate::ports::conversation_store_port::ConversationTurn {
+            session_id,
+            role: Self::role_from_str(&role)?,
+            content,
+            tool_calls: serde_json::from_str(&tool_calls_json)
+                .map_err(|e| std::format!("Invalid tool calls JSON: {:?}", e))?,
+            created_at: chrono::DateTime::parse_from_rfc3339(&created_at)
+                .map_err(|e| std::format!("Invalid timestamp: {:?}", e))?
+                .with_timezone(&chrono::Utc),
+        })
+    }
+
+    /// Appends one summary line per trimmed turn to `previous`, dropping the
+    /// oldest lines once the summary exceeds `MAX_SUMMARY_CHARS`.
+    fn fold_into_summary(
+        previous: std::option::Option<String>,
+        trimmed: &[(String, String)],
+    ) -> String {
+        let mut lines: std::vec::Vec<String> = previous
+            .map(|summary| summary.lines().map(String::from).collect())
+            .unwrap_or_default();
+
+        for (role, content) in trimmed {
+            let flattened = content.split_whitespace().collect::<std::vec::Vec<_>>().join(" ");
+            let mut excerpt: String = flattened.chars().take(SUMMARY_EXCERPT_CHARS).collect();
+            if flattened.chars().count() > SUMMARY_EXCERPT_CHARS {
+                excerpt.push('…');
+            }
+            let label = match role.as_str() {
+                "user" => "User",
+                "assistant" => "Assistant",
+                _ => "System",
+            };
+            lines.push(std::format!("{}: {}", label, excerpt));
+        }
+
+        let mut total: usize = lines.iter().map(|line| line.chars().count() + 1).sum();
+        let mut start = 0;
+        while total > MAX_SUMMARY_CHARS && start + 1 < lines.len() {
+            total -= lines[start].chars().count() + 1;
+            start += 1;
+        }
+        lines[start..].join("\n")
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::ports::conversation_store_port::ConversationStorePort for SqliteConversationAdapter {
+    async fn append_turn(
+        &self,
+        turn: crate::ports::conversation_store_port::ConversationTurn,
+    ) -> std::result::Result<(), String> {
+        let tool_calls_json = serde_json::to_string(&turn.tool_calls)
+            .map_err(|e| std::format!("Failed to serialize tool calls: {:?}", e))?;
+
+        let mut tx = self.pool.begin()
+            .await
+            .map_err(|e| std::format!("Failed to begin transaction: {:?}", e))?;
+
+        sqlx::query(
+            "INSERT INTO conversation_turns (session_id, role, content, tool_calls_json, created_at)
+             VALUES (?, ?, ?, ?, ?)"
+        )
+        .bind(&turn.session_id)
+        .bind(Self::role_to_str(turn.role))
+        .bind(&turn.content)
+        .bind(&tool_calls_json)
+        .bind(turn.created_at.to_rfc3339())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| std::format!("Failed to insert conversation turn: {:?}", e))?;
+
+        if self.max_turns > 0 {
+            // Everything past the newest `max_turns` rows, newest first
+            let overflow = sqlx::query(
+                "SELECT id, role, content FROM conversation_turns
+                 WHERE session_id = ? ORDER BY id DESC LIMIT -1 OFFSET ?"
+            )
+            .bind(&turn.session_id)
+            .bind(self.max_turns as i64)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| std::format!("Failed to find turns to trim: {:?}", e))?;
+
+            if let std::option::Option::Some(newest_trimmed) = overflow.first() {
+                let newest_trimmed_id: i64 = sqlx::Row::get(newest_trimmed, "id");
+                let trimmed: std::vec::Vec<(String, String)> = overflow
+                    .iter()
+                    .rev()
+                    .map(|row| (sqlx::Row::get(row, "role"), sqlx::Row::get(row, "content")))
+                    .collect();
+
+                let previous: std::option::Option<String> = sqlx::query_scalar(
+                    "SELECT summary FROM conversation_summaries WHERE session_id = ?"
+                )
+                .bind(&turn.session_id)
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(|e| std::format!("Failed to load conversation summary: {:?}", e))?;
+
+                sqlx::query(
+                    "INSERT INTO conversation_summaries (session_id, summary, updated_at) VALUES (?, ?, ?)
+                     ON CONFLICT(session_id) DO UPDATE SET summary = excluded.summary, updated_at = excluded.updated_at"
+                )
+                .bind(&turn.session_id)
+                .bind(Self::fold_into_summary(previous, &trimmed))
+                .bind(chrono::Utc::now().to_rfc3339())
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| std::format!("Failed to save conversation summary: {:?}", e))?;
+
+                sqlx::query("DELETE FROM conversation_turns WHERE session_id = ? AND id <= ?")
+                    .bind(&turn.session_id)
+                    .bind(newest_trimmed_id)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| std::format!("Failed to trim conversation turns: {:?}", e))?;
+            }
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| std::format!("Failed to commit conversation turn: {:?}", e))?;
+
+        std::result::Result::Ok(())
+    }
+
+    async fn load_history(
+        &self,
+        session_id: &str,
+    ) -> std::result::Result<crate::ports::conversation_store_port::ConversationHistory, String> {
+        let summary: std::option::Option<String> = sqlx::query_scalar(
+            "SELECT summary FROM conversation_summaries WHERE session_id = ?"
+        )
+        .bind(session_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| std::format!("Failed to load conversation summary: {:?}", e))?;
+
+        let rows = sqlx::query(
+            "SELECT session_id, role, content, tool_calls_json, created_at
+             FROM conversation_turns WHERE session_id = ? ORDER BY id ASC"
+        )
+        .bind(session_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| std::format!("Failed to load conversation turns: {:?}", e))?;
+
+        let turns: std::result::Result<std::vec::Vec<_>, _> = rows
+            .into_iter()
+            .map(Self::row_to_turn)
+            .collect();
+
+        std::result::Result::Ok(crate::ports::conversation_store_port::ConversationHistory {
+            summary,
+            turns: turns?,
+        })
+    }
+
+    async fn clear_session(&self, session_id: &str) -> std::result::Result<(), String> {
+        sqlx::query("DELETE FROM conversation_turns WHERE session_id = ?")
+            .bind(session_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| std::format!("Failed to clear conversation turns: {:?}", e))?;
+
+        sqlx::query("DELETE FROM conversation_summaries WHERE session_id = ?")
+            .bind(session_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| std::format!("Failed to clear conversation summary: {:?}", e))?;
+
+        std::result::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::conversation_store_port::{ConversationStorePort, ConversationTurn, RecordedToolCall};
+    use crate::ports::llm_agent_port::AgentRole;
+
+    async fn create_test_store(max_turns: usize) -> SqliteConversationAdapter {
+        // One connection, so every query sees the same in-memory database
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        let store = SqliteConversationAdapter::new(pool).with_max_turns(max_turns);
+        store.init_schema().await.unwrap();
+        store
+    }
+
+    #[tokio::test]
+    async fn test_turns_are_saved_and_reloaded_in_order() {
+        // Test: Validates turns, including tool calls, reload oldest first and only for their own session.
+        // Justification: The TUI rebuilds the chat from this history, so order and session isolation must hold.
+        let store = create_test_store(DEFAULT_MAX_TURNS).await;
+
+        let mut answer = ConversationTurn::new("project-1", AgentRole::Assistant, "Two tasks are blocked.");
+        answer.tool_calls.push(RecordedToolCall {
+            tool_name: String::from("search_tasks"),
+            args_json: String::from("{\"status\":\"Blocked\"}"),
+            result: std::option::Option::Some(String::from("[\"t1\",\"t2\"]")),
+        });
+
+        store.append_turn(ConversationTurn::new("project-1", AgentRole::User, "What is blocked?")).await.unwrap();
+        store.append_turn(ConversationTurn::new("project-2", AgentRole::User, "Other project")).await.unwrap();
+        store.append_turn(answer.clone()).await.unwrap();
+        store.append_turn(ConversationTurn::new("project-1", AgentRole::User, "Thanks")).await.unwrap();
+
+        let history = store.load_history("project-1").await.unwrap();
+        let contents: std::vec::Vec<&str> = history.turns.iter().map(|t| t.content.as_str()).collect();
+
+        std::assert_eq!(contents, std::vec!["What is blocked?", "Two tasks are blocked.", "Thanks"]);
+        std::assert_eq!(history.turns[1].role, AgentRole::Assistant);
+        std::assert_eq!(history.turns[1].tool_calls, answer.tool_calls);
+        std::assert!(history.summary.is_none());
+
+        store.clear_session("project-1").await.unwrap();
+        std::assert!(store.load_history("project-1").await.unwrap().turns.is_empty());
+        std::assert_eq!(store.load_history("project-2").await.unwrap().turns.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_trimming_keeps_recent_turns_and_summary() {
+        // Test: Validates exceeding the window keeps only the newest N turns and summarizes the dropped ones.
+        // Justification: Long chats must stay bounded without the agent losing what was discussed earlier.
+        let store = create_test_store(3).await;
+
+        for i in 1..=6 {
+            let role = if i % 2 == 1 { AgentRole::User } else { AgentRole::Assistant };
+            store.append_turn(ConversationTurn::new("project-1", role, std::format!("message {}", i))).await.unwrap();
+        }
+
+        let history = store.load_history("project-1").await.unwrap();
+        let contents: std::vec::Vec<&str> = history.turns.iter().map(|t| t.content.as_str()).collect();
+        std::assert_eq!(contents, std::vec!["message 4", "message 5", "message 6"]);
+
+        let summary = history.summary.unwrap();
+        std::assert_eq!(summary, "User: message 1\nAssistant: message 2\nUser: message 3");
+    }
+}
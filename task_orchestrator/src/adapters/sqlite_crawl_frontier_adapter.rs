@@ -0,0 +1,325 @@
+//! SQLite-backed crawl frontier adapter.
+//!
+//! Persists each crawl's queue in `crawl_frontier_queue` and its visited set
+//! in `crawl_frontier_visited`, keyed by crawl key. Fetched pages are stored
+//! as JSON on their visited row so a resumed crawl can return them without
+//! refetching. Each visit is recorded in a single transaction, so a crash
+//! leaves the frontier either before or after the visit, never in between.
+//!
+//! Schema is created and upgraded by versioned migrations in `connect_and_init()`.
+//!
+//! Revision History
+//! - 2026-10-17T13:10:00Z @AI: Create SQLite crawl frontier store for resumable crawls.
+
+/// SQLite-backed implementation of CrawlFrontierPort.
+///
+/// # Examples
+///
+/// ```no_run
+/// use task_orchestrator::adapters::sqlite_crawl_frontier_adapter::SqliteCrawlFrontierAdapter;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let store = SqliteCrawlFrontierAdapter::connect_and_init("sqlite:.rigger/tasks.db")
+///         .await
+///         .unwrap();
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SqliteCrawlFrontierAdapter {
+    pool: sqlx::Pool<sqlx::Sqlite>,
+}
+
+impl SqliteCrawlFrontierAdapter {
+    /// Scope under which this adapter's schema versions are recorded in `schema_migrations`.
+    const MIGRATION_SCOPE: &'static str = "crawl_frontier";
+
+    /// Schema history of the frontier tables. Append new migrations; never edit shipped ones.
+    const MIGRATIONS: &'static [task_manager::infrastructure::sqlite_migrations::migration::Migration] = &[task_manager::infrastructure::sqlite_migrations::migration::Migration {
+        version: 1,
+        description: "Create crawl frontier tables",
+        steps: &[
+            task_manager::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute(
+                "CREATE TABLE IF NOT EXISTS crawl_frontier_queue (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    crawl_key TEXT NOT NULL,
+                    url TEXT NOT NULL,
+                    depth INTEGER NOT NULL,
+                    UNIQUE(crawl_key, url)
+                )",
+            ),
+            task_manager::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute(
+                "CREATE TABLE IF NOT EXISTS crawl_frontier_visited (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    crawl_key TEXT NOT NULL,
+                    url TEXT NOT NULL,
+                    page_json TEXT,
+                    UNIQUE(crawl_key, url)
+                )",
+            ),
+        ],
+    }];
+
+    /// Creates a new adapter from an existing SQLite pool.
+    ///
+    /// Call `init_schema` before use unless the tables already exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - SQLite connection pool
+    pub fn new(pool: sqlx::Pool<sqlx::Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    /// Connects to a SQLite database and initializes the schema if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `database_url` - SQLite database URL (e.g., "sqlite:.rigger/tasks.db")
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection or schema creation fails.
+    pub async fn connect_and_init(database_url: &str) -> std::result::Result<Self, String> {
+        let pool = task_manager::infrastructure::sqlite_pool::connector::connect(
+            database_url,
+            &task_manager::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings::default(),
+            std::option::Option::None,
+        )
+        .await?;
+
+        let adapter = Self::new(pool);
+        adapter.init_schema().await?;
+        std::result::Result::Ok(adapter)
+    }
+
+    /// Applies the pending schema migrations. Idempotent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a statement fails.
+    pub async fn init_schema(&self) -> std::result::Result<(), String> {
+        task_manager::infrastructure::sqlite_migrations::migrator::run_pending(&self.pool, Self::MIGRATION_SCOPE, Self::MIGRATIONS)
+            .await
+            .map(|_| ())
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::ports::crawl_frontier_port::CrawlFrontierPort for SqliteCrawlFrontierAdapter {
+    async fn load(
+        &self,
+        crawl_key: &str,
+    ) -> std::result::Result<std::option::Option<crate::ports::crawl_frontier_port::CrawlFrontier>, String> {
+        let queue_rows = sqlx::query("SELECT url, depth FROM crawl_frontier_queue WHERE crawl_key = ? ORDER BY id ASC")
+            .bind(crawl_key)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| std::format!("Failed to load crawl queue: {:?}", e))?;
+
+        let visited_rows = sqlx::query("SELECT url, page_json FROM crawl_frontier_visited WHERE crawl_key = ? ORDER BY id ASC")
+            .bind(crawl_key)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| std::format!("Failed to load visited URLs: {:?}", e))?;
+
+        let mut frontier = crate::ports::crawl_frontier_port::CrawlFrontier::default();
+        for row in queue_rows {
+            let depth: i64 = sqlx::Row::get(&row, "depth");
+            frontier.queued.push((sqlx::Row::get(&row, "url"), depth as usize));
+        }
+        for row in visited_rows {
+            frontier.visited.push(sqlx::Row::get(&row, "url"));
+            let page_json: std::option::Option<String> = sqlx::Row::get(&row, "page_json");
+            if let std::option::Option::Some(json) = page_json {
+                let page = serde_json::from_str(&json)
+                    .map_err(|e| std::format!("Invalid saved page JSON: {:?}", e))?;
+                frontier.pages.push(page);
+            }
+        }
+
+        if frontier.is_empty() {
+            return std::result::Result::Ok(std::option::Option::None);
+        }
+        std::result::Result::Ok(std::option::Option::Some(frontier))
+    }
+
+    async fn reset(&self, crawl_key: &str, start_url: &str) -> std::result::Result<(), String> {
+        let mut tx = self.pool.begin()
+            .await
+            .map_err(|e| std::format!("Failed to begin transaction: {:?}", e))?;
+
+        sqlx::query("DELETE FROM crawl_frontier_queue WHERE crawl_key = ?")
+            .bind(crawl_key)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| std::format!("Failed to clear crawl queue: {:?}", e))?;
+
+        sqlx::query("DELETE FROM crawl_frontier_visited WHERE crawl_key = ?")
+            .bind(crawl_key)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| std::format!("Failed to clear visited URLs: {:?}", e))?;
+
+        sqlx::query("INSERT INTO crawl_frontier_queue (crawl_key, url, depth) VALUES (?, ?, 0)")
+            .bind(crawl_key)
+            .bind(start_url)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| std::format!("Failed to queue start URL: {:?}", e))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| std::format!("Failed to commit crawl reset: {:?}", e))
+    }
+
+    async fn record_visit(
+        &self,
+        crawl_key: &str,
+        url: &str,
+        normalized_url: &str,
+        page: std::option::Option<&crate::domain::crawl_result::CrawledPage>,
+        links: &[(String, usize)],
+    ) -> std::result::Result<(), String> {
+        let page_json = page
+            .map(serde_json::to_string)
+            .transpose()
+            .map_err(|e| std::format!("Failed to serialize crawled page: {:?}", e))?;
+
+        let mut tx = self.pool.begin()
+            .await
+            .map_err(|e| std::format!("Failed to begin transaction: {:?}", e))?;
+
+        sqlx::query("DELETE FROM crawl_frontier_queue WHERE crawl_key = ? AND url IN (?, ?)")
+            .bind(crawl_key)
+            .bind(url)
+            .bind(normalized_url)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| std::format!("Failed to dequeue URL: {:?}", e))?;
+
+        sqlx::query("INSERT OR REPLACE INTO crawl_frontier_visited (crawl_key, url, page_json) VALUES (?, ?, ?)")
+            .bind(crawl_key)
+            .bind(normalized_url)
+            .bind(page_json)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| std::format!("Failed to record visited URL: {:?}", e))?;
+
+        for (link, depth) in links {
+            sqlx::query(
+                "INSERT OR IGNORE INTO crawl_frontier_queue (crawl_key, url, depth)
+                 SELECT ?, ?, ? WHERE NOT EXISTS (
+                     SELECT 1 FROM crawl_frontier_visited WHERE crawl_key = ? AND url = ?
+                 )"
+            )
+            .bind(crawl_key)
+            .bind(link)
+            .bind(*depth as i64)
+            .bind(crawl_key)
+            .bind(link)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| std::format!("Failed to queue link: {:?}", e))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| std::format!("Failed to commit crawl visit: {:?}", e))
+    }
+
+    async fn clear(&self, crawl_key: &str) -> std::result::Result<(), String> {
+        sqlx::query("DELETE FROM crawl_frontier_queue WHERE crawl_key = ?")
+            .bind(crawl_key)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| std::format!("Failed to clear crawl queue: {:?}", e))?;
+
+        sqlx::query("DELETE FROM crawl_frontier_visited WHERE crawl_key = ?")
+            .bind(crawl_key)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| std::format!("Failed to clear visited URLs: {:?}", e))?;
+
+        std::result::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::crawl_frontier_port::CrawlFrontierPort;
+
+    async fn create_test_store() -> SqliteCrawlFrontierAdapter {
+        // One connection, so every query sees the same in-memory database
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        let store = SqliteCrawlFrontierAdapter::new(pool);
+        store.init_schema().await.unwrap();
+        store
+    }
+
+    fn page(url: &str) -> crate::domain::crawl_result::CrawledPage {
+        crate::domain::crawl_result::CrawledPage {
+            url: String::from(url),
+            title: String::from("Title"),
+            content: std::format!("Content of {}", url),
+            links: std::vec::Vec::new(),
+            depth: 0,
+            status_code: 200,
+            content_type: String::from("text/html"),
+            raw_body: std::option::Option::None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_visits_move_urls_from_queue_to_visited_with_pages() {
+        // Test: Validates a recorded visit dequeues the URL, saves its page, and queues only unvisited links.
+        // Justification: Resume relies on the saved queue and pages matching exactly what was crawled.
+        let store = create_test_store().await;
+        let key = "https://example.com";
+        std::assert!(store.load(key).await.unwrap().is_none());
+
+        store.reset(key, key).await.unwrap();
+        let links = std::vec![
+            (String::from("https://example.com/a"), 1),
+            (String::from("https://example.com/b"), 1),
+            (String::from("https://example.com"), 1),
+        ];
+        store.record_visit(key, key, key, std::option::Option::Some(&page(key)), &links).await.unwrap();
+        store
+            .record_visit(key, "https://example.com/a", "https://example.com/a", std::option::Option::None, &[(String::from("https://example.com/b"), 2)])
+            .await
+            .unwrap();
+
+        let frontier = store.load(key).await.unwrap().unwrap();
+        std::assert_eq!(frontier.queued, std::vec![(String::from("https://example.com/b"), 1)]);
+        std::assert_eq!(frontier.visited, std::vec![String::from("https://example.com"), String::from("https://example.com/a")]);
+        std::assert_eq!(frontier.pages.len(), 1);
+        std::assert_eq!(frontier.pages[0].content, "Content of https://example.com");
+        std::assert!(store.load("https://other.example").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_reset_and_clear_discard_progress() {
+        // Test: Validates reset restarts from the start URL and clear removes the frontier.
+        // Justification: A fresh crawl must not inherit a stale frontier.
+        let store = create_test_store().await;
+        let key = "https://example.com";
+
+        store.reset(key, key).await.unwrap();
+        store.record_visit(key, key, key, std::option::Option::Some(&page(key)), &[(String::from("https://example.com/a"), 1)]).await.unwrap();
+        store.reset(key, key).await.unwrap();
+
+        let frontier = store.load(key).await.unwrap().unwrap();
+        std::assert_eq!(frontier.queued, std::vec![(String::from(key), 0)]);
+        std::assert!(frontier.visited.is_empty());
+        std::assert!(frontier.pages.is_empty());
+
+        store.clear(key).await.unwrap();
+        std::assert!(store.load(key).await.unwrap().is_none());
+    }
+}
@@ -4,9 +4,13 @@
 //! implements MetricsCollectorPort for durable storage of benchmark data across
 //! sessions. JSON columns store optional role and error fields.
 //!
-//! Schema is created automatically via `connect_and_init()` if it doesn't exist.
+//! Schema is created and upgraded by versioned migrations in `connect_and_init()`.
+//! Retry counters live in a `retry_counters` table with one row per provider.
 //!
 //! Revision History
+//! - 2026-10-17T05:50:00Z @AI: Add connect_and_init_with; pools now come from task_manager's shared connector.
+//! - 2026-10-17T05:30:00Z @AI: Create the schema through versioned migrations.
+//! - 2026-10-17T02:10:00Z @AI: Persist per-provider retry counters in a retry_counters table.
 //! - 2025-11-24T01:30:00Z @AI: Create SQLite metrics collector for Phase 5 Sprint 12 Task 5.12.
 
 /// SQLite-backed implementation of MetricsCollectorPort.
@@ -34,6 +38,51 @@ pub struct SqliteMetricsCollector {
 }
 
 impl SqliteMetricsCollector {
+    /// Scope under which this adapter's schema versions are recorded in `schema_migrations`.
+    const MIGRATION_SCOPE: &'static str = "metrics";
+
+    /// Schema history of the metrics tables. Append new migrations; never edit shipped ones.
+    const MIGRATIONS: &'static [task_manager::infrastructure::sqlite_migrations::migration::Migration] = &[
+        task_manager::infrastructure::sqlite_migrations::migration::Migration {
+            version: 1,
+            description: "Create performance_metrics table",
+            steps: &[
+                task_manager::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute(
+                    "CREATE TABLE IF NOT EXISTS performance_metrics (
+                        id TEXT PRIMARY KEY,
+                        timestamp TEXT NOT NULL,
+                        operation_type TEXT NOT NULL,
+                        provider TEXT NOT NULL,
+                        model TEXT NOT NULL,
+                        role TEXT NULL,
+                        duration_ms INTEGER NULL,
+                        input_tokens INTEGER NULL,
+                        output_tokens INTEGER NULL,
+                        tokens_per_second REAL NULL,
+                        success INTEGER NOT NULL,
+                        error TEXT NULL
+                    )",
+                ),
+                // Indices for common queries
+                task_manager::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute("CREATE INDEX IF NOT EXISTS idx_provider ON performance_metrics(provider)"),
+                task_manager::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute("CREATE INDEX IF NOT EXISTS idx_operation_type ON performance_metrics(operation_type)"),
+                task_manager::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute("CREATE INDEX IF NOT EXISTS idx_timestamp ON performance_metrics(timestamp)"),
+            ],
+        },
+        task_manager::infrastructure::sqlite_migrations::migration::Migration {
+            version: 2,
+            description: "Create retry_counters table",
+            steps: &[task_manager::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute(
+                "CREATE TABLE IF NOT EXISTS retry_counters (
+                    provider TEXT PRIMARY KEY,
+                    attempts INTEGER NOT NULL DEFAULT 0,
+                    successes_after_retry INTEGER NOT NULL DEFAULT 0,
+                    exhausted_retries INTEGER NOT NULL DEFAULT 0
+                )",
+            )],
+        },
+    ];
+
     /// Creates a new adapter from an existing SQLite pool.
     ///
     /// # Arguments
@@ -85,52 +134,38 @@ impl SqliteMetricsCollector {
     /// }
     /// ```
     pub async fn connect_and_init(database_url: &str) -> std::result::Result<Self, String> {
-        let pool = sqlx::sqlite::SqlitePoolOptions::new()
-            .max_connections(5)
-            .connect(database_url)
-            .await
-            .map_err(|e| std::format!("Failed to connect to SQLite: {:?}", e))?;
-
-        // Create schema if not exists
-        sqlx::query(
-            "CREATE TABLE IF NOT EXISTS performance_metrics (
-                id TEXT PRIMARY KEY,
-                timestamp TEXT NOT NULL,
-                operation_type TEXT NOT NULL,
-                provider TEXT NOT NULL,
-                model TEXT NOT NULL,
-                role TEXT NULL,
-                duration_ms INTEGER NULL,
-                input_tokens INTEGER NULL,
-                output_tokens INTEGER NULL,
-                tokens_per_second REAL NULL,
-                success INTEGER NOT NULL,
-                error TEXT NULL
-            )"
+        Self::connect_and_init_with(
+            database_url,
+            &task_manager::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings::default(),
         )
-        .execute(&pool)
         .await
-        .map_err(|e| std::format!("Failed to create schema: {:?}", e))?;
-
-        // Create indices for common queries
-        sqlx::query("CREATE INDEX IF NOT EXISTS idx_provider ON performance_metrics(provider)")
-            .execute(&pool)
-            .await
-            .map_err(|e| std::format!("Failed to create provider index: {:?}", e))?;
+    }
 
-        sqlx::query("CREATE INDEX IF NOT EXISTS idx_operation_type ON performance_metrics(operation_type)")
-            .execute(&pool)
-            .await
-            .map_err(|e| std::format!("Failed to create operation_type index: {:?}", e))?;
+    /// Connects with a pool sized by `settings` (WAL mode and busy timeout included) and runs migrations.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection or a migration fails.
+    pub async fn connect_and_init_with(
+        database_url: &str,
+        settings: &task_manager::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings,
+    ) -> std::result::Result<Self, String> {
+        let pool = task_manager::infrastructure::sqlite_pool::connector::connect(database_url, settings, std::option::Option::None).await?;
 
-        sqlx::query("CREATE INDEX IF NOT EXISTS idx_timestamp ON performance_metrics(timestamp)")
-            .execute(&pool)
-            .await
-            .map_err(|e| std::format!("Failed to create timestamp index: {:?}", e))?;
+        Self::migrate(&pool).await?;
 
         std::result::Result::Ok(Self { pool })
     }
 
+    /// Applies the pending schema migrations of this adapter.
+    ///
+    /// # Returns
+    ///
+    /// The versions applied; empty when the schema was already current.
+    pub async fn migrate(pool: &sqlx::Pool<sqlx::Sqlite>) -> std::result::Result<std::vec::Vec<i64>, String> {
+        task_manager::infrastructure::sqlite_migrations::migrator::run_pending(pool, Self::MIGRATION_SCOPE, Self::MIGRATIONS).await
+    }
+
     /// Converts a database row to InferenceMetrics entity.
     fn row_to_metrics(row: sqlx::sqlite::SqliteRow) -> std::result::Result<crate::domain::performance_metrics::InferenceMetrics, String> {
         let id: String = sqlx::Row::get(&row, "id");
@@ -290,12 +325,75 @@ impl crate::ports::metrics_collector_port::MetricsCollectorPort for SqliteMetric
         metrics
     }
 
+    async fn record_retry(
+        &self,
+        provider: &str,
+        event: crate::domain::performance_metrics::RetryEvent,
+    ) -> std::result::Result<(), String> {
+        let query = match event {
+            crate::domain::performance_metrics::RetryEvent::Attempt => {
+                "INSERT INTO retry_counters (provider, attempts) VALUES (?, 1)
+                 ON CONFLICT(provider) DO UPDATE SET attempts = attempts + 1"
+            }
+            crate::domain::performance_metrics::RetryEvent::SucceededAfterRetry => {
+                "INSERT INTO retry_counters (provider, successes_after_retry) VALUES (?, 1)
+                 ON CONFLICT(provider) DO UPDATE SET successes_after_retry = successes_after_retry + 1"
+            }
+            crate::domain::performance_metrics::RetryEvent::Exhausted => {
+                "INSERT INTO retry_counters (provider, exhausted_retries) VALUES (?, 1)
+                 ON CONFLICT(provider) DO UPDATE SET exhausted_retries = exhausted_retries + 1"
+            }
+        };
+
+        sqlx::query(query)
+            .bind(provider)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| std::format!("Failed to record retry: {:?}", e))?;
+
+        std::result::Result::Ok(())
+    }
+
+    async fn get_retry_counters(
+        &self,
+    ) -> std::result::Result<std::collections::BTreeMap<String, crate::domain::performance_metrics::RetryCounters>, String> {
+        let rows = sqlx::query("SELECT provider, attempts, successes_after_retry, exhausted_retries FROM retry_counters")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| std::format!("Failed to fetch retry counters: {:?}", e))?;
+
+        let counters = rows
+            .into_iter()
+            .map(|row| {
+                let provider: String = sqlx::Row::get(&row, "provider");
+                let attempts: i64 = sqlx::Row::get(&row, "attempts");
+                let successes_after_retry: i64 = sqlx::Row::get(&row, "successes_after_retry");
+                let exhausted_retries: i64 = sqlx::Row::get(&row, "exhausted_retries");
+                (
+                    provider,
+                    crate::domain::performance_metrics::RetryCounters {
+                        attempts: attempts as u64,
+                        successes_after_retry: successes_after_retry as u64,
+                        exhausted_retries: exhausted_retries as u64,
+                    },
+                )
+            })
+            .collect();
+
+        std::result::Result::Ok(counters)
+    }
+
     async fn clear_metrics(&self) -> std::result::Result<(), String> {
         sqlx::query("DELETE FROM performance_metrics")
             .execute(&self.pool)
             .await
             .map_err(|e| std::format!("Failed to clear metrics: {:?}", e))?;
 
+        sqlx::query("DELETE FROM retry_counters")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| std::format!("Failed to clear retry counters: {:?}", e))?;
+
         std::result::Result::Ok(())
     }
 }
@@ -530,4 +628,32 @@ mod tests {
         std::assert!(!all_metrics[0].success);
         std::assert_eq!(all_metrics[0].error, std::option::Option::Some("Connection timeout".to_string()));
     }
+
+    #[tokio::test]
+    async fn test_retry_counters_persist_per_provider() {
+        // Test: Validates retry events increment the right provider's counters and are cleared with the metrics.
+        // Justification: `rig metrics` reads these counters to flag flaky providers.
+        let collector = create_test_collector().await;
+        let events = [
+            ("mlx", crate::domain::performance_metrics::RetryEvent::Attempt),
+            ("mlx", crate::domain::performance_metrics::RetryEvent::SucceededAfterRetry),
+            ("mlx", crate::domain::performance_metrics::RetryEvent::Attempt),
+            ("mlx", crate::domain::performance_metrics::RetryEvent::Attempt),
+            ("mlx", crate::domain::performance_metrics::RetryEvent::Exhausted),
+            ("ollama", crate::domain::performance_metrics::RetryEvent::Attempt),
+        ];
+        for (provider, event) in events {
+            collector.record_retry(provider, event).await.unwrap();
+        }
+
+        let counters = collector.get_retry_counters().await.unwrap();
+        std::assert_eq!(
+            counters["mlx"],
+            crate::domain::performance_metrics::RetryCounters { attempts: 3, successes_after_retry: 1, exhausted_retries: 1 }
+        );
+        std::assert_eq!(counters["ollama"].attempts, 1);
+
+        collector.clear_metrics().await.unwrap();
+        std::assert!(collector.get_retry_counters().await.unwrap().is_empty());
+    }
 }
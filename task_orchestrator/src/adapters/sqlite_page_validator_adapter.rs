@@ -0,0 +1,181 @@
+//! SQLite-backed page validator adapter.
+//!
+//! Stores the ETag, Last-Modified, and links of each crawled page in a
+//! `crawl_page_validators` table keyed by normalized URL, for conditional
+//! requests on the next crawl.
+//!
+//! Schema is created and upgraded by versioned migrations in `connect_and_init()`.
+//!
+//! Revision History
+//! - 2026-10-17T13:30:00Z @AI: Create SQLite page validator store for conditional re-crawls.
+
+/// SQLite-backed implementation of PageValidatorPort.
+///
+/// # Examples
+///
+/// ```no_run
+/// use task_orchestrator::adapters::sqlite_page_validator_adapter::SqlitePageValidatorAdapter;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let store = SqlitePageValidatorAdapter::connect_and_init("sqlite:.rigger/tasks.db")
+///         .await
+///         .unwrap();
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SqlitePageValidatorAdapter {
+    pool: sqlx::Pool<sqlx::Sqlite>,
+}
+
+impl SqlitePageValidatorAdapter {
+    /// Scope under which this adapter's schema versions are recorded in `schema_migrations`.
+    const MIGRATION_SCOPE: &'static str = "page_validators";
+
+    /// Schema history of the validator table. Append new migrations; never edit shipped ones.
+    const MIGRATIONS: &'static [task_manager::infrastructure::sqlite_migrations::migration::Migration] = &[task_manager::infrastructure::sqlite_migrations::migration::Migration {
+        version: 1,
+        description: "Create crawl page validators table",
+        steps: &[task_manager::infrastructure::sqlite_migrations::migration_step::MigrationStep::Execute(
+            "CREATE TABLE IF NOT EXISTS crawl_page_validators (
+                url TEXT PRIMARY KEY,
+                etag TEXT,
+                last_modified TEXT,
+                links_json TEXT NOT NULL DEFAULT '[]',
+                updated_at TEXT NOT NULL
+            )",
+        )],
+    }];
+
+    /// Creates a new adapter from an existing SQLite pool.
+    ///
+    /// Call `init_schema` before use unless the table already exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - SQLite connection pool
+    pub fn new(pool: sqlx::Pool<sqlx::Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    /// Connects to a SQLite database and initializes the schema if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `database_url` - SQLite database URL (e.g., "sqlite:.rigger/tasks.db")
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection or schema creation fails.
+    pub async fn connect_and_init(database_url: &str) -> std::result::Result<Self, String> {
+        let pool = task_manager::infrastructure::sqlite_pool::connector::connect(
+            database_url,
+            &task_manager::infrastructure::sqlite_pool::sqlite_pool_settings::SqlitePoolSettings::default(),
+            std::option::Option::None,
+        )
+        .await?;
+
+        let adapter = Self::new(pool);
+        adapter.init_schema().await?;
+        std::result::Result::Ok(adapter)
+    }
+
+    /// Applies the pending schema migrations. Idempotent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a statement fails.
+    pub async fn init_schema(&self) -> std::result::Result<(), String> {
+        task_manager::infrastructure::sqlite_migrations::migrator::run_pending(&self.pool, Self::MIGRATION_SCOPE, Self::MIGRATIONS)
+            .await
+            .map(|_| ())
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::ports::page_validator_port::PageValidatorPort for SqlitePageValidatorAdapter {
+    async fn get(
+        &self,
+        url: &str,
+    ) -> std::result::Result<std::option::Option<crate::domain::crawl_result::PageValidators>, String> {
+        let row = sqlx::query("SELECT etag, last_modified, links_json FROM crawl_page_validators WHERE url = ?")
+            .bind(url)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| std::format!("Failed to load page validators: {:?}", e))?;
+
+        let std::option::Option::Some(row) = row else {
+            return std::result::Result::Ok(std::option::Option::None);
+        };
+        let links_json: String = sqlx::Row::get(&row, "links_json");
+        std::result::Result::Ok(std::option::Option::Some(crate::domain::crawl_result::PageValidators {
+            etag: sqlx::Row::get(&row, "etag"),
+            last_modified: sqlx::Row::get(&row, "last_modified"),
+            links: serde_json::from_str(&links_json)
+                .map_err(|e| std::format!("Invalid saved links JSON: {:?}", e))?,
+        }))
+    }
+
+    async fn put(
+        &self,
+        url: &str,
+        validators: &crate::domain::crawl_result::PageValidators,
+    ) -> std::result::Result<(), String> {
+        let links_json = serde_json::to_string(&validators.links)
+            .map_err(|e| std::format!("Failed to serialize links: {:?}", e))?;
+
+        sqlx::query(
+            "INSERT INTO crawl_page_validators (url, etag, last_modified, links_json, updated_at) VALUES (?, ?, ?, ?, ?)
+             ON CONFLICT(url) DO UPDATE SET etag = excluded.etag, last_modified = excluded.last_modified,
+                 links_json = excluded.links_json, updated_at = excluded.updated_at"
+        )
+        .bind(url)
+        .bind(&validators.etag)
+        .bind(&validators.last_modified)
+        .bind(&links_json)
+        .bind(chrono::Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| std::format!("Failed to save page validators: {:?}", e))?;
+
+        std::result::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::page_validator_port::PageValidatorPort;
+
+    #[tokio::test]
+    async fn test_validators_round_trip_and_replace() {
+        // Test: Validates saved validators reload intact and a later put replaces them.
+        // Justification: Stale validators would make re-crawls skip pages that changed.
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        let store = SqlitePageValidatorAdapter::new(pool);
+        store.init_schema().await.unwrap();
+
+        let url = "https://example.com/docs";
+        std::assert!(store.get(url).await.unwrap().is_none());
+
+        let first = crate::domain::crawl_result::PageValidators {
+            etag: std::option::Option::Some(String::from("\"v1\"")),
+            last_modified: std::option::Option::Some(String::from("Wed, 21 Oct 2026 07:28:00 GMT")),
+            links: std::vec![String::from("https://example.com/docs/a")],
+        };
+        store.put(url, &first).await.unwrap();
+        std::assert_eq!(store.get(url).await.unwrap(), std::option::Option::Some(first));
+
+        let second = crate::domain::crawl_result::PageValidators {
+            etag: std::option::Option::Some(String::from("\"v2\"")),
+            last_modified: std::option::Option::None,
+            links: std::vec::Vec::new(),
+        };
+        store.put(url, &second).await.unwrap();
+        std::assert_eq!(store.get(url).await.unwrap(), std::option::Option::Some(second));
+    }
+}
@@ -5,7 +5,15 @@
 //! tooling. It complements HEXSER by surfacing where HexEntity/HexAdapter are
 //! applied and which boundaries exist.
 //!
+//! `describe_architecture_as` renders the same ports, adapters, and graph
+//! nodes as structured JSON or as a Graphviz DOT digraph, for example
+//! `rig architecture --format dot | dot -Tsvg > architecture.svg`.
+//!
 //! Revision History
+//! - 2026-10-17T08:30:00Z @AI: Add JsonlMetricsCollector.
+//! - 2026-10-17T08:10:00Z @AI: Add RecordingAdapter and ReplayAdapter to the provider ports.
+//! - 2026-10-17T00:30:00Z @AI: Add ConversationStorePort with SqliteConversationAdapter.
+//! - 2026-10-16T21:50:00Z @AI: Add ArchitectureDescription with JSON and Graphviz DOT output via describe_architecture_as.
 //! - 2025-11-13T21:46:00Z @AI: Add Orchestrator facade to description and extend unit test.
 //! - 2025-11-13T21:06:00Z @AI: Update description to reflect unified graph_flow and add run_task_with_flow.
 //! - 2025-11-12T17:20:00Z @AI: Introduce describe_architecture() with unit tests.
@@ -62,6 +70,259 @@ pub fn describe_architecture() -> String {
     s
 }
 
+/// Output format of `describe_architecture_as`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchitectureFormat {
+    /// The human-readable summary from `describe_architecture`
+    Text,
+
+    /// `ArchitectureDescription` as pretty-printed JSON
+    Json,
+
+    /// Graphviz DOT digraph
+    Dot,
+}
+
+impl std::str::FromStr for ArchitectureFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "text" => std::result::Result::Ok(ArchitectureFormat::Text),
+            "json" => std::result::Result::Ok(ArchitectureFormat::Json),
+            "dot" => std::result::Result::Ok(ArchitectureFormat::Dot),
+            other => std::result::Result::Err(std::format!(
+                "Invalid format: '{}'. Valid values: text, json, dot",
+                other
+            )),
+        }
+    }
+}
+
+/// A port (async trait) and the adapters implementing it.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct PortDescription {
+    /// Trait name, e.g. "TaskEnhancementPort"
+    pub name: String,
+
+    /// Module under `crate::ports`
+    pub module: String,
+
+    /// Names of the adapter types implementing the port
+    pub adapters: std::vec::Vec<String>,
+}
+
+/// A node of the orchestrator flow built by `assemble_orchestrator_flow`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct GraphNodeDescription {
+    /// Short node name used by edges, e.g. "enhance"
+    pub id: String,
+
+    /// graph_flow task type running the node
+    pub task: String,
+
+    /// Port the node calls, if any
+    pub port: std::option::Option<String>,
+}
+
+/// A transition between two flow nodes.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct GraphEdgeDescription {
+    /// Source node id
+    pub from: String,
+
+    /// Target node id
+    pub to: String,
+
+    /// `routing_decision` value selecting this edge, for conditional edges
+    pub condition: std::option::Option<String>,
+}
+
+/// Structured view of the orchestrator's ports, adapters, and flow graph.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ArchitectureDescription {
+    /// Ports with their implementing adapters
+    pub ports: std::vec::Vec<PortDescription>,
+
+    /// Flow nodes
+    pub nodes: std::vec::Vec<GraphNodeDescription>,
+
+    /// Flow edges
+    pub edges: std::vec::Vec<GraphEdgeDescription>,
+}
+
+/// Returns the orchestrator's ports, adapters, and flow graph.
+///
+/// # Examples
+///
+/// ```
+/// let description = task_orchestrator::architecture::describe_architecture::architecture_description();
+/// let enhancement = description.ports.iter().find(|port| port.name == "TaskEnhancementPort").unwrap();
+/// assert!(enhancement.adapters.contains(&"OllamaEnhancementAdapter".to_string()));
+/// ```
+pub fn architecture_description() -> ArchitectureDescription {
+    let port = |name: &str, module: &str, adapters: &[&str]| PortDescription {
+        name: String::from(name),
+        module: String::from(module),
+        adapters: adapters.iter().map(|adapter| String::from(*adapter)).collect(),
+    };
+    let node = |id: &str, task: &str, port: std::option::Option<&str>| GraphNodeDescription {
+        id: String::from(id),
+        task: String::from(task),
+        port: port.map(String::from),
+    };
+    let edge = |from: &str, to: &str, condition: std::option::Option<&str>| GraphEdgeDescription {
+        from: String::from(from),
+        to: String::from(to),
+        condition: condition.map(String::from),
+    };
+
+    ArchitectureDescription {
+        ports: std::vec![
+            port(
+                "TaskEnhancementPort",
+                "task_enhancement_port",
+                &["OllamaEnhancementAdapter", "MlxSubprocessAdapter", "BudgetGuardAdapter", "RecordingAdapter", "ReplayAdapter"],
+            ),
+            port(
+                "ComprehensionTestPort",
+                "comprehension_test_port",
+                &["OllamaComprehensionTestAdapter", "BudgetGuardAdapter", "RecordingAdapter", "ReplayAdapter"],
+            ),
+            port(
+                "TaskDecompositionPort",
+                "task_decomposition_port",
+                &["RigTaskDecompositionAdapter", "MlxSubprocessAdapter", "RecordingAdapter", "ReplayAdapter"],
+            ),
+            port("PRDParserPort", "prd_parser_port", &["RigPRDParserAdapter"]),
+            port("EmbeddingPort", "embedding_port", &["RigEmbeddingAdapter"]),
+            port("VisionPort", "vision_port", &["RigVisionAdapter"]),
+            port("LLMAgentPort", "llm_agent_port", &["RigAgentAdapter"]),
+            port("ConversationStorePort", "conversation_store_port", &["SqliteConversationAdapter"]),
+            port(
+                "MetricsCollectorPort",
+                "metrics_collector_port",
+                &["MemoryMetricsCollector", "SqliteMetricsCollector", "JsonlMetricsCollector"],
+            ),
+            port("ModelListPort", "model_list_port", &["ReqwestModelListAdapter"]),
+            port("WebCrawlerPort", "web_crawler_port", &["ReqwestWebCrawler"]),
+        ],
+        nodes: std::vec![
+            node("router", "SemanticRouterTaskShim", std::option::Option::None),
+            node("decompose", "TaskDecompositionTaskShim", std::option::Option::Some("TaskDecompositionPort")),
+            node("enhance", "EnhancementTaskShim", std::option::Option::Some("TaskEnhancementPort")),
+            node("comprehend", "ComprehensionTestTaskShim", std::option::Option::Some("ComprehensionTestPort")),
+            node("check", "CheckTestResultTaskShim", std::option::Option::None),
+            node("end", "EndTask", std::option::Option::None),
+        ],
+        edges: std::vec![
+            edge("router", "decompose", std::option::Option::Some("decompose")),
+            edge("router", "enhance", std::option::Option::Some("enhance")),
+            edge("decompose", "end", std::option::Option::None),
+            edge("enhance", "comprehend", std::option::Option::None),
+            edge("comprehend", "check", std::option::Option::None),
+            edge("check", "end", std::option::Option::Some("pass")),
+            edge("check", "enhance", std::option::Option::Some("fail")),
+        ],
+    }
+}
+
+/// Renders the architecture in the given format.
+///
+/// # Errors
+///
+/// Returns an error if JSON serialization fails.
+///
+/// # Examples
+///
+/// ```
+/// use task_orchestrator::architecture::describe_architecture::{describe_architecture_as, ArchitectureFormat};
+///
+/// let dot = describe_architecture_as(ArchitectureFormat::Dot).unwrap();
+/// assert!(dot.starts_with("digraph task_orchestrator {"));
+/// ```
+pub fn describe_architecture_as(format: ArchitectureFormat) -> std::result::Result<String, String> {
+    match format {
+        ArchitectureFormat::Text => std::result::Result::Ok(describe_architecture()),
+        ArchitectureFormat::Json => serde_json::to_string_pretty(&architecture_description())
+            .map_err(|e| std::format!("Failed to serialize architecture: {}", e)),
+        ArchitectureFormat::Dot => std::result::Result::Ok(to_dot(&architecture_description())),
+    }
+}
+
+/// Renders a description as a Graphviz DOT digraph.
+///
+/// Ports, adapters, and flow nodes are grouped into clusters. Adapters point
+/// at the ports they implement, flow nodes at the ports they call, and flow
+/// edges are labelled with their routing condition.
+pub fn to_dot(description: &ArchitectureDescription) -> String {
+    let mut adapters: std::vec::Vec<&str> = description
+        .ports
+        .iter()
+        .flat_map(|port| port.adapters.iter().map(String::as_str))
+        .collect();
+    adapters.sort_unstable();
+    adapters.dedup();
+
+    let mut s = String::new();
+    s.push_str("digraph task_orchestrator {\n");
+    s.push_str("    rankdir=LR;\n");
+
+    s.push_str("    subgraph cluster_ports {\n        label=\"Ports\";\n");
+    for port in &description.ports {
+        s.push_str(&std::format!("        {} [shape=box];\n", dot_id(&port.name)));
+    }
+    s.push_str("    }\n");
+
+    s.push_str("    subgraph cluster_adapters {\n        label=\"Adapters\";\n");
+    for adapter in &adapters {
+        s.push_str(&std::format!("        {} [shape=component];\n", dot_id(adapter)));
+    }
+    s.push_str("    }\n");
+
+    s.push_str("    subgraph cluster_graph {\n        label=\"Graph\";\n");
+    for node in &description.nodes {
+        s.push_str(&std::format!(
+            "        {} [shape=ellipse, label={}];\n",
+            dot_id(&node.id),
+            dot_id(&std::format!("{}: {}", node.id, node.task))
+        ));
+    }
+    s.push_str("    }\n");
+
+    for port in &description.ports {
+        for adapter in &port.adapters {
+            s.push_str(&std::format!("    {} -> {} [style=dashed];\n", dot_id(adapter), dot_id(&port.name)));
+        }
+    }
+    for node in &description.nodes {
+        if let std::option::Option::Some(port) = &node.port {
+            s.push_str(&std::format!("    {} -> {} [style=dotted];\n", dot_id(&node.id), dot_id(port)));
+        }
+    }
+    for edge in &description.edges {
+        match &edge.condition {
+            std::option::Option::Some(condition) => s.push_str(&std::format!(
+                "    {} -> {} [label={}];\n",
+                dot_id(&edge.from),
+                dot_id(&edge.to),
+                dot_id(condition)
+            )),
+            std::option::Option::None => {
+                s.push_str(&std::format!("    {} -> {};\n", dot_id(&edge.from), dot_id(&edge.to)))
+            }
+        }
+    }
+
+    s.push_str("}\n");
+    s
+}
+
+/// Quotes a name as a DOT string ID.
+fn dot_id(name: &str) -> String {
+    std::format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -79,4 +340,203 @@ mod tests {
         std::assert!(s.contains("run_task_with_flow"));
         std::assert!(s.contains("Orchestrator"));
     }
+
+    /// Minimal parser for the DOT subset `to_dot` emits: a digraph of
+    /// subgraphs, node statements, edge statements, and `id=value` attributes.
+    /// Collects the declared node IDs and the edges.
+    struct DotParser {
+        tokens: std::vec::Vec<String>,
+        pos: usize,
+        nodes: std::collections::HashSet<String>,
+        edges: std::vec::Vec<(String, String)>,
+    }
+
+    impl DotParser {
+        fn parse(source: &str) -> std::result::Result<Self, String> {
+            let mut parser = DotParser {
+                tokens: Self::tokenize(source)?,
+                pos: 0,
+                nodes: std::collections::HashSet::new(),
+                edges: std::vec::Vec::new(),
+            };
+            parser.expect("digraph")?;
+            parser.id()?;
+            parser.block()?;
+            if parser.pos != parser.tokens.len() {
+                return std::result::Result::Err(std::format!("trailing token {:?}", parser.tokens[parser.pos]));
+            }
+            std::result::Result::Ok(parser)
+        }
+
+        fn tokenize(source: &str) -> std::result::Result<std::vec::Vec<String>, String> {
+            let mut tokens = std::vec::Vec::new();
+            let mut chars = source.chars().peekable();
+            while let std::option::Option::Some(c) = chars.next() {
+                match c {
+                    c if c.is_whitespace() => {}
+                    '{' | '}' | '[' | ']' | '=' | ';' | ',' => tokens.push(c.to_string()),
+                    '-' if chars.next_if_eq(&'>').is_some() => tokens.push(String::from("->")),
+                    '"' => {
+                        let mut quoted = String::from("\"");
+                        loop {
+                            match chars.next() {
+                                std::option::Option::Some('\\') => {
+                                    quoted.push('\\');
+                                    quoted.push(chars.next().ok_or("unterminated escape")?);
+                                }
+                                std::option::Option::Some('"') => break,
+                                std::option::Option::Some(c) => quoted.push(c),
+                                std::option::Option::None => return std::result::Result::Err(String::from("unterminated string")),
+                            }
+                        }
+                        tokens.push(quoted);
+                    }
+                    c if c.is_ascii_alphanumeric() || c == '_' => {
+                        let mut word = c.to_string();
+                        while let std::option::Option::Some(c) = chars.next_if(|c| c.is_ascii_alphanumeric() || *c == '_') {
+                            word.push(c);
+                        }
+                        tokens.push(word);
+                    }
+                    other => return std::result::Result::Err(std::format!("unexpected character {:?}", other)),
+                }
+            }
+            std::result::Result::Ok(tokens)
+        }
+
+        fn peek(&self) -> std::option::Option<&str> {
+            self.tokens.get(self.pos).map(String::as_str)
+        }
+
+        fn next(&mut self) -> std::result::Result<String, String> {
+            let token = self.tokens.get(self.pos).cloned().ok_or("unexpected end of input")?;
+            self.pos += 1;
+            std::result::Result::Ok(token)
+        }
+
+        fn expect(&mut self, expected: &str) -> std::result::Result<(), String> {
+            let token = self.next()?;
+            if token != expected {
+                return std::result::Result::Err(std::format!("expected {:?}, found {:?}", expected, token));
+            }
+            std::result::Result::Ok(())
+        }
+
+        fn id(&mut self) -> std::result::Result<String, String> {
+            let token = self.next()?;
+            if token.len() == 1 && "{}[]=;,".contains(token.as_str()) || token == "->" {
+                return std::result::Result::Err(std::format!("expected ID, found {:?}", token));
+            }
+            std::result::Result::Ok(token.trim_start_matches('"').to_string())
+        }
+
+        fn block(&mut self) -> std::result::Result<(), String> {
+            self.expect("{")?;
+            while self.peek() != std::option::Option::Some("}") {
+                self.statement()?;
+            }
+            self.expect("}")
+        }
+
+        fn statement(&mut self) -> std::result::Result<(), String> {
+            if self.peek() == std::option::Option::Some("subgraph") {
+                self.next()?;
+                self.id()?;
+                return self.block();
+            }
+            let first = self.id()?;
+            match self.peek() {
+                std::option::Option::Some("=") => {
+                    self.next()?;
+                    self.id()?;
+                }
+                std::option::Option::Some("->") => {
+                    self.next()?;
+                    let second = self.id()?;
+                    self.attributes()?;
+                    self.edges.push((first, second));
+                }
+                _ => {
+                    self.attributes()?;
+                    self.nodes.insert(first);
+                }
+            }
+            self.expect(";")
+        }
+
+        fn attributes(&mut self) -> std::result::Result<(), String> {
+            if self.peek() != std::option::Option::Some("[") {
+                return std::result::Result::Ok(());
+            }
+            self.next()?;
+            loop {
+                self.id()?;
+                self.expect("=")?;
+                self.id()?;
+                match self.next()?.as_str() {
+                    "," => {}
+                    "]" => return std::result::Result::Ok(()),
+                    other => return std::result::Result::Err(std::format!("expected ',' or ']', found {:?}", other)),
+                }
+            }
+        }
+    }
+
+    /// Collects (port, adapter) pairs from the `impl crate::ports::...` blocks in src/adapters.
+    fn implemented_ports() -> std::vec::Vec<(String, String)> {
+        let adapters_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join("adapters");
+        let mut pairs = std::vec::Vec::new();
+        for entry in std::fs::read_dir(adapters_dir).unwrap() {
+            let source = std::fs::read_to_string(entry.unwrap().path()).unwrap();
+            for (i, _) in source.match_indices("\nimpl crate::ports::") {
+                let header = source[i + 1..].split('{').next().unwrap();
+                let (port_path, adapter) = header["impl ".len()..].split_once(" for ").unwrap();
+                let port = port_path.trim().rsplit("::").next().unwrap();
+                let adapter = adapter.trim().split('<').next().unwrap();
+                pairs.push((String::from(port), String::from(adapter)));
+            }
+        }
+        pairs
+    }
+
+    #[test]
+    fn test_dot_output_parses_and_covers_every_port_and_adapter() {
+        // Test: Validates the DOT export parses and has a node for every port trait and every adapter implementing one.
+        // Justification: The hand-maintained description must not drift from the adapters in the tree, and Graphviz rejects malformed input outright.
+        let dot = super::describe_architecture_as(super::ArchitectureFormat::Dot).unwrap();
+        let DotParser { nodes, edges, .. } = DotParser::parse(&dot).unwrap();
+
+        let pairs = implemented_ports();
+        std::assert!(!pairs.is_empty());
+        for (port, adapter) in &pairs {
+            std::assert!(nodes.contains(port), "missing port node {}", port);
+            std::assert!(nodes.contains(adapter), "missing adapter node {}", adapter);
+            std::assert!(edges.contains(&(adapter.clone(), port.clone())), "missing edge {} -> {}", adapter, port);
+        }
+
+        for node in ["router", "decompose", "enhance", "comprehend", "check", "end"] {
+            std::assert!(nodes.contains(node), "missing graph node {}", node);
+        }
+        for (from, to) in &edges {
+            std::assert!(nodes.contains(from) && nodes.contains(to), "edge {} -> {} uses an undeclared node", from, to);
+        }
+    }
+
+    #[test]
+    fn test_json_output_and_format_parsing() {
+        // Test: Validates the JSON export lists ports with adapters and flow edges, and that format names parse.
+        // Justification: The JSON is consumed by tooling, so its shape is part of the contract.
+        let json = super::describe_architecture_as(super::ArchitectureFormat::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let ports = value["ports"].as_array().unwrap();
+        std::assert!(ports.iter().any(|port| port["name"] == "PRDParserPort" && port["adapters"][0] == "RigPRDParserAdapter"));
+        std::assert!(value["edges"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|edge| edge["from"] == "check" && edge["to"] == "end" && edge["condition"] == "pass"));
+
+        std::assert_eq!("dot".parse::<super::ArchitectureFormat>(), std::result::Result::Ok(super::ArchitectureFormat::Dot));
+        std::assert!("svg".parse::<super::ArchitectureFormat>().is_err());
+    }
 }
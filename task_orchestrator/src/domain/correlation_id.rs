@@ -0,0 +1,82 @@
+//! Correlation ID identifying one orchestration run across logs.
+//!
+//! Entry points (`rig do`, the gRPC and MCP servers) generate one ID per run
+//! and thread it through `GraphState`; it is attached to the `run` and `node`
+//! tracing spans so every event of a run can be grouped when several runs
+//! interleave in the same log.
+//!
+//! IDs are ULIDs: 26 Crockford base32 characters encoding a 48-bit
+//! millisecond timestamp followed by 80 random bits, so they sort by start
+//! time and stay short enough to grep for.
+//!
+//! Revision History
+//! - 2026-10-16T20:10:00Z @AI: Initial ULID-based CorrelationId.
+
+/// Crockford base32 alphabet (no I, L, O, or U).
+const CROCKFORD_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Length of an encoded ULID.
+const ULID_LENGTH: usize = 26;
+
+/// Identifier shared by all log events of one orchestration run.
+///
+/// # Examples
+///
+/// ```
+/// use task_orchestrator::domain::correlation_id::CorrelationId;
+///
+/// let id = CorrelationId::generate();
+/// std::assert_eq!(id.as_str().len(), 26);
+/// std::assert_ne!(id, CorrelationId::generate());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct CorrelationId(String);
+
+impl CorrelationId {
+    /// Generates a new ULID from the current time and 80 random bits.
+    pub fn generate() -> Self {
+        let millis = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis())
+            .unwrap_or(0);
+        let random = uuid::Uuid::new_v4().as_u128() & ((1u128 << 80) - 1);
+        CorrelationId(encode_ulid(((millis & 0xFFFF_FFFF_FFFF) << 80) | random))
+    }
+
+    /// Returns the ID as a string slice.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl std::fmt::Display for CorrelationId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Encodes a 128-bit value as 26 Crockford base32 characters, most significant first.
+fn encode_ulid(value: u128) -> String {
+    (0..ULID_LENGTH)
+        .rev()
+        .map(|index| CROCKFORD_ALPHABET[((value >> (index * 5)) & 0x1F) as usize] as char)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_ulid_encoding_and_time_ordering() {
+        // Test: Validates the ULID encoding and that later IDs sort after earlier ones.
+        // Justification: Sortable IDs let a log reader order runs without parsing timestamps.
+        std::assert_eq!(super::encode_ulid(0), "00000000000000000000000000");
+        std::assert_eq!(super::encode_ulid(u128::MAX), "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+
+        let first = super::CorrelationId::generate();
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let second = super::CorrelationId::generate();
+        std::assert!(first.as_str() < second.as_str());
+        std::assert!(second.as_str().bytes().all(|byte| super::CROCKFORD_ALPHABET.contains(&byte)));
+    }
+}
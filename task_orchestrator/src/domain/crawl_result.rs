@@ -5,6 +5,12 @@
 //! while CrawledPage represents fetched pages ready for artifact generation.
 //!
 //! Revision History
+//! - 2026-10-17T22:30:00Z @AI: Add ValidatorError for page validators that could not be saved.
+//! - 2026-10-17T13:30:00Z @AI: Add PageValidators, the force setting, pages_unchanged, and NotModified for conditional re-crawls.
+//! - 2026-10-17T13:10:00Z @AI: Add resume setting, pages_resumed stat, and FrontierError.
+//! - 2026-10-17T12:50:00Z @AI: rate_limit_ms is the minimum delay between requests to the same host.
+//! - 2026-10-17T12:30:00Z @AI: Add include_subdomains and path_prefix crawl scope settings.
+//! - 2026-10-17T12:10:00Z @AI: Add content-type allowlist, CrawlContentKind, raw PDF bodies, and UnsupportedContentType.
 //! - 2025-11-30T18:45:00Z @AI: Initial crawl_result module for Phase 1 artifact generator.
 
 /// Configuration for web crawling operations.
@@ -18,9 +24,14 @@
 /// * `max_depth` - Maximum link depth to follow (0 = start page only).
 /// * `max_pages` - Maximum number of pages to crawl.
 /// * `follow_external` - Whether to follow links to external domains.
-/// * `rate_limit_ms` - Delay between requests in milliseconds.
-/// * `respect_robots_txt` - Whether to honor robots.txt directives.
+/// * `include_subdomains` - Whether subdomains of the start host are in scope.
+/// * `path_prefix` - Only follow links whose path starts with this prefix.
+/// * `rate_limit_ms` - Minimum delay between requests to the same host in milliseconds.
+/// * `respect_robots_txt` - Whether to honor robots.txt directives (including a longer `Crawl-delay`).
 /// * `user_agent` - User-Agent header to send with requests.
+/// * `allowed_content_types` - Response content types the crawler keeps; others are skipped.
+/// * `resume` - Continue from a saved frontier instead of starting over.
+/// * `force` - Refetch every page, ignoring validators saved by earlier crawls.
 ///
 /// # Examples
 ///
@@ -45,10 +56,21 @@ pub struct CrawlConfig {
     /// Whether to follow links to external domains.
     pub follow_external: bool,
 
-    /// Delay between requests in milliseconds (rate limiting).
+    /// Whether subdomains of the start host (e.g. docs.example.com for
+    /// example.com) are in scope. Ignored when `follow_external` is set.
+    #[serde(default)]
+    pub include_subdomains: bool,
+
+    /// Only follow links whose URL path starts with this prefix (e.g. "/docs").
+    #[serde(default)]
+    pub path_prefix: std::option::Option<String>,
+
+    /// Minimum delay in milliseconds between requests to the same host.
+    /// Requests to different hosts are not delayed by each other.
     pub rate_limit_ms: u64,
 
-    /// Whether to honor robots.txt directives.
+    /// Whether to honor robots.txt directives. A robots.txt `Crawl-delay`
+    /// longer than `rate_limit_ms` wins.
     pub respect_robots_txt: bool,
 
     /// User-Agent header to send with requests.
@@ -59,6 +81,20 @@ pub struct CrawlConfig {
 
     /// CSS selectors for elements to exclude from content.
     pub exclude_selectors: std::vec::Vec<String>,
+
+    /// Content types to keep, e.g. "text/html" or "text/*" (parameters such as charset are ignored).
+    #[serde(default = "CrawlConfig::default_allowed_content_types")]
+    pub allowed_content_types: std::vec::Vec<String>,
+
+    /// Continue from the crawler's saved frontier, if any, instead of starting
+    /// over. Only has an effect when the crawler persists its frontier.
+    #[serde(default)]
+    pub resume: bool,
+
+    /// Refetch every page instead of sending conditional requests with the
+    /// validators saved by an earlier crawl.
+    #[serde(default)]
+    pub force: bool,
 }
 
 impl CrawlConfig {
@@ -82,7 +118,7 @@ impl CrawlConfig {
     /// A CrawlConfig with sensible defaults:
     /// - Depth 2 (start page + 2 levels of links)
     /// - 100 max pages
-    /// - 1 second between requests
+    /// - 1 second between requests to the same host
     /// - No external domains
     /// - Respects robots.txt
     ///
@@ -99,11 +135,16 @@ impl CrawlConfig {
             max_depth: 2,
             max_pages: Self::DEFAULT_MAX_PAGES,
             follow_external: false,
+            include_subdomains: false,
+            path_prefix: std::option::Option::None,
             rate_limit_ms: Self::DEFAULT_RATE_LIMIT_MS,
             respect_robots_txt: true,
             user_agent: String::from(Self::DEFAULT_USER_AGENT),
             content_selectors: std::vec::Vec::new(),
             exclude_selectors: Self::default_exclude_selectors(),
+            allowed_content_types: Self::default_allowed_content_types(),
+            resume: false,
+            force: false,
         }
     }
 
@@ -122,11 +163,14 @@ impl CrawlConfig {
             max_depth: 0,
             max_pages: 1,
             follow_external: false,
+            include_subdomains: false,
+            path_prefix: std::option::Option::None,
             rate_limit_ms: 0,
             respect_robots_txt: true,
             user_agent: String::from(Self::DEFAULT_USER_AGENT),
             content_selectors: std::vec::Vec::new(),
             exclude_selectors: Self::default_exclude_selectors(),
+            allowed_content_types: Self::default_allowed_content_types(),
         }
     }
 
@@ -149,6 +193,17 @@ impl CrawlConfig {
         ]
     }
 
+    /// Returns the content types crawled by default: HTML, plain text, markdown, and PDF.
+    fn default_allowed_content_types() -> std::vec::Vec<String> {
+        std::vec![
+            String::from("text/html"),
+            String::from("application/xhtml+xml"),
+            String::from("text/plain"),
+            String::from("text/markdown"),
+            String::from("application/pdf"),
+        ]
+    }
+
     /// Sets the maximum crawl depth.
     ///
     /// # Arguments
@@ -191,7 +246,35 @@ impl CrawlConfig {
         self
     }
 
-    /// Sets the rate limit between requests.
+    /// Includes or excludes subdomains of the start host in the crawl scope.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` - Whether subdomains are in scope.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_include_subdomains(mut self, include: bool) -> Self {
+        self.include_subdomains = include;
+        self
+    }
+
+    /// Restricts followed links to URL paths starting with `prefix`.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - Path prefix such as "/docs".
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_path_prefix(mut self, prefix: String) -> Self {
+        self.path_prefix = std::option::Option::Some(prefix);
+        self
+    }
+
+    /// Sets the minimum delay between requests to the same host.
     ///
     /// # Arguments
     ///
@@ -205,6 +288,34 @@ impl CrawlConfig {
         self
     }
 
+    /// Resumes from a saved frontier instead of starting over.
+    ///
+    /// # Arguments
+    ///
+    /// * `resume` - Whether to resume.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_resume(mut self, resume: bool) -> Self {
+        self.resume = resume;
+        self
+    }
+
+    /// Refetches every page even if an earlier crawl saved validators for it.
+    ///
+    /// # Arguments
+    ///
+    /// * `force` - Whether to skip conditional requests.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
     /// Adds a CSS selector for content extraction.
     ///
     /// # Arguments
@@ -218,23 +329,105 @@ impl CrawlConfig {
         self.content_selectors.push(selector);
         self
     }
+
+    /// Replaces the content-type allowlist.
+    ///
+    /// # Arguments
+    ///
+    /// * `content_types` - Allowed types, e.g. "text/html" or "text/*".
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_allowed_content_types(mut self, content_types: std::vec::Vec<String>) -> Self {
+        self.allowed_content_types = content_types;
+        self
+    }
+
+    /// Returns true if a response with this Content-Type header should be kept.
+    ///
+    /// Matching ignores case and parameters, and an entry ending in "/*"
+    /// allows every subtype.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use task_orchestrator::domain::crawl_result::CrawlConfig;
+    /// let config = CrawlConfig::new(std::string::String::from("https://example.com"));
+    ///
+    /// std::assert!(config.allows_content_type("text/html; charset=utf-8"));
+    /// std::assert!(!config.allows_content_type("image/png"));
+    /// ```
+    pub fn allows_content_type(&self, content_type: &str) -> bool {
+        let essence = content_type_essence(content_type);
+        self.allowed_content_types.iter().any(|allowed| {
+            let allowed = allowed.trim().to_lowercase();
+            match allowed.strip_suffix("/*") {
+                std::option::Option::Some(top_level) => essence.split('/').next() == std::option::Option::Some(top_level),
+                std::option::Option::None => essence == allowed,
+            }
+        })
+    }
+}
+
+/// Returns the lowercased media type of a Content-Type header, without parameters.
+fn content_type_essence(content_type: &str) -> String {
+    content_type.split(';').next().unwrap_or("").trim().to_lowercase()
+}
+
+/// How a crawled response is ingested, based on its content type.
+///
+/// # Examples
+///
+/// ```
+/// # use task_orchestrator::domain::crawl_result::CrawlContentKind;
+/// std::assert_eq!(CrawlContentKind::from_content_type("text/html; charset=utf-8"), CrawlContentKind::Html);
+/// std::assert_eq!(CrawlContentKind::from_content_type("application/pdf"), CrawlContentKind::Pdf);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrawlContentKind {
+    /// HTML, converted to markdown before chunking.
+    Html,
+
+    /// Plain text or markdown, used as-is.
+    Text,
+
+    /// PDF, ingested from the raw response bytes.
+    Pdf,
+
+    /// Anything else.
+    Other,
+}
+
+impl CrawlContentKind {
+    /// Classifies a Content-Type header value.
+    pub fn from_content_type(content_type: &str) -> Self {
+        match content_type_essence(content_type).as_str() {
+            "text/html" | "application/xhtml+xml" => CrawlContentKind::Html,
+            "text/plain" | "text/markdown" => CrawlContentKind::Text,
+            "application/pdf" => CrawlContentKind::Pdf,
+            _ => CrawlContentKind::Other,
+        }
+    }
 }
 
 /// Represents a page fetched during web crawling.
 ///
 /// CrawledPage contains the URL, extracted content, and metadata for a
-/// successfully fetched web page. The content is cleaned text ready for
-/// chunking and embedding.
+/// successfully fetched web page. The content is markdown (for HTML pages) or
+/// plain text ready for chunking and embedding; PDF responses keep their bytes
+/// in `raw_body` instead.
 ///
 /// # Fields
 ///
 /// * `url` - The URL that was fetched.
 /// * `title` - Page title extracted from <title> tag.
-/// * `content` - Cleaned text content (HTML tags stripped).
+/// * `content` - Main content as markdown or plain text.
 /// * `links` - URLs of links found on this page.
 /// * `depth` - How many links deep from the start URL (0 = start page).
 /// * `status_code` - HTTP status code of the response.
 /// * `content_type` - Content-Type header value.
+/// * `raw_body` - Response bytes for content that is not text (PDFs).
 ///
 /// # Examples
 ///
@@ -248,6 +441,7 @@ impl CrawlConfig {
 ///     depth: 0,
 ///     status_code: 200,
 ///     content_type: std::string::String::from("text/html"),
+///     raw_body: std::option::Option::None,
 /// };
 ///
 /// std::assert_eq!(page.status_code, 200);
@@ -260,7 +454,7 @@ pub struct CrawledPage {
     /// Page title extracted from <title> tag.
     pub title: String,
 
-    /// Cleaned text content (HTML tags stripped).
+    /// Main content as markdown (HTML pages) or plain text.
     pub content: String,
 
     /// URLs of links found on this page.
@@ -274,6 +468,10 @@ pub struct CrawledPage {
 
     /// Content-Type header value.
     pub content_type: String,
+
+    /// Raw response bytes, kept for content that is ingested from bytes (PDFs).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_body: std::option::Option<std::vec::Vec<u8>>,
 }
 
 impl CrawledPage {
@@ -293,6 +491,50 @@ impl CrawledPage {
     pub fn has_content(&self) -> bool {
         self.content.len() >= 50
     }
+
+    /// Returns how this page should be ingested, based on its content type.
+    pub fn kind(&self) -> CrawlContentKind {
+        CrawlContentKind::from_content_type(&self.content_type)
+    }
+}
+
+/// HTTP cache validators saved for a page by an earlier crawl.
+///
+/// Re-crawls send them as `If-None-Match` / `If-Modified-Since`; a 304 reply
+/// means the page is unchanged, and its saved links are followed instead of
+/// the (unsent) body's.
+///
+/// # Examples
+///
+/// ```
+/// # use task_orchestrator::domain::crawl_result::PageValidators;
+/// let validators = PageValidators {
+///     etag: std::option::Option::Some(std::string::String::from("\"v1\"")),
+///     last_modified: std::option::Option::None,
+///     links: std::vec::Vec::new(),
+/// };
+///
+/// std::assert!(!validators.is_empty());
+/// std::assert!(PageValidators::default().is_empty());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct PageValidators {
+    /// ETag response header, sent back as If-None-Match.
+    pub etag: std::option::Option<String>,
+
+    /// Last-Modified response header, sent back as If-Modified-Since.
+    pub last_modified: std::option::Option<String>,
+
+    /// Links found on the page when it was last fetched.
+    #[serde(default)]
+    pub links: std::vec::Vec<String>,
+}
+
+impl PageValidators {
+    /// Returns true if there is nothing to send in a conditional request.
+    pub fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
 }
 
 /// Errors that can occur during web crawling.
@@ -309,6 +551,10 @@ impl CrawledPage {
 /// * `RateLimited` - Server returned 429 Too Many Requests.
 /// * `RobotsTxtBlocked` - URL is disallowed by robots.txt.
 /// * `Timeout` - Request timed out.
+/// * `UnsupportedContentType` - Response content type is not in the allowlist.
+/// * `FrontierError` - The crawl frontier could not be loaded or saved.
+/// * `NotModified` - Server replied 304 to a conditional request; the page is unchanged.
+/// * `ValidatorError` - A page's ETag/Last-Modified could not be saved.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CrawlError {
     /// The URL is malformed or uses an unsupported scheme.
@@ -331,6 +577,18 @@ pub enum CrawlError {
 
     /// Request timed out.
     Timeout(String),
+
+    /// Response content type is not in `CrawlConfig::allowed_content_types`.
+    UnsupportedContentType { url: String, content_type: String },
+
+    /// The persisted crawl frontier could not be loaded or saved.
+    FrontierError(String),
+
+    /// Server replied 304 Not Modified: the page is unchanged since the last crawl.
+    NotModified(String),
+
+    /// A stored page's ETag/Last-Modified could not be saved.
+    ValidatorError(String),
 }
 
 impl std::fmt::Display for CrawlError {
@@ -357,6 +615,18 @@ impl std::fmt::Display for CrawlError {
             CrawlError::Timeout(url) => {
                 std::write!(f, "Request timed out: {}", url)
             }
+            CrawlError::UnsupportedContentType { url, content_type } => {
+                std::write!(f, "Content type {} not allowed: {}", content_type, url)
+            }
+            CrawlError::FrontierError(msg) => {
+                std::write!(f, "Crawl frontier error: {}", msg)
+            }
+            CrawlError::NotModified(url) => {
+                std::write!(f, "Not modified since last crawl: {}", url)
+            }
+            CrawlError::ValidatorError(msg) => {
+                std::write!(f, "Page validator error: {}", msg)
+            }
         }
     }
 }
@@ -372,6 +642,9 @@ impl std::error::Error for CrawlError {}
 ///
 /// * `pages_crawled` - Number of pages successfully fetched.
 /// * `pages_failed` - Number of pages that failed to fetch.
+/// * `pages_skipped` - Number of responses skipped for their content type.
+/// * `pages_resumed` - Number of pages restored from a saved frontier instead of fetched.
+/// * `pages_unchanged` - Number of pages the server reported unchanged (304).
 /// * `links_found` - Total number of links discovered.
 /// * `links_followed` - Number of links that were actually followed.
 /// * `total_content_bytes` - Total bytes of content extracted.
@@ -384,6 +657,18 @@ pub struct CrawlStats {
     /// Number of pages that failed to fetch.
     pub pages_failed: usize,
 
+    /// Number of responses skipped because their content type is not allowed.
+    #[serde(default)]
+    pub pages_skipped: usize,
+
+    /// Number of pages restored from a saved frontier (included in `pages_crawled`).
+    #[serde(default)]
+    pub pages_resumed: usize,
+
+    /// Number of pages the server reported unchanged since the last crawl (304).
+    #[serde(default)]
+    pub pages_unchanged: usize,
+
     /// Total number of links discovered on crawled pages.
     pub links_found: usize,
 
@@ -464,6 +749,7 @@ mod tests {
             depth: 0,
             status_code: 200,
             content_type: String::from("text/html"),
+            raw_body: std::option::Option::None,
         };
 
         let redirect_page = CrawledPage {
@@ -493,6 +779,7 @@ mod tests {
             depth: 0,
             status_code: 200,
             content_type: String::from("text/html"),
+            raw_body: std::option::Option::None,
         };
 
         let no_content = CrawledPage {
@@ -525,6 +812,7 @@ mod tests {
         let stats = CrawlStats {
             pages_crawled: 80,
             pages_failed: 20,
+            pages_skipped: 0,
             links_found: 500,
             links_followed: 100,
             total_content_bytes: 1000000,
@@ -553,4 +841,24 @@ mod tests {
         std::assert!(config.exclude_selectors.contains(&String::from("nav")));
         std::assert!(config.exclude_selectors.contains(&String::from("script")));
     }
+
+    #[test]
+    fn test_content_type_allowlist_and_kind() {
+        // Test: Validates allowlist matching ignores parameters and case, supports wildcards, and classifies kinds.
+        // Justification: Binary responses must be skipped or routed before being treated as text.
+        let config = CrawlConfig::new(String::from("https://example.com"));
+
+        std::assert!(config.allows_content_type("Text/HTML; charset=UTF-8"));
+        std::assert!(config.allows_content_type("application/pdf"));
+        std::assert!(!config.allows_content_type("image/png"));
+        std::assert!(!config.allows_content_type("application/octet-stream"));
+
+        let images = config.with_allowed_content_types(std::vec![String::from("image/*")]);
+        std::assert!(images.allows_content_type("image/png"));
+        std::assert!(!images.allows_content_type("text/html"));
+
+        std::assert_eq!(CrawlContentKind::from_content_type("application/xhtml+xml"), CrawlContentKind::Html);
+        std::assert_eq!(CrawlContentKind::from_content_type("text/markdown"), CrawlContentKind::Text);
+        std::assert_eq!(CrawlContentKind::from_content_type("image/png"), CrawlContentKind::Other);
+    }
 }
@@ -2,9 +2,18 @@
 //!
 //! Contains core domain entities and services for the orchestration pipeline,
 //! including model selection strategies for the heterogeneous agent architecture,
-//! performance metrics for benchmarking LLM operations, and web crawling types.
+//! performance metrics for benchmarking LLM operations, web crawling types,
+//! the correlation IDs that tie a run's log events together, per-run token budgets,
+//! the grading of answers to comprehension tests, the router's confidence settings,
+//! the events that trace a run's path through the graph, and replayable run logs.
 //!
 //! Revision History
+//! - 2026-10-17T08:10:00Z @AI: Add run_log module for recording and replaying runs.
+//! - 2026-10-17T07:50:00Z @AI: Add trace_event module for graph execution tracing.
+//! - 2026-10-17T07:30:00Z @AI: Add router_config module for the router's confidence threshold and fallback route.
+//! - 2026-10-17T07:10:00Z @AI: Add test_grade module for grading comprehension test answers.
+//! - 2026-10-16T20:50:00Z @AI: Add token_budget module for per-run token and cost limits.
+//! - 2026-10-16T20:10:00Z @AI: Add correlation_id module for per-run log correlation.
 //! - 2025-11-30T18:45:00Z @AI: Add crawl_result module for Phase 1 artifact generator web crawling.
 //! - 2025-11-24T00:50:00Z @AI: Add performance_metrics module for Phase 5 Sprint 12 Task 5.10.
 //! - 2025-11-23T22:05:00Z @AI: Add domain module with model_role for heterogeneous pipeline (Phase 5 Sprint 10 Task 5.1).
@@ -12,3 +21,9 @@
 pub mod model_role;
 pub mod performance_metrics;
 pub mod crawl_result;
+pub mod correlation_id;
+pub mod token_budget;
+pub mod test_grade;
+pub mod router_config;
+pub mod trace_event;
+pub mod run_log;
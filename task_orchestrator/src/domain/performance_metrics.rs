@@ -6,6 +6,8 @@
 //! the heterogeneous agent pipeline.
 //!
 //! Revision History
+//! - 2026-10-17T02:10:00Z @AI: Add RetryEvent and per-provider RetryCounters with retry rate and flakiness check.
+//! - 2026-10-16T20:30:00Z @AI: Add ModelPrice and CostAccumulator for per-run dollar cost, reporting unpriced models separately.
 //! - 2025-11-24T00:45:00Z @AI: Create performance metrics domain entity for Phase 5 Sprint 12 Task 5.10.
 
 /// Performance metrics for a single LLM inference operation.
@@ -493,6 +495,248 @@ impl MetricAggregate {
     }
 }
 
+/// Price of one model in dollars per 1,000 tokens.
+///
+/// # Examples
+///
+/// ```
+/// use task_orchestrator::domain::performance_metrics::ModelPrice;
+///
+/// let price = ModelPrice::new(0.5, 1.5);
+/// // 2,000 input tokens at $0.50/1K + 1,000 output tokens at $1.50/1K
+/// std::assert!((price.cost(2000, 1000) - 2.5).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ModelPrice {
+    /// Cost per 1,000 input (prompt) tokens.
+    pub input_per_1k: f64,
+
+    /// Cost per 1,000 output (generated) tokens.
+    pub output_per_1k: f64,
+}
+
+impl ModelPrice {
+    /// Creates a price from per-1K-token input and output costs.
+    pub fn new(input_per_1k: f64, output_per_1k: f64) -> Self {
+        Self { input_per_1k, output_per_1k }
+    }
+
+    /// Returns the dollar cost of the given token counts.
+    pub fn cost(&self, input_tokens: usize, output_tokens: usize) -> f64 {
+        (input_tokens as f64 / 1000.0) * self.input_per_1k + (output_tokens as f64 / 1000.0) * self.output_per_1k
+    }
+}
+
+/// Token usage and cost of one model within a run.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ModelCost {
+    /// Number of completed calls to the model.
+    pub calls: usize,
+
+    /// Total input tokens across the calls.
+    pub input_tokens: usize,
+
+    /// Total output tokens across the calls.
+    pub output_tokens: usize,
+
+    /// Dollar cost, or None if the model has no price configured.
+    pub cost: std::option::Option<f64>,
+}
+
+/// Sums the dollar cost of a run's LLM calls from their recorded token counts.
+///
+/// Models missing from the price table are reported as unpriced instead of
+/// being counted as free, so a total is only complete when
+/// `unpriced_models()` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use task_orchestrator::domain::performance_metrics::{CostAccumulator, InferenceMetrics, ModelPrice};
+///
+/// let mut prices = std::collections::HashMap::new();
+/// prices.insert(std::string::String::from("gpt-4o-mini"), ModelPrice::new(0.15, 0.6));
+/// let mut accumulator = CostAccumulator::new(prices);
+///
+/// let mut metrics = InferenceMetrics::new("decomposition".to_string(), "openai".to_string(), "gpt-4o-mini".to_string(), std::option::Option::None);
+/// metrics.record_completion(std::time::Duration::from_millis(800), 1000, 1000);
+/// accumulator.record(&metrics);
+///
+/// std::assert!((accumulator.total_cost() - 0.75).abs() < 1e-9);
+/// std::assert!(accumulator.unpriced_models().is_empty());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CostAccumulator {
+    prices: std::collections::HashMap<String, ModelPrice>,
+    models: std::collections::BTreeMap<String, ModelCost>,
+}
+
+impl CostAccumulator {
+    /// Creates an empty accumulator using the given model → price table.
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Prices keyed by model name exactly as sent to the provider
+    pub fn new(prices: std::collections::HashMap<String, ModelPrice>) -> Self {
+        Self {
+            prices,
+            models: std::collections::BTreeMap::new(),
+        }
+    }
+
+    /// Adds one inference's token counts to its model's totals.
+    ///
+    /// Failed operations and operations without token counts are ignored.
+    pub fn record(&mut self, metrics: &InferenceMetrics) {
+        if !metrics.success {
+            return;
+        }
+        let (input_tokens, output_tokens) = match (metrics.input_tokens, metrics.output_tokens) {
+            (std::option::Option::Some(input), std::option::Option::Some(output)) => (input, output),
+            _ => return,
+        };
+
+        let price = self.prices.get(&metrics.model).copied();
+        let entry = self.models.entry(metrics.model.clone()).or_default();
+        entry.calls += 1;
+        entry.input_tokens += input_tokens;
+        entry.output_tokens += output_tokens;
+        entry.cost = price.map(|price| price.cost(entry.input_tokens, entry.output_tokens));
+    }
+
+    /// Returns usage and cost per model, ordered by model name.
+    pub fn models(&self) -> &std::collections::BTreeMap<String, ModelCost> {
+        &self.models
+    }
+
+    /// Returns the summed cost of all priced models.
+    pub fn total_cost(&self) -> f64 {
+        self.models.values().filter_map(|model| model.cost).sum()
+    }
+
+    /// Returns the models that were used but have no configured price.
+    pub fn unpriced_models(&self) -> std::vec::Vec<&str> {
+        self.models
+            .iter()
+            .filter(|(_, model)| model.cost.is_none())
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+}
+
+impl std::fmt::Display for CostAccumulator {
+    /// Formats a per-model cost summary followed by the run total.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.models.is_empty() {
+            return writeln!(f, "  No LLM token usage recorded");
+        }
+        for (name, model) in &self.models {
+            let cost = match model.cost {
+                std::option::Option::Some(cost) => std::format!("${:.4}", cost),
+                std::option::Option::None => std::string::String::from("unpriced"),
+            };
+            writeln!(
+                f,
+                "  {}: {} call{}, {} input + {} output tokens, {}",
+                name,
+                model.calls,
+                if model.calls == 1 { "" } else { "s" },
+                model.input_tokens,
+                model.output_tokens,
+                cost
+            )?;
+        }
+        let unpriced = self.unpriced_models();
+        if unpriced.is_empty() {
+            writeln!(f, "  Total: ${:.4}", self.total_cost())
+        } else {
+            writeln!(
+                f,
+                "  Total: ${:.4} (excludes unpriced: {}; add them to `pricing` in .rigger/config.json)",
+                self.total_cost(),
+                unpriced.join(", ")
+            )
+        }
+    }
+}
+
+/// A point in an adapter's retry loop, recorded per provider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum RetryEvent {
+    /// A failed call is being retried (one event per retry, not counting the first call)
+    Attempt,
+
+    /// A call succeeded after at least one retry
+    SucceededAfterRetry,
+
+    /// A call failed after its last allowed retry
+    Exhausted,
+}
+
+/// Retry rate (retries per call) at or above which a provider is reported as flaky.
+pub const FLAKY_RETRY_RATE: f64 = 0.2;
+
+/// Retry counters for one provider.
+///
+/// # Examples
+///
+/// ```
+/// use task_orchestrator::domain::performance_metrics::{RetryCounters, RetryEvent};
+///
+/// let mut counters = RetryCounters::default();
+/// counters.record(RetryEvent::Attempt);
+/// counters.record(RetryEvent::SucceededAfterRetry);
+///
+/// std::assert_eq!(counters.attempts, 1);
+/// std::assert_eq!(counters.successes_after_retry, 1);
+/// std::assert!(counters.is_flaky(4));
+/// std::assert!(!counters.is_flaky(10));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RetryCounters {
+    /// Retries made (calls beyond the first).
+    pub attempts: u64,
+
+    /// Calls that succeeded after at least one retry.
+    pub successes_after_retry: u64,
+
+    /// Calls that failed after exhausting their retries.
+    pub exhausted_retries: u64,
+}
+
+impl RetryCounters {
+    /// Adds one retry loop event to the counters.
+    pub fn record(&mut self, event: RetryEvent) {
+        match event {
+            RetryEvent::Attempt => self.attempts += 1,
+            RetryEvent::SucceededAfterRetry => self.successes_after_retry += 1,
+            RetryEvent::Exhausted => self.exhausted_retries += 1,
+        }
+    }
+
+    /// Returns retries per call, or None when no calls were recorded.
+    ///
+    /// # Arguments
+    ///
+    /// * `calls` - Number of calls made to the provider
+    pub fn retry_rate(&self, calls: usize) -> std::option::Option<f64> {
+        if calls == 0 {
+            return std::option::Option::None;
+        }
+        std::option::Option::Some(self.attempts as f64 / calls as f64)
+    }
+
+    /// Returns true if any call exhausted its retries or the retry rate
+    /// reaches `FLAKY_RETRY_RATE`.
+    ///
+    /// # Arguments
+    ///
+    /// * `calls` - Number of calls made to the provider
+    pub fn is_flaky(&self, calls: usize) -> bool {
+        self.exhausted_retries > 0 || self.retry_rate(calls).is_some_and(|rate| rate >= FLAKY_RETRY_RATE)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -683,4 +927,82 @@ mod tests {
         std::assert_eq!(aggregate.success_count, 1);
         std::assert_eq!(aggregate.failure_count, 1);
     }
+
+    #[test]
+    fn test_cost_accumulator_prices_known_token_counts() {
+        // Test: Validates cost is computed per 1K tokens and summed across calls of a priced model.
+        // Justification: The run summary's dollar figure must match the configured price table.
+        let mut prices = std::collections::HashMap::new();
+        prices.insert("gpt-4o".to_string(), ModelPrice::new(2.5, 10.0));
+        let mut accumulator = CostAccumulator::new(prices);
+
+        for (input, output) in [(1200, 300), (800, 200)] {
+            let mut metrics = InferenceMetrics::new("decomposition".to_string(), "openai".to_string(), "gpt-4o".to_string(), std::option::Option::None);
+            metrics.record_completion(std::time::Duration::from_millis(500), input, output);
+            accumulator.record(&metrics);
+        }
+
+        // 2,000 input tokens * $2.50/1K + 500 output tokens * $10/1K = $5.00 + $5.00
+        let model = &accumulator.models()["gpt-4o"];
+        std::assert_eq!(model.calls, 2);
+        std::assert_eq!(model.input_tokens, 2000);
+        std::assert_eq!(model.output_tokens, 500);
+        std::assert!((accumulator.total_cost() - 10.0).abs() < 1e-9);
+        std::assert!(accumulator.unpriced_models().is_empty());
+        std::assert!(accumulator.to_string().contains("Total: $10.0000"));
+    }
+
+    #[test]
+    fn test_cost_accumulator_reports_unpriced_models() {
+        // Test: Validates a model missing from the price table is reported as unpriced and excluded from the total.
+        // Justification: Treating unknown models as free would understate what a run costs.
+        let mut prices = std::collections::HashMap::new();
+        prices.insert("gpt-4o-mini".to_string(), ModelPrice::new(0.15, 0.6));
+        let mut accumulator = CostAccumulator::new(prices);
+
+        let mut priced = InferenceMetrics::new("enhancement".to_string(), "openai".to_string(), "gpt-4o-mini".to_string(), std::option::Option::None);
+        priced.record_completion(std::time::Duration::from_millis(500), 1000, 1000);
+        accumulator.record(&priced);
+
+        let mut unpriced = InferenceMetrics::new("enhancement".to_string(), "ollama".to_string(), "llama3.2".to_string(), std::option::Option::None);
+        unpriced.record_completion(std::time::Duration::from_millis(500), 4000, 2000);
+        accumulator.record(&unpriced);
+
+        let mut failed = InferenceMetrics::new("enhancement".to_string(), "ollama".to_string(), "mistral".to_string(), std::option::Option::None);
+        failed.record_failure("Timeout".to_string());
+        accumulator.record(&failed);
+
+        std::assert_eq!(accumulator.models()["llama3.2"].cost, std::option::Option::None);
+        std::assert_eq!(accumulator.unpriced_models(), std::vec!["llama3.2"]);
+        std::assert!((accumulator.total_cost() - 0.75).abs() < 1e-9);
+        std::assert!(!accumulator.models().contains_key("mistral"));
+
+        let summary = accumulator.to_string();
+        std::assert!(summary.contains("llama3.2: 1 call, 4000 input + 2000 output tokens, unpriced"));
+        std::assert!(summary.contains("excludes unpriced: llama3.2"));
+    }
+
+    #[test]
+    fn test_retry_counters_fail_then_succeed_and_always_fail() {
+        // Test: Validates counters across a fail-then-succeed call and an always-fail call with two retries.
+        // Justification: The three counters must separate recovered calls from exhausted ones to spot flaky providers.
+        let mut counters = RetryCounters::default();
+
+        // Call 1: fails once, then succeeds
+        counters.record(RetryEvent::Attempt);
+        counters.record(RetryEvent::SucceededAfterRetry);
+
+        // Call 2: fails on every attempt
+        counters.record(RetryEvent::Attempt);
+        counters.record(RetryEvent::Attempt);
+        counters.record(RetryEvent::Exhausted);
+
+        std::assert_eq!(
+            counters,
+            RetryCounters { attempts: 3, successes_after_retry: 1, exhausted_retries: 1 }
+        );
+        std::assert_eq!(counters.retry_rate(2), std::option::Option::Some(1.5));
+        std::assert_eq!(counters.retry_rate(0), std::option::Option::None);
+        std::assert!(counters.is_flaky(100), "an exhausted call always marks the provider flaky");
+    }
 }
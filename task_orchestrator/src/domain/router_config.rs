@@ -0,0 +1,129 @@
+//! Confidence threshold and fallback route for the semantic router.
+//!
+//! The router commits to its classification only when its confidence reaches
+//! `confidence_threshold`. Below it the task takes `fallback_route` instead of
+//! a guess; the "clarify" route ends the run after the router, leaving the
+//! task unchanged. `rig do` reads both values from the `orchestration`
+//! section of the project config (see `from_orchestration_config`), the graph
+//! runtime from the environment (see `from_env`), and either can be set per
+//! node or runner in code. The default threshold of 0.0 never falls back, so
+//! routing is unchanged until a threshold is configured.
+//!
+//! Revision History
+//! - 2026-10-18T04:30:00Z @AI: Add the "clarify" route and from_orchestration_config() reading the project config.
+//! - 2026-10-17T21:30:00Z @AI: Default the threshold to 0.0 so unconfigured routing matches the plain classification.
+//! - 2026-10-17T07:30:00Z @AI: Initial RouterConfig with env loading and route validation.
+
+/// Routes the orchestration graph can take after the router.
+pub const ROUTES: &[&str] = rigger_core::config::ROUTER_ROUTES;
+
+/// Route that stops the run after the router so the task can be clarified.
+pub const CLARIFY_ROUTE: &str = "clarify";
+
+/// Confidence threshold and fallback route for SemanticRouterNode.
+///
+/// # Examples
+///
+/// ```
+/// # use task_orchestrator::domain::router_config::RouterConfig;
+/// let config = RouterConfig::new(0.5, "decompose").unwrap();
+/// std::assert_eq!(config.fallback_route, "decompose");
+/// std::assert!(RouterConfig::new(0.5, "clarify").is_ok());
+/// std::assert!(RouterConfig::new(0.5, "review").is_err());
+/// std::assert!(RouterConfig::new(1.5, "enhance").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct RouterConfig {
+    /// Minimum confidence (0.0 to 1.0) for the router to commit to its classification.
+    pub confidence_threshold: f64,
+
+    /// Route taken when confidence is below the threshold; one of `ROUTES`.
+    pub fallback_route: String,
+}
+
+impl RouterConfig {
+    /// Confidence below which the router falls back by default.
+    ///
+    /// Confidence is never negative, so by default the router always commits
+    /// to its classification.
+    pub const DEFAULT_CONFIDENCE_THRESHOLD: f64 = 0.0;
+
+    /// Route taken by default when the router is unsure.
+    ///
+    /// Enhancement adds detail to the task without splitting it, the cheaper
+    /// path to undo if the task turns out to be complex.
+    pub const DEFAULT_FALLBACK_ROUTE: &'static str = "enhance";
+
+    /// Creates a validated config.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the threshold is outside 0.0 to 1.0 or the route
+    /// is not one of `ROUTES`.
+    pub fn new(confidence_threshold: f64, fallback_route: &str) -> std::result::Result<Self, String> {
+        if !(0.0..=1.0).contains(&confidence_threshold) {
+            return std::result::Result::Err(std::format!(
+                "Router confidence threshold must be between 0.0 and 1.0, got {}",
+                confidence_threshold
+            ));
+        }
+        if !ROUTES.contains(&fallback_route) {
+            return std::result::Result::Err(std::format!(
+                "Unknown router fallback route '{}'; expected one of: {}",
+                fallback_route,
+                ROUTES.join(", ")
+            ));
+        }
+        std::result::Result::Ok(RouterConfig {
+            confidence_threshold,
+            fallback_route: std::string::String::from(fallback_route),
+        })
+    }
+
+    /// Loads the config from `ROUTER_CONFIDENCE_THRESHOLD` and `ROUTER_FALLBACK_ROUTE`.
+    ///
+    /// Unset variables keep their defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a variable is set to an invalid value.
+    pub fn from_env() -> std::result::Result<Self, String> {
+        Self::with_env_overrides(Self::DEFAULT_CONFIDENCE_THRESHOLD, Self::DEFAULT_FALLBACK_ROUTE)
+    }
+
+    /// Loads the config from `orchestration.router_confidence_threshold` and
+    /// `orchestration.router_fallback_route` of the project config.
+    ///
+    /// `ROUTER_CONFIDENCE_THRESHOLD` and `ROUTER_FALLBACK_ROUTE` still
+    /// override the configured values when set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configured or overriding values are invalid.
+    pub fn from_orchestration_config(
+        config: &rigger_core::config::OrchestrationConfig,
+    ) -> std::result::Result<Self, String> {
+        Self::with_env_overrides(config.router_confidence_threshold, &config.router_fallback_route)
+    }
+
+    fn with_env_overrides(confidence_threshold: f64, fallback_route: &str) -> std::result::Result<Self, String> {
+        let confidence_threshold = match std::env::var("ROUTER_CONFIDENCE_THRESHOLD") {
+            std::result::Result::Ok(value) => value
+                .trim()
+                .parse::<f64>()
+                .map_err(|e| std::format!("Invalid ROUTER_CONFIDENCE_THRESHOLD '{}': {}", value, e))?,
+            std::result::Result::Err(_) => confidence_threshold,
+        };
+        let fallback_route = std::env::var("ROUTER_FALLBACK_ROUTE").unwrap_or_else(|_| std::string::String::from(fallback_route));
+        Self::new(confidence_threshold, fallback_route.trim())
+    }
+}
+
+impl std::default::Default for RouterConfig {
+    fn default() -> Self {
+        RouterConfig {
+            confidence_threshold: Self::DEFAULT_CONFIDENCE_THRESHOLD,
+            fallback_route: std::string::String::from(Self::DEFAULT_FALLBACK_ROUTE),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Grading of an agent's answer to a comprehension test.
+//!
+//! The expected answer is split into key points (see
+//! `ComprehensionTest::key_points`). Each point is scored by how many of its
+//! significant words appear in the answer, and the grade's score is the mean
+//! over all points. Matching is deterministic word overlap, not an LLM call,
+//! so grading is free and reproducible; it rewards an answer that names the
+//! right things, not one that phrases them well.
+//!
+//! Revision History
+//! - 2026-10-17T07:10:00Z @AI: Initial TestGrade and KeyPointFeedback with key point coverage scoring.
+
+/// Share of a key point's significant words an answer must contain for the point to count as covered.
+const POINT_COVERAGE: f64 = 0.5;
+
+/// Words ignored when matching key points, since they carry no content.
+const STOP_WORDS: &[&str] = &[
+    "the", "and", "for", "with", "that", "this", "from", "are", "was", "were", "will", "into", "its", "has",
+    "have", "not", "but", "all", "any", "can", "should", "must", "our", "your", "their", "them", "they",
+];
+
+/// How well an answer covered one key point.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct KeyPointFeedback {
+    /// The key point from the expected answer.
+    pub key_point: String,
+
+    /// Share of the point's significant words found in the answer (0.0 to 1.0).
+    pub coverage: f64,
+
+    /// Whether coverage reached the per-point bar.
+    pub covered: bool,
+
+    /// Significant words of the point that the answer did not mention.
+    pub missing: std::vec::Vec<String>,
+}
+
+/// Score and per-point feedback for one answer.
+///
+/// # Examples
+///
+/// ```
+/// # use task_orchestrator::domain::test_grade::TestGrade;
+/// let points = [std::string::String::from("Validate the JWT"), std::string::String::from("Return 401 on failure")];
+/// let grade = TestGrade::grade(&points, "We validate the JWT and return 401 when it fails", 0.7);
+///
+/// std::assert!(grade.passed);
+/// std::assert!(grade.feedback.iter().all(|point| point.covered));
+/// ```
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TestGrade {
+    /// Mean coverage over all key points (0.0 to 1.0).
+    pub score: f64,
+
+    /// Threshold the score was compared against.
+    pub threshold: f64,
+
+    /// Whether the score reached the threshold.
+    pub passed: bool,
+
+    /// Feedback for each key point, in expected-answer order.
+    pub feedback: std::vec::Vec<KeyPointFeedback>,
+}
+
+impl TestGrade {
+    /// Grades `answer` against `key_points`.
+    ///
+    /// With no key points there is nothing to miss, and the score is 1.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `key_points` - Points the answer is expected to cover
+    /// * `answer` - The agent's answer
+    /// * `threshold` - Minimum score to pass (0.0 to 1.0)
+    pub fn grade(key_points: &[String], answer: &str, threshold: f64) -> Self {
+        let answer_words: std::collections::HashSet<String> = words(answer).into_iter().collect();
+        let feedback: std::vec::Vec<KeyPointFeedback> = key_points
+            .iter()
+            .map(|point| {
+                let mut seen = std::collections::HashSet::new();
+                let mut expected = significant_words(point);
+                expected.retain(|word| seen.insert(word.clone()));
+                let missing: std::vec::Vec<String> =
+                    expected.iter().filter(|word| !answer_words.contains(*word)).cloned().collect();
+                let coverage = if expected.is_empty() {
+                    1.0
+                } else {
+                    (expected.len() - missing.len()) as f64 / expected.len() as f64
+                };
+                KeyPointFeedback {
+                    key_point: point.clone(),
+                    coverage,
+                    covered: coverage >= POINT_COVERAGE,
+                    missing,
+                }
+            })
+            .collect();
+
+        let score = if feedback.is_empty() {
+            1.0
+        } else {
+            feedback.iter().map(|point| point.coverage).sum::<f64>() / feedback.len() as f64
+        };
+        TestGrade { score, threshold, passed: score >= threshold, feedback }
+    }
+}
+
+/// Lowercased alphanumeric words of `text`.
+fn words(text: &str) -> std::vec::Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Content words of a key point; all words when it has no content words (e.g. "4").
+fn significant_words(point: &str) -> std::vec::Vec<String> {
+    let all = words(point);
+    let significant: std::vec::Vec<String> = all
+        .iter()
+        .filter(|word| word.chars().count() >= 3 && !STOP_WORDS.contains(&word.as_str()))
+        .cloned()
+        .collect();
+    if significant.is_empty() { all } else { significant }
+}
+
+#[cfg(test)]
+mod tests {
+    fn points(points: &[&str]) -> std::vec::Vec<String> {
+        points.iter().map(|point| std::string::String::from(*point)).collect()
+    }
+
+    #[test]
+    fn test_partial_answer_scores_covered_share_and_lists_missing_words() {
+        // Test: Validates the score is the mean point coverage and feedback names the missing words.
+        // Justification: The feedback is what tells the enhancement retry what to add.
+        let grade = super::TestGrade::grade(
+            &points(&["Hash passwords with bcrypt", "Lock the account after five failures"]),
+            "Passwords are hashed with bcrypt before storage.",
+            0.7,
+        );
+
+        std::assert!(grade.feedback[0].covered);
+        std::assert!(!grade.feedback[1].covered);
+        std::assert_eq!(grade.feedback[1].missing, ["lock", "account", "after", "five", "failures"]);
+        std::assert!(grade.score < 0.7);
+        std::assert!(!grade.passed);
+    }
+
+    #[test]
+    fn test_short_and_empty_key_points() {
+        // Test: Validates a key point made only of short words still has to appear, and no key points scores 1.0.
+        // Justification: Numeric answers ("4") would otherwise always count as covered.
+        std::assert!(!super::TestGrade::grade(&points(&["4"]), "5", 0.5).passed);
+        std::assert!(super::TestGrade::grade(&points(&["4"]), "It is 4.", 0.5).passed);
+        std::assert_eq!(super::TestGrade::grade(&[], "anything", 0.9).score, 1.0);
+    }
+}
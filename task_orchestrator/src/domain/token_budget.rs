@@ -0,0 +1,254 @@
+//! Per-run token and cost budget.
+//!
+//! A `TokenBudget` is shared (behind an `Arc`) by everything that makes
+//! provider calls during one run. Adapters record each call's token usage;
+//! before the next call the budget projects that call's usage from the
+//! average call so far and refuses it with `BudgetExceeded` if the projection
+//! would cross `max_tokens` or `max_cost`. Refusing before the call, rather
+//! than failing after it, leaves work completed earlier in the run intact.
+//!
+//! Adapters whose client does not report token counts record calls with
+//! `record_exchange`, which estimates them from the prompt and reply text.
+//!
+//! Revision History
+//! - 2026-10-17T21:20:00Z @AI: Add record_exchange for adapters whose client does not report token counts.
+//! - 2026-10-16T20:50:00Z @AI: Initial TokenBudget with projected pre-call checks and BudgetExceeded.
+
+/// Error returned when the next provider call would exceed the run's budget.
+///
+/// Carries the totals accumulated before the refused call.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetExceeded {
+    /// Number of provider calls completed in the run.
+    pub calls: usize,
+
+    /// Input plus output tokens used so far.
+    pub tokens_used: usize,
+
+    /// Dollar cost so far (unpriced models excluded).
+    pub cost_used: f64,
+
+    /// Token limit, if one is set.
+    pub max_tokens: std::option::Option<usize>,
+
+    /// Cost limit, if one is set.
+    pub max_cost: std::option::Option<f64>,
+}
+
+impl std::fmt::Display for BudgetExceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BudgetExceeded: run halted after {} provider calls; {} tokens", self.calls, self.tokens_used)?;
+        if let std::option::Option::Some(max_tokens) = self.max_tokens {
+            write!(f, " of {}", max_tokens)?;
+        }
+        write!(f, " and ${:.4}", self.cost_used)?;
+        if let std::option::Option::Some(max_cost) = self.max_cost {
+            write!(f, " of ${:.4}", max_cost)?;
+        }
+        write!(f, " used")
+    }
+}
+
+impl std::error::Error for BudgetExceeded {}
+
+/// Characters per token used to estimate the usage of calls without token counts.
+const CHARS_PER_TOKEN: usize = 4;
+
+/// Token and cost limits for one run, with the usage recorded against them.
+///
+/// Calls to models without a price count toward `max_tokens` but not
+/// `max_cost`.
+///
+/// # Examples
+///
+/// ```
+/// use task_orchestrator::domain::performance_metrics::InferenceMetrics;
+/// use task_orchestrator::domain::token_budget::TokenBudget;
+///
+/// let budget = TokenBudget::new(std::option::Option::Some(1000), std::option::Option::None, std::collections::HashMap::new());
+/// std::assert!(budget.check().is_ok());
+///
+/// let mut metrics = InferenceMetrics::new("enhancement".to_string(), "ollama".to_string(), "llama3.2".to_string(), std::option::Option::None);
+/// metrics.record_completion(std::time::Duration::from_millis(100), 400, 200);
+/// budget.record(&metrics);
+///
+/// // 600 used + ~600 projected for the next call would exceed 1000
+/// let exceeded = budget.check().unwrap_err();
+/// std::assert_eq!(exceeded.tokens_used, 600);
+/// ```
+#[derive(Debug)]
+pub struct TokenBudget {
+    max_tokens: std::option::Option<usize>,
+    max_cost: std::option::Option<f64>,
+    usage: std::sync::Mutex<crate::domain::performance_metrics::CostAccumulator>,
+}
+
+impl TokenBudget {
+    /// Creates a budget with no usage recorded.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_tokens` - Maximum input plus output tokens for the run
+    /// * `max_cost` - Maximum dollar cost for the run
+    /// * `prices` - Model prices used to cost recorded calls
+    pub fn new(
+        max_tokens: std::option::Option<usize>,
+        max_cost: std::option::Option<f64>,
+        prices: std::collections::HashMap<String, crate::domain::performance_metrics::ModelPrice>,
+    ) -> Self {
+        Self {
+            max_tokens,
+            max_cost,
+            usage: std::sync::Mutex::new(crate::domain::performance_metrics::CostAccumulator::new(prices)),
+        }
+    }
+
+    /// Returns true if neither limit is set.
+    pub fn is_unlimited(&self) -> bool {
+        self.max_tokens.is_none() && self.max_cost.is_none()
+    }
+
+    /// Records the token usage of a completed provider call.
+    pub fn record(&self, metrics: &crate::domain::performance_metrics::InferenceMetrics) {
+        if let std::result::Result::Ok(mut usage) = self.usage.lock() {
+            usage.record(metrics);
+        }
+    }
+
+    /// Records a completed provider call from its prompt and reply text.
+    ///
+    /// Token counts are estimated at four characters per token, for clients
+    /// (such as Rig's `Prompt`) that return only the reply.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - Operation that made the call (e.g., "enhancement")
+    /// * `provider` - Provider that served the call (e.g., "ollama")
+    /// * `model` - Model that served the call, used to look up its price
+    /// * `prompt` - Text sent to the model, including the preamble
+    /// * `reply` - Text the model returned
+    pub fn record_exchange(&self, operation: &str, provider: &str, model: &str, prompt: &str, reply: &str) {
+        let mut metrics = crate::domain::performance_metrics::InferenceMetrics::new(
+            String::from(operation),
+            String::from(provider),
+            String::from(model),
+            std::option::Option::None,
+        );
+        metrics.record_completion(
+            std::time::Duration::ZERO,
+            prompt.chars().count().div_ceil(CHARS_PER_TOKEN),
+            reply.chars().count().div_ceil(CHARS_PER_TOKEN),
+        );
+        self.record(&metrics);
+    }
+
+    /// Returns the usage recorded so far, priced per model.
+    pub fn usage(&self) -> crate::domain::performance_metrics::CostAccumulator {
+        self.usage.lock().map(|usage| usage.clone()).unwrap_or_default()
+    }
+
+    /// Checks whether another provider call fits in the budget.
+    ///
+    /// The next call is assumed to use as many tokens, and cost as much, as
+    /// the average call so far; the first call is always allowed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BudgetExceeded` with the accumulated totals if the projected
+    /// usage would exceed either limit.
+    pub fn check(&self) -> std::result::Result<(), BudgetExceeded> {
+        let usage = self.usage();
+        let calls: usize = usage.models().values().map(|model| model.calls).sum();
+        let tokens_used: usize = usage
+            .models()
+            .values()
+            .map(|model| model.input_tokens + model.output_tokens)
+            .sum();
+        let cost_used = usage.total_cost();
+        if calls == 0 {
+            return std::result::Result::Ok(());
+        }
+
+        let projected_tokens = tokens_used + tokens_used.div_ceil(calls);
+        let projected_cost = cost_used + cost_used / calls as f64;
+        let over_tokens = self.max_tokens.is_some_and(|max_tokens| projected_tokens > max_tokens);
+        let over_cost = self.max_cost.is_some_and(|max_cost| projected_cost > max_cost);
+        if over_tokens || over_cost {
+            return std::result::Result::Err(BudgetExceeded {
+                calls,
+                tokens_used,
+                cost_used,
+                max_tokens: self.max_tokens,
+                max_cost: self.max_cost,
+            });
+        }
+        std::result::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn completed_call(model: &str, input_tokens: usize, output_tokens: usize) -> crate::domain::performance_metrics::InferenceMetrics {
+        let mut metrics = crate::domain::performance_metrics::InferenceMetrics::new(
+            std::string::String::from("enhancement"),
+            std::string::String::from("openai"),
+            std::string::String::from(model),
+            std::option::Option::None,
+        );
+        metrics.record_completion(std::time::Duration::from_millis(100), input_tokens, output_tokens);
+        metrics
+    }
+
+    #[test]
+    fn test_cost_limit_refuses_call_projected_to_cross_it() {
+        // Test: Validates the check projects the next call from the average call and reports accumulated totals.
+        // Justification: Checking only spent usage would let the call that crosses the limit through.
+        let mut prices = std::collections::HashMap::new();
+        prices.insert(
+            std::string::String::from("gpt-4o"),
+            crate::domain::performance_metrics::ModelPrice::new(0.0025, 0.01),
+        );
+        let budget = super::TokenBudget::new(std::option::Option::None, std::option::Option::Some(0.06), prices);
+
+        // 1000 input + 1000 output tokens = $0.0125 per call
+        budget.record(&completed_call("gpt-4o", 1000, 1000));
+        budget.record(&completed_call("gpt-4o", 1000, 1000));
+        budget.record(&completed_call("gpt-4o", 1000, 1000));
+        std::assert!(budget.check().is_ok(), "$0.0375 spent + $0.0125 projected fits in $0.06");
+
+        budget.record(&completed_call("gpt-4o", 1000, 1000));
+        let exceeded = budget.check().unwrap_err();
+        std::assert_eq!(exceeded.calls, 4);
+        std::assert_eq!(exceeded.tokens_used, 8000);
+        std::assert!((exceeded.cost_used - 0.05).abs() < 1e-9);
+        std::assert!(exceeded.to_string().contains("$0.0500 of $0.0600"));
+    }
+
+    #[test]
+    fn test_unlimited_budget_and_unpriced_models_never_refuse() {
+        // Test: Validates calls are allowed without limits, and unpriced calls do not count toward max_cost.
+        // Justification: A budget must not halt runs the user never limited.
+        let unlimited = super::TokenBudget::new(std::option::Option::None, std::option::Option::None, std::collections::HashMap::new());
+        unlimited.record(&completed_call("llama3.2", 100_000, 100_000));
+        std::assert!(unlimited.is_unlimited());
+        std::assert!(unlimited.check().is_ok());
+
+        let cost_only = super::TokenBudget::new(std::option::Option::None, std::option::Option::Some(0.01), std::collections::HashMap::new());
+        cost_only.record(&completed_call("llama3.2", 100_000, 100_000));
+        std::assert!(cost_only.check().is_ok());
+    }
+
+    #[test]
+    fn test_record_exchange_estimates_tokens_from_text() {
+        // Test: Validates a call recorded from its text counts about four characters per token.
+        // Justification: Ollama and MLX replies carry no token counts, yet must still count toward max_tokens.
+        let budget = super::TokenBudget::new(std::option::Option::Some(100), std::option::Option::None, std::collections::HashMap::new());
+
+        budget.record_exchange("enhancement", "ollama", "llama3.2", &"p".repeat(160), &"r".repeat(41));
+
+        let usage = budget.usage();
+        let model = &usage.models()["llama3.2"];
+        std::assert_eq!((model.calls, model.input_tokens, model.output_tokens), (1, 40, 11));
+        std::assert_eq!(budget.check().unwrap_err().tokens_used, 51);
+    }
+}
@@ -0,0 +1,115 @@
+//! Events describing the path an orchestration run took through the graph.
+//!
+//! The graph runtime (see `run_task_with_flow_traced`) sends one event when a
+//! node starts, one when it finishes (with its duration), and one for every
+//! conditional edge it follows (with the decision and the node's reason for
+//! it). The events are plain data, so the CLI can print them and tests can
+//! assert on the recorded path.
+//!
+//! Revision History
+//! - 2026-10-17T07:50:00Z @AI: Initial TraceEvent with node-entered, node-exited, and branch-taken events.
+
+/// One step of a traced orchestration run.
+///
+/// Node names are the last segment of the graph task ID, e.g.
+/// `SemanticRouterTaskShim`.
+///
+/// # Examples
+///
+/// ```
+/// # use task_orchestrator::domain::trace_event::TraceEvent;
+/// let events = [
+///     TraceEvent::entered("crate::shims::RouterShim"),
+///     TraceEvent::exited("crate::shims::RouterShim", std::time::Duration::from_millis(4)),
+///     TraceEvent::branch_taken("crate::shims::RouterShim", "crate::shims::EndTask", "decompose", std::option::Option::None),
+///     TraceEvent::entered("crate::shims::EndTask"),
+/// ];
+///
+/// std::assert_eq!(TraceEvent::path(&events), ["RouterShim", "EndTask"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TraceEvent {
+    /// A node started running.
+    NodeEntered {
+        node: String,
+        at: chrono::DateTime<chrono::Utc>,
+    },
+
+    /// A node finished running.
+    NodeExited {
+        node: String,
+        at: chrono::DateTime<chrono::Utc>,
+        duration_ms: u64,
+    },
+
+    /// A node's decision selected the next node.
+    BranchTaken {
+        from: String,
+        to: String,
+        decision: String,
+        reason: std::option::Option<String>,
+        at: chrono::DateTime<chrono::Utc>,
+    },
+}
+
+impl TraceEvent {
+    /// Creates a NodeEntered event stamped now.
+    pub fn entered(task_id: &str) -> Self {
+        TraceEvent::NodeEntered { node: node_name(task_id), at: chrono::Utc::now() }
+    }
+
+    /// Creates a NodeExited event stamped now.
+    pub fn exited(task_id: &str, duration: std::time::Duration) -> Self {
+        TraceEvent::NodeExited {
+            node: node_name(task_id),
+            at: chrono::Utc::now(),
+            duration_ms: u64::try_from(duration.as_millis()).unwrap_or(u64::MAX),
+        }
+    }
+
+    /// Creates a BranchTaken event stamped now.
+    pub fn branch_taken(from: &str, to: &str, decision: &str, reason: std::option::Option<String>) -> Self {
+        TraceEvent::BranchTaken {
+            from: node_name(from),
+            to: node_name(to),
+            decision: String::from(decision),
+            reason,
+            at: chrono::Utc::now(),
+        }
+    }
+
+    /// Returns the nodes entered, in order: the path the run took.
+    pub fn path(events: &[TraceEvent]) -> std::vec::Vec<String> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                TraceEvent::NodeEntered { node, .. } => std::option::Option::Some(node.clone()),
+                _ => std::option::Option::None,
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Display for TraceEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TraceEvent::NodeEntered { node, at } => write!(f, "{} enter  {}", at.format("%H:%M:%S%.3f"), node),
+            TraceEvent::NodeExited { node, at, duration_ms } => {
+                write!(f, "{} exit   {} ({} ms)", at.format("%H:%M:%S%.3f"), node, duration_ms)
+            }
+            TraceEvent::BranchTaken { from, to, decision, reason, at } => {
+                write!(f, "{} branch {} -> {} [{}]", at.format("%H:%M:%S%.3f"), from, to, decision)?;
+                match reason {
+                    std::option::Option::Some(reason) => write!(f, ": {}", reason),
+                    std::option::Option::None => std::result::Result::Ok(()),
+                }
+            }
+        }
+    }
+}
+
+/// Short display name of a graph task ID: its last `::` segment.
+fn node_name(task_id: &str) -> String {
+    String::from(task_id.rsplit("::").next().unwrap_or(task_id))
+}
@@ -25,6 +25,7 @@
 /// Edges:
 /// - router --[routing_decision == "decompose"]--> decompose --> end
 /// - router --[routing_decision == "enhance"]--> enhance -> comprehend -> check
+/// - router --[routing_decision == "clarify"]--> (graph ends; the router returns End)
 /// - check --[routing_decision == "pass"]--> end; else -> enhance (loop)
 pub fn assemble_orchestrator_flow(
     enhancement_port: std::sync::Arc<dyn crate::ports::task_enhancement_port::TaskEnhancementPort>,
@@ -113,6 +114,8 @@ mod tests {
                 question: std::format!("Q for {}", task.title),
                 options: std::option::Option::None,
                 correct_answer: std::string::String::from("A"),
+                description_hash: std::option::Option::None,
+                stale: false,
             };
             std::result::Result::Ok(ct)
         }
@@ -3,25 +3,42 @@
 //! This shim delegates to `CheckTestResultNode::execute` to write a
 //! `routing_decision` of "pass" or "fail" into the `GraphState` and update
 //! task status on pass. It provides a stable surface a graph runtime could
-//! call without changing node internals.
+//! call without changing node internals. As a graph_flow task it reads the
+//! agent's answer from the context's "agent_answer" key and stores the
+//! resulting grade under "test_grade".
 //!
 //! Revision History
+//! - 2026-10-17T07:50:00Z @AI: Write routing_reason to the context for graph traces.
+//! - 2026-10-17T07:10:00Z @AI: Add with_pass_threshold(); pass the agent answer through the flow context and store the grade.
+//! - 2026-10-16T20:10:00Z @AI: Carry the run's correlation ID from the flow context into GraphState.
 //! - 2025-11-15T10:25:00Z @AI: Implement graph_flow::Task for shim; persist decision and task in Context; add Task-impl unit test.
 //! - 2025-11-14T09:27:30Z @AI: Add CheckTestResultTaskShim with run() delegating to node; add unit tests.
 
 /// Shim that mirrors how a graph runtime would invoke the check-test node.
-pub struct CheckTestResultTaskShim;
+pub struct CheckTestResultTaskShim {
+    pass_threshold: f64,
+}
 
 impl CheckTestResultTaskShim {
-    /// Constructs a new CheckTestResultTaskShim.
-    pub fn new() -> Self { CheckTestResultTaskShim }
+    /// Constructs a new CheckTestResultTaskShim with the node's default pass threshold.
+    pub fn new() -> Self {
+        CheckTestResultTaskShim {
+            pass_threshold: crate::graph::nodes::check_test_result_node::CheckTestResultNode::DEFAULT_PASS_THRESHOLD,
+        }
+    }
+
+    /// Sets the minimum grade score an agent answer needs to pass.
+    pub fn with_pass_threshold(mut self, pass_threshold: f64) -> Self {
+        self.pass_threshold = pass_threshold;
+        self
+    }
 
     /// Runs the check by delegating to CheckTestResultNode::execute.
     pub async fn run(
         &self,
         state: crate::graph::state::GraphState,
     ) -> std::result::Result<crate::graph::state::GraphState, std::string::String> {
-        let node = crate::graph::nodes::check_test_result_node::CheckTestResultNode::new();
+        let node = crate::graph::nodes::check_test_result_node::CheckTestResultNode::new().with_pass_threshold(self.pass_threshold);
         crate::graph::nodes::graph_node::GraphNode::execute(&node, state).await
     }
 }
@@ -46,16 +63,28 @@ impl graph_flow::Task for CheckTestResultTaskShim {
                 task_manager::domain::task::Task::from_action_item(&ai, std::option::Option::None)
             }
         };
-        let state_in = crate::graph::state::GraphState::new(task);
+        let mut state_in = crate::graph::state::GraphState::new(task);
+        if let std::option::Option::Some(correlation_id) = context.get::<crate::domain::correlation_id::CorrelationId>("correlation_id").await {
+            state_in = state_in.with_correlation_id(correlation_id);
+        }
+        if let std::option::Option::Some(answer) = context.get::<std::string::String>("agent_answer").await {
+            state_in = state_in.with_agent_answer(answer);
+        }
         let state_out = match CheckTestResultTaskShim::run(self, state_in).await {
             std::result::Result::Ok(s) => s,
             std::result::Result::Err(e) => {
                 return std::result::Result::Err(graph_flow::GraphError::TaskExecutionFailed(e))
             }
         };
+        if let std::option::Option::Some(grade) = state_out.test_grade.clone() {
+            context.set("test_grade", grade).await;
+        }
         // Persist decision and updated task into context for downstream usage
         if let std::option::Option::Some(decision) = state_out.routing_decision.clone() {
             context.set("routing_decision", decision.clone()).await;
+            if let std::option::Option::Some(reason) = state_out.routing_reason.clone() {
+                context.set("routing_reason", reason).await;
+            }
             context.set("task", state_out.task.clone()).await;
             return std::result::Result::Ok(graph_flow::TaskResult::new(
                 std::option::Option::Some(decision),
@@ -99,6 +128,8 @@ mod tests {
             question: std::string::String::from("Short?"),
             options: std::option::Option::None,
             correct_answer: std::string::String::from("Yes"),
+            description_hash: std::option::Option::None,
+            stale: false,
         };
         task.comprehension_tests = std::option::Option::Some(vec![ct]);
         let state = crate::graph::state::GraphState::new(task);
@@ -121,4 +152,39 @@ mod tests {
         let task_after: std::option::Option<task_manager::domain::task::Task> = ctx.get("task").await;
         std::assert!(task_after.is_some());
     }
+
+    #[tokio::test]
+    async fn test_task_impl_grades_agent_answer_from_context() {
+        // Test: Validates the graph task grades the context's agent_answer and stores the grade next to the decision.
+        // Justification: In the graph runtime the answer and grade travel through the context, not GraphState.
+        let ai = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Title"),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let mut task = task_manager::domain::task::Task::from_action_item(&ai, std::option::Option::None);
+        task.comprehension_tests = std::option::Option::Some(vec![task_manager::domain::comprehension_test::ComprehensionTest {
+            test_id: std::string::String::from("ct-1"),
+            task_id: task.id.clone(),
+            timestamp: chrono::Utc::now(),
+            test_type: std::string::String::from("short_answer"),
+            question: std::string::String::from("Short?"),
+            options: std::option::Option::None,
+            correct_answer: std::string::String::from("Rotate the signing keys; revoke old sessions"),
+            description_hash: std::option::Option::None,
+            stale: false,
+        }]);
+        let ctx = graph_flow::Context::new();
+        ctx.set("task", task).await;
+        ctx.set("agent_answer", std::string::String::from("Rotate the signing keys.")).await;
+
+        let shim = super::CheckTestResultTaskShim::new().with_pass_threshold(0.9);
+        <super::CheckTestResultTaskShim as graph_flow::Task>::run(&shim, ctx.clone()).await.unwrap();
+
+        let decision: std::option::Option<std::string::String> = ctx.get("routing_decision").await;
+        std::assert_eq!(decision, std::option::Option::Some(std::string::String::from("fail")));
+        let grade: crate::domain::test_grade::TestGrade = ctx.get("test_grade").await.unwrap();
+        std::assert_eq!(grade.score, 0.5);
+        std::assert_eq!(grade.feedback[1].missing, ["revoke", "old", "sessions"]);
+    }
 }
@@ -1,11 +1,15 @@
 //! Shim for ComprehensionTestNode that generates a comprehension test via the port.
 //!
 //! This shim delegates to `ComprehensionTestNode::execute` to append a test
-//! to the task in `GraphState` and advance status to PendingFollowOn. It
+//! to the task in `GraphState` and advance status to PendingFollowOn. The
+//! agent's answer to the test is stored under the context's "agent_answer"
+//! key, where CheckTestResultTaskShim grades it. It
 //! provides a stable call surface for a graph runtime to invoke without
 //! changing node internals.
 //!
 //! Revision History
+//! - 2026-10-18T02:30:00Z @AI: Store the agent's answer in the context's agent_answer key for the check task.
+//! - 2026-10-16T20:10:00Z @AI: Carry the run's correlation ID from the flow context into GraphState.
 //! - 2025-11-15T09:46:10Z @AI: Implement graph_flow::Task; persist updated Task in Context; add Task-impl unit test.
 //! - 2025-11-14T09:27:30Z @AI: Add ComprehensionTestTaskShim with run() delegating to node; add unit test.
 
@@ -49,13 +53,19 @@ impl graph_flow::Task for ComprehensionTestTaskShim {
                 task_manager::domain::task::Task::from_action_item(&ai, std::option::Option::None)
             }
         };
-        let state_in = crate::graph::state::GraphState::new(task);
+        let mut state_in = crate::graph::state::GraphState::new(task);
+        if let std::option::Option::Some(correlation_id) = context.get::<crate::domain::correlation_id::CorrelationId>("correlation_id").await {
+            state_in = state_in.with_correlation_id(correlation_id);
+        }
         let state_out = match ComprehensionTestTaskShim::run(self, state_in).await {
             std::result::Result::Ok(s) => s,
             std::result::Result::Err(e) => return std::result::Result::Err(graph_flow::GraphError::TaskExecutionFailed(e)),
         };
         // Persist updated task for downstream tasks
         context.set("task", state_out.task.clone()).await;
+        if let std::option::Option::Some(answer) = state_out.agent_answer {
+            context.set("agent_answer", answer).await;
+        }
         std::result::Result::Ok(graph_flow::TaskResult::new(std::option::Option::None, graph_flow::NextAction::Continue))
     }
 }
@@ -78,6 +88,8 @@ mod tests {
                 question: std::format!("Q for {}", task.title),
                 options: std::option::Option::None,
                 correct_answer: std::string::String::from("A"),
+                description_hash: std::option::Option::None,
+                stale: false,
             };
             std::result::Result::Ok(ct)
         }
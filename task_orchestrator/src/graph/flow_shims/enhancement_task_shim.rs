@@ -6,6 +6,7 @@
 //! node internals.
 //!
 //! Revision History
+//! - 2026-10-16T20:10:00Z @AI: Carry the run's correlation ID from the flow context into GraphState.
 //! - 2025-11-15T09:45:30Z @AI: Implement graph_flow::Task; persist updated Task in Context; add Task-impl unit test.
 //! - 2025-11-13T09:32:00Z @AI: Add EnhancementTaskShim with run() delegating to node; add unit test.
 
@@ -42,7 +43,10 @@ impl graph_flow::Task for EnhancementTaskShim {
                 task_manager::domain::task::Task::from_action_item(&ai, std::option::Option::None)
             }
         };
-        let state_in = crate::graph::state::GraphState::new(task);
+        let mut state_in = crate::graph::state::GraphState::new(task);
+        if let std::option::Option::Some(correlation_id) = context.get::<crate::domain::correlation_id::CorrelationId>("correlation_id").await {
+            state_in = state_in.with_correlation_id(correlation_id);
+        }
         let state_out = match EnhancementTaskShim::run(self, state_in).await {
             std::result::Result::Ok(s) => s,
             std::result::Result::Err(e) => return std::result::Result::Err(graph_flow::GraphError::TaskExecutionFailed(e)),
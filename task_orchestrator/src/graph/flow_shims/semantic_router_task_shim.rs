@@ -3,8 +3,15 @@
 //! This shim delegates to `SemanticRouterNode::execute` to update the
 //! `routing_decision` in the `GraphState`. It exists to provide a stable API
 //! surface that a graph runtime can call without changing node internals.
+//! A `router_config` entry in the flow context overrides the shim's
+//! RouterConfig for that run. A "clarify" decision ends the graph here, so
+//! the task is returned unchanged for the user to add detail.
 //!
 //! Revision History
+//! - 2026-10-18T04:30:00Z @AI: End the graph on a "clarify" routing decision.
+//! - 2026-10-17T07:50:00Z @AI: Write routing_reason to the context for graph traces.
+//! - 2026-10-17T07:30:00Z @AI: Apply RouterConfig (from the flow context when seeded) and write routing_confidence to the context.
+//! - 2026-10-16T20:10:00Z @AI: Carry the run's correlation ID from the flow context into GraphState.
 //! - 2025-11-23T16:30:00Z @AI: Update shim to inject TriageService for intelligent routing (Phase 3 Sprint 6).
 //! - 2025-11-15T09:21:00Z @AI: Implement graph_flow::Task for shim; add Context round-trip unit test.
 //! - 2025-11-13T09:32:00Z @AI: Add SemanticRouterTaskShim with run() delegating to node; add unit test.
@@ -12,6 +19,7 @@
 /// Shim that mirrors how a graph runtime would invoke the router node.
 pub struct SemanticRouterTaskShim {
     triage_service: task_manager::domain::services::triage_service::TriageService,
+    config: crate::domain::router_config::RouterConfig,
 }
 
 impl SemanticRouterTaskShim {
@@ -19,7 +27,16 @@ impl SemanticRouterTaskShim {
     pub fn new() -> Self {
         let scorer = task_manager::domain::services::complexity_scorer::ComplexityScorer::new();
         let triage_service = task_manager::domain::services::triage_service::TriageService::new(scorer);
-        SemanticRouterTaskShim { triage_service }
+        SemanticRouterTaskShim {
+            triage_service,
+            config: crate::domain::router_config::RouterConfig::default(),
+        }
+    }
+
+    /// Replaces the confidence threshold and fallback route.
+    pub fn with_config(mut self, config: crate::domain::router_config::RouterConfig) -> Self {
+        self.config = config;
+        self
     }
 
     /// Runs the router logic by delegating to SemanticRouterNode::execute.
@@ -27,7 +44,16 @@ impl SemanticRouterTaskShim {
         &self,
         state: crate::graph::state::GraphState,
     ) -> std::result::Result<crate::graph::state::GraphState, std::string::String> {
-        let node = crate::graph::nodes::semantic_router_node::SemanticRouterNode::new(self.triage_service.clone());
+        self.run_with_config(state, self.config.clone()).await
+    }
+
+    async fn run_with_config(
+        &self,
+        state: crate::graph::state::GraphState,
+        config: crate::domain::router_config::RouterConfig,
+    ) -> std::result::Result<crate::graph::state::GraphState, std::string::String> {
+        let node = crate::graph::nodes::semantic_router_node::SemanticRouterNode::new(self.triage_service.clone())
+            .with_config(config);
         crate::graph::nodes::semantic_router_node::SemanticRouterNode::execute(&node, state).await
     }
 }
@@ -46,15 +72,33 @@ impl graph_flow::Task for SemanticRouterTaskShim {
             }
         };
         // Run node logic
-        let state_in = crate::graph::state::GraphState::new(task);
-        let state_out = match SemanticRouterTaskShim::run(self, state_in).await {
+        let mut state_in = crate::graph::state::GraphState::new(task);
+        if let std::option::Option::Some(correlation_id) = context.get::<crate::domain::correlation_id::CorrelationId>("correlation_id").await {
+            state_in = state_in.with_correlation_id(correlation_id);
+        }
+        let config = context
+            .get::<crate::domain::router_config::RouterConfig>("router_config")
+            .await
+            .unwrap_or_else(|| self.config.clone());
+        let state_out = match self.run_with_config(state_in, config).await {
                     std::result::Result::Ok(s) => s,
                     std::result::Result::Err(e) => return std::result::Result::Err(graph_flow::GraphError::TaskExecutionFailed(e)),
                 };
         // Persist routing decision back to context for downstream tasks
+        if let std::option::Option::Some(confidence) = state_out.routing_confidence {
+            context.set("routing_confidence", confidence).await;
+        }
         if let std::option::Option::Some(decision) = state_out.routing_decision.clone() {
             context.set("routing_decision", decision.clone()).await;
-            return std::result::Result::Ok(graph_flow::TaskResult::new(std::option::Option::Some(decision), graph_flow::NextAction::Continue));
+            if let std::option::Option::Some(reason) = state_out.routing_reason.clone() {
+                context.set("routing_reason", reason).await;
+            }
+            let next_action = if decision == crate::domain::router_config::CLARIFY_ROUTE {
+                graph_flow::NextAction::End
+            } else {
+                graph_flow::NextAction::Continue
+            };
+            return std::result::Result::Ok(graph_flow::TaskResult::new(std::option::Option::Some(decision), next_action));
         }
         std::result::Result::Ok(graph_flow::TaskResult::new(std::option::Option::None, graph_flow::NextAction::Continue))
     }
@@ -87,4 +131,37 @@ mod tests {
         let decision: std::option::Option<std::string::String> = ctx.get("routing_decision").await;
         std::assert_eq!(decision, std::option::Option::Some(std::string::String::from("enhance")));
     }
+
+    #[tokio::test]
+    async fn test_task_impl_uses_router_config_from_context() {
+        // Test: Validates a RouterConfig seeded in the context overrides the shim's default and the confidence is written back.
+        // Justification: run_flow loads the config from the environment and hands it to the graph through the context.
+        let shim = super::SemanticRouterTaskShim::new();
+        let ctx = graph_flow::Context::new();
+        ctx.set("task_title", std::string::String::from("Fix typo")).await;
+        ctx.set("router_config", crate::domain::router_config::RouterConfig::new(0.5, "decompose").unwrap()).await;
+
+        <super::SemanticRouterTaskShim as graph_flow::Task>::run(&shim, ctx.clone()).await.unwrap();
+
+        // "Fix typo" scores 5 (no persona, no due date): confidence 0.43 < 0.5, so the fallback wins
+        let decision: std::option::Option<std::string::String> = ctx.get("routing_decision").await;
+        std::assert_eq!(decision, std::option::Option::Some(std::string::String::from("decompose")));
+        let confidence: std::option::Option<f64> = ctx.get("routing_confidence").await;
+        std::assert!(confidence.unwrap() < 0.5);
+    }
+
+    #[tokio::test]
+    async fn test_clarify_decision_ends_the_graph() {
+        // Test: Validates a low-confidence task routed to "clarify" ends the graph instead of continuing to enhancement.
+        // Justification: The clarify route must stop before any provider call so the task is left as the user wrote it.
+        let shim = super::SemanticRouterTaskShim::new().with_config(crate::domain::router_config::RouterConfig::new(0.5, "clarify").unwrap());
+        let ctx = graph_flow::Context::new();
+        ctx.set("task_title", std::string::String::from("Fix typo")).await;
+
+        let result = <super::SemanticRouterTaskShim as graph_flow::Task>::run(&shim, ctx.clone()).await.unwrap();
+
+        std::assert!(matches!(result.next_action, graph_flow::NextAction::End));
+        let decision: std::option::Option<std::string::String> = ctx.get("routing_decision").await;
+        std::assert_eq!(decision.as_deref(), std::option::Option::Some("clarify"));
+    }
 }
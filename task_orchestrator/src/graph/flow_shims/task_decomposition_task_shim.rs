@@ -5,6 +5,7 @@
 //! framework. It handles state marshalling between graph_flow::Context and GraphState.
 //!
 //! Revision History
+//! - 2026-10-16T20:10:00Z @AI: Carry the run's correlation ID from the flow context into GraphState.
 //! - 2025-11-23T17:45:00Z @AI: Create TaskDecompositionTaskShim for Phase 3 Sprint 7.
 
 /// Shim that wraps TaskDecompositionNode for graph-flow integration.
@@ -71,7 +72,10 @@ impl graph_flow::Task for TaskDecompositionTaskShim {
         };
 
         // Run decomposition node logic
-        let state_in = crate::graph::state::GraphState::new(task);
+        let mut state_in = crate::graph::state::GraphState::new(task);
+        if let std::option::Option::Some(correlation_id) = context.get::<crate::domain::correlation_id::CorrelationId>("correlation_id").await {
+            state_in = state_in.with_correlation_id(correlation_id);
+        }
         let state_out = match TaskDecompositionTaskShim::run(self, state_in).await {
             std::result::Result::Ok(s) => s,
             std::result::Result::Err(e) => {
@@ -2,40 +2,85 @@
 //!
 //! This node examines the latest comprehension test attached to the task and
 //! determines whether the task should proceed to completion ("pass") or loop
-//! back for further enhancement ("fail"). The current heuristic is simple and
-//! deterministic for unit testing.
+//! back for further enhancement ("fail"). When the agent has answered the
+//! test, the answer is graded against the test's key points and passes if the
+//! score reaches the configured threshold. Without an answer a simple,
+//! deterministic heuristic on the question decides. A failed test is marked
+//! stale so the next pass regenerates it.
 //!
 //! Revision History
+//! - 2026-10-17T22:20:00Z @AI: Close the task's in-progress time interval when setting its status.
+//! - 2026-10-17T07:50:00Z @AI: Record the reason for the pass/fail decision in GraphState.
+//! - 2026-10-17T07:10:00Z @AI: Grade the agent's answer against the test's key points with a configurable pass threshold.
+//! - 2026-10-17T06:50:00Z @AI: Mark the task's tests stale on "fail" so the enhancement loop regenerates them.
+//! - 2026-10-16T20:10:00Z @AI: Pass the whole state to node_span so the span carries the correlation ID.
+//! - 2026-10-16T19:50:00Z @AI: Run execute inside a timed `node` tracing span.
 //! - 2025-11-12T21:44:00Z @AI: Add CheckTestResultNode with deterministic pass/fail heuristic and tests.
 
 /// Node that decides routing based on comprehension test content.
-pub struct CheckTestResultNode;
+pub struct CheckTestResultNode {
+    pass_threshold: f64,
+}
 
 impl CheckTestResultNode {
-    /// Creates a new CheckTestResultNode.
-    pub fn new() -> Self { CheckTestResultNode }
+    /// Score an answer needs to pass when no threshold is configured.
+    pub const DEFAULT_PASS_THRESHOLD: f64 = 0.7;
+
+    /// Creates a new CheckTestResultNode with the default pass threshold.
+    pub fn new() -> Self {
+        CheckTestResultNode { pass_threshold: Self::DEFAULT_PASS_THRESHOLD }
+    }
+
+    /// Sets the minimum grade score (0.0 to 1.0) an answer needs to pass.
+    pub fn with_pass_threshold(mut self, pass_threshold: f64) -> Self {
+        self.pass_threshold = pass_threshold;
+        self
+    }
 
     /// Executes the check and writes a routing_decision of "pass" or "fail".
+    ///
+    /// With an agent answer in the state, also stores its TestGrade.
     pub async fn execute(
         &self,
         mut state: crate::graph::state::GraphState,
     ) -> std::result::Result<crate::graph::state::GraphState, std::string::String> {
-        let decision = match &state.task.comprehension_tests {
-            std::option::Option::Some(list) if !list.is_empty() => {
-                let last = list.last().unwrap();
-                // Heuristic: if question length <= 80, treat as pass; else fail.
-                if last.question.len() <= 80 { "pass" } else { "fail" }
+        let span = crate::infrastructure::telemetry::node_span("check_test_result", &state);
+        task_manager::infrastructure::telemetry::timed(span, async move {
+            let (decision, reason) = match &state.task.comprehension_tests {
+                std::option::Option::Some(list) if !list.is_empty() => {
+                    let last = list.last().unwrap();
+                    match &state.agent_answer {
+                        std::option::Option::Some(answer) => {
+                            let grade = crate::domain::test_grade::TestGrade::grade(&last.key_points(), answer, self.pass_threshold);
+                            tracing::info!(task_id = %state.task.id, score = grade.score, passed = grade.passed, "graded comprehension test answer");
+                            let passed = grade.passed;
+                            let reason = std::format!("grade {:.2}, threshold {:.2}", grade.score, grade.threshold);
+                            state.test_grade = std::option::Option::Some(grade);
+                            (if passed { "pass" } else { "fail" }, reason)
+                        }
+                        // Heuristic: if question length <= 80, treat as pass; else fail.
+                        std::option::Option::None => (
+                            if last.question.len() <= 80 { "pass" } else { "fail" },
+                            std::format!("question length {}, limit 80", last.question.len()),
+                        ),
+                    }
+                }
+                _ => {
+                    // No tests yet: fail safe to request more enhancement.
+                    ("fail", std::string::String::from("no comprehension test"))
+                }
+            };
+            state.routing_decision = std::option::Option::Some(std::string::String::from(decision));
+            state.routing_reason = std::option::Option::Some(reason);
+            if decision == "pass" {
+                state.task.track_status_time(&task_manager::domain::task_status::TaskStatus::OrchestrationComplete, chrono::Utc::now());
+                state.task.status = task_manager::domain::task_status::TaskStatus::OrchestrationComplete;
+            } else {
+                state.task.mark_comprehension_tests_stale();
             }
-            _ => {
-                // No tests yet: fail safe to request more enhancement.
-                "fail"
-            }
-        };
-        state.routing_decision = std::option::Option::Some(std::string::String::from(decision));
-        if decision == "pass" {
-            state.task.status = task_manager::domain::task_status::TaskStatus::OrchestrationComplete;
-        }
-        std::result::Result::Ok(state)
+            std::result::Result::Ok(state)
+        })
+        .await
     }
 }
 
@@ -73,6 +118,8 @@ mod tests {
             question: std::string::String::from("Short?"),
             options: std::option::Option::None,
             correct_answer: std::string::String::from("Yes"),
+            description_hash: std::option::Option::None,
+            stale: false,
         };
         task.comprehension_tests = std::option::Option::Some(vec![ct]);
         let state = crate::graph::state::GraphState::new(task);
@@ -94,11 +141,75 @@ mod tests {
             question: std::string::String::from("This is a very long, verbose question intended to exceed the threshold of eighty characters to force a fail routing decision in tests."),
             options: std::option::Option::None,
             correct_answer: std::string::String::from("A"),
+            description_hash: std::option::Option::None,
+            stale: false,
         };
         task.comprehension_tests = std::option::Option::Some(vec![ct]);
         let state = crate::graph::state::GraphState::new(task);
         let node = super::CheckTestResultNode::new();
         let out = crate::graph::nodes::graph_node::GraphNode::execute(&node, state).await.unwrap();
         std::assert_eq!(out.routing_decision, std::option::Option::Some(std::string::String::from("fail")));
+        std::assert!(out.task.comprehension_tests.unwrap()[0].stale);
+    }
+
+    fn state_with_answer(answer: &str) -> crate::graph::state::GraphState {
+        let ai = transcript_extractor::domain::action_item::ActionItem { title: std::string::String::from("Secure login"), assignee: std::option::Option::None, due_date: std::option::Option::None };
+        let mut task = task_manager::domain::task::Task::from_action_item(&ai, std::option::Option::None);
+        let ct = task_manager::domain::comprehension_test::ComprehensionTest {
+            test_id: std::string::String::from("ct-1"),
+            task_id: task.id.clone(),
+            timestamp: chrono::Utc::now(),
+            test_type: std::string::String::from("short_answer"),
+            question: std::string::String::from("What must the login endpoint do?"),
+            options: std::option::Option::None,
+            correct_answer: std::string::String::from("Hash passwords with bcrypt; lock the account after five failed attempts; log every failure"),
+            description_hash: std::option::Option::None,
+            stale: false,
+        };
+        task.comprehension_tests = std::option::Option::Some(vec![ct]);
+        crate::graph::state::GraphState::new(task).with_agent_answer(std::string::String::from(answer))
+    }
+
+    #[tokio::test]
+    async fn test_answer_above_threshold_passes_with_grade() {
+        // Test: Validates an answer covering the key points passes and carries its score and feedback.
+        // Justification: A good answer must complete orchestration instead of looping back.
+        let state = state_with_answer("Passwords are hashed with bcrypt, the account gets a lock after five failed attempts, and we log every failure.");
+        let node = super::CheckTestResultNode::new();
+        let out = crate::graph::nodes::graph_node::GraphNode::execute(&node, state).await.unwrap();
+
+        let grade = out.test_grade.unwrap();
+        std::assert!(grade.score >= super::CheckTestResultNode::DEFAULT_PASS_THRESHOLD, "{:?}", grade);
+        std::assert_eq!(grade.feedback.len(), 3);
+        std::assert_eq!(out.routing_decision, std::option::Option::Some(std::string::String::from("pass")));
+        std::assert_eq!(out.task.status, task_manager::domain::task_status::TaskStatus::OrchestrationComplete);
+    }
+
+    #[tokio::test]
+    async fn test_answer_below_threshold_routes_to_enhancement_retry() {
+        // Test: Validates an answer missing key points fails, routes to the retry branch, and reports what was missed.
+        // Justification: The "fail" decision is what sends the flow back to enhancement.
+        let state = state_with_answer("Passwords are hashed with bcrypt.");
+        let node = super::CheckTestResultNode::new();
+        let out = crate::graph::nodes::graph_node::GraphNode::execute(&node, state).await.unwrap();
+
+        let grade = out.test_grade.unwrap();
+        std::assert!(!grade.passed);
+        std::assert!(grade.feedback[0].covered);
+        std::assert!(!grade.feedback[1].covered && !grade.feedback[2].covered);
+        std::assert_eq!(out.routing_decision, std::option::Option::Some(std::string::String::from("fail")));
+        std::assert_ne!(out.task.status, task_manager::domain::task_status::TaskStatus::OrchestrationComplete);
+        std::assert!(out.task.comprehension_tests.unwrap()[0].stale);
+    }
+
+    #[tokio::test]
+    async fn test_pass_threshold_is_configurable() {
+        // Test: Validates the same partial answer passes under a lower threshold.
+        // Justification: Teams tune how strict grading is without code changes to the node.
+        let state = state_with_answer("Passwords are hashed with bcrypt.");
+        let node = super::CheckTestResultNode::new().with_pass_threshold(0.2);
+        let out = crate::graph::nodes::graph_node::GraphNode::execute(&node, state).await.unwrap();
+
+        std::assert_eq!(out.routing_decision, std::option::Option::Some(std::string::String::from("pass")));
     }
 }
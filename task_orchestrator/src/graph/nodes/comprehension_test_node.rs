@@ -1,11 +1,20 @@
 //! ComprehensionTestNode generates a comprehension test via the port.
 //!
-//! This node requests a comprehension test from the provided port and appends
-//! it to the task's comprehension_tests list in the GraphState. It advances the
-//! task status toward follow-on actions. This is a runtime-agnostic placeholder
+//! This node requests a comprehension test from the provided port and stores
+//! it in the task's comprehension_tests list in the GraphState, replacing
+//! tests that are stale or were generated for another description. A task
+//! that already has a current test keeps it and no provider call is made.
+//! The port then answers the latest test as the agent, and the answer is
+//! stored in `agent_answer` for CheckTestResultNode to grade. It
+//! advances the task status toward follow-on actions. This is a runtime-agnostic placeholder
 //! pending rs-graph-llm integration in Phase 6.
 //!
 //! Revision History
+//! - 2026-10-18T02:30:00Z @AI: Ask the port to answer the latest test and store the answer in agent_answer for grading.
+//! - 2026-10-17T22:20:00Z @AI: Close the task's in-progress time interval when setting its status.
+//! - 2026-10-17T06:50:00Z @AI: Generate only when the task has no current test; stamp the description hash and replace outdated tests.
+//! - 2026-10-16T20:10:00Z @AI: Pass the whole state to node_span so the span carries the correlation ID.
+//! - 2026-10-16T19:50:00Z @AI: Run execute inside a timed `node` tracing span.
 //! - 2025-11-12T21:43:00Z @AI: Add ComprehensionTestNode with port integration and unit tests.
 
 /// Node responsible for generating a comprehension test for the task.
@@ -24,20 +33,49 @@ impl ComprehensionTestNode {
     }
 
     /// Executes test generation and updates the task in state.
+    ///
+    /// Skips generation when the task already has a current test (see
+    /// Task::needs_comprehension_test()).
     pub async fn execute(
         &self,
         mut state: crate::graph::state::GraphState,
     ) -> std::result::Result<crate::graph::state::GraphState, std::string::String> {
-        let ct = crate::ports::comprehension_test_port::ComprehensionTestPort::generate_comprehension_test(
-            self.port.as_ref(),
-            &state.task,
-            self.test_type.as_str(),
-        ).await?;
-        let mut list = state.task.comprehension_tests.unwrap_or_else(|| std::vec::Vec::new());
-        list.push(ct);
-        state.task.comprehension_tests = std::option::Option::Some(list);
-        state.task.status = task_manager::domain::task_status::TaskStatus::PendingFollowOn;
-        std::result::Result::Ok(state)
+        let span = crate::infrastructure::telemetry::node_span("comprehension_test", &state);
+        task_manager::infrastructure::telemetry::timed(span, async move {
+            if state.task.needs_comprehension_test() {
+                let mut ct = crate::ports::comprehension_test_port::ComprehensionTestPort::generate_comprehension_test(
+                    self.port.as_ref(),
+                    &state.task,
+                    self.test_type.as_str(),
+                ).await?;
+                ct.description_hash = std::option::Option::Some(
+                    task_manager::domain::comprehension_test::ComprehensionTest::hash_description(&state.task.description),
+                );
+                // Outdated tests are replaced by the regenerated one
+                let description = state.task.description.clone();
+                let mut list = state.task.comprehension_tests.unwrap_or_else(|| std::vec::Vec::new());
+                list.retain(|test| test.is_current_for(&description));
+                list.push(ct);
+                state.task.comprehension_tests = std::option::Option::Some(list);
+            } else {
+                tracing::info!(task_id = %state.task.id, "comprehension test is current; skipping generation");
+            }
+            // Answered again on every pass, since re-enhancement changes what the agent knows
+            if let std::option::Option::Some(test) = state.task.comprehension_tests.as_ref().and_then(|list| list.last()) {
+                let answer = crate::ports::comprehension_test_port::ComprehensionTestPort::answer_comprehension_test(
+                    self.port.as_ref(),
+                    &state.task,
+                    test,
+                ).await?;
+                if let std::option::Option::Some(answer) = answer {
+                    state.agent_answer = std::option::Option::Some(answer);
+                }
+            }
+            state.task.track_status_time(&task_manager::domain::task_status::TaskStatus::PendingFollowOn, chrono::Utc::now());
+            state.task.status = task_manager::domain::task_status::TaskStatus::PendingFollowOn;
+            std::result::Result::Ok(state)
+        })
+        .await
     }
 }
 
@@ -69,11 +107,49 @@ mod tests {
                 question: std::format!("Q for {}", task.title),
                 options: std::option::Option::None,
                 correct_answer: std::string::String::from("A"),
+                description_hash: std::option::Option::None,
+                stale: false,
             };
             std::result::Result::Ok(ct)
         }
     }
 
+    struct AnsweringPort;
+    #[async_trait::async_trait]
+    impl crate::ports::comprehension_test_port::ComprehensionTestPort for AnsweringPort {
+        async fn generate_comprehension_test(
+            &self,
+            task: &task_manager::domain::task::Task,
+            test_type: &str,
+        ) -> std::result::Result<task_manager::domain::comprehension_test::ComprehensionTest, std::string::String> {
+            crate::ports::comprehension_test_port::ComprehensionTestPort::generate_comprehension_test(&MockPort, task, test_type).await
+        }
+
+        async fn answer_comprehension_test(
+            &self,
+            _task: &task_manager::domain::task::Task,
+            test: &task_manager::domain::comprehension_test::ComprehensionTest,
+        ) -> std::result::Result<std::option::Option<std::string::String>, std::string::String> {
+            std::result::Result::Ok(std::option::Option::Some(std::format!("Answer to {}", test.question)))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_node_stores_the_ports_answer_for_grading() {
+        // Test: Validates the port's answer to the generated test lands in agent_answer, and a port without answers leaves it unset.
+        // Justification: CheckTestResultNode grades agent_answer; without it every real run fell back to the question-length heuristic.
+        let ai = transcript_extractor::domain::action_item::ActionItem { title: std::string::String::from("Title"), assignee: std::option::Option::None, due_date: std::option::Option::None };
+        let task = task_manager::domain::task::Task::from_action_item(&ai, std::option::Option::None);
+        let answering = super::ComprehensionTestNode::new(std::sync::Arc::new(AnsweringPort), std::string::String::from("short_answer"));
+        let silent = super::ComprehensionTestNode::new(std::sync::Arc::new(MockPort), std::string::String::from("short_answer"));
+
+        let answered = crate::graph::nodes::graph_node::GraphNode::execute(&answering, crate::graph::state::GraphState::new(task.clone())).await.unwrap();
+        let unanswered = crate::graph::nodes::graph_node::GraphNode::execute(&silent, crate::graph::state::GraphState::new(task)).await.unwrap();
+
+        std::assert_eq!(answered.agent_answer.as_deref(), std::option::Option::Some("Answer to Q for Title"));
+        std::assert!(unanswered.agent_answer.is_none());
+    }
+
     #[tokio::test]
     async fn test_comprehension_test_node_appends_test() {
         let ai = transcript_extractor::domain::action_item::ActionItem { title: std::string::String::from("Title"), assignee: std::option::Option::None, due_date: std::option::Option::None };
@@ -85,4 +161,30 @@ mod tests {
         std::assert_eq!(list.len(), 1);
         std::assert_eq!(out.task.status, task_manager::domain::task_status::TaskStatus::PendingFollowOn);
     }
+
+    #[tokio::test]
+    async fn test_stale_test_is_regenerated_and_current_test_kept() {
+        // Test: Validates a stale test is replaced by a freshly generated one, while a current test is kept without a provider call.
+        // Justification: Edited descriptions must get new tests, but unchanged tasks must not pay for regeneration.
+        let ai = transcript_extractor::domain::action_item::ActionItem { title: std::string::String::from("Title"), assignee: std::option::Option::None, due_date: std::option::Option::None };
+        let mut task = task_manager::domain::task::Task::from_action_item(&ai, std::option::Option::None);
+        task.description = std::string::String::from("Original description");
+        let node = super::ComprehensionTestNode::new(std::sync::Arc::new(MockPort), std::string::String::from("short_answer"));
+
+        let first = crate::graph::nodes::graph_node::GraphNode::execute(&node, crate::graph::state::GraphState::new(task)).await.unwrap();
+        let mut task = first.task;
+        task.comprehension_tests.as_mut().unwrap()[0].test_id = std::string::String::from("ct-original");
+
+        let kept = crate::graph::nodes::graph_node::GraphNode::execute(&node, crate::graph::state::GraphState::new(task.clone())).await.unwrap();
+        let kept_tests = kept.task.comprehension_tests.unwrap();
+        std::assert_eq!(kept_tests.len(), 1);
+        std::assert_eq!(kept_tests[0].test_id, "ct-original");
+
+        std::assert_eq!(task.set_description(std::string::String::from("Rewritten description")), 1);
+        let regenerated = crate::graph::nodes::graph_node::GraphNode::execute(&node, crate::graph::state::GraphState::new(task)).await.unwrap();
+        let tests = regenerated.task.comprehension_tests.unwrap();
+        std::assert_eq!(tests.len(), 1);
+        std::assert_eq!(tests[0].test_id, "ct-1");
+        std::assert!(tests[0].is_current_for("Rewritten description"));
+    }
 }
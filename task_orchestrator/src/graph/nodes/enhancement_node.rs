@@ -6,6 +6,9 @@
 //! provide a framework-agnostic async execute method and GraphNode impl.
 //!
 //! Revision History
+//! - 2026-10-17T22:20:00Z @AI: Close the task's in-progress time interval when setting its status.
+//! - 2026-10-16T20:10:00Z @AI: Pass the whole state to node_span so the span carries the correlation ID.
+//! - 2026-10-16T19:50:00Z @AI: Run execute inside a timed `node` tracing span.
 //! - 2025-11-12T21:42:00Z @AI: Add EnhancementNode with port integration and unit tests.
 
 /// Node responsible for generating a task enhancement through a provided port.
@@ -24,13 +27,18 @@ impl EnhancementNode {
         &self,
         mut state: crate::graph::state::GraphState,
     ) -> std::result::Result<crate::graph::state::GraphState, std::string::String> {
-        let enh = crate::ports::task_enhancement_port::TaskEnhancementPort::generate_enhancement(self.port.as_ref(), &state.task).await?;
-        let mut list = state.task.enhancements.unwrap_or_else(|| std::vec::Vec::new());
-        list.push(enh);
-        state.task.enhancements = std::option::Option::Some(list);
-        // Suggest next step status for clarity; no strict coupling to UI.
-        state.task.status = task_manager::domain::task_status::TaskStatus::PendingComprehensionTest;
-        std::result::Result::Ok(state)
+        let span = crate::infrastructure::telemetry::node_span("enhancement", &state);
+        task_manager::infrastructure::telemetry::timed(span, async move {
+            let enh = crate::ports::task_enhancement_port::TaskEnhancementPort::generate_enhancement(self.port.as_ref(), &state.task).await?;
+            let mut list = state.task.enhancements.unwrap_or_else(|| std::vec::Vec::new());
+            list.push(enh);
+            state.task.enhancements = std::option::Option::Some(list);
+            // Suggest next step status for clarity; no strict coupling to UI.
+            state.task.track_status_time(&task_manager::domain::task_status::TaskStatus::PendingComprehensionTest, chrono::Utc::now());
+            state.task.status = task_manager::domain::task_status::TaskStatus::PendingComprehensionTest;
+            std::result::Result::Ok(state)
+        })
+        .await
     }
 }
 
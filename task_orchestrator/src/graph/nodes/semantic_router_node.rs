@@ -4,7 +4,18 @@
 //! routing decisions. High-complexity tasks (score >= 7) are routed to decomposition,
 //! while simpler tasks follow the standard enhancement flow.
 //!
+//! The node also reports a confidence: how far the score sits from the
+//! decomposition boundary, from 0.14 for scores 6 and 7 up to 1.0 for scores
+//! 3 and 10. Below the RouterConfig threshold the task takes the configured
+//! fallback route rather than a guess.
+//!
 //! Revision History
+//! - 2026-10-18T04:30:00Z @AI: Document the "clarify" fallback route; test routing a borderline task to it.
+//! - 2026-10-17T21:30:00Z @AI: Test that the default RouterConfig keeps the plain classification.
+//! - 2026-10-17T07:50:00Z @AI: Record the routing reason (score, confidence, fallback) in GraphState.
+//! - 2026-10-17T07:30:00Z @AI: Emit a routing confidence and take RouterConfig's fallback route below its threshold.
+//! - 2026-10-16T20:10:00Z @AI: Pass the whole state to node_span so the span carries the correlation ID.
+//! - 2026-10-16T19:50:00Z @AI: Run execute inside a timed `node` tracing span.
 //! - 2025-11-23T23:00:00Z @AI: Document heterogeneous pipeline routing strategy (Phase 5 Sprint 10 Task 5.4).
 //! - 2025-11-23T16:15:00Z @AI: Upgrade to use TriageService for intelligent routing (Phase 3 Sprint 6).
 //! - 2025-11-12T21:41:00Z @AI: Add SemanticRouterNode with deterministic classifier and tests.
@@ -24,16 +35,34 @@
 /// the appropriate routing path through the orchestration pipeline.
 pub struct SemanticRouterNode {
     triage_service: task_manager::domain::services::triage_service::TriageService,
+    config: crate::domain::router_config::RouterConfig,
 }
 
 impl SemanticRouterNode {
-    /// Creates a new SemanticRouterNode with the provided TriageService.
+    /// Creates a new SemanticRouterNode with the provided TriageService and the default RouterConfig.
     ///
     /// # Arguments
     ///
     /// * `triage_service` - The triage service to use for routing decisions
     pub fn new(triage_service: task_manager::domain::services::triage_service::TriageService) -> Self {
-        SemanticRouterNode { triage_service }
+        SemanticRouterNode {
+            triage_service,
+            config: crate::domain::router_config::RouterConfig::default(),
+        }
+    }
+
+    /// Replaces the confidence threshold and fallback route.
+    pub fn with_config(mut self, config: crate::domain::router_config::RouterConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Returns the router's confidence for a complexity score.
+    ///
+    /// The distance from the midpoint between 6 (enhance) and 7 (decompose),
+    /// scaled so the lowest possible score (3) and the cap (10) give 1.0.
+    pub fn confidence(complexity_score: u8) -> f64 {
+        ((f64::from(complexity_score) - 6.5).abs() / 3.5).min(1.0)
     }
 
     /// Executes routing logic using intelligent complexity-based classification.
@@ -41,19 +70,49 @@ impl SemanticRouterNode {
     /// Uses TriageService to analyze the task and set the routing_decision:
     /// - "enhance" for simple tasks (complexity score < 7)
     /// - "decompose" for complex tasks (complexity score >= 7)
+    /// - the configured fallback route when the confidence is below the threshold,
+    ///   which may be "clarify" to stop the run for a better task description
+    ///
+    /// The confidence is stored in routing_confidence either way, and a
+    /// summary of the decision in routing_reason.
     pub async fn execute(
         &self,
         mut state: crate::graph::state::GraphState,
     ) -> std::result::Result<crate::graph::state::GraphState, std::string::String> {
-        let decision = self.triage_service.classify_task(&state.task);
+        let span = crate::infrastructure::telemetry::node_span("semantic_router", &state);
+        task_manager::infrastructure::telemetry::timed(span, async move {
+            let assessment = self.triage_service.assess_task(&state.task);
+            let confidence = Self::confidence(assessment.complexity_score);
 
-        let route = match decision {
-            task_manager::domain::services::triage_service::TriageDecision::Enhance => "enhance",
-            task_manager::domain::services::triage_service::TriageDecision::Decompose => "decompose",
-        };
+            let classified = match assessment.decision {
+                task_manager::domain::services::triage_service::TriageDecision::Enhance => "enhance",
+                task_manager::domain::services::triage_service::TriageDecision::Decompose => "decompose",
+            };
+            let mut reason = std::format!("complexity {}, confidence {:.2}", assessment.complexity_score, confidence);
+            let route = if confidence < self.config.confidence_threshold {
+                reason.push_str(&std::format!(
+                    " below threshold {:.2}; fallback instead of {}",
+                    self.config.confidence_threshold, classified
+                ));
+                tracing::info!(
+                    classified,
+                    confidence,
+                    threshold = self.config.confidence_threshold,
+                    fallback = %self.config.fallback_route,
+                    "router confidence below threshold; taking fallback route"
+                );
+                self.config.fallback_route.as_str()
+            } else {
+                classified
+            };
 
-        state.routing_decision = std::option::Option::Some(std::string::String::from(route));
-        std::result::Result::Ok(state)
+            tracing::debug!(route, confidence, "routed task");
+            state.routing_decision = std::option::Option::Some(std::string::String::from(route));
+            state.routing_confidence = std::option::Option::Some(confidence);
+            state.routing_reason = std::option::Option::Some(reason);
+            std::result::Result::Ok(state)
+        })
+        .await
     }
 }
 
@@ -98,4 +157,77 @@ mod tests {
         let out = super::SemanticRouterNode::execute(&node, state).await.unwrap();
         std::assert_eq!(out.routing_decision, std::option::Option::Some(std::string::String::from("decompose")));
     }
+
+    fn borderline_task() -> task_manager::domain::task::Task {
+        // 3 (base) + 1 (title > 50 chars) + 2 ("migrate") + 1 (no persona) = 7: one point past the boundary
+        let ai = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Migrate the nightly billing export to the new storage bucket"),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::Some(std::string::String::from("2025-12-01")),
+        };
+        task_manager::domain::task::Task::from_action_item(&ai, std::option::Option::None)
+    }
+
+    fn node_with(config: crate::domain::router_config::RouterConfig) -> super::SemanticRouterNode {
+        let scorer = task_manager::domain::services::complexity_scorer::ComplexityScorer::new();
+        let triage = task_manager::domain::services::triage_service::TriageService::new(scorer);
+        super::SemanticRouterNode::new(triage).with_config(config)
+    }
+
+    #[tokio::test]
+    async fn test_borderline_task_takes_fallback_route() {
+        // Test: Validates a task scored right at the boundary takes the configured fallback route instead of "decompose".
+        // Justification: A one-point margin is not enough evidence to split a task into subtasks.
+        let config = crate::domain::router_config::RouterConfig::new(0.25, "enhance").unwrap();
+        let state = crate::graph::state::GraphState::new(borderline_task());
+
+        let out = super::SemanticRouterNode::execute(&node_with(config), state).await.unwrap();
+
+        std::assert_eq!(out.routing_decision, std::option::Option::Some(std::string::String::from("enhance")));
+        std::assert!(out.routing_confidence.unwrap() < 0.25);
+    }
+
+    #[tokio::test]
+    async fn test_clear_task_takes_confident_route() {
+        // Test: Validates a clearly complex task keeps its classification even when the fallback is configured differently.
+        // Justification: The fallback must only replace uncertain decisions.
+        let config = crate::domain::router_config::RouterConfig::new(0.25, "enhance").unwrap();
+        let ai = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Refactor entire authentication system to support OAuth2 and SAML with multi-region deployment"),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let state = crate::graph::state::GraphState::new(task_manager::domain::task::Task::from_action_item(&ai, std::option::Option::None));
+
+        let out = super::SemanticRouterNode::execute(&node_with(config), state).await.unwrap();
+
+        std::assert_eq!(out.routing_decision, std::option::Option::Some(std::string::String::from("decompose")));
+        std::assert!(out.routing_confidence.unwrap() >= 0.25);
+    }
+
+    #[tokio::test]
+    async fn test_default_config_keeps_plain_classification() {
+        // Test: Validates the default RouterConfig routes a borderline task by its classification alone.
+        // Justification: Routing must not change for anyone who has not configured a threshold.
+        let state = crate::graph::state::GraphState::new(borderline_task());
+
+        let out = super::SemanticRouterNode::execute(&node_with(crate::domain::router_config::RouterConfig::default()), state)
+            .await
+            .unwrap();
+
+        std::assert_eq!(out.routing_decision, std::option::Option::Some(std::string::String::from("decompose")));
+    }
+
+    #[tokio::test]
+    async fn test_borderline_task_can_fall_back_to_clarify() {
+        // Test: Validates a low-confidence task is routed to "clarify" when that is the configured fallback.
+        // Justification: Asking for a clearer task is safer than guessing between enhancement and decomposition.
+        let config = crate::domain::router_config::RouterConfig::new(0.25, "clarify").unwrap();
+        let state = crate::graph::state::GraphState::new(borderline_task());
+
+        let out = super::SemanticRouterNode::execute(&node_with(config), state).await.unwrap();
+
+        std::assert_eq!(out.routing_decision.as_deref(), std::option::Option::Some(crate::domain::router_config::CLARIFY_ROUTE));
+        std::assert!(out.routing_reason.unwrap().contains("fallback instead of decompose"));
+    }
 }
@@ -5,6 +5,10 @@
 //! and the parent task status is updated to Decomposed.
 //!
 //! Revision History
+//! - 2026-10-17T22:20:00Z @AI: Close the task's in-progress time interval when setting its status.
+//! - 2026-10-16T20:10:00Z @AI: Pass the whole state to node_span so the span carries the correlation ID.
+//! - 2026-10-16T19:50:00Z @AI: Run execute inside a timed `node` tracing span.
+//! - 2026-10-16T11:50:00Z @AI: Link only direct children in parent subtask_ids now that decomposition can return nested subtasks; leave tasks below the decomposition threshold (no subtasks) un-decomposed.
 //! - 2025-11-23T23:20:00Z @AI: Document Orca-2 usage for heterogeneous pipeline (Phase 5 Sprint 10 Task 5.5).
 //! - 2025-11-23T17:30:00Z @AI: Create TaskDecompositionNode for Phase 3 Sprint 7.
 //!
@@ -135,20 +139,31 @@ impl TaskDecompositionNode {
         &self,
         mut state: crate::graph::state::GraphState,
     ) -> std::result::Result<crate::graph::state::GraphState, std::string::String> {
-        // Decompose task into subtasks
-        let subtasks = self.decomposition_port.decompose_task(&state.task).await?;
+        let span = crate::infrastructure::telemetry::node_span("task_decomposition", &state);
+        task_manager::infrastructure::telemetry::timed(span, async move {
+            // Decompose task into subtasks
+            let subtasks = self.decomposition_port.decompose_task(&state.task).await?;
 
-        // Extract subtask IDs for parent task linkage
-        let subtask_ids: std::vec::Vec<String> = subtasks.iter().map(|st| st.id.clone()).collect();
+            // Extract direct child IDs for parent task linkage (nested subtasks link to their own parent)
+            let subtask_ids: std::vec::Vec<String> = subtasks
+                .iter()
+                .filter(|st| st.parent_task_id.is_none() || st.parent_task_id.as_deref() == std::option::Option::Some(state.task.id.as_str()))
+                .map(|st| st.id.clone())
+                .collect();
 
-        // Update parent task
-        state.task.status = task_manager::domain::task_status::TaskStatus::Decomposed;
-        state.task.subtask_ids = subtask_ids;
+            // Update parent task (an empty result means the task was left atomic)
+            if !subtask_ids.is_empty() {
+                state.task.track_status_time(&task_manager::domain::task_status::TaskStatus::Decomposed, chrono::Utc::now());
+                state.task.status = task_manager::domain::task_status::TaskStatus::Decomposed;
+                state.task.subtask_ids = subtask_ids;
+            }
 
-        // Store subtasks in GraphState
-        state.subtasks = std::option::Option::Some(subtasks);
+            // Store subtasks in GraphState
+            state.subtasks = std::option::Option::Some(subtasks);
 
-        std::result::Result::Ok(state)
+            std::result::Result::Ok(state)
+        })
+        .await
     }
 }
 
@@ -1,11 +1,16 @@
 //! Graph state for the task orchestrator.
 //!
 //! The GraphState carries state between graph nodes while orchestrating a single
-//! task. It includes the current task snapshot and any routing decision emitted
-//! by classifier nodes. This type lives in the orchestrator crate and is kept
+//! task. It includes the current task snapshot and any routing decision (with
+//! its confidence and reason) emitted by classifier nodes, the agent's answer to the comprehension test and its
+//! grade, plus the correlation ID of the run. This type lives in the orchestrator crate and is kept
 //! framework-agnostic.
 //!
 //! Revision History
+//! - 2026-10-17T07:50:00Z @AI: Add routing_reason explaining the router's and check's decisions for traces.
+//! - 2026-10-17T07:30:00Z @AI: Add routing_confidence set by the semantic router.
+//! - 2026-10-17T07:10:00Z @AI: Add agent_answer and test_grade for grading comprehension test answers.
+//! - 2026-10-16T20:10:00Z @AI: Add correlation_id threaded from the run's entry point to every node span.
 //! - 2025-11-23T17:30:00Z @AI: Add subtasks field for Phase 3 Sprint 7 decomposition support.
 //! - 2025-11-12T21:45:00Z @AI: Add GraphState struct with constructor and unit tests (Phase 5).
 
@@ -16,19 +21,52 @@ pub struct GraphState {
     pub task: task_manager::domain::task::Task,
     /// Optional routing decision emitted by classifier nodes.
     pub routing_decision: std::option::Option<std::string::String>,
+    /// Router confidence (0.0 to 1.0) in its classification, if a router ran.
+    #[serde(default)]
+    pub routing_confidence: std::option::Option<f64>,
+    /// Why the last classifier node decided as it did, e.g. "complexity 8, confidence 0.43".
+    #[serde(default)]
+    pub routing_reason: std::option::Option<std::string::String>,
     /// Optional subtasks generated by decomposition node.
     pub subtasks: std::option::Option<std::vec::Vec<task_manager::domain::task::Task>>,
+    /// ID attached to every log event of this orchestration run.
+    #[serde(default = "crate::domain::correlation_id::CorrelationId::generate")]
+    pub correlation_id: crate::domain::correlation_id::CorrelationId,
+    /// The agent's answer to the task's latest comprehension test, if it answered.
+    #[serde(default)]
+    pub agent_answer: std::option::Option<std::string::String>,
+    /// Grade of `agent_answer`, set by the check node.
+    #[serde(default)]
+    pub test_grade: std::option::Option<crate::domain::test_grade::TestGrade>,
 }
 
 impl GraphState {
-    /// Creates a new GraphState initialized with a task and no routing decision or subtasks.
+    /// Creates a new GraphState initialized with a task, no routing decision,
+    /// subtasks, or answer, and a freshly generated correlation ID.
     pub fn new(task: task_manager::domain::task::Task) -> Self {
         GraphState {
             task,
             routing_decision: std::option::Option::None,
+            routing_confidence: std::option::Option::None,
+            routing_reason: std::option::Option::None,
             subtasks: std::option::Option::None,
+            correlation_id: crate::domain::correlation_id::CorrelationId::generate(),
+            agent_answer: std::option::Option::None,
+            test_grade: std::option::Option::None,
         }
     }
+
+    /// Sets the agent's answer to the comprehension test, to be graded by the check node.
+    pub fn with_agent_answer(mut self, answer: std::string::String) -> Self {
+        self.agent_answer = std::option::Option::Some(answer);
+        self
+    }
+
+    /// Replaces the correlation ID with one generated by the run's entry point.
+    pub fn with_correlation_id(mut self, correlation_id: crate::domain::correlation_id::CorrelationId) -> Self {
+        self.correlation_id = correlation_id;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,269 @@
+//! Per-provider circuit breaker for failing fast during outages.
+//!
+//! Without a breaker, every call to a provider that is down waits out its
+//! full timeout (and retries) before failing, so a batch run crawls. A
+//! `CircuitBreaker` counts consecutive failed calls; after
+//! `failure_threshold` of them it opens and rejects calls with `CircuitOpen`
+//! for `cooldown`. After the cooldown it half-opens and lets a single probe
+//! through: success closes it, failure opens it for another cooldown.
+//!
+//! `for_provider` hands out one shared breaker per provider name, so every
+//! adapter the `ProviderFactory` builds for that provider trips and recovers
+//! together. `RetryPolicy::run` consults the breaker before each attempt.
+//!
+//! Revision History
+//! - 2026-10-17T15:30:00Z @AI: Initial closed/open/half-open breaker and per-provider registry.
+
+/// Consecutive failures that open a provider's breaker by default.
+pub const DEFAULT_FAILURE_THRESHOLD: u32 = 5;
+
+/// How long an open breaker rejects calls by default before probing.
+pub const DEFAULT_COOLDOWN: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Breakers shared by all adapters of a provider, keyed by provider name.
+static BREAKERS: std::sync::OnceLock<std::sync::Mutex<std::collections::HashMap<String, std::sync::Arc<CircuitBreaker>>>> =
+    std::sync::OnceLock::new();
+
+/// Returns the process-wide breaker for `provider`, creating it with the defaults.
+pub fn for_provider(provider: &str) -> std::sync::Arc<CircuitBreaker> {
+    let breakers = BREAKERS.get_or_init(|| std::sync::Mutex::new(std::collections::HashMap::new()));
+    let mut breakers = breakers.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    breakers
+        .entry(provider.to_string())
+        .or_insert_with(|| std::sync::Arc::new(CircuitBreaker::new(provider, DEFAULT_FAILURE_THRESHOLD, DEFAULT_COOLDOWN)))
+        .clone()
+}
+
+/// Error returned instead of calling a provider whose breaker is open.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CircuitOpen {
+    /// Provider the breaker guards
+    pub provider: String,
+
+    /// Time until the breaker lets a probe call through (zero while a probe is in flight)
+    pub retry_in: std::time::Duration,
+}
+
+impl std::fmt::Display for CircuitOpen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Provider {} is unavailable after repeated failures (circuit open); next attempt in {}s",
+            self.provider,
+            self.retry_in.as_secs()
+        )
+    }
+}
+
+impl std::error::Error for CircuitOpen {}
+
+/// Where a breaker is in its closed / open / half-open cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Calls pass; `failures` counts consecutive failures
+    Closed { failures: u32 },
+
+    /// Calls are rejected until `until`
+    Open { until: std::time::Instant },
+
+    /// The cooldown has passed and a probe call started at `probe_started` is in flight
+    HalfOpen { probe_started: std::time::Instant },
+}
+
+/// Circuit breaker guarding calls to one provider.
+///
+/// # Examples
+///
+/// ```
+/// use task_orchestrator::infrastructure::circuit_breaker::CircuitBreaker;
+///
+/// let breaker = CircuitBreaker::new("ollama", 2, std::time::Duration::from_secs(30));
+/// breaker.record_failure();
+/// std::assert!(breaker.try_acquire().is_ok());
+/// breaker.record_failure();
+/// std::assert!(breaker.try_acquire().is_err());
+/// ```
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    provider: String,
+    failure_threshold: u32,
+    cooldown: std::time::Duration,
+    state: std::sync::Mutex<State>,
+}
+
+impl CircuitBreaker {
+    /// Creates a closed breaker.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - Provider name, used in `CircuitOpen` errors and logs
+    /// * `failure_threshold` - Consecutive failures that open the breaker (at least 1)
+    /// * `cooldown` - How long the breaker stays open before a probe
+    pub fn new(provider: &str, failure_threshold: u32, cooldown: std::time::Duration) -> Self {
+        Self {
+            provider: provider.to_string(),
+            failure_threshold: failure_threshold.max(1),
+            cooldown,
+            state: std::sync::Mutex::new(State::Closed { failures: 0 }),
+        }
+    }
+
+    /// Returns the provider this breaker guards.
+    pub fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    /// Returns true if calls are currently rejected without a probe being due.
+    pub fn is_open(&self) -> bool {
+        std::matches!(*self.lock(), State::Open { until } if std::time::Instant::now() < until)
+    }
+
+    /// Asks to make a call; the caller must report its outcome with
+    /// `record_success` or `record_failure`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircuitOpen` while the breaker is open, or while another
+    /// caller's half-open probe is in flight.
+    pub fn try_acquire(&self) -> std::result::Result<(), CircuitOpen> {
+        self.try_acquire_at(std::time::Instant::now())
+    }
+
+    /// Reports a successful call, closing the breaker.
+    pub fn record_success(&self) {
+        let mut state = self.lock();
+        if std::matches!(*state, State::HalfOpen { .. }) {
+            tracing::info!(provider = %self.provider, "provider recovered; circuit closed");
+        }
+        *state = State::Closed { failures: 0 };
+    }
+
+    /// Reports a failed call, opening the breaker at the threshold or after a failed probe.
+    pub fn record_failure(&self) {
+        self.record_failure_at(std::time::Instant::now())
+    }
+
+    fn try_acquire_at(&self, now: std::time::Instant) -> std::result::Result<(), CircuitOpen> {
+        let mut state = self.lock();
+        match *state {
+            State::Closed { .. } => std::result::Result::Ok(()),
+            State::Open { until } if now >= until => {
+                *state = State::HalfOpen { probe_started: now };
+                std::result::Result::Ok(())
+            }
+            State::Open { until } => std::result::Result::Err(self.open_error(until - now)),
+            // A probe whose outcome was never reported (e.g. its call was dropped) expires after a cooldown
+            State::HalfOpen { probe_started } if now >= probe_started + self.cooldown => {
+                *state = State::HalfOpen { probe_started: now };
+                std::result::Result::Ok(())
+            }
+            State::HalfOpen { .. } => std::result::Result::Err(self.open_error(std::time::Duration::ZERO)),
+        }
+    }
+
+    fn record_failure_at(&self, now: std::time::Instant) {
+        let mut state = self.lock();
+        let failures = match *state {
+            State::Closed { failures } => failures + 1,
+            State::HalfOpen { .. } => self.failure_threshold,
+            State::Open { .. } => return,
+        };
+        if failures >= self.failure_threshold {
+            tracing::warn!(provider = %self.provider, failures, cooldown_s = self.cooldown.as_secs(), "provider failing; circuit open");
+            *state = State::Open { until: now + self.cooldown };
+        } else {
+            *state = State::Closed { failures };
+        }
+    }
+
+    fn open_error(&self, retry_in: std::time::Duration) -> CircuitOpen {
+        CircuitOpen {
+            provider: self.provider.clone(),
+            retry_in,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn breaker() -> super::CircuitBreaker {
+        super::CircuitBreaker::new("ollama", 3, std::time::Duration::from_secs(30))
+    }
+
+    #[test]
+    fn test_opens_after_consecutive_failure_threshold() {
+        // Test: Validates the breaker stays closed below the threshold, a success resets the count, and the threshold opens it.
+        // Justification: One flaky call must not cut off a healthy provider, but a run of failures must.
+        let breaker = breaker();
+        let now = std::time::Instant::now();
+
+        breaker.record_failure_at(now);
+        breaker.record_failure_at(now);
+        breaker.record_success();
+        breaker.record_failure_at(now);
+        breaker.record_failure_at(now);
+        std::assert!(breaker.try_acquire_at(now).is_ok());
+
+        breaker.record_failure_at(now);
+        std::assert!(breaker.try_acquire_at(now).is_err());
+    }
+
+    #[test]
+    fn test_rejects_calls_while_open() {
+        // Test: Validates every call during the cooldown is rejected with CircuitOpen naming the provider and wait.
+        // Justification: Failing fast during an outage is what keeps a batch run from waiting out each timeout.
+        let breaker = breaker();
+        let opened = std::time::Instant::now();
+        for _ in 0..3 {
+            breaker.record_failure_at(opened);
+        }
+
+        for elapsed in [0, 10, 29] {
+            let error = breaker.try_acquire_at(opened + std::time::Duration::from_secs(elapsed)).unwrap_err();
+            std::assert_eq!(error.provider, "ollama");
+            std::assert_eq!(error.retry_in, std::time::Duration::from_secs(30 - elapsed));
+        }
+        std::assert!(breaker.try_acquire_at(opened).unwrap_err().to_string().contains("circuit open"));
+    }
+
+    #[test]
+    fn test_half_open_probe_closes_on_success_and_reopens_on_failure() {
+        // Test: Validates one probe is let through after the cooldown, others wait, and the probe's outcome closes or reopens the breaker.
+        // Justification: The breaker must notice a recovered provider without flooding one that is still down.
+        let breaker = breaker();
+        let opened = std::time::Instant::now();
+        for _ in 0..3 {
+            breaker.record_failure_at(opened);
+        }
+        let after_cooldown = opened + std::time::Duration::from_secs(30);
+
+        std::assert!(breaker.try_acquire_at(after_cooldown).is_ok());
+        std::assert!(breaker.try_acquire_at(after_cooldown).is_err(), "only one probe at a time");
+        breaker.record_failure_at(after_cooldown);
+        std::assert!(breaker.try_acquire_at(after_cooldown + std::time::Duration::from_secs(1)).is_err());
+
+        let second_probe = after_cooldown + std::time::Duration::from_secs(30);
+        std::assert!(breaker.try_acquire_at(second_probe).is_ok());
+        breaker.record_success();
+        for _ in 0..5 {
+            std::assert!(breaker.try_acquire_at(second_probe).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_for_provider_shares_one_breaker_per_name() {
+        // Test: Validates adapters asking for the same provider get the same breaker, and other providers get their own.
+        // Justification: The breaker only helps if every adapter of a provider sees the same failure count.
+        let first = super::for_provider("breaker-test-provider");
+        let second = super::for_provider("breaker-test-provider");
+        let other = super::for_provider("breaker-test-other");
+
+        std::assert!(std::sync::Arc::ptr_eq(&first, &second));
+        std::assert!(!std::sync::Arc::ptr_eq(&first, &other));
+        std::assert_eq!(other.provider(), "breaker-test-other");
+    }
+}
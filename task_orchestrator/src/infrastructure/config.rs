@@ -5,6 +5,9 @@
 //! sensible defaults if the file is missing or malformed.
 //!
 //! Revision History
+//! - 2026-10-17T22:40:00Z @AI: Add DecompositionConfig::load reading only the decomposition section of a given config file.
+//! - 2026-10-17T08:30:00Z @AI: Add performance.metrics_max_bytes; metrics_file accepts {project}/{timestamp} placeholders.
+//! - 2026-10-16T11:50:00Z @AI: Add decomposition section with complexity threshold and max recursion depth.
 //! - 2025-11-23T23:30:00Z @AI: Create config module for heterogeneous pipeline (Phase 5 Sprint 10 Task 5.6).
 
 /// Orchestrator configuration loaded from `.rigger/config.json`.
@@ -42,6 +45,10 @@ pub struct OrchestratorConfig {
     /// TUI settings
     #[serde(default = "default_tui")]
     pub tui: TuiConfig,
+
+    /// Auto-decomposition settings
+    #[serde(default)]
+    pub decomposition: DecompositionConfig,
 }
 
 /// Provider configuration section.
@@ -60,8 +67,13 @@ pub struct PerformanceConfig {
     #[serde(default = "default_enable_metrics")]
     pub enable_metrics: bool,
 
+    /// Metrics JSONL path; `{project}` and `{timestamp}` are substituted per run
     #[serde(default = "default_metrics_file")]
     pub metrics_file: String,
+
+    /// Size in bytes past which the metrics file is rotated (unset: never)
+    #[serde(default, skip_serializing_if = "std::option::Option::is_none")]
+    pub metrics_max_bytes: std::option::Option<u64>,
 }
 
 /// TUI configuration.
@@ -77,6 +89,54 @@ pub struct TuiConfig {
     pub auto_refresh_interval_ms: u64,
 }
 
+/// Auto-decomposition configuration.
+///
+/// Tasks whose ComplexityScorer score meets or exceeds `complexity_threshold`
+/// are decomposed; subtasks are re-scored and split again while still above
+/// the threshold, down to at most `max_depth` levels below the original task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct DecompositionConfig {
+    #[serde(default = "default_complexity_threshold")]
+    pub complexity_threshold: u8,
+
+    #[serde(default = "default_max_decomposition_depth")]
+    pub max_depth: usize,
+}
+
+impl DecompositionConfig {
+    /// Reads the `decomposition` section of the config file at `config_path`.
+    ///
+    /// Only that section is parsed, so the rest of the file may use the CLI's
+    /// config layout. Defaults are returned when the file or section is missing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or the section is malformed.
+    pub fn load(config_path: &std::path::Path) -> std::result::Result<Self, String> {
+        if !config_path.exists() {
+            return std::result::Result::Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(config_path)
+            .map_err(|e| std::format!("Failed to read {}: {}", config_path.display(), e))?;
+        let mut config: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|e| std::format!("Failed to parse {}: {}", config_path.display(), e))?;
+        match config.get_mut("decomposition") {
+            std::option::Option::Some(section) => serde_json::from_value(section.take())
+                .map_err(|e| std::format!("Invalid decomposition section in {}: {}", config_path.display(), e)),
+            std::option::Option::None => std::result::Result::Ok(Self::default()),
+        }
+    }
+}
+
+impl Default for DecompositionConfig {
+    fn default() -> Self {
+        Self {
+            complexity_threshold: default_complexity_threshold(),
+            max_depth: default_max_decomposition_depth(),
+        }
+    }
+}
+
 // Default value functions for serde
 fn default_model_roles() -> std::collections::HashMap<String, String> {
     let mut map = std::collections::HashMap::new();
@@ -107,6 +167,7 @@ fn default_performance() -> PerformanceConfig {
     PerformanceConfig {
         enable_metrics: true,
         metrics_file: String::from(".rigger/metrics.jsonl"),
+        metrics_max_bytes: std::option::Option::None,
     }
 }
 
@@ -146,6 +207,14 @@ fn default_refresh_interval() -> u64 {
     30000
 }
 
+fn default_complexity_threshold() -> u8 {
+    7
+}
+
+fn default_max_decomposition_depth() -> usize {
+    2
+}
+
 impl OrchestratorConfig {
     /// Loads configuration from the specified `.rigger` directory.
     ///
@@ -214,6 +283,7 @@ impl Default for OrchestratorConfig {
             providers: default_providers(),
             performance: default_performance(),
             tui: default_tui(),
+            decomposition: DecompositionConfig::default(),
         }
     }
 }
@@ -274,5 +344,44 @@ mod tests {
         let parsed: super::OrchestratorConfig = serde_json::from_str(&json).unwrap();
         std::assert_eq!(parsed.model_roles.get("router").unwrap(), "phi3");
         std::assert_eq!(parsed.quantization.get("orca2").unwrap(), "Q5_K_M");
+        std::assert_eq!(parsed.decomposition, config.decomposition);
+    }
+
+    #[test]
+    fn test_partial_decomposition_section_uses_defaults() {
+        // Test: Validates a partially specified decomposition section fills in defaults.
+        // Justification: Existing config.json files only set the fields users care about.
+        let json = r#"{"decomposition": {"complexity_threshold": 8}}"#;
+        let parsed: super::OrchestratorConfig = serde_json::from_str(json).unwrap();
+
+        std::assert_eq!(parsed.decomposition.complexity_threshold, 8);
+        std::assert_eq!(parsed.decomposition.max_depth, 2);
+        std::assert_eq!(super::OrchestratorConfig::default().decomposition.complexity_threshold, 7);
+    }
+
+    #[test]
+    fn test_decomposition_load_reads_section_of_given_file() {
+        // Test: Validates DecompositionConfig::load reads the section from the given path, ignoring other sections.
+        // Justification: The CLI's config.json has a providers layout OrchestratorConfig cannot parse.
+        let dir = std::env::temp_dir().join(std::format!("decomposition_config_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.json");
+        std::fs::write(
+            &path,
+            r#"{"providers": {"ollama": {"timeout_seconds": 300}}, "decomposition": {"complexity_threshold": 9, "max_depth": 1}}"#,
+        )
+        .unwrap();
+
+        let loaded = super::DecompositionConfig::load(&path).unwrap();
+        std::assert_eq!(loaded, super::DecompositionConfig { complexity_threshold: 9, max_depth: 1 });
+        std::assert_eq!(
+            super::DecompositionConfig::load(&dir.join("missing.json")).unwrap(),
+            super::DecompositionConfig::default()
+        );
+
+        std::fs::write(&path, r#"{"decomposition": {"max_depth": "deep"}}"#).unwrap();
+        std::assert!(super::DecompositionConfig::load(&path).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
     }
 }
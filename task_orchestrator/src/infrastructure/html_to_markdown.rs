@@ -0,0 +1,388 @@
+//! Converts crawled HTML pages into markdown for chunking and embedding.
+//!
+//! Raw HTML embeds poorly: tags, scripts, and navigation drown out the text.
+//! `html_to_markdown` keeps only the page's main content (the `main` element,
+//! `[role="main"]`, or the longest `article`, falling back to `body`), drops
+//! scripts, styles, navigation, and other boilerplate, and renders what is
+//! left as markdown: headings, paragraphs, lists, links, emphasis, code,
+//! block quotes, and tables.
+//!
+//! Revision History
+//! - 2026-10-17T12:10:00Z @AI: Initial html_to_markdown with main-content extraction and boilerplate removal.
+
+/// Elements that never carry page content and are always removed.
+const BOILERPLATE_SELECTORS: &[&str] = &[
+    "script", "style", "noscript", "template", "iframe", "svg", "nav", "aside", "form", "button",
+];
+
+/// Selectors tried in order to find the main content; the longest match wins.
+const MAIN_CONTENT_SELECTORS: &[&str] = &["main", "[role=\"main\"]", "article"];
+
+/// Converts an HTML document to markdown, keeping only its main content.
+///
+/// # Arguments
+///
+/// * `html` - The HTML document.
+/// * `exclude_selectors` - Extra CSS selectors whose elements are removed
+///   (e.g. `CrawlConfig::exclude_selectors`); invalid selectors are ignored.
+///
+/// # Returns
+///
+/// The markdown text, with blocks separated by blank lines.
+///
+/// # Examples
+///
+/// ```
+/// # use task_orchestrator::infrastructure::html_to_markdown::html_to_markdown;
+/// let html = r#"<html><body>
+///     <nav><a href="/">Home</a></nav>
+///     <main><h1>Install</h1><p>Run <code>cargo add rig</code>.</p></main>
+///     <script>track();</script>
+/// </body></html>"#;
+///
+/// assert_eq!(html_to_markdown(html, &[]), "# Install\n\nRun `cargo add rig`.");
+/// ```
+pub fn html_to_markdown(html: &str, exclude_selectors: &[String]) -> String {
+    let document = scraper::Html::parse_document(html);
+
+    let mut excluded: std::vec::Vec<scraper::ElementRef> = std::vec::Vec::new();
+    let selectors = BOILERPLATE_SELECTORS.iter().copied().chain(exclude_selectors.iter().map(String::as_str));
+    for selector in selectors {
+        if let std::result::Result::Ok(selector) = scraper::Selector::parse(selector) {
+            excluded.extend(document.select(&selector));
+        }
+    }
+
+    let mut converter = Converter { excluded: &excluded, blocks: std::vec::Vec::new(), inline: String::new() };
+    converter.container(main_content(&document, &excluded));
+    converter.finish()
+}
+
+/// Returns the element holding the page's main content.
+fn main_content<'a>(document: &'a scraper::Html, excluded: &[scraper::ElementRef<'a>]) -> scraper::ElementRef<'a> {
+    let text_length = |element: &scraper::ElementRef| element.text().map(str::len).sum::<usize>();
+    for selector in MAIN_CONTENT_SELECTORS {
+        let std::result::Result::Ok(selector) = scraper::Selector::parse(selector) else {
+            continue;
+        };
+        let best = document
+            .select(&selector)
+            .filter(|element| !excluded.contains(element))
+            .max_by_key(text_length);
+        if let std::option::Option::Some(element) = best
+            && text_length(&element) > 0
+        {
+            return element;
+        }
+    }
+    scraper::Selector::parse("body")
+        .ok()
+        .and_then(|body| document.select(&body).next())
+        .unwrap_or_else(|| document.root_element())
+}
+
+/// Accumulates markdown blocks while walking the content tree.
+struct Converter<'a, 'b> {
+    /// Elements removed from the output, with everything inside them.
+    excluded: &'b [scraper::ElementRef<'a>],
+    /// Finished markdown blocks.
+    blocks: std::vec::Vec<String>,
+    /// Loose inline text not yet closed into a paragraph.
+    inline: String,
+}
+
+impl<'a> Converter<'a, '_> {
+    /// Renders the children of a container element (body, div, section, ...).
+    fn container(&mut self, element: scraper::ElementRef<'a>) {
+        for child in element.children() {
+            match child.value() {
+                scraper::Node::Text(text) => push_text(&mut self.inline, text),
+                scraper::Node::Element(_) => {
+                    if let std::option::Option::Some(child) = scraper::ElementRef::wrap(child) {
+                        self.element(child);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Renders one element as a block, or as inline text if it is not a block element.
+    fn element(&mut self, element: scraper::ElementRef<'a>) {
+        if self.excluded.contains(&element) {
+            return;
+        }
+        let name = element.value().name();
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                let level = usize::from(name.as_bytes()[1] - b'0');
+                let text = self.inline_text(element);
+                self.block(std::format!("{} {}", "#".repeat(level), text), !text.is_empty());
+            }
+            "p" => {
+                let text = self.inline_text(element);
+                self.block(text.clone(), !text.is_empty());
+            }
+            "pre" => {
+                let code: String = element.text().collect();
+                let code = code.trim_matches('\n');
+                self.block(std::format!("```\n{}\n```", code), !code.trim().is_empty());
+            }
+            "ul" | "ol" => {
+                let list = self.list(element, name == "ol", 0);
+                self.block(list.clone(), !list.is_empty());
+            }
+            "blockquote" => {
+                let mut inner = Converter { excluded: self.excluded, blocks: std::vec::Vec::new(), inline: String::new() };
+                inner.container(element);
+                let quoted: std::vec::Vec<String> = inner
+                    .finish()
+                    .lines()
+                    .map(|line| if line.is_empty() { String::from(">") } else { std::format!("> {}", line) })
+                    .collect();
+                self.block(quoted.join("\n"), !quoted.is_empty());
+            }
+            "table" => {
+                let table = self.table(element);
+                self.block(table.clone(), !table.is_empty());
+            }
+            "hr" => self.block(String::from("---"), true),
+            "div" | "section" | "article" | "main" | "header" | "footer" | "figure" | "body" | "html" | "dl" | "dd" | "dt" | "li" => {
+                self.flush();
+                self.container(element);
+                self.flush();
+            }
+            _ => {
+                let mut text = String::new();
+                self.inline_element(element, &mut text);
+                self.inline.push_str(&text);
+            }
+        }
+    }
+
+    /// Closes pending inline text and appends `block` if `keep` is true.
+    fn block(&mut self, block: String, keep: bool) {
+        self.flush();
+        if keep {
+            self.blocks.push(block);
+        }
+    }
+
+    /// Turns pending inline text into a paragraph.
+    fn flush(&mut self) {
+        let text = collapse_lines(&std::mem::take(&mut self.inline));
+        if !text.is_empty() {
+            self.blocks.push(text);
+        }
+    }
+
+    /// Renders an element's content as one line of inline markdown.
+    fn inline_text(&self, element: scraper::ElementRef<'a>) -> String {
+        let mut out = String::new();
+        self.inline_into(element, &mut out);
+        collapse_lines(&out)
+    }
+
+    /// Appends the inline markdown of an element's children to `out`.
+    fn inline_into(&self, element: scraper::ElementRef<'a>, out: &mut String) {
+        for child in element.children() {
+            if let scraper::Node::Text(text) = child.value() {
+                push_text(out, text);
+            } else if let std::option::Option::Some(child) = scraper::ElementRef::wrap(child) {
+                self.inline_element(child, out);
+            }
+        }
+    }
+
+    /// Appends the inline markdown of one element to `out`; nested lists are rendered by list().
+    fn inline_element(&self, child: scraper::ElementRef<'a>, out: &mut String) {
+        if self.excluded.contains(&child) {
+            return;
+        }
+        match child.value().name() {
+            "br" => out.push('\n'),
+            "ul" | "ol" => {}
+            "a" => {
+                let text = self.inline_text(child);
+                match child.value().attr("href") {
+                    std::option::Option::Some(href) if !text.is_empty() && !href.starts_with('#') && !href.starts_with("javascript:") => {
+                        out.push_str(&std::format!("[{}]({})", text, href));
+                    }
+                    _ => out.push_str(&text),
+                }
+            }
+            "strong" | "b" => wrap_inline(out, "**", &self.inline_text(child)),
+            "em" | "i" => wrap_inline(out, "*", &self.inline_text(child)),
+            "code" => wrap_inline(out, "`", child.text().collect::<String>().trim()),
+            _ => self.inline_into(child, out),
+        }
+    }
+
+    /// Renders a list, indenting nested lists two spaces per level.
+    fn list(&self, element: scraper::ElementRef<'a>, ordered: bool, depth: usize) -> String {
+        let mut lines: std::vec::Vec<String> = std::vec::Vec::new();
+        let items = element
+            .children()
+            .filter_map(scraper::ElementRef::wrap)
+            .filter(|child| child.value().name() == "li" && !self.excluded.contains(child));
+        for (index, item) in items.enumerate() {
+            let marker = if ordered { std::format!("{}.", index + 1) } else { String::from("-") };
+            let text = self.inline_text(item);
+            if !text.is_empty() {
+                lines.push(std::format!("{}{} {}", "  ".repeat(depth), marker, text));
+            }
+            for nested in item.children().filter_map(scraper::ElementRef::wrap) {
+                let nested_name = nested.value().name();
+                if (nested_name == "ul" || nested_name == "ol") && !self.excluded.contains(&nested) {
+                    let nested_list = self.list(nested, nested_name == "ol", depth + 1);
+                    if !nested_list.is_empty() {
+                        lines.push(nested_list);
+                    }
+                }
+            }
+        }
+        lines.join("\n")
+    }
+
+    /// Renders a table as a markdown pipe table, using the first row as the header.
+    fn table(&self, element: scraper::ElementRef<'a>) -> String {
+        let std::result::Result::Ok(row_selector) = scraper::Selector::parse("tr") else {
+            return String::new();
+        };
+        let rows: std::vec::Vec<std::vec::Vec<String>> = element
+            .select(&row_selector)
+            .map(|row| {
+                row.children()
+                    .filter_map(scraper::ElementRef::wrap)
+                    .filter(|cell| matches!(cell.value().name(), "td" | "th"))
+                    .map(|cell| self.inline_text(cell).replace('|', "\\|"))
+                    .collect::<std::vec::Vec<_>>()
+            })
+            .filter(|cells| !cells.is_empty())
+            .collect();
+        let std::option::Option::Some(header) = rows.first() else {
+            return String::new();
+        };
+        let mut lines = std::vec![
+            std::format!("| {} |", header.join(" | ")),
+            std::format!("|{}", " --- |".repeat(header.len())),
+        ];
+        lines.extend(rows.iter().skip(1).map(|cells| std::format!("| {} |", cells.join(" | "))));
+        lines.join("\n")
+    }
+
+    /// Returns the markdown for everything rendered so far.
+    fn finish(mut self) -> String {
+        self.flush();
+        self.blocks.join("\n\n")
+    }
+}
+
+/// Appends HTML text, collapsing whitespace runs (including newlines) to one space.
+fn push_text(out: &mut String, text: &str) {
+    let mut words = text.split_whitespace().peekable();
+    if text.starts_with(char::is_whitespace) && !out.ends_with([' ', '\n']) {
+        out.push(' ');
+    }
+    while let std::option::Option::Some(word) = words.next() {
+        out.push_str(word);
+        if words.peek().is_some() {
+            out.push(' ');
+        }
+    }
+    if text.ends_with(char::is_whitespace) && !text.trim().is_empty() {
+        out.push(' ');
+    }
+}
+
+/// Appends `text` wrapped in a markdown `marker`; empty text adds nothing.
+fn wrap_inline(out: &mut String, marker: &str, text: &str) {
+    if !text.is_empty() {
+        out.push_str(&std::format!("{}{}{}", marker, text, marker));
+    }
+}
+
+/// Trims every line and drops empty ones; `<br>` is the only source of line breaks.
+fn collapse_lines(text: &str) -> String {
+    text.lines().map(str::trim).filter(|line| !line.is_empty()).collect::<std::vec::Vec<_>>().join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    const SAMPLE_PAGE: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+  <title>Rig Guide</title>
+  <style>body { color: red; }</style>
+  <script>window.analytics = {};</script>
+</head>
+<body>
+  <header class="site-header"><a href="/">Rig</a> <a href="/blog">Blog</a></header>
+  <nav><ul><li><a href="/docs">Docs</a></li><li><a href="/api">API</a></li></ul></nav>
+  <main>
+    <h1>Getting Started</h1>
+    <p>Rig builds <strong>LLM pipelines</strong> in <em>Rust</em>. See the
+       <a href="https://docs.rs/rig">API docs</a>.</p>
+    <h2>Steps</h2>
+    <ol>
+      <li>Install the crate</li>
+      <li>Create a client
+        <ul><li>OpenAI</li><li>Ollama</li></ul>
+      </li>
+    </ol>
+    <pre><code>cargo add rig-core</code></pre>
+    <table>
+      <tr><th>Provider</th><th>Local</th></tr>
+      <tr><td>Ollama</td><td>yes</td></tr>
+    </table>
+    <script>console.log("inline");</script>
+  </main>
+  <aside class="sidebar">Related posts</aside>
+  <footer>© 2026 Rig</footer>
+</body>
+</html>"#;
+
+    #[test]
+    fn test_sample_page_keeps_main_text_as_markdown() {
+        // Test: Validates the main content of a full page becomes markdown with headings, emphasis, links, lists, code, and tables.
+        // Justification: Crawled pages are chunked and embedded; structure in markdown keeps chunks readable.
+        let markdown = super::html_to_markdown(SAMPLE_PAGE, &[]);
+
+        std::assert_eq!(
+            markdown,
+            "# Getting Started\n\n\
+             Rig builds **LLM pipelines** in *Rust*. See the [API docs](https://docs.rs/rig).\n\n\
+             ## Steps\n\n\
+             1. Install the crate\n\
+             2. Create a client\n  - OpenAI\n  - Ollama\n\n\
+             ```\ncargo add rig-core\n```\n\n\
+             | Provider | Local |\n| --- | --- |\n| Ollama | yes |"
+        );
+    }
+
+    #[test]
+    fn test_scripts_styles_and_navigation_are_removed() {
+        // Test: Validates scripts, styles, navigation, sidebars, headers, and footers do not reach the output.
+        // Justification: Boilerplate repeats on every page and pollutes embeddings.
+        let markdown = super::html_to_markdown(SAMPLE_PAGE, &[]);
+
+        for boilerplate in ["analytics", "console.log", "color: red", "Docs", "Blog", "Related posts", "© 2026"] {
+            std::assert!(!markdown.contains(boilerplate), "found {:?} in {}", boilerplate, markdown);
+        }
+    }
+
+    #[test]
+    fn test_pages_without_main_fall_back_to_body_minus_excluded_selectors() {
+        // Test: Validates a page with no main/article uses the body and honors extra exclude selectors.
+        // Justification: Many simple sites have no semantic landmarks; config selectors must still apply.
+        let html = r#"<html><body>
+            <div class="menu">Home | About</div>
+            <div><p>First paragraph.</p>Loose text<br>next line</div>
+            <blockquote><p>Quoted</p></blockquote>
+        </body></html>"#;
+
+        let markdown = super::html_to_markdown(html, &[std::string::String::from(".menu")]);
+
+        std::assert_eq!(markdown, "First paragraph.\n\nLoose text\nnext line\n\n> Quoted");
+    }
+}
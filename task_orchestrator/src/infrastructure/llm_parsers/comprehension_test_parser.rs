@@ -5,13 +5,16 @@
 //! schema variations and provides fallback alias mapping when strict deserialization fails.
 //!
 //! Revision History
+//! - 2026-10-17T22:50:00Z @AI: Drive the property tests with proptest strategies.
+//! - 2026-10-17T06:50:00Z @AI: Leave description_hash unset and stale false; ComprehensionTestNode stamps the hash.
+//! - 2026-10-17T06:10:00Z @AI: Extract via json_repair, trim options and keep numeric ones, add property tests.
 //! - 2025-11-23T21:40:00Z @AI: Refactor from utils/ to infrastructure/llm_parsers/ (HEXSER compliance).
 //! - 2025-11-23 @AI: Introduce tolerant parser for ComprehensionTest (Phase 1 Sprint 3 Task 1.8).
 
 /// Parses a possibly noisy LLM response into a ComprehensionTest.
 ///
 /// This function is designed to handle LLM responses that may:
-/// - Include extra text around the JSON
+/// - Include extra text or the syntax noise listed in `task_manager`'s `json_repair` around the JSON
 /// - Use field name aliases (e.g., "options" vs "answer_options")
 /// - Use inconsistent casing for test_type
 ///
@@ -37,29 +40,22 @@ pub fn parse_comprehension_test_tolerant(
     response_text: &str,
     task_id: &str,
 ) -> std::result::Result<task_manager::domain::comprehension_test::ComprehensionTest, std::string::String> {
-    // Try to find JSON object in response (model might include extra text)
-    let json_start = response_text
-        .find('{')
-        .ok_or_else(|| std::string::String::from("No JSON object found in response"))?;
-    let json_end = response_text
-        .rfind('}')
-        .ok_or_else(|| std::string::String::from("No JSON object found in response"))?;
-
-    let json_str = &response_text[json_start..=json_end];
-
-    // Parse as loose JSON Value first
-    let value: serde_json::Value = serde_json::from_str(json_str)
-        .map_err(|e| std::format!("Failed to parse LLM response as JSON: {}", e))?;
-
-    let obj = match value {
-        serde_json::Value::Object(m) => m,
-        _ => {
-            return std::result::Result::Err(std::string::String::from(
-                "Top-level JSON is not an object",
-            ))
-        }
-    };
+    // Locate and repair the object (the model might include extra text or sloppy syntax)
+    task_manager::infrastructure::llm_parsers::json_repair::parse_first(response_text, '{', |value| match value {
+        serde_json::Value::Object(obj) => comprehension_test_from_object(&obj, task_id),
+        _ => std::result::Result::Err(std::string::String::from("Top-level JSON is not an object")),
+    })
+}
 
+/// Maps a parsed response object onto a ComprehensionTest.
+///
+/// # Errors
+///
+/// Returns an error if the question or correct answer is missing.
+fn comprehension_test_from_object(
+    obj: &serde_json::Map<std::string::String, serde_json::Value>,
+    task_id: &str,
+) -> std::result::Result<task_manager::domain::comprehension_test::ComprehensionTest, std::string::String> {
     // Helper to extract a string value from multiple candidate keys
     fn extract_string(
         m: &serde_json::Map<std::string::String, serde_json::Value>,
@@ -95,12 +91,14 @@ pub fn parse_comprehension_test_tolerant(
             if let std::option::Option::Some(v) = m.get(*k) {
                 match v {
                     serde_json::Value::Array(arr) => {
+                        // Trimmed like scalar fields; blank entries are dropped
                         let strings: std::vec::Vec<std::string::String> = arr
                             .iter()
                             .filter_map(|item| match item {
-                                serde_json::Value::String(s) => {
-                                    std::option::Option::Some(s.clone())
+                                serde_json::Value::String(s) if !s.trim().is_empty() => {
+                                    std::option::Option::Some(s.trim().to_string())
                                 }
+                                serde_json::Value::Number(n) => std::option::Option::Some(n.to_string()),
                                 _ => std::option::Option::None,
                             })
                             .collect();
@@ -117,7 +115,7 @@ pub fn parse_comprehension_test_tolerant(
 
     // Extract question (required field)
     let question = extract_string(
-        &obj,
+        obj,
         &[
             "question",
             "q",
@@ -131,7 +129,7 @@ pub fn parse_comprehension_test_tolerant(
 
     // Extract correct answer (required field)
     let correct_answer = extract_string(
-        &obj,
+        obj,
         &[
             "correct_answer",
             "answer",
@@ -145,7 +143,7 @@ pub fn parse_comprehension_test_tolerant(
 
     // Extract test_type with normalization to lowercase
     let test_type = extract_string(
-        &obj,
+        obj,
         &[
             "test_type",
             "type",
@@ -162,7 +160,7 @@ pub fn parse_comprehension_test_tolerant(
     // Extract options (optional field)
     // Support common schema variants: "options", "answer_options", "choices", "alternatives"
     let options = extract_string_array(
-        &obj,
+        obj,
         &[
             "options",
             "answer_options",
@@ -184,6 +182,8 @@ pub fn parse_comprehension_test_tolerant(
         question,
         options,
         correct_answer,
+        description_hash: std::option::Option::None,
+        stale: false,
     })
 }
 
@@ -296,4 +296,192 @@ mod tests {
         assert!(test.options.is_some());
         assert_eq!(test.options.as_ref().unwrap().len(), 4);
     }
+
+    #[test]
+    fn test_options_are_trimmed_and_blank_ones_dropped() {
+        // Test: Validates option entries are trimmed, blank ones dropped, and numeric ones kept as text.
+        // Justification: Padded or empty options used to reach the multiple-choice prompt verbatim.
+        let json = r#"{"question": "Pick", "correct_answer": "4", "options": [" 3 ", "", 4, "  "]}"#;
+
+        let test = parse_comprehension_test_tolerant(json, "task-trim").unwrap();
+        assert_eq!(test.options, Some(vec![String::from("3"), String::from("4")]));
+    }
+
+    // Property tests: cases come from proptest strategies, and a failing
+    // case is shrunk and printed with the response it produced.
+
+    /// Characters that need escaping: quotes, backslashes, braces, control characters, and non-ASCII.
+    const NOISY_CHARS: &[char] = &['a', 'Q', '4', ' ', ' ', '"', '\\', '\n', '\t', '{', '}', '[', ']', ',', ':', 'ß', '→'];
+
+    proptest::prop_compose! {
+        /// Text made of `NOISY_CHARS` that does not end in whitespace.
+        fn noisy_text()(
+            body in proptest::collection::vec(proptest::sample::select(NOISY_CHARS), 0..10),
+            last in proptest::sample::select(vec!['x', '?', 'ß']),
+        ) -> String {
+            body.into_iter().chain(std::iter::once(last)).collect()
+        }
+    }
+
+    /// Question, correct answer, test type, and options the parser must return.
+    type Expected = (String, String, String, Option<Vec<String>>);
+
+    proptest::prop_compose! {
+        /// A response object and what the parser must return for it.
+        fn generated_test()(
+            aliases in proptest::bool::weighted(0.3),
+            question in noisy_text(),
+            answer in noisy_text(),
+            test_type in proptest::sample::select(vec!["short_answer", "MCQ", "Multiple_Choice"]),
+            options in proptest::option::of(proptest::collection::vec(
+                proptest::prop_oneof![85 => noisy_text(), 15 => proptest::strategy::Just(String::from("  "))],
+                1..5,
+            )),
+        ) -> (serde_json::Value, Expected) {
+            let mut obj = serde_json::Map::new();
+            obj.insert(String::from(if aliases { "q" } else { "question" }), serde_json::json!(question));
+            obj.insert(String::from(if aliases { "answer" } else { "correct_answer" }), serde_json::json!(answer));
+            obj.insert(String::from(if aliases { "type" } else { "test_type" }), serde_json::json!(test_type));
+            if let Some(o) = &options {
+                obj.insert(String::from(if aliases { "choices" } else { "options" }), serde_json::json!(o));
+            }
+
+            let expected_options = options
+                .map(|o| o.iter().map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect::<Vec<_>>())
+                .filter(|o| !o.is_empty());
+            (
+                serde_json::Value::Object(obj),
+                (question.trim().to_string(), answer.trim().to_string(), test_type.to_lowercase(), expected_options),
+            )
+        }
+    }
+
+    /// Syntax noise json_repair guarantees to recover from.
+    #[derive(Debug, Clone)]
+    struct SyntaxNoise {
+        /// Whether escaped newlines and tabs in strings become raw control characters.
+        raw_controls: bool,
+        /// Whether a trailing comma goes before each successive closing bracket.
+        trailing_commas: Vec<bool>,
+    }
+
+    proptest::prop_compose! {
+        fn syntax_noise()(
+            raw_controls in proptest::bool::ANY,
+            trailing_commas in proptest::collection::vec(proptest::bool::weighted(0.3), 0..8),
+        ) -> SyntaxNoise {
+            SyntaxNoise { raw_controls, trailing_commas }
+        }
+    }
+
+    /// Text around the JSON: a prefix, a suffix, and whether it is fenced as a code block.
+    type Wrapping = (&'static str, &'static str, bool);
+
+    fn wrapping() -> impl proptest::strategy::Strategy<Value = Wrapping> {
+        (
+            proptest::sample::select(vec!["", "Here's the test {draft}:\n", "Sure! [1]\n", "{}\n"]),
+            proptest::sample::select(vec!["", "\nHope this helps {really}.", "\n}"]),
+            proptest::bool::weighted(0.4),
+        )
+    }
+
+    /// Wraps a JSON document in the given surrounding text.
+    fn wrap(json: &str, (prefix, suffix, fenced): Wrapping) -> String {
+        if fenced {
+            format!("{}```json\n{}\n```{}", prefix, json, suffix)
+        } else {
+            format!("{}{}{}", prefix, json, suffix)
+        }
+    }
+
+    /// Adds trailing commas and raw control characters to a JSON document.
+    fn add_syntax_noise(noise: &SyntaxNoise, json: &str) -> String {
+        let mut out = String::new();
+        let mut in_string = false;
+        let mut chars = json.chars();
+        let mut last_significant = ' ';
+        let mut closing = 0;
+        while let Some(c) = chars.next() {
+            if in_string {
+                if c == '\\' {
+                    let next = chars.next().unwrap();
+                    match next {
+                        'n' if noise.raw_controls => out.push('\n'),
+                        't' if noise.raw_controls => out.push('\t'),
+                        _ => {
+                            out.push(c);
+                            out.push(next);
+                        }
+                    }
+                    continue;
+                }
+                if c == '"' {
+                    in_string = false;
+                }
+                out.push(c);
+                continue;
+            }
+            if (c == ']' || c == '}') && !matches!(last_significant, '[' | '{' | ',') {
+                if noise.trailing_commas.get(closing).copied().unwrap_or(false) {
+                    out.push(',');
+                }
+                closing += 1;
+            }
+            if c == '"' {
+                in_string = true;
+            }
+            out.push(c);
+            last_significant = c;
+        }
+        out
+    }
+
+    fn render(value: &serde_json::Value, pretty: bool) -> String {
+        if pretty {
+            serde_json::to_string_pretty(value).unwrap()
+        } else {
+            serde_json::to_string(value).unwrap()
+        }
+    }
+
+    proptest::proptest! {
+        #![proptest_config(proptest::test_runner::Config::with_cases(256))]
+
+        #[test]
+        fn property_recoverable_noise_yields_the_original_test(
+            (value, expected) in generated_test(),
+            pretty in proptest::bool::ANY,
+            noise in syntax_noise(),
+            wrapping in wrapping(),
+        ) {
+            // Test: Validates every generated response with guaranteed-recoverable noise parses to exactly the generated test.
+            // Justification: Trailing commas and raw newlines used to fail the response or garble fields.
+            let response = wrap(&add_syntax_noise(&noise, &render(&value, pretty)), wrapping);
+
+            let result = parse_comprehension_test_tolerant(&response, "task-prop");
+            proptest::prop_assert!(result.is_ok(), "{:?} -> {:?}", response, result);
+            let test = result.unwrap();
+            proptest::prop_assert_eq!((test.question, test.correct_answer, test.test_type, test.options), expected, "{:?}", response);
+            proptest::prop_assert_eq!(test.task_id, "task-prop");
+        }
+
+        #[test]
+        fn property_truncated_responses_fail(
+            (value, _) in generated_test(),
+            pretty in proptest::bool::ANY,
+            noise in syntax_noise(),
+            cut in proptest::prelude::any::<proptest::sample::Index>(),
+        ) {
+            // Test: Validates a response cut off before its closing brace is rejected instead of yielding a partial test.
+            // Justification: Streaming and token limits truncate output; a half-read question must not become a test.
+            let mut payload = add_syntax_noise(&noise, &render(&value, pretty));
+            let cut = cut.index(payload.len());
+            let cut = (0..=cut).rev().find(|i| payload.is_char_boundary(*i)).unwrap();
+            payload.truncate(cut);
+            let response = format!("Here's the test {{draft}}:\n{}", payload);
+
+            let result = parse_comprehension_test_tolerant(&response, "task-prop");
+            proptest::prop_assert!(result.is_err(), "{:?} -> {:?}", response, result);
+        }
+    }
 }
@@ -7,8 +7,23 @@
 //! Modules:
 //! - `llm_parsers`: Parse LLM responses into structured domain entities
 //! - `sqlite_session_storage`: SQLite-backed session persistence
+//! - `telemetry`: Tracing spans for node execution and provider calls
+//! - `sampling`: Temperature and seed for generation calls
+//! - `response_cache`: On-disk cache of deterministic LLM responses
+//! - `html_to_markdown`: Main-content HTML to markdown conversion for crawled pages
+//! - `ollama_errors`: Friendly errors when the Ollama server is not running
+//! - `ollama_pull`: Pull a missing Ollama model and retry the request
+//! - `retry_policy`: Shared retry, backoff, and timeout policy for provider calls
+//! - `circuit_breaker`: Per-provider circuit breaker that fails fast during outages
 //!
 //! Revision History
+//! - 2026-10-17T15:30:00Z @AI: Add circuit_breaker module.
+//! - 2026-10-17T15:10:00Z @AI: Add retry_policy module.
+//! - 2026-10-17T14:50:00Z @AI: Add ollama_pull module.
+//! - 2026-10-17T14:30:00Z @AI: Add ollama_errors module.
+//! - 2026-10-17T12:10:00Z @AI: Add html_to_markdown module.
+//! - 2026-10-16T21:30:00Z @AI: Add sampling and response_cache modules.
+//! - 2026-10-16T19:50:00Z @AI: Add telemetry module for node and provider call spans.
 //! - 2025-11-23T23:35:00Z @AI: Add config module for configuration management (Phase 5 Sprint 10 Task 5.6).
 //! - 2025-11-23T21:27:00Z @AI: Add llm_parsers module (HEXSER compliance refactoring).
 //! - 2025-11-18T11:22:00Z @AI: Introduce infrastructure module and declare SQLite session storage adapter.
@@ -16,3 +31,11 @@
 pub mod config;
 pub mod llm_parsers;
 pub mod sqlite_session_storage;
+pub mod telemetry;
+pub mod sampling;
+pub mod response_cache;
+pub mod html_to_markdown;
+pub mod ollama_errors;
+pub mod ollama_pull;
+pub mod retry_policy;
+pub mod circuit_breaker;
@@ -0,0 +1,103 @@
+//! Friendly errors for an Ollama server that is not running.
+//!
+//! When nothing listens on the Ollama base URL, Rig surfaces a raw reqwest
+//! connection error ("error sending request for url ..."). The Ollama
+//! adapters pass their provider errors through `not_running_error`, which
+//! recognizes a refused connection anywhere in the error's source chain and
+//! replaces it with instructions to start Ollama and pull the model.
+//!
+//! Revision History
+//! - 2026-10-17T14:30:00Z @AI: Initial connection-refused detection and message for Ollama adapters.
+
+/// Base URL of a local Ollama server on its default port.
+pub const DEFAULT_OLLAMA_BASE_URL: &str = "http://localhost:11434";
+
+/// Returns the friendly message if `error` is a refused connection to Ollama.
+///
+/// # Arguments
+///
+/// * `error` - Error returned by a Rig Ollama call
+/// * `base_url` - Ollama base URL the adapter sent the request to
+/// * `model` - Model the adapter asked for
+///
+/// # Returns
+///
+/// `None` for any other error, so callers keep their own message.
+pub fn not_running_error<E>(error: &E, base_url: &str, model: &str) -> std::option::Option<String>
+where
+    E: std::error::Error + 'static,
+{
+    if is_connection_refused(error) {
+        std::option::Option::Some(not_running_message(base_url, model))
+    } else {
+        std::option::Option::None
+    }
+}
+
+/// Tells the user to start Ollama at `base_url` and pull `model`.
+pub fn not_running_message(base_url: &str, model: &str) -> String {
+    std::format!(
+        "Could not connect to Ollama at {}. Is it running? Start it with `ollama serve`, \
+         pull the model with `ollama pull {}`, then try again.",
+        base_url, model
+    )
+}
+
+/// Returns true if any error in the source chain is a refused TCP connection.
+pub fn is_connection_refused(error: &(dyn std::error::Error + 'static)) -> bool {
+    let mut current = std::option::Option::Some(error);
+    while let std::option::Option::Some(err) = current {
+        if let std::option::Option::Some(io) = err.downcast_ref::<std::io::Error>()
+            && io.kind() == std::io::ErrorKind::ConnectionRefused
+        {
+            return true;
+        }
+        // Some layers wrap the OS error without exposing it as a source
+        if err.to_string().to_lowercase().contains("connection refused") {
+            return true;
+        }
+        current = err.source();
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    /// Returns a local URL that refuses connections (a port that was bound, then released).
+    async fn refused_base_url() -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+        std::format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn test_refused_reqwest_error_maps_to_friendly_message() {
+        // Test: Validates a real connection-refused reqwest error is recognized and names the URL and model.
+        // Justification: The raw "error sending request" text gives new users no hint that Ollama is down.
+        let base_url = refused_base_url().await;
+        let error = reqwest::Client::builder()
+            .no_proxy()
+            .build()
+            .unwrap()
+            .get(std::format!("{}/api/tags", base_url))
+            .send()
+            .await
+            .unwrap_err();
+
+        let message = super::not_running_error(&error, &base_url, "llama3.1").unwrap();
+
+        std::assert!(message.contains(&base_url), "message: {}", message);
+        std::assert!(message.contains("ollama serve"));
+        std::assert!(message.contains("ollama pull llama3.1"));
+    }
+
+    #[test]
+    fn test_other_errors_are_left_alone() {
+        // Test: Validates errors other than a refused connection produce no friendly message.
+        // Justification: Model or parse errors must keep their own text rather than blaming a stopped server.
+        let error = std::io::Error::new(std::io::ErrorKind::TimedOut, "model not found");
+
+        std::assert!(super::not_running_error(&error, super::DEFAULT_OLLAMA_BASE_URL, "llama3.1").is_none());
+    }
+}
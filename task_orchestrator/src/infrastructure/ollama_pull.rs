@@ -0,0 +1,185 @@
+//! Automatic model download for Ollama.
+//!
+//! Ollama answers a request for a model that has not been pulled with an
+//! error such as `model "llama3.1" not found, try pulling it first`. When a
+//! provider enables `auto_pull`, the Ollama adapters wrap their calls in
+//! `retry_after_pull`, which downloads the model through `/api/pull`
+//! (logging its progress) and retries the call once. Auto-pull is off by
+//! default because models are often several gigabytes.
+//!
+//! Revision History
+//! - 2026-10-17T14:50:00Z @AI: Initial /api/pull download and single retry for missing Ollama models.
+
+/// Returns true if an Ollama error message says the model is not available.
+pub fn is_model_not_found(message: &str) -> bool {
+    let lower = message.to_lowercase();
+    lower.contains("model") && lower.contains("not found")
+}
+
+/// Runs `call`; if auto-pull is on and Ollama reports the model missing,
+/// pulls the model and runs `call` once more.
+///
+/// # Arguments
+///
+/// * `auto_pull` - Whether the provider allows downloading missing models
+/// * `base_url` - Ollama server URL
+/// * `model` - Model the call asks for
+/// * `call` - The Ollama request; called at most twice
+///
+/// # Errors
+///
+/// Returns the error of the retried call, or the original error if auto-pull
+/// is off, the error is not a missing model, or the pull fails.
+pub async fn retry_after_pull<T, E, F, Fut>(auto_pull: bool, base_url: &str, model: &str, mut call: F) -> std::result::Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = std::result::Result<T, E>>,
+    E: std::fmt::Display,
+{
+    let result = call().await;
+    match &result {
+        std::result::Result::Err(error) if auto_pull && is_model_not_found(&error.to_string()) => {}
+        _ => return result,
+    }
+
+    tracing::warn!(model, base_url, "Ollama model not found; pulling it before retrying");
+    if let std::result::Result::Err(pull_error) = pull_model(base_url, model).await {
+        tracing::warn!(model, error = %pull_error, "Ollama model pull failed");
+        return result;
+    }
+    call().await
+}
+
+/// Downloads `model` through Ollama's `/api/pull`, logging streamed progress.
+///
+/// # Errors
+///
+/// Returns an error if the request fails or Ollama reports a pull error.
+pub async fn pull_model(base_url: &str, model: &str) -> std::result::Result<(), String> {
+    let url = std::format!("{}/api/pull", base_url.trim_end_matches('/'));
+    let mut response = reqwest::Client::new()
+        .post(&url)
+        .json(&serde_json::json!({ "model": model, "stream": true }))
+        .send()
+        .await
+        .map_err(|e| std::format!("Failed to pull {}: {}", model, e))?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return std::result::Result::Err(std::format!("Failed to pull {}: HTTP {} {}", model, status, body));
+    }
+
+    // Progress arrives as one JSON object per line, split arbitrarily across chunks
+    let mut pending: std::vec::Vec<u8> = std::vec::Vec::new();
+    while let std::option::Option::Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| std::format!("Failed to pull {}: {}", model, e))?
+    {
+        pending.extend_from_slice(&chunk);
+        while let std::option::Option::Some(end) = pending.iter().position(|&b| b == b'\n') {
+            let line: std::vec::Vec<u8> = pending.drain(..=end).collect();
+            log_progress(model, &line)?;
+        }
+    }
+    log_progress(model, &pending)
+}
+
+/// Logs one progress line of a pull, failing on a reported error.
+fn log_progress(model: &str, line: &[u8]) -> std::result::Result<(), String> {
+    let std::result::Result::Ok(progress) = serde_json::from_slice::<serde_json::Value>(line) else {
+        return std::result::Result::Ok(());
+    };
+    if let std::option::Option::Some(error) = progress["error"].as_str() {
+        return std::result::Result::Err(std::format!("Failed to pull {}: {}", model, error));
+    }
+    let status = progress["status"].as_str().unwrap_or_default();
+    match (progress["completed"].as_u64(), progress["total"].as_u64()) {
+        (std::option::Option::Some(completed), std::option::Option::Some(total)) if total > 0 => {
+            tracing::info!(model, status, percent = completed * 100 / total, "pulling Ollama model");
+        }
+        _ => tracing::info!(model, status, "pulling Ollama model"),
+    }
+    std::result::Result::Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    /// Serves a mock Ollama: `/api/chat` answers 404 "model not found" until
+    /// `/api/pull` has been called, then 200. Returns the base URL and the
+    /// request lines received.
+    async fn spawn_mock_ollama() -> (String, std::sync::Arc<std::sync::Mutex<std::vec::Vec<String>>>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = std::format!("http://{}", listener.local_addr().unwrap());
+        let requests = std::sync::Arc::new(std::sync::Mutex::new(std::vec::Vec::new()));
+        let seen = requests.clone();
+        tokio::spawn(async move {
+            let mut pulled = false;
+            while let std::result::Result::Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = [0u8; 8192];
+                let n = tokio::io::AsyncReadExt::read(&mut socket, &mut buf).await.unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..n]).into_owned();
+                let request_line = request.lines().next().unwrap_or_default().to_string();
+                seen.lock().unwrap().push(request_line.clone());
+
+                let (status, body) = if request_line.starts_with("POST /api/pull ") {
+                    pulled = true;
+                    ("200 OK", String::from("{\"status\":\"pulling manifest\"}\n{\"status\":\"downloading\",\"completed\":50,\"total\":100}\n{\"status\":\"success\"}\n"))
+                } else if pulled {
+                    ("200 OK", String::from("{\"message\":{\"content\":\"hi\"}}"))
+                } else {
+                    ("404 Not Found", String::from("{\"error\":\"model \\\"llama3.1\\\" not found, try pulling it first\"}"))
+                };
+                let response = std::format!(
+                    "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = tokio::io::AsyncWriteExt::write_all(&mut socket, response.as_bytes()).await;
+            }
+        });
+        (base_url, requests)
+    }
+
+    /// Sends a chat request the way an adapter would, failing with the response body on error.
+    async fn chat(base_url: &str) -> std::result::Result<String, String> {
+        let response = reqwest::Client::new()
+            .post(std::format!("{}/api/chat", base_url))
+            .json(&serde_json::json!({ "model": "llama3.1", "messages": [] }))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        let succeeded = response.status().is_success();
+        let body = response.text().await.map_err(|e| e.to_string())?;
+        if succeeded { std::result::Result::Ok(body) } else { std::result::Result::Err(body) }
+    }
+
+    #[tokio::test]
+    async fn test_missing_model_is_pulled_and_request_retried() {
+        // Test: Validates a "model not found" reply triggers /api/pull and one retry of the original request.
+        // Justification: With auto_pull on, a first run on a fresh machine must succeed instead of failing on the missing model.
+        let (base_url, requests) = spawn_mock_ollama().await;
+
+        let reply = super::retry_after_pull(true, &base_url, "llama3.1", || chat(&base_url)).await.unwrap();
+
+        std::assert!(reply.contains("hi"));
+        let requests = requests.lock().unwrap().clone();
+        std::assert_eq!(requests.len(), 3, "requests: {:?}", requests);
+        std::assert!(requests[0].starts_with("POST /api/chat "));
+        std::assert!(requests[1].starts_with("POST /api/pull "));
+        std::assert!(requests[2].starts_with("POST /api/chat "));
+    }
+
+    #[tokio::test]
+    async fn test_missing_model_is_not_pulled_when_auto_pull_is_off() {
+        // Test: Validates the missing-model error is returned as-is without a pull when auto_pull is false.
+        // Justification: Auto-pull defaults off so nobody gets a surprise multi-gigabyte download.
+        let (base_url, requests) = spawn_mock_ollama().await;
+
+        let error = super::retry_after_pull(false, &base_url, "llama3.1", || chat(&base_url)).await.unwrap_err();
+
+        std::assert!(super::is_model_not_found(&error), "error: {}", error);
+        std::assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,337 @@
+//! On-disk cache of LLM responses for repeatable calls.
+//!
+//! Re-running the same PRD parse during development repeats identical LLM
+//! calls. `ResponseCache` stores each response in its own JSON file, named
+//! by a SHA-256 key over the provider, model, sampling parameters, prompt,
+//! and the schema the response must follow. Entries older than the TTL are
+//! ignored and overwritten.
+//!
+//! Only deterministic calls (temperature 0 or a fixed seed, see
+//! `SamplingParams::is_deterministic`) are cached; any other call goes
+//! straight to the provider. Cache read and write failures are logged and
+//! treated as misses, never as call failures.
+//!
+//! A response is only cached once it passes the caller's schema check, so a
+//! malformed reply is asked for again on the next call instead of being
+//! replayed until the entry expires.
+//!
+//! Revision History
+//! - 2026-10-18T04:40:00Z @AI: cached_call stores only responses that pass the caller's schema check and ignores cached ones that fail it.
+//! - 2026-10-17T17:30:00Z @AI: Add CacheKey::for_embedding so embeddings can be cached alongside responses.
+//! - 2026-10-16T21:30:00Z @AI: Initial ResponseCache with TTL and cached_call.
+
+/// Cache key identifying one LLM request.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey(String);
+
+impl CacheKey {
+    /// Derives the key for a request.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - Provider name (e.g. "ollama")
+    /// * `model` - Model name
+    /// * `sampling` - Temperature and seed; differing values never share an entry
+    /// * `prompt` - Full prompt text, including any preamble
+    /// * `schema` - Description of the expected response format (a JSON schema or format name)
+    pub fn new(
+        provider: &str,
+        model: &str,
+        sampling: &crate::infrastructure::sampling::SamplingParams,
+        prompt: &str,
+        schema: &str,
+    ) -> Self {
+        let prompt_hash = sha256_hex(prompt.as_bytes());
+        let schema_hash = sha256_hex(schema.as_bytes());
+        let material = std::format!(
+            "{}\n{}\n{:?}\n{:?}\n{}\n{}",
+            provider, model, sampling.temperature, sampling.seed, prompt_hash, schema_hash
+        );
+        CacheKey(sha256_hex(material.as_bytes()))
+    }
+
+    /// Derives the key for embedding `text` with a model.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - Embedding model name
+    /// * `dimension` - Dimension of the model's vectors, so a model change under the same name misses
+    /// * `text` - Text being embedded
+    pub fn for_embedding(model: &str, dimension: usize, text: &str) -> Self {
+        let material = std::format!("embedding\n{}\n{}\n{}", model, dimension, sha256_hex(text.as_bytes()));
+        CacheKey(sha256_hex(material.as_bytes()))
+    }
+
+    /// Returns the key as a hex string.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+/// A cached response and when it was stored.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct CacheEntry {
+    /// Unix timestamp (seconds) of when the response was stored
+    created_at: u64,
+
+    /// Raw response text
+    response: String,
+}
+
+/// Directory of cached LLM responses, one JSON file per key.
+///
+/// # Examples
+///
+/// ```
+/// use task_orchestrator::infrastructure::response_cache::{CacheKey, ResponseCache};
+/// use task_orchestrator::infrastructure::sampling::SamplingParams;
+///
+/// let dir = std::env::temp_dir().join("rigger_response_cache_doc");
+/// let cache = ResponseCache::new(&dir, std::option::Option::Some(std::time::Duration::from_secs(3600)));
+/// let key = CacheKey::new("ollama", "llama3.2", &SamplingParams::default(), "prompt", "schema");
+///
+/// cache.put(&key, "response");
+/// std::assert_eq!(cache.get(&key).as_deref(), std::option::Option::Some("response"));
+/// # std::fs::remove_dir_all(&dir).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    dir: std::path::PathBuf,
+    ttl: std::option::Option<std::time::Duration>,
+}
+
+impl ResponseCache {
+    /// Creates a cache rooted at `dir`; the directory is created on first write.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - Directory holding the cache files
+    /// * `ttl` - Maximum entry age; `None` keeps entries forever
+    pub fn new(dir: impl std::convert::AsRef<std::path::Path>, ttl: std::option::Option<std::time::Duration>) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+            ttl,
+        }
+    }
+
+    /// Returns the cache directory.
+    pub fn dir(&self) -> &std::path::Path {
+        &self.dir
+    }
+
+    /// Returns the cached response for `key`, or `None` if it is missing,
+    /// expired, or unreadable.
+    pub fn get(&self, key: &CacheKey) -> std::option::Option<String> {
+        let content = std::fs::read_to_string(self.entry_path(key)).ok()?;
+        let entry: CacheEntry = match serde_json::from_str(&content) {
+            std::result::Result::Ok(entry) => entry,
+            std::result::Result::Err(e) => {
+                tracing::warn!(key = key.as_str(), error = %e, "ignoring unreadable response cache entry");
+                return std::option::Option::None;
+            }
+        };
+        let age = std::time::Duration::from_secs(unix_now().saturating_sub(entry.created_at));
+        if self.ttl.is_some_and(|ttl| age > ttl) {
+            return std::option::Option::None;
+        }
+        std::option::Option::Some(entry.response)
+    }
+
+    /// Stores `response` under `key`, replacing any previous entry.
+    ///
+    /// Failures are logged and otherwise ignored.
+    pub fn put(&self, key: &CacheKey, response: &str) {
+        let entry = CacheEntry {
+            created_at: unix_now(),
+            response: std::string::String::from(response),
+        };
+        let written = std::fs::create_dir_all(&self.dir)
+            .and_then(|_| serde_json::to_string(&entry).map_err(std::io::Error::other))
+            .and_then(|json| std::fs::write(self.entry_path(key), json));
+        if let std::result::Result::Err(e) = written {
+            tracing::warn!(key = key.as_str(), dir = %self.dir.display(), error = %e, "failed to write response cache entry");
+        }
+    }
+
+    fn entry_path(&self, key: &CacheKey) -> std::path::PathBuf {
+        self.dir.join(std::format!("{}.json", key.as_str()))
+    }
+}
+
+/// Runs `request` through the cache if the call is deterministic.
+///
+/// Returns a cached response that passes `validate` without calling
+/// `request`. On a miss, calls `request` and stores its response only if it
+/// passes `validate`; the response is returned either way, so the caller
+/// still sees and handles the invalid reply. Without a cache, or for
+/// non-deterministic sampling, just calls `request`.
+///
+/// # Arguments
+///
+/// * `cache` - Cache to use, if caching is enabled
+/// * `key` - Key of the request
+/// * `sampling` - Sampling parameters the request is sent with
+/// * `validate` - Checks a response against the schema the caller expects
+/// * `request` - Performs the provider call
+///
+/// # Errors
+///
+/// Returns the error from `request`.
+pub async fn cached_call<V, F, Fut>(
+    cache: std::option::Option<&ResponseCache>,
+    key: &CacheKey,
+    sampling: &crate::infrastructure::sampling::SamplingParams,
+    validate: V,
+    request: F,
+) -> std::result::Result<String, String>
+where
+    V: Fn(&str) -> std::result::Result<(), String>,
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = std::result::Result<String, String>>,
+{
+    let cache = match cache.filter(|_| sampling.is_deterministic()) {
+        std::option::Option::Some(cache) => cache,
+        std::option::Option::None => return request().await,
+    };
+
+    if let std::option::Option::Some(response) = cache.get(key) {
+        match validate(&response) {
+            std::result::Result::Ok(()) => {
+                tracing::debug!(key = key.as_str(), "response cache hit");
+                return std::result::Result::Ok(response);
+            }
+            std::result::Result::Err(e) => {
+                tracing::warn!(key = key.as_str(), error = %e, "ignoring cached response that fails validation");
+            }
+        }
+    }
+
+    let response = request().await?;
+    match validate(&response) {
+        std::result::Result::Ok(()) => cache.put(key, &response),
+        std::result::Result::Err(e) => tracing::debug!(key = key.as_str(), error = %e, "not caching response that fails validation"),
+    }
+    std::result::Result::Ok(response)
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    sha2::Digest::finalize(<sha2::Sha256 as sha2::Digest>::new_with_prefix(bytes))
+        .iter()
+        .map(|byte| std::format!("{:02x}", byte))
+        .collect()
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    fn temp_cache(name: &str, ttl: std::option::Option<std::time::Duration>) -> super::ResponseCache {
+        let dir = std::env::temp_dir().join(std::format!("rigger_response_cache_{}_{}", name, uuid::Uuid::new_v4()));
+        super::ResponseCache::new(dir, ttl)
+    }
+
+    async fn counted_call(
+        cache: &super::ResponseCache,
+        sampling: &crate::infrastructure::sampling::SamplingParams,
+        requests: &std::sync::atomic::AtomicUsize,
+    ) -> String {
+        let key = super::CacheKey::new("ollama", "llama3.2", sampling, "Break down: build login page", "subtasks");
+        super::cached_call(std::option::Option::Some(cache), &key, sampling, |_| std::result::Result::Ok(()), || async {
+            let n = requests.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            std::result::Result::Ok(std::format!("response {}", n))
+        })
+        .await
+        .unwrap()
+    }
+
+    /// Calls through `cache` with a JSON check, answering with the last of `replies`.
+    async fn json_call(
+        cache: &super::ResponseCache,
+        key: &super::CacheKey,
+        replies: &std::sync::Mutex<std::vec::Vec<String>>,
+        requests: &std::sync::atomic::AtomicUsize,
+    ) -> String {
+        let sampling = crate::infrastructure::sampling::SamplingParams::new(std::option::Option::Some(0.0), std::option::Option::None);
+        let validate = |response: &str| serde_json::from_str::<serde_json::Value>(response).map(|_| ()).map_err(|e| e.to_string());
+        super::cached_call(std::option::Option::Some(cache), key, &sampling, validate, || async {
+            requests.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            std::result::Result::Ok(replies.lock().unwrap().pop().unwrap())
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_temperature_zero_hits_cache_and_sampled_call_bypasses_it() {
+        // Test: Validates a repeated temperature-0 call is served from the cache without a provider request, while temperature>0 calls always go out.
+        // Justification: Caching sampled calls would freeze one random answer; not caching greedy ones wastes identical requests.
+        let cache = temp_cache("determinism", std::option::Option::None);
+        let requests = std::sync::atomic::AtomicUsize::new(0);
+
+        let greedy = crate::infrastructure::sampling::SamplingParams::new(std::option::Option::Some(0.0), std::option::Option::None);
+        std::assert_eq!(counted_call(&cache, &greedy, &requests).await, "response 1");
+        std::assert_eq!(counted_call(&cache, &greedy, &requests).await, "response 1");
+        std::assert_eq!(requests.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        let sampled = crate::infrastructure::sampling::SamplingParams::new(std::option::Option::Some(0.7), std::option::Option::None);
+        std::assert_eq!(counted_call(&cache, &sampled, &requests).await, "response 2");
+        std::assert_eq!(counted_call(&cache, &sampled, &requests).await, "response 3");
+        std::assert_eq!(requests.load(std::sync::atomic::Ordering::SeqCst), 3);
+
+        std::fs::remove_dir_all(cache.dir()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_responses_failing_validation_are_not_cached() {
+        // Test: Validates a reply that fails the schema check is returned but not stored, and the next call asks again and caches the valid reply.
+        // Justification: Caching a malformed reply would replay the same failure on every run until the entry expired.
+        let cache = temp_cache("validation", std::option::Option::None);
+        let sampling = crate::infrastructure::sampling::SamplingParams::new(std::option::Option::Some(0.0), std::option::Option::None);
+        let key = super::CacheKey::new("ollama", "llama3.2", &sampling, "List subtasks", "subtasks");
+        let replies = std::sync::Mutex::new(std::vec![std::string::String::from("[]"), std::string::String::from("not json")]);
+        let requests = std::sync::atomic::AtomicUsize::new(0);
+
+        std::assert_eq!(json_call(&cache, &key, &replies, &requests).await, "not json");
+        std::assert_eq!(cache.get(&key), std::option::Option::None);
+        std::assert_eq!(json_call(&cache, &key, &replies, &requests).await, "[]");
+        std::assert_eq!(json_call(&cache, &key, &replies, &requests).await, "[]");
+        std::assert_eq!(requests.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+        // An invalid entry written before responses were validated is asked for again
+        cache.put(&key, "not json");
+        replies.lock().unwrap().push(std::string::String::from("[1]"));
+        std::assert_eq!(json_call(&cache, &key, &replies, &requests).await, "[1]");
+        std::assert_eq!(cache.get(&key).as_deref(), std::option::Option::Some("[1]"));
+
+        std::fs::remove_dir_all(cache.dir()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_expired_entries_and_changed_keys_miss() {
+        // Test: Validates entries past the TTL are ignored, and that model, seed, and schema are part of the key.
+        // Justification: A stale or mismatched hit would silently return output for a different request.
+        let cache = temp_cache("ttl", std::option::Option::Some(std::time::Duration::from_secs(60)));
+        let sampling = crate::infrastructure::sampling::SamplingParams::new(std::option::Option::None, std::option::Option::Some(7));
+        let key = super::CacheKey::new("ollama", "llama3.2", &sampling, "prompt", "schema");
+
+        let stale = super::CacheEntry { created_at: super::unix_now() - 120, response: std::string::String::from("old") };
+        std::fs::create_dir_all(cache.dir()).unwrap();
+        std::fs::write(cache.entry_path(&key), serde_json::to_string(&stale).unwrap()).unwrap();
+        std::assert_eq!(cache.get(&key), std::option::Option::None);
+
+        cache.put(&key, "fresh");
+        std::assert_eq!(cache.get(&key).as_deref(), std::option::Option::Some("fresh"));
+
+        let other_seed = crate::infrastructure::sampling::SamplingParams::new(std::option::Option::None, std::option::Option::Some(8));
+        std::assert_ne!(key, super::CacheKey::new("ollama", "llama3.2", &other_seed, "prompt", "schema"));
+        std::assert_ne!(key, super::CacheKey::new("ollama", "qwen2.5", &sampling, "prompt", "schema"));
+        std::assert_ne!(key, super::CacheKey::new("ollama", "llama3.2", &sampling, "prompt", "other schema"));
+
+        std::fs::remove_dir_all(cache.dir()).unwrap();
+    }
+}
@@ -0,0 +1,501 @@
+//! Shared retry and timeout policy for provider-calling adapters.
+//!
+//! Adapters that call an LLM provider wrap each request in
+//! `RetryPolicy::run`, which bounds every attempt by `timeout` and retries
+//! transient failures (timeouts, refused or reset connections, and HTTP statuses listed
+//! in `retryable_statuses`) with capped exponential backoff. Each delay is
+//! shortened by a random fraction of up to `jitter` so that parallel callers
+//! do not retry in lockstep. The policy is built from a provider's
+//! `timeout_seconds` and `max_retries` in config, and the MLX adapter derives
+//! its restart backoff from the same computation.
+//!
+//! A policy may carry the provider's `CircuitBreaker`: each attempt asks it
+//! for permission and reports its outcome, so once the provider is down
+//! calls fail at once with `CallError::CircuitOpen` instead of timing out.
+//! Only timeouts and retryable errors count as failures; a non-retryable
+//! error such as a 400 means the provider answered, so it counts as a success.
+//!
+//! With `with_metrics`, every retry, every success after a retry, and every
+//! call that runs out of retries is reported to a metrics collector under
+//! the provider's name, the same events the MLX adapter reports for restarts.
+//!
+//! Revision History
+//! - 2026-10-17T23:55:00Z @AI: Breaker counts only timeouts and retryable errors as failures; refused connections are retryable.
+//! - 2026-10-17T21:40:00Z @AI: Add optional metrics collector receiving a RetryEvent for each retry and its outcome.
+//! - 2026-10-17T15:30:00Z @AI: Add optional circuit breaker consulted before and updated after each attempt.
+//! - 2026-10-17T15:10:00Z @AI: Initial RetryPolicy with capped, jittered backoff and per-attempt timeout.
+
+/// How provider calls are timed out and retried.
+///
+/// # Examples
+///
+/// ```
+/// use task_orchestrator::infrastructure::retry_policy::RetryPolicy;
+///
+/// let policy = RetryPolicy {
+///     jitter: 0.0,
+///     ..RetryPolicy::default()
+/// };
+/// std::assert_eq!(policy.delay_for(1), std::time::Duration::from_millis(500));
+/// std::assert_eq!(policy.delay_for(2), std::time::Duration::from_secs(1));
+/// std::assert!(policy.is_retryable_status(429));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Retries allowed after the first attempt (0 disables retrying)
+    pub max_retries: u32,
+
+    /// Wait before the first retry; doubles after each further failure
+    pub base_delay: std::time::Duration,
+
+    /// Upper bound for the wait between attempts
+    pub max_delay: std::time::Duration,
+
+    /// Largest fraction (0.0 to 1.0) by which a delay is randomly shortened
+    pub jitter: f64,
+
+    /// HTTP statuses worth retrying (rate limits and transient server errors)
+    pub retryable_statuses: std::vec::Vec<u16>,
+
+    /// Deadline for a single attempt
+    pub timeout: std::time::Duration,
+
+    /// Breaker shared by all calls to the provider; `None` never short-circuits
+    pub circuit_breaker: std::option::Option<std::sync::Arc<crate::infrastructure::circuit_breaker::CircuitBreaker>>,
+
+    /// Collector retry events are reported to; `None` records nothing
+    pub metrics: std::option::Option<RetryMetrics>,
+}
+
+/// Where a `RetryPolicy` reports its retry events.
+#[derive(Clone)]
+pub struct RetryMetrics {
+    /// Provider name the events are counted under (e.g., "ollama")
+    pub provider: String,
+
+    /// Collector receiving the events
+    pub collector: std::sync::Arc<dyn crate::ports::metrics_collector_port::MetricsCollectorPort>,
+}
+
+impl std::fmt::Debug for RetryMetrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryMetrics").field("provider", &self.provider).finish_non_exhaustive()
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: std::time::Duration::from_millis(500),
+            max_delay: std::time::Duration::from_secs(8),
+            jitter: 0.2,
+            retryable_statuses: std::vec![408, 429, 500, 502, 503, 504],
+            timeout: std::time::Duration::from_secs(60),
+            circuit_breaker: std::option::Option::None,
+            metrics: std::option::Option::None,
+        }
+    }
+}
+
+/// Why a call run under a `RetryPolicy` failed.
+#[derive(Debug)]
+pub enum CallError<E> {
+    /// The last attempt returned an error
+    Failed(E),
+
+    /// The last attempt did not finish within the policy's timeout
+    TimedOut(std::time::Duration),
+
+    /// The provider's circuit breaker is open; no request was sent
+    CircuitOpen(crate::infrastructure::circuit_breaker::CircuitOpen),
+}
+
+impl<E> CallError<E> {
+    /// Describes the failure, formatting a provider error with `describe`.
+    pub fn describe(self, describe: impl FnOnce(E) -> String) -> String {
+        match self {
+            CallError::Failed(error) => describe(error),
+            CallError::TimedOut(timeout) => std::format!("Request timed out after {}s", timeout.as_secs_f64()),
+            CallError::CircuitOpen(open) => open.to_string(),
+        }
+    }
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for CallError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CallError::Failed(error) => write!(f, "{}", error),
+            CallError::TimedOut(timeout) => write!(f, "Request timed out after {}s", timeout.as_secs_f64()),
+            CallError::CircuitOpen(open) => write!(f, "{}", open),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Builds the policy for a provider from its `timeout_seconds` and `max_retries`.
+    pub fn from_provider_config(config: &rigger_core::config::provider::ProviderConfig) -> Self {
+        Self {
+            max_retries: u32::try_from(config.max_retries).unwrap_or(u32::MAX),
+            timeout: std::time::Duration::from_secs(config.timeout_seconds),
+            ..Self::default()
+        }
+    }
+
+    /// Guards calls with `breaker`, usually the provider's shared one from
+    /// `circuit_breaker::for_provider`.
+    pub fn with_circuit_breaker(mut self, breaker: std::sync::Arc<crate::infrastructure::circuit_breaker::CircuitBreaker>) -> Self {
+        self.circuit_breaker = std::option::Option::Some(breaker);
+        self
+    }
+
+    /// Reports each retry and its outcome to `collector`, counted under `provider`.
+    pub fn with_metrics(
+        mut self,
+        provider: &str,
+        collector: std::sync::Arc<dyn crate::ports::metrics_collector_port::MetricsCollectorPort>,
+    ) -> Self {
+        self.metrics = std::option::Option::Some(RetryMetrics {
+            provider: String::from(provider),
+            collector,
+        });
+        self
+    }
+
+    /// Returns the un-jittered wait before retry number `retry` (1-based):
+    /// `base_delay * 2^(retry - 1)`, capped at `max_delay`.
+    pub fn delay_for(&self, retry: u32) -> std::time::Duration {
+        let factor = 2u32.checked_pow(retry.saturating_sub(1));
+        match factor.and_then(|factor| self.base_delay.checked_mul(factor)) {
+            std::option::Option::Some(delay) => std::cmp::min(delay, self.max_delay),
+            std::option::Option::None => self.max_delay,
+        }
+    }
+
+    /// Returns `delay_for(retry)` shortened by `sample * jitter` of itself.
+    ///
+    /// `sample` is a value in `[0, 1]`; the result always lies between
+    /// `(1 - jitter) * delay_for(retry)` and `delay_for(retry)`.
+    pub fn jittered_delay(&self, retry: u32, sample: f64) -> std::time::Duration {
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        let scale = 1.0 - jitter * sample.clamp(0.0, 1.0);
+        self.delay_for(retry).mul_f64(scale)
+    }
+
+    /// Returns the wait before retry number `retry`, with random jitter applied.
+    pub fn delay(&self, retry: u32) -> std::time::Duration {
+        self.jittered_delay(retry, random_unit())
+    }
+
+    /// Returns true if a response with HTTP `status` should be retried.
+    pub fn is_retryable_status(&self, status: u16) -> bool {
+        self.retryable_statuses.contains(&status)
+    }
+
+    /// Returns true if a provider error message describes a transient failure:
+    /// a timeout, a refused or reset connection, or one of the retryable HTTP statuses.
+    pub fn is_retryable_error(&self, message: &str) -> bool {
+        let lower = message.to_lowercase();
+        if lower.contains("timed out")
+            || lower.contains("timeout")
+            || lower.contains("connection reset")
+            || lower.contains("connection refused")
+        {
+            return true;
+        }
+        lower
+            .split(|c: char| !c.is_ascii_digit())
+            .filter_map(|token| token.parse::<u16>().ok())
+            .any(|status| self.is_retryable_status(status))
+    }
+
+    /// Runs `call` with a per-attempt timeout, retrying transient failures.
+    ///
+    /// # Arguments
+    ///
+    /// * `call` - The provider request; called at most `max_retries + 1` times
+    ///
+    /// # Errors
+    ///
+    /// Returns the last failure once retries are exhausted, or immediately
+    /// for an error `is_retryable_error` does not consider transient.
+    /// Returns `CallError::CircuitOpen` without calling if the breaker is open.
+    pub async fn run<T, E, F, Fut>(&self, mut call: F) -> std::result::Result<T, CallError<E>>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = std::result::Result<T, E>>,
+        E: std::fmt::Display,
+    {
+        let mut retry: u32 = 0;
+        loop {
+            if let std::option::Option::Some(breaker) = &self.circuit_breaker {
+                breaker.try_acquire().map_err(CallError::CircuitOpen)?;
+            }
+            let outcome = tokio::time::timeout(self.timeout, call()).await;
+            if let std::option::Option::Some(breaker) = &self.circuit_breaker {
+                match &outcome {
+                    std::result::Result::Ok(std::result::Result::Err(error)) if self.is_retryable_error(&error.to_string()) => {
+                        breaker.record_failure()
+                    }
+                    std::result::Result::Ok(_) => breaker.record_success(),
+                    std::result::Result::Err(_) => breaker.record_failure(),
+                }
+            }
+
+            let failure = match outcome {
+                std::result::Result::Ok(std::result::Result::Ok(value)) => {
+                    if retry > 0 {
+                        self.record_retry(crate::domain::performance_metrics::RetryEvent::SucceededAfterRetry).await;
+                    }
+                    return std::result::Result::Ok(value);
+                }
+                std::result::Result::Ok(std::result::Result::Err(error)) => {
+                    if !self.is_retryable_error(&error.to_string()) {
+                        return std::result::Result::Err(CallError::Failed(error));
+                    }
+                    CallError::Failed(error)
+                }
+                std::result::Result::Err(_) => CallError::TimedOut(self.timeout),
+            };
+
+            if retry >= self.max_retries {
+                if self.max_retries > 0 {
+                    self.record_retry(crate::domain::performance_metrics::RetryEvent::Exhausted).await;
+                }
+                return std::result::Result::Err(failure);
+            }
+            retry += 1;
+            let delay = self.delay(retry);
+            tracing::warn!(retry, error = %failure, delay_ms = delay.as_millis() as u64, "provider call failed; retrying");
+            self.record_retry(crate::domain::performance_metrics::RetryEvent::Attempt).await;
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Records a retry event if a metrics collector is set; failures are only logged.
+    async fn record_retry(&self, event: crate::domain::performance_metrics::RetryEvent) {
+        let std::option::Option::Some(metrics) = &self.metrics else {
+            return;
+        };
+        if let std::result::Result::Err(e) = metrics.collector.record_retry(&metrics.provider, event).await {
+            tracing::warn!(error = %e, provider = %metrics.provider, "failed to record retry event");
+        }
+    }
+}
+
+/// Returns a pseudo-random value in `[0, 1)` for jitter.
+fn random_unit() -> f64 {
+    // 53 random bits fill an f64 mantissa exactly
+    (uuid::Uuid::new_v4().as_u128() >> 75) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    fn policy() -> super::RetryPolicy {
+        super::RetryPolicy {
+            max_retries: 3,
+            base_delay: std::time::Duration::from_millis(100),
+            max_delay: std::time::Duration::from_millis(1_000),
+            jitter: 0.5,
+            retryable_statuses: std::vec![429, 503],
+            timeout: std::time::Duration::from_millis(50),
+            circuit_breaker: std::option::Option::None,
+            metrics: std::option::Option::None,
+        }
+    }
+
+    #[test]
+    fn test_backoff_is_monotonic_and_capped() {
+        // Test: Validates delays double per retry, never decrease, and stop at max_delay even for huge retry counts.
+        // Justification: An uncapped or overflowing backoff would stall a run for hours or panic.
+        let policy = policy();
+
+        std::assert_eq!(policy.delay_for(1), std::time::Duration::from_millis(100));
+        std::assert_eq!(policy.delay_for(2), std::time::Duration::from_millis(200));
+        std::assert_eq!(policy.delay_for(4), std::time::Duration::from_millis(800));
+        std::assert_eq!(policy.delay_for(5), std::time::Duration::from_millis(1_000));
+        std::assert_eq!(policy.delay_for(u32::MAX), std::time::Duration::from_millis(1_000));
+
+        let delays: std::vec::Vec<std::time::Duration> = (1..=64).map(|retry| policy.delay_for(retry)).collect();
+        std::assert!(delays.windows(2).all(|pair| pair[0] <= pair[1]), "delays: {:?}", delays);
+        std::assert!(delays.iter().all(|delay| *delay <= policy.max_delay));
+    }
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        // Test: Validates jittered delays lie between (1 - jitter) and 1 times the base delay, for fixed and random samples.
+        // Justification: Jitter must spread retries out without ever exceeding the cap or collapsing to zero.
+        let policy = policy();
+
+        std::assert_eq!(policy.jittered_delay(3, 0.0), std::time::Duration::from_millis(400));
+        std::assert_eq!(policy.jittered_delay(3, 1.0), std::time::Duration::from_millis(200));
+        std::assert_eq!(policy.jittered_delay(3, 7.0), std::time::Duration::from_millis(200));
+
+        for retry in 1..=8 {
+            let full = policy.delay_for(retry);
+            for _ in 0..50 {
+                let delay = policy.delay(retry);
+                std::assert!(delay <= full && delay >= full.mul_f64(0.5), "retry {}: {:?} vs {:?}", retry, delay, full);
+            }
+        }
+
+        let no_jitter = super::RetryPolicy { jitter: 0.0, ..policy };
+        std::assert_eq!(no_jitter.delay(2), no_jitter.delay_for(2));
+    }
+
+    #[test]
+    fn test_from_provider_config_uses_timeout_and_max_retries() {
+        // Test: Validates the policy takes timeout_seconds and max_retries from the provider config.
+        // Justification: Changing a provider's limits in config must change how every adapter calls it.
+        let config = rigger_core::config::provider::ProviderConfig {
+            provider_type: rigger_core::config::provider::ProviderType::Ollama,
+            base_url: String::from("http://localhost:11434"),
+            api_key_env: std::option::Option::None,
+            timeout_seconds: 120,
+            max_retries: 5,
+            default_model: String::from("llama3.1"),
+            auto_pull: false,
+        };
+
+        let policy = super::RetryPolicy::from_provider_config(&config);
+
+        std::assert_eq!(policy.max_retries, 5);
+        std::assert_eq!(policy.timeout, std::time::Duration::from_secs(120));
+        std::assert_eq!(policy.retryable_statuses, super::RetryPolicy::default().retryable_statuses);
+    }
+
+    #[tokio::test]
+    async fn test_run_retries_transient_errors_only() {
+        // Test: Validates run retries timeouts and retryable statuses up to max_retries but returns other errors at once.
+        // Justification: Retrying a bad request or missing API key only delays the real error.
+        let policy = super::RetryPolicy {
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(2),
+            ..policy()
+        };
+
+        let calls = std::sync::atomic::AtomicU32::new(0);
+        let result: std::result::Result<(), _> = policy
+            .run(|| async {
+                calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                std::result::Result::Err::<(), String>(String::from("HTTP 503 Service Unavailable"))
+            })
+            .await;
+        std::assert!(std::matches!(result, std::result::Result::Err(super::CallError::Failed(_))));
+        std::assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 4);
+
+        let calls = std::sync::atomic::AtomicU32::new(0);
+        let result: std::result::Result<(), _> = policy
+            .run(|| async {
+                calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                std::result::Result::Err::<(), String>(String::from("HTTP 401 Unauthorized"))
+            })
+            .await;
+        std::assert!(result.is_err());
+        std::assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        let calls = std::sync::atomic::AtomicU32::new(0);
+        let result = policy
+            .run(|| async {
+                if calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0 {
+                    tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+                }
+                std::result::Result::Ok::<&str, String>("done")
+            })
+            .await;
+        std::assert_eq!(result.unwrap(), "done");
+        std::assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_run_fails_fast_while_circuit_is_open() {
+        // Test: Validates failures open the provider's breaker and later runs return CircuitOpen without calling.
+        // Justification: During an outage each task must fail at once instead of waiting out the timeout.
+        let breaker = std::sync::Arc::new(crate::infrastructure::circuit_breaker::CircuitBreaker::new(
+            "ollama",
+            2,
+            std::time::Duration::from_secs(60),
+        ));
+        let policy = super::RetryPolicy { max_retries: 0, ..policy() }.with_circuit_breaker(breaker.clone());
+        let calls = std::sync::atomic::AtomicU32::new(0);
+        let failing = || async {
+            calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            std::result::Result::Err::<(), String>(String::from("connection refused"))
+        };
+
+        std::assert!(std::matches!(policy.run(failing).await, std::result::Result::Err(super::CallError::Failed(_))));
+        std::assert!(std::matches!(policy.run(failing).await, std::result::Result::Err(super::CallError::Failed(_))));
+        let result = policy.run(failing).await;
+
+        std::assert!(std::matches!(result, std::result::Result::Err(super::CallError::CircuitOpen(_))));
+        std::assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 2);
+        std::assert!(breaker.is_open());
+    }
+
+    #[tokio::test]
+    async fn test_non_retryable_errors_do_not_open_circuit() {
+        // Test: Validates repeated 4xx errors leave the breaker closed and every call reaches the provider.
+        // Justification: A stream of bad requests means the provider is up; it must not cut off other callers.
+        let breaker = std::sync::Arc::new(crate::infrastructure::circuit_breaker::CircuitBreaker::new(
+            "ollama",
+            2,
+            std::time::Duration::from_secs(60),
+        ));
+        let policy = super::RetryPolicy { max_retries: 0, ..policy() }.with_circuit_breaker(breaker.clone());
+        let calls = std::sync::atomic::AtomicU32::new(0);
+        let bad_request = || async {
+            calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            std::result::Result::Err::<(), String>(String::from("HTTP 400 Bad Request"))
+        };
+
+        for _ in 0..3 {
+            std::assert!(std::matches!(policy.run(bad_request).await, std::result::Result::Err(super::CallError::Failed(_))));
+        }
+
+        std::assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 3);
+        std::assert!(!breaker.is_open());
+    }
+
+    #[tokio::test]
+    async fn test_run_reports_retry_events_to_metrics() {
+        // Test: Validates each retry is recorded as an Attempt and the outcome as SucceededAfterRetry or Exhausted.
+        // Justification: `rig metrics` can only flag a flaky provider if the shared retry loop reports its retries.
+        let collector = std::sync::Arc::new(crate::adapters::memory_metrics_collector::MemoryMetricsCollector::new());
+        let policy = super::RetryPolicy {
+            max_retries: 2,
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(2),
+            ..policy()
+        }
+        .with_metrics("ollama", collector.clone());
+
+        let calls = std::sync::atomic::AtomicU32::new(0);
+        let recovered = policy
+            .run(|| async {
+                if calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0 {
+                    return std::result::Result::Err(String::from("HTTP 503 Service Unavailable"));
+                }
+                std::result::Result::Ok::<&str, String>("done")
+            })
+            .await;
+        std::assert_eq!(recovered.unwrap(), "done");
+
+        let exhausted: std::result::Result<(), _> = policy
+            .run(|| async { std::result::Result::Err::<(), String>(String::from("HTTP 429 Too Many Requests")) })
+            .await;
+        std::assert!(exhausted.is_err());
+
+        let rejected: std::result::Result<(), _> = policy
+            .run(|| async { std::result::Result::Err::<(), String>(String::from("HTTP 401 Unauthorized")) })
+            .await;
+        std::assert!(rejected.is_err());
+
+        let counters = crate::ports::metrics_collector_port::MetricsCollectorPort::get_retry_counters(collector.as_ref())
+            .await
+            .unwrap();
+        std::assert_eq!(
+            counters["ollama"],
+            crate::domain::performance_metrics::RetryCounters { attempts: 3, successes_after_retry: 1, exhausted_retries: 1 }
+        );
+    }
+}
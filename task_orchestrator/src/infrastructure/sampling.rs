@@ -0,0 +1,71 @@
+//! Sampling parameters for LLM generation calls.
+//!
+//! Temperature and seed decide whether a call is deterministic enough to
+//! serve from the response cache: a call is cacheable if it runs at
+//! temperature 0 or with a fixed seed. Ollama receives both as request
+//! `options`.
+//!
+//! Revision History
+//! - 2026-10-16T21:30:00Z @AI: Initial SamplingParams and Ollama agent builder.
+
+/// Temperature and seed for a generation call.
+///
+/// Unset values leave the provider's defaults in place.
+///
+/// # Examples
+///
+/// ```
+/// use task_orchestrator::infrastructure::sampling::SamplingParams;
+///
+/// std::assert!(!SamplingParams::default().is_deterministic());
+/// std::assert!(SamplingParams::new(std::option::Option::Some(0.0), std::option::Option::None).is_deterministic());
+/// std::assert!(SamplingParams::new(std::option::Option::Some(0.7), std::option::Option::Some(42)).is_deterministic());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SamplingParams {
+    /// Sampling temperature; 0 makes decoding greedy
+    pub temperature: std::option::Option<f64>,
+
+    /// Random seed; a fixed seed makes sampling repeatable
+    pub seed: std::option::Option<u64>,
+}
+
+impl SamplingParams {
+    /// Creates sampling parameters.
+    pub fn new(temperature: std::option::Option<f64>, seed: std::option::Option<u64>) -> Self {
+        Self { temperature, seed }
+    }
+
+    /// Returns true if repeated calls with the same prompt should give the
+    /// same response: temperature is 0 or a seed is fixed.
+    pub fn is_deterministic(&self) -> bool {
+        self.temperature == std::option::Option::Some(0.0) || self.seed.is_some()
+    }
+
+    /// Returns true if neither temperature nor seed is set.
+    pub fn is_unset(&self) -> bool {
+        self.temperature.is_none() && self.seed.is_none()
+    }
+}
+
+/// Creates an Ollama agent builder with `sampling` applied.
+///
+/// # Arguments
+///
+/// * `client` - Ollama client
+/// * `model` - Model name
+/// * `sampling` - Temperature and seed to send with every request
+pub fn ollama_agent(
+    client: &rig::providers::ollama::Client,
+    model: &str,
+    sampling: &SamplingParams,
+) -> rig::agent::AgentBuilder<rig::providers::ollama::CompletionModel> {
+    let mut builder = client.agent(model);
+    if let std::option::Option::Some(temperature) = sampling.temperature {
+        builder = builder.temperature(temperature);
+    }
+    if let std::option::Option::Some(seed) = sampling.seed {
+        builder = builder.additional_params(serde_json::json!({ "seed": seed }));
+    }
+    builder
+}
@@ -5,6 +5,7 @@
 //! via an adapter and return structured results suitable for routing decisions.
 //!
 //! Revision History
+//! - 2026-10-18T02:30:00Z @AI: Add answer_comprehension_test; the default answers nothing, leaving grading to the question heuristic.
 //! - 2025-11-12T17:22:00Z @AI: Revert HexPort attribute; current hexser release does not support trait annotation.
 //! - 2025-11-12T21:38:00Z @AI: Refactor to use async_trait with async fn; keep tests and NO `use` compliance.
 //! - 2025-11-12T21:11:00Z @AI: Add ComprehensionTestPort trait with async API and unit test.
@@ -25,6 +26,22 @@ pub trait ComprehensionTestPort: std::marker::Send + std::marker::Sync {
         task: &task_manager::domain::task::Task,
         test_type: &str,
     ) -> std::result::Result<task_manager::domain::comprehension_test::ComprehensionTest, std::string::String>;
+
+    /// Answers `test` as the agent working on `task` would, for CheckTestResultNode to grade.
+    ///
+    /// The default answers nothing (`Ok(None)`); the check then falls back to
+    /// its question-length heuristic.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(String)` if the provider call fails.
+    async fn answer_comprehension_test(
+        &self,
+        _task: &task_manager::domain::task::Task,
+        _test: &task_manager::domain::comprehension_test::ComprehensionTest,
+    ) -> std::result::Result<std::option::Option<std::string::String>, std::string::String> {
+        std::result::Result::Ok(std::option::Option::None)
+    }
 }
 
 #[cfg(test)]
@@ -47,6 +64,8 @@ mod tests {
                     question: std::format!("What is the core goal of '{}'?", task.title),
                     options: std::option::Option::None,
                     correct_answer: std::string::String::from("To be determined"),
+                    description_hash: std::option::Option::None,
+                    stale: false,
                 };
                 std::result::Result::Ok(ct)
             }
@@ -0,0 +1,115 @@
+//! Defines the ConversationStorePort for persisting chat agent conversations.
+//!
+//! A conversation is a sequence of turns (user, assistant, or system messages,
+//! with any tool calls the assistant made) stored under a session ID, usually
+//! the project ID. Stores may keep only a window of recent turns and fold the
+//! older ones into a running summary, so `load_history` returns both.
+//!
+//! Revision History
+//! - 2026-10-17T00:30:00Z @AI: Initial ConversationStorePort with ConversationTurn and ConversationHistory.
+
+/// A tool call recorded with the assistant turn that made it.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RecordedToolCall {
+    /// Name of the tool that was called
+    pub tool_name: std::string::String,
+
+    /// JSON-formatted arguments passed to the tool
+    pub args_json: std::string::String,
+
+    /// Result returned by the tool, if it completed
+    pub result: std::option::Option<std::string::String>,
+}
+
+/// One message in a stored conversation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConversationTurn {
+    /// Session the turn belongs to (e.g. a project ID)
+    pub session_id: std::string::String,
+
+    /// Who sent the message
+    pub role: crate::ports::llm_agent_port::AgentRole,
+
+    /// Message text
+    pub content: std::string::String,
+
+    /// Tool calls made while producing this turn
+    pub tool_calls: std::vec::Vec<RecordedToolCall>,
+
+    /// When the turn was recorded
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl ConversationTurn {
+    /// Creates a turn without tool calls, timestamped now.
+    ///
+    /// # Arguments
+    ///
+    /// * `session_id` - Session the turn belongs to
+    /// * `role` - Who sent the message
+    /// * `content` - Message text
+    pub fn new(
+        session_id: impl std::convert::Into<std::string::String>,
+        role: crate::ports::llm_agent_port::AgentRole,
+        content: impl std::convert::Into<std::string::String>,
+    ) -> Self {
+        Self {
+            session_id: session_id.into(),
+            role,
+            content: content.into(),
+            tool_calls: std::vec::Vec::new(),
+            created_at: chrono::Utc::now(),
+        }
+    }
+}
+
+/// The stored part of a conversation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConversationHistory {
+    /// Summary of turns that were trimmed from the window, if any
+    pub summary: std::option::Option<std::string::String>,
+
+    /// Remaining turns, oldest first
+    pub turns: std::vec::Vec<ConversationTurn>,
+}
+
+/// Port (interface) for chat conversation persistence.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use task_orchestrator::ports::conversation_store_port::{ConversationStorePort, ConversationTurn};
+/// # use task_orchestrator::ports::llm_agent_port::AgentRole;
+/// # async fn example<S: ConversationStorePort>(store: &S) -> std::result::Result<(), std::string::String> {
+/// store.append_turn(ConversationTurn::new("project-1", AgentRole::User, "What is blocked?")).await?;
+///
+/// let history = store.load_history("project-1").await?;
+/// std::assert_eq!(history.turns.last().unwrap().content, "What is blocked?");
+/// # std::result::Result::Ok(())
+/// # }
+/// ```
+#[async_trait::async_trait]
+pub trait ConversationStorePort: std::marker::Send + std::marker::Sync {
+    /// Appends a turn to its session.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the turn cannot be stored.
+    async fn append_turn(&self, turn: ConversationTurn) -> std::result::Result<(), std::string::String>;
+
+    /// Loads a session's summary and remaining turns, oldest first.
+    ///
+    /// An unknown session yields an empty history.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the history cannot be read.
+    async fn load_history(&self, session_id: &str) -> std::result::Result<ConversationHistory, std::string::String>;
+
+    /// Deletes a session's turns and summary.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the session cannot be deleted.
+    async fn clear_session(&self, session_id: &str) -> std::result::Result<(), std::string::String>;
+}
@@ -0,0 +1,94 @@
+//! Defines the CrawlFrontierPort for persisting web crawl progress.
+//!
+//! A crawl's frontier is the queue of URLs still to fetch (with their link
+//! depth) plus the set of URLs already visited. Crawlers record every visit
+//! together with the links it queued, so a crawl that dies can be resumed
+//! from the saved frontier without refetching pages it already has.
+//!
+//! Revision History
+//! - 2026-10-17T13:10:00Z @AI: Initial CrawlFrontierPort with CrawlFrontier snapshot.
+
+/// Saved progress of one crawl.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CrawlFrontier {
+    /// URLs still to fetch with their link depth, in queue order.
+    pub queued: std::vec::Vec<(String, usize)>,
+
+    /// Normalized URLs already visited, in visit order (fetched, failed, or skipped).
+    pub visited: std::vec::Vec<String>,
+
+    /// Pages fetched successfully before the crawl stopped, in visit order.
+    pub pages: std::vec::Vec<crate::domain::crawl_result::CrawledPage>,
+}
+
+impl CrawlFrontier {
+    /// Returns true if nothing has been visited or queued.
+    pub fn is_empty(&self) -> bool {
+        self.queued.is_empty() && self.visited.is_empty()
+    }
+}
+
+/// Port (interface) for crawl frontier persistence.
+///
+/// Frontiers are keyed by a crawl key (the normalized start URL), so crawls
+/// of different sites do not share progress.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use task_orchestrator::ports::crawl_frontier_port::CrawlFrontierPort;
+/// async fn resume_or_start<S: CrawlFrontierPort>(store: &S, key: &str) -> std::result::Result<(), String> {
+///     match store.load(key).await? {
+///         std::option::Option::Some(frontier) => println!("{} URLs left", frontier.queued.len()),
+///         std::option::Option::None => store.reset(key, key).await?,
+///     }
+///     std::result::Result::Ok(())
+/// }
+/// ```
+#[async_trait::async_trait]
+pub trait CrawlFrontierPort: std::marker::Send + std::marker::Sync {
+    /// Loads the saved frontier for a crawl, or `None` if none is saved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be read or a saved page is corrupt.
+    async fn load(&self, crawl_key: &str) -> std::result::Result<std::option::Option<CrawlFrontier>, String>;
+
+    /// Discards any saved progress and queues `start_url` at depth 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be written.
+    async fn reset(&self, crawl_key: &str, start_url: &str) -> std::result::Result<(), String>;
+
+    /// Records a visit in one step: drops `url` from the queue, marks
+    /// `normalized_url` visited with its page (if it was fetched), and queues
+    /// the newly discovered `links` that are not already queued or visited.
+    ///
+    /// # Arguments
+    ///
+    /// * `crawl_key` - Crawl the visit belongs to
+    /// * `url` - URL as it was dequeued
+    /// * `normalized_url` - Normalized form recorded in the visited set
+    /// * `page` - The fetched page, or `None` if the fetch failed or was skipped
+    /// * `links` - Links to queue with their depth
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be written.
+    async fn record_visit(
+        &self,
+        crawl_key: &str,
+        url: &str,
+        normalized_url: &str,
+        page: std::option::Option<&crate::domain::crawl_result::CrawledPage>,
+        links: &[(String, usize)],
+    ) -> std::result::Result<(), String>;
+
+    /// Deletes the saved frontier for a crawl.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be written.
+    async fn clear(&self, crawl_key: &str) -> std::result::Result<(), String>;
+}
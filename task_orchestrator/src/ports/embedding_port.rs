@@ -5,6 +5,7 @@
 //! for RAG (Retrieval-Augmented Generation) systems.
 //!
 //! Revision History
+//! - 2026-10-16T22:30:00Z @AI: Add embedding_model() so stored artifacts record which model embedded them.
 //! - 2025-11-28T19:10:00Z @AI: Initial EmbeddingPort for Phase 1 RAG implementation.
 
 /// Port (interface) for generating text embeddings.
@@ -127,4 +128,21 @@ pub trait EmbeddingPort: std::marker::Send + std::marker::Sync {
     /// # }
     /// ```
     async fn embedding_dimension(&self) -> usize;
+
+    /// Returns the name of the embedding model, if the adapter knows it.
+    ///
+    /// Recorded with each stored artifact so a later model change can be
+    /// detected. Defaults to None.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use task_orchestrator::ports::embedding_port::EmbeddingPort;
+    /// # fn example<E: EmbeddingPort>(embedder: &E) {
+    /// println!("Embedding with {}", embedder.embedding_model().unwrap_or_default());
+    /// # }
+    /// ```
+    fn embedding_model(&self) -> std::option::Option<std::string::String> {
+        std::option::Option::None
+    }
 }
@@ -5,6 +5,7 @@
 //! domain or application layers.
 //!
 //! Revision History
+//! - 2026-10-17T02:10:00Z @AI: Add record_retry and get_retry_counters for per-provider retry counters.
 //! - 2025-11-24T01:00:00Z @AI: Create MetricsCollectorPort for Phase 5 Sprint 12 Task 5.11.
 
 /// Port for collecting and storing performance metrics.
@@ -96,7 +97,33 @@ pub trait MetricsCollectorPort: std::marker::Send + std::marker::Sync {
         role: crate::domain::model_role::ModelRole,
     ) -> std::result::Result<std::vec::Vec<crate::domain::performance_metrics::InferenceMetrics>, String>;
 
-    /// Clears all stored metrics.
+    /// Records one event of an adapter's retry loop for a provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - Provider whose call is being retried (e.g., "mlx", "openai")
+    /// * `event` - Retry attempt, success after retry, or exhausted retries
+    ///
+    /// # Errors
+    ///
+    /// Returns error if storage operation fails.
+    async fn record_retry(
+        &self,
+        provider: &str,
+        event: crate::domain::performance_metrics::RetryEvent,
+    ) -> std::result::Result<(), String>;
+
+    /// Retrieves retry counters keyed by provider.
+    ///
+    /// # Returns
+    ///
+    /// Counters for every provider that recorded a retry event, ordered by
+    /// provider name, or error if retrieval fails.
+    async fn get_retry_counters(
+        &self,
+    ) -> std::result::Result<std::collections::BTreeMap<String, crate::domain::performance_metrics::RetryCounters>, String>;
+
+    /// Clears all stored metrics and retry counters.
     ///
     /// Useful for resetting between benchmark runs.
     ///
@@ -5,6 +5,11 @@
 //! adapters. Traits here are async and object-safe for use behind Arc<dyn _>.
 //!
 //! Revision History
+//! - 2026-10-17T18:10:00Z @AI: Add rerank_port for LLM re-ranking of search hits.
+//! - 2026-10-17T13:30:00Z @AI: Add page_validator_port for conditional re-crawls.
+//! - 2026-10-17T13:10:00Z @AI: Add crawl_frontier_port for resumable web crawls.
+//! - 2026-10-17T00:30:00Z @AI: Add conversation_store_port for persisted chat agent history.
+//! - 2026-10-16T19:10:00Z @AI: Add model_list_port for `rig models`.
 //! - 2025-12-03T00:00:00Z @AI: Add llm_agent_port for chain-of-thought chat agent implementation.
 //! - 2025-11-30T19:45:00Z @AI: Add web_crawler_port for Phase 3 artifact generator.
 //! - 2025-11-30T11:05:00Z @AI: Add vision_port for Phase 5 image processing implementation.
@@ -20,6 +25,11 @@ pub mod prd_parser_port;
 pub mod task_decomposition_port;
 pub mod metrics_collector_port;
 pub mod embedding_port;
+pub mod rerank_port;
 pub mod vision_port;
 pub mod web_crawler_port;
+pub mod crawl_frontier_port;
+pub mod page_validator_port;
 pub mod llm_agent_port;
+pub mod model_list_port;
+pub mod conversation_store_port;
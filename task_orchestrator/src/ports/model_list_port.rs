@@ -0,0 +1,40 @@
+//! Defines the ModelListPort output port for listing a provider's models.
+//!
+//! This port represents the interface for discovering which model IDs an LLM
+//! provider offers (for Ollama, the locally pulled models), so users can pick
+//! valid values for task slot configuration.
+//!
+//! Revision History
+//! - 2026-10-16T19:10:00Z @AI: Initial ModelListPort for `rig models`.
+
+/// Port (interface) for listing the models a provider offers.
+///
+/// # Object Safety
+///
+/// This trait is object-safe and uses async_trait to support async methods
+/// in trait objects. All methods require Send + Sync for concurrent usage.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use task_orchestrator::ports::model_list_port::ModelListPort;
+/// # async fn example<M: ModelListPort>(lister: &M) {
+/// for model in lister.list_models().await.unwrap() {
+///     println!("{}", model);
+/// }
+/// # }
+/// ```
+#[async_trait::async_trait]
+pub trait ModelListPort: std::marker::Send + std::marker::Sync {
+    /// Lists the model IDs available from the provider.
+    ///
+    /// # Returns
+    ///
+    /// Model IDs sorted alphabetically, or an error message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, the provider rejects the
+    /// credentials, or the response cannot be parsed.
+    async fn list_models(&self) -> std::result::Result<std::vec::Vec<std::string::String>, std::string::String>;
+}
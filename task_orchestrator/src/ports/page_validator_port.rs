@@ -0,0 +1,44 @@
+//! Defines the PageValidatorPort for remembering HTTP cache validators per URL.
+//!
+//! Re-crawls of a site look up the ETag and Last-Modified saved for each page
+//! by the previous crawl and send them as conditional request headers, so
+//! pages the server reports unchanged (304) are skipped instead of re-ingested.
+//!
+//! Revision History
+//! - 2026-10-17T13:30:00Z @AI: Initial PageValidatorPort for conditional re-crawls.
+
+/// Port (interface) for per-URL cache validator persistence.
+///
+/// URLs are stored in the crawler's normalized form (no fragment or trailing slash).
+///
+/// # Examples
+///
+/// ```no_run
+/// # use task_orchestrator::ports::page_validator_port::PageValidatorPort;
+/// async fn etag_of<S: PageValidatorPort>(store: &S, url: &str) -> std::option::Option<String> {
+///     store.get(url).await.ok().flatten().and_then(|v| v.etag)
+/// }
+/// ```
+#[async_trait::async_trait]
+pub trait PageValidatorPort: std::marker::Send + std::marker::Sync {
+    /// Returns the validators saved for `url`, or `None` if it was never fetched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be read.
+    async fn get(
+        &self,
+        url: &str,
+    ) -> std::result::Result<std::option::Option<crate::domain::crawl_result::PageValidators>, String>;
+
+    /// Saves the validators for `url`, replacing any earlier ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be written.
+    async fn put(
+        &self,
+        url: &str,
+        validators: &crate::domain::crawl_result::PageValidators,
+    ) -> std::result::Result<(), String>;
+}
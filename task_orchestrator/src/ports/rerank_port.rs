@@ -0,0 +1,33 @@
+//! Port for scoring retrieved passages by relevance to a query.
+//!
+//! Vector search ranks by embedding distance, which sometimes puts a
+//! tangential chunk above the one that answers the query. A RerankPort
+//! judges the passages against the query directly (typically with an LLM)
+//! so `ArtifactService::search` can re-order its top hits.
+//!
+//! Revision History
+//! - 2026-10-17T18:10:00Z @AI: Initial RerankPort for LLM re-ranking of artifact search results.
+
+/// Port for scoring passages by how relevant they are to a query.
+///
+/// Adapters report errors via `Err(String)`; callers fall back to the
+/// original order.
+#[async_trait::async_trait]
+pub trait RerankPort: std::marker::Send + std::marker::Sync {
+    /// Scores each passage's relevance to `query`; higher is more relevant.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query
+    /// * `passages` - Candidate passages, in their current order
+    ///
+    /// # Returns
+    ///
+    /// One score per passage, in the order of `passages`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(String)` if scoring fails or the model's answer cannot be
+    /// read as one score per passage.
+    async fn score(&self, query: &str, passages: &[&str]) -> std::result::Result<std::vec::Vec<f32>, std::string::String>;
+}
@@ -5,6 +5,10 @@
 //! extraction of textual descriptions from visual content in PRDs.
 //!
 //! Revision History
+//! - 2026-10-18T03:40:00Z @AI: Validate images read by describe_images() and extract_text() with VisionService::validate_image.
+//! - 2026-10-16T13:10:00Z @AI: Expose image_mime_from_magic() for vision input validation.
+//! - 2026-10-16T12:50:00Z @AI: Add extract_text() OCR mode and shared read_image_file() helper.
+//! - 2026-10-16T12:30:00Z @AI: Add describe_images() batch API with bounded concurrency and per-image error isolation.
 //! - 2025-11-30T11:00:00Z @AI: Initial VisionPort for Phase 5 image processing implementation.
 
 /// Response from a vision LLM describing visual content.
@@ -19,6 +23,28 @@ pub struct VisionResponse {
     pub processing_time_ms: u64,
 }
 
+/// Result of describing one image file in a batch.
+///
+/// Each entry carries its source path so callers can match results to inputs;
+/// a failure for one image never affects the others.
+#[derive(Debug, Clone)]
+pub struct ImageDescriptionResult {
+    /// Path of the image file that was processed.
+    pub path: std::path::PathBuf,
+
+    /// Description on success, or the error for this image alone.
+    pub result: std::result::Result<VisionResponse, std::string::String>,
+}
+
+/// Maximum number of images described concurrently by `describe_images`.
+pub const DEFAULT_BATCH_CONCURRENCY: usize = 4;
+
+/// Instruction used for OCR: transcribe the image's text verbatim, without description.
+pub const OCR_PROMPT: &str = "Transcribe all text visible in this image exactly as written. \
+Preserve the original wording, line breaks, and reading order. \
+Do not describe, summarize, translate, or correct the text. \
+If the image contains no text, respond with an empty message.";
+
 /// Port (interface) for vision-capable LLM interactions.
 ///
 /// VisionPort defines the contract for adapters that can describe images and PDFs
@@ -132,6 +158,78 @@ pub trait VisionPort: std::marker::Send + std::marker::Sync {
         context: std::option::Option<&str>,
     ) -> std::result::Result<VisionResponse, std::string::String>;
 
+    /// Describes a batch of image files with bounded concurrency.
+    ///
+    /// Each file is read, identified by its magic bytes, encoded as base64, and
+    /// passed to `describe_image`. At most `DEFAULT_BATCH_CONCURRENCY` images
+    /// are in flight at once. Errors are isolated per image: an unreadable or
+    /// corrupt file yields an `Err` entry while the rest of the batch proceeds.
+    ///
+    /// # Arguments
+    ///
+    /// * `paths` - Image files to describe
+    ///
+    /// # Returns
+    ///
+    /// One ImageDescriptionResult per input path, in input order.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use task_orchestrator::ports::vision_port::VisionPort;
+    /// # async fn example<V: VisionPort>(vision: &V) {
+    /// let paths = std::vec![std::path::PathBuf::from("docs/architecture.png")];
+    /// for item in vision.describe_images(&paths).await {
+    ///     match item.result {
+    ///         std::result::Result::Ok(response) => println!("{}: {}", item.path.display(), response.description),
+    ///         std::result::Result::Err(e) => eprintln!("{}: {}", item.path.display(), e),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    async fn describe_images(&self, paths: &[std::path::PathBuf]) -> std::vec::Vec<ImageDescriptionResult> {
+        describe_image_files(self, paths, DEFAULT_BATCH_CONCURRENCY).await
+    }
+
+    /// Extracts the text contained in an image file (OCR).
+    ///
+    /// Unlike `describe_image`, this asks the model for a verbatim transcription
+    /// of the visible text, which suits screenshots, scanned documents, and
+    /// whiteboard photos. The default implementation reads the file, identifies
+    /// it by its magic bytes, and passes `OCR_PROMPT` as the context to
+    /// `describe_image`; adapters that build their own prompts should override it
+    /// to send `OCR_PROMPT` directly.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Image file to transcribe
+    ///
+    /// # Returns
+    ///
+    /// The transcribed text, trimmed of surrounding whitespace.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, fails image validation,
+    /// or the vision request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use task_orchestrator::ports::vision_port::VisionPort;
+    /// # async fn example<V: VisionPort>(vision: &V) {
+    /// let text = vision.extract_text(std::path::Path::new("docs/whiteboard.jpg")).await.unwrap();
+    /// println!("{}", text);
+    /// # }
+    /// ```
+    async fn extract_text(&self, path: &std::path::Path) -> std::result::Result<std::string::String, std::string::String> {
+        let (base64_data, mime_type) = read_image_file(path).await?;
+        let response = self
+            .describe_image(&base64_data, &mime_type, std::option::Option::Some(OCR_PROMPT))
+            .await?;
+        std::result::Result::Ok(response.description.trim().to_string())
+    }
+
     /// Returns the name of the underlying vision model.
     ///
     /// # Returns
@@ -147,10 +245,257 @@ pub trait VisionPort: std::marker::Send + std::marker::Sync {
     fn provider_name(&self) -> &str;
 }
 
+/// Describes image files through `vision` with at most `concurrency` requests in flight.
+///
+/// Shared implementation behind `VisionPort::describe_images`, exposed so
+/// callers can choose their own concurrency bound.
+///
+/// # Arguments
+///
+/// * `vision` - Vision adapter used for each image
+/// * `paths` - Image files to describe
+/// * `concurrency` - Maximum concurrent requests (values below 1 are treated as 1)
+///
+/// # Returns
+///
+/// One ImageDescriptionResult per input path, in input order.
+pub async fn describe_image_files<V>(
+    vision: &V,
+    paths: &[std::path::PathBuf],
+    concurrency: usize,
+) -> std::vec::Vec<ImageDescriptionResult>
+where
+    V: VisionPort + ?std::marker::Sized,
+{
+    futures::StreamExt::collect(futures::StreamExt::buffered(
+        futures::stream::iter(paths.iter().cloned().map(|path| async move {
+            let result = describe_image_file(vision, &path).await;
+            ImageDescriptionResult { path, result }
+        })),
+        concurrency.max(1),
+    ))
+    .await
+}
+
+/// Reads, identifies, and describes a single image file.
+async fn describe_image_file<V>(
+    vision: &V,
+    path: &std::path::Path,
+) -> std::result::Result<VisionResponse, std::string::String>
+where
+    V: VisionPort + ?std::marker::Sized,
+{
+    let (base64_data, mime_type) = read_image_file(path).await?;
+    vision.describe_image(&base64_data, &mime_type, std::option::Option::None).await
+}
+
+/// Reads an image file, validates it, and returns its base64 encoding and MIME type.
+///
+/// The bytes go through `VisionService::validate_image` with the default
+/// `ImageValidationConfig`, so oversized images are downscaled before they
+/// reach a vision model.
+///
+/// # Arguments
+///
+/// * `path` - Image file to read
+///
+/// # Returns
+///
+/// A tuple of (base64 data, MIME type of the validated image).
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or fails image validation.
+pub async fn read_image_file(
+    path: &std::path::Path,
+) -> std::result::Result<(std::string::String, std::string::String), std::string::String> {
+    let bytes = tokio::fs::read(path)
+        .await
+        .map_err(|e| std::format!("Failed to read image {}: {}", path.display(), e))?;
+    let validated = crate::services::vision_service::VisionService::validate_image(
+        &bytes,
+        &path.to_string_lossy(),
+        &crate::services::vision_service::ImageValidationConfig::default(),
+    )
+    .map_err(|e| e.to_string())?;
+    std::result::Result::Ok((validated.base64_data, validated.mime_type))
+}
+
+/// Identifies PNG, JPEG, GIF, and WebP data by its leading magic bytes.
+///
+/// # Arguments
+///
+/// * `bytes` - Raw file contents
+///
+/// # Returns
+///
+/// The MIME type (e.g., "image/png"), or None if the data is not a supported image.
+pub fn image_mime_from_magic(bytes: &[u8]) -> std::option::Option<&'static str> {
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        std::option::Option::Some("image/png")
+    } else if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        std::option::Option::Some("image/jpeg")
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        std::option::Option::Some("image/gif")
+    } else if bytes.len() >= 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP" {
+        std::option::Option::Some("image/webp")
+    } else {
+        std::option::Option::None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Minimal 1x1 PNG used as a valid image fixture.
+    const TINY_PNG_BASE64: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNk+M9QDwADhgGAWjR9awAAAABJRU5ErkJggg==";
+
+    /// Mock vision adapter recording peak concurrency.
+    struct CountingVision {
+        in_flight: std::sync::atomic::AtomicUsize,
+        peak: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl VisionPort for CountingVision {
+        async fn describe_image(
+            &self,
+            base64_data: &str,
+            mime_type: &str,
+            _context: std::option::Option<&str>,
+        ) -> std::result::Result<VisionResponse, std::string::String> {
+            let now = self.in_flight.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            self.peak.fetch_max(now, std::sync::atomic::Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            self.in_flight.fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
+            std::result::Result::Ok(VisionResponse {
+                description: std::format!("{} image ({} base64 chars)", mime_type, base64_data.len()),
+                processing_time_ms: 10,
+            })
+        }
+
+        async fn describe_pdf_page(
+            &self,
+            _page_image_base64: &str,
+            _page_number: u32,
+            _context: std::option::Option<&str>,
+        ) -> std::result::Result<VisionResponse, std::string::String> {
+            std::result::Result::Err(String::from("not used"))
+        }
+
+        fn model_name(&self) -> &str {
+            "counting"
+        }
+
+        fn provider_name(&self) -> &str {
+            "mock"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_describe_images_isolates_corrupt_image() {
+        // Test: Validates a corrupt and a missing image fail individually while valid images succeed.
+        // Justification: One unreadable diagram must not fail a whole folder ingestion.
+        let dir = std::env::temp_dir().join(std::format!("vision_batch_test_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let png = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, TINY_PNG_BASE64).unwrap();
+        let valid_a = dir.join("a.png");
+        let corrupt = dir.join("corrupt.png");
+        let valid_b = dir.join("b.png");
+        let missing = dir.join("missing.png");
+        std::fs::write(&valid_a, &png).unwrap();
+        std::fs::write(&corrupt, b"not really a png").unwrap();
+        std::fs::write(&valid_b, &png).unwrap();
+        let vision = CountingVision {
+            in_flight: std::sync::atomic::AtomicUsize::new(0),
+            peak: std::sync::atomic::AtomicUsize::new(0),
+        };
+
+        let results = vision
+            .describe_images(&[valid_a.clone(), corrupt.clone(), valid_b.clone(), missing.clone()])
+            .await;
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        std::assert_eq!(results.len(), 4);
+        std::assert_eq!(results[0].path, valid_a);
+        std::assert!(results[0].result.as_ref().unwrap().description.starts_with("image/png"));
+        std::assert_eq!(results[1].path, corrupt);
+        std::assert!(results[1].result.as_ref().unwrap_err().contains("Unsupported image format"));
+        std::assert_eq!(results[2].path, valid_b);
+        std::assert!(results[2].result.is_ok());
+        std::assert!(results[3].result.as_ref().unwrap_err().contains("Failed to read image"));
+    }
+
+    #[tokio::test]
+    async fn test_describe_image_files_bounds_concurrency() {
+        // Test: Validates no more than the requested number of images are described at once.
+        // Justification: Bounded concurrency protects local and remote vision providers.
+        let dir = std::env::temp_dir().join(std::format!("vision_concurrency_test_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let png = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, TINY_PNG_BASE64).unwrap();
+        let paths: std::vec::Vec<std::path::PathBuf> = (0..8)
+            .map(|i| {
+                let path = dir.join(std::format!("{}.png", i));
+                std::fs::write(&path, &png).unwrap();
+                path
+            })
+            .collect();
+        let vision = CountingVision {
+            in_flight: std::sync::atomic::AtomicUsize::new(0),
+            peak: std::sync::atomic::AtomicUsize::new(0),
+        };
+
+        let results = describe_image_files(&vision, &paths, 2).await;
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        std::assert_eq!(results.len(), 8);
+        std::assert!(results.iter().all(|r| r.result.is_ok()));
+        std::assert!(vision.peak.load(std::sync::atomic::Ordering::SeqCst) <= 2);
+    }
+
+    #[tokio::test]
+    async fn test_file_inputs_are_validated_before_vision_calls() {
+        // Test: Validates describe_images downscales an oversized image and extract_text rejects an unsupported extension.
+        // Justification: Both file paths used to send raw bytes to the model, skipping the size and format limits.
+        let dir = std::env::temp_dir().join(std::format!("vision_validation_test_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let wide = dir.join("wide.png");
+        image::RgbImage::new(4096, 4).save(&wide).unwrap();
+        let bitmap = dir.join("scan.bmp");
+        std::fs::write(&bitmap, base64::Engine::decode(&base64::engine::general_purpose::STANDARD, TINY_PNG_BASE64).unwrap()).unwrap();
+        let vision = CountingVision {
+            in_flight: std::sync::atomic::AtomicUsize::new(0),
+            peak: std::sync::atomic::AtomicUsize::new(0),
+        };
+
+        let described = vision.describe_images(&[wide.clone()]).await;
+        let (wide_base64, _) = read_image_file(&wide).await.unwrap();
+        let extracted = vision.extract_text(&bitmap).await;
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let wide_bytes = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, &wide_base64).unwrap();
+        let (width, _) = image::ImageReader::new(std::io::Cursor::new(wide_bytes))
+            .with_guessed_format()
+            .unwrap()
+            .into_dimensions()
+            .unwrap();
+        std::assert_eq!(width, 2048);
+        std::assert!(described[0].result.is_ok());
+        std::assert!(extracted.unwrap_err().contains("has extension .bmp"));
+        std::assert_eq!(vision.peak.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_image_mime_from_magic() {
+        // Test: Validates magic-byte detection of supported image formats.
+        // Justification: File extensions are unreliable; content decides the MIME type.
+        std::assert_eq!(image_mime_from_magic(&[0xFF, 0xD8, 0xFF, 0xE0]), std::option::Option::Some("image/jpeg"));
+        std::assert_eq!(image_mime_from_magic(b"GIF89a...."), std::option::Option::Some("image/gif"));
+        std::assert_eq!(image_mime_from_magic(b"RIFF\0\0\0\0WEBPVP8 "), std::option::Option::Some("image/webp"));
+        std::assert_eq!(image_mime_from_magic(b"%PDF-1.7"), std::option::Option::None);
+    }
+
     #[test]
     fn test_vision_response_creation() {
         // Test: Verifies VisionResponse can be created with expected fields.
@@ -5,6 +5,11 @@
 //! abstracting the HTTP client and HTML parsing implementation.
 //!
 //! Revision History
+//! - 2026-10-18T04:00:00Z @AI: Add crawl_with_progress reporting each page as it is crawled.
+//! - 2026-10-17T22:30:00Z @AI: Add page_stored so crawlers save validators only after ingestion.
+//! - 2026-10-17T13:30:00Z @AI: CrawlResult lists pages unchanged since the last crawl.
+//! - 2026-10-17T12:30:00Z @AI: CrawlResult records out-of-scope links instead of dropping them.
+//! - 2026-10-17T12:10:00Z @AI: CrawlResult lists pages skipped for their content type; extract_text returns markdown.
 //! - 2025-11-30T19:45:00Z @AI: Initial WebCrawlerPort for Phase 3 artifact generator.
 
 /// Port trait for web crawling with content extraction.
@@ -59,6 +64,33 @@ pub trait WebCrawlerPort: std::marker::Send + std::marker::Sync {
         config: &crate::domain::crawl_result::CrawlConfig,
     ) -> std::result::Result<CrawlResult, crate::domain::crawl_result::CrawlError>;
 
+    /// Crawls like `crawl`, calling `on_page` with each page's URL as the page
+    /// is fetched (or restored from a resumed crawl).
+    ///
+    /// The default crawls first and then reports every returned page;
+    /// crawlers that fetch incrementally override it so callers can show
+    /// progress while the crawl runs.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Crawl configuration specifying URL, depth, limits, and options.
+    /// * `on_page` - Called once per crawled page, in crawl order.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of `crawl`.
+    async fn crawl_with_progress(
+        &self,
+        config: &crate::domain::crawl_result::CrawlConfig,
+        on_page: &(dyn Fn(&str) + std::marker::Send + std::marker::Sync),
+    ) -> std::result::Result<CrawlResult, crate::domain::crawl_result::CrawlError> {
+        let result = self.crawl(config).await?;
+        for page in &result.pages {
+            on_page(&page.url);
+        }
+        std::result::Result::Ok(result)
+    }
+
     /// Fetches a single page and extracts its content.
     ///
     /// This method is useful for fetching individual pages without following
@@ -82,9 +114,26 @@ pub trait WebCrawlerPort: std::marker::Send + std::marker::Sync {
         crate::domain::crawl_result::CrawlError,
     >;
 
-    /// Extracts text content from HTML.
+    /// Records that a crawled page's artifacts were stored.
+    ///
+    /// Crawlers that skip unchanged pages on re-crawls save the page's
+    /// validators here rather than when it is fetched, so a page whose
+    /// ingestion failed is fetched in full next time. The default does nothing.
+    ///
+    /// # Arguments
     ///
-    /// Parses HTML and extracts readable text, optionally using CSS selectors
+    /// * `page` - A page returned by `crawl` or `fetch_page`.
+    async fn page_stored(
+        &self,
+        _page: &crate::domain::crawl_result::CrawledPage,
+    ) -> std::result::Result<(), crate::domain::crawl_result::CrawlError> {
+        std::result::Result::Ok(())
+    }
+
+    /// Extracts the main content of HTML as markdown.
+    ///
+    /// Parses HTML, drops boilerplate (scripts, styles, navigation), and
+    /// converts the main content to markdown, optionally using CSS selectors
     /// to target specific content areas and exclude navigation/ads.
     ///
     /// # Arguments
@@ -94,7 +143,7 @@ pub trait WebCrawlerPort: std::marker::Send + std::marker::Sync {
     ///
     /// # Returns
     ///
-    /// Extracted text content with HTML tags stripped.
+    /// Markdown of the extracted content.
     fn extract_text(
         &self,
         html: &str,
@@ -131,8 +180,9 @@ pub trait WebCrawlerPort: std::marker::Send + std::marker::Sync {
 
     /// Checks if a URL should be followed based on configuration.
     ///
-    /// Determines whether a discovered link should be added to the crawl queue
-    /// based on domain restrictions, depth limits, and exclusion patterns.
+    /// Determines whether a discovered link is in the crawl scope: the start
+    /// host (plus its subdomains if `include_subdomains` is set, or any host if
+    /// `follow_external` is set) and, if set, `path_prefix`.
     ///
     /// # Arguments
     ///
@@ -156,6 +206,9 @@ pub trait WebCrawlerPort: std::marker::Send + std::marker::Sync {
 /// * `pages` - Successfully crawled pages with extracted content.
 /// * `stats` - Statistics about the crawl operation.
 /// * `errors` - Non-fatal errors encountered during crawling.
+/// * `skipped` - Responses skipped because their content type is not allowed.
+/// * `external_links` - Links outside the crawl scope, recorded but not fetched.
+/// * `unchanged` - Pages the server reported unchanged since the last crawl (not re-ingested).
 #[derive(Debug, Clone)]
 pub struct CrawlResult {
     /// Successfully crawled pages.
@@ -166,6 +219,15 @@ pub struct CrawlResult {
 
     /// Non-fatal errors encountered during crawling.
     pub errors: std::vec::Vec<CrawlPageError>,
+
+    /// Responses skipped because their content type is not allowed.
+    pub skipped: std::vec::Vec<CrawlPageError>,
+
+    /// Links found outside the crawl scope, recorded but not fetched (deduplicated).
+    pub external_links: std::vec::Vec<String>,
+
+    /// Pages the server reported unchanged since the last crawl (304), so not re-ingested.
+    pub unchanged: std::vec::Vec<String>,
 }
 
 impl CrawlResult {
@@ -175,6 +237,9 @@ impl CrawlResult {
             pages: std::vec::Vec::new(),
             stats: crate::domain::crawl_result::CrawlStats::default(),
             errors: std::vec::Vec::new(),
+            skipped: std::vec::Vec::new(),
+            external_links: std::vec::Vec::new(),
+            unchanged: std::vec::Vec::new(),
         }
     }
 
@@ -300,6 +365,7 @@ mod tests {
             depth: 0,
             status_code: 200,
             content_type: String::from("text/html"),
+            raw_body: std::option::Option::None,
         });
 
         std::assert_eq!(result.total_content_bytes(), 11);
@@ -6,8 +6,23 @@
 //!
 //! This service enables pre-populating the artifact database with context
 //! from codebases, documentation sites, and other sources before task generation.
+//! `generate` auto-detects whether a source is a directory, a single file, a
+//! PDF, an image, or an http(s) URL and dispatches to the matching pipeline.
 //!
 //! Revision History
+//! - 2026-10-18T04:00:00Z @AI: Report web pages as they are fetched (GenerationStage::Fetching) and always end a run with a complete event.
+//! - 2026-10-18T03:10:00Z @AI: Ingest PDFs found by a directory scan page by page instead of as plain text.
+//! - 2026-10-17T23:50:00Z @AI: Recognize encrypted PDFs by any decrypt/encrypt/password error; test against an encrypted PDF fixture.
+//! - 2026-10-17T22:30:00Z @AI: Tell the crawler once a page's artifacts are stored so validators are saved only then.
+//! - 2026-10-17T13:30:00Z @AI: Report pages the crawler found unchanged since the last crawl.
+//! - 2026-10-17T12:30:00Z @AI: Carry out-of-scope links from the crawl into GenerationReport::external_links.
+//! - 2026-10-17T12:10:00Z @AI: Ingest crawled PDFs through the per-page PDF pipeline and report pages skipped for their content type.
+//! - 2026-10-16T22:30:00Z @AI: Record the embedding model and dimension on generated artifacts.
+//! - 2026-10-16T15:10:00Z @AI: Add GenerationProgress events sent over an optional mpsc channel (with_progress_sender) after each file, page, or single source.
+//! - 2026-10-16T14:50:00Z @AI: Record source_path and per-chunk line_start/line_end in file artifact metadata for citations.
+//! - 2026-10-16T14:30:00Z @AI: Add per-page PDF ingestion (generate_from_pdf) with page-number citation metadata; encrypted and image-only PDFs are skipped with a report warning.
+//! - 2026-10-16T14:10:00Z @AI: Add SourceKind auto-detection, generate() dispatch, generate_from_file() for single files/PDFs/images, and source_type metadata on every artifact.
+//! - 2026-10-16T12:50:00Z @AI: Add OCR mode: image files are transcribed via VisionPort::extract_text into artifact content.
 //! - 2025-11-30T21:00:00Z @AI: Create ArtifactGeneratorService for Phase 4 artifact generator.
 
 /// Report of artifact generation results.
@@ -44,6 +59,15 @@ pub struct GenerationReport {
     /// Non-fatal errors encountered during generation.
     pub errors: std::vec::Vec<String>,
 
+    /// Sources skipped on purpose, with the reason (e.g., encrypted PDFs).
+    pub warnings: std::vec::Vec<String>,
+
+    /// Links outside the crawl scope that were recorded but not fetched (URL mode).
+    pub external_links: std::vec::Vec<String>,
+
+    /// Pages unchanged since the last crawl, so not re-ingested (URL mode).
+    pub pages_unchanged: usize,
+
     /// Duration of the generation operation in milliseconds.
     pub duration_ms: u64,
 }
@@ -58,6 +82,9 @@ impl GenerationReport {
             chunks_generated: 0,
             bytes_processed: 0,
             errors: std::vec::Vec::new(),
+            warnings: std::vec::Vec::new(),
+            external_links: std::vec::Vec::new(),
+            pages_unchanged: 0,
             duration_ms: 0,
         }
     }
@@ -76,6 +103,11 @@ impl GenerationReport {
     pub fn add_error(&mut self, error: String) {
         self.errors.push(error);
     }
+
+    /// Records a source that was skipped and why.
+    pub fn add_warning(&mut self, warning: String) {
+        self.warnings.push(warning);
+    }
 }
 
 impl std::default::Default for GenerationReport {
@@ -84,6 +116,163 @@ impl std::default::Default for GenerationReport {
     }
 }
 
+/// Stage of an artifact generation run a progress event belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenerationStage {
+    /// Pages are being fetched by the web crawler; `total` is the page limit.
+    Fetching,
+
+    /// Items are being chunked, embedded, and stored.
+    Processing,
+}
+
+/// Progress update emitted after each item during artifact generation.
+///
+/// The service stays UI-agnostic: callers receive these over an mpsc
+/// channel (see `ArtifactGeneratorService::with_progress_sender`) and
+/// render them however they like. Web sources report each page as it is
+/// fetched, then again as it is processed; every run ends with a complete
+/// event, even when there was nothing to process.
+///
+/// # Examples
+///
+/// ```
+/// # use task_orchestrator::services::artifact_generator_service::{GenerationProgress, GenerationStage};
+/// let progress = GenerationProgress {
+///     stage: GenerationStage::Processing,
+///     processed: 3,
+///     total: 3,
+///     current_item: std::string::String::from("src/lib.rs"),
+/// };
+/// std::assert!(progress.is_complete());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenerationProgress {
+    /// Stage the run is in.
+    pub stage: GenerationStage,
+
+    /// Number of items processed so far, including the current one.
+    pub processed: usize,
+
+    /// Total number of items to process.
+    pub total: usize,
+
+    /// File path or URL of the item just processed.
+    pub current_item: String,
+}
+
+impl GenerationProgress {
+    /// Returns true once every item has been processed.
+    pub fn is_complete(&self) -> bool {
+        self.stage == GenerationStage::Processing && self.processed >= self.total
+    }
+}
+
+/// Kind of source an artifact generation run reads from.
+///
+/// Detected from the source string by `SourceKind::detect` and recorded as
+/// `source_type` in every generated artifact's metadata.
+///
+/// # Variants
+///
+/// * `File` - A single text file.
+/// * `Directory` - A directory scanned recursively.
+/// * `Web` - An http(s) URL crawled for pages.
+/// * `Pdf` - A single PDF document.
+/// * `Image` - A single image file (transcribed with OCR).
+///
+/// # Examples
+///
+/// ```
+/// # use task_orchestrator::services::artifact_generator_service::SourceKind;
+/// let kind = SourceKind::detect("https://docs.example.com").unwrap();
+/// std::assert_eq!(kind, SourceKind::Web);
+/// std::assert_eq!(kind.as_str(), "web");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceKind {
+    /// A single text file.
+    File,
+
+    /// A directory scanned recursively.
+    Directory,
+
+    /// An http(s) URL crawled for pages.
+    Web,
+
+    /// A single PDF document.
+    Pdf,
+
+    /// A single image file.
+    Image,
+}
+
+impl SourceKind {
+    /// Detects the kind of a source path or URL.
+    ///
+    /// Strings starting with `http://` or `https://` are web sources. Anything
+    /// else must be an existing path: directories are scanned, `.pdf` files are
+    /// PDFs, png/jpg/jpeg/gif/webp files are images, and all other files are
+    /// read as text.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Directory path, file path, or URL
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source is not a URL and does not exist.
+    pub fn detect(source: &str) -> std::result::Result<Self, String> {
+        let lower = source.to_lowercase();
+        if lower.starts_with("http://") || lower.starts_with("https://") {
+            return std::result::Result::Ok(SourceKind::Web);
+        }
+
+        let path = std::path::Path::new(source);
+        if path.is_dir() {
+            return std::result::Result::Ok(SourceKind::Directory);
+        }
+        if !path.is_file() {
+            return std::result::Result::Err(std::format!(
+                "Source not found: '{}' is neither an existing file or directory nor an http(s) URL",
+                source
+            ));
+        }
+
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+        let kind = match extension.as_str() {
+            "pdf" => SourceKind::Pdf,
+            "png" | "jpg" | "jpeg" | "gif" | "webp" => SourceKind::Image,
+            _ => SourceKind::File,
+        };
+        std::result::Result::Ok(kind)
+    }
+
+    /// Returns the lowercase tag stored in artifact metadata (e.g., "pdf").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SourceKind::File => "file",
+            SourceKind::Directory => "directory",
+            SourceKind::Web => "web",
+            SourceKind::Pdf => "pdf",
+            SourceKind::Image => "image",
+        }
+    }
+}
+
+/// Outcome of extracting text from a PDF.
+enum PdfText {
+    /// Text of each page, in page order.
+    Pages(std::vec::Vec<String>),
+
+    /// The PDF is encrypted and cannot be read without a password.
+    Encrypted,
+}
+
 /// Configuration for artifact generation operations.
 ///
 /// GenerationConfig specifies options for how content should be processed,
@@ -101,6 +290,9 @@ pub struct GenerationConfig {
 
     /// Whether to skip files that already have artifacts (incremental mode).
     pub incremental: bool,
+
+    /// Whether to transcribe image files with OCR (requires a vision port).
+    pub ocr: bool,
 }
 
 impl GenerationConfig {
@@ -111,6 +303,7 @@ impl GenerationConfig {
             chunk_strategy: task_manager::domain::scan_config::ChunkStrategy::Paragraph,
             max_chunk_size: 1000,
             incremental: false,
+            ocr: false,
         }
     }
 
@@ -131,6 +324,12 @@ impl GenerationConfig {
         self.incremental = incremental;
         self
     }
+
+    /// Enables OCR mode (transcribe image files into artifact content).
+    pub fn with_ocr(mut self, ocr: bool) -> Self {
+        self.ocr = ocr;
+        self
+    }
 }
 
 /// Service for generating artifacts from directories and websites.
@@ -141,6 +340,7 @@ impl GenerationConfig {
 /// 3. Content chunking with configurable strategies
 /// 4. Embedding generation via EmbeddingPort
 /// 5. Artifact persistence via ArtifactRepositoryPort
+/// 6. Optional OCR of image files via VisionPort
 ///
 /// # Type Parameters
 ///
@@ -151,6 +351,8 @@ pub struct ArtifactGeneratorService {
     web_crawler: std::sync::Arc<dyn crate::ports::web_crawler_port::WebCrawlerPort + std::marker::Send + std::marker::Sync>,
     embedding_port: std::sync::Arc<dyn crate::ports::embedding_port::EmbeddingPort + std::marker::Send + std::marker::Sync>,
     artifact_repository: std::sync::Arc<std::sync::Mutex<dyn task_manager::ports::artifact_repository_port::ArtifactRepositoryPort + std::marker::Send>>,
+    vision_port: std::option::Option<std::sync::Arc<dyn crate::ports::vision_port::VisionPort + std::marker::Send + std::marker::Sync>>,
+    progress_sender: std::option::Option<tokio::sync::mpsc::Sender<GenerationProgress>>,
 }
 
 impl ArtifactGeneratorService {
@@ -173,13 +375,75 @@ impl ArtifactGeneratorService {
             web_crawler,
             embedding_port,
             artifact_repository,
+            vision_port: std::option::Option::None,
+            progress_sender: std::option::Option::None,
+        }
+    }
+
+    /// Sets the vision port used to transcribe image files in OCR mode.
+    pub fn with_vision_port(mut self, vision_port: std::sync::Arc<dyn crate::ports::vision_port::VisionPort + std::marker::Send + std::marker::Sync>) -> Self {
+        self.vision_port = std::option::Option::Some(vision_port);
+        self
+    }
+
+    /// Sets a channel that receives a GenerationProgress event after each item.
+    pub fn with_progress_sender(mut self, sender: tokio::sync::mpsc::Sender<GenerationProgress>) -> Self {
+        self.progress_sender = std::option::Option::Some(sender);
+        self
+    }
+
+    /// Sends a processing progress event if a progress channel is configured.
+    async fn report_progress(&self, processed: usize, total: usize, current_item: &str) {
+        if let std::option::Option::Some(ref tx) = self.progress_sender {
+            let _ = tx.send(GenerationProgress {
+                stage: GenerationStage::Processing,
+                processed,
+                total,
+                current_item: String::from(current_item),
+            }).await;
+        }
+    }
+
+    /// Generates artifacts from any source, detecting its kind automatically.
+    ///
+    /// Directories go through the directory scanner, http(s) URLs through the
+    /// web crawler, and single files (text, PDF, image) through
+    /// `generate_from_file`.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Directory path, file path, or URL
+    /// * `config` - Generation configuration options
+    /// * `scan_config` - Directory scan configuration (directory sources)
+    /// * `crawl_config` - Web crawl configuration (URL sources)
+    ///
+    /// # Returns
+    ///
+    /// Returns a GenerationReport with statistics and any errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source does not exist or cannot be processed.
+    pub async fn generate(
+        &self,
+        source: &str,
+        config: &GenerationConfig,
+        scan_config: &task_manager::domain::scan_config::ScanConfig,
+        crawl_config: &crate::domain::crawl_result::CrawlConfig,
+    ) -> std::result::Result<GenerationReport, String> {
+        match SourceKind::detect(source)? {
+            SourceKind::Directory => self.generate_from_directory(source, config, scan_config).await,
+            SourceKind::Web => self.generate_from_url(source, config, crawl_config).await,
+            kind => self.generate_from_file(source, kind, config).await,
         }
     }
 
     /// Generates artifacts from a directory by scanning files.
     ///
     /// Scans the directory at `path` respecting .gitignore patterns, chunks
-    /// file contents, generates embeddings, and persists artifacts.
+    /// file contents, generates embeddings, and persists artifacts. PDFs in
+    /// the directory are read from disk and ingested page by page, like a
+    /// single PDF source.
     ///
     /// # Arguments
     ///
@@ -217,8 +481,27 @@ impl ArtifactGeneratorService {
         }
 
         // 2. Process each file
-        for file in scan_result.files {
-            match self.process_file(&file, config).await {
+        let total = scan_result.files.len();
+        for (index, file) in scan_result.files.into_iter().enumerate() {
+            // The scanner reads files as text, which a PDF is not
+            if matches!(Self::artifact_type_from_extension(&file.extension), task_manager::domain::artifact::ArtifactType::PDF) {
+                let ingested = match std::fs::read(&file.absolute_path) {
+                    std::result::Result::Ok(bytes) => {
+                        self.ingest_pdf(&bytes, &file.path, std::option::Option::None, config, &mut report, false).await
+                    }
+                    std::result::Result::Err(e) => std::result::Result::Err(std::format!("Failed to read {}: {}", file.absolute_path, e)),
+                };
+                match ingested {
+                    std::result::Result::Ok(true) => report.bytes_processed += file.size_bytes,
+                    std::result::Result::Ok(false) => {}
+                    std::result::Result::Err(e) => {
+                        report.add_error(std::format!("File processing failed for {}: {}", file.path, e));
+                    }
+                }
+                self.report_progress(index + 1, total, &file.path).await;
+                continue;
+            }
+            match self.process_file(&file, config, SourceKind::Directory).await {
                 std::result::Result::Ok(artifacts_created) => {
                     report.artifacts_created += artifacts_created;
                     report.bytes_processed += file.size_bytes;
@@ -227,6 +510,10 @@ impl ArtifactGeneratorService {
                     report.add_error(std::format!("File processing failed for {}: {}", file.path, e));
                 }
             }
+            self.report_progress(index + 1, total, &file.path).await;
+        }
+        if total == 0 {
+            self.report_progress(0, 0, path).await;
         }
 
         report.duration_ms = start_time.elapsed().as_millis() as u64;
@@ -238,6 +525,10 @@ impl ArtifactGeneratorService {
     /// Crawls the website starting at `url`, extracts text content, chunks it,
     /// generates embeddings, and persists artifacts.
     ///
+    /// HTML pages arrive as markdown; PDF responses are ingested page by page
+    /// like local PDFs, citing the page URL. Responses the crawler skipped for
+    /// their content type are listed as report warnings.
+    ///
     /// # Arguments
     ///
     /// * `url` - Starting URL to crawl
@@ -260,48 +551,369 @@ impl ArtifactGeneratorService {
         let start_time = std::time::Instant::now();
         let mut report = GenerationReport::new();
 
-        // 1. Crawl the website
+        // 1. Crawl the website, reporting each page as it is fetched
+        let fetched = std::sync::atomic::AtomicUsize::new(0);
+        let progress_sender = self.progress_sender.clone();
+        let on_page = |page_url: &str| {
+            let processed = fetched.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            if let std::option::Option::Some(ref tx) = progress_sender {
+                // Best effort: the crawler cannot wait on a full channel
+                let _ = tx.try_send(GenerationProgress {
+                    stage: GenerationStage::Fetching,
+                    processed,
+                    total: crawl_config.max_pages.max(processed),
+                    current_item: String::from(page_url),
+                });
+            }
+        };
         let crawl_result = self.web_crawler
-            .crawl(crawl_config)
+            .crawl_with_progress(crawl_config, &on_page)
             .await
             .map_err(|e| std::format!("Web crawl failed: {:?}", e))?;
 
         report.pages_crawled = crawl_result.pages.len();
+        report.external_links = crawl_result.external_links;
+        report.pages_unchanged = crawl_result.stats.pages_unchanged;
 
         // Collect non-fatal crawl errors
         for error in &crawl_result.errors {
             report.add_error(std::format!("Crawl error: {} - {}", error.url, error.message));
         }
+        for skipped in &crawl_result.skipped {
+            report.add_warning(std::format!("Skipped {}: {}", skipped.url, skipped.message));
+        }
 
         // 2. Process each page
-        for page in crawl_result.pages {
+        let total = crawl_result.pages.len();
+        for (index, page) in crawl_result.pages.into_iter().enumerate() {
+            if let std::option::Option::Some(bytes) = &page.raw_body
+                && page.kind() == crate::domain::crawl_result::CrawlContentKind::Pdf
+            {
+                let source_url = std::option::Option::Some(page.url.clone());
+                match self.ingest_pdf(bytes, &page.url, source_url, config, &mut report, false).await {
+                    std::result::Result::Ok(true) => {
+                        report.bytes_processed += bytes.len();
+                        self.mark_page_stored(&page, &mut report).await;
+                    }
+                    std::result::Result::Ok(false) => {}
+                    std::result::Result::Err(e) => {
+                        report.add_error(std::format!("PDF processing failed for {}: {}", page.url, e));
+                    }
+                }
+                self.report_progress(index + 1, total, &page.url).await;
+                continue;
+            }
+
             match self.process_page(&page, config).await {
                 std::result::Result::Ok(artifacts_created) => {
                     report.artifacts_created += artifacts_created;
                     report.bytes_processed += page.content.len();
+                    self.mark_page_stored(&page, &mut report).await;
                 }
                 std::result::Result::Err(e) => {
                     report.add_error(std::format!("Page processing failed for {}: {}", page.url, e));
                 }
             }
+            self.report_progress(index + 1, total, &page.url).await;
+        }
+        if total == 0 {
+            self.report_progress(0, 0, url).await;
+        }
+
+        report.duration_ms = start_time.elapsed().as_millis() as u64;
+        std::result::Result::Ok(report)
+    }
+
+    /// Tells the crawler a page's artifacts are stored so it can skip the page
+    /// on re-crawls until it changes; a failure is reported as a warning.
+    async fn mark_page_stored(&self, page: &crate::domain::crawl_result::CrawledPage, report: &mut GenerationReport) {
+        if let std::result::Result::Err(e) = self.web_crawler.page_stored(page).await {
+            report.add_warning(std::format!("{}; the page will be fetched again on the next crawl", e));
         }
+    }
+
+    /// Generates artifacts from a single file.
+    ///
+    /// Text files are chunked as-is, PDFs have their text extracted first,
+    /// and images are transcribed via the vision port (requires OCR mode).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the file
+    /// * `kind` - Detected kind of the file (`File`, `Pdf`, or `Image`)
+    /// * `config` - Generation configuration options
+    ///
+    /// # Returns
+    ///
+    /// Returns a GenerationReport with statistics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, is an image without OCR
+    /// mode, or fails to process.
+    pub async fn generate_from_file(
+        &self,
+        path: &str,
+        kind: SourceKind,
+        config: &GenerationConfig,
+    ) -> std::result::Result<GenerationReport, String> {
+        if kind == SourceKind::Pdf {
+            return self.generate_from_pdf(path, config).await;
+        }
+
+        let start_time = std::time::Instant::now();
+        let mut report = GenerationReport::new();
+        let file_path = std::path::Path::new(path);
+
+        let content = match kind {
+            SourceKind::Image => {
+                if !config.ocr {
+                    return std::result::Result::Err(std::format!(
+                        "Image source '{}' requires OCR mode to extract text",
+                        path
+                    ));
+                }
+                String::new()
+            }
+            _ => std::fs::read_to_string(file_path)
+                .map_err(|e| std::format!("Failed to read {} as text: {}", path, e))?,
+        };
+
+        let size_bytes = std::fs::metadata(file_path)
+            .map(|m| m.len() as usize)
+            .map_err(|e| std::format!("Failed to read metadata for {}: {}", path, e))?;
+        let modified_at = std::fs::metadata(file_path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+
+        let file = task_manager::domain::scan_config::ScannedFile {
+            path: file_path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .map(String::from)
+                .unwrap_or_else(|| String::from(path)),
+            absolute_path: String::from(path),
+            extension: file_path
+                .extension()
+                .and_then(|e| e.to_str())
+                .unwrap_or("")
+                .to_string(),
+            size_bytes,
+            fingerprint: task_manager::domain::scan_config::FileFingerprint::from_content(&content, modified_at),
+            line_count: content.lines().count(),
+            content,
+        };
+
+        report.files_scanned = 1;
+        report.artifacts_created = self.process_file(&file, config, kind).await?;
+        report.bytes_processed = file.size_bytes;
+        self.report_progress(1, 1, path).await;
+
+        report.duration_ms = start_time.elapsed().as_millis() as u64;
+        std::result::Result::Ok(report)
+    }
+
+    /// Generates artifacts from a PDF, one page at a time.
+    ///
+    /// Text is extracted per page and chunked with the configured strategy.
+    /// Each artifact records its page number (`page_number` and `"page"` in
+    /// metadata) so answers can cite the page. Encrypted PDFs and PDFs with
+    /// no extractable text (image-only or scanned) are skipped with a warning
+    /// in the report instead of producing empty artifacts.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the PDF file
+    /// * `config` - Generation configuration options
+    ///
+    /// # Returns
+    ///
+    /// Returns a GenerationReport with statistics and any skip warning.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed, or if
+    /// embedding or persistence fails.
+    pub async fn generate_from_pdf(
+        &self,
+        path: &str,
+        config: &GenerationConfig,
+    ) -> std::result::Result<GenerationReport, String> {
+        let start_time = std::time::Instant::now();
+        let mut report = GenerationReport::new();
 
+        let bytes = std::fs::read(path)
+            .map_err(|e| std::format!("Failed to read {}: {}", path, e))?;
+        report.files_scanned = 1;
+
+        let source_id = std::path::Path::new(path)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .map(String::from)
+            .unwrap_or_else(|| String::from(path));
+        if self.ingest_pdf(&bytes, &source_id, std::option::Option::None, config, &mut report, true).await? {
+            report.bytes_processed = bytes.len();
+        }
         report.duration_ms = start_time.elapsed().as_millis() as u64;
         std::result::Result::Ok(report)
     }
 
+    /// Chunks, embeds, and persists the pages of a PDF held in memory.
+    ///
+    /// Shared by local PDFs and PDFs found while crawling. Encrypted and
+    /// image-only PDFs add a warning to `report` and create nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The PDF file contents
+    /// * `source_id` - Source recorded on each artifact (file name or URL)
+    /// * `source_url` - URL the PDF was fetched from, if any
+    /// * `config` - Generation configuration options
+    /// * `report` - Report receiving chunk and artifact counts and warnings
+    /// * `page_progress` - Whether to send a progress event per PDF page
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the PDF had text to ingest, `false` if it was skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the PDF cannot be parsed, or if embedding or
+    /// persistence fails.
+    async fn ingest_pdf(
+        &self,
+        bytes: &[u8],
+        source_id: &str,
+        source_url: std::option::Option<String>,
+        config: &GenerationConfig,
+        report: &mut GenerationReport,
+        page_progress: bool,
+    ) -> std::result::Result<bool, String> {
+        let pages = match Self::extract_pdf_pages(bytes)? {
+            PdfText::Encrypted => {
+                report.add_warning(std::format!("Skipped {}: PDF is encrypted", source_id));
+                return std::result::Result::Ok(false);
+            }
+            PdfText::Pages(pages) => pages,
+        };
+        if pages.iter().all(|page| page.trim().is_empty()) {
+            report.add_warning(std::format!(
+                "Skipped {}: no extractable text (image-only or scanned PDF)",
+                source_id
+            ));
+            return std::result::Result::Ok(false);
+        }
+
+        let page_count = pages.len();
+
+        for (index, page_text) in pages.iter().enumerate() {
+            let page_number = (index + 1) as u32;
+            let chunks = self.chunk_content(page_text, &config.chunk_strategy, config.max_chunk_size);
+            if chunks.is_empty() {
+                if page_progress {
+                    self.report_progress(index + 1, page_count, &std::format!("{}#page={}", source_id, page_number)).await;
+                }
+                continue;
+            }
+            report.chunks_generated += chunks.len();
+
+            // Generate embeddings for all chunks on this page
+            let chunk_refs: std::vec::Vec<&str> = chunks.iter().map(|s| s.as_str()).collect();
+            let embeddings = self.embedding_port
+                .generate_embeddings(&chunk_refs)
+                .await
+                .map_err(|e| std::format!("Embedding generation failed: {}", e))?;
+
+            if embeddings.len() != chunks.len() {
+                return std::result::Result::Err(std::format!(
+                    "Embedding count mismatch: expected {}, got {}",
+                    chunks.len(),
+                    embeddings.len()
+                ));
+            }
+
+            {
+                let mut repo = self.artifact_repository.lock()
+                    .map_err(|e| std::format!("Failed to acquire repository lock: {}", e))?;
+
+                for (i, (chunk, embedding)) in chunks.into_iter().zip(embeddings).enumerate() {
+                    let artifact = task_manager::domain::artifact::Artifact {
+                        id: uuid::Uuid::new_v4().to_string(),
+                        project_id: config.project_id.clone(),
+                        source_id: String::from(source_id),
+                        source_type: task_manager::domain::artifact::ArtifactType::PDF,
+                        content: chunk,
+                        embedding_dimension: std::option::Option::Some(embedding.len()),
+                        embedding,
+                        metadata: std::option::Option::Some(std::format!(
+                            "{{\"chunk_index\": {}, \"page\": {}, \"page_count\": {}, \"source_type\": \"{}\"}}",
+                            i, page_number, page_count, SourceKind::Pdf.as_str()
+                        )),
+                        created_at: chrono::Utc::now(),
+                        binary_content: std::option::Option::None,
+                        mime_type: std::option::Option::Some(String::from("application/pdf")),
+                        source_url: source_url.clone(),
+                        page_number: std::option::Option::Some(page_number),
+                        embedding_model: self.embedding_port.embedding_model(),
+                    };
+
+                    repo.save(artifact)
+                        .map_err(|e| std::format!("Failed to save artifact: {}", e))?;
+
+                    report.artifacts_created += 1;
+                }
+            }
+
+            if page_progress {
+                self.report_progress(index + 1, page_count, &std::format!("{}#page={}", source_id, page_number)).await;
+            }
+        }
+
+        std::result::Result::Ok(true)
+    }
+
     /// Processes a single file into artifacts.
+    ///
+    /// Images and PDFs are tagged with their own source kind; other files
+    /// keep the kind of the source they were found in.
     async fn process_file(
         &self,
         file: &task_manager::domain::scan_config::ScannedFile,
         config: &GenerationConfig,
+        source_kind: SourceKind,
     ) -> std::result::Result<usize, String> {
-        if file.content.is_empty() {
+        // Determine artifact type from extension
+        let artifact_type = Self::artifact_type_from_extension(&file.extension);
+        let source_kind = match artifact_type {
+            task_manager::domain::artifact::ArtifactType::Image => SourceKind::Image,
+            task_manager::domain::artifact::ArtifactType::PDF => SourceKind::Pdf,
+            _ => source_kind,
+        };
+
+        // In OCR mode, image text comes from the vision port instead of the scanner
+        let ocr_image = config.ocr
+            && matches!(artifact_type, task_manager::domain::artifact::ArtifactType::Image);
+        let content = if ocr_image {
+            let vision = self.vision_port.as_ref().ok_or_else(|| {
+                String::from("OCR requested but no vision port is configured")
+            })?;
+            vision
+                .extract_text(std::path::Path::new(&file.absolute_path))
+                .await
+                .map_err(|e| std::format!("OCR failed: {}", e))?
+        } else {
+            file.content.clone()
+        };
+
+        if content.is_empty() {
             return std::result::Result::Ok(0);
         }
 
         // Chunk the content
-        let chunks = self.chunk_content(&file.content, &config.chunk_strategy, config.max_chunk_size);
+        let chunks = self.chunk_content(&content, &config.chunk_strategy, config.max_chunk_size);
         if chunks.is_empty() {
             return std::result::Result::Ok(0);
         }
@@ -321,8 +933,10 @@ impl ArtifactGeneratorService {
             ));
         }
 
-        // Determine artifact type from extension
-        let artifact_type = Self::artifact_type_from_extension(&file.extension);
+        // Locate each chunk's line range for citations
+        let line_ranges = Self::chunk_line_ranges(&content, &chunks);
+        let source_path = serde_json::to_string(&file.path)
+            .map_err(|e| std::format!("Failed to encode source path: {}", e))?;
 
         // Create and persist artifacts
         let mut artifacts_created = 0;
@@ -330,22 +944,28 @@ impl ArtifactGeneratorService {
             .map_err(|e| std::format!("Failed to acquire repository lock: {}", e))?;
 
         for (i, (chunk, embedding)) in chunks.into_iter().zip(embeddings.into_iter()).enumerate() {
+            let line_range = match line_ranges[i] {
+                std::option::Option::Some((start, end)) => std::format!(", \"line_start\": {}, \"line_end\": {}", start, end),
+                std::option::Option::None => String::new(),
+            };
             let artifact = task_manager::domain::artifact::Artifact {
                 id: uuid::Uuid::new_v4().to_string(),
                 project_id: config.project_id.clone(),
                 source_id: file.path.clone(),
                 source_type: artifact_type.clone(),
                 content: chunk,
+                embedding_dimension: std::option::Option::Some(embedding.len()),
                 embedding,
                 metadata: std::option::Option::Some(std::format!(
-                    "{{\"chunk_index\": {}, \"line_count\": {}, \"file_size\": {}}}",
-                    i, file.line_count, file.size_bytes
+                    "{{\"chunk_index\": {}, \"line_count\": {}, \"file_size\": {}, \"ocr\": {}, \"source_type\": \"{}\", \"source_path\": {}{}}}",
+                    i, file.line_count, file.size_bytes, ocr_image, source_kind.as_str(), source_path, line_range
                 )),
                 created_at: chrono::Utc::now(),
                 binary_content: std::option::Option::None,
                 mime_type: std::option::Option::None,
                 source_url: std::option::Option::None,
                 page_number: std::option::Option::None,
+                embedding_model: self.embedding_port.embedding_model(),
             };
 
             repo.save(artifact)
@@ -400,18 +1020,21 @@ impl ArtifactGeneratorService {
                 source_id: page.url.clone(),
                 source_type: task_manager::domain::artifact::ArtifactType::WebResearch,
                 content: chunk,
+                embedding_dimension: std::option::Option::Some(embedding.len()),
                 embedding,
                 metadata: std::option::Option::Some(std::format!(
-                    "{{\"chunk_index\": {}, \"page_title\": \"{}\", \"depth\": {}}}",
+                    "{{\"chunk_index\": {}, \"page_title\": \"{}\", \"depth\": {}, \"source_type\": \"{}\"}}",
                     i,
                     page.title.replace('"', "\\\""),
-                    page.depth
+                    page.depth,
+                    SourceKind::Web.as_str()
                 )),
                 created_at: chrono::Utc::now(),
                 binary_content: std::option::Option::None,
                 mime_type: std::option::Option::None,
                 source_url: std::option::Option::Some(page.url.clone()),
                 page_number: std::option::Option::None,
+                embedding_model: self.embedding_port.embedding_model(),
             };
 
             repo.save(artifact)
@@ -504,6 +1127,42 @@ impl ArtifactGeneratorService {
         chunks
     }
 
+    /// Finds the 1-indexed line range each chunk occupies in the content.
+    ///
+    /// Chunks are searched for in order, so repeated text resolves to
+    /// successive occurrences. Chunks not found verbatim get None.
+    fn chunk_line_ranges(content: &str, chunks: &[String]) -> std::vec::Vec<std::option::Option<(usize, usize)>> {
+        let mut cursor = 0;
+        chunks
+            .iter()
+            .map(|chunk| {
+                let start = cursor + content[cursor..].find(chunk.as_str())?;
+                cursor = start + chunk.len();
+                let line_start = content[..start].matches('\n').count() + 1;
+                std::option::Option::Some((line_start, line_start + chunk.matches('\n').count()))
+            })
+            .collect()
+    }
+
+    /// Extracts the text of each page of a PDF.
+    ///
+    /// Returns `PdfText::Encrypted` for password-protected PDFs and an error
+    /// for files that cannot be parsed as PDF at all.
+    fn extract_pdf_pages(bytes: &[u8]) -> std::result::Result<PdfText, String> {
+        match pdf_extract::extract_text_from_mem_by_pages(bytes) {
+            std::result::Result::Ok(pages) => std::result::Result::Ok(PdfText::Pages(pages)),
+            std::result::Result::Err(e) => {
+                let message = e.to_string();
+                let lowered = message.to_lowercase();
+                if lowered.contains("decrypt") || lowered.contains("encrypt") || lowered.contains("password") {
+                    std::result::Result::Ok(PdfText::Encrypted)
+                } else {
+                    std::result::Result::Err(std::format!("Failed to extract text from PDF: {}", message))
+                }
+            }
+        }
+    }
+
     /// Determines artifact type from file extension.
     fn artifact_type_from_extension(extension: &str) -> task_manager::domain::artifact::ArtifactType {
         match extension.to_lowercase().as_str() {
@@ -563,16 +1222,33 @@ mod tests {
     /// Mock web crawler for testing.
     struct MockWebCrawler {
         pages: std::vec::Vec<crate::domain::crawl_result::CrawledPage>,
+        stored: std::sync::Mutex<std::vec::Vec<String>>,
+    }
+
+    impl MockWebCrawler {
+        fn new(pages: std::vec::Vec<crate::domain::crawl_result::CrawledPage>) -> Self {
+            MockWebCrawler { pages, stored: std::sync::Mutex::new(std::vec::Vec::new()) }
+        }
     }
 
     #[async_trait::async_trait]
     impl crate::ports::web_crawler_port::WebCrawlerPort for MockWebCrawler {
         async fn crawl(
             &self,
-            _config: &crate::domain::crawl_result::CrawlConfig,
+            config: &crate::domain::crawl_result::CrawlConfig,
         ) -> std::result::Result<crate::ports::web_crawler_port::CrawlResult, crate::domain::crawl_result::CrawlError> {
             let mut result = crate::ports::web_crawler_port::CrawlResult::new();
-            result.pages = self.pages.clone();
+            for page in &self.pages {
+                if config.allows_content_type(&page.content_type) {
+                    result.pages.push(page.clone());
+                } else {
+                    result.skipped.push(crate::ports::web_crawler_port::CrawlPageError::new(
+                        page.url.clone(),
+                        std::option::Option::Some(page.status_code),
+                        std::format!("content type {} not allowed", page.content_type),
+                    ));
+                }
+            }
             std::result::Result::Ok(result)
         }
 
@@ -586,6 +1262,14 @@ mod tests {
             ))
         }
 
+        async fn page_stored(
+            &self,
+            page: &crate::domain::crawl_result::CrawledPage,
+        ) -> std::result::Result<(), crate::domain::crawl_result::CrawlError> {
+            self.stored.lock().unwrap().push(page.url.clone());
+            std::result::Result::Ok(())
+        }
+
         fn extract_text(&self, _html: &str, _config: &crate::domain::crawl_result::CrawlConfig) -> String {
             String::new()
         }
@@ -623,15 +1307,35 @@ mod tests {
         }
     }
 
+    /// Mock embedding port whose calls always fail.
+    struct FailingEmbeddingPort;
+
+    #[async_trait::async_trait]
+    impl crate::ports::embedding_port::EmbeddingPort for FailingEmbeddingPort {
+        async fn generate_embedding(&self, _text: &str) -> std::result::Result<std::vec::Vec<f32>, String> {
+            std::result::Result::Err(String::from("embedding service unavailable"))
+        }
+
+        async fn generate_embeddings(&self, _texts: &[&str]) -> std::result::Result<std::vec::Vec<std::vec::Vec<f32>>, String> {
+            std::result::Result::Err(String::from("embedding service unavailable"))
+        }
+
+        async fn embedding_dimension(&self) -> usize {
+            384
+        }
+    }
+
     /// Mock artifact repository for testing.
     struct MockArtifactRepository {
         saved_count: std::sync::atomic::AtomicUsize,
+        saved: std::vec::Vec<task_manager::domain::artifact::Artifact>,
     }
 
     impl MockArtifactRepository {
         fn new() -> Self {
             MockArtifactRepository {
                 saved_count: std::sync::atomic::AtomicUsize::new(0),
+                saved: std::vec::Vec::new(),
             }
         }
 
@@ -641,8 +1345,9 @@ mod tests {
     }
 
     impl hexser::ports::Repository<task_manager::domain::artifact::Artifact> for MockArtifactRepository {
-        fn save(&mut self, _entity: task_manager::domain::artifact::Artifact) -> hexser::HexResult<()> {
+        fn save(&mut self, entity: task_manager::domain::artifact::Artifact) -> hexser::HexResult<()> {
             self.saved_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            self.saved.push(entity);
             std::result::Result::Ok(())
         }
     }
@@ -672,6 +1377,98 @@ mod tests {
         }
     }
 
+    /// Mock vision port recording the context of each request.
+    struct MockVisionPort {
+        contexts: std::sync::Mutex<std::vec::Vec<std::option::Option<String>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::ports::vision_port::VisionPort for MockVisionPort {
+        async fn describe_image(
+            &self,
+            _base64_data: &str,
+            _mime_type: &str,
+            context: std::option::Option<&str>,
+        ) -> std::result::Result<crate::ports::vision_port::VisionResponse, String> {
+            self.contexts.lock().unwrap().push(context.map(String::from));
+            std::result::Result::Ok(crate::ports::vision_port::VisionResponse {
+                description: String::from("  INVOICE #42\nTotal: $100  "),
+                processing_time_ms: 5,
+            })
+        }
+
+        async fn describe_pdf_page(
+            &self,
+            _page_image_base64: &str,
+            _page_number: u32,
+            _context: std::option::Option<&str>,
+        ) -> std::result::Result<crate::ports::vision_port::VisionResponse, String> {
+            std::result::Result::Err(String::from("not used"))
+        }
+
+        fn model_name(&self) -> &str {
+            "mock-vision"
+        }
+
+        fn provider_name(&self) -> &str {
+            "mock"
+        }
+    }
+
+    /// Writes a minimal PNG to a temp file and returns it as a scanned image file.
+    fn scanned_png(dir: &std::path::Path) -> task_manager::domain::scan_config::ScannedFile {
+        let absolute = dir.join("invoice.png");
+        std::fs::write(&absolute, b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR").unwrap();
+        task_manager::domain::scan_config::ScannedFile {
+            path: String::from("invoice.png"),
+            absolute_path: absolute.display().to_string(),
+            content: String::new(),
+            extension: String::from("png"),
+            size_bytes: 16,
+            fingerprint: task_manager::domain::scan_config::FileFingerprint::new(String::from("img"), 0, 16),
+            line_count: 0,
+        }
+    }
+
+    /// Builds a minimal PDF with one Helvetica text line per page; empty strings give pages without text.
+    fn build_pdf(pages: &[&str]) -> std::vec::Vec<u8> {
+        let mut objects: std::vec::Vec<String> = std::vec::Vec::new();
+        let kids: std::vec::Vec<String> = (0..pages.len()).map(|i| std::format!("{} 0 R", 4 + 2 * i)).collect();
+        objects.push(String::from("<< /Type /Catalog /Pages 2 0 R >>"));
+        objects.push(std::format!("<< /Type /Pages /Kids [{}] /Count {} >>", kids.join(" "), pages.len()));
+        objects.push(String::from("<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>"));
+        for (i, text) in pages.iter().enumerate() {
+            objects.push(std::format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+                5 + 2 * i
+            ));
+            let stream = if text.is_empty() {
+                String::new()
+            } else {
+                std::format!("BT /F1 12 Tf 72 720 Td ({}) Tj ET", text)
+            };
+            objects.push(std::format!("<< /Length {} >>\nstream\n{}\nendstream", stream.len(), stream));
+        }
+
+        let mut pdf = String::from("%PDF-1.4\n");
+        let mut offsets = std::vec::Vec::new();
+        for (i, object) in objects.iter().enumerate() {
+            offsets.push(pdf.len());
+            pdf.push_str(&std::format!("{} 0 obj\n{}\nendobj\n", i + 1, object));
+        }
+        let xref_offset = pdf.len();
+        pdf.push_str(&std::format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1));
+        for offset in offsets {
+            pdf.push_str(&std::format!("{:010} 00000 n \n", offset));
+        }
+        pdf.push_str(&std::format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            xref_offset
+        ));
+        pdf.into_bytes()
+    }
+
     #[test]
     fn test_generation_report_new() {
         // Test: Validates new report is empty.
@@ -703,12 +1500,14 @@ mod tests {
         let config = GenerationConfig::new(String::from("project-123"))
             .with_chunk_strategy(task_manager::domain::scan_config::ChunkStrategy::Sentence)
             .with_max_chunk_size(500)
-            .with_incremental(true);
+            .with_incremental(true)
+            .with_ocr(true);
 
         std::assert_eq!(config.project_id, "project-123");
         std::assert!(matches!(config.chunk_strategy, task_manager::domain::scan_config::ChunkStrategy::Sentence));
         std::assert_eq!(config.max_chunk_size, 500);
         std::assert!(config.incremental);
+        std::assert!(config.ocr);
     }
 
     #[test]
@@ -778,7 +1577,7 @@ mod tests {
         // Test: Validates empty directory handling.
         // Justification: No files should produce zero artifacts.
         let scanner = std::sync::Arc::new(MockDirectoryScanner { files: std::vec::Vec::new() });
-        let crawler = std::sync::Arc::new(MockWebCrawler { pages: std::vec::Vec::new() });
+        let crawler = std::sync::Arc::new(MockWebCrawler::new(std::vec::Vec::new()));
         let embedding = std::sync::Arc::new(MockEmbeddingPort { dimension: 384 });
         let repo = std::sync::Arc::new(std::sync::Mutex::new(MockArtifactRepository::new()));
 
@@ -816,7 +1615,7 @@ mod tests {
         ];
 
         let scanner = std::sync::Arc::new(MockDirectoryScanner { files });
-        let crawler = std::sync::Arc::new(MockWebCrawler { pages: std::vec::Vec::new() });
+        let crawler = std::sync::Arc::new(MockWebCrawler::new(std::vec::Vec::new()));
         let embedding = std::sync::Arc::new(MockEmbeddingPort { dimension: 384 });
         let repo = std::sync::Arc::new(std::sync::Mutex::new(MockArtifactRepository::new()));
 
@@ -855,17 +1654,18 @@ mod tests {
                 depth: 0,
                 status_code: 200,
                 content_type: String::from("text/html"),
+                raw_body: std::option::Option::None,
             },
         ];
 
         let scanner = std::sync::Arc::new(MockDirectoryScanner { files: std::vec::Vec::new() });
-        let crawler = std::sync::Arc::new(MockWebCrawler { pages });
+        let crawler = std::sync::Arc::new(MockWebCrawler::new(pages));
         let embedding = std::sync::Arc::new(MockEmbeddingPort { dimension: 384 });
         let repo = std::sync::Arc::new(std::sync::Mutex::new(MockArtifactRepository::new()));
 
         let service = ArtifactGeneratorService::new(
             scanner,
-            crawler,
+            crawler.clone(),
             embedding,
             repo.clone(),
         );
@@ -883,5 +1683,446 @@ mod tests {
         // Verify repository was called
         let saved = repo.lock().unwrap().get_saved_count();
         std::assert_eq!(saved, 2);
+        std::assert_eq!(*crawler.stored.lock().unwrap(), std::vec![String::from("https://example.com/")]);
+    }
+
+    #[tokio::test]
+    async fn test_failed_page_is_not_marked_stored() {
+        // Test: Validates a page whose artifacts could not be created is not reported to the crawler as stored.
+        // Justification: Saving its validators would make re-crawls skip the page with nothing ingested.
+        let pages = std::vec![
+            crate::domain::crawl_result::CrawledPage {
+                url: String::from("https://example.com/"),
+                title: String::from("Example"),
+                content: String::from("First section.\n\nSecond section."),
+                links: std::vec::Vec::new(),
+                depth: 0,
+                status_code: 200,
+                content_type: String::from("text/html"),
+                raw_body: std::option::Option::None,
+            },
+        ];
+
+        let scanner = std::sync::Arc::new(MockDirectoryScanner { files: std::vec::Vec::new() });
+        let crawler = std::sync::Arc::new(MockWebCrawler::new(pages));
+        let repo = std::sync::Arc::new(std::sync::Mutex::new(MockArtifactRepository::new()));
+        let service = ArtifactGeneratorService::new(scanner, crawler.clone(), std::sync::Arc::new(FailingEmbeddingPort), repo);
+
+        let config = GenerationConfig::new(String::from("project-123"));
+        let crawl_config = crate::domain::crawl_result::CrawlConfig::new(String::from("https://example.com"));
+        let report = service.generate_from_url("https://example.com", &config, &crawl_config).await.unwrap();
+
+        std::assert_eq!(report.artifacts_created, 0);
+        std::assert_eq!(report.errors.len(), 1);
+        std::assert!(crawler.stored.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_ocr_mode_stores_extracted_text_as_artifact_content() {
+        // Test: Validates OCR mode sends the OCR prompt and stores the transcription as content.
+        // Justification: Screenshots and scanned documents must become searchable text artifacts.
+        let dir = std::env::temp_dir().join(std::format!("artifact_ocr_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let scanner = std::sync::Arc::new(MockDirectoryScanner { files: std::vec![scanned_png(&dir)] });
+        let crawler = std::sync::Arc::new(MockWebCrawler::new(std::vec::Vec::new()));
+        let embedding = std::sync::Arc::new(MockEmbeddingPort { dimension: 8 });
+        let repo = std::sync::Arc::new(std::sync::Mutex::new(MockArtifactRepository::new()));
+        let vision = std::sync::Arc::new(MockVisionPort { contexts: std::sync::Mutex::new(std::vec::Vec::new()) });
+
+        let service = ArtifactGeneratorService::new(scanner, crawler, embedding, repo.clone())
+            .with_vision_port(vision.clone());
+        let config = GenerationConfig::new(String::from("project-123"))
+            .with_chunk_strategy(task_manager::domain::scan_config::ChunkStrategy::WholeFile)
+            .with_ocr(true);
+        let scan_config = task_manager::domain::scan_config::ScanConfig::new(dir.display().to_string());
+
+        let report = service.generate_from_directory("/test", &config, &scan_config).await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        std::assert_eq!(report.artifacts_created, 1);
+        let contexts = vision.contexts.lock().unwrap();
+        std::assert_eq!(contexts.len(), 1);
+        std::assert_eq!(contexts[0].as_deref(), std::option::Option::Some(crate::ports::vision_port::OCR_PROMPT));
+        let repo = repo.lock().unwrap();
+        std::assert_eq!(repo.saved[0].content, "INVOICE #42\nTotal: $100");
+        std::assert!(matches!(repo.saved[0].source_type, task_manager::domain::artifact::ArtifactType::Image));
+    }
+
+    #[tokio::test]
+    async fn test_images_skipped_without_ocr() {
+        // Test: Validates image files produce no artifacts and no vision calls when OCR is off.
+        // Justification: OCR costs a vision request per image and must be opt-in.
+        let dir = std::env::temp_dir().join(std::format!("artifact_no_ocr_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let scanner = std::sync::Arc::new(MockDirectoryScanner { files: std::vec![scanned_png(&dir)] });
+        let crawler = std::sync::Arc::new(MockWebCrawler::new(std::vec::Vec::new()));
+        let embedding = std::sync::Arc::new(MockEmbeddingPort { dimension: 8 });
+        let repo = std::sync::Arc::new(std::sync::Mutex::new(MockArtifactRepository::new()));
+        let vision = std::sync::Arc::new(MockVisionPort { contexts: std::sync::Mutex::new(std::vec::Vec::new()) });
+
+        let service = ArtifactGeneratorService::new(scanner, crawler, embedding, repo.clone())
+            .with_vision_port(vision.clone());
+        let config = GenerationConfig::new(String::from("project-123"));
+        let scan_config = task_manager::domain::scan_config::ScanConfig::new(dir.display().to_string());
+
+        let report = service.generate_from_directory("/test", &config, &scan_config).await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        std::assert_eq!(report.artifacts_created, 0);
+        std::assert!(vision.contexts.lock().unwrap().is_empty());
+        std::assert_eq!(repo.lock().unwrap().get_saved_count(), 0);
+    }
+
+    #[test]
+    fn test_detect_source_kinds() {
+        // Test: Validates directories, text files, PDFs, images, and http(s) URLs are told apart.
+        // Justification: `rig artifacts generate` dispatches on the detected kind.
+        let dir = std::env::temp_dir().join(std::format!("artifact_detect_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("notes.txt"), "Notes").unwrap();
+        std::fs::write(dir.join("spec.PDF"), b"%PDF-1.4").unwrap();
+        scanned_png(&dir);
+
+        let detect = |name: &str| SourceKind::detect(&dir.join(name).display().to_string()).unwrap();
+        std::assert_eq!(SourceKind::detect(&dir.display().to_string()).unwrap(), SourceKind::Directory);
+        std::assert_eq!(detect("notes.txt"), SourceKind::File);
+        std::assert_eq!(detect("spec.PDF"), SourceKind::Pdf);
+        std::assert_eq!(detect("invoice.png"), SourceKind::Image);
+        std::assert_eq!(SourceKind::detect("https://example.com/docs").unwrap(), SourceKind::Web);
+        std::assert_eq!(SourceKind::detect("http://localhost:8080").unwrap(), SourceKind::Web);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_detect_missing_path_errors() {
+        // Test: Validates a path that does not exist and is not a URL is rejected.
+        // Justification: Typos should fail clearly instead of silently generating nothing.
+        let missing = std::env::temp_dir().join(std::format!("artifact_missing_{}", uuid::Uuid::new_v4()));
+
+        let result = SourceKind::detect(&missing.display().to_string());
+
+        std::assert!(result.unwrap_err().contains("Source not found"));
+        std::assert!(SourceKind::detect("ftp.example.com/docs").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_generate_single_file_tags_source_type() {
+        // Test: Validates generate() ingests a single text file and tags artifacts as "file".
+        // Justification: Single files are a distinct source kind from directories.
+        let dir = std::env::temp_dir().join(std::format!("artifact_single_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("design.rs");
+        std::fs::write(&path, "fn main() {}\n\nfn helper() {}").unwrap();
+        let scanner = std::sync::Arc::new(MockDirectoryScanner { files: std::vec::Vec::new() });
+        let crawler = std::sync::Arc::new(MockWebCrawler::new(std::vec::Vec::new()));
+        let embedding = std::sync::Arc::new(MockEmbeddingPort { dimension: 8 });
+        let repo = std::sync::Arc::new(std::sync::Mutex::new(MockArtifactRepository::new()));
+        let service = ArtifactGeneratorService::new(scanner, crawler, embedding, repo.clone());
+        let source = path.display().to_string();
+
+        let report = service
+            .generate(
+                &source,
+                &GenerationConfig::new(String::from("project-123")),
+                &task_manager::domain::scan_config::ScanConfig::new(source.clone()),
+                &crate::domain::crawl_result::CrawlConfig::new(source.clone()),
+            )
+            .await
+            .unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        std::assert_eq!(report.files_scanned, 1);
+        std::assert_eq!(report.artifacts_created, 2);
+        let repo = repo.lock().unwrap();
+        std::assert_eq!(repo.saved[0].source_id, "design.rs");
+        std::assert!(repo.saved[0].metadata.as_ref().unwrap().contains("\"source_type\": \"file\""));
+    }
+
+    #[tokio::test]
+    async fn test_generate_single_image_requires_ocr_and_tags_image() {
+        // Test: Validates a single image is transcribed with OCR and tagged as "image", and is rejected without OCR.
+        // Justification: Images have no text content unless the vision model transcribes them.
+        let dir = std::env::temp_dir().join(std::format!("artifact_single_img_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let source = scanned_png(&dir).absolute_path;
+        let scanner = std::sync::Arc::new(MockDirectoryScanner { files: std::vec::Vec::new() });
+        let crawler = std::sync::Arc::new(MockWebCrawler::new(std::vec::Vec::new()));
+        let embedding = std::sync::Arc::new(MockEmbeddingPort { dimension: 8 });
+        let repo = std::sync::Arc::new(std::sync::Mutex::new(MockArtifactRepository::new()));
+        let vision = std::sync::Arc::new(MockVisionPort { contexts: std::sync::Mutex::new(std::vec::Vec::new()) });
+        let service = ArtifactGeneratorService::new(scanner, crawler, embedding, repo.clone())
+            .with_vision_port(vision);
+        let scan_config = task_manager::domain::scan_config::ScanConfig::new(source.clone());
+        let crawl_config = crate::domain::crawl_result::CrawlConfig::new(source.clone());
+
+        let without_ocr = service
+            .generate(&source, &GenerationConfig::new(String::from("p")), &scan_config, &crawl_config)
+            .await;
+        let with_ocr = service
+            .generate(&source, &GenerationConfig::new(String::from("p")).with_ocr(true), &scan_config, &crawl_config)
+            .await
+            .unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        std::assert!(without_ocr.unwrap_err().contains("requires OCR"));
+        std::assert_eq!(with_ocr.artifacts_created, 1);
+        let repo = repo.lock().unwrap();
+        std::assert!(repo.saved[0].metadata.as_ref().unwrap().contains("\"source_type\": \"image\""));
+    }
+
+    #[tokio::test]
+    async fn test_pdf_ingestion_extracts_text_per_page() {
+        // Test: Validates each PDF page becomes its own artifact tagged with its page number.
+        // Justification: Page numbers let answers cite where in a PDF a claim comes from.
+        let dir = std::env::temp_dir().join(std::format!("artifact_pdf_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("spec.pdf");
+        std::fs::write(&path, build_pdf(&["Alpha requirements", "Beta constraints"])).unwrap();
+        let scanner = std::sync::Arc::new(MockDirectoryScanner { files: std::vec::Vec::new() });
+        let crawler = std::sync::Arc::new(MockWebCrawler::new(std::vec::Vec::new()));
+        let embedding = std::sync::Arc::new(MockEmbeddingPort { dimension: 8 });
+        let repo = std::sync::Arc::new(std::sync::Mutex::new(MockArtifactRepository::new()));
+        let service = ArtifactGeneratorService::new(scanner, crawler, embedding, repo.clone());
+        let config = GenerationConfig::new(String::from("project-123"))
+            .with_chunk_strategy(task_manager::domain::scan_config::ChunkStrategy::WholeFile);
+
+        let report = service.generate_from_file(&path.display().to_string(), SourceKind::Pdf, &config).await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        std::assert_eq!(report.artifacts_created, 2);
+        std::assert!(report.warnings.is_empty());
+        let repo = repo.lock().unwrap();
+        std::assert!(repo.saved[0].content.contains("Alpha requirements"));
+        std::assert_eq!(repo.saved[0].page_number, std::option::Option::Some(1));
+        std::assert!(repo.saved[1].content.contains("Beta constraints"));
+        std::assert_eq!(repo.saved[1].page_number, std::option::Option::Some(2));
+        std::assert!(repo.saved[1].metadata.as_ref().unwrap().contains("\"page\": 2"));
+        std::assert_eq!(repo.saved[1].source_id, "spec.pdf");
+    }
+
+    #[tokio::test]
+    async fn test_directory_scan_ingests_pdfs_per_page() {
+        // Test: Validates a PDF found by a directory scan is read from disk and stored page by page.
+        // Justification: Scanned PDFs used to be chunked as their raw text content, without page numbers.
+        let dir = std::env::temp_dir().join(std::format!("artifact_dir_pdf_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("spec.pdf");
+        let bytes = build_pdf(&["Alpha requirements", "Beta constraints"]);
+        std::fs::write(&path, &bytes).unwrap();
+        let files = std::vec![task_manager::domain::scan_config::ScannedFile {
+            path: String::from("docs/spec.pdf"),
+            absolute_path: path.display().to_string(),
+            content: String::from("%PDF-1.4 garbled"),
+            extension: String::from("pdf"),
+            size_bytes: bytes.len(),
+            fingerprint: task_manager::domain::scan_config::FileFingerprint::new(String::from("pdf"), 1234567890, bytes.len()),
+            line_count: 1,
+        }];
+        let scanner = std::sync::Arc::new(MockDirectoryScanner { files });
+        let crawler = std::sync::Arc::new(MockWebCrawler::new(std::vec::Vec::new()));
+        let embedding = std::sync::Arc::new(MockEmbeddingPort { dimension: 8 });
+        let repo = std::sync::Arc::new(std::sync::Mutex::new(MockArtifactRepository::new()));
+        let service = ArtifactGeneratorService::new(scanner, crawler, embedding, repo.clone());
+        let config = GenerationConfig::new(String::from("project-123"))
+            .with_chunk_strategy(task_manager::domain::scan_config::ChunkStrategy::WholeFile);
+        let scan_config = task_manager::domain::scan_config::ScanConfig::new(dir.display().to_string());
+
+        let report = service.generate_from_directory(&dir.display().to_string(), &config, &scan_config).await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        std::assert!(report.errors.is_empty(), "errors: {:?}", report.errors);
+        std::assert_eq!(report.artifacts_created, 2);
+        let repo = repo.lock().unwrap();
+        std::assert!(repo.saved[1].content.contains("Beta constraints"));
+        std::assert_eq!(repo.saved[1].page_number, std::option::Option::Some(2));
+        std::assert_eq!(repo.saved[1].source_id, "docs/spec.pdf");
+        std::assert!(!repo.saved.iter().any(|artifact| artifact.content.contains("garbled")));
+    }
+
+    #[tokio::test]
+    async fn test_generate_from_url_ingests_pdfs_and_reports_skipped_types() {
+        // Test: Validates crawled PDFs become per-page artifacts citing the URL, and disallowed types are only warned about.
+        // Justification: Binary responses must go to the right ingestion path instead of being chunked as text.
+        let page = crate::domain::crawl_result::CrawledPage {
+            url: String::from("https://example.com/spec.pdf"),
+            title: String::new(),
+            content: String::new(),
+            links: std::vec::Vec::new(),
+            depth: 1,
+            status_code: 200,
+            content_type: String::from("application/pdf"),
+            raw_body: std::option::Option::Some(build_pdf(&["Alpha requirements"])),
+        };
+        let image = crate::domain::crawl_result::CrawledPage {
+            url: String::from("https://example.com/logo.png"),
+            content_type: String::from("image/png"),
+            raw_body: std::option::Option::None,
+            ..page.clone()
+        };
+        let scanner = std::sync::Arc::new(MockDirectoryScanner { files: std::vec::Vec::new() });
+        let crawler = std::sync::Arc::new(MockWebCrawler::new(std::vec![page, image]));
+        let embedding = std::sync::Arc::new(MockEmbeddingPort { dimension: 8 });
+        let repo = std::sync::Arc::new(std::sync::Mutex::new(MockArtifactRepository::new()));
+        let service = ArtifactGeneratorService::new(scanner, crawler, embedding, repo.clone());
+        let crawl_config = crate::domain::crawl_result::CrawlConfig::new(String::from("https://example.com"));
+
+        let report = service
+            .generate_from_url("https://example.com", &GenerationConfig::new(String::from("p")), &crawl_config)
+            .await
+            .unwrap();
+
+        std::assert_eq!(report.pages_crawled, 1);
+        std::assert_eq!(report.artifacts_created, 1);
+        std::assert!(!report.has_errors());
+        std::assert_eq!(report.warnings.len(), 1);
+        std::assert!(report.warnings[0].contains("logo.png"));
+        let repo = repo.lock().unwrap();
+        std::assert!(repo.saved[0].content.contains("Alpha requirements"));
+        std::assert_eq!(repo.saved[0].page_number, std::option::Option::Some(1));
+        std::assert_eq!(repo.saved[0].source_url.as_deref(), std::option::Option::Some("https://example.com/spec.pdf"));
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_pdf_is_skipped_with_user_facing_warning() {
+        // Test: Validates a password-protected PDF fixture creates no artifacts and tells the user why.
+        // Justification: The encrypted path depends on the extractor's error text, which only a real encrypted file exercises.
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("encrypted.pdf");
+        let scanner = std::sync::Arc::new(MockDirectoryScanner { files: std::vec::Vec::new() });
+        let crawler = std::sync::Arc::new(MockWebCrawler::new(std::vec::Vec::new()));
+        let embedding = std::sync::Arc::new(MockEmbeddingPort { dimension: 8 });
+        let repo = std::sync::Arc::new(std::sync::Mutex::new(MockArtifactRepository::new()));
+        let service = ArtifactGeneratorService::new(scanner, crawler, embedding, repo.clone());
+
+        let report = service
+            .generate_from_pdf(&path.display().to_string(), &GenerationConfig::new(String::from("p")))
+            .await
+            .unwrap();
+
+        std::assert_eq!(report.artifacts_created, 0);
+        std::assert_eq!(report.warnings, std::vec![String::from("Skipped encrypted.pdf: PDF is encrypted")]);
+        std::assert_eq!(report.bytes_processed, 0);
+        std::assert_eq!(repo.lock().unwrap().get_saved_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_image_only_pdf_is_skipped_with_reason() {
+        // Test: Validates a PDF without extractable text creates no artifacts and reports why.
+        // Justification: Scanned PDFs would otherwise pollute the knowledge base with empty artifacts.
+        let dir = std::env::temp_dir().join(std::format!("artifact_pdf_scan_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("scan.pdf");
+        std::fs::write(&path, build_pdf(&["", ""])).unwrap();
+        let scanner = std::sync::Arc::new(MockDirectoryScanner { files: std::vec::Vec::new() });
+        let crawler = std::sync::Arc::new(MockWebCrawler::new(std::vec::Vec::new()));
+        let embedding = std::sync::Arc::new(MockEmbeddingPort { dimension: 8 });
+        let repo = std::sync::Arc::new(std::sync::Mutex::new(MockArtifactRepository::new()));
+        let service = ArtifactGeneratorService::new(scanner, crawler, embedding, repo.clone());
+
+        let report = service
+            .generate_from_pdf(&path.display().to_string(), &GenerationConfig::new(String::from("p")))
+            .await
+            .unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        std::assert_eq!(report.artifacts_created, 0);
+        std::assert_eq!(report.warnings.len(), 1);
+        std::assert!(report.warnings[0].contains("no extractable text"));
+        std::assert_eq!(repo.lock().unwrap().get_saved_count(), 0);
+    }
+
+    #[test]
+    fn test_chunk_line_ranges() {
+        // Test: Validates each chunk maps to the lines it spans in the source file.
+        // Justification: Line ranges are the citation for file artifacts.
+        let content = "fn a() {}\n\nfn b() {\n    1\n}\n";
+        let chunks = ArtifactGeneratorService::chunk_by_paragraph(content);
+
+        let ranges = ArtifactGeneratorService::chunk_line_ranges(content, &chunks);
+
+        std::assert_eq!(ranges, std::vec![std::option::Option::Some((1, 1)), std::option::Option::Some((3, 5))]);
+    }
+
+    #[tokio::test]
+    async fn test_progress_events_cover_every_item_and_complete() {
+        // Test: Validates one progress event per processed file, ending with a completion event.
+        // Justification: The CLI progress bar is driven entirely by these events.
+        let files: std::vec::Vec<task_manager::domain::scan_config::ScannedFile> = (0..3)
+            .map(|i| task_manager::domain::scan_config::ScannedFile {
+                path: std::format!("src/file_{}.rs", i),
+                absolute_path: std::format!("/test/src/file_{}.rs", i),
+                content: String::from("fn f() {}"),
+                extension: String::from("rs"),
+                size_bytes: 9,
+                fingerprint: task_manager::domain::scan_config::FileFingerprint::new(String::from("h"), 0, 9),
+                line_count: 1,
+            })
+            .collect();
+        let scanner = std::sync::Arc::new(MockDirectoryScanner { files });
+        let crawler = std::sync::Arc::new(MockWebCrawler::new(std::vec::Vec::new()));
+        let embedding = std::sync::Arc::new(MockEmbeddingPort { dimension: 8 });
+        let repo = std::sync::Arc::new(std::sync::Mutex::new(MockArtifactRepository::new()));
+        let (tx, mut rx) = tokio::sync::mpsc::channel(16);
+        let service = ArtifactGeneratorService::new(scanner, crawler, embedding, repo)
+            .with_progress_sender(tx);
+        let config = GenerationConfig::new(String::from("project-123"));
+        let scan_config = task_manager::domain::scan_config::ScanConfig::new(String::from("/test"));
+
+        let report = service.generate_from_directory("/test", &config, &scan_config).await.unwrap();
+        drop(service);
+        let mut events = std::vec::Vec::new();
+        while let std::option::Option::Some(event) = rx.recv().await {
+            events.push(event);
+        }
+
+        std::assert_eq!(events.len(), report.files_scanned);
+        std::assert_eq!(events[0].processed, 1);
+        std::assert_eq!(events[0].current_item, "src/file_0.rs");
+        let last = events.last().unwrap();
+        std::assert!(last.is_complete());
+        std::assert_eq!(last.total, 3);
+        std::assert!(!events[1].is_complete());
+    }
+
+    #[tokio::test]
+    async fn test_progress_reports_fetched_pages_and_completes_empty_runs() {
+        // Test: Validates web sources report fetching then processing, and an empty directory still completes.
+        // Justification: The bar sat idle for the whole crawl and never finished for empty sources.
+        let page = crate::domain::crawl_result::CrawledPage {
+            url: String::from("https://example.com/"),
+            title: String::from("Home"),
+            content: String::from("Welcome to the docs"),
+            links: std::vec::Vec::new(),
+            depth: 0,
+            status_code: 200,
+            content_type: String::from("text/html"),
+            raw_body: std::option::Option::None,
+        };
+        let scanner = std::sync::Arc::new(MockDirectoryScanner { files: std::vec::Vec::new() });
+        let crawler = std::sync::Arc::new(MockWebCrawler::new(std::vec![page]));
+        let embedding = std::sync::Arc::new(MockEmbeddingPort { dimension: 8 });
+        let repo = std::sync::Arc::new(std::sync::Mutex::new(MockArtifactRepository::new()));
+        let (tx, mut rx) = tokio::sync::mpsc::channel(16);
+        let service = ArtifactGeneratorService::new(scanner, crawler, embedding, repo)
+            .with_progress_sender(tx);
+        let config = GenerationConfig::new(String::from("project-123"));
+        let crawl_config = crate::domain::crawl_result::CrawlConfig::new(String::from("https://example.com/"));
+        let scan_config = task_manager::domain::scan_config::ScanConfig::new(String::from("/empty"));
+
+        service.generate_from_url("https://example.com/", &config, &crawl_config).await.unwrap();
+        service.generate_from_directory("/empty", &config, &scan_config).await.unwrap();
+        drop(service);
+        let mut events = std::vec::Vec::new();
+        while let std::option::Option::Some(event) = rx.recv().await {
+            events.push(event);
+        }
+
+        std::assert_eq!(events.len(), 3);
+        std::assert_eq!(events[0].stage, GenerationStage::Fetching);
+        std::assert_eq!(events[0].current_item, "https://example.com/");
+        std::assert!(!events[0].is_complete());
+        std::assert_eq!(events[1].stage, GenerationStage::Processing);
+        std::assert!(events[1].is_complete());
+        std::assert_eq!((events[2].processed, events[2].total), (0, 0));
+        std::assert!(events[2].is_complete());
     }
 }
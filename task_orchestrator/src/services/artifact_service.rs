@@ -30,9 +30,218 @@
 //! ```
 //!
 //! Revision History
+//! - 2026-10-18T03:50:00Z @AI: Tests create the stand-in artifacts_vec table of their in-memory databases.
+//! - 2026-10-18T01:10:00Z @AI: Add search_with_outcome reporting a re-rank fallback to the caller.
+//! - 2026-10-17T23:20:00Z @AI: reembed re-saves each batch through replace_embeddings, one transaction per batch.
+//! - 2026-10-17T18:30:00Z @AI: search takes an ArtifactSearchFilter (project, source types, extensions, language) applied before ranking.
+//! - 2026-10-17T18:10:00Z @AI: Add optional LLM re-ranking of search hits (with_reranker) with fallback to vector order.
+//! - 2026-10-16T22:30:00Z @AI: Record the embedding model and dimension on ingest; search refuses query embeddings whose dimension differs from stored artifacts; add ArtifactService::reembed.
+//! - 2026-10-16T14:50:00Z @AI: Add ArtifactService::search returning similar artifacts with their citation metadata.
+//! - 2026-10-16T13:50:00Z @AI: Add delete_artifact and delete_artifacts_by_project with ArtifactService::delete/delete_by_project.
+//! - 2026-10-16T13:30:00Z @AI: Add paginated artifact listing (list_artifacts, ArtifactPage) with total count.
 //! - 2025-11-30T11:30:00Z @AI: Add missing binary_content fields for Phase 5 Artifact extension compatibility.
 //! - 2025-11-28T20:15:00Z @AI: Create ArtifactService for Phase 3 RAG implementation (Task 4.1).
 
+/// One page of an artifact listing with the total number of matches.
+///
+/// # Examples
+///
+/// ```
+/// # use task_orchestrator::services::artifact_service::ArtifactPage;
+/// let page = ArtifactPage { artifacts: std::vec::Vec::new(), total: 412, offset: 20 };
+/// std::assert!(page.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArtifactPage {
+    /// Artifacts on this page, newest first.
+    pub artifacts: std::vec::Vec<task_manager::domain::artifact::Artifact>,
+
+    /// Total number of artifacts matching the filter across all pages.
+    pub total: usize,
+
+    /// Zero-based position of the first artifact on this page.
+    pub offset: usize,
+}
+
+impl ArtifactPage {
+    /// Returns true if this page holds no artifacts.
+    pub fn is_empty(&self) -> bool {
+        self.artifacts.is_empty()
+    }
+}
+
+/// Lists one page of artifacts, newest first, with the total match count.
+///
+/// Ordering is by creation time descending with the artifact ID as a
+/// tie-breaker, so consecutive pages never overlap or skip artifacts. An
+/// offset past the last artifact yields an empty page.
+///
+/// # Arguments
+///
+/// * `repository` - Artifact repository to query
+/// * `filter` - Filter selecting which artifacts to list
+/// * `limit` - Maximum artifacts on the page
+/// * `offset` - Number of matching artifacts to skip
+///
+/// # Errors
+///
+/// Returns an error if the page or count query fails.
+pub fn list_artifacts<R>(
+    repository: &R,
+    filter: &task_manager::ports::artifact_repository_port::ArtifactFilter,
+    limit: usize,
+    offset: usize,
+) -> std::result::Result<ArtifactPage, std::string::String>
+where
+    R: task_manager::ports::artifact_repository_port::ArtifactRepositoryPort + ?std::marker::Sized,
+{
+    let options = hexser::ports::repository::FindOptions {
+        sort: std::option::Option::Some(std::vec![hexser::ports::repository::Sort {
+            key: task_manager::ports::artifact_repository_port::ArtifactSortKey::CreatedAt,
+            direction: hexser::ports::repository::Direction::Desc,
+        }]),
+        limit: std::option::Option::Some(limit as u32),
+        offset: std::option::Option::Some(offset as u64),
+    };
+
+    let artifacts = hexser::ports::repository::QueryRepository::find(repository, filter, options)
+        .map_err(|e| std::format!("Failed to query artifacts: {}", e))?;
+    let total = hexser::ports::repository::QueryRepository::count(repository, filter)
+        .map_err(|e| std::format!("Failed to count artifacts: {}", e))? as usize;
+
+    std::result::Result::Ok(ArtifactPage { artifacts, total, offset })
+}
+
+/// Deletes a single artifact and its embedding.
+///
+/// # Arguments
+///
+/// * `repository` - Artifact repository to delete from
+/// * `id` - ID of the artifact to delete
+///
+/// # Returns
+///
+/// True if the artifact existed and was deleted, false if no artifact had that ID.
+///
+/// # Errors
+///
+/// Returns an error if the delete fails.
+pub fn delete_artifact<R>(
+    repository: &mut R,
+    id: &str,
+) -> std::result::Result<bool, std::string::String>
+where
+    R: task_manager::ports::artifact_repository_port::ArtifactRepositoryPort + ?std::marker::Sized,
+{
+    let filter = task_manager::ports::artifact_repository_port::ArtifactFilter::ById(std::string::String::from(id));
+    let deleted = hexser::ports::repository::QueryRepository::delete_where(repository, &filter)
+        .map_err(|e| std::format!("Failed to delete artifact {}: {}", id, e))?;
+    std::result::Result::Ok(deleted > 0)
+}
+
+/// Deletes every artifact belonging to a project, along with their embeddings.
+///
+/// Artifacts of other projects are left untouched.
+///
+/// # Arguments
+///
+/// * `repository` - Artifact repository to delete from
+/// * `project_id` - Project whose artifacts are purged
+///
+/// # Returns
+///
+/// The number of artifacts deleted.
+///
+/// # Errors
+///
+/// Returns an error if the delete fails.
+pub fn delete_artifacts_by_project<R>(
+    repository: &mut R,
+    project_id: &str,
+) -> std::result::Result<usize, std::string::String>
+where
+    R: task_manager::ports::artifact_repository_port::ArtifactRepositoryPort + ?std::marker::Sized,
+{
+    let filter = task_manager::ports::artifact_repository_port::ArtifactFilter::ByProjectId(std::string::String::from(project_id));
+    let deleted = hexser::ports::repository::QueryRepository::delete_where(repository, &filter)
+        .map_err(|e| std::format!("Failed to purge artifacts for project {}: {}", project_id, e))?;
+    std::result::Result::Ok(deleted as usize)
+}
+
+/// Checks that a query embedding can be compared with stored artifacts.
+///
+/// Cosine distance between vectors of different dimensions is meaningless,
+/// so a search must be refused if any stored artifact was embedded with a
+/// different dimension than the query.
+///
+/// # Arguments
+///
+/// * `spaces` - Embedding models and dimensions of the stored artifacts
+/// * `query_model` - Model that embedded the query, if known
+/// * `query_dimension` - Dimension of the query embedding
+///
+/// # Errors
+///
+/// Returns an error naming the mismatched model and dimension and
+/// suggesting re-ingestion or `rig artifacts reembed`.
+///
+/// # Examples
+///
+/// ```
+/// # use task_orchestrator::services::artifact_service::check_embedding_dimension;
+/// # use task_manager::ports::artifact_repository_port::EmbeddingSpace;
+/// let stored = std::vec![EmbeddingSpace {
+///     model: std::option::Option::Some(std::string::String::from("nomic-embed-text")),
+///     dimension: 768,
+///     artifacts: 10,
+/// }];
+/// std::assert!(check_embedding_dimension(&stored, std::option::Option::None, 768).is_ok());
+/// std::assert!(check_embedding_dimension(&stored, std::option::Option::Some("text-embedding-3-small"), 1536).is_err());
+/// ```
+pub fn check_embedding_dimension(
+    spaces: &[task_manager::ports::artifact_repository_port::EmbeddingSpace],
+    query_model: std::option::Option<&str>,
+    query_dimension: usize,
+) -> std::result::Result<(), std::string::String> {
+    let mismatched = match spaces.iter().find(|space| space.dimension != query_dimension) {
+        std::option::Option::Some(space) => space,
+        std::option::Option::None => return std::result::Result::Ok(()),
+    };
+    let query_model = query_model.unwrap_or("the current embedding model");
+    std::result::Result::Err(std::format!(
+        "Embedding dimension mismatch: the query was embedded by {} with {} dimensions, but {} stored artifacts were embedded by {} with {} dimensions. \
+         Re-ingest them, or run `rig artifacts reembed --model <model>` to regenerate all embeddings with one model.",
+        query_model,
+        query_dimension,
+        mismatched.artifacts,
+        mismatched.model_name(),
+        mismatched.dimension
+    ))
+}
+
+/// Hits of an artifact search and whether re-ranking fell back to vector order.
+#[derive(Debug, Clone)]
+pub struct SearchOutcome {
+    /// Matching artifacts, best first.
+    pub hits: std::vec::Vec<task_manager::ports::artifact_repository_port::SimilarArtifact>,
+
+    /// Why the re-ranker's order was not used, if re-ranking was configured and failed.
+    pub rerank_fallback: std::option::Option<String>,
+}
+
+/// Result of re-embedding the knowledge base under a new model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReembedSummary {
+    /// Number of artifacts whose embeddings were regenerated.
+    pub artifacts: usize,
+
+    /// Model that produced the new embeddings, if the adapter reports it.
+    pub model: std::option::Option<String>,
+
+    /// Dimension of the new embeddings.
+    pub dimension: usize,
+}
+
 /// Service for ingesting and managing artifacts in the RAG knowledge base.
 ///
 /// ArtifactService coordinates the complex workflow of:
@@ -49,8 +258,13 @@
 pub struct ArtifactService {
     artifact_repository: std::sync::Arc<std::sync::Mutex<dyn task_manager::ports::artifact_repository_port::ArtifactRepositoryPort + std::marker::Send>>,
     embedding_port: std::sync::Arc<dyn crate::ports::embedding_port::EmbeddingPort + std::marker::Send + std::marker::Sync>,
+    reranker: std::option::Option<std::sync::Arc<dyn crate::ports::rerank_port::RerankPort + std::marker::Send + std::marker::Sync>>,
+    rerank_candidates: usize,
 }
 
+/// Default number of vector hits handed to the re-ranker.
+pub const DEFAULT_RERANK_CANDIDATES: usize = 20;
+
 impl ArtifactService {
     /// Creates a new ArtifactService with the given repository and embedding port.
     ///
@@ -78,9 +292,31 @@ impl ArtifactService {
         Self {
             artifact_repository,
             embedding_port,
+            reranker: std::option::Option::None,
+            rerank_candidates: DEFAULT_RERANK_CANDIDATES,
         }
     }
 
+    /// Re-ranks search hits with `reranker`.
+    ///
+    /// `search` then fetches the top `candidates` vector hits (at least its
+    /// `limit`), has the re-ranker score them against the query, and returns
+    /// the best `limit` by score. Each search costs one re-rank call.
+    ///
+    /// # Arguments
+    ///
+    /// * `reranker` - Scores passages against the query
+    /// * `candidates` - Number of vector hits to re-rank
+    pub fn with_reranker(
+        mut self,
+        reranker: std::sync::Arc<dyn crate::ports::rerank_port::RerankPort + std::marker::Send + std::marker::Sync>,
+        candidates: usize,
+    ) -> Self {
+        self.reranker = std::option::Option::Some(reranker);
+        self.rerank_candidates = candidates;
+        self
+    }
+
     /// Ingests a PRD document by chunking, embedding, and storing artifacts.
     ///
     /// This method orchestrates the full artifact ingestion pipeline:
@@ -150,6 +386,7 @@ impl ArtifactService {
         }
 
         // 3. Create Artifact entities for each chunk
+        let embedding_model = self.embedding_port.embedding_model();
         let mut artifacts = std::vec::Vec::new();
         for (i, (chunk, embedding)) in chunks.into_iter().zip(embeddings.into_iter()).enumerate() {
             let artifact = task_manager::domain::artifact::Artifact {
@@ -158,6 +395,7 @@ impl ArtifactService {
                 source_id: prd_id.clone(),
                 source_type: task_manager::domain::artifact::ArtifactType::PRD,
                 content: chunk,
+                embedding_dimension: std::option::Option::Some(embedding.len()),
                 embedding,
                 metadata: std::option::Option::Some(std::format!("{{\"chunk_index\": {}}}", i)),
                 created_at: chrono::Utc::now(),
@@ -165,6 +403,7 @@ impl ArtifactService {
                 mime_type: std::option::Option::None,
                 source_url: std::option::Option::None,
                 page_number: std::option::Option::None,
+                embedding_model: embedding_model.clone(),
             };
             artifacts.push(artifact);
         }
@@ -181,6 +420,234 @@ impl ArtifactService {
         std::result::Result::Ok(artifacts)
     }
 
+    /// Lists one page of artifacts with the total match count.
+    ///
+    /// See `list_artifacts` for ordering and paging semantics.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Filter selecting which artifacts to list
+    /// * `limit` - Maximum artifacts on the page
+    /// * `offset` - Number of matching artifacts to skip
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository lock or queries fail.
+    pub fn list(
+        &self,
+        filter: &task_manager::ports::artifact_repository_port::ArtifactFilter,
+        limit: usize,
+        offset: usize,
+    ) -> std::result::Result<ArtifactPage, std::string::String> {
+        let repo = self.artifact_repository.lock()
+            .map_err(|e| std::format!("Failed to acquire repository lock: {}", e))?;
+        list_artifacts(&*repo, filter, limit, offset)
+    }
+
+    /// Searches the knowledge base for artifacts similar to a query.
+    ///
+    /// Each result's artifact keeps its source metadata, so
+    /// `Artifact::citation()` yields a path + line range or URL + page to
+    /// attribute the content.
+    ///
+    /// With a re-ranker (`with_reranker`), the top vector hits are re-ordered
+    /// by the re-ranker's scores; ties keep their vector order. If the
+    /// re-rank call fails, the vector order is used and a warning is logged;
+    /// `search_with_outcome` also returns the reason to the caller.
+    ///
+    /// Only artifacts matching `filter` are ranked, so a narrow filter still
+    /// returns up to `limit` results.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Natural language search query
+    /// * `limit` - Maximum number of results
+    /// * `threshold` - Optional maximum distance for a match
+    /// * `filter` - Project, source type, extension, and language filters (all must match)
+    ///
+    /// # Returns
+    ///
+    /// Matching artifacts ordered by similarity (or re-rank score), best first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if embedding the query or the repository search
+    /// fails, or if stored artifacts in scope have a different embedding
+    /// dimension than the query (see `check_embedding_dimension`).
+    pub async fn search(
+        &self,
+        query: &str,
+        limit: usize,
+        threshold: std::option::Option<f32>,
+        filter: &task_manager::ports::artifact_repository_port::ArtifactSearchFilter,
+    ) -> std::result::Result<std::vec::Vec<task_manager::ports::artifact_repository_port::SimilarArtifact>, String> {
+        self.search_with_outcome(query, limit, threshold, filter).await.map(|outcome| outcome.hits)
+    }
+
+    /// Same as `search`, but reports a re-rank fallback so the caller can
+    /// tell the user the results are in vector order.
+    ///
+    /// # Errors
+    ///
+    /// Same as `search`.
+    pub async fn search_with_outcome(
+        &self,
+        query: &str,
+        limit: usize,
+        threshold: std::option::Option<f32>,
+        filter: &task_manager::ports::artifact_repository_port::ArtifactSearchFilter,
+    ) -> std::result::Result<SearchOutcome, String> {
+        let query_embedding = self.embedding_port
+            .generate_embedding(query)
+            .await
+            .map_err(|e| std::format!("Failed to generate query embedding: {}", e))?;
+
+        let reranker = self.reranker.as_ref().filter(|_| limit > 0);
+        let fetch = match reranker {
+            std::option::Option::Some(_) => self.rerank_candidates.max(limit),
+            std::option::Option::None => limit,
+        };
+
+        let hits = {
+            let repo = self.artifact_repository.lock()
+                .map_err(|e| std::format!("Failed to acquire repository lock: {}", e))?;
+            let spaces = repo.embedding_spaces(filter.project_id.clone())?;
+            check_embedding_dimension(&spaces, self.embedding_port.embedding_model().as_deref(), query_embedding.len())?;
+            repo.find_similar_filtered(&query_embedding, fetch, threshold, filter)?
+        };
+
+        match reranker {
+            std::option::Option::Some(reranker) => std::result::Result::Ok(rerank_hits(reranker.as_ref(), query, hits, limit).await),
+            std::option::Option::None => std::result::Result::Ok(SearchOutcome {
+                hits,
+                rerank_fallback: std::option::Option::None,
+            }),
+        }
+    }
+
+    /// Regenerates the embedding of every stored artifact with this
+    /// service's embedding port.
+    ///
+    /// All embeddings are generated before anything is written, so a failing
+    /// provider leaves the existing index untouched. The repository's vector
+    /// index is then reset to the new dimension and the artifacts are saved
+    /// with their new embedding, model, and dimension one batch at a time;
+    /// each batch's delete and re-insert happen in one transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `batch_size` - Number of artifacts embedded per provider call
+    ///
+    /// # Returns
+    ///
+    /// The number of artifacts re-embedded with the new model and dimension.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Embedding generation fails or returns the wrong number of vectors
+    /// - The model returns vectors of differing dimensions or only zero
+    ///   vectors (the adapter's fallback when the model is unavailable)
+    /// - Resetting the index or saving a batch fails (that batch is rolled back)
+    pub async fn reembed(&self, batch_size: usize) -> std::result::Result<ReembedSummary, std::string::String> {
+        let mut artifacts = {
+            let repo = self.artifact_repository.lock()
+                .map_err(|e| std::format!("Failed to acquire repository lock: {}", e))?;
+            hexser::ports::repository::QueryRepository::find(
+                &*repo,
+                &task_manager::ports::artifact_repository_port::ArtifactFilter::All,
+                hexser::ports::repository::FindOptions::default(),
+            )
+            .map_err(|e| std::format!("Failed to load artifacts: {}", e))?
+        };
+        let model = self.embedding_port.embedding_model();
+
+        let mut embeddings = std::vec::Vec::with_capacity(artifacts.len());
+        for batch in artifacts.chunks(batch_size.max(1)) {
+            let texts: std::vec::Vec<&str> = batch.iter().map(|artifact| artifact.content.as_str()).collect();
+            let batch_embeddings = self.embedding_port.generate_embeddings(&texts).await?;
+            if batch_embeddings.len() != batch.len() {
+                return std::result::Result::Err(std::format!(
+                    "Embedding count mismatch: expected {}, got {}",
+                    batch.len(),
+                    batch_embeddings.len()
+                ));
+            }
+            embeddings.extend(batch_embeddings);
+        }
+
+        let dimension = match embeddings.first() {
+            std::option::Option::Some(embedding) => embedding.len(),
+            std::option::Option::None => self.embedding_port.embedding_dimension().await,
+        };
+        if embeddings.iter().any(|embedding| embedding.len() != dimension) {
+            return std::result::Result::Err(String::from("Embedding model returned vectors of differing dimensions"));
+        }
+        if !embeddings.is_empty() && embeddings.iter().flatten().all(|value| *value == 0.0) {
+            return std::result::Result::Err(std::format!(
+                "Embedding model {} returned only zero vectors; check that it is available. Existing embeddings were kept.",
+                model.as_deref().unwrap_or("(unknown)")
+            ));
+        }
+
+        for (artifact, embedding) in artifacts.iter_mut().zip(embeddings) {
+            artifact.embedding = embedding;
+            artifact.embedding_dimension = std::option::Option::Some(dimension);
+            artifact.embedding_model = model.clone();
+        }
+
+        let mut repo = self.artifact_repository.lock()
+            .map_err(|e| std::format!("Failed to acquire repository lock: {}", e))?;
+        repo.reset_embedding_index(dimension)?;
+        for batch in artifacts.chunks(batch_size.max(1)) {
+            repo.replace_embeddings(batch.to_vec())?;
+        }
+
+        std::result::Result::Ok(ReembedSummary {
+            artifacts: artifacts.len(),
+            model,
+            dimension,
+        })
+    }
+
+    /// Deletes a single artifact and its embedding.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - ID of the artifact to delete
+    ///
+    /// # Returns
+    ///
+    /// True if the artifact existed and was deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository lock or delete fails.
+    pub fn delete(&self, id: &str) -> std::result::Result<bool, std::string::String> {
+        let mut repo = self.artifact_repository.lock()
+            .map_err(|e| std::format!("Failed to acquire repository lock: {}", e))?;
+        delete_artifact(&mut *repo, id)
+    }
+
+    /// Deletes every artifact belonging to a project.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - Project whose artifacts are purged
+    ///
+    /// # Returns
+    ///
+    /// The number of artifacts deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository lock or delete fails.
+    pub fn delete_by_project(&self, project_id: &str) -> std::result::Result<usize, std::string::String> {
+        let mut repo = self.artifact_repository.lock()
+            .map_err(|e| std::format!("Failed to acquire repository lock: {}", e))?;
+        delete_artifacts_by_project(&mut *repo, project_id)
+    }
+
     /// Chunks text into semantic units using paragraph boundaries.
     ///
     /// This private method implements the chunking strategy by splitting on
@@ -217,10 +684,61 @@ impl ArtifactService {
     }
 }
 
+/// Re-orders `hits` by the re-ranker's scores and keeps the best `limit`.
+///
+/// Falls back to the vector order (truncated to `limit`) if scoring fails,
+/// returning the reason as `rerank_fallback`.
+async fn rerank_hits(
+    reranker: &(dyn crate::ports::rerank_port::RerankPort + std::marker::Send + std::marker::Sync),
+    query: &str,
+    mut hits: std::vec::Vec<task_manager::ports::artifact_repository_port::SimilarArtifact>,
+    limit: usize,
+) -> SearchOutcome {
+    let mut rerank_fallback = std::option::Option::None;
+    if hits.len() > 1 {
+        let passages: std::vec::Vec<&str> = hits.iter().map(|hit| hit.artifact.content.as_str()).collect();
+        match reranker.score(query, &passages).await {
+            std::result::Result::Ok(scores) if scores.len() == hits.len() => {
+                let mut scored: std::vec::Vec<(f32, task_manager::ports::artifact_repository_port::SimilarArtifact)> =
+                    scores.into_iter().zip(hits).collect();
+                // Stable sort: equally scored hits keep their vector order
+                scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+                hits = scored.into_iter().map(|(_, hit)| hit).collect();
+            }
+            std::result::Result::Ok(scores) => {
+                tracing::warn!(scores = scores.len(), hits = hits.len(), "re-ranker returned the wrong number of scores; keeping vector order");
+                rerank_fallback = std::option::Option::Some(std::format!(
+                    "the re-ranker returned {} scores for {} hits",
+                    scores.len(),
+                    hits.len()
+                ));
+            }
+            std::result::Result::Err(e) => {
+                tracing::warn!(error = %e, "re-ranking failed; keeping vector order");
+                rerank_fallback = std::option::Option::Some(e);
+            }
+        }
+    }
+    hits.truncate(limit);
+    SearchOutcome { hits, rerank_fallback }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Connects to an in-memory database with a plain `artifacts_vec` table standing in for sqlite-vec.
+    async fn in_memory_adapter() -> task_manager::adapters::sqlite_artifact_adapter::SqliteArtifactAdapter {
+        let adapter = task_manager::adapters::sqlite_artifact_adapter::SqliteArtifactAdapter::connect_and_init("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE IF NOT EXISTS artifacts_vec (artifact_id TEXT PRIMARY KEY, embedding TEXT)")
+            .execute(adapter.pool())
+            .await
+            .unwrap();
+        adapter
+    }
+
     /// Mock artifact repository for testing.
     struct MockArtifactRepository;
 
@@ -255,6 +773,48 @@ mod tests {
         }
     }
 
+    /// Mock repository whose similarity search returns its stored artifacts in order.
+    struct MockSearchRepository {
+        artifacts: std::vec::Vec<task_manager::domain::artifact::Artifact>,
+    }
+
+    impl hexser::ports::Repository<task_manager::domain::artifact::Artifact> for MockSearchRepository {
+        fn save(&mut self, entity: task_manager::domain::artifact::Artifact) -> hexser::HexResult<()> {
+            self.artifacts.push(entity);
+            std::result::Result::Ok(())
+        }
+    }
+
+    impl hexser::ports::repository::QueryRepository<task_manager::domain::artifact::Artifact> for MockSearchRepository {
+        type Filter = task_manager::ports::artifact_repository_port::ArtifactFilter;
+        type SortKey = task_manager::ports::artifact_repository_port::ArtifactSortKey;
+
+        fn find_one(&self, _filter: &Self::Filter) -> hexser::HexResult<std::option::Option<task_manager::domain::artifact::Artifact>> {
+            std::result::Result::Ok(std::option::Option::None)
+        }
+
+        fn find(&self, _filter: &Self::Filter, _options: hexser::ports::repository::FindOptions<Self::SortKey>) -> hexser::HexResult<std::vec::Vec<task_manager::domain::artifact::Artifact>> {
+            std::result::Result::Ok(self.artifacts.clone())
+        }
+    }
+
+    impl task_manager::ports::artifact_repository_port::ArtifactRepositoryPort for MockSearchRepository {
+        fn find_similar(
+            &self,
+            _query_embedding: &[f32],
+            limit: usize,
+            _threshold: std::option::Option<f32>,
+            _project_id: std::option::Option<String>,
+        ) -> std::result::Result<std::vec::Vec<task_manager::ports::artifact_repository_port::SimilarArtifact>, String> {
+            std::result::Result::Ok(self.artifacts.iter().take(limit).map(|artifact| {
+                task_manager::ports::artifact_repository_port::SimilarArtifact {
+                    artifact: artifact.clone(),
+                    distance: 0.1,
+                }
+            }).collect())
+        }
+    }
+
     /// Mock embedding port for testing.
     struct MockEmbeddingPort {
         dimension: usize,
@@ -273,6 +833,10 @@ mod tests {
         async fn embedding_dimension(&self) -> usize {
             self.dimension
         }
+
+        fn embedding_model(&self) -> std::option::Option<String> {
+            std::option::Option::Some(std::format!("mock-{}", self.dimension))
+        }
     }
 
     #[tokio::test]
@@ -378,4 +942,359 @@ mod tests {
         std::assert!(artifacts[0].metadata.as_ref().unwrap().contains("\"chunk_index\": 0"));
         std::assert!(artifacts[1].metadata.as_ref().unwrap().contains("\"chunk_index\": 1"));
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_list_pages_are_stable_and_counted() {
+        // Test: Validates pages cover all artifacts without duplicates and report the total.
+        // Justification: The CLI shows "showing X–Y of N" and pages through large knowledge bases.
+        let adapter = in_memory_adapter().await;
+        for i in 0..5 {
+            let artifact = task_manager::domain::artifact::Artifact::new(
+                String::from("proj-1"),
+                std::format!("src-{}", i),
+                task_manager::domain::artifact::ArtifactType::File,
+                std::format!("Chunk {}", i),
+                std::vec![0.1],
+                std::option::Option::None,
+            );
+            adapter.save_async(artifact).await.unwrap();
+        }
+        let service = ArtifactService::new(
+            std::sync::Arc::new(std::sync::Mutex::new(adapter)),
+            std::sync::Arc::new(MockEmbeddingPort { dimension: 1 }),
+        );
+        let filter = task_manager::ports::artifact_repository_port::ArtifactFilter::All;
+
+        let first = service.list(&filter, 2, 0).unwrap();
+        let second = service.list(&filter, 2, 2).unwrap();
+        let third = service.list(&filter, 2, 4).unwrap();
+
+        let mut ids: std::vec::Vec<String> = first.artifacts.iter()
+            .chain(second.artifacts.iter())
+            .chain(third.artifacts.iter())
+            .map(|a| a.id.clone())
+            .collect();
+        std::assert_eq!(ids.len(), 5);
+        ids.sort();
+        ids.dedup();
+        std::assert_eq!(ids.len(), 5);
+        std::assert_eq!(first.total, 5);
+        std::assert_eq!(third.artifacts.len(), 1);
+        std::assert_eq!(third.offset, 4);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_list_offset_beyond_end_is_empty() {
+        // Test: Validates an offset past the end returns an empty page rather than an error.
+        // Justification: Users may request a page beyond the last one.
+        let adapter = in_memory_adapter().await;
+        let service = ArtifactService::new(
+            std::sync::Arc::new(std::sync::Mutex::new(adapter)),
+            std::sync::Arc::new(MockEmbeddingPort { dimension: 1 }),
+        );
+
+        let page = service
+            .list(&task_manager::ports::artifact_repository_port::ArtifactFilter::All, 20, 400)
+            .unwrap();
+
+        std::assert!(page.is_empty());
+        std::assert_eq!(page.total, 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_delete_removes_artifact() {
+        // Test: Validates delete removes the artifact and reports whether it existed.
+        // Justification: `rig artifacts delete` must drop stale knowledge from retrieval.
+        let adapter = in_memory_adapter().await;
+        let artifact = task_manager::domain::artifact::Artifact::new(
+            String::from("proj-1"),
+            String::from("src-1"),
+            task_manager::domain::artifact::ArtifactType::File,
+            String::from("Outdated"),
+            std::vec![0.1],
+            std::option::Option::None,
+        );
+        let id = artifact.id.clone();
+        adapter.save_async(artifact).await.unwrap();
+        let service = ArtifactService::new(
+            std::sync::Arc::new(std::sync::Mutex::new(adapter)),
+            std::sync::Arc::new(MockEmbeddingPort { dimension: 1 }),
+        );
+
+        std::assert!(service.delete(&id).unwrap());
+        std::assert!(!service.delete(&id).unwrap());
+        let page = service
+            .list(&task_manager::ports::artifact_repository_port::ArtifactFilter::All, 10, 0)
+            .unwrap();
+        std::assert_eq!(page.total, 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_delete_by_project_is_scoped() {
+        // Test: Validates purging one project leaves other projects' artifacts in place.
+        // Justification: Purge must never delete knowledge from unrelated projects.
+        let adapter = in_memory_adapter().await;
+        for project in ["proj-a", "proj-a", "proj-b"] {
+            let artifact = task_manager::domain::artifact::Artifact::new(
+                String::from(project),
+                String::from("src"),
+                task_manager::domain::artifact::ArtifactType::PRD,
+                String::from("Content"),
+                std::vec![0.1],
+                std::option::Option::None,
+            );
+            adapter.save_async(artifact).await.unwrap();
+        }
+        let service = ArtifactService::new(
+            std::sync::Arc::new(std::sync::Mutex::new(adapter)),
+            std::sync::Arc::new(MockEmbeddingPort { dimension: 1 }),
+        );
+
+        let deleted = service.delete_by_project("proj-a").unwrap();
+        let remaining = service
+            .list(&task_manager::ports::artifact_repository_port::ArtifactFilter::All, 10, 0)
+            .unwrap();
+
+        std::assert_eq!(deleted, 2);
+        std::assert_eq!(remaining.total, 1);
+        std::assert_eq!(remaining.artifacts[0].project_id, "proj-b");
+    }
+
+    #[tokio::test]
+    async fn test_search_returns_source_path_for_citation() {
+        // Test: Validates search results round-trip the artifact's source path and line range.
+        // Justification: Agents cite search results, so the source must survive retrieval.
+        let artifact = task_manager::domain::artifact::Artifact::new(
+            String::from("proj-1"),
+            String::from("src/auth.rs"),
+            task_manager::domain::artifact::ArtifactType::File,
+            String::from("fn login() {}"),
+            std::vec![0.1],
+            std::option::Option::Some(String::from(r#"{"source_path": "src/auth.rs", "line_start": 3, "line_end": 7}"#)),
+        );
+        let service = ArtifactService::new(
+            std::sync::Arc::new(std::sync::Mutex::new(MockSearchRepository { artifacts: std::vec![artifact] })),
+            std::sync::Arc::new(MockEmbeddingPort { dimension: 1 }),
+        );
+
+        let results = service.search("login", 5, std::option::Option::None, &task_manager::ports::artifact_repository_port::ArtifactSearchFilter::default()).await.unwrap();
+
+        std::assert_eq!(results.len(), 1);
+        std::assert_eq!(results[0].artifact.source_id, "src/auth.rs");
+        std::assert_eq!(results[0].artifact.citation(), "src/auth.rs:3-7");
+    }
+
+    /// Builds a search service over a mixed knowledge base, in vector order:
+    /// a web page, a Rust file, a PRD, a doc in another project, and a doc.
+    fn mixed_search_service() -> ArtifactService {
+        let artifact = |project: &str, source: &str, source_type: task_manager::domain::artifact::ArtifactType| {
+            task_manager::domain::artifact::Artifact::new(
+                String::from(project),
+                String::from(source),
+                source_type,
+                std::format!("About {}", source),
+                std::vec![0.1],
+                std::option::Option::None,
+            )
+        };
+        let artifacts = std::vec![
+            artifact("proj-1", "https://example.com/auth", task_manager::domain::artifact::ArtifactType::WebResearch),
+            artifact("proj-1", "src/auth.rs", task_manager::domain::artifact::ArtifactType::File),
+            artifact("proj-1", "prd-1", task_manager::domain::artifact::ArtifactType::PRD),
+            artifact("proj-2", "docs/other.md", task_manager::domain::artifact::ArtifactType::File),
+            artifact("proj-1", "docs/AUTH.MD", task_manager::domain::artifact::ArtifactType::File),
+        ];
+        ArtifactService::new(
+            std::sync::Arc::new(std::sync::Mutex::new(MockSearchRepository { artifacts })),
+            std::sync::Arc::new(MockEmbeddingPort { dimension: 1 }),
+        )
+    }
+
+    fn sources(results: &[task_manager::ports::artifact_repository_port::SimilarArtifact]) -> std::vec::Vec<&str> {
+        results.iter().map(|result| result.artifact.source_id.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_search_by_source_type_excludes_other_types_before_limit() {
+        // Test: Validates a source type filter drops non-matching artifacts and the limit counts only matching ones.
+        // Justification: Filtering after the top-K cut would return too few, or no, docs when code and web pages rank higher.
+        let service = mixed_search_service();
+        let files = task_manager::ports::artifact_repository_port::ArtifactSearchFilter::default()
+            .with_source_type(task_manager::domain::artifact::ArtifactType::File);
+
+        let results = service.search("auth", 10, std::option::Option::None, &files).await.unwrap();
+        let top_two = service.search("auth", 2, std::option::Option::None, &files).await.unwrap();
+
+        std::assert_eq!(sources(&results), ["src/auth.rs", "docs/other.md", "docs/AUTH.MD"]);
+        std::assert_eq!(sources(&top_two), ["src/auth.rs", "docs/other.md"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_filters_combine_with_and() {
+        // Test: Validates project, source type, extension, and language filters must all match.
+        // Justification: "Only docs of this project" is a combination of filters; any one alone returns too much.
+        let service = mixed_search_service();
+        let project_docs = task_manager::ports::artifact_repository_port::ArtifactSearchFilter::default()
+            .with_project(std::option::Option::Some(String::from("proj-1")))
+            .with_source_type(task_manager::domain::artifact::ArtifactType::File)
+            .with_extension(".md");
+        let rust_docs = task_manager::ports::artifact_repository_port::ArtifactSearchFilter::default()
+            .with_extension("md")
+            .with_language("rust");
+        let rust_files = task_manager::ports::artifact_repository_port::ArtifactSearchFilter::default()
+            .with_source_type(task_manager::domain::artifact::ArtifactType::File)
+            .with_language("Rust");
+
+        let docs = service.search("auth", 10, std::option::Option::None, &project_docs).await.unwrap();
+        let none = service.search("auth", 10, std::option::Option::None, &rust_docs).await.unwrap();
+        let code = service.search("auth", 10, std::option::Option::None, &rust_files).await.unwrap();
+
+        std::assert_eq!(sources(&docs), ["docs/AUTH.MD"]);
+        std::assert!(none.is_empty());
+        std::assert_eq!(sources(&code), ["src/auth.rs"]);
+    }
+
+    /// Re-ranker that scores each passage from a fixed table, or fails.
+    struct MockReranker {
+        scores: std::collections::HashMap<&'static str, f32>,
+        fail: bool,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::ports::rerank_port::RerankPort for MockReranker {
+        async fn score(&self, _query: &str, passages: &[&str]) -> std::result::Result<std::vec::Vec<f32>, String> {
+            if self.fail {
+                return std::result::Result::Err(String::from("model unavailable"));
+            }
+            std::result::Result::Ok(passages.iter().map(|passage| self.scores.get(passage).copied().unwrap_or(0.0)).collect())
+        }
+    }
+
+    /// Builds a search service over four artifacts whose vector order is "a", "b", "c", "d".
+    fn rerank_service(reranker: MockReranker) -> ArtifactService {
+        let artifacts = ["a", "b", "c", "d"]
+            .iter()
+            .map(|content| {
+                task_manager::domain::artifact::Artifact::new(
+                    String::from("proj-1"),
+                    String::from("doc.md"),
+                    task_manager::domain::artifact::ArtifactType::File,
+                    String::from(*content),
+                    std::vec![0.1],
+                    std::option::Option::None,
+                )
+            })
+            .collect();
+        ArtifactService::new(
+            std::sync::Arc::new(std::sync::Mutex::new(MockSearchRepository { artifacts })),
+            std::sync::Arc::new(MockEmbeddingPort { dimension: 1 }),
+        )
+        .with_reranker(std::sync::Arc::new(reranker), 4)
+    }
+
+    #[tokio::test]
+    async fn test_search_reorders_candidates_by_rerank_scores() {
+        // Test: Validates the re-ranker sees all candidates, the final order follows its scores, and only the top K are returned.
+        // Justification: The point of re-ranking is to lift a relevant chunk that vector search placed below the cut-off.
+        let service = rerank_service(MockReranker {
+            scores: std::collections::HashMap::from([("a", 2.0), ("b", 5.0), ("c", 1.0), ("d", 9.0)]),
+            fail: false,
+        });
+
+        let outcome = service
+            .search_with_outcome("query", 2, std::option::Option::None, &task_manager::ports::artifact_repository_port::ArtifactSearchFilter::default())
+            .await
+            .unwrap();
+
+        let order: std::vec::Vec<&str> = outcome.hits.iter().map(|hit| hit.artifact.content.as_str()).collect();
+        std::assert_eq!(order, ["d", "b"]);
+        std::assert!(outcome.rerank_fallback.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_search_falls_back_to_vector_order_when_rerank_fails() {
+        // Test: Validates a failing re-ranker leaves the vector order, cut to the requested limit.
+        // Justification: Re-ranking is an optional refinement; a provider outage must not break search.
+        let service = rerank_service(MockReranker {
+            scores: std::collections::HashMap::new(),
+            fail: true,
+        });
+
+        let outcome = service
+            .search_with_outcome("query", 3, std::option::Option::None, &task_manager::ports::artifact_repository_port::ArtifactSearchFilter::default())
+            .await
+            .unwrap();
+
+        let order: std::vec::Vec<&str> = outcome.hits.iter().map(|hit| hit.artifact.content.as_str()).collect();
+        std::assert_eq!(order, ["a", "b", "c"]);
+        std::assert_eq!(outcome.rerank_fallback.as_deref(), std::option::Option::Some("model unavailable"));
+    }
+
+    /// Saves 3-dimensional artifacts embedded by "mock-3" into an in-memory repository.
+    async fn three_dimensional_repository(count: usize) -> std::sync::Arc<std::sync::Mutex<task_manager::adapters::sqlite_artifact_adapter::SqliteArtifactAdapter>> {
+        let adapter = in_memory_adapter().await;
+        for i in 0..count {
+            let artifact = task_manager::domain::artifact::Artifact::new(
+                String::from("proj-1"),
+                std::format!("src-{}", i),
+                task_manager::domain::artifact::ArtifactType::File,
+                std::format!("Chunk {}", i),
+                std::vec![0.1, 0.2, 0.3],
+                std::option::Option::None,
+            )
+            .with_embedding_model(std::option::Option::Some(String::from("mock-3")));
+            adapter.save_async(artifact).await.unwrap();
+        }
+        std::sync::Arc::new(std::sync::Mutex::new(adapter))
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_search_refuses_mismatched_embedding_dimension() {
+        // Test: Validates search fails with a re-ingestion hint when the query embedding's dimension differs from stored artifacts.
+        // Justification: Comparing vectors of different models silently returns meaningless matches.
+        let repository = three_dimensional_repository(2).await;
+        let service = ArtifactService::new(repository, std::sync::Arc::new(MockEmbeddingPort { dimension: 5 }));
+
+        let error = service
+            .search("login", 5, std::option::Option::None, &task_manager::ports::artifact_repository_port::ArtifactSearchFilter::default())
+            .await
+            .unwrap_err();
+
+        std::assert!(error.contains("Embedding dimension mismatch"), "{}", error);
+        std::assert!(error.contains("mock-5 with 5 dimensions"), "{}", error);
+        std::assert!(error.contains("2 stored artifacts were embedded by mock-3 with 3 dimensions"), "{}", error);
+        std::assert!(error.contains("rig artifacts reembed"), "{}", error);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_reembed_updates_stored_model_and_dimension() {
+        // Test: Validates reembed regenerates every embedding and records the new model and dimension.
+        // Justification: After switching embedding models, reembed is the way back to a searchable knowledge base.
+        let repository = three_dimensional_repository(3).await;
+        let service = ArtifactService::new(repository.clone(), std::sync::Arc::new(MockEmbeddingPort { dimension: 5 }));
+
+        let summary = service.reembed(2).await.unwrap();
+
+        std::assert_eq!(summary, ReembedSummary {
+            artifacts: 3,
+            model: std::option::Option::Some(String::from("mock-5")),
+            dimension: 5,
+        });
+        let repo = repository.lock().unwrap();
+        std::assert_eq!(
+            task_manager::ports::artifact_repository_port::ArtifactRepositoryPort::embedding_spaces(&*repo, std::option::Option::None).unwrap(),
+            std::vec![task_manager::ports::artifact_repository_port::EmbeddingSpace {
+                model: std::option::Option::Some(String::from("mock-5")),
+                dimension: 5,
+                artifacts: 3,
+            }]
+        );
+        let stored = hexser::ports::repository::QueryRepository::find(
+            &*repo,
+            &task_manager::ports::artifact_repository_port::ArtifactFilter::All,
+            hexser::ports::repository::FindOptions::default(),
+        )
+        .unwrap();
+        std::assert!(stored.iter().all(|artifact| artifact.embedding.len() == 5));
+    }
 }
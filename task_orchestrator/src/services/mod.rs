@@ -6,6 +6,8 @@
 //! and retrieval.
 //!
 //! Revision History
+//! - 2026-10-17T17:30:00Z @AI: Add similar_tasks_service for top-K similar task lookup.
+//! - 2026-10-17T17:10:00Z @AI: Add task_dedup_service for embedding-based duplicate detection.
 //! - 2025-11-30T21:00:00Z @AI: Add artifact_generator_service for Phase 4 artifact generator.
 //! - 2025-11-30T11:50:00Z @AI: Add vision_service for Phase 3 media processing implementation.
 //! - 2025-11-28T20:15:00Z @AI: Create services module for Phase 3 RAG artifact management (Task 4.1).
@@ -13,3 +15,5 @@
 pub mod artifact_service;
 pub mod vision_service;
 pub mod artifact_generator_service;
+pub mod task_dedup_service;
+pub mod similar_tasks_service;
@@ -0,0 +1,179 @@
+//! Nearest-neighbour lookup of tasks by embedding similarity.
+//!
+//! SimilarTasksService embeds a task and its candidates (title plus
+//! description, as for duplicate detection) through the EmbeddingPort and
+//! ranks the candidates by cosine similarity to it. The task itself is never
+//! a candidate; completed and archived tasks are skipped unless asked for.
+//! Wrap the port in a `CachingEmbeddingAdapter` so unchanged tasks are not
+//! re-embedded on every lookup.
+//!
+//! Revision History
+//! - 2026-10-17T17:30:00Z @AI: Initial top-K similar task lookup.
+
+/// A task and how similar it is to the task looked up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimilarTask {
+    /// ID of the similar task
+    pub task_id: String,
+
+    /// Cosine similarity to the looked-up task (1.0 = same direction)
+    pub similarity: f32,
+}
+
+/// Finds the tasks most similar to a given task.
+pub struct SimilarTasksService {
+    embedding_port: std::sync::Arc<dyn crate::ports::embedding_port::EmbeddingPort + std::marker::Send + std::marker::Sync>,
+}
+
+impl SimilarTasksService {
+    /// Creates a service embedding through `embedding_port`.
+    pub fn new(
+        embedding_port: std::sync::Arc<dyn crate::ports::embedding_port::EmbeddingPort + std::marker::Send + std::marker::Sync>,
+    ) -> Self {
+        Self { embedding_port }
+    }
+
+    /// Returns up to `limit` tasks most similar to `task`, best first.
+    ///
+    /// # Arguments
+    ///
+    /// * `task` - Task to find neighbours of
+    /// * `tasks` - Candidate tasks; `task` itself is skipped if present
+    /// * `limit` - Maximum number of results
+    /// * `include_closed` - Also consider completed and archived tasks
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if embedding generation fails.
+    pub async fn find_similar(
+        &self,
+        task: &task_manager::domain::task::Task,
+        tasks: &[task_manager::domain::task::Task],
+        limit: usize,
+        include_closed: bool,
+    ) -> std::result::Result<std::vec::Vec<SimilarTask>, String> {
+        let candidates: std::vec::Vec<&task_manager::domain::task::Task> = tasks
+            .iter()
+            .filter(|candidate| candidate.id != task.id)
+            .filter(|candidate| include_closed || !is_closed(&candidate.status))
+            .collect();
+        if candidates.is_empty() || limit == 0 {
+            return std::result::Result::Ok(std::vec::Vec::new());
+        }
+
+        let mut texts = std::vec![crate::services::task_dedup_service::embedding_text(task)];
+        texts.extend(candidates.iter().map(|candidate| crate::services::task_dedup_service::embedding_text(candidate)));
+        let text_refs: std::vec::Vec<&str> = texts.iter().map(String::as_str).collect();
+        let embeddings = self.embedding_port.generate_embeddings(&text_refs).await?;
+        if embeddings.len() != texts.len() {
+            return std::result::Result::Err(std::format!("Expected {} embeddings, got {}", texts.len(), embeddings.len()));
+        }
+
+        let mut similar: std::vec::Vec<SimilarTask> = candidates
+            .iter()
+            .zip(&embeddings[1..])
+            .map(|(candidate, embedding)| SimilarTask {
+                task_id: candidate.id.clone(),
+                similarity: crate::services::task_dedup_service::cosine_similarity(&embeddings[0], embedding),
+            })
+            .collect();
+        // Stable sort keeps input order among equally similar tasks
+        similar.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+        similar.truncate(limit);
+        std::result::Result::Ok(similar)
+    }
+}
+
+/// Returns true for statuses excluded by default: finished or archived (cancelled).
+fn is_closed(status: &task_manager::domain::task_status::TaskStatus) -> bool {
+    task_manager::domain::services::blocked_tasks::is_finished(status)
+        || *status == task_manager::domain::task_status::TaskStatus::Archived
+}
+
+#[cfg(test)]
+mod tests {
+    /// Embeds text as word counts over a fixed vocabulary; other words are ignored.
+    struct BagOfWordsEmbedder;
+
+    const VOCABULARY: &[&str] = &["login", "page", "form", "oauth", "release", "notes", "password"];
+
+    #[async_trait::async_trait]
+    impl crate::ports::embedding_port::EmbeddingPort for BagOfWordsEmbedder {
+        async fn generate_embedding(&self, text: &str) -> std::result::Result<std::vec::Vec<f32>, String> {
+            let words: std::vec::Vec<String> = text.split_whitespace().map(|word| word.to_lowercase()).collect();
+            std::result::Result::Ok(VOCABULARY.iter().map(|term| words.iter().filter(|word| word == term).count() as f32).collect())
+        }
+
+        async fn generate_embeddings(&self, texts: &[&str]) -> std::result::Result<std::vec::Vec<std::vec::Vec<f32>>, String> {
+            let mut embeddings = std::vec::Vec::new();
+            for text in texts {
+                embeddings.push(self.generate_embedding(text).await?);
+            }
+            std::result::Result::Ok(embeddings)
+        }
+
+        async fn embedding_dimension(&self) -> usize {
+            VOCABULARY.len()
+        }
+    }
+
+    fn task(id: &str, title: &str) -> task_manager::domain::task::Task {
+        let item = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from(title),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let mut task = task_manager::domain::task::Task::from_action_item(&item, std::option::Option::None);
+        task.id = std::string::String::from(id);
+        task
+    }
+
+    fn seeded() -> std::vec::Vec<task_manager::domain::task::Task> {
+        let mut done = task("done", "login page");
+        done.status = task_manager::domain::task_status::TaskStatus::Completed;
+        let mut cancelled = task("cancelled", "login page form");
+        cancelled.status = task_manager::domain::task_status::TaskStatus::Archived;
+        std::vec![
+            task("target", "login page form"),
+            task("notes", "release notes"),
+            task("oauth", "oauth login"),
+            task("form", "login form password"),
+            done,
+            cancelled,
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_neighbours_are_ordered_by_similarity_without_self_or_closed_tasks() {
+        // Test: Validates results are ranked nearest first, limited to K, and exclude the task itself and completed or archived tasks.
+        // Justification: The task always matches itself best, and finished work is rarely what the user is looking for.
+        let tasks = seeded();
+        let service = super::SimilarTasksService::new(std::sync::Arc::new(BagOfWordsEmbedder));
+
+        let similar = service.find_similar(&tasks[0], &tasks, 3, false).await.unwrap();
+
+        let ids: std::vec::Vec<&str> = similar.iter().map(|s| s.task_id.as_str()).collect();
+        std::assert_eq!(ids, ["form", "oauth", "notes"]);
+        std::assert!(similar[0].similarity > similar[1].similarity);
+        std::assert_eq!(similar[2].similarity, 0.0);
+
+        let top_one = service.find_similar(&tasks[0], &tasks, 1, false).await.unwrap();
+        std::assert_eq!(top_one[0].task_id, "form");
+    }
+
+    #[tokio::test]
+    async fn test_include_closed_adds_finished_and_archived_tasks() {
+        // Test: Validates include_closed brings completed and archived tasks back in, still never the task itself.
+        // Justification: Looking up how a similar task was done before needs finished tasks.
+        let tasks = seeded();
+        let service = super::SimilarTasksService::new(std::sync::Arc::new(BagOfWordsEmbedder));
+
+        let similar = service.find_similar(&tasks[0], &tasks, 10, true).await.unwrap();
+
+        let ids: std::vec::Vec<&str> = similar.iter().map(|s| s.task_id.as_str()).collect();
+        std::assert_eq!(ids[0], "cancelled", "identical text ranks first");
+        std::assert!(ids.contains(&"done"));
+        std::assert!(!ids.contains(&"target"));
+        std::assert_eq!(similar.len(), 5);
+    }
+}
@@ -0,0 +1,327 @@
+//! Semantic duplicate detection over existing tasks.
+//!
+//! TaskDedupService embeds the title and description of every open task
+//! through the EmbeddingPort and groups tasks whose cosine similarity reaches
+//! a threshold. Grouping is single-linkage: if A matches B and B matches C,
+//! all three form one group even when A and C fall just under the threshold.
+//!
+//! Finding groups never changes a task. `merge_group` computes the changes a
+//! merge makes: it keeps the earliest-created task of a group, archives the
+//! others with a `duplicates` link to the kept task, and repoints tasks that
+//! depended on an archived duplicate to the kept task, so nothing ends up
+//! blocked by a cancelled task. Callers persist the returned tasks.
+//!
+//! Revision History
+//! - 2026-10-17T17:30:00Z @AI: Share embedding_text() and cosine_similarity() with similar_tasks_service.
+//! - 2026-10-17T17:10:00Z @AI: Initial duplicate grouping by embedding similarity and merge into the earliest task.
+
+/// Default minimum cosine similarity for two tasks to count as duplicates.
+pub const DEFAULT_THRESHOLD: f32 = 0.9;
+
+/// A set of open tasks that look like duplicates of each other.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicateGroup {
+    /// Task IDs, earliest-created first; the first is kept on merge
+    pub task_ids: std::vec::Vec<String>,
+
+    /// Lowest similarity among the pairs that joined the group
+    pub similarity: f32,
+}
+
+impl DuplicateGroup {
+    /// Returns the ID of the task a merge keeps.
+    pub fn keeper_id(&self) -> &str {
+        &self.task_ids[0]
+    }
+}
+
+/// Finds and merges near-duplicate tasks using text embeddings.
+pub struct TaskDedupService {
+    embedding_port: std::sync::Arc<dyn crate::ports::embedding_port::EmbeddingPort + std::marker::Send + std::marker::Sync>,
+}
+
+impl TaskDedupService {
+    /// Creates a service embedding through `embedding_port`.
+    pub fn new(
+        embedding_port: std::sync::Arc<dyn crate::ports::embedding_port::EmbeddingPort + std::marker::Send + std::marker::Sync>,
+    ) -> Self {
+        Self { embedding_port }
+    }
+
+    /// Groups the open tasks whose embeddings are at least `threshold` similar.
+    ///
+    /// # Arguments
+    ///
+    /// * `tasks` - Tasks to examine; finished, archived, errored, and decomposed tasks are skipped
+    /// * `threshold` - Minimum cosine similarity (0.0-1.0) for two tasks to be grouped
+    ///
+    /// # Returns
+    ///
+    /// Groups of two or more tasks, ordered by their earliest task's creation time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if embedding generation fails.
+    pub async fn find_duplicates(
+        &self,
+        tasks: &[task_manager::domain::task::Task],
+        threshold: f32,
+    ) -> std::result::Result<std::vec::Vec<DuplicateGroup>, String> {
+        let mut open: std::vec::Vec<&task_manager::domain::task::Task> = tasks
+            .iter()
+            .filter(|task| task_manager::domain::services::blocked_tasks::is_open(&task.status))
+            .collect();
+        if open.len() < 2 {
+            return std::result::Result::Ok(std::vec::Vec::new());
+        }
+        open.sort_by(|a, b| a.created_at.cmp(&b.created_at).then_with(|| a.id.cmp(&b.id)));
+
+        let texts: std::vec::Vec<String> = open.iter().map(|task| embedding_text(task)).collect();
+        let text_refs: std::vec::Vec<&str> = texts.iter().map(String::as_str).collect();
+        let embeddings = self.embedding_port.generate_embeddings(&text_refs).await?;
+        if embeddings.len() != open.len() {
+            return std::result::Result::Err(std::format!(
+                "Expected {} embeddings, got {}",
+                open.len(),
+                embeddings.len()
+            ));
+        }
+
+        // Union-find over tasks; each root tracks the weakest edge that joined its group
+        let mut parent: std::vec::Vec<usize> = (0..open.len()).collect();
+        let mut weakest: std::vec::Vec<f32> = std::vec![f32::INFINITY; open.len()];
+        for i in 0..open.len() {
+            for j in (i + 1)..open.len() {
+                let similarity = cosine_similarity(&embeddings[i], &embeddings[j]);
+                if similarity < threshold {
+                    continue;
+                }
+                let (root_i, root_j) = (find_root(&mut parent, i), find_root(&mut parent, j));
+                let (keep, merged) = (root_i.min(root_j), root_i.max(root_j));
+                parent[merged] = keep;
+                weakest[keep] = weakest[keep].min(weakest[merged]).min(similarity);
+            }
+        }
+
+        let mut members: std::collections::BTreeMap<usize, std::vec::Vec<usize>> = std::collections::BTreeMap::new();
+        for i in 0..open.len() {
+            let root = find_root(&mut parent, i);
+            members.entry(root).or_default().push(i);
+        }
+
+        // Roots are the lowest index of their group, so BTreeMap order is creation order
+        std::result::Result::Ok(
+            members
+                .into_iter()
+                .filter(|(_, indices)| indices.len() > 1)
+                .map(|(root, indices)| DuplicateGroup {
+                    task_ids: indices.iter().map(|&i| open[i].id.clone()).collect(),
+                    similarity: weakest[root],
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Computes the tasks a merge of `group` changes.
+///
+/// The group's first (earliest) task is kept unchanged. Every other member
+/// is archived with a `duplicates` link to the kept task and a revision
+/// entry, and any task depending on a merged duplicate depends on the kept
+/// task instead.
+///
+/// # Arguments
+///
+/// * `tasks` - All tasks of the project, so dependents can be repointed
+/// * `group` - The group to merge
+///
+/// # Returns
+///
+/// The changed tasks, to be saved by the caller.
+pub fn merge_group(
+    tasks: &[task_manager::domain::task::Task],
+    group: &DuplicateGroup,
+) -> std::vec::Vec<task_manager::domain::task::Task> {
+    let keeper = group.keeper_id();
+    let merged: std::collections::HashSet<&str> = group.task_ids[1..].iter().map(String::as_str).collect();
+    let now = chrono::Utc::now();
+    let mut changed = std::vec::Vec::new();
+
+    for task in tasks {
+        let mut updated = task.clone();
+        if merged.contains(task.id.as_str()) {
+            updated.add_link(keeper, task_manager::domain::task_link::LinkType::Duplicates);
+            updated.revisions.push(task_manager::domain::task_revision::TaskRevision {
+                revision_id: uuid::Uuid::new_v4().to_string(),
+                task_id: task.id.clone(),
+                timestamp: now,
+                change_description: std::format!("Merged into {} as a duplicate", keeper),
+                previous_state_json: serde_json::to_string(&task.status).ok(),
+            });
+            updated.track_status_time(&task_manager::domain::task_status::TaskStatus::Archived, now);
+            updated.status = task_manager::domain::task_status::TaskStatus::Archived;
+        }
+
+        if updated.dependencies.iter().any(|id| merged.contains(id.as_str())) {
+            let mut dependencies = std::vec::Vec::new();
+            for id in &updated.dependencies {
+                let id = if merged.contains(id.as_str()) { keeper } else { id.as_str() };
+                if id != updated.id && !dependencies.iter().any(|existing: &String| existing == id) {
+                    dependencies.push(id.to_string());
+                }
+            }
+            updated.dependencies = dependencies;
+        }
+
+        if updated.status != task.status || updated.dependencies != task.dependencies {
+            updated.updated_at = now;
+            changed.push(updated);
+        }
+    }
+    changed
+}
+
+/// Text embedded for a task: its title, plus its description when present.
+pub(crate) fn embedding_text(task: &task_manager::domain::task::Task) -> String {
+    let description = task.description.trim();
+    if description.is_empty() {
+        task.title.clone()
+    } else {
+        std::format!("{}\n{}", task.title, description)
+    }
+}
+
+/// Returns the root of `i` in the union-find forest, compressing the path.
+fn find_root(parent: &mut [usize], i: usize) -> usize {
+    let mut root = i;
+    while parent[root] != root {
+        root = parent[root];
+    }
+    let mut node = i;
+    while parent[node] != root {
+        let next = parent[node];
+        parent[node] = root;
+        node = next;
+    }
+    root
+}
+
+/// Cosine similarity of two vectors; 0.0 if their lengths differ or either is zero.
+pub(crate) fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+
+    let dot_product: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+    let magnitude_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let magnitude_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+
+    if magnitude_a == 0.0 || magnitude_b == 0.0 {
+        return 0.0;
+    }
+
+    dot_product / (magnitude_a * magnitude_b)
+}
+
+#[cfg(test)]
+mod tests {
+    /// Embeds text as word counts over a fixed vocabulary; other words are ignored.
+    struct BagOfWordsEmbedder {
+        vocabulary: &'static [&'static str],
+    }
+
+    #[async_trait::async_trait]
+    impl crate::ports::embedding_port::EmbeddingPort for BagOfWordsEmbedder {
+        async fn generate_embedding(&self, text: &str) -> std::result::Result<std::vec::Vec<f32>, String> {
+            let words: std::vec::Vec<String> = text.split_whitespace().map(|word| word.to_lowercase()).collect();
+            std::result::Result::Ok(
+                self.vocabulary
+                    .iter()
+                    .map(|term| words.iter().filter(|word| word == term).count() as f32)
+                    .collect(),
+            )
+        }
+
+        async fn generate_embeddings(&self, texts: &[&str]) -> std::result::Result<std::vec::Vec<std::vec::Vec<f32>>, String> {
+            let mut embeddings = std::vec::Vec::new();
+            for text in texts {
+                embeddings.push(self.generate_embedding(text).await?);
+            }
+            std::result::Result::Ok(embeddings)
+        }
+
+        async fn embedding_dimension(&self) -> usize {
+            self.vocabulary.len()
+        }
+    }
+
+    fn service() -> super::TaskDedupService {
+        super::TaskDedupService::new(std::sync::Arc::new(BagOfWordsEmbedder {
+            vocabulary: &["add", "login", "page", "form", "write", "release", "notes", "fix", "crash"],
+        }))
+    }
+
+    /// Tasks created one minute apart in the given order.
+    fn tasks(specs: &[(&str, &str)]) -> std::vec::Vec<task_manager::domain::task::Task> {
+        let start = chrono::Utc::now() - chrono::Duration::hours(1);
+        specs
+            .iter()
+            .enumerate()
+            .map(|(i, (id, title))| {
+                let item = transcript_extractor::domain::action_item::ActionItem {
+                    title: std::string::String::from(*title),
+                    assignee: std::option::Option::None,
+                    due_date: std::option::Option::None,
+                };
+                let mut task = task_manager::domain::task::Task::from_action_item(&item, std::option::Option::None);
+                task.id = std::string::String::from(*id);
+                task.created_at = start + chrono::Duration::minutes(i as i64);
+                task
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_groups_near_duplicates_and_leaves_distinct_tasks_alone() {
+        // Test: Validates near-duplicate titles are grouped earliest-first while distinct and closed tasks are not grouped.
+        // Justification: The report must point at real duplicates only, with the task a merge would keep listed first.
+        let mut seeded = tasks(&[
+            ("login-1", "Add login page"),
+            ("notes", "Write release notes"),
+            ("login-2", "Add login page form"),
+            ("crash", "Fix crash"),
+            ("login-3", "add LOGIN page"),
+            ("done-login", "Add login page"),
+        ]);
+        seeded[5].status = task_manager::domain::task_status::TaskStatus::Completed;
+
+        let groups = service().find_duplicates(&seeded, 0.8).await.unwrap();
+
+        std::assert_eq!(groups.len(), 1);
+        std::assert_eq!(groups[0].task_ids, ["login-1", "login-2", "login-3"]);
+        std::assert_eq!(groups[0].keeper_id(), "login-1");
+        std::assert!(groups[0].similarity >= 0.8 && groups[0].similarity < 1.0);
+
+        let strict = service().find_duplicates(&seeded, 0.99).await.unwrap();
+        std::assert_eq!(strict[0].task_ids, ["login-1", "login-3"]);
+    }
+
+    #[tokio::test]
+    async fn test_merge_keeps_earliest_and_archives_the_rest_as_duplicates() {
+        // Test: Validates a merge leaves the earliest task untouched, archives the others linked to it, and repoints their dependents.
+        // Justification: Merging must not lose the original task or leave dependents blocked by an archived duplicate.
+        let mut seeded = tasks(&[("login-1", "Add login page"), ("login-2", "Add login page form"), ("release", "Write release notes")]);
+        seeded[2].dependencies = std::vec![std::string::String::from("login-2"), std::string::String::from("login-1")];
+        let group = service().find_duplicates(&seeded, 0.8).await.unwrap().remove(0);
+
+        let changed = super::merge_group(&seeded, &group);
+
+        let ids: std::vec::Vec<&str> = changed.iter().map(|task| task.id.as_str()).collect();
+        std::assert_eq!(ids, ["login-2", "release"], "the kept task is not changed");
+        std::assert_eq!(changed[0].status, task_manager::domain::task_status::TaskStatus::Archived);
+        std::assert_eq!(changed[0].links[0].task_id, "login-1");
+        std::assert_eq!(changed[0].links[0].link_type, task_manager::domain::task_link::LinkType::Duplicates);
+        std::assert!(changed[0].revisions.last().unwrap().change_description.contains("login-1"));
+        std::assert_eq!(changed[1].dependencies, std::vec![std::string::String::from("login-1")]);
+    }
+}
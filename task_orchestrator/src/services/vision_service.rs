@@ -19,7 +19,15 @@
 //! - HTML: `<img src="url">`
 //! - Raw URLs: `https://....(png|jpg|jpeg|gif|webp|pdf)`
 //!
+//! # Image Validation
+//!
+//! Images are checked before any vision request: the extension and magic bytes
+//! must identify PNG, JPEG, WebP, or GIF, and images exceeding the configured
+//! dimension or byte limits are downscaled (or rejected when downscaling is off).
+//!
 //! Revision History
+//! - 2026-10-16T19:50:00Z @AI: Replace eprintln! media diagnostics with tracing events.
+//! - 2026-10-16T13:10:00Z @AI: Add image format/size validation with optional downscaling and VisionError.
 //! - 2025-11-30T13:00:00Z @AI: Phase 6 PDF support - add extract_pdf_text() using pdf-extract crate for text extraction from PDF documents. PDFs with substantial text use extracted text as description, while image-heavy PDFs fall back to vision LLM. Added process_pdf_content() helper for multi-page handling and PdfProcessingResult struct.
 //! - 2025-11-30T11:45:00Z @AI: Initial VisionService for Phase 3 media processing implementation.
 
@@ -115,6 +123,117 @@ pub struct PdfProcessingResult {
     pub pages: std::vec::Vec<String>,
 }
 
+/// Image formats accepted by vision models, by file extension.
+const SUPPORTED_IMAGE_EXTENSIONS: [&str; 5] = ["png", "jpg", "jpeg", "webp", "gif"];
+
+/// Errors raised while validating images for vision processing.
+///
+/// # Variants
+///
+/// * `UnsupportedFormat` - The extension or content is not PNG, JPEG, WebP, or GIF.
+/// * `TooLarge` - The image exceeds the configured limits and cannot be downscaled.
+/// * `InvalidImage` - The image data could not be decoded or re-encoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VisionError {
+    /// The extension or content is not a supported image format.
+    UnsupportedFormat(String),
+
+    /// The image exceeds the configured size limits.
+    TooLarge(String),
+
+    /// The image data could not be decoded or re-encoded.
+    InvalidImage(String),
+}
+
+impl std::fmt::Display for VisionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VisionError::UnsupportedFormat(msg) => {
+                std::write!(f, "Unsupported image format: {} (supported: PNG, JPEG, WEBP, GIF)", msg)
+            }
+            VisionError::TooLarge(msg) => {
+                std::write!(f, "Image too large: {}", msg)
+            }
+            VisionError::InvalidImage(msg) => {
+                std::write!(f, "Invalid image: {}", msg)
+            }
+        }
+    }
+}
+
+impl std::error::Error for VisionError {}
+
+/// Limits applied to images before they are sent to a vision model.
+///
+/// # Examples
+///
+/// ```
+/// # use task_orchestrator::services::vision_service::ImageValidationConfig;
+/// let config = ImageValidationConfig::default().with_max_dimension(1024);
+/// std::assert_eq!(config.max_dimension, 1024);
+/// std::assert!(config.downscale);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageValidationConfig {
+    /// Maximum encoded size in bytes.
+    pub max_bytes: usize,
+
+    /// Maximum width or height in pixels.
+    pub max_dimension: u32,
+
+    /// Whether oversized images are downscaled instead of rejected.
+    pub downscale: bool,
+}
+
+impl ImageValidationConfig {
+    /// Sets the maximum encoded size in bytes.
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Sets the maximum width or height in pixels.
+    pub fn with_max_dimension(mut self, max_dimension: u32) -> Self {
+        self.max_dimension = max_dimension;
+        self
+    }
+
+    /// Enables or disables downscaling of oversized images.
+    pub fn with_downscale(mut self, downscale: bool) -> Self {
+        self.downscale = downscale;
+        self
+    }
+}
+
+impl std::default::Default for ImageValidationConfig {
+    fn default() -> Self {
+        ImageValidationConfig {
+            max_bytes: 5 * 1024 * 1024,
+            max_dimension: 2048,
+            downscale: true,
+        }
+    }
+}
+
+/// An image that passed validation and is ready for a vision request.
+#[derive(Debug, Clone)]
+pub struct ValidatedImage {
+    /// Image data encoded as base64.
+    pub base64_data: String,
+
+    /// MIME type detected from the magic bytes.
+    pub mime_type: String,
+
+    /// Width in pixels after any downscaling.
+    pub width: u32,
+
+    /// Height in pixels after any downscaling.
+    pub height: u32,
+
+    /// Whether the image was downscaled to fit the limits.
+    pub downscaled: bool,
+}
+
 /// Service for processing media in PRD documents.
 ///
 /// VisionService orchestrates the detection, fetching, and description of
@@ -146,6 +265,7 @@ pub struct PdfProcessingResult {
 pub struct VisionService {
     vision_port: std::sync::Arc<dyn crate::ports::vision_port::VisionPort + std::marker::Send + std::marker::Sync>,
     http_client: reqwest::Client,
+    image_validation: ImageValidationConfig,
 }
 
 impl VisionService {
@@ -171,7 +291,151 @@ impl VisionService {
         VisionService {
             vision_port,
             http_client: reqwest::Client::new(),
+            image_validation: ImageValidationConfig::default(),
+        }
+    }
+
+    /// Sets the limits applied to images before vision requests.
+    pub fn with_image_validation(mut self, config: ImageValidationConfig) -> Self {
+        self.image_validation = config;
+        self
+    }
+
+    /// Checks that a path or URL does not name an unsupported image format.
+    ///
+    /// Sources without an extension pass; their content is checked by
+    /// `validate_image` once fetched.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - File path or URL of the image.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VisionError::UnsupportedFormat` for extensions other than
+    /// png, jpg, jpeg, webp, or gif.
+    pub fn validate_image_source(source: &str) -> std::result::Result<(), VisionError> {
+        let path = source.split(['?', '#']).next().unwrap_or(source);
+        let file_name = path.rsplit('/').next().unwrap_or(path);
+        match file_name.rsplit_once('.') {
+            std::option::Option::Some((_, ext))
+                if !SUPPORTED_IMAGE_EXTENSIONS.iter().any(|s| s.eq_ignore_ascii_case(ext)) =>
+            {
+                std::result::Result::Err(VisionError::UnsupportedFormat(std::format!(
+                    "{} has extension .{}",
+                    source, ext
+                )))
+            }
+            _ => std::result::Result::Ok(()),
+        }
+    }
+
+    /// Validates image bytes and prepares them for a vision request.
+    ///
+    /// Checks the extension of `source` and the magic bytes of `bytes` against
+    /// PNG, JPEG, WebP, and GIF, then enforces the dimension and byte limits.
+    /// Oversized images are downscaled (preserving aspect ratio) when enabled;
+    /// valid images within limits are passed through unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Raw image bytes.
+    /// * `source` - File path or URL, used for the extension check and messages.
+    /// * `config` - Size limits and downscaling option.
+    ///
+    /// # Returns
+    ///
+    /// The base64-encoded image with its MIME type and final dimensions.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UnsupportedFormat`, `TooLarge`, or `InvalidImage`.
+    pub fn validate_image(
+        bytes: &[u8],
+        source: &str,
+        config: &ImageValidationConfig,
+    ) -> std::result::Result<ValidatedImage, VisionError> {
+        Self::validate_image_source(source)?;
+
+        let mime_type = crate::ports::vision_port::image_mime_from_magic(bytes).ok_or_else(|| {
+            VisionError::UnsupportedFormat(std::format!("{} content is not a recognized image", source))
+        })?;
+
+        let (width, height) = image::ImageReader::new(std::io::Cursor::new(bytes))
+            .with_guessed_format()
+            .map_err(|e| VisionError::InvalidImage(std::format!("{}: {}", source, e)))?
+            .into_dimensions()
+            .map_err(|e| VisionError::InvalidImage(std::format!("{}: {}", source, e)))?;
+
+        let longest = width.max(height);
+        if longest <= config.max_dimension && bytes.len() <= config.max_bytes {
+            return std::result::Result::Ok(ValidatedImage {
+                base64_data: base64::Engine::encode(&base64::engine::general_purpose::STANDARD, bytes),
+                mime_type: mime_type.to_string(),
+                width,
+                height,
+                downscaled: false,
+            });
+        }
+
+        if !config.downscale {
+            return std::result::Result::Err(VisionError::TooLarge(std::format!(
+                "{} is {}x{} ({} bytes); limits are {}px and {} bytes",
+                source, width, height, bytes.len(), config.max_dimension, config.max_bytes
+            )));
         }
+
+        Self::downscale_image(bytes, source, mime_type, config)
+    }
+
+    /// Shrinks an image until it fits the dimension and byte limits.
+    ///
+    /// JPEG input is re-encoded as JPEG; all other formats become PNG.
+    fn downscale_image(
+        bytes: &[u8],
+        source: &str,
+        mime_type: &str,
+        config: &ImageValidationConfig,
+    ) -> std::result::Result<ValidatedImage, VisionError> {
+        let decoded = image::load_from_memory(bytes)
+            .map_err(|e| VisionError::InvalidImage(std::format!("{}: {}", source, e)))?;
+        let (output_format, output_mime) = if mime_type == "image/jpeg" {
+            (image::ImageFormat::Jpeg, "image/jpeg")
+        } else {
+            (image::ImageFormat::Png, "image/png")
+        };
+
+        let longest = decoded.width().max(decoded.height());
+        let mut target = longest.min(config.max_dimension);
+        if target == longest {
+            // Only the byte limit was exceeded; start one step smaller
+            target = target * 3 / 4;
+        }
+
+        while target >= 1 {
+            let resized = decoded.resize(target, target, image::imageops::FilterType::Triangle);
+            let mut encoded = std::io::Cursor::new(std::vec::Vec::new());
+            resized
+                .write_to(&mut encoded, output_format)
+                .map_err(|e| VisionError::InvalidImage(std::format!("{}: {}", source, e)))?;
+            let encoded = encoded.into_inner();
+
+            if encoded.len() <= config.max_bytes {
+                return std::result::Result::Ok(ValidatedImage {
+                    base64_data: base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &encoded),
+                    mime_type: output_mime.to_string(),
+                    width: resized.width(),
+                    height: resized.height(),
+                    downscaled: true,
+                });
+            }
+            target = target * 3 / 4;
+        }
+
+        std::result::Result::Err(VisionError::TooLarge(std::format!(
+            "{} cannot be downscaled below {} bytes",
+            source, config.max_bytes
+        )))
     }
 
     /// Scans PRD content for media URLs.
@@ -301,6 +565,17 @@ impl VisionService {
         &self,
         url: &str,
     ) -> std::result::Result<(String, String), String> {
+        let (bytes, mime_type) = self.fetch_media_bytes(url).await?;
+        let base64_data = base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &bytes);
+
+        std::result::Result::Ok((base64_data, mime_type))
+    }
+
+    /// Fetches raw media bytes and the MIME type from a URL.
+    async fn fetch_media_bytes(
+        &self,
+        url: &str,
+    ) -> std::result::Result<(std::vec::Vec<u8>, String), String> {
         let response = self
             .http_client
             .get(url)
@@ -329,9 +604,7 @@ impl VisionService {
             .await
             .map_err(|e| std::format!("Failed to read media bytes: {:?}", e))?;
 
-        let base64_data = base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &bytes);
-
-        std::result::Result::Ok((base64_data, mime_type))
+        std::result::Result::Ok((bytes.to_vec(), mime_type))
     }
 
     /// Determines MIME type from URL extension.
@@ -454,15 +727,11 @@ impl VisionService {
                     return std::result::Result::Ok(summary);
                 }
                 // Image-heavy PDF - fall through to vision LLM
-                eprintln!(
-                    "PDF at {} appears image-heavy ({} chars), using vision LLM",
-                    url,
-                    result.text.len()
-                );
+                tracing::info!(url, text_chars = result.text.len(), "PDF appears image-heavy; using vision LLM");
             }
             std::result::Result::Err(e) => {
                 // Text extraction failed - fall through to vision LLM
-                eprintln!("PDF text extraction failed for {}: {}", url, e);
+                tracing::warn!(url, error = %e, "PDF text extraction failed; using vision LLM");
             }
         }
 
@@ -551,17 +820,46 @@ impl VisionService {
                 }).await;
             }
 
+            // Reject unsupported image formats before any network call
+            let source_check = match media_ref.media_type {
+                MediaType::Image => Self::validate_image_source(&media_ref.url),
+                MediaType::PDF => std::result::Result::Ok(()),
+            };
+            if let std::result::Result::Err(e) = source_check {
+                tracing::warn!(url = %media_ref.url, error = %e, "skipping media");
+                failed += 1;
+                continue;
+            }
+
             // Fetch media
-            let fetch_result = self.fetch_media(&media_ref.url).await;
-            let (base64_data, mime_type) = match fetch_result {
+            let fetch_result = self.fetch_media_bytes(&media_ref.url).await;
+            let (bytes, fetched_mime_type) = match fetch_result {
                 std::result::Result::Ok(data) => data,
                 std::result::Result::Err(e) => {
-                    eprintln!("Failed to fetch {}: {}", media_ref.url, e);
+                    tracing::warn!(url = %media_ref.url, error = %e, "failed to fetch media");
                     failed += 1;
                     continue;
                 }
             };
 
+            // Validate and, if needed, downscale images before the vision request
+            let (base64_data, mime_type) = match media_ref.media_type {
+                MediaType::Image => {
+                    match Self::validate_image(&bytes, &media_ref.url, &self.image_validation) {
+                        std::result::Result::Ok(image) => (image.base64_data, image.mime_type),
+                        std::result::Result::Err(e) => {
+                            tracing::warn!(url = %media_ref.url, error = %e, "skipping media");
+                            failed += 1;
+                            continue;
+                        }
+                    }
+                }
+                MediaType::PDF => (
+                    base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &bytes),
+                    fetched_mime_type,
+                ),
+            };
+
             // Generate description based on media type
             let description = match media_ref.media_type {
                 MediaType::Image => {
@@ -572,7 +870,7 @@ impl VisionService {
                     {
                         std::result::Result::Ok(resp) => resp.description,
                         std::result::Result::Err(e) => {
-                            eprintln!("Failed to describe image {}: {}", media_ref.url, e);
+                            tracing::warn!(url = %media_ref.url, error = %e, "failed to describe image");
                             failed += 1;
                             continue;
                         }
@@ -583,7 +881,7 @@ impl VisionService {
                     match self.process_pdf_content(&base64_data, &media_ref.url).await {
                         std::result::Result::Ok(desc) => desc,
                         std::result::Result::Err(e) => {
-                            eprintln!("Failed to process PDF {}: {}", media_ref.url, e);
+                            tracing::warn!(url = %media_ref.url, error = %e, "failed to process PDF");
                             failed += 1;
                             continue;
                         }
@@ -789,11 +1087,79 @@ Raw: https://example.com/c.gif
         assert_eq!(VisionService::mime_type_from_url("https://example.com/doc.pdf"), "application/pdf");
     }
 
+    // Helper to encode a blank PNG of the given size
+    fn png_bytes(width: u32, height: u32) -> std::vec::Vec<u8> {
+        let image = image::DynamicImage::ImageRgb8(image::RgbImage::new(width, height));
+        let mut encoded = std::io::Cursor::new(std::vec::Vec::new());
+        image.write_to(&mut encoded, image::ImageFormat::Png).unwrap();
+        encoded.into_inner()
+    }
+
+    #[test]
+    fn test_validate_image_rejects_unsupported_format() {
+        // Test: Validates TIFF files are rejected by extension and by content.
+        // Justification: Unsupported formats must fail clearly before any vision request.
+        let tiff = b"II*\x00\x08\x00\x00\x00\x00\x00";
+        let config = ImageValidationConfig::default();
+
+        let by_extension = VisionService::validate_image(tiff, "docs/scan.tiff", &config);
+        let by_content = VisionService::validate_image(tiff, "docs/scan.png", &config);
+
+        std::assert!(matches!(by_extension, std::result::Result::Err(VisionError::UnsupportedFormat(_))));
+        std::assert!(matches!(by_content, std::result::Result::Err(VisionError::UnsupportedFormat(_))));
+        std::assert!(VisionService::validate_image_source("https://example.com/a.svg?v=2").is_err());
+        std::assert!(VisionService::validate_image_source("https://example.com/image").is_ok());
+    }
+
+    #[test]
+    fn test_validate_image_downscales_oversized_image() {
+        // Test: Validates images beyond the max dimension are resized with aspect ratio preserved.
+        // Justification: Oversized images are shrunk rather than sent to (and rejected by) the provider.
+        let config = ImageValidationConfig::default().with_max_dimension(16);
+
+        let validated = VisionService::validate_image(&png_bytes(64, 32), "wide.png", &config).unwrap();
+        let decoded = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, &validated.base64_data).unwrap();
+        let resized = image::load_from_memory(&decoded).unwrap();
+
+        std::assert!(validated.downscaled);
+        std::assert_eq!((validated.width, validated.height), (16, 8));
+        std::assert_eq!((resized.width(), resized.height()), (16, 8));
+        std::assert_eq!(validated.mime_type, "image/png");
+    }
+
+    #[test]
+    fn test_validate_image_rejects_oversized_without_downscale() {
+        // Test: Validates oversized images fail with TooLarge when downscaling is disabled.
+        // Justification: Callers can opt out of silent resizing.
+        let config = ImageValidationConfig::default().with_max_dimension(16).with_downscale(false);
+
+        let result = VisionService::validate_image(&png_bytes(64, 32), "wide.png", &config);
+
+        std::assert!(matches!(result, std::result::Result::Err(VisionError::TooLarge(_))));
+    }
+
+    #[test]
+    fn test_validate_image_passes_valid_image_through() {
+        // Test: Validates images within limits are encoded unchanged.
+        // Justification: Valid images must not be re-encoded or altered.
+        let bytes = png_bytes(8, 8);
+
+        let validated = VisionService::validate_image(&bytes, "icon.png", &ImageValidationConfig::default()).unwrap();
+
+        std::assert!(!validated.downscaled);
+        std::assert_eq!((validated.width, validated.height), (8, 8));
+        std::assert_eq!(
+            validated.base64_data,
+            base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &bytes)
+        );
+    }
+
     // Helper to create a test service with a mock vision port
     fn create_test_service() -> VisionService {
         VisionService {
             vision_port: std::sync::Arc::new(MockVisionPort),
             http_client: reqwest::Client::new(),
+            image_validation: ImageValidationConfig::default(),
         }
     }
 
@@ -4,7 +4,20 @@
 //! Only allows access to files within the project root directory.
 //! Prevents attacks using "..", absolute paths, or symlinks.
 //!
+//! Writes are disabled unless a sandbox root is configured with
+//! `with_sandbox_root`. A write target must resolve, after canonicalizing its
+//! deepest existing ancestor and any symlink at the target itself, to a
+//! location inside the sandbox root; anything else is an error, never
+//! clamped into the sandbox.
+//!
+//! `list_dir` and `glob` return paths relative to the project root, sorted
+//! and capped at `ListOptions::max_results`. Hidden entries and the `.git`
+//! and `target` directories are skipped unless `include_hidden` is set.
+//! Symlinked directories are never descended into.
+//!
 //! Revision History
+//! - 2026-10-16T23:30:00Z @AI: Add list_dir and glob with sorted, capped results and default exclusion of hidden and ignored directories; add GlobTool.
+//! - 2026-10-16T23:10:00Z @AI: Gate write_file behind a sandbox root; reject `..` components, absolute paths, and symlink escapes (including dangling symlinks) for writes.
 //! - 2025-11-23 @AI: Implement FileSystemTool with path sandboxing (Phase 4 Sprint 9 Task 4.7).
 
 /// Error type for filesystem tool operations.
@@ -18,6 +31,8 @@ pub enum FileSystemError {
     IoError(std::string::String),
     /// Path resolution failed
     InvalidPath(std::string::String),
+    /// Write attempted without a configured sandbox root
+    WriteDisabled(std::string::String),
 }
 
 impl std::fmt::Display for FileSystemError {
@@ -27,12 +42,54 @@ impl std::fmt::Display for FileSystemError {
             FileSystemError::PathEscape(msg) => write!(f, "Path outside project root: {}", msg),
             FileSystemError::IoError(msg) => write!(f, "I/O error: {}", msg),
             FileSystemError::InvalidPath(msg) => write!(f, "Invalid path: {}", msg),
+            FileSystemError::WriteDisabled(msg) => write!(f, "Writes are disabled: {}", msg),
         }
     }
 }
 
 impl std::error::Error for FileSystemError {}
 
+/// Directories skipped by `list_dir` and `glob` unless hidden entries are included.
+const IGNORED_DIRS: [&str; 2] = [".git", "target"];
+
+/// Options for `list_dir` and `glob`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListOptions {
+    /// Maximum number of paths returned; further matches are dropped
+    pub max_results: usize,
+
+    /// Include hidden entries and the `.git` and `target` directories
+    pub include_hidden: bool,
+}
+
+impl std::default::Default for ListOptions {
+    fn default() -> Self {
+        Self {
+            max_results: 200,
+            include_hidden: false,
+        }
+    }
+}
+
+/// Paths returned by `list_dir` or `glob`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PathListing {
+    /// Paths relative to the project root, sorted; directories end with `/`
+    pub paths: std::vec::Vec<std::string::String>,
+
+    /// True if more paths matched than `max_results`
+    pub truncated: bool,
+}
+
+impl PathListing {
+    fn from_unsorted(mut paths: std::vec::Vec<std::string::String>, max_results: usize) -> Self {
+        paths.sort();
+        let truncated = paths.len() > max_results;
+        paths.truncate(max_results);
+        Self { paths, truncated }
+    }
+}
+
 /// Sandboxed filesystem tool for LLM agents.
 ///
 /// This tool allows agents to read files, write files, and list directories
@@ -41,16 +98,21 @@ impl std::error::Error for FileSystemError {}
 /// - No path traversal ("..") allowed
 /// - No absolute paths outside project
 /// - No symlink following
+/// - Writes only inside the sandbox root, if one is configured
 ///
 /// # Examples
 ///
 /// ```ignore
-/// let tool = task_orchestrator::tools::file_system_tool::FileSystemTool::new("/project/root");
+/// let tool = task_orchestrator::tools::file_system_tool::FileSystemTool::new("/project/root")
+///     .with_sandbox_root("/project/root/scaffold");
 /// let content = tool.read_file("src/main.rs").await?;
+/// tool.write_file("scaffold/src/lib.rs", "pub fn hello() {}").await?;
 /// ```
 #[derive(Debug, Clone)]
 pub struct FileSystemTool {
     project_root: std::path::PathBuf,
+    sandbox_root: std::option::Option<std::path::PathBuf>,
+    list_options: ListOptions,
 }
 
 impl FileSystemTool {
@@ -66,9 +128,30 @@ impl FileSystemTool {
     pub fn new(project_root: impl std::convert::AsRef<std::path::Path>) -> Self {
         Self {
             project_root: project_root.as_ref().to_path_buf(),
+            sandbox_root: std::option::Option::None,
+            list_options: ListOptions::default(),
         }
     }
 
+    /// Sets the result cap and hidden-entry handling for `list_dir` and `glob`.
+    pub fn with_list_options(mut self, list_options: ListOptions) -> Self {
+        self.list_options = list_options;
+        self
+    }
+
+    /// Enables writes confined to `sandbox_root`.
+    ///
+    /// Write paths are still given relative to the project root, but must
+    /// resolve inside `sandbox_root`, which must exist when writing.
+    ///
+    /// # Arguments
+    ///
+    /// * `sandbox_root` - Directory that all writes must stay within
+    pub fn with_sandbox_root(mut self, sandbox_root: impl std::convert::AsRef<std::path::Path>) -> Self {
+        self.sandbox_root = std::option::Option::Some(sandbox_root.as_ref().to_path_buf());
+        self
+    }
+
     /// Validates and resolves a path relative to project root.
     ///
     /// # Arguments
@@ -144,6 +227,88 @@ impl FileSystemTool {
         std::result::Result::Ok(canonical)
     }
 
+    /// Validates and resolves a write target inside the sandbox root.
+    ///
+    /// # Arguments
+    ///
+    /// * `relative_path` - Path relative to project root
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PathBuf)` - Absolute target path whose existing part lies in the sandbox
+    /// * `Err(FileSystemError)` - No sandbox, or the path escapes it
+    ///
+    /// # Security
+    ///
+    /// Rejects:
+    /// - Any ".." component and absolute paths
+    /// - Targets whose deepest existing ancestor resolves outside the sandbox
+    /// - Symlinks at the target that resolve outside the sandbox or dangle
+    fn validate_write_path(&self, relative_path: &str) -> std::result::Result<std::path::PathBuf, FileSystemError> {
+        let sandbox_root = self.sandbox_root.as_ref().ok_or_else(|| {
+            FileSystemError::WriteDisabled(std::format!("no sandbox root configured for {}", relative_path))
+        })?;
+        let canonical_sandbox = sandbox_root.canonicalize().map_err(|e| {
+            FileSystemError::IoError(std::format!("Cannot canonicalize sandbox root {:?}: {}", sandbox_root, e))
+        })?;
+
+        let relative = std::path::Path::new(relative_path);
+        for component in relative.components() {
+            match component {
+                std::path::Component::Normal(_) | std::path::Component::CurDir => {}
+                std::path::Component::ParentDir => {
+                    return std::result::Result::Err(FileSystemError::PathTraversal(relative_path.to_string()));
+                }
+                std::path::Component::RootDir | std::path::Component::Prefix(_) => {
+                    return std::result::Result::Err(FileSystemError::PathEscape(std::format!(
+                        "{} (absolute paths are not allowed)",
+                        relative_path
+                    )));
+                }
+            }
+        }
+        if relative.file_name().is_none() {
+            return std::result::Result::Err(FileSystemError::InvalidPath(std::format!("Invalid filename: {}", relative_path)));
+        }
+
+        let target = self.project_root.join(relative);
+        let escape = |resolved: &std::path::Path| {
+            FileSystemError::PathEscape(std::format!(
+                "{} (resolved to {:?}, sandbox: {:?})",
+                relative_path, resolved, canonical_sandbox
+            ))
+        };
+
+        // A symlink at the target is followed by the write, so its destination must be inside too
+        let is_symlink = std::fs::symlink_metadata(&target).is_ok_and(|metadata| metadata.file_type().is_symlink());
+        if is_symlink {
+            let resolved = target.canonicalize().map_err(|_| {
+                FileSystemError::PathEscape(std::format!("{} (dangling symlink)", relative_path))
+            })?;
+            if !resolved.starts_with(&canonical_sandbox) {
+                return std::result::Result::Err(escape(&resolved));
+            }
+            return std::result::Result::Ok(resolved);
+        }
+
+        // Resolve the deepest existing ancestor; the missing rest is plain names created under it
+        let mut existing = target.as_path();
+        while !existing.exists() {
+            existing = existing.parent().ok_or_else(|| {
+                FileSystemError::InvalidPath(std::format!("Cannot resolve path: {}", relative_path))
+            })?;
+        }
+        let canonical_existing = existing.canonicalize().map_err(|e| {
+            FileSystemError::IoError(std::format!("Cannot canonicalize {:?}: {}", existing, e))
+        })?;
+        if !canonical_existing.starts_with(&canonical_sandbox) {
+            return std::result::Result::Err(escape(&canonical_existing));
+        }
+        let missing = target.strip_prefix(existing).unwrap_or(std::path::Path::new(""));
+
+        std::result::Result::Ok(canonical_existing.join(missing))
+    }
+
     /// Reads a file within the project.
     ///
     /// # Arguments
@@ -163,7 +328,9 @@ impl FileSystemTool {
         }
     }
 
-    /// Writes content to a file within the project.
+    /// Writes content to a file within the sandbox root.
+    ///
+    /// Missing parent directories are created.
     ///
     /// # Arguments
     ///
@@ -173,9 +340,9 @@ impl FileSystemTool {
     /// # Returns
     ///
     /// * `Ok(())` - File written successfully
-    /// * `Err(String)` - Security violation or I/O error
+    /// * `Err(FileSystemError)` - No sandbox root, path escapes the sandbox, or I/O error
     pub async fn write_file(&self, path: &str, content: &str) -> std::result::Result<(), FileSystemError> {
-        let validated_path = self.validate_path(path)?;
+        let validated_path = self.validate_write_path(path)?;
 
         // Create parent directories if needed
         if let std::option::Option::Some(parent) = validated_path.parent() {
@@ -218,6 +385,136 @@ impl FileSystemTool {
 
         std::result::Result::Ok(filenames)
     }
+
+    /// Lists the entries of a directory within the project.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Relative path to directory from project root
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PathListing)` - Sorted, capped paths relative to the project root
+    /// * `Err(FileSystemError)` - Security violation or I/O error
+    pub async fn list_dir(&self, path: &str) -> std::result::Result<PathListing, FileSystemError> {
+        let dir = self.validate_path(path)?;
+        let root = self.canonical_root()?;
+        let options = self.list_options;
+
+        let paths = tokio::task::spawn_blocking(move || {
+            let mut paths = std::vec::Vec::new();
+            for (entry_path, is_dir) in visible_entries(&dir, &options)? {
+                paths.push(display_path(&root, &entry_path, is_dir));
+            }
+            std::result::Result::Ok(paths)
+        })
+        .await
+        .map_err(|e| FileSystemError::IoError(std::format!("List task failed: {}", e)))??;
+
+        std::result::Result::Ok(PathListing::from_unsorted(paths, options.max_results))
+    }
+
+    /// Finds files within the project matching a glob pattern.
+    ///
+    /// `*` does not cross directory separators; use `**` to match any depth.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - Glob relative to project root (e.g. `src/**/*.rs`)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PathListing)` - Sorted, capped matching paths relative to the project root
+    /// * `Err(FileSystemError)` - Invalid or escaping pattern, or I/O error
+    pub async fn glob(&self, pattern: &str) -> std::result::Result<PathListing, FileSystemError> {
+        for component in std::path::Path::new(pattern).components() {
+            match component {
+                std::path::Component::ParentDir => {
+                    return std::result::Result::Err(FileSystemError::PathTraversal(pattern.to_string()));
+                }
+                std::path::Component::RootDir | std::path::Component::Prefix(_) => {
+                    return std::result::Result::Err(FileSystemError::PathEscape(std::format!(
+                        "{} (absolute patterns are not allowed)",
+                        pattern
+                    )));
+                }
+                std::path::Component::Normal(_) | std::path::Component::CurDir => {}
+            }
+        }
+        let matcher = glob::Pattern::new(pattern.trim_start_matches("./"))
+            .map_err(|e| FileSystemError::InvalidPath(std::format!("Invalid glob pattern {}: {}", pattern, e)))?;
+        let root = self.canonical_root()?;
+        let options = self.list_options;
+
+        let paths = tokio::task::spawn_blocking(move || {
+            let match_options = glob::MatchOptions {
+                case_sensitive: true,
+                require_literal_separator: true,
+                require_literal_leading_dot: false,
+            };
+            let mut paths = std::vec::Vec::new();
+            let mut pending = std::vec![root.clone()];
+            while let std::option::Option::Some(dir) = pending.pop() {
+                for (entry_path, is_dir) in visible_entries(&dir, &options)? {
+                    let relative = entry_path.strip_prefix(&root).unwrap_or(&entry_path);
+                    if matcher.matches_path_with(relative, match_options) {
+                        paths.push(display_path(&root, &entry_path, is_dir));
+                    }
+                    if is_dir {
+                        pending.push(entry_path);
+                    }
+                }
+            }
+            std::result::Result::Ok(paths)
+        })
+        .await
+        .map_err(|e| FileSystemError::IoError(std::format!("Glob task failed: {}", e)))??;
+
+        std::result::Result::Ok(PathListing::from_unsorted(paths, options.max_results))
+    }
+
+    fn canonical_root(&self) -> std::result::Result<std::path::PathBuf, FileSystemError> {
+        self.project_root
+            .canonicalize()
+            .map_err(|e| FileSystemError::IoError(std::format!("Cannot canonicalize project root: {}", e)))
+    }
+}
+
+/// Reads `dir`, dropping excluded entries.
+///
+/// Returns each entry's path and whether it is a real (not symlinked) directory.
+fn visible_entries(
+    dir: &std::path::Path,
+    options: &ListOptions,
+) -> std::result::Result<std::vec::Vec<(std::path::PathBuf, bool)>, FileSystemError> {
+    let entries = std::fs::read_dir(dir).map_err(|e| FileSystemError::IoError(std::format!("Read dir error: {}", e)))?;
+    let mut visible = std::vec::Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|e| FileSystemError::IoError(std::format!("Read dir error: {}", e)))?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        let excluded = name.starts_with('.') || IGNORED_DIRS.contains(&name.as_ref());
+        if excluded && !options.include_hidden {
+            continue;
+        }
+        let is_dir = entry.file_type().map(|file_type| file_type.is_dir()).unwrap_or(false);
+        visible.push((entry.path(), is_dir));
+    }
+    std::result::Result::Ok(visible)
+}
+
+/// Formats `path` relative to `root` with `/` separators, adding a trailing `/` to directories.
+fn display_path(root: &std::path::Path, path: &std::path::Path, is_dir: bool) -> std::string::String {
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    let mut display = relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().into_owned())
+        .collect::<std::vec::Vec<_>>()
+        .join("/");
+    if is_dir {
+        display.push('/');
+    }
+    display
 }
 
 // Rig Tool trait implementations for each operation
@@ -294,10 +591,18 @@ pub struct WriteFileTool {
 }
 
 impl WriteFileTool {
-    /// Creates a new WriteFileTool.
-    pub fn new(project_root: impl std::convert::AsRef<std::path::Path>) -> Self {
+    /// Creates a new WriteFileTool whose writes are confined to `sandbox_root`.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_root` - Directory write paths are relative to
+    /// * `sandbox_root` - Directory all writes must stay within
+    pub fn new(
+        project_root: impl std::convert::AsRef<std::path::Path>,
+        sandbox_root: impl std::convert::AsRef<std::path::Path>,
+    ) -> Self {
         Self {
-            fs: FileSystemTool::new(project_root),
+            fs: FileSystemTool::new(project_root).with_sandbox_root(sandbox_root),
         }
     }
 }
@@ -313,7 +618,7 @@ impl rig::tool::Tool for WriteFileTool {
         async {
             rig::completion::ToolDefinition {
                 name: Self::NAME.to_string(),
-                description: "Writes content to a file in the project directory. Path must be relative to project root. Creates parent directories if needed.".to_string(),
+                description: "Writes content to a file in the project's writable sandbox directory. Path must be relative to project root and stay inside the sandbox. Creates parent directories if needed.".to_string(),
                 parameters: serde_json::json!({
                     "type": "object",
                     "properties": {
@@ -368,13 +673,13 @@ impl rig::tool::Tool for ListDirectoryTool {
 
     type Error = FileSystemError;
     type Args = ListDirectoryArgs;
-    type Output = std::vec::Vec<std::string::String>;
+    type Output = PathListing;
 
     fn definition(&self, _prompt: std::string::String) -> impl std::future::Future<Output = rig::completion::ToolDefinition> + Send + Sync {
         async {
             rig::completion::ToolDefinition {
                 name: Self::NAME.to_string(),
-                description: "Lists files and directories in a project directory. Path must be relative to project root. Returns array of filenames.".to_string(),
+                description: "Lists files and directories in a project directory. Path must be relative to project root. Returns sorted paths relative to project root (directories end with '/'), capped in number; `truncated` is true if entries were left out. Hidden files, .git and target are skipped.".to_string(),
                 parameters: serde_json::json!({
                     "type": "object",
                     "properties": {
@@ -392,7 +697,63 @@ impl rig::tool::Tool for ListDirectoryTool {
     fn call(&self, args: Self::Args) -> impl std::future::Future<Output = std::result::Result<Self::Output, Self::Error>> + Send {
         let fs = self.fs.clone();
         async move {
-            fs.list_directory(&args.path).await
+            fs.list_dir(&args.path).await
+        }
+    }
+}
+
+/// Arguments for glob tool.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct GlobArgs {
+    /// Glob pattern relative to project root
+    pub pattern: std::string::String,
+}
+
+/// Glob tool for Rig agents.
+#[derive(Debug, Clone)]
+pub struct GlobTool {
+    fs: FileSystemTool,
+}
+
+impl GlobTool {
+    /// Creates a new GlobTool.
+    pub fn new(project_root: impl std::convert::AsRef<std::path::Path>) -> Self {
+        Self {
+            fs: FileSystemTool::new(project_root),
+        }
+    }
+}
+
+impl rig::tool::Tool for GlobTool {
+    const NAME: &'static str = "glob";
+
+    type Error = FileSystemError;
+    type Args = GlobArgs;
+    type Output = PathListing;
+
+    fn definition(&self, _prompt: std::string::String) -> impl std::future::Future<Output = rig::completion::ToolDefinition> + Send + Sync {
+        async {
+            rig::completion::ToolDefinition {
+                name: Self::NAME.to_string(),
+                description: "Finds project files and directories matching a glob pattern relative to project root. '*' stays within one directory, '**' matches any depth. Returns sorted paths, capped in number; `truncated` is true if matches were left out. Hidden files, .git and target are skipped.".to_string(),
+                parameters: serde_json::json!({
+                    "type": "object",
+                    "properties": {
+                        "pattern": {
+                            "type": "string",
+                            "description": "Glob pattern relative to project root (e.g., 'src/**/*.rs' or '*.toml')"
+                        }
+                    },
+                    "required": ["pattern"]
+                }),
+            }
+        }
+    }
+
+    fn call(&self, args: Self::Args) -> impl std::future::Future<Output = std::result::Result<Self::Output, Self::Error>> + Send {
+        let fs = self.fs.clone();
+        async move {
+            fs.glob(&args.pattern).await
         }
     }
 }
@@ -423,7 +784,7 @@ mod tests {
         let temp_dir = std::env::temp_dir().join(std::format!("fs_tool_test_{}", uuid::Uuid::new_v4()));
         std::fs::create_dir(&temp_dir).unwrap();
 
-        let tool = FileSystemTool::new(&temp_dir);
+        let tool = FileSystemTool::new(&temp_dir).with_sandbox_root(&temp_dir);
 
         // Write a file
         let write_result = tool.write_file("test.txt", "Hello, Rig!").await;
@@ -443,7 +804,7 @@ mod tests {
         let temp_dir = std::env::temp_dir().join(std::format!("fs_tool_list_test_{}", uuid::Uuid::new_v4()));
         std::fs::create_dir(&temp_dir).unwrap();
 
-        let tool = FileSystemTool::new(&temp_dir);
+        let tool = FileSystemTool::new(&temp_dir).with_sandbox_root(&temp_dir);
 
         // Create some files
         tool.write_file("file1.txt", "content1").await.unwrap();
@@ -484,7 +845,7 @@ mod tests {
         std::fs::create_dir(&temp_dir).unwrap();
 
         // Setup test file
-        let fs = FileSystemTool::new(&temp_dir);
+        let fs = FileSystemTool::new(&temp_dir).with_sandbox_root(&temp_dir);
         fs.write_file("sample.txt", "Rig tool content").await.unwrap();
 
         // Test via Rig Tool trait
@@ -500,4 +861,153 @@ mod tests {
         // Cleanup
         std::fs::remove_dir_all(&temp_dir).unwrap();
     }
+
+    /// Creates `<tmp>/<name>/{project/sandbox, outside}` and returns (project, sandbox, outside).
+    fn sandbox_layout(name: &str) -> (std::path::PathBuf, std::path::PathBuf, std::path::PathBuf) {
+        let base = std::env::temp_dir().join(std::format!("{}_{}", name, uuid::Uuid::new_v4()));
+        let project = base.join("project");
+        let sandbox = project.join("sandbox");
+        let outside = base.join("outside");
+        std::fs::create_dir_all(&sandbox).unwrap();
+        std::fs::create_dir_all(&outside).unwrap();
+        (project, sandbox, outside)
+    }
+
+    #[tokio::test]
+    async fn test_write_inside_sandbox_creates_file() {
+        // Test: Validates a write into a new nested directory of the sandbox succeeds and a write without a sandbox is refused.
+        // Justification: Scaffolding writes must work, but only when a sandbox was configured.
+        let (project, sandbox, _) = sandbox_layout("fs_tool_sandbox_write");
+        let tool = FileSystemTool::new(&project).with_sandbox_root(&sandbox);
+
+        tool.write_file("sandbox/src/generated/lib.rs", "pub fn hello() {}").await.unwrap();
+
+        std::assert_eq!(std::fs::read_to_string(sandbox.join("src/generated/lib.rs")).unwrap(), "pub fn hello() {}");
+        let unsandboxed = FileSystemTool::new(&project).write_file("sandbox/other.rs", "x").await;
+        std::assert!(std::matches!(unsandboxed, std::result::Result::Err(FileSystemError::WriteDisabled(_))));
+        let outside_sandbox = tool.write_file("README.md", "x").await;
+        std::assert!(std::matches!(outside_sandbox, std::result::Result::Err(FileSystemError::PathEscape(_))));
+        std::assert!(!project.join("README.md").exists());
+
+        std::fs::remove_dir_all(project.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_write_rejects_parent_dir_traversal() {
+        // Test: Validates `..` components and absolute paths are rejected rather than clamped into the sandbox.
+        // Justification: A traversal that silently lands elsewhere could still overwrite project files.
+        let (project, sandbox, outside) = sandbox_layout("fs_tool_sandbox_traversal");
+        let tool = FileSystemTool::new(&project).with_sandbox_root(&sandbox);
+
+        let traversal = tool.write_file("sandbox/../../outside/pwned.txt", "x").await;
+        let absolute = tool.write_file(outside.join("pwned.txt").to_str().unwrap(), "x").await;
+
+        std::assert!(std::matches!(traversal, std::result::Result::Err(FileSystemError::PathTraversal(_))));
+        std::assert!(std::matches!(absolute, std::result::Result::Err(FileSystemError::PathEscape(_))));
+        std::assert!(!outside.join("pwned.txt").exists());
+
+        std::fs::remove_dir_all(project.parent().unwrap()).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_write_rejects_symlink_escape() {
+        // Test: Validates writes through a symlinked directory, a symlinked file, and a dangling symlink pointing outside are blocked.
+        // Justification: Canonicalizing only the parent would let a dangling symlink redirect the write outside the sandbox.
+        let (project, sandbox, outside) = sandbox_layout("fs_tool_sandbox_symlink");
+        std::fs::write(outside.join("existing.txt"), "original").unwrap();
+        std::os::unix::fs::symlink(&outside, sandbox.join("linked_dir")).unwrap();
+        std::os::unix::fs::symlink(outside.join("existing.txt"), sandbox.join("linked_file.txt")).unwrap();
+        std::os::unix::fs::symlink(outside.join("created.txt"), sandbox.join("dangling.txt")).unwrap();
+        let tool = FileSystemTool::new(&project).with_sandbox_root(&sandbox);
+
+        for path in ["sandbox/linked_dir/pwned.txt", "sandbox/linked_file.txt", "sandbox/dangling.txt"] {
+            let result = tool.write_file(path, "pwned").await;
+            std::assert!(
+                std::matches!(result, std::result::Result::Err(FileSystemError::PathEscape(_))),
+                "{} should be blocked, got {:?}",
+                path,
+                result
+            );
+        }
+
+        std::assert!(!outside.join("pwned.txt").exists());
+        std::assert!(!outside.join("created.txt").exists());
+        std::assert_eq!(std::fs::read_to_string(outside.join("existing.txt")).unwrap(), "original");
+
+        std::fs::remove_dir_all(project.parent().unwrap()).unwrap();
+    }
+
+    /// Creates a fixture tree with hidden files, `.git`, `target`, and nested sources.
+    fn listing_fixture(name: &str) -> std::path::PathBuf {
+        let root = std::env::temp_dir().join(std::format!("{}_{}", name, uuid::Uuid::new_v4()));
+        for (path, content) in [
+            ("b.txt", "b"),
+            ("a.txt", "a"),
+            (".env", "SECRET=1"),
+            (".git/config", "[core]"),
+            ("target/debug/build.rs", "fn main() {}"),
+            ("src/main.rs", "fn main() {}"),
+            ("src/lib.rs", "pub mod nested;"),
+            ("src/nested/deep.rs", "pub fn deep() {}"),
+        ] {
+            let file = root.join(path);
+            std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+            std::fs::write(file, content).unwrap();
+        }
+        root
+    }
+
+    #[tokio::test]
+    async fn test_list_dir_sorts_and_excludes_hidden_by_default() {
+        // Test: Validates list_dir returns sorted root-relative paths, skipping hidden entries, .git and target unless asked.
+        // Justification: Agents exploring a repo should not see VCS internals or build output, and must get stable output.
+        let root = listing_fixture("fs_tool_list_dir");
+        let tool = FileSystemTool::new(&root);
+
+        std::assert_eq!(tool.list_dir(".").await.unwrap().paths, std::vec!["a.txt", "b.txt", "src/"]);
+        std::assert_eq!(tool.list_dir("src").await.unwrap().paths, std::vec!["src/lib.rs", "src/main.rs", "src/nested/"]);
+
+        let all = tool
+            .with_list_options(ListOptions { include_hidden: true, ..ListOptions::default() })
+            .list_dir(".")
+            .await
+            .unwrap();
+        std::assert_eq!(all.paths, std::vec![".env", ".git/", "a.txt", "b.txt", "src/", "target/"]);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_glob_matches_recursively_and_excludes_ignored_dirs() {
+        // Test: Validates `**` globbing returns sorted matches outside target, and that traversal patterns are rejected.
+        // Justification: A glob must neither surface ignored build files nor reach outside the project root.
+        let root = listing_fixture("fs_tool_glob");
+        let tool = FileSystemTool::new(&root);
+
+        let rust_files = tool.glob("**/*.rs").await.unwrap();
+        std::assert_eq!(rust_files.paths, std::vec!["src/lib.rs", "src/main.rs", "src/nested/deep.rs"]);
+        std::assert!(!rust_files.truncated);
+        std::assert_eq!(tool.glob("*.txt").await.unwrap().paths, std::vec!["a.txt", "b.txt"]);
+        std::assert!(std::matches!(tool.glob("../**/*").await, std::result::Result::Err(FileSystemError::PathTraversal(_))));
+        std::assert!(std::matches!(tool.glob("/etc/*").await, std::result::Result::Err(FileSystemError::PathEscape(_))));
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_list_dir_and_glob_cap_results() {
+        // Test: Validates results are cut to max_results after sorting and flagged as truncated.
+        // Justification: Large trees must not flood the model context, and the cut must be deterministic.
+        let root = listing_fixture("fs_tool_cap");
+        let tool = FileSystemTool::new(&root).with_list_options(ListOptions { max_results: 2, ..ListOptions::default() });
+
+        let listing = tool.list_dir(".").await.unwrap();
+        std::assert_eq!(listing, PathListing { paths: std::vec!["a.txt".to_string(), "b.txt".to_string()], truncated: true });
+        let globbed = tool.glob("src/**/*.rs").await.unwrap();
+        std::assert_eq!(globbed.paths, std::vec!["src/lib.rs", "src/main.rs"]);
+        std::assert!(globbed.truncated);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
 }
@@ -3,7 +3,13 @@
 //! GetPRDSummaryTool allows Rig agents to fetch PRD objectives, tech stack,
 //! and constraints. This enables agents to understand project context and requirements.
 //!
+//! The summary is formatted directly from the parsed PRD fields on every
+//! call; no LLM is involved, so there is no generated summary to cache and
+//! edits to the PRD are reflected immediately.
+//!
 //! Revision History
+//! - 2026-10-16T22:50:00Z @AI: Document that summaries are formatted without an LLM call (nothing to cache); test that edits show up immediately.
+//! - 2026-10-16T11:30:00Z @AI: Add requirements field to test PRD fixture.
 //! - 2025-12-03T00:00:00Z @AI: Create GetPRDSummaryTool for LLM agent PRD inspection.
 
 /// Error type for PRD summary operations.
@@ -208,6 +214,7 @@ mod tests {
                 std::string::String::from("Must be secure"),
             ],
             raw_content: std::string::String::new(),
+            requirements: std::vec::Vec::new(),
             created_at: chrono::Utc::now(),
         }];
 
@@ -238,4 +245,32 @@ mod tests {
         std::assert!(result.is_err());
         std::assert!(result.unwrap_err().to_string().contains("No PRDs available"));
     }
+
+    #[tokio::test]
+    async fn test_get_prd_summary_reflects_edits_without_caching() {
+        // Test: Validates repeated calls return the same summary and an edited PRD is summarized from its new fields.
+        // Justification: Summaries are built from PRD fields, not an LLM, so they must never go stale.
+        let prds = std::sync::Arc::new(std::sync::Mutex::new(std::vec![task_manager::domain::prd::PRD {
+            id: std::string::String::from("prd-1"),
+            project_id: std::string::String::from("proj-1"),
+            title: std::string::String::from("Test PRD"),
+            objectives: std::vec![std::string::String::from("Build authentication")],
+            tech_stack: std::vec::Vec::new(),
+            constraints: std::vec::Vec::new(),
+            raw_content: std::string::String::new(),
+            requirements: std::vec::Vec::new(),
+            created_at: chrono::Utc::now(),
+        }]));
+        let tool = GetPRDSummaryTool::new(prds.clone());
+
+        let first = tool.get_summary(std::option::Option::None).await.unwrap();
+        let second = tool.get_summary(std::option::Option::None).await.unwrap();
+        std::assert_eq!(first, second);
+
+        prds.lock().unwrap()[0].objectives = std::vec![std::string::String::from("Add SSO login")];
+        let edited = tool.get_summary(std::option::Option::None).await.unwrap();
+
+        std::assert!(edited.contains("Add SSO login"));
+        std::assert!(!edited.contains("Build authentication"));
+    }
 }
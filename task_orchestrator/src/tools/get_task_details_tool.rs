@@ -321,6 +321,17 @@ mod tests {
             context_files: std::vec::Vec::new(),
             dependencies: std::vec![std::string::String::from("task-123")],
             sort_order: std::option::Option::None,
+            source_section: std::option::Option::None,
+            priority: std::option::Option::None,
+            tags: std::vec::Vec::new(),
+            revisions: std::vec::Vec::new(),
+            checklist: std::vec::Vec::new(),
+            started_at: std::option::Option::None,
+            time_spent_seconds: 0,
+            source_utterance: std::option::Option::None,
+            idempotency_key: std::option::Option::None,
+            links: std::vec::Vec::new(),
+            estimate: std::option::Option::None,
         }).unwrap();
 
         let tool = GetTaskDetailsTool::new(
@@ -365,6 +376,17 @@ mod tests {
             context_files: std::vec::Vec::new(),
             dependencies: std::vec::Vec::new(),
             sort_order: std::option::Option::None,
+            source_section: std::option::Option::None,
+            priority: std::option::Option::None,
+            tags: std::vec::Vec::new(),
+            revisions: std::vec::Vec::new(),
+            checklist: std::vec::Vec::new(),
+            started_at: std::option::Option::None,
+            time_spent_seconds: 0,
+            source_utterance: std::option::Option::None,
+            idempotency_key: std::option::Option::None,
+            links: std::vec::Vec::new(),
+            estimate: std::option::Option::None,
         }).unwrap();
 
         let tool = GetTaskDetailsTool::new(
@@ -0,0 +1,228 @@
+//! Per-conversation call limits for agent tools.
+//!
+//! `ToolCallBudget` counts tool calls within one conversation against the
+//! limits configured on a persona. `LimitedTool` wraps any Rig tool: while the
+//! budget allows the call it runs the inner tool, otherwise it skips the tool
+//! and returns a "limit reached" result the model can read and react to.
+//! Counts are shared between clones of a budget and cleared by `reset` at
+//! the start of each conversation.
+//!
+//! Revision History
+//! - 2026-10-17T00:10:00Z @AI: Initial ToolCallBudget and LimitedTool.
+
+#[derive(Debug, Default)]
+struct BudgetState {
+    limits: std::collections::HashMap<std::string::String, u32>,
+    calls: std::collections::HashMap<std::string::String, u32>,
+}
+
+/// Shared per-conversation tool call counter with per-tool limits.
+///
+/// Tools without a limit are never refused.
+///
+/// # Examples
+///
+/// ```
+/// use task_orchestrator::tools::limited_tool::ToolCallBudget;
+///
+/// let budget = ToolCallBudget::new(std::collections::HashMap::from([(std::string::String::from("web_search"), 1)]));
+/// std::assert!(budget.try_acquire("web_search"));
+/// std::assert!(!budget.try_acquire("web_search"));
+/// std::assert!(budget.try_acquire("search_tasks"));
+///
+/// budget.reset();
+/// std::assert!(budget.try_acquire("web_search"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ToolCallBudget {
+    state: std::sync::Arc<std::sync::Mutex<BudgetState>>,
+}
+
+impl ToolCallBudget {
+    /// Creates a budget with the given per-tool limits and no calls recorded.
+    ///
+    /// # Arguments
+    ///
+    /// * `limits` - Maximum calls per conversation, keyed by tool name
+    pub fn new(limits: std::collections::HashMap<std::string::String, u32>) -> Self {
+        let budget = Self::default();
+        budget.set_limits(limits);
+        budget
+    }
+
+    /// Replaces the limits and clears the recorded calls.
+    pub fn set_limits(&self, limits: std::collections::HashMap<std::string::String, u32>) {
+        let mut state = self.lock();
+        state.limits = limits;
+        state.calls.clear();
+    }
+
+    /// Clears the recorded calls, starting a new conversation.
+    pub fn reset(&self) {
+        self.lock().calls.clear();
+    }
+
+    /// Records a call to `tool_name` if its limit allows one more.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the call may run, `false` if the limit is already reached.
+    pub fn try_acquire(&self, tool_name: &str) -> bool {
+        let mut state = self.lock();
+        let limit = state.limits.get(tool_name).copied();
+        let calls = state.calls.entry(std::string::String::from(tool_name)).or_insert(0);
+        if limit.is_some_and(|limit| *calls >= limit) {
+            return false;
+        }
+        *calls += 1;
+        true
+    }
+
+    /// Returns the limit configured for `tool_name`, if any.
+    pub fn limit(&self, tool_name: &str) -> std::option::Option<u32> {
+        self.lock().limits.get(tool_name).copied()
+    }
+
+    /// Returns how many calls to `tool_name` ran in this conversation.
+    pub fn calls(&self, tool_name: &str) -> u32 {
+        self.lock().calls.get(tool_name).copied().unwrap_or(0)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BudgetState> {
+        self.state.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// Result of a limited tool call.
+///
+/// Serialized untagged, so a completed call looks exactly like the inner
+/// tool's output and a refused call like `{"limit_reached": "..."}`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(untagged)]
+pub enum LimitedOutput<O> {
+    /// The inner tool ran and returned this output
+    Completed(O),
+
+    /// The tool was not run because its call limit was reached
+    LimitReached {
+        /// Message telling the model the tool is unavailable
+        limit_reached: std::string::String,
+    },
+}
+
+/// Rig tool wrapper that enforces a `ToolCallBudget`.
+#[derive(Debug, Clone)]
+pub struct LimitedTool<T> {
+    inner: T,
+    budget: ToolCallBudget,
+}
+
+impl<T: rig::tool::Tool> LimitedTool<T> {
+    /// Wraps `inner` so its calls count against `budget`.
+    pub fn new(inner: T, budget: ToolCallBudget) -> Self {
+        Self { inner, budget }
+    }
+}
+
+impl<T: rig::tool::Tool> rig::tool::Tool for LimitedTool<T> {
+    const NAME: &'static str = T::NAME;
+
+    type Error = T::Error;
+    type Args = T::Args;
+    type Output = LimitedOutput<T::Output>;
+
+    fn definition(&self, prompt: std::string::String) -> impl std::future::Future<Output = rig::completion::ToolDefinition> + Send + Sync {
+        self.inner.definition(prompt)
+    }
+
+    fn call(&self, args: Self::Args) -> impl std::future::Future<Output = std::result::Result<Self::Output, Self::Error>> + Send + Sync {
+        let permitted = self.budget.try_acquire(T::NAME);
+        let limit = self.budget.limit(T::NAME).unwrap_or(0);
+        let inner_call = if permitted {
+            std::option::Option::Some(self.inner.call(args))
+        } else {
+            tracing::info!(tool = T::NAME, limit, "tool call limit reached; not executing");
+            std::option::Option::None
+        };
+        async move {
+            match inner_call {
+                std::option::Option::Some(call) => call.await.map(LimitedOutput::Completed),
+                std::option::Option::None => std::result::Result::Ok(LimitedOutput::LimitReached {
+                    limit_reached: std::format!(
+                        "The {} tool has reached its limit of {} call(s) for this conversation and was not run. Continue without it.",
+                        T::NAME,
+                        limit
+                    ),
+                }),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    /// Test tool that counts how often it actually executes.
+    #[derive(Debug, Clone, Default)]
+    struct CountingTool<const WEB: bool> {
+        executions: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    impl<const WEB: bool> rig::tool::Tool for CountingTool<WEB> {
+        const NAME: &'static str = if WEB { "web_search" } else { "search_tasks" };
+
+        type Error = std::io::Error;
+        type Args = serde_json::Value;
+        type Output = usize;
+
+        async fn definition(&self, _prompt: std::string::String) -> rig::completion::ToolDefinition {
+            rig::completion::ToolDefinition {
+                name: Self::NAME.to_string(),
+                description: std::string::String::from("test tool"),
+                parameters: serde_json::json!({}),
+            }
+        }
+
+        async fn call(&self, _args: Self::Args) -> std::result::Result<Self::Output, Self::Error> {
+            std::result::Result::Ok(self.executions.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tool_stops_executing_after_limit() {
+        // Test: Validates a limited tool runs up to its limit, then returns a limit-reached result without executing, until the budget is reset.
+        // Justification: Expensive tools must not run past the persona's cap, but the cap applies per conversation.
+        let budget = super::ToolCallBudget::new(std::collections::HashMap::from([(std::string::String::from("web_search"), 2)]));
+        let web = CountingTool::<true>::default();
+        let limited = super::LimitedTool::new(web.clone(), budget.clone());
+
+        std::assert_eq!(rig::tool::Tool::call(&limited, serde_json::json!({})).await.unwrap(), super::LimitedOutput::Completed(1));
+        std::assert_eq!(rig::tool::Tool::call(&limited, serde_json::json!({})).await.unwrap(), super::LimitedOutput::Completed(2));
+        let refused = rig::tool::Tool::call(&limited, serde_json::json!({})).await.unwrap();
+
+        std::assert!(std::matches!(&refused, super::LimitedOutput::LimitReached { limit_reached } if limit_reached.contains("limit of 2")));
+        std::assert!(serde_json::to_string(&refused).unwrap().starts_with("{\"limit_reached\":"));
+        std::assert_eq!(web.executions.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+        budget.reset();
+        std::assert_eq!(rig::tool::Tool::call(&limited, serde_json::json!({})).await.unwrap(), super::LimitedOutput::Completed(3));
+    }
+
+    #[tokio::test]
+    async fn test_other_tools_remain_available_after_limit() {
+        // Test: Validates exhausting one tool's limit does not affect tools without a limit sharing the same budget.
+        // Justification: A capped web search must not take the rest of the agent's tools down with it.
+        let budget = super::ToolCallBudget::new(std::collections::HashMap::from([(std::string::String::from("web_search"), 1)]));
+        let web = super::LimitedTool::new(CountingTool::<true>::default(), budget.clone());
+        let tasks = super::LimitedTool::new(CountingTool::<false>::default(), budget.clone());
+
+        std::assert_eq!(rig::tool::Tool::call(&web, serde_json::json!({})).await.unwrap(), super::LimitedOutput::Completed(1));
+        std::assert!(std::matches!(
+            rig::tool::Tool::call(&web, serde_json::json!({})).await.unwrap(),
+            super::LimitedOutput::LimitReached { .. }
+        ));
+        for expected in 1..=5 {
+            std::assert_eq!(rig::tool::Tool::call(&tasks, serde_json::json!({})).await.unwrap(), super::LimitedOutput::Completed(expected));
+        }
+        std::assert_eq!(budget.calls("search_tasks"), 5);
+    }
+}
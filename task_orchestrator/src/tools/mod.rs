@@ -5,6 +5,7 @@
 //! artifacts semantically, and access project knowledge.
 //!
 //! Revision History
+//! - 2026-10-17T00:10:00Z @AI: Add limited_tool module for per-conversation tool call limits.
 //! - 2025-12-05T00:00:00Z @AI: Export all Rig tools for LLM agent integration (fixed module names).
 //! - 2025-12-04T00:00:00Z @AI: Initial tools module for LLM agent tool calling support.
 
@@ -14,6 +15,7 @@ pub mod get_task_details_tool;
 pub mod file_system_tool;
 pub mod get_prd_summary_tool;
 pub mod list_project_artifacts_tool;
+pub mod limited_tool;
 
 pub use search_artifacts_tool::SearchArtifactsTool;
 pub use search_tasks_tool::SearchTasksTool;
@@ -21,3 +23,4 @@ pub use get_task_details_tool::GetTaskDetailsTool;
 pub use file_system_tool::FileSystemTool;
 pub use get_prd_summary_tool::GetPRDSummaryTool;
 pub use list_project_artifacts_tool::ListProjectArtifactsTool;
+pub use limited_tool::{LimitedTool, ToolCallBudget};
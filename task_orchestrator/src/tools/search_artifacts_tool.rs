@@ -5,6 +5,8 @@
 //! embeddings and retrieves the most similar artifacts from the database.
 //!
 //! Revision History
+//! - 2026-10-16T22:30:00Z @AI: Set embedding_model/embedding_dimension on the test artifact.
+//! - 2026-10-16T14:50:00Z @AI: Include a citation (path + line range or URL + page) for each result so agents can attribute claims.
 //! - 2025-11-30T11:35:00Z @AI: Add missing binary_content fields for Phase 5 Artifact extension compatibility.
 //! - 2025-11-28T21:30:00Z @AI: Fix Sync requirement using tokio::spawn for Rig Tool trait compatibility (Task 5.1).
 //! - 2025-11-28T21:15:00Z @AI: Create SearchArtifactsTool for Phase 5 RAG retrieval (Task 5.1).
@@ -170,10 +172,11 @@ impl SearchArtifactsTool {
             let similarity = 1.0 - distance; // Convert distance to similarity score
 
             result.push_str(&std::format!(
-                "{}. [Similarity: {:.2}%] Source: {:?}\n   {}\n\n",
+                "{}. [Similarity: {:.2}%] Source: {:?}\n   Citation: {}\n   {}\n\n",
                 i + 1,
                 similarity * 100.0,
                 artifact.source_type,
+                artifact.citation(),
                 artifact.content.chars().take(200).collect::<String>()
             ));
 
@@ -199,7 +202,7 @@ impl rig::tool::Tool for SearchArtifactsTool {
         async {
             rig::completion::ToolDefinition {
                 name: Self::NAME.to_string(),
-                description: "Searches the knowledge base for relevant information using semantic similarity. Use this to find context from PRDs, documentation, and other project artifacts.".to_string(),
+                description: "Searches the knowledge base for relevant information using semantic similarity. Use this to find context from PRDs, documentation, and other project artifacts. Each result includes a Citation; cite it when using that result.".to_string(),
                 parameters: serde_json::json!({
                     "type": "object",
                     "properties": {
@@ -329,6 +332,8 @@ mod tests {
             mime_type: std::option::Option::None,
             source_url: std::option::Option::None,
             page_number: std::option::Option::None,
+            embedding_model: std::option::Option::None,
+            embedding_dimension: std::option::Option::Some(3),
         }).unwrap();
 
         let tool = SearchArtifactsTool::new(
@@ -345,6 +350,30 @@ mod tests {
         std::assert!(output.contains("authentication requirements"));
     }
 
+    #[tokio::test]
+    async fn test_search_output_includes_citation() {
+        // Test: Validates each result carries its citation string.
+        // Justification: Agents must attribute claims to a verifiable source.
+        let mut artifact = task_manager::domain::artifact::Artifact::new(
+            String::from("proj-1"),
+            String::from("https://docs.example.com/api"),
+            task_manager::domain::artifact::ArtifactType::WebResearch,
+            String::from("Tokens expire after one hour."),
+            std::vec![0.1, 0.2, 0.3],
+            std::option::Option::Some(String::from(r#"{"anchor": "expiry"}"#)),
+        );
+        artifact.source_url = std::option::Option::Some(String::from("https://docs.example.com/api"));
+        let tool = SearchArtifactsTool::new(
+            std::sync::Arc::new(MockEmbeddingPort),
+            std::sync::Arc::new(std::sync::Mutex::new(MockArtifactRepository { artifacts: std::vec![artifact] })),
+            std::option::Option::None,
+        );
+
+        let output = tool.search("token expiry", 5, 0.5).await.unwrap();
+
+        std::assert!(output.contains("Citation: https://docs.example.com/api#expiry"));
+    }
+
     #[tokio::test]
     async fn test_search_empty_query() {
         // Test: Validates empty query is rejected.
@@ -6,6 +6,11 @@
 //! The runner is deterministic and avoids external side effects.
 //!
 //! Revision History
+//! - 2026-10-18T04:30:00Z @AI: Stop after the router on the "clarify" route, returning the task unchanged.
+//! - 2026-10-17T07:30:00Z @AI: Add with_router_config() for the router's confidence threshold and fallback route.
+//! - 2026-10-17T07:10:00Z @AI: Add with_pass_threshold() for grading agent answers in the check step.
+//! - 2026-10-16T20:50:00Z @AI: Add with_budget() to check a per-run TokenBudget before each provider call.
+//! - 2026-10-16T20:10:00Z @AI: Run each task inside a `run` span carrying the state's correlation ID.
 //! - 2025-11-14T15:44:00Z @AI: Introduce FlowRunner that executes shims sequentially with a pass/fail check.
 
 /// Executes the task orchestration using shimmed nodes.
@@ -14,6 +19,8 @@ pub struct FlowRunner {
     enhancement_port: std::sync::Arc<dyn crate::ports::task_enhancement_port::TaskEnhancementPort>,
     test_port: std::sync::Arc<dyn crate::ports::comprehension_test_port::ComprehensionTestPort>,
     test_type: String,
+    pass_threshold: f64,
+    router_config: crate::domain::router_config::RouterConfig,
 }
 
 impl FlowRunner {
@@ -23,13 +30,50 @@ impl FlowRunner {
         test_port: std::sync::Arc<dyn crate::ports::comprehension_test_port::ComprehensionTestPort>,
         test_type: String,
     ) -> Self {
-        FlowRunner { enhancement_port, test_port, test_type }
+        FlowRunner {
+            enhancement_port,
+            test_port,
+            test_type,
+            pass_threshold: crate::graph::nodes::check_test_result_node::CheckTestResultNode::DEFAULT_PASS_THRESHOLD,
+            router_config: crate::domain::router_config::RouterConfig::default(),
+        }
+    }
+
+    /// Sets the minimum grade score an agent's answer needs in the check step.
+    ///
+    /// Only applies when the state carries an answer; see CheckTestResultNode.
+    pub fn with_pass_threshold(mut self, pass_threshold: f64) -> Self {
+        self.pass_threshold = pass_threshold;
+        self
+    }
+
+    /// Sets the router's confidence threshold and fallback route.
+    pub fn with_router_config(mut self, router_config: crate::domain::router_config::RouterConfig) -> Self {
+        self.router_config = router_config;
+        self
+    }
+
+    /// Guards the enhancement and comprehension-test ports with `budget`.
+    ///
+    /// Before each provider call the budget is checked; once the next call
+    /// would exceed it, the run fails with the `BudgetExceeded` message and no
+    /// further calls are made. Tasks finished (and persisted by the caller)
+    /// earlier are unaffected. The ports' adapters must record their token
+    /// usage in the same budget.
+    pub fn with_budget(mut self, budget: std::sync::Arc<crate::domain::token_budget::TokenBudget>) -> Self {
+        self.enhancement_port = std::sync::Arc::new(crate::adapters::budget_guard_adapter::BudgetGuardAdapter::new(
+            self.enhancement_port,
+            budget.clone(),
+        ));
+        self.test_port = std::sync::Arc::new(crate::adapters::budget_guard_adapter::BudgetGuardAdapter::new(self.test_port, budget));
+        self
     }
 
     /// Runs the orchestration flow and returns the updated task.
     ///
     /// Flow:
-    /// 1) SemanticRouterTaskShim decides route ("enhance" or "decompose").
+    /// 1) SemanticRouterTaskShim decides route ("enhance", "decompose", or "clarify").
+    ///    On "clarify" the run stops here and returns the task unchanged.
     /// 2) EnhancementTaskShim generates enhancement and updates status.
     /// 3) ComprehensionTestTaskShim generates test and updates status.
     /// 4) CheckTestResultTaskShim evaluates pass/fail and may set OrchestrationComplete.
@@ -41,12 +85,24 @@ impl FlowRunner {
         &self,
         task: task_manager::domain::task::Task,
     ) -> std::result::Result<task_manager::domain::task::Task, std::string::String> {
-        // Initialize state
-        let mut state = crate::graph::state::GraphState::new(task);
+        // Initialize state; every event of this run is tagged with its correlation ID
+        let state = crate::graph::state::GraphState::new(task);
+        let span = crate::infrastructure::telemetry::run_span(&state.correlation_id);
+        task_manager::infrastructure::telemetry::timed(span, self.run_shims(state)).await
+    }
 
+    /// Executes the shim sequence for `run` inside its `run` span.
+    async fn run_shims(
+        &self,
+        mut state: crate::graph::state::GraphState,
+    ) -> std::result::Result<task_manager::domain::task::Task, std::string::String> {
         // 1) Route
-        let router = crate::graph::flow_shims::semantic_router_task_shim::SemanticRouterTaskShim::new();
+        let router = crate::graph::flow_shims::semantic_router_task_shim::SemanticRouterTaskShim::new()
+            .with_config(self.router_config.clone());
         state = crate::graph::flow_shims::semantic_router_task_shim::SemanticRouterTaskShim::run(&router, state).await?;
+        if state.routing_decision.as_deref() == std::option::Option::Some(crate::domain::router_config::CLARIFY_ROUTE) {
+            return std::result::Result::Ok(state.task);
+        }
 
         // 2) Enhance (we enhance regardless of route; decompose fallback not implemented)
         let enh = crate::graph::flow_shims::enhancement_task_shim::EnhancementTaskShim::new(self.enhancement_port.clone());
@@ -60,7 +116,7 @@ impl FlowRunner {
         state = crate::graph::flow_shims::comprehension_test_task_shim::ComprehensionTestTaskShim::run(&ct, state).await?;
 
         // 4) Check result
-        let check = crate::graph::flow_shims::check_test_result_task_shim::CheckTestResultTaskShim::new();
+        let check = crate::graph::flow_shims::check_test_result_task_shim::CheckTestResultTaskShim::new().with_pass_threshold(self.pass_threshold);
         state = crate::graph::flow_shims::check_test_result_task_shim::CheckTestResultTaskShim::run(&check, state).await?;
 
         std::result::Result::Ok(state.task)
@@ -103,6 +159,8 @@ mod tests {
                 question: std::format!("Q for {}", task.title),
                 options: std::option::Option::None,
                 correct_answer: std::string::String::from("A"),
+                description_hash: std::option::Option::None,
+                stale: false,
             };
             std::result::Result::Ok(ct)
         }
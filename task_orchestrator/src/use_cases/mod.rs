@@ -6,6 +6,7 @@
 //! that executes nodes sequentially to keep the system verifiable.
 //!
 //! Revision History
+//! - 2026-10-17T16:10:00Z @AI: Export run_plan for previewing a run without provider calls.
 //! - 2025-11-14T15:44:00Z @AI: Export FlowRunner module to back run_task_with_flow.
 //! - 2025-11-13T08:31:00Z @AI: Export run_task_with_ports helper to enable DI of ports.
 //! - 2025-11-13T21:45:00Z @AI: Export Orchestrator facade; unify API for running flows.
@@ -19,3 +20,4 @@ pub mod run_task_with_flow;
 pub mod orchestrator;
 pub mod run_task_with_ports;
 pub mod flow_runner;
+pub mod run_plan;
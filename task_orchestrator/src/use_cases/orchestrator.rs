@@ -7,6 +7,8 @@
 //! to a graph runtime transparently without changing call sites.
 //!
 //! Revision History
+//! - 2026-10-17T22:40:00Z @AI: Add with_decomposition_config, passed to the provider factory.
+//! - 2026-10-17T06:50:00Z @AI: Add with_regenerate_tests() to regenerate comprehension tests even when current.
 //! - 2025-11-23 @AI: Update Orchestrator to use ProviderFactory (Phase 1 Sprint 3 Task 1.10).
 //! - 2025-11-18T13:03:00Z @AI: Adjust constructor to take &str, add struct docs with example; no behavior change.
 //! - 2025-11-13T21:39:00Z @AI: Introduce Orchestrator facade with async run() and unit test.
@@ -35,6 +37,7 @@
 pub struct Orchestrator {
     factory: crate::adapters::provider_factory::ProviderFactory,
     test_type: String,
+    regenerate_tests: bool,
 }
 
 impl Orchestrator {
@@ -53,6 +56,7 @@ impl Orchestrator {
         std::result::Result::Ok(Self {
             factory,
             test_type,
+            regenerate_tests: false,
         })
     }
 
@@ -73,6 +77,7 @@ impl Orchestrator {
         std::result::Result::Ok(Self {
             factory,
             test_type: test_type.to_string(),
+            regenerate_tests: false,
         })
     }
 
@@ -96,11 +101,29 @@ impl Orchestrator {
         &self.factory
     }
 
+    /// Regenerates comprehension tests on every run, even when they are current.
+    ///
+    /// By default a run only generates a test when the task has none that
+    /// matches its description (see Task::needs_comprehension_test()).
+    pub fn with_regenerate_tests(mut self, regenerate_tests: bool) -> Self {
+        self.regenerate_tests = regenerate_tests;
+        self
+    }
+
+    /// Sets the decomposition threshold and depth used by the run's decomposition adapter.
+    pub fn with_decomposition_config(mut self, decomposition: crate::infrastructure::config::DecompositionConfig) -> Self {
+        self.factory = self.factory.with_decomposition_config(decomposition);
+        self
+    }
+
     /// Runs orchestration for the provided Task and returns the updated Task.
     pub async fn run(
         &self,
-        task: task_manager::domain::task::Task,
+        mut task: task_manager::domain::task::Task,
     ) -> std::result::Result<task_manager::domain::task::Task, std::string::String> {
+        if self.regenerate_tests {
+            task.mark_comprehension_tests_stale();
+        }
         crate::use_cases::run_task_with_flow::run_task_with_flow(
             &self.factory,
             self.test_type.as_str(),
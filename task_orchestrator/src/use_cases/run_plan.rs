@@ -0,0 +1,235 @@
+//! Dry-run plan of an orchestration run.
+//!
+//! `plan_run` predicts what the orchestrator will do for a task without
+//! calling a provider: it runs the semantic router node (a heuristic, so no
+//! LLM call) to pick the route, then lists the nodes on that route with what
+//! each one asks the model for and a rough token estimate. `RunPlan::cost`
+//! prices the estimate with the same `CostAccumulator` used for run cost
+//! summaries. Estimates assume a single pass (no enhance/check retry loop)
+//! and about four characters per token.
+//!
+//! Revision History
+//! - 2026-10-18T04:30:00Z @AI: Plan only the router for the "clarify" route.
+//! - 2026-10-17T16:10:00Z @AI: Initial route prediction and per-node token estimates for `rig do` previews.
+
+/// Rough characters per token for estimating prompt sizes.
+const CHARS_PER_TOKEN: usize = 4;
+
+/// One node the orchestrator is expected to run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedNode {
+    /// Node name as used in the orchestration graph
+    pub node: &'static str,
+
+    /// What the node sends to the model, or does without one
+    pub prompt: String,
+
+    /// Estimated prompt tokens; zero for nodes that make no LLM call
+    pub input_tokens: usize,
+
+    /// Estimated response tokens; zero for nodes that make no LLM call
+    pub output_tokens: usize,
+}
+
+impl PlannedNode {
+    /// Returns true if the node calls the model.
+    pub fn calls_llm(&self) -> bool {
+        self.input_tokens > 0
+    }
+}
+
+/// Predicted route, nodes, and token usage of an orchestration run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunPlan {
+    /// Route the router is expected to take ("enhance", "decompose", or "clarify")
+    pub route: String,
+
+    /// The router's explanation, e.g. "complexity 4, confidence 0.71"
+    pub reason: String,
+
+    /// Nodes in the order they are expected to run
+    pub nodes: std::vec::Vec<PlannedNode>,
+}
+
+impl RunPlan {
+    /// Returns the number of planned LLM calls.
+    pub fn llm_calls(&self) -> usize {
+        self.nodes.iter().filter(|node| node.calls_llm()).count()
+    }
+
+    /// Prices the estimated tokens of every LLM node.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - Provider the calls go to
+    /// * `model` - Model the calls go to, as keyed in `prices`
+    /// * `prices` - Model → price table, as for run cost summaries
+    pub fn cost(
+        &self,
+        provider: &str,
+        model: &str,
+        prices: std::collections::HashMap<String, crate::domain::performance_metrics::ModelPrice>,
+    ) -> crate::domain::performance_metrics::CostAccumulator {
+        let mut accumulator = crate::domain::performance_metrics::CostAccumulator::new(prices);
+        for node in self.nodes.iter().filter(|node| node.calls_llm()) {
+            let mut metrics = crate::domain::performance_metrics::InferenceMetrics::new(
+                std::string::String::from(node.node),
+                std::string::String::from(provider),
+                std::string::String::from(model),
+                std::option::Option::None,
+            );
+            metrics.record_completion(std::time::Duration::ZERO, node.input_tokens, node.output_tokens);
+            accumulator.record(&metrics);
+        }
+        accumulator
+    }
+}
+
+/// Predicts the orchestration run for `task` without calling a provider.
+///
+/// # Arguments
+///
+/// * `task` - Task to be run
+/// * `router_config` - Confidence threshold and fallback route the run will use
+///
+/// # Errors
+///
+/// Returns an error if the router node fails.
+pub async fn plan_run(
+    task: &task_manager::domain::task::Task,
+    router_config: crate::domain::router_config::RouterConfig,
+) -> std::result::Result<RunPlan, String> {
+    let router = crate::graph::nodes::semantic_router_node::SemanticRouterNode::new(
+        task_manager::domain::services::triage_service::TriageService::new(
+            task_manager::domain::services::complexity_scorer::ComplexityScorer::new(),
+        ),
+    )
+    .with_config(router_config);
+    let routed = router.execute(crate::graph::state::GraphState::new(task.clone())).await?;
+    let route = routed.routing_decision.unwrap_or_default();
+
+    // Every prompt embeds the task's title and description
+    let task_tokens = (task.title.len() + task.description.len()).div_ceil(CHARS_PER_TOKEN);
+    let mut nodes = std::vec![PlannedNode {
+        node: "semantic_router",
+        prompt: std::string::String::from("Score the task's complexity (heuristic, no LLM call)"),
+        input_tokens: 0,
+        output_tokens: 0,
+    }];
+    // On the "clarify" route the run ends after the router
+    if route == "decompose" {
+        nodes.push(PlannedNode {
+            node: "task_decomposition",
+            prompt: std::format!("Break \"{}\" into 3-5 subtasks", task.title),
+            input_tokens: 400 + task_tokens,
+            output_tokens: 800,
+        });
+    } else if route != crate::domain::router_config::CLARIFY_ROUTE {
+        nodes.push(PlannedNode {
+            node: "enhancement",
+            prompt: std::format!("Suggest one enhancement for \"{}\"", task.title),
+            input_tokens: 350 + task_tokens,
+            output_tokens: 300,
+        });
+        nodes.push(if task.needs_comprehension_test() {
+            PlannedNode {
+                node: "comprehension_test",
+                prompt: std::format!("Write a comprehension test for \"{}\"", task.title),
+                input_tokens: 200 + task_tokens,
+                output_tokens: 150,
+            }
+        } else {
+            PlannedNode {
+                node: "comprehension_test",
+                prompt: std::string::String::from("Reuse the task's current comprehension test (no LLM call)"),
+                input_tokens: 0,
+                output_tokens: 0,
+            }
+        });
+        nodes.push(PlannedNode {
+            node: "check_test_result",
+            prompt: std::string::String::from("Grade the answer to the comprehension test (no LLM call)"),
+            input_tokens: 0,
+            output_tokens: 0,
+        });
+    }
+
+    std::result::Result::Ok(RunPlan {
+        route,
+        reason: routed.routing_reason.unwrap_or_default(),
+        nodes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    fn task(title: &str) -> task_manager::domain::task::Task {
+        let ai = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from(title),
+            assignee: std::option::Option::Some(std::string::String::from("Backend Developer")),
+            due_date: std::option::Option::None,
+        };
+        task_manager::domain::task::Task::from_action_item(&ai, std::option::Option::None)
+    }
+
+    #[tokio::test]
+    async fn test_simple_task_plans_enhancement_route() {
+        // Test: Validates a simple task is planned through enhancement, comprehension test, and check, with two LLM calls.
+        // Justification: The preview must show the nodes the real run will take.
+        let plan = super::plan_run(&task("Fix typo"), crate::domain::router_config::RouterConfig::default()).await.unwrap();
+
+        std::assert_eq!(plan.route, "enhance");
+        let nodes: std::vec::Vec<&str> = plan.nodes.iter().map(|node| node.node).collect();
+        std::assert_eq!(nodes, ["semantic_router", "enhancement", "comprehension_test", "check_test_result"]);
+        std::assert_eq!(plan.llm_calls(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_complex_task_plans_decomposition_route() {
+        // Test: Validates a clearly complex task is planned through decomposition only.
+        // Justification: Decomposition replaces the enhancement path, so its cost must not include enhancement calls.
+        let title = "Refactor entire authentication system to support OAuth2 and SAML with multi-region deployment";
+        let mut complex = task(title);
+        complex.agent_persona = std::option::Option::None;
+
+        let plan = super::plan_run(&complex, crate::domain::router_config::RouterConfig::default()).await.unwrap();
+
+        std::assert_eq!(plan.route, "decompose");
+        std::assert_eq!(plan.llm_calls(), 1);
+        std::assert_eq!(plan.nodes[1].node, "task_decomposition");
+    }
+
+    #[tokio::test]
+    async fn test_clarify_route_plans_no_llm_calls() {
+        // Test: Validates a task below the threshold with a "clarify" fallback is planned through the router only.
+        // Justification: `rig do` reads the plan to stop before running a task that needs clarification.
+        let config = crate::domain::router_config::RouterConfig::new(0.5, "clarify").unwrap();
+
+        let plan = super::plan_run(&task("Fix typo"), config).await.unwrap();
+
+        std::assert_eq!(plan.route, "clarify");
+        std::assert_eq!(plan.nodes.len(), 1);
+        std::assert_eq!(plan.llm_calls(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_cost_prices_estimated_tokens() {
+        // Test: Validates the plan's estimated tokens are priced per model from the price table.
+        // Justification: The confirmation prompt shows this figure before any money is spent.
+        let plan = super::plan_run(&task("Fix typo"), crate::domain::router_config::RouterConfig::default()).await.unwrap();
+        let prices = std::collections::HashMap::from([(
+            std::string::String::from("gpt-4o"),
+            crate::domain::performance_metrics::ModelPrice::new(1.0, 2.0),
+        )]);
+
+        let cost = plan.cost("openai", "gpt-4o", prices);
+
+        let usage = &cost.models()["gpt-4o"];
+        std::assert_eq!(usage.calls, 2);
+        let input: usize = plan.nodes.iter().map(|node| node.input_tokens).sum();
+        let output: usize = plan.nodes.iter().map(|node| node.output_tokens).sum();
+        std::assert_eq!((usage.input_tokens, usage.output_tokens), (input, output));
+        let expected = input as f64 / 1000.0 + 2.0 * output as f64 / 1000.0;
+        std::assert!((cost.total_cost() - expected).abs() < 1e-9);
+    }
+}
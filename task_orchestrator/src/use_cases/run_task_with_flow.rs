@@ -4,7 +4,17 @@
 //! graph_flow::FlowRunner backed by the assembled orchestrator graph.
 //! This maintains a verifiable end-to-end flow using the StateGraph.
 //!
+//! When `TASK_ORCHESTRATOR_RUN_LOG_DIR` is set, each run is recorded to
+//! `<dir>/<correlation_id>.json` (see `domain::run_log`), and
+//! `replay_run_log` re-executes such a log, or one recorded by
+//! `TaskGraphRunner::with_run_log_dir`, without calling a provider.
+//!
 //! Revision History
+//! - 2026-10-18T04:20:00Z @AI: Replay logs of sequential TaskGraphRunner runs with the runner; expose run_log_dir_from_env.
+//! - 2026-10-17T08:10:00Z @AI: Record runs to TASK_ORCHESTRATOR_RUN_LOG_DIR and add replay_run_log to re-execute them from recorded provider responses.
+//! - 2026-10-17T07:50:00Z @AI: Add run_task_with_flow_traced, sending node-entered/exited and branch-taken TraceEvents while driving the graph.
+//! - 2026-10-17T07:30:00Z @AI: Load RouterConfig from the environment and seed it into the flow context.
+//! - 2026-10-16T20:10:00Z @AI: Add run_task_with_flow_correlated; run inside a `run` span and seed the flow context with the correlation ID.
 //! - 2025-11-23T23:15:00Z @AI: Use role-based adapter creation for heterogeneous pipeline (Phase 5 Sprint 10 Task 5.5).
 //! - 2025-11-23 @AI: Update to use ProviderFactory for vendor-agnostic LLM providers (Phase 1 Sprint 3 Task 1.11).
 //! - 2025-11-18T11:23:10Z @AI: Add optional SQLite-backed session storage behind `sqlite_persistence` feature; default remains in-memory.
@@ -34,20 +44,123 @@ pub async fn run_task_with_flow(
     test_type: &str,
     task: task_manager::domain::task::Task,
 ) -> std::result::Result<task_manager::domain::task::Task, std::string::String> {
+    run_task_with_flow_correlated(
+        factory,
+        test_type,
+        task,
+        crate::domain::correlation_id::CorrelationId::generate(),
+    )
+    .await
+}
+
+/// Runs the orchestration flow under a correlation ID chosen by the caller.
+///
+/// Every log event emitted during the run (node, provider, and DB spans
+/// included) is nested in a `run` span carrying `correlation_id`, and the ID
+/// is stored in the flow context so each node's `GraphState` carries it too.
+///
+/// # Arguments
+///
+/// * `factory` - The ProviderFactory for creating LLM adapters.
+/// * `test_type` - The comprehension test type to request (e.g., "short_answer").
+/// * `task` - The Task to orchestrate.
+/// * `correlation_id` - ID generated by the entry point (CLI command or server request).
+///
+/// # Returns
+///
+/// * `Ok(Task)` - The updated task after orchestration.
+/// * `Err(String)` - An error message if any node fails during execution.
+pub async fn run_task_with_flow_correlated(
+    factory: &crate::adapters::provider_factory::ProviderFactory,
+    test_type: &str,
+    task: task_manager::domain::task::Task,
+    correlation_id: crate::domain::correlation_id::CorrelationId,
+) -> std::result::Result<task_manager::domain::task::Task, std::string::String> {
+    let span = crate::infrastructure::telemetry::run_span(&correlation_id);
+    task_manager::infrastructure::telemetry::timed(
+        span,
+        run_flow(factory, test_type, task, correlation_id, std::option::Option::None),
+    )
+    .await
+}
+
+/// Runs the orchestration flow and sends a TraceEvent for each step to `trace`.
+///
+/// Each node produces a `NodeEntered` and a `NodeExited` event (with its
+/// duration); the router and check nodes additionally produce a
+/// `BranchTaken` event naming their decision, its reason, and the next node.
+/// Events are sent as the run progresses, so the receiver can print them live;
+/// a dropped receiver does not stop the run.
+///
+/// # Arguments
+///
+/// * `factory` - The ProviderFactory for creating LLM adapters.
+/// * `test_type` - The comprehension test type to request (e.g., "short_answer").
+/// * `task` - The Task to orchestrate.
+/// * `correlation_id` - ID generated by the entry point (CLI command or server request).
+/// * `trace` - Channel receiving the run's TraceEvents.
+///
+/// # Returns
+///
+/// * `Ok(Task)` - The updated task after orchestration.
+/// * `Err(String)` - An error message if any node fails during execution.
+pub async fn run_task_with_flow_traced(
+    factory: &crate::adapters::provider_factory::ProviderFactory,
+    test_type: &str,
+    task: task_manager::domain::task::Task,
+    correlation_id: crate::domain::correlation_id::CorrelationId,
+    trace: tokio::sync::mpsc::Sender<crate::domain::trace_event::TraceEvent>,
+) -> std::result::Result<task_manager::domain::task::Task, std::string::String> {
+    let span = crate::infrastructure::telemetry::run_span(&correlation_id);
+    task_manager::infrastructure::telemetry::timed(
+        span,
+        run_flow(factory, test_type, task, correlation_id, std::option::Option::Some(trace)),
+    )
+    .await
+}
+
+/// Builds the graph and drives it to completion; see `run_task_with_flow_traced`.
+async fn run_flow(
+    factory: &crate::adapters::provider_factory::ProviderFactory,
+    test_type: &str,
+    task: task_manager::domain::task::Task,
+    correlation_id: crate::domain::correlation_id::CorrelationId,
+    trace: std::option::Option<tokio::sync::mpsc::Sender<crate::domain::trace_event::TraceEvent>>,
+) -> std::result::Result<task_manager::domain::task::Task, std::string::String> {
+    tracing::info!(task_id = %task.id, "starting orchestration run");
+
+    let router_config = crate::domain::router_config::RouterConfig::from_env()?;
+
     // Build adapters (ports) using the factory
     // Use role-based adapter creation for heterogeneous pipeline optimization
-    let enh_port = factory.create_enhancement_adapter_for_role(
+    let mut enh_port: std::sync::Arc<dyn crate::ports::task_enhancement_port::TaskEnhancementPort> = factory.create_enhancement_adapter_for_role(
         crate::domain::model_role::ModelRole::Enhancer
     ).map_err(|e| e.to_string())?;
 
-    let ct_port = factory.create_comprehension_test_adapter()
+    let mut ct_port: std::sync::Arc<dyn crate::ports::comprehension_test_port::ComprehensionTestPort> = factory.create_comprehension_test_adapter()
         .map_err(|e| e.to_string())?;
 
     // Use Orca-2 for decomposition (excels at complex reasoning)
-    let decomp_port = factory.create_task_decomposition_adapter_for_role(
+    let mut decomp_port: std::sync::Arc<dyn crate::ports::task_decomposition_port::TaskDecompositionPort> = factory.create_task_decomposition_adapter_for_role(
         crate::domain::model_role::ModelRole::Decomposer
     ).map_err(|e| e.to_string())?;
 
+    // Record provider responses for `rig replay` when a log directory is configured
+    let run_log_dir = run_log_dir_from_env();
+    let recorder = run_log_dir.as_ref().map(|_| {
+        std::sync::Arc::new(crate::domain::run_log::RunRecorder::new(crate::domain::run_log::RunLog::new(
+            correlation_id.clone(),
+            test_type,
+            router_config.clone(),
+            task.clone(),
+        )))
+    });
+    if let std::option::Option::Some(recorder) = &recorder {
+        enh_port = std::sync::Arc::new(crate::adapters::recording_adapter::RecordingAdapter::new(enh_port, recorder.clone()));
+        ct_port = std::sync::Arc::new(crate::adapters::recording_adapter::RecordingAdapter::new(ct_port, recorder.clone()));
+        decomp_port = std::sync::Arc::new(crate::adapters::recording_adapter::RecordingAdapter::new(decomp_port, recorder.clone()));
+    }
+
     // Assemble graph
     let builder = crate::graph::assemble_orchestrator_flow::assemble_orchestrator_flow(
         enh_port.clone(),
@@ -80,6 +193,108 @@ pub async fn run_task_with_flow(
             arc
         }
     };
+    let log_path = run_log_dir.map(|dir| dir.join(std::format!("{}.json", correlation_id)));
+    let outcome = drive_graph(graph, storage, task, correlation_id, router_config, trace, recorder.as_deref()).await;
+
+    if let (std::option::Option::Some(recorder), std::option::Option::Some(path)) = (&recorder, log_path) {
+        // A log that cannot be written must not fail the run it describes
+        match recorder.finish(outcome.clone()).save(&path) {
+            std::result::Result::Ok(()) => tracing::info!(path = %path.display(), "run log written"),
+            std::result::Result::Err(e) => tracing::warn!(error = %e, "failed to write run log"),
+        }
+    }
+    outcome
+}
+
+/// Re-executes a recorded run, serving the recorded provider responses instead of calling providers.
+///
+/// The graph (or, for `RunRunner::Sequential` logs, the TaskGraphRunner),
+/// router config, and test type are the recorded run's, so the decision logic
+/// runs exactly as it did then; `trace` receives the replay's TraceEvents
+/// (graph replays only). Compare the result with `RunLog::outcome_matches`.
+///
+/// # Arguments
+///
+/// * `log` - The recorded run.
+/// * `trace` - Optional channel receiving the replay's TraceEvents.
+///
+/// # Returns
+///
+/// * `Ok(Task)` - The replayed final task.
+/// * `Err(String)` - The replayed run's error, or "Replay diverged: ..." if the
+///   replay requested provider calls other than the recorded ones.
+pub async fn replay_run_log(
+    log: &crate::domain::run_log::RunLog,
+    trace: std::option::Option<tokio::sync::mpsc::Sender<crate::domain::trace_event::TraceEvent>>,
+) -> std::result::Result<task_manager::domain::task::Task, std::string::String> {
+    let replay = std::sync::Arc::new(crate::adapters::replay_adapter::ReplayAdapter::new(log.provider_calls.clone()));
+    if let crate::domain::run_log::RunRunner::Sequential { pass_threshold } = log.runner {
+        let runner = crate::use_cases::task_graph_runner::TaskGraphRunner::new(replay.clone(), replay.clone(), log.test_type.clone())
+            .with_router_config(log.router_config.clone())
+            .with_pass_threshold(pass_threshold);
+        let outcome = runner.run_task(log.task.clone()).await;
+        return finish_replay(outcome, &replay);
+    }
+
+    let builder = crate::graph::assemble_orchestrator_flow::assemble_orchestrator_flow(
+        replay.clone(),
+        replay.clone(),
+        replay.clone(),
+        log.test_type.clone(),
+    );
+    let span = crate::infrastructure::telemetry::run_span(&log.correlation_id);
+    let outcome = task_manager::infrastructure::telemetry::timed(
+        span,
+        drive_graph(
+            std::sync::Arc::new(builder.build()),
+            std::sync::Arc::new(graph_flow::InMemorySessionStorage::new()),
+            log.task.clone(),
+            log.correlation_id.clone(),
+            log.router_config.clone(),
+            trace,
+            std::option::Option::None,
+        ),
+    )
+    .await;
+    finish_replay(outcome, &replay)
+}
+
+/// Fails a successful replay that left recorded provider calls unused.
+fn finish_replay(
+    outcome: std::result::Result<task_manager::domain::task::Task, std::string::String>,
+    replay: &crate::adapters::replay_adapter::ReplayAdapter,
+) -> std::result::Result<task_manager::domain::task::Task, std::string::String> {
+    if outcome.is_ok() && replay.remaining() > 0 {
+        return std::result::Result::Err(std::format!(
+            "Replay diverged: {} recorded provider call(s) were never requested",
+            replay.remaining()
+        ));
+    }
+    outcome
+}
+
+/// Directory runs are recorded to, from `TASK_ORCHESTRATOR_RUN_LOG_DIR`; None when unset or empty.
+pub fn run_log_dir_from_env() -> std::option::Option<std::path::PathBuf> {
+    std::env::var("TASK_ORCHESTRATOR_RUN_LOG_DIR")
+        .ok()
+        .filter(|dir| !dir.trim().is_empty())
+        .map(std::path::PathBuf::from)
+}
+
+/// Runs `task` through an assembled graph until it completes.
+///
+/// Seeds a new session with the task, correlation ID and router config, then
+/// executes one node per step, sending TraceEvents to `trace` and recording
+/// each node's input task in `recorder` if given.
+async fn drive_graph(
+    graph: std::sync::Arc<graph_flow::Graph>,
+    storage: std::sync::Arc<dyn graph_flow::SessionStorage>,
+    task: task_manager::domain::task::Task,
+    correlation_id: crate::domain::correlation_id::CorrelationId,
+    router_config: crate::domain::router_config::RouterConfig,
+    trace: std::option::Option<tokio::sync::mpsc::Sender<crate::domain::trace_event::TraceEvent>>,
+    recorder: std::option::Option<&crate::domain::run_log::RunRecorder>,
+) -> std::result::Result<task_manager::domain::task::Task, std::string::String> {
     let runner = graph_flow::FlowRunner::new(graph, storage.clone());
 
     // Create a session and seed context with the task
@@ -89,20 +304,39 @@ pub async fn run_task_with_flow(
     let start_id = <crate::graph::flow_shims::semantic_router_task_shim::SemanticRouterTaskShim as graph_flow::Task>::id(router.as_ref());
     let session = graph_flow::Session::new_from_task(session_id.clone(), start_id);
     graph_flow::Context::set(&session.context, "task", task.clone()).await;
+    graph_flow::Context::set(&session.context, "correlation_id", correlation_id).await;
+    graph_flow::Context::set(&session.context, "router_config", router_config).await;
     match graph_flow::SessionStorage::save(storage.as_ref(), session).await {
         std::result::Result::Ok(_) => {}
         std::result::Result::Err(e) => return std::result::Result::Err(std::format!("session save error: {:?}", e)),
     }
 
-    // Execute until completion or waiting for input
+    // Execute until completion or waiting for input, one node per step
+    let mut current_task_id = std::string::String::from(start_id);
     loop {
+        if let std::option::Option::Some(recorder) = recorder
+            && let std::option::Option::Some(input) = session_value(storage.as_ref(), &session_id, "task").await
+        {
+            recorder.record_input(&current_task_id, input);
+        }
+        emit(&trace, crate::domain::trace_event::TraceEvent::entered(&current_task_id)).await;
+        let started = std::time::Instant::now();
         let step = match graph_flow::FlowRunner::run(&runner, &session_id).await {
             std::result::Result::Ok(s) => s,
             std::result::Result::Err(e) => return std::result::Result::Err(std::format!("runner error: {:?}", e)),
         };
+        emit(&trace, crate::domain::trace_event::TraceEvent::exited(&current_task_id, started.elapsed())).await;
         match step.status {
             graph_flow::ExecutionStatus::Completed => break,
-            graph_flow::ExecutionStatus::Paused { next_task_id: _, reason: _ } => continue,
+            graph_flow::ExecutionStatus::Paused { next_task_id, reason: _ } => {
+                // Only classifier nodes (router, check) respond with a decision
+                if let (std::option::Option::Some(trace), std::option::Option::Some(decision)) = (&trace, step.response) {
+                    let reason = session_value(storage.as_ref(), &session_id, "routing_reason").await;
+                    let event = crate::domain::trace_event::TraceEvent::branch_taken(&current_task_id, &next_task_id, &decision, reason);
+                    let _ = trace.send(event).await;
+                }
+                current_task_id = next_task_id;
+            }
             graph_flow::ExecutionStatus::WaitingForInput => {
                 return std::result::Result::Err(std::string::String::from("waiting for input"))
             }
@@ -125,8 +359,221 @@ pub async fn run_task_with_flow(
     std::result::Result::Err(std::string::String::from("task not found in final context"))
 }
 
+/// Sends `event` if tracing is enabled; a closed receiver is ignored.
+async fn emit(
+    trace: &std::option::Option<tokio::sync::mpsc::Sender<crate::domain::trace_event::TraceEvent>>,
+    event: crate::domain::trace_event::TraceEvent,
+) {
+    if let std::option::Option::Some(trace) = trace {
+        let _ = trace.send(event).await;
+    }
+}
+
+/// Reads `key` from the stored session's context.
+async fn session_value<T: serde::de::DeserializeOwned>(
+    storage: &dyn graph_flow::SessionStorage,
+    session_id: &str,
+    key: &str,
+) -> std::option::Option<T> {
+    let session = graph_flow::SessionStorage::get(storage, session_id).await.ok().flatten()?;
+    graph_flow::Context::get(&session.context, key).await
+}
+
 #[cfg(test)]
 mod tests {
+    struct MockEnh;
+    #[async_trait::async_trait]
+    impl crate::ports::task_enhancement_port::TaskEnhancementPort for MockEnh {
+        async fn generate_enhancement(
+            &self,
+            task: &task_manager::domain::task::Task,
+        ) -> std::result::Result<task_manager::domain::enhancement::Enhancement, std::string::String> {
+            std::result::Result::Ok(task_manager::domain::enhancement::Enhancement {
+                enhancement_id: std::string::String::from("e-1"),
+                task_id: task.id.clone(),
+                timestamp: chrono::Utc::now(),
+                enhancement_type: std::string::String::from("rewrite"),
+                content: std::format!("E:{}", task.title),
+            })
+        }
+    }
+
+    /// Returns a question too long for the check heuristic first, then a short one.
+    struct FailOnceCT {
+        calls: std::sync::atomic::AtomicUsize,
+    }
+    #[async_trait::async_trait]
+    impl crate::ports::comprehension_test_port::ComprehensionTestPort for FailOnceCT {
+        async fn generate_comprehension_test(
+            &self,
+            task: &task_manager::domain::task::Task,
+            test_type: &str,
+        ) -> std::result::Result<task_manager::domain::comprehension_test::ComprehensionTest, std::string::String> {
+            let call = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let question = if call == 0 { "Q".repeat(100) } else { std::string::String::from("Q?") };
+            std::result::Result::Ok(task_manager::domain::comprehension_test::ComprehensionTest {
+                test_id: std::format!("ct-{}", call),
+                task_id: task.id.clone(),
+                timestamp: chrono::Utc::now(),
+                test_type: std::string::String::from(test_type),
+                question,
+                options: std::option::Option::None,
+                correct_answer: std::string::String::from("A"),
+                description_hash: std::option::Option::None,
+                stale: false,
+            })
+        }
+    }
+
+    struct MockDecomp;
+    #[async_trait::async_trait]
+    impl crate::ports::task_decomposition_port::TaskDecompositionPort for MockDecomp {
+        async fn decompose_task(
+            &self,
+            _task: &task_manager::domain::task::Task,
+        ) -> std::result::Result<std::vec::Vec<task_manager::domain::task::Task>, std::string::String> {
+            std::result::Result::Ok(std::vec::Vec::new())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_trace_records_path_through_check_loop() {
+        // Test: Validates the trace of a run whose first check fails records both loops, the branches with reasons, and every node's exit.
+        // Justification: The trace must show which path the graph took, including conditional edges taken more than once.
+        let builder = crate::graph::assemble_orchestrator_flow::assemble_orchestrator_flow(
+            std::sync::Arc::new(MockEnh),
+            std::sync::Arc::new(FailOnceCT { calls: std::sync::atomic::AtomicUsize::new(0) }),
+            std::sync::Arc::new(MockDecomp),
+            std::string::String::from("short_answer"),
+        );
+        let ai = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Fix typo"),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::Some(std::string::String::from("2025-12-01")),
+        };
+        let task = task_manager::domain::task::Task::from_action_item(&ai, std::option::Option::None);
+        let (tx, mut rx) = tokio::sync::mpsc::channel(64);
+
+        let out = super::drive_graph(
+            std::sync::Arc::new(builder.build()),
+            std::sync::Arc::new(graph_flow::InMemorySessionStorage::new()),
+            task,
+            crate::domain::correlation_id::CorrelationId::generate(),
+            crate::domain::router_config::RouterConfig::default(),
+            std::option::Option::Some(tx),
+            std::option::Option::None,
+        )
+        .await
+        .unwrap();
+        let mut events = std::vec::Vec::new();
+        while let std::option::Option::Some(event) = rx.recv().await {
+            events.push(event);
+        }
+
+        std::assert_eq!(out.status, task_manager::domain::task_status::TaskStatus::OrchestrationComplete);
+        std::assert_eq!(
+            crate::domain::trace_event::TraceEvent::path(&events),
+            [
+                "SemanticRouterTaskShim",
+                "EnhancementTaskShim",
+                "ComprehensionTestTaskShim",
+                "CheckTestResultTaskShim",
+                "EnhancementTaskShim",
+                "ComprehensionTestTaskShim",
+                "CheckTestResultTaskShim",
+                "EndTask",
+            ]
+        );
+        let branches: std::vec::Vec<(std::string::String, std::string::String, std::string::String)> = events
+            .iter()
+            .filter_map(|event| match event {
+                crate::domain::trace_event::TraceEvent::BranchTaken { from, to, decision, reason, .. } => {
+                    std::assert!(reason.is_some(), "branch without reason: {}", event);
+                    std::option::Option::Some((from.clone(), to.clone(), decision.clone()))
+                }
+                _ => std::option::Option::None,
+            })
+            .collect();
+        let branch = |from: &str, to: &str, decision: &str| (from.to_string(), to.to_string(), decision.to_string());
+        std::assert_eq!(
+            branches,
+            [
+                branch("SemanticRouterTaskShim", "EnhancementTaskShim", "enhance"),
+                branch("CheckTestResultTaskShim", "EnhancementTaskShim", "fail"),
+                branch("CheckTestResultTaskShim", "EndTask", "pass"),
+            ]
+        );
+        let exits = events
+            .iter()
+            .filter(|event| std::matches!(event, crate::domain::trace_event::TraceEvent::NodeExited { .. }))
+            .count();
+        std::assert_eq!(exits, 8);
+    }
+
+    #[tokio::test]
+    async fn test_replay_reaches_recorded_final_state() {
+        // Test: Validates a recorded run, saved and loaded from disk, replays to the same final task along the same path.
+        // Justification: `rig replay` is only useful for debugging if it reproduces the recorded run exactly.
+        let ai = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Fix typo"),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let task = task_manager::domain::task::Task::from_action_item(&ai, std::option::Option::None);
+        let correlation_id = crate::domain::correlation_id::CorrelationId::generate();
+        let recorder = std::sync::Arc::new(crate::domain::run_log::RunRecorder::new(crate::domain::run_log::RunLog::new(
+            correlation_id.clone(),
+            "short_answer",
+            crate::domain::router_config::RouterConfig::default(),
+            task.clone(),
+        )));
+        let enh: std::sync::Arc<dyn crate::ports::task_enhancement_port::TaskEnhancementPort> = std::sync::Arc::new(MockEnh);
+        let ct: std::sync::Arc<dyn crate::ports::comprehension_test_port::ComprehensionTestPort> =
+            std::sync::Arc::new(FailOnceCT { calls: std::sync::atomic::AtomicUsize::new(0) });
+        let decomp: std::sync::Arc<dyn crate::ports::task_decomposition_port::TaskDecompositionPort> = std::sync::Arc::new(MockDecomp);
+        let builder = crate::graph::assemble_orchestrator_flow::assemble_orchestrator_flow(
+            std::sync::Arc::new(crate::adapters::recording_adapter::RecordingAdapter::new(enh, recorder.clone())),
+            std::sync::Arc::new(crate::adapters::recording_adapter::RecordingAdapter::new(ct, recorder.clone())),
+            std::sync::Arc::new(crate::adapters::recording_adapter::RecordingAdapter::new(decomp, recorder.clone())),
+            std::string::String::from("short_answer"),
+        );
+        let (tx, mut rx) = tokio::sync::mpsc::channel(64);
+        let recorded = super::drive_graph(
+            std::sync::Arc::new(builder.build()),
+            std::sync::Arc::new(graph_flow::InMemorySessionStorage::new()),
+            task,
+            correlation_id,
+            crate::domain::router_config::RouterConfig::default(),
+            std::option::Option::Some(tx),
+            std::option::Option::Some(recorder.as_ref()),
+        )
+        .await;
+        let mut recorded_events = std::vec::Vec::new();
+        while let std::option::Option::Some(event) = rx.recv().await {
+            recorded_events.push(event);
+        }
+        let path = std::env::temp_dir().join(std::format!("run_log_{}.json", uuid::Uuid::new_v4()));
+        recorder.finish(recorded).save(&path).unwrap();
+
+        let log = crate::domain::run_log::RunLog::load(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        let (tx, mut rx) = tokio::sync::mpsc::channel(64);
+        let replayed = super::replay_run_log(&log, std::option::Option::Some(tx)).await;
+        let mut replayed_events = std::vec::Vec::new();
+        while let std::option::Option::Some(event) = rx.recv().await {
+            replayed_events.push(event);
+        }
+
+        std::assert_eq!(log.provider_calls.len(), 4);
+        std::assert_eq!(log.node_inputs.len(), 8);
+        std::assert!(replayed.is_ok());
+        std::assert!(log.outcome_matches(&replayed));
+        std::assert_eq!(
+            crate::domain::trace_event::TraceEvent::path(&replayed_events),
+            crate::domain::trace_event::TraceEvent::path(&recorded_events)
+        );
+    }
+
     #[tokio::test]
     async fn test_run_task_with_flow_completes() {
         let ai = transcript_extractor::domain::action_item::ActionItem {
@@ -75,6 +75,8 @@ mod tests {
                 question: std::format!("Q for {}", task.title),
                 options: std::option::Option::None,
                 correct_answer: std::string::String::from("A"),
+                description_hash: std::option::Option::None,
+                stale: false,
             };
             std::result::Result::Ok(ct)
         }
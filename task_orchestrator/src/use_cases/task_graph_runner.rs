@@ -6,6 +6,13 @@
 //! runtime. In Phase 6, this will be replaced by an rs-graph-llm based runner.
 //!
 //! Revision History
+//! - 2026-10-18T04:30:00Z @AI: Stop after the router on the "clarify" route, returning the task unchanged.
+//! - 2026-10-18T04:20:00Z @AI: Add with_run_log_dir() recording each run (node inputs and provider responses) for replay_run_log.
+//! - 2026-10-17T21:50:00Z @AI: Add with_metrics() recording each enhancement and test call in a metrics collector.
+//! - 2026-10-17T07:30:00Z @AI: Add with_router_config() for the router's confidence threshold and fallback route.
+//! - 2026-10-17T07:10:00Z @AI: Add with_pass_threshold() for grading agent answers in the check step.
+//! - 2026-10-16T20:50:00Z @AI: Add with_budget() to check a per-run TokenBudget before each provider call.
+//! - 2026-10-16T20:10:00Z @AI: Run each task inside a `run` span carrying the state's correlation ID.
 //! - 2025-11-12T22:22:00Z @AI: Introduce TaskGraphRunner with sequential execution and unit tests.
 
 /// Runner that executes the orchestration flow over a single task.
@@ -13,6 +20,9 @@ pub struct TaskGraphRunner {
     enhancement_port: std::sync::Arc<dyn crate::ports::task_enhancement_port::TaskEnhancementPort>,
     test_port: std::sync::Arc<dyn crate::ports::comprehension_test_port::ComprehensionTestPort>,
     test_type: String,
+    pass_threshold: f64,
+    router_config: crate::domain::router_config::RouterConfig,
+    run_log_dir: std::option::Option<std::path::PathBuf>,
 }
 
 impl TaskGraphRunner {
@@ -22,13 +32,86 @@ impl TaskGraphRunner {
         test_port: std::sync::Arc<dyn crate::ports::comprehension_test_port::ComprehensionTestPort>,
         test_type: String,
     ) -> Self {
-        TaskGraphRunner { enhancement_port, test_port, test_type }
+        TaskGraphRunner {
+            enhancement_port,
+            test_port,
+            test_type,
+            pass_threshold: crate::graph::nodes::check_test_result_node::CheckTestResultNode::DEFAULT_PASS_THRESHOLD,
+            router_config: crate::domain::router_config::RouterConfig::default(),
+            run_log_dir: std::option::Option::None,
+        }
+    }
+
+    /// Sets the minimum grade score an agent's answer needs in the check step.
+    ///
+    /// Only applies when the state carries an answer; see CheckTestResultNode.
+    pub fn with_pass_threshold(mut self, pass_threshold: f64) -> Self {
+        self.pass_threshold = pass_threshold;
+        self
+    }
+
+    /// Sets the router's confidence threshold and fallback route.
+    pub fn with_router_config(mut self, router_config: crate::domain::router_config::RouterConfig) -> Self {
+        self.router_config = router_config;
+        self
+    }
+
+    /// Records each run to `<dir>/<correlation_id>.json` for `replay_run_log`.
+    ///
+    /// The log holds the task each node received and every provider response
+    /// (as returned through the metrics and budget wrappers), with secrets
+    /// redacted. A log that cannot be written is only warned about.
+    pub fn with_run_log_dir(mut self, dir: std::path::PathBuf) -> Self {
+        self.run_log_dir = std::option::Option::Some(dir);
+        self
+    }
+
+    /// Records every enhancement and comprehension-test call in `collector`
+    /// under `provider` and `model`.
+    ///
+    /// Call before `with_budget` so calls the budget refuses are not recorded.
+    pub fn with_metrics(
+        mut self,
+        collector: std::sync::Arc<dyn crate::ports::metrics_collector_port::MetricsCollectorPort>,
+        provider: &str,
+        model: &str,
+    ) -> Self {
+        self.enhancement_port = std::sync::Arc::new(crate::adapters::metrics_recording_adapter::MetricsRecordingAdapter::new(
+            self.enhancement_port,
+            collector.clone(),
+            provider,
+            model,
+        ));
+        self.test_port = std::sync::Arc::new(crate::adapters::metrics_recording_adapter::MetricsRecordingAdapter::new(
+            self.test_port,
+            collector,
+            provider,
+            model,
+        ));
+        self
+    }
+
+    /// Guards the enhancement and comprehension-test ports with `budget`.
+    ///
+    /// Before each provider call the budget is checked; once the next call
+    /// would exceed it, the run fails with the `BudgetExceeded` message and no
+    /// further calls are made. Tasks finished (and persisted by the caller)
+    /// earlier are unaffected. The ports' adapters must record their token
+    /// usage in the same budget.
+    pub fn with_budget(mut self, budget: std::sync::Arc<crate::domain::token_budget::TokenBudget>) -> Self {
+        self.enhancement_port = std::sync::Arc::new(crate::adapters::budget_guard_adapter::BudgetGuardAdapter::new(
+            self.enhancement_port,
+            budget.clone(),
+        ));
+        self.test_port = std::sync::Arc::new(crate::adapters::budget_guard_adapter::BudgetGuardAdapter::new(self.test_port, budget));
+        self
     }
 
     /// Runs the task through the sequential node flow and returns the updated task.
     ///
     /// Flow:
-    /// 1) SemanticRouterNode decides route ("enhance" or "decompose").
+    /// 1) SemanticRouterNode decides route ("enhance", "decompose", or "clarify").
+    ///    On "clarify" the run stops here and returns the task unchanged.
     /// 2) EnhancementNode generates an enhancement.
     /// 3) ComprehensionTestNode generates a test (self.test_type).
     /// 4) CheckTestResultNode evaluates pass/fail and may set OrchestrationComplete.
@@ -39,32 +122,84 @@ impl TaskGraphRunner {
         &self,
         task: task_manager::domain::task::Task,
     ) -> std::result::Result<task_manager::domain::task::Task, std::string::String> {
-        // Initialize state
-        let mut state = crate::graph::state::GraphState::new(task);
+        // Initialize state; every event of this run is tagged with its correlation ID
+        let state = crate::graph::state::GraphState::new(task);
+        let span = crate::infrastructure::telemetry::run_span(&state.correlation_id);
+        let std::option::Option::Some(dir) = &self.run_log_dir else {
+            let nodes = self.run_nodes(state, self.enhancement_port.clone(), self.test_port.clone(), std::option::Option::None);
+            return task_manager::infrastructure::telemetry::timed(span, nodes).await;
+        };
+
+        // Record provider responses for `rig replay`
+        let recorder = std::sync::Arc::new(crate::domain::run_log::RunRecorder::new(
+            crate::domain::run_log::RunLog::new(
+                state.correlation_id.clone(),
+                &self.test_type,
+                self.router_config.clone(),
+                state.task.clone(),
+            )
+            .with_runner(crate::domain::run_log::RunRunner::Sequential { pass_threshold: self.pass_threshold }),
+        ));
+        let enhancement_port: std::sync::Arc<dyn crate::ports::task_enhancement_port::TaskEnhancementPort> = std::sync::Arc::new(
+            crate::adapters::recording_adapter::RecordingAdapter::new(self.enhancement_port.clone(), recorder.clone()),
+        );
+        let test_port: std::sync::Arc<dyn crate::ports::comprehension_test_port::ComprehensionTestPort> = std::sync::Arc::new(
+            crate::adapters::recording_adapter::RecordingAdapter::new(self.test_port.clone(), recorder.clone()),
+        );
+        let path = dir.join(std::format!("{}.json", state.correlation_id));
+        let nodes = self.run_nodes(state, enhancement_port, test_port, std::option::Option::Some(recorder.as_ref()));
+        let outcome = task_manager::infrastructure::telemetry::timed(span, nodes).await;
+
+        // A log that cannot be written must not fail the run it describes
+        match recorder.finish(outcome.clone()).save(&path) {
+            std::result::Result::Ok(()) => tracing::info!(path = %path.display(), "run log written"),
+            std::result::Result::Err(e) => tracing::warn!(error = %e, "failed to write run log"),
+        }
+        outcome
+    }
+
+    /// Executes the node sequence for `run_task` inside its `run` span,
+    /// recording each node's input task in `recorder` if given.
+    async fn run_nodes(
+        &self,
+        mut state: crate::graph::state::GraphState,
+        enhancement_port: std::sync::Arc<dyn crate::ports::task_enhancement_port::TaskEnhancementPort>,
+        test_port: std::sync::Arc<dyn crate::ports::comprehension_test_port::ComprehensionTestPort>,
+        recorder: std::option::Option<&crate::domain::run_log::RunRecorder>,
+    ) -> std::result::Result<task_manager::domain::task::Task, std::string::String> {
+        let record_input = |node: &str, state: &crate::graph::state::GraphState| {
+            if let std::option::Option::Some(recorder) = recorder {
+                recorder.record_input(node, state.task.clone());
+            }
+        };
 
         // 1) Route
+        record_input("SemanticRouterNode", &state);
         let scorer = task_manager::domain::services::complexity_scorer::ComplexityScorer::new();
         let triage_service = task_manager::domain::services::triage_service::TriageService::new(scorer);
-        let router = crate::graph::nodes::semantic_router_node::SemanticRouterNode::new(triage_service);
+        let router = crate::graph::nodes::semantic_router_node::SemanticRouterNode::new(triage_service)
+            .with_config(self.router_config.clone());
         state = crate::graph::nodes::semantic_router_node::SemanticRouterNode::execute(&router, state).await?;
-        let _route = state
-            .routing_decision
-            .clone()
-            .unwrap_or_else(|| std::string::String::from("enhance"));
+        if state.routing_decision.as_deref() == std::option::Option::Some(crate::domain::router_config::CLARIFY_ROUTE) {
+            return std::result::Result::Ok(state.task);
+        }
 
         // 2) Enhance (same path even if "decompose" for now)
-        let enh_node = crate::graph::nodes::enhancement_node::EnhancementNode::new(self.enhancement_port.clone());
+        record_input("EnhancementNode", &state);
+        let enh_node = crate::graph::nodes::enhancement_node::EnhancementNode::new(enhancement_port);
         state = crate::graph::nodes::graph_node::GraphNode::execute(&enh_node, state).await?;
 
         // 3) Comprehension test
+        record_input("ComprehensionTestNode", &state);
         let comp_node = crate::graph::nodes::comprehension_test_node::ComprehensionTestNode::new(
-            self.test_port.clone(),
+            test_port,
             self.test_type.clone(),
         );
         state = crate::graph::nodes::graph_node::GraphNode::execute(&comp_node, state).await?;
 
         // 4) Check result
-        let check = crate::graph::nodes::check_test_result_node::CheckTestResultNode::new();
+        record_input("CheckTestResultNode", &state);
+        let check = crate::graph::nodes::check_test_result_node::CheckTestResultNode::new().with_pass_threshold(self.pass_threshold);
         state = crate::graph::nodes::graph_node::GraphNode::execute(&check, state).await?;
 
         // Return updated task
@@ -81,6 +216,9 @@ mod tests {
             &self,
             task: &task_manager::domain::task::Task,
         ) -> std::result::Result<task_manager::domain::enhancement::Enhancement, std::string::String> {
+            // Yield so concurrent runs interleave, as real provider calls do
+            tokio::task::yield_now().await;
+            tracing::info!(task_id = %task.id, "mock enhancement generated");
             let enh = task_manager::domain::enhancement::Enhancement {
                 enhancement_id: std::string::String::from("e-1"),
                 task_id: task.id.clone(),
@@ -108,6 +246,8 @@ mod tests {
                 question: std::format!("Q for {}", task.title),
                 options: std::option::Option::None,
                 correct_answer: std::string::String::from("A"),
+                description_hash: std::option::Option::None,
+                stale: false,
             };
             std::result::Result::Ok(ct)
         }
@@ -134,6 +274,64 @@ mod tests {
         std::assert_eq!(out.status, task_manager::domain::task_status::TaskStatus::OrchestrationComplete);
     }
 
+    #[tokio::test]
+    async fn test_clarify_route_returns_task_unchanged() {
+        // Test: Validates a task the router sends to "clarify" comes back without enhancements, tests, or a status change.
+        // Justification: The clarify route asks for a better task description instead of spending provider calls on a guess.
+        let ai = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Title"),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let task = task_manager::domain::task::Task::from_action_item(&ai, std::option::Option::None);
+        let runner = super::TaskGraphRunner::new(
+            std::sync::Arc::new(MockEnh),
+            std::sync::Arc::new(MockCT),
+            std::string::String::from("short_answer"),
+        )
+        .with_router_config(crate::domain::router_config::RouterConfig::new(1.0, "clarify").unwrap());
+
+        let out = runner.run_task(task.clone()).await.unwrap();
+
+        std::assert!(out.enhancements.is_none());
+        std::assert!(out.comprehension_tests.is_none());
+        std::assert_eq!(out.status, task.status);
+    }
+
+    #[tokio::test]
+    async fn test_recorded_run_replays_to_the_same_state() {
+        // Test: Validates with_run_log_dir writes a sequential run log that replays to the recorded final task.
+        // Justification: `rig do` runs TaskGraphRunner, whose runs were never recorded for `rig replay`.
+        let dir = std::env::temp_dir().join(std::format!("runner_run_log_{}", uuid::Uuid::new_v4()));
+        let ai = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Rotate the staging keys"),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+        let task = task_manager::domain::task::Task::from_action_item(&ai, std::option::Option::None);
+        let runner = super::TaskGraphRunner::new(
+            std::sync::Arc::new(MockEnh),
+            std::sync::Arc::new(MockCT),
+            std::string::String::from("short_answer"),
+        )
+        .with_pass_threshold(0.5)
+        .with_run_log_dir(dir.clone());
+
+        let out = runner.run_task(task).await;
+        let logs: std::vec::Vec<std::path::PathBuf> = std::fs::read_dir(&dir).unwrap().map(|e| e.unwrap().path()).collect();
+        let log = crate::domain::run_log::RunLog::load(&logs[0]);
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let log = log.unwrap();
+        std::assert_eq!(logs.len(), 1);
+        std::assert_eq!(log.runner, crate::domain::run_log::RunRunner::Sequential { pass_threshold: 0.5 });
+        std::assert_eq!(log.node_inputs.len(), 4);
+        std::assert_eq!(log.provider_calls.len(), 3);
+        let replayed = crate::use_cases::run_task_with_flow::replay_run_log(&log, std::option::Option::None).await;
+        std::assert!(log.outcome_matches(&out));
+        std::assert!(log.outcome_matches(&replayed));
+    }
+
     #[tokio::test]
     async fn test_runner_long_title_routes_and_runs() {
         let ai = transcript_extractor::domain::action_item::ActionItem {
@@ -156,4 +354,211 @@ mod tests {
             || status == task_manager::domain::task_status::TaskStatus::OrchestrationComplete;
         std::assert!(allowed);
     }
+
+    #[tokio::test]
+    async fn test_concurrent_runs_carry_distinct_correlation_ids() {
+        // Test: Validates every event of a run carries that run's correlation ID and concurrent runs get different IDs.
+        // Justification: Interleaved logs from concurrent runs are only separable if no event lacks or mixes IDs.
+        let output = crate::infrastructure::telemetry::tests::CapturedOutput::default();
+        let subscriber = tracing_subscriber::fmt()
+            .json()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_writer(output.clone())
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let make_task = |title: &str| {
+            let ai = transcript_extractor::domain::action_item::ActionItem {
+                title: std::string::String::from(title),
+                assignee: std::option::Option::None,
+                due_date: std::option::Option::None,
+            };
+            task_manager::domain::task::Task::from_action_item(&ai, std::option::Option::None)
+        };
+        let (first, second) = (make_task("First"), make_task("Second"));
+        let runner = super::TaskGraphRunner::new(
+            std::sync::Arc::new(MockEnh),
+            std::sync::Arc::new(MockCT),
+            std::string::String::from("short_answer"),
+        );
+        let (a, b) = tokio::join!(runner.run_task(first.clone()), runner.run_task(second.clone()));
+        a.unwrap();
+        b.unwrap();
+
+        // Group each event's correlation ID (from its enclosing spans) by the task it belongs to
+        let mut ids_by_task: std::collections::HashMap<std::string::String, std::collections::HashSet<std::string::String>> =
+            std::collections::HashMap::new();
+        let events = output.events();
+        for event in &events {
+            let spans = event["spans"].as_array().unwrap_or_else(|| std::panic!("event outside any span: {}", event));
+            let correlation_id = spans
+                .iter()
+                .find_map(|span| span["correlation_id"].as_str())
+                .unwrap_or_else(|| std::panic!("event without correlation_id: {}", event));
+            let task_id = spans
+                .iter()
+                .find_map(|span| span["task_id"].as_str())
+                .or_else(|| event["fields"]["task_id"].as_str())
+                .unwrap_or_else(|| std::panic!("event without task_id: {}", event));
+            ids_by_task
+                .entry(std::string::String::from(task_id))
+                .or_default()
+                .insert(std::string::String::from(correlation_id));
+        }
+
+        let first_ids = &ids_by_task[&first.id];
+        let second_ids = &ids_by_task[&second.id];
+        std::assert_eq!(first_ids.len(), 1, "first run mixed IDs: {:?}", first_ids);
+        std::assert_eq!(second_ids.len(), 1, "second run mixed IDs: {:?}", second_ids);
+        std::assert_ne!(first_ids, second_ids);
+    }
+
+    /// Enhancement and test port that reports 500 input + 500 output tokens per call.
+    struct MeteredPorts {
+        budget: std::sync::Arc<crate::domain::token_budget::TokenBudget>,
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    impl MeteredPorts {
+        fn record_call(&self, operation: &str) {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let mut metrics = crate::domain::performance_metrics::InferenceMetrics::new(
+                std::string::String::from(operation),
+                std::string::String::from("mock"),
+                std::string::String::from("mock-model"),
+                std::option::Option::None,
+            );
+            metrics.record_completion(std::time::Duration::from_millis(1), 500, 500);
+            self.budget.record(&metrics);
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl crate::ports::task_enhancement_port::TaskEnhancementPort for MeteredPorts {
+        async fn generate_enhancement(
+            &self,
+            task: &task_manager::domain::task::Task,
+        ) -> std::result::Result<task_manager::domain::enhancement::Enhancement, std::string::String> {
+            let enhancement = crate::ports::task_enhancement_port::TaskEnhancementPort::generate_enhancement(&MockEnh, task).await?;
+            self.record_call("enhancement");
+            std::result::Result::Ok(enhancement)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl crate::ports::comprehension_test_port::ComprehensionTestPort for MeteredPorts {
+        async fn generate_comprehension_test(
+            &self,
+            task: &task_manager::domain::task::Task,
+            test_type: &str,
+        ) -> std::result::Result<task_manager::domain::comprehension_test::ComprehensionTest, std::string::String> {
+            let test = crate::ports::comprehension_test_port::ComprehensionTestPort::generate_comprehension_test(&MockCT, task, test_type).await?;
+            self.record_call("comprehension_test");
+            std::result::Result::Ok(test)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_budget_halts_run_and_keeps_completed_tasks() {
+        // Test: Validates the run stops before the call that would cross max_tokens, and tasks saved earlier remain.
+        // Justification: A budget that fires after the overspending call, or discards finished work, defeats its purpose.
+        let budget = std::sync::Arc::new(crate::domain::token_budget::TokenBudget::new(
+            std::option::Option::Some(3000),
+            std::option::Option::None,
+            std::collections::HashMap::new(),
+        ));
+        let ports = std::sync::Arc::new(MeteredPorts {
+            budget: budget.clone(),
+            calls: std::sync::atomic::AtomicUsize::new(0),
+        });
+        let runner = super::TaskGraphRunner::new(ports.clone(), ports.clone(), std::string::String::from("short_answer"))
+            .with_budget(budget.clone());
+        let repository = task_manager::adapters::sqlite_task_adapter::SqliteTaskAdapter::connect_and_init("sqlite::memory:")
+            .await
+            .unwrap();
+
+        let tasks: std::vec::Vec<task_manager::domain::task::Task> = ["First", "Second", "Third"]
+            .iter()
+            .map(|title| {
+                let ai = transcript_extractor::domain::action_item::ActionItem {
+                    title: std::string::String::from(*title),
+                    assignee: std::option::Option::None,
+                    due_date: std::option::Option::None,
+                };
+                task_manager::domain::task::Task::from_action_item(&ai, std::option::Option::None)
+            })
+            .collect();
+
+        // Run tasks in sequence, persisting each one as it completes, until the budget halts the run
+        let mut halted = std::option::Option::None;
+        for task in &tasks {
+            match runner.run_task(task.clone()).await {
+                std::result::Result::Ok(done) => repository.save_async(done).await.unwrap(),
+                std::result::Result::Err(error) => {
+                    halted = std::option::Option::Some(error);
+                    break;
+                }
+            }
+        }
+
+        // 1000 tokens per call: the first task uses 2000; the second's enhancement brings the
+        // total to 3000, so its comprehension test (projected 4000) is refused
+        let error = halted.expect("run should have been halted by the budget");
+        std::assert!(error.starts_with("BudgetExceeded"), "unexpected error: {}", error);
+        std::assert!(error.contains("3000 tokens of 3000"), "unexpected error: {}", error);
+        std::assert_eq!(ports.calls.load(std::sync::atomic::Ordering::SeqCst), 3);
+        std::assert_eq!(budget.check().unwrap_err().tokens_used, 3000);
+
+        let saved = repository
+            .find_one_async(&task_manager::ports::task_repository_port::TaskFilter::ById(tasks[0].id.clone()))
+            .await
+            .unwrap()
+            .expect("completed task should be persisted");
+        std::assert_eq!(saved.status, task_manager::domain::task_status::TaskStatus::OrchestrationComplete);
+        for unfinished in &tasks[1..] {
+            let found = repository
+                .find_one_async(&task_manager::ports::task_repository_port::TaskFilter::ById(unfinished.id.clone()))
+                .await
+                .unwrap();
+            std::assert!(found.is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_metrics_records_calls_the_budget_allows() {
+        // Test: Validates each forwarded call is recorded with its operation and role, and a call the budget refuses is not.
+        // Justification: The metrics file must describe the provider calls a run made, not calls it never sent.
+        let budget = std::sync::Arc::new(crate::domain::token_budget::TokenBudget::new(
+            std::option::Option::Some(1500),
+            std::option::Option::None,
+            std::collections::HashMap::new(),
+        ));
+        let ports = std::sync::Arc::new(MeteredPorts {
+            budget: budget.clone(),
+            calls: std::sync::atomic::AtomicUsize::new(0),
+        });
+        let collector = std::sync::Arc::new(crate::adapters::memory_metrics_collector::MemoryMetricsCollector::new());
+        let runner = super::TaskGraphRunner::new(ports.clone(), ports, std::string::String::from("short_answer"))
+            .with_metrics(collector.clone(), "ollama", "llama3.1")
+            .with_budget(budget);
+        let ai = transcript_extractor::domain::action_item::ActionItem {
+            title: std::string::String::from("Write docs"),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+        };
+
+        let result = runner.run_task(task_manager::domain::task::Task::from_action_item(&ai, std::option::Option::None)).await;
+
+        std::assert!(result.is_err());
+        let metrics = crate::ports::metrics_collector_port::MetricsCollectorPort::get_all_metrics(collector.as_ref())
+            .await
+            .unwrap();
+        std::assert_eq!(metrics.len(), 1);
+        std::assert_eq!(metrics[0].operation_type, "enhancement");
+        std::assert_eq!(metrics[0].provider, "ollama");
+        std::assert_eq!(metrics[0].model, "llama3.1");
+        std::assert_eq!(metrics[0].role, std::option::Option::Some(crate::domain::model_role::ModelRole::Enhancer));
+        std::assert!(metrics[0].success);
+        std::assert!(metrics[0].duration_ms.is_some());
+    }
 }
@@ -10,6 +10,9 @@
 //! Run ignored tests: `cargo test --package task_orchestrator --test integration_end_to_end_flow -- --ignored`
 //!
 //! Revision History
+//! - 2026-10-17T21:50:00Z @AI: Add a test running tasks concurrently with a JSONL metrics collector, then reading the file back.
+//! - 2026-10-17T21:30:00Z @AI: Restore the decomposition task's due date; add a test of the configured router fallback.
+//! - 2026-10-17T07:30:00Z @AI: Drop the decomposition task's due date so it scores clear of the router's fallback band.
 //! - 2025-11-22T19:30:00Z @AI: Create end-to-end integration test for Phase 1 Sprint 1.1.
 
 #[tokio::test]
@@ -547,6 +550,41 @@ async fn test_task_decomposition_end_to_end() {
     std::println!("  - Parent task marked as Decomposed");
 }
 
+#[tokio::test]
+async fn test_configured_threshold_sends_borderline_task_to_fallback() {
+    // Test: Validates a configured confidence threshold reroutes the borderline decomposition task, while the default keeps it.
+    // Justification: The fallback must only apply once a threshold is configured, and then take effect on real fixtures.
+    let action = transcript_extractor::domain::action_item::ActionItem {
+        title: std::string::String::from(
+            "Refactor entire authentication system to support OAuth2, SAML, and multi-region deployment with zero-downtime migration"
+        ),
+        assignee: std::option::Option::None,
+        due_date: std::option::Option::Some(std::string::String::from("2025-12-31")),
+    };
+    let task = task_manager::domain::task::Task::from_action_item(&action, std::option::Option::None);
+    let route = |config: task_orchestrator::domain::router_config::RouterConfig| {
+        let task = task.clone();
+        async move {
+            let scorer = task_manager::domain::services::complexity_scorer::ComplexityScorer::new();
+            let triage_service = task_manager::domain::services::triage_service::TriageService::new(scorer);
+            let router = task_orchestrator::graph::nodes::semantic_router_node::SemanticRouterNode::new(triage_service).with_config(config);
+            task_orchestrator::graph::nodes::semantic_router_node::SemanticRouterNode::execute(
+                &router,
+                task_orchestrator::graph::state::GraphState::new(task),
+            )
+            .await
+            .unwrap()
+        }
+    };
+
+    let default_route = route(task_orchestrator::domain::router_config::RouterConfig::default()).await;
+    let configured_route = route(task_orchestrator::domain::router_config::RouterConfig::new(0.25, "enhance").unwrap()).await;
+
+    std::assert_eq!(default_route.routing_decision, std::option::Option::Some(std::string::String::from("decompose")));
+    std::assert!(default_route.routing_confidence.unwrap() < 0.25, "fixture should sit next to the boundary");
+    std::assert_eq!(configured_route.routing_decision, std::option::Option::Some(std::string::String::from("enhance")));
+}
+
 #[tokio::test]
 async fn test_simple_task_does_not_decompose() {
     // Test: Validates simple tasks bypass decomposition and go through enhancement flow.
@@ -615,3 +653,109 @@ async fn test_simple_task_does_not_decompose() {
     std::println!("  - No decomposition occurred");
     std::println!("  - Enhancements generated normally");
 }
+
+/// Enhancement and comprehension test port answering instantly, after a yield so concurrent runs interleave.
+struct InstantPorts;
+
+#[async_trait::async_trait]
+impl task_orchestrator::ports::task_enhancement_port::TaskEnhancementPort for InstantPorts {
+    async fn generate_enhancement(
+        &self,
+        task: &task_manager::domain::task::Task,
+    ) -> std::result::Result<task_manager::domain::enhancement::Enhancement, std::string::String> {
+        tokio::task::yield_now().await;
+        std::result::Result::Ok(task_manager::domain::enhancement::Enhancement {
+            enhancement_id: uuid::Uuid::new_v4().to_string(),
+            task_id: task.id.clone(),
+            timestamp: chrono::Utc::now(),
+            enhancement_type: std::string::String::from("rewrite"),
+            content: std::format!("Enhanced: {}", task.title),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl task_orchestrator::ports::comprehension_test_port::ComprehensionTestPort for InstantPorts {
+    async fn generate_comprehension_test(
+        &self,
+        task: &task_manager::domain::task::Task,
+        test_type: &str,
+    ) -> std::result::Result<task_manager::domain::comprehension_test::ComprehensionTest, std::string::String> {
+        tokio::task::yield_now().await;
+        std::result::Result::Ok(task_manager::domain::comprehension_test::ComprehensionTest {
+            test_id: uuid::Uuid::new_v4().to_string(),
+            task_id: task.id.clone(),
+            timestamp: chrono::Utc::now(),
+            test_type: std::string::String::from(test_type),
+            question: std::format!("What does '{}' deliver?", task.title),
+            options: std::option::Option::None,
+            correct_answer: std::string::String::from("A"),
+            description_hash: std::option::Option::None,
+            stale: false,
+        })
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_concurrent_runs_write_whole_metrics_lines() {
+    // Test: Validates tasks run concurrently through a runner recording to a JSONL collector leave one parseable line per call.
+    // Justification: Concurrent runs used to interleave partial records; the file must be readable after a real run.
+    let dir = std::env::temp_dir().join(std::format!("e2e_metrics_{}", uuid::Uuid::new_v4()));
+    let template = dir.join("{project}.jsonl");
+    let collector = std::sync::Arc::new(task_orchestrator::adapters::jsonl_metrics_collector::JsonlMetricsCollector::new(
+        &template.to_string_lossy(),
+        "billing",
+    ));
+    let ports = std::sync::Arc::new(InstantPorts);
+    let runner = std::sync::Arc::new(
+        task_orchestrator::use_cases::task_graph_runner::TaskGraphRunner::new(
+            ports.clone(),
+            ports,
+            std::string::String::from("short_answer"),
+        )
+        .with_metrics(collector.clone(), "ollama", "llama3.1"),
+    );
+
+    let runs: std::vec::Vec<_> = (0..16)
+        .map(|n| {
+            let runner = runner.clone();
+            tokio::spawn(async move {
+                let action_item = transcript_extractor::domain::action_item::ActionItem {
+                    title: std::format!("Write release notes {}", n),
+                    assignee: std::option::Option::None,
+                    due_date: std::option::Option::None,
+                };
+                runner
+                    .run_task(task_manager::domain::task::Task::from_action_item(&action_item, std::option::Option::None))
+                    .await
+            })
+        })
+        .collect();
+    for run in runs {
+        run.await.unwrap().expect("run should complete");
+    }
+
+    let content = std::fs::read_to_string(collector.path()).unwrap();
+    let lines: std::vec::Vec<&str> = content.lines().collect();
+    std::assert_eq!(lines.len(), 32, "one enhancement and one test call per run");
+    for line in &lines {
+        std::assert!(
+            serde_json::from_str::<task_orchestrator::domain::performance_metrics::InferenceMetrics>(line).is_ok(),
+            "corrupt metrics line: {}",
+            line
+        );
+    }
+
+    let metrics = task_orchestrator::adapters::jsonl_metrics_collector::tail_metrics(
+        collector.path(),
+        std::option::Option::None,
+        std::option::Option::None,
+    )
+    .unwrap();
+    let enhancements = metrics.iter().filter(|metric| metric.operation_type == "enhancement").count();
+    let tests = metrics.iter().filter(|metric| metric.operation_type == "comprehension_test").count();
+    std::assert_eq!((enhancements, tests), (16, 16));
+    std::assert!(metrics.iter().all(|metric| metric.success && metric.provider == "ollama"));
+
+    std::fs::remove_dir_all(&dir).ok();
+}
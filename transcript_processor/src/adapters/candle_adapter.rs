@@ -1,11 +1,15 @@
 //! Candle-based transcript extractor adapter for embedded ML inference.
 //!
 //! This adapter implements the TranscriptExtractorPort using the Candle ML framework
-//! with a Phi-3.5-mini-instruct model for on-device, embedded extraction of action items from transcripts.
+//! with a phi3-family model (default Phi-3.5-mini-instruct, selectable via CANDLE_MODEL) for on-device,
+//! embedded extraction of action items from transcripts.
 //! Unlike the Ollama adapter which requires an external service, this adapter runs
 //! the model inference entirely in-process using Rust-native tensor operations.
 //!
 //! Revision History
+//! - 2026-10-17T06:30:00Z @AI: Resolve response field names through configurable FieldAliases via with_field_aliases.
+//! - 2026-10-17T04:30:00Z @AI: Build the prompt from a configurable ExtractionPromptTemplate via with_prompt_template.
+//! - 2026-10-16T10:10:00Z @AI: Select the Hugging Face model via `model_id` (CANDLE_MODEL) from CandleModelSpec; key the model cache by model ID.
 //! - 2025-11-23T22:10:00Z @AI: Update imports from task_manager::utils to task_manager::infrastructure (HEXSER compliance).
 //! - 2025-11-08T08:57:00Z @AI: Add process-wide in-memory cache for Candle model/tokenizer to skip rebuild; env CANDLE_DISABLE_MODEL_CACHE to bypass.
 //! - 2025-11-08T08:39:30Z @AI: Delegate tolerant JSON parsing to task_manager::infrastructure for shared reuse across adapters.
@@ -29,30 +33,32 @@
 //! - 2025-11-06T21:11:00Z @AI: Fix compilation errors - add IndexOp import, fix model.forward() signature, fix Tensor::new() usage.
 //! - 2025-11-06T21:00:00Z @AI: Initial CandleExtractorAdapter implementation with Phi-2.
 
-/// Adapter for extracting action items using Candle framework with Phi-3.5-mini-instruct model.
+/// Adapter for extracting action items using Candle framework with a phi3-family model.
 ///
 /// This struct implements the TranscriptExtractorPort by loading and running
-/// a Phi-3.5-mini-instruct language model entirely in-process using the Candle ML framework.
+/// a phi3-family language model (see `CandleModelSpec`) entirely in-process using the Candle ML framework.
 /// The model and tokenizer are loaded from HuggingFace Hub at initialization
 /// and kept in memory for subsequent inference calls.
 ///
 /// # Fields
 ///
-/// * `model` - The loaded Phi-3.5-mini-instruct model for text generation.
+/// * `model` - The loaded phi3-family model for text generation.
 /// * `tokenizer` - The tokenizer for encoding prompts and decoding responses.
 /// * `device` - The compute device (CPU or GPU) for tensor operations.
+/// * `prompt_template` - The extraction prompt template (built-in default unless configured).
+/// * `field_aliases` - Field names accepted in the model's JSON (built-in default unless configured).
 ///
 /// # Examples
 ///
 /// ```no_run
 /// # use transcript_processor::adapters::candle_adapter::CandleTranscriptExtractorAdapter;
 /// # async fn example() -> anyhow::Result<()> {
-/// let adapter = CandleTranscriptExtractorAdapter::new().await?;
+/// let adapter = CandleTranscriptExtractorAdapter::new(std::string::String::from("microsoft/Phi-3.5-mini-instruct")).await?;
 /// // Use adapter to extract tasks from transcript text
 /// # Ok(())
 /// # }
 /// ```
-static CANDLE_MODEL_CACHE: std::sync::OnceLock<std::sync::Arc<(std::sync::Arc<std::sync::RwLock<candle_transformers::models::phi3::Model>>, std::sync::Arc<tokenizers::Tokenizer>, std::sync::Arc<candle_core::Device>, std::sync::Arc<candle_transformers::models::phi3::Config>)>> = std::sync::OnceLock::new();
+static CANDLE_MODEL_CACHE: std::sync::OnceLock<std::sync::Mutex<std::collections::HashMap<String, std::sync::Arc<(std::sync::Arc<std::sync::RwLock<candle_transformers::models::phi3::Model>>, std::sync::Arc<tokenizers::Tokenizer>, std::sync::Arc<candle_core::Device>, std::sync::Arc<candle_transformers::models::phi3::Config>)>>>> = std::sync::OnceLock::new();
 
 #[derive(hexser::HexAdapter)]
 pub struct CandleTranscriptExtractorAdapter {
@@ -60,30 +66,38 @@ pub struct CandleTranscriptExtractorAdapter {
     tokenizer: std::sync::Arc<tokenizers::Tokenizer>,
     device: std::sync::Arc<candle_core::Device>,
     config: std::sync::Arc<candle_transformers::models::phi3::Config>,
+    prompt_template: crate::domain::extraction_prompt_template::ExtractionPromptTemplate,
+    field_aliases: task_manager::infrastructure::llm_parsers::field_aliases::FieldAliases,
 }
 
 impl CandleTranscriptExtractorAdapter {
-    /// Creates a new CandleTranscriptExtractorAdapter by loading Phi-3.5-mini-instruct from HuggingFace Hub.
+    /// Creates a new CandleTranscriptExtractorAdapter by loading the selected model from HuggingFace Hub.
     ///
-    /// This constructor downloads the Phi-3.5-mini-instruct model weights and tokenizer from HuggingFace
-    /// (if not already cached) and loads them into memory for inference. The model is
-    /// loaded onto the CPU device by default.
+    /// This constructor validates `model_id` against the supported phi3-family models,
+    /// then downloads its weights and tokenizer from HuggingFace (if not already cached)
+    /// and loads them into memory for inference. The model is loaded onto the CPU
+    /// device by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_id` - Hugging Face repository ID; see `CandleModelSpec::supported()`.
     ///
     /// # Returns
     ///
     /// * `Ok(CandleTranscriptExtractorAdapter)` - Successfully loaded adapter.
-    /// * `Err(anyhow::Error)` - Failed to load model or tokenizer.
+    /// * `Err(anyhow::Error)` - Unsupported model ID, or failed to load model or tokenizer.
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use transcript_processor::adapters::candle_adapter::CandleTranscriptExtractorAdapter;
+    /// # use transcript_processor::adapters::candle_model_spec::CandleModelSpec;
     /// # async fn example() -> anyhow::Result<()> {
-    /// let adapter = CandleTranscriptExtractorAdapter::new().await?;
+    /// let adapter = CandleTranscriptExtractorAdapter::new(CandleModelSpec::model_id_from_env()).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn new() -> anyhow::Result<Self> {
+    pub async fn new(model_id: String) -> anyhow::Result<Self> {
         // Local helper to always flush stdout so progress appears even when tests capture output.
         fn log(msg: &str) {
             println!("{}", msg);
@@ -91,6 +105,11 @@ impl CandleTranscriptExtractorAdapter {
             let _ = std::io::Write::flush(&mut out);
         }
 
+        // Validate the model before any device or network work so bad IDs fail fast.
+        let spec = crate::adapters::candle_model_spec::CandleModelSpec::resolve(&model_id)
+            .map_err(|e| anyhow::anyhow!(e))?;
+        log(&std::format!("[Candle] Selected model: {} ({})", spec.model_id, spec.approx_download));
+
         log("[Candle] Selecting compute device (auto-detect: Metal/CUDA/CPU)...");
         // Determine desired device from env or auto-detect. GPU backends require compiling candle-core with corresponding features.
         let requested = std::env::var("CANDLE_DEVICE").unwrap_or_else(|_| std::string::String::from("auto"));
@@ -274,13 +293,18 @@ impl CandleTranscriptExtractorAdapter {
         let disable_cache_env = std::env::var("CANDLE_DISABLE_MODEL_CACHE").unwrap_or_else(|_| std::string::String::from("false"));
         let disable_cache = matches!(disable_cache_env.to_ascii_lowercase().as_str(), "1" | "true" | "yes");
         if !disable_cache {
-            if let std::option::Option::Some(cache_arc) = CANDLE_MODEL_CACHE.get().cloned() {
+            let cached = CANDLE_MODEL_CACHE
+                .get()
+                .and_then(|cache| cache.lock().ok().and_then(|map| map.get(spec.model_id).cloned()));
+            if let std::option::Option::Some(cache_arc) = cached {
                 log("[Candle] Using cached in-memory model/tokenizer (set CANDLE_DISABLE_MODEL_CACHE=1 to rebuild).");
                 return std::result::Result::Ok(Self {
                     model: std::sync::Arc::clone(&cache_arc.0),
                     tokenizer: std::sync::Arc::clone(&cache_arc.1),
                     device: std::sync::Arc::clone(&cache_arc.2),
                     config: std::sync::Arc::clone(&cache_arc.3),
+                    prompt_template: crate::domain::extraction_prompt_template::ExtractionPromptTemplate::default(),
+                    field_aliases: task_manager::infrastructure::llm_parsers::field_aliases::FieldAliases::default(),
                 });
             }
         }
@@ -289,11 +313,11 @@ impl CandleTranscriptExtractorAdapter {
         // Download model and tokenizer from HuggingFace Hub
         let api = hf_hub::api::tokio::Api::new()?;
         let repo = api.repo(hf_hub::Repo::with_revision(
-            "microsoft/Phi-3.5-mini-instruct".to_string(),
+            spec.model_id.to_string(),
             hf_hub::RepoType::Model,
-            "main".to_string(),
+            spec.revision.to_string(),
         ));
-        log("[Candle] ✓ Repository handle ready (microsoft/Phi-3.5-mini-instruct)");
+        log(&std::format!("[Candle] ✓ Repository handle ready ({})", spec.model_id));
 
         // Download model files
         log("[Candle] Downloading tokenizer.json (first run may take minutes)...");
@@ -310,20 +334,15 @@ impl CandleTranscriptExtractorAdapter {
             config_path.display()
         ));
 
-        // Download sharded model weights (Phi-3.5-mini-instruct model is split across 2 files, ~7.6GB total)
-        log("[Candle] Downloading model weights shard 1/2 (model-00001-of-00002.safetensors)...");
-        let weights_path_1 = repo.get("model-00001-of-00002.safetensors").await?;
-        log(&std::format!(
-            "[Candle] ✓ Shard 1 ready at {}",
-            weights_path_1.display()
-        ));
-
-        log("[Candle] Downloading model weights shard 2/2 (model-00002-of-00002.safetensors)...");
-        let weights_path_2 = repo.get("model-00002-of-00002.safetensors").await?;
-        log(&std::format!(
-            "[Candle] ✓ Shard 2 ready at {}",
-            weights_path_2.display()
-        ));
+        // Download sharded model weights listed by the model spec
+        let shard_count = spec.weight_files.len();
+        let mut weight_paths = std::vec::Vec::with_capacity(shard_count);
+        for (index, file) in spec.weight_files.iter().enumerate() {
+            log(&std::format!("[Candle] Downloading model weights shard {}/{} ({})...", index + 1, shard_count, file));
+            let path = repo.get(file).await?;
+            log(&std::format!("[Candle] ✓ Shard {} ready at {}", index + 1, path.display()));
+            weight_paths.push(path);
+        }
 
         log("[Candle] Parsing config.json and loading tokenizer...");
         // Load configuration
@@ -340,14 +359,14 @@ impl CandleTranscriptExtractorAdapter {
         log("[Candle] Memory-mapping model weights (this may take several minutes on first run)...");
         let vb = unsafe {
             candle_nn::VarBuilder::from_mmaped_safetensors(
-                &[weights_path_1, weights_path_2],
+                &weight_paths,
                 candle_core::DType::F32,
                 &*device,
             )?
         };
         log("[Candle] ✓ Weights memory-mapped");
 
-        log("[Candle] Building Phi-3.5-mini-instruct model in memory...");
+        log(&std::format!("[Candle] Building {} model in memory...", spec.model_id));
         // Initialize the model
         let mut model = candle_transformers::models::phi3::Model::new(&config, vb)?;
         log("[Candle] ✓ Model initialized successfully");
@@ -380,7 +399,12 @@ impl CandleTranscriptExtractorAdapter {
                 std::sync::Arc::clone(&device),
                 std::sync::Arc::clone(&config_arc),
             ));
-            let _ = CANDLE_MODEL_CACHE.set(tuple_arc);
+            if let std::result::Result::Ok(mut map) = CANDLE_MODEL_CACHE
+                .get_or_init(|| std::sync::Mutex::new(std::collections::HashMap::new()))
+                .lock()
+            {
+                map.insert(std::string::String::from(spec.model_id), tuple_arc);
+            }
             log("[Candle] ✓ Cached model/tokenizer in memory for reuse within this process.");
         } else {
             log("[Candle] Cache disabled by CANDLE_DISABLE_MODEL_CACHE; not storing model in global cache.");
@@ -391,39 +415,48 @@ impl CandleTranscriptExtractorAdapter {
             tokenizer: tokenizer_arc,
             device,
             config: config_arc,
+            prompt_template: crate::domain::extraction_prompt_template::ExtractionPromptTemplate::default(),
+            field_aliases: task_manager::infrastructure::llm_parsers::field_aliases::FieldAliases::default(),
         })
     }
 
-    /// Constructs the system prompt for the LLM extraction task.
+    /// Replaces the built-in extraction prompt with a custom template.
+    ///
+    /// # Arguments
+    ///
+    /// * `prompt_template` - The template to render for each transcript.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_prompt_template(
+        mut self,
+        prompt_template: crate::domain::extraction_prompt_template::ExtractionPromptTemplate,
+    ) -> Self {
+        self.prompt_template = prompt_template;
+        self
+    }
+
+    /// Adds custom field aliases to the response parser.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_aliases` - Built-in plus configured field names.
     ///
-    /// This prompt instructs the model to extract action items from a transcript
-    /// and format them as a JSON array matching the ActionItem schema. This uses
-    /// the same prompt pattern as the Ollama adapter to ensure consistent behavior.
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_field_aliases(mut self, field_aliases: task_manager::infrastructure::llm_parsers::field_aliases::FieldAliases) -> Self {
+        self.field_aliases = field_aliases;
+        self
+    }
+
+    /// Constructs the prompt for the LLM extraction task.
+    ///
+    /// Renders the configured template with the transcript. The built-in
+    /// template is shared with the Ollama adapter to ensure consistent behavior.
     fn build_extraction_prompt(&self, transcript: &str) -> String {
-        std::format!(
-            r#"Extract all action items from the following meeting transcript.
-Return ONLY a valid JSON array of objects, where each object has this exact structure:
-{{
-  "title": "Brief task title",
-  "assignee": "Name of person assigned (or null if not specified)",
-  "due_date": "YYYY-MM-DD format (or null if not specified)"
-}}
-
-IMPORTANT: Pay close attention to who is assigned each task. Look for patterns like:
-- "I'll take ownership of..." -> extract the speaker's name
-- "James will complete..." -> assignee is "James"
-- "Maria can implement..." -> assignee is "Maria"
-- "Let's have David..." -> assignee is "David"
-- "Emily should..." -> assignee is "Emily"
-
-Extract the person's first name only. If no assignee is clearly identified, use null.
-
-Transcript:
-{}
-
-Respond with ONLY the JSON array, no other text."#,
-            transcript
-        )
+        self.prompt_template.render(transcript)
     }
 
     /// Generates text using the Candle model with the given prompt.
@@ -633,13 +666,14 @@ Respond with ONLY the JSON array, no other text."#,
         response_text: &str,
     ) -> std::result::Result<std::vec::Vec<crate::domain::action_item::ActionItem>, String> {
         let parsed: std::vec::Vec<task_manager::infrastructure::dtos::extracted_action_item::ExtractedActionItem> =
-            task_manager::infrastructure::llm_parsers::action_item_parser::parse_action_items_tolerant(response_text)?;
+            task_manager::infrastructure::llm_parsers::action_item_parser::parse_action_items_with_aliases(response_text, &self.field_aliases)?;
         let mapped: std::vec::Vec<crate::domain::action_item::ActionItem> = parsed
             .into_iter()
             .map(|e| crate::domain::action_item::ActionItem {
                 title: e.title,
                 assignee: e.assignee,
                 due_date: e.due_date,
+                source_utterance: None,
             })
             .collect();
         std::result::Result::Ok(mapped)
@@ -682,31 +716,8 @@ mod tests {
 
         let transcript = "Alice will review the code by Friday.";
 
-        // Test the prompt format directly without requiring a full adapter instance
-        let prompt = std::format!(
-            r#"Extract all action items from the following meeting transcript.
-Return ONLY a valid JSON array of objects, where each object has this exact structure:
-{{
-  "title": "Brief task title",
-  "assignee": "Name of person assigned (or null if not specified)",
-  "due_date": "YYYY-MM-DD format (or null if not specified)"
-}}
-
-IMPORTANT: Pay close attention to who is assigned each task. Look for patterns like:
-- "I'll take ownership of..." -> extract the speaker's name
-- "James will complete..." -> assignee is "James"
-- "Maria can implement..." -> assignee is "Maria"
-- "Let's have David..." -> assignee is "David"
-- "Emily should..." -> assignee is "Emily"
-
-Extract the person's first name only. If no assignee is clearly identified, use null.
-
-Transcript:
-{}
-
-Respond with ONLY the JSON array, no other text."#,
-            transcript
-        );
+        // Test the default template directly without requiring a full adapter instance
+        let prompt = crate::domain::extraction_prompt_template::ExtractionPromptTemplate::default().render(transcript);
 
         assert!(prompt.contains(transcript));
         assert!(prompt.contains("JSON array"));
@@ -765,4 +776,15 @@ Hope this helps!"#;
         assert_eq!(items.len(), 1);
         assert_eq!(items[0].title, "Task 1");
     }
+
+    #[tokio::test]
+    async fn test_new_rejects_unknown_model_before_download() {
+        // Test: Validates that an unsupported model ID fails in the constructor with the supported list.
+        // Justification: Bad CANDLE_MODEL values must fail fast rather than deep in download or inference.
+        let result = CandleTranscriptExtractorAdapter::new(std::string::String::from("not-a/real-model")).await;
+
+        let err = result.err().expect("unknown model must be rejected").to_string();
+        assert!(err.contains("Unsupported Candle model 'not-a/real-model'"));
+        assert!(err.contains(crate::adapters::candle_model_spec::CandleModelSpec::DEFAULT_MODEL_ID));
+    }
 }
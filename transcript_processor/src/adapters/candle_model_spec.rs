@@ -0,0 +1,151 @@
+//! Defines the Hugging Face models supported by the Candle transcript extractor.
+//!
+//! The Candle adapter builds a `phi3` architecture model in-process, so only
+//! repositories whose config and sharded safetensors match that architecture
+//! can be loaded. CandleModelSpec enumerates those repositories and resolves a
+//! requested model ID up front, failing with the supported list instead of
+//! deep inside download or inference.
+//!
+//! Revision History
+//! - 2026-10-16T10:10:00Z @AI: Initial CandleModelSpec registry with CANDLE_MODEL env selection.
+
+/// Describes a Hugging Face repository loadable by the Candle adapter.
+///
+/// # Fields
+///
+/// * `model_id` - Hugging Face repository ID (e.g., "microsoft/Phi-3.5-mini-instruct").
+/// * `revision` - Git revision to download.
+/// * `weight_files` - Sharded safetensors files holding the model weights.
+/// * `approx_download` - Human-readable approximate download size.
+///
+/// # Examples
+///
+/// ```
+/// # use transcript_processor::adapters::candle_model_spec::CandleModelSpec;
+/// let spec = CandleModelSpec::resolve("microsoft/Phi-3.5-mini-instruct").unwrap();
+/// std::assert_eq!(spec.weight_files.len(), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CandleModelSpec {
+    /// Hugging Face repository ID.
+    pub model_id: &'static str,
+
+    /// Git revision to download.
+    pub revision: &'static str,
+
+    /// Sharded safetensors files holding the model weights.
+    pub weight_files: &'static [&'static str],
+
+    /// Human-readable approximate download size.
+    pub approx_download: &'static str,
+}
+
+/// Weight shards shared by the two-file Phi-3 family checkpoints.
+const PHI3_TWO_SHARDS: &[&str] = &["model-00001-of-00002.safetensors", "model-00002-of-00002.safetensors"];
+
+/// Models known to load with `candle_transformers::models::phi3`.
+const SUPPORTED_MODELS: &[CandleModelSpec] = &[
+    CandleModelSpec {
+        model_id: "microsoft/Phi-3.5-mini-instruct",
+        revision: "main",
+        weight_files: PHI3_TWO_SHARDS,
+        approx_download: "~7.6GB",
+    },
+    CandleModelSpec {
+        model_id: "microsoft/Phi-3-mini-4k-instruct",
+        revision: "main",
+        weight_files: PHI3_TWO_SHARDS,
+        approx_download: "~7.6GB",
+    },
+    CandleModelSpec {
+        model_id: "microsoft/Phi-3-mini-128k-instruct",
+        revision: "main",
+        weight_files: PHI3_TWO_SHARDS,
+        approx_download: "~7.6GB",
+    },
+];
+
+impl CandleModelSpec {
+    /// Model used when neither a parameter nor `CANDLE_MODEL` selects one.
+    pub const DEFAULT_MODEL_ID: &'static str = "microsoft/Phi-3.5-mini-instruct";
+
+    /// Environment variable that selects the Candle model.
+    pub const MODEL_ENV_VAR: &'static str = "CANDLE_MODEL";
+
+    /// Returns all supported model specifications.
+    pub fn supported() -> &'static [CandleModelSpec] {
+        SUPPORTED_MODELS
+    }
+
+    /// Resolves a model ID (case-insensitive) to its specification.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_id` - The requested Hugging Face repository ID.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(&CandleModelSpec)` - The matching specification.
+    /// * `Err(String)` - The model is unsupported; the message lists supported IDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use transcript_processor::adapters::candle_model_spec::CandleModelSpec;
+    /// let err = CandleModelSpec::resolve("meta-llama/Llama-3.2-1B").unwrap_err();
+    /// std::assert!(err.contains("microsoft/Phi-3.5-mini-instruct"));
+    /// ```
+    pub fn resolve(model_id: &str) -> std::result::Result<&'static CandleModelSpec, String> {
+        let requested = model_id.trim();
+        SUPPORTED_MODELS
+            .iter()
+            .find(|spec| spec.model_id.eq_ignore_ascii_case(requested))
+            .ok_or_else(|| {
+                let supported: Vec<&str> = SUPPORTED_MODELS.iter().map(|spec| spec.model_id).collect();
+                std::format!(
+                    "Unsupported Candle model '{}'. Supported models: {}",
+                    requested,
+                    supported.join(", ")
+                )
+            })
+    }
+
+    /// Returns the model ID from `CANDLE_MODEL`, or the default when unset or blank.
+    pub fn model_id_from_env() -> String {
+        std::env::var(Self::MODEL_ENV_VAR)
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+            .unwrap_or_else(|| std::string::String::from(Self::DEFAULT_MODEL_ID))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_supported_models_not_empty() {
+        // Test: Validates that at least one model is supported and the default resolves.
+        // Justification: The adapter cannot start without a loadable model.
+        std::assert!(!super::CandleModelSpec::supported().is_empty());
+        std::assert!(super::CandleModelSpec::resolve(super::CandleModelSpec::DEFAULT_MODEL_ID).is_ok());
+    }
+
+    #[test]
+    fn test_resolve_is_case_insensitive() {
+        // Test: Validates case-insensitive matching of model IDs.
+        // Justification: Env values are often typed by hand.
+        let spec = super::CandleModelSpec::resolve(" MICROSOFT/phi-3-mini-4k-instruct ").unwrap();
+        std::assert_eq!(spec.model_id, "microsoft/Phi-3-mini-4k-instruct");
+    }
+
+    #[test]
+    fn test_unknown_model_lists_supported_options() {
+        // Test: Validates that an unknown ID yields a descriptive error.
+        // Justification: Users must learn the valid choices before any download starts.
+        let err = super::CandleModelSpec::resolve("mistralai/Mistral-7B-v0.1").unwrap_err();
+
+        std::assert!(err.contains("Unsupported Candle model 'mistralai/Mistral-7B-v0.1'"));
+        for spec in super::CandleModelSpec::supported() {
+            std::assert!(err.contains(spec.model_id));
+        }
+    }
+}
@@ -8,6 +8,7 @@
 //! use, consider replacing with a persistent storage adapter (e.g., database).
 //!
 //! Revision History
+//! - 2026-10-17T04:10:00Z @AI: Support the BySourceTranscript filter.
 //! - 2025-11-06T18:14:00Z @AI: Rewrite to implement HEXSER Repository and QueryRepository traits.
 //! - 2025-11-06T18:00:00Z @AI: Initial InMemoryTaskAdapter implementation.
 
@@ -100,6 +101,12 @@ impl hexser::ports::repository::QueryRepository<crate::domain::task::Task>
                     })
                     .cloned()
             }
+            crate::application::ports::task_repository_port::TaskFilter::BySourceTranscript(transcript_id) => {
+                tasks
+                    .values()
+                    .find(|task| task.source_transcript_id.as_ref() == std::option::Option::Some(transcript_id))
+                    .cloned()
+            }
             crate::application::ports::task_repository_port::TaskFilter::All => {
                 tasks.values().next().cloned()
             }
@@ -139,6 +146,13 @@ impl hexser::ports::repository::QueryRepository<crate::domain::task::Task>
                     .cloned()
                     .collect()
             }
+            crate::application::ports::task_repository_port::TaskFilter::BySourceTranscript(transcript_id) => {
+                tasks
+                    .values()
+                    .filter(|task| task.source_transcript_id.as_ref() == std::option::Option::Some(transcript_id))
+                    .cloned()
+                    .collect()
+            }
             crate::application::ports::task_repository_port::TaskFilter::All => {
                 tasks.values().cloned().collect()
             }
@@ -219,6 +233,7 @@ mod tests {
             title: std::string::String::from(title),
             assignee: std::option::Option::None,
             due_date: std::option::Option::None,
+            source_utterance: std::option::Option::None,
         };
 
         let mut task = crate::domain::task::Task::from_action_item(&action, std::option::Option::None);
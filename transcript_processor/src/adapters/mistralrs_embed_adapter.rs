@@ -62,7 +62,7 @@ Respond with ONLY the JSON array, no other text."#,
             task_manager::infrastructure::llm_parsers::action_item_parser::parse_action_items_tolerant(response_text)?;
         let mapped: std::vec::Vec<crate::domain::action_item::ActionItem> = parsed
             .into_iter()
-            .map(|e| crate::domain::action_item::ActionItem { title: e.title, assignee: e.assignee, due_date: e.due_date })
+            .map(|e| crate::domain::action_item::ActionItem { title: e.title, assignee: e.assignee, due_date: e.due_date, source_utterance: None })
             .collect();
         std::result::Result::Ok(mapped)
     }
@@ -7,12 +7,15 @@
 //! The adapters in this module include:
 //! - OllamaTranscriptExtractorAdapter: Implements TranscriptExtractorPort using the Ollama LLM service
 //! - CandleTranscriptExtractorAdapter: Implements TranscriptExtractorPort using the Candle ML framework
+//! - OpenAiTranscriptExtractorAdapter: Implements TranscriptExtractorPort against any OpenAI-compatible endpoint
 //! - InMemoryTaskAdapter: Implements TaskRepositoryPort using an in-memory data structure
 //!
 //! These adapters are the "driven" components in the Hexagonal Architecture,
 //! implementing the abstract interfaces (ports) defined by the application layer.
 //!
 //! Revision History
+//! - 2026-10-16T10:30:00Z @AI: Add OpenAiTranscriptExtractorAdapter for OpenAI-compatible endpoints.
+//! - 2026-10-16T10:10:00Z @AI: Add candle_model_spec registry for Candle model selection.
 //! - 2025-11-08T11:36:00Z @AI: Add optional embedded MistralRs adapter module (feature: mistralrs_embed).
 //! - 2025-11-08T10:55:00Z @AI: Add optional RigTranscriptExtractorAdapter module (feature: rig_adapter).
 //! - 2025-11-08T09:26:00Z @AI: Add optional MistralTranscriptExtractorAdapter module (feature: mistral_rs).
@@ -22,6 +25,8 @@
 
 pub mod ollama_adapter;
 pub mod candle_adapter;
+pub mod candle_model_spec;
+pub mod openai_adapter;
 pub mod in_memory_task_adapter;
 #[cfg(feature = "mistral_rs")]
 pub mod mistral_adapter;
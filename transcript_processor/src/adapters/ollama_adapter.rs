@@ -8,6 +8,7 @@
 //! to extract action items in a specific JSON format matching the ActionItem schema.
 //!
 //! Revision History
+//! - 2026-10-17T04:30:00Z @AI: Build the prompt from a configurable ExtractionPromptTemplate via with_prompt_template.
 //! - 2025-11-06T20:45:00Z @AI: Fix assignee extraction by correcting JSON field name mismatch (assigned_to -> assignee).
 //! - 2025-11-06T18:56:00Z @AI: Rename OllamaExtractorAdapter to OllamaTranscriptExtractorAdapter for clarity.
 //! - 2025-11-06T18:14:00Z @AI: Add HexAdapter derive, fix method name to match port trait.
@@ -23,6 +24,7 @@
 ///
 /// * `model_name` - The name of the Ollama model to use (e.g., "phi3:mini").
 /// * `ollama_client` - The Ollama client instance for API communication.
+/// * `prompt_template` - The extraction prompt template (built-in default unless configured).
 ///
 /// # Examples
 ///
@@ -35,6 +37,7 @@
 pub struct OllamaTranscriptExtractorAdapter {
     model_name: String,
     ollama_client: ollama_rs::Ollama,
+    prompt_template: crate::domain::extraction_prompt_template::ExtractionPromptTemplate,
 }
 
 impl OllamaTranscriptExtractorAdapter {
@@ -59,41 +62,45 @@ impl OllamaTranscriptExtractorAdapter {
         Self {
             model_name,
             ollama_client,
+            prompt_template: crate::domain::extraction_prompt_template::ExtractionPromptTemplate::default(),
         }
     }
 
-    /// Constructs the system prompt for the LLM extraction task.
+    /// Replaces the built-in extraction prompt with a custom template.
     ///
-    /// This prompt instructs the model to extract action items from a transcript
-    /// and format them as a JSON array matching the ActionItem schema.
+    /// # Arguments
+    ///
+    /// * `prompt_template` - The template to render for each transcript.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use transcript_processor::adapters::ollama_adapter::OllamaTranscriptExtractorAdapter;
+    /// # use transcript_processor::domain::extraction_prompt_template::ExtractionPromptTemplate;
+    /// let template = ExtractionPromptTemplate::new(std::string::String::from("TODO: lines are tasks.\n{transcript}")).unwrap();
+    /// let adapter = OllamaTranscriptExtractorAdapter::new(std::string::String::from("phi3:mini"))
+    ///     .with_prompt_template(template);
+    /// ```
+    pub fn with_prompt_template(
+        mut self,
+        prompt_template: crate::domain::extraction_prompt_template::ExtractionPromptTemplate,
+    ) -> Self {
+        self.prompt_template = prompt_template;
+        self
+    }
+
+    /// Constructs the prompt for the LLM extraction task.
     ///
-    /// The prompt emphasizes assignee extraction by providing examples of how
-    /// people are assigned tasks in conversations (e.g., "I'll take", "James will").
+    /// Renders the configured template with the transcript. The built-in
+    /// template asks for a JSON array matching the ActionItem schema and
+    /// emphasizes assignee extraction with examples of how people are assigned
+    /// tasks in conversations (e.g., "I'll take", "James will").
     fn build_extraction_prompt(&self, transcript: &str) -> String {
-        std::format!(
-            r#"Extract all action items from the following meeting transcript.
-Return ONLY a valid JSON array of objects, where each object has this exact structure:
-{{
-  "title": "Brief task title",
-  "assignee": "Name of person assigned (or null if not specified)",
-  "due_date": "YYYY-MM-DD format (or null if not specified)"
-}}
-
-IMPORTANT: Pay close attention to who is assigned each task. Look for patterns like:
-- "I'll take ownership of..." -> extract the speaker's name
-- "James will complete..." -> assignee is "James"
-- "Maria can implement..." -> assignee is "Maria"
-- "Let's have David..." -> assignee is "David"
-- "Emily should..." -> assignee is "Emily"
-
-Extract the person's first name only. If no assignee is clearly identified, use null.
-
-Transcript:
-{}
-
-Respond with ONLY the JSON array, no other text."#,
-            transcript
-        )
+        self.prompt_template.render(transcript)
     }
 
     /// Parses the LLM response string into a vector of ActionItem entities.
@@ -169,6 +176,21 @@ mod tests {
         assert!(prompt.contains("JSON array"));
     }
 
+    #[test]
+    fn test_build_extraction_prompt_uses_custom_template() {
+        // Test: Validates a configured template replaces the built-in prompt.
+        // Justification: Teams with their own meeting conventions (e.g. "TODO:" markers) rely on their template being used.
+        let template = crate::domain::extraction_prompt_template::ExtractionPromptTemplate::new(
+            std::string::String::from("Every TODO: line is an action item. Return a JSON array.\n{transcript}"),
+        )
+        .unwrap();
+        let adapter = OllamaTranscriptExtractorAdapter::new(std::string::String::from("test-model")).with_prompt_template(template);
+
+        let prompt = adapter.build_extraction_prompt("TODO: John reviews the document");
+
+        assert_eq!(prompt, "Every TODO: line is an action item. Return a JSON array.\nTODO: John reviews the document");
+    }
+
     #[test]
     fn test_parse_response_valid_json() {
         // Test: Validates that the parser correctly deserializes valid JSON into ActionItem structs.
@@ -0,0 +1,319 @@
+//! OpenAI-compatible transcript extractor adapter via the chat-completions API.
+//!
+//! This adapter implements the TranscriptExtractorPort against any server that
+//! speaks the OpenAI chat-completions protocol (OpenAI, vLLM, LM Studio). It
+//! requests JSON-schema structured output matching the ActionItem shape and
+//! still parses the reply with the shared tolerant parser from
+//! task_manager::infrastructure, since local servers do not always honor the schema.
+//!
+//! Configuration:
+//! - Env:
+//!   - OPENAI_BASE_URL (default: https://api.openai.com/v1)
+//!   - OPENAI_API_KEY (optional; omitted for local servers that need no auth)
+//!   - OPENAI_MODEL (default: gpt-4o-mini)
+//!   - FIELD_ALIASES (optional; JSON file of extra field names, applied by main via with_field_aliases)
+//!
+//! Example run:
+//!   EXTRACTOR=openai OPENAI_BASE_URL=http://127.0.0.1:8000/v1 OPENAI_MODEL=Qwen/Qwen2.5-7B-Instruct cargo run -p transcript_processor
+//!
+//! Revision History
+//! - 2026-10-18T05:10:00Z @AI: Serve test responses through task_manager::test_support::mock_http_server.
+//! - 2026-10-17T06:30:00Z @AI: Resolve response field names through configurable FieldAliases via with_field_aliases.
+//! - 2026-10-16T10:30:00Z @AI: Initial OpenAiTranscriptExtractorAdapter with JSON-schema response format and tolerant parsing.
+
+/// Adapter for extracting action items from any OpenAI-compatible endpoint.
+///
+/// # Fields
+///
+/// * `base_url` - API base URL including the version segment (e.g., "https://api.openai.com/v1").
+/// * `api_key` - Optional bearer token sent in the Authorization header.
+/// * `model_name` - Model identifier passed in the request body.
+/// * `http` - Shared reqwest client.
+/// * `field_aliases` - Field names accepted in the model's JSON (built-in default unless configured).
+///
+/// # Examples
+///
+/// ```
+/// # use transcript_processor::adapters::openai_adapter::OpenAiTranscriptExtractorAdapter;
+/// let adapter = OpenAiTranscriptExtractorAdapter::with_endpoint(
+///     std::string::String::from("http://127.0.0.1:1234/v1"),
+///     None,
+///     std::string::String::from("local-model"),
+/// );
+/// ```
+#[derive(hexser::HexAdapter)]
+pub struct OpenAiTranscriptExtractorAdapter {
+    base_url: String,
+    api_key: Option<String>,
+    model_name: String,
+    http: reqwest::Client,
+    field_aliases: task_manager::infrastructure::llm_parsers::field_aliases::FieldAliases,
+}
+
+impl OpenAiTranscriptExtractorAdapter {
+    /// Creates a new instance configured from environment variables.
+    ///
+    /// Uses OPENAI_BASE_URL, OPENAI_API_KEY, and OPENAI_MODEL with sensible defaults.
+    pub fn new() -> Self {
+        let base_url = std::env::var("OPENAI_BASE_URL")
+            .unwrap_or_else(|_| std::string::String::from("https://api.openai.com/v1"));
+        let api_key = std::env::var("OPENAI_API_KEY")
+            .ok()
+            .filter(|key| !key.trim().is_empty());
+        let model_name = std::env::var("OPENAI_MODEL")
+            .unwrap_or_else(|_| std::string::String::from("gpt-4o-mini"));
+        Self::with_endpoint(base_url, api_key, model_name)
+    }
+
+    /// Creates a new instance for an explicit endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - API base URL including the version segment.
+    /// * `api_key` - Optional bearer token.
+    /// * `model_name` - Model identifier.
+    ///
+    /// # Returns
+    ///
+    /// A new OpenAiTranscriptExtractorAdapter.
+    pub fn with_endpoint(base_url: String, api_key: Option<String>, model_name: String) -> Self {
+        Self {
+            base_url,
+            api_key,
+            model_name,
+            http: reqwest::Client::new(),
+            field_aliases: task_manager::infrastructure::llm_parsers::field_aliases::FieldAliases::default(),
+        }
+    }
+
+    /// Adds custom field aliases to the response parser.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_aliases` - Built-in plus configured field names.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_field_aliases(mut self, field_aliases: task_manager::infrastructure::llm_parsers::field_aliases::FieldAliases) -> Self {
+        self.field_aliases = field_aliases;
+        self
+    }
+
+    /// Builds the extraction prompt instructing the model to return the action_items object.
+    fn build_extraction_prompt(&self, transcript: &str) -> String {
+        std::format!(
+            r#"Extract all action items from the following meeting transcript.
+Return ONLY a JSON object of the form {{"action_items": [...]}}, where each item has this exact structure:
+{{
+  "title": "Brief task title",
+  "assignee": "Name of person assigned (or null if not specified)",
+  "due_date": "YYYY-MM-DD format (or null if not specified)"
+}}
+
+IMPORTANT: Pay close attention to who is assigned each task. Look for patterns like:
+- "I'll take ownership of..." -> extract the speaker's name
+- "James will complete..." -> assignee is "James"
+- "Maria can implement..." -> assignee is "Maria"
+- "Let's have David..." -> assignee is "David"
+- "Emily should..." -> assignee is "Emily"
+
+Extract the person's first name only. If no assignee is clearly identified, use null.
+
+Transcript:
+{}"#,
+            transcript
+        )
+    }
+
+    /// Returns the JSON-schema response format enforcing the ActionItem shape.
+    ///
+    /// Strict structured output requires an object root, so items are wrapped
+    /// in an `action_items` array.
+    fn response_format() -> serde_json::Value {
+        serde_json::json!({
+            "type": "json_schema",
+            "json_schema": {
+                "name": "transcript_action_items",
+                "strict": true,
+                "schema": {
+                    "type": "object",
+                    "properties": {
+                        "action_items": {
+                            "type": "array",
+                            "items": {
+                                "type": "object",
+                                "properties": {
+                                    "title": {"type": "string"},
+                                    "assignee": {"type": ["string", "null"]},
+                                    "due_date": {"type": ["string", "null"]}
+                                },
+                                "required": ["title", "assignee", "due_date"],
+                                "additionalProperties": false
+                            }
+                        }
+                    },
+                    "required": ["action_items"],
+                    "additionalProperties": false
+                }
+            }
+        })
+    }
+
+    /// Calls the chat completions endpoint and returns the raw content string.
+    async fn chat_completion(&self, user_prompt: &str) -> std::result::Result<String, String> {
+        let url = std::format!("{}/chat/completions", self.base_url.trim_end_matches('/'));
+        let body = serde_json::json!({
+            "model": self.model_name,
+            "messages": [
+                {"role": "system", "content": "You are a precise extraction engine. Output strictly valid JSON without any prose."},
+                {"role": "user", "content": user_prompt}
+            ],
+            "temperature": 0.0,
+            "response_format": Self::response_format()
+        });
+        let request = self.http.post(url).json(&body);
+        let request = match &self.api_key {
+            std::option::Option::Some(key) => request.bearer_auth(key),
+            std::option::Option::None => request,
+        };
+        let resp = request
+            .send()
+            .await
+            .map_err(|e| std::format!("HTTP error: {}", e))?;
+        if !resp.status().is_success() {
+            return std::result::Result::Err(std::format!("HTTP status {}", resp.status()));
+        }
+        let json: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| std::format!("Failed to decode JSON response: {}", e))?;
+        json.pointer("/choices/0/message/content")
+            .and_then(|content| content.as_str())
+            .map(std::string::String::from)
+            .ok_or_else(|| std::string::String::from("Missing content in chat completion response"))
+    }
+
+    /// Delegates tolerant parsing to shared task_manager infrastructure and maps into local domain type.
+    fn parse_response(&self, response_text: &str) -> std::result::Result<std::vec::Vec<crate::domain::action_item::ActionItem>, String> {
+        let parsed: std::vec::Vec<task_manager::infrastructure::dtos::extracted_action_item::ExtractedActionItem> =
+            task_manager::infrastructure::llm_parsers::action_item_parser::parse_action_items_with_aliases(response_text, &self.field_aliases)?;
+        let mapped: std::vec::Vec<crate::domain::action_item::ActionItem> = parsed
+            .into_iter()
+            .map(|e| crate::domain::action_item::ActionItem { title: e.title, assignee: e.assignee, due_date: e.due_date, source_utterance: None })
+            .collect();
+        std::result::Result::Ok(mapped)
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::application::ports::transcript_extractor_port::TranscriptExtractorPort for OpenAiTranscriptExtractorAdapter {
+    async fn extract_analysis(
+        &self,
+        transcript: &str,
+    ) -> std::result::Result<crate::domain::transcript_analysis::TranscriptAnalysis, std::string::String> {
+        let prompt = self.build_extraction_prompt(transcript);
+        let response_text = self.chat_completion(&prompt).await?;
+        let items = self.parse_response(&response_text)?;
+        std::result::Result::Ok(crate::domain::transcript_analysis::TranscriptAnalysis { action_items: items })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    /// Serves a single chat-completions response whose message content is `content`.
+    ///
+    /// Returns the base URL and a handle resolving to the raw HTTP request received.
+    async fn spawn_mock_server(content: &str) -> (String, tokio::task::JoinHandle<String>) {
+        let body = serde_json::json!({
+            "choices": [{"index": 0, "message": {"role": "assistant", "content": content}}]
+        })
+        .to_string();
+        let (base_url, handle) = task_manager::test_support::mock_http_server(move |_| ("200 OK", body)).await;
+        (std::format!("{}/v1", base_url), handle)
+    }
+
+    #[tokio::test]
+    async fn test_extracts_well_formed_response() {
+        // Test: Validates extraction from a schema-conforming response and the request shape.
+        // Justification: The adapter must send JSON-schema mode, the model, and the bearer token.
+        let content = r#"{"action_items": [{"title": "Write API docs", "assignee": "Alice", "due_date": "2025-11-15"}]}"#;
+        let (base_url, server) = spawn_mock_server(content).await;
+        let adapter = super::OpenAiTranscriptExtractorAdapter::with_endpoint(
+            base_url,
+            std::option::Option::Some(std::string::String::from("sk-test")),
+            std::string::String::from("test-model"),
+        );
+
+        let analysis = crate::application::ports::transcript_extractor_port::TranscriptExtractorPort::extract_analysis(
+            &adapter,
+            "Alice: I'll write the API docs by Nov 15.",
+        )
+        .await
+        .unwrap();
+        let request = server.await.unwrap();
+
+        assert_eq!(analysis.action_items.len(), 1);
+        assert_eq!(analysis.action_items[0].title, "Write API docs");
+        assert_eq!(analysis.action_items[0].assignee.as_deref(), Some("Alice"));
+        assert!(request.starts_with("POST /v1/chat/completions"));
+        assert!(request.to_lowercase().contains("authorization: bearer sk-test"));
+        assert!(request.contains("\"json_schema\""));
+        assert!(request.contains("\"test-model\""));
+    }
+
+    #[tokio::test]
+    async fn test_tolerant_parser_recovers_malformed_response() {
+        // Test: Validates recovery from prose-wrapped output using alias field names.
+        // Justification: Local OpenAI-compatible servers often ignore the response schema.
+        let content = "Sure! Here are the items:\n```json\n{\"action_items\": [{\"task\": \"Fix login bug\", \"owner\": \"Bob Smith\", \"deadline\": \"2025-11-30\"}]}\n```";
+        let (base_url, server) = spawn_mock_server(content).await;
+        let adapter = super::OpenAiTranscriptExtractorAdapter::with_endpoint(
+            base_url,
+            std::option::Option::None,
+            std::string::String::from("local-model"),
+        );
+
+        let analysis = crate::application::ports::transcript_extractor_port::TranscriptExtractorPort::extract_analysis(
+            &adapter,
+            "Bob will fix the login bug.",
+        )
+        .await
+        .unwrap();
+        let request = server.await.unwrap();
+
+        assert_eq!(analysis.action_items[0].title, "Fix login bug");
+        assert_eq!(analysis.action_items[0].assignee.as_deref(), Some("Bob"));
+        assert_eq!(analysis.action_items[0].due_date.as_deref(), Some("2025-11-30"));
+        assert!(!request.to_lowercase().contains("authorization:"));
+    }
+
+    #[tokio::test]
+    async fn test_configured_field_aliases_apply_to_responses() {
+        // Test: Validates aliases passed via with_field_aliases reach the response parser.
+        // Justification: FIELD_ALIASES is only useful if the adapter parses with it.
+        let content = r#"[{"headline": "Rotate keys", "driver": "Carol"}]"#;
+        let (base_url, server) = spawn_mock_server(content).await;
+        let aliases = task_manager::infrastructure::llm_parsers::field_aliases::FieldAliases::from_json(
+            r#"{"title": ["headline"], "assignee": ["driver"]}"#,
+        )
+        .unwrap();
+        let adapter = super::OpenAiTranscriptExtractorAdapter::with_endpoint(
+            base_url,
+            std::option::Option::None,
+            std::string::String::from("local-model"),
+        )
+        .with_field_aliases(aliases);
+
+        let analysis = crate::application::ports::transcript_extractor_port::TranscriptExtractorPort::extract_analysis(
+            &adapter,
+            "Carol rotates the keys.",
+        )
+        .await
+        .unwrap();
+        server.await.unwrap();
+
+        assert_eq!(analysis.action_items[0].title, "Rotate keys");
+        assert_eq!(analysis.action_items[0].assignee.as_deref(), Some("Carol"));
+    }
+}
@@ -76,6 +76,7 @@ Respond with ONLY the JSON array, no other text."#,
                 title: e.title,
                 assignee: e.assignee,
                 due_date: e.due_date,
+                source_utterance: None,
             })
             .collect();
         std::result::Result::Ok(mapped)
@@ -5,6 +5,7 @@
 //! Repository and QueryRepository traits to provide type-safe persistence operations.
 //!
 //! Revision History
+//! - 2026-10-17T04:10:00Z @AI: Add BySourceTranscript filter for re-processing de-duplication.
 //! - 2025-11-06T18:14:00Z @AI: Refactor to use HEXSER Repository pattern with filters and sort keys.
 //! - 2025-11-06T17:41:00Z @AI: Initial TaskRepositoryPort trait definition.
 
@@ -24,6 +25,9 @@ pub enum TaskFilter {
     /// Filter by assignee name.
     ByAssignee(String),
 
+    /// Filter by the ID of the transcript the task was extracted from.
+    BySourceTranscript(String),
+
     /// Return all tasks (no filtering).
     All,
 }
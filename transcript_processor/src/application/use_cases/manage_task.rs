@@ -237,6 +237,7 @@ mod tests {
             title: std::string::String::from("Test task"),
             assignee: None,
             due_date: None,
+            source_utterance: None,
         };
 
         let task = crate::domain::task::Task::from_action_item(&action, None);
@@ -5,6 +5,16 @@
 //! by depending on port interfaces rather than concrete implementations.
 //!
 //! Revision History
+//! - 2026-10-18T05:20:00Z @AI: Auto-triage tasks created from a transcript (priority, triage label, revision entry).
+//! - 2026-10-18T02:40:00Z @AI: Size transcript windows after subtracting the speaker context and language hint sent with each.
+//! - 2026-10-18T01:50:00Z @AI: Match re-extracted items to earlier tasks by idempotency key (same utterance) before title similarity.
+//! - 2026-10-17T20:20:00Z @AI: Test that an edited transcript file re-processed under its path-derived ID updates its tasks.
+//! - 2026-10-17T20:10:00Z @AI: Derive the transcript ID in process() from the content instead of a random UUID.
+//! - 2026-10-17T04:50:00Z @AI: Detect the transcript language (or use an explicit override) and prepend a language hint to the extractor input.
+//! - 2026-10-17T04:10:00Z @AI: Update tasks matched by title from earlier runs of the same transcript instead of duplicating them.
+//! - 2026-10-17T03:50:00Z @AI: Link each task to its transcript ID and source utterance via process_with_id.
+//! - 2026-10-16T09:40:00Z @AI: Chunk long transcripts to a token budget and merge near-duplicate items across chunks.
+//! - 2026-10-16T09:00:00Z @AI: Add speaker diarization pre-processing and assignee resolution.
 //! - 2025-11-06T18:56:00Z @AI: Update adapter name to OllamaTranscriptExtractorAdapter for clarity.
 //! - 2025-11-06T18:30:00Z @AI: Refactor to use generic concrete repository type (HEXSER pattern).
 //! - 2025-11-06T17:41:00Z @AI: Initial ProcessTranscriptUseCase implementation.
@@ -15,6 +25,19 @@
 /// extracting action items, converting them to tasks, and persisting them with
 /// full history tracking.
 ///
+/// Transcripts longer than the configured window are split into overlapping
+/// chunks on speaker/paragraph boundaries; action items from all chunks are
+/// merged by fuzzy title match before persistence.
+///
+/// The transcript's dominant language is detected (unless set explicitly
+/// with `with_language`) and passed to the extractor as a hint.
+///
+/// Re-processing a transcript under the same ID (e.g. after correcting it)
+/// updates the tasks created by earlier runs instead of duplicating them.
+///
+/// New tasks are auto-triaged with task_manager's TriageService, like tasks
+/// created through `rig add` or `rig parse`; updated tasks keep their triage.
+///
 /// This use case is generic over the repository type to enable compile-time
 /// polymorphism and mutable access to the repository for HEXSER's save() method.
 ///
@@ -43,6 +66,9 @@ where
 {
     extractor: std::sync::Arc<dyn crate::application::ports::transcript_extractor_port::TranscriptExtractorPort>,
     task_repo: R,
+    chunking: crate::domain::chunking_config::ChunkingConfig,
+    language: Option<crate::domain::transcript_language::TranscriptLanguage>,
+    triage_service: task_manager::domain::services::triage_service::TriageService,
 }
 
 impl<R> ProcessTranscriptUseCase<R>
@@ -77,15 +103,71 @@ where
         ProcessTranscriptUseCase {
             extractor,
             task_repo,
+            chunking: crate::domain::chunking_config::ChunkingConfig::default(),
+            language: None,
+            triage_service: task_manager::domain::services::triage_service::TriageService::new(
+                task_manager::domain::services::complexity_scorer::ComplexityScorer::new(),
+            ),
         }
     }
 
+    /// Sets the chunking configuration used for long transcripts.
+    ///
+    /// # Arguments
+    ///
+    /// * `chunking` - Window size, overlap, and duplicate similarity settings.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use transcript_processor::application::use_cases::process_transcript::ProcessTranscriptUseCase;
+    /// # use transcript_processor::adapters::ollama_adapter::OllamaTranscriptExtractorAdapter;
+    /// # use transcript_processor::adapters::in_memory_task_adapter::InMemoryTaskAdapter;
+    /// # use transcript_processor::domain::chunking_config::ChunkingConfig;
+    /// let extractor = std::sync::Arc::new(OllamaTranscriptExtractorAdapter::new(std::string::String::from("llama3.2")));
+    /// let use_case = ProcessTranscriptUseCase::new(extractor, InMemoryTaskAdapter::new())
+    ///     .with_chunking(ChunkingConfig::new(4000));
+    /// ```
+    pub fn with_chunking(mut self, chunking: crate::domain::chunking_config::ChunkingConfig) -> Self {
+        self.chunking = chunking;
+        self
+    }
+
+    /// Sets the transcript language explicitly instead of detecting it.
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - The language every processed transcript is written in.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use transcript_processor::application::use_cases::process_transcript::ProcessTranscriptUseCase;
+    /// # use transcript_processor::adapters::ollama_adapter::OllamaTranscriptExtractorAdapter;
+    /// # use transcript_processor::adapters::in_memory_task_adapter::InMemoryTaskAdapter;
+    /// # use transcript_processor::domain::transcript_language::TranscriptLanguage;
+    /// let extractor = std::sync::Arc::new(OllamaTranscriptExtractorAdapter::new(std::string::String::from("llama3.2")));
+    /// let use_case = ProcessTranscriptUseCase::new(extractor, InMemoryTaskAdapter::new())
+    ///     .with_language(TranscriptLanguage::parse("spa").unwrap());
+    /// ```
+    pub fn with_language(mut self, language: crate::domain::transcript_language::TranscriptLanguage) -> Self {
+        self.language = Some(language);
+        self
+    }
+
     /// Processes a transcript and creates tasks from extracted action items.
     ///
-    /// This method orchestrates the complete workflow:
-    /// 1. Extracts action items from the transcript via the extractor port
-    /// 2. Converts each ActionItem to a Task with generated UUID and timestamps
-    /// 3. Persists each task via the repository port using HEXSER's save() method
+    /// The transcript ID is derived from a hash of the content, so processing
+    /// identical text again updates its earlier tasks. Use `process_with_id`
+    /// with a file-derived ID when the transcript may be edited between runs.
     ///
     /// # Arguments
     ///
@@ -115,14 +197,140 @@ where
         &mut self,
         transcript: &str,
     ) -> std::result::Result<Vec<crate::domain::task::Task>, std::string::String> {
-        // Extract action items from the transcript
-        let analysis = self.extractor.extract_analysis(transcript).await?;
+        let transcript_id = crate::domain::transcript_id::from_content(transcript);
+        self.process_with_id(transcript, &transcript_id).await
+    }
+
+    /// Processes a transcript and creates tasks linked back to it.
+    ///
+    /// This method orchestrates the complete workflow:
+    /// 1. Detects `Speaker:` prefixes and the dominant language (unless set explicitly)
+    ///    and prepends speaker context and a language hint to the extractor input
+    /// 2. Splits the transcript into windows sized to the chunking budget and
+    ///    extracts action items from each via the extractor port
+    /// 3. Merges near-duplicate items extracted from overlapping windows
+    /// 4. Defaults missing assignees to the committing speaker (or the person they named)
+    /// 5. Locates the transcript line each item was taken from
+    /// 6. Matches each item against tasks already extracted from `transcript_id`;
+    ///    an existing task with the same idempotency key (same utterance), or
+    ///    else with a title at least `duplicate_similarity` similar, is
+    ///    updated, otherwise a new Task is created
+    /// 7. Persists each task via the repository port using HEXSER's save() method
+    ///
+    /// # Arguments
+    ///
+    /// * `transcript` - The raw transcript text to process.
+    /// * `transcript_id` - Reference to the transcript (e.g. its file name), stored on every task.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Task>)` - All created or updated tasks, in extraction order.
+    /// * `Err(String)` - Error message if processing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use transcript_processor::application::use_cases::process_transcript::ProcessTranscriptUseCase;
+    /// # use transcript_processor::adapters::ollama_adapter::OllamaTranscriptExtractorAdapter;
+    /// # use transcript_processor::adapters::in_memory_task_adapter::InMemoryTaskAdapter;
+    /// # async fn example() {
+    /// let extractor = std::sync::Arc::new(OllamaTranscriptExtractorAdapter::new(std::string::String::from("llama3.2")));
+    /// let mut use_case = ProcessTranscriptUseCase::new(extractor, InMemoryTaskAdapter::new());
+    /// let tasks = use_case.process_with_id("Alice: I'll send the notes.", "standup-2025-11-06.txt").await.unwrap();
+    /// println!("Line {:?}", tasks[0].source_utterance);
+    /// # }
+    /// ```
+    pub async fn process_with_id(
+        &mut self,
+        transcript: &str,
+        transcript_id: &str,
+    ) -> std::result::Result<Vec<crate::domain::task::Task>, std::string::String> {
+        // Detect speakers so the extractor knows who committed to each action
+        let diarization = crate::domain::speaker_diarization::SpeakerDiarization::from_transcript(transcript);
+
+        // Tell the extractor which language to expect; an explicit language wins over detection
+        let language_hint = self
+            .language
+            .or_else(|| crate::domain::transcript_language::TranscriptLanguage::detect(transcript))
+            .map(|language| language.prompt_hint())
+            .unwrap_or_default();
+
+        // Extract action items from each window so long transcripts are not truncated;
+        // the preamble is sent with every window, so it comes out of the window budget
+        let preamble = std::format!("{}{}", language_hint, diarization.prompt_preamble());
+        let chunking = self
+            .chunking
+            .reserving_tokens(crate::domain::transcript_chunker::estimate_tokens(&preamble));
+        let mut extracted_items = Vec::new();
+        for chunk in crate::domain::transcript_chunker::chunk_transcript(transcript, &chunking) {
+            let prompt_input = std::format!("{}{}", preamble, chunk);
+            let analysis = self.extractor.extract_analysis(&prompt_input).await?;
+            extracted_items.extend(analysis.action_items);
+        }
+        let action_items = crate::domain::merge_action_items::merge_action_items(
+            extracted_items,
+            self.chunking.duplicate_similarity,
+        );
+
+        // Tasks from earlier runs of this transcript; each can absorb at most one item
+        let mut previous_tasks = self
+            .task_repo
+            .find(
+                &crate::application::ports::task_repository_port::TaskFilter::BySourceTranscript(
+                    std::string::String::from(transcript_id),
+                ),
+                hexser::ports::repository::FindOptions::default(),
+            )
+            .map_err(|e| std::format!("Failed to load existing tasks: {:?}", e))?;
 
         let mut created_tasks = Vec::new();
 
-        // Convert each action item to a task and persist it
-        for action_item in &analysis.action_items {
-            let task = crate::domain::task::Task::from_action_item(action_item, None);
+        // Convert each action item to a task (or update its earlier task) and persist it
+        for action_item in &action_items {
+            let resolved_item = crate::domain::action_item::ActionItem {
+                assignee: diarization.resolve_assignee(action_item),
+                source_utterance: crate::domain::speaker_diarization::SpeakerDiarization::locate_utterance(
+                    transcript,
+                    &action_item.title,
+                ),
+                ..action_item.clone()
+            };
+            // The same utterance is the same task, however the extractor phrased it this time
+            let key = resolved_item
+                .source_utterance
+                .as_ref()
+                .map(|utterance| task_manager::domain::task::Task::utterance_idempotency_key(transcript_id, utterance));
+            let same_utterance = key.and_then(|key| {
+                previous_tasks
+                    .iter()
+                    .position(|task| task.idempotency_key().as_ref() == std::option::Option::Some(&key))
+            });
+            let duplicate = same_utterance.or_else(|| {
+                previous_tasks
+                    .iter()
+                    .enumerate()
+                    .map(|(index, task)| {
+                        (index, crate::domain::title_similarity::title_similarity(&task.title, &resolved_item.title))
+                    })
+                    .filter(|(_, similarity)| *similarity >= self.chunking.duplicate_similarity)
+                    .max_by(|left, right| left.1.total_cmp(&right.1))
+                    .map(|(index, _)| index)
+            });
+            let task = match duplicate {
+                std::option::Option::Some(index) => {
+                    let mut task = previous_tasks.swap_remove(index);
+                    task.update_from_action_item(&resolved_item);
+                    task
+                }
+                std::option::Option::None => {
+                    let mut task = crate::domain::task::Task::from_action_item(
+                        &resolved_item,
+                        std::option::Option::Some(std::string::String::from(transcript_id)),
+                    );
+                    task.auto_triage(&self.triage_service);
+                    task
+                }
+            };
 
             // Persist the task using HEXSER Repository trait's save() method
             self.task_repo
@@ -154,6 +362,7 @@ mod tests {
                         title: std::string::String::from("Test action"),
                         assignee: None,
                         due_date: None,
+                        source_utterance: None,
                     },
                 ],
             })
@@ -197,10 +406,19 @@ mod tests {
 
         fn find(
             &self,
-            _filter: &Self::Filter,
+            filter: &Self::Filter,
             _opts: hexser::ports::repository::FindOptions<Self::SortKey>,
         ) -> hexser::HexResult<std::vec::Vec<crate::domain::task::Task>> {
-            std::result::Result::Ok(Vec::new())
+            let tasks = match filter {
+                crate::application::ports::task_repository_port::TaskFilter::BySourceTranscript(transcript_id) => self
+                    .tasks
+                    .values()
+                    .filter(|task| task.source_transcript_id.as_ref() == Some(transcript_id))
+                    .cloned()
+                    .collect(),
+                _ => Vec::new(),
+            };
+            std::result::Result::Ok(tasks)
         }
     }
 
@@ -223,4 +441,354 @@ mod tests {
         assert_eq!(tasks.len(), 1);
         assert_eq!(tasks[0].title, "Test action");
     }
+
+    struct UnassignedExtractor {
+        titles: Vec<&'static str>,
+        received: std::sync::Mutex<String>,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::application::ports::transcript_extractor_port::TranscriptExtractorPort for UnassignedExtractor {
+        async fn extract_analysis(
+            &self,
+            transcript: &str,
+        ) -> std::result::Result<crate::domain::transcript_analysis::TranscriptAnalysis, std::string::String> {
+            *self.received.lock().unwrap() = std::string::String::from(transcript);
+            std::result::Result::Ok(crate::domain::transcript_analysis::TranscriptAnalysis {
+                action_items: self
+                    .titles
+                    .iter()
+                    .map(|title| crate::domain::action_item::ActionItem {
+                        title: std::string::String::from(*title),
+                        assignee: None,
+                        due_date: None,
+                        source_utterance: None,
+                    })
+                    .collect(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_process_multi_speaker_transcript_assigns_speakers() {
+        // Test: Validates speaker-aware assignment across a multi-speaker transcript.
+        // Justification: Self-commitments must default to the speaker while delegated
+        // actions must go to the named person, and the extractor must receive speaker context.
+        let extractor = std::sync::Arc::new(UnassignedExtractor {
+            titles: vec!["Finish the API documentation", "Update the deployment scripts"],
+            received: std::sync::Mutex::new(std::string::String::new()),
+        });
+        let mut use_case = ProcessTranscriptUseCase::new(extractor.clone(), MockRepo::new());
+        let transcript = "John: I'll finish the API documentation by Friday.\nSarah: Mike should update the deployment scripts.\nMike: Will do.";
+
+        let tasks = use_case.process(transcript).await.unwrap();
+
+        assert_eq!(tasks[0].assignee.as_deref(), Some("John"));
+        assert_eq!(tasks[1].assignee.as_deref(), Some("Mike"));
+        assert!(extractor.received.lock().unwrap().contains("Speakers: John, Sarah, Mike."));
+    }
+
+    #[tokio::test]
+    async fn test_process_with_id_links_tasks_to_source_utterance() {
+        // Test: Validates every task carries the transcript ID and the line it was said on, in the repository too.
+        // Justification: The linkage is what lets `rig show` display the originating snippet.
+        let extractor = std::sync::Arc::new(UnassignedExtractor {
+            titles: vec!["Update the deployment scripts"],
+            received: std::sync::Mutex::new(std::string::String::new()),
+        });
+        let mut use_case = ProcessTranscriptUseCase::new(extractor, MockRepo::new());
+        let transcript = "Standup\nJohn: Morning.\nSarah: Mike should update the deployment scripts.";
+
+        let tasks = use_case.process_with_id(transcript, "standup-2025-11-06.txt").await.unwrap();
+        let stored = &use_case.task_repo.tasks[&tasks[0].id];
+
+        assert_eq!(stored.source_transcript_id.as_deref(), Some("standup-2025-11-06.txt"));
+        let utterance = stored.source_utterance.as_ref().unwrap();
+        assert_eq!(utterance.line, 3);
+        assert_eq!(utterance.speaker.as_deref(), Some("Sarah"));
+        assert_eq!(utterance.text, "Mike should update the deployment scripts.");
+    }
+
+    #[tokio::test]
+    async fn test_new_transcript_tasks_are_auto_triaged_once() {
+        // Test: Validates tasks created from a transcript get a priority, a triage label, and one triage revision, which re-processing keeps.
+        // Justification: Only tasks created through task_manager were triaged, so transcript tasks had no priority.
+        let extractor = std::sync::Arc::new(UnassignedExtractor {
+            titles: vec!["Finish the API documentation"],
+            received: std::sync::Mutex::new(std::string::String::new()),
+        });
+        let mut use_case = ProcessTranscriptUseCase::new(extractor, MockRepo::new());
+        let transcript = "John: I'll finish the API documentation.";
+
+        let first = use_case.process_with_id(transcript, "standup").await.unwrap();
+        let second = use_case.process_with_id(transcript, "standup").await.unwrap();
+
+        assert!(first[0].priority.is_some());
+        assert_eq!(first[0].tags.iter().filter(|tag| tag.starts_with("triage:")).count(), 1);
+        assert_eq!(first[0].revisions.len(), 1);
+        assert!(first[0].revisions[0].change_description.starts_with("Auto-triage"));
+        assert_eq!(second[0].id, first[0].id);
+        assert_eq!(second[0].priority, first[0].priority);
+        assert_eq!(second[0].revisions.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_reprocessing_same_transcript_creates_no_duplicates() {
+        // Test: Validates processing the same transcript twice leaves one task per item, keeping IDs and status.
+        // Justification: Re-running a meeting through the pipeline must not double the backlog or reset progress.
+        let extractor = std::sync::Arc::new(UnassignedExtractor {
+            titles: vec!["Finish the API documentation", "Update the deployment scripts"],
+            received: std::sync::Mutex::new(std::string::String::new()),
+        });
+        let mut use_case = ProcessTranscriptUseCase::new(extractor, MockRepo::new());
+        let transcript = "John: I'll finish the API documentation.\nSarah: Mike should update the deployment scripts.";
+
+        let first = use_case.process_with_id(transcript, "standup").await.unwrap();
+        let first_id = first[0].id.clone();
+        use_case.task_repo.tasks.get_mut(&first_id).unwrap().status = crate::domain::task_status::TaskStatus::InProgress;
+        let second = use_case.process_with_id(transcript, "standup").await.unwrap();
+
+        assert_eq!(use_case.task_repo.tasks.len(), 2);
+        assert_eq!(second[0].id, first_id);
+        assert_eq!(second[1].id, first[1].id);
+        assert_eq!(use_case.task_repo.tasks[&first_id].status, crate::domain::task_status::TaskStatus::InProgress);
+    }
+
+    #[tokio::test]
+    async fn test_reprocessing_matches_rephrased_items_by_utterance() {
+        // Test: Validates re-extracting an item under a different title updates the task from the same utterance.
+        // Justification: LLM phrasing varies between runs; title similarity alone would duplicate the task.
+        let original = std::sync::Arc::new(UnassignedExtractor {
+            titles: vec!["Finish the API documentation"],
+            received: std::sync::Mutex::new(std::string::String::new()),
+        });
+        let mut use_case = ProcessTranscriptUseCase::new(original, MockRepo::new());
+        let transcript = "John: I'll finish the API documentation.\nSarah: Sounds good.";
+        let first = use_case.process_with_id(transcript, "standup").await.unwrap();
+
+        use_case.extractor = std::sync::Arc::new(UnassignedExtractor {
+            titles: vec!["Complete the documentation"],
+            received: std::sync::Mutex::new(std::string::String::new()),
+        });
+        let second = use_case.process_with_id(transcript, "standup").await.unwrap();
+
+        assert!(
+            crate::domain::title_similarity::title_similarity("Finish the API documentation", "Complete the documentation")
+                < crate::domain::chunking_config::ChunkingConfig::DEFAULT_DUPLICATE_SIMILARITY
+        );
+        assert_eq!(use_case.task_repo.tasks.len(), 1);
+        assert_eq!(second[0].id, first[0].id);
+        assert!(first[0].idempotency_key().is_some());
+        assert_eq!(second[0].idempotency_key(), first[0].idempotency_key());
+        assert_eq!(use_case.task_repo.tasks[&first[0].id].title, "Complete the documentation");
+    }
+
+    #[tokio::test]
+    async fn test_reprocessing_edited_transcript_updates_matches_and_inserts_new_items() {
+        // Test: Validates a corrected transcript updates near-identical items in place and inserts genuinely new ones.
+        // Justification: Corrections should refine existing tasks, while newly captured items still need tasks.
+        let original = std::sync::Arc::new(UnassignedExtractor {
+            titles: vec!["Finish the API documentation"],
+            received: std::sync::Mutex::new(std::string::String::new()),
+        });
+        let mut use_case = ProcessTranscriptUseCase::new(original, MockRepo::new());
+        let first = use_case.process_with_id("John: I'll finish the API documentation.", "standup").await.unwrap();
+
+        let edited = std::sync::Arc::new(UnassignedExtractor {
+            titles: vec!["Finish the API documentation and examples", "Book the retro room"],
+            received: std::sync::Mutex::new(std::string::String::new()),
+        });
+        use_case.extractor = edited;
+        let transcript = "John: I'll finish the API documentation and examples.\nEmily: I'll book the retro room.";
+        let second = use_case.process_with_id(transcript, "standup").await.unwrap();
+
+        assert_eq!(use_case.task_repo.tasks.len(), 2);
+        assert_eq!(second[0].id, first[0].id);
+        assert_eq!(use_case.task_repo.tasks[&first[0].id].title, "Finish the API documentation and examples");
+        assert_ne!(second[1].id, first[0].id);
+        assert_eq!(second[1].assignee.as_deref(), Some("Emily"));
+    }
+
+    #[tokio::test]
+    async fn test_process_same_text_twice_keeps_transcript_id() {
+        // Test: Validates process() gives identical text the same source_transcript_id and creates no duplicates.
+        // Justification: A random ID per run defeated both the provenance link and re-processing dedup.
+        let extractor = std::sync::Arc::new(UnassignedExtractor {
+            titles: vec!["Update the deployment scripts"],
+            received: std::sync::Mutex::new(std::string::String::new()),
+        });
+        let mut use_case = ProcessTranscriptUseCase::new(extractor, MockRepo::new());
+        let transcript = "Sarah: Mike should update the deployment scripts.";
+
+        let first = use_case.process(transcript).await.unwrap();
+        let second = use_case.process(transcript).await.unwrap();
+
+        assert_eq!(use_case.task_repo.tasks.len(), 1);
+        assert_eq!(second[0].id, first[0].id);
+        let expected = crate::domain::transcript_id::from_content(transcript);
+        assert_eq!(use_case.task_repo.tasks[&first[0].id].source_transcript_id.as_deref(), Some(expected.as_str()));
+    }
+
+    #[tokio::test]
+    async fn test_reprocessing_edited_file_under_path_id_updates_tasks() {
+        // Test: Validates an edited transcript file re-processed under its path-derived ID updates the earlier task.
+        // Justification: The content hash changes with every edit, so only the file ID lets corrections dedup.
+        let dir = std::env::temp_dir().join(std::format!("reprocess_path_id_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("standup.txt");
+        std::fs::write(&path, "John: I'll finish the API documentation.").unwrap();
+        let original = std::sync::Arc::new(UnassignedExtractor {
+            titles: vec!["Finish the API documentation"],
+            received: std::sync::Mutex::new(std::string::String::new()),
+        });
+        let mut use_case = ProcessTranscriptUseCase::new(original, MockRepo::new());
+        let first_text = std::fs::read_to_string(&path).unwrap();
+        let first = use_case
+            .process_with_id(&first_text, &crate::domain::transcript_id::from_path(&path))
+            .await
+            .unwrap();
+
+        std::fs::write(&path, "John: I'll finish the API documentation and examples.").unwrap();
+        use_case.extractor = std::sync::Arc::new(UnassignedExtractor {
+            titles: vec!["Finish the API documentation and examples"],
+            received: std::sync::Mutex::new(std::string::String::new()),
+        });
+        let second_text = std::fs::read_to_string(&path).unwrap();
+        let second = use_case
+            .process_with_id(&second_text, &crate::domain::transcript_id::from_path(&path))
+            .await
+            .unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(use_case.task_repo.tasks.len(), 1);
+        assert_eq!(second[0].id, first[0].id);
+        assert_eq!(use_case.task_repo.tasks[&first[0].id].title, "Finish the API documentation and examples");
+    }
+
+    const SPANISH_TRANSCRIPT: &str = "Ana: Buenos días a todos. Yo me encargo de revisar el presupuesto antes del viernes.\n\
+Carlos: Luis tiene que actualizar los scripts de despliegue esta semana.";
+
+    #[tokio::test]
+    async fn test_process_passes_detected_language_hint() {
+        // Test: Validates a Spanish transcript reaches the extractor with a Spanish language hint.
+        // Justification: Without the hint, English-tuned prompts extract Spanish meetings poorly.
+        let extractor = std::sync::Arc::new(UnassignedExtractor {
+            titles: vec!["Revisar el presupuesto"],
+            received: std::sync::Mutex::new(std::string::String::new()),
+        });
+        let mut use_case = ProcessTranscriptUseCase::new(extractor.clone(), MockRepo::new());
+
+        use_case.process(SPANISH_TRANSCRIPT).await.unwrap();
+
+        let received = extractor.received.lock().unwrap();
+        assert!(received.starts_with("Language hint: the transcript is in Spanish."));
+        assert!(received.contains("Speakers: Ana, Carlos."));
+    }
+
+    #[tokio::test]
+    async fn test_explicit_language_overrides_detection() {
+        // Test: Validates an explicitly set language is hinted even when detection would pick another.
+        // Justification: `--language` exists for transcripts detection gets wrong, so it must take precedence.
+        let extractor = std::sync::Arc::new(UnassignedExtractor {
+            titles: vec!["Revisar el presupuesto"],
+            received: std::sync::Mutex::new(std::string::String::new()),
+        });
+        let mut use_case = ProcessTranscriptUseCase::new(extractor.clone(), MockRepo::new())
+            .with_language(crate::domain::transcript_language::TranscriptLanguage::parse("Portuguese").unwrap());
+
+        use_case.process(SPANISH_TRANSCRIPT).await.unwrap();
+
+        let received = extractor.received.lock().unwrap();
+        assert!(received.starts_with("Language hint: the transcript is in Portuguese."));
+        assert!(!received.contains("Spanish"));
+    }
+
+    struct ChunkEchoExtractor {
+        calls: std::sync::Mutex<usize>,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::application::ports::transcript_extractor_port::TranscriptExtractorPort for ChunkEchoExtractor {
+        async fn extract_analysis(
+            &self,
+            transcript: &str,
+        ) -> std::result::Result<crate::domain::transcript_analysis::TranscriptAnalysis, std::string::String> {
+            let mut calls = self.calls.lock().unwrap();
+            *calls += 1;
+            // Every chunk reports the recurring item with a slightly different title.
+            let recurring = if *calls % 2 == 0 { "Review the security audit" } else { "Review security audit" };
+            let action_items = [("kickoff budget", "Approve the kickoff budget"), ("billing database", "Migrate the billing database")]
+                .iter()
+                .filter(|(marker, _)| transcript.contains(marker))
+                .map(|(_, title)| *title)
+                .chain(std::iter::once(recurring))
+                .map(|title| crate::domain::action_item::ActionItem {
+                    title: std::string::String::from(title),
+                    assignee: None,
+                    due_date: None,
+                    source_utterance: None,
+                })
+                .collect();
+            std::result::Result::Ok(crate::domain::transcript_analysis::TranscriptAnalysis { action_items })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_process_long_transcript_in_chunks() {
+        // Test: Validates chunked extraction of a transcript far larger than the window.
+        // Justification: Items from both halves must survive, and near-duplicates from
+        // overlapping windows must collapse into a single task.
+        let filler: Vec<String> = (0..200)
+            .map(|i| std::format!("Bob: Status update {} on the quarterly roadmap discussion.", i))
+            .collect();
+        let transcript = std::format!(
+            "Alice: I'll approve the kickoff budget today.\n{}\nCarol: I will migrate the billing database next week.",
+            filler.join("\n")
+        );
+        let extractor = std::sync::Arc::new(ChunkEchoExtractor { calls: std::sync::Mutex::new(0) });
+        let mut use_case = ProcessTranscriptUseCase::new(extractor.clone(), MockRepo::new())
+            .with_chunking(crate::domain::chunking_config::ChunkingConfig::new(500));
+
+        let tasks = use_case.process(&transcript).await.unwrap();
+        let titles: Vec<&str> = tasks.iter().map(|task| task.title.as_str()).collect();
+
+        assert!(*extractor.calls.lock().unwrap() > 2);
+        assert_eq!(titles, vec!["Approve the kickoff budget", "Review security audit", "Migrate the billing database"]);
+        assert_eq!(tasks[0].assignee.as_deref(), Some("Alice"));
+        assert_eq!(tasks[2].assignee.as_deref(), Some("Carol"));
+    }
+
+    struct PromptSizeExtractor {
+        largest_prompt_tokens: std::sync::Mutex<usize>,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::application::ports::transcript_extractor_port::TranscriptExtractorPort for PromptSizeExtractor {
+        async fn extract_analysis(
+            &self,
+            transcript: &str,
+        ) -> std::result::Result<crate::domain::transcript_analysis::TranscriptAnalysis, std::string::String> {
+            let mut largest = self.largest_prompt_tokens.lock().unwrap();
+            *largest = (*largest).max(crate::domain::transcript_chunker::estimate_tokens(transcript));
+            std::result::Result::Ok(crate::domain::transcript_analysis::TranscriptAnalysis { action_items: Vec::new() })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chunked_prompts_with_speaker_context_fit_the_window() {
+        // Test: Validates every extractor input, speaker context included, stays within the window budget.
+        // Justification: The speaker context used to be added after sizing, pushing each window past its budget.
+        let transcript: Vec<String> = (0..200)
+            .map(|i| std::format!("{}: Status update {} on the quarterly roadmap discussion.", ["Alice", "Bob"][i % 2], i))
+            .collect();
+        let extractor = std::sync::Arc::new(PromptSizeExtractor { largest_prompt_tokens: std::sync::Mutex::new(0) });
+        let mut use_case = ProcessTranscriptUseCase::new(extractor.clone(), MockRepo::new())
+            .with_chunking(crate::domain::chunking_config::ChunkingConfig::new(300));
+
+        use_case.process(&transcript.join("\n")).await.unwrap();
+
+        let largest = *extractor.largest_prompt_tokens.lock().unwrap();
+        assert!(largest > 200, "the windows should be filled, largest prompt was {} tokens", largest);
+        assert!(largest <= 300, "largest prompt was {} tokens", largest);
+    }
 }
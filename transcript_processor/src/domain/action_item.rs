@@ -5,6 +5,7 @@
 //! into a Task entity for persistence and tracking.
 //!
 //! Revision History
+//! - 2026-10-17T03:50:00Z @AI: Add source_utterance linking the item to the transcript line it came from.
 //! - 2025-11-06T18:14:00Z @AI: Add HexEntity derive for HEXSER framework alignment.
 //! - 2025-11-06T17:41:00Z @AI: Initial ActionItem struct definition.
 
@@ -19,6 +20,7 @@
 /// * `title` - The description of the action item (required).
 /// * `assignee` - The person responsible for the action item (optional).
 /// * `due_date` - The deadline for completing the action item as a string (optional).
+/// * `source_utterance` - The transcript line the item was said in, located after extraction (optional).
 ///
 /// # Examples
 ///
@@ -28,6 +30,7 @@
 ///     title: std::string::String::from("Review design document"),
 ///     assignee: Some(std::string::String::from("Alice")),
 ///     due_date: Some(std::string::String::from("2025-11-15")),
+///     source_utterance: None,
 /// };
 /// ```
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema, hexser::HexEntity)]
@@ -40,6 +43,14 @@ pub struct ActionItem {
 
     /// The due date for this action item in string format.
     pub due_date: Option<String>,
+
+    /// The transcript line this action item was extracted from.
+    ///
+    /// Located by the pipeline after extraction, so it is not part of the
+    /// schema the LLM is asked to fill.
+    #[serde(default)]
+    #[schemars(skip)]
+    pub source_utterance: Option<task_manager::domain::transcript_utterance::TranscriptUtterance>,
 }
 
 #[cfg(test)]
@@ -55,6 +66,7 @@ mod tests {
             title: std::string::String::from("Test action"),
             assignee: Some(std::string::String::from("Bob")),
             due_date: Some(std::string::String::from("2025-12-01")),
+            source_utterance: None,
         };
 
         assert_eq!(action.title, "Test action");
@@ -71,6 +83,7 @@ mod tests {
             title: std::string::String::from("Unassigned task"),
             assignee: None,
             due_date: None,
+            source_utterance: None,
         };
 
         assert_eq!(action.title, "Unassigned task");
@@ -0,0 +1,157 @@
+//! Defines ChunkingConfig for splitting long transcripts into model-sized windows.
+//!
+//! Long meeting transcripts overflow the extractor's context window and are
+//! silently truncated. ChunkingConfig sets the token budget for each window,
+//! the overlap carried between consecutive windows, and the title similarity
+//! at which action items from different windows are treated as duplicates.
+//!
+//! Revision History
+//! - 2026-10-18T02:40:00Z @AI: Add reserving_tokens to shrink the window by the size of a prompt preamble.
+//! - 2026-10-16T09:40:00Z @AI: Initial ChunkingConfig for transcript chunking.
+
+/// Configuration for transcript chunking and cross-chunk de-duplication.
+///
+/// # Fields
+///
+/// * `window_tokens` - Approximate token budget for each transcript window.
+/// * `overlap_tokens` - Approximate tokens of trailing context repeated at the start of the next window.
+/// * `duplicate_similarity` - Title similarity (0.0-1.0) at which action items are merged.
+///
+/// # Examples
+///
+/// ```
+/// # use transcript_processor::domain::chunking_config::ChunkingConfig;
+/// let config = ChunkingConfig::new(4000).with_overlap_tokens(250);
+/// std::assert_eq!(config.window_tokens, 4000);
+/// std::assert_eq!(config.overlap_tokens, 250);
+/// ```
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ChunkingConfig {
+    /// Approximate token budget for each transcript window.
+    pub window_tokens: usize,
+
+    /// Approximate tokens of trailing context repeated at the start of the next window.
+    pub overlap_tokens: usize,
+
+    /// Title similarity (0.0-1.0) at which action items are merged.
+    pub duplicate_similarity: f64,
+}
+
+impl ChunkingConfig {
+    /// Default window budget; leaves room for the extraction prompt in a 2048-token context.
+    pub const DEFAULT_WINDOW_TOKENS: usize = 1500;
+
+    /// Default overlap between consecutive windows.
+    pub const DEFAULT_OVERLAP_TOKENS: usize = 100;
+
+    /// Default title similarity threshold for merging action items.
+    pub const DEFAULT_DUPLICATE_SIMILARITY: f64 = 0.6;
+
+    /// Creates a ChunkingConfig with the given window size and default overlap and similarity.
+    ///
+    /// # Arguments
+    ///
+    /// * `window_tokens` - Approximate token budget for each window.
+    ///
+    /// # Returns
+    ///
+    /// A new ChunkingConfig.
+    pub fn new(window_tokens: usize) -> Self {
+        ChunkingConfig {
+            window_tokens,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the overlap between consecutive windows.
+    ///
+    /// # Arguments
+    ///
+    /// * `overlap_tokens` - Approximate tokens repeated at the start of the next window.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_overlap_tokens(mut self, overlap_tokens: usize) -> Self {
+        self.overlap_tokens = overlap_tokens;
+        self
+    }
+
+    /// Sets the title similarity threshold for merging action items.
+    ///
+    /// # Arguments
+    ///
+    /// * `similarity` - Threshold between 0.0 and 1.0.
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining.
+    pub fn with_duplicate_similarity(mut self, similarity: f64) -> Self {
+        self.duplicate_similarity = similarity;
+        self
+    }
+
+    /// Returns a copy whose window leaves room for text prepended to every window.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - Approximate tokens of the prepended text (e.g. speaker context).
+    ///
+    /// # Returns
+    ///
+    /// The config with `window_tokens` reduced by `tokens`, but at least 1.
+    pub fn reserving_tokens(&self, tokens: usize) -> Self {
+        ChunkingConfig {
+            window_tokens: self.window_tokens.saturating_sub(tokens).max(1),
+            ..self.clone()
+        }
+    }
+}
+
+impl std::default::Default for ChunkingConfig {
+    fn default() -> Self {
+        ChunkingConfig {
+            window_tokens: Self::DEFAULT_WINDOW_TOKENS,
+            overlap_tokens: Self::DEFAULT_OVERLAP_TOKENS,
+            duplicate_similarity: Self::DEFAULT_DUPLICATE_SIMILARITY,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_default_config() {
+        // Test: Validates default window, overlap, and similarity values.
+        // Justification: Defaults must keep the overlap well inside the window.
+        let config = super::ChunkingConfig::default();
+
+        std::assert_eq!(config.window_tokens, super::ChunkingConfig::DEFAULT_WINDOW_TOKENS);
+        std::assert!(config.overlap_tokens < config.window_tokens);
+        std::assert!(config.duplicate_similarity > 0.0 && config.duplicate_similarity <= 1.0);
+    }
+
+    #[test]
+    fn test_builder_methods() {
+        // Test: Validates that builder methods override individual fields.
+        // Justification: Callers tune one setting without restating the others.
+        let config = super::ChunkingConfig::new(800)
+            .with_overlap_tokens(50)
+            .with_duplicate_similarity(0.75);
+
+        std::assert_eq!(config.window_tokens, 800);
+        std::assert_eq!(config.overlap_tokens, 50);
+        std::assert_eq!(config.duplicate_similarity, 0.75);
+    }
+
+    #[test]
+    fn test_reserving_tokens_shrinks_only_the_window() {
+        // Test: Validates reserved tokens come off the window, never below one token, and other settings are kept.
+        // Justification: The speaker context is sent with every window, so it must fit the same budget.
+        let config = super::ChunkingConfig::new(800).with_overlap_tokens(50);
+
+        std::assert_eq!(config.reserving_tokens(120).window_tokens, 680);
+        std::assert_eq!(config.reserving_tokens(120).overlap_tokens, 50);
+        std::assert_eq!(config.reserving_tokens(5000).window_tokens, 1);
+    }
+}
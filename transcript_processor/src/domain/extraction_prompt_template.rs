@@ -0,0 +1,174 @@
+//! Defines ExtractionPromptTemplate for customizing the action item extraction prompt.
+//!
+//! The built-in prompt suits free-form meetings; teams with their own
+//! conventions (e.g. "TODO:" markers) can supply a template file instead. A
+//! template is plain text containing the `{transcript}` placeholder, which is
+//! replaced with the transcript (or transcript window) being processed.
+//!
+//! Revision History
+//! - 2026-10-17T04:30:00Z @AI: Initial ExtractionPromptTemplate with file loading and EXTRACTION_PROMPT_TEMPLATE selection.
+
+/// Built-in extraction prompt used when no template is configured.
+const DEFAULT_TEMPLATE: &str = r#"Extract all action items from the following meeting transcript.
+Return ONLY a valid JSON array of objects, where each object has this exact structure:
+{
+  "title": "Brief task title",
+  "assignee": "Name of person assigned (or null if not specified)",
+  "due_date": "YYYY-MM-DD format (or null if not specified)"
+}
+
+IMPORTANT: Pay close attention to who is assigned each task. Look for patterns like:
+- "I'll take ownership of..." -> extract the speaker's name
+- "James will complete..." -> assignee is "James"
+- "Maria can implement..." -> assignee is "Maria"
+- "Let's have David..." -> assignee is "David"
+- "Emily should..." -> assignee is "Emily"
+
+Extract the person's first name only. If no assignee is clearly identified, use null.
+
+Transcript:
+{transcript}
+
+Respond with ONLY the JSON array, no other text."#;
+
+/// Prompt template for action item extraction.
+///
+/// # Fields
+///
+/// * `template` - Template text containing the `{transcript}` placeholder.
+///
+/// # Examples
+///
+/// ```
+/// # use transcript_processor::domain::extraction_prompt_template::ExtractionPromptTemplate;
+/// let template = ExtractionPromptTemplate::new(std::string::String::from(
+///     "List every line starting with TODO: as an action item.\n\n{transcript}",
+/// ))
+/// .unwrap();
+/// std::assert_eq!(
+///     template.render("TODO: Alice sends the notes"),
+///     "List every line starting with TODO: as an action item.\n\nTODO: Alice sends the notes"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtractionPromptTemplate {
+    template: String,
+}
+
+impl ExtractionPromptTemplate {
+    /// Placeholder replaced with the transcript text.
+    pub const TRANSCRIPT_PLACEHOLDER: &'static str = "{transcript}";
+
+    /// Environment variable holding the path of a template file.
+    pub const TEMPLATE_ENV_VAR: &'static str = "EXTRACTION_PROMPT_TEMPLATE";
+
+    /// Creates a template from its text.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - Template text containing the `{transcript}` placeholder.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ExtractionPromptTemplate)` - The template.
+    /// * `Err(String)` - The text has no `{transcript}` placeholder, so the model would never see the transcript.
+    pub fn new(template: String) -> std::result::Result<Self, String> {
+        if !template.contains(Self::TRANSCRIPT_PLACEHOLDER) {
+            return std::result::Result::Err(std::format!(
+                "Extraction prompt template must contain the {} placeholder",
+                Self::TRANSCRIPT_PLACEHOLDER
+            ));
+        }
+        std::result::Result::Ok(ExtractionPromptTemplate { template })
+    }
+
+    /// Loads a template from a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the template file.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ExtractionPromptTemplate)` - The loaded template.
+    /// * `Err(String)` - The file cannot be read or lacks the `{transcript}` placeholder.
+    pub fn from_file(path: &std::path::Path) -> std::result::Result<Self, String> {
+        let template = std::fs::read_to_string(path)
+            .map_err(|e| std::format!("Failed to read extraction prompt template {}: {}", path.display(), e))?;
+        Self::new(template).map_err(|e| std::format!("{} ({})", e, path.display()))
+    }
+
+    /// Loads the template named by `EXTRACTION_PROMPT_TEMPLATE`, or the built-in default when unset or blank.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ExtractionPromptTemplate)` - The configured or default template.
+    /// * `Err(String)` - A template file is configured but cannot be loaded.
+    pub fn from_env() -> std::result::Result<Self, String> {
+        match std::env::var(Self::TEMPLATE_ENV_VAR).ok().filter(|value| !value.trim().is_empty()) {
+            std::option::Option::Some(path) => Self::from_file(std::path::Path::new(path.trim())),
+            std::option::Option::None => std::result::Result::Ok(Self::default()),
+        }
+    }
+
+    /// Substitutes the transcript into the template.
+    ///
+    /// # Arguments
+    ///
+    /// * `transcript` - The transcript text to embed.
+    ///
+    /// # Returns
+    ///
+    /// The prompt to send to the model.
+    pub fn render(&self, transcript: &str) -> String {
+        self.template.replace(Self::TRANSCRIPT_PLACEHOLDER, transcript)
+    }
+}
+
+impl Default for ExtractionPromptTemplate {
+    fn default() -> Self {
+        ExtractionPromptTemplate {
+            template: std::string::String::from(DEFAULT_TEMPLATE),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_default_template_embeds_transcript() {
+        // Test: Validates the built-in prompt includes the transcript and the JSON instructions.
+        // Justification: The default must keep working for everyone who configures nothing.
+        let prompt = super::ExtractionPromptTemplate::default().render("Alice will review the code by Friday.");
+
+        std::assert!(prompt.contains("Alice will review the code by Friday."));
+        std::assert!(prompt.contains("JSON array"));
+        std::assert!(!prompt.contains(super::ExtractionPromptTemplate::TRANSCRIPT_PLACEHOLDER));
+    }
+
+    #[test]
+    fn test_template_loaded_from_file() {
+        // Test: Validates a template file is loaded and its placeholder substituted.
+        // Justification: File templates are how teams tailor extraction to their meeting style.
+        let path = std::env::temp_dir().join(std::format!("prompt_template_{}.txt", uuid::Uuid::new_v4()));
+        std::fs::write(&path, "Each TODO: line is an action item.\n{transcript}\nReturn a JSON array.").unwrap();
+
+        let template = super::ExtractionPromptTemplate::from_file(&path);
+        let _ = std::fs::remove_file(&path);
+
+        std::assert_eq!(
+            template.unwrap().render("TODO: Bob books the room"),
+            "Each TODO: line is an action item.\nTODO: Bob books the room\nReturn a JSON array."
+        );
+    }
+
+    #[test]
+    fn test_invalid_templates_are_rejected() {
+        // Test: Validates missing files and templates without the placeholder produce errors.
+        // Justification: A broken template must stop startup instead of silently falling back.
+        let missing = std::env::temp_dir().join(std::format!("missing_template_{}.txt", uuid::Uuid::new_v4()));
+
+        std::assert!(super::ExtractionPromptTemplate::from_file(&missing).unwrap_err().contains("Failed to read"));
+        std::assert!(super::ExtractionPromptTemplate::new(std::string::String::from("No placeholder here")).is_err());
+    }
+}
@@ -0,0 +1,96 @@
+//! Merges near-duplicate action items extracted from overlapping transcript windows.
+//!
+//! When a long transcript is processed in overlapping chunks, the same commitment
+//! is often extracted twice with slightly different titles. This function keeps
+//! the first occurrence and fills its missing assignee or due date from later
+//! near-duplicates, preserving first-seen order.
+//!
+//! Revision History
+//! - 2026-10-16T09:40:00Z @AI: Initial fuzzy-title action item merge for chunked extraction.
+
+/// Merges action items whose titles are at least `threshold` similar.
+///
+/// # Arguments
+///
+/// * `items` - Action items in extraction order.
+/// * `threshold` - Minimum title similarity (0.0-1.0) for two items to merge.
+///
+/// # Returns
+///
+/// The de-duplicated action items in first-seen order.
+///
+/// # Examples
+///
+/// ```
+/// # use transcript_processor::domain::merge_action_items::merge_action_items;
+/// # use transcript_processor::domain::action_item::ActionItem;
+/// let items = vec![
+///     ActionItem { title: std::string::String::from("Review the audit"), assignee: None, due_date: None, source_utterance: None },
+///     ActionItem { title: std::string::String::from("Review audit"), assignee: Some(std::string::String::from("Sarah")), due_date: None, source_utterance: None },
+/// ];
+/// let merged = merge_action_items(items, 0.6);
+/// std::assert_eq!(merged.len(), 1);
+/// std::assert_eq!(merged[0].assignee.as_deref(), Some("Sarah"));
+/// ```
+pub fn merge_action_items(
+    items: Vec<crate::domain::action_item::ActionItem>,
+    threshold: f64,
+) -> Vec<crate::domain::action_item::ActionItem> {
+    items.into_iter().fold(Vec::new(), |mut merged: Vec<crate::domain::action_item::ActionItem>, item| {
+        let existing = merged
+            .iter_mut()
+            .find(|kept| crate::domain::title_similarity::title_similarity(&kept.title, &item.title) >= threshold);
+        match existing {
+            std::option::Option::Some(kept) => {
+                kept.assignee = kept.assignee.take().or(item.assignee);
+                kept.due_date = kept.due_date.take().or(item.due_date);
+            }
+            std::option::Option::None => merged.push(item),
+        }
+        merged
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    fn item(title: &str, assignee: Option<&str>, due_date: Option<&str>) -> crate::domain::action_item::ActionItem {
+        crate::domain::action_item::ActionItem {
+            title: std::string::String::from(title),
+            assignee: assignee.map(std::string::String::from),
+            due_date: due_date.map(std::string::String::from),
+            source_utterance: None,
+        }
+    }
+
+    #[test]
+    fn test_near_duplicates_are_merged() {
+        // Test: Validates that near-duplicate titles collapse into the first occurrence.
+        // Justification: Overlapping chunks must not produce duplicate tasks.
+        let merged = super::merge_action_items(
+            vec![
+                item("Review the security audit findings", None, Some("2025-11-10")),
+                item("Deploy the service", Some("Mike"), None),
+                item("Review security audit findings", Some("Sarah"), Some("2025-11-12")),
+            ],
+            0.6,
+        );
+
+        std::assert_eq!(merged.len(), 2);
+        std::assert_eq!(merged[0].title, "Review the security audit findings");
+        std::assert_eq!(merged[0].assignee.as_deref(), Some("Sarah"));
+        std::assert_eq!(merged[0].due_date.as_deref(), Some("2025-11-10"));
+    }
+
+    #[test]
+    fn test_distinct_items_are_kept() {
+        // Test: Validates that dissimilar titles are preserved in order.
+        // Justification: Merging must never drop genuinely different action items.
+        let merged = super::merge_action_items(
+            vec![item("Write API docs", None, None), item("Book the offsite venue", None, None)],
+            0.6,
+        );
+
+        std::assert_eq!(merged.len(), 2);
+        std::assert_eq!(merged[1].title, "Book the offsite venue");
+    }
+}
@@ -5,6 +5,11 @@
 //! infrastructure-agnostic and follow hexagonal architecture principles.
 //!
 //! Revision History
+//! - 2026-10-17T20:10:00Z @AI: Add transcript_id for stable transcript IDs.
+//! - 2026-10-17T04:50:00Z @AI: Add transcript_language for language detection and prompt hints.
+//! - 2026-10-17T04:30:00Z @AI: Add extraction_prompt_template for configurable extraction prompts.
+//! - 2026-10-16T09:40:00Z @AI: Add chunking_config, transcript_chunker, title_similarity, merge_action_items for long transcripts.
+//! - 2026-10-16T09:00:00Z @AI: Add speaker_turn and speaker_diarization for speaker-aware extraction.
 //! - 2025-11-06T17:41:00Z @AI: Initial domain module structure created.
 
 pub mod action_item;
@@ -15,3 +20,12 @@ pub mod task;
 pub mod task_revision;
 pub mod task_sort_key;
 pub mod sort_order;
+pub mod speaker_turn;
+pub mod speaker_diarization;
+pub mod chunking_config;
+pub mod transcript_chunker;
+pub mod title_similarity;
+pub mod merge_action_items;
+pub mod extraction_prompt_template;
+pub mod transcript_language;
+pub mod transcript_id;
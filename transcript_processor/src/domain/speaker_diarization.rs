@@ -0,0 +1,440 @@
+//! Defines SpeakerDiarization for speaker-aware action item extraction.
+//!
+//! SpeakerDiarization scans a raw transcript for `Speaker:` line prefixes and
+//! groups the text into SpeakerTurns. It provides prompt context so the LLM knows
+//! who is speaking, and a deterministic fallback that resolves a missing assignee
+//! to the committing speaker unless another person is explicitly named.
+//!
+//! Revision History
+//! - 2026-10-18T02:40:00Z @AI: Add prompt_preamble so chunking can reserve room for the speaker context.
+//! - 2026-10-17T03:50:00Z @AI: Add locate_utterance to find the transcript line an action item came from.
+//! - 2026-10-16T09:40:00Z @AI: Expose has_speaker_prefix for speaker-boundary transcript chunking.
+//! - 2026-10-16T09:00:00Z @AI: Initial SpeakerDiarization with prefix detection and assignee resolution.
+
+/// Maximum length of a speaker prefix before the colon.
+const MAX_SPEAKER_PREFIX_LEN: usize = 40;
+
+/// Maximum number of words in a speaker name.
+const MAX_SPEAKER_NAME_WORDS: usize = 3;
+
+/// Verbs that, following a capitalized name, delegate an action to that person.
+const DELEGATION_VERBS: &[&str] = &["will", "should", "can", "needs", "must", "to"];
+
+/// Capitalized words that precede delegation verbs but are not names.
+const NON_NAME_WORDS: &[&str] = &[
+    "I", "We", "You", "They", "He", "She", "It", "This", "That", "Someone", "Everyone", "Somebody",
+    "Nobody", "Who", "Today", "Tomorrow",
+];
+
+/// Speaker-attributed view of a transcript.
+///
+/// Built from raw transcript text, it records each detected speaker turn in
+/// order. Transcripts without any `Speaker:` prefixes produce an empty
+/// diarization, in which case all methods are no-ops.
+///
+/// # Examples
+///
+/// ```
+/// # use transcript_processor::domain::speaker_diarization::SpeakerDiarization;
+/// # use transcript_processor::domain::action_item::ActionItem;
+/// let diarization = SpeakerDiarization::from_transcript("John: I'll update the deployment scripts.");
+/// let item = ActionItem {
+///     title: std::string::String::from("Update deployment scripts"),
+///     assignee: None,
+///     due_date: None,
+///     source_utterance: None,
+/// };
+/// assert_eq!(diarization.resolve_assignee(&item), Some(std::string::String::from("John")));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SpeakerDiarization {
+    turns: Vec<crate::domain::speaker_turn::SpeakerTurn>,
+}
+
+impl SpeakerDiarization {
+    /// Detects speaker turns in a raw transcript.
+    ///
+    /// Lines of the form `Name: text` (optionally preceded by a bracketed
+    /// timestamp) start a new turn. Unprefixed lines continue the current
+    /// speaker's turn; unprefixed lines before the first speaker are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `transcript` - The raw transcript text.
+    ///
+    /// # Returns
+    ///
+    /// A SpeakerDiarization containing all detected turns.
+    pub fn from_transcript(transcript: &str) -> Self {
+        let turns = transcript
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .fold(Vec::new(), |mut turns: Vec<crate::domain::speaker_turn::SpeakerTurn>, line| {
+                match Self::parse_speaker_prefix(line) {
+                    std::option::Option::Some((speaker, text)) => {
+                        turns.push(crate::domain::speaker_turn::SpeakerTurn { speaker, text });
+                    }
+                    std::option::Option::None => {
+                        if let std::option::Option::Some(current) = turns.last_mut() {
+                            current.text.push(' ');
+                            current.text.push_str(line);
+                        }
+                    }
+                }
+                turns
+            });
+        SpeakerDiarization { turns }
+    }
+
+    /// Returns the detected speaker turns in transcript order.
+    pub fn turns(&self) -> &[crate::domain::speaker_turn::SpeakerTurn] {
+        &self.turns
+    }
+
+    /// Returns true when at least one speaker prefix was detected.
+    pub fn is_diarized(&self) -> bool {
+        !self.turns.is_empty()
+    }
+
+    /// Returns unique speaker names in order of first appearance.
+    pub fn speakers(&self) -> Vec<String> {
+        self.turns.iter().fold(Vec::new(), |mut speakers, turn| {
+            if !speakers.contains(&turn.speaker) {
+                speakers.push(turn.speaker.clone());
+            }
+            speakers
+        })
+    }
+
+    /// Builds the extractor input, prepending speaker context when diarized.
+    ///
+    /// The context lists the detected speakers and instructs the model to
+    /// assign self-committed actions to the speaker unless another person is
+    /// named. Non-diarized transcripts are returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `transcript` - The raw transcript text.
+    ///
+    /// # Returns
+    ///
+    /// The text to send to the extractor port.
+    pub fn build_prompt_input(&self, transcript: &str) -> String {
+        std::format!("{}{}", self.prompt_preamble(), transcript)
+    }
+
+    /// Returns the speaker context `build_prompt_input` prepends, or an empty
+    /// string for non-diarized transcripts.
+    pub fn prompt_preamble(&self) -> String {
+        if !self.is_diarized() {
+            return std::string::String::new();
+        }
+        std::format!(
+            "Speaker context: each line is prefixed with the name of the person speaking. Speakers: {}.\n\
+When a speaker commits to an action themselves (\"I'll\", \"I will\", \"I can\", \"let me\"), the assignee is that speaker.\n\
+If the speaker names another person as responsible, the assignee is that person instead.\n\n",
+            self.speakers().join(", ")
+        )
+    }
+
+    /// Resolves the assignee for an extracted action item.
+    ///
+    /// An assignee already present on the item is kept. Otherwise the turn that
+    /// best matches the item title is located; a person explicitly named as
+    /// responsible in that turn wins, falling back to the turn's speaker.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The extracted action item.
+    ///
+    /// # Returns
+    ///
+    /// The resolved assignee, or None when no turn matches the title.
+    pub fn resolve_assignee(&self, item: &crate::domain::action_item::ActionItem) -> Option<String> {
+        if item.assignee.is_some() {
+            return item.assignee.clone();
+        }
+        self.best_matching_turn(&item.title)
+            .map(|turn| self.named_assignee(turn).unwrap_or_else(|| turn.speaker.clone()))
+    }
+
+    /// Finds the transcript line an action item title was most likely taken from.
+    ///
+    /// Every non-empty line is scored by the keywords it shares with the
+    /// title; the best line wins, the earliest on ties. Unprefixed lines are
+    /// attributed to the preceding speaker, if any. Works on transcripts
+    /// without speaker prefixes too.
+    ///
+    /// # Arguments
+    ///
+    /// * `transcript` - The raw transcript text.
+    /// * `title` - The extracted action item title.
+    ///
+    /// # Returns
+    ///
+    /// The matching utterance with its 1-based line number, or None when no
+    /// line shares a keyword with the title.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use transcript_processor::domain::speaker_diarization::SpeakerDiarization;
+    /// let transcript = "Standup\nJohn: I'll finish the API documentation by Friday.\nSarah: Sounds good.";
+    /// let utterance = SpeakerDiarization::locate_utterance(transcript, "Finish API documentation").unwrap();
+    /// assert_eq!(utterance.line, 2);
+    /// assert_eq!(utterance.speaker.as_deref(), Some("John"));
+    /// assert_eq!(utterance.text, "I'll finish the API documentation by Friday.");
+    /// ```
+    pub fn locate_utterance(
+        transcript: &str,
+        title: &str,
+    ) -> Option<task_manager::domain::transcript_utterance::TranscriptUtterance> {
+        let title_words = Self::keywords(title);
+        let mut speaker: Option<String> = std::option::Option::None;
+        let mut best: Option<(usize, task_manager::domain::transcript_utterance::TranscriptUtterance)> =
+            std::option::Option::None;
+        for (index, line) in transcript.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let text = match Self::parse_speaker_prefix(line) {
+                std::option::Option::Some((name, text)) => {
+                    speaker = std::option::Option::Some(name);
+                    text
+                }
+                std::option::Option::None => std::string::String::from(line),
+            };
+            let line_words = Self::keywords(&text);
+            let score = title_words.iter().filter(|w| line_words.contains(w)).count();
+            if score > 0 && best.as_ref().is_none_or(|(best_score, _)| score > *best_score) {
+                best = std::option::Option::Some((
+                    score,
+                    task_manager::domain::transcript_utterance::TranscriptUtterance {
+                        line: index + 1,
+                        speaker: speaker.clone(),
+                        text,
+                    },
+                ));
+            }
+        }
+        best.map(|(_, utterance)| utterance)
+    }
+
+    /// Returns true when the line starts with a `Speaker:` prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use transcript_processor::domain::speaker_diarization::SpeakerDiarization;
+    /// std::assert!(SpeakerDiarization::has_speaker_prefix("Sarah: I'll take it."));
+    /// std::assert!(!SpeakerDiarization::has_speaker_prefix("Action Items:"));
+    /// ```
+    pub fn has_speaker_prefix(line: &str) -> bool {
+        Self::parse_speaker_prefix(line.trim()).is_some()
+    }
+
+    /// Splits a line into `(speaker, text)` when it carries a speaker prefix.
+    fn parse_speaker_prefix(line: &str) -> Option<(String, String)> {
+        let line = match (line.starts_with('['), line.find(']')) {
+            (true, std::option::Option::Some(end)) => line[end + 1..].trim_start(),
+            _ => line,
+        };
+        let (prefix, text) = line.split_once(':')?;
+        let (prefix, text) = (prefix.trim(), text.trim());
+        let words: Vec<&str> = prefix.split_whitespace().collect();
+        let is_name = !words.is_empty()
+            && prefix.len() <= MAX_SPEAKER_PREFIX_LEN
+            && words.len() <= MAX_SPEAKER_NAME_WORDS
+            && words.iter().all(|word| {
+                word.chars().next().is_some_and(char::is_uppercase)
+                    && word.chars().all(|c| c.is_alphabetic() || c == '.' || c == '-' || c == '\'')
+            });
+        if is_name && !text.is_empty() {
+            std::option::Option::Some((std::string::String::from(prefix), std::string::String::from(text)))
+        } else {
+            std::option::Option::None
+        }
+    }
+
+    /// Lowercased significant words (four or more characters) of a text.
+    fn keywords(text: &str) -> Vec<String> {
+        text.split(|c: char| !c.is_alphanumeric())
+            .filter(|word| word.len() >= 4)
+            .map(str::to_lowercase)
+            .collect()
+    }
+
+    /// Finds the turn sharing the most keywords with the title (earliest on ties).
+    fn best_matching_turn(&self, title: &str) -> Option<&crate::domain::speaker_turn::SpeakerTurn> {
+        let title_words = Self::keywords(title);
+        self.turns
+            .iter()
+            .map(|turn| {
+                let turn_words = Self::keywords(&turn.text);
+                let score = title_words.iter().filter(|w| turn_words.contains(w)).count();
+                (score, turn)
+            })
+            .filter(|(score, _)| *score > 0)
+            .rev()
+            .max_by_key(|(score, _)| *score)
+            .map(|(_, turn)| turn)
+    }
+
+    /// Splits turn text into words, keeping apostrophes for contractions.
+    fn words(text: &str) -> Vec<&str> {
+        text.split(|c: char| !c.is_alphanumeric() && c != '\'')
+            .filter(|word| !word.is_empty())
+            .collect()
+    }
+
+    /// Returns a person other than the speaker who is named as responsible in the turn.
+    ///
+    /// A capitalized name directly followed by a delegation verb wins. Failing
+    /// that, a first-person commitment keeps the speaker; otherwise any other
+    /// known speaker mentioned by first name is used.
+    fn named_assignee(&self, turn: &crate::domain::speaker_turn::SpeakerTurn) -> Option<String> {
+        let words = Self::words(&turn.text);
+        let speaker_first_name = turn.speaker.split_whitespace().next().unwrap_or_default();
+        let delegated = words
+            .windows(2)
+            .find(|pair| {
+                pair[0].chars().next().is_some_and(char::is_uppercase)
+                    && !NON_NAME_WORDS.contains(&pair[0])
+                    && pair[0] != speaker_first_name
+                    && DELEGATION_VERBS.contains(&pair[1].to_lowercase().as_str())
+            })
+            .map(|pair| std::string::String::from(pair[0]));
+        if delegated.is_some() || Self::is_self_commitment(&words) {
+            return delegated;
+        }
+        self.speakers()
+            .into_iter()
+            .filter(|speaker| *speaker != turn.speaker)
+            .find(|speaker| {
+                let first_name = speaker.split_whitespace().next().unwrap_or_default();
+                words.contains(&first_name)
+            })
+    }
+
+    /// Returns true when the words contain a first-person commitment phrase.
+    fn is_self_commitment(words: &[&str]) -> bool {
+        let lowered: Vec<String> = words.iter().map(|word| word.to_lowercase()).collect();
+        lowered.iter().any(|word| word == "i'll")
+            || lowered.windows(2).any(|pair| {
+                matches!(
+                    (pair[0].as_str(), pair[1].as_str()),
+                    ("i", "will") | ("i", "can") | ("let", "me") | ("i'm", "going") | ("i", "am")
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const MULTI_SPEAKER_TRANSCRIPT: &str = r#"
+Sprint Planning - November 6, 2025
+
+John: Morning everyone. I'll finish the API documentation by Friday.
+Sarah: Thanks John. I will review the security audit findings.
+John: Mike, can you update the deployment scripts?
+Mike: Sure.
+Sarah: Emily should organize the team retrospective.
+[00:12:41] Alex: Let me test the database migration script
+before November 12th.
+"#;
+
+    fn item(title: &str) -> crate::domain::action_item::ActionItem {
+        crate::domain::action_item::ActionItem {
+            title: std::string::String::from(title),
+            assignee: std::option::Option::None,
+            due_date: std::option::Option::None,
+            source_utterance: std::option::Option::None,
+        }
+    }
+
+    #[test]
+    fn test_detects_speaker_turns() {
+        // Test: Validates speaker prefix detection, timestamps, and continuation lines.
+        // Justification: Turns are the basis of all speaker-aware assignment.
+        let diarization = super::SpeakerDiarization::from_transcript(MULTI_SPEAKER_TRANSCRIPT);
+
+        std::assert_eq!(diarization.turns().len(), 6);
+        std::assert_eq!(diarization.speakers(), vec!["John", "Sarah", "Mike", "Alex"]);
+        std::assert_eq!(
+            diarization.turns()[5].text,
+            "Let me test the database migration script before November 12th."
+        );
+    }
+
+    #[test]
+    fn test_plain_transcript_is_not_diarized() {
+        // Test: Validates that transcripts without prefixes pass through unchanged.
+        // Justification: Existing non-diarized inputs must keep their current behavior.
+        let transcript = "Action Items:\n1. John will complete the docs.";
+        let diarization = super::SpeakerDiarization::from_transcript(transcript);
+
+        std::assert!(!diarization.is_diarized());
+        std::assert_eq!(diarization.build_prompt_input(transcript), transcript);
+        std::assert!(diarization.resolve_assignee(&item("Complete the docs")).is_none());
+    }
+
+    #[test]
+    fn test_prompt_input_lists_speakers() {
+        // Test: Validates that speaker context is prepended for diarized transcripts.
+        // Justification: The LLM needs the speaker list to default assignees correctly.
+        let diarization = super::SpeakerDiarization::from_transcript(MULTI_SPEAKER_TRANSCRIPT);
+        let input = diarization.build_prompt_input(MULTI_SPEAKER_TRANSCRIPT);
+
+        std::assert!(input.contains("Speakers: John, Sarah, Mike, Alex."));
+        std::assert!(input.ends_with(MULTI_SPEAKER_TRANSCRIPT));
+    }
+
+    #[test]
+    fn test_self_assignments_use_speaker() {
+        // Test: Validates that first-person commitments resolve to the speaker.
+        // Justification: This is the primary gap diarization closes.
+        let diarization = super::SpeakerDiarization::from_transcript(MULTI_SPEAKER_TRANSCRIPT);
+
+        std::assert_eq!(diarization.resolve_assignee(&item("Finish API documentation")).as_deref(), Some("John"));
+        std::assert_eq!(diarization.resolve_assignee(&item("Review security audit findings")).as_deref(), Some("Sarah"));
+        std::assert_eq!(diarization.resolve_assignee(&item("Test database migration script")).as_deref(), Some("Alex"));
+    }
+
+    #[test]
+    fn test_third_party_assignments_override_speaker() {
+        // Test: Validates that a named person overrides the speaker.
+        // Justification: Delegated actions must not be attributed to the delegator.
+        let diarization = super::SpeakerDiarization::from_transcript(MULTI_SPEAKER_TRANSCRIPT);
+
+        std::assert_eq!(diarization.resolve_assignee(&item("Update deployment scripts")).as_deref(), Some("Mike"));
+        std::assert_eq!(diarization.resolve_assignee(&item("Organize team retrospective")).as_deref(), Some("Emily"));
+    }
+
+    #[test]
+    fn test_explicit_assignee_is_kept() {
+        // Test: Validates that an assignee returned by the extractor is never replaced.
+        // Justification: The LLM's explicit extraction takes precedence over heuristics.
+        let diarization = super::SpeakerDiarization::from_transcript(MULTI_SPEAKER_TRANSCRIPT);
+        let mut explicit = item("Finish API documentation");
+        explicit.assignee = std::option::Option::Some(std::string::String::from("Priya"));
+
+        std::assert_eq!(diarization.resolve_assignee(&explicit).as_deref(), Some("Priya"));
+    }
+
+    #[test]
+    fn test_locate_utterance_finds_line_and_speaker() {
+        // Test: Validates the best-matching line is reported with its 1-based number and speaker.
+        // Justification: The line reference is what lets a reader check a generated task against the transcript.
+        let update = super::SpeakerDiarization::locate_utterance(MULTI_SPEAKER_TRANSCRIPT, "Update the deployment scripts").unwrap();
+        let migration = super::SpeakerDiarization::locate_utterance(MULTI_SPEAKER_TRANSCRIPT, "Test database migration").unwrap();
+
+        std::assert_eq!(update.line, 6);
+        std::assert_eq!(update.speaker.as_deref(), Some("John"));
+        std::assert_eq!(update.text, "Mike, can you update the deployment scripts?");
+        std::assert_eq!(migration.line, 9);
+        std::assert_eq!(migration.speaker.as_deref(), Some("Alex"));
+        std::assert!(super::SpeakerDiarization::locate_utterance(MULTI_SPEAKER_TRANSCRIPT, "Buy snacks").is_none());
+    }
+}
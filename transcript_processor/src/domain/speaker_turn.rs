@@ -0,0 +1,50 @@
+//! Defines the SpeakerTurn value object for diarized transcript lines.
+//!
+//! A SpeakerTurn captures one contiguous utterance attributed to a single
+//! speaker, as detected from `Speaker:` prefixes in a raw transcript. Lines
+//! without a prefix are folded into the preceding speaker's turn.
+//!
+//! Revision History
+//! - 2026-10-16T09:00:00Z @AI: Initial SpeakerTurn struct for speaker diarization.
+
+/// Represents a single utterance attributed to a named speaker.
+///
+/// # Fields
+///
+/// * `speaker` - The speaker name as written in the transcript prefix.
+/// * `text` - The utterance text with the speaker prefix removed.
+///
+/// # Examples
+///
+/// ```
+/// # use transcript_processor::domain::speaker_turn::SpeakerTurn;
+/// let turn = SpeakerTurn {
+///     speaker: std::string::String::from("John"),
+///     text: std::string::String::from("I'll update the deployment scripts."),
+/// };
+/// assert_eq!(turn.speaker, "John");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SpeakerTurn {
+    /// The speaker name as written in the transcript prefix.
+    pub speaker: String,
+
+    /// The utterance text with the speaker prefix removed.
+    pub text: String,
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_speaker_turn_creation() {
+        // Test: Validates that a SpeakerTurn stores speaker and text.
+        // Justification: SpeakerTurn is the unit consumed by assignee resolution.
+        let turn = super::SpeakerTurn {
+            speaker: std::string::String::from("Sarah"),
+            text: std::string::String::from("I will review the audit."),
+        };
+
+        std::assert_eq!(turn.speaker, "Sarah");
+        std::assert_eq!(turn.text, "I will review the audit.");
+    }
+}
@@ -5,6 +5,10 @@
 //! links back to the source transcript for traceability.
 //!
 //! Revision History
+//! - 2026-10-18T05:20:00Z @AI: Add priority, tags, and revisions, and auto_triage() through task_manager's TriageService.
+//! - 2026-10-18T01:50:00Z @AI: Add idempotency_key derived from the source transcript and utterance.
+//! - 2026-10-17T04:10:00Z @AI: Add update_from_action_item for re-processed transcripts.
+//! - 2026-10-17T03:50:00Z @AI: Carry the source utterance from the ActionItem for traceability.
 //! - 2025-11-06T18:14:00Z @AI: Add HexEntity derive for HEXSER framework alignment.
 //! - 2025-11-06T17:41:00Z @AI: Initial Task struct definition with from_action_item constructor.
 
@@ -22,6 +26,10 @@
 /// * `due_date` - Optional deadline in string format.
 /// * `status` - Current lifecycle status of the task.
 /// * `source_transcript_id` - Optional link to the originating transcript.
+/// * `source_utterance` - Optional transcript line the task was extracted from.
+/// * `priority` - Priority assigned by auto-triage; None until triaged.
+/// * `tags` - Labels, including the triage label (e.g. "triage:enhance").
+/// * `revisions` - Revision entries, including the auto-triage decision and its reason.
 /// * `created_at` - UTC timestamp when task was created.
 /// * `updated_at` - UTC timestamp of last modification.
 ///
@@ -34,6 +42,7 @@
 ///     title: std::string::String::from("Review code"),
 ///     assignee: Some(std::string::String::from("Alice")),
 ///     due_date: None,
+///     source_utterance: None,
 /// };
 ///
 /// let task = Task::from_action_item(&action, Some(std::string::String::from("transcript-123")));
@@ -59,6 +68,22 @@ pub struct Task {
     /// Optional link to the source transcript this task was extracted from.
     pub source_transcript_id: Option<String>,
 
+    /// Optional transcript line (number, speaker, text) this task was extracted from.
+    #[serde(default)]
+    pub source_utterance: Option<task_manager::domain::transcript_utterance::TranscriptUtterance>,
+
+    /// Priority assigned by auto-triage; None until triaged.
+    #[serde(default)]
+    pub priority: Option<task_manager::domain::priority::Priority>,
+
+    /// Labels, including the triage label (e.g. "triage:enhance").
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    /// Revision entries, including the auto-triage decision and its reason.
+    #[serde(default)]
+    pub revisions: Vec<task_manager::domain::task_revision::TaskRevision>,
+
     /// UTC timestamp when this task was created.
     pub created_at: chrono::DateTime<chrono::Utc>,
 
@@ -90,6 +115,7 @@ impl Task {
     ///     title: std::string::String::from("Test task"),
     ///     assignee: None,
     ///     due_date: None,
+    ///     source_utterance: None,
     /// };
     ///
     /// let task = Task::from_action_item(&action, None);
@@ -109,10 +135,118 @@ impl Task {
             due_date: action.due_date.clone(),
             status: crate::domain::task_status::TaskStatus::Todo,
             source_transcript_id: transcript_id,
+            source_utterance: action.source_utterance.clone(),
+            priority: None,
+            tags: Vec::new(),
+            revisions: Vec::new(),
             created_at: now,
             updated_at: now,
         }
     }
+
+    /// Updates this task from a re-extracted ActionItem of the same transcript.
+    ///
+    /// Title, assignee, due date, and source utterance are replaced, and the
+    /// modification timestamp is refreshed. ID, status, source transcript, and
+    /// creation time are kept, so progress made on the task survives.
+    ///
+    /// # Arguments
+    ///
+    /// * `action` - The ActionItem matched to this task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use transcript_processor::domain::task::Task;
+    /// # use transcript_processor::domain::action_item::ActionItem;
+    /// let first = ActionItem {
+    ///     title: std::string::String::from("Review code"),
+    ///     assignee: None,
+    ///     due_date: None,
+    ///     source_utterance: None,
+    /// };
+    /// let mut task = Task::from_action_item(&first, Some(std::string::String::from("transcript-123")));
+    /// let id = task.id.clone();
+    ///
+    /// let corrected = ActionItem { assignee: Some(std::string::String::from("Alice")), ..first };
+    /// task.update_from_action_item(&corrected);
+    /// assert_eq!(task.id, id);
+    /// assert_eq!(task.assignee.as_deref(), Some("Alice"));
+    /// ```
+    pub fn update_from_action_item(&mut self, action: &crate::domain::action_item::ActionItem) {
+        self.title = action.title.clone();
+        self.assignee = action.assignee.clone();
+        self.due_date = action.due_date.clone();
+        self.source_utterance = action.source_utterance.clone();
+        self.updated_at = chrono::Utc::now();
+    }
+
+    /// Triages this task the way ManageTaskUseCase::create triages tasks in task_manager.
+    ///
+    /// The task is scored as its task_manager counterpart; the suggested
+    /// priority (only when none is set), the triage label, and the revision
+    /// entry explaining the decision are copied back onto this task.
+    ///
+    /// # Arguments
+    ///
+    /// * `triage` - The TriageService that scores the task.
+    ///
+    /// # Returns
+    ///
+    /// The TriageAssessment that was applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use transcript_processor::domain::task::Task;
+    /// # use transcript_processor::domain::action_item::ActionItem;
+    /// let action = ActionItem {
+    ///     title: std::string::String::from("Fix typo"),
+    ///     assignee: Some(std::string::String::from("Alice")),
+    ///     due_date: Some(std::string::String::from("2025-12-01")),
+    ///     source_utterance: None,
+    /// };
+    /// let mut task = Task::from_action_item(&action, None);
+    /// let triage = task_manager::domain::services::triage_service::TriageService::new(
+    ///     task_manager::domain::services::complexity_scorer::ComplexityScorer::new(),
+    /// );
+    ///
+    /// task.auto_triage(&triage);
+    /// assert!(task.priority.is_some());
+    /// assert!(task.tags.contains(&std::string::String::from("triage:enhance")));
+    /// assert_eq!(task.revisions.len(), 1);
+    /// ```
+    pub fn auto_triage(
+        &mut self,
+        triage: &task_manager::domain::services::triage_service::TriageService,
+    ) -> task_manager::domain::services::triage_service::TriageAssessment {
+        let action = transcript_extractor::domain::action_item::ActionItem {
+            title: self.title.clone(),
+            assignee: self.assignee.clone(),
+            due_date: self.due_date.clone(),
+        };
+        let mut counterpart = task_manager::domain::task::Task::from_action_item(&action, self.source_transcript_id.clone());
+        counterpart.id = self.id.clone();
+        counterpart.priority = self.priority;
+        counterpart.tags = self.tags.clone();
+
+        let assessment = triage.auto_triage(&mut counterpart);
+        self.priority = counterpart.priority;
+        self.tags = counterpart.tags;
+        self.revisions.extend(counterpart.revisions);
+        assessment
+    }
+
+    /// Returns the idempotency key of this task, derived from its source
+    /// transcript and utterance as task_manager derives it; None unless both are set.
+    ///
+    /// Two items said on the same line of the same transcript share a key,
+    /// even if the extractor phrased their titles differently.
+    pub fn idempotency_key(&self) -> Option<String> {
+        let transcript_id = self.source_transcript_id.as_ref()?;
+        let utterance = self.source_utterance.as_ref()?;
+        Some(task_manager::domain::task::Task::utterance_idempotency_key(transcript_id, utterance))
+    }
 }
 
 #[cfg(test)]
@@ -128,6 +262,7 @@ mod tests {
             title: std::string::String::from("Test action"),
             assignee: Some(std::string::String::from("Bob")),
             due_date: Some(std::string::String::from("2025-11-30")),
+            source_utterance: None,
         };
 
         let task = Task::from_action_item(&action, Some(std::string::String::from("transcript-456")));
@@ -150,6 +285,7 @@ mod tests {
             title: std::string::String::from("Minimal task"),
             assignee: None,
             due_date: None,
+            source_utterance: None,
         };
 
         let task = Task::from_action_item(&action, None);
@@ -170,6 +306,7 @@ mod tests {
             title: std::string::String::from("UUID test"),
             assignee: None,
             due_date: None,
+            source_utterance: None,
         };
 
         let task1 = Task::from_action_item(&action, None);
@@ -0,0 +1,83 @@
+//! Computes a fuzzy similarity score between two action item titles.
+//!
+//! Titles extracted by an LLM from overlapping transcript windows often differ
+//! only in articles or word order ("Review the audit" vs "Review audit"). This
+//! function compares significant lowercase words using Jaccard similarity so
+//! such near-duplicates can be merged.
+//!
+//! Revision History
+//! - 2026-10-16T09:40:00Z @AI: Initial word-level Jaccard title similarity.
+
+/// Words ignored when comparing titles.
+const STOPWORDS: &[&str] = &[
+    "a", "an", "the", "and", "or", "to", "of", "for", "on", "in", "by", "with", "at", "from",
+];
+
+/// Returns the Jaccard similarity (0.0-1.0) of the significant words in two titles.
+///
+/// Comparison is case-insensitive and ignores punctuation and common stopwords.
+/// Two titles without any significant words are considered identical only when
+/// both are empty after normalization.
+///
+/// # Arguments
+///
+/// * `left` - The first title.
+/// * `right` - The second title.
+///
+/// # Returns
+///
+/// The similarity score, where 1.0 means the same set of words.
+///
+/// # Examples
+///
+/// ```
+/// # use transcript_processor::domain::title_similarity::title_similarity;
+/// std::assert_eq!(title_similarity("Review the audit", "review audit"), 1.0);
+/// std::assert_eq!(title_similarity("Review audit", "Deploy service"), 0.0);
+/// ```
+pub fn title_similarity(left: &str, right: &str) -> f64 {
+    let left_words = significant_words(left);
+    let right_words = significant_words(right);
+    if left_words.is_empty() && right_words.is_empty() {
+        return 1.0;
+    }
+    let shared = left_words.intersection(&right_words).count();
+    let total = left_words.union(&right_words).count();
+    shared as f64 / total as f64
+}
+
+/// Lowercases a title and collects its words minus stopwords.
+fn significant_words(title: &str) -> std::collections::HashSet<String> {
+    title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .filter(|word| !STOPWORDS.contains(&word.as_str()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_identical_after_normalization() {
+        // Test: Validates that case, punctuation, and stopwords are ignored.
+        // Justification: These are the most common variations between chunk extractions.
+        std::assert_eq!(super::title_similarity("Update the Deployment Scripts.", "update deployment scripts"), 1.0);
+    }
+
+    #[test]
+    fn test_partial_overlap() {
+        // Test: Validates the Jaccard score for partially overlapping titles.
+        // Justification: The merge threshold is applied to this score.
+        // Shared {review, security, audit} / union {review, security, audit, findings} = 0.75
+        std::assert_eq!(super::title_similarity("Review security audit findings", "Review the security audit"), 0.75);
+    }
+
+    #[test]
+    fn test_disjoint_and_empty_titles() {
+        // Test: Validates the boundary scores.
+        // Justification: Unrelated titles must never merge; empty titles must not divide by zero.
+        std::assert_eq!(super::title_similarity("Write tests", "Book venue"), 0.0);
+        std::assert_eq!(super::title_similarity("", "the"), 1.0);
+    }
+}
@@ -29,6 +29,7 @@
 ///             title: std::string::String::from("Review PR"),
 ///             assignee: Some(std::string::String::from("Alice")),
 ///             due_date: None,
+///             source_utterance: None,
 ///         },
 ///     ],
 /// };
@@ -66,12 +67,14 @@ mod tests {
             title: std::string::String::from("First action"),
             assignee: None,
             due_date: None,
+            source_utterance: None,
         };
 
         let action2 = crate::domain::action_item::ActionItem {
             title: std::string::String::from("Second action"),
             assignee: Some(std::string::String::from("Bob")),
             due_date: Some(std::string::String::from("2025-11-20")),
+            source_utterance: None,
         };
 
         let analysis = TranscriptAnalysis {
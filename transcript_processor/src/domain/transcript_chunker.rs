@@ -0,0 +1,210 @@
+//! Splits long transcripts into overlapping windows sized to a token budget.
+//!
+//! Segments are formed on speaker and paragraph boundaries so that a window
+//! never starts mid-utterance. Segments are packed greedily into windows up to
+//! the configured budget, and trailing segments are repeated at the start of the
+//! next window so commitments spanning a boundary are seen whole at least once.
+//!
+//! Revision History
+//! - 2026-10-16T09:40:00Z @AI: Initial speaker/paragraph-aware transcript chunker.
+
+/// Approximate characters per token for English text under BPE tokenizers.
+const CHARS_PER_TOKEN: usize = 4;
+
+/// Estimates the token count of a text using a characters-per-token heuristic.
+///
+/// # Arguments
+///
+/// * `text` - The text to measure.
+///
+/// # Returns
+///
+/// The approximate number of tokens, rounded up.
+///
+/// # Examples
+///
+/// ```
+/// # use transcript_processor::domain::transcript_chunker::estimate_tokens;
+/// std::assert_eq!(estimate_tokens("abcdefgh"), 2);
+/// std::assert_eq!(estimate_tokens(""), 0);
+/// ```
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(CHARS_PER_TOKEN)
+}
+
+/// Splits a transcript into windows that fit the configured token budget.
+///
+/// A transcript that already fits is returned as a single, unmodified chunk.
+/// Otherwise each window holds whole segments (speaker turns or paragraphs);
+/// a single segment larger than the budget is split on word boundaries.
+///
+/// # Arguments
+///
+/// * `transcript` - The raw transcript text.
+/// * `config` - Window and overlap sizes.
+///
+/// # Returns
+///
+/// The ordered transcript windows.
+///
+/// # Examples
+///
+/// ```
+/// # use transcript_processor::domain::transcript_chunker::chunk_transcript;
+/// # use transcript_processor::domain::chunking_config::ChunkingConfig;
+/// let chunks = chunk_transcript("Alice: I'll send the notes.", &ChunkingConfig::default());
+/// std::assert_eq!(chunks.len(), 1);
+/// ```
+pub fn chunk_transcript(
+    transcript: &str,
+    config: &crate::domain::chunking_config::ChunkingConfig,
+) -> Vec<String> {
+    let window = config.window_tokens.max(1);
+    if estimate_tokens(transcript) <= window {
+        return vec![std::string::String::from(transcript)];
+    }
+    // Clamp overlap so every window makes forward progress.
+    let overlap = config.overlap_tokens.min(window / 2);
+
+    let segments: Vec<String> = split_segments(transcript)
+        .into_iter()
+        .flat_map(|segment| split_oversized(segment, window))
+        .collect();
+
+    let mut chunks = Vec::new();
+    let mut current: Vec<String> = Vec::new();
+    for segment in segments {
+        // Each segment costs its own tokens plus one for the joining newline.
+        let segment_tokens = estimate_tokens(&segment) + 1;
+        if !current.is_empty() && window_tokens(&current) + segment_tokens > window {
+            chunks.push(current.join("\n"));
+            current = overlap_tail(&current, overlap);
+            if window_tokens(&current) + segment_tokens > window {
+                current.clear();
+            }
+        }
+        current.push(segment);
+    }
+    if !current.is_empty() {
+        chunks.push(current.join("\n"));
+    }
+    chunks
+}
+
+/// Groups lines into segments that start at speaker prefixes or after blank lines.
+fn split_segments(transcript: &str) -> Vec<String> {
+    let mut segments: Vec<String> = Vec::new();
+    let mut starts_new = true;
+    for line in transcript.lines() {
+        if line.trim().is_empty() {
+            starts_new = true;
+            continue;
+        }
+        let is_speaker_line = crate::domain::speaker_diarization::SpeakerDiarization::has_speaker_prefix(line);
+        match segments.last_mut() {
+            std::option::Option::Some(last) if !starts_new && !is_speaker_line => {
+                last.push('\n');
+                last.push_str(line);
+            }
+            _ => segments.push(std::string::String::from(line)),
+        }
+        starts_new = false;
+    }
+    segments
+}
+
+/// Splits a segment exceeding the window into word-bounded pieces.
+fn split_oversized(segment: String, window: usize) -> Vec<String> {
+    if estimate_tokens(&segment) <= window {
+        return vec![segment];
+    }
+    segment
+        .split_whitespace()
+        .fold(Vec::new(), |mut pieces: Vec<String>, word| {
+            match pieces.last_mut() {
+                std::option::Option::Some(piece) if estimate_tokens(piece) + estimate_tokens(word) < window => {
+                    piece.push(' ');
+                    piece.push_str(word);
+                }
+                _ => pieces.push(std::string::String::from(word)),
+            }
+            pieces
+        })
+}
+
+/// Sums the estimated tokens of a window's segments including separators.
+fn window_tokens(segments: &[String]) -> usize {
+    segments.iter().map(|segment| estimate_tokens(segment) + 1).sum()
+}
+
+/// Returns the trailing segments whose combined size fits within the overlap budget.
+fn overlap_tail(segments: &[String], overlap: usize) -> Vec<String> {
+    let mut tail: Vec<String> = segments
+        .iter()
+        .rev()
+        .scan(0usize, |used, segment| {
+            *used += estimate_tokens(segment) + 1;
+            if *used <= overlap { std::option::Option::Some(segment.clone()) } else { std::option::Option::None }
+        })
+        .collect();
+    tail.reverse();
+    tail
+}
+
+#[cfg(test)]
+mod tests {
+    fn long_transcript(turns: usize) -> String {
+        (0..turns)
+            .map(|i| std::format!("Speaker{}: Discussion point number {} about the quarterly roadmap.", ["A", "B", "C"][i % 3], i))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_short_transcript_is_single_chunk() {
+        // Test: Validates that transcripts within budget are passed through unchanged.
+        // Justification: Short inputs must keep the existing single-call behavior.
+        let transcript = "\nJohn: I'll review the PR.\n";
+        let chunks = super::chunk_transcript(transcript, &crate::domain::chunking_config::ChunkingConfig::default());
+
+        std::assert_eq!(chunks, vec![std::string::String::from(transcript)]);
+    }
+
+    #[test]
+    fn test_long_transcript_splits_within_budget() {
+        // Test: Validates that every window respects the token budget.
+        // Justification: Exceeding the budget is exactly the truncation bug being fixed.
+        let config = crate::domain::chunking_config::ChunkingConfig::new(100).with_overlap_tokens(20);
+        let chunks = super::chunk_transcript(&long_transcript(60), &config);
+
+        std::assert!(chunks.len() > 1);
+        std::assert!(chunks.iter().all(|chunk| super::estimate_tokens(chunk) <= 100));
+    }
+
+    #[test]
+    fn test_chunks_split_on_speaker_boundaries_and_overlap() {
+        // Test: Validates that windows start at speaker turns and share trailing context.
+        // Justification: Mid-utterance splits and missing overlap would drop boundary commitments.
+        let config = crate::domain::chunking_config::ChunkingConfig::new(100).with_overlap_tokens(20);
+        let chunks = super::chunk_transcript(&long_transcript(60), &config);
+
+        std::assert!(chunks.iter().all(|chunk| chunk.starts_with("Speaker")));
+        let first_tail = chunks[0].lines().last().unwrap();
+        std::assert!(chunks[1].starts_with(first_tail));
+        std::assert!(chunks.last().unwrap().contains("number 59"));
+    }
+
+    #[test]
+    fn test_paragraph_boundaries_and_oversized_segments() {
+        // Test: Validates paragraph grouping and word-level splitting of oversized paragraphs.
+        // Justification: Transcripts without speaker prefixes must still be chunked safely.
+        let paragraph = "word ".repeat(200);
+        let transcript = std::format!("{}\n\n{}", paragraph.trim(), "Closing remarks.");
+        let config = crate::domain::chunking_config::ChunkingConfig::new(50).with_overlap_tokens(0);
+        let chunks = super::chunk_transcript(&transcript, &config);
+
+        std::assert!(chunks.len() > 2);
+        std::assert!(chunks.iter().all(|chunk| super::estimate_tokens(chunk) <= 50));
+        std::assert!(chunks.last().unwrap().ends_with("Closing remarks."));
+    }
+}
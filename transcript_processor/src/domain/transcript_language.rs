@@ -0,0 +1,159 @@
+//! Defines TranscriptLanguage for language-aware action item extraction.
+//!
+//! The extraction prompts are written in English, and models read other
+//! languages poorly unless told what to expect. TranscriptLanguage detects the
+//! dominant language of a transcript (or takes an explicit override) and
+//! renders a hint that is prepended to the extractor input.
+//!
+//! Detection runs per line and weights each line by its length, so a Spanish
+//! meeting with a few English phrases is still detected as Spanish.
+//!
+//! Revision History
+//! - 2026-10-17T04:50:00Z @AI: Initial TranscriptLanguage with whatlang detection and explicit override parsing.
+
+/// Lines with fewer words than this are too short to detect reliably and are skipped.
+const MIN_WORDS_PER_LINE: usize = 3;
+
+/// The language a transcript is written in.
+///
+/// # Examples
+///
+/// ```
+/// # use transcript_processor::domain::transcript_language::TranscriptLanguage;
+/// let language = TranscriptLanguage::parse("Spanish").unwrap();
+/// std::assert_eq!(language.code(), "spa");
+/// std::assert_eq!(TranscriptLanguage::parse("spa").unwrap(), language);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TranscriptLanguage {
+    lang: whatlang::Lang,
+}
+
+impl TranscriptLanguage {
+    /// Detects the dominant language of a transcript.
+    ///
+    /// Each line of at least three words is detected separately and weighted
+    /// by its character count; the language with the largest total wins. When
+    /// no line is long enough, the whole transcript is detected at once and
+    /// the result is kept only if whatlang considers it reliable.
+    ///
+    /// # Arguments
+    ///
+    /// * `transcript` - The raw transcript text.
+    ///
+    /// # Returns
+    ///
+    /// The dominant language, or None when the text gives no signal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use transcript_processor::domain::transcript_language::TranscriptLanguage;
+    /// let language = TranscriptLanguage::detect("Ana: Yo me encargo de revisar el presupuesto antes del viernes.").unwrap();
+    /// std::assert_eq!(language.name(), "Spanish");
+    /// ```
+    pub fn detect(transcript: &str) -> Option<Self> {
+        let mut weights: std::collections::HashMap<whatlang::Lang, usize> = std::collections::HashMap::new();
+        for line in transcript.lines().map(str::trim) {
+            if line.split_whitespace().count() < MIN_WORDS_PER_LINE {
+                continue;
+            }
+            if let std::option::Option::Some(lang) = whatlang::detect_lang(line) {
+                *weights.entry(lang).or_insert(0) += line.chars().count();
+            }
+        }
+        let dominant = weights
+            .into_iter()
+            .max_by(|left, right| left.1.cmp(&right.1).then_with(|| right.0.code().cmp(left.0.code())))
+            .map(|(lang, _)| lang)
+            .or_else(|| whatlang::detect(transcript).filter(whatlang::Info::is_reliable).map(|info| info.lang()));
+        dominant.map(|lang| TranscriptLanguage { lang })
+    }
+
+    /// Parses an explicit language given as an ISO 639-3 code or English name.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A code such as "spa" or a name such as "Spanish" (case-insensitive).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(TranscriptLanguage)` - The language.
+    /// * `Err(String)` - The value names no supported language.
+    pub fn parse(value: &str) -> std::result::Result<Self, String> {
+        let requested = value.trim().to_lowercase();
+        whatlang::Lang::all()
+            .iter()
+            .find(|lang| lang.code() == requested || lang.eng_name().to_lowercase() == requested)
+            .map(|lang| TranscriptLanguage { lang: *lang })
+            .ok_or_else(|| {
+                std::format!(
+                    "Unknown language '{}'. Use an ISO 639-3 code (e.g. spa, eng, fra) or an English name (e.g. Spanish).",
+                    value.trim()
+                )
+            })
+    }
+
+    /// Returns the ISO 639-3 code, e.g. "spa".
+    pub fn code(&self) -> &'static str {
+        self.lang.code()
+    }
+
+    /// Returns the English name, e.g. "Spanish".
+    pub fn name(&self) -> &'static str {
+        self.lang.eng_name()
+    }
+
+    /// Builds the hint prepended to the extractor input.
+    ///
+    /// English transcripts get no hint since the prompts are already in English.
+    ///
+    /// # Returns
+    ///
+    /// The hint followed by a blank line, or an empty string for English.
+    pub fn prompt_hint(&self) -> String {
+        if self.lang == whatlang::Lang::Eng {
+            return std::string::String::new();
+        }
+        std::format!(
+            "Language hint: the transcript is in {name}. Read it as {name} and write action item titles in {name}; keep the JSON field names in English.\n\n",
+            name = self.name()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const SPANISH_TRANSCRIPT: &str = "Reunión de planificación\n\
+Ana: Buenos días a todos. Yo me encargo de revisar el presupuesto antes del viernes.\n\
+Carlos: Perfecto. Luis tiene que actualizar los scripts de despliegue esta semana.\n\
+Luis: De acuerdo, lo haré mañana por la mañana.";
+
+    #[test]
+    fn test_detects_spanish_transcript() {
+        // Test: Validates a Spanish meeting transcript is detected as Spanish and gets a Spanish hint.
+        // Justification: Spanish meetings are the case the detection was added for.
+        let language = super::TranscriptLanguage::detect(SPANISH_TRANSCRIPT).unwrap();
+
+        std::assert_eq!(language.code(), "spa");
+        std::assert!(language.prompt_hint().contains("the transcript is in Spanish"));
+    }
+
+    #[test]
+    fn test_mixed_transcript_picks_dominant_language() {
+        // Test: Validates a mostly Spanish transcript with a short English exchange is detected as Spanish.
+        // Justification: Meetings often mix in English phrases; the hint must follow the majority.
+        let transcript = std::format!("{}\nMike: Sounds good, I will check it.", SPANISH_TRANSCRIPT);
+
+        std::assert_eq!(super::TranscriptLanguage::detect(&transcript).unwrap().code(), "spa");
+    }
+
+    #[test]
+    fn test_parse_and_english_hint() {
+        // Test: Validates codes and names parse, unknown values are rejected, and English needs no hint.
+        // Justification: The explicit override comes from user input and must fail loudly when mistyped.
+        std::assert_eq!(super::TranscriptLanguage::parse("FRA").unwrap().name(), "French");
+        std::assert!(super::TranscriptLanguage::parse("Klingon").unwrap_err().contains("Unknown language"));
+        std::assert_eq!(super::TranscriptLanguage::parse("English").unwrap().prompt_hint(), "");
+    }
+}
@@ -12,13 +12,28 @@
 //!
 //! Prerequisites:
 //! - For Ollama adapter: Ollama must be installed and running (https://ollama.ai) + Run: ollama pull llama3.2
-//! - For Candle adapter: First run will download Phi-3.5-mini-instruct model from HuggingFace (~7.6GB)
+//! - For Candle adapter: First run will download the selected model from HuggingFace (~7.6GB; default Phi-3.5-mini-instruct, override with CANDLE_MODEL)
 //!
 //! Usage:
 //! - Use Ollama adapter (default): cargo run
 //! - Use Candle adapter: EXTRACTOR=candle cargo run
+//! - Use an OpenAI-compatible endpoint: EXTRACTOR=openai OPENAI_BASE_URL=http://127.0.0.1:8000/v1 OPENAI_MODEL=... cargo run
+//! - Override the chunk window for long transcripts: TRANSCRIPT_WINDOW_TOKENS=4000 cargo run
+//! - Use a custom extraction prompt (Ollama/Candle): EXTRACTION_PROMPT_TEMPLATE=prompt.txt cargo run
+//! - Accept extra JSON field names (Candle/OpenAI): FIELD_ALIASES=aliases.json cargo run
+//! - Override transcript language detection: cargo run -- --language spa
+//! - Process a transcript file instead of the built-in demo: cargo run -- --transcript standup.txt
 //!
 //! Revision History
+//! - 2026-10-18T05:20:00Z @AI: Print the auto-triaged priority and labels of each task.
+//! - 2026-10-17T20:10:00Z @AI: Add --transcript and process under a path- or content-derived transcript ID instead of a random one.
+//! - 2026-10-17T06:30:00Z @AI: Load custom parser field aliases from FIELD_ALIASES at startup.
+//! - 2026-10-17T04:50:00Z @AI: Add --language to override transcript language detection.
+//! - 2026-10-17T04:30:00Z @AI: Load a custom extraction prompt template from EXTRACTION_PROMPT_TEMPLATE at startup.
+//! - 2026-10-17T03:50:00Z @AI: Print the transcript line each task was extracted from.
+//! - 2026-10-16T10:30:00Z @AI: Add OpenAI-compatible adapter selection via EXTRACTOR=openai.
+//! - 2026-10-16T10:10:00Z @AI: Pass CANDLE_MODEL selection to the Candle adapter.
+//! - 2026-10-16T09:40:00Z @AI: Configure transcript chunk window via TRANSCRIPT_WINDOW_TOKENS.
 //! - 2025-11-08T11:36:00Z @AI: Add mistral.rs embedded adapter selection via EXTRACTOR=mistral_embed with feature gate.
 //! - 2025-11-08T10:55:00Z @AI: Add Rig adapter selection via EXTRACTOR=rig with feature gate.
 //! - 2025-11-08T10:44:00Z @AI: Add Mistral.rs adapter selection via EXTRACTOR=mistral with feature gate.
@@ -51,17 +66,56 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
 
     println!("Using extractor: {}", extractor_type);
 
+    // An explicit --language overrides detection; an unknown language fails startup
+    let args: Vec<String> = std::env::args().collect();
+    let language = match args.iter().position(|arg| arg == "--language") {
+        Some(index) => {
+            let value = args.get(index + 1).ok_or("--language requires a value, e.g. --language spa")?;
+            Some(transcript_processor::domain::transcript_language::TranscriptLanguage::parse(value)?)
+        }
+        None => None,
+    };
+
+    // An explicit --transcript reads that file; its path is the stable transcript ID
+    let transcript_path = match args.iter().position(|arg| arg == "--transcript") {
+        Some(index) => {
+            let value = args.get(index + 1).ok_or("--transcript requires a path, e.g. --transcript standup.txt")?;
+            Some(std::path::PathBuf::from(value))
+        }
+        None => None,
+    };
+
+    // Load the extraction prompt template up front so a broken template fails startup
+    let prompt_template = transcript_processor::domain::extraction_prompt_template::ExtractionPromptTemplate::from_env()?;
+
+    // Extra field names for the response parser; a broken alias file also fails startup
+    let field_aliases = match std::env::var("FIELD_ALIASES") {
+        Ok(path) if !path.trim().is_empty() => {
+            task_manager::infrastructure::llm_parsers::field_aliases::FieldAliases::from_file(std::path::Path::new(&path))?
+        }
+        _ => task_manager::infrastructure::llm_parsers::field_aliases::FieldAliases::default(),
+    };
+
     // Create the appropriate transcript extractor adapter based on environment variable
     // Both adapters implement TranscriptExtractorPort, demonstrating the port/adapter pattern
     let transcript_extractor: std::sync::Arc<dyn transcript_processor::application::ports::transcript_extractor_port::TranscriptExtractorPort> = match extractor_type.as_str() {
         "candle" => {
-            println!("Initializing Candle adapter with Phi-3.5-mini-instruct model...");
-            println!("(First run will download ~7.6GB model from HuggingFace)");
-            let candle_adapter = transcript_processor::adapters::candle_adapter::CandleTranscriptExtractorAdapter::new()
+            let model_id = transcript_processor::adapters::candle_model_spec::CandleModelSpec::model_id_from_env();
+            println!("Initializing Candle adapter with {} model...", model_id);
+            println!("(First run will download the model from HuggingFace)");
+            let candle_adapter = transcript_processor::adapters::candle_adapter::CandleTranscriptExtractorAdapter::new(model_id)
                 .await
-                .map_err(|e| std::format!("Failed to initialize Candle adapter: {}", e))?;
+                .map_err(|e| std::format!("Failed to initialize Candle adapter: {}", e))?
+                .with_prompt_template(prompt_template)
+                .with_field_aliases(field_aliases);
             std::sync::Arc::new(candle_adapter)
         },
+        "openai" => {
+            println!("Initializing OpenAI-compatible adapter (OPENAI_BASE_URL / OPENAI_MODEL)...");
+            let adapter = transcript_processor::adapters::openai_adapter::OpenAiTranscriptExtractorAdapter::new()
+                .with_field_aliases(field_aliases);
+            std::sync::Arc::new(adapter)
+        },
         "mistral" => {
             println!("Initializing Mistral.rs adapter (via mistralrs-server)...");
             #[cfg(feature = "mistral_rs")]
@@ -102,7 +156,8 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
             println!("Initializing Ollama adapter with llama3.2 model...");
             let ollama_adapter = transcript_processor::adapters::ollama_adapter::OllamaTranscriptExtractorAdapter::new(
                 std::string::String::from("llama3.2"),
-            );
+            )
+            .with_prompt_template(prompt_template);
             std::sync::Arc::new(ollama_adapter)
         }
     };
@@ -126,11 +181,22 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
     // Create the transcript processing use case
     // Pass concrete repository by value (owned by use case)
     // Must be mutable because process() requires &mut self for save() operations
+    // Long transcripts are split into windows of this many (approximate) tokens
+    let window_tokens = std::env::var("TRANSCRIPT_WINDOW_TOKENS")
+        .ok()
+        .and_then(|value| value.parse::<usize>().ok())
+        .unwrap_or(transcript_processor::domain::chunking_config::ChunkingConfig::DEFAULT_WINDOW_TOKENS);
+
     let mut process_transcript_use_case =
         transcript_processor::application::use_cases::process_transcript::ProcessTranscriptUseCase::new(
             transcript_extractor.clone(),
             task_repo_for_processing,
-        );
+        )
+        .with_chunking(transcript_processor::domain::chunking_config::ChunkingConfig::new(window_tokens));
+    if let Some(language) = language {
+        println!("Transcript language set to {} (detection disabled)", language.name());
+        process_transcript_use_case = process_transcript_use_case.with_language(language);
+    }
 
     println!("✓ Use cases initialized\n");
 
@@ -141,8 +207,8 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
 
     println!("=== Processing Transcript ===\n");
 
-    // Example transcript from a project meeting
-    let transcript = r#"
+    // Example transcript from a project meeting, used when no --transcript is given
+    const DEMO_TRANSCRIPT: &str = r#"
 Team Meeting - November 6, 2025
 
 Action Items:
@@ -153,14 +219,26 @@ Action Items:
 5. The database migration script needs to be tested by Alex before November 12th. High priority.
 "#;
 
-    println!("Input transcript:\n{}\n", transcript);
+    // File transcripts keep their ID across edits; the demo's ID comes from its content
+    let (transcript, transcript_id) = match &transcript_path {
+        Some(path) => (
+            std::fs::read_to_string(path).map_err(|e| std::format!("Failed to read {}: {}", path.display(), e))?,
+            transcript_processor::domain::transcript_id::from_path(path),
+        ),
+        None => (
+            std::string::String::from(DEMO_TRANSCRIPT),
+            transcript_processor::domain::transcript_id::from_content(DEMO_TRANSCRIPT),
+        ),
+    };
+
+    println!("Input transcript ({}):\n{}\n", transcript_id, transcript);
     println!("Sending to LLM for extraction...\n");
 
     // Execute the processing pipeline
     // This calls the LLM, parses the response, and persists tasks using HEXSER's save()
-    // Note: process() is now synchronous but still calls async extractor internally
+    // Re-running with the same transcript ID updates earlier tasks instead of duplicating them
     let extracted_tasks = process_transcript_use_case
-        .process(transcript)
+        .process_with_id(&transcript, &transcript_id)
         .await
         .map_err(|e| std::format!("Failed to process transcript: {}", e))?;
 
@@ -186,7 +264,13 @@ Action Items:
                 .unwrap_or(&std::string::String::from("No deadline"))
         );
         println!("   Status: {:?}", task.status);
+        if let Some(priority) = task.priority {
+            println!("   Priority: {} ({})", priority.as_str(), task.tags.join(", "));
+        }
         println!("   Task ID: {}", task.id);
+        if let Some(utterance) = &task.source_utterance {
+            println!("   Source: {}", utterance);
+        }
         println!("   Created: {}", task.created_at);
         println!();
     }
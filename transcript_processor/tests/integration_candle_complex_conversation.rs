@@ -23,6 +23,7 @@
 //! ```
 //!
 //! Revision History
+//! - 2026-10-16T10:10:00Z @AI: Pass CANDLE_MODEL (default Phi-3.5-mini-instruct) to the adapter constructor.
 //! - 2025-11-07T09:07:00Z @AI: Upgrade to Phi-3.5-mini-instruct using phi3 module (~7.6GB).
 //! - 2025-11-07T08:34:00Z @AI: Revert to Phi-2 model to fix config deserialization error (~5.3GB).
 //! - 2025-11-06T21:43:00Z @AI: Downgrade model from Phi-4 to Phi-3.5-mini-instruct to reduce download size (~7.1GB instead of 14.7GB).
@@ -154,7 +155,9 @@ async fn test_candle_complex_conversation_with_red_herrings() {
     println!("Initializing Candle adapter with Phi-3.5-mini-instruct model...");
     println!("(First run will download ~7.6GB model from HuggingFace)\n");
 
-    let candle_adapter = transcript_processor::adapters::candle_adapter::CandleTranscriptExtractorAdapter::new()
+    let candle_adapter = transcript_processor::adapters::candle_adapter::CandleTranscriptExtractorAdapter::new(
+        transcript_processor::adapters::candle_model_spec::CandleModelSpec::model_id_from_env(),
+    )
         .await
         .expect("Failed to initialize Candle adapter");
 